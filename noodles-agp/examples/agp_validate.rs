@@ -0,0 +1,33 @@
+//! Validates an AGP file against a FASTA file of its components.
+//!
+//! The FASTA must be indexed, i.e., `<fasta-src>.fai` must exist.
+
+use std::{
+    env,
+    fs::File,
+    io::{self, BufReader},
+};
+
+use noodles_agp as agp;
+use noodles_fasta::{self as fasta, repository::adapters::IndexedReader};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = env::args().skip(1);
+
+    let src = args.next().expect("missing src");
+    let fasta_src = args.next().expect("missing fasta-src");
+
+    let mut reader = File::open(src)
+        .map(BufReader::new)
+        .map(agp::io::Reader::new)?;
+    let records: Vec<_> = reader.records().collect::<io::Result<_>>()?;
+
+    let repository = fasta::io::indexed_reader::Builder::default()
+        .build_from_path(fasta_src)
+        .map(IndexedReader::new)
+        .map(fasta::Repository::new)?;
+
+    agp::validate(&records, &repository)?;
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+//! Prints all records in an AGP file.
+//!
+//! The result matches the output of `grep --invert-match "^#" <src>`.
+
+use std::{
+    env,
+    fs::File,
+    io::{self, BufReader},
+};
+
+use noodles_agp as agp;
+
+fn main() -> io::Result<()> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let mut reader = File::open(src)
+        .map(BufReader::new)
+        .map(agp::io::Reader::new)?;
+
+    let stdout = io::stdout().lock();
+    let mut writer = agp::io::Writer::new(stdout);
+
+    for result in reader.records() {
+        let record = result?;
+        writer.write_record(&record)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,6 @@
+//! AGP I/O.
+
+mod reader;
+mod writer;
+
+pub use self::{reader::Reader, writer::Writer};
@@ -0,0 +1,237 @@
+use std::{
+    io::{self, BufRead},
+    iter,
+};
+
+use crate::{Line, Record};
+
+/// An AGP reader.
+pub struct Reader<R> {
+    inner: R,
+}
+
+impl<R> Reader<R>
+where
+    R: BufRead,
+{
+    /// Creates an AGP reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_agp as agp;
+    /// let data = [];
+    /// let reader = agp::io::Reader::new(&data[..]);
+    /// ```
+    pub fn new(inner: R) -> Self {
+        Self { inner }
+    }
+
+    /// Returns a reference to the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_agp as agp;
+    /// let data = [];
+    /// let reader = agp::io::Reader::new(&data[..]);
+    /// assert!(reader.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_agp as agp;
+    /// let data = [];
+    /// let mut reader = agp::io::Reader::new(&data[..]);
+    /// assert!(reader.get_mut().is_empty());
+    /// ```
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_agp as agp;
+    /// let data = [];
+    /// let reader = agp::io::Reader::new(&data[..]);
+    /// assert!(reader.into_inner().is_empty());
+    /// ```
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Reads a raw AGP line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_agp as agp;
+    ///
+    /// let data = b"scf0\t1\t8\t1\tW\tctg0\t1\t8\t+\n";
+    /// let mut reader = agp::io::Reader::new(&data[..]);
+    ///
+    /// let mut buf = String::new();
+    /// reader.read_line(&mut buf)?;
+    ///
+    /// assert_eq!(buf, "scf0\t1\t8\t1\tW\tctg0\t1\t8\t+");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
+        read_line(&mut self.inner, buf)
+    }
+
+    /// Returns an iterator over lines starting from the current stream position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_agp as agp;
+    ///
+    /// let data = b"##agp-version\t2.1
+    /// scf0\t1\t8\t1\tW\tctg0\t1\t8\t+
+    /// ";
+    /// let mut reader = agp::io::Reader::new(&data[..]);
+    ///
+    /// let mut lines = reader.lines();
+    ///
+    /// let line = lines.next().transpose()?;
+    /// assert_eq!(line, Some(agp::Line::Comment(String::from("#agp-version\t2.1"))));
+    ///
+    /// let line = lines.next().transpose()?;
+    /// assert!(matches!(line, Some(agp::Line::Record(_))));
+    ///
+    /// assert!(lines.next().is_none());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn lines(&mut self) -> impl Iterator<Item = io::Result<Line>> + '_ {
+        let mut buf = String::new();
+
+        iter::from_fn(move || {
+            buf.clear();
+
+            match self.read_line(&mut buf) {
+                Ok(0) => None,
+                Ok(_) => Some(
+                    buf.parse()
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+                ),
+                Err(e) => Some(Err(e)),
+            }
+        })
+    }
+
+    /// Returns an iterator over records starting from the current stream position.
+    ///
+    /// Comments are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_agp as agp;
+    ///
+    /// let data = b"##agp-version\t2.1
+    /// scf0\t1\t8\t1\tW\tctg0\t1\t8\t+
+    /// ";
+    /// let mut reader = agp::io::Reader::new(&data[..]);
+    ///
+    /// let mut records = reader.records();
+    ///
+    /// let record = records.next().transpose()?;
+    /// assert_eq!(record.as_ref().map(|r| r.object()), Some("scf0"));
+    /// // ...
+    ///
+    /// assert!(records.next().is_none());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn records(&mut self) -> impl Iterator<Item = io::Result<Record>> + '_ {
+        let mut lines = self.lines();
+
+        iter::from_fn(move || loop {
+            match lines.next()? {
+                Ok(Line::Record(r)) => return Some(Ok(r)),
+                Ok(_) => {}
+                Err(e) => return Some(Err(e)),
+            }
+        })
+    }
+}
+
+fn read_line<R>(reader: &mut R, buf: &mut String) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    const LINE_FEED: char = '\n';
+    const CARRIAGE_RETURN: char = '\r';
+
+    match reader.read_line(buf) {
+        Ok(0) => Ok(0),
+        Ok(n) => {
+            if buf.ends_with(LINE_FEED) {
+                buf.pop();
+
+                if buf.ends_with(CARRIAGE_RETURN) {
+                    buf.pop();
+                }
+            }
+
+            Ok(n)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_line() -> io::Result<()> {
+        fn t(buf: &mut String, mut reader: &[u8], expected: &str) -> io::Result<()> {
+            buf.clear();
+            read_line(&mut reader, buf)?;
+            assert_eq!(buf, expected);
+            Ok(())
+        }
+
+        let mut buf = String::new();
+
+        t(&mut buf, b"noodles\n", "noodles")?;
+        t(&mut buf, b"noodles\r\n", "noodles")?;
+        t(&mut buf, b"noodles", "noodles")?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records() -> io::Result<()> {
+        let data = b"##agp-version\t2.1
+# ndls
+scf0\t1\t8\t1\tW\tctg0\t1\t8\t+
+scf0\t9\t108\t2\tN\t100\tscaffold\tyes\tpaired-ends
+scf0\t109\t113\t3\tW\tctg1\t1\t5\t-
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let records: Vec<_> = reader.records().collect::<io::Result<_>>()?;
+
+        assert_eq!(records.len(), 3);
+        assert_eq!(
+            records.iter().map(|r| r.part_number()).collect::<Vec<_>>(),
+            [1, 2, 3]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+use std::io::{self, Write};
+
+use crate::{Line, Record};
+
+/// An AGP writer.
+pub struct Writer<W> {
+    inner: W,
+}
+
+impl<W> Writer<W>
+where
+    W: Write,
+{
+    /// Creates an AGP writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_agp as agp;
+    /// let writer = agp::io::Writer::new(Vec::new());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_agp as agp;
+    /// let writer = agp::io::Writer::new(Vec::new());
+    /// assert!(writer.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_agp as agp;
+    /// let mut writer = agp::io::Writer::new(Vec::new());
+    /// assert!(writer.get_mut().is_empty());
+    /// ```
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_agp as agp;
+    /// let writer = agp::io::Writer::new(Vec::new());
+    /// assert!(writer.into_inner().is_empty());
+    /// ```
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Writes an AGP version pragma (`##agp-version`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_agp as agp;
+    /// let mut writer = agp::io::Writer::new(Vec::new());
+    /// writer.write_version()?;
+    /// assert_eq!(writer.get_ref(), b"##agp-version\t2.1\n");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_version(&mut self) -> io::Result<()> {
+        writeln!(self.inner, "##agp-version\t2.1")
+    }
+
+    /// Writes an AGP line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_agp as agp;
+    /// let mut writer = agp::io::Writer::new(Vec::new());
+    /// writer.write_line(&agp::Line::Comment(String::from(" ndls")))?;
+    /// assert_eq!(writer.get_ref(), b"# ndls\n");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_line(&mut self, line: &Line) -> io::Result<()> {
+        writeln!(self.inner, "{line}")
+    }
+
+    /// Writes an AGP record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_agp as agp;
+    ///
+    /// let record: agp::Record = "scf0\t1\t8\t1\tW\tctg0\t1\t8\t+".parse()?;
+    ///
+    /// let mut writer = agp::io::Writer::new(Vec::new());
+    /// writer.write_record(&record)?;
+    ///
+    /// assert_eq!(writer.get_ref(), b"scf0\t1\t8\t1\tW\tctg0\t1\t8\t+\n");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
+        writeln!(self.inner, "{record}")
+    }
+}
@@ -0,0 +1,32 @@
+#![warn(missing_docs)]
+
+//! **noodles-agp** handles the reading and writing of the AGP (A Golden Path) format.
+//!
+//! AGP describes the assembly of a larger sequence (an object, e.g., a scaffold or chromosome)
+//! from smaller sequences (components, e.g., contigs) and gaps. This implements AGP 2.1.
+//!
+//! # Examples
+//!
+//! ## Read all records in an AGP file
+//!
+//! ```no_run
+//! # use std::{fs::File, io::{self, BufReader}};
+//! use noodles_agp as agp;
+//!
+//! let mut reader = File::open("assembly.agp")
+//!     .map(BufReader::new)
+//!     .map(agp::io::Reader::new)?;
+//!
+//! for result in reader.records() {
+//!     let record = result?;
+//!     // ...
+//! }
+//! # Ok::<_, io::Error>(())
+//! ```
+
+pub mod io;
+pub mod line;
+pub mod record;
+pub mod validate;
+
+pub use self::{line::Line, record::Record, validate::validate};
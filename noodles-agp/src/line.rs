@@ -0,0 +1,91 @@
+//! AGP lines.
+
+use std::{error, fmt, str::FromStr};
+
+use super::{record, Record};
+
+const COMMENT_PREFIX: char = '#';
+
+/// An AGP line.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Line {
+    /// A comment (`#`).
+    ///
+    /// This includes pragmas, e.g., `##agp-version`.
+    Comment(String),
+    /// A record.
+    Record(Record),
+}
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Line::Comment(comment) => write!(f, "{COMMENT_PREFIX}{comment}"),
+            Line::Record(record) => write!(f, "{record}"),
+        }
+    }
+}
+
+/// An error returned when a raw AGP line fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The record is invalid.
+    InvalidRecord(record::ParseError),
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidRecord(e) => Some(e),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidRecord(_) => f.write_str("invalid record"),
+        }
+    }
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(t) = s.strip_prefix(COMMENT_PREFIX) {
+            Ok(Self::Comment(t.into()))
+        } else {
+            s.parse()
+                .map(Self::Record)
+                .map_err(ParseError::InvalidRecord)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        let line = Line::Comment(String::from("#agp-version\t2.1"));
+        assert_eq!(line.to_string(), "##agp-version\t2.1");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "##agp-version\t2.1".parse(),
+            Ok(Line::Comment(String::from("#agp-version\t2.1")))
+        );
+
+        let s = "scf0\t1\t8\t1\tW\tctg0\t1\t8\t+";
+        assert!(matches!(s.parse(), Ok(Line::Record(_))));
+
+        assert!(matches!(
+            "scf0".parse::<Line>(),
+            Err(ParseError::InvalidRecord(_))
+        ));
+    }
+}
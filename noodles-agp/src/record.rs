@@ -0,0 +1,322 @@
+//! AGP record and fields.
+
+pub mod component;
+pub mod component_type;
+
+pub use self::{component::Component, component_type::ComponentType};
+
+use std::{error, fmt, num, str::FromStr};
+
+use noodles_core::Position;
+
+const FIELD_DELIMITER: char = '\t';
+
+/// An AGP record.
+///
+/// A record describes either a component sequence or a gap that makes up a part of an object.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Record {
+    object: String,
+    object_start: Position,
+    object_end: Position,
+    part_number: usize,
+    component_type: ComponentType,
+    component: Component,
+}
+
+impl Record {
+    /// Creates an AGP record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_agp::{self as agp, record::{component::{self, Orientation}, Component, ComponentType}};
+    /// use noodles_core::Position;
+    ///
+    /// let record = agp::Record::new(
+    ///     "scf0",
+    ///     Position::MIN,
+    ///     Position::try_from(8)?,
+    ///     1,
+    ///     ComponentType::WholeGenomeShotgun,
+    ///     Component::Sequence(component::Sequence::new(
+    ///         "ctg0",
+    ///         Position::MIN,
+    ///         Position::try_from(8)?,
+    ///         Orientation::Forward,
+    ///     )),
+    /// );
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn new<O>(
+        object: O,
+        object_start: Position,
+        object_end: Position,
+        part_number: usize,
+        component_type: ComponentType,
+        component: Component,
+    ) -> Self
+    where
+        O: Into<String>,
+    {
+        Self {
+            object: object.into(),
+            object_start,
+            object_end,
+            part_number,
+            component_type,
+            component,
+        }
+    }
+
+    /// Returns the object identifier (`object`).
+    pub fn object(&self) -> &str {
+        &self.object
+    }
+
+    /// Returns the start position of the part in the object (`object_beg`).
+    pub fn object_start(&self) -> Position {
+        self.object_start
+    }
+
+    /// Returns the end position of the part in the object (`object_end`).
+    pub fn object_end(&self) -> Position {
+        self.object_end
+    }
+
+    /// Returns the length of the part in the object.
+    ///
+    /// This returns `None` if the end position is before the start position.
+    pub fn object_len(&self) -> Option<usize> {
+        usize::from(self.object_end)
+            .checked_sub(usize::from(self.object_start))
+            .map(|n| n + 1)
+    }
+
+    /// Returns the part number (`part_number`).
+    pub fn part_number(&self) -> usize {
+        self.part_number
+    }
+
+    /// Returns the component type (`component_type`).
+    pub fn component_type(&self) -> ComponentType {
+        self.component_type
+    }
+
+    /// Returns the component.
+    ///
+    /// This is either a component sequence or a gap, which describes columns 6–9.
+    pub fn component(&self) -> &Component {
+        &self.component
+    }
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{object}\t{object_start}\t{object_end}\t{part_number}\t{component_type}\t{component}",
+            object = self.object(),
+            object_start = self.object_start(),
+            object_end = self.object_end(),
+            part_number = self.part_number(),
+            component_type = self.component_type(),
+            component = self.component(),
+        )
+    }
+}
+
+/// An error returned when a raw AGP record fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The object is missing.
+    MissingObject,
+    /// The object start is missing.
+    MissingObjectStart,
+    /// The object start is invalid.
+    InvalidObjectStart(num::ParseIntError),
+    /// The object end is missing.
+    MissingObjectEnd,
+    /// The object end is invalid.
+    InvalidObjectEnd(num::ParseIntError),
+    /// The part number is missing.
+    MissingPartNumber,
+    /// The part number is invalid.
+    InvalidPartNumber(num::ParseIntError),
+    /// The component type is missing.
+    MissingComponentType,
+    /// The component type is invalid.
+    InvalidComponentType(component_type::ParseError),
+    /// The component is invalid.
+    InvalidComponent(component::ParseError),
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidObjectStart(e)
+            | Self::InvalidObjectEnd(e)
+            | Self::InvalidPartNumber(e) => Some(e),
+            Self::InvalidComponentType(e) => Some(e),
+            Self::InvalidComponent(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingObject => write!(f, "missing object"),
+            Self::MissingObjectStart => write!(f, "missing object start"),
+            Self::InvalidObjectStart(_) => write!(f, "invalid object start"),
+            Self::MissingObjectEnd => write!(f, "missing object end"),
+            Self::InvalidObjectEnd(_) => write!(f, "invalid object end"),
+            Self::MissingPartNumber => write!(f, "missing part number"),
+            Self::InvalidPartNumber(_) => write!(f, "invalid part number"),
+            Self::MissingComponentType => write!(f, "missing component type"),
+            Self::InvalidComponentType(_) => write!(f, "invalid component type"),
+            Self::InvalidComponent(_) => write!(f, "invalid component"),
+        }
+    }
+}
+
+impl FromStr for Record {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const MAX_FIELDS: usize = 6;
+
+        let mut fields = s.splitn(MAX_FIELDS, FIELD_DELIMITER);
+
+        let object = fields
+            .next()
+            .filter(|t| !t.is_empty())
+            .map(|t| t.into())
+            .ok_or(ParseError::MissingObject)?;
+
+        let object_start = fields
+            .next()
+            .ok_or(ParseError::MissingObjectStart)
+            .and_then(|t| t.parse().map_err(ParseError::InvalidObjectStart))?;
+
+        let object_end = fields
+            .next()
+            .ok_or(ParseError::MissingObjectEnd)
+            .and_then(|t| t.parse().map_err(ParseError::InvalidObjectEnd))?;
+
+        let part_number = fields
+            .next()
+            .ok_or(ParseError::MissingPartNumber)
+            .and_then(|t| t.parse().map_err(ParseError::InvalidPartNumber))?;
+
+        let component_type = fields
+            .next()
+            .ok_or(ParseError::MissingComponentType)
+            .and_then(|t| t.parse().map_err(ParseError::InvalidComponentType))?;
+
+        let component = component::parse_component(fields.next().unwrap_or(""), component_type)
+            .map_err(ParseError::InvalidComponent)?;
+
+        Ok(Self {
+            object,
+            object_start,
+            object_end,
+            part_number,
+            component_type,
+            component,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::component::{Gap, GapType, LinkageEvidence, Orientation, Sequence};
+
+    fn build_sequence_record() -> Result<Record, noodles_core::position::TryFromIntError> {
+        Ok(Record::new(
+            "scf0",
+            Position::MIN,
+            Position::try_from(8)?,
+            1,
+            ComponentType::WholeGenomeShotgun,
+            Component::Sequence(Sequence::new(
+                "ctg0",
+                Position::MIN,
+                Position::try_from(8)?,
+                Orientation::Forward,
+            )),
+        ))
+    }
+
+    fn build_gap_record() -> Result<Record, noodles_core::position::TryFromIntError> {
+        Ok(Record::new(
+            "scf0",
+            Position::try_from(9)?,
+            Position::try_from(108)?,
+            2,
+            ComponentType::Unknown,
+            Component::Gap(Gap::new(
+                100,
+                GapType::Scaffold,
+                true,
+                vec![LinkageEvidence::PairedEnds],
+            )),
+        ))
+    }
+
+    #[test]
+    fn test_object_len() -> Result<(), noodles_core::position::TryFromIntError> {
+        let record = build_sequence_record()?;
+        assert_eq!(record.object_len(), Some(8));
+        Ok(())
+    }
+
+    #[test]
+    fn test_fmt() -> Result<(), noodles_core::position::TryFromIntError> {
+        let record = build_sequence_record()?;
+        assert_eq!(record.to_string(), "scf0\t1\t8\t1\tW\tctg0\t1\t8\t+");
+
+        let record = build_gap_record()?;
+        assert_eq!(
+            record.to_string(),
+            "scf0\t9\t108\t2\tU\t100\tscaffold\tyes\tpaired-ends"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str() -> Result<(), noodles_core::position::TryFromIntError> {
+        assert_eq!(
+            "scf0\t1\t8\t1\tW\tctg0\t1\t8\t+".parse(),
+            Ok(build_sequence_record()?)
+        );
+
+        assert_eq!(
+            "scf0\t9\t108\t2\tU\t100\tscaffold\tyes\tpaired-ends".parse(),
+            Ok(build_gap_record()?)
+        );
+
+        assert_eq!("".parse::<Record>(), Err(ParseError::MissingObject));
+        assert_eq!(
+            "scf0".parse::<Record>(),
+            Err(ParseError::MissingObjectStart)
+        );
+        assert!(matches!(
+            "scf0\t.\t8\t1\tW\tctg0\t1\t8\t+".parse::<Record>(),
+            Err(ParseError::InvalidObjectStart(_))
+        ));
+        assert!(matches!(
+            "scf0\t1\t8\t1\tX\tctg0\t1\t8\t+".parse::<Record>(),
+            Err(ParseError::InvalidComponentType(_))
+        ));
+        assert!(matches!(
+            "scf0\t1\t8\t1\tW\tctg0\t1\t8".parse::<Record>(),
+            Err(ParseError::InvalidComponent(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,290 @@
+//! AGP record component.
+
+mod gap;
+pub mod gap_type;
+pub mod linkage_evidence;
+pub mod orientation;
+mod sequence;
+
+pub use self::{
+    gap::Gap, gap_type::GapType, linkage_evidence::LinkageEvidence, orientation::Orientation,
+    sequence::Sequence,
+};
+
+use std::{error, fmt, num};
+
+use super::{ComponentType, FIELD_DELIMITER};
+
+const MISSING: &str = "na";
+
+/// An AGP record component (columns 6–9).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Component {
+    /// A component sequence.
+    Sequence(Sequence),
+    /// A gap.
+    Gap(Gap),
+}
+
+impl fmt::Display for Component {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sequence(sequence) => write!(
+                f,
+                "{id}\t{start}\t{end}\t{orientation}",
+                id = sequence.id(),
+                start = sequence.start(),
+                end = sequence.end(),
+                orientation = sequence.orientation(),
+            ),
+            Self::Gap(gap) => {
+                write!(
+                    f,
+                    "{length}\t{gap_type}\t{linkage}\t",
+                    length = gap.length(),
+                    gap_type = gap.gap_type(),
+                    linkage = if gap.linkage() { "yes" } else { "no" },
+                )?;
+
+                if gap.linkage_evidence().is_empty() {
+                    f.write_str(MISSING)?;
+                } else {
+                    for (i, evidence) in gap.linkage_evidence().iter().enumerate() {
+                        if i > 0 {
+                            f.write_str(";")?;
+                        }
+
+                        write!(f, "{evidence}")?;
+                    }
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+/// An error returned when a raw AGP record component fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The component ID is missing.
+    MissingId,
+    /// The component start is missing.
+    MissingStart,
+    /// The component start is invalid.
+    InvalidStart(num::ParseIntError),
+    /// The component end is missing.
+    MissingEnd,
+    /// The component end is invalid.
+    InvalidEnd(num::ParseIntError),
+    /// The orientation is missing.
+    MissingOrientation,
+    /// The orientation is invalid.
+    InvalidOrientation(orientation::ParseError),
+    /// The gap length is missing.
+    MissingGapLength,
+    /// The gap length is invalid.
+    InvalidGapLength(num::ParseIntError),
+    /// The gap type is missing.
+    MissingGapType,
+    /// The gap type is invalid.
+    InvalidGapType(gap_type::ParseError),
+    /// The linkage is missing.
+    MissingLinkage,
+    /// The linkage is invalid.
+    InvalidLinkage,
+    /// The linkage evidence is missing.
+    MissingLinkageEvidence,
+    /// The linkage evidence is invalid.
+    InvalidLinkageEvidence(linkage_evidence::ParseError),
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidStart(e) | Self::InvalidEnd(e) | Self::InvalidGapLength(e) => Some(e),
+            Self::InvalidOrientation(e) => Some(e),
+            Self::InvalidGapType(e) => Some(e),
+            Self::InvalidLinkageEvidence(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingId => write!(f, "missing component ID"),
+            Self::MissingStart => write!(f, "missing component start"),
+            Self::InvalidStart(_) => write!(f, "invalid component start"),
+            Self::MissingEnd => write!(f, "missing component end"),
+            Self::InvalidEnd(_) => write!(f, "invalid component end"),
+            Self::MissingOrientation => write!(f, "missing orientation"),
+            Self::InvalidOrientation(_) => write!(f, "invalid orientation"),
+            Self::MissingGapLength => write!(f, "missing gap length"),
+            Self::InvalidGapLength(_) => write!(f, "invalid gap length"),
+            Self::MissingGapType => write!(f, "missing gap type"),
+            Self::InvalidGapType(_) => write!(f, "invalid gap type"),
+            Self::MissingLinkage => write!(f, "missing linkage"),
+            Self::InvalidLinkage => write!(f, "invalid linkage"),
+            Self::MissingLinkageEvidence => write!(f, "missing linkage evidence"),
+            Self::InvalidLinkageEvidence(_) => write!(f, "invalid linkage evidence"),
+        }
+    }
+}
+
+pub(super) fn parse_component(
+    s: &str,
+    component_type: ComponentType,
+) -> Result<Component, ParseError> {
+    let mut fields = s.split(FIELD_DELIMITER);
+
+    if component_type.is_gap() {
+        parse_gap(&mut fields).map(Component::Gap)
+    } else {
+        parse_sequence(&mut fields).map(Component::Sequence)
+    }
+}
+
+fn parse_sequence<'a, I>(fields: &mut I) -> Result<Sequence, ParseError>
+where
+    I: Iterator<Item = &'a str>,
+{
+    let id = fields
+        .next()
+        .filter(|t| !t.is_empty())
+        .ok_or(ParseError::MissingId)?;
+
+    let start = fields
+        .next()
+        .ok_or(ParseError::MissingStart)
+        .and_then(|t| t.parse().map_err(ParseError::InvalidStart))?;
+
+    let end = fields
+        .next()
+        .ok_or(ParseError::MissingEnd)
+        .and_then(|t| t.parse().map_err(ParseError::InvalidEnd))?;
+
+    let orientation = fields
+        .next()
+        .ok_or(ParseError::MissingOrientation)
+        .and_then(|t| t.parse().map_err(ParseError::InvalidOrientation))?;
+
+    Ok(Sequence::new(id, start, end, orientation))
+}
+
+fn parse_gap<'a, I>(fields: &mut I) -> Result<Gap, ParseError>
+where
+    I: Iterator<Item = &'a str>,
+{
+    let length = fields
+        .next()
+        .ok_or(ParseError::MissingGapLength)
+        .and_then(|t| t.parse().map_err(ParseError::InvalidGapLength))?;
+
+    let gap_type = fields
+        .next()
+        .ok_or(ParseError::MissingGapType)
+        .and_then(|t| t.parse().map_err(ParseError::InvalidGapType))?;
+
+    let linkage = fields
+        .next()
+        .ok_or(ParseError::MissingLinkage)
+        .and_then(|t| match t {
+            "yes" => Ok(true),
+            "no" => Ok(false),
+            _ => Err(ParseError::InvalidLinkage),
+        })?;
+
+    let linkage_evidence = fields
+        .next()
+        .ok_or(ParseError::MissingLinkageEvidence)
+        .and_then(parse_linkage_evidence)?;
+
+    Ok(Gap::new(length, gap_type, linkage, linkage_evidence))
+}
+
+fn parse_linkage_evidence(s: &str) -> Result<Vec<LinkageEvidence>, ParseError> {
+    const DELIMITER: char = ';';
+
+    if s == MISSING {
+        Ok(Vec::new())
+    } else {
+        s.split(DELIMITER)
+            .map(|t| t.parse().map_err(ParseError::InvalidLinkageEvidence))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+
+    #[test]
+    fn test_fmt() -> Result<(), noodles_core::position::TryFromIntError> {
+        let component = Component::Sequence(Sequence::new(
+            "ctg0",
+            Position::MIN,
+            Position::try_from(8)?,
+            Orientation::Reverse,
+        ));
+        assert_eq!(component.to_string(), "ctg0\t1\t8\t-");
+
+        let component = Component::Gap(Gap::new(100, GapType::Contig, false, Vec::new()));
+        assert_eq!(component.to_string(), "100\tcontig\tno\tna");
+
+        let component = Component::Gap(Gap::new(
+            100,
+            GapType::Scaffold,
+            true,
+            vec![LinkageEvidence::PairedEnds, LinkageEvidence::Map],
+        ));
+        assert_eq!(component.to_string(), "100\tscaffold\tyes\tpaired-ends;map");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_component() -> Result<(), noodles_core::position::TryFromIntError> {
+        assert_eq!(
+            parse_component("ctg0\t1\t8\t?", ComponentType::WholeGenomeShotgun),
+            Ok(Component::Sequence(Sequence::new(
+                "ctg0",
+                Position::MIN,
+                Position::try_from(8)?,
+                Orientation::Unknown,
+            )))
+        );
+
+        assert_eq!(
+            parse_component("100\tcontig\tno\tna", ComponentType::SpecifiedGap),
+            Ok(Component::Gap(Gap::new(
+                100,
+                GapType::Contig,
+                false,
+                Vec::new()
+            )))
+        );
+
+        assert_eq!(
+            parse_component("", ComponentType::WholeGenomeShotgun),
+            Err(ParseError::MissingId)
+        );
+        assert_eq!(
+            parse_component("ctg0\t1\t8", ComponentType::WholeGenomeShotgun),
+            Err(ParseError::MissingOrientation)
+        );
+        assert_eq!(
+            parse_component("100\tcontig\tmaybe\tna", ComponentType::SpecifiedGap),
+            Err(ParseError::InvalidLinkage)
+        );
+        assert!(matches!(
+            parse_component("100\tcontig\tyes\tndls", ComponentType::SpecifiedGap),
+            Err(ParseError::InvalidLinkageEvidence(_))
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use super::{GapType, LinkageEvidence};
+
+/// An AGP record gap.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Gap {
+    length: usize,
+    gap_type: GapType,
+    linkage: bool,
+    linkage_evidence: Vec<LinkageEvidence>,
+}
+
+impl Gap {
+    /// Creates an AGP record gap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_agp::record::component::{Gap, GapType};
+    /// let gap = Gap::new(100, GapType::Contig, false, Vec::new());
+    /// ```
+    pub fn new(
+        length: usize,
+        gap_type: GapType,
+        linkage: bool,
+        linkage_evidence: Vec<LinkageEvidence>,
+    ) -> Self {
+        Self {
+            length,
+            gap_type,
+            linkage,
+            linkage_evidence,
+        }
+    }
+
+    /// Returns the gap length (`gap_length`).
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /// Returns the gap type (`gap_type`).
+    pub fn gap_type(&self) -> GapType {
+        self.gap_type
+    }
+
+    /// Returns whether there is evidence of linkage between the adjacent components (`linkage`).
+    pub fn linkage(&self) -> bool {
+        self.linkage
+    }
+
+    /// Returns the linkage evidence (`linkage_evidence`).
+    ///
+    /// This is empty when the linkage evidence is missing (`na`).
+    pub fn linkage_evidence(&self) -> &[LinkageEvidence] {
+        &self.linkage_evidence
+    }
+}
@@ -0,0 +1,116 @@
+//! AGP record gap type.
+
+use std::{error, fmt, str::FromStr};
+
+/// An AGP record gap type (`gap_type`).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum GapType {
+    /// A gap between two sequence contigs in a scaffold (`scaffold`).
+    Scaffold,
+    /// An unspanned gap between two sequence contigs (`contig`).
+    Contig,
+    /// A gap inserted for the centromere (`centromere`).
+    Centromere,
+    /// A gap inserted at the start of an acrocentric chromosome (`short_arm`).
+    ShortArm,
+    /// A heterochromatin gap (`heterochromatin`).
+    Heterochromatin,
+    /// A gap inserted for the telomere (`telomere`).
+    Telomere,
+    /// An unresolvable repeat (`repeat`).
+    Repeat,
+    /// A gap inserted in place of foreign sequence (`contamination`).
+    Contamination,
+}
+
+impl AsRef<str> for GapType {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Scaffold => "scaffold",
+            Self::Contig => "contig",
+            Self::Centromere => "centromere",
+            Self::ShortArm => "short_arm",
+            Self::Heterochromatin => "heterochromatin",
+            Self::Telomere => "telomere",
+            Self::Repeat => "repeat",
+            Self::Contamination => "contamination",
+        }
+    }
+}
+
+impl fmt::Display for GapType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// An error returned when a raw AGP record gap type fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The input is invalid.
+    Invalid,
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::Invalid => f.write_str("invalid input"),
+        }
+    }
+}
+
+impl FromStr for GapType {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err(ParseError::Empty),
+            "scaffold" => Ok(Self::Scaffold),
+            "contig" => Ok(Self::Contig),
+            "centromere" => Ok(Self::Centromere),
+            "short_arm" => Ok(Self::ShortArm),
+            "heterochromatin" => Ok(Self::Heterochromatin),
+            "telomere" => Ok(Self::Telomere),
+            "repeat" => Ok(Self::Repeat),
+            "contamination" => Ok(Self::Contamination),
+            _ => Err(ParseError::Invalid),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(GapType::Scaffold.to_string(), "scaffold");
+        assert_eq!(GapType::Contig.to_string(), "contig");
+        assert_eq!(GapType::Centromere.to_string(), "centromere");
+        assert_eq!(GapType::ShortArm.to_string(), "short_arm");
+        assert_eq!(GapType::Heterochromatin.to_string(), "heterochromatin");
+        assert_eq!(GapType::Telomere.to_string(), "telomere");
+        assert_eq!(GapType::Repeat.to_string(), "repeat");
+        assert_eq!(GapType::Contamination.to_string(), "contamination");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("scaffold".parse(), Ok(GapType::Scaffold));
+        assert_eq!("contig".parse(), Ok(GapType::Contig));
+        assert_eq!("centromere".parse(), Ok(GapType::Centromere));
+        assert_eq!("short_arm".parse(), Ok(GapType::ShortArm));
+        assert_eq!("heterochromatin".parse(), Ok(GapType::Heterochromatin));
+        assert_eq!("telomere".parse(), Ok(GapType::Telomere));
+        assert_eq!("repeat".parse(), Ok(GapType::Repeat));
+        assert_eq!("contamination".parse(), Ok(GapType::Contamination));
+
+        assert_eq!("".parse::<GapType>(), Err(ParseError::Empty));
+        assert_eq!("fragment".parse::<GapType>(), Err(ParseError::Invalid));
+    }
+}
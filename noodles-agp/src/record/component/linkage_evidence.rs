@@ -0,0 +1,125 @@
+//! AGP record gap linkage evidence.
+
+use std::{error, fmt, str::FromStr};
+
+/// An AGP record gap linkage evidence (`linkage_evidence`).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LinkageEvidence {
+    /// Paired sequences from the two ends of a DNA fragment (`paired-ends`).
+    PairedEnds,
+    /// Alignment to a reference genome within the same genus (`align_genus`).
+    AlignGenus,
+    /// Alignment to a reference genome within another genus (`align_xgenus`).
+    AlignXgenus,
+    /// Alignment to a transcript from the same species (`align_trnscpt`).
+    AlignTranscript,
+    /// Sequence on both sides of the gap is derived from the same clone (`within_clone`).
+    WithinClone,
+    /// Linkage is provided by a clone contig in the tiling path (`clone_contig`).
+    CloneContig,
+    /// Linkage asserted using a non-sequence based map (`map`).
+    Map,
+    /// PCR using primers on both sides of the gap (`pcr`).
+    Pcr,
+    /// Ligation segments derived from the same chromatin interaction (`proximity_ligation`).
+    ProximityLigation,
+    /// Strobe sequencing (`strobe`).
+    Strobe,
+    /// Unspecified (`unspecified`).
+    Unspecified,
+}
+
+impl AsRef<str> for LinkageEvidence {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::PairedEnds => "paired-ends",
+            Self::AlignGenus => "align_genus",
+            Self::AlignXgenus => "align_xgenus",
+            Self::AlignTranscript => "align_trnscpt",
+            Self::WithinClone => "within_clone",
+            Self::CloneContig => "clone_contig",
+            Self::Map => "map",
+            Self::Pcr => "pcr",
+            Self::ProximityLigation => "proximity_ligation",
+            Self::Strobe => "strobe",
+            Self::Unspecified => "unspecified",
+        }
+    }
+}
+
+impl fmt::Display for LinkageEvidence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// An error returned when a raw AGP record gap linkage evidence fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The input is invalid.
+    Invalid,
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::Invalid => f.write_str("invalid input"),
+        }
+    }
+}
+
+impl FromStr for LinkageEvidence {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err(ParseError::Empty),
+            "paired-ends" => Ok(Self::PairedEnds),
+            "align_genus" => Ok(Self::AlignGenus),
+            "align_xgenus" => Ok(Self::AlignXgenus),
+            "align_trnscpt" => Ok(Self::AlignTranscript),
+            "within_clone" => Ok(Self::WithinClone),
+            "clone_contig" => Ok(Self::CloneContig),
+            "map" => Ok(Self::Map),
+            "pcr" => Ok(Self::Pcr),
+            "proximity_ligation" => Ok(Self::ProximityLigation),
+            "strobe" => Ok(Self::Strobe),
+            "unspecified" => Ok(Self::Unspecified),
+            _ => Err(ParseError::Invalid),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("paired-ends".parse(), Ok(LinkageEvidence::PairedEnds));
+        assert_eq!("align_genus".parse(), Ok(LinkageEvidence::AlignGenus));
+        assert_eq!("align_xgenus".parse(), Ok(LinkageEvidence::AlignXgenus));
+        assert_eq!(
+            "align_trnscpt".parse(),
+            Ok(LinkageEvidence::AlignTranscript)
+        );
+        assert_eq!("within_clone".parse(), Ok(LinkageEvidence::WithinClone));
+        assert_eq!("clone_contig".parse(), Ok(LinkageEvidence::CloneContig));
+        assert_eq!("map".parse(), Ok(LinkageEvidence::Map));
+        assert_eq!("pcr".parse(), Ok(LinkageEvidence::Pcr));
+        assert_eq!(
+            "proximity_ligation".parse(),
+            Ok(LinkageEvidence::ProximityLigation)
+        );
+        assert_eq!("strobe".parse(), Ok(LinkageEvidence::Strobe));
+        assert_eq!("unspecified".parse(), Ok(LinkageEvidence::Unspecified));
+
+        assert_eq!("".parse::<LinkageEvidence>(), Err(ParseError::Empty));
+        assert_eq!("na".parse::<LinkageEvidence>(), Err(ParseError::Invalid));
+    }
+}
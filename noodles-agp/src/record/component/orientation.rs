@@ -0,0 +1,95 @@
+//! AGP record component orientation.
+
+use std::{error, fmt, str::FromStr};
+
+/// An AGP record component orientation (`orientation`).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Orientation {
+    /// Forward (`+`).
+    Forward,
+    /// Reverse (`-`).
+    Reverse,
+    /// Unknown (`?`).
+    ///
+    /// The deprecated value `0` is also parsed as unknown.
+    Unknown,
+    /// Irrelevant (`na`).
+    Irrelevant,
+}
+
+impl AsRef<str> for Orientation {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Forward => "+",
+            Self::Reverse => "-",
+            Self::Unknown => "?",
+            Self::Irrelevant => "na",
+        }
+    }
+}
+
+impl fmt::Display for Orientation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// An error returned when a raw AGP record component orientation fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The input is invalid.
+    Invalid,
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::Invalid => f.write_str("invalid input"),
+        }
+    }
+}
+
+impl FromStr for Orientation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err(ParseError::Empty),
+            "+" => Ok(Self::Forward),
+            "-" => Ok(Self::Reverse),
+            "?" | "0" => Ok(Self::Unknown),
+            "na" => Ok(Self::Irrelevant),
+            _ => Err(ParseError::Invalid),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Orientation::Forward.to_string(), "+");
+        assert_eq!(Orientation::Reverse.to_string(), "-");
+        assert_eq!(Orientation::Unknown.to_string(), "?");
+        assert_eq!(Orientation::Irrelevant.to_string(), "na");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("+".parse(), Ok(Orientation::Forward));
+        assert_eq!("-".parse(), Ok(Orientation::Reverse));
+        assert_eq!("?".parse(), Ok(Orientation::Unknown));
+        assert_eq!("0".parse(), Ok(Orientation::Unknown));
+        assert_eq!("na".parse(), Ok(Orientation::Irrelevant));
+
+        assert_eq!("".parse::<Orientation>(), Err(ParseError::Empty));
+        assert_eq!("ndls".parse::<Orientation>(), Err(ParseError::Invalid));
+    }
+}
@@ -0,0 +1,76 @@
+use noodles_core::Position;
+
+use super::Orientation;
+
+/// An AGP record component sequence.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sequence {
+    id: String,
+    start: Position,
+    end: Position,
+    orientation: Orientation,
+}
+
+impl Sequence {
+    /// Creates an AGP record component sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_agp::record::component::{Orientation, Sequence};
+    /// use noodles_core::Position;
+    /// let sequence = Sequence::new("ctg0", Position::MIN, Position::try_from(8)?, Orientation::Forward);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn new<I>(id: I, start: Position, end: Position, orientation: Orientation) -> Self
+    where
+        I: Into<String>,
+    {
+        Self {
+            id: id.into(),
+            start,
+            end,
+            orientation,
+        }
+    }
+
+    /// Returns the component ID (`component_id`).
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the start position in the component (`component_beg`).
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Returns the end position in the component (`component_end`).
+    pub fn end(&self) -> Position {
+        self.end
+    }
+
+    /// Returns the length of the used span of the component.
+    ///
+    /// This returns `None` if the end position is before the start position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_agp::record::component::{Orientation, Sequence};
+    /// use noodles_core::Position;
+    /// let sequence = Sequence::new("ctg0", Position::try_from(3)?, Position::try_from(8)?, Orientation::Forward);
+    /// assert_eq!(sequence.len(), Some(6));
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> Option<usize> {
+        usize::from(self.end)
+            .checked_sub(usize::from(self.start))
+            .map(|n| n + 1)
+    }
+
+    /// Returns the orientation of the component relative to the object (`orientation`).
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+}
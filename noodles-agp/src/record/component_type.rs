@@ -0,0 +1,138 @@
+//! AGP record component type.
+
+use std::{error, fmt, str::FromStr};
+
+/// An AGP record component type (`component_type`).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ComponentType {
+    /// Active finishing (`A`).
+    ActiveFinishing,
+    /// Draft HTG (`D`).
+    Draft,
+    /// Finished HTG (`F`).
+    Finished,
+    /// Whole genome finishing (`G`).
+    WholeGenomeFinishing,
+    /// Other sequence (`O`).
+    Other,
+    /// Pre-draft (`P`).
+    PreDraft,
+    /// WGS contig (`W`).
+    WholeGenomeShotgun,
+    /// Gap with specified size (`N`).
+    SpecifiedGap,
+    /// Gap of unknown size (`U`).
+    Unknown,
+}
+
+impl ComponentType {
+    /// Returns whether the component type describes a gap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_agp::record::ComponentType;
+    /// assert!(ComponentType::SpecifiedGap.is_gap());
+    /// assert!(ComponentType::Unknown.is_gap());
+    /// assert!(!ComponentType::WholeGenomeShotgun.is_gap());
+    /// ```
+    pub fn is_gap(&self) -> bool {
+        matches!(self, Self::SpecifiedGap | Self::Unknown)
+    }
+}
+
+impl AsRef<str> for ComponentType {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::ActiveFinishing => "A",
+            Self::Draft => "D",
+            Self::Finished => "F",
+            Self::WholeGenomeFinishing => "G",
+            Self::Other => "O",
+            Self::PreDraft => "P",
+            Self::WholeGenomeShotgun => "W",
+            Self::SpecifiedGap => "N",
+            Self::Unknown => "U",
+        }
+    }
+}
+
+impl fmt::Display for ComponentType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// An error returned when a raw AGP record component type fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The input is invalid.
+    Invalid,
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::Invalid => f.write_str("invalid input"),
+        }
+    }
+}
+
+impl FromStr for ComponentType {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err(ParseError::Empty),
+            "A" => Ok(Self::ActiveFinishing),
+            "D" => Ok(Self::Draft),
+            "F" => Ok(Self::Finished),
+            "G" => Ok(Self::WholeGenomeFinishing),
+            "O" => Ok(Self::Other),
+            "P" => Ok(Self::PreDraft),
+            "W" => Ok(Self::WholeGenomeShotgun),
+            "N" => Ok(Self::SpecifiedGap),
+            "U" => Ok(Self::Unknown),
+            _ => Err(ParseError::Invalid),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(ComponentType::ActiveFinishing.to_string(), "A");
+        assert_eq!(ComponentType::Draft.to_string(), "D");
+        assert_eq!(ComponentType::Finished.to_string(), "F");
+        assert_eq!(ComponentType::WholeGenomeFinishing.to_string(), "G");
+        assert_eq!(ComponentType::Other.to_string(), "O");
+        assert_eq!(ComponentType::PreDraft.to_string(), "P");
+        assert_eq!(ComponentType::WholeGenomeShotgun.to_string(), "W");
+        assert_eq!(ComponentType::SpecifiedGap.to_string(), "N");
+        assert_eq!(ComponentType::Unknown.to_string(), "U");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("A".parse(), Ok(ComponentType::ActiveFinishing));
+        assert_eq!("D".parse(), Ok(ComponentType::Draft));
+        assert_eq!("F".parse(), Ok(ComponentType::Finished));
+        assert_eq!("G".parse(), Ok(ComponentType::WholeGenomeFinishing));
+        assert_eq!("O".parse(), Ok(ComponentType::Other));
+        assert_eq!("P".parse(), Ok(ComponentType::PreDraft));
+        assert_eq!("W".parse(), Ok(ComponentType::WholeGenomeShotgun));
+        assert_eq!("N".parse(), Ok(ComponentType::SpecifiedGap));
+        assert_eq!("U".parse(), Ok(ComponentType::Unknown));
+
+        assert_eq!("".parse::<ComponentType>(), Err(ParseError::Empty));
+        assert_eq!("w".parse::<ComponentType>(), Err(ParseError::Invalid));
+    }
+}
@@ -0,0 +1,286 @@
+//! AGP validation.
+
+use std::{error, fmt, io};
+
+use noodles_fasta as fasta;
+
+use crate::{record::Component, Record};
+
+/// An error returned when AGP records fail validation.
+#[derive(Debug)]
+pub enum ValidationError {
+    /// The object end is before the object start.
+    InvalidObjectInterval {
+        /// The object identifier.
+        object: String,
+        /// The part number.
+        part_number: usize,
+    },
+    /// The part number does not follow the previous part number of the object.
+    InvalidPartNumber {
+        /// The object identifier.
+        object: String,
+        /// The part number.
+        part_number: usize,
+    },
+    /// The part does not start directly after the end of the previous part of the object.
+    NonContiguousObject {
+        /// The object identifier.
+        object: String,
+        /// The part number.
+        part_number: usize,
+    },
+    /// The length of the part in the object does not match the component or gap length.
+    LengthMismatch {
+        /// The object identifier.
+        object: String,
+        /// The part number.
+        part_number: usize,
+    },
+    /// The component sequence does not exist in the repository.
+    MissingComponentSequence(String),
+    /// The component span is out of bounds of the component sequence.
+    ComponentOutOfBounds {
+        /// The component ID.
+        id: String,
+        /// The length of the component sequence.
+        sequence_len: usize,
+    },
+    /// An I/O error occurred when reading a component sequence.
+    Io(io::Error),
+}
+
+impl error::Error for ValidationError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidObjectInterval {
+                object,
+                part_number,
+            } => write!(f, "invalid object interval: {object} part {part_number}"),
+            Self::InvalidPartNumber {
+                object,
+                part_number,
+            } => write!(f, "invalid part number: {object} part {part_number}"),
+            Self::NonContiguousObject {
+                object,
+                part_number,
+            } => write!(f, "non-contiguous object: {object} part {part_number}"),
+            Self::LengthMismatch {
+                object,
+                part_number,
+            } => write!(f, "length mismatch: {object} part {part_number}"),
+            Self::MissingComponentSequence(id) => write!(f, "missing component sequence: {id}"),
+            Self::ComponentOutOfBounds { id, sequence_len } => write!(
+                f,
+                "component out of bounds: {id} (sequence length = {sequence_len})"
+            ),
+            Self::Io(_) => write!(f, "I/O error"),
+        }
+    }
+}
+
+/// Validates AGP records against a sequence repository.
+///
+/// This checks that
+///
+///   1. the parts of each object are numbered sequentially from 1 and contiguously cover the
+///      object starting at position 1;
+///   2. the length of each part in the object equals the length of its component span or gap;
+///      and
+///   3. each component sequence exists in the repository and the component span is within its
+///      bounds.
+///
+/// Records of an object are expected to be grouped together and ordered by part number.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_agp as agp;
+/// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+///
+/// let records: Vec<agp::Record> = vec![
+///     "scf0\t1\t4\t1\tW\tctg0\t1\t4\t+".parse()?,
+///     "scf0\t5\t14\t2\tN\t10\tscaffold\tyes\tpaired-ends".parse()?,
+///     "scf0\t15\t16\t3\tW\tctg1\t3\t4\t-".parse()?,
+/// ];
+///
+/// let repository = fasta::Repository::new(vec![
+///     fasta::Record::new(Definition::new("ctg0", None), Sequence::from(b"ACGT".to_vec())),
+///     fasta::Record::new(Definition::new("ctg1", None), Sequence::from(b"TTGA".to_vec())),
+/// ]);
+///
+/// agp::validate(&records, &repository)?;
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn validate<'a, I>(records: I, repository: &fasta::Repository) -> Result<(), ValidationError>
+where
+    I: IntoIterator<Item = &'a Record>,
+{
+    let mut previous: Option<&Record> = None;
+
+    for record in records {
+        validate_part(previous, record)?;
+        validate_component(record, repository)?;
+        previous = Some(record);
+    }
+
+    Ok(())
+}
+
+fn validate_part(previous: Option<&Record>, record: &Record) -> Result<(), ValidationError> {
+    let object_len = record
+        .object_len()
+        .ok_or_else(|| ValidationError::InvalidObjectInterval {
+            object: record.object().into(),
+            part_number: record.part_number(),
+        })?;
+
+    let (expected_part_number, expected_object_start) = match previous {
+        Some(prev) if prev.object() == record.object() => {
+            (prev.part_number() + 1, usize::from(prev.object_end()) + 1)
+        }
+        _ => (1, 1),
+    };
+
+    if record.part_number() != expected_part_number {
+        return Err(ValidationError::InvalidPartNumber {
+            object: record.object().into(),
+            part_number: record.part_number(),
+        });
+    }
+
+    if usize::from(record.object_start()) != expected_object_start {
+        return Err(ValidationError::NonContiguousObject {
+            object: record.object().into(),
+            part_number: record.part_number(),
+        });
+    }
+
+    let part_len = match record.component() {
+        Component::Sequence(sequence) => sequence.len(),
+        Component::Gap(gap) => Some(gap.length()),
+    };
+
+    if part_len != Some(object_len) {
+        return Err(ValidationError::LengthMismatch {
+            object: record.object().into(),
+            part_number: record.part_number(),
+        });
+    }
+
+    Ok(())
+}
+
+fn validate_component(
+    record: &Record,
+    repository: &fasta::Repository,
+) -> Result<(), ValidationError> {
+    let Component::Sequence(component) = record.component() else {
+        return Ok(());
+    };
+
+    let sequence = repository
+        .get(component.id().as_bytes())
+        .ok_or_else(|| ValidationError::MissingComponentSequence(component.id().into()))?
+        .map_err(ValidationError::Io)?;
+
+    if usize::from(component.end()) > sequence.len() {
+        return Err(ValidationError::ComponentOutOfBounds {
+            id: component.id().into(),
+            sequence_len: sequence.len(),
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use fasta::record::{Definition, Sequence};
+
+    use super::*;
+
+    fn build_repository() -> fasta::Repository {
+        fasta::Repository::new(vec![
+            fasta::Record::new(
+                Definition::new("ctg0", None),
+                Sequence::from(b"ACGT".to_vec()),
+            ),
+            fasta::Record::new(
+                Definition::new("ctg1", None),
+                Sequence::from(b"TTGA".to_vec()),
+            ),
+        ])
+    }
+
+    fn parse_records(lines: &[&str]) -> Vec<Record> {
+        lines.iter().map(|s| s.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_validate() {
+        let repository = build_repository();
+
+        let records = parse_records(&[
+            "scf0\t1\t4\t1\tW\tctg0\t1\t4\t+",
+            "scf0\t5\t104\t2\tU\t100\tscaffold\tyes\tpaired-ends",
+            "scf0\t105\t106\t3\tW\tctg1\t3\t4\t-",
+            "scf1\t1\t4\t1\tW\tctg1\t1\t4\t+",
+        ]);
+        assert!(validate(&records, &repository).is_ok());
+
+        let records = parse_records(&[
+            "scf0\t1\t4\t1\tW\tctg0\t1\t4\t+",
+            "scf0\t5\t6\t3\tW\tctg1\t1\t2\t+",
+        ]);
+        assert!(matches!(
+            validate(&records, &repository),
+            Err(ValidationError::InvalidPartNumber { part_number: 3, .. })
+        ));
+
+        let records = parse_records(&[
+            "scf0\t1\t4\t1\tW\tctg0\t1\t4\t+",
+            "scf0\t6\t7\t2\tW\tctg1\t1\t2\t+",
+        ]);
+        assert!(matches!(
+            validate(&records, &repository),
+            Err(ValidationError::NonContiguousObject { part_number: 2, .. })
+        ));
+
+        let records = parse_records(&["scf0\t1\t4\t1\tW\tctg0\t1\t3\t+"]);
+        assert!(matches!(
+            validate(&records, &repository),
+            Err(ValidationError::LengthMismatch { part_number: 1, .. })
+        ));
+
+        let records = parse_records(&["scf0\t4\t1\t1\tW\tctg0\t1\t4\t+"]);
+        assert!(matches!(
+            validate(&records, &repository),
+            Err(ValidationError::InvalidObjectInterval { part_number: 1, .. })
+        ));
+
+        let records = parse_records(&["scf0\t1\t4\t1\tW\tctg2\t1\t4\t+"]);
+        assert!(matches!(
+            validate(&records, &repository),
+            Err(ValidationError::MissingComponentSequence(id)) if id == "ctg2"
+        ));
+
+        let records = parse_records(&["scf0\t1\t5\t1\tW\tctg0\t1\t5\t+"]);
+        assert!(matches!(
+            validate(&records, &repository),
+            Err(ValidationError::ComponentOutOfBounds {
+                sequence_len: 4,
+                ..
+            })
+        ));
+    }
+}
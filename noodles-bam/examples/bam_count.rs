@@ -9,7 +9,7 @@ use noodles_bam as bam;
 fn main() -> io::Result<()> {
     let src = env::args().nth(1).expect("missing src");
 
-    let mut reader = bam::io::reader::Builder.build_from_path(src)?;
+    let mut reader = bam::io::reader::Builder::default().build_from_path(src)?;
     reader.read_header()?;
 
     let mut n = 0;
@@ -203,7 +203,7 @@ fn print_stats(qc_pass_counts: &Counts, qc_fail_counts: &Counts) {
 fn main() -> io::Result<()> {
     let src = env::args().nth(1).expect("missing src");
 
-    let mut reader = bam::io::reader::Builder.build_from_path(src)?;
+    let mut reader = bam::io::reader::Builder::default().build_from_path(src)?;
     reader.read_header()?;
 
     let mut qc_pass_counts = Counts::default();
@@ -36,7 +36,7 @@ fn alignment_context(
 fn main() -> io::Result<()> {
     let src = env::args().nth(1).expect("missing src");
 
-    let mut reader = bam::io::reader::Builder.build_from_path(src)?;
+    let mut reader = bam::io::reader::Builder::default().build_from_path(src)?;
     let header = reader.read_header()?;
 
     if !is_coordinate_sorted(&header) {
@@ -0,0 +1,90 @@
+//! Builds and writes a BAM index from a BAM file.
+//!
+//! The input BAM must be coordinate-sorted, i.e., `SO:coordinate`.
+//!
+//! This writes the output to stdout rather than `<src>.bai`.
+//!
+//! The output is similar to the output of `samtools index <src>`.
+
+use std::env;
+
+use futures::stream;
+use noodles_bam::{self as bam, bai};
+use noodles_core::Position;
+use noodles_csi::{
+    self as csi,
+    binning_index::{index::reference_sequence::bin::Chunk, Indexer},
+};
+use noodles_sam::{self as sam, alignment::Record as _};
+use tokio::{fs::File, io};
+
+fn is_coordinate_sorted(header: &sam::Header) -> bool {
+    use sam::header::record::value::map::header::{sort_order, tag};
+
+    header
+        .header()
+        .and_then(|hdr| hdr.other_fields().get(&tag::SORT_ORDER))
+        .map(|sort_order| sort_order == sort_order::COORDINATE)
+        .unwrap_or_default()
+}
+
+fn alignment_context(
+    record: &bam::Record,
+) -> io::Result<Option<(usize, Position, Position, bool)>> {
+    match (
+        record.reference_sequence_id().transpose()?,
+        record.alignment_start().transpose()?,
+        record.alignment_end().transpose()?,
+    ) {
+        (Some(id), Some(start), Some(end)) => {
+            let is_mapped = !record.flags().is_unmapped();
+            Ok(Some((id, start, end, is_mapped)))
+        }
+        _ => Ok(None),
+    }
+}
+
+#[tokio::main]
+async fn main() -> io::Result<()> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let mut reader = File::open(src).await.map(bam::AsyncReader::new)?;
+    let header = reader.read_header().await?;
+
+    if !is_coordinate_sorted(&header) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "the input BAM must be coordinate-sorted to be indexed",
+        ));
+    }
+
+    let records = stream::try_unfold(
+        (reader, bam::Record::default()),
+        |(mut reader, mut record)| async move {
+            let start_position = reader.get_ref().virtual_position();
+
+            if reader.read_record(&mut record).await? == 0 {
+                return Ok(None);
+            }
+
+            let end_position = reader.get_ref().virtual_position();
+            let chunk = Chunk::new(start_position, end_position);
+            let item = (alignment_context(&record)?, chunk);
+
+            Ok(Some((item, (reader, record))))
+        },
+    );
+
+    let index = csi::r#async::io::index_records(
+        records,
+        Indexer::default(),
+        header.reference_sequences().len(),
+    )
+    .await?;
+
+    let mut writer = bai::AsyncWriter::new(io::stdout());
+    writer.write_index(&index).await?;
+    writer.shutdown().await?;
+
+    Ok(())
+}
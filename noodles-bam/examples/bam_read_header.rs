@@ -12,7 +12,7 @@ use noodles_sam as sam;
 fn main() -> io::Result<()> {
     let src = env::args().nth(1).expect("missing src");
 
-    let mut reader = bam::io::reader::Builder.build_from_path(src)?;
+    let mut reader = bam::io::reader::Builder::default().build_from_path(src)?;
     let header = reader.read_header()?;
 
     let stdout = io::stdout().lock();
@@ -113,7 +113,9 @@ where
 
     for _ in 0..n_ref {
         let (name, reference_sequence) = read_reference_sequence(reader).await?;
-        reference_sequences.insert(name, reference_sequence);
+        reference_sequences
+            .insert(name, reference_sequence)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
     }
 
     Ok(reference_sequences)
@@ -0,0 +1,252 @@
+//! BAM record counting.
+
+use std::{
+    fs::File,
+    io::{self, Read},
+    path::Path,
+};
+
+use noodles_csi::BinningIndex;
+use noodles_sam::alignment::record::Flags;
+
+use crate::{
+    io::{indexed_reader::read_associated_index, Reader},
+    Record,
+};
+
+/// A record filter by flags.
+///
+/// This is equivalent to `samtools view -f <required> -F <excluded>`. A record passes the filter
+/// if it has all of the required flags set and none of the excluded flags set.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Filter {
+    required: Flags,
+    excluded: Flags,
+}
+
+impl Filter {
+    /// Creates a record filter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam::count::Filter;
+    /// use noodles_sam::alignment::record::Flags;
+    /// let filter = Filter::new(Flags::empty(), Flags::SECONDARY | Flags::SUPPLEMENTARY);
+    /// ```
+    pub fn new(required: Flags, excluded: Flags) -> Self {
+        Self { required, excluded }
+    }
+
+    /// Returns the flags that must be set.
+    pub fn required(&self) -> Flags {
+        self.required
+    }
+
+    /// Returns the flags that must not be set.
+    pub fn excluded(&self) -> Flags {
+        self.excluded
+    }
+
+    /// Returns whether the given flags pass the filter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam::count::Filter;
+    /// use noodles_sam::alignment::record::Flags;
+    ///
+    /// let filter = Filter::new(Flags::empty(), Flags::SECONDARY | Flags::SUPPLEMENTARY);
+    ///
+    /// assert!(filter.matches(Flags::SEGMENTED));
+    /// assert!(!filter.matches(Flags::SEGMENTED | Flags::SECONDARY));
+    /// ```
+    pub fn matches(&self, flags: Flags) -> bool {
+        flags.contains(self.required) && !flags.intersects(self.excluded)
+    }
+}
+
+/// Counts the records in a BAM file that pass the given filter.
+///
+/// If the filter only tests the unmapped flag (`0x04`), or not at all, and an associated index
+/// (`<src>.bai` or `<src>.csi`) with reference sequence metadata exists, the count is computed
+/// from the index metadata without reading any records.
+///
+/// Otherwise, all records are read, but only their flags are decoded.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_bam::{self as bam, count::Filter};
+/// use noodles_sam::alignment::record::Flags;
+///
+/// let filter = Filter::new(Flags::empty(), Flags::SECONDARY | Flags::SUPPLEMENTARY);
+/// let n = bam::count_records("sample.bam", &filter)?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn count_records<P>(src: P, filter: &Filter) -> io::Result<u64>
+where
+    P: AsRef<Path>,
+{
+    let src = src.as_ref();
+
+    match read_associated_index(src) {
+        Ok(index) => {
+            if let Some(n) = count_records_from_index(&index, filter) {
+                return Ok(n);
+            }
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e),
+    }
+
+    let mut reader = File::open(src).map(Reader::new)?;
+    reader.read_header()?;
+    count_records_from_reader(&mut reader, filter)
+}
+
+fn count_records_from_index<I>(index: &I, filter: &Filter) -> Option<u64>
+where
+    I: BinningIndex + ?Sized,
+{
+    if !Flags::UNMAPPED.contains(filter.required() | filter.excluded()) {
+        return None;
+    }
+
+    let count_mapped = !filter.required().contains(Flags::UNMAPPED);
+    let count_unmapped = !filter.excluded().contains(Flags::UNMAPPED);
+
+    let mut n = 0;
+
+    for reference_sequence in index.reference_sequences() {
+        let metadata = reference_sequence.metadata()?;
+
+        if count_mapped {
+            n += metadata.mapped_record_count();
+        }
+
+        if count_unmapped {
+            n += metadata.unmapped_record_count();
+        }
+    }
+
+    if count_unmapped {
+        n += index.unplaced_unmapped_record_count()?;
+    }
+
+    Some(n)
+}
+
+fn count_records_from_reader<R>(reader: &mut Reader<R>, filter: &Filter) -> io::Result<u64>
+where
+    R: Read,
+{
+    let mut record = Record::default();
+    let mut n = 0;
+
+    while reader.read_record(&mut record)? != 0 {
+        if filter.matches(record.flags()) {
+            n += 1;
+        }
+    }
+
+    Ok(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_bgzf as bgzf;
+    use noodles_csi::binning_index::index::{
+        reference_sequence::{index::LinearIndex, Metadata},
+        ReferenceSequence,
+    };
+    use noodles_sam::{
+        self as sam,
+        alignment::{io::Write, RecordBuf},
+    };
+
+    use super::*;
+    use crate::bai;
+
+    #[test]
+    fn test_count_records_from_index() {
+        let reference_sequences = vec![
+            ReferenceSequence::new(
+                Default::default(),
+                LinearIndex::default(),
+                Some(Metadata::new(
+                    bgzf::VirtualPosition::default(),
+                    bgzf::VirtualPosition::default(),
+                    8,
+                    2,
+                )),
+            ),
+            ReferenceSequence::new(
+                Default::default(),
+                LinearIndex::default(),
+                Some(Metadata::new(
+                    bgzf::VirtualPosition::default(),
+                    bgzf::VirtualPosition::default(),
+                    5,
+                    0,
+                )),
+            ),
+        ];
+
+        let index = bai::Index::builder()
+            .set_reference_sequences(reference_sequences)
+            .set_unplaced_unmapped_record_count(3)
+            .build();
+
+        assert_eq!(
+            count_records_from_index(&index, &Filter::default()),
+            Some(18)
+        );
+
+        assert_eq!(
+            count_records_from_index(&index, &Filter::new(Flags::empty(), Flags::UNMAPPED)),
+            Some(13)
+        );
+
+        assert_eq!(
+            count_records_from_index(&index, &Filter::new(Flags::UNMAPPED, Flags::empty())),
+            Some(5)
+        );
+
+        assert!(
+            count_records_from_index(&index, &Filter::new(Flags::empty(), Flags::SECONDARY))
+                .is_none()
+        );
+
+        let index = bai::Index::default();
+        assert!(count_records_from_index(&index, &Filter::default()).is_none());
+    }
+
+    #[test]
+    fn test_count_records_from_reader() -> io::Result<()> {
+        let header = sam::Header::default();
+
+        let mut writer = crate::io::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for flags in [
+            Flags::UNMAPPED,
+            Flags::UNMAPPED | Flags::SECONDARY,
+            Flags::UNMAPPED | Flags::SUPPLEMENTARY,
+            Flags::UNMAPPED | Flags::DUPLICATE,
+        ] {
+            let record = RecordBuf::builder().set_flags(flags).build();
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.try_finish()?;
+
+        let mut reader = Reader::new(writer.get_ref().get_ref().as_slice());
+        reader.read_header()?;
+
+        let filter = Filter::new(Flags::empty(), Flags::SECONDARY | Flags::SUPPLEMENTARY);
+        assert_eq!(count_records_from_reader(&mut reader, &filter)?, 2);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+//! BAM index statistics.
+
+use std::io;
+
+use bstr::BString;
+use noodles_csi::BinningIndex;
+use noodles_sam as sam;
+
+const UNPLACED_REFERENCE_SEQUENCE_NAME: &str = "*";
+
+/// Returns per-reference sequence record counts from the metadata of an index.
+///
+/// This is equivalent to `samtools idxstats`. Each row is a tuple of the reference sequence name,
+/// its length, and the number of mapped and unmapped records placed on it. The last row, named
+/// `*`, has the number of unplaced, unmapped records.
+///
+/// Only the header and index are used; the alignment records are not read. Counts are 0 when an
+/// index has no metadata for a reference sequence.
+///
+/// # Errors
+///
+/// This returns an error if the index has more reference sequences than the header.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::fs::File;
+/// use noodles_bam::{self as bam, bai};
+///
+/// let header = File::open("sample.bam")
+///     .map(bam::io::Reader::new)?
+///     .read_header()?;
+///
+/// let index = bai::read("sample.bam.bai")?;
+///
+/// for (name, length, mapped, unmapped) in bam::idxstats(&header, &index)? {
+///     println!("{name}\t{length}\t{mapped}\t{unmapped}");
+/// }
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn idxstats<I>(header: &sam::Header, index: &I) -> io::Result<Vec<(BString, usize, u64, u64)>>
+where
+    I: BinningIndex + ?Sized,
+{
+    let reference_sequences = header.reference_sequences();
+    let mut index_reference_sequences = index.reference_sequences();
+
+    let mut rows = Vec::with_capacity(reference_sequences.len() + 1);
+
+    for (name, reference_sequence) in reference_sequences {
+        let (mapped, unmapped) = index_reference_sequences
+            .next()
+            .and_then(|index_reference_sequence| index_reference_sequence.metadata())
+            .map(|metadata| {
+                (
+                    metadata.mapped_record_count(),
+                    metadata.unmapped_record_count(),
+                )
+            })
+            .unwrap_or_default();
+
+        rows.push((
+            name.clone(),
+            reference_sequence.length().get(),
+            mapped,
+            unmapped,
+        ));
+    }
+
+    if index_reference_sequences.next().is_some() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "index has more reference sequences than the header",
+        ));
+    }
+
+    rows.push((
+        BString::from(UNPLACED_REFERENCE_SEQUENCE_NAME),
+        0,
+        0,
+        index.unplaced_unmapped_record_count().unwrap_or_default(),
+    ));
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_bgzf as bgzf;
+    use noodles_csi::binning_index::index::{reference_sequence::Metadata, ReferenceSequence};
+    use noodles_sam::header::record::value::{map, Map};
+
+    use super::*;
+    use crate::bai;
+
+    #[test]
+    fn test_idxstats() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<map::ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<map::ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .add_reference_sequence(
+                "sq2",
+                Map::<map::ReferenceSequence>::new(NonZeroUsize::try_from(21)?),
+            )
+            .build();
+
+        let metadata = Metadata::new(
+            bgzf::VirtualPosition::from(610),
+            bgzf::VirtualPosition::from(1597),
+            55,
+            3,
+        );
+
+        let index = bai::Index::builder()
+            .set_reference_sequences(vec![
+                ReferenceSequence::new(Default::default(), Default::default(), Some(metadata)),
+                ReferenceSequence::new(Default::default(), Default::default(), None),
+            ])
+            .set_unplaced_unmapped_record_count(5)
+            .build();
+
+        let actual = idxstats(&header, &index)?;
+
+        let expected = [
+            (BString::from("sq0"), 8, 55, 3),
+            (BString::from("sq1"), 13, 0, 0),
+            (BString::from("sq2"), 21, 0, 0),
+            (BString::from("*"), 0, 0, 5),
+        ];
+
+        assert_eq!(actual, expected);
+
+        let header = sam::Header::default();
+        assert!(matches!(
+            idxstats(&header, &index),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
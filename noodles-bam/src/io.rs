@@ -1,7 +1,12 @@
 //! BAM I/O.
 
+pub mod appender;
 pub mod indexed_reader;
 pub mod reader;
+mod recompress;
 pub mod writer;
 
-pub use self::{indexed_reader::IndexedReader, reader::Reader, writer::Writer};
+pub use self::{
+    appender::Appender, indexed_reader::IndexedReader, reader::Reader, recompress::recompress,
+    writer::Writer,
+};
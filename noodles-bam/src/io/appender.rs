@@ -0,0 +1,354 @@
+//! BAM appender.
+
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, Read, Seek},
+    path::{Path, PathBuf},
+};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+use noodles_csi::{
+    binning_index::{
+        index::reference_sequence::{bin::Chunk, index::LinearIndex},
+        Indexer,
+    },
+    BinningIndex,
+};
+use noodles_sam::{self as sam, alignment::io::Write as _};
+
+use super::{indexed_reader::build_index_src, Reader, Writer};
+use crate::{bai, Record};
+
+/// The last record in a BAM file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LastRecord {
+    reference_sequence_id: Option<usize>,
+    alignment_start: Option<Position>,
+    start_position: bgzf::VirtualPosition,
+}
+
+impl LastRecord {
+    /// Returns the reference sequence ID.
+    pub fn reference_sequence_id(&self) -> Option<usize> {
+        self.reference_sequence_id
+    }
+
+    /// Returns the alignment start.
+    pub fn alignment_start(&self) -> Option<Position> {
+        self.alignment_start
+    }
+
+    /// Returns the virtual position of the start of the record.
+    pub fn start_position(&self) -> bgzf::VirtualPosition {
+        self.start_position
+    }
+
+    fn sort_key(&self) -> SortKey {
+        sort_key(self.reference_sequence_id, self.alignment_start)
+    }
+}
+
+/// Finds the last record in a BAM file using its index.
+///
+/// The reader is expected to be positioned after the header. It seeks to the start of the last
+/// linear bin in the index and reads the remaining records, so only the tail of the file is read.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::fs::File;
+/// use noodles_bam::{self as bam, bai, io::appender::find_last_record};
+///
+/// let mut reader = File::open("sample.bam").map(bam::io::Reader::new)?;
+/// reader.read_header()?;
+///
+/// let index = bai::read("sample.bam.bai")?;
+/// let last_record = find_last_record(&mut reader, &index)?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn find_last_record<R, I>(
+    reader: &mut Reader<bgzf::Reader<R>>,
+    index: &I,
+) -> io::Result<Option<LastRecord>>
+where
+    R: Read + Seek,
+    I: BinningIndex,
+{
+    if let Some(pos) = index.last_first_record_start_position() {
+        reader.get_mut().seek(pos)?;
+    }
+
+    let mut record = Record::default();
+    let mut last_record = None;
+
+    loop {
+        let start_position = reader.get_ref().virtual_position();
+
+        if reader.read_record(&mut record)? == 0 {
+            break;
+        }
+
+        last_record = Some(LastRecord {
+            reference_sequence_id: record.reference_sequence_id().transpose()?,
+            alignment_start: record.alignment_start().transpose()?,
+            start_position,
+        });
+    }
+
+    Ok(last_record)
+}
+
+/// A BAM appender.
+///
+/// This appends coordinate-sorted records to an existing BAM file with an associated BAM index
+/// (`<src>.bai`) and updates the index with the new records, without rewriting or reindexing the
+/// existing records.
+///
+/// Appended records must sort after the last record in the file.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_bam as bam;
+/// use noodles_sam as sam;
+///
+/// let mut appender = bam::io::Appender::open("sample.bam")?;
+///
+/// let record = sam::alignment::RecordBuf::default();
+/// appender.append_record(&record)?;
+///
+/// appender.finish()?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub struct Appender {
+    writer: Writer<bgzf::Writer<File>>,
+    header: sam::Header,
+    indexer: Indexer<LinearIndex>,
+    index_dst: PathBuf,
+    last_sort_key: Option<SortKey>,
+}
+
+impl Appender {
+    /// Opens an indexed BAM file for appending.
+    ///
+    /// This reads the header and the BAM index (`<src>.bai`) and locates the last record.
+    pub fn open<P>(src: P) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let src = src.as_ref();
+
+        let index_dst = build_index_src(src, "bai");
+        let index = bai::read(&index_dst)?;
+
+        let mut reader = File::open(src).map(Reader::new)?;
+        let header = reader.read_header()?;
+        let last_record = find_last_record(&mut reader, &index)?;
+
+        let file = OpenOptions::new().read(true).write(true).open(src)?;
+        let writer = bgzf::writer::Builder::default()
+            .build_for_append(file)
+            .map(Writer::from)?;
+
+        Ok(Self {
+            writer,
+            header,
+            indexer: Indexer::from(index),
+            index_dst,
+            last_sort_key: last_record.map(|record| record.sort_key()),
+        })
+    }
+
+    /// Returns the SAM header of the BAM file.
+    pub fn header(&self) -> &sam::Header {
+        &self.header
+    }
+
+    /// Appends an alignment record.
+    ///
+    /// This returns an error if the record sorts before the last written record.
+    pub fn append_record(&mut self, record: &dyn sam::alignment::Record) -> io::Result<()> {
+        let reference_sequence_id = record.reference_sequence_id(&self.header).transpose()?;
+        let alignment_start = record.alignment_start().transpose()?;
+
+        let sort_key = sort_key(reference_sequence_id, alignment_start);
+
+        if let Some(last_sort_key) = self.last_sort_key {
+            if sort_key < last_sort_key {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "record is out of order",
+                ));
+            }
+        }
+
+        let start_position = self.writer.get_ref().virtual_position();
+        self.writer.write_alignment_record(&self.header, record)?;
+        let end_position = self.writer.get_ref().virtual_position();
+
+        let alignment_end = record.alignment_end().transpose()?;
+
+        let alignment_context = match (reference_sequence_id, alignment_start, alignment_end) {
+            (Some(id), Some(start), Some(end)) => {
+                let is_mapped = !record.flags()?.is_unmapped();
+                Some((id, start, end, is_mapped))
+            }
+            _ => None,
+        };
+
+        self.indexer
+            .add_record(alignment_context, Chunk::new(start_position, end_position))?;
+
+        self.last_sort_key = Some(sort_key);
+
+        Ok(())
+    }
+
+    /// Finishes the BAM file and writes the updated index.
+    ///
+    /// This writes the BGZF EOF block and overwrites the associated BAM index (`<src>.bai`). The
+    /// updated index is returned.
+    pub fn finish(mut self) -> io::Result<bai::Index> {
+        self.writer.try_finish()?;
+
+        let reference_sequence_count = self.header.reference_sequences().len();
+        let index = self.indexer.build(reference_sequence_count);
+        bai::write(&self.index_dst, &index)?;
+
+        Ok(index)
+    }
+}
+
+// Unplaced records sort after all placed records.
+type SortKey = (bool, Option<usize>, Option<Position>);
+
+fn sort_key(reference_sequence_id: Option<usize>, alignment_start: Option<Position>) -> SortKey {
+    (
+        reference_sequence_id.is_none(),
+        reference_sequence_id,
+        alignment_start,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, num::NonZeroUsize};
+
+    use noodles_core::Region;
+    use noodles_sam::{
+        alignment::{
+            record::{
+                cigar::{op::Kind, Op},
+                Flags,
+            },
+            record_buf::Cigar,
+            RecordBuf,
+        },
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_append_record() -> Result<(), Box<dyn std::error::Error>> {
+        let dst = env::temp_dir().join(format!("noodles-bam-appender-{}.bam", std::process::id()));
+        let index_dst = build_index_src(&dst, "bai");
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(34)?),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(55)?),
+            )
+            .build();
+
+        let mut writer = File::create(&dst).map(Writer::new)?;
+        writer.write_header(&header)?;
+
+        let mut indexer = Indexer::<LinearIndex>::default();
+
+        for alignment_start in [8, 13] {
+            let record = RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(alignment_start)?)
+                .set_cigar(Cigar::from(vec![Op::new(Kind::Match, 4)]))
+                .build();
+
+            let start_position = writer.get_ref().virtual_position();
+            writer.write_alignment_record(&header, &record)?;
+            let end_position = writer.get_ref().virtual_position();
+
+            let start = Position::try_from(alignment_start)?;
+            let end = Position::try_from(alignment_start + 3)?;
+            indexer.add_record(
+                Some((0, start, end, true)),
+                Chunk::new(start_position, end_position),
+            )?;
+        }
+
+        writer.try_finish()?;
+        drop(writer);
+
+        bai::write(&index_dst, &indexer.build(2))?;
+
+        let cigar = Cigar::from(vec![Op::new(Kind::Match, 4)]);
+        let mut appender = Appender::open(&dst)?;
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(5)?)
+            .set_cigar(cigar.clone())
+            .build();
+        assert!(appender.append_record(&record).is_err());
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(21)?)
+            .set_cigar(cigar.clone())
+            .build();
+        appender.append_record(&record)?;
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(1)
+            .set_alignment_start(Position::MIN)
+            .set_cigar(cigar)
+            .build();
+        appender.append_record(&record)?;
+
+        appender.finish()?;
+
+        let mut reader = crate::io::indexed_reader::Builder::default().build_from_path(&dst)?;
+        let header = reader.read_header()?;
+
+        let region: Region = "sq0:20-30".parse()?;
+        let records: Vec<_> = reader.query(&header, &region)?.collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].alignment_start().transpose()?, Position::new(21));
+
+        let region: Region = "sq1".parse()?;
+        let records: Vec<_> = reader.query(&header, &region)?.collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 1);
+
+        let index = bai::read(&index_dst)?;
+        let mut reader = File::open(&dst).map(Reader::new)?;
+        reader.read_header()?;
+        let last_record = find_last_record(&mut reader, &index)?;
+        assert_eq!(
+            last_record.and_then(|record| record.reference_sequence_id()),
+            Some(1)
+        );
+
+        fs::remove_file(dst)?;
+        fs::remove_file(index_dst)?;
+
+        Ok(())
+    }
+}
@@ -2,7 +2,12 @@
 
 mod builder;
 
-use std::io::{self, Read};
+use std::{
+    fs::File,
+    io::{self, Read},
+    path::PathBuf,
+    sync::Arc,
+};
 
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
@@ -10,16 +15,21 @@ use noodles_csi::BinningIndex;
 use noodles_sam::{self as sam, alignment::RecordBuf};
 
 pub use self::builder::Builder;
+pub(crate) use self::builder::{build_index_src, read_associated_index};
 use super::{
-    reader::{Query, RecordBufs, Records},
+    reader::{BatchQuery, Query, RecordBufs, Records},
     Reader,
 };
 use crate::Record;
 
 /// An indexed BAM reader.
+///
+/// The associated index is immutable and shared by reference count, so it can be loaded once and
+/// shared by readers on multiple threads. See [`IndexedReader::try_clone`].
 pub struct IndexedReader<R> {
     inner: Reader<R>,
-    index: Box<dyn BinningIndex>,
+    index: Arc<dyn BinningIndex + Send + Sync>,
+    src: Option<PathBuf>,
 }
 
 impl<R> IndexedReader<R> {
@@ -86,15 +96,94 @@ where
     /// Creates an indexed BAM reader.
     pub fn new<I>(inner: R, index: I) -> Self
     where
-        I: BinningIndex + 'static,
+        I: BinningIndex + Send + Sync + 'static,
     {
+        Self::from_shared_index(inner, Arc::new(index))
+    }
+
+    pub(crate) fn from_shared_index(inner: R, index: Arc<dyn BinningIndex + Send + Sync>) -> Self {
+        Self::from_shared_bgzf_reader(bgzf::Reader::new(inner), index)
+    }
+
+    pub(crate) fn from_shared_bgzf_reader(
+        inner: bgzf::Reader<R>,
+        index: Arc<dyn BinningIndex + Send + Sync>,
+    ) -> Self {
         Self {
-            inner: Reader::new(inner),
-            index: Box::new(index),
+            inner: Reader::from(inner),
+            index,
+            src: None,
         }
     }
 }
 
+impl IndexedReader<bgzf::Reader<File>> {
+    /// Creates a new indexed BAM reader for the same file that shares the loaded index.
+    ///
+    /// This reopens the source file, so the returned reader has its own file handle and stream
+    /// position, starting at the beginning of the file. The index is not read again. This is the
+    /// recommended way to query a BAM file from multiple threads: build one reader, then give
+    /// each thread its own clone.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the reader was not built from a path (see
+    /// [`Builder::build_from_path`]) or if the file cannot be reopened.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::{io, sync::Arc, thread};
+    ///
+    /// use noodles_bam as bam;
+    /// use noodles_core::Region;
+    ///
+    /// let mut reader = bam::io::indexed_reader::Builder::default().build_from_path("sample.bam")?;
+    /// let header = Arc::new(reader.read_header()?);
+    ///
+    /// let handles = ["sq0", "sq1"]
+    ///     .into_iter()
+    ///     .map(|name| {
+    ///         let mut reader = reader.try_clone()?;
+    ///         let header = Arc::clone(&header);
+    ///         let region = Region::new(name, ..);
+    ///
+    ///         Ok(thread::spawn(move || -> io::Result<usize> {
+    ///             let mut n = 0;
+    ///
+    ///             for result in reader.query(&header, &region)? {
+    ///                 result?;
+    ///                 n += 1;
+    ///             }
+    ///
+    ///             Ok(n)
+    ///         }))
+    ///     })
+    ///     .collect::<io::Result<Vec<_>>>()?;
+    ///
+    /// for handle in handles {
+    ///     let n = handle.join().unwrap()?;
+    ///     println!("{n}");
+    /// }
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn try_clone(&self) -> io::Result<Self> {
+        let src = self.src.as_ref().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::Unsupported,
+                "reader was not built from a path",
+            )
+        })?;
+
+        let file = File::open(src)?;
+
+        let mut reader = Self::from_shared_index(file, self.index.clone());
+        reader.src = Some(src.clone());
+
+        Ok(reader)
+    }
+}
+
 impl<R> IndexedReader<R>
 where
     R: bgzf::io::BufRead + bgzf::io::Seek,
@@ -108,8 +197,268 @@ where
         self.inner.query(header, &self.index, region)
     }
 
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// See [`Reader::query_batch`].
+    pub fn query_batch(
+        &mut self,
+        header: &sam::Header,
+        regions: &[Region],
+    ) -> io::Result<BatchQuery<'_, R>> {
+        self.inner.query_batch(header, &self.index, regions)
+    }
+
     /// Returns an iterator of unmapped records after querying for the unmapped region.
     pub fn query_unmapped(&mut self) -> io::Result<impl Iterator<Item = io::Result<Record>> + '_> {
         self.inner.query_unmapped(&self.index)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, num::NonZeroUsize, path::Path, thread};
+
+    use noodles_core::Position;
+    use noodles_csi::binning_index::{
+        index::reference_sequence::{bin::Chunk, index::LinearIndex},
+        Indexer,
+    };
+    use noodles_sam::{
+        alignment::{
+            io::Write as _,
+            record::{
+                cigar::{op::Kind, Op},
+                Flags,
+            },
+            record_buf::Cigar,
+        },
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+    use crate::{bai, io::Writer};
+
+    #[test]
+    fn test_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<bai::Index>();
+        assert_send_sync::<noodles_csi::Index>();
+        assert_send_sync::<sam::Header>();
+        assert_send_sync::<IndexedReader<bgzf::Reader<File>>>();
+    }
+
+    fn write_data(src: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(34)?),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(55)?),
+            )
+            .build();
+
+        let mut writer = File::create(src).map(Writer::new)?;
+        writer.write_header(&header)?;
+
+        let mut indexer = Indexer::<LinearIndex>::default();
+
+        for (reference_sequence_id, alignment_start) in [(0, 8), (0, 13), (1, 5)] {
+            let start = Position::try_from(alignment_start)?;
+
+            let record = RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(reference_sequence_id)
+                .set_alignment_start(start)
+                .set_cigar(Cigar::from(vec![Op::new(Kind::Match, 4)]))
+                .build();
+
+            let start_position = writer.get_ref().virtual_position();
+            writer.write_alignment_record(&header, &record)?;
+            let end_position = writer.get_ref().virtual_position();
+
+            let end = Position::try_from(alignment_start + 3)?;
+            indexer.add_record(
+                Some((reference_sequence_id, start, end, true)),
+                Chunk::new(start_position, end_position),
+            )?;
+        }
+
+        writer.try_finish()?;
+        drop(writer);
+
+        bai::write(build_index_src(src, "bai"), &indexer.build(2))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_clone() -> Result<(), Box<dyn std::error::Error>> {
+        let src = env::temp_dir().join(format!(
+            "noodles-bam-indexed-reader-try-clone-{}.bam",
+            std::process::id()
+        ));
+        let index_src = build_index_src(&src, "bai");
+
+        write_data(&src)?;
+
+        let result = (|| -> Result<Vec<usize>, Box<dyn std::error::Error>> {
+            let mut reader = Builder::default().build_from_path(&src)?;
+            let header = Arc::new(reader.read_header()?);
+
+            let handles = ["sq0", "sq1"]
+                .into_iter()
+                .map(|name| {
+                    let mut reader = reader.try_clone()?;
+                    let header = Arc::clone(&header);
+                    let region = Region::new(name, ..);
+
+                    Ok(thread::spawn(move || -> io::Result<usize> {
+                        let mut n = 0;
+
+                        for result in reader.query(&header, &region)? {
+                            result?;
+                            n += 1;
+                        }
+
+                        Ok(n)
+                    }))
+                })
+                .collect::<io::Result<Vec<_>>>()?;
+
+            let counts = handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect::<io::Result<_>>()?;
+
+            Ok(counts)
+        })();
+
+        fs::remove_file(&src)?;
+        fs::remove_file(&index_src)?;
+
+        assert_eq!(result?, [2, 1]);
+
+        let file = File::open(env::current_exe()?)?;
+        let reader = IndexedReader::new(file, bai::Index::default());
+        assert!(matches!(
+            reader.try_clone(),
+            Err(e) if e.kind() == io::ErrorKind::Unsupported
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_batch() -> Result<(), Box<dyn std::error::Error>> {
+        let src = env::temp_dir().join(format!(
+            "noodles-bam-indexed-reader-query-batch-{}.bam",
+            std::process::id()
+        ));
+        let index_src = build_index_src(&src, "bai");
+
+        write_data(&src)?;
+
+        let result = (|| -> Result<_, Box<dyn std::error::Error>> {
+            let mut reader = Builder::default().build_from_path(&src)?;
+            let header = reader.read_header()?;
+
+            let regions = [
+                "sq0:10-13".parse()?,
+                "sq0:1-20".parse()?,
+                "sq1:1-4".parse()?,
+                "sq1:6-6".parse()?,
+            ];
+
+            let records: Vec<(Vec<usize>, Option<usize>)> = reader
+                .query_batch(&header, &regions)?
+                .map(|result| {
+                    let (indices, record) = result?;
+                    let start = record.alignment_start().transpose()?.map(usize::from);
+                    Ok((indices, start))
+                })
+                .collect::<io::Result<_>>()?;
+
+            Ok(records)
+        })();
+
+        fs::remove_file(&src)?;
+        fs::remove_file(&index_src)?;
+
+        assert_eq!(
+            result?,
+            [
+                (vec![0, 1], Some(8)),
+                (vec![0, 1], Some(13)),
+                (vec![3], Some(5)),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_with_range_reader() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::io::RangeReader;
+
+        let src = env::temp_dir().join(format!(
+            "noodles-bam-indexed-reader-range-reader-{}.bam",
+            std::process::id()
+        ));
+        let index_src = build_index_src(&src, "bai");
+
+        write_data(&src)?;
+
+        let result = (|| -> io::Result<(Vec<u8>, bai::Index)> {
+            Ok((fs::read(&src)?, bai::read(&index_src)?))
+        })();
+
+        fs::remove_file(&src)?;
+        fs::remove_file(&index_src)?;
+
+        let (data, index) = result?;
+
+        let mut reader = IndexedReader::new(RangeReader::with_capacity(16, &data[..]), index);
+        let header = reader.read_header()?;
+
+        let region = "sq0:10-20".parse()?;
+        let records: Vec<_> = reader.query(&header, &region)?.collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 2);
+
+        let region = Region::new("sq1", ..);
+        assert_eq!(reader.query(&header, &region)?.count(), 1);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn test_build_mmap_from_path() -> Result<(), Box<dyn std::error::Error>> {
+        let src = env::temp_dir().join(format!(
+            "noodles-bam-indexed-reader-build-mmap-from-path-{}.bam",
+            std::process::id()
+        ));
+        let index_src = build_index_src(&src, "bai");
+
+        write_data(&src)?;
+
+        let result = (|| -> Result<usize, Box<dyn std::error::Error>> {
+            let mut reader = Builder::default().build_mmap_from_path(&src)?;
+            let header = reader.read_header()?;
+
+            let region = "sq0:10-20".parse()?;
+            let records: Vec<_> = reader.query(&header, &region)?.collect::<io::Result<_>>()?;
+
+            Ok(records.len())
+        })();
+
+        fs::remove_file(&src)?;
+        fs::remove_file(&index_src)?;
+
+        assert_eq!(result?, 2);
+
+        Ok(())
+    }
+}
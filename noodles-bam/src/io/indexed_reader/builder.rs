@@ -3,6 +3,7 @@ use std::{
     fs::File,
     io::{self, Read},
     path::{Path, PathBuf},
+    sync::Arc,
 };
 
 use noodles_bgzf as bgzf;
@@ -14,7 +15,7 @@ use crate::bai;
 /// An indexed BAM reader builder.
 #[derive(Default)]
 pub struct Builder {
-    index: Option<Box<dyn BinningIndex>>,
+    index: Option<Arc<dyn BinningIndex + Send + Sync>>,
 }
 
 impl Builder {
@@ -29,9 +30,24 @@ impl Builder {
     /// ```
     pub fn set_index<I>(mut self, index: I) -> Self
     where
-        I: BinningIndex + 'static,
+        I: BinningIndex + Send + Sync + 'static,
     {
-        self.index = Some(Box::new(index));
+        self.index = Some(Arc::new(index));
+        self
+    }
+
+    /// Sets an index that is shared with other readers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use noodles_bam::{bai, io::indexed_reader::Builder};
+    /// let index = Arc::new(bai::Index::default());
+    /// let builder = Builder::default().set_shared_index(index);
+    /// ```
+    pub fn set_shared_index(mut self, index: Arc<dyn BinningIndex + Send + Sync>) -> Self {
+        self.index = Some(index);
         self
     }
 
@@ -55,12 +71,48 @@ impl Builder {
 
         let index = match self.index {
             Some(index) => index,
-            None => read_associated_index(src)?,
+            None => Arc::from(read_associated_index(src)?),
         };
 
         let file = File::open(src)?;
 
-        Ok(IndexedReader::new(file, index))
+        let mut reader = IndexedReader::from_shared_index(file, index);
+        reader.src = Some(src.into());
+
+        Ok(reader)
+    }
+
+    /// Builds a memory-mapped indexed BAM reader from a path.
+    ///
+    /// This is the same as [`Self::build_from_path`] but maps the file into memory instead of
+    /// reading it using system calls (see [`bgzf::reader::Builder::build_mmap_from_path`]). The
+    /// file must not be modified while the reader is in use.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_bam::io::indexed_reader::Builder;
+    /// let reader = Builder::default().build_mmap_from_path("sample.bam")?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    #[cfg(feature = "mmap")]
+    pub fn build_mmap_from_path<P>(
+        self,
+        src: P,
+    ) -> io::Result<IndexedReader<bgzf::Reader<io::Cursor<bgzf::reader::Mmap>>>>
+    where
+        P: AsRef<Path>,
+    {
+        let src = src.as_ref();
+
+        let index = match self.index {
+            Some(index) => index,
+            None => Arc::from(read_associated_index(src)?),
+        };
+
+        let reader = bgzf::reader::Builder::default().build_mmap_from_path(src)?;
+
+        Ok(IndexedReader::from_shared_bgzf_reader(reader, index))
     }
 
     /// Builds an indexed BAM reader from a reader.
@@ -82,11 +134,11 @@ impl Builder {
             .index
             .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing index"))?;
 
-        Ok(IndexedReader::new(reader, index))
+        Ok(IndexedReader::from_shared_index(reader, index))
     }
 }
 
-fn read_associated_index<P>(src: P) -> io::Result<Box<dyn BinningIndex>>
+pub(crate) fn read_associated_index<P>(src: P) -> io::Result<Box<dyn BinningIndex + Send + Sync>>
 where
     P: AsRef<Path>,
 {
@@ -102,7 +154,7 @@ where
     }
 }
 
-fn build_index_src<P, S>(src: P, ext: S) -> PathBuf
+pub(crate) fn build_index_src<P, S>(src: P, ext: S) -> PathBuf
 where
     P: AsRef<Path>,
     S: AsRef<OsStr>,
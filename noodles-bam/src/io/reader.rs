@@ -1,12 +1,19 @@
 //! BAM reader.
 
+mod batch_query;
 mod builder;
+mod field_selector;
 pub(crate) mod header;
+mod lending_records;
+mod name_groups;
 pub(crate) mod query;
 mod record;
 mod record_buf;
 mod record_bufs;
+mod record_error;
 mod records;
+mod records_lossy;
+mod validation_level;
 
 use std::{
     ffi::CStr,
@@ -16,11 +23,19 @@ use std::{
 use bstr::BString;
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
-use noodles_csi::BinningIndex;
+use noodles_csi::{self as csi, BinningIndex};
 use noodles_sam::{self as sam, alignment::RecordBuf, header::ReferenceSequences};
 
-pub use self::{builder::Builder, query::Query, record_bufs::RecordBufs, records::Records};
-use self::{record::read_record, record_buf::read_record_buf};
+pub use self::{
+    batch_query::BatchQuery, builder::Builder, field_selector::FieldSelector,
+    lending_records::LendingRecords, name_groups::NameGroups, query::Query,
+    record_bufs::RecordBufs, record_error::RecordError, records::Records,
+    records_lossy::RecordsLossy, validation_level::ValidationLevel,
+};
+use self::{
+    record::read_record,
+    record_buf::{read_record_buf, read_record_buf_with_fields},
+};
 use crate::Record;
 
 /// A BAM reader.
@@ -48,6 +63,7 @@ use crate::Record;
 pub struct Reader<R> {
     inner: R,
     buf: Vec<u8>,
+    validation_level: ValidationLevel,
 }
 
 impl<R> Reader<R> {
@@ -118,7 +134,7 @@ where
     /// ```
     pub fn read_header(&mut self) -> io::Result<sam::Header> {
         use self::header::read_header;
-        read_header(&mut self.inner)
+        read_header(&mut self.inner, self.validation_level)
     }
 
     /// Reads a record into an alignment record buffer.
@@ -159,6 +175,37 @@ where
         read_record_buf(&mut self.inner, header, &mut self.buf, record)
     }
 
+    /// Reads a record into an alignment record buffer, decoding only the selected fields.
+    ///
+    /// Fields that are not selected are skipped and set to their default values. See
+    /// [`FieldSelector`].
+    ///
+    /// If successful, the record block size is returned. If a block size of 0 is returned, the
+    /// stream reached EOF.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bam::{self as bam, io::reader::FieldSelector};
+    /// use noodles_sam::alignment::RecordBuf;
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::io::Reader::new)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let mut record = RecordBuf::default();
+    /// reader.read_record_buf_with_fields(&header, FieldSelector::FLAGS, &mut record)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn read_record_buf_with_fields(
+        &mut self,
+        header: &sam::Header,
+        fields: FieldSelector,
+        record: &mut RecordBuf,
+    ) -> io::Result<usize> {
+        read_record_buf_with_fields(&mut self.inner, header, &mut self.buf, fields, record)
+    }
+
     /// Reads a record.
     ///
     /// The record block size (`bs`) is read from the underlying stream and `bs` bytes are read
@@ -219,7 +266,39 @@ where
     /// # Ok::<_, io::Error>(())
     /// ```
     pub fn record_bufs<'a>(&'a mut self, header: &'a sam::Header) -> RecordBufs<'_, R> {
-        RecordBufs::new(self, header)
+        RecordBufs::new(self, header, FieldSelector::all())
+    }
+
+    /// Returns an iterator over alignment record buffers that only decodes the selected fields.
+    ///
+    /// This is useful for passes that only count or summarize records, e.g., by flags and
+    /// position, since skipping the sequence, quality scores, and data avoids most of the
+    /// decoding work. Fields that are not selected are set to their default values. See
+    /// [`FieldSelector`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bam::{self as bam, io::reader::FieldSelector};
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::io::Reader::new)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let fields = FieldSelector::FLAGS | FieldSelector::POSITION;
+    ///
+    /// for result in reader.record_bufs_with_fields(&header, fields) {
+    ///     let record = result?;
+    ///     println!("{:?} {:?}", record.flags(), record.alignment_start());
+    /// }
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn record_bufs_with_fields<'a>(
+        &'a mut self,
+        header: &'a sam::Header,
+        fields: FieldSelector,
+    ) -> RecordBufs<'a, R> {
+        RecordBufs::new(self, header, fields)
     }
 
     /// Returns an iterator over records.
@@ -245,6 +324,60 @@ where
     pub fn records(&mut self) -> Records<'_, R> {
         Records::new(self)
     }
+
+    /// Returns a lending iterator over records.
+    ///
+    /// This reuses a single record buffer, avoiding an allocation per record. See
+    /// [`LendingRecords`].
+    ///
+    /// The stream is expected to be directly after the header or at the start of another record.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bam as bam;
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::io::Reader::new)?;
+    /// reader.read_header()?;
+    ///
+    /// let mut records = reader.lending_records();
+    ///
+    /// while let Some(record) = records.next_record().transpose()? {
+    ///     // ...
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn lending_records(&mut self) -> LendingRecords<'_, R> {
+        LendingRecords::new(self)
+    }
+
+    /// Returns an iterator over groups of records that share a name.
+    ///
+    /// The input must be name-collated, e.g., the output of `samtools collate`, but it does not
+    /// need to be sorted by name. Only the records of the current group are buffered. See
+    /// [`NameGroups`].
+    ///
+    /// The stream is expected to be directly after the header or at the start of another record.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bam as bam;
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::io::Reader::new)?;
+    /// reader.read_header()?;
+    ///
+    /// for result in reader.name_groups() {
+    ///     let records = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn name_groups(&mut self) -> NameGroups<'_, R> {
+        NameGroups::new(self)
+    }
 }
 
 impl<R> Reader<bgzf::Reader<R>>
@@ -267,6 +400,43 @@ where
     }
 }
 
+impl<R> Reader<R>
+where
+    R: bgzf::io::Read,
+{
+    /// Returns an iterator over records that skips records that fail to decode.
+    ///
+    /// Each error is a [`RecordError`], which includes the index and virtual position of the
+    /// record and, if known, its reference sequence name. Records that fail to decode are skipped,
+    /// and iteration continues with the next record. If a record cannot be read, e.g., the stream
+    /// is truncated, the error is returned, and iteration stops.
+    ///
+    /// The stream is expected to be directly after the header or at the start of another record.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bam as bam;
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::io::Reader::new)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// for result in reader.records_lossy(&header) {
+    ///     match result {
+    ///         Ok(record) => {
+    ///             // ...
+    ///         }
+    ///         Err(e) => eprintln!("{e}"),
+    ///     }
+    /// }
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn records_lossy<'r, 'h>(&'r mut self, header: &'h sam::Header) -> RecordsLossy<'r, 'h, R> {
+        RecordsLossy::new(self, header)
+    }
+}
+
 impl<R> Reader<R>
 where
     R: bgzf::io::BufRead + bgzf::io::Seek,
@@ -325,6 +495,56 @@ where
         ))
     }
 
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// Unlike calling [`Self::query`] for each region, the chunks of all regions are sorted and
+    /// merged, and the file is read in a single forward sweep. This is useful for many small
+    /// regions, e.g., an exon panel, where regions can share blocks.
+    ///
+    /// Each record is returned once, along with the (sorted) indices of the regions it
+    /// intersects. Records are in file order, not region order.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bam::{self as bam, bai};
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::io::Reader::new)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let index = bai::read("sample.bam.bai")?;
+    /// let regions = ["sq0:8-13".parse()?, "sq0:21-34".parse()?];
+    /// let query = reader.query_batch(&header, &index, &regions)?;
+    ///
+    /// for result in query {
+    ///     let (region_indices, record) = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_batch<I>(
+        &mut self,
+        header: &sam::Header,
+        index: &I,
+        regions: &[Region],
+    ) -> io::Result<BatchQuery<'_, R>>
+    where
+        I: BinningIndex,
+    {
+        let queries = regions
+            .iter()
+            .map(|region| {
+                resolve_region(header.reference_sequences(), region)
+                    .map(|id| (id, region.interval()))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let batch_query = csi::binning_index::BatchQuery::new(index, queries)?;
+
+        Ok(BatchQuery::new(self.get_mut(), batch_query))
+    }
+
     /// Returns an iterator of unmapped records after querying for the unmapped region.
     ///
     /// # Examples
@@ -372,6 +592,7 @@ impl<R> From<R> for Reader<R> {
         Self {
             inner,
             buf: Vec::new(),
+            validation_level: ValidationLevel::default(),
         }
     }
 }
@@ -0,0 +1,69 @@
+use std::io;
+
+use noodles_bgzf as bgzf;
+use noodles_csi as csi;
+use noodles_sam::alignment::Record as _;
+
+use super::Reader;
+use crate::Record;
+
+/// An iterator over records of a BAM reader that intersect any of a batch of regions.
+///
+/// This is created by calling [`Reader::query_batch`].
+pub struct BatchQuery<'a, R> {
+    reader: Reader<csi::io::Query<'a, R>>,
+    batch_query: csi::binning_index::BatchQuery,
+    record: Record,
+}
+
+impl<'a, R> BatchQuery<'a, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    pub(super) fn new(reader: &'a mut R, batch_query: csi::binning_index::BatchQuery) -> Self {
+        let chunks = batch_query.chunks().to_vec();
+
+        Self {
+            reader: Reader::from(csi::io::Query::new(reader, chunks)),
+            batch_query,
+            record: Record::default(),
+        }
+    }
+}
+
+impl<'a, R> Iterator for BatchQuery<'a, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    type Item = io::Result<(Vec<usize>, Record)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.reader.read_record(&mut self.record) {
+                Ok(0) => return None,
+                Ok(_) => {}
+                Err(e) => return Some(Err(e)),
+            }
+
+            match region_indices(&self.batch_query, &self.record) {
+                Ok(indices) if indices.is_empty() => {}
+                Ok(indices) => return Some(Ok((indices, self.record.clone()))),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+fn region_indices(
+    batch_query: &csi::binning_index::BatchQuery,
+    record: &Record,
+) -> io::Result<Vec<usize>> {
+    match (
+        record.reference_sequence_id().transpose()?,
+        record.alignment_start().transpose()?,
+        record.alignment_end().transpose()?,
+    ) {
+        (Some(id), Some(start), Some(end)) => Ok(batch_query.intersecting(id, start, end)),
+        _ => Ok(Vec::new()),
+    }
+}
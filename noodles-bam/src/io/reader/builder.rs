@@ -6,13 +6,30 @@ use std::{
 
 use noodles_bgzf as bgzf;
 
-use super::Reader;
+use super::{Reader, ValidationLevel};
 
 /// A BAM reader builder.
 #[derive(Debug, Default)]
-pub struct Builder;
+pub struct Builder {
+    validation_level: ValidationLevel,
+}
 
 impl Builder {
+    /// Sets the validation level.
+    ///
+    /// By default, the validation level is [`ValidationLevel::Strict`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam::io::reader::{Builder, ValidationLevel};
+    /// let builder = Builder::default().set_validation_level(ValidationLevel::Lenient);
+    /// ```
+    pub fn set_validation_level(mut self, validation_level: ValidationLevel) -> Self {
+        self.validation_level = validation_level;
+        self
+    }
+
     /// Builds a BAM reader from a path.
     ///
     /// # Examples
@@ -42,6 +59,8 @@ impl Builder {
     where
         R: Read,
     {
-        Reader::new(reader)
+        let mut reader = Reader::new(reader);
+        reader.validation_level = self.validation_level;
+        reader
     }
 }
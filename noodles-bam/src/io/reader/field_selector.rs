@@ -0,0 +1,37 @@
+bitflags::bitflags! {
+    /// A selection of alignment record fields to decode.
+    ///
+    /// When reading record buffers with a field selector, fields that are not selected are not
+    /// decoded and are set to their default values. In particular, skipping the variable-length
+    /// fields (sequence, quality scores, and data) avoids most of the decoding work, which is
+    /// useful for passes that only count or summarize records (e.g., `flagstat` or `idxstats`).
+    ///
+    /// The default selection is all fields.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub struct FieldSelector: u16 {
+        /// The flags.
+        const FLAGS = 0x01;
+        /// The reference sequence ID and alignment start.
+        const POSITION = 0x02;
+        /// The mapping quality.
+        const MAPPING_QUALITY = 0x04;
+        /// The mate reference sequence ID, mate alignment start, and template length.
+        const MATE = 0x08;
+        /// The name.
+        const NAME = 0x10;
+        /// The CIGAR operations.
+        const CIGAR = 0x20;
+        /// The sequence.
+        const SEQUENCE = 0x40;
+        /// The quality scores.
+        const QUALITY_SCORES = 0x80;
+        /// The data.
+        const DATA = 0x0100;
+    }
+}
+
+impl Default for FieldSelector {
+    fn default() -> Self {
+        Self::all()
+    }
+}
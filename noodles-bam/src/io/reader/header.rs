@@ -13,10 +13,16 @@ use noodles_sam::{
     },
 };
 
-use super::bytes_with_nul_to_bstring;
+use super::{bytes_with_nul_to_bstring, ValidationLevel};
 use crate::MAGIC_NUMBER;
 
-pub(super) fn read_header<R>(reader: &mut R) -> io::Result<sam::Header>
+// The maximum number of reference sequences to preallocate, regardless of `n_ref`.
+const MAX_REFERENCE_SEQUENCES_CAPACITY: usize = 1 << 16;
+
+pub(super) fn read_header<R>(
+    reader: &mut R,
+    validation_level: ValidationLevel,
+) -> io::Result<sam::Header>
 where
     R: Read,
 {
@@ -28,10 +34,19 @@ where
     if header.reference_sequences().is_empty() {
         *header.reference_sequences_mut() = reference_sequences;
     } else if !reference_sequences_eq(header.reference_sequences(), &reference_sequences) {
-        return Err(io::Error::new(
-            io::ErrorKind::InvalidData,
-            "SAM header and binary reference sequence dictionaries mismatch",
-        ));
+        match validation_level {
+            ValidationLevel::Strict => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "SAM header and binary reference sequence dictionaries mismatch",
+                ));
+            }
+            ValidationLevel::Lenient => {
+                let reference_sequences =
+                    merge_reference_sequences(header.reference_sequences(), reference_sequences);
+                *header.reference_sequences_mut() = reference_sequences;
+            }
+        }
     }
 
     Ok(header)
@@ -132,11 +147,15 @@ where
         usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
     })?;
 
-    let mut reference_sequences = ReferenceSequences::with_capacity(n_ref);
+    // `n_ref` is untrusted, so the preallocation is bounded.
+    let mut reference_sequences =
+        ReferenceSequences::with_capacity(n_ref.min(MAX_REFERENCE_SEQUENCES_CAPACITY));
 
     for _ in 0..n_ref {
         let (name, reference_sequence) = read_reference_sequence(reader)?;
-        reference_sequences.insert(name, reference_sequence);
+        reference_sequences
+            .insert(name, reference_sequence)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
     }
 
     Ok(reference_sequences)
@@ -146,12 +165,14 @@ fn read_reference_sequence<R>(reader: &mut R) -> io::Result<(BString, Map<Refere
 where
     R: Read,
 {
-    let l_name = reader.read_u32::<LittleEndian>().and_then(|n| {
-        usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-    })?;
+    let l_name = reader.read_u32::<LittleEndian>().map(u64::from)?;
+
+    // `l_name` is untrusted, so the name is read without preallocating `l_name` bytes.
+    let mut c_name = Vec::new();
 
-    let mut c_name = vec![0; l_name];
-    reader.read_exact(&mut c_name)?;
+    if reader.take(l_name).read_to_end(&mut c_name)? as u64 != l_name {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+    }
 
     let name = bytes_with_nul_to_bstring(&c_name)?;
 
@@ -166,6 +187,25 @@ where
     Ok((name, reference_sequence))
 }
 
+fn merge_reference_sequences(
+    header_reference_sequences: &ReferenceSequences,
+    binary_reference_sequences: ReferenceSequences,
+) -> ReferenceSequences {
+    binary_reference_sequences
+        .into_iter()
+        .map(
+            |(name, reference_sequence)| match header_reference_sequences.get(&name) {
+                Some(header_reference_sequence)
+                    if header_reference_sequence.length() == reference_sequence.length() =>
+                {
+                    (name, header_reference_sequence.clone())
+                }
+                _ => (name, reference_sequence),
+            },
+        )
+        .collect()
+}
+
 pub(crate) fn reference_sequences_eq(
     header_reference_sequences: &ReferenceSequences,
     binary_reference_sequences: &ReferenceSequences,
@@ -232,7 +272,7 @@ mod tests {
         data.put_u32_le(8); // ref[0].l_ref
 
         let mut reader = &data[..];
-        let actual = read_header(&mut reader)?;
+        let actual = read_header(&mut reader, ValidationLevel::Strict)?;
 
         let expected = sam::Header::builder()
             .set_header(Map::<map::Header>::new(Version::new(1, 6)))
@@ -259,7 +299,7 @@ mod tests {
         data.put_u32_le(8); // ref[0].l_ref
 
         let mut reader = &data[..];
-        let actual = read_header(&mut reader)?;
+        let actual = read_header(&mut reader, ValidationLevel::Strict)?;
 
         let expected = sam::Header::builder()
             .set_header(Map::<map::Header>::new(Version::new(1, 6)))
@@ -283,7 +323,7 @@ mod tests {
         data.put_u32_le(8); // ref[0].l_ref
 
         let mut reader = &data[..];
-        let actual = read_header(&mut reader)?;
+        let actual = read_header(&mut reader, ValidationLevel::Strict)?;
 
         let expected = sam::Header::builder()
             .set_header(Map::<map::Header>::new(Version::new(1, 6)))
@@ -295,6 +335,60 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_header_with_reference_sequence_dictionary_mismatch() -> io::Result<()> {
+        const SQ1_LN: NonZeroUsize = match NonZeroUsize::new(13) {
+            Some(length) => length,
+            None => unreachable!(),
+        };
+
+        let mut data = Vec::new();
+        data.put_slice(MAGIC_NUMBER); // magic
+        data.put_u32_le(27); // l_text
+        data.put_slice(b"@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:8\n"); // text
+        data.put_u32_le(2); // n_ref
+        data.put_u32_le(4); // ref[0].l_name
+        data.put_slice(b"sq0\x00"); // ref[0].name
+        data.put_u32_le(8); // ref[0].l_ref
+        data.put_u32_le(4); // ref[1].l_name
+        data.put_slice(b"sq1\x00"); // ref[1].name
+        data.put_u32_le(13); // ref[1].l_ref
+
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_header(&mut reader, ValidationLevel::Strict),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let mut reader = &data[..];
+        let actual = read_header(&mut reader, ValidationLevel::Lenient)?;
+
+        let expected = sam::Header::builder()
+            .set_header(Map::<map::Header>::new(Version::new(1, 6)))
+            .add_reference_sequence("sq0", Map::<map::ReferenceSequence>::new(SQ0_LN))
+            .add_reference_sequence("sq1", Map::<map::ReferenceSequence>::new(SQ1_LN))
+            .build();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_reference_sequences_with_pathological_sizes() {
+        let data = [
+            0xff, 0xff, 0xff, 0xff, // n_ref = 4294967295
+            0xff, 0xff, 0xff, 0xff, // ref[0].l_name = 4294967295
+            0x73, 0x71, 0x30, 0x00, // ref[0].name = "sq0\x00"
+        ];
+
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_reference_sequences(&mut reader),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+    }
+
     #[test]
     fn test_read_reference_sequences() -> Result<(), Box<dyn std::error::Error>> {
         let data = [
@@ -0,0 +1,169 @@
+use std::io::{self, Read};
+
+use super::Reader;
+use crate::Record;
+
+/// An iterator over groups of records that share a name.
+///
+/// The input is expected to be name-collated, i.e., records with the same name are adjacent, but
+/// the names themselves do not need to be sorted. Only the records of the current group are
+/// buffered.
+///
+/// Records without a name are each yielded as a single-record group.
+///
+/// Collation is not validated. If records with the same name are not adjacent, they are yielded
+/// in separate groups.
+///
+/// This is created by calling [`Reader::name_groups`].
+pub struct NameGroups<'r, R> {
+    reader: &'r mut Reader<R>,
+    next_record: Option<Record>,
+    is_eof: bool,
+}
+
+impl<'r, R> NameGroups<'r, R>
+where
+    R: Read,
+{
+    pub(super) fn new(reader: &'r mut Reader<R>) -> Self {
+        Self {
+            reader,
+            next_record: None,
+            is_eof: false,
+        }
+    }
+
+    fn read_record(&mut self) -> io::Result<Option<Record>> {
+        let mut record = Record::default();
+
+        match self.reader.read_record(&mut record)? {
+            0 => Ok(None),
+            _ => Ok(Some(record)),
+        }
+    }
+
+    fn read_group(&mut self) -> io::Result<Option<Vec<Record>>> {
+        let first_record = match self.next_record.take() {
+            Some(record) => record,
+            None => match self.read_record()? {
+                Some(record) => record,
+                None => return Ok(None),
+            },
+        };
+
+        let mut group = vec![first_record];
+
+        if group[0].name().is_none() {
+            return Ok(Some(group));
+        }
+
+        while let Some(record) = self.read_record()? {
+            if record.name().is_some() && record.name() == group[0].name() {
+                group.push(record);
+            } else {
+                self.next_record = Some(record);
+                break;
+            }
+        }
+
+        Ok(Some(group))
+    }
+}
+
+impl<'r, R> Iterator for NameGroups<'r, R>
+where
+    R: Read,
+{
+    type Item = io::Result<Vec<Record>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_eof {
+            return None;
+        }
+
+        match self.read_group() {
+            Ok(Some(group)) => Some(Ok(group)),
+            Ok(None) => {
+                self.is_eof = true;
+                None
+            }
+            Err(e) => {
+                self.is_eof = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::{
+        self as sam,
+        alignment::{io::Write, RecordBuf},
+    };
+
+    use super::*;
+    use crate::io::Writer;
+
+    fn write(header: &sam::Header, names: &[Option<&str>]) -> io::Result<Vec<u8>> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(header)?;
+
+        for name in names {
+            let mut builder = RecordBuf::builder();
+
+            if let Some(name) = name {
+                builder = builder.set_name(*name);
+            }
+
+            writer.write_alignment_record(header, &builder.build())?;
+        }
+
+        writer.into_inner().finish()
+    }
+
+    #[test]
+    fn test_next() -> io::Result<()> {
+        let header = sam::Header::default();
+
+        let src = write(
+            &header,
+            &[
+                Some("r1"),
+                Some("r1"),
+                Some("r0"),
+                None,
+                None,
+                Some("r2"),
+                Some("r2"),
+                Some("r2"),
+            ],
+        )?;
+
+        let mut reader = Reader::new(&src[..]);
+        reader.read_header()?;
+
+        let groups: Vec<_> = reader
+            .name_groups()
+            .map(|result| {
+                result.map(|group| {
+                    let name = group[0].name().map(|name| name.to_string());
+                    (name, group.len())
+                })
+            })
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(
+            groups,
+            [
+                (Some(String::from("r1")), 2),
+                (Some(String::from("r0")), 1),
+                (None, 1),
+                (None, 1),
+                (Some(String::from("r2")), 3),
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@ use std::io::{self, Read};
 
 use noodles_sam::{self as sam, alignment::RecordBuf};
 
-use super::read_record;
+use super::{read_record, FieldSelector};
 
 pub(crate) fn read_record_buf<R>(
     reader: &mut R,
@@ -26,6 +26,30 @@ where
     Ok(block_size)
 }
 
+pub(crate) fn read_record_buf_with_fields<R>(
+    reader: &mut R,
+    header: &sam::Header,
+    buf: &mut Vec<u8>,
+    fields: FieldSelector,
+    record: &mut RecordBuf,
+) -> io::Result<usize>
+where
+    R: Read,
+{
+    use crate::record::codec::decoder::decode_with_fields;
+
+    let block_size = match read_record(reader, buf)? {
+        0 => return Ok(0),
+        n => n,
+    };
+
+    let mut src = &buf[..];
+    decode_with_fields(&mut src, header, record, fields)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    Ok(block_size)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,17 +2,18 @@ use std::io::{self, Read};
 
 use noodles_sam::{self as sam, alignment::RecordBuf};
 
-use super::Reader;
+use super::{FieldSelector, Reader};
 
 /// An iterator over records of a BAM reader.
 ///
-/// This is created by calling [`Reader::record_bufs`].
+/// This is created by calling [`Reader::record_bufs`] or [`Reader::record_bufs_with_fields`].
 pub struct RecordBufs<'a, R>
 where
     R: Read,
 {
     reader: &'a mut Reader<R>,
     header: &'a sam::Header,
+    fields: FieldSelector,
     record: RecordBuf,
 }
 
@@ -20,10 +21,15 @@ impl<'a, R> RecordBufs<'a, R>
 where
     R: Read,
 {
-    pub(super) fn new(reader: &'a mut Reader<R>, header: &'a sam::Header) -> Self {
+    pub(super) fn new(
+        reader: &'a mut Reader<R>,
+        header: &'a sam::Header,
+        fields: FieldSelector,
+    ) -> Self {
         Self {
             reader,
             header,
+            fields,
             record: RecordBuf::default(),
         }
     }
@@ -36,7 +42,10 @@ where
     type Item = io::Result<RecordBuf>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.reader.read_record_buf(self.header, &mut self.record) {
+        match self
+            .reader
+            .read_record_buf_with_fields(self.header, self.fields, &mut self.record)
+        {
             Ok(0) => None,
             Ok(_) => Some(Ok(self.record.clone())),
             Err(e) => Some(Err(e)),
@@ -0,0 +1,89 @@
+use std::{error, fmt, io};
+
+use bstr::{BStr, BString};
+use noodles_bgzf as bgzf;
+
+/// An error returned when a BAM record fails to be read or decoded.
+///
+/// This wraps the underlying error with the location of the record in the stream. It is created
+/// by [`super::RecordsLossy`].
+#[derive(Debug)]
+pub struct RecordError {
+    record_index: u64,
+    position: bgzf::VirtualPosition,
+    reference_sequence_name: Option<BString>,
+    source: io::Error,
+}
+
+impl RecordError {
+    pub(super) fn new(
+        record_index: u64,
+        position: bgzf::VirtualPosition,
+        reference_sequence_name: Option<BString>,
+        source: io::Error,
+    ) -> Self {
+        Self {
+            record_index,
+            position,
+            reference_sequence_name,
+            source,
+        }
+    }
+
+    /// Returns the (0-based) index of the record, counted from the start of iteration.
+    pub fn record_index(&self) -> u64 {
+        self.record_index
+    }
+
+    /// Returns the virtual position of the start of the record.
+    pub fn position(&self) -> bgzf::VirtualPosition {
+        self.position
+    }
+
+    /// Returns the reference sequence name of the record, if known.
+    pub fn reference_sequence_name(&self) -> Option<&BStr> {
+        self.reference_sequence_name
+            .as_ref()
+            .map(|name| name.as_ref())
+    }
+
+    /// Returns a reference to the underlying error.
+    pub fn get_ref(&self) -> &io::Error {
+        &self.source
+    }
+
+    /// Unwraps and returns the underlying error.
+    pub fn into_inner(self) -> io::Error {
+        self.source
+    }
+}
+
+impl error::Error for RecordError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl fmt::Display for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (cpos, upos) = self.position.into();
+
+        write!(
+            f,
+            "invalid record (record index = {}, virtual position = ({cpos}, {upos})",
+            self.record_index
+        )?;
+
+        if let Some(name) = &self.reference_sequence_name {
+            write!(f, ", reference sequence name = {name}")?;
+        }
+
+        f.write_str(")")
+    }
+}
+
+impl From<RecordError> for io::Error {
+    fn from(e: RecordError) -> Self {
+        Self::new(e.source.kind(), e)
+    }
+}
@@ -0,0 +1,165 @@
+use std::io;
+
+use bstr::BString;
+use noodles_bgzf as bgzf;
+use noodles_sam::{self as sam, alignment::RecordBuf};
+
+use super::{read_record, Reader, RecordError};
+
+/// An iterator over records of a BAM reader that skips records that fail to decode.
+///
+/// This is created by calling [`Reader::records_lossy`].
+pub struct RecordsLossy<'r, 'h, R> {
+    reader: &'r mut Reader<R>,
+    header: &'h sam::Header,
+    record: RecordBuf,
+    record_index: u64,
+    is_eof: bool,
+}
+
+impl<'r, 'h, R> RecordsLossy<'r, 'h, R> {
+    pub(super) fn new(reader: &'r mut Reader<R>, header: &'h sam::Header) -> Self {
+        Self {
+            reader,
+            header,
+            record: RecordBuf::default(),
+            record_index: 0,
+            is_eof: false,
+        }
+    }
+}
+
+impl<'r, 'h, R> Iterator for RecordsLossy<'r, 'h, R>
+where
+    R: bgzf::io::Read,
+{
+    type Item = Result<RecordBuf, RecordError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use crate::record::codec::decode;
+
+        if self.is_eof {
+            return None;
+        }
+
+        let record_index = self.record_index;
+        let position = self.reader.get_ref().virtual_position();
+
+        match read_record(&mut self.reader.inner, &mut self.reader.buf) {
+            Ok(0) => return None,
+            Ok(_) => self.record_index += 1,
+            Err(e) => {
+                // The record boundary is unknown, so the stream cannot be resynchronized.
+                self.is_eof = true;
+                return Some(Err(RecordError::new(record_index, position, None, e)));
+            }
+        }
+
+        let buf = &self.reader.buf;
+        let mut src = &buf[..];
+
+        match decode(&mut src, self.header, &mut self.record) {
+            Ok(()) => Some(Ok(self.record.clone())),
+            Err(e) => Some(Err(RecordError::new(
+                record_index,
+                position,
+                reference_sequence_name(self.header, buf),
+                io::Error::new(io::ErrorKind::InvalidData, e),
+            ))),
+        }
+    }
+}
+
+fn reference_sequence_name(header: &sam::Header, buf: &[u8]) -> Option<BString> {
+    let raw_id = buf.get(..4)?.try_into().ok()?;
+    let id = usize::try_from(i32::from_le_bytes(raw_id)).ok()?;
+
+    header
+        .reference_sequences()
+        .get_index(id)
+        .map(|(name, _)| name.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Write, num::NonZeroUsize};
+
+    use noodles_sam::header::record::value::{map::ReferenceSequence, Map};
+
+    use super::*;
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        #[rustfmt::skip]
+        const UNMAPPED_RECORD: [u8; 38] = [
+            0x22, 0x00, 0x00, 0x00, // block_size = 34
+            0xff, 0xff, 0xff, 0xff, // ref_id = -1
+            0xff, 0xff, 0xff, 0xff, // pos = -1
+            0x02, // l_read_name = 2
+            0xff, // mapq = 255
+            0x48, 0x12, // bin = 4680
+            0x00, 0x00, // n_cigar_op = 0
+            0x04, 0x00, // flag = 4
+            0x00, 0x00, 0x00, 0x00, // l_seq = 0
+            0xff, 0xff, 0xff, 0xff, // next_ref_id = -1
+            0xff, 0xff, 0xff, 0xff, // next_pos = -1
+            0x00, 0x00, 0x00, 0x00, // tlen = 0
+            0x2a, 0x00, // read_name = "*\x00"
+        ];
+
+        #[rustfmt::skip]
+        const INVALID_RECORD: [u8; 42] = [
+            0x26, 0x00, 0x00, 0x00, // block_size = 38
+            0x00, 0x00, 0x00, 0x00, // ref_id = 0
+            0x00, 0x00, 0x00, 0x00, // pos = 0
+            0x02, // l_read_name = 2
+            0xff, // mapq = 255
+            0x49, 0x12, // bin = 4681
+            0x01, 0x00, // n_cigar_op = 1
+            0x00, 0x00, // flag = 0
+            0x00, 0x00, 0x00, 0x00, // l_seq = 0
+            0xff, 0xff, 0xff, 0xff, // next_ref_id = -1
+            0xff, 0xff, 0xff, 0xff, // next_pos = -1
+            0x00, 0x00, 0x00, 0x00, // tlen = 0
+            0x2a, 0x00, // read_name = "*\x00"
+            0x1f, 0x00, 0x00, 0x00, // cigar = [1 (invalid kind)]
+        ];
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(&UNMAPPED_RECORD)?;
+        writer.write_all(&INVALID_RECORD)?;
+        writer.write_all(&UNMAPPED_RECORD)?;
+        // truncated record
+        writer.write_all(&UNMAPPED_RECORD[..8])?;
+        let data = writer.finish()?;
+
+        let mut reader = Reader::new(&data[..]);
+        let mut records = reader.records_lossy(&header);
+
+        assert!(matches!(records.next(), Some(Ok(_))));
+
+        let e = records.next().unwrap().unwrap_err();
+        assert_eq!(e.record_index(), 1);
+        assert_eq!(e.position(), bgzf::VirtualPosition::from(38));
+        assert_eq!(e.reference_sequence_name(), Some(b"sq0".as_ref().into()));
+        assert_eq!(e.get_ref().kind(), io::ErrorKind::InvalidData);
+
+        assert!(matches!(records.next(), Some(Ok(_))));
+
+        let e = records.next().unwrap().unwrap_err();
+        assert_eq!(e.record_index(), 3);
+        assert!(e.reference_sequence_name().is_none());
+        assert_eq!(e.get_ref().kind(), io::ErrorKind::UnexpectedEof);
+
+        assert!(records.next().is_none());
+
+        Ok(())
+    }
+}
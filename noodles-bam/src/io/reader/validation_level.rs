@@ -0,0 +1,16 @@
+/// A BAM reader validation level.
+///
+/// This controls how strictly the header is checked when reading.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ValidationLevel {
+    /// The SAM header and binary reference sequence dictionaries must agree.
+    #[default]
+    Strict,
+    /// Disagreements between the SAM header and binary reference sequence dictionaries are
+    /// accepted.
+    ///
+    /// Record reference sequence IDs refer to the binary reference sequence dictionary, so it is
+    /// used as the reference sequence dictionary of the header. Fields of SAM header reference
+    /// sequences that match a binary reference sequence by name and length are kept.
+    Lenient,
+}
@@ -0,0 +1,170 @@
+use std::{
+    io::{self, Read, Write},
+    num::NonZeroUsize,
+};
+
+use noodles_bgzf::{self as bgzf, writer::CompressionLevel};
+
+use crate::MAGIC_NUMBER;
+
+/// Recompresses a BAM stream.
+///
+/// The input is decompressed and recompressed using the given compression level, where both
+/// decompression and compression are done in parallel using `worker_count` threads each. The
+/// header and records are copied as-is, i.e., they are not decoded, but BGZF block boundaries
+/// are not preserved. This means any associated index must be regenerated.
+///
+/// This is useful, e.g., to convert fast, low compression intermediate files to smaller files
+/// for archiving.
+///
+/// The returned value is the underlying writer after the BGZF EOF block is written.
+///
+/// # Errors
+///
+/// This returns an `io::Error` if the input is not a BAM stream.
+///
+/// # Examples
+///
+/// ```
+/// use std::{io, num::NonZeroUsize};
+/// use noodles_bam as bam;
+/// use noodles_bgzf::writer::CompressionLevel;
+/// use noodles_sam as sam;
+///
+/// let mut writer = bam::io::Writer::new(Vec::new());
+/// writer.write_header(&sam::Header::default())?;
+/// let src = writer.into_inner().finish()?;
+///
+/// let dst = bam::io::recompress(
+///     io::Cursor::new(src),
+///     Vec::new(),
+///     CompressionLevel::BEST,
+///     NonZeroUsize::MIN,
+/// )?;
+///
+/// let mut reader = bam::io::Reader::new(&dst[..]);
+/// let header = reader.read_header()?;
+/// assert!(header.is_empty());
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn recompress<R, W>(
+    reader: R,
+    writer: W,
+    compression_level: CompressionLevel,
+    worker_count: NonZeroUsize,
+) -> io::Result<W>
+where
+    R: Read + Send + 'static,
+    W: Write + Send + 'static,
+{
+    let mut reader = bgzf::MultithreadedReader::with_worker_count(worker_count, reader);
+
+    let mut writer = bgzf::multithreaded_writer::Builder::default()
+        .set_compression_level(compression_level)
+        .set_worker_count(worker_count)
+        .build_from_writer(writer);
+
+    let mut magic = [0; 4];
+    reader.read_exact(&mut magic)?;
+
+    if magic != MAGIC_NUMBER {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid BAM header",
+        ));
+    }
+
+    writer.write_all(&magic)?;
+    io::copy(&mut reader, &mut writer)?;
+
+    writer.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use noodles_sam::{
+        self as sam,
+        alignment::{io::Write as _, RecordBuf},
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+    use crate::io::{Reader, Writer};
+
+    #[test]
+    fn test_recompress() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let records: Vec<_> = (0..1024)
+            .map(|i| {
+                RecordBuf::builder()
+                    .set_name(format!("r{i}"))
+                    .set_sequence(b"ACGTACGTACGT".to_vec().into())
+                    .build()
+            })
+            .collect();
+
+        let mut writer = Writer::from(
+            bgzf::writer::Builder::default()
+                .set_compression_level(CompressionLevel::NONE)
+                .build_with_writer(Vec::new()),
+        );
+
+        writer.write_header(&header)?;
+
+        for record in &records {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        let src = writer.into_inner().finish()?;
+
+        for worker_count in [1, 2, 4] {
+            let dst = recompress(
+                Cursor::new(src.clone()),
+                Vec::new(),
+                CompressionLevel::BEST,
+                NonZeroUsize::try_from(worker_count)?,
+            )?;
+
+            assert!(dst.len() < src.len());
+
+            let mut reader = Reader::new(&dst[..]);
+            let actual_header = reader.read_header()?;
+            assert_eq!(actual_header, header);
+
+            let actual_records = reader
+                .record_bufs(&actual_header)
+                .collect::<io::Result<Vec<_>>>()?;
+
+            assert_eq!(actual_records, records);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_recompress_with_invalid_magic_number() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"CRAM")?;
+        let src = writer.finish()?;
+
+        assert!(matches!(
+            recompress(
+                Cursor::new(src),
+                Vec::new(),
+                CompressionLevel::default(),
+                NonZeroUsize::MIN,
+            ),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -33,6 +33,14 @@ use crate::Record;
 pub struct Writer<W> {
     inner: W,
     buf: Vec<u8>,
+    header_state: HeaderState,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum HeaderState {
+    Unchecked,
+    Required,
+    Written,
 }
 
 impl<W> Writer<W>
@@ -81,7 +89,12 @@ where
     /// Writes a SAM header.
     ///
     /// This writes the BAM magic number, the raw SAM header, and a copy of the reference sequence
-    /// dictionary as binary reference sequences.
+    /// dictionary as binary reference sequences. There is no separate call to write the reference
+    /// sequences.
+    ///
+    /// If the writer was built to require a header (see
+    /// [`Builder::set_require_header`](self::Builder::set_require_header)), this must be called
+    /// before writing any records, and it can only be called once.
     ///
     /// # Examples
     ///
@@ -98,7 +111,21 @@ where
     /// ```
     pub fn write_header(&mut self, header: &sam::Header) -> io::Result<()> {
         use self::header::write_header;
-        write_header(&mut self.inner, header)
+
+        if self.header_state == HeaderState::Written {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "header already written",
+            ));
+        }
+
+        write_header(&mut self.inner, header)?;
+
+        if self.header_state == HeaderState::Required {
+            self.header_state = HeaderState::Written;
+        }
+
+        Ok(())
     }
 
     /// Writes a BAM record.
@@ -167,6 +194,7 @@ impl<W> From<W> for Writer<W> {
         Self {
             inner,
             buf: Vec::new(),
+            header_state: HeaderState::Unchecked,
         }
     }
 }
@@ -186,6 +214,13 @@ where
     ) -> io::Result<()> {
         use crate::record::codec::encode;
 
+        if self.header_state == HeaderState::Required {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "record written before header",
+            ));
+        }
+
         self.buf.clear();
         encode(&mut self.buf, header, record)?;
 
@@ -214,6 +249,31 @@ mod tests {
     use super::*;
     use crate::io::Reader;
 
+    #[test]
+    fn test_write_alignment_record_with_required_header() -> io::Result<()> {
+        let header = sam::Header::default();
+        let record = RecordBuf::default();
+
+        let mut writer = Builder::default()
+            .set_require_header(true)
+            .build_from_writer(Vec::new());
+
+        assert!(matches!(
+            writer.write_alignment_record(&header, &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        writer.write_header(&header)?;
+        writer.write_alignment_record(&header, &record)?;
+
+        assert!(matches!(
+            writer.write_header(&header),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_alignment_record() -> Result<(), Box<dyn std::error::Error>> {
         let mut writer = Writer::new(Vec::new());
@@ -388,4 +448,139 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_alignment_record_with_array_data_fields() -> io::Result<()> {
+        use sam::alignment::{
+            record::data::field::Tag,
+            record_buf::data::field::{value::Array, Value},
+        };
+
+        use crate::Record;
+
+        // Integer arrays with values that fit in smaller subtypes keep their subtypes.
+        let arrays = [
+            Array::from(vec![-1i8, 0, 1]),
+            Array::from(vec![0u8, 1, 2]),
+            Array::from(vec![-1i16, 0, 1]),
+            Array::from(vec![0u16, 1, 2]),
+            Array::from(vec![-1i32, 0, 1]),
+            Array::from(vec![0u32, 1, 2]),
+            Array::from(vec![-1.5f32, 0.0, 1.5]),
+            Array::from(Vec::<i32>::new()),
+        ];
+
+        let data = arrays
+            .into_iter()
+            .zip(b'a'..)
+            .map(|(array, c)| (Tag::new(b'X', c), Value::Array(array)))
+            .collect();
+
+        let header = sam::Header::default();
+        let expected = RecordBuf::builder().set_data(data).build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_alignment_record(&header, &expected)?;
+        writer.try_finish()?;
+
+        let mut reader = Reader::new(writer.get_ref().get_ref().as_slice());
+        let mut actual = RecordBuf::default();
+        reader.read_record_buf(&header, &mut actual)?;
+        assert_eq!(actual.data(), expected.data());
+
+        let mut reader = Reader::new(writer.get_ref().get_ref().as_slice());
+        let mut record = Record::default();
+        reader.read_record(&mut record)?;
+        let actual = RecordBuf::try_from_alignment_record(&header, &record)?;
+        assert_eq!(actual.data(), expected.data());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_alignment_record_with_long_read() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use noodles_core::Position;
+        use sam::{
+            alignment::{
+                record::cigar::{op::Kind, Op},
+                record_buf::Cigar,
+                Record as _,
+            },
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        use crate::Record;
+
+        // A 1.5 Mbp spliced read with 200,000 ops, i.e., more than the 65535 ops that fit in
+        // `n_cigar_op`.
+        const OP_PAIR_COUNT: usize = 100_000;
+        const BASE_COUNT: usize = 15 * OP_PAIR_COUNT;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1 << 29)?),
+            )
+            .build();
+
+        let cigar: Cigar = [Op::new(Kind::Match, 15), Op::new(Kind::Skip, 1000)]
+            .into_iter()
+            .cycle()
+            .take(2 * OP_PAIR_COUNT)
+            .collect();
+
+        let expected = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_cigar(cigar)
+            .set_sequence(Sequence::from(vec![b'A'; BASE_COUNT]))
+            .set_quality_scores(QualityScores::from(vec![30; BASE_COUNT]))
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_alignment_record(&header, &expected)?;
+        writer.try_finish()?;
+
+        let mut reader = Reader::new(writer.get_ref().get_ref().as_slice());
+        let mut actual = RecordBuf::default();
+        reader.read_record_buf(&header, &mut actual)?;
+        assert_eq!(actual, expected);
+
+        let mut reader = Reader::new(writer.get_ref().get_ref().as_slice());
+        let mut record = Record::default();
+        reader.read_record(&mut record)?;
+        assert_eq!(record.cigar().len(), 2 * OP_PAIR_COUNT);
+        assert_eq!(
+            record.alignment_end().transpose()?,
+            expected.alignment_end()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_alignment_record_with_oversized_op() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+        use sam::alignment::record::cigar::{op::Kind, Op};
+
+        let header = sam::Header::default();
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::UNMAPPED)
+            .set_alignment_start(Position::MIN)
+            .set_cigar([Op::new(Kind::Match, 1 << 28)].into_iter().collect())
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+
+        assert!(matches!(
+            writer.write_alignment_record(&header, &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
 }
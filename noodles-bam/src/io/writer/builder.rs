@@ -6,13 +6,55 @@ use std::{
 
 use noodles_bgzf as bgzf;
 
-use super::Writer;
+use super::{HeaderState, Writer};
 
 /// A BAM writer builder.
 #[derive(Debug, Default)]
-pub struct Builder;
+pub struct Builder {
+    require_header: bool,
+    compression_level: Option<bgzf::writer::CompressionLevel>,
+}
 
 impl Builder {
+    /// Sets whether a header must be written before any records.
+    ///
+    /// When enabled, writing a record before calling [`Writer::write_header`] returns an error
+    /// instead of producing a BAM without a header. By default, this is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let builder = bam::io::writer::Builder::default().set_require_header(true);
+    /// ```
+    pub fn set_require_header(mut self, require_header: bool) -> Self {
+        self.require_header = require_header;
+        self
+    }
+
+    /// Sets the compression level.
+    ///
+    /// By default, the BGZF writer's default compression level is used.
+    /// [`bgzf::writer::CompressionLevel::NONE`] writes uncompressed BAM (cf. `samtools view -u`),
+    /// which is useful for fast intermediate files.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// use noodles_bgzf::writer::CompressionLevel;
+    ///
+    /// let builder = bam::io::writer::Builder::default()
+    ///     .set_compression_level(CompressionLevel::NONE);
+    /// ```
+    pub fn set_compression_level(
+        mut self,
+        compression_level: bgzf::writer::CompressionLevel,
+    ) -> Self {
+        self.compression_level = Some(compression_level);
+        self
+    }
+
     /// Builds a BAM writer from a path.
     ///
     /// # Examples
@@ -26,7 +68,7 @@ impl Builder {
     where
         P: AsRef<Path>,
     {
-        File::create(dst).map(Writer::new)
+        File::create(dst).map(|file| self.build_from_writer(file))
     }
 
     /// Builds a BAM writer from a writer.
@@ -42,6 +84,60 @@ impl Builder {
     where
         W: Write,
     {
-        Writer::new(writer)
+        let mut builder = bgzf::writer::Builder::default();
+
+        if let Some(compression_level) = self.compression_level {
+            builder = builder.set_compression_level(compression_level);
+        }
+
+        let mut writer = Writer::from(builder.build_with_writer(writer));
+
+        if self.require_header {
+            writer.header_state = HeaderState::Required;
+        }
+
+        writer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::{
+        self as sam,
+        alignment::{io::Write, RecordBuf},
+    };
+
+    use super::*;
+    use crate::io::Reader;
+
+    #[test]
+    fn test_build_from_writer_with_compression_level() -> io::Result<()> {
+        fn write(compression_level: Option<bgzf::writer::CompressionLevel>) -> io::Result<Vec<u8>> {
+            let mut builder = Builder::default();
+
+            if let Some(compression_level) = compression_level {
+                builder = builder.set_compression_level(compression_level);
+            }
+
+            let header = sam::Header::default();
+            let mut writer = builder.build_from_writer(Vec::new());
+            writer.write_header(&header)?;
+
+            for _ in 0..64 {
+                writer.write_alignment_record(&header, &RecordBuf::default())?;
+            }
+
+            writer.into_inner().finish()
+        }
+
+        let compressed_data = write(None)?;
+        let uncompressed_data = write(Some(bgzf::writer::CompressionLevel::NONE))?;
+        assert!(uncompressed_data.len() > compressed_data.len());
+
+        let mut reader = Reader::new(&uncompressed_data[..]);
+        reader.read_header()?;
+        assert_eq!(reader.records().count(), 64);
+
+        Ok(())
     }
 }
@@ -48,12 +48,37 @@
 pub mod r#async;
 
 pub mod bai;
+pub mod count;
+pub mod idxstats;
 pub mod io;
 pub mod record;
 
-pub use self::record::Record;
+pub use self::{count::count_records, idxstats::idxstats, record::Record};
 
 #[cfg(feature = "async")]
 pub use self::r#async::io::{Reader as AsyncReader, Writer as AsyncWriter};
 
+use std::{fs::File, path::Path};
+
+use noodles_sam as sam;
+
 static MAGIC_NUMBER: &[u8] = b"BAM\x01";
+
+/// Reads only the SAM header from a BAM file.
+///
+/// This stops reading after the header and does not read any records.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_bam as bam;
+/// let header = bam::read_header_only("sample.bam")?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn read_header_only<P>(src: P) -> std::io::Result<sam::Header>
+where
+    P: AsRef<Path>,
+{
+    let mut reader = File::open(src).map(io::Reader::new)?;
+    reader.read_header()
+}
@@ -337,9 +337,70 @@ mod tests {
 
         encode(&mut buf, &header, &record)?;
 
+        let mut src = &buf[..];
+        let mut decoded_record = RecordBuf::default();
+        crate::record::codec::decode(&mut src, &header, &mut decoded_record)?;
+        assert_eq!(decoded_record, record);
+
         let record = Fields::try_from(buf).map(Record)?;
         assert_eq!(record.cigar().len(), BASE_COUNT);
 
+        let data = record.data();
+        assert!(data.get(&Tag::CIGAR).is_none());
+        assert_eq!(data.iter().count(), 1);
+        assert!(data.get(&Tag::ALIGNMENT_HIT_COUNT).is_some());
+
+        let record = RecordBuf::try_from_alignment_record(&header, &record)?;
+        assert_eq!(record.cigar(), decoded_record.cigar());
+        assert_eq!(record.data(), decoded_record.data());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_sam_string() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use noodles_sam::{
+            alignment::{
+                record::{
+                    cigar::{op::Kind, Op},
+                    Flags,
+                },
+                record_buf::{Cigar, QualityScores, Sequence},
+                Record as _, RecordBuf,
+            },
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        use crate::record::codec::encode;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let record = RecordBuf::builder()
+            .set_name("r0")
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_cigar(Cigar::from(vec![Op::new(Kind::Match, 4)]))
+            .set_sequence(Sequence::from(b"ACGT".to_vec()))
+            .set_quality_scores(QualityScores::from(vec![45, 35, 43, 50]))
+            .build();
+
+        let mut buf = Vec::new();
+        encode(&mut buf, &header, &record)?;
+        let record = Fields::try_from(buf).map(Record)?;
+
+        assert_eq!(
+            record.to_sam_string(&header)?,
+            "r0\t0\tsq0\t1\t255\t4M\t*\t0\t0\tACGT\tNDLS"
+        );
+
         Ok(())
     }
 }
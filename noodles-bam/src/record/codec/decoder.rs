@@ -19,12 +19,16 @@ pub(crate) use self::{
 use std::{error, fmt, mem};
 
 use bytes::Buf;
-use noodles_sam::{self as sam, alignment::RecordBuf};
+use noodles_sam::{
+    self as sam,
+    alignment::{record::Flags, RecordBuf},
+};
 
 use self::{
     flags::get_flags, mapping_quality::get_mapping_quality, name::get_name, position::get_position,
     template_length::get_template_length,
 };
+use crate::io::reader::FieldSelector;
 
 /// An error when a raw BAM record fails to parse.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -100,52 +104,159 @@ pub(crate) fn decode<B>(
     header: &sam::Header,
     record: &mut RecordBuf,
 ) -> Result<(), DecodeError>
+where
+    B: Buf,
+{
+    decode_with_fields(src, header, record, FieldSelector::all())
+}
+
+// Decodes only the selected fields. Fields that are not selected are set to their default values.
+pub(crate) fn decode_with_fields<B>(
+    src: &mut B,
+    header: &sam::Header,
+    record: &mut RecordBuf,
+    fields: FieldSelector,
+) -> Result<(), DecodeError>
 where
     B: Buf,
 {
     let n_ref = header.reference_sequences().len();
 
-    *record.reference_sequence_id_mut() =
+    let reference_sequence_id =
         get_reference_sequence_id(src, n_ref).map_err(DecodeError::InvalidReferenceSequenceId)?;
 
-    *record.alignment_start_mut() =
-        get_position(src).map_err(DecodeError::InvalidAlignmentStart)?;
+    let alignment_start = get_position(src).map_err(DecodeError::InvalidAlignmentStart)?;
 
     let l_read_name = name::get_length(src).map_err(DecodeError::InvalidName)?;
 
-    *record.mapping_quality_mut() =
-        get_mapping_quality(src).map_err(DecodeError::InvalidMappingQuality)?;
+    let mapping_quality = get_mapping_quality(src).map_err(DecodeError::InvalidMappingQuality)?;
 
     // Discard bin.
     src.advance(mem::size_of::<u16>());
 
     let n_cigar_op = cigar::get_op_count(src).map_err(DecodeError::InvalidCigar)?;
 
-    *record.flags_mut() = get_flags(src).map_err(DecodeError::InvalidFlags)?;
+    let flags = get_flags(src).map_err(DecodeError::InvalidFlags)?;
 
     let l_seq = sequence::get_length(src).map_err(DecodeError::InvalidSequence)?;
 
-    *record.mate_reference_sequence_id_mut() = get_reference_sequence_id(src, n_ref)
+    let mate_reference_sequence_id = get_reference_sequence_id(src, n_ref)
         .map_err(DecodeError::InvalidMateReferenceSequenceId)?;
 
-    *record.mate_alignment_start_mut() =
-        get_position(src).map_err(DecodeError::InvalidMateAlignmentStart)?;
+    let mate_alignment_start = get_position(src).map_err(DecodeError::InvalidMateAlignmentStart)?;
+
+    let template_length = get_template_length(src).map_err(DecodeError::InvalidTemplateLength)?;
+
+    if fields.contains(FieldSelector::POSITION) {
+        *record.reference_sequence_id_mut() = reference_sequence_id;
+        *record.alignment_start_mut() = alignment_start;
+    } else {
+        *record.reference_sequence_id_mut() = None;
+        *record.alignment_start_mut() = None;
+    }
+
+    *record.mapping_quality_mut() = if fields.contains(FieldSelector::MAPPING_QUALITY) {
+        mapping_quality
+    } else {
+        None
+    };
+
+    *record.flags_mut() = if fields.contains(FieldSelector::FLAGS) {
+        flags
+    } else {
+        Flags::UNMAPPED
+    };
+
+    if fields.contains(FieldSelector::MATE) {
+        *record.mate_reference_sequence_id_mut() = mate_reference_sequence_id;
+        *record.mate_alignment_start_mut() = mate_alignment_start;
+        *record.template_length_mut() = template_length;
+    } else {
+        *record.mate_reference_sequence_id_mut() = None;
+        *record.mate_alignment_start_mut() = None;
+        *record.template_length_mut() = 0;
+    }
+
+    if fields.contains(FieldSelector::NAME) {
+        get_name(src, record.name_mut(), l_read_name).map_err(DecodeError::InvalidName)?;
+    } else {
+        skip(
+            src,
+            usize::from(l_read_name),
+            DecodeError::InvalidName(name::DecodeError::UnexpectedEof),
+        )?;
+        *record.name_mut() = None;
+    }
+
+    if fields.contains(FieldSelector::CIGAR) {
+        get_cigar(src, record.cigar_mut(), n_cigar_op).map_err(DecodeError::InvalidCigar)?;
+    } else {
+        skip(
+            src,
+            mem::size_of::<u32>() * n_cigar_op,
+            DecodeError::InvalidCigar(cigar::DecodeError::UnexpectedEof),
+        )?;
+        record.cigar_mut().as_mut().clear();
+    }
 
-    *record.template_length_mut() =
-        get_template_length(src).map_err(DecodeError::InvalidTemplateLength)?;
+    if fields.contains(FieldSelector::SEQUENCE) {
+        get_sequence(src, record.sequence_mut(), l_seq).map_err(DecodeError::InvalidSequence)?;
+    } else {
+        skip(
+            src,
+            (l_seq + 1) / 2,
+            DecodeError::InvalidSequence(sequence::DecodeError::UnexpectedEof),
+        )?;
+        record.sequence_mut().as_mut().clear();
+    }
 
-    get_name(src, record.name_mut(), l_read_name).map_err(DecodeError::InvalidName)?;
-    get_cigar(src, record.cigar_mut(), n_cigar_op).map_err(DecodeError::InvalidCigar)?;
-    get_sequence(src, record.sequence_mut(), l_seq).map_err(DecodeError::InvalidSequence)?;
-    get_quality_scores(src, record.quality_scores_mut(), l_seq)
-        .map_err(DecodeError::InvalidQualityScores)?;
-    get_data(src, record.data_mut()).map_err(DecodeError::InvalidData)?;
+    if fields.contains(FieldSelector::QUALITY_SCORES) {
+        get_quality_scores(src, record.quality_scores_mut(), l_seq)
+            .map_err(DecodeError::InvalidQualityScores)?;
+    } else {
+        skip(
+            src,
+            l_seq,
+            DecodeError::InvalidQualityScores(quality_scores::DecodeError::UnexpectedEof),
+        )?;
+        record.quality_scores_mut().as_mut().clear();
+    }
 
-    cigar::resolve(record).map_err(DecodeError::InvalidCigar)?;
+    // The data is also decoded when the CIGAR is selected and stored in the data (`CG`).
+    let is_data_selected = fields.contains(FieldSelector::DATA);
+
+    if is_data_selected
+        || (fields.contains(FieldSelector::CIGAR) && cigar::is_placeholder(record.cigar(), l_seq))
+    {
+        get_data(src, record.data_mut()).map_err(DecodeError::InvalidData)?;
+    } else {
+        src.advance(src.remaining());
+        record.data_mut().clear();
+    }
+
+    if fields.contains(FieldSelector::CIGAR) {
+        cigar::resolve(record, l_seq).map_err(DecodeError::InvalidCigar)?;
+    }
+
+    if !is_data_selected {
+        record.data_mut().clear();
+    }
 
     Ok(())
 }
 
+fn skip<B>(src: &mut B, len: usize, e: DecodeError) -> Result<(), DecodeError>
+where
+    B: Buf,
+{
+    if src.remaining() < len {
+        Err(e)
+    } else {
+        src.advance(len);
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -167,4 +278,69 @@ mod tests {
             Err(DecodeError::InvalidName(_))
         ));
     }
+
+    #[test]
+    fn test_decode_with_fields() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use noodles_core::Position;
+        use sam::{
+            alignment::{
+                record::{
+                    cigar::{op::Kind, Op},
+                    data::field::Tag,
+                    MappingQuality,
+                },
+                record_buf::{data::field::Value, QualityScores, Sequence},
+            },
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let record = RecordBuf::builder()
+            .set_name("r0")
+            .set_flags(Flags::SEGMENTED | Flags::FIRST_SEGMENT)
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(2)?)
+            .set_mapping_quality(MappingQuality::try_from(13)?)
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .set_mate_reference_sequence_id(0)
+            .set_mate_alignment_start(Position::try_from(5)?)
+            .set_template_length(7)
+            .set_sequence(Sequence::from(b"ACGT"))
+            .set_quality_scores(QualityScores::from(vec![45, 35, 43, 50]))
+            .set_data(
+                [(Tag::ALIGNMENT_HIT_COUNT, Value::from(1))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+
+        let mut buf = Vec::new();
+        crate::record::codec::encode(&mut buf, &header, &record)?;
+
+        let mut actual = RecordBuf::default();
+        decode_with_fields(&mut &buf[..], &header, &mut actual, FieldSelector::all())?;
+        assert_eq!(actual, record);
+
+        let fields = FieldSelector::FLAGS | FieldSelector::POSITION | FieldSelector::CIGAR;
+        decode_with_fields(&mut &buf[..], &header, &mut actual, fields)?;
+
+        let expected = RecordBuf::builder()
+            .set_flags(record.flags())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(2)?)
+            .set_cigar(record.cigar().clone())
+            .build();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
@@ -73,29 +73,34 @@ where
     Ok(())
 }
 
+// Returns whether the CIGAR is a placeholder for a CIGAR stored in the data (`CG`), i.e., `kSmN`,
+// where `k` is the read length.
+pub(super) fn is_placeholder(cigar: &Cigar, l_seq: usize) -> bool {
+    matches!(
+        cigar.as_ref(),
+        [op_0, op_1] if *op_0 == Op::new(Kind::SoftClip, l_seq) && op_1.kind() == Kind::Skip
+    )
+}
+
 // § 4.2.2 "`N_CIGAR_OP` field" (2022-08-22)
-pub(super) fn resolve(record: &mut RecordBuf) -> Result<(), DecodeError> {
+pub(super) fn resolve(record: &mut RecordBuf, l_seq: usize) -> Result<(), DecodeError> {
     use sam::alignment::{
         record::data::field::Tag,
         record_buf::data::field::{value::Array, Value},
     };
 
-    if let [op_0, op_1] = record.cigar().as_ref() {
-        let k = record.sequence().len();
-
-        if *op_0 == Op::new(Kind::SoftClip, k) && op_1.kind() == Kind::Skip {
-            if let Some((_, value)) = record.data_mut().remove(&Tag::CIGAR) {
-                let Value::Array(Array::UInt32(values)) = value else {
-                    return Err(DecodeError::InvalidDataType);
-                };
+    if is_placeholder(record.cigar(), l_seq) {
+        if let Some((_, value)) = record.data_mut().remove(&Tag::CIGAR) {
+            let Value::Array(Array::UInt32(values)) = value else {
+                return Err(DecodeError::InvalidDataType);
+            };
 
-                let cigar = record.cigar_mut().as_mut();
-                cigar.clear();
+            let cigar = record.cigar_mut().as_mut();
+            cigar.clear();
 
-                for n in values {
-                    let op = decode_op(n).map_err(DecodeError::InvalidOp)?;
-                    cigar.push(op);
-                }
+            for n in values {
+                let op = decode_op(n).map_err(DecodeError::InvalidOp)?;
+                cigar.push(op);
             }
         }
     }
@@ -178,7 +183,7 @@ mod tests {
             )
             .build();
 
-        resolve(&mut record)?;
+        resolve(&mut record, 4)?;
 
         let expected = [Op::new(Kind::Match, 4)].into_iter().collect();
 
@@ -53,7 +53,8 @@ impl fmt::Display for DecodeError {
     }
 }
 
-pub(crate) fn get_field<B>(src: &mut B) -> Result<(Tag, Value), DecodeError>
+/// Decodes a raw BAM record data field.
+pub fn get_field<B>(src: &mut B) -> Result<(Tag, Value), DecodeError>
 where
     B: Buf,
 {
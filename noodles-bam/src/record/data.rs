@@ -12,16 +12,36 @@ use noodles_sam::{
 use self::field::decode_field;
 
 /// BAM record data.
-pub struct Data<'a>(&'a [u8]);
+///
+/// If the record CIGAR overflowed into the `CG` data field, this field is hidden, as it is
+/// exposed as the record CIGAR instead. The raw data (`AsRef<[u8]>`) is unchanged.
+pub struct Data<'a> {
+    src: &'a [u8],
+    is_cigar_hidden: bool,
+}
 
 impl<'a> Data<'a> {
     pub(super) fn new(src: &'a [u8]) -> Self {
-        Self(src)
+        Self {
+            src,
+            is_cigar_hidden: false,
+        }
+    }
+
+    pub(super) fn with_hidden_cigar(src: &'a [u8]) -> Self {
+        Self {
+            src,
+            is_cigar_hidden: true,
+        }
     }
 
     /// Returns whether there are any fields.
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        if self.is_cigar_hidden {
+            self.iter().next().is_none()
+        } else {
+            self.src.is_empty()
+        }
     }
 
     /// Returns the value of the given tag.
@@ -45,7 +65,8 @@ impl<'a> Data<'a> {
 
     /// Returns an iterator over all tag-value pairs.
     pub fn iter(&self) -> impl Iterator<Item = io::Result<(Tag, Value<'_>)>> + '_ {
-        let mut src = self.0;
+        let mut src = self.src;
+        let is_cigar_hidden = self.is_cigar_hidden;
 
         iter::from_fn(move || {
             if src.is_empty() {
@@ -54,6 +75,9 @@ impl<'a> Data<'a> {
                 Some(decode_field(&mut src))
             }
         })
+        .filter(move |result| {
+            !(is_cigar_hidden && matches!(result, Ok((tag, _)) if *tag == Tag::CIGAR))
+        })
     }
 }
 
@@ -86,7 +110,7 @@ impl<'a> sam::alignment::record::Data for Data<'a> {
 
 impl<'a> AsRef<[u8]> for Data<'a> {
     fn as_ref(&self) -> &[u8] {
-        self.0
+        self.src
     }
 }
 
@@ -96,11 +120,15 @@ impl<'a> TryFrom<Data<'a>> for sam::alignment::record_buf::Data {
     fn try_from(bam_data: Data<'a>) -> Result<Self, Self::Error> {
         use crate::record::codec::decoder::get_data;
 
-        let mut src = bam_data.0;
+        let mut src = bam_data.src;
         let mut sam_data = Self::default();
         get_data(&mut src, &mut sam_data)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
+        if bam_data.is_cigar_hidden {
+            sam_data.remove(&Tag::CIGAR);
+        }
+
         Ok(sam_data)
     }
 }
@@ -168,4 +196,28 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_iter_with_hidden_cigar() -> io::Result<()> {
+        let src = [
+            b'C', b'G', b'B', b'I', 0x01, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00,
+            0x00, // CG:B:I,64
+            b'N', b'H', b'C', 0x01, // NH:C:1
+        ];
+
+        let data = Data::with_hidden_cigar(&src);
+        let actual: Vec<_> = data.iter().collect::<io::Result<_>>()?;
+        assert_eq!(actual.len(), 1);
+        assert_eq!(actual[0].0, Tag::ALIGNMENT_HIT_COUNT);
+        assert!(data.get(&Tag::CIGAR).is_none());
+        assert_eq!(data.as_ref(), &src);
+
+        let data = Data::with_hidden_cigar(&src[..12]);
+        assert!(data.is_empty());
+
+        let data = Data::new(&src);
+        assert!(data.get(&Tag::CIGAR).is_some());
+
+        Ok(())
+    }
 }
@@ -72,6 +72,18 @@ impl Fields {
     }
 
     pub(super) fn cigar(&self) -> Cigar<'_> {
+        if let Some(buf) = self.overflowing_cigar() {
+            return Cigar::new(buf);
+        }
+
+        let src = &self.buf[self.bounds.cigar_range()];
+        Cigar::new(src)
+    }
+
+    // § 4.2.2 "`N_CIGAR_OP` field" (2022-08-22): If the CIGAR has more than 65535 ops, the CIGAR
+    // field holds the placeholder `<k>S<m>N`, where `<k>` is the read length, and the real CIGAR is
+    // stored in the `CG` data field.
+    fn overflowing_cigar(&self) -> Option<&[u8]> {
         use super::data::get_raw_cigar;
 
         const SKIP: u8 = 3;
@@ -96,12 +108,12 @@ impl Fields {
                 let mut data_src = &self.buf[self.bounds.data_range()];
 
                 if let Ok(Some(buf)) = get_raw_cigar(&mut data_src) {
-                    return Cigar::new(buf);
+                    return Some(buf);
                 }
             }
         }
 
-        Cigar::new(src)
+        None
     }
 
     pub(super) fn sequence(&self) -> Sequence<'_> {
@@ -118,7 +130,12 @@ impl Fields {
 
     pub(super) fn data(&self) -> Data<'_> {
         let src = &self.buf[self.bounds.data_range()];
-        Data::new(src)
+
+        if self.overflowing_cigar().is_some() {
+            Data::with_hidden_cigar(src)
+        } else {
+            Data::new(src)
+        }
     }
 
     pub(crate) fn index(&mut self) -> io::Result<()> {
@@ -1,15 +1,16 @@
 mod header;
 mod query;
 mod record;
+mod record_buf;
 
 use futures::{stream, Stream};
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
 use noodles_csi::BinningIndex;
-use noodles_vcf as vcf;
+use noodles_vcf::{self as vcf, variant::RecordBuf};
 use tokio::io::{self, AsyncRead, AsyncReadExt, AsyncSeek};
 
-use self::{header::read_header, query::query, record::read_record};
+use self::{header::read_header, query::query, record::read_record, record_buf::read_record_buf};
 use crate::Record;
 
 /// An async BCF reader.
@@ -38,6 +39,7 @@ use crate::Record;
 /// ```
 pub struct Reader<R> {
     inner: R,
+    buf: Vec<u8>,
 }
 
 impl<R> Reader<R>
@@ -113,6 +115,41 @@ where
         read_header(&mut self.inner).await
     }
 
+    /// Reads a single record.
+    ///
+    /// The stream is expected to be directly after the header or at the start of another record.
+    ///
+    /// It is more ergonomic to read records using a stream (see [`Self::record_bufs`]), but using
+    /// this method directly allows the reuse of a single [`RecordBuf`].
+    ///
+    /// If successful, the record size is returned. If a record size of 0 is returned, the stream
+    /// reached EOF.
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf as vcf;
+    /// use tokio::fs::File;
+    ///
+    /// let mut reader = File::open("sample.bcf").await.map(bcf::r#async::io::Reader::new)?;
+    /// let header = reader.read_header().await?;
+    ///
+    /// let mut record = vcf::variant::RecordBuf::default();
+    /// reader.read_record_buf(&header, &mut record).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_record_buf(
+        &mut self,
+        header: &vcf::Header,
+        record: &mut RecordBuf,
+    ) -> io::Result<usize> {
+        read_record_buf(&mut self.inner, header, &mut self.buf, record).await
+    }
+
     /// Reads a single record without decoding (most of) its feilds.
     ///
     /// The stream is expected to be directly after the header or at the start of another record.
@@ -184,6 +221,53 @@ where
             },
         ))
     }
+
+    /// Returns an (async) stream over records starting from the current (input) stream
+    /// position.
+    ///
+    /// The (input) stream is expected to be directly after the header or at the start of another
+    /// record.
+    ///
+    /// Unlike [`Self::records`], each record is decoded as a [`RecordBuf`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use futures::TryStreamExt;
+    /// use noodles_bcf as bcf;
+    /// use tokio::fs::File;
+    ///
+    /// let mut reader = File::open("sample.bcf").await.map(bcf::r#async::io::Reader::new)?;
+    /// let header = reader.read_header().await?;
+    ///
+    /// let mut records = reader.record_bufs(&header);
+    ///
+    /// while let Some(record) = records.try_next().await? {
+    ///     // ...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn record_bufs<'r, 'h: 'r>(
+        &'r mut self,
+        header: &'h vcf::Header,
+    ) -> impl Stream<Item = io::Result<RecordBuf>> + 'r {
+        Box::pin(stream::try_unfold(self, move |reader| async move {
+            let mut record = RecordBuf::default();
+
+            reader
+                .read_record_buf(header, &mut record)
+                .await
+                .map(|n| match n {
+                    0 => None,
+                    _ => Some((record, reader)),
+                })
+        }))
+    }
 }
 
 impl<R> Reader<bgzf::AsyncReader<R>>
@@ -259,7 +343,10 @@ where
 
 impl<R> From<R> for Reader<R> {
     fn from(inner: R) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            buf: Vec::new(),
+        }
     }
 }
 
@@ -28,7 +28,7 @@ where
     Ok(l_shared + l_indiv)
 }
 
-async fn read_site_length<R>(reader: &mut R) -> io::Result<usize>
+pub(super) async fn read_site_length<R>(reader: &mut R) -> io::Result<usize>
 where
     R: AsyncRead + Unpin,
 {
@@ -63,7 +63,7 @@ where
     }
 }
 
-async fn read_samples_length<R>(reader: &mut R) -> io::Result<usize>
+pub(super) async fn read_samples_length<R>(reader: &mut R) -> io::Result<usize>
 where
     R: AsyncRead + Unpin,
 {
@@ -0,0 +1,82 @@
+use noodles_vcf::{self as vcf, variant::RecordBuf};
+use tokio::io::{self, AsyncRead, AsyncReadExt};
+
+use super::record::{read_samples_length, read_site_length};
+
+pub(super) async fn read_record_buf<R>(
+    reader: &mut R,
+    header: &vcf::Header,
+    buf: &mut Vec<u8>,
+    record: &mut RecordBuf,
+) -> io::Result<usize>
+where
+    R: AsyncRead + Unpin,
+{
+    use crate::record::codec::decoder::{read_samples, read_site};
+
+    let l_shared = match read_site_length(reader).await? {
+        0 => return Ok(0),
+        n => n,
+    };
+
+    let l_indiv = read_samples_length(reader).await?;
+
+    buf.resize(l_shared, 0);
+    reader.read_exact(buf).await?;
+    let mut src = &buf[..];
+    let (n_fmt, n_sample) = read_site(&mut src, header, record)?;
+
+    buf.resize(l_indiv, 0);
+    reader.read_exact(buf).await?;
+    let mut src = &buf[..];
+
+    *record.samples_mut() = read_samples(&mut src, header, n_sample, n_fmt)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    Ok(l_shared + l_indiv)
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+    use noodles_vcf::header::{
+        record::value::{map::Contig, Map},
+        StringMaps,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_read_record_buf() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::io::reader::record::tests::{DATA, RAW_HEADER};
+
+        let mut header: vcf::Header = RAW_HEADER.parse()?;
+
+        for name in ["sq0", "sq1"] {
+            header
+                .contigs_mut()
+                .insert(name.parse()?, Map::<Contig>::new());
+        }
+
+        *header.string_maps_mut() = StringMaps::try_from(&header)?;
+
+        let mut reader = &DATA[..];
+        let mut buf = Vec::new();
+        let mut record = RecordBuf::default();
+
+        let n = read_record_buf(&mut reader, &header, &mut buf, &mut record).await?;
+        assert_eq!(n, DATA.len() - 8);
+
+        assert_eq!(record.reference_sequence_name(), "sq1");
+        assert_eq!(record.variant_start(), Some(Position::try_from(101)?));
+        assert_eq!(record.samples().values().count(), 3);
+
+        let mut record = RecordBuf::default();
+        assert_eq!(
+            read_record_buf(&mut reader, &header, &mut buf, &mut record).await?,
+            0
+        );
+
+        Ok(())
+    }
+}
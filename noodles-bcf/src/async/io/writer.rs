@@ -60,6 +60,28 @@ where
         self.inner
     }
 
+    /// Shuts down the output stream.
+    ///
+    /// For BGZF-compressed output, this flushes any buffered data and writes the final EOF
+    /// block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_bcf as bcf;
+    /// let mut writer = bcf::r#async::io::Writer::new(Vec::new());
+    /// writer.shutdown().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn shutdown(&mut self) -> io::Result<()> {
+        self.inner.shutdown().await
+    }
+
     /// Writes a VCF header.
     ///
     /// # Examples
@@ -219,4 +241,56 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        use futures::TryStreamExt;
+        use noodles_core::Position;
+        use noodles_vcf::{
+            header::record::value::{map::Contig, Map},
+            variant::RecordBuf,
+        };
+
+        use crate::r#async::io::Reader;
+
+        let header = vcf::Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .build();
+
+        let records = [
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::MIN)
+                .set_reference_bases("A")
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::try_from(8)?)
+                .set_reference_bases("C")
+                .build(),
+        ];
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header).await?;
+
+        for record in &records {
+            writer.write_variant_record(&header, record).await?;
+        }
+
+        writer.shutdown().await?;
+
+        let data = writer.into_inner().into_inner();
+
+        let mut reader = Reader::new(&data[..]);
+        let actual_header = reader.read_header().await?;
+        let actual: Vec<_> = reader.record_bufs(&actual_header).try_collect().await?;
+        assert_eq!(actual, records);
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_header().await?;
+        let actual: Vec<_> = reader.records().try_collect().await?;
+        assert_eq!(actual.len(), records.len());
+
+        Ok(())
+    }
 }
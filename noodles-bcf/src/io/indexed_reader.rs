@@ -12,7 +12,7 @@ use noodles_csi::BinningIndex;
 use noodles_vcf::{self as vcf, variant::RecordBuf};
 
 use super::{
-    reader::{Query, RecordBufs},
+    reader::{BatchQuery, Query, RecordBufs},
     Reader,
 };
 use crate::Record;
@@ -105,4 +105,179 @@ where
     ) -> io::Result<Query<'r, 'h, R>> {
         self.inner.query(header, &self.index, region)
     }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// See [`Reader::query_batch`].
+    pub fn query_batch<'r, 'h>(
+        &'r mut self,
+        header: &'h vcf::Header,
+        regions: &[Region],
+    ) -> io::Result<BatchQuery<'r, 'h, R>> {
+        self.inner.query_batch(header, &self.index, regions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use noodles_core::Position;
+    use noodles_csi::{
+        self as csi,
+        binning_index::{
+            index::reference_sequence::{bin::Chunk, index::BinnedIndex},
+            Indexer,
+        },
+    };
+    use noodles_vcf::{
+        header::record::value::{map::Contig, Map},
+        variant::{io::Write, record::ReferenceBases as _},
+    };
+
+    use super::*;
+
+    fn build_header() -> vcf::Header {
+        vcf::Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .add_contig("sq1", Map::<Contig>::new())
+            .build()
+    }
+
+    fn build_data(header: &vcf::Header) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+        let mut writer = crate::io::Writer::new(Vec::new());
+        writer.write_header(header)?;
+
+        for (reference_sequence_name, position, reference_bases) in [
+            ("sq0", 8, "A"),
+            ("sq0", 100, "A"),
+            ("sq0", 150, "ACGT"),
+            ("sq0", 40000, "A"),
+            ("sq1", 120, "A"),
+        ] {
+            let record = RecordBuf::builder()
+                .set_reference_sequence_name(reference_sequence_name)
+                .set_variant_start(Position::try_from(position)?)
+                .set_reference_bases(reference_bases)
+                .build();
+
+            writer.write_variant_record(header, &record)?;
+        }
+
+        let data = writer.into_inner().finish()?;
+
+        Ok(data)
+    }
+
+    fn build_index(data: &[u8]) -> io::Result<csi::Index> {
+        let mut reader = Reader::new(data);
+        reader.read_header()?;
+
+        let mut indexer = Indexer::<BinnedIndex>::default();
+        let mut record = Record::default();
+        let mut start_position = reader.get_ref().virtual_position();
+
+        while reader.read_record(&mut record)? != 0 {
+            let end_position = reader.get_ref().virtual_position();
+            let chunk = Chunk::new(start_position, end_position);
+
+            let reference_sequence_id = record.reference_sequence_id()?;
+            let start = record
+                .variant_start()
+                .transpose()?
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing position"))?;
+            let end = start
+                .checked_add(record.reference_bases().len() - 1)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid end"))?;
+
+            indexer.add_record(Some((reference_sequence_id, start, end, true)), chunk)?;
+
+            start_position = end_position;
+        }
+
+        Ok(indexer.build(2))
+    }
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+        let data = build_data(&header)?;
+        let index = build_index(&data)?;
+
+        for (region, expected) in [
+            ("sq0:100-200", &[100, 150][..]),
+            ("sq0:152-153", &[150][..]),
+            ("sq0", &[8, 100, 150, 40000][..]),
+            ("sq1:1-100", &[][..]),
+            ("sq1", &[120][..]),
+        ] {
+            let mut reader = Builder::default()
+                .set_index(index.clone())
+                .build_from_reader(Cursor::new(&data))?;
+
+            let header = reader.read_header()?;
+            let region = region.parse()?;
+
+            let actual = reader
+                .query(&header, &region)?
+                .map(|result| {
+                    result.and_then(|record| {
+                        record
+                            .variant_start()
+                            .transpose()
+                            .map(|position| position.map(usize::from).unwrap_or_default())
+                    })
+                })
+                .collect::<io::Result<Vec<_>>>()?;
+
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_batch() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+        let data = build_data(&header)?;
+        let index = build_index(&data)?;
+
+        let mut reader = Builder::default()
+            .set_index(index)
+            .build_from_reader(Cursor::new(&data))?;
+
+        let header = reader.read_header()?;
+
+        let regions = [
+            "sq0:100-200".parse()?,
+            "sq1".parse()?,
+            "sq0:152-153".parse()?,
+            "sq0:1-10".parse()?,
+        ];
+
+        let actual: Vec<(Vec<usize>, usize)> = reader
+            .query_batch(&header, &regions)?
+            .map(|result| {
+                let (indices, record) = result?;
+                let position = record
+                    .variant_start()
+                    .transpose()?
+                    .map(usize::from)
+                    .unwrap_or_default();
+                Ok((indices, position))
+            })
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(
+            actual,
+            [
+                (vec![3], 8),
+                (vec![0], 100),
+                (vec![0, 2], 150),
+                (vec![1], 120),
+            ]
+        );
+
+        Ok(())
+    }
 }
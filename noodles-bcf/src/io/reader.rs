@@ -1,13 +1,23 @@
 //! BCF reader.
 
+mod batch_query;
 mod builder;
 mod header;
+mod header_conflict;
+mod lending_records;
 pub(crate) mod query;
 pub(crate) mod record;
 pub(crate) mod record_buf;
 mod record_bufs;
-
-pub use self::{builder::Builder, query::Query, record_bufs::RecordBufs};
+mod record_error;
+mod records_lossy;
+mod validation_level;
+
+pub use self::{
+    batch_query::BatchQuery, builder::Builder, header_conflict::HeaderConflict,
+    lending_records::LendingRecords, query::Query, record_bufs::RecordBufs,
+    record_error::RecordError, records_lossy::RecordsLossy, validation_level::ValidationLevel,
+};
 
 use std::{
     io::{self, BufRead, Read},
@@ -17,7 +27,7 @@ use std::{
 use byteorder::ReadBytesExt;
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
-use noodles_csi::BinningIndex;
+use noodles_csi::{self as csi, BinningIndex};
 use noodles_vcf::{self as vcf, header::string_maps::ContigStringMap, variant::RecordBuf};
 
 use self::{header::read_header, record::read_record, record_buf::read_record_buf};
@@ -29,6 +39,8 @@ use crate::Record;
 pub struct Reader<R> {
     inner: R,
     buf: Vec<u8>,
+    validation_level: ValidationLevel,
+    header_conflicts: Vec<HeaderConflict>,
 }
 
 impl<R> Reader<R>
@@ -96,7 +108,27 @@ where
     pub fn read_header(&mut self) -> io::Result<vcf::Header> {
         read_magic(&mut self.inner)?;
         read_format_version(&mut self.inner)?;
-        read_header(&mut self.inner)
+
+        let (header, header_conflicts) = read_header(&mut self.inner, self.validation_level)?;
+        self.header_conflicts = header_conflicts;
+
+        Ok(header)
+    }
+
+    /// Returns the header records that were discarded when reading the header.
+    ///
+    /// This is only populated when the reader is built with [`ValidationLevel::Lenient`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let data = [];
+    /// let reader = bcf::io::Reader::from(&data[..]);
+    /// assert!(reader.header_conflicts().is_empty());
+    /// ```
+    pub fn header_conflicts(&self) -> &[HeaderConflict] {
+        &self.header_conflicts
     }
 
     /// Reads a single record.
@@ -209,6 +241,33 @@ where
             Err(e) => Some(Err(e)),
         })
     }
+
+    /// Returns a lending iterator over records.
+    ///
+    /// This reuses a single record buffer, avoiding an allocation per record. See
+    /// [`LendingRecords`].
+    ///
+    /// The stream is expected to be directly after the header or at the start of another record.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bcf as bcf;
+    ///
+    /// let mut reader = File::open("sample.bcf").map(bcf::io::Reader::new)?;
+    /// reader.read_header()?;
+    ///
+    /// let mut records = reader.lending_records();
+    ///
+    /// while let Some(record) = records.next_record().transpose()? {
+    ///     // ...
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn lending_records(&mut self) -> LendingRecords<'_, R> {
+        LendingRecords::new(self)
+    }
 }
 
 impl<R> Reader<bgzf::Reader<R>>
@@ -229,6 +288,43 @@ where
     }
 }
 
+impl<R> Reader<R>
+where
+    R: bgzf::io::Read,
+{
+    /// Returns an iterator over records that skips records that fail to decode.
+    ///
+    /// Each error is a [`RecordError`], which includes the index and virtual position of the
+    /// record and, if known, its reference sequence name. Records that fail to decode are skipped,
+    /// and iteration continues with the next record. If a record cannot be read, e.g., the stream
+    /// is truncated, the error is returned, and iteration stops.
+    ///
+    /// The stream is expected to be directly after the header or at the start of another record.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bcf as bcf;
+    ///
+    /// let mut reader = File::open("sample.bcf").map(bcf::io::Reader::new)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// for result in reader.records_lossy(&header) {
+    ///     match result {
+    ///         Ok(record) => {
+    ///             // ...
+    ///         }
+    ///         Err(e) => eprintln!("{e}"),
+    ///     }
+    /// }
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn records_lossy<'r, 'h>(&'r mut self, header: &'h vcf::Header) -> RecordsLossy<'r, 'h, R> {
+        RecordsLossy::new(self, header)
+    }
+}
+
 impl<R> Reader<R>
 where
     R: bgzf::io::BufRead + bgzf::io::Seek,
@@ -276,6 +372,57 @@ where
             region.interval(),
         ))
     }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// Unlike calling [`Self::query`] for each region, the chunks of all regions are sorted and
+    /// merged, and the file is read in a single forward sweep. This is useful for many small
+    /// regions, e.g., an exon panel, where regions can share blocks.
+    ///
+    /// Each record is returned once, along with the (sorted) indices of the regions it
+    /// intersects. Records are in file order, not region order.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bcf as bcf;
+    /// use noodles_csi as csi;
+    ///
+    /// let mut reader = File::open("sample.bcf").map(bcf::io::Reader::new)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let index = csi::read("sample.bcf.csi")?;
+    /// let regions = ["sq0:8-13".parse()?, "sq0:21-34".parse()?];
+    /// let query = reader.query_batch(&header, &index, &regions)?;
+    ///
+    /// for result in query {
+    ///     let (region_indices, record) = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_batch<'r, 'h, I>(
+        &'r mut self,
+        header: &'h vcf::Header,
+        index: &I,
+        regions: &[Region],
+    ) -> io::Result<BatchQuery<'r, 'h, R>>
+    where
+        I: BinningIndex,
+    {
+        let queries = regions
+            .iter()
+            .map(|region| {
+                resolve_region(header.string_maps().contigs(), region)
+                    .map(|id| (id, region.interval()))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let batch_query = csi::binning_index::BatchQuery::new(index, queries)?;
+
+        Ok(BatchQuery::new(&mut self.inner, header, batch_query))
+    }
 }
 
 impl<R> From<R> for Reader<R> {
@@ -283,6 +430,8 @@ impl<R> From<R> for Reader<R> {
         Self {
             inner,
             buf: Vec::new(),
+            validation_level: ValidationLevel::default(),
+            header_conflicts: Vec::new(),
         }
     }
 }
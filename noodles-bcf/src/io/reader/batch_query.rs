@@ -0,0 +1,77 @@
+use std::io;
+
+use noodles_bgzf as bgzf;
+use noodles_csi as csi;
+use noodles_vcf::{self as vcf, variant::Record as _};
+
+use super::Reader;
+use crate::Record;
+
+/// An iterator over records of a BCF reader that intersect any of a batch of regions.
+///
+/// This is created by calling [`Reader::query_batch`].
+pub struct BatchQuery<'r, 'h, R> {
+    reader: Reader<csi::io::Query<'r, R>>,
+    header: &'h vcf::Header,
+    batch_query: csi::binning_index::BatchQuery,
+    record: Record,
+}
+
+impl<'r, 'h, R> BatchQuery<'r, 'h, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    pub(super) fn new(
+        reader: &'r mut R,
+        header: &'h vcf::Header,
+        batch_query: csi::binning_index::BatchQuery,
+    ) -> Self {
+        let chunks = batch_query.chunks().to_vec();
+
+        Self {
+            reader: Reader::from(csi::io::Query::new(reader, chunks)),
+            header,
+            batch_query,
+            record: Record::default(),
+        }
+    }
+}
+
+impl<'r, 'h, R> Iterator for BatchQuery<'r, 'h, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    type Item = io::Result<(Vec<usize>, Record)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.reader.read_record(&mut self.record) {
+                Ok(0) => return None,
+                Ok(_) => {}
+                Err(e) => return Some(Err(e)),
+            }
+
+            match region_indices(&self.batch_query, self.header, &self.record) {
+                Ok(indices) if indices.is_empty() => {}
+                Ok(indices) => return Some(Ok((indices, self.record.clone()))),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+fn region_indices(
+    batch_query: &csi::binning_index::BatchQuery,
+    header: &vcf::Header,
+    record: &Record,
+) -> io::Result<Vec<usize>> {
+    let reference_sequence_id = record.reference_sequence_id()?;
+
+    let Some(start) = record.variant_start().transpose()? else {
+        return Ok(Vec::new());
+    };
+
+    let end = record.variant_end(header)?;
+
+    Ok(batch_query.intersecting(reference_sequence_id, start, end))
+}
@@ -6,13 +6,14 @@ use std::{
 
 use noodles_bgzf as bgzf;
 
-use super::Reader;
+use super::{Reader, ValidationLevel};
 use crate::io::CompressionMethod;
 
 /// A BCF reader builder.
 #[derive(Default)]
 pub struct Builder {
     compression_method: Option<CompressionMethod>,
+    validation_level: ValidationLevel,
 }
 
 impl Builder {
@@ -29,6 +30,21 @@ impl Builder {
         self
     }
 
+    /// Sets the validation level.
+    ///
+    /// By default, the validation level is [`ValidationLevel::Strict`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::io::reader::{Builder, ValidationLevel};
+    /// let builder = Builder::default().set_validation_level(ValidationLevel::Lenient);
+    /// ```
+    pub fn set_validation_level(mut self, validation_level: ValidationLevel) -> Self {
+        self.validation_level = validation_level;
+        self
+    }
+
     /// Builds a BCF reader from a path.
     ///
     /// # Examples
@@ -64,6 +80,9 @@ impl Builder {
             Some(CompressionMethod::None) => Box::new(reader),
         };
 
-        Ok(Reader::from(inner))
+        let mut reader = Reader::from(inner);
+        reader.validation_level = self.validation_level;
+
+        Ok(reader)
     }
 }
@@ -1,7 +1,18 @@
-use std::io::{self, BufRead, BufReader, Read, Take};
+use std::{
+    collections::HashMap,
+    io::{self, BufRead, BufReader, Read, Take},
+};
 
 use byteorder::{LittleEndian, ReadBytesExt};
-use noodles_vcf::{self as vcf, header::StringMaps};
+use noodles_vcf::{
+    self as vcf,
+    header::{
+        parser::{parse_record, Entry},
+        FileFormat, Record, StringMaps,
+    },
+};
+
+use super::{HeaderConflict, ValidationLevel};
 
 #[derive(Clone, Copy, Debug)]
 enum State {
@@ -85,33 +96,154 @@ where
     }
 }
 
-pub(super) fn read_header<R>(reader: &mut R) -> io::Result<vcf::Header>
+pub(super) fn read_header<R>(
+    reader: &mut R,
+    validation_level: ValidationLevel,
+) -> io::Result<(vcf::Header, Vec<HeaderConflict>)>
 where
     R: Read,
 {
     let mut parser = vcf::header::Parser::default();
     let mut string_maps = StringMaps::default();
 
+    let mut file_format = FileFormat::default();
+    let mut descriptions = Descriptions::default();
+    let mut conflicts = Vec::new();
+    let mut unindexed_entries = Vec::new();
+
     let mut reader = Reader::new(reader);
     let mut buf = Vec::new();
 
     while read_line(&mut reader, &mut buf)? != 0 {
+        if validation_level == ValidationLevel::Lenient
+            && descriptions.is_duplicate(&buf, file_format, &mut conflicts)?
+        {
+            continue;
+        }
+
         let entry = parser
             .parse_partial(&buf)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
+        if let Entry::FileFormat(f) = entry {
+            file_format = f;
+        }
+
+        if validation_level == ValidationLevel::Lenient {
+            descriptions.insert_entry(&entry);
+
+            if let Some(unindexed_entry) = UnindexedEntry::from_entry(&entry) {
+                unindexed_entries.push(unindexed_entry);
+                continue;
+            }
+        }
+
         string_maps
             .insert_entry(&entry)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
     }
 
+    // Unindexed entries are appended after all indexed entries so that they do not take the
+    // positions of indexed entries that follow them.
+    for entry in unindexed_entries {
+        let (string_map, id) = match entry {
+            UnindexedEntry::Contig(id) => (string_maps.contigs_mut(), id),
+            UnindexedEntry::String(id) => (string_maps.strings_mut(), id),
+        };
+
+        string_map.insert(id);
+    }
+
     let mut header = parser
         .finish()
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
     *header.string_maps_mut() = string_maps;
 
-    Ok(header)
+    Ok((header, conflicts))
+}
+
+enum UnindexedEntry {
+    Contig(String),
+    String(String),
+}
+
+impl UnindexedEntry {
+    fn from_entry(entry: &Entry<'_>) -> Option<Self> {
+        match entry {
+            Entry::Contig(id, contig) if contig.idx().is_none() => Some(Self::Contig((*id).into())),
+            Entry::Filter(id, filter) if filter.idx().is_none() => Some(Self::String((*id).into())),
+            Entry::Format(id, format) if format.idx().is_none() => Some(Self::String((*id).into())),
+            Entry::Info(id, info) if info.idx().is_none() => Some(Self::String((*id).into())),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Descriptions {
+    infos: HashMap<String, String>,
+    filters: HashMap<String, String>,
+}
+
+impl Descriptions {
+    fn insert_entry(&mut self, entry: &Entry<'_>) {
+        match entry {
+            Entry::Info(id, info) => {
+                self.infos.insert((*id).into(), info.description().into());
+            }
+            Entry::Filter(id, filter) => {
+                self.filters
+                    .insert((*id).into(), filter.description().into());
+            }
+            _ => {}
+        }
+    }
+
+    // Checks whether the line is a duplicate `INFO` or `FILTER` record.
+    //
+    // A conflict is recorded if the description of a duplicate record differs from the first
+    // record.
+    fn is_duplicate(
+        &self,
+        src: &[u8],
+        file_format: FileFormat,
+        conflicts: &mut Vec<HeaderConflict>,
+    ) -> io::Result<bool> {
+        const INFO_PREFIX: &[u8] = b"##INFO=";
+        const FILTER_PREFIX: &[u8] = b"##FILTER=";
+
+        if !src.starts_with(INFO_PREFIX) && !src.starts_with(FILTER_PREFIX) {
+            return Ok(false);
+        }
+
+        let record = parse_record(src, file_format)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let conflict = match record {
+            Record::Info(id, info) => match self.infos.get(&id) {
+                Some(description) if description == info.description() => None,
+                Some(_) => Some(HeaderConflict::Info {
+                    id,
+                    description: info.description().into(),
+                }),
+                None => return Ok(false),
+            },
+            Record::Filter(id, filter) => match self.filters.get(&id) {
+                Some(description) if description == filter.description() => None,
+                Some(_) => Some(HeaderConflict::Filter {
+                    id,
+                    description: filter.description().into(),
+                }),
+                None => return Ok(false),
+            },
+            _ => return Ok(false),
+        };
+
+        conflicts.extend(conflict);
+
+        Ok(true)
+    }
 }
 
 fn read_line<R>(reader: &mut R, dst: &mut Vec<u8>) -> io::Result<usize>
@@ -158,13 +290,74 @@ mod tests {
         data.push(NUL);
 
         let mut reader = &data[..];
-        let actual = read_header(&mut reader)?;
+        let (actual, conflicts) = read_header(&mut reader, ValidationLevel::Strict)?;
 
         let expected = vcf::Header::builder()
             .set_file_format(FileFormat::new(4, 3))
             .build();
 
         assert_eq!(actual, expected);
+        assert!(conflicts.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_header_with_lenient_validation_level() -> io::Result<()> {
+        const NUL: u8 = 0x00;
+
+        let raw_header = br#"##fileformat=VCFv4.3
+##FILTER=<ID=PASS,Description="All filters passed",IDX=0>
+##INFO=<ID=DP,Number=1,Type=Integer,Description="Combined depth">
+##INFO=<ID=NS,Number=1,Type=Integer,Description="Number of samples",IDX=1>
+##INFO=<ID=DP,Number=1,Type=Integer,Description="Total depth">
+##FILTER=<ID=q10,Description="Quality below 10",IDX=3>
+##FILTER=<ID=q10,Description="Quality below 10",IDX=3>
+##FILTER=<ID=q10,Description="Quality < 10",IDX=3>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+"#;
+
+        let mut data = u32::try_from(raw_header.len() + 1)
+            .map(u32::to_le_bytes)
+            .map(Vec::from)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        data.extend_from_slice(raw_header);
+        data.push(NUL);
+
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_header(&mut reader, ValidationLevel::Strict),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let mut reader = &data[..];
+        let (header, conflicts) = read_header(&mut reader, ValidationLevel::Lenient)?;
+
+        assert_eq!(header.infos().len(), 2);
+        assert_eq!(header.infos()["DP"].description(), "Combined depth");
+        assert_eq!(header.filters().len(), 2);
+        assert_eq!(header.filters()["q10"].description(), "Quality below 10");
+
+        let string_map = header.string_maps().strings();
+        assert_eq!(string_map.get_index_of("PASS"), Some(0));
+        assert_eq!(string_map.get_index_of("NS"), Some(1));
+        assert!(string_map.get_index(2).is_none());
+        assert_eq!(string_map.get_index_of("q10"), Some(3));
+        assert_eq!(string_map.get_index_of("DP"), Some(4));
+
+        assert_eq!(
+            conflicts,
+            [
+                HeaderConflict::Info {
+                    id: String::from("DP"),
+                    description: String::from("Total depth"),
+                },
+                HeaderConflict::Filter {
+                    id: String::from("q10"),
+                    description: String::from("Quality < 10"),
+                },
+            ]
+        );
 
         Ok(())
     }
@@ -0,0 +1,20 @@
+/// A header record that was discarded when leniently reading a header.
+///
+/// See [`super::ValidationLevel::Lenient`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum HeaderConflict {
+    /// A duplicate `INFO` record with a different description.
+    Info {
+        /// The ID.
+        id: String,
+        /// The description of the discarded record.
+        description: String,
+    },
+    /// A duplicate `FILTER` record with a different description.
+    Filter {
+        /// The ID.
+        id: String,
+        /// The description of the discarded record.
+        description: String,
+    },
+}
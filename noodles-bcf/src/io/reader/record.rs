@@ -8,6 +8,20 @@ use byteorder::{LittleEndian, ReadBytesExt};
 use crate::Record;
 
 pub fn read_record<R>(reader: &mut R, record: &mut Record) -> io::Result<usize>
+where
+    R: Read,
+{
+    let n = read_raw_record(reader, record)?;
+
+    if n > 0 {
+        record.fields_mut().index()?;
+    }
+
+    Ok(n)
+}
+
+// Reads the site and samples buffers of a record without indexing its fields.
+pub(super) fn read_raw_record<R>(reader: &mut R, record: &mut Record) -> io::Result<usize>
 where
     R: Read,
 {
@@ -22,8 +36,6 @@ where
     site_buf.resize(l_shared, 0);
     reader.read_exact(site_buf)?;
 
-    record.fields_mut().index()?;
-
     let samples_buf = record.fields_mut().samples_buf_mut();
     samples_buf.resize(l_indiv, 0);
     reader.read_exact(samples_buf)?;
@@ -316,6 +328,31 @@ pub(crate) mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_record_does_not_decode_samples() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+
+        let mut data = DATA.to_vec();
+        // Corrupt the type of the first series key (formats[GT]).
+        data[59] = 0xff;
+
+        let mut reader = &data[..];
+        let mut record = Record::default();
+        read_record(&mut reader, &mut record)?;
+
+        assert_eq!(
+            record.variant_start().transpose()?,
+            Some(Position::try_from(101)?)
+        );
+        assert_eq!(record.quality_score()?, Some(30.1));
+
+        let samples = record.samples()?;
+        assert_eq!(samples.as_ref(), &data[59..]);
+        assert!(samples.series().next().transpose().is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_record_at_eof() -> io::Result<()> {
         let data = [];
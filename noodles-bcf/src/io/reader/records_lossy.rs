@@ -0,0 +1,143 @@
+use noodles_bgzf as bgzf;
+use noodles_vcf::{self as vcf, variant::RecordBuf};
+
+use super::{record::read_raw_record, Reader, RecordError};
+use crate::Record;
+
+/// An iterator over records of a BCF reader that skips records that fail to decode.
+///
+/// This is created by calling [`Reader::records_lossy`].
+pub struct RecordsLossy<'r, 'h, R> {
+    reader: &'r mut Reader<R>,
+    header: &'h vcf::Header,
+    record: Record,
+    record_index: u64,
+    is_eof: bool,
+}
+
+impl<'r, 'h, R> RecordsLossy<'r, 'h, R> {
+    pub(super) fn new(reader: &'r mut Reader<R>, header: &'h vcf::Header) -> Self {
+        Self {
+            reader,
+            header,
+            record: Record::default(),
+            record_index: 0,
+            is_eof: false,
+        }
+    }
+}
+
+impl<'r, 'h, R> Iterator for RecordsLossy<'r, 'h, R>
+where
+    R: bgzf::io::Read,
+{
+    type Item = Result<RecordBuf, RecordError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_eof {
+            return None;
+        }
+
+        let record_index = self.record_index;
+        let position = self.reader.get_ref().virtual_position();
+
+        match read_raw_record(&mut self.reader.inner, &mut self.record) {
+            Ok(0) => return None,
+            Ok(_) => self.record_index += 1,
+            Err(e) => {
+                // The record boundary is unknown, so the stream cannot be resynchronized.
+                self.is_eof = true;
+                return Some(Err(RecordError::new(record_index, position, None, e)));
+            }
+        }
+
+        let result = self
+            .record
+            .fields_mut()
+            .index()
+            .and_then(|_| RecordBuf::try_from_variant_record(self.header, &self.record));
+
+        match result {
+            Ok(record) => Some(Ok(record)),
+            Err(e) => Some(Err(RecordError::new(
+                record_index,
+                position,
+                reference_sequence_name(self.header, &mut self.record),
+                e,
+            ))),
+        }
+    }
+}
+
+fn reference_sequence_name(header: &vcf::Header, record: &mut Record) -> Option<String> {
+    let buf = record.fields_mut().site_buf_mut();
+    let raw_id = buf.get(..4)?.try_into().ok()?;
+    let id = usize::try_from(i32::from_le_bytes(raw_id)).ok()?;
+
+    header
+        .string_maps()
+        .contigs()
+        .get_index(id)
+        .map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+
+    use noodles_vcf::header::{
+        record::value::{map::Contig, Map},
+        StringMaps,
+    };
+
+    use super::*;
+    use crate::io::reader::record::tests::{DATA, RAW_HEADER};
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let mut header: vcf::Header = RAW_HEADER.parse()?;
+
+        for name in ["sq0", "sq1"] {
+            header
+                .contigs_mut()
+                .insert(name.parse()?, Map::<Contig>::new());
+        }
+
+        *header.string_maps_mut() = StringMaps::try_from(&header)?;
+
+        let mut invalid_data = DATA.to_vec();
+        // Corrupt the type of the first series key (formats[GT]).
+        invalid_data[59] = 0xff;
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(&DATA)?;
+        writer.write_all(&invalid_data)?;
+        writer.write_all(&DATA)?;
+        // truncated record
+        writer.write_all(&DATA[..16])?;
+        let data = writer.finish()?;
+
+        let mut reader = Reader::new(&data[..]);
+        let mut records = reader.records_lossy(&header);
+
+        assert!(matches!(records.next(), Some(Ok(_))));
+
+        let e = records.next().unwrap().unwrap_err();
+        assert_eq!(e.record_index(), 1);
+        assert_eq!(e.position(), bgzf::VirtualPosition::from(101));
+        assert_eq!(e.reference_sequence_name(), Some("sq1"));
+        assert_eq!(e.get_ref().kind(), io::ErrorKind::InvalidData);
+
+        let record = records.next().unwrap()?;
+        assert_eq!(record.reference_sequence_name(), "sq1");
+
+        let e = records.next().unwrap().unwrap_err();
+        assert_eq!(e.record_index(), 3);
+        assert!(e.reference_sequence_name().is_none());
+        assert_eq!(e.get_ref().kind(), io::ErrorKind::UnexpectedEof);
+
+        assert!(records.next().is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+/// A BCF reader validation level.
+///
+/// This controls how strictly the header is checked when reading.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ValidationLevel {
+    /// The header must be a valid VCF header, and its string maps must be consistent.
+    #[default]
+    Strict,
+    /// Common header idiosyncrasies, e.g., those produced by concatenating or merging files, are
+    /// accepted.
+    ///
+    /// Duplicate `INFO` and `FILTER` records keep the first record. Discarded records with a
+    /// different description are recorded as header conflicts ([`super::HeaderConflict`]).
+    ///
+    /// Records without an IDX field are appended to the string maps after all indexed records
+    /// rather than taking the next position, which may otherwise collide with an IDX value of a
+    /// later record.
+    Lenient,
+}
@@ -2,17 +2,31 @@
 
 mod builder;
 pub(crate) mod header;
+mod indexer;
 mod record;
 
-use std::io::{self, Write};
+use std::{
+    borrow::Cow,
+    io::{self, Write},
+};
 
 use byteorder::WriteBytesExt;
 use noodles_bgzf as bgzf;
+use noodles_csi::{
+    self as csi,
+    binning_index::{
+        index::reference_sequence::{bin::Chunk, index::BinnedIndex},
+        Indexer as CsiIndexer,
+    },
+};
 use noodles_vcf::{self as vcf, header::StringMaps};
 
 pub use self::builder::Builder;
-use self::header::write_header;
 pub(crate) use self::record::write_record;
+use self::{
+    header::{preserve_idx, write_header},
+    indexer::Indexer,
+};
 use crate::Record;
 
 pub(crate) const MAJOR: u8 = 2;
@@ -22,6 +36,8 @@ pub(crate) const MINOR: u8 = 2;
 pub struct Writer<W> {
     inner: W,
     string_maps: StringMaps,
+    preserve_idx: bool,
+    indexer: Option<Indexer<W>>,
 }
 
 impl<W> Writer<W>
@@ -69,6 +85,11 @@ where
 
     /// Writes a VCF header.
     ///
+    /// The dictionaries of strings and contigs are built from the header. If `IDX` preservation
+    /// is enabled (see [`Builder::set_preserve_idx`]), the positions in the string maps of the
+    /// header are kept, and each contig, FILTER, FORMAT, and INFO record is written with its
+    /// `IDX` field.
+    ///
     /// # Examples
     ///
     /// ```
@@ -85,10 +106,16 @@ where
     pub fn write_header(&mut self, header: &vcf::Header) -> io::Result<()> {
         write_file_format(&mut self.inner)?;
 
-        self.string_maps = StringMaps::try_from(header)
+        let header = if self.preserve_idx {
+            Cow::Owned(preserve_idx(header))
+        } else {
+            Cow::Borrowed(header)
+        };
+
+        self.string_maps = StringMaps::try_from(header.as_ref())
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
 
-        write_header(&mut self.inner, header)
+        write_header(&mut self.inner, &header)
     }
 
     /// Writes a record.
@@ -121,7 +148,24 @@ where
     /// # Ok::<_, Box<dyn std::error::Error>>(())
     /// ```
     pub fn write_record(&mut self, header: &vcf::Header, record: &Record) -> io::Result<()> {
-        write_record(&mut self.inner, header, &self.string_maps, record)
+        self.write_indexed_record(header, record)
+    }
+
+    fn write_indexed_record(
+        &mut self,
+        header: &vcf::Header,
+        record: &dyn vcf::variant::Record,
+    ) -> io::Result<()> {
+        let Some(indexer) = self.indexer.as_mut() else {
+            return write_record(&mut self.inner, header, &self.string_maps, record);
+        };
+
+        let start_position = indexer.virtual_position(&self.inner);
+        write_record(&mut self.inner, header, &self.string_maps, record)?;
+        let end_position = indexer.virtual_position(&self.inner);
+
+        let chunk = Chunk::new(start_position, end_position);
+        indexer.add_record(header, &self.string_maps, record, chunk)
     }
 }
 
@@ -144,6 +188,58 @@ where
         Self::from(bgzf::Writer::new(writer))
     }
 
+    /// Enables building a coordinate-sorted index (CSI) of written records.
+    ///
+    /// Each record is indexed by its reference sequence, variant start, and variant end, which
+    /// accounts for the reference bases length and the `END` and `SVLEN` INFO fields. Records
+    /// must be written in coordinate order.
+    ///
+    /// The index is retrieved using [`Self::finish_index`] after writing all records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    /// use noodles_csi::binning_index::{index::reference_sequence::index::BinnedIndex, Indexer};
+    ///
+    /// let mut writer = bcf::io::Writer::new(io::sink());
+    /// writer.set_indexer(Indexer::<BinnedIndex>::default());
+    /// ```
+    pub fn set_indexer(&mut self, indexer: CsiIndexer<BinnedIndex>) {
+        self.indexer = Some(Indexer::new(indexer, bgzf::Writer::virtual_position));
+    }
+
+    /// Builds and returns the index of written records.
+    ///
+    /// This returns `None` if indexing is not enabled (see [`Self::set_indexer`]). Indexing is
+    /// disabled after the index is built.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    /// use noodles_csi::binning_index::{index::reference_sequence::index::BinnedIndex, Indexer};
+    /// use noodles_vcf as vcf;
+    ///
+    /// let header = vcf::Header::default();
+    ///
+    /// let mut writer = bcf::io::Writer::new(io::sink());
+    /// writer.set_indexer(Indexer::<BinnedIndex>::default());
+    /// writer.write_header(&header)?;
+    /// writer.try_finish()?;
+    ///
+    /// let index = writer.finish_index(&header);
+    /// assert!(index.is_some());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn finish_index(&mut self, header: &vcf::Header) -> Option<csi::Index> {
+        self.indexer
+            .take()
+            .map(|indexer| indexer.build(header.contigs().len()))
+    }
+
     /// Attempts to finish the output stream.
     ///
     /// This is typically only manually called if the underlying stream is needed before the writer
@@ -168,6 +264,8 @@ impl<W> From<W> for Writer<W> {
         Self {
             inner,
             string_maps: StringMaps::default(),
+            preserve_idx: false,
+            indexer: None,
         }
     }
 }
@@ -185,7 +283,7 @@ where
         header: &vcf::Header,
         record: &dyn vcf::variant::Record,
     ) -> io::Result<()> {
-        write_record(&mut self.inner, header, &self.string_maps, record)
+        self.write_indexed_record(header, record)
     }
 }
 
@@ -221,4 +319,135 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_finish_index() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Cursor;
+
+        use noodles_core::Position;
+        use noodles_vcf::{
+            header::record::value::{
+                map::{Contig, Info},
+                Map,
+            },
+            variant::{
+                io::Write as _, record::info::field::key, record_buf::info::field::Value, RecordBuf,
+            },
+        };
+
+        use crate::io::indexed_reader;
+
+        let header = vcf::Header::builder()
+            .add_info(key::END_POSITION, Map::<Info>::from(key::END_POSITION))
+            .add_contig("sq0", Map::<Contig>::new())
+            .add_contig("sq1", Map::<Contig>::new())
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.set_indexer(CsiIndexer::default());
+        writer.write_header(&header)?;
+
+        for (reference_sequence_name, position, reference_bases, end) in [
+            ("sq0", 8, "A", None),
+            ("sq0", 100, "ACGT", None),
+            ("sq0", 150, "A", Some(300)),
+            ("sq1", 120, "A", None),
+        ] {
+            let mut builder = RecordBuf::builder()
+                .set_reference_sequence_name(reference_sequence_name)
+                .set_variant_start(Position::try_from(position)?)
+                .set_reference_bases(reference_bases);
+
+            if let Some(end) = end {
+                builder = builder.set_info(
+                    [(String::from(key::END_POSITION), Some(Value::from(end)))]
+                        .into_iter()
+                        .collect(),
+                );
+            }
+
+            writer.write_variant_record(&header, &builder.build())?;
+        }
+
+        writer.try_finish()?;
+
+        let index = writer.finish_index(&header).expect("missing index");
+        assert!(writer.finish_index(&header).is_none());
+
+        let data = writer.into_inner().into_inner();
+
+        for (region, expected) in [
+            ("sq0:102-103", &[100][..]),
+            ("sq0:250-260", &[150][..]),
+            ("sq0", &[8, 100, 150][..]),
+            ("sq1", &[120][..]),
+        ] {
+            let mut reader = indexed_reader::Builder::default()
+                .set_index(index.clone())
+                .build_from_reader(Cursor::new(&data))?;
+
+            let header = reader.read_header()?;
+            let region = region.parse()?;
+
+            let actual = reader
+                .query(&header, &region)?
+                .map(|result| {
+                    result.and_then(|record| {
+                        record
+                            .variant_start()
+                            .transpose()
+                            .map(|position| position.map(usize::from).unwrap_or_default())
+                    })
+                })
+                .collect::<io::Result<Vec<_>>>()?;
+
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_header_with_preserve_idx() -> Result<(), Box<dyn std::error::Error>> {
+        const SRC: &str = r#"##fileformat=VCFv4.3
+##FILTER=<ID=q10,Description="Quality below 10",IDX=3>
+##INFO=<ID=DP,Number=1,Type=Integer,Description="Combined depth across samples">
+##contig=<ID=sq0>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+"#;
+
+        fn write_and_read_string_maps(
+            header: &vcf::Header,
+            preserve_idx: bool,
+        ) -> io::Result<StringMaps> {
+            let mut buf = Vec::new();
+
+            {
+                let mut writer = Builder::default()
+                    .set_preserve_idx(preserve_idx)
+                    .build_from_writer(&mut buf);
+
+                writer.write_header(header)?;
+            }
+
+            let mut reader = crate::io::Reader::new(&buf[..]);
+            let header = reader.read_header()?;
+
+            Ok(header.string_maps().clone())
+        }
+
+        let mut header: vcf::Header = SRC.parse()?;
+        let string_maps: StringMaps = SRC.parse()?;
+        *header.string_maps_mut() = string_maps.clone();
+
+        assert_eq!(string_maps.strings().get_index_of("DP"), Some(4));
+
+        let actual = write_and_read_string_maps(&header, true)?;
+        assert_eq!(actual, string_maps);
+
+        let actual = write_and_read_string_maps(&header, false)?;
+        assert_eq!(actual.strings().get_index_of("DP"), Some(1));
+
+        Ok(())
+    }
 }
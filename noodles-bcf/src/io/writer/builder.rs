@@ -13,6 +13,8 @@ use crate::io::CompressionMethod;
 #[derive(Debug, Default)]
 pub struct Builder {
     compression_method: Option<CompressionMethod>,
+    compression_level: Option<bgzf::writer::CompressionLevel>,
+    preserve_idx: bool,
 }
 
 impl Builder {
@@ -29,6 +31,47 @@ impl Builder {
         self
     }
 
+    /// Sets the BGZF compression level.
+    ///
+    /// This is only used when the compression method is BGZF. By default, the BGZF writer's
+    /// default compression level is used. [`bgzf::writer::CompressionLevel::NONE`] writes
+    /// uncompressed BGZF blocks (cf. `bcftools view -Ou`), which is useful for fast intermediate
+    /// files.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::io::writer::Builder;
+    /// use noodles_bgzf::writer::CompressionLevel;
+    /// let builder = Builder::default().set_compression_level(CompressionLevel::NONE);
+    /// ```
+    pub fn set_compression_level(
+        mut self,
+        compression_level: bgzf::writer::CompressionLevel,
+    ) -> Self {
+        self.compression_level = Some(compression_level);
+        self
+    }
+
+    /// Sets whether to preserve the string map positions of the header.
+    ///
+    /// By default, the dictionaries of strings and contigs are rebuilt from the header records.
+    /// When enabled, the positions in the string maps of the header, e.g., as read from a BCF
+    /// file, are kept, and each contig, FILTER, FORMAT, and INFO record is written with an `IDX`
+    /// field. Records missing from the string maps are appended. This keeps the dictionaries
+    /// identical when rewriting a BCF file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::io::writer::Builder;
+    /// let builder = Builder::default().set_preserve_idx(true);
+    /// ```
+    pub fn set_preserve_idx(mut self, preserve_idx: bool) -> Self {
+        self.preserve_idx = preserve_idx;
+        self
+    }
+
     /// Builds a BCF writer from a path.
     ///
     /// # Examples
@@ -60,10 +103,20 @@ impl Builder {
         W: Write + 'w,
     {
         let inner: Box<dyn Write> = match self.compression_method {
-            Some(CompressionMethod::Bgzf) | None => Box::new(bgzf::Writer::new(writer)),
+            Some(CompressionMethod::Bgzf) | None => {
+                let mut builder = bgzf::writer::Builder::default();
+
+                if let Some(compression_level) = self.compression_level {
+                    builder = builder.set_compression_level(compression_level);
+                }
+
+                Box::new(builder.build_with_writer(writer))
+            }
             Some(CompressionMethod::None) => Box::new(BufWriter::new(writer)),
         };
 
-        Writer::from(inner)
+        let mut writer = Writer::from(inner);
+        writer.preserve_idx = self.preserve_idx;
+        writer
     }
 }
@@ -24,6 +24,48 @@ where
     Ok(())
 }
 
+/// Returns a copy of the header with the `IDX` field set on each contig, FILTER, FORMAT, and INFO
+/// record.
+///
+/// Positions are taken from the string maps of the header. Records missing from the string maps
+/// are appended.
+pub(super) fn preserve_idx(header: &vcf::Header) -> vcf::Header {
+    use vcf::header::string_maps::StringMap;
+
+    fn get_or_insert(string_map: &mut StringMap, id: &str) -> usize {
+        match string_map.get_index_of(id) {
+            Some(i) => i,
+            None => {
+                string_map.insert(id.into());
+                string_map.get_index_of(id).expect("missing entry")
+            }
+        }
+    }
+
+    let mut header = header.clone();
+    let mut string_maps = header.string_maps().clone();
+
+    for (id, contig) in header.contigs_mut() {
+        *contig.idx_mut() = Some(get_or_insert(string_maps.contigs_mut(), id));
+    }
+
+    for (id, info) in header.infos_mut() {
+        *info.idx_mut() = Some(get_or_insert(string_maps.strings_mut(), id));
+    }
+
+    for (id, filter) in header.filters_mut() {
+        *filter.idx_mut() = Some(get_or_insert(string_maps.strings_mut(), id));
+    }
+
+    for (id, format) in header.formats_mut() {
+        *format.idx_mut() = Some(get_or_insert(string_maps.strings_mut(), id));
+    }
+
+    *header.string_maps_mut() = string_maps;
+
+    header
+}
+
 pub(crate) fn serialize_header(header: &vcf::Header) -> io::Result<Vec<u8>> {
     let mut writer = vcf::io::Writer::new(Vec::new());
     writer.write_header(header)?;
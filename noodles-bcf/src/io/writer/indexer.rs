@@ -0,0 +1,67 @@
+use std::io;
+
+use noodles_bgzf as bgzf;
+use noodles_csi::{
+    self as csi,
+    binning_index::{
+        index::reference_sequence::{bin::Chunk, index::BinnedIndex},
+        Indexer as CsiIndexer,
+    },
+};
+use noodles_vcf::{self as vcf, header::StringMaps, variant::Record};
+
+/// A record indexer that tracks the virtual positions of records written to a stream.
+pub(super) struct Indexer<W> {
+    inner: CsiIndexer<BinnedIndex>,
+    virtual_position: fn(&W) -> bgzf::VirtualPosition,
+}
+
+impl<W> Indexer<W> {
+    pub(super) fn new(
+        inner: CsiIndexer<BinnedIndex>,
+        virtual_position: fn(&W) -> bgzf::VirtualPosition,
+    ) -> Self {
+        Self {
+            inner,
+            virtual_position,
+        }
+    }
+
+    pub(super) fn virtual_position(&self, writer: &W) -> bgzf::VirtualPosition {
+        (self.virtual_position)(writer)
+    }
+
+    pub(super) fn add_record(
+        &mut self,
+        header: &vcf::Header,
+        string_maps: &StringMaps,
+        record: &dyn Record,
+        chunk: Chunk,
+    ) -> io::Result<()> {
+        let reference_sequence_name = record.reference_sequence_name(header)?;
+
+        let reference_sequence_id = string_maps
+            .contigs()
+            .get_index_of(reference_sequence_name)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("missing contig: {reference_sequence_name}"),
+                )
+            })?;
+
+        let start = record
+            .variant_start()
+            .transpose()?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing variant start"))?;
+
+        let end = record.variant_end(header)?;
+
+        self.inner
+            .add_record(Some((reference_sequence_id, start, end, true)), chunk)
+    }
+
+    pub(super) fn build(self, reference_sequence_count: usize) -> csi::Index {
+        self.inner.build(reference_sequence_count)
+    }
+}
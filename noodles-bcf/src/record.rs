@@ -23,6 +23,13 @@ pub use self::{
 };
 
 /// A BCF record.
+///
+/// A record holds the raw bytes of its site (shared) and samples (individual) blocks, and fields
+/// are decoded on access. When reading, only the boundaries of the variable-length site fields
+/// (IDs, reference bases, alternate bases, and filters) are computed; INFO fields are decoded when
+/// iterated; and the samples block is not parsed until [`Self::samples`] is used. Filtering on,
+/// e.g., the reference sequence ID, position, or quality score therefore does not decode any
+/// INFO or genotype data.
 #[derive(Clone, Default, PartialEq)]
 pub struct Record(Fields);
 
@@ -659,47 +659,25 @@ where
     Ok(())
 }
 
-fn encode_genotype_str(genotype: &str) -> io::Result<Vec<i8>> {
-    const MISSING_ALLELE: &str = ".";
+fn encode_genotype_str(s: &str) -> io::Result<Vec<i8>> {
+    use noodles_vcf::variant::record_buf::samples::sample::value::Genotype as GenotypeBuf;
 
-    fn is_phasing(c: char) -> bool {
-        matches!(c, '|' | '/')
+    fn has_explicit_first_phasing(s: &str) -> bool {
+        s.starts_with(['|', '/'])
     }
 
-    fn encode(s: &str, phasing: &str) -> io::Result<i8> {
-        if s == MISSING_ALLELE {
-            return Ok(0);
-        }
-
-        let j: i8 = s
-            .parse()
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
-        let is_phased = phasing == "|";
+    let mut genotype: GenotypeBuf = s
+        .parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
 
-        let mut i = (j + 1) << 1;
-
-        if is_phased {
-            i |= 0x01;
+    // Without an explicit phasing prefix (VCF < 4.4), the phase bit of the first allele is unset.
+    if !has_explicit_first_phasing(s) {
+        if let Some(allele) = genotype.as_mut().first_mut() {
+            *allele.phasing_mut() = Phasing::Unphased;
         }
-
-        Ok(i)
-    }
-
-    let mut values = Vec::new();
-    let mut start = 0;
-    let mut last_phasing = "/";
-
-    for (end, phasing) in genotype.match_indices(is_phasing) {
-        let i = encode(&genotype[start..end], last_phasing)?;
-        values.push(i);
-        start = end + 1;
-        last_phasing = phasing;
     }
 
-    let i = encode(&genotype[start..], last_phasing)?;
-    values.push(i);
-
-    Ok(values)
+    encode_genotype(&&genotype)
 }
 
 fn encode_genotype(genotype: &dyn Genotype) -> io::Result<Vec<i8>> {
@@ -1354,6 +1332,13 @@ mod tests {
         assert_eq!(encode_genotype_str("1")?, [0x04]);
         assert_eq!(encode_genotype_str("0/1/2")?, [0x02, 0x04, 0x06]);
         assert_eq!(encode_genotype_str("0/1|2")?, [0x02, 0x04, 0x07]);
+        assert_eq!(encode_genotype_str("|0|1")?, [0x03, 0x05]);
+        assert_eq!(encode_genotype_str("/0|1")?, [0x02, 0x05]);
+
+        assert!(matches!(
+            encode_genotype_str("0:1"),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
 
         Ok(())
     }
@@ -0,0 +1,31 @@
+//! Prints the per-base coverage of a sorted BED file as bedGraph.
+
+use std::{
+    env,
+    fs::File,
+    io::{self, BufReader, BufWriter, Write},
+};
+
+use noodles_bed::{self as bed, coverage::Interval};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let mut reader = File::open(src)
+        .map(BufReader::new)
+        .map(bed::io::Reader::new)?;
+
+    let intervals = reader
+        .records::<3>()
+        .map(|result| result.map(|record| Interval::from(&record)));
+
+    let stdout = io::stdout().lock();
+    let mut writer = BufWriter::new(stdout);
+
+    for result in bed::coverage::Builder::default().build(intervals) {
+        let segment = result?;
+        writeln!(writer, "{segment}")?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,573 @@
+//! BED coverage tracks.
+//!
+//! This computes genome coverage from a stream of sorted intervals, e.g., BED records or the
+//! alignment spans of alignment records, and emits it as bedGraph segments.
+//!
+//! # Examples
+//!
+//! ```
+//! use noodles_bed::coverage::{self, Interval};
+//! use noodles_core::Position;
+//!
+//! let intervals = [(1, 10), (6, 15)].into_iter().map(|(start, end)| {
+//!     let start = Position::try_from(start).unwrap();
+//!     let end = Position::try_from(end).unwrap();
+//!     Ok(Interval::new("sq0", start, end, None))
+//! });
+//!
+//! let lines: Vec<_> = coverage::Builder::default()
+//!     .build(intervals)
+//!     .map(|result| result.map(|segment| segment.to_string()))
+//!     .collect::<std::io::Result<_>>()?;
+//!
+//! assert_eq!(lines, ["sq0\t0\t5\t1", "sq0\t5\t10\t2", "sq0\t10\t15\t1"]);
+//! # Ok::<_, std::io::Error>(())
+//! ```
+
+mod builder;
+mod interval;
+mod segment;
+
+pub use self::{builder::Builder, interval::Interval, segment::Segment};
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashSet, VecDeque},
+    io,
+    num::{NonZeroU64, NonZeroUsize},
+};
+
+use noodles_core::Position;
+
+use crate::record::Strand;
+
+/// A coverage normalization method.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Normalization {
+    /// The raw depth.
+    #[default]
+    None,
+    /// Counts per million (CPM), given the total number of counted intervals, e.g., mapped reads.
+    CountsPerMillion(NonZeroU64),
+}
+
+impl Normalization {
+    fn scale_factor(&self) -> f64 {
+        match self {
+            Self::None => 1.0,
+            Self::CountsPerMillion(n) => 1_000_000.0 / n.get() as f64,
+        }
+    }
+}
+
+/// An iterator over coverage segments.
+///
+/// The input intervals must be sorted by reference sequence (grouped by name) and then by start
+/// position. Unsorted input is an error.
+///
+/// Segments with no coverage are not emitted. When coverage is split by strand, the segments of
+/// each strand are sorted, but the segments of both strands are interleaved.
+///
+/// This is created by [`Builder::build`].
+pub struct Coverage<I> {
+    intervals: I,
+    strand_split: bool,
+    tracks: Vec<Track>,
+    reference_sequence_name: Option<String>,
+    finished_reference_sequence_names: HashSet<String>,
+    last_start: Option<Position>,
+    queue: VecDeque<Segment>,
+    is_eof: bool,
+}
+
+impl<I> Coverage<I>
+where
+    I: Iterator<Item = io::Result<Interval>>,
+{
+    fn new(
+        intervals: I,
+        window_size: Option<NonZeroUsize>,
+        strand_split: bool,
+        normalization: Normalization,
+    ) -> Self {
+        let scale_factor = normalization.scale_factor();
+
+        let tracks = if strand_split {
+            vec![
+                Track::new(Some(Strand::Forward), window_size, scale_factor),
+                Track::new(Some(Strand::Reverse), window_size, scale_factor),
+            ]
+        } else {
+            vec![Track::new(None, window_size, scale_factor)]
+        };
+
+        Self {
+            intervals,
+            strand_split,
+            tracks,
+            reference_sequence_name: None,
+            finished_reference_sequence_names: HashSet::new(),
+            last_start: None,
+            queue: VecDeque::new(),
+            is_eof: false,
+        }
+    }
+
+    fn add_interval(&mut self, interval: Interval) -> io::Result<()> {
+        if interval.end() < interval.start() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid interval: end < start",
+            ));
+        }
+
+        if self.reference_sequence_name.as_deref() != Some(interval.reference_sequence_name()) {
+            self.finish_reference_sequence()?;
+
+            let name = interval.reference_sequence_name();
+
+            if self.finished_reference_sequence_names.contains(name) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "intervals are not sorted",
+                ));
+            }
+
+            self.reference_sequence_name = Some(name.into());
+        } else if self
+            .last_start
+            .map(|start| interval.start() < start)
+            .unwrap_or_default()
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "intervals are not sorted",
+            ));
+        }
+
+        self.last_start = Some(interval.start());
+
+        let i = if self.strand_split {
+            match interval.strand() {
+                Some(Strand::Forward) => 0,
+                Some(Strand::Reverse) => 1,
+                None => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "missing interval strand",
+                    ))
+                }
+            }
+        } else {
+            0
+        };
+
+        let name = interval.reference_sequence_name();
+        let start = usize::from(interval.start());
+
+        for track in &mut self.tracks {
+            track.advance_to(name, start, &mut self.queue)?;
+        }
+
+        self.tracks[i].push(start, usize::from(interval.end()));
+
+        Ok(())
+    }
+
+    fn finish_reference_sequence(&mut self) -> io::Result<()> {
+        let Some(name) = self.reference_sequence_name.take() else {
+            return Ok(());
+        };
+
+        for track in &mut self.tracks {
+            track.finish(&name, &mut self.queue)?;
+        }
+
+        self.finished_reference_sequence_names.insert(name);
+        self.last_start = None;
+
+        Ok(())
+    }
+}
+
+impl<I> Iterator for Coverage<I>
+where
+    I: Iterator<Item = io::Result<Interval>>,
+{
+    type Item = io::Result<Segment>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(segment) = self.queue.pop_front() {
+                return Some(Ok(segment));
+            }
+
+            if self.is_eof {
+                return None;
+            }
+
+            let result = match self.intervals.next() {
+                Some(Ok(interval)) => self.add_interval(interval),
+                Some(Err(e)) => Err(e),
+                None => {
+                    self.is_eof = true;
+                    self.finish_reference_sequence()
+                }
+            };
+
+            if let Err(e) = result {
+                return Some(Err(e));
+            }
+        }
+    }
+}
+
+// The coverage of a single strand (or both strands, when not split) on the current reference
+// sequence.
+struct Track {
+    strand: Option<Strand>,
+    window_size: Option<NonZeroUsize>,
+    scale_factor: f64,
+    // The first position with unresolved depth.
+    position: usize,
+    // The end positions of the intervals overlapping `position`.
+    ends: BinaryHeap<Reverse<usize>>,
+    // The pending run of positions with the same depth: (start, end, depth).
+    run: Option<(usize, usize, u64)>,
+    // The pending window: (index, sum of depths).
+    window: Option<(usize, u64)>,
+}
+
+impl Track {
+    fn new(strand: Option<Strand>, window_size: Option<NonZeroUsize>, scale_factor: f64) -> Self {
+        Self {
+            strand,
+            window_size,
+            scale_factor,
+            position: 1,
+            ends: BinaryHeap::new(),
+            run: None,
+            window: None,
+        }
+    }
+
+    fn push(&mut self, start: usize, end: usize) {
+        if self.ends.is_empty() {
+            self.position = start;
+        }
+
+        self.ends.push(Reverse(end));
+    }
+
+    // Resolves the depths of all positions before `target`.
+    fn advance_to(
+        &mut self,
+        name: &str,
+        target: usize,
+        queue: &mut VecDeque<Segment>,
+    ) -> io::Result<()> {
+        while let Some(&Reverse(end)) = self.ends.peek() {
+            if end >= target {
+                break;
+            }
+
+            self.pop_through(name, end, queue)?;
+        }
+
+        if !self.ends.is_empty() && self.position < target {
+            let depth = self.ends.len() as u64;
+            self.add_run(name, self.position, target - 1, depth, queue)?;
+            self.position = target;
+        }
+
+        Ok(())
+    }
+
+    fn finish(&mut self, name: &str, queue: &mut VecDeque<Segment>) -> io::Result<()> {
+        while let Some(&Reverse(end)) = self.ends.peek() {
+            self.pop_through(name, end, queue)?;
+        }
+
+        if let Some((start, end, depth)) = self.run.take() {
+            self.push_segment(name, start, end, depth as f64, queue)?;
+        }
+
+        if let Some((i, sum)) = self.window.take() {
+            self.push_window(name, i, sum, queue)?;
+        }
+
+        self.position = 1;
+
+        Ok(())
+    }
+
+    // Resolves the depths of positions through `end`, which is the smallest end position.
+    fn pop_through(
+        &mut self,
+        name: &str,
+        end: usize,
+        queue: &mut VecDeque<Segment>,
+    ) -> io::Result<()> {
+        let depth = self.ends.len() as u64;
+        self.add_run(name, self.position, end, depth, queue)?;
+        self.position = end + 1;
+
+        while self.ends.peek() == Some(&Reverse(end)) {
+            self.ends.pop();
+        }
+
+        Ok(())
+    }
+
+    fn add_run(
+        &mut self,
+        name: &str,
+        start: usize,
+        end: usize,
+        depth: u64,
+        queue: &mut VecDeque<Segment>,
+    ) -> io::Result<()> {
+        if let Some(window_size) = self.window_size {
+            return self.add_run_to_windows(name, window_size.get(), start, end, depth, queue);
+        }
+
+        match self.run.as_mut() {
+            Some((_, run_end, run_depth)) if *run_end + 1 == start && *run_depth == depth => {
+                *run_end = end;
+            }
+            _ => {
+                if let Some((run_start, run_end, run_depth)) = self.run.replace((start, end, depth))
+                {
+                    self.push_segment(name, run_start, run_end, run_depth as f64, queue)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn add_run_to_windows(
+        &mut self,
+        name: &str,
+        window_size: usize,
+        mut start: usize,
+        end: usize,
+        depth: u64,
+        queue: &mut VecDeque<Segment>,
+    ) -> io::Result<()> {
+        while start <= end {
+            let i = (start - 1) / window_size;
+            let window_end = end.min((i + 1) * window_size);
+
+            match self.window.as_mut() {
+                Some((j, sum)) if *j == i => {
+                    *sum += depth * (window_end - start + 1) as u64;
+                }
+                _ => {
+                    let sum = depth * (window_end - start + 1) as u64;
+
+                    if let Some((j, prev_sum)) = self.window.replace((i, sum)) {
+                        self.push_window(name, j, prev_sum, queue)?;
+                    }
+                }
+            }
+
+            start = window_end + 1;
+        }
+
+        Ok(())
+    }
+
+    fn push_window(
+        &self,
+        name: &str,
+        i: usize,
+        sum: u64,
+        queue: &mut VecDeque<Segment>,
+    ) -> io::Result<()> {
+        let window_size = self.window_size.map(|n| n.get()).unwrap_or(1);
+        let start = i * window_size + 1;
+        let end = (i + 1) * window_size;
+        let mean_depth = sum as f64 / window_size as f64;
+        self.push_segment(name, start, end, mean_depth, queue)
+    }
+
+    fn push_segment(
+        &self,
+        name: &str,
+        start: usize,
+        end: usize,
+        value: f64,
+        queue: &mut VecDeque<Segment>,
+    ) -> io::Result<()> {
+        let start =
+            Position::try_from(start).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let end =
+            Position::try_from(end).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        queue.push_back(Segment::new(
+            name.into(),
+            start,
+            end,
+            self.strand,
+            value * self.scale_factor,
+        ));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_intervals(
+        intervals: &[(&str, usize, usize, Option<Strand>)],
+    ) -> Vec<io::Result<Interval>> {
+        intervals
+            .iter()
+            .map(|&(name, start, end, strand)| {
+                let start = Position::try_from(start)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+                let end = Position::try_from(end)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+                Ok(Interval::new(name, start, end, strand))
+            })
+            .collect()
+    }
+
+    fn collect_lines<I>(coverage: Coverage<I>) -> io::Result<Vec<String>>
+    where
+        I: Iterator<Item = io::Result<Interval>>,
+    {
+        coverage
+            .map(|result| result.map(|segment| segment.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_next() -> io::Result<()> {
+        let intervals = build_intervals(&[
+            ("sq0", 1, 5, None),
+            ("sq0", 3, 5, None),
+            ("sq0", 6, 10, None),
+            ("sq0", 6, 8, None),
+            ("sq0", 21, 25, None),
+            ("sq1", 8, 13, None),
+        ]);
+
+        let coverage = Builder::default().build(intervals);
+
+        assert_eq!(
+            collect_lines(coverage)?,
+            [
+                "sq0\t0\t2\t1",
+                "sq0\t2\t8\t2",
+                "sq0\t8\t10\t1",
+                "sq0\t20\t25\t1",
+                "sq1\t7\t13\t1",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_window_size() -> io::Result<()> {
+        let intervals = build_intervals(&[
+            ("sq0", 1, 10, None),
+            ("sq0", 6, 15, None),
+            ("sq0", 31, 32, None),
+        ]);
+
+        let window_size = NonZeroUsize::try_from(10)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        let coverage = Builder::default()
+            .set_window_size(window_size)
+            .build(intervals);
+
+        assert_eq!(
+            collect_lines(coverage)?,
+            ["sq0\t0\t10\t1.5", "sq0\t10\t20\t0.5", "sq0\t30\t40\t0.2"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_strand_split() -> io::Result<()> {
+        let intervals = build_intervals(&[
+            ("sq0", 1, 5, Some(Strand::Forward)),
+            ("sq0", 3, 8, Some(Strand::Reverse)),
+            ("sq0", 4, 6, Some(Strand::Forward)),
+        ]);
+
+        let segments: Vec<_> = Builder::default()
+            .set_strand_split(true)
+            .build(intervals)
+            .map(|result| result.map(|segment| (segment.strand(), segment.to_string())))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(
+            segments,
+            [
+                (Some(Strand::Forward), String::from("sq0\t0\t3\t1")),
+                (Some(Strand::Forward), String::from("sq0\t3\t5\t2")),
+                (Some(Strand::Forward), String::from("sq0\t5\t6\t1")),
+                (Some(Strand::Reverse), String::from("sq0\t2\t8\t1")),
+            ]
+        );
+
+        let intervals = build_intervals(&[("sq0", 1, 5, None)]);
+        let mut coverage = Builder::default().set_strand_split(true).build(intervals);
+        assert!(matches!(
+            coverage.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_counts_per_million() -> io::Result<()> {
+        let intervals = build_intervals(&[("sq0", 1, 5, None), ("sq0", 3, 5, None)]);
+
+        let total = NonZeroU64::try_from(4_000_000)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        let coverage = Builder::default()
+            .set_normalization(Normalization::CountsPerMillion(total))
+            .build(intervals);
+
+        assert_eq!(
+            collect_lines(coverage)?,
+            ["sq0\t0\t2\t0.25", "sq0\t2\t5\t0.5"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_unsorted_intervals() -> io::Result<()> {
+        let intervals = build_intervals(&[("sq0", 5, 8, None), ("sq0", 1, 3, None)]);
+        let coverage = Builder::default().build(intervals);
+        assert!(matches!(
+            collect_lines(coverage),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let intervals = build_intervals(&[
+            ("sq0", 1, 3, None),
+            ("sq1", 1, 3, None),
+            ("sq0", 5, 8, None),
+        ]);
+        let coverage = Builder::default().build(intervals);
+        assert!(matches!(
+            collect_lines(coverage),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use std::{io, num::NonZeroUsize};
+
+use super::{Coverage, Interval, Normalization};
+
+/// A coverage builder.
+#[derive(Clone, Debug, Default)]
+pub struct Builder {
+    window_size: Option<NonZeroUsize>,
+    strand_split: bool,
+    normalization: Normalization,
+}
+
+impl Builder {
+    /// Sets the window size.
+    ///
+    /// By default, coverage is emitted per base, merging adjacent positions with the same depth.
+    /// With a window size, the mean depth of each fixed-size window is emitted instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bed::coverage;
+    ///
+    /// let builder = coverage::Builder::default()
+    ///     .set_window_size(NonZeroUsize::try_from(100)?);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_window_size(mut self, window_size: NonZeroUsize) -> Self {
+        self.window_size = Some(window_size);
+        self
+    }
+
+    /// Sets whether to split coverage by strand.
+    ///
+    /// When enabled, forward and reverse strand coverage are computed separately, and each
+    /// emitted segment has a strand. Intervals without a strand are an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::coverage;
+    /// let builder = coverage::Builder::default().set_strand_split(true);
+    /// ```
+    pub fn set_strand_split(mut self, strand_split: bool) -> Self {
+        self.strand_split = strand_split;
+        self
+    }
+
+    /// Sets the normalization method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroU64;
+    /// use noodles_bed::coverage::{self, Normalization};
+    ///
+    /// let total = NonZeroU64::try_from(2_000_000)?;
+    /// let builder = coverage::Builder::default()
+    ///     .set_normalization(Normalization::CountsPerMillion(total));
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_normalization(mut self, normalization: Normalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
+    /// Builds a coverage iterator from an iterator of intervals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::iter;
+    /// use noodles_bed::coverage;
+    ///
+    /// let mut coverage = coverage::Builder::default().build(iter::empty());
+    /// assert!(coverage.next().is_none());
+    /// ```
+    pub fn build<I>(self, intervals: I) -> Coverage<I::IntoIter>
+    where
+        I: IntoIterator<Item = io::Result<Interval>>,
+    {
+        Coverage::new(
+            intervals.into_iter(),
+            self.window_size,
+            self.strand_split,
+            self.normalization,
+        )
+    }
+}
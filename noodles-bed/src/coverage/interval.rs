@@ -0,0 +1,139 @@
+use noodles_core::Position;
+
+use crate::{record::Strand, Record};
+
+/// A coverage input interval.
+///
+/// An interval is a closed range of 1-based positions on a reference sequence, with an optional
+/// strand. Intervals can be built from BED records or, e.g., from the alignment span of
+/// alignment records.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Interval {
+    reference_sequence_name: String,
+    start: Position,
+    end: Position,
+    strand: Option<Strand>,
+}
+
+impl Interval {
+    /// Creates a coverage input interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::{coverage::Interval, record::Strand};
+    /// use noodles_core::Position;
+    ///
+    /// let interval = Interval::new(
+    ///     "sq0",
+    ///     Position::try_from(8)?,
+    ///     Position::try_from(13)?,
+    ///     Some(Strand::Forward),
+    /// );
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn new<N>(
+        reference_sequence_name: N,
+        start: Position,
+        end: Position,
+        strand: Option<Strand>,
+    ) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            reference_sequence_name: reference_sequence_name.into(),
+            start,
+            end,
+            strand,
+        }
+    }
+
+    /// Returns the reference sequence name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::coverage::Interval;
+    /// use noodles_core::Position;
+    ///
+    /// let interval = Interval::new("sq0", Position::try_from(8)?, Position::try_from(13)?, None);
+    /// assert_eq!(interval.reference_sequence_name(), "sq0");
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn reference_sequence_name(&self) -> &str {
+        &self.reference_sequence_name
+    }
+
+    /// Returns the start position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::coverage::Interval;
+    /// use noodles_core::Position;
+    ///
+    /// let start = Position::try_from(8)?;
+    /// let interval = Interval::new("sq0", start, Position::try_from(13)?, None);
+    /// assert_eq!(interval.start(), start);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Returns the end position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::coverage::Interval;
+    /// use noodles_core::Position;
+    ///
+    /// let end = Position::try_from(13)?;
+    /// let interval = Interval::new("sq0", Position::try_from(8)?, end, None);
+    /// assert_eq!(interval.end(), end);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn end(&self) -> Position {
+        self.end
+    }
+
+    /// Returns the strand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bed::coverage::Interval;
+    /// use noodles_core::Position;
+    ///
+    /// let interval = Interval::new("sq0", Position::try_from(8)?, Position::try_from(13)?, None);
+    /// assert!(interval.strand().is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn strand(&self) -> Option<Strand> {
+        self.strand
+    }
+}
+
+impl From<&Record<3>> for Interval {
+    fn from(record: &Record<3>) -> Self {
+        Self::new(
+            record.reference_sequence_name(),
+            record.start_position(),
+            record.end_position(),
+            None,
+        )
+    }
+}
+
+impl From<&Record<6>> for Interval {
+    fn from(record: &Record<6>) -> Self {
+        Self::new(
+            record.reference_sequence_name(),
+            record.start_position(),
+            record.end_position(),
+            record.strand(),
+        )
+    }
+}
@@ -0,0 +1,99 @@
+use std::fmt;
+
+use noodles_core::Position;
+
+use crate::record::Strand;
+
+/// A coverage segment.
+///
+/// A segment is a closed range of 1-based positions on a reference sequence with a coverage
+/// value. Its [`fmt::Display`] implementation writes it as a bedGraph line (without a trailing
+/// newline), i.e., with a 0-based start position.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Segment {
+    reference_sequence_name: String,
+    start: Position,
+    end: Position,
+    strand: Option<Strand>,
+    value: f64,
+}
+
+impl Segment {
+    pub(super) fn new(
+        reference_sequence_name: String,
+        start: Position,
+        end: Position,
+        strand: Option<Strand>,
+        value: f64,
+    ) -> Self {
+        Self {
+            reference_sequence_name,
+            start,
+            end,
+            strand,
+            value,
+        }
+    }
+
+    /// Returns the reference sequence name.
+    pub fn reference_sequence_name(&self) -> &str {
+        &self.reference_sequence_name
+    }
+
+    /// Returns the start position.
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Returns the end position.
+    pub fn end(&self) -> Position {
+        self.end
+    }
+
+    /// Returns the strand.
+    ///
+    /// This is only set when coverage is split by strand.
+    pub fn strand(&self) -> Option<Strand> {
+        self.strand
+    }
+
+    /// Returns the coverage value.
+    ///
+    /// This is the depth, the mean depth of a window, or either normalized.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl fmt::Display for Segment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}",
+            self.reference_sequence_name,
+            usize::from(self.start) - 1,
+            self.end,
+            self.value
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() -> Result<(), noodles_core::position::TryFromIntError> {
+        let segment = Segment::new(
+            String::from("sq0"),
+            Position::try_from(8)?,
+            Position::try_from(13)?,
+            None,
+            2.5,
+        );
+
+        assert_eq!(segment.to_string(), "sq0\t7\t13\t2.5");
+
+        Ok(())
+    }
+}
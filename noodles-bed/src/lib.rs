@@ -2,6 +2,7 @@
 
 //! **noodles-bed** handles the reading and writing of the BED (Browser Extensible Data) format.
 
+pub mod coverage;
 pub mod io;
 pub mod record;
 
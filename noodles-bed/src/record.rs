@@ -16,7 +16,10 @@ use std::{
     str::FromStr,
 };
 
-use noodles_core::Position;
+use noodles_core::{
+    position::{Positioned, ReferenceSequence},
+    Position,
+};
 
 const DELIMITER: char = '\t';
 const MISSING_STRING: &str = ".";
@@ -771,6 +774,25 @@ impl fmt::Display for ParseError {
     }
 }
 
+impl<const N: u8> Positioned for Record<N>
+where
+    Self: BedN<3>,
+{
+    fn reference_sequence(&self) -> Option<ReferenceSequence<'_>> {
+        Some(ReferenceSequence::Name(
+            self.reference_sequence_name().into(),
+        ))
+    }
+
+    fn start(&self) -> Option<Position> {
+        Some(self.start_position())
+    }
+
+    fn end(&self) -> Option<Position> {
+        Some(self.end_position())
+    }
+}
+
 impl FromStr for Record<3> {
     type Err = ParseError;
 
@@ -16,12 +16,7 @@ use tokio::io::{self, AsyncWrite};
 
 pub use self::builder::Builder;
 use self::{deflate::Deflate, deflater::Deflater};
-use crate::writer::MAX_BUF_SIZE;
-
-#[cfg(feature = "libdeflate")]
-type CompressionLevel = libdeflater::CompressionLvl;
-#[cfg(not(feature = "libdeflate"))]
-type CompressionLevel = flate2::Compression;
+use crate::writer::{CompressionLevel, MAX_BUF_SIZE};
 
 pin_project! {
     /// An async BGZF writer.
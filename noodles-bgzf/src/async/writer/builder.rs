@@ -81,7 +81,7 @@ impl Builder {
             sink: Deflater::new(FramedWrite::new(writer, BlockCodec)).buffer(worker_count.get()),
             buf: BytesMut::with_capacity(MAX_BUF_SIZE),
             eof_buf: Bytes::from_static(BGZF_EOF),
-            compression_level: compression_level.into(),
+            compression_level,
         }
     }
 }
@@ -1,7 +1,9 @@
-use std::io;
+use std::{fmt, io};
 
 use flate2::Crc;
 
+use crate::writer::CompressionLevel;
+
 #[cfg(feature = "libdeflate")]
 pub(crate) fn decode(src: &[u8], dst: &mut [u8]) -> io::Result<()> {
     use libdeflater::Decompressor;
@@ -24,49 +26,197 @@ pub(crate) fn decode(src: &[u8], dst: &mut [u8]) -> io::Result<()> {
     decoder.read_exact(dst)
 }
 
-#[cfg(feature = "libdeflate")]
+#[cfg(feature = "async")]
 pub(crate) fn encode(
     src: &[u8],
-    compression_level: libdeflater::CompressionLvl,
+    compression_level: CompressionLevel,
     dst: &mut Vec<u8>,
 ) -> io::Result<u32> {
-    use libdeflater::Compressor;
-
-    let mut encoder = Compressor::new(compression_level);
+    Encoder::new(compression_level).encode(src, dst)
+}
 
-    let max_len = encoder.deflate_compress_bound(src.len());
-    dst.resize(max_len, 0);
+/// A DEFLATE encoder that reuses its compressor state across blocks.
+///
+/// Each BGZF block is compressed independently, but allocating and initializing a new compressor
+/// for every block is a significant cost for small blocks.
+pub(crate) struct Encoder {
+    compression_level: CompressionLevel,
+    #[cfg(feature = "libdeflate")]
+    compressor: libdeflater::Compressor,
+    #[cfg(not(feature = "libdeflate"))]
+    compressor: flate2::Compress,
+}
 
-    let len = encoder
-        .deflate_compress(src, dst)
-        .map_err(|_| io::Error::from(io::ErrorKind::InvalidInput))?;
+impl Encoder {
+    pub(crate) fn new(compression_level: CompressionLevel) -> Self {
+        Self {
+            compression_level,
+            #[cfg(feature = "libdeflate")]
+            compressor: libdeflater::Compressor::new(compression_level.into()),
+            #[cfg(not(feature = "libdeflate"))]
+            compressor: flate2::Compress::new(compression_level.into(), false),
+        }
+    }
+
+    pub(crate) fn compression_level(&self) -> CompressionLevel {
+        self.compression_level
+    }
+
+    pub(crate) fn encode(&mut self, src: &[u8], dst: &mut Vec<u8>) -> io::Result<u32> {
+        if self.compression_level == CompressionLevel::NONE {
+            encode_stored(src, dst);
+        } else {
+            self.encode_compressed(src, dst)?;
+        }
+
+        let mut crc = Crc::new();
+        crc.update(src);
+
+        Ok(crc.sum())
+    }
+
+    #[cfg(feature = "libdeflate")]
+    fn encode_compressed(&mut self, src: &[u8], dst: &mut Vec<u8>) -> io::Result<()> {
+        let max_len = self.compressor.deflate_compress_bound(src.len());
+        dst.resize(max_len, 0);
+
+        let len = self
+            .compressor
+            .deflate_compress(src, dst)
+            .map_err(|_| io::Error::from(io::ErrorKind::InvalidInput))?;
+
+        dst.truncate(len);
+
+        Ok(())
+    }
+
+    #[cfg(not(feature = "libdeflate"))]
+    fn encode_compressed(&mut self, src: &[u8], dst: &mut Vec<u8>) -> io::Result<()> {
+        use flate2::{FlushCompress, Status};
+
+        // The DEFLATE overhead for incompressible data is a few bytes per stored block.
+        const MARGIN: usize = 1024;
+
+        self.compressor.reset();
+
+        dst.clear();
+        dst.reserve(src.len() + MARGIN);
+
+        loop {
+            // SAFETY: The input is at most `BGZF_MAX_ISIZE` bytes.
+            let consumed = self.compressor.total_in() as usize;
+
+            let status = self
+                .compressor
+                .compress_vec(&src[consumed..], dst, FlushCompress::Finish)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+            match status {
+                Status::StreamEnd => break,
+                Status::Ok | Status::BufError => dst.reserve(MARGIN),
+            }
+        }
+
+        Ok(())
+    }
+}
 
-    dst.truncate(len);
+impl fmt::Debug for Encoder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Encoder")
+            .field("compression_level", &self.compression_level)
+            .finish_non_exhaustive()
+    }
+}
 
-    let mut crc = Crc::new();
-    crc.update(src);
+// Writes the data as stored (non-compressed) DEFLATE blocks.
+//
+// See RFC 1951 § 3.2.4 "Non-compressed blocks (BTYPE=00)".
+fn encode_stored(src: &[u8], dst: &mut Vec<u8>) {
+    const MAX_LEN: usize = u16::MAX as usize;
+    const BTYPE_STORED: u8 = 0b00 << 1;
+    const BFINAL: u8 = 0b1;
 
-    Ok(crc.sum())
-}
+    dst.clear();
 
-#[cfg(not(feature = "libdeflate"))]
-pub(crate) fn encode(
-    src: &[u8],
-    compression_level: flate2::Compression,
-    dst: &mut Vec<u8>,
-) -> io::Result<u32> {
-    use std::io::Write;
+    let mut chunks = src.chunks(MAX_LEN).peekable();
 
-    use flate2::write::DeflateEncoder;
+    if chunks.peek().is_none() {
+        dst.extend([BFINAL | BTYPE_STORED, 0x00, 0x00, 0xff, 0xff]);
+        return;
+    }
 
-    dst.clear();
+    while let Some(chunk) = chunks.next() {
+        let bfinal = if chunks.peek().is_none() { BFINAL } else { 0 };
+        dst.push(bfinal | BTYPE_STORED);
 
-    let mut encoder = DeflateEncoder::new(dst, compression_level);
-    encoder.write_all(src)?;
-    encoder.finish()?;
+        // SAFETY: `chunk.len() <= u16::MAX`.
+        let len = chunk.len() as u16;
+        dst.extend(len.to_le_bytes());
+        dst.extend((!len).to_le_bytes());
 
-    let mut crc = Crc::new();
-    crc.update(src);
+        dst.extend(chunk);
+    }
+}
 
-    Ok(crc.sum())
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_stored() -> io::Result<()> {
+        let mut dst = Vec::new();
+
+        encode_stored(b"", &mut dst);
+        assert_eq!(dst, [0x01, 0x00, 0x00, 0xff, 0xff]);
+
+        encode_stored(b"noodles", &mut dst);
+        assert_eq!(dst, b"\x01\x07\x00\xf8\xffnoodles");
+
+        let mut buf = vec![0; 7];
+        decode(&dst, &mut buf)?;
+        assert_eq!(buf, b"noodles");
+
+        let src = vec![b'n'; usize::from(u16::MAX) + 8];
+        encode_stored(&src, &mut dst);
+        assert_eq!(dst.len(), src.len() + 2 * 5);
+        assert_eq!(dst[0], 0x00);
+
+        let mut buf = vec![0; src.len()];
+        decode(&dst, &mut buf)?;
+        assert_eq!(buf, src);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encoder_encode() -> io::Result<()> {
+        // Pseudorandom data is incompressible.
+        let mut state = 1u32;
+        let incompressible: Vec<_> = (0..65280)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state as u8
+            })
+            .collect();
+
+        let mut encoder = Encoder::new(CompressionLevel::default());
+        let mut dst = Vec::new();
+
+        for src in [&b"noodles"[..], b"", &[b'n'; 65280], &incompressible] {
+            let crc32 = encoder.encode(src, &mut dst)?;
+
+            let mut crc = Crc::new();
+            crc.update(src);
+            assert_eq!(crc32, crc.sum());
+
+            let mut buf = vec![0; src.len()];
+            decode(&dst, &mut buf)?;
+            assert_eq!(buf, src);
+        }
+
+        Ok(())
+    }
 }
@@ -45,6 +45,7 @@ pub mod indexed_reader;
 pub mod io;
 mod multithreaded_reader;
 pub mod multithreaded_writer;
+pub mod raw_block;
 pub mod reader;
 pub mod virtual_position;
 pub mod writer;
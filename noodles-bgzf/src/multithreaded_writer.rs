@@ -13,7 +13,7 @@ use bytes::{Bytes, BytesMut};
 use crossbeam_channel::{Receiver, Sender};
 
 pub use self::builder::Builder;
-use super::writer::{CompressionLevelImpl, MAX_BUF_SIZE};
+use super::{deflate::Encoder, writer::CompressionLevel};
 
 type FrameParts = (Vec<u8>, u32, usize);
 type BufferedTx = Sender<io::Result<FrameParts>>;
@@ -42,6 +42,7 @@ where
 {
     state: State<W>,
     buf: BytesMut,
+    block_size: usize,
 }
 
 impl<W> MultithreadedWriter<W>
@@ -117,11 +118,11 @@ where
     }
 
     fn remaining(&self) -> usize {
-        MAX_BUF_SIZE - self.buf.len()
+        self.block_size - self.buf.len()
     }
 
     fn has_remaining(&self) -> bool {
-        self.buf.len() < MAX_BUF_SIZE
+        self.buf.len() < self.block_size
     }
 
     fn send(&mut self) -> io::Result<()> {
@@ -201,23 +202,20 @@ where
     })
 }
 
-fn spawn_deflaters<L>(
-    compression_level: L,
+fn spawn_deflaters(
+    compression_level: CompressionLevel,
     worker_count: NonZeroUsize,
     deflate_rx: DeflateRx,
-) -> Vec<JoinHandle<()>>
-where
-    L: Into<CompressionLevelImpl>,
-{
-    let compression_level = compression_level.into();
-
+) -> Vec<JoinHandle<()>> {
     (0..worker_count.get())
         .map(|_| {
             let deflate_rx = deflate_rx.clone();
 
             thread::spawn(move || {
+                let mut encoder = Encoder::new(compression_level);
+
                 while let Ok((src, buffered_tx)) = deflate_rx.recv() {
-                    let result = compress(&src, compression_level);
+                    let result = compress(&mut encoder, &src);
                     buffered_tx.send(result).ok();
                 }
             })
@@ -225,9 +223,8 @@ where
         .collect()
 }
 
-fn compress(src: &[u8], compression_level: CompressionLevelImpl) -> io::Result<FrameParts> {
-    use super::deflate;
+fn compress(encoder: &mut Encoder, src: &[u8]) -> io::Result<FrameParts> {
     let mut dst = Vec::new();
-    let crc32 = deflate::encode(src, compression_level, &mut dst)?;
+    let crc32 = encoder.encode(src, &mut dst)?;
     Ok((dst, crc32, src.len()))
 }
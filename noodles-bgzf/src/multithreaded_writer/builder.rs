@@ -3,15 +3,46 @@ use std::{io::Write, num::NonZeroUsize};
 use bytes::BytesMut;
 
 use super::MultithreadedWriter;
-use crate::writer::CompressionLevel;
+use crate::writer::{CompressionLevel, Preset, MAX_BUF_SIZE};
 
 /// A multithreaded BGZF writer builder.
 pub struct Builder {
     compression_level: CompressionLevel,
+    block_size: usize,
     worker_count: NonZeroUsize,
 }
 
 impl Builder {
+    /// Sets the compression level and block size from a preset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::{multithreaded_writer::Builder, writer::Preset};
+    /// let builder = Builder::default().set_preset(Preset::Fastq);
+    /// ```
+    pub fn set_preset(mut self, preset: Preset) -> Self {
+        self.compression_level = preset.compression_level();
+        self.block_size = preset.block_size();
+        self
+    }
+
+    /// Sets the max uncompressed size of a block.
+    ///
+    /// The size is limited to the max size that fits in a BGZF block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bgzf::multithreaded_writer::Builder;
+    /// let builder = Builder::default().set_block_size(NonZeroUsize::try_from(16384).unwrap());
+    /// ```
+    pub fn set_block_size(mut self, block_size: NonZeroUsize) -> Self {
+        self.block_size = block_size.get().min(MAX_BUF_SIZE);
+        self
+    }
+
     /// Sets the compression level.
     ///
     /// # Examples
@@ -71,14 +102,18 @@ impl Builder {
                 deflate_tx,
             },
             buf: BytesMut::new(),
+            block_size: self.block_size,
         }
     }
 }
 
 impl Default for Builder {
     fn default() -> Self {
+        let preset = Preset::default();
+
         Self {
-            compression_level: CompressionLevel::default(),
+            compression_level: preset.compression_level(),
+            block_size: preset.block_size(),
             worker_count: NonZeroUsize::MIN,
         }
     }
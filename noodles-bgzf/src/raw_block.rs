@@ -0,0 +1,224 @@
+//! Raw BGZF blocks.
+//!
+//! A raw block is a compressed BGZF block that is read and written verbatim, i.e., without
+//! decompressing or recompressing its data. This allows BGZF streams to be concatenated quickly,
+//! similar to `samtools cat`.
+
+use std::io::{self, Read};
+
+use crate::{reader::frame, writer::BGZF_EOF, BGZF_HEADER_SIZE};
+
+/// A raw (compressed) BGZF block.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RawBlock {
+    buf: Vec<u8>,
+}
+
+impl RawBlock {
+    /// Returns the compressed block, including its header and trailer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::raw_block::RawBlock;
+    /// let block = RawBlock::default();
+    /// assert!(block.as_bytes().is_empty());
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf
+    }
+
+    /// Returns the size of the compressed block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::raw_block::RawBlock;
+    /// let block = RawBlock::default();
+    /// assert_eq!(block.size(), 0);
+    /// ```
+    pub fn size(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns the size of the uncompressed data (ISIZE).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::raw_block::RawBlock;
+    /// let block = RawBlock::default();
+    /// assert_eq!(block.uncompressed_size(), 0);
+    /// ```
+    pub fn uncompressed_size(&self) -> usize {
+        const ISIZE_SIZE: usize = 4;
+
+        let Some(i) = self.buf.len().checked_sub(ISIZE_SIZE) else {
+            return 0;
+        };
+
+        // SAFETY: `buf[i..]` is 4 bytes.
+        let buf: [u8; ISIZE_SIZE] = self.buf[i..].try_into().unwrap();
+        u32::from_le_bytes(buf) as usize
+    }
+
+    /// Returns whether the block is the BGZF end-of-file marker.
+    ///
+    /// When concatenating BGZF streams, EOF markers are typically dropped from all inputs, and a
+    /// single marker is written at the end of the output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::raw_block::RawBlock;
+    /// let block = RawBlock::default();
+    /// assert!(!block.is_eof());
+    /// ```
+    pub fn is_eof(&self) -> bool {
+        self.buf == BGZF_EOF
+    }
+}
+
+/// A raw BGZF block reader.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Write};
+/// use noodles_bgzf::{self as bgzf, raw_block};
+///
+/// let mut writer = bgzf::Writer::new(Vec::new());
+/// writer.write_all(b"noodles")?;
+/// let data = writer.finish()?;
+///
+/// let mut reader = raw_block::Reader::new(&data[..]);
+/// let blocks: Vec<_> = reader.raw_blocks().collect::<io::Result<_>>()?;
+///
+/// assert_eq!(blocks.len(), 2);
+/// assert_eq!(blocks[0].uncompressed_size(), 7);
+/// assert!(blocks[1].is_eof());
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct Reader<R> {
+    inner: R,
+}
+
+impl<R> Reader<R>
+where
+    R: Read,
+{
+    /// Creates a raw BGZF block reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::raw_block;
+    /// let reader = raw_block::Reader::new(&[][..]);
+    /// ```
+    pub fn new(inner: R) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::raw_block;
+    /// let reader = raw_block::Reader::new(&[][..]);
+    /// assert!(reader.into_inner().is_empty());
+    /// ```
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Reads a raw block.
+    ///
+    /// The block header is validated, but the compressed data is not decompressed.
+    ///
+    /// This returns the size of the block. If there are no more blocks, this returns 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf::raw_block::{self, RawBlock};
+    ///
+    /// let mut reader = raw_block::Reader::new(&[][..]);
+    /// let mut block = RawBlock::default();
+    /// assert_eq!(reader.read_raw_block(&mut block)?, 0);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn read_raw_block(&mut self, block: &mut RawBlock) -> io::Result<usize> {
+        let buf = &mut block.buf;
+
+        match frame::read_frame_into(&mut self.inner, buf)? {
+            Some(()) => {
+                frame::parse_header(&buf[..BGZF_HEADER_SIZE])?;
+                Ok(buf.len())
+            }
+            None => {
+                buf.clear();
+                Ok(0)
+            }
+        }
+    }
+
+    /// Returns an iterator over raw blocks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::raw_block;
+    /// let mut reader = raw_block::Reader::new(&[][..]);
+    /// assert!(reader.raw_blocks().next().is_none());
+    /// ```
+    pub fn raw_blocks(&mut self) -> impl Iterator<Item = io::Result<RawBlock>> + '_ {
+        let mut block = RawBlock::default();
+
+        std::iter::from_fn(move || match self.read_raw_block(&mut block) {
+            Ok(0) => None,
+            Ok(_) => Some(Ok(block.clone())),
+            Err(e) => Some(Err(e)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    #[test]
+    fn test_read_raw_block() -> io::Result<()> {
+        let mut writer = crate::Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        let data = writer.finish()?;
+
+        let mut reader = Reader::new(&data[..]);
+        let mut block = RawBlock::default();
+
+        let n = reader.read_raw_block(&mut block)?;
+        assert_eq!(n, data.len() - BGZF_EOF.len());
+        assert_eq!(block.as_bytes(), &data[..n]);
+        assert_eq!(block.uncompressed_size(), 7);
+        assert!(!block.is_eof());
+
+        assert_eq!(reader.read_raw_block(&mut block)?, BGZF_EOF.len());
+        assert!(block.is_eof());
+
+        assert_eq!(reader.read_raw_block(&mut block)?, 0);
+        assert!(block.as_bytes().is_empty());
+
+        let mut data = BGZF_EOF.to_vec();
+        data[0] = 0x00;
+        let mut reader = Reader::new(&data[..]);
+        assert!(matches!(
+            reader.read_raw_block(&mut block),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -1,13 +1,23 @@
 //! BGZF reader.
 
+mod block_cache;
 mod builder;
 pub(crate) mod frame;
+mod truncated_block_error;
 
-pub use self::builder::Builder;
+pub use self::{
+    block_cache::BlockCache, builder::Builder, truncated_block_error::TruncatedBlockError,
+};
 
-use std::io::{self, BufRead, Read, Seek, SeekFrom};
+#[cfg(feature = "mmap")]
+pub use memmap2::Mmap;
 
-use super::{gzi, Block, VirtualPosition, BGZF_MAX_ISIZE};
+use std::{
+    io::{self, BufRead, Read, Seek, SeekFrom},
+    sync::Arc,
+};
+
+use super::{gzi, writer::BGZF_EOF, Block, VirtualPosition, BGZF_MAX_ISIZE};
 
 /// A BGZF reader.
 ///
@@ -31,6 +41,9 @@ pub struct Reader<R> {
     buf: Vec<u8>,
     position: u64,
     block: Block,
+    block_cache: Option<Arc<BlockCache>>,
+    salvage: bool,
+    is_truncated: bool,
 }
 
 impl<R> Reader<R> {
@@ -75,6 +88,24 @@ impl<R> Reader<R> {
     pub fn into_inner(self) -> R {
         self.inner
     }
+
+    /// Returns whether a truncated block was found and skipped.
+    ///
+    /// This is only set when the reader is in salvage mode (see
+    /// [`Builder::set_salvage`]). Otherwise, reading a truncated block returns a
+    /// [`TruncatedBlockError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let data = [];
+    /// let reader = bgzf::Reader::new(&data[..]);
+    /// assert!(!reader.is_truncated());
+    /// ```
+    pub fn is_truncated(&self) -> bool {
+        self.is_truncated
+    }
 }
 
 impl<R> Reader<R>
@@ -91,7 +122,7 @@ where
     /// let reader = bgzf::Reader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Builder.build_from_reader(inner)
+        Builder::default().build_from_reader(inner)
     }
 
     /// Returns the current position of the stream.
@@ -124,12 +155,22 @@ where
 
     fn read_nonempty_block_with<F>(&mut self, mut f: F) -> io::Result<usize>
     where
-        F: FnMut(&[u8], &mut Block) -> io::Result<()>,
+        F: FnMut(u64, &[u8], &mut Block) -> io::Result<()>,
     {
         use self::frame::read_frame_into;
 
-        while read_frame_into(&mut self.inner, &mut self.buf)?.is_some() {
-            f(&self.buf, &mut self.block)?;
+        loop {
+            match read_frame_into(&mut self.inner, &mut self.buf) {
+                Ok(Some(())) => {}
+                Ok(None) => break,
+                Err(e) if self.salvage && is_truncated_block_error(&e) => {
+                    self.is_truncated = true;
+                    break;
+                }
+                Err(e) => return Err(e),
+            }
+
+            f(self.position, &self.buf, &mut self.block)?;
 
             self.block.set_position(self.position);
             self.position += self.block.size();
@@ -144,12 +185,19 @@ where
 
     fn read_block(&mut self) -> io::Result<usize> {
         use self::frame::parse_block;
-        self.read_nonempty_block_with(parse_block)
+
+        if let Some(block_cache) = self.block_cache.clone() {
+            self.read_nonempty_block_with(|position, src, block| {
+                block_cache.parse_block(position, src, block)
+            })
+        } else {
+            self.read_nonempty_block_with(|_, src, block| parse_block(src, block))
+        }
     }
 
     fn read_block_into_buf(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         use self::frame::parse_block_into_buf;
-        self.read_nonempty_block_with(|src, block| parse_block_into_buf(src, block, buf))
+        self.read_nonempty_block_with(|_, src, block| parse_block_into_buf(src, block, buf))
     }
 }
 
@@ -220,6 +268,47 @@ where
 
         Ok(pos)
     }
+
+    /// Returns whether the stream ends with a BGZF EOF marker block.
+    ///
+    /// A missing EOF block typically means the stream is truncated. The position of the stream is
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor, Write};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::Writer::new(Vec::new());
+    /// writer.write_all(b"noodles")?;
+    /// let data = writer.finish()?;
+    ///
+    /// let mut reader = bgzf::Reader::new(Cursor::new(&data));
+    /// assert!(reader.is_eof_block_present()?);
+    ///
+    /// let mut reader = bgzf::Reader::new(Cursor::new(&data[..data.len() - 1]));
+    /// assert!(!reader.is_eof_block_present()?);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn is_eof_block_present(&mut self) -> io::Result<bool> {
+        let position = self.inner.stream_position()?;
+        let len = self.inner.seek(SeekFrom::End(0))?;
+
+        let result = match len.checked_sub(BGZF_EOF.len() as u64) {
+            Some(eof_position) => {
+                let mut buf = vec![0; BGZF_EOF.len()];
+                self.inner.seek(SeekFrom::Start(eof_position))?;
+                self.inner.read_exact(&mut buf)?;
+                buf == BGZF_EOF
+            }
+            None => false,
+        };
+
+        self.inner.seek(SeekFrom::Start(position))?;
+
+        Ok(result)
+    }
 }
 
 impl<R> Read for Reader<R>
@@ -230,7 +319,11 @@ where
         // If a new block is about to be read and the given buffer is guaranteed to be larger than
         // the next block, reading to the block buffer can be skipped. The uncompressed data is
         // decoded into the given buffer to avoid having to subsequently recopy it from the block.
-        if !self.block.data().has_remaining() && buf.len() >= BGZF_MAX_ISIZE {
+        // This is skipped when using a block cache, which needs the block buffer to be filled.
+        if !self.block.data().has_remaining()
+            && buf.len() >= BGZF_MAX_ISIZE
+            && self.block_cache.is_none()
+        {
             self.read_block_into_buf(buf)
         } else {
             let mut src = self.fill_buf()?;
@@ -295,6 +388,11 @@ where
     }
 }
 
+fn is_truncated_block_error(e: &io::Error) -> bool {
+    e.kind() == io::ErrorKind::UnexpectedEof
+        && e.get_ref().is_some_and(|e| e.is::<TruncatedBlockError>())
+}
+
 pub(crate) fn default_read_exact<R>(reader: &mut R, mut buf: &mut [u8]) -> io::Result<()>
 where
     R: Read,
@@ -419,4 +517,121 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_seek_with_block_cache() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        #[rustfmt::skip]
+        let data = [
+            // block 0 (b"noodles")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x22, 0x00, 0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00, 0xa1,
+            0x58, 0x2a, 0x80, 0x07, 0x00, 0x00, 0x00,
+            // block 1 (b"bgzf")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1f, 0x00, 0x4b, 0x4a, 0xaf, 0x4a, 0x03, 0x00, 0x20, 0x68, 0xf2, 0x8c,
+            0x04, 0x00, 0x00, 0x00,
+            // EOF block
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1b, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let block_cache = Arc::new(BlockCache::new(NonZeroUsize::try_from(2)?));
+
+        let mut reader = Builder::default()
+            .set_block_cache(block_cache.clone())
+            .build_from_reader(Cursor::new(&data));
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodlesbgzf");
+        assert_eq!(block_cache.len(), 2);
+
+        let mut reader = Builder::default()
+            .set_block_cache(block_cache.clone())
+            .build_from_reader(Cursor::new(&data));
+
+        reader.seek(VirtualPosition::try_from((35, 1))?)?;
+        buf.clear();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"gzf");
+
+        reader.seek(VirtualPosition::try_from((0, 3))?)?;
+        let mut buf = [0; 4];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"dles");
+        assert_eq!(
+            reader.virtual_position(),
+            VirtualPosition::try_from((35, 0))?
+        );
+
+        assert_eq!(block_cache.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_eof_block_present() -> io::Result<()> {
+        use std::io::Write;
+
+        let mut writer = crate::Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        let data = writer.finish()?;
+
+        let mut reader = Reader::new(Cursor::new(&data));
+        let mut buf = [0; 3];
+        reader.read_exact(&mut buf)?;
+        assert!(reader.is_eof_block_present()?);
+        assert_eq!(
+            reader.get_mut().position(),
+            (data.len() - BGZF_EOF.len()) as u64
+        );
+
+        let mut reader = Reader::new(Cursor::new(&data[..data.len() - BGZF_EOF.len()]));
+        assert!(!reader.is_eof_block_present()?);
+
+        let mut reader = Reader::new(Cursor::new(&[][..]));
+        assert!(!reader.is_eof_block_present()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_with_truncated_block() -> io::Result<()> {
+        use std::io::Write;
+
+        let mut writer = crate::Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        writer.write_all(b"bgzf")?;
+        let data = writer.finish()?;
+
+        // Truncates the second block, dropping its trailer and the EOF block.
+        let end = data.len() - BGZF_EOF.len() - 4;
+        let src = &data[..end];
+
+        let mut reader = Reader::new(src);
+        let mut buf = Vec::new();
+        assert!(matches!(
+            reader.read_to_end(&mut buf),
+            Err(e) if is_truncated_block_error(&e)
+        ));
+
+        let mut reader = Builder::default().set_salvage(true).build_from_reader(src);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodles");
+        assert!(reader.is_truncated());
+
+        let mut reader = Builder::default()
+            .set_salvage(true)
+            .build_from_reader(&data[..]);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodlesbgzf");
+        assert!(!reader.is_truncated());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,399 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt, io,
+    num::NonZeroUsize,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use super::frame::parse_block;
+use crate::{gz, Block};
+
+/// A BGZF block cache.
+///
+/// The cache holds the uncompressed data of up to a fixed number of blocks, keyed by the
+/// compressed position of each block. When full, the least recently used block is evicted.
+///
+/// A block cache is useful for random access workloads, e.g., many small indexed queries, where
+/// the same blocks would otherwise be repeatedly decompressed. It can be shared by multiple
+/// readers of the same file using an [`Arc`].
+///
+/// The block trailer (CRC32 and uncompressed size) is also compared on lookup, which guards
+/// against returning data from a different stream that has a block at the same compressed
+/// position. Nonetheless, a cache is intended to be shared only among readers of the same file.
+///
+/// # Examples
+///
+/// ```
+/// use std::{num::NonZeroUsize, sync::Arc};
+/// use noodles_bgzf as bgzf;
+///
+/// let block_cache = NonZeroUsize::try_from(256).map(bgzf::reader::BlockCache::new)?;
+/// let block_cache = Arc::new(block_cache);
+///
+/// let data = [];
+/// let reader = bgzf::reader::Builder::default()
+///     .set_block_cache(block_cache.clone())
+///     .build_from_reader(&data[..]);
+/// # Ok::<_, std::num::TryFromIntError>(())
+/// ```
+pub struct BlockCache {
+    capacity: NonZeroUsize,
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    tick: u64,
+    hits: u64,
+    misses: u64,
+    entries: HashMap<u64, Entry>,
+    ticks: BTreeMap<u64, u64>,
+}
+
+struct Entry {
+    tick: u64,
+    block_size: u64,
+    trailer: [u8; gz::TRAILER_SIZE],
+    data: Arc<[u8]>,
+}
+
+impl BlockCache {
+    /// Creates a block cache with the given capacity, in blocks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bgzf as bgzf;
+    /// let block_cache = NonZeroUsize::try_from(256).map(bgzf::reader::BlockCache::new)?;
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// Returns the maximum number of blocks the cache holds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bgzf as bgzf;
+    /// let block_cache = NonZeroUsize::try_from(256).map(bgzf::reader::BlockCache::new)?;
+    /// assert_eq!(block_cache.capacity().get(), 256);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn capacity(&self) -> NonZeroUsize {
+        self.capacity
+    }
+
+    /// Returns the number of cached blocks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bgzf as bgzf;
+    /// let block_cache = NonZeroUsize::try_from(256).map(bgzf::reader::BlockCache::new)?;
+    /// assert_eq!(block_cache.len(), 0);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Returns whether the cache is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bgzf as bgzf;
+    /// let block_cache = NonZeroUsize::try_from(256).map(bgzf::reader::BlockCache::new)?;
+    /// assert!(block_cache.is_empty());
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.lock().entries.is_empty()
+    }
+
+    /// Returns the number of lookups that were served from the cache.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bgzf as bgzf;
+    /// let block_cache = NonZeroUsize::try_from(256).map(bgzf::reader::BlockCache::new)?;
+    /// assert_eq!(block_cache.hits(), 0);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn hits(&self) -> u64 {
+        self.lock().hits
+    }
+
+    /// Returns the number of lookups that were not served from the cache.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bgzf as bgzf;
+    /// let block_cache = NonZeroUsize::try_from(256).map(bgzf::reader::BlockCache::new)?;
+    /// assert_eq!(block_cache.misses(), 0);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn misses(&self) -> u64 {
+        self.lock().misses
+    }
+
+    /// Returns the ratio of lookups that were served from the cache.
+    ///
+    /// This returns `None` if there have been no lookups.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bgzf as bgzf;
+    /// let block_cache = NonZeroUsize::try_from(256).map(bgzf::reader::BlockCache::new)?;
+    /// assert!(block_cache.hit_rate().is_none());
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn hit_rate(&self) -> Option<f64> {
+        let state = self.lock();
+        let lookups = state.hits + state.misses;
+
+        if lookups == 0 {
+            None
+        } else {
+            Some(state.hits as f64 / lookups as f64)
+        }
+    }
+
+    /// Resets the hit and miss counts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bgzf as bgzf;
+    /// let block_cache = NonZeroUsize::try_from(256).map(bgzf::reader::BlockCache::new)?;
+    /// block_cache.reset_stats();
+    /// assert_eq!(block_cache.hits(), 0);
+    /// assert_eq!(block_cache.misses(), 0);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn reset_stats(&self) {
+        let mut state = self.lock();
+        state.hits = 0;
+        state.misses = 0;
+    }
+
+    /// Removes all cached blocks.
+    ///
+    /// This does not reset the hit and miss counts. See [`Self::reset_stats`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bgzf as bgzf;
+    /// let block_cache = NonZeroUsize::try_from(256).map(bgzf::reader::BlockCache::new)?;
+    /// block_cache.clear();
+    /// assert!(block_cache.is_empty());
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn clear(&self) {
+        let mut state = self.lock();
+        state.entries.clear();
+        state.ticks.clear();
+    }
+
+    /// Decodes a block frame at the given compressed position, using the cached data if present.
+    pub(super) fn parse_block(
+        &self,
+        position: u64,
+        src: &[u8],
+        block: &mut Block,
+    ) -> io::Result<()> {
+        let trailer = get_trailer(src)?;
+
+        if let Some(data) = self.get(position, src.len() as u64, trailer) {
+            block.set_size(src.len() as u64);
+
+            let block_data = block.data_mut();
+            block_data.set_position(0);
+            block_data.resize(data.len());
+            block_data.as_mut().copy_from_slice(&data);
+
+            return Ok(());
+        }
+
+        parse_block(src, block)?;
+
+        let data = Arc::from(block.data().as_ref());
+        self.insert(position, block.size(), trailer, data);
+
+        Ok(())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn get(
+        &self,
+        position: u64,
+        block_size: u64,
+        trailer: [u8; gz::TRAILER_SIZE],
+    ) -> Option<Arc<[u8]>> {
+        let mut state = self.lock();
+        let State {
+            tick,
+            hits,
+            misses,
+            entries,
+            ticks,
+        } = &mut *state;
+
+        let Some(entry) = entries
+            .get_mut(&position)
+            .filter(|entry| entry.block_size == block_size && entry.trailer == trailer)
+        else {
+            *misses += 1;
+            return None;
+        };
+
+        *hits += 1;
+
+        ticks.remove(&entry.tick);
+        *tick += 1;
+        entry.tick = *tick;
+        ticks.insert(entry.tick, position);
+
+        Some(entry.data.clone())
+    }
+
+    fn insert(
+        &self,
+        position: u64,
+        block_size: u64,
+        trailer: [u8; gz::TRAILER_SIZE],
+        data: Arc<[u8]>,
+    ) {
+        let mut state = self.lock();
+
+        if let Some(entry) = state.entries.remove(&position) {
+            state.ticks.remove(&entry.tick);
+        } else if state.entries.len() >= self.capacity.get() {
+            if let Some((_, evicted_position)) = state.ticks.pop_first() {
+                state.entries.remove(&evicted_position);
+            }
+        }
+
+        state.tick += 1;
+        let tick = state.tick;
+
+        state.ticks.insert(tick, position);
+        state.entries.insert(
+            position,
+            Entry {
+                tick,
+                block_size,
+                trailer,
+                data,
+            },
+        );
+    }
+}
+
+impl fmt::Debug for BlockCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlockCache")
+            .field("capacity", &self.capacity)
+            .field("len", &self.len())
+            .field("hits", &self.hits())
+            .field("misses", &self.misses())
+            .finish()
+    }
+}
+
+fn get_trailer(src: &[u8]) -> io::Result<[u8; gz::TRAILER_SIZE]> {
+    src.len()
+        .checked_sub(gz::TRAILER_SIZE)
+        .and_then(|i| src[i..].try_into().ok())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid frame size"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_block_cache(capacity: usize) -> BlockCache {
+        BlockCache::new(NonZeroUsize::new(capacity).unwrap())
+    }
+
+    #[test]
+    fn test_insert_with_eviction() {
+        const TRAILER: [u8; gz::TRAILER_SIZE] = [0; gz::TRAILER_SIZE];
+
+        let block_cache = build_block_cache(2);
+
+        block_cache.insert(0, 8, TRAILER, Arc::from(&b"ndls"[..]));
+        block_cache.insert(8, 8, TRAILER, Arc::from(&b"bgzf"[..]));
+        assert_eq!(block_cache.len(), 2);
+
+        // Marks block 0 as recently used.
+        assert_eq!(
+            block_cache.get(0, 8, TRAILER).as_deref(),
+            Some(&b"ndls"[..])
+        );
+
+        block_cache.insert(16, 8, TRAILER, Arc::from(&b"noodles"[..]));
+        assert_eq!(block_cache.len(), 2);
+
+        assert!(block_cache.get(0, 8, TRAILER).is_some());
+        assert!(block_cache.get(8, 8, TRAILER).is_none());
+        assert!(block_cache.get(16, 8, TRAILER).is_some());
+    }
+
+    #[test]
+    fn test_get_with_mismatched_frame() {
+        const TRAILER: [u8; gz::TRAILER_SIZE] = [0; gz::TRAILER_SIZE];
+
+        let block_cache = build_block_cache(1);
+        block_cache.insert(0, 8, TRAILER, Arc::from(&b"ndls"[..]));
+
+        assert!(block_cache.get(0, 13, TRAILER).is_none());
+        assert!(block_cache.get(0, 8, [1; gz::TRAILER_SIZE]).is_none());
+    }
+
+    #[test]
+    fn test_stats() {
+        const TRAILER: [u8; gz::TRAILER_SIZE] = [0; gz::TRAILER_SIZE];
+
+        let block_cache = build_block_cache(1);
+        assert!(block_cache.hit_rate().is_none());
+
+        assert!(block_cache.get(0, 8, TRAILER).is_none());
+        block_cache.insert(0, 8, TRAILER, Arc::from(&b"ndls"[..]));
+        assert!(block_cache.get(0, 8, TRAILER).is_some());
+        assert!(block_cache.get(0, 8, TRAILER).is_some());
+        assert!(block_cache.get(8, 8, TRAILER).is_none());
+
+        assert_eq!(block_cache.hits(), 2);
+        assert_eq!(block_cache.misses(), 2);
+        assert_eq!(block_cache.hit_rate(), Some(0.5));
+
+        block_cache.clear();
+        assert_eq!(block_cache.hits(), 2);
+
+        block_cache.reset_stats();
+        assert_eq!(block_cache.hits(), 0);
+        assert_eq!(block_cache.misses(), 0);
+    }
+}
@@ -2,16 +2,61 @@ use std::{
     fs::File,
     io::{self, Read},
     path::Path,
+    sync::Arc,
 };
 
-use super::Reader;
+#[cfg(feature = "mmap")]
+use super::Mmap;
+use super::{BlockCache, Reader};
 use crate::Block;
 
 /// A BGZF reader builder.
 #[derive(Debug, Default)]
-pub struct Builder;
+pub struct Builder {
+    block_cache: Option<Arc<BlockCache>>,
+    salvage: bool,
+}
 
 impl Builder {
+    /// Sets a block cache.
+    ///
+    /// By default, the reader does not cache blocks. A block cache can be shared by multiple
+    /// readers of the same file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{num::NonZeroUsize, sync::Arc};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let block_cache = NonZeroUsize::try_from(256).map(bgzf::reader::BlockCache::new)?;
+    /// let builder = bgzf::reader::Builder::default().set_block_cache(Arc::new(block_cache));
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_block_cache(mut self, block_cache: Arc<BlockCache>) -> Self {
+        self.block_cache = Some(block_cache);
+        self
+    }
+
+    /// Sets whether to salvage truncated streams.
+    ///
+    /// By default, reading a truncated block returns an error with a
+    /// [`super::TruncatedBlockError`]. In salvage mode, a truncated block is instead
+    /// treated as the end of the stream, which allows reading all data up to the last intact
+    /// block, e.g., from an interrupted transfer. Use [`Reader::is_truncated`] to check whether a
+    /// truncated block was skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let builder = bgzf::reader::Builder::default().set_salvage(true);
+    /// ```
+    pub fn set_salvage(mut self, salvage: bool) -> Self {
+        self.salvage = salvage;
+        self
+    }
+
     /// Builds a BGZF reader from a path.
     ///
     /// # Examples
@@ -30,6 +75,36 @@ impl Builder {
         Ok(self.build_from_reader(file))
     }
 
+    /// Builds a memory-mapped BGZF reader from a path.
+    ///
+    /// Rather than reading the file using system calls, the file is mapped into memory. This
+    /// makes seeking cheap, e.g., for random access by indexed queries on local storage.
+    ///
+    /// The file must not be modified, e.g., truncated or rewritten, by this or another process
+    /// while the reader is in use.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    /// let reader = bgzf::reader::Builder::default().build_mmap_from_path("example.gz")?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    #[cfg(feature = "mmap")]
+    pub fn build_mmap_from_path<P>(self, src: P) -> io::Result<Reader<io::Cursor<Mmap>>>
+    where
+        P: AsRef<Path>,
+    {
+        let file = File::open(src)?;
+
+        // SAFETY: The mapping is read-only, and the caller is documented to not modify the file
+        // while it is mapped.
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        Ok(self.build_from_reader(io::Cursor::new(mmap)))
+    }
+
     /// Builds a BGZF reader from a reader.
     ///
     /// # Examples
@@ -48,6 +123,51 @@ impl Builder {
             buf: Vec::new(),
             position: 0,
             block: Block::default(),
+            block_cache: self.block_cache,
+            salvage: self.salvage,
+            is_truncated: false,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn test_build_mmap_from_path() -> std::io::Result<()> {
+        use std::{
+            env, fs,
+            io::{Read, Write},
+        };
+
+        use super::*;
+
+        let src = env::temp_dir().join(format!(
+            "noodles-bgzf-build-mmap-from-path-{}.gz",
+            std::process::id()
+        ));
+
+        let mut writer = File::create(&src).map(crate::Writer::new)?;
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        let position = writer.virtual_position();
+        writer.write_all(b"-bgzf")?;
+        writer.finish()?;
+
+        let mut reader = Builder::default().build_mmap_from_path(&src)?;
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodles-bgzf");
+
+        reader.seek(position)?;
+        buf.clear();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"-bgzf");
+
+        drop(reader);
+        fs::remove_file(&src)?;
+
+        Ok(())
+    }
+}
@@ -3,6 +3,7 @@ use std::io::{self, Read};
 use bytes::Buf;
 use flate2::Crc;
 
+use super::TruncatedBlockError;
 use crate::{gz, Block, BGZF_HEADER_SIZE};
 
 const MIN_FRAME_SIZE: usize = BGZF_HEADER_SIZE + gz::TRAILER_SIZE;
@@ -15,10 +16,10 @@ where
 
     buf.resize(BGZF_HEADER_SIZE, 0);
 
-    match reader.read_exact(buf) {
-        Ok(()) => {}
-        Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
-        Err(e) => return Err(e),
+    match read_exact_or_eof(reader, buf)? {
+        0 => return Ok(None),
+        BGZF_HEADER_SIZE => {}
+        _ => return Err(truncated_block_error()),
     }
 
     let bsize = (&buf[BSIZE_POSITION..]).get_u16_le();
@@ -32,9 +33,36 @@ where
     }
 
     buf.resize(block_size, 0);
-    reader.read_exact(&mut buf[BGZF_HEADER_SIZE..])?;
 
-    Ok(Some(()))
+    match reader.read_exact(&mut buf[BGZF_HEADER_SIZE..]) {
+        Ok(()) => Ok(Some(())),
+        Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => Err(truncated_block_error()),
+        Err(e) => Err(e),
+    }
+}
+
+// Fills the buffer, returning the number of bytes read, which is less than the buffer length only
+// at EOF.
+fn read_exact_or_eof<R>(reader: &mut R, buf: &mut [u8]) -> io::Result<usize>
+where
+    R: Read,
+{
+    let mut n = 0;
+
+    while n < buf.len() {
+        match reader.read(&mut buf[n..]) {
+            Ok(0) => break,
+            Ok(m) => n += m,
+            Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(n)
+}
+
+fn truncated_block_error() -> io::Error {
+    io::Error::new(io::ErrorKind::UnexpectedEof, TruncatedBlockError)
 }
 
 fn split_frame(buf: &[u8]) -> io::Result<(&[u8], &[u8], &[u8])> {
@@ -55,7 +83,7 @@ fn split_frame(buf: &[u8]) -> io::Result<(&[u8], &[u8], &[u8])> {
     Ok((header, cdata, trailer))
 }
 
-fn parse_header(src: &[u8]) -> io::Result<()> {
+pub(crate) fn parse_header(src: &[u8]) -> io::Result<()> {
     if is_valid_header(src) {
         Ok(())
     } else {
@@ -239,4 +267,31 @@ mod tests {
         let mut buf = Vec::new();
         assert!(read_frame_into(&mut reader, &mut buf).is_err());
     }
+
+    #[test]
+    fn test_read_frame_into_with_truncated_frame() {
+        fn is_truncated_block_error(result: io::Result<Option<()>>) -> bool {
+            matches!(
+                result,
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+                    && e.get_ref().is_some_and(|e| e.is::<TruncatedBlockError>())
+            )
+        }
+
+        let mut buf = Vec::new();
+
+        // header
+        let mut reader = &BGZF_EOF[..4];
+        assert!(is_truncated_block_error(read_frame_into(
+            &mut reader,
+            &mut buf
+        )));
+
+        // cdata
+        let mut reader = &BGZF_EOF[..BGZF_EOF.len() - 1];
+        assert!(is_truncated_block_error(read_frame_into(
+            &mut reader,
+            &mut buf
+        )));
+    }
 }
@@ -0,0 +1,31 @@
+use std::{error, fmt};
+
+/// An error returned when a BGZF block is truncated.
+///
+/// This is the inner error of an [`std::io::Error`] with the kind
+/// [`std::io::ErrorKind::UnexpectedEof`]. It is returned when the stream ends in the middle of a
+/// block, e.g., from an interrupted transfer.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{self, Read};
+/// use noodles_bgzf::{self as bgzf, reader::TruncatedBlockError};
+///
+/// let data = [0x1f, 0x8b, 0x08, 0x04];
+/// let mut reader = bgzf::Reader::new(&data[..]);
+///
+/// let mut buf = Vec::new();
+/// let e = reader.read_to_end(&mut buf).unwrap_err();
+/// assert!(e.get_ref().is_some_and(|e| e.is::<TruncatedBlockError>()));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct TruncatedBlockError;
+
+impl error::Error for TruncatedBlockError {}
+
+impl fmt::Display for TruncatedBlockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("truncated BGZF block")
+    }
+}
@@ -3,13 +3,16 @@
 mod builder;
 mod compression_level;
 mod frame;
+mod preset;
 
-pub use self::{builder::Builder, compression_level::CompressionLevel};
+pub use self::{builder::Builder, compression_level::CompressionLevel, preset::Preset};
 
 use std::io::{self, Write};
 
 pub(crate) use self::frame::write_frame;
-use super::{gz, VirtualPosition, BGZF_HEADER_SIZE, BGZF_MAX_ISIZE};
+use super::{
+    deflate::Encoder, gz, raw_block::RawBlock, VirtualPosition, BGZF_HEADER_SIZE, BGZF_MAX_ISIZE,
+};
 
 // The max DEFLATE overhead for 65536 bytes of data at compression level 0.
 //
@@ -41,11 +44,6 @@ pub(crate) static BGZF_EOF: &[u8] = &[
     0x00, 0x00, 0x00, 0x00, // ISIZE = 0
 ];
 
-#[cfg(feature = "libdeflate")]
-pub(crate) type CompressionLevelImpl = libdeflater::CompressionLvl;
-#[cfg(not(feature = "libdeflate"))]
-pub(crate) type CompressionLevelImpl = flate2::Compression;
-
 /// A BZGF writer.
 ///
 /// This implements [`std::io::Write`], consuming uncompressed data and emitting compressed data.
@@ -71,7 +69,8 @@ where
     position: u64,
     staging_buf: Vec<u8>,
     compression_buf: Vec<u8>,
-    compression_level: CompressionLevelImpl,
+    encoder: Encoder,
+    block_size: usize,
 }
 
 impl<W> Writer<W>
@@ -153,11 +152,82 @@ where
         VirtualPosition::try_from((self.position, uncompressed_position)).unwrap()
     }
 
-    fn flush_block(&mut self) -> io::Result<()> {
-        use crate::deflate;
+    /// Returns the compression level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf::{self as bgzf, writer::CompressionLevel};
+    /// let writer = bgzf::Writer::new(io::sink());
+    /// assert_eq!(writer.compression_level(), CompressionLevel::default());
+    /// ```
+    pub fn compression_level(&self) -> CompressionLevel {
+        self.encoder.compression_level()
+    }
+
+    /// Returns the max uncompressed size of a block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf::{self as bgzf, writer::Preset};
+    /// let writer = bgzf::Writer::new(io::sink());
+    /// assert_eq!(writer.block_size(), Preset::default().block_size());
+    /// ```
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
 
+    /// Writes a raw (compressed) block verbatim.
+    ///
+    /// Any buffered uncompressed data is first flushed as its own block. The raw block is written
+    /// as is, without decompressing or recompressing it. This is useful to copy blocks from
+    /// another BGZF stream, e.g., to concatenate streams.
+    ///
+    /// A raw EOF block is also written as is. To concatenate streams, skip EOF blocks from inputs
+    /// (see [`RawBlock::is_eof`]), since the writer appends one when it is finished.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Write};
+    /// use noodles_bgzf::{self as bgzf, raw_block};
+    ///
+    /// let mut src = bgzf::Writer::new(Vec::new());
+    /// src.write_all(b"noodles")?;
+    /// let src = src.finish()?;
+    ///
+    /// let mut reader = raw_block::Reader::new(&src[..]);
+    /// let mut writer = bgzf::Writer::new(Vec::new());
+    ///
+    /// for result in reader.raw_blocks() {
+    ///     let block = result?;
+    ///
+    ///     if !block.is_eof() {
+    ///         writer.write_raw_block(&block)?;
+    ///     }
+    /// }
+    ///
+    /// let dst = writer.finish()?;
+    /// assert_eq!(dst, src);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_raw_block(&mut self, block: &RawBlock) -> io::Result<()> {
+        self.flush()?;
+
+        let inner = self.inner.as_mut().unwrap();
+        inner.write_all(block.as_bytes())?;
+
+        self.position += block.size() as u64;
+
+        Ok(())
+    }
+
+    fn flush_block(&mut self) -> io::Result<()> {
         let compressed_data = &mut self.compression_buf;
-        let crc32 = deflate::encode(&self.staging_buf, self.compression_level, compressed_data)?;
+        let crc32 = self.encoder.encode(&self.staging_buf, compressed_data)?;
 
         let inner = self.inner.as_mut().unwrap();
         let uncompressed_len = self.staging_buf.len();
@@ -220,11 +290,11 @@ where
     }
 
     fn remaining(&self) -> usize {
-        MAX_BUF_SIZE - self.staging_buf.len()
+        self.block_size - self.staging_buf.len()
     }
 
     fn has_remaining(&self) -> bool {
-        self.staging_buf.len() < MAX_BUF_SIZE
+        self.staging_buf.len() < self.block_size
     }
 }
 
@@ -290,6 +360,50 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_write_with_block_size() -> io::Result<()> {
+        use std::{io::Read, num::NonZeroUsize};
+
+        let mut writer = Builder::default()
+            .set_block_size(NonZeroUsize::try_from(4).unwrap())
+            .build_with_writer(Vec::new());
+
+        writer.write_all(b"noodles")?;
+        assert_eq!(writer.virtual_position().uncompressed(), 3);
+
+        let data = writer.finish()?;
+
+        let mut reader = crate::Reader::new(&data[..]);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodles");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_with_compression_level_none() -> io::Result<()> {
+        use std::io::Read;
+
+        let mut writer = Builder::default()
+            .set_compression_level(CompressionLevel::NONE)
+            .build_with_writer(Vec::new());
+
+        writer.write_all(b"noodles")?;
+        let data = writer.finish()?;
+
+        const STORED_BLOCK_HEADER_SIZE: usize = 5;
+        let cdata_start = BGZF_HEADER_SIZE + STORED_BLOCK_HEADER_SIZE;
+        assert_eq!(&data[cdata_start..cdata_start + 7], b"noodles");
+
+        let mut reader = crate::Reader::new(&data[..]);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodles");
+
+        Ok(())
+    }
+
     #[test]
     fn test_finish() -> io::Result<()> {
         let mut writer = Writer::new(Vec::new());
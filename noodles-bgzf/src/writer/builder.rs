@@ -1,14 +1,55 @@
-use std::io::Write;
+use std::{
+    fs::File,
+    io::{self, Read, Seek, SeekFrom, Write},
+    num::NonZeroUsize,
+};
 
-use super::{CompressionLevel, Writer, MAX_BUF_SIZE};
+use super::{CompressionLevel, Preset, Writer, BGZF_EOF, MAX_BUF_SIZE};
+use crate::deflate::Encoder;
 
 /// A BGZF writer builder.
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct Builder {
     compression_level: CompressionLevel,
+    block_size: usize,
+    position: u64,
 }
 
 impl Builder {
+    /// Sets the compression level and block size from a preset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::{self as bgzf, writer::Preset};
+    /// let builder = bgzf::writer::Builder::default().set_preset(Preset::Text);
+    /// ```
+    pub fn set_preset(mut self, preset: Preset) -> Self {
+        self.compression_level = preset.compression_level();
+        self.block_size = preset.block_size();
+        self
+    }
+
+    /// Sets the max uncompressed size of a block.
+    ///
+    /// The size is limited to the max size that fits in a BGZF block.
+    ///
+    /// By default, this is the max size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let builder = bgzf::writer::Builder::default()
+    ///     .set_block_size(NonZeroUsize::try_from(16384).unwrap());
+    /// ```
+    pub fn set_block_size(mut self, block_size: NonZeroUsize) -> Self {
+        self.block_size = block_size.get().min(MAX_BUF_SIZE);
+        self
+    }
+
     /// Sets a compression level.
     ///
     /// By default, the compression level is set to level 6.
@@ -26,6 +67,31 @@ impl Builder {
         self
     }
 
+    /// Sets the starting compressed position.
+    ///
+    /// This is the position of the underlying writer in the BGZF stream, e.g., when appending
+    /// blocks to an existing BGZF file (after removing its EOF block). Virtual positions reported
+    /// by the writer are relative to this position.
+    ///
+    /// By default, the position is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let writer = bgzf::writer::Builder::default()
+    ///     .set_position(144)
+    ///     .build_with_writer(io::sink());
+    ///
+    /// assert_eq!(writer.position(), 144);
+    /// ```
+    pub fn set_position(mut self, position: u64) -> Self {
+        self.position = position;
+        self
+    }
+
     /// Builds a BGZF writer from a writer.
     ///
     /// # Examples
@@ -41,10 +107,108 @@ impl Builder {
     {
         Writer {
             inner: Some(writer),
-            position: 0,
-            staging_buf: Vec::with_capacity(MAX_BUF_SIZE),
+            position: self.position,
+            staging_buf: Vec::with_capacity(self.block_size),
             compression_buf: Vec::new(),
-            compression_level: self.compression_level.into(),
+            encoder: Encoder::new(self.compression_level),
+            block_size: self.block_size,
+        }
+    }
+
+    /// Builds a BGZF writer that appends to an existing BGZF file.
+    ///
+    /// The file must end with a BGZF EOF block. This block is removed, and the writer is positioned
+    /// at the end of the file. A new EOF block is written when the writer is finished.
+    ///
+    /// This overrides the starting position set by [`Self::set_position`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::OpenOptions;
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let file = OpenOptions::new().read(true).write(true).open("sample.gz")?;
+    /// let writer = bgzf::writer::Builder::default().build_for_append(file)?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn build_for_append(mut self, mut file: File) -> io::Result<Writer<File>> {
+        let eof_len = BGZF_EOF.len() as u64;
+        let len = file.metadata()?.len();
+
+        let position = len
+            .checked_sub(eof_len)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing BGZF EOF"))?;
+
+        let mut buf = vec![0; BGZF_EOF.len()];
+        file.seek(SeekFrom::Start(position))?;
+        file.read_exact(&mut buf)?;
+
+        if buf != BGZF_EOF {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "missing BGZF EOF",
+            ));
         }
+
+        file.set_len(position)?;
+        file.seek(SeekFrom::Start(position))?;
+
+        self.position = position;
+
+        Ok(self.build_with_writer(file))
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        let preset = Preset::default();
+
+        Self {
+            compression_level: preset.compression_level(),
+            block_size: preset.block_size(),
+            position: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use super::*;
+
+    #[test]
+    fn test_build_for_append() -> io::Result<()> {
+        let dst = env::temp_dir().join(format!(
+            "noodles-bgzf-build-for-append-{}.gz",
+            std::process::id()
+        ));
+
+        let mut writer = File::create(&dst).map(Writer::new)?;
+        writer.write_all(b"noodles")?;
+        let data_len = writer.finish()?.metadata()?.len();
+
+        let file = fs::OpenOptions::new().read(true).write(true).open(&dst)?;
+        let mut writer = Builder::default().build_for_append(file)?;
+        assert_eq!(writer.position(), data_len - BGZF_EOF.len() as u64);
+        writer.write_all(b"-bgzf")?;
+        writer.finish()?;
+
+        let mut reader = File::open(&dst).map(crate::Reader::new)?;
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodles-bgzf");
+
+        let file = fs::OpenOptions::new().read(true).write(true).open(&dst)?;
+        fs::write(&dst, b"noodles")?;
+        assert!(matches!(
+            Builder::default().build_for_append(file),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        fs::remove_file(dst)?;
+
+        Ok(())
     }
 }
@@ -13,6 +13,9 @@ pub struct CompressionLevel(u8);
 
 impl CompressionLevel {
     /// No compression.
+    ///
+    /// Data is written as stored (non-compressed) DEFLATE blocks within valid BGZF blocks. This
+    /// is the fastest level to write and is still readable by any BGZF reader.
     pub const NONE: Self = Self(0);
 
     /// A compression level optimized for speed.
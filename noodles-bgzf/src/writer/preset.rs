@@ -0,0 +1,74 @@
+use super::{CompressionLevel, MAX_BUF_SIZE};
+
+/// A BGZF writer preset.
+///
+/// A preset selects a compression level and block size suited to the kind of data being
+/// written.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Preset {
+    /// Binary records, e.g., BAM and BCF.
+    ///
+    /// Binary records are dense, and higher compression levels give little gain for their cost.
+    /// This is the same as the writer defaults.
+    #[default]
+    Binary,
+    /// Line-based text, e.g., VCF, SAM, BED, and GFF.
+    ///
+    /// Text has long repeated substrings (e.g., INFO keys and sample fields) that benefit from a
+    /// higher compression level.
+    Text,
+    /// FASTQ records.
+    ///
+    /// Base quality scores are mostly incompressible with DEFLATE. A lower compression level is
+    /// considerably faster with a small increase in output size.
+    Fastq,
+}
+
+impl Preset {
+    /// Returns the compression level of the preset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::writer::{CompressionLevel, Preset};
+    /// assert_eq!(Preset::Binary.compression_level(), CompressionLevel::default());
+    /// ```
+    pub fn compression_level(&self) -> CompressionLevel {
+        // SAFETY: These are valid compression levels for all DEFLATE backends.
+        match self {
+            Self::Binary => CompressionLevel::default(),
+            Self::Text => CompressionLevel::new(8).unwrap(),
+            Self::Fastq => CompressionLevel::new(4).unwrap(),
+        }
+    }
+
+    /// Returns the max uncompressed block size of the preset.
+    ///
+    /// All presets use the largest block size that fits in a BGZF block. Smaller blocks reduce
+    /// the amount of data decompressed for random access at the cost of compression rate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::writer::Preset;
+    /// assert_eq!(Preset::Text.block_size(), 65495);
+    /// ```
+    pub fn block_size(&self) -> usize {
+        MAX_BUF_SIZE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compression_level() {
+        assert_eq!(
+            Preset::Binary.compression_level(),
+            CompressionLevel::default()
+        );
+        assert_eq!(Preset::Text.compression_level().get(), 8);
+        assert_eq!(Preset::Fastq.compression_level().get(), 4);
+    }
+}
@@ -0,0 +1,314 @@
+mod convert;
+mod index;
+mod sort;
+mod stats;
+mod view;
+
+use std::{
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use noodles_core::Region;
+use noodles_fasta::{self as fasta, repository::adapters::IndexedReader};
+
+const USAGE: &str = "\
+usage: noodles <command> [options] <args>
+
+commands:
+  view [-r <fasta>] <src> [<region>]   print records as SAM or VCF
+  sort [-r <fasta>] <src> <dst>        sort records by position
+  index <src>                          write an index for a BAM, CRAM, VCF.gz, or BCF
+  stats [-r <fasta>] <src>             print record summary statistics
+  convert [-r <fasta>] <src> <dst>     convert between formats of the same kind
+
+options:
+  -r, --reference <fasta>              reference sequences (for CRAM)
+  -h, --help                           print this message";
+
+/// A CLI command.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Command {
+    /// Prints records, optionally in a region, as SAM or VCF to stdout.
+    View {
+        /// The input path.
+        src: PathBuf,
+        /// The region to query. This requires an associated index.
+        region: Option<Region>,
+        /// The reference sequences path.
+        reference: Option<PathBuf>,
+    },
+    /// Sorts records by reference sequence and position.
+    Sort {
+        /// The input path.
+        src: PathBuf,
+        /// The output path.
+        dst: PathBuf,
+        /// The reference sequences path.
+        reference: Option<PathBuf>,
+    },
+    /// Writes an index next to the input, e.g., `<src>.bai`.
+    Index {
+        /// The input path.
+        src: PathBuf,
+    },
+    /// Prints record summary statistics to stdout.
+    Stats {
+        /// The input path.
+        src: PathBuf,
+        /// The reference sequences path.
+        reference: Option<PathBuf>,
+    },
+    /// Converts between formats of the same kind, e.g., BAM to CRAM.
+    Convert {
+        /// The input path.
+        src: PathBuf,
+        /// The output path.
+        dst: PathBuf,
+        /// The reference sequences path.
+        reference: Option<PathBuf>,
+    },
+    /// Prints the usage message to stdout.
+    Help,
+}
+
+impl Command {
+    /// Parses a command from a list of arguments.
+    ///
+    /// The arguments exclude the program name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    /// use noodles_cli::Command;
+    ///
+    /// let command = Command::parse(["index", "sample.bam"])?;
+    /// assert_eq!(command, Command::Index { src: PathBuf::from("sample.bam") });
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn parse<I, S>(args: I) -> io::Result<Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut args = args.into_iter().map(Into::into);
+
+        let name = args
+            .next()
+            .ok_or_else(|| invalid_input("missing command"))?;
+
+        if is_help(&name) {
+            return Ok(Self::Help);
+        }
+
+        let mut reference = None;
+        let mut positionals = Vec::new();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-r" | "--reference" => {
+                    let src = args
+                        .next()
+                        .ok_or_else(|| invalid_input("missing reference"))?;
+
+                    reference = Some(PathBuf::from(src));
+                }
+                s if is_help(s) => return Ok(Self::Help),
+                s if s.starts_with('-') && s.len() > 1 => {
+                    return Err(invalid_input(format!("invalid option: {s}")));
+                }
+                _ => positionals.push(arg),
+            }
+        }
+
+        let mut positionals = positionals.into_iter();
+
+        let mut next_path = |name: &str| {
+            positionals
+                .next()
+                .map(PathBuf::from)
+                .ok_or_else(|| invalid_input(format!("missing {name}")))
+        };
+
+        let command = match name.as_str() {
+            "view" => {
+                let src = next_path("src")?;
+
+                let region = positionals
+                    .next()
+                    .map(|s| {
+                        s.parse()
+                            .map_err(|e| invalid_input(format!("invalid region: {e}")))
+                    })
+                    .transpose()?;
+
+                Self::View {
+                    src,
+                    region,
+                    reference,
+                }
+            }
+            "sort" => Self::Sort {
+                src: next_path("src")?,
+                dst: next_path("dst")?,
+                reference,
+            },
+            "index" => Self::Index {
+                src: next_path("src")?,
+            },
+            "stats" => Self::Stats {
+                src: next_path("src")?,
+                reference,
+            },
+            "convert" => Self::Convert {
+                src: next_path("src")?,
+                dst: next_path("dst")?,
+                reference,
+            },
+            _ => return Err(invalid_input(format!("invalid command: {name}"))),
+        };
+
+        if let Some(arg) = positionals.next() {
+            return Err(invalid_input(format!("unexpected argument: {arg}")));
+        }
+
+        Ok(command)
+    }
+
+    /// Runs the command.
+    pub fn run(self) -> io::Result<()> {
+        match self {
+            Self::View {
+                src,
+                region,
+                reference,
+            } => {
+                let repository = build_repository(reference.as_deref())?;
+                let stdout = BufWriter::new(io::stdout().lock());
+                view::view(stdout, &src, region.as_ref(), repository)
+            }
+            Self::Sort {
+                src,
+                dst,
+                reference,
+            } => {
+                let repository = build_repository(reference.as_deref())?;
+                sort::sort(&src, &dst, repository)
+            }
+            Self::Index { src } => index::index(&src).map(|_| ()),
+            Self::Stats { src, reference } => {
+                let repository = build_repository(reference.as_deref())?;
+                let stdout = BufWriter::new(io::stdout().lock());
+                stats::stats(stdout, &src, repository)
+            }
+            Self::Convert {
+                src,
+                dst,
+                reference,
+            } => {
+                let repository = build_repository(reference.as_deref())?;
+                convert::convert(&src, &dst, repository)
+            }
+            Self::Help => writeln!(io::stdout().lock(), "{USAGE}"),
+        }
+    }
+
+    /// Returns the usage message.
+    pub fn usage() -> &'static str {
+        USAGE
+    }
+}
+
+fn is_help(arg: &str) -> bool {
+    matches!(arg, "-h" | "--help")
+}
+
+fn build_repository(src: Option<&Path>) -> io::Result<fasta::Repository> {
+    match src {
+        Some(src) => fasta::io::indexed_reader::Builder::default()
+            .build_from_path(src)
+            .map(IndexedReader::new)
+            .map(fasta::Repository::new),
+        None => Ok(fasta::Repository::default()),
+    }
+}
+
+fn invalid_input<S>(message: S) -> io::Error
+where
+    S: Into<String>,
+{
+    io::Error::new(io::ErrorKind::InvalidInput, message.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> io::Result<()> {
+        assert_eq!(
+            Command::parse(["view", "-r", "ref.fa", "sample.cram", "sq0:8-13"])?,
+            Command::View {
+                src: PathBuf::from("sample.cram"),
+                region: "sq0:8-13".parse().ok(),
+                reference: Some(PathBuf::from("ref.fa")),
+            }
+        );
+
+        assert_eq!(
+            Command::parse(["view", "sample.vcf.gz"])?,
+            Command::View {
+                src: PathBuf::from("sample.vcf.gz"),
+                region: None,
+                reference: None,
+            }
+        );
+
+        assert_eq!(
+            Command::parse(["sort", "in.bam", "out.bam"])?,
+            Command::Sort {
+                src: PathBuf::from("in.bam"),
+                dst: PathBuf::from("out.bam"),
+                reference: None,
+            }
+        );
+
+        assert_eq!(
+            Command::parse(["stats", "sample.bcf", "--reference", "ref.fa"])?,
+            Command::Stats {
+                src: PathBuf::from("sample.bcf"),
+                reference: Some(PathBuf::from("ref.fa")),
+            }
+        );
+
+        assert_eq!(
+            Command::parse(["convert", "in.bam", "out.cram"])?,
+            Command::Convert {
+                src: PathBuf::from("in.bam"),
+                dst: PathBuf::from("out.cram"),
+                reference: None,
+            }
+        );
+
+        assert_eq!(Command::parse(["--help"])?, Command::Help);
+        assert_eq!(Command::parse(["view", "-h", "in.bam"])?, Command::Help);
+
+        for args in [
+            &[][..],
+            &["merge"][..],
+            &["index"][..],
+            &["index", "a.bam", "b.bam"][..],
+            &["sort", "in.bam"][..],
+            &["view", "-x", "in.bam"][..],
+            &["view", "in.bam", "-r"][..],
+        ] {
+            assert!(matches!(
+                Command::parse(args.iter().copied()),
+                Err(e) if e.kind() == io::ErrorKind::InvalidInput
+            ));
+        }
+
+        Ok(())
+    }
+}
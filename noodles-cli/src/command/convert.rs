@@ -0,0 +1,58 @@
+use std::{io, path::Path};
+
+use noodles_fasta as fasta;
+use noodles_util::{alignment, variant};
+
+use crate::Kind;
+
+pub(super) fn convert(src: &Path, dst: &Path, repository: fasta::Repository) -> io::Result<()> {
+    let kind = Kind::from_path(src)?;
+
+    if Kind::from_path(dst)? != kind {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "input and output must be the same kind of file",
+        ));
+    }
+
+    match kind {
+        Kind::Alignment => convert_alignment(src, dst, repository),
+        Kind::Variant => convert_variant(src, dst),
+    }
+}
+
+fn convert_alignment(src: &Path, dst: &Path, repository: fasta::Repository) -> io::Result<()> {
+    let mut reader = alignment::io::reader::Builder::default()
+        .set_reference_sequence_repository(repository.clone())
+        .build_from_path(src)?;
+
+    let header = reader.read_header()?;
+
+    let mut writer = alignment::io::writer::Builder::default()
+        .set_reference_sequence_repository(repository)
+        .build_from_path(dst)?;
+
+    writer.write_header(&header)?;
+
+    for result in reader.records(&header) {
+        let record = result?;
+        writer.write_record(&header, record.as_ref())?;
+    }
+
+    writer.finish(&header)
+}
+
+fn convert_variant(src: &Path, dst: &Path) -> io::Result<()> {
+    let mut reader = variant::io::reader::Builder::default().build_from_path(src)?;
+    let header = reader.read_header()?;
+
+    let mut writer = variant::io::writer::Builder::default().build_from_path(dst)?;
+    writer.write_header(&header)?;
+
+    for result in reader.records(&header) {
+        let record = result?;
+        writer.write_record(&header, record.as_ref())?;
+    }
+
+    Ok(())
+}
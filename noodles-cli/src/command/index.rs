@@ -0,0 +1,158 @@
+use std::{
+    ffi::OsString,
+    fs::File,
+    io,
+    path::{Path, PathBuf},
+};
+
+use noodles_bam::{self as bam, bai};
+use noodles_bcf as bcf;
+use noodles_core::Position;
+use noodles_cram::{self as cram, crai};
+use noodles_csi::{self as csi, binning_index::index::reference_sequence::bin::Chunk};
+use noodles_sam::{
+    self as sam,
+    alignment::Record as _,
+    header::record::value::map::header::{sort_order, tag},
+};
+use noodles_tabix as tabix;
+use noodles_vcf::{self as vcf, variant::Record as _};
+
+// Writes an index to `<src>.<ext>` and returns the index path.
+pub(super) fn index(src: &Path) -> io::Result<PathBuf> {
+    let file_name = src.file_name().and_then(|s| s.to_str()).unwrap_or_default();
+
+    if file_name.ends_with(".bam") {
+        let dst = push_extension(src, "bai");
+        let index = index_bam(src)?;
+        bai::write(&dst, &index)?;
+        Ok(dst)
+    } else if file_name.ends_with(".cram") {
+        let dst = push_extension(src, "crai");
+        let index = cram::index(src)?;
+        crai::write(&dst, &index)?;
+        Ok(dst)
+    } else if file_name.ends_with(".vcf.gz") || file_name.ends_with(".vcf.bgz") {
+        let dst = push_extension(src, "tbi");
+        let index = vcf::index(src)?;
+        tabix::write(&dst, &index)?;
+        Ok(dst)
+    } else if file_name.ends_with(".bcf") {
+        let dst = push_extension(src, "csi");
+        let index = index_bcf(src)?;
+        csi::write(&dst, &index)?;
+        Ok(dst)
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "unsupported file type (expected BAM, CRAM, VCF.gz, or BCF): {}",
+                src.display()
+            ),
+        ))
+    }
+}
+
+fn push_extension(src: &Path, extension: &str) -> PathBuf {
+    let mut dst = OsString::from(src);
+    dst.push(".");
+    dst.push(extension);
+    PathBuf::from(dst)
+}
+
+fn index_bam(src: &Path) -> io::Result<bai::Index> {
+    let mut reader = File::open(src).map(bam::io::Reader::new)?;
+    let header = reader.read_header()?;
+
+    if !is_coordinate_sorted(&header) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "the input BAM must be coordinate-sorted to be indexed",
+        ));
+    }
+
+    let mut record = bam::Record::default();
+
+    let mut indexer = csi::binning_index::Indexer::default();
+    let mut start_position = reader.get_ref().virtual_position();
+
+    while reader.read_record(&mut record)? != 0 {
+        let end_position = reader.get_ref().virtual_position();
+        let chunk = Chunk::new(start_position, end_position);
+
+        let alignment_context = match (
+            record.reference_sequence_id().transpose()?,
+            record.alignment_start().transpose()?,
+            record.alignment_end().transpose()?,
+        ) {
+            (Some(id), Some(start), Some(end)) => {
+                let is_mapped = !record.flags().is_unmapped();
+                Some((id, start, end, is_mapped))
+            }
+            _ => None,
+        };
+
+        indexer.add_record(alignment_context, chunk)?;
+
+        start_position = end_position;
+    }
+
+    Ok(indexer.build(header.reference_sequences().len()))
+}
+
+fn is_coordinate_sorted(header: &sam::Header) -> bool {
+    header
+        .header()
+        .and_then(|hdr| hdr.other_fields().get(&tag::SORT_ORDER))
+        .map(|sort_order| sort_order == sort_order::COORDINATE)
+        .unwrap_or_default()
+}
+
+fn index_bcf(src: &Path) -> io::Result<csi::Index> {
+    let mut reader = File::open(src).map(bcf::io::Reader::new)?;
+    let header = reader.read_header()?;
+
+    let mut record = bcf::Record::default();
+
+    let mut indexer = csi::binning_index::Indexer::default();
+    let mut start_position = reader.get_ref().virtual_position();
+
+    while reader.read_record(&mut record)? != 0 {
+        let end_position = reader.get_ref().virtual_position();
+        let chunk = Chunk::new(start_position, end_position);
+
+        let reference_sequence_id = record.reference_sequence_id()?;
+        let start = record
+            .variant_start()
+            .transpose()?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing position"))?;
+        let end: Position = record.variant_end(&header)?;
+
+        indexer.add_record(Some((reference_sequence_id, start, end, true)), chunk)?;
+
+        start_position = end_position;
+    }
+
+    Ok(indexer.build(header.contigs().len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_extension() {
+        assert_eq!(
+            push_extension(Path::new("sample.bam"), "bai"),
+            PathBuf::from("sample.bam.bai")
+        );
+    }
+
+    #[test]
+    fn test_index_with_unsupported_file_type() {
+        assert!(matches!(
+            index(Path::new("sample.sam")),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+    }
+}
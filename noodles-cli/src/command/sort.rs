@@ -0,0 +1,216 @@
+use std::{cmp::Ordering, io, path::Path};
+
+use noodles_fasta as fasta;
+use noodles_sam::{
+    self as sam,
+    alignment::cmp,
+    header::record::value::{
+        map::{self, header::SortOrder},
+        Map,
+    },
+};
+use noodles_util::{alignment, variant};
+use noodles_vcf as vcf;
+
+use crate::Kind;
+
+// Records are sorted in memory.
+pub(super) fn sort(src: &Path, dst: &Path, repository: fasta::Repository) -> io::Result<()> {
+    match Kind::from_path(src)? {
+        Kind::Alignment => sort_alignment(src, dst, repository),
+        Kind::Variant => sort_variant(src, dst),
+    }
+}
+
+fn sort_alignment(src: &Path, dst: &Path, repository: fasta::Repository) -> io::Result<()> {
+    let mut reader = alignment::io::reader::Builder::default()
+        .set_reference_sequence_repository(repository.clone())
+        .build_from_path(src)?;
+
+    let mut header = reader.read_header()?;
+
+    let mut records = reader
+        .records(&header)
+        .map(|result| {
+            result.and_then(|record| {
+                sam::alignment::RecordBuf::try_from_alignment_record(&header, &record)
+            })
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+
+    sort_alignment_records(&header, &mut records)?;
+
+    header
+        .header_mut()
+        .get_or_insert_with(Map::<map::Header>::default)
+        .set_sort_order(SortOrder::Coordinate);
+
+    let mut writer = alignment::io::writer::Builder::default()
+        .set_reference_sequence_repository(repository)
+        .build_from_path(dst)?;
+
+    writer.write_header(&header)?;
+
+    for record in &records {
+        writer.write_record(&header, record)?;
+    }
+
+    writer.finish(&header)
+}
+
+// Records are sorted by coordinate (see `cmp::coordinate`). The sort is stable.
+fn sort_alignment_records(
+    header: &sam::Header,
+    records: &mut [sam::alignment::RecordBuf],
+) -> io::Result<()> {
+    let mut result = Ok(());
+
+    records.sort_by(|a, b| {
+        cmp::coordinate(a, b, header).unwrap_or_else(|e| {
+            if result.is_ok() {
+                result = Err(e);
+            }
+
+            Ordering::Equal
+        })
+    });
+
+    result
+}
+
+fn sort_variant(src: &Path, dst: &Path) -> io::Result<()> {
+    let mut reader = variant::io::reader::Builder::default().build_from_path(src)?;
+    let header = reader.read_header()?;
+
+    let mut records = reader
+        .records(&header)
+        .map(|result| {
+            result.and_then(|record| {
+                vcf::variant::RecordBuf::try_from_variant_record(&header, &record)
+            })
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+
+    sort_variant_records(&header, &mut records);
+
+    let mut writer = variant::io::writer::Builder::default().build_from_path(dst)?;
+    writer.write_header(&header)?;
+
+    for record in &records {
+        writer.write_record(&header, record)?;
+    }
+
+    writer.finish(&header)
+}
+
+// Records are sorted by contig (in header order) and position. Records on contigs not in the
+// header are last. The sort is stable.
+fn sort_variant_records(header: &vcf::Header, records: &mut [vcf::variant::RecordBuf]) {
+    records.sort_by_key(|record| {
+        (
+            header
+                .contigs()
+                .get_index_of(record.reference_sequence_name())
+                .unwrap_or(usize::MAX),
+            record.variant_start(),
+        )
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+    use noodles_sam::alignment::record::Flags;
+    use noodles_vcf::header::record::value::{map::Contig, Map as VcfMap};
+
+    use super::*;
+
+    #[test]
+    fn test_sort_alignment_records() -> Result<(), Box<dyn std::error::Error>> {
+        fn build(
+            reference_sequence_id: Option<usize>,
+            alignment_start: Option<usize>,
+            name: &str,
+        ) -> Result<sam::alignment::RecordBuf, noodles_core::position::TryFromIntError> {
+            let mut builder = sam::alignment::RecordBuf::builder().set_name(name);
+
+            if let Some(id) = reference_sequence_id {
+                builder = builder.set_reference_sequence_id(id);
+            }
+
+            if let Some(start) = alignment_start {
+                builder = builder.set_alignment_start(Position::try_from(start)?);
+            } else {
+                builder = builder.set_flags(Flags::UNMAPPED);
+            }
+
+            Ok(builder.build())
+        }
+
+        let mut records = [
+            build(None, None, "r0")?,
+            build(Some(1), Some(5), "r1")?,
+            build(Some(0), Some(13), "r2")?,
+            build(Some(0), Some(8), "r3")?,
+            build(Some(0), None, "r4")?,
+        ];
+
+        sort_alignment_records(&sam::Header::default(), &mut records)?;
+
+        let names: Vec<_> = records
+            .iter()
+            .filter_map(|record| record.name())
+            .map(|name| name.to_string())
+            .collect();
+
+        assert_eq!(names, ["r3", "r2", "r4", "r1", "r0"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_variant_records() -> Result<(), noodles_core::position::TryFromIntError> {
+        let header = vcf::Header::builder()
+            .add_contig("sq1", VcfMap::<Contig>::new())
+            .add_contig("sq0", VcfMap::<Contig>::new())
+            .build();
+
+        let build = |name: &str, start| -> Result<_, noodles_core::position::TryFromIntError> {
+            Ok(vcf::variant::RecordBuf::builder()
+                .set_reference_sequence_name(name)
+                .set_variant_start(Position::try_from(start)?)
+                .build())
+        };
+
+        let mut records = [
+            build("sq2", 1)?,
+            build("sq0", 8)?,
+            build("sq1", 13)?,
+            build("sq0", 5)?,
+        ];
+
+        sort_variant_records(&header, &mut records);
+
+        let actual: Vec<_> = records
+            .iter()
+            .map(|record| {
+                (
+                    record.reference_sequence_name(),
+                    record.variant_start().map(usize::from),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            actual,
+            [
+                ("sq1", Some(13)),
+                ("sq0", Some(5)),
+                ("sq0", Some(8)),
+                ("sq2", Some(1)),
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,220 @@
+use std::{
+    fmt,
+    io::{self, Write},
+    path::Path,
+};
+
+use noodles_fasta as fasta;
+use noodles_sam::alignment::record::Flags;
+use noodles_util::{alignment, variant};
+use noodles_vcf as vcf;
+
+use crate::Kind;
+
+pub(super) fn stats<W>(mut writer: W, src: &Path, repository: fasta::Repository) -> io::Result<()>
+where
+    W: Write,
+{
+    match Kind::from_path(src)? {
+        Kind::Alignment => {
+            let mut reader = alignment::io::reader::Builder::default()
+                .set_reference_sequence_repository(repository)
+                .build_from_path(src)?;
+
+            let header = reader.read_header()?;
+            let mut stats = AlignmentStats::default();
+
+            for result in reader.records(&header) {
+                let record = result?;
+                stats.add(record.flags()?);
+            }
+
+            write!(writer, "{stats}")?;
+        }
+        Kind::Variant => {
+            let mut reader = variant::io::reader::Builder::default().build_from_path(src)?;
+            let header = reader.read_header()?;
+            let mut stats = VariantStats::default();
+
+            for result in reader.records(&header) {
+                let record = result?;
+                let record = vcf::variant::RecordBuf::try_from_variant_record(&header, &record)?;
+                stats.add(&record);
+            }
+
+            write!(writer, "{stats}")?;
+        }
+    }
+
+    writer.flush()
+}
+
+// Alignment record counts by flags.
+#[derive(Debug, Default, Eq, PartialEq)]
+struct AlignmentStats {
+    records: u64,
+    mapped: u64,
+    unmapped: u64,
+    secondary: u64,
+    supplementary: u64,
+    duplicate: u64,
+    qc_fail: u64,
+    paired: u64,
+    properly_paired: u64,
+}
+
+impl AlignmentStats {
+    fn add(&mut self, flags: Flags) {
+        self.records += 1;
+
+        if flags.is_unmapped() {
+            self.unmapped += 1;
+        } else {
+            self.mapped += 1;
+        }
+
+        self.secondary += u64::from(flags.is_secondary());
+        self.supplementary += u64::from(flags.is_supplementary());
+        self.duplicate += u64::from(flags.is_duplicate());
+        self.qc_fail += u64::from(flags.is_qc_fail());
+        self.paired += u64::from(flags.is_segmented());
+        self.properly_paired += u64::from(flags.is_segmented() && flags.is_properly_segmented());
+    }
+}
+
+impl fmt::Display for AlignmentStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "records\t{}", self.records)?;
+        writeln!(f, "mapped\t{}", self.mapped)?;
+        writeln!(f, "unmapped\t{}", self.unmapped)?;
+        writeln!(f, "secondary\t{}", self.secondary)?;
+        writeln!(f, "supplementary\t{}", self.supplementary)?;
+        writeln!(f, "duplicate\t{}", self.duplicate)?;
+        writeln!(f, "qc_fail\t{}", self.qc_fail)?;
+        writeln!(f, "paired\t{}", self.paired)?;
+        writeln!(f, "properly_paired\t{}", self.properly_paired)
+    }
+}
+
+// Variant record counts and alternate allele counts by type.
+#[derive(Debug, Default, Eq, PartialEq)]
+struct VariantStats {
+    records: u64,
+    pass: u64,
+    snvs: u64,
+    mnvs: u64,
+    indels: u64,
+    others: u64,
+}
+
+impl VariantStats {
+    fn add(&mut self, record: &vcf::variant::RecordBuf) {
+        self.records += 1;
+        self.pass += u64::from(record.filters().is_pass());
+
+        let reference_bases_len = record.reference_bases().len();
+
+        for allele in record.alternate_bases().as_ref() {
+            if allele.starts_with('<') || allele.contains(['[', ']', '*', '.']) {
+                self.others += 1;
+            } else if allele.len() != reference_bases_len {
+                self.indels += 1;
+            } else if allele.len() == 1 {
+                self.snvs += 1;
+            } else {
+                self.mnvs += 1;
+            }
+        }
+    }
+}
+
+impl fmt::Display for VariantStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "records\t{}", self.records)?;
+        writeln!(f, "pass\t{}", self.pass)?;
+        writeln!(f, "snvs\t{}", self.snvs)?;
+        writeln!(f, "mnvs\t{}", self.mnvs)?;
+        writeln!(f, "indels\t{}", self.indels)?;
+        writeln!(f, "others\t{}", self.others)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_vcf::variant::record_buf::{AlternateBases, Filters};
+
+    use super::*;
+
+    #[test]
+    fn test_alignment_stats_add() {
+        let mut stats = AlignmentStats::default();
+
+        stats.add(Flags::SEGMENTED | Flags::PROPERLY_SEGMENTED | Flags::DUPLICATE);
+        stats.add(Flags::UNMAPPED | Flags::QC_FAIL);
+        stats.add(Flags::SUPPLEMENTARY);
+
+        assert_eq!(
+            stats,
+            AlignmentStats {
+                records: 3,
+                mapped: 2,
+                unmapped: 1,
+                secondary: 0,
+                supplementary: 1,
+                duplicate: 1,
+                qc_fail: 1,
+                paired: 1,
+                properly_paired: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_variant_stats_add() {
+        let mut stats = VariantStats::default();
+
+        let record = vcf::variant::RecordBuf::builder()
+            .set_reference_bases("AC")
+            .set_alternate_bases(AlternateBases::from(vec![
+                String::from("GT"),
+                String::from("A"),
+                String::from("<DEL>"),
+            ]))
+            .set_filters(Filters::pass())
+            .build();
+
+        stats.add(&record);
+
+        let record = vcf::variant::RecordBuf::builder()
+            .set_reference_bases("A")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("T")]))
+            .build();
+
+        stats.add(&record);
+
+        assert_eq!(
+            stats,
+            VariantStats {
+                records: 2,
+                pass: 1,
+                snvs: 1,
+                mnvs: 1,
+                indels: 1,
+                others: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_fmt() {
+        let stats = VariantStats {
+            records: 2,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            stats.to_string(),
+            "records\t2\npass\t0\nsnvs\t0\nmnvs\t0\nindels\t0\nothers\t0\n"
+        );
+    }
+}
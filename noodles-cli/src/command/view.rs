@@ -0,0 +1,95 @@
+use std::{io::Write, path::Path};
+
+use noodles_core::Region;
+use noodles_fasta as fasta;
+use noodles_sam as sam;
+use noodles_util::{alignment, variant};
+use noodles_vcf::{self as vcf, variant::io::Write as _};
+
+use crate::Kind;
+
+pub(super) fn view<W>(
+    writer: W,
+    src: &Path,
+    region: Option<&Region>,
+    repository: fasta::Repository,
+) -> std::io::Result<()>
+where
+    W: Write,
+{
+    match Kind::from_path(src)? {
+        Kind::Alignment => view_alignment(writer, src, region, repository),
+        Kind::Variant => view_variant(writer, src, region),
+    }
+}
+
+fn view_alignment<W>(
+    writer: W,
+    src: &Path,
+    region: Option<&Region>,
+    repository: fasta::Repository,
+) -> std::io::Result<()>
+where
+    W: Write,
+{
+    let mut writer = sam::io::Writer::new(writer);
+
+    if let Some(region) = region {
+        let mut reader = alignment::io::indexed_reader::Builder::default()
+            .set_reference_sequence_repository(repository)
+            .build_from_path(src)?;
+
+        let header = reader.read_header()?;
+        writer.write_header(&header)?;
+
+        for result in reader.query(&header, region)? {
+            let record = result?;
+            writer.write_alignment_record(&header, &record)?;
+        }
+    } else {
+        let mut reader = alignment::io::reader::Builder::default()
+            .set_reference_sequence_repository(repository)
+            .build_from_path(src)?;
+
+        let header = reader.read_header()?;
+        writer.write_header(&header)?;
+
+        for result in reader.records(&header) {
+            let record = result?;
+            writer.write_alignment_record(&header, &record)?;
+        }
+    }
+
+    writer.get_mut().flush()
+}
+
+fn view_variant<W>(writer: W, src: &Path, region: Option<&Region>) -> std::io::Result<()>
+where
+    W: Write,
+{
+    let mut writer = vcf::io::Writer::new(writer);
+
+    if let Some(region) = region {
+        let mut reader = variant::io::indexed_reader::Builder::default().build_from_path(src)?;
+
+        let header = reader.read_header()?;
+        writer.write_header(&header)?;
+
+        for result in reader.query(&header, region)? {
+            let record = result?;
+            writer.write_variant_record(&header, record.as_ref())?;
+        }
+    } else {
+        let mut reader = variant::io::reader::Builder::default().build_from_path(src)?;
+
+        let header = reader.read_header()?;
+        writer.write_header(&header)?;
+
+        for result in reader.records(&header) {
+            let record = result?;
+            writer.write_variant_record(&header, record.as_ref())?;
+        }
+    }
+
+    writer.get_mut().flush()
+}
@@ -0,0 +1,46 @@
+use std::{ffi::OsStr, io, path::Path};
+
+/// A kind of input or output file.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Kind {
+    /// An alignment file (SAM, BAM, or CRAM).
+    Alignment,
+    /// A variant file (VCF or BCF).
+    Variant,
+}
+
+impl Kind {
+    /// Determines the kind of file from the path extension.
+    ///
+    /// A trailing compression extension (`.gz` or `.bgz`) is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cli::Kind;
+    /// assert_eq!(Kind::from_path("sample.bam")?, Kind::Alignment);
+    /// assert_eq!(Kind::from_path("sample.vcf.gz")?, Kind::Variant);
+    /// assert!(Kind::from_path("sample.fa").is_err());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn from_path<P>(src: P) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let src = src.as_ref();
+
+        let path = match src.extension().and_then(OsStr::to_str) {
+            Some("gz" | "bgz") => src.file_stem().map(Path::new).unwrap_or(src),
+            _ => src,
+        };
+
+        match path.extension().and_then(OsStr::to_str) {
+            Some("sam" | "bam" | "cram") => Ok(Self::Alignment),
+            Some("vcf" | "bcf") => Ok(Self::Variant),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("unsupported file type: {}", src.display()),
+            )),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+#![warn(missing_docs)]
+
+//! **noodles-cli** is a command-line interface for common operations on alignment (SAM, BAM, and
+//! CRAM) and variant (VCF and BCF) files.
+//!
+//! It is built on the public APIs of noodles-util and the format crates and provides a small,
+//! pure Rust subset of samtools and bcftools. The `noodles` binary is enabled by the `cli`
+//! feature.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use noodles_cli::Command;
+//!
+//! let command = Command::parse(["view", "sample.bam", "sq0:8-13"])?;
+//! command.run()?;
+//! # Ok::<_, std::io::Error>(())
+//! ```
+
+mod command;
+mod kind;
+
+pub use self::{command::Command, kind::Kind};
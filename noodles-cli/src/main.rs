@@ -0,0 +1,17 @@
+use std::{env, process};
+
+use noodles_cli::Command;
+
+fn main() {
+    let result = Command::parse(env::args().skip(1)).and_then(|command| command.run());
+
+    if let Err(e) = result {
+        eprintln!("noodles: {e}");
+
+        if e.kind() == std::io::ErrorKind::InvalidInput {
+            eprintln!("\n{}", Command::usage());
+        }
+
+        process::exit(1);
+    }
+}
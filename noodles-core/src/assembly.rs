@@ -0,0 +1,441 @@
+//! Genome assembly.
+
+use std::{error, fmt, str::FromStr};
+
+const PATCH_DELIMITER: &str = ".p";
+
+/// A genome assembly (build) identifier.
+///
+/// An assembly is identified by a name, e.g., `GRCh38`, and an optional patch level, e.g.,
+/// `GRCh38.p14`. Patches do not change the primary assembly, so assemblies that only differ by
+/// patch level are compatible (see [`Self::is_compatible`]).
+///
+/// Common UCSC aliases are recognized as their Genome Reference Consortium counterparts, e.g.,
+/// `hg38` is compatible with `GRCh38`.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Assembly;
+///
+/// let assembly: Assembly = "GRCh38.p14".parse()?;
+/// assert_eq!(assembly.name(), "GRCh38");
+/// assert_eq!(assembly.patch(), Some(14));
+///
+/// assert!(assembly.is_compatible(&"hg38".parse()?));
+/// assert!(!assembly.is_compatible(&"GRCh37".parse()?));
+/// # Ok::<_, noodles_core::assembly::ParseError>(())
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Assembly {
+    name: String,
+    patch: Option<u32>,
+}
+
+impl Assembly {
+    /// Creates a genome assembly identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Assembly;
+    /// let assembly = Assembly::new("GRCh38", Some(14));
+    /// assert_eq!(assembly.to_string(), "GRCh38.p14");
+    /// ```
+    pub fn new<N>(name: N, patch: Option<u32>) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            patch,
+        }
+    }
+
+    /// Returns the assembly name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Assembly;
+    /// let assembly = Assembly::new("GRCh38", None);
+    /// assert_eq!(assembly.name(), "GRCh38");
+    /// ```
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the patch level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Assembly;
+    /// let assembly = Assembly::new("GRCh38", Some(14));
+    /// assert_eq!(assembly.patch(), Some(14));
+    /// ```
+    pub fn patch(&self) -> Option<u32> {
+        self.patch
+    }
+
+    /// Returns whether the given assembly has the same primary assembly.
+    ///
+    /// Names are compared case-insensitively after resolving aliases, and patch levels are
+    /// ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Assembly;
+    ///
+    /// let assembly = Assembly::new("GRCh38", Some(14));
+    ///
+    /// assert!(assembly.is_compatible(&Assembly::new("GRCh38", None)));
+    /// assert!(assembly.is_compatible(&Assembly::new("hg38", None)));
+    /// assert!(!assembly.is_compatible(&Assembly::new("GRCh37", Some(13))));
+    /// ```
+    pub fn is_compatible(&self, other: &Self) -> bool {
+        canonical_name(&self.name).eq_ignore_ascii_case(canonical_name(&other.name))
+    }
+
+    /// Checks whether the given assembly has the same primary assembly.
+    ///
+    /// This is the same as [`Self::is_compatible`] but returns an error on a mismatch, e.g., to
+    /// refuse operations across files with different assemblies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Assembly;
+    ///
+    /// let assembly = Assembly::new("GRCh38", None);
+    ///
+    /// assert!(assembly.check_compatible(&Assembly::new("hg38", None)).is_ok());
+    /// assert!(assembly.check_compatible(&Assembly::new("GRCh37", None)).is_err());
+    /// ```
+    pub fn check_compatible(&self, other: &Self) -> Result<(), MismatchError> {
+        if self.is_compatible(other) {
+            Ok(())
+        } else {
+            Err(MismatchError {
+                expected: self.clone(),
+                actual: other.clone(),
+            })
+        }
+    }
+
+    /// Infers the assembly from a set of reference sequences.
+    ///
+    /// Each reference sequence is given as its name, length, and optional MD5 checksum
+    /// (lowercase hexadecimal). These are compared to the primary chromosomes of known
+    /// assemblies: GRCh37, GRCh38, T2T-CHM13v2.0, GRCm38, and GRCm39. Names with and without a
+    /// `chr` prefix are recognized.
+    ///
+    /// This returns `None` if no known assembly matches, e.g., no known chromosomes are present,
+    /// or if the reference sequences conflict with every matching assembly. The patch level is
+    /// not inferred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Assembly;
+    ///
+    /// let reference_sequences = [
+    ///     (&b"chr1"[..], 248956422, None),
+    ///     (&b"chr2"[..], 242193529, None),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     Assembly::infer(reference_sequences),
+    ///     Some(Assembly::new("GRCh38", None))
+    /// );
+    ///
+    /// assert!(Assembly::infer([(&b"sq0"[..], 8, None)]).is_none());
+    /// ```
+    pub fn infer<'a, I>(reference_sequences: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = (&'a [u8], usize, Option<&'a [u8]>)>,
+    {
+        let mut matches = [0; KNOWN_ASSEMBLIES.len()];
+        let mut conflicts = [false; KNOWN_ASSEMBLIES.len()];
+
+        for (name, length, md5) in reference_sequences {
+            let Some(chromosome) = chromosome_name(name) else {
+                continue;
+            };
+
+            for (i, known_assembly) in KNOWN_ASSEMBLIES.iter().enumerate() {
+                let Some(signature) = known_assembly
+                    .chromosomes
+                    .iter()
+                    .find(|signature| signature.name.as_bytes() == chromosome)
+                else {
+                    continue;
+                };
+
+                let is_length_match = signature.length == length;
+
+                let is_md5_match = match (signature.md5, md5) {
+                    (Some(expected), Some(actual)) => {
+                        expected.as_bytes().eq_ignore_ascii_case(actual)
+                    }
+                    _ => true,
+                };
+
+                if is_length_match && is_md5_match {
+                    matches[i] += 1;
+                } else {
+                    conflicts[i] = true;
+                }
+            }
+        }
+
+        let mut candidates = KNOWN_ASSEMBLIES
+            .iter()
+            .zip(matches.iter().zip(&conflicts))
+            .filter(|(_, (&n, &is_conflict))| n > 0 && !is_conflict)
+            .map(|(known_assembly, _)| known_assembly.name);
+
+        match (candidates.next(), candidates.next()) {
+            (Some(name), None) => Some(Self::new(name, None)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Assembly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)?;
+
+        if let Some(patch) = self.patch {
+            write!(f, "{PATCH_DELIMITER}{patch}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned when a genome assembly fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The input is invalid.
+    Invalid,
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::Invalid => f.write_str("invalid input"),
+        }
+    }
+}
+
+impl FromStr for Assembly {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        } else if s.chars().any(|c| c.is_whitespace()) {
+            return Err(ParseError::Invalid);
+        }
+
+        if let Some((name, raw_patch)) = s.rsplit_once(PATCH_DELIMITER) {
+            if !name.is_empty()
+                && !raw_patch.is_empty()
+                && raw_patch.bytes().all(|b| b.is_ascii_digit())
+            {
+                let patch = raw_patch.parse().map_err(|_| ParseError::Invalid)?;
+                return Ok(Self::new(name, Some(patch)));
+            }
+        }
+
+        Ok(Self::new(s, None))
+    }
+}
+
+/// An error returned when two genome assemblies are incompatible.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MismatchError {
+    expected: Assembly,
+    actual: Assembly,
+}
+
+impl MismatchError {
+    /// Returns the expected assembly.
+    pub fn expected(&self) -> &Assembly {
+        &self.expected
+    }
+
+    /// Returns the actual assembly.
+    pub fn actual(&self) -> &Assembly {
+        &self.actual
+    }
+}
+
+impl error::Error for MismatchError {}
+
+impl fmt::Display for MismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "assembly mismatch: expected {}, got {}",
+            self.expected, self.actual
+        )
+    }
+}
+
+struct KnownAssembly {
+    name: &'static str,
+    chromosomes: &'static [Signature],
+}
+
+struct Signature {
+    name: &'static str,
+    length: usize,
+    md5: Option<&'static str>,
+}
+
+const fn signature(name: &'static str, length: usize, md5: Option<&'static str>) -> Signature {
+    Signature { name, length, md5 }
+}
+
+static KNOWN_ASSEMBLIES: [KnownAssembly; 5] = [
+    KnownAssembly {
+        name: "GRCh37",
+        chromosomes: &[
+            signature("1", 249250621, Some("1b22b98cdeb4a9304cb5d48026a85128")),
+            signature("2", 243199373, None),
+            signature("X", 155270560, None),
+            signature("Y", 59373566, None),
+        ],
+    },
+    KnownAssembly {
+        name: "GRCh38",
+        chromosomes: &[
+            signature("1", 248956422, Some("6aef897c3d6ff0c78aff06ac189178dd")),
+            signature("2", 242193529, None),
+            signature("X", 156040895, None),
+            signature("Y", 57227415, None),
+        ],
+    },
+    KnownAssembly {
+        name: "T2T-CHM13v2.0",
+        chromosomes: &[
+            signature("1", 248387328, None),
+            signature("2", 242696752, None),
+            signature("X", 154259566, None),
+        ],
+    },
+    KnownAssembly {
+        name: "GRCm38",
+        chromosomes: &[
+            signature("1", 195471971, None),
+            signature("2", 182113224, None),
+            signature("X", 171031299, None),
+        ],
+    },
+    KnownAssembly {
+        name: "GRCm39",
+        chromosomes: &[
+            signature("1", 195154279, None),
+            signature("2", 181755017, None),
+            signature("X", 169476592, None),
+        ],
+    },
+];
+
+fn canonical_name(name: &str) -> &str {
+    const ALIASES: [(&str, &str); 6] = [
+        ("hg19", "GRCh37"),
+        ("hg38", "GRCh38"),
+        ("hs1", "T2T-CHM13v2.0"),
+        ("CHM13v2.0", "T2T-CHM13v2.0"),
+        ("mm10", "GRCm38"),
+        ("mm39", "GRCm39"),
+    ];
+
+    ALIASES
+        .iter()
+        .find(|(alias, _)| alias.eq_ignore_ascii_case(name))
+        .map(|(_, canonical_name)| *canonical_name)
+        .unwrap_or(name)
+}
+
+fn chromosome_name(name: &[u8]) -> Option<&[u8]> {
+    const PREFIX: &[u8] = b"chr";
+
+    let name = match name.get(..PREFIX.len()) {
+        Some(prefix) if prefix.eq_ignore_ascii_case(PREFIX) => &name[PREFIX.len()..],
+        _ => name,
+    };
+
+    (!name.is_empty()).then_some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Assembly::new("GRCh38", None).to_string(), "GRCh38");
+        assert_eq!(Assembly::new("GRCh38", Some(14)).to_string(), "GRCh38.p14");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("GRCh38".parse(), Ok(Assembly::new("GRCh38", None)));
+        assert_eq!("GRCh38.p14".parse(), Ok(Assembly::new("GRCh38", Some(14))));
+        assert_eq!(
+            "T2T-CHM13v2.0".parse(),
+            Ok(Assembly::new("T2T-CHM13v2.0", None))
+        );
+        assert_eq!("GRCh38.p".parse(), Ok(Assembly::new("GRCh38.p", None)));
+
+        assert_eq!("".parse::<Assembly>(), Err(ParseError::Empty));
+        assert_eq!("GRC h38".parse::<Assembly>(), Err(ParseError::Invalid));
+        assert_eq!(
+            "GRCh38.p99999999999".parse::<Assembly>(),
+            Err(ParseError::Invalid)
+        );
+    }
+
+    #[test]
+    fn test_infer() {
+        const GRCH37_CHR1_MD5: &[u8] = b"1b22b98cdeb4a9304cb5d48026a85128";
+
+        let grch37 = Some(Assembly::new("GRCh37", None));
+        let grch38 = Some(Assembly::new("GRCh38", None));
+
+        assert_eq!(
+            Assembly::infer([
+                (&b"1"[..], 249250621, Some(GRCH37_CHR1_MD5)),
+                (&b"2"[..], 243199373, None),
+                (&b"MT"[..], 16569, None),
+                (&b"GL000192.1"[..], 547496, None),
+            ]),
+            grch37
+        );
+
+        assert_eq!(Assembly::infer([(&b"CHR1"[..], 248956422, None)]), grch38);
+
+        // MD5 checksum conflict
+        assert!(Assembly::infer([(&b"chr1"[..], 249250621, Some(&b"00"[..]))]).is_none());
+
+        // mixed assemblies
+        assert!(Assembly::infer([
+            (&b"chr1"[..], 248956422, None),
+            (&b"chr2"[..], 243199373, None),
+        ])
+        .is_none());
+
+        assert!(Assembly::infer([]).is_none());
+        assert!(Assembly::infer([(&b"chr"[..], 248956422, None)]).is_none());
+    }
+}
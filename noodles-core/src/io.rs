@@ -0,0 +1,5 @@
+//! I/O adapters.
+
+mod range_reader;
+
+pub use self::range_reader::{RangeRead, RangeReader};
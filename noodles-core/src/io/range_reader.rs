@@ -0,0 +1,324 @@
+use std::io::{self, BufRead, Read, Seek, SeekFrom};
+
+const DEFAULT_CAPACITY: usize = 1 << 16;
+
+/// A source that reads byte ranges at arbitrary offsets.
+///
+/// This is the minimal interface for random access sources that are not seekable streams, e.g.,
+/// an object store or an HTTP server that supports range requests. Wrap an implementation in a
+/// [`RangeReader`] to use it as a [`Read`] + [`Seek`] source, e.g., for an indexed reader.
+pub trait RangeRead {
+    /// Reads bytes starting at the given offset into the given buffer.
+    ///
+    /// This returns the number of bytes read. A return value of 0 indicates the offset is at or
+    /// past the end of the source.
+    fn read_range(&mut self, offset: u64, buf: &mut [u8]) -> io::Result<usize>;
+
+    /// Returns the size of the source in bytes.
+    fn size(&mut self) -> io::Result<u64>;
+}
+
+/// A buffered reader over a [`RangeRead`] source.
+///
+/// Each buffer refill is a single call to [`RangeRead::read_range`]. Seeking within the current
+/// buffer does not make a new request.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Read, Seek, SeekFrom};
+/// use noodles_core::io::{RangeRead, RangeReader};
+///
+/// struct Source(Vec<u8>);
+///
+/// impl RangeRead for Source {
+///     fn read_range(&mut self, offset: u64, buf: &mut [u8]) -> io::Result<usize> {
+///         let src = self.0.get(offset as usize..).unwrap_or_default();
+///         let n = src.len().min(buf.len());
+///         buf[..n].copy_from_slice(&src[..n]);
+///         Ok(n)
+///     }
+///
+///     fn size(&mut self) -> io::Result<u64> {
+///         Ok(self.0.len() as u64)
+///     }
+/// }
+///
+/// let mut reader = RangeReader::new(Source(b"noodles".to_vec()));
+/// reader.seek(SeekFrom::Start(2))?;
+///
+/// let mut buf = String::new();
+/// reader.read_to_string(&mut buf)?;
+/// assert_eq!(buf, "odles");
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct RangeReader<R> {
+    inner: R,
+    buf: Vec<u8>,
+    buf_position: u64,
+    consumed: usize,
+    capacity: usize,
+}
+
+impl<R> RangeReader<R>
+where
+    R: RangeRead,
+{
+    /// Creates a range reader with a default buffer capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::io::RangeReader;
+    /// let reader = RangeReader::new(&b"noodles"[..]);
+    /// ```
+    pub fn new(inner: R) -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY, inner)
+    }
+
+    /// Creates a range reader with the given buffer capacity.
+    ///
+    /// The capacity is the number of bytes requested per range read. It is clamped to at least 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::io::RangeReader;
+    /// let reader = RangeReader::with_capacity(1 << 20, &b"noodles"[..]);
+    /// ```
+    pub fn with_capacity(capacity: usize, inner: R) -> Self {
+        Self {
+            inner,
+            buf: Vec::new(),
+            buf_position: 0,
+            consumed: 0,
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Returns a reference to the underlying source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::io::RangeReader;
+    /// let reader = RangeReader::new(&b"noodles"[..]);
+    /// assert_eq!(reader.get_ref(), b"noodles");
+    /// ```
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::io::RangeReader;
+    /// let mut reader = RangeReader::new(&b"noodles"[..]);
+    /// let _inner = reader.get_mut();
+    /// ```
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the underlying source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::io::RangeReader;
+    /// let reader = RangeReader::new(&b"noodles"[..]);
+    /// assert_eq!(reader.into_inner(), b"noodles");
+    /// ```
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Returns the current position of the reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::io::RangeReader;
+    /// let reader = RangeReader::new(&b"noodles"[..]);
+    /// assert_eq!(reader.position(), 0);
+    /// ```
+    pub fn position(&self) -> u64 {
+        self.buf_position + self.consumed as u64
+    }
+
+    fn set_position(&mut self, position: u64) {
+        let buf_end = self.buf_position + self.buf.len() as u64;
+
+        if (self.buf_position..=buf_end).contains(&position) {
+            self.consumed = (position - self.buf_position) as usize;
+        } else {
+            self.buf.clear();
+            self.buf_position = position;
+            self.consumed = 0;
+        }
+    }
+}
+
+impl<R> Read for RangeReader<R>
+where
+    R: RangeRead,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let src = self.fill_buf()?;
+        let amt = src.len().min(buf.len());
+        buf[..amt].copy_from_slice(&src[..amt]);
+        self.consume(amt);
+        Ok(amt)
+    }
+}
+
+impl<R> BufRead for RangeReader<R>
+where
+    R: RangeRead,
+{
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.consumed >= self.buf.len() {
+            let position = self.position();
+
+            self.buf.resize(self.capacity, 0);
+
+            let n = match self.inner.read_range(position, &mut self.buf) {
+                Ok(n) => n,
+                Err(e) => {
+                    self.buf.clear();
+                    self.buf_position = position;
+                    self.consumed = 0;
+                    return Err(e);
+                }
+            };
+
+            self.buf.truncate(n);
+            self.buf_position = position;
+            self.consumed = 0;
+        }
+
+        Ok(&self.buf[self.consumed..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.consumed = self.buf.len().min(self.consumed + amt);
+    }
+}
+
+impl<R> Seek for RangeReader<R>
+where
+    R: RangeRead,
+{
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let (base, offset) = match pos {
+            SeekFrom::Start(n) => {
+                self.set_position(n);
+                return Ok(n);
+            }
+            SeekFrom::Current(n) => (self.position(), n),
+            SeekFrom::End(n) => (self.inner.size()?, n),
+        };
+
+        let position = base.checked_add_signed(offset).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+
+        self.set_position(position);
+
+        Ok(position)
+    }
+
+    fn stream_position(&mut self) -> io::Result<u64> {
+        Ok(self.position())
+    }
+}
+
+impl RangeRead for &[u8] {
+    fn read_range(&mut self, offset: u64, buf: &mut [u8]) -> io::Result<usize> {
+        let src = usize::try_from(offset)
+            .ok()
+            .and_then(|i| self.get(i..))
+            .unwrap_or_default();
+
+        let n = src.len().min(buf.len());
+        buf[..n].copy_from_slice(&src[..n]);
+
+        Ok(n)
+    }
+
+    fn size(&mut self) -> io::Result<u64> {
+        Ok(self.len() as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct CountingSource<'a> {
+        data: &'a [u8],
+        requests: Vec<(u64, usize)>,
+    }
+
+    impl RangeRead for CountingSource<'_> {
+        fn read_range(&mut self, offset: u64, buf: &mut [u8]) -> io::Result<usize> {
+            self.requests.push((offset, buf.len()));
+            self.data.read_range(offset, buf)
+        }
+
+        fn size(&mut self) -> io::Result<u64> {
+            Ok(self.data.len() as u64)
+        }
+    }
+
+    #[test]
+    fn test_read() -> io::Result<()> {
+        let mut reader = RangeReader::with_capacity(3, &b"noodles"[..]);
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+
+        assert_eq!(buf, b"noodles");
+        assert_eq!(reader.position(), 7);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek() -> io::Result<()> {
+        let source = CountingSource {
+            data: b"noodles",
+            requests: Vec::new(),
+        };
+
+        let mut reader = RangeReader::with_capacity(4, source);
+        let mut buf = [0; 2];
+
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"no");
+
+        // within the buffer
+        assert_eq!(reader.seek(SeekFrom::Current(1))?, 3);
+        reader.read_exact(&mut buf[..1])?;
+        assert_eq!(&buf[..1], b"d");
+
+        assert_eq!(reader.seek(SeekFrom::End(-2))?, 5);
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"es");
+
+        assert_eq!(reader.seek(SeekFrom::Start(1))?, 1);
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"oo");
+
+        assert_eq!(reader.get_ref().requests, [(0, 4), (5, 4), (1, 4)]);
+
+        assert!(reader.seek(SeekFrom::Current(-8)).is_err());
+
+        Ok(())
+    }
+}
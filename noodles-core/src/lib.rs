@@ -2,7 +2,9 @@
 
 //! **noodles-core** contains shared structures and behavior among noodles libraries.
 
+pub mod assembly;
+pub mod io;
 pub mod position;
 pub mod region;
 
-pub use self::{position::Position, region::Region};
+pub use self::{assembly::Assembly, position::Position, region::Region};
@@ -1,8 +1,16 @@
 //! 1-based position.
 
+pub mod merge;
+pub mod overlap_join;
+mod positioned;
 mod sequence_index;
 
-pub use self::sequence_index::SequenceIndex;
+pub use self::{
+    merge::{merge, merge_by},
+    overlap_join::{overlap_join, overlap_join_by},
+    positioned::{Positioned, ReferenceSequence},
+    sequence_index::SequenceIndex,
+};
 
 use std::{
     fmt,
@@ -69,6 +77,27 @@ impl Position {
             None
         }
     }
+
+    /// Subtracts an unsigned integer from a 1-based position.
+    ///
+    /// This returns `None` if the result is less than 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// let position = Position::try_from(8)?;
+    /// assert_eq!(position.checked_sub(5), Position::new(3));
+    /// assert!(position.checked_sub(8).is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub const fn checked_sub(self, other: usize) -> Option<Self> {
+        if let Some(n) = self.0.get().checked_sub(other) {
+            Self::new(n)
+        } else {
+            None
+        }
+    }
 }
 
 impl fmt::Display for Position {
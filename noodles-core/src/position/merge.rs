@@ -0,0 +1,294 @@
+//! Sorted stream merge.
+
+use std::{cmp::Ordering, io};
+
+use super::{
+    positioned::{compare, natural_order, NaturalOrder, ReferenceSequence},
+    Positioned,
+};
+
+/// A k-way merger of sorted streams.
+///
+/// Unlike [`Merge`], records are compared using a function given on each call to
+/// [`Self::next_by`], which allows the comparison to borrow state owned by the caller, e.g., a
+/// header. Records that compare equal are emitted in stream order.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_core::position::merge::Merger;
+///
+/// let a = vec![1, 3];
+/// let b = vec![2];
+/// let mut merger = Merger::new(vec![a.into_iter().map(Ok), b.into_iter().map(Ok)]);
+///
+/// let mut values = Vec::new();
+///
+/// while let Some(n) = merger.next_by(|a, b| Ok(a.cmp(b)))? {
+///     values.push(n);
+/// }
+///
+/// assert_eq!(values, [1, 2, 3]);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct Merger<I, T> {
+    streams: Vec<I>,
+    heads: Vec<Option<T>>,
+    pending: Vec<usize>,
+}
+
+impl<I, T> Merger<I, T> {
+    /// Creates a merger of sorted streams.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{io, iter};
+    /// use noodles_core::position::merge::Merger;
+    /// let merger: Merger<_, u8> = Merger::new(vec![iter::empty::<io::Result<u8>>()]);
+    /// ```
+    pub fn new(streams: Vec<I>) -> Self {
+        let heads = streams.iter().map(|_| None).collect();
+        let pending = (0..streams.len()).rev().collect();
+
+        Self {
+            streams,
+            heads,
+            pending,
+        }
+    }
+}
+
+impl<I, T> Merger<I, T>
+where
+    I: Iterator<Item = io::Result<T>>,
+{
+    /// Returns the next record using the given comparison function.
+    ///
+    /// Streams are only read when their current record is needed, so a stream that returns an
+    /// error is not read again.
+    pub fn next_by<F>(&mut self, mut compare: F) -> io::Result<Option<T>>
+    where
+        F: FnMut(&T, &T) -> io::Result<Ordering>,
+    {
+        while let Some(i) = self.pending.pop() {
+            self.heads[i] = self.streams[i].next().transpose()?;
+        }
+
+        let mut min: Option<(usize, &T)> = None;
+
+        for (i, head) in self.heads.iter().enumerate() {
+            let Some(record) = head else {
+                continue;
+            };
+
+            let is_less = match min {
+                Some((_, min_record)) => compare(record, min_record)?.is_lt(),
+                None => true,
+            };
+
+            if is_less {
+                min = Some((i, record));
+            }
+        }
+
+        let Some((i, _)) = min else {
+            return Ok(None);
+        };
+
+        self.pending.push(i);
+
+        Ok(self.heads[i].take())
+    }
+}
+
+/// An iterator that merges sorted streams of positioned records.
+///
+/// This is created by [`merge`] or [`merge_by`].
+pub struct Merge<I, T, F> {
+    merger: Merger<I, T>,
+    compare_reference_sequences: F,
+}
+
+impl<I, T, F> Iterator for Merge<I, T, F>
+where
+    I: Iterator<Item = io::Result<T>>,
+    T: Positioned,
+    F: FnMut(&ReferenceSequence<'_>, &ReferenceSequence<'_>) -> Ordering,
+{
+    type Item = io::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let compare_reference_sequences = &mut self.compare_reference_sequences;
+
+        self.merger
+            .next_by(|a, b| Ok(compare(a, b, compare_reference_sequences)))
+            .transpose()
+    }
+}
+
+/// Merges sorted streams of positioned records.
+///
+/// Each stream must be sorted by reference sequence, start, and end. Reference sequences are
+/// compared using their natural order ([`ReferenceSequence`]). Records without a reference
+/// sequence are ordered last. Records that compare equal are emitted in stream order.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_core::{
+///     position::{self, Positioned, ReferenceSequence},
+///     Position,
+/// };
+///
+/// struct Record(usize, Position);
+///
+/// impl Positioned for Record {
+///     fn reference_sequence(&self) -> Option<ReferenceSequence<'_>> {
+///         Some(ReferenceSequence::Id(self.0))
+///     }
+///
+///     fn start(&self) -> Option<Position> {
+///         Some(self.1)
+///     }
+///
+///     fn end(&self) -> Option<Position> {
+///         Some(self.1)
+///     }
+/// }
+///
+/// let a = vec![Record(0, Position::MIN), Record(1, Position::MIN)];
+/// let b = vec![Record(0, Position::try_from(8)?)];
+///
+/// let records: Vec<_> = position::merge(vec![a.into_iter().map(Ok), b.into_iter().map(Ok)])
+///     .map(|result| result.map(|record| (record.0, usize::from(record.1))))
+///     .collect::<io::Result<_>>()?;
+///
+/// assert_eq!(records, [(0, 1), (0, 8), (1, 1)]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn merge<I, T>(streams: Vec<I>) -> Merge<I, T, NaturalOrder>
+where
+    I: Iterator<Item = io::Result<T>>,
+    T: Positioned,
+{
+    merge_by(streams, natural_order)
+}
+
+/// Merges sorted streams of positioned records using a reference sequence comparator.
+///
+/// This is the same as [`merge`] but compares reference sequences using the given function, e.g.,
+/// by their order in a header.
+pub fn merge_by<I, T, F>(streams: Vec<I>, compare_reference_sequences: F) -> Merge<I, T, F>
+where
+    I: Iterator<Item = io::Result<T>>,
+    T: Positioned,
+    F: FnMut(&ReferenceSequence<'_>, &ReferenceSequence<'_>) -> Ordering,
+{
+    Merge {
+        merger: Merger::new(streams),
+        compare_reference_sequences,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Position;
+
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    struct Record(Option<&'static str>, usize, usize);
+
+    impl Positioned for Record {
+        fn reference_sequence(&self) -> Option<ReferenceSequence<'_>> {
+            self.0.map(|name| ReferenceSequence::Name(name.into()))
+        }
+
+        fn start(&self) -> Option<Position> {
+            Position::new(self.1)
+        }
+
+        fn end(&self) -> Option<Position> {
+            Position::new(self.2)
+        }
+    }
+
+    #[test]
+    fn test_merger_next_by() -> io::Result<()> {
+        let a = vec![(1, 'a'), (3, 'a')];
+        let b = vec![(1, 'b'), (2, 'b')];
+        let mut merger = Merger::new(vec![a.into_iter().map(Ok), b.into_iter().map(Ok)]);
+
+        let mut values = Vec::new();
+
+        while let Some(value) = merger.next_by(|x, y| Ok(x.0.cmp(&y.0)))? {
+            values.push(value);
+        }
+
+        assert_eq!(values, [(1, 'a'), (1, 'b'), (2, 'b'), (3, 'a')]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merger_next_by_with_stream_error() {
+        let a = vec![Ok(1), Err(io::Error::from(io::ErrorKind::InvalidData))];
+        let b = vec![Ok(2)];
+        let mut merger = Merger::new(vec![a.into_iter(), b.into_iter()]);
+        let mut compare = |x: &i32, y: &i32| Ok(x.cmp(y));
+
+        assert_eq!(merger.next_by(&mut compare).ok(), Some(Some(1)));
+        assert!(merger.next_by(&mut compare).is_err());
+        assert_eq!(merger.next_by(&mut compare).ok(), Some(Some(2)));
+        assert_eq!(merger.next_by(&mut compare).ok(), Some(None));
+    }
+
+    #[test]
+    fn test_merge_by() -> io::Result<()> {
+        let a = vec![
+            Record(Some("sq1"), 5, 8),
+            Record(Some("sq0"), 1, 3),
+            Record(None, 0, 0),
+        ];
+
+        let b = vec![
+            Record(Some("sq1"), 2, 3),
+            Record(Some("sq1"), 5, 8),
+            Record(Some("sq0"), 2, 2),
+        ];
+
+        let c = Vec::new();
+
+        let order = ["sq1", "sq0"];
+        let rank = |reference_sequence: &ReferenceSequence<'_>| match reference_sequence {
+            ReferenceSequence::Name(name) => order.iter().position(|n| n.as_bytes() == *name),
+            ReferenceSequence::Id(_) => None,
+        };
+
+        let records: Vec<_> = merge_by(
+            vec![
+                a.into_iter().map(Ok),
+                b.into_iter().map(Ok),
+                c.into_iter().map(Ok),
+            ],
+            |x, y| rank(x).cmp(&rank(y)),
+        )
+        .collect::<io::Result<_>>()?;
+
+        assert_eq!(
+            records,
+            [
+                Record(Some("sq1"), 2, 3),
+                Record(Some("sq1"), 5, 8),
+                Record(Some("sq1"), 5, 8),
+                Record(Some("sq0"), 1, 3),
+                Record(Some("sq0"), 2, 2),
+                Record(None, 0, 0),
+            ]
+        );
+
+        Ok(())
+    }
+}
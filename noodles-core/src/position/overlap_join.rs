@@ -0,0 +1,259 @@
+//! Sorted stream overlap join.
+
+use std::{cmp::Ordering, io};
+
+use super::{
+    positioned::{natural_order, NaturalOrder, ReferenceSequence},
+    Positioned,
+};
+
+/// An iterator that joins two sorted streams of positioned records by overlap.
+///
+/// This is created by [`overlap_join`] or [`overlap_join_by`].
+pub struct OverlapJoin<L, R, U, F> {
+    left: L,
+    right: R,
+    next_right: Option<U>,
+    window: Vec<U>,
+    compare_reference_sequences: F,
+}
+
+impl<L, R, T, U, F> OverlapJoin<L, R, U, F>
+where
+    L: Iterator<Item = io::Result<T>>,
+    R: Iterator<Item = io::Result<U>>,
+    T: Positioned,
+    U: Positioned + Clone,
+    F: FnMut(&ReferenceSequence<'_>, &ReferenceSequence<'_>) -> Ordering,
+{
+    fn join(&mut self, record: T) -> io::Result<(T, Vec<U>)> {
+        let (Some(reference_sequence), Some(start)) = (record.reference_sequence(), record.start())
+        else {
+            return Ok((record, Vec::new()));
+        };
+
+        let end = record.end().unwrap_or(start);
+
+        let compare_reference_sequences = &mut self.compare_reference_sequences;
+
+        // Records before the start of the left record cannot overlap it or any record after it.
+        self.window.retain(|r| match r.reference_sequence() {
+            Some(rs) => match compare_reference_sequences(&rs, &reference_sequence) {
+                Ordering::Less => false,
+                Ordering::Equal => end_or_start(r).map(|e| e >= start).unwrap_or(false),
+                Ordering::Greater => true,
+            },
+            None => false,
+        });
+
+        loop {
+            if self.next_right.is_none() {
+                self.next_right = self.right.next().transpose()?;
+            }
+
+            let Some(r) = &self.next_right else {
+                break;
+            };
+
+            let (Some(rs), Some(r_start)) = (r.reference_sequence(), r.start()) else {
+                self.next_right = None;
+                continue;
+            };
+
+            match compare_reference_sequences(&rs, &reference_sequence) {
+                Ordering::Less => self.next_right = None,
+                Ordering::Equal if r_start <= end => {
+                    let is_before = end_or_start(r).map(|e| e < start).unwrap_or(true);
+
+                    if let Some(r) = self.next_right.take() {
+                        if !is_before {
+                            self.window.push(r);
+                        }
+                    }
+                }
+                _ => break,
+            }
+        }
+
+        let overlaps = self
+            .window
+            .iter()
+            .filter(|r| {
+                r.reference_sequence()
+                    .map(|rs| compare_reference_sequences(&rs, &reference_sequence))
+                    == Some(Ordering::Equal)
+                    && r.start().map(|s| s <= end).unwrap_or(false)
+                    && end_or_start(*r).map(|e| e >= start).unwrap_or(false)
+            })
+            .cloned()
+            .collect();
+
+        Ok((record, overlaps))
+    }
+}
+
+impl<L, R, T, U, F> Iterator for OverlapJoin<L, R, U, F>
+where
+    L: Iterator<Item = io::Result<T>>,
+    R: Iterator<Item = io::Result<U>>,
+    T: Positioned,
+    U: Positioned + Clone,
+    F: FnMut(&ReferenceSequence<'_>, &ReferenceSequence<'_>) -> Ordering,
+{
+    type Item = io::Result<(T, Vec<U>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.left.next()? {
+            Ok(record) => Some(self.join(record)),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+fn end_or_start<T>(record: &T) -> Option<super::Position>
+where
+    T: Positioned,
+{
+    record.end().or_else(|| record.start())
+}
+
+/// Joins two sorted streams of positioned records by overlap.
+///
+/// Each left record is emitted with the right records that overlap it, i.e., are on the same
+/// reference sequence and share at least one position. Left records without overlaps are emitted
+/// with an empty list, i.e., this is a left outer join; filter those out for an inner join.
+///
+/// Both streams must be sorted by reference sequence and start, and reference sequences are
+/// compared using their natural order ([`ReferenceSequence`]). Only a window of right records
+/// that can overlap the current left record is kept in memory. Records without a reference
+/// sequence or start position do not overlap any record.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_core::{
+///     position::{self, Positioned, ReferenceSequence},
+///     Position,
+/// };
+///
+/// #[derive(Clone)]
+/// struct Record(usize, usize);
+///
+/// impl Positioned for Record {
+///     fn reference_sequence(&self) -> Option<ReferenceSequence<'_>> {
+///         Some(ReferenceSequence::Id(0))
+///     }
+///
+///     fn start(&self) -> Option<Position> {
+///         Position::new(self.0)
+///     }
+///
+///     fn end(&self) -> Option<Position> {
+///         Position::new(self.1)
+///     }
+/// }
+///
+/// let left = [Record(1, 5), Record(8, 13)];
+/// let right = [Record(3, 3), Record(5, 8), Record(21, 34)];
+///
+/// let counts: Vec<_> = position::overlap_join(left.into_iter().map(Ok), right.into_iter().map(Ok))
+///     .map(|result| result.map(|(_, overlaps)| overlaps.len()))
+///     .collect::<io::Result<_>>()?;
+///
+/// assert_eq!(counts, [2, 1]);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn overlap_join<L, R, T, U>(left: L, right: R) -> OverlapJoin<L, R, U, NaturalOrder>
+where
+    L: Iterator<Item = io::Result<T>>,
+    R: Iterator<Item = io::Result<U>>,
+    T: Positioned,
+    U: Positioned + Clone,
+{
+    overlap_join_by(left, right, natural_order)
+}
+
+/// Joins two sorted streams of positioned records by overlap using a reference sequence
+/// comparator.
+///
+/// This is the same as [`overlap_join`] but compares reference sequences using the given
+/// function, e.g., by their order in a header.
+pub fn overlap_join_by<L, R, T, U, F>(
+    left: L,
+    right: R,
+    compare_reference_sequences: F,
+) -> OverlapJoin<L, R, U, F>
+where
+    L: Iterator<Item = io::Result<T>>,
+    R: Iterator<Item = io::Result<U>>,
+    T: Positioned,
+    U: Positioned + Clone,
+    F: FnMut(&ReferenceSequence<'_>, &ReferenceSequence<'_>) -> Ordering,
+{
+    OverlapJoin {
+        left,
+        right,
+        next_right: None,
+        window: Vec::new(),
+        compare_reference_sequences,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Position;
+
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    struct Record(usize, usize, usize);
+
+    impl Positioned for Record {
+        fn reference_sequence(&self) -> Option<ReferenceSequence<'_>> {
+            Some(ReferenceSequence::Id(self.0))
+        }
+
+        fn start(&self) -> Option<Position> {
+            Position::new(self.1)
+        }
+
+        fn end(&self) -> Option<Position> {
+            Position::new(self.2)
+        }
+    }
+
+    #[test]
+    fn test_overlap_join() -> io::Result<()> {
+        let left = vec![
+            Record(0, 1, 10),
+            Record(0, 4, 5),
+            Record(0, 20, 21),
+            Record(2, 1, 1),
+            Record(3, 8, 13),
+        ];
+
+        let right = vec![
+            Record(0, 2, 2),
+            Record(0, 3, 12),
+            Record(0, 11, 11),
+            Record(1, 1, 1),
+            Record(2, 1, 8),
+            Record(4, 1, 1),
+        ];
+
+        let actual: Vec<_> = overlap_join(left.into_iter().map(Ok), right.into_iter().map(Ok))
+            .collect::<io::Result<_>>()?;
+
+        let expected = [
+            (Record(0, 1, 10), vec![Record(0, 2, 2), Record(0, 3, 12)]),
+            (Record(0, 4, 5), vec![Record(0, 3, 12)]),
+            (Record(0, 20, 21), vec![]),
+            (Record(2, 1, 1), vec![Record(2, 1, 8)]),
+            (Record(3, 8, 13), vec![]),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+use std::cmp::Ordering;
+
+use bstr::BStr;
+
+use super::Position;
+
+/// A reference sequence of a positioned record.
+///
+/// Alignment records typically refer to reference sequences by ID, i.e., their index in the
+/// header, while other formats use names. IDs are ordered numerically, and names are ordered
+/// lexicographically. IDs are ordered before names.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ReferenceSequence<'a> {
+    /// A reference sequence ID.
+    Id(usize),
+    /// A reference sequence name.
+    Name(&'a BStr),
+}
+
+/// A record with a position on a reference sequence.
+///
+/// This allows the implementation of algorithms on sorted streams, e.g., merging
+/// ([`super::merge`]) and joining by overlap ([`super::overlap_join`]), independent of the
+/// format.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::{position::{Positioned, ReferenceSequence}, Position};
+///
+/// struct Interval {
+///     name: String,
+///     start: Position,
+///     end: Position,
+/// }
+///
+/// impl Positioned for Interval {
+///     fn reference_sequence(&self) -> Option<ReferenceSequence<'_>> {
+///         Some(ReferenceSequence::Name(self.name.as_str().into()))
+///     }
+///
+///     fn start(&self) -> Option<Position> {
+///         Some(self.start)
+///     }
+///
+///     fn end(&self) -> Option<Position> {
+///         Some(self.end)
+///     }
+/// }
+/// ```
+pub trait Positioned {
+    /// Returns the reference sequence.
+    fn reference_sequence(&self) -> Option<ReferenceSequence<'_>>;
+
+    /// Returns the start position.
+    ///
+    /// This position is 1-based, inclusive.
+    fn start(&self) -> Option<Position>;
+
+    /// Returns the end position.
+    ///
+    /// This position is 1-based, inclusive.
+    fn end(&self) -> Option<Position>;
+}
+
+impl<T> Positioned for &T
+where
+    T: Positioned + ?Sized,
+{
+    fn reference_sequence(&self) -> Option<ReferenceSequence<'_>> {
+        (**self).reference_sequence()
+    }
+
+    fn start(&self) -> Option<Position> {
+        (**self).start()
+    }
+
+    fn end(&self) -> Option<Position> {
+        (**self).end()
+    }
+}
+
+pub(super) type NaturalOrder = fn(&ReferenceSequence<'_>, &ReferenceSequence<'_>) -> Ordering;
+
+// Compares reference sequences by their natural order.
+pub(super) fn natural_order(a: &ReferenceSequence<'_>, b: &ReferenceSequence<'_>) -> Ordering {
+    a.cmp(b)
+}
+
+// Compares records by reference sequence, start, and end.
+//
+// Records without a reference sequence are ordered last.
+pub(super) fn compare<T, F>(a: &T, b: &T, compare_reference_sequences: &mut F) -> Ordering
+where
+    T: Positioned,
+    F: FnMut(&ReferenceSequence<'_>, &ReferenceSequence<'_>) -> Ordering,
+{
+    let ordering = match (a.reference_sequence(), b.reference_sequence()) {
+        (Some(a), Some(b)) => compare_reference_sequences(&a, &b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    };
+
+    ordering
+        .then_with(|| a.start().cmp(&b.start()))
+        .then_with(|| a.end().cmp(&b.end()))
+}
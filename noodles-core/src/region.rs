@@ -1,10 +1,11 @@
 //! Genomic region.
 
 pub mod interval;
+mod interval_set;
 
 use bstr::{BStr, BString};
 
-pub use self::interval::Interval;
+pub use self::{interval::Interval, interval_set::IntervalSet};
 
 use std::{
     error, fmt,
@@ -14,6 +15,10 @@ use std::{
 
 use super::Position;
 
+const NAME_DELIMITER: u8 = b':';
+const NAME_PREFIX: char = '{';
+const NAME_SUFFIX: char = '}';
+
 /// A genomic region.
 ///
 /// A genomic region describes a region on a reference sequence. It consists of reference sequence
@@ -23,6 +28,11 @@ use super::Position;
 /// end positions are 1-based, inclusive. If no end position is given, it is assumed to span from
 /// the start to the end of the reference sequence. If no interval is given, it is assumed to span
 /// the entirety of the reference sequence.
+///
+/// This follows the samtools region syntax: either position may be omitted, e.g., `sq0:5-` or
+/// `sq0:-8`; positions may include thousands separators, e.g., `sq0:1,000-2,000`; and a reference
+/// sequence name that contains colons (`:`) can be escaped using curly braces, e.g.,
+/// `{HLA-A*01:01}:5-8`. The name `*` is conventionally used to denote unmapped records.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Region {
     name: BString,
@@ -131,7 +141,11 @@ impl Region {
 
 impl fmt::Display for Region {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.name())?;
+        if self.name().contains(&NAME_DELIMITER) {
+            write!(f, "{NAME_PREFIX}{}{NAME_SUFFIX}", self.name())?;
+        } else {
+            write!(f, "{}", self.name())?;
+        }
 
         match (self.interval.start_bound(), self.interval.end_bound()) {
             (Bound::Unbounded, Bound::Unbounded) => {}
@@ -183,7 +197,11 @@ impl FromStr for Region {
             return Err(ParseError::Empty);
         }
 
-        if let Some((name, suffix)) = s.rsplit_once(':') {
+        if let Some(t) = s.strip_prefix(NAME_PREFIX) {
+            return parse_escaped(t);
+        }
+
+        if let Some((name, suffix)) = s.rsplit_once(char::from(NAME_DELIMITER)) {
             let interval: Interval = suffix.parse().map_err(ParseError::InvalidInterval)?;
             Ok(Self::new(name, interval))
         } else {
@@ -192,6 +210,23 @@ impl FromStr for Region {
     }
 }
 
+// Parses `name}[:interval]`.
+fn parse_escaped(s: &str) -> Result<Region, ParseError> {
+    let (name, suffix) = s.split_once(NAME_SUFFIX).ok_or(ParseError::Invalid)?;
+
+    let interval = if suffix.is_empty() {
+        Interval::from(..)
+    } else {
+        suffix
+            .strip_prefix(char::from(NAME_DELIMITER))
+            .ok_or(ParseError::Invalid)?
+            .parse()
+            .map_err(ParseError::InvalidInterval)?
+    };
+
+    Ok(Region::new(name, interval))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -206,6 +241,12 @@ mod tests {
         assert_eq!(Region::new("sq0", start..).to_string(), "sq0:5");
         assert_eq!(Region::new("sq0", start..=end).to_string(), "sq0:5-8");
 
+        assert_eq!(Region::new("HLA-A*01:01", ..).to_string(), "{HLA-A*01:01}");
+        assert_eq!(
+            Region::new("HLA-A*01:01", start..=end).to_string(),
+            "{HLA-A*01:01}:5-8"
+        );
+
         Ok(())
     }
 
@@ -220,6 +261,25 @@ mod tests {
         let end = Position::try_from(8)?;
         assert_eq!("sq3:5-8".parse(), Ok(Region::new("sq3", start..=end)));
 
+        assert_eq!("sq4:5-".parse(), Ok(Region::new("sq4", start..)));
+        assert_eq!("sq5:-8".parse(), Ok(Region::new("sq5", ..=end)));
+        assert_eq!("*".parse(), Ok(Region::new("*", ..)));
+
+        assert_eq!("{HLA-A*01:01}".parse(), Ok(Region::new("HLA-A*01:01", ..)));
+        assert_eq!(
+            "{HLA-A*01:01}:5-8".parse(),
+            Ok(Region::new("HLA-A*01:01", start..=end))
+        );
+        assert_eq!(
+            "{HLA-A*01:01}:5-".parse(),
+            Ok(Region::new("HLA-A*01:01", start..))
+        );
+        assert_eq!("{sq0".parse::<Region>(), Err(ParseError::Invalid));
+        assert_eq!("{sq0}5-8".parse::<Region>(), Err(ParseError::Invalid));
+
+        let region = Region::new("HLA-A*01:01", start..=end);
+        assert_eq!(region.to_string().parse(), Ok(region));
+
         assert_eq!("".parse::<Region>(), Err(ParseError::Empty));
 
         Ok(())
@@ -94,17 +94,193 @@ impl Interval {
     /// # Ok::<_, noodles_core::position::TryFromIntError>(())
     /// ```
     pub fn intersects(&self, other: Self) -> bool {
-        fn resolve(interval: Interval) -> (Position, Position) {
-            (
-                interval.start().unwrap_or(Position::MIN),
-                interval.end().unwrap_or(Position::MAX),
-            )
+        let (a_start, a_end) = self.resolve();
+        let (b_start, b_end) = other.resolve();
+
+        a_start <= b_end && b_start <= a_end
+    }
+
+    /// Returns whether the given interval is entirely in this interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Interval, Position};
+    ///
+    /// let a = Interval::from(Position::try_from(5)?..=Position::try_from(13)?);
+    ///
+    /// let b = Interval::from(Position::try_from(8)?..=Position::try_from(13)?);
+    /// assert!(a.contains_interval(b));
+    ///
+    /// let c = Interval::from(Position::try_from(8)?..=Position::try_from(21)?);
+    /// assert!(!a.contains_interval(c));
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn contains_interval(&self, other: Self) -> bool {
+        let (a_start, a_end) = self.resolve();
+        let (b_start, b_end) = other.resolve();
+
+        a_start <= b_start && b_end <= a_end
+    }
+
+    /// Returns the intersection of this interval and the given interval.
+    ///
+    /// This returns `None` if the intervals do not intersect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Interval, Position};
+    ///
+    /// let a = Interval::from(Position::try_from(5)?..=Position::try_from(13)?);
+    /// let b = Interval::from(Position::try_from(8)?..);
+    /// let expected = Interval::from(Position::try_from(8)?..=Position::try_from(13)?);
+    /// assert_eq!(a.intersection(b), Some(expected));
+    ///
+    /// let c = Interval::from(Position::try_from(21)?..);
+    /// assert!(a.intersection(c).is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn intersection(&self, other: Self) -> Option<Self> {
+        if !self.intersects(other) {
+            return None;
         }
 
-        let (a_start, a_end) = resolve(*self);
-        let (b_start, b_end) = resolve(other);
+        let start = match (self.start, other.start) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        };
+
+        let end = match (self.end, other.end) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
 
-        a_start <= b_end && b_start <= a_end
+        Some(Self { start, end })
+    }
+
+    /// Returns the union of this interval and the given interval.
+    ///
+    /// This returns `None` if the intervals neither intersect nor are adjacent, i.e., if the union
+    /// is not a single interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Interval, Position};
+    ///
+    /// let a = Interval::from(Position::try_from(5)?..=Position::try_from(8)?);
+    /// let b = Interval::from(Position::try_from(9)?..=Position::try_from(13)?);
+    /// let expected = Interval::from(Position::try_from(5)?..=Position::try_from(13)?);
+    /// assert_eq!(a.union(b), Some(expected));
+    ///
+    /// let c = Interval::from(Position::try_from(21)?..);
+    /// assert!(a.union(c).is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn union(&self, other: Self) -> Option<Self> {
+        if !self.intersects(other) && !self.is_adjacent(other) {
+            return None;
+        }
+
+        let start = match (self.start, other.start) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            _ => None,
+        };
+
+        let end = match (self.end, other.end) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            _ => None,
+        };
+
+        Some(Self { start, end })
+    }
+
+    /// Returns the number of positions in this interval.
+    ///
+    /// This returns `None` if the interval is unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Interval, Position};
+    ///
+    /// let start = Position::try_from(8)?;
+    /// let end = Position::try_from(13)?;
+    ///
+    /// assert_eq!(Interval::from(start..=end).len(), Some(6));
+    /// assert_eq!(Interval::from(end..=start).len(), Some(0));
+    /// assert!(Interval::from(start..).len().is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn len(&self) -> Option<usize> {
+        match (self.start, self.end) {
+            (Some(start), Some(end)) => Some((end.get() + 1).saturating_sub(start.get())),
+            _ => None,
+        }
+    }
+
+    /// Returns whether this interval has no positions.
+    ///
+    /// An interval is empty if its start is greater than its end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Interval, Position};
+    ///
+    /// let start = Position::try_from(8)?;
+    /// let end = Position::try_from(13)?;
+    ///
+    /// assert!(!Interval::from(start..=end).is_empty());
+    /// assert!(Interval::from(end..=start).is_empty());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        let (start, end) = self.resolve();
+        start > end
+    }
+
+    /// Returns this interval bounded by a reference sequence of the given length.
+    ///
+    /// The result is always closed. This returns `None` if the interval does not intersect [1,
+    /// `length`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Interval, Position};
+    ///
+    /// let interval = Interval::from(Position::try_from(8)?..);
+    /// let expected = Interval::from(Position::try_from(8)?..=Position::try_from(13)?);
+    /// assert_eq!(interval.clamp(13), Some(expected));
+    ///
+    /// assert!(interval.clamp(5).is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn clamp(&self, length: usize) -> Option<Self> {
+        let end = Position::new(length)?;
+        self.intersection(Self::from(Position::MIN..=end))
+            .map(|interval| interval.resolve())
+            .map(|(start, end)| Self::from(start..=end))
+    }
+
+    fn resolve(&self) -> (Position, Position) {
+        (
+            self.start.unwrap_or(Position::MIN),
+            self.end.unwrap_or(Position::MAX),
+        )
+    }
+
+    fn is_adjacent(&self, other: Self) -> bool {
+        fn precedes(a: Interval, b: Interval) -> bool {
+            match (a.end, b.start) {
+                (Some(end), Some(start)) => end.checked_add(1) == Some(start),
+                _ => false,
+            }
+        }
+
+        precedes(*self, other) || precedes(other, *self)
     }
 }
 
@@ -172,18 +348,12 @@ impl FromStr for Interval {
         let mut components = s.splitn(2, '-');
 
         let start = match components.next() {
-            Some(t) => t
-                .parse()
-                .map(Some)
-                .map_err(ParseError::InvalidStartPosition)?,
+            Some(t) => parse_position(t).map_err(ParseError::InvalidStartPosition)?,
             None => None,
         };
 
         let end = match components.next() {
-            Some(t) => t
-                .parse()
-                .map(Some)
-                .map_err(ParseError::InvalidEndPosition)?,
+            Some(t) => parse_position(t).map_err(ParseError::InvalidEndPosition)?,
             None => None,
         };
 
@@ -191,6 +361,20 @@ impl FromStr for Interval {
     }
 }
 
+// An empty position is unbounded, e.g., `-8` or `5-`. Positions may include thousands separators,
+// e.g., `1,000`.
+fn parse_position(s: &str) -> Result<Option<Position>, position::ParseError> {
+    const THOUSANDS_SEPARATOR: char = ',';
+
+    if s.is_empty() {
+        Ok(None)
+    } else if s.contains(THOUSANDS_SEPARATOR) {
+        s.replace(THOUSANDS_SEPARATOR, "").parse().map(Some)
+    } else {
+        s.parse().map(Some)
+    }
+}
+
 impl From<RangeFrom<Position>> for Interval {
     fn from(range: RangeFrom<Position>) -> Self {
         Self {
@@ -304,6 +488,113 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_contains_interval() -> Result<(), crate::position::TryFromIntError> {
+        let a = Interval::from(Position::try_from(5)?..=Position::try_from(8)?);
+        assert!(a.contains_interval(a));
+        assert!(a.contains_interval((Position::try_from(6)?..=Position::try_from(7)?).into()));
+        assert!(!a.contains_interval((Position::try_from(4)?..=Position::try_from(7)?).into()));
+        assert!(!a.contains_interval((Position::try_from(6)?..).into()));
+
+        let b = Interval::from(..);
+        assert!(b.contains_interval(a));
+        assert!(!a.contains_interval(b));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_intersection() -> Result<(), crate::position::TryFromIntError> {
+        let a = Interval::from(Position::try_from(5)?..=Position::try_from(8)?);
+
+        assert_eq!(a.intersection(a), Some(a));
+        assert_eq!(
+            a.intersection((Position::try_from(7)?..=Position::try_from(13)?).into()),
+            Some((Position::try_from(7)?..=Position::try_from(8)?).into())
+        );
+        assert_eq!(
+            a.intersection((..=Position::try_from(6)?).into()),
+            Some((Position::try_from(5)?..=Position::try_from(6)?).into())
+        );
+        assert_eq!(a.intersection(Interval::from(..)), Some(a));
+        assert!(a.intersection((Position::try_from(9)?..).into()).is_none());
+
+        let b = Interval::from(Position::try_from(8)?..);
+        let c = Interval::from(..=Position::try_from(13)?);
+        assert_eq!(
+            b.intersection(c),
+            Some((Position::try_from(8)?..=Position::try_from(13)?).into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_union() -> Result<(), crate::position::TryFromIntError> {
+        let a = Interval::from(Position::try_from(5)?..=Position::try_from(8)?);
+
+        assert_eq!(a.union(a), Some(a));
+        assert_eq!(
+            a.union((Position::try_from(7)?..=Position::try_from(13)?).into()),
+            Some((Position::try_from(5)?..=Position::try_from(13)?).into())
+        );
+        assert_eq!(
+            a.union((Position::try_from(1)?..=Position::try_from(4)?).into()),
+            Some((Position::try_from(1)?..=Position::try_from(8)?).into())
+        );
+        assert_eq!(
+            a.union((Position::try_from(9)?..).into()),
+            Some((Position::try_from(5)?..).into())
+        );
+        assert_eq!(
+            a.union((..=Position::try_from(6)?).into()),
+            Some((..=Position::try_from(8)?).into())
+        );
+        assert!(a
+            .union((Position::try_from(10)?..=Position::try_from(13)?).into())
+            .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_len() -> Result<(), crate::position::TryFromIntError> {
+        let start = Position::try_from(5)?;
+        let end = Position::try_from(8)?;
+
+        assert_eq!(Interval::from(start..=end).len(), Some(4));
+        assert_eq!(Interval::from(start..=start).len(), Some(1));
+        assert_eq!(Interval::from(end..=start).len(), Some(0));
+        assert!(Interval::from(start..).len().is_none());
+        assert!(Interval::from(..=end).len().is_none());
+        assert!(Interval::from(..).len().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clamp() -> Result<(), crate::position::TryFromIntError> {
+        let start = Position::try_from(5)?;
+        let end = Position::try_from(8)?;
+
+        assert_eq!(
+            Interval::from(start..=end).clamp(13),
+            Some((start..=end).into())
+        );
+        assert_eq!(
+            Interval::from(start..=end).clamp(6),
+            Some((start..=Position::try_from(6)?).into())
+        );
+        assert_eq!(
+            Interval::from(..).clamp(13),
+            Some((Position::MIN..=Position::try_from(13)?).into())
+        );
+        assert!(Interval::from(start..=end).clamp(4).is_none());
+        assert!(Interval::from(..).clamp(0).is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() -> Result<(), crate::position::TryFromIntError> {
         let start = Position::try_from(8)?;
@@ -332,6 +623,13 @@ mod tests {
         assert_eq!("".parse(), Ok(Interval::from(..)));
         assert_eq!("8".parse(), Ok(Interval::from(start..)));
         assert_eq!("8-13".parse(), Ok(Interval::from(start..=end)));
+        assert_eq!("8-".parse(), Ok(Interval::from(start..)));
+        assert_eq!("-13".parse(), Ok(Interval::from(..=end)));
+        assert_eq!("-".parse(), Ok(Interval::from(..)));
+
+        let start = Position::try_from(1000)?;
+        let end = Position::try_from(2000000)?;
+        assert_eq!("1,000-2,000,000".parse(), Ok(Interval::from(start..=end)));
 
         assert!(matches!(
             "x".parse::<Interval>(),
@@ -0,0 +1,255 @@
+//! Genomic region interval set.
+
+use super::Interval;
+use crate::Position;
+
+/// A set of intervals.
+///
+/// Intervals are stored sorted and merged, i.e., overlapping and adjacent intervals are combined,
+/// which allows membership queries in logarithmic time.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::{region::{Interval, IntervalSet}, Position};
+///
+/// let set: IntervalSet = [
+///     Interval::from(Position::try_from(8)?..=Position::try_from(13)?),
+///     Interval::from(Position::try_from(1)?..=Position::try_from(5)?),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// assert!(set.contains(Position::try_from(3)?));
+/// assert!(!set.contains(Position::try_from(6)?));
+/// # Ok::<_, noodles_core::position::TryFromIntError>(())
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IntervalSet(Vec<(Position, Position)>);
+
+impl IntervalSet {
+    /// Returns the number of disjoint intervals in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::IntervalSet;
+    /// let set = IntervalSet::default();
+    /// assert_eq!(set.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether the set has no intervals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::region::IntervalSet;
+    /// let set = IntervalSet::default();
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns whether the given position is in any interval in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::{Interval, IntervalSet}, Position};
+    ///
+    /// let set: IntervalSet = [Interval::from(Position::try_from(8)?..=Position::try_from(13)?)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// assert!(set.contains(Position::try_from(8)?));
+    /// assert!(!set.contains(Position::try_from(21)?));
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn contains(&self, position: Position) -> bool {
+        self.intersects(Interval::from(position..=position))
+    }
+
+    /// Returns whether the given interval intersects any interval in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::{Interval, IntervalSet}, Position};
+    ///
+    /// let set: IntervalSet = [Interval::from(Position::try_from(8)?..=Position::try_from(13)?)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// assert!(set.intersects(Interval::from(Position::try_from(13)?..)));
+    /// assert!(!set.intersects(Interval::from(..=Position::try_from(5)?)));
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn intersects(&self, interval: Interval) -> bool {
+        if interval.is_empty() {
+            return false;
+        }
+
+        let start = interval.start().unwrap_or(Position::MIN);
+        let end = interval.end().unwrap_or(Position::MAX);
+
+        let i = self.0.partition_point(|&(_, e)| e < start);
+
+        self.0.get(i).map(|&(s, _)| s <= end).unwrap_or(false)
+    }
+
+    /// Returns an iterator over the disjoint intervals in the set.
+    ///
+    /// The intervals are closed and sorted by start position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::{Interval, IntervalSet}, Position};
+    ///
+    /// let set: IntervalSet = [
+    ///     Interval::from(Position::try_from(5)?..=Position::try_from(13)?),
+    ///     Interval::from(Position::try_from(1)?..=Position::try_from(8)?),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let intervals: Vec<_> = set.iter().collect();
+    /// assert_eq!(
+    ///     intervals,
+    ///     [Interval::from(Position::try_from(1)?..=Position::try_from(13)?)]
+    /// );
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = Interval> + '_ {
+        self.0
+            .iter()
+            .map(|&(start, end)| Interval::from(start..=end))
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<T: IntoIterator<Item = Interval>>(iter: T) -> Self {
+        let mut intervals: Vec<_> = iter
+            .into_iter()
+            .filter(|interval| !interval.is_empty())
+            .map(|interval| {
+                (
+                    interval.start().unwrap_or(Position::MIN),
+                    interval.end().unwrap_or(Position::MAX),
+                )
+            })
+            .collect();
+
+        intervals.sort_unstable();
+
+        let mut merged: Vec<(Position, Position)> = Vec::with_capacity(intervals.len());
+
+        for (start, end) in intervals {
+            match merged.last_mut() {
+                Some((_, last_end))
+                    if last_end.checked_add(1).map(|p| start <= p).unwrap_or(true) =>
+                {
+                    *last_end = (*last_end).max(end);
+                }
+                _ => merged.push((start, end)),
+            }
+        }
+
+        Self(merged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_iter() -> Result<(), crate::position::TryFromIntError> {
+        let set: IntervalSet = [
+            Interval::from(Position::try_from(8)?..=Position::try_from(13)?),
+            Interval::from(Position::try_from(1)?..=Position::try_from(3)?),
+            Interval::from(Position::try_from(4)?..=Position::try_from(5)?),
+            Interval::from(Position::try_from(10)?..=Position::try_from(21)?),
+            Interval::from(Position::try_from(34)?..=Position::try_from(21)?),
+        ]
+        .into_iter()
+        .collect();
+
+        let intervals: Vec<_> = set.iter().collect();
+        assert_eq!(
+            intervals,
+            [
+                Interval::from(Position::try_from(1)?..=Position::try_from(5)?),
+                Interval::from(Position::try_from(8)?..=Position::try_from(21)?)
+            ]
+        );
+
+        let set: IntervalSet = [
+            Interval::from(Position::try_from(8)?..=Position::try_from(13)?),
+            Interval::from(Position::MIN..),
+        ]
+        .into_iter()
+        .collect();
+
+        let intervals: Vec<_> = set.iter().collect();
+        assert_eq!(intervals, [Interval::from(Position::MIN..=Position::MAX)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_intersects() -> Result<(), crate::position::TryFromIntError> {
+        let set: IntervalSet = [
+            Interval::from(Position::try_from(1)?..=Position::try_from(5)?),
+            Interval::from(Position::try_from(8)?..=Position::try_from(21)?),
+        ]
+        .into_iter()
+        .collect();
+
+        assert!(set.intersects(Interval::from(
+            Position::try_from(1)?..=Position::try_from(1)?
+        )));
+        assert!(set.intersects(Interval::from(
+            Position::try_from(5)?..=Position::try_from(8)?
+        )));
+        assert!(!set.intersects(Interval::from(
+            Position::try_from(6)?..=Position::try_from(7)?
+        )));
+        assert!(set.intersects(Interval::from(
+            Position::try_from(6)?..=Position::try_from(34)?
+        )));
+        assert!(!set.intersects(Interval::from(
+            Position::try_from(22)?..=Position::try_from(34)?
+        )));
+        assert!(!set.intersects(Interval::from(
+            Position::try_from(13)?..=Position::try_from(8)?
+        )));
+        assert!(set.intersects(Interval::from(..)));
+        assert!(!set.intersects(Interval::from(Position::try_from(22)?..)));
+
+        assert!(!IntervalSet::default().intersects(Interval::from(..)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_contains() -> Result<(), crate::position::TryFromIntError> {
+        let set: IntervalSet = [
+            Interval::from(Position::try_from(1)?..=Position::try_from(5)?),
+            Interval::from(Position::try_from(8)?..=Position::try_from(21)?),
+        ]
+        .into_iter()
+        .collect();
+
+        assert!(set.contains(Position::try_from(1)?));
+        assert!(set.contains(Position::try_from(21)?));
+        assert!(!set.contains(Position::try_from(6)?));
+        assert!(!set.contains(Position::try_from(22)?));
+
+        Ok(())
+    }
+}
@@ -11,7 +11,7 @@ use std::{
 
 use noodles_cram as cram;
 use noodles_fasta::{self as fasta, repository::adapters::IndexedReader};
-use noodles_sam::{self as sam, alignment::io::Write as _};
+use noodles_sam as sam;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut args = env::args().skip(1);
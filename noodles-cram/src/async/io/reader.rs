@@ -24,6 +24,7 @@ pub struct Reader<R> {
     inner: R,
     reference_sequence_repository: fasta::Repository,
     buf: BytesMut,
+    version: Version,
 }
 
 impl<R> Reader<R>
@@ -115,6 +116,8 @@ where
         let format = read_format(&mut self.inner).await?;
         let file_id = read_file_id(&mut self.inner).await?;
 
+        self.version = format;
+
         Ok(FileDefinition::new(format, file_id))
     }
 
@@ -145,7 +148,7 @@ where
     /// ```
     pub async fn read_file_header(&mut self) -> io::Result<String> {
         use self::header_container::read_header_container;
-        read_header_container(&mut self.inner, &mut self.buf).await
+        read_header_container(&mut self.inner, &mut self.buf, self.version).await
     }
 
     /// Reads the SAM header.
@@ -203,7 +206,7 @@ where
     pub async fn read_data_container(&mut self) -> io::Result<Option<DataContainer>> {
         use self::data_container::read_data_container;
 
-        read_data_container(&mut self.inner, &mut self.buf).await
+        read_data_container(&mut self.inner, &mut self.buf, self.version).await
     }
 
     /// Returns an (async) stream over records starting from the current (input) stream position.
@@ -364,7 +367,17 @@ where
 {
     let major = reader.read_u8().await?;
     let minor = reader.read_u8().await?;
-    Ok(Version::new(major, minor))
+
+    let version = Version::new(major, minor);
+
+    if matches!(version.major(), 2 | 3) {
+        Ok(version)
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("unsupported CRAM version: {major}.{minor}"),
+        ))
+    }
 }
 
 async fn read_file_id<R>(reader: &mut R) -> io::Result<[u8; 20]>
@@ -8,6 +8,7 @@ use tokio::{
 };
 
 use super::Reader;
+use crate::file_definition::Version;
 
 /// An async CRAM reader builder.
 #[derive(Default)]
@@ -75,6 +76,7 @@ impl Builder {
             inner: reader,
             reference_sequence_repository: self.reference_sequence_repository,
             buf: BytesMut::new(),
+            version: Version::default(),
         }
     }
 }
@@ -2,13 +2,14 @@ use tokio::io::{self, AsyncRead, AsyncReadExt};
 
 use crate::{
     data_container::Header,
+    file_definition::Version,
     r#async::io::reader::{
         num::{read_itf8, read_ltf8},
         CrcReader,
     },
 };
 
-pub async fn read_header<R>(reader: &mut R) -> io::Result<Option<Header>>
+pub async fn read_header<R>(reader: &mut R, version: Version) -> io::Result<Option<Header>>
 where
     R: AsyncRead + Unpin,
 {
@@ -26,9 +27,15 @@ where
 
     let number_of_records = read_itf8(&mut crc_reader).await?;
 
-    let record_counter = read_ltf8(&mut crc_reader).await.and_then(|n| {
-        u64::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-    })?;
+    let record_counter = if version.has_ltf8_record_counter() {
+        read_ltf8(&mut crc_reader).await.and_then(|n| {
+            u64::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })?
+    } else {
+        read_itf8(&mut crc_reader).await.and_then(|n| {
+            u64::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })?
+    };
 
     let bases = read_ltf8(&mut crc_reader).await.and_then(|n| {
         u64::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
@@ -40,26 +47,32 @@ where
 
     let landmarks = read_landmarks(&mut crc_reader).await?;
 
-    let actual_crc32 = crc_reader.crc().sum();
+    let crc32 = if version.has_crc32() {
+        let actual_crc32 = crc_reader.crc().sum();
 
-    let reader = crc_reader.into_inner();
-    let expected_crc32 = reader.read_u32_le().await?;
+        let reader = crc_reader.into_inner();
+        let expected_crc32 = reader.read_u32_le().await?;
 
-    if actual_crc32 != expected_crc32 {
-        return Err(io::Error::new(
-            io::ErrorKind::InvalidData,
-            format!(
-                "container header checksum mismatch: expected {expected_crc32:08x}, got {actual_crc32:08x}"
-            ),
-        ));
-    }
+        if actual_crc32 != expected_crc32 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "container header checksum mismatch: expected {expected_crc32:08x}, got {actual_crc32:08x}"
+                ),
+            ));
+        }
+
+        Some(expected_crc32)
+    } else {
+        None
+    };
 
     if is_eof(
         length,
         reference_sequence_id,
         alignment_start,
         number_of_blocks,
-        actual_crc32,
+        crc32,
     ) {
         return Ok(None);
     }
@@ -126,7 +139,7 @@ mod tests {
         ];
 
         let mut reader = &data[..];
-        let actual = read_header(&mut reader).await?;
+        let actual = read_header(&mut reader, Version::default()).await?;
 
         let expected = Header::builder()
             .set_length(144)
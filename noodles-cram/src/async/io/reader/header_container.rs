@@ -6,28 +6,35 @@ use bytes::{Buf, Bytes, BytesMut};
 use tokio::io::{self, AsyncRead, AsyncReadExt};
 
 use self::header::read_header;
-use crate::container::{
-    block::{CompressionMethod, ContentType},
-    Block,
+use crate::{
+    container::{
+        block::{CompressionMethod, ContentType},
+        Block,
+    },
+    file_definition::Version,
 };
 
-pub async fn read_header_container<R>(reader: &mut R, buf: &mut BytesMut) -> io::Result<String>
+pub async fn read_header_container<R>(
+    reader: &mut R,
+    buf: &mut BytesMut,
+    version: Version,
+) -> io::Result<String>
 where
     R: AsyncRead + Unpin,
 {
-    let len = read_header(reader).await?;
+    let len = read_header(reader, version).await?;
 
     buf.resize(len, 0);
     reader.read_exact(buf).await?;
     let mut buf = buf.split().freeze();
 
-    read_raw_sam_header_from_block(&mut buf)
+    read_raw_sam_header_from_block(&mut buf, version)
 }
 
-fn read_raw_sam_header_from_block(src: &mut Bytes) -> io::Result<String> {
+fn read_raw_sam_header_from_block(src: &mut Bytes, version: Version) -> io::Result<String> {
     use crate::io::reader::container::read_block;
 
-    let block = read_block(src)?;
+    let block = read_block(src, version)?;
     read_raw_sam_header(&block)
 }
 
@@ -8,7 +8,9 @@ use tokio::{
 
 use super::Writer;
 use crate::{
-    data_container::BlockContentEncoderMap, file_definition::Version, io::writer::Options,
+    data_container::{block_content_encoder_map::Profile, BlockContentEncoderMap},
+    file_definition::Version,
+    io::writer::Options,
     DataContainer,
 };
 
@@ -55,6 +57,11 @@ impl Builder {
         self
     }
 
+    /// Sets the block content-encoder map from an encoding profile.
+    pub fn set_profile(self, profile: Profile) -> Self {
+        self.set_block_content_encoder_map(BlockContentEncoderMap::from(profile))
+    }
+
     /// Builds an async CRAM writer from a path.
     ///
     /// # Examples
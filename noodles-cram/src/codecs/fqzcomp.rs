@@ -1,3 +1,5 @@
+//! fqzcomp quality score codec.
+
 mod decode;
 mod encode;
 mod parameter;
@@ -63,4 +65,44 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_round_trip() -> io::Result<()> {
+        // xorshift32
+        let mut state = 0x2545f491u32;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state
+        };
+
+        for max_score in [0, 1, 41, 93] {
+            for record_count in [0, 1, 2, 100] {
+                for is_fixed_len in [true, false] {
+                    let lens: Vec<_> = (0..record_count)
+                        .map(|_| {
+                            if is_fixed_len {
+                                150
+                            } else {
+                                1 + (next() % 300) as usize
+                            }
+                        })
+                        .collect();
+
+                    let len = lens.iter().sum();
+                    let src: Vec<_> = (0..len).map(|_| (next() % (max_score + 1)) as u8).collect();
+
+                    let compressed_data = encode(&lens, &src)?;
+
+                    let mut reader = &compressed_data[..];
+                    let uncompressed_data = decode(&mut reader)?;
+
+                    assert_eq!(uncompressed_data, src);
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
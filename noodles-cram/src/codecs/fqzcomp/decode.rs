@@ -10,6 +10,21 @@ use super::{
 };
 use crate::{codecs::aac::RangeCoder, io::reader::num::read_uint7};
 
+/// Decodes fqzcomp-encoded quality scores.
+///
+/// This returns the concatenated quality scores of all records.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram::codecs::fqzcomp;
+///
+/// let compressed_data = fqzcomp::encode(&[3], &[40, 37, 21])?;
+///
+/// let mut reader = &compressed_data[..];
+/// assert_eq!(fqzcomp::decode(&mut reader)?, [40, 37, 21]);
+/// # Ok::<_, std::io::Error>(())
+/// ```
 pub fn decode<R>(reader: &mut R) -> io::Result<Vec<u8>>
 where
     R: Read,
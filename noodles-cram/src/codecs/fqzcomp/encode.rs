@@ -5,6 +5,25 @@ use byteorder::{LittleEndian, WriteBytesExt};
 use super::{parameter, parameters, Models};
 use crate::{codecs::aac::RangeCoder, io::writer::num::write_uint7};
 
+/// Encodes quality scores using fqzcomp.
+///
+/// `src` is the concatenated quality scores of one or more records, and `lens` is the number of
+/// quality scores in each record. Quality scores are raw Phred quality scores, i.e., not offset
+/// by 33.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram::codecs::fqzcomp;
+///
+/// let lens = [4, 3];
+/// let src = [40, 40, 37, 21, 8, 13, 13];
+/// let compressed_data = fqzcomp::encode(&lens, &src)?;
+///
+/// let mut reader = &compressed_data[..];
+/// assert_eq!(fqzcomp::decode(&mut reader)?, src);
+/// # Ok::<_, std::io::Error>(())
+/// ```
 pub fn encode(lens: &[usize], src: &[u8]) -> io::Result<Vec<u8>> {
     let mut dst = Vec::new();
 
@@ -135,7 +154,7 @@ fn build_parameters(lens: &[usize], src: &[u8]) -> Parameters {
     let q_shift = 5;
     let q_bits = if q_shift > 4 { 9 } else { 8 };
     let p_bits = 7;
-    let p_shift = i32::from(lens[0] > 128);
+    let p_shift = i32::from(lens.first().copied().unwrap_or_default() > 128);
 
     let q_tab: Vec<_> = (0..=u8::MAX).collect();
 
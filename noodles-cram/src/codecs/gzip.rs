@@ -45,7 +45,7 @@ pub fn encode(compression_level: Compression, src: &[u8]) -> io::Result<Vec<u8>>
     let mut dst = vec![0; max_len];
 
     let len = encoder
-        .deflate_compress(src, &mut dst)
+        .gzip_compress(src, &mut dst)
         .map_err(|_| io::Error::from(io::ErrorKind::InvalidInput))?;
 
     dst.resize(len, 0);
@@ -63,3 +63,22 @@ pub fn encode(compression_level: Compression, src: &[u8]) -> io::Result<Vec<u8>>
     encoder.write_all(src)?;
     encoder.finish()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_and_decode() -> io::Result<()> {
+        let data = b"noodles";
+
+        let compressed_data = encode(Compression::default(), data)?;
+
+        let mut buf = vec![0; data.len()];
+        decode(&compressed_data, &mut buf)?;
+
+        assert_eq!(buf, data);
+
+        Ok(())
+    }
+}
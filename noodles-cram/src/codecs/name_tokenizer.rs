@@ -1,3 +1,5 @@
+//! Name tokenizer codec.
+
 mod decode;
 mod encode;
 
@@ -93,4 +95,73 @@ I17_08765:2:124:45613:16161#9\0\
 
         Ok(())
     }
+
+    #[test]
+    fn test_round_trip() -> io::Result<()> {
+        // xorshift32
+        let mut state = 0x2545f491u32;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state
+        };
+
+        fn t(names: &[String]) -> io::Result<()> {
+            let mut data = Vec::new();
+
+            for name in names {
+                data.extend(name.as_bytes());
+                data.push(0x00);
+            }
+
+            let input = encode(&data)?;
+
+            let mut reader = &input[..];
+            let output = decode(&mut reader)?;
+
+            assert_eq!(output, data);
+
+            Ok(())
+        }
+
+        t(&[])?;
+
+        for name_count in [1, 2, 16, 512] {
+            // Illumina-style names, with duplicates and varying (padded) numeric fields
+            let mut names = Vec::new();
+
+            for _ in 0..name_count {
+                let name = format!(
+                    "A00123:8:H5KJ7DRXX:{}:{}:{:05}:{}",
+                    1 + next() % 4,
+                    1101 + next() % 3,
+                    next() % 32768,
+                    next() % 1000000,
+                );
+
+                if next() % 4 == 0 {
+                    names.push(name.clone());
+                }
+
+                names.push(name);
+            }
+
+            t(&names)?;
+
+            // arbitrary printable names
+            let names: Vec<_> = (0..name_count)
+                .map(|_| {
+                    let len = (next() % 32) as usize;
+                    (0..len)
+                        .map(|_| char::from(b'!' + (next() % 94) as u8))
+                        .collect()
+                })
+                .collect();
+
+            t(&names)?;
+        }
+
+        Ok(())
+    }
 }
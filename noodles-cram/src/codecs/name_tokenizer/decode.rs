@@ -8,6 +8,21 @@ use crate::{
     io::reader::num::read_uint7,
 };
 
+/// Decodes name tokenizer-encoded read names.
+///
+/// This returns a list of NUL-terminated names.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram::codecs::name_tokenizer;
+///
+/// let compressed_data = name_tokenizer::encode(b"r0\0r1\0")?;
+///
+/// let mut reader = &compressed_data[..];
+/// assert_eq!(name_tokenizer::decode(&mut reader)?, b"r0\0r1\0");
+/// # Ok::<_, std::io::Error>(())
+/// ```
 pub fn decode<R>(reader: &mut R) -> io::Result<Vec<u8>>
 where
     R: Read,
@@ -16,6 +31,10 @@ where
 
     let (ulen, n_names, use_arith) = read_header(reader)?;
 
+    if n_names == 0 {
+        return Ok(Vec::new());
+    }
+
     let mut b = decode_token_byte_streams(reader, use_arith, n_names)?;
 
     let mut names = vec![String::new(); n_names];
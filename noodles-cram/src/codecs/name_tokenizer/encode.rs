@@ -11,6 +11,22 @@ use crate::io::writer::num::write_uint7;
 
 const NUL: u8 = 0x00;
 
+/// Encodes read names using the name tokenizer.
+///
+/// `src` is a list of NUL-terminated names. Names must be valid UTF-8.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram::codecs::name_tokenizer;
+///
+/// let src = b"r0:1:2101\0r0:1:2102\0";
+/// let compressed_data = name_tokenizer::encode(src)?;
+///
+/// let mut reader = &compressed_data[..];
+/// assert_eq!(name_tokenizer::decode(&mut reader)?, src);
+/// # Ok::<_, std::io::Error>(())
+/// ```
 pub fn encode(mut src: &[u8]) -> io::Result<Vec<u8>> {
     let mut dst = Vec::new();
 
@@ -18,6 +34,11 @@ pub fn encode(mut src: &[u8]) -> io::Result<Vec<u8>> {
         src = buf;
     }
 
+    if src.is_empty() {
+        write_header(&mut dst, 0, 0)?;
+        return Ok(dst);
+    }
+
     let names: Vec<_> = src
         .split(|&b| b == NUL)
         .map(str::from_utf8)
@@ -5,7 +5,7 @@ mod encode;
 mod order;
 
 pub use self::order::Order;
-pub(crate) use self::{decode::decode, encode::encode};
+pub use self::{decode::decode, encode::encode};
 
 #[cfg(test)]
 mod tests {
@@ -40,4 +40,34 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_round_trip() -> io::Result<()> {
+        // xorshift32
+        let mut state = 0x2545f491u32;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state
+        };
+
+        for alphabet_size in [1, 4, 64, 256] {
+            for len in (0..=16).chain([255, 1024, 65537]) {
+                let data: Vec<_> = (0..len).map(|_| (next() % alphabet_size) as u8).collect();
+
+                for order in [Order::Zero, Order::One] {
+                    let compressed_data = encode(order, &data)?;
+
+                    let mut reader = &compressed_data[..];
+                    let decompressed_data = decode(&mut reader)?;
+
+                    assert_eq!(decompressed_data, data, "order = {order:?}, len = {len}");
+                    assert!(reader.is_empty());
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
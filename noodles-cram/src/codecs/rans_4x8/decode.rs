@@ -7,12 +7,31 @@ use byteorder::{LittleEndian, ReadBytesExt};
 
 use super::Order;
 
+/// Decodes rANS 4x8-encoded data.
+///
+/// The order is read from the encoded data.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram::codecs::rans_4x8::{self, Order};
+///
+/// let compressed_data = rans_4x8::encode(Order::Zero, b"noodles")?;
+///
+/// let mut reader = &compressed_data[..];
+/// assert_eq!(rans_4x8::decode(&mut reader)?, b"noodles");
+/// # Ok::<_, std::io::Error>(())
+/// ```
 pub fn decode<R>(reader: &mut R) -> io::Result<Vec<u8>>
 where
     R: Read,
 {
     let (order, _, data_len) = read_header(reader)?;
 
+    if data_len == 0 {
+        return Ok(Vec::new());
+    }
+
     let mut dst = vec![0; data_len];
 
     match order {
@@ -13,10 +13,36 @@ const BASE: usize = 256;
 // Lower bound `L`.
 const LOWER_BOUND: u32 = 0x800000;
 
+/// Encodes data using rANS 4x8.
+///
+/// Order-1 encoding is only used for input that is at least 4 bytes. Smaller input is encoded
+/// using order-0 encoding.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram::codecs::rans_4x8::{self, Order};
+///
+/// let data = b"noodles";
+/// let compressed_data = rans_4x8::encode(Order::One, data)?;
+///
+/// let mut reader = &compressed_data[..];
+/// assert_eq!(rans_4x8::decode(&mut reader)?, data);
+/// # Ok::<_, std::io::Error>(())
+/// ```
 pub fn encode(order: Order, src: &[u8]) -> io::Result<Vec<u8>> {
+    if src.is_empty() {
+        let mut dst = Vec::new();
+        write_header(&mut dst, order, 0, 0)?;
+        return Ok(dst);
+    }
+
+    // Order-1 encoding does not support input smaller than 4 bytes.
+    const ORDER_1_MIN_LEN: usize = 4;
+
     match order {
-        Order::Zero => order_0::encode(src),
-        Order::One => order_1::encode(src),
+        Order::One if src.len() >= ORDER_1_MIN_LEN => order_1::encode(src),
+        _ => order_0::encode(src),
     }
 }
 
@@ -2,14 +2,16 @@
 
 pub mod block_content_encoder_map;
 pub(crate) mod builder;
+pub mod checksum_mismatch;
 pub mod compression_header;
+pub mod decode_stats;
 mod header;
 mod reference_sequence_context;
 pub(crate) mod slice;
 
 pub use self::{
-    block_content_encoder_map::BlockContentEncoderMap, compression_header::CompressionHeader,
-    slice::Slice,
+    block_content_encoder_map::BlockContentEncoderMap, checksum_mismatch::ChecksumMismatch,
+    compression_header::CompressionHeader, decode_stats::DecodeStats, slice::Slice,
 };
 pub(crate) use self::{
     builder::Builder, header::Header, reference_sequence_context::ReferenceSequenceContext,
@@ -0,0 +1,188 @@
+use flate2::Compression;
+
+use super::BlockContentEncoderMap;
+use crate::{
+    codecs::{rans_4x8, rans_nx16, Encoder},
+    data_container::compression_header::data_series_encoding_map::{
+        data_series::STANDARD_DATA_SERIES, DataSeries,
+    },
+};
+
+/// A CRAM encoding profile.
+///
+/// A profile is a preset block content-encoder map that trades encoding speed for output size.
+/// These are similar to the htslib CRAM profiles (`fast`, `normal`, `small`, and `archive`).
+///
+/// The `small` and `archive` profiles use CRAM 3.1 codecs (rANS Nx16, the name tokenizer, and
+/// fqzcomp).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Profile {
+    /// Fast encoding using gzip with the fastest compression level.
+    Fast,
+    /// Balanced encoding using rANS 4x8 and gzip.
+    #[default]
+    Normal,
+    /// Smaller output using rANS Nx16, the name tokenizer, and fqzcomp.
+    Small,
+    /// Smallest output using rANS Nx16, the name tokenizer, fqzcomp, bzip2, and xz, with the
+    /// best compression levels.
+    Archive,
+}
+
+impl From<Profile> for BlockContentEncoderMap {
+    fn from(profile: Profile) -> Self {
+        let mut builder = Self::builder();
+
+        for &data_series in STANDARD_DATA_SERIES {
+            builder = builder.set_data_series_encoder(
+                data_series,
+                Some(data_series_encoder(profile, data_series)),
+            );
+        }
+
+        builder
+            .set_core_data_encoder(Some(core_data_encoder(profile)))
+            .build()
+    }
+}
+
+fn core_data_encoder(profile: Profile) -> Encoder {
+    match profile {
+        Profile::Fast => Encoder::Gzip(Compression::fast()),
+        Profile::Normal | Profile::Small => Encoder::Gzip(Compression::default()),
+        Profile::Archive => Encoder::Gzip(Compression::best()),
+    }
+}
+
+fn data_series_encoder(profile: Profile, data_series: DataSeries) -> Encoder {
+    match profile {
+        Profile::Fast => Encoder::Gzip(Compression::fast()),
+        Profile::Normal => match data_series {
+            DataSeries::ReadNames | DataSeries::Insertion | DataSeries::SoftClip => {
+                Encoder::Gzip(Compression::default())
+            }
+            DataSeries::Bases | DataSeries::QualityScores => Encoder::Rans4x8(rans_4x8::Order::One),
+            _ => Encoder::Rans4x8(rans_4x8::Order::Zero),
+        },
+        Profile::Small => match data_series {
+            DataSeries::ReadNames => Encoder::NameTokenizer,
+            DataSeries::QualityScores => Encoder::Fqzcomp,
+            DataSeries::Insertion | DataSeries::SoftClip => {
+                Encoder::Bzip2(bzip2::Compression::default())
+            }
+            DataSeries::Bases => Encoder::RansNx16(rans_nx16::Flags::ORDER),
+            _ => Encoder::RansNx16(rans_nx16::Flags::empty()),
+        },
+        Profile::Archive => match data_series {
+            DataSeries::ReadNames => Encoder::NameTokenizer,
+            DataSeries::QualityScores => Encoder::Fqzcomp,
+            DataSeries::Insertion | DataSeries::SoftClip => {
+                Encoder::Bzip2(bzip2::Compression::best())
+            }
+            DataSeries::Bases => Encoder::Lzma(9),
+            _ => Encoder::RansNx16(rans_nx16::Flags::ORDER),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::*;
+    use crate::io::writer::builder::uses_cram_3_1_codecs;
+
+    #[test]
+    fn test_from_profile_for_block_content_encoder_map() {
+        for (profile, expected) in [
+            (Profile::Fast, false),
+            (Profile::Normal, false),
+            (Profile::Small, true),
+            (Profile::Archive, true),
+        ] {
+            let map = BlockContentEncoderMap::from(profile);
+            assert_eq!(uses_cram_3_1_codecs(&map), expected);
+        }
+
+        let map = BlockContentEncoderMap::from(Profile::Small);
+        assert!(matches!(
+            map.get_data_series_encoder(DataSeries::QualityScores.into()),
+            Some(Some(Encoder::Fqzcomp))
+        ));
+    }
+
+    #[test]
+    fn test_write_with_profile() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_sam::{
+            self as sam,
+            alignment::{io::Write, record::Flags, record_buf::QualityScores, RecordBuf},
+        };
+
+        use crate::io::{writer::Builder, Reader};
+
+        let header = sam::Header::default();
+
+        let records: Vec<_> = (0..16)
+            .map(|i| {
+                RecordBuf::builder()
+                    .set_name(format!("r{i}"))
+                    .set_flags(Flags::UNMAPPED)
+                    .set_sequence(b"ACGTACGTNNACGT".to_vec().into())
+                    .set_quality_scores(QualityScores::from(vec![(i % 40) as u8; 14]))
+                    .build()
+            })
+            .collect();
+
+        for profile in [
+            Profile::Fast,
+            Profile::Normal,
+            Profile::Small,
+            Profile::Archive,
+        ] {
+            let mut writer = Builder::default()
+                .set_profile(profile)
+                .build_with_writer(Vec::new());
+
+            writer.write_alignment_header(&header)?;
+
+            for record in &records {
+                writer.write_alignment_record(&header, record)?;
+            }
+
+            writer.try_finish(&header)?;
+
+            let mut reader = Reader::new(&writer.get_ref()[..]);
+            reader.read_header()?;
+
+            let actual: Vec<_> = reader
+                .records(&header)
+                .map(|result| {
+                    result.and_then(|record| {
+                        record.try_into_alignment_record(&header).map(|record| {
+                            (
+                                record.name().map(|name| name.to_vec()),
+                                record.sequence().as_ref().to_vec(),
+                                record.quality_scores().as_ref().to_vec(),
+                            )
+                        })
+                    })
+                })
+                .collect::<io::Result<_>>()?;
+
+            let expected: Vec<_> = records
+                .iter()
+                .map(|record| {
+                    (
+                        record.name().map(|name| name.to_vec()),
+                        record.sequence().as_ref().to_vec(),
+                        record.quality_scores().as_ref().to_vec(),
+                    )
+                })
+                .collect();
+
+            assert_eq!(actual, expected, "{profile:?}");
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+//! CRAM data container slice checksum mismatch.
+
+use std::{error, fmt};
+
+/// A slice block data checksum mismatch.
+///
+/// A slice header can include an MD5 checksum of its block data (`BD`), i.e., the uncompressed
+/// data of the core data block followed by each external block, in order. A mismatch means the
+/// slice is corrupt.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChecksumMismatch {
+    record_counter: u64,
+    expected: [u8; 16],
+    actual: [u8; 16],
+}
+
+impl ChecksumMismatch {
+    pub(crate) fn new(record_counter: u64, expected: [u8; 16], actual: [u8; 16]) -> Self {
+        Self {
+            record_counter,
+            expected,
+            actual,
+        }
+    }
+
+    /// Returns the record counter of the slice, i.e., the ID of its first record.
+    pub fn record_counter(&self) -> u64 {
+        self.record_counter
+    }
+
+    /// Returns the checksum stored in the slice header.
+    pub fn expected(&self) -> &[u8; 16] {
+        &self.expected
+    }
+
+    /// Returns the checksum of the decoded block data.
+    pub fn actual(&self) -> &[u8; 16] {
+        &self.actual
+    }
+}
+
+impl error::Error for ChecksumMismatch {}
+
+impl fmt::Display for ChecksumMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "slice block data checksum mismatch (record counter = {}): expected {:?}, got {:?}",
+            self.record_counter, self.expected, self.actual
+        )
+    }
+}
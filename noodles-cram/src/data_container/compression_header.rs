@@ -4,8 +4,10 @@ mod builder;
 pub mod data_series_encoding_map;
 pub(crate) mod encoding;
 pub mod preservation_map;
+pub mod summary;
 mod tag_encoding_map;
 
+pub use self::summary::Summary;
 pub(crate) use self::{
     builder::Builder,
     data_series_encoding_map::DataSeriesEncodingMap,
@@ -53,4 +55,28 @@ impl CompressionHeader {
     pub(crate) fn tag_encoding_map(&self) -> &TagEncodingMap {
         &self.tag_encoding_map
     }
+
+    /// Returns a read-only summary of the compression header.
+    ///
+    /// This describes the preservation map and which codecs and external blocks each data series
+    /// and tag is encoded with. The summary can be dumped using its [`std::fmt::Display`]
+    /// implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram as cram;
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::io::Reader::new)?;
+    /// reader.read_header()?;
+    ///
+    /// while let Some(data_container) = reader.read_data_container()? {
+    ///     print!("{}", data_container.compression_header().summary());
+    /// }
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn summary(&self) -> Summary {
+        Summary::from(self)
+    }
 }
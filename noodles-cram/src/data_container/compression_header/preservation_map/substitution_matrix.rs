@@ -1,3 +1,5 @@
+//! CRAM data container preservation map substitution matrix.
+
 mod builder;
 mod histogram;
 
@@ -10,20 +12,66 @@ use crate::record::feature::substitution::Base;
 
 type Substitutions = [[Base; 4]; 5];
 
+/// A CRAM data container preservation map substitution matrix.
+///
+/// A substitution matrix maps each reference base and a 2-bit substitution code to a read base.
+/// Use a [`Builder`] to compute one from observed base substitutions.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SubstitutionMatrix {
     substitutions: Substitutions,
 }
 
 impl SubstitutionMatrix {
+    /// Returns a builder to create a substitution matrix from observed base substitutions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::compression_header::preservation_map::SubstitutionMatrix;
+    /// let builder = SubstitutionMatrix::builder();
+    /// ```
     pub fn builder() -> Builder {
         Builder::default()
     }
 
+    /// Returns the read base for the given reference base and substitution code.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the substitution code is > 3.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{
+    ///     data_container::compression_header::preservation_map::SubstitutionMatrix,
+    ///     record::feature::substitution::Base,
+    /// };
+    ///
+    /// let substitution_matrix = SubstitutionMatrix::default();
+    /// assert_eq!(substitution_matrix.get(Base::A, 0b00), Base::C);
+    /// ```
     pub fn get(&self, reference_base: Base, substitution_code: u8) -> Base {
         self.substitutions[reference_base as usize][substitution_code as usize]
     }
 
+    /// Returns the substitution code for the given reference and read bases.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the reference base and read base are the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{
+    ///     data_container::compression_header::preservation_map::SubstitutionMatrix,
+    ///     record::feature::substitution::Base,
+    /// };
+    ///
+    /// let substitution_matrix = SubstitutionMatrix::default();
+    /// assert_eq!(substitution_matrix.find_code(Base::A, Base::G), 0b01);
+    /// ```
     pub fn find_code(&self, reference_base: Base, read_base: Base) -> u8 {
         for code in [0b00, 0b01, 0b10, 0b11] {
             if self.get(reference_base, code) == read_base {
@@ -77,6 +125,7 @@ impl From<Histogram> for SubstitutionMatrix {
     }
 }
 
+/// An error returned when a substitution matrix fails to convert from a byte array.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TryFromByteArrayError([u8; 5]);
 
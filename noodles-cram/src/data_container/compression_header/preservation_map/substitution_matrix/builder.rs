@@ -1,20 +1,66 @@
 use super::{Histogram, SubstitutionMatrix};
 use crate::{
-    record::{feature::substitution, Feature},
+    record::{
+        feature::substitution::{self, Base},
+        Feature,
+    },
     Record,
 };
 
+/// A CRAM data container preservation map substitution matrix builder.
+///
+/// The builder counts observed base substitutions. The built substitution matrix assigns codes
+/// for each reference base by descending substitution frequency, with ties broken using the
+/// fixed order ACGTN.
 #[derive(Debug, Default)]
 pub struct Builder {
     histogram: Histogram,
 }
 
 impl Builder {
+    /// Adds an observed substitution of a reference base by a read base.
+    ///
+    /// Same base substitutions are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{
+    ///     data_container::compression_header::preservation_map::substitution_matrix,
+    ///     record::feature::substitution::Base,
+    /// };
+    ///
+    /// let mut builder = substitution_matrix::Builder::default();
+    /// builder.add_substitution(Base::A, Base::T);
+    /// ```
+    pub fn add_substitution(&mut self, reference_base: Base, read_base: Base) {
+        if reference_base != read_base {
+            self.histogram.hit(reference_base, read_base);
+        }
+    }
+
+    /// Adds the base substitutions of a record.
+    ///
+    /// # Panics
+    ///
+    /// This panics if a substitution feature is a substitution code rather than bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{
+    ///     data_container::compression_header::preservation_map::substitution_matrix,
+    ///     Record,
+    /// };
+    ///
+    /// let mut builder = substitution_matrix::Builder::default();
+    /// builder.update(&Record::default());
+    /// ```
     pub fn update(&mut self, record: &Record) {
         for feature in record.features().iter() {
             match feature {
                 Feature::Substitution(_, substitution::Value::Bases(reference_base, read_base)) => {
-                    self.histogram.hit(*reference_base, *read_base);
+                    self.add_substitution(*reference_base, *read_base);
                 }
                 Feature::Substitution(_, substitution::Value::Code(_)) => {
                     panic!("substitution matrix cannot be built from substitution codes");
@@ -24,6 +70,27 @@ impl Builder {
         }
     }
 
+    /// Builds a substitution matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{
+    ///     data_container::compression_header::preservation_map::substitution_matrix,
+    ///     record::feature::substitution::Base,
+    /// };
+    ///
+    /// let mut builder = substitution_matrix::Builder::default();
+    /// builder.add_substitution(Base::A, Base::T);
+    /// builder.add_substitution(Base::A, Base::T);
+    /// builder.add_substitution(Base::A, Base::G);
+    ///
+    /// let substitution_matrix = builder.build();
+    /// assert_eq!(substitution_matrix.find_code(Base::A, Base::T), 0b00);
+    /// assert_eq!(substitution_matrix.find_code(Base::A, Base::G), 0b01);
+    /// assert_eq!(substitution_matrix.find_code(Base::A, Base::C), 0b10);
+    /// assert_eq!(substitution_matrix.find_code(Base::A, Base::N), 0b11);
+    /// ```
     pub fn build(self) -> SubstitutionMatrix {
         SubstitutionMatrix::from(self.histogram)
     }
@@ -95,4 +162,17 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_add_substitution() {
+        let mut builder = Builder::default();
+        builder.add_substitution(Base::C, Base::N);
+        builder.add_substitution(Base::C, Base::C);
+        let matrix = builder.build();
+
+        assert_eq!(
+            matrix.substitutions[Base::C as usize],
+            [Base::N, Base::A, Base::G, Base::T]
+        );
+    }
 }
@@ -3,13 +3,30 @@
 mod builder;
 mod key;
 
-pub(crate) use self::builder::Builder;
-pub use self::key::Key;
+pub use self::{builder::Builder, key::Key};
 
 use std::ops::Deref;
 
+/// A CRAM data container preservation map tag IDs dictionary.
+///
+/// Each line is a list of tag keys (tag-type pairs) of a record. Records refer to a line by its
+/// index.
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub(crate) struct TagIdsDictionary(Vec<Vec<Key>>);
+pub struct TagIdsDictionary(Vec<Vec<Key>>);
+
+impl TagIdsDictionary {
+    /// Returns a builder to create a tag IDs dictionary from record data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::compression_header::preservation_map::TagIdsDictionary;
+    /// let builder = TagIdsDictionary::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+}
 
 impl Deref for TagIdsDictionary {
     type Target = [Vec<Key>];
@@ -1,29 +1,92 @@
 use std::collections::HashMap;
 
+use std::cmp;
+
+use noodles_sam::alignment::record_buf::Data;
+
 use super::{Key, TagIdsDictionary};
 use crate::Record;
 
+/// A CRAM data container preservation map tag IDs dictionary builder.
+///
+/// The builder counts the occurrences of each distinct list of tag keys. In the built
+/// dictionary, lines are ordered by descending frequency, with ties broken by first occurrence.
 #[derive(Debug, Default)]
 pub struct Builder {
-    keys_indices: HashMap<Vec<Key>, usize>,
+    keys_indices: HashMap<Vec<Key>, (usize, u64)>,
 }
 
 impl Builder {
-    pub fn update(&mut self, record: &Record) {
-        let keys: Vec<_> = record
-            .tags()
+    /// Adds a list of tag keys, i.e., a line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::compression_header::preservation_map::tag_ids_dictionary;
+    /// use noodles_sam::alignment::record::data::field::{Tag, Type};
+    ///
+    /// let mut builder = tag_ids_dictionary::Builder::default();
+    /// builder.add_keys(vec![tag_ids_dictionary::Key::new(Tag::ALIGNMENT_HIT_COUNT, Type::UInt8)]);
+    /// ```
+    pub fn add_keys(&mut self, keys: Vec<Key>) {
+        let next_index = self.keys_indices.len();
+        let (_, count) = self.keys_indices.entry(keys).or_insert((next_index, 0));
+        *count += 1;
+    }
+
+    /// Adds the tag keys of record data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::compression_header::preservation_map::tag_ids_dictionary;
+    /// use noodles_sam::alignment::{
+    ///     record::data::field::Tag,
+    ///     record_buf::{data::field::Value, Data},
+    /// };
+    ///
+    /// let data: Data = [(Tag::ALIGNMENT_HIT_COUNT, Value::from(1))].into_iter().collect();
+    ///
+    /// let mut builder = tag_ids_dictionary::Builder::default();
+    /// builder.add_data(&data);
+    /// ```
+    pub fn add_data(&mut self, data: &Data) {
+        let keys = data
             .iter()
             .map(|(tag, value)| Key::new(tag, value.ty()))
             .collect();
 
-        let next_index = self.keys_indices.len();
+        self.add_keys(keys);
+    }
 
-        self.keys_indices.entry(keys).or_insert(next_index);
+    /// Adds the tag keys of a record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{
+    ///     data_container::compression_header::preservation_map::tag_ids_dictionary, Record,
+    /// };
+    ///
+    /// let mut builder = tag_ids_dictionary::Builder::default();
+    /// builder.update(&Record::default());
+    /// ```
+    pub fn update(&mut self, record: &Record) {
+        self.add_data(record.tags());
     }
 
-    pub(crate) fn build(self) -> TagIdsDictionary {
+    /// Builds a tag IDs dictionary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::compression_header::preservation_map::tag_ids_dictionary;
+    /// let dictionary = tag_ids_dictionary::Builder::default().build();
+    /// assert!(dictionary.is_empty());
+    /// ```
+    pub fn build(self) -> TagIdsDictionary {
         let mut lines: Vec<_> = self.keys_indices.into_iter().collect();
-        lines.sort_by_key(|(_, index)| *index);
+        lines.sort_by_key(|(_, (index, count))| (cmp::Reverse(*count), *index));
         let dictionary: Vec<_> = lines.into_iter().map(|(keys, _)| keys).collect();
         TagIdsDictionary::from(dictionary)
     }
@@ -68,4 +131,20 @@ mod tests {
             ]
         )
     }
+
+    #[test]
+    fn test_build_orders_lines_by_frequency() {
+        let mut builder = Builder::default();
+
+        let nh = vec![Key::new(Tag::ALIGNMENT_HIT_COUNT, Type::Int8)];
+        let co = vec![Key::new(Tag::COMMENT, Type::String)];
+
+        builder.add_keys(nh.clone());
+        builder.add_keys(co.clone());
+        builder.add_keys(co.clone());
+        builder.add_keys(Vec::new());
+
+        let dictionary = builder.build();
+        assert_eq!(*dictionary, [co, nh, Vec::new()]);
+    }
 }
@@ -0,0 +1,574 @@
+//! CRAM data container compression header summary.
+
+use std::fmt;
+
+use super::{
+    data_series_encoding_map::DataSeries,
+    encoding::codec::{Byte, ByteArray, Integer},
+    preservation_map::tag_ids_dictionary::Key,
+    CompressionHeader, Encoding,
+};
+use crate::{container::block, record::feature::substitution::Base};
+
+const BASES: [Base; 5] = [Base::A, Base::C, Base::G, Base::T, Base::N];
+
+/// A summary of an encoding in a compression header.
+///
+/// This describes the codec and its parameters, including the content IDs of the external blocks
+/// it reads from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EncodingSummary {
+    /// Values are read from an external block (`EXTERNAL`).
+    External {
+        /// The content ID of the external block.
+        content_id: i32,
+    },
+    /// Golomb coding (`GOLOMB`).
+    Golomb {
+        /// The offset.
+        offset: i32,
+        /// The Golomb parameter.
+        m: i32,
+    },
+    /// Canonical Huffman coding (`HUFFMAN`).
+    Huffman {
+        /// The alphabet.
+        alphabet: Vec<i32>,
+        /// The bit lengths of each symbol in the alphabet.
+        bit_lens: Vec<u32>,
+    },
+    /// Byte arrays with an encoded length (`BYTE_ARRAY_LEN`).
+    ByteArrayLen {
+        /// The encoding of the length.
+        len_encoding: Box<EncodingSummary>,
+        /// The encoding of the values.
+        value_encoding: Box<EncodingSummary>,
+    },
+    /// Byte arrays terminated by a stop byte (`BYTE_ARRAY_STOP`).
+    ByteArrayStop {
+        /// The stop byte.
+        stop_byte: u8,
+        /// The content ID of the external block.
+        content_id: i32,
+    },
+    /// Binary coding (`BETA`).
+    Beta {
+        /// The offset.
+        offset: i32,
+        /// The number of bits.
+        len: u32,
+    },
+    /// Subexponential coding (`SUBEXP`).
+    Subexp {
+        /// The offset.
+        offset: i32,
+        /// The order.
+        k: i32,
+    },
+    /// Golomb-Rice coding (`GOLOMB_RICE`).
+    GolombRice {
+        /// The offset.
+        offset: i32,
+        /// The log base 2 of the Golomb parameter.
+        log2_m: i32,
+    },
+    /// Elias gamma coding (`GAMMA`).
+    Gamma {
+        /// The offset.
+        offset: i32,
+    },
+}
+
+impl EncodingSummary {
+    /// Returns the content IDs of the external blocks the encoding reads from.
+    ///
+    /// Encodings that only read from the core data block return an empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::compression_header::summary::EncodingSummary;
+    ///
+    /// let encoding = EncodingSummary::ByteArrayLen {
+    ///     len_encoding: Box::new(EncodingSummary::External { content_id: 8 }),
+    ///     value_encoding: Box::new(EncodingSummary::External { content_id: 13 }),
+    /// };
+    ///
+    /// assert_eq!(encoding.content_ids(), [8, 13]);
+    /// ```
+    pub fn content_ids(&self) -> Vec<i32> {
+        match self {
+            Self::External { content_id } | Self::ByteArrayStop { content_id, .. } => {
+                vec![*content_id]
+            }
+            Self::ByteArrayLen {
+                len_encoding,
+                value_encoding,
+            } => {
+                let mut content_ids = len_encoding.content_ids();
+
+                for content_id in value_encoding.content_ids() {
+                    if !content_ids.contains(&content_id) {
+                        content_ids.push(content_id);
+                    }
+                }
+
+                content_ids
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for EncodingSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::External { content_id } => write!(f, "EXTERNAL(content_id={content_id})"),
+            Self::Golomb { offset, m } => write!(f, "GOLOMB(offset={offset}, m={m})"),
+            Self::Huffman { alphabet, bit_lens } => {
+                write!(f, "HUFFMAN(alphabet={alphabet:?}, bit_lens={bit_lens:?})")
+            }
+            Self::ByteArrayLen {
+                len_encoding,
+                value_encoding,
+            } => write!(
+                f,
+                "BYTE_ARRAY_LEN(len={len_encoding}, value={value_encoding})"
+            ),
+            Self::ByteArrayStop {
+                stop_byte,
+                content_id,
+            } => write!(
+                f,
+                "BYTE_ARRAY_STOP(stop_byte={stop_byte:#04x}, content_id={content_id})"
+            ),
+            Self::Beta { offset, len } => write!(f, "BETA(offset={offset}, len={len})"),
+            Self::Subexp { offset, k } => write!(f, "SUBEXP(offset={offset}, k={k})"),
+            Self::GolombRice { offset, log2_m } => {
+                write!(f, "GOLOMB_RICE(offset={offset}, log2_m={log2_m})")
+            }
+            Self::Gamma { offset } => write!(f, "GAMMA(offset={offset})"),
+        }
+    }
+}
+
+impl From<&Encoding<Integer>> for EncodingSummary {
+    fn from(encoding: &Encoding<Integer>) -> Self {
+        match encoding.get() {
+            Integer::External(content_id) => Self::External {
+                content_id: i32::from(*content_id),
+            },
+            Integer::Golomb(offset, m) => Self::Golomb {
+                offset: *offset,
+                m: *m,
+            },
+            Integer::Huffman(alphabet, bit_lens) => Self::Huffman {
+                alphabet: alphabet.clone(),
+                bit_lens: bit_lens.clone(),
+            },
+            Integer::Beta(offset, len) => Self::Beta {
+                offset: *offset,
+                len: *len,
+            },
+            Integer::Subexp(offset, k) => Self::Subexp {
+                offset: *offset,
+                k: *k,
+            },
+            Integer::GolombRice(offset, log2_m) => Self::GolombRice {
+                offset: *offset,
+                log2_m: *log2_m,
+            },
+            Integer::Gamma(offset) => Self::Gamma { offset: *offset },
+        }
+    }
+}
+
+impl From<&Encoding<Byte>> for EncodingSummary {
+    fn from(encoding: &Encoding<Byte>) -> Self {
+        match encoding.get() {
+            Byte::External(content_id) => Self::External {
+                content_id: i32::from(*content_id),
+            },
+            Byte::Huffman(alphabet, bit_lens) => Self::Huffman {
+                alphabet: alphabet.clone(),
+                bit_lens: bit_lens.clone(),
+            },
+        }
+    }
+}
+
+impl From<&Encoding<ByteArray>> for EncodingSummary {
+    fn from(encoding: &Encoding<ByteArray>) -> Self {
+        match encoding.get() {
+            ByteArray::ByteArrayLen(len_encoding, value_encoding) => Self::ByteArrayLen {
+                len_encoding: Box::new(Self::from(len_encoding)),
+                value_encoding: Box::new(Self::from(value_encoding)),
+            },
+            ByteArray::ByteArrayStop(stop_byte, content_id) => Self::ByteArrayStop {
+                stop_byte: *stop_byte,
+                content_id: i32::from(*content_id),
+            },
+        }
+    }
+}
+
+/// A read-only summary of a compression header.
+///
+/// This lists the preservation map, the encodings of the data series that are present, and the
+/// encodings of tags, which together describe which external blocks and codecs each field is
+/// stored in. The [`fmt::Display`] implementation writes a human-readable dump.
+///
+/// This is created by [`CompressionHeader::summary`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Summary {
+    read_names_included: bool,
+    ap_data_series_delta: bool,
+    is_reference_required: bool,
+    substitution_matrix: [[Base; 4]; 5],
+    tag_ids_dictionary: Vec<Vec<Key>>,
+    data_series_encodings: Vec<(DataSeries, EncodingSummary)>,
+    tag_encodings: Vec<(Key, EncodingSummary)>,
+}
+
+impl Summary {
+    /// Returns whether read names are preserved for all records (`RN`).
+    pub fn read_names_included(&self) -> bool {
+        self.read_names_included
+    }
+
+    /// Returns whether the alignment starts (`AP`) are delta-encoded.
+    pub fn ap_data_series_delta(&self) -> bool {
+        self.ap_data_series_delta
+    }
+
+    /// Returns whether a reference sequence is required to restore the data (`RR`).
+    pub fn is_reference_required(&self) -> bool {
+        self.is_reference_required
+    }
+
+    /// Returns the substitution matrix (`SM`).
+    ///
+    /// Rows are the reference bases A, C, G, T, and N, and columns are the read bases of
+    /// substitution codes 0 to 3.
+    pub fn substitution_matrix(&self) -> &[[Base; 4]; 5] {
+        &self.substitution_matrix
+    }
+
+    /// Returns the tag IDs dictionary (`TD`).
+    pub fn tag_ids_dictionary(&self) -> &[Vec<Key>] {
+        &self.tag_ids_dictionary
+    }
+
+    /// Returns the encodings of the data series that are present, in specification order.
+    pub fn data_series_encodings(&self) -> &[(DataSeries, EncodingSummary)] {
+        &self.data_series_encodings
+    }
+
+    /// Returns the encodings of the tags in the tag IDs dictionary.
+    ///
+    /// Tags are listed in order of first appearance in the tag IDs dictionary. Tags without an
+    /// encoding are omitted.
+    pub fn tag_encodings(&self) -> &[(Key, EncodingSummary)] {
+        &self.tag_encodings
+    }
+}
+
+impl From<&CompressionHeader> for Summary {
+    fn from(compression_header: &CompressionHeader) -> Self {
+        let preservation_map = compression_header.preservation_map();
+
+        let matrix = preservation_map.substitution_matrix();
+        let mut substitution_matrix = [[Base::N; 4]; 5];
+
+        for (row, reference_base) in substitution_matrix.iter_mut().zip(BASES) {
+            for (code, read_base) in (0..).zip(row.iter_mut()) {
+                *read_base = matrix.get(reference_base, code);
+            }
+        }
+
+        let tag_ids_dictionary: Vec<_> = preservation_map
+            .tag_ids_dictionary()
+            .iter()
+            .cloned()
+            .collect();
+
+        let tag_encoding_map = compression_header.tag_encoding_map();
+        let mut tag_encodings: Vec<(Key, EncodingSummary)> = Vec::new();
+
+        for &key in tag_ids_dictionary.iter().flatten() {
+            if tag_encodings.iter().any(|(k, _)| *k == key) {
+                continue;
+            }
+
+            if let Some(encoding) = tag_encoding_map.get(&block::ContentId::from(key)) {
+                tag_encodings.push((key, EncodingSummary::from(encoding)));
+            }
+        }
+
+        Self {
+            read_names_included: preservation_map.read_names_included(),
+            ap_data_series_delta: preservation_map.ap_data_series_delta(),
+            is_reference_required: preservation_map.is_reference_required(),
+            substitution_matrix,
+            tag_ids_dictionary,
+            data_series_encodings: data_series_encodings(compression_header),
+            tag_encodings,
+        }
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "preservation map:")?;
+        writeln!(f, "  RN: {}", self.read_names_included)?;
+        writeln!(f, "  AP: {}", self.ap_data_series_delta)?;
+        writeln!(f, "  RR: {}", self.is_reference_required)?;
+
+        write!(f, "  SM:")?;
+
+        for (reference_base, row) in BASES.iter().zip(&self.substitution_matrix) {
+            write!(f, " {}>", char::from(u8::from(*reference_base)))?;
+
+            for read_base in row {
+                write!(f, "{}", char::from(u8::from(*read_base)))?;
+            }
+        }
+
+        writeln!(f)?;
+
+        writeln!(f, "  TD:")?;
+
+        for (i, keys) in self.tag_ids_dictionary.iter().enumerate() {
+            write!(f, "    {i}:")?;
+
+            for key in keys {
+                write!(f, " ")?;
+                fmt_key(f, *key)?;
+            }
+
+            writeln!(f)?;
+        }
+
+        writeln!(f, "data series encodings:")?;
+
+        for (data_series, encoding) in &self.data_series_encodings {
+            let [a, b] = <[u8; 2]>::from(*data_series);
+            writeln!(f, "  {}{}: {encoding}", char::from(a), char::from(b))?;
+        }
+
+        writeln!(f, "tag encodings:")?;
+
+        for (key, encoding) in &self.tag_encodings {
+            write!(f, "  ")?;
+            fmt_key(f, *key)?;
+            writeln!(f, ": {encoding}")?;
+        }
+
+        Ok(())
+    }
+}
+
+fn fmt_key(f: &mut fmt::Formatter<'_>, key: Key) -> fmt::Result {
+    use noodles_bam::record::codec::encoder::data::field::ty::type_to_u8;
+
+    let [a, b]: [u8; 2] = key.tag().into();
+    let ty = type_to_u8(key.ty());
+
+    write!(f, "{}{}:{}", char::from(a), char::from(b), char::from(ty))
+}
+
+fn data_series_encodings(
+    compression_header: &CompressionHeader,
+) -> Vec<(DataSeries, EncodingSummary)> {
+    let map = compression_header.data_series_encoding_map();
+
+    [
+        (
+            DataSeries::BamBitFlags,
+            Some(map.bam_bit_flags_encoding().into()),
+        ),
+        (
+            DataSeries::CramBitFlags,
+            Some(map.cram_bit_flags_encoding().into()),
+        ),
+        (
+            DataSeries::ReferenceId,
+            map.reference_id_encoding().map(EncodingSummary::from),
+        ),
+        (
+            DataSeries::ReadLengths,
+            Some(map.read_lengths_encoding().into()),
+        ),
+        (
+            DataSeries::InSeqPositions,
+            Some(map.in_seq_positions_encoding().into()),
+        ),
+        (
+            DataSeries::ReadGroups,
+            Some(map.read_groups_encoding().into()),
+        ),
+        (
+            DataSeries::ReadNames,
+            map.read_names_encoding().map(EncodingSummary::from),
+        ),
+        (
+            DataSeries::NextMateBitFlags,
+            map.next_mate_bit_flags_encoding()
+                .map(EncodingSummary::from),
+        ),
+        (
+            DataSeries::NextFragmentReferenceSequenceId,
+            map.next_fragment_reference_sequence_id_encoding()
+                .map(EncodingSummary::from),
+        ),
+        (
+            DataSeries::NextMateAlignmentStart,
+            map.next_mate_alignment_start_encoding()
+                .map(EncodingSummary::from),
+        ),
+        (
+            DataSeries::TemplateSize,
+            map.template_size_encoding().map(EncodingSummary::from),
+        ),
+        (
+            DataSeries::DistanceToNextFragment,
+            map.distance_to_next_fragment_encoding()
+                .map(EncodingSummary::from),
+        ),
+        (DataSeries::TagIds, Some(map.tag_ids_encoding().into())),
+        (
+            DataSeries::NumberOfReadFeatures,
+            map.number_of_read_features_encoding()
+                .map(EncodingSummary::from),
+        ),
+        (
+            DataSeries::ReadFeaturesCodes,
+            map.read_features_codes_encoding()
+                .map(EncodingSummary::from),
+        ),
+        (
+            DataSeries::InReadPositions,
+            map.in_read_positions_encoding().map(EncodingSummary::from),
+        ),
+        (
+            DataSeries::DeletionLengths,
+            map.deletion_lengths_encoding().map(EncodingSummary::from),
+        ),
+        (
+            DataSeries::StretchesOfBases,
+            map.stretches_of_bases_encoding().map(EncodingSummary::from),
+        ),
+        (
+            DataSeries::StretchesOfQualityScores,
+            map.stretches_of_quality_scores_encoding()
+                .map(EncodingSummary::from),
+        ),
+        (
+            DataSeries::BaseSubstitutionCodes,
+            map.base_substitution_codes_encoding()
+                .map(EncodingSummary::from),
+        ),
+        (
+            DataSeries::Insertion,
+            map.insertion_encoding().map(EncodingSummary::from),
+        ),
+        (
+            DataSeries::ReferenceSkipLength,
+            map.reference_skip_length_encoding()
+                .map(EncodingSummary::from),
+        ),
+        (
+            DataSeries::Padding,
+            map.padding_encoding().map(EncodingSummary::from),
+        ),
+        (
+            DataSeries::HardClip,
+            map.hard_clip_encoding().map(EncodingSummary::from),
+        ),
+        (
+            DataSeries::SoftClip,
+            map.soft_clip_encoding().map(EncodingSummary::from),
+        ),
+        (
+            DataSeries::MappingQualities,
+            map.mapping_qualities_encoding().map(EncodingSummary::from),
+        ),
+        (
+            DataSeries::Bases,
+            map.bases_encoding().map(EncodingSummary::from),
+        ),
+        (
+            DataSeries::QualityScores,
+            map.quality_scores_encoding().map(EncodingSummary::from),
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(data_series, encoding)| encoding.map(|e| (data_series, e)))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::alignment::{
+        record::data::field::{Tag, Type},
+        record_buf::{data::field::Value, Data},
+    };
+
+    use super::*;
+    use crate::Record;
+
+    #[test]
+    fn test_from_compression_header_for_summary() {
+        let record = Record::builder()
+            .set_tags(Data::from_iter([(
+                Tag::ALIGNMENT_HIT_COUNT,
+                Value::from(1u8),
+            )]))
+            .build();
+
+        let mut builder = CompressionHeader::builder();
+        builder.update(&record);
+        let compression_header = builder.build();
+
+        let summary = Summary::from(&compression_header);
+
+        assert_eq!(summary.data_series_encodings().len(), 28);
+        assert_eq!(
+            summary.data_series_encodings()[0],
+            (
+                DataSeries::BamBitFlags,
+                EncodingSummary::External { content_id: 1 }
+            )
+        );
+        assert_eq!(
+            summary.substitution_matrix()[0],
+            [Base::C, Base::G, Base::T, Base::N]
+        );
+
+        let key = Key::new(Tag::ALIGNMENT_HIT_COUNT, Type::UInt8);
+        assert_eq!(summary.tag_ids_dictionary(), [vec![key]]);
+        assert_eq!(
+            summary.tag_encodings(),
+            [(
+                key,
+                EncodingSummary::ByteArrayLen {
+                    len_encoding: Box::new(EncodingSummary::External {
+                        content_id: 5130307
+                    }),
+                    value_encoding: Box::new(EncodingSummary::External {
+                        content_id: 5130307
+                    }),
+                }
+            )]
+        );
+
+        let s = summary.to_string();
+        assert!(s.contains("\n  BF: EXTERNAL(content_id=1)\n"));
+        assert!(s.contains("\n  RN: BYTE_ARRAY_STOP(stop_byte=0x00, content_id=7)\n"));
+        assert!(s.contains("\n  SM: A>CGTN C>AGTN G>ACTN T>ACGN N>ACGT\n"));
+        assert!(s.contains("\n    0: NH:C\n"));
+        assert!(s.contains(
+            "\n  NH:C: BYTE_ARRAY_LEN(len=EXTERNAL(content_id=5130307), value=EXTERNAL(content_id=5130307))\n"
+        ));
+    }
+}
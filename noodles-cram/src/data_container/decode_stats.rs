@@ -0,0 +1,120 @@
+//! CRAM data container decode statistics.
+
+use std::time::Duration;
+
+pub use crate::container::block::{CompressionMethod, ContentType};
+
+/// Decode statistics of a block.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BlockDecodeStats {
+    content_type: ContentType,
+    content_id: i32,
+    compression_method: CompressionMethod,
+    compressed_len: usize,
+    uncompressed_len: usize,
+    duration: Duration,
+}
+
+impl BlockDecodeStats {
+    pub(crate) fn new(
+        content_type: ContentType,
+        content_id: i32,
+        compression_method: CompressionMethod,
+        compressed_len: usize,
+        uncompressed_len: usize,
+        duration: Duration,
+    ) -> Self {
+        Self {
+            content_type,
+            content_id,
+            compression_method,
+            compressed_len,
+            uncompressed_len,
+            duration,
+        }
+    }
+
+    /// Returns the block content type.
+    pub fn content_type(&self) -> ContentType {
+        self.content_type
+    }
+
+    /// Returns the block content ID.
+    ///
+    /// For external data blocks, this identifies the data series or tag stored in the block.
+    pub fn content_id(&self) -> i32 {
+        self.content_id
+    }
+
+    /// Returns the compression method (codec) of the block.
+    pub fn compression_method(&self) -> CompressionMethod {
+        self.compression_method
+    }
+
+    /// Returns the size of the compressed data.
+    pub fn compressed_len(&self) -> usize {
+        self.compressed_len
+    }
+
+    /// Returns the size of the uncompressed data.
+    pub fn uncompressed_len(&self) -> usize {
+        self.uncompressed_len
+    }
+
+    /// Returns the time taken to decompress the block.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+/// Decode statistics of a data container.
+///
+/// This includes the statistics of each decompressed slice block, in order of decoding.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DecodeStats {
+    blocks: Vec<BlockDecodeStats>,
+    record_count: usize,
+    duration: Duration,
+}
+
+impl DecodeStats {
+    /// Returns the decode statistics of each block.
+    pub fn blocks(&self) -> &[BlockDecodeStats] {
+        &self.blocks
+    }
+
+    /// Returns the number of records decoded.
+    pub fn record_count(&self) -> usize {
+        self.record_count
+    }
+
+    /// Returns the total time taken to decode and resolve the records of the container.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the total size of the compressed data of all blocks.
+    pub fn compressed_len(&self) -> usize {
+        self.blocks.iter().map(|block| block.compressed_len()).sum()
+    }
+
+    /// Returns the total size of the uncompressed data of all blocks.
+    pub fn uncompressed_len(&self) -> usize {
+        self.blocks
+            .iter()
+            .map(|block| block.uncompressed_len())
+            .sum()
+    }
+
+    pub(crate) fn add_block(&mut self, block: BlockDecodeStats) {
+        self.blocks.push(block);
+    }
+
+    pub(crate) fn set_record_count(&mut self, record_count: usize) {
+        self.record_count = record_count;
+    }
+
+    pub(crate) fn set_duration(&mut self, duration: Duration) {
+        self.duration = duration;
+    }
+}
@@ -3,14 +3,18 @@ pub(crate) mod header;
 
 pub use self::{builder::Builder, header::Header};
 
-use std::io;
+use std::{io, time::Instant};
 
 use bstr::BString;
+use bytes::Bytes;
 use noodles_core::Position;
 use noodles_fasta as fasta;
 use noodles_sam as sam;
 
-use super::{CompressionHeader, ReferenceSequenceContext};
+use super::{
+    decode_stats::{BlockDecodeStats, DecodeStats},
+    ChecksumMismatch, CompressionHeader, ReferenceSequenceContext,
+};
 use crate::{
     container::Block,
     io::BitReader,
@@ -56,6 +60,9 @@ impl Slice {
 
     /// Reads and returns a list of raw records in this slice.
     ///
+    /// If the slice header has a checksum of the block data, it is verified, and a mismatch is
+    /// an error.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -75,20 +82,60 @@ impl Slice {
     /// # Ok::<_, io::Error>(())
     /// ```
     pub fn records(&self, compression_header: &CompressionHeader) -> io::Result<Vec<Record>> {
+        self.records_with_decode_stats(compression_header, None, None)
+    }
+
+    /// Reads and returns a list of raw records in this slice.
+    ///
+    /// When given, a block data checksum mismatch is passed to `on_checksum_mismatch` rather than
+    /// returned as an error.
+    pub(crate) fn records_with_decode_stats(
+        &self,
+        compression_header: &CompressionHeader,
+        mut decode_stats: Option<&mut DecodeStats>,
+        on_checksum_mismatch: Option<&mut dyn FnMut(&ChecksumMismatch)>,
+    ) -> io::Result<Vec<Record>> {
+        use md5::{Digest, Md5};
+
         use crate::io::reader::record::ExternalDataReaders;
 
-        let core_data_reader = self
-            .core_data_block
-            .decompressed_data()
-            .map(BitReader::new)?;
+        let expected_md5 = self.header.block_data_md5()?;
+        let mut hasher = expected_md5.map(|_| Md5::new());
+
+        let core_data = decompress_block(&self.core_data_block, decode_stats.as_deref_mut())?;
+
+        if let Some(hasher) = hasher.as_mut() {
+            hasher.update(&core_data);
+        }
+
+        let core_data_reader = BitReader::new(core_data);
 
         let mut external_data_readers = ExternalDataReaders::new();
 
         for block in self.external_blocks() {
-            let reader = block.decompressed_data()?;
+            let reader = decompress_block(block, decode_stats.as_deref_mut())?;
+
+            if let Some(hasher) = hasher.as_mut() {
+                hasher.update(&reader);
+            }
+
             external_data_readers.insert(block.content_id(), reader);
         }
 
+        if let (Some(expected), Some(hasher)) = (expected_md5, hasher) {
+            let actual: [u8; 16] = hasher.finalize().into();
+
+            if actual != expected {
+                let mismatch =
+                    ChecksumMismatch::new(self.header.record_counter(), expected, actual);
+
+                match on_checksum_mismatch {
+                    Some(f) => f(&mismatch),
+                    None => return Err(io::Error::new(io::ErrorKind::InvalidData, mismatch)),
+                }
+            }
+        }
+
         let mut record_reader = crate::io::reader::record::Reader::new(
             compression_header,
             core_data_reader,
@@ -138,6 +185,27 @@ impl Slice {
     }
 }
 
+fn decompress_block(block: &Block, decode_stats: Option<&mut DecodeStats>) -> io::Result<Bytes> {
+    let Some(decode_stats) = decode_stats else {
+        return block.decompressed_data();
+    };
+
+    let start = Instant::now();
+    let data = block.decompressed_data()?;
+    let duration = start.elapsed();
+
+    decode_stats.add_block(BlockDecodeStats::new(
+        block.content_type(),
+        i32::from(block.content_id()),
+        block.compression_method(),
+        block.data().len(),
+        data.len(),
+        duration,
+    ));
+
+    Ok(data)
+}
+
 fn resolve_mates(records: &mut [Record]) -> io::Result<()> {
     let mut mate_indices: Vec<_> = records
         .iter()
@@ -691,4 +759,57 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_records_with_block_data_md5() -> Result<(), Box<dyn std::error::Error>> {
+        use bytes::Bytes;
+        use md5::{Digest, Md5};
+
+        use crate::container::block::{CompressionMethod, ContentType};
+
+        fn build_slice(md5: [u8; 16]) -> Slice {
+            let mut optional_tags = vec![b'B', b'D', b'B', b'C', 0x10, 0x00, 0x00, 0x00];
+            optional_tags.extend(md5);
+
+            Slice {
+                header: Header::builder().set_optional_tags(optional_tags).build(),
+                core_data_block: Block::builder()
+                    .set_compression_method(CompressionMethod::None)
+                    .set_content_type(ContentType::CoreData)
+                    .build(),
+                external_blocks: vec![Block::builder()
+                    .set_compression_method(CompressionMethod::None)
+                    .set_content_type(ContentType::ExternalData)
+                    .set_uncompressed_len(4)
+                    .set_data(Bytes::from_static(b"ndls"))
+                    .build()],
+            }
+        }
+
+        let compression_header = CompressionHeader::builder().build();
+        let md5: [u8; 16] = Md5::digest(b"ndls").into();
+
+        let slice = build_slice(md5);
+        assert!(slice.records(&compression_header)?.is_empty());
+
+        let slice = build_slice([0; 16]);
+
+        assert!(matches!(
+            slice.records(&compression_header),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let mut mismatches = Vec::new();
+        let mut on_checksum_mismatch =
+            |mismatch: &ChecksumMismatch| mismatches.push(mismatch.clone());
+        let records = slice.records_with_decode_stats(
+            &compression_header,
+            None,
+            Some(&mut on_checksum_mismatch),
+        )?;
+        assert!(records.is_empty());
+        assert_eq!(mismatches, [ChecksumMismatch::new(0, [0; 16], md5)]);
+
+        Ok(())
+    }
 }
@@ -2,7 +2,6 @@ use std::{collections::HashMap, io};
 
 use bstr::BString;
 use bytes::Bytes;
-use md5::{Digest, Md5};
 use noodles_fasta as fasta;
 use noodles_sam as sam;
 
@@ -285,17 +284,7 @@ fn set_detached(record: &mut Record) {
 
 // _Sequence Alignment/Map Format Specification_ (2021-06-03) § 1.3.2 "Reference MD5 calculation"
 pub(crate) fn calculate_normalized_sequence_digest(sequence: &[u8]) -> [u8; 16] {
-    let mut hasher = Md5::new();
-
-    for &b in sequence {
-        // "All characters outside of the inclusive range 33 ('!') to 126 ('~') are stripped out."
-        if b.is_ascii_graphic() {
-            // "All lowercase characters are converted to uppercase."
-            hasher.update([b.to_ascii_uppercase()]);
-        }
-    }
-
-    hasher.finalize().into()
+    sam::dict::calculate_md5_checksum(sequence).into()
 }
 
 #[cfg(test)]
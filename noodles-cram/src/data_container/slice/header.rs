@@ -2,8 +2,19 @@ mod builder;
 
 pub use builder::Builder;
 
+use std::io;
+
+use noodles_bam as bam;
+use noodles_sam::alignment::{
+    record::data::field::Tag,
+    record_buf::data::field::{value::Array, Value},
+};
+
 use crate::{container::block, data_container::ReferenceSequenceContext};
 
+// The MD5 checksum of the slice block data.
+const BLOCK_DATA_MD5: Tag = Tag::new(b'B', b'D');
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Header {
     reference_sequence_context: ReferenceSequenceContext,
@@ -52,4 +63,68 @@ impl Header {
     pub fn optional_tags(&self) -> &[u8] {
         &self.optional_tags
     }
+
+    /// Returns the MD5 checksum of the slice block data (`BD`), if present.
+    pub fn block_data_md5(&self) -> io::Result<Option<[u8; 16]>> {
+        use bam::record::codec::decoder::data::field::get_field;
+
+        let mut src = &self.optional_tags[..];
+
+        while !src.is_empty() {
+            let (tag, value) =
+                get_field(&mut src).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+            if tag != BLOCK_DATA_MD5 {
+                continue;
+            }
+
+            return match value {
+                Value::Array(Array::UInt8(buf)) => buf
+                    .try_into()
+                    .map(Some)
+                    .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "invalid BD length")),
+                _ => Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "invalid BD type",
+                )),
+            };
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_block_data_md5() -> io::Result<()> {
+        let header = Header::builder().build();
+        assert!(header.block_data_md5()?.is_none());
+
+        let md5 = [
+            0x57, 0xf0, 0x5b, 0xf4, 0x7f, 0x1b, 0x2b, 0xd4, 0x8a, 0x09, 0x6b, 0x35, 0x14, 0x5f,
+            0xf4, 0xd5,
+        ];
+
+        let mut optional_tags = vec![
+            b'N', b'H', b'C', 0x01, // NH:C:1
+            b'B', b'D', b'B', b'C', 0x10, 0x00, 0x00, 0x00, // BD:B:C,...
+        ];
+        optional_tags.extend(md5);
+
+        let header = Header::builder().set_optional_tags(optional_tags).build();
+        assert_eq!(header.block_data_md5()?, Some(md5));
+
+        let header = Header::builder()
+            .set_optional_tags(vec![b'B', b'D', b'C', 0x01])
+            .build();
+        assert!(matches!(
+            header.block_data_md5(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
 }
@@ -45,6 +45,16 @@ impl Version {
     pub fn minor(&self) -> u8 {
         self.minor
     }
+
+    // CRAM 2.x container headers and blocks do not have CRC32 checksums.
+    pub(crate) fn has_crc32(&self) -> bool {
+        self.major >= 3
+    }
+
+    // CRAM 2.x container and slice header record counters are ITF8 values rather than LTF8.
+    pub(crate) fn has_ltf8_record_counter(&self) -> bool {
+        self.major >= 3
+    }
 }
 
 impl Default for Version {
@@ -1,4 +1,10 @@
-use std::{cmp, collections::HashMap, fs::File, io, path::Path};
+use std::{
+    cmp,
+    collections::HashMap,
+    fs::File,
+    io::{self, Read, Seek},
+    path::Path,
+};
 
 use noodles_core::Position;
 
@@ -22,7 +28,27 @@ pub fn index<P>(src: P) -> io::Result<crai::Index>
 where
     P: AsRef<Path>,
 {
-    let mut reader = File::open(src).map(Reader::new)?;
+    File::open(src).and_then(index_from_reader)
+}
+
+/// Indexes CRAM data from a reader.
+///
+/// The reader must be positioned at the start of the CRAM file. It can be any seekable source,
+/// e.g., a [`noodles_core::io::RangeReader`] over an HTTP server or object store.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io};
+/// use noodles_cram as cram;
+/// let index = File::open("sample.cram").and_then(cram::index_from_reader)?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn index_from_reader<R>(reader: R) -> io::Result<crai::Index>
+where
+    R: Read + Seek,
+{
+    let mut reader = Reader::new(reader);
     reader.read_header()?;
 
     let mut index = Vec::new();
@@ -4,7 +4,13 @@ mod bit_reader;
 mod bit_writer;
 pub mod indexed_reader;
 pub mod reader;
+mod reheader;
 pub mod writer;
 
 pub(crate) use self::{bit_reader::BitReader, bit_writer::BitWriter};
-pub use self::{indexed_reader::IndexedReader, reader::Reader, writer::Writer};
+pub use self::{
+    indexed_reader::IndexedReader,
+    reader::Reader,
+    reheader::{reheader, reheader_index},
+    writer::Writer,
+};
@@ -7,11 +7,19 @@ pub(crate) mod header_container;
 pub(crate) mod num;
 mod query;
 pub(crate) mod record;
+mod record_error;
 mod records;
+mod records_lossy;
 
-pub use self::{builder::Builder, query::Query, records::Records};
+pub use self::{
+    builder::Builder, query::Query, record_error::RecordError, records::Records,
+    records_lossy::RecordsLossy,
+};
 
-use std::io::{self, Read, Seek, SeekFrom};
+use std::{
+    io::{self, Read, Seek, SeekFrom},
+    time::Instant,
+};
 
 use bytes::BytesMut;
 use noodles_core::Region;
@@ -19,9 +27,15 @@ use noodles_fasta as fasta;
 use noodles_sam as sam;
 
 use crate::{
-    crai, data_container::DataContainer, file_definition::Version, FileDefinition, MAGIC_NUMBER,
+    crai,
+    data_container::{ChecksumMismatch, CompressionHeader, DataContainer, DecodeStats, Slice},
+    file_definition::Version,
+    FileDefinition, Record, MAGIC_NUMBER,
 };
 
+type DecodeStatsCallback = Box<dyn FnMut(&DecodeStats)>;
+type ChecksumMismatchCallback = Box<dyn FnMut(&ChecksumMismatch)>;
+
 /// A CRAM reader.
 ///
 /// The CRAM format is comprised of four main parts: 1) a file definition, 2) a file header, 3) a
@@ -47,7 +61,10 @@ use crate::{
 pub struct Reader<R> {
     inner: R,
     reference_sequence_repository: fasta::Repository,
+    decode_stats_callback: Option<DecodeStatsCallback>,
+    checksum_mismatch_callback: Option<ChecksumMismatchCallback>,
     buf: BytesMut,
+    version: Version,
 }
 
 impl<R> Reader<R>
@@ -114,9 +131,74 @@ where
         &self.reference_sequence_repository
     }
 
+    fn decode_data_container_records(
+        &mut self,
+        header: &sam::Header,
+        container: &DataContainer,
+    ) -> io::Result<Vec<Record>> {
+        let start = Instant::now();
+
+        let compression_header = container.compression_header();
+
+        let mut decode_stats = self
+            .decode_stats_callback
+            .as_ref()
+            .map(|_| DecodeStats::default());
+
+        let mut records = Vec::new();
+
+        for slice in container.slices() {
+            let slice_records = self.decode_slice_records(
+                header,
+                compression_header,
+                slice,
+                decode_stats.as_mut(),
+            )?;
+
+            records.extend(slice_records);
+        }
+
+        if let (Some(callback), Some(mut decode_stats)) =
+            (self.decode_stats_callback.as_mut(), decode_stats)
+        {
+            decode_stats.set_record_count(records.len());
+            decode_stats.set_duration(start.elapsed());
+            callback(&decode_stats);
+        }
+
+        Ok(records)
+    }
+
+    fn decode_slice_records(
+        &mut self,
+        header: &sam::Header,
+        compression_header: &CompressionHeader,
+        slice: &Slice,
+        decode_stats: Option<&mut DecodeStats>,
+    ) -> io::Result<Vec<Record>> {
+        let mut records = slice.records_with_decode_stats(
+            compression_header,
+            decode_stats,
+            self.checksum_mismatch_callback
+                .as_mut()
+                .map(|f| &mut **f as &mut dyn FnMut(&ChecksumMismatch)),
+        )?;
+
+        slice.resolve_records(
+            &self.reference_sequence_repository,
+            header,
+            compression_header,
+            &mut records,
+        )?;
+
+        Ok(records)
+    }
+
     /// Reads the CRAM file definition.
     ///
-    /// The CRAM magic number is also checked.
+    /// The CRAM magic number is also checked. The format version is recorded and used to select
+    /// version-specific code paths when reading the following containers. Only CRAM 2.x and 3.x
+    /// are supported.
     ///
     /// The position of the stream is expected to be at the start.
     ///
@@ -135,6 +217,8 @@ where
         let format = read_format(&mut self.inner)?;
         let file_id = read_file_id(&mut self.inner)?;
 
+        self.version = format;
+
         Ok(FileDefinition::new(format, file_id))
     }
 
@@ -157,7 +241,7 @@ where
     /// ```
     pub fn read_file_header(&mut self) -> io::Result<sam::Header> {
         use self::header_container::read_header_container;
-        read_header_container(&mut self.inner, &mut self.buf)
+        read_header_container(&mut self.inner, &mut self.buf, self.version)
     }
 
     /// Reads the SAM header.
@@ -185,7 +269,15 @@ where
         &mut self,
     ) -> io::Result<Option<(crate::data_container::Header, DataContainer)>> {
         use self::data_container::read_data_container_with_container_header;
-        read_data_container_with_container_header(&mut self.inner, &mut self.buf)
+        read_data_container_with_container_header(&mut self.inner, &mut self.buf, self.version)
+    }
+
+    pub(crate) fn read_data_container_with_landmarks(
+        &mut self,
+        landmarks: &[usize],
+    ) -> io::Result<Option<DataContainer>> {
+        use self::data_container::read_data_container_with_landmarks;
+        read_data_container_with_landmarks(&mut self.inner, &mut self.buf, self.version, landmarks)
     }
 
     /// Reads a data container.
@@ -210,7 +302,7 @@ where
     pub fn read_data_container(&mut self) -> io::Result<Option<DataContainer>> {
         use self::data_container::read_data_container;
 
-        read_data_container(&mut self.inner, &mut self.buf)
+        read_data_container(&mut self.inner, &mut self.buf, self.version)
     }
 
     /// Returns a iterator over records starting from the current stream position.
@@ -278,8 +370,45 @@ where
         self.inner.stream_position()
     }
 
+    /// Returns an iterator over records that skips slices that fail to decode.
+    ///
+    /// Each error is a [`RecordError`], which includes the index of the first affected record, the
+    /// position of the data container, the slice index, and, if known, the reference sequence
+    /// name. Records are decoded by slice, so a slice that fails to decode is skipped as a whole,
+    /// and iteration continues with the next slice. If a data container cannot be read, e.g., the
+    /// stream is truncated, the error is returned, and iteration stops.
+    ///
+    /// The stream is expected to be at the start of a data container.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram as cram;
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::io::Reader::new)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// for result in reader.records_lossy(&header) {
+    ///     match result {
+    ///         Ok(record) => {
+    ///             // ...
+    ///         }
+    ///         Err(e) => eprintln!("{e}"),
+    ///     }
+    /// }
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn records_lossy<'r, 'h>(&'r mut self, header: &'h sam::Header) -> RecordsLossy<'r, 'h, R> {
+        RecordsLossy::new(self, header)
+    }
+
     /// Returns an iterator over records that intersects the given region.
     ///
+    /// This uses the CRAI index to seek to the containers that have slices intersecting the region.
+    /// Only those slices are decoded. Records in multi-reference slices are filtered by reference
+    /// sequence.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -316,13 +445,13 @@ where
                 )
             })?;
 
-        Ok(Query::new(
+        Query::new(
             self,
             header,
             index,
             reference_sequence_id,
             region.interval(),
-        ))
+        )
     }
 }
 
@@ -373,7 +502,21 @@ where
 {
     let mut buf = [0; 2];
     reader.read_exact(&mut buf)?;
-    Ok(Version::new(buf[0], buf[1]))
+
+    let version = Version::new(buf[0], buf[1]);
+
+    if matches!(version.major(), 2 | 3) {
+        Ok(version)
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "unsupported CRAM version: {}.{}",
+                version.major(),
+                version.minor()
+            ),
+        ))
+    }
 }
 
 fn read_file_id<R>(reader: &mut R) -> io::Result<[u8; 20]>
@@ -409,6 +552,150 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_format() -> io::Result<()> {
+        let mut src = &[0x02, 0x01][..];
+        assert_eq!(read_format(&mut src)?, Version::new(2, 1));
+
+        let mut src = &[0x03, 0x00][..];
+        assert_eq!(read_format(&mut src)?, Version::new(3, 0));
+
+        let mut src = &[0x04, 0x00][..];
+        assert!(matches!(
+            read_format(&mut src),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_with_decode_stats_callback() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{cell::RefCell, rc::Rc};
+
+        let header = sam::Header::default();
+
+        let mut writer = crate::io::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_record(&header, Record::default())?;
+        writer.write_record(&header, Record::default())?;
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+
+        let decode_stats = Rc::new(RefCell::new(Vec::new()));
+        let callback_decode_stats = Rc::clone(&decode_stats);
+
+        let mut reader = Builder::default()
+            .set_decode_stats_callback(move |stats| {
+                callback_decode_stats.borrow_mut().push(stats.clone());
+            })
+            .build_from_reader(&data[..]);
+
+        reader.read_header()?;
+        let records: Vec<_> = reader.records(&header).collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 2);
+
+        let decode_stats = decode_stats.borrow();
+        assert_eq!(decode_stats.len(), 1);
+
+        let stats = &decode_stats[0];
+        assert_eq!(stats.record_count(), 2);
+        assert!(!stats.blocks().is_empty());
+        assert_eq!(
+            stats.uncompressed_len(),
+            stats
+                .blocks()
+                .iter()
+                .map(|block| block.uncompressed_len())
+                .sum::<usize>()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{io::Cursor, num::NonZeroUsize};
+
+        use fasta::record::{Definition, Sequence};
+        use noodles_core::Position;
+        use sam::{
+            alignment::{
+                io::Write,
+                record::{
+                    cigar::{op::Kind, Op},
+                    Flags,
+                },
+                RecordBuf,
+            },
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        let repository = fasta::Repository::new(vec![
+            fasta::Record::new(
+                Definition::new("sq0", None),
+                Sequence::from(b"TTCACCCA".to_vec()),
+            ),
+            fasta::Record::new(
+                Definition::new("sq1", None),
+                Sequence::from(b"GATCTTACTTTTT".to_vec()),
+            ),
+        ]);
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .build();
+
+        let mut writer = crate::io::writer::Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .build_with_writer(Vec::new());
+
+        writer.write_alignment_header(&header)?;
+
+        for (i, (reference_sequence_id, alignment_start)) in
+            [(0, 1), (1, 2), (1, 9)].into_iter().enumerate()
+        {
+            let record = RecordBuf::builder()
+                .set_name(format!("r{i}"))
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(reference_sequence_id)
+                .set_alignment_start(Position::try_from(alignment_start)?)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(b"ACGT".to_vec().into())
+                .set_quality_scores(vec![45; 4].into())
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+        let index = crate::index_from_reader(Cursor::new(data))?;
+
+        let mut reader = Builder::default()
+            .set_reference_sequence_repository(repository)
+            .build_from_reader(Cursor::new(data));
+
+        let region = "sq1:1-5".parse()?;
+        let names: Vec<_> = reader
+            .query(&header, &index, &region)?
+            .map(|result| result.map(|record| record.name().map(|name| name.to_vec())))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(names, [Some(b"r1".to_vec())]);
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_magic_number() {
         let data = b"CRAM";
@@ -1,4 +1,5 @@
 use std::{
+    fmt,
     fs::File,
     io::{self, Read},
     path::Path,
@@ -7,12 +8,18 @@ use std::{
 use bytes::BytesMut;
 use noodles_fasta as fasta;
 
-use super::Reader;
+use super::{ChecksumMismatchCallback, DecodeStatsCallback, Reader};
+use crate::{
+    data_container::{ChecksumMismatch, DecodeStats},
+    file_definition::Version,
+};
 
 /// A CRAM reader builder.
-#[derive(Debug, Default)]
+#[derive(Default)]
 pub struct Builder {
     reference_sequence_repository: fasta::Repository,
+    decode_stats_callback: Option<DecodeStatsCallback>,
+    checksum_mismatch_callback: Option<ChecksumMismatchCallback>,
 }
 
 impl Builder {
@@ -37,6 +44,62 @@ impl Builder {
         self
     }
 
+    /// Sets a callback that receives the decode statistics of each data container.
+    ///
+    /// When set, the reader times the decompression of each slice block and, after decoding the
+    /// records of a data container, calls the callback with the collected statistics. This can
+    /// be used to find which data series dominate the cost of reading a CRAM file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::reader::Builder;
+    ///
+    /// let builder = Builder::default().set_decode_stats_callback(|stats| {
+    ///     for block in stats.blocks() {
+    ///         eprintln!(
+    ///             "{}\t{:?}\t{}\t{}\t{:?}",
+    ///             block.content_id(),
+    ///             block.compression_method(),
+    ///             block.compressed_len(),
+    ///             block.uncompressed_len(),
+    ///             block.duration(),
+    ///         );
+    ///     }
+    /// });
+    /// ```
+    pub fn set_decode_stats_callback<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(&DecodeStats) + 'static,
+    {
+        self.decode_stats_callback = Some(Box::new(callback));
+        self
+    }
+
+    /// Sets a callback that receives slice block data checksum mismatches.
+    ///
+    /// When a slice header has an MD5 checksum of its block data (`BD`), the reader verifies it
+    /// while decoding the slice. By default, a mismatch is an error. When this callback is set,
+    /// mismatches are instead passed to the callback as warnings, and the records are decoded as
+    /// is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::reader::Builder;
+    ///
+    /// let builder = Builder::default().set_checksum_mismatch_callback(|mismatch| {
+    ///     eprintln!("warning: {mismatch}");
+    /// });
+    /// ```
+    pub fn set_checksum_mismatch_callback<F>(mut self, callback: F) -> Self
+    where
+        F: FnMut(&ChecksumMismatch) + 'static,
+    {
+        self.checksum_mismatch_callback = Some(Box::new(callback));
+        self
+    }
+
     /// Builds a CRAM reader from a path.
     ///
     /// # Examples
@@ -70,7 +133,29 @@ impl Builder {
         Reader {
             inner: reader,
             reference_sequence_repository: self.reference_sequence_repository,
+            decode_stats_callback: self.decode_stats_callback,
+            checksum_mismatch_callback: self.checksum_mismatch_callback,
             buf: BytesMut::new(),
+            version: Version::default(),
         }
     }
 }
+
+impl fmt::Debug for Builder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Builder")
+            .field(
+                "reference_sequence_repository",
+                &self.reference_sequence_repository,
+            )
+            .field(
+                "decode_stats_callback",
+                &self.decode_stats_callback.as_ref().map(|_| ".."),
+            )
+            .field(
+                "checksum_mismatch_callback",
+                &self.checksum_mismatch_callback.as_ref().map(|_| ".."),
+            )
+            .finish()
+    }
+}
@@ -9,16 +9,20 @@ use std::io::{self, Read};
 use bytes::{Bytes, BytesMut};
 
 use self::header::read_header;
-use crate::{container::block::ContentType, data_container::CompressionHeader, DataContainer};
+use crate::{
+    container::block::ContentType, data_container::CompressionHeader, file_definition::Version,
+    DataContainer,
+};
 
 pub fn read_data_container<R>(
     reader: &mut R,
     buf: &mut BytesMut,
+    version: Version,
 ) -> io::Result<Option<DataContainer>>
 where
     R: Read,
 {
-    let Some(header) = read_header(reader)? else {
+    let Some(header) = read_header(reader, version)? else {
         return Ok(None);
     };
 
@@ -26,13 +30,13 @@ where
     reader.read_exact(buf)?;
     let mut buf = buf.split().freeze();
 
-    let compression_header = read_compression_header_from_block(&mut buf)?;
+    let compression_header = read_compression_header_from_block(&mut buf, version)?;
 
     let slice_count = header.landmarks().len();
     let mut slices = Vec::with_capacity(slice_count);
 
     for _ in 0..slice_count {
-        let slice = read_slice(&mut buf)?;
+        let slice = read_slice(&mut buf, version)?;
         slices.push(slice);
     }
 
@@ -42,11 +46,12 @@ where
 pub fn read_data_container_with_container_header<R>(
     reader: &mut R,
     buf: &mut BytesMut,
+    version: Version,
 ) -> io::Result<Option<(crate::data_container::Header, DataContainer)>>
 where
     R: Read,
 {
-    let Some(header) = read_header(reader)? else {
+    let Some(header) = read_header(reader, version)? else {
         return Ok(None);
     };
 
@@ -54,13 +59,13 @@ where
     reader.read_exact(buf)?;
     let mut buf = buf.split().freeze();
 
-    let compression_header = read_compression_header_from_block(&mut buf)?;
+    let compression_header = read_compression_header_from_block(&mut buf, version)?;
 
     let slice_count = header.landmarks().len();
     let mut slices = Vec::with_capacity(slice_count);
 
     for _ in 0..slice_count {
-        let slice = read_slice(&mut buf)?;
+        let slice = read_slice(&mut buf, version)?;
         slices.push(slice);
     }
 
@@ -69,10 +74,50 @@ where
     Ok(Some((header, data_container)))
 }
 
-pub(crate) fn read_compression_header_from_block(src: &mut Bytes) -> io::Result<CompressionHeader> {
+pub fn read_data_container_with_landmarks<R>(
+    reader: &mut R,
+    buf: &mut BytesMut,
+    version: Version,
+    landmarks: &[usize],
+) -> io::Result<Option<DataContainer>>
+where
+    R: Read,
+{
+    let Some(header) = read_header(reader, version)? else {
+        return Ok(None);
+    };
+
+    buf.resize(header.len(), 0);
+    reader.read_exact(buf)?;
+    let src = buf.split().freeze();
+
+    let compression_header = read_compression_header_from_block(&mut src.clone(), version)?;
+
+    let mut slices = Vec::with_capacity(landmarks.len());
+
+    for &landmark in landmarks {
+        if !header.landmarks().contains(&landmark) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid slice landmark",
+            ));
+        }
+
+        let mut slice_src = src.slice(landmark..);
+        let slice = read_slice(&mut slice_src, version)?;
+        slices.push(slice);
+    }
+
+    Ok(Some(DataContainer::new(compression_header, slices)))
+}
+
+pub(crate) fn read_compression_header_from_block(
+    src: &mut Bytes,
+    version: Version,
+) -> io::Result<CompressionHeader> {
     use super::container::read_block;
 
-    let block = read_block(src)?;
+    let block = read_block(src, version)?;
 
     if block.content_type() != ContentType::CompressionHeader {
         return Err(io::Error::new(
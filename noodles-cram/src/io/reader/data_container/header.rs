@@ -9,17 +9,21 @@ use noodles_core::Position;
 
 use crate::{
     data_container::{Header, ReferenceSequenceContext},
+    file_definition::Version,
     io::reader::num::{read_itf8, read_ltf8},
 };
 
 // § 9 "End of file container" (2022-04-12)
 const EOF_LENGTH: usize = 15;
+// The CRAM 2.1 EOF container has the same fields but without a checksum and with a smaller
+// compression header block.
+const EOF_2_1_LENGTH: usize = 11;
 const EOF_REFERENCE_SEQUENCE_ID: i32 = -1;
 const EOF_ALIGNMENT_START: i32 = 4_542_278;
 const EOF_BLOCK_COUNT: usize = 1;
 const EOF_CRC32: u32 = 0x4f_d9_bd_05;
 
-pub fn read_header<R>(reader: &mut R) -> io::Result<Option<Header>>
+pub fn read_header<R>(reader: &mut R, version: Version) -> io::Result<Option<Header>>
 where
     R: Read,
 {
@@ -35,9 +39,15 @@ where
 
     let number_of_records = read_itf8(&mut crc_reader)?;
 
-    let record_counter = read_ltf8(&mut crc_reader).and_then(|n| {
-        u64::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-    })?;
+    let record_counter = if version.has_ltf8_record_counter() {
+        read_ltf8(&mut crc_reader).and_then(|n| {
+            u64::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })?
+    } else {
+        read_itf8(&mut crc_reader).and_then(|n| {
+            u64::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })?
+    };
 
     let bases = read_ltf8(&mut crc_reader).and_then(|n| {
         u64::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
@@ -49,26 +59,32 @@ where
 
     let landmarks = read_landmarks(&mut crc_reader)?;
 
-    let actual_crc32 = crc_reader.crc().sum();
+    let crc32 = if version.has_crc32() {
+        let actual_crc32 = crc_reader.crc().sum();
 
-    let reader = crc_reader.into_inner();
-    let expected_crc32 = reader.read_u32::<LittleEndian>()?;
+        let reader = crc_reader.into_inner();
+        let expected_crc32 = reader.read_u32::<LittleEndian>()?;
 
-    if actual_crc32 != expected_crc32 {
-        return Err(io::Error::new(
-            io::ErrorKind::InvalidData,
-            format!(
-                "container header checksum mismatch: expected {expected_crc32:08x}, got {actual_crc32:08x}"
-            ),
-        ));
-    }
+        if actual_crc32 != expected_crc32 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "container header checksum mismatch: expected {expected_crc32:08x}, got {actual_crc32:08x}"
+                ),
+            ));
+        }
+
+        Some(expected_crc32)
+    } else {
+        None
+    };
 
     if is_eof(
         length,
         reference_sequence_id,
         alignment_start,
         number_of_blocks,
-        expected_crc32,
+        crc32,
     ) {
         return Ok(None);
     }
@@ -115,13 +131,17 @@ pub(crate) fn is_eof(
     reference_sequence_id: i32,
     alignment_start: i32,
     block_count: usize,
-    crc32: u32,
+    crc32: Option<u32>,
 ) -> bool {
-    length == EOF_LENGTH
+    let is_eof_length_and_crc32 = match crc32 {
+        Some(crc32) => length == EOF_LENGTH && crc32 == EOF_CRC32,
+        None => length == EOF_2_1_LENGTH,
+    };
+
+    is_eof_length_and_crc32
         && reference_sequence_id == EOF_REFERENCE_SEQUENCE_ID
         && alignment_start == EOF_ALIGNMENT_START
         && block_count == EOF_BLOCK_COUNT
-        && crc32 == EOF_CRC32
 }
 
 pub(crate) fn build_reference_sequence_context(
@@ -181,7 +201,7 @@ mod tests {
             0x21, 0xf7, 0x9c, 0xed, // CRC32
         ];
         let mut reader = &data[..];
-        let actual = read_header(&mut reader)?;
+        let actual = read_header(&mut reader, Version::default())?;
 
         let expected = Header::builder()
             .set_length(144)
@@ -217,7 +237,66 @@ mod tests {
             0x05, 0xbd, 0xd9, 0x4f, // CRC32
         ];
         let mut reader = &data[..];
-        let actual = read_header(&mut reader)?;
+        let actual = read_header(&mut reader, Version::default())?;
+
+        assert!(actual.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_header_with_cram_2_1() -> Result<(), Box<dyn std::error::Error>> {
+        let data = [
+            0x90, 0x00, 0x00, 0x00, // length = 144 bytes
+            0x02, // reference sequence ID = 2
+            0x03, // starting position on the reference = 3
+            0x05, // alignment span = 5
+            0x08, // number of records = 8
+            0x0d, // record counter = 13 (ITF8)
+            0x15, // bases = 21
+            0x22, // number of blocks = 34
+            0x02, // landmark count = 2
+            0x37, // landmarks[0] = 55
+            0x59, // landmarks[1] = 89
+        ];
+        let mut reader = &data[..];
+        let actual = read_header(&mut reader, Version::new(2, 1))?;
+
+        let expected = Header::builder()
+            .set_length(144)
+            .set_reference_sequence_context(ReferenceSequenceContext::some(
+                2,
+                Position::try_from(3)?,
+                Position::try_from(7)?,
+            ))
+            .set_record_count(8)
+            .set_record_counter(13)
+            .set_base_count(21)
+            .set_block_count(34)
+            .set_landmarks(vec![55, 89])
+            .build();
+
+        assert_eq!(actual, Some(expected));
+        assert!(reader.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_header_with_cram_2_1_eof() -> io::Result<()> {
+        let data = [
+            0x0b, 0x00, 0x00, 0x00, // length = 11 bytes
+            0xff, 0xff, 0xff, 0xff, 0xff, // reference sequence ID = None (-1)
+            0xe0, 0x45, 0x4f, 0x46, // starting position on the reference = 4542278
+            0x00, // alignment span = 0
+            0x00, // number of records = 0
+            0x00, // record counter = 0
+            0x00, // bases = 0
+            0x01, // number of blocks = 1
+            0x00, // landmark count = 0
+        ];
+        let mut reader = &data[..];
+        let actual = read_header(&mut reader, Version::new(2, 1))?;
 
         assert!(actual.is_none());
 
@@ -242,7 +321,7 @@ mod tests {
         let mut reader = &data[..];
 
         assert!(matches!(
-            read_header(&mut reader),
+            read_header(&mut reader, Version::default()),
             Err(e) if e.kind() == io::ErrorKind::InvalidData,
         ));
     }
@@ -6,10 +6,11 @@ use noodles_core::Position;
 use crate::{
     container::block,
     data_container::{slice, ReferenceSequenceContext},
+    file_definition::Version,
     io::reader::num::{get_itf8, get_ltf8},
 };
 
-pub fn get_header<B>(src: &mut B) -> io::Result<slice::Header>
+pub fn get_header<B>(src: &mut B, version: Version) -> io::Result<slice::Header>
 where
     B: Buf,
 {
@@ -19,9 +20,15 @@ where
         usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
     })?;
 
-    let record_counter = get_ltf8(src).and_then(|n| {
-        u64::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-    })?;
+    let record_counter = if version.has_ltf8_record_counter() {
+        get_ltf8(src).and_then(|n| {
+            u64::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })?
+    } else {
+        get_itf8(src).and_then(|n| {
+            u64::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })?
+    };
 
     let block_count = get_itf8(src).and_then(|n| {
         usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
@@ -174,7 +181,7 @@ mod tests {
             0x7e, 0xf7, // reference MD5 (b"ACGTA")
         ];
         let mut reader = &data[..];
-        let actual = get_header(&mut reader)?;
+        let actual = get_header(&mut reader, Version::default())?;
 
         let expected = slice::Header::builder()
             .set_reference_sequence_context(ReferenceSequenceContext::some(
@@ -3,9 +3,12 @@ use std::io::{self, Read};
 use byteorder::{LittleEndian, ReadBytesExt};
 use flate2::CrcReader;
 
-use crate::io::reader::num::{read_itf8, read_ltf8};
+use crate::{
+    file_definition::Version,
+    io::reader::num::{read_itf8, read_ltf8},
+};
 
-pub(super) fn read_header<R>(reader: &mut R) -> io::Result<usize>
+pub(super) fn read_header<R>(reader: &mut R, version: Version) -> io::Result<usize>
 where
     R: Read,
 {
@@ -28,7 +31,11 @@ where
     read_itf8(&mut crc_reader)?;
 
     // record counter
-    read_ltf8(&mut crc_reader)?;
+    if version.has_ltf8_record_counter() {
+        read_ltf8(&mut crc_reader)?;
+    } else {
+        read_itf8(&mut crc_reader)?;
+    }
 
     // base count
     read_ltf8(&mut crc_reader)?;
@@ -40,18 +47,20 @@ where
 
     read_landmarks(&mut crc_reader)?;
 
-    let actual_crc32 = crc_reader.crc().sum();
+    if version.has_crc32() {
+        let actual_crc32 = crc_reader.crc().sum();
 
-    let reader = crc_reader.into_inner();
-    let expected_crc32 = reader.read_u32::<LittleEndian>()?;
+        let reader = crc_reader.into_inner();
+        let expected_crc32 = reader.read_u32::<LittleEndian>()?;
 
-    if actual_crc32 != expected_crc32 {
-        return Err(io::Error::new(
-            io::ErrorKind::InvalidData,
-            format!(
-                "container header checksum mismatch: expected {expected_crc32:08x}, got {actual_crc32:08x}"
-            ),
-        ));
+        if actual_crc32 != expected_crc32 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "container header checksum mismatch: expected {expected_crc32:08x}, got {actual_crc32:08x}"
+                ),
+            ));
+        }
     }
 
     Ok(length)
@@ -1,6 +1,7 @@
 use std::{
+    collections::BTreeMap,
     io::{self, Read, Seek, SeekFrom},
-    slice, vec,
+    vec,
 };
 
 use noodles_core::region::Interval;
@@ -20,7 +21,7 @@ where
 
     header: &'a sam::Header,
 
-    index: slice::Iter<'a, crai::Record>,
+    containers: vec::IntoIter<(u64, Vec<usize>)>,
 
     reference_sequence_id: usize,
     interval: Interval,
@@ -38,68 +39,44 @@ where
         index: &'a crai::Index,
         reference_sequence_id: usize,
         interval: Interval,
-    ) -> Self {
-        Self {
+    ) -> io::Result<Self> {
+        let containers = intersecting_slices(index, reference_sequence_id, interval)?;
+
+        Ok(Self {
             reader,
 
             header,
 
-            index: index.iter(),
+            containers: containers.into_iter(),
 
             reference_sequence_id,
             interval,
 
             records: Vec::new().into_iter(),
-        }
+        })
     }
 
     fn read_next_container(&mut self) -> Option<io::Result<()>> {
-        let index_record = self.index.next()?;
-
-        if index_record.reference_sequence_id() != Some(self.reference_sequence_id) {
-            return Some(Ok(()));
-        }
+        let (offset, landmarks) = self.containers.next()?;
 
-        if let Err(e) = self.reader.seek(SeekFrom::Start(index_record.offset())) {
+        if let Err(e) = self.reader.seek(SeekFrom::Start(offset)) {
             return Some(Err(e));
         }
 
-        let container = match self.reader.read_data_container() {
+        let container = match self.reader.read_data_container_with_landmarks(&landmarks) {
             Ok(Some(c)) => c,
             Ok(None) => return None,
             Err(e) => return Some(Err(e)),
         };
 
-        let records = container
-            .slices()
-            .iter()
-            .map(|slice| {
-                let compression_header = container.compression_header();
-
-                slice.records(compression_header).and_then(|mut records| {
-                    slice.resolve_records(
-                        self.reader.reference_sequence_repository(),
-                        self.header,
-                        compression_header,
-                        &mut records,
-                    )?;
-
-                    Ok(records)
-                })
-            })
-            .collect::<Result<Vec<_>, _>>();
-
-        let records = match records {
-            Ok(records) => records,
+        self.records = match self
+            .reader
+            .decode_data_container_records(self.header, &container)
+        {
+            Ok(records) => records.into_iter(),
             Err(e) => return Some(Err(e)),
         };
 
-        self.records = records
-            .into_iter()
-            .flatten()
-            .collect::<Vec<_>>()
-            .into_iter();
-
         Some(Ok(()))
     }
 }
@@ -114,6 +91,10 @@ where
         loop {
             match self.records.next() {
                 Some(r) => {
+                    if r.reference_sequence_id() != Some(self.reference_sequence_id) {
+                        continue;
+                    }
+
                     if let (Some(start), Some(end)) = (r.alignment_start(), r.alignment_end()) {
                         let alignment_interval = (start..=end).into();
 
@@ -131,3 +112,82 @@ where
         }
     }
 }
+
+// Returns the landmarks of the slices that intersect the given region, grouped by container
+// offset in stream order.
+fn intersecting_slices(
+    index: &crai::Index,
+    reference_sequence_id: usize,
+    interval: Interval,
+) -> io::Result<Vec<(u64, Vec<usize>)>> {
+    let mut containers: BTreeMap<u64, Vec<usize>> = BTreeMap::new();
+
+    for record in index {
+        if record.reference_sequence_id() != Some(reference_sequence_id) {
+            continue;
+        }
+
+        let Some(start) = record.alignment_start() else {
+            continue;
+        };
+
+        let end = start
+            .checked_add(record.alignment_span().saturating_sub(1))
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "invalid index record alignment span",
+                )
+            })?;
+
+        if !interval.intersects((start..=end).into()) {
+            continue;
+        }
+
+        let landmark = usize::try_from(record.landmark())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        containers
+            .entry(record.offset())
+            .or_default()
+            .push(landmark);
+    }
+
+    Ok(containers
+        .into_iter()
+        .map(|(offset, mut landmarks)| {
+            landmarks.sort_unstable();
+            landmarks.dedup();
+            (offset, landmarks)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+
+    #[test]
+    fn test_intersecting_slices() -> Result<(), Box<dyn std::error::Error>> {
+        let index = vec![
+            crai::Record::new(Some(0), Position::new(1), 10, 100, 50, 200),
+            crai::Record::new(Some(0), Position::new(11), 10, 100, 250, 200),
+            crai::Record::new(Some(1), Position::new(1), 10, 100, 250, 200),
+            crai::Record::new(Some(0), Position::new(21), 10, 500, 50, 200),
+            crai::Record::new(Some(1), Position::new(15), 10, 500, 50, 200),
+            crai::Record::new(None, None, 0, 900, 50, 200),
+        ];
+
+        let interval = (Position::try_from(8)?..=Position::try_from(13)?).into();
+        let actual = intersecting_slices(&index, 0, interval)?;
+        assert_eq!(actual, [(100, vec![50, 250])]);
+
+        let interval = (Position::try_from(8)?..=Position::try_from(16)?).into();
+        let actual = intersecting_slices(&index, 1, interval)?;
+        assert_eq!(actual, [(100, vec![250]), (500, vec![50])]);
+
+        Ok(())
+    }
+}
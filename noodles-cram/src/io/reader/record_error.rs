@@ -0,0 +1,101 @@
+use std::{error, fmt, io};
+
+use bstr::{BStr, BString};
+
+/// An error returned when CRAM records fail to be read or decoded.
+///
+/// This wraps the underlying error with the location of the records in the stream. Records are
+/// decoded by slice, so a decode error applies to all the records of a slice. It is created by
+/// [`super::RecordsLossy`].
+#[derive(Debug)]
+pub struct RecordError {
+    record_index: u64,
+    position: u64,
+    slice_index: Option<usize>,
+    reference_sequence_name: Option<BString>,
+    source: io::Error,
+}
+
+impl RecordError {
+    pub(super) fn new(
+        record_index: u64,
+        position: u64,
+        slice_index: Option<usize>,
+        reference_sequence_name: Option<BString>,
+        source: io::Error,
+    ) -> Self {
+        Self {
+            record_index,
+            position,
+            slice_index,
+            reference_sequence_name,
+            source,
+        }
+    }
+
+    /// Returns the (0-based) index of the first affected record, counted from the start of
+    /// iteration.
+    pub fn record_index(&self) -> u64 {
+        self.record_index
+    }
+
+    /// Returns the position of the start of the data container.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns the index of the slice in the data container, if the error is a slice decode
+    /// error.
+    pub fn slice_index(&self) -> Option<usize> {
+        self.slice_index
+    }
+
+    /// Returns the reference sequence name of the slice, if known.
+    pub fn reference_sequence_name(&self) -> Option<&BStr> {
+        self.reference_sequence_name
+            .as_ref()
+            .map(|name| name.as_ref())
+    }
+
+    /// Returns a reference to the underlying error.
+    pub fn get_ref(&self) -> &io::Error {
+        &self.source
+    }
+
+    /// Unwraps and returns the underlying error.
+    pub fn into_inner(self) -> io::Error {
+        self.source
+    }
+}
+
+impl error::Error for RecordError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl fmt::Display for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid records (record index = {}, position = {}",
+            self.record_index, self.position
+        )?;
+
+        if let Some(i) = self.slice_index {
+            write!(f, ", slice index = {i}")?;
+        }
+
+        if let Some(name) = &self.reference_sequence_name {
+            write!(f, ", reference sequence name = {name}")?;
+        }
+
+        f.write_str(")")
+    }
+}
+
+impl From<RecordError> for io::Error {
+    fn from(e: RecordError) -> Self {
+        Self::new(e.source.kind(), e)
+    }
+}
@@ -37,27 +37,9 @@ where
             return Ok(true);
         };
 
-        self.records = container
-            .slices()
-            .iter()
-            .map(|slice| {
-                let compression_header = container.compression_header();
-
-                slice.records(compression_header).and_then(|mut records| {
-                    slice.resolve_records(
-                        self.reader.reference_sequence_repository(),
-                        self.header,
-                        compression_header,
-                        &mut records,
-                    )?;
-
-                    Ok(records)
-                })
-            })
-            .collect::<Result<Vec<_>, _>>()?
-            .into_iter()
-            .flatten()
-            .collect::<Vec<_>>()
+        self.records = self
+            .reader
+            .decode_data_container_records(self.header, &container)?
             .into_iter();
 
         Ok(false)
@@ -0,0 +1,222 @@
+use std::{
+    io::{Read, Seek},
+    vec,
+};
+
+use bstr::BString;
+use noodles_sam as sam;
+
+use super::{Reader, RecordError};
+use crate::{
+    data_container::{ReferenceSequenceContext, Slice},
+    Record,
+};
+
+/// An iterator over records of a CRAM reader that skips slices that fail to decode.
+///
+/// This is created by calling [`Reader::records_lossy`].
+pub struct RecordsLossy<'r, 'h, R> {
+    reader: &'r mut Reader<R>,
+    header: &'h sam::Header,
+    records: vec::IntoIter<Result<Record, RecordError>>,
+    record_index: u64,
+    is_eof: bool,
+}
+
+impl<'r, 'h, R> RecordsLossy<'r, 'h, R>
+where
+    R: Read + Seek,
+{
+    pub(super) fn new(reader: &'r mut Reader<R>, header: &'h sam::Header) -> Self {
+        Self {
+            reader,
+            header,
+            records: Vec::new().into_iter(),
+            record_index: 0,
+            is_eof: false,
+        }
+    }
+
+    fn read_container_records(&mut self) -> Result<bool, RecordError> {
+        let position = self
+            .reader
+            .position()
+            .map_err(|e| RecordError::new(self.record_index, 0, None, None, e))?;
+
+        let container = match self.reader.read_data_container() {
+            Ok(Some(container)) => container,
+            Ok(None) => return Ok(true),
+            Err(e) => return Err(RecordError::new(self.record_index, position, None, None, e)),
+        };
+
+        let compression_header = container.compression_header();
+        let mut records = Vec::new();
+
+        for (i, slice) in container.slices().iter().enumerate() {
+            let record_index = self.record_index;
+            self.record_index += slice.header().record_count() as u64;
+
+            match self
+                .reader
+                .decode_slice_records(self.header, compression_header, slice, None)
+            {
+                Ok(slice_records) => records.extend(slice_records.into_iter().map(Ok)),
+                Err(e) => records.push(Err(RecordError::new(
+                    record_index,
+                    position,
+                    Some(i),
+                    reference_sequence_name(self.header, slice),
+                    e,
+                ))),
+            }
+        }
+
+        self.records = records.into_iter();
+
+        Ok(false)
+    }
+}
+
+impl<'r, 'h, R> Iterator for RecordsLossy<'r, 'h, R>
+where
+    R: Read + Seek,
+{
+    type Item = Result<Record, RecordError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(result) = self.records.next() {
+                return Some(result);
+            }
+
+            if self.is_eof {
+                return None;
+            }
+
+            match self.read_container_records() {
+                Ok(true) => return None,
+                Ok(false) => {}
+                Err(e) => {
+                    // The container boundary is unknown, so the stream cannot be resynchronized.
+                    self.is_eof = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+fn reference_sequence_name(header: &sam::Header, slice: &Slice) -> Option<BString> {
+    match slice.header().reference_sequence_context() {
+        ReferenceSequenceContext::Some(context) => header
+            .reference_sequences()
+            .get_index(context.reference_sequence_id())
+            .map(|(name, _)| name.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{self, Cursor},
+        num::NonZeroUsize,
+    };
+
+    use noodles_core::Position;
+    use noodles_fasta::{
+        self as fasta,
+        record::{Definition, Sequence},
+    };
+    use noodles_sam::{
+        alignment::{
+            io::Write,
+            record::{
+                cigar::{op::Kind, Op},
+                Flags,
+            },
+            RecordBuf,
+        },
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            Definition::new("sq0", None),
+            Sequence::from(b"TTCACCCA".to_vec()),
+        )]);
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let mut writer = crate::io::writer::Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .build_with_writer(Vec::new());
+
+        writer.write_alignment_header(&header)?;
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .set_sequence(b"ACGT".to_vec().into())
+            .set_quality_scores(vec![45; 4].into())
+            .build();
+
+        writer.write_alignment_record(&header, &record)?;
+        writer.write_alignment_record(&header, &record)?;
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+
+        let mut reader = crate::io::reader::Builder::default()
+            .set_reference_sequence_repository(repository)
+            .build_from_reader(Cursor::new(data));
+        reader.read_header()?;
+        let position = reader.position()?;
+        let records: Vec<_> = reader.records_lossy(&header).collect::<Result<_, _>>()?;
+        assert_eq!(records.len(), 2);
+
+        // With a different reference sequence, the slice fails to resolve.
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            Definition::new("sq0", None),
+            Sequence::from(b"GGGGGGGG".to_vec()),
+        )]);
+
+        let mut reader = crate::io::reader::Builder::default()
+            .set_reference_sequence_repository(repository)
+            .build_from_reader(Cursor::new(data));
+        reader.read_header()?;
+        let mut records = reader.records_lossy(&header);
+
+        let e = records.next().unwrap().unwrap_err();
+        assert_eq!(e.record_index(), 0);
+        assert_eq!(e.position(), position);
+        assert_eq!(e.slice_index(), Some(0));
+        assert_eq!(e.reference_sequence_name(), Some(b"sq0".as_ref().into()));
+        assert_eq!(e.get_ref().kind(), io::ErrorKind::InvalidData);
+        assert!(records.next().is_none());
+
+        // truncated data container
+        let end = usize::try_from(position)? + 8;
+        let mut reader = Reader::new(Cursor::new(&data[..end]));
+        reader.read_header()?;
+        let mut records = reader.records_lossy(&header);
+
+        let e = records.next().unwrap().unwrap_err();
+        assert_eq!(e.position(), position);
+        assert!(e.slice_index().is_none());
+        assert_eq!(e.get_ref().kind(), io::ErrorKind::UnexpectedEof);
+        assert!(records.next().is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,218 @@
+use std::io::{self, Read, Write};
+
+use noodles_sam as sam;
+
+use super::{
+    writer::{header_container::write_header_container, write_file_definition},
+    Reader,
+};
+use crate::crai;
+
+/// Replaces the SAM header of a CRAM file.
+///
+/// This reads the file definition and header container from `reader`, writes the file
+/// definition and a new header container with the given SAM header to `writer`, and copies the
+/// remaining containers byte-for-byte. Data containers are not decoded, so this is much faster
+/// than transcoding the file.
+///
+/// Data containers refer to reference sequences by index, so the new header must have the same
+/// number of reference sequences as the original header, in the same order. Reference sequences
+/// must have MD5 checksums (`M5`).
+///
+/// The file offset of each data container changes by the difference in size between the new and
+/// original header containers. This difference is returned and can be used to update an
+/// associated CRAI index with [`reheader_index`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::fs::File;
+/// use noodles_cram as cram;
+///
+/// let mut reader = File::open("sample.cram").map(cram::io::Reader::new)?;
+/// let mut header = reader.read_header()?;
+///
+/// header.add_comment("noodles-cram");
+///
+/// let mut src = File::open("sample.cram")?;
+/// let mut dst = File::create("sample.reheadered.cram")?;
+/// let offset_delta = cram::io::reheader(&mut src, &mut dst, &header)?;
+///
+/// let index = cram::crai::read("sample.cram.crai")?;
+/// let index = cram::io::reheader_index(&index, offset_delta)?;
+/// cram::crai::write("sample.reheadered.cram.crai", &index)?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn reheader<R, W>(reader: R, writer: &mut W, header: &sam::Header) -> io::Result<i64>
+where
+    R: Read,
+    W: Write,
+{
+    let mut reader = Reader::new(CountingReader::new(reader));
+
+    let file_definition = reader.read_file_definition()?;
+    let file_definition_len = reader.get_ref().position();
+
+    let src_header = reader.read_file_header()?;
+    let src_header_container_len = reader.get_ref().position() - file_definition_len;
+
+    let src_reference_sequence_count = src_header.reference_sequences().len();
+    let dst_reference_sequence_count = header.reference_sequences().len();
+
+    if dst_reference_sequence_count != src_reference_sequence_count {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "reference sequence count mismatch: expected {src_reference_sequence_count}, got {dst_reference_sequence_count}"
+            ),
+        ));
+    }
+
+    let mut header_container = Vec::new();
+    write_header_container(&mut header_container, header)?;
+
+    write_file_definition(writer, &file_definition)?;
+    writer.write_all(&header_container)?;
+
+    io::copy(reader.get_mut(), writer)?;
+
+    let src_header_container_len = i64::try_from(src_header_container_len)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let dst_header_container_len = i64::try_from(header_container.len())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    Ok(dst_header_container_len - src_header_container_len)
+}
+
+/// Adjusts the container offsets of a CRAI index by the given difference.
+///
+/// This is typically used with the offset difference returned by [`reheader`].
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_cram::{self as cram, crai};
+///
+/// let index = vec![crai::Record::new(Some(0), Position::new(8), 13, 21, 34, 55)];
+/// let index = cram::io::reheader_index(&index, -5)?;
+/// assert_eq!(index[0].offset(), 16);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn reheader_index(index: &[crai::Record], offset_delta: i64) -> io::Result<crai::Index> {
+    index
+        .iter()
+        .map(|record| {
+            let offset = i64::try_from(record.offset())
+                .ok()
+                .and_then(|n| n.checked_add(offset_delta))
+                .and_then(|n| u64::try_from(n).ok())
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid offset"))?;
+
+            Ok(crai::Record::new(
+                record.reference_sequence_id(),
+                record.alignment_start(),
+                record.alignment_span(),
+                offset,
+                record.landmark(),
+                record.slice_length(),
+            ))
+        })
+        .collect()
+}
+
+struct CountingReader<R> {
+    inner: R,
+    position: u64,
+}
+
+impl<R> CountingReader<R> {
+    fn new(inner: R) -> Self {
+        Self { inner, position: 0 }
+    }
+
+    fn position(&self) -> u64 {
+        self.position
+    }
+}
+
+impl<R> Read for CountingReader<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.position += n as u64;
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_sam::header::record::value::{
+        map::{reference_sequence::tag, ReferenceSequence},
+        Map,
+    };
+
+    use super::*;
+    use crate::io::Writer;
+
+    const SQ0_LN: NonZeroUsize = match NonZeroUsize::new(8) {
+        Some(length) => length,
+        None => unreachable!(),
+    };
+
+    fn build_header() -> Result<sam::Header, Box<dyn std::error::Error>> {
+        Ok(sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::builder()
+                    .set_length(SQ0_LN)
+                    .insert(
+                        tag::MD5_CHECKSUM,
+                        Vec::from("d7eba311421bbc9d3ada44709dd61534"),
+                    )
+                    .build()?,
+            )
+            .build())
+    }
+
+    #[test]
+    fn test_reheader() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_record(&header, crate::Record::default())?;
+        writer.try_finish(&header)?;
+        let src = writer.get_ref().clone();
+
+        let mut new_header = header.clone();
+        new_header.add_comment("noodles-cram");
+
+        let mut dst = Vec::new();
+        let offset_delta = reheader(&src[..], &mut dst, &new_header)?;
+
+        let mut reader = Reader::new(&dst[..]);
+        assert_eq!(reader.read_header()?, new_header);
+
+        let records: Vec<_> = reader.records(&new_header).collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 1);
+
+        let dst_len = i64::try_from(dst.len())?;
+        let src_len = i64::try_from(src.len())?;
+        assert_eq!(dst_len - src_len, offset_delta);
+        assert!(offset_delta > 0);
+
+        let mut new_header = header.clone();
+        new_header.reference_sequences_mut().clear();
+        assert!(matches!(
+            reheader(&src[..], &mut Vec::new(), &new_header),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -7,13 +7,18 @@ pub(crate) mod header_container;
 pub(crate) mod num;
 mod options;
 pub(crate) mod record;
+mod worker_pool;
 
 pub use self::builder::Builder;
 pub(crate) use self::options::Options;
+use self::worker_pool::{EncodedDataContainer, WorkerPool};
 
 use std::{
+    collections::VecDeque,
     io::{self, Write},
     mem,
+    num::NonZeroUsize,
+    sync::Arc,
 };
 
 use noodles_fasta as fasta;
@@ -53,6 +58,10 @@ where
     options: Options,
     data_container_builder: crate::data_container::Builder,
     record_counter: u64,
+    worker_count: NonZeroUsize,
+    header: Option<Arc<sam::Header>>,
+    worker_pool: Option<WorkerPool>,
+    pending_data_containers: VecDeque<EncodedDataContainer>,
 }
 
 impl<W> Writer<W>
@@ -104,7 +113,17 @@ where
     /// ```
     pub fn try_finish(&mut self, header: &sam::Header) -> io::Result<()> {
         use self::container::write_eof_container;
+
         self.flush(header)?;
+
+        while !self.pending_data_containers.is_empty() {
+            self.write_pending_data_container()?;
+        }
+
+        if let Some(worker_pool) = self.worker_pool.take() {
+            worker_pool.finish()?;
+        }
+
         write_eof_container(&mut self.inner)
     }
 
@@ -163,6 +182,9 @@ where
     pub fn write_file_header(&mut self, header: &sam::Header) -> io::Result<()> {
         use self::header_container::write_header_container;
 
+        // The header is shared with the data container encoders.
+        self.header = Some(Arc::new(header.clone()));
+
         let mut header = header.clone();
 
         add_missing_reference_sequence_checksums(
@@ -251,13 +273,55 @@ where
 
         let base_count = data_container_builder.base_count();
 
-        let data_container = data_container_builder.build(
-            &self.options,
-            &self.reference_sequence_repository,
-            header,
-        )?;
+        if self.worker_count.get() == 1 {
+            let data_container = data_container_builder.build(
+                &self.options,
+                &self.reference_sequence_repository,
+                header,
+            )?;
 
-        write_data_container(&mut self.inner, &data_container, base_count)
+            return write_data_container(&mut self.inner, &data_container, base_count);
+        }
+
+        // At most `worker_count` data containers are encoded concurrently. When all workers are
+        // busy, the oldest data container is waited on and written before another is started,
+        // which bounds memory usage and keeps the output in order.
+        if self.pending_data_containers.len() >= self.worker_count.get() {
+            self.write_pending_data_container()?;
+        }
+
+        let header = self
+            .header
+            .get_or_insert_with(|| Arc::new(header.clone()))
+            .clone();
+
+        let worker_pool = self.worker_pool.get_or_insert_with(|| {
+            WorkerPool::new(
+                self.worker_count,
+                &self.options,
+                &self.reference_sequence_repository,
+            )
+        });
+
+        let encoded_data_container =
+            worker_pool.encode(data_container_builder, base_count, header)?;
+
+        self.pending_data_containers
+            .push_back(encoded_data_container);
+
+        Ok(())
+    }
+
+    fn write_pending_data_container(&mut self) -> io::Result<()> {
+        if let Some(encoded_data_container) = self.pending_data_containers.pop_front() {
+            let buf = encoded_data_container
+                .recv()
+                .map_err(|_| worker_pool::encoder_panicked_error())??;
+
+            self.inner.write_all(&buf)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -283,7 +347,10 @@ where
     }
 }
 
-fn write_file_definition<W>(writer: &mut W, file_definition: &FileDefinition) -> io::Result<()>
+pub(crate) fn write_file_definition<W>(
+    writer: &mut W,
+    file_definition: &FileDefinition,
+) -> io::Result<()>
 where
     W: Write,
 {
@@ -398,4 +465,222 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_alignment_record_with_all_data_field_value_types(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use sam::alignment::{
+            io::Write,
+            record::{data::field::Tag, Flags},
+            record_buf::data::field::{value::Array, Value},
+            RecordBuf,
+        };
+
+        use noodles_bam as bam;
+
+        use crate::io::Reader;
+
+        let header = sam::Header::default();
+
+        let values = [
+            Value::Character(b'n'),
+            Value::Int8(-8),
+            Value::UInt8(8),
+            Value::Int16(-13),
+            Value::UInt16(13),
+            Value::Int32(-21),
+            Value::UInt32(21),
+            Value::Float(34.0),
+            Value::from("noodles"),
+            Value::Hex("CAFE".into()),
+            Value::Array(Array::Int8(vec![-1, 0, 1])),
+            Value::Array(Array::UInt8(vec![0, 1, 255])),
+            Value::Array(Array::Int16(vec![i16::MIN, 0, i16::MAX])),
+            Value::Array(Array::UInt16(vec![0, 1, u16::MAX])),
+            Value::Array(Array::Int32(vec![i32::MIN, 0, i32::MAX])),
+            Value::Array(Array::UInt32(vec![0, 1, u32::MAX])),
+            Value::Array(Array::Float(vec![-1.5, 0.0, 1.5])),
+            Value::Array(Array::UInt8(Vec::new())),
+        ];
+
+        let data = values
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| {
+                let tag = Tag::new(b'X', b'a' + i as u8);
+                (tag, value)
+            })
+            .collect();
+
+        let record = RecordBuf::builder()
+            .set_name("r0")
+            .set_flags(Flags::UNMAPPED)
+            .set_sequence(b"ACGT".to_vec().into())
+            .set_data(data)
+            .build();
+
+        // The same record as a lazy BAM record.
+        let bam_record = {
+            let mut bam_writer = bam::io::Writer::from(Vec::new());
+            bam_writer.write_alignment_record(&header, &record)?;
+
+            let mut bam_reader = bam::io::Reader::from(&bam_writer.get_ref()[..]);
+            let mut bam_record = bam::Record::default();
+            bam_reader.read_record(&mut bam_record)?;
+
+            bam_record
+        };
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_alignment_header(&header)?;
+        writer.write_alignment_record(&header, &record)?;
+        writer.write_alignment_record(&header, &bam_record)?;
+        writer.try_finish(&header)?;
+
+        let mut reader = Reader::new(&writer.get_ref()[..]);
+        reader.read_header()?;
+
+        let records: Vec<_> = reader
+            .records(&header)
+            .map(|result| result.and_then(|r| r.try_into_alignment_record(&header)))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].data(), record.data());
+        assert_eq!(records[1].data(), record.data());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_alignment_record_with_long_read() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use fasta::record::{Definition, Sequence};
+        use noodles_core::Position;
+        use sam::{
+            alignment::{
+                io::Write,
+                record::{
+                    cigar::{op::Kind, Op},
+                    Flags,
+                },
+                record_buf::{Cigar, QualityScores},
+                RecordBuf,
+            },
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        use crate::io::reader;
+
+        // A 1.5 Mbp spliced read with 200,000 ops.
+        const OP_PAIR_COUNT: usize = 100_000;
+        const BASE_COUNT: usize = 15 * OP_PAIR_COUNT;
+        const SQ0_LN: usize = 20 * OP_PAIR_COUNT;
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            Definition::new("sq0", None),
+            Sequence::from(b"ACGT".repeat(SQ0_LN / 4)),
+        )]);
+
+        let mut header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(SQ0_LN)?),
+            )
+            .build();
+
+        add_missing_reference_sequence_checksums(&repository, header.reference_sequences_mut())?;
+
+        let cigar: Cigar = [Op::new(Kind::Match, 15), Op::new(Kind::Skip, 5)]
+            .into_iter()
+            .cycle()
+            .take(2 * OP_PAIR_COUNT)
+            .collect();
+
+        // The read matches the reference in the aligned blocks.
+        let sequence: Vec<_> = (0..OP_PAIR_COUNT)
+            .flat_map(|i| (20 * i..20 * i + 15).map(|j| b"ACGT"[j % 4]))
+            .collect();
+
+        let expected = RecordBuf::builder()
+            .set_name("r0")
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_cigar(cigar)
+            .set_sequence(sequence.into())
+            .set_quality_scores(QualityScores::from(vec![30; BASE_COUNT]))
+            .build();
+
+        let mut writer = Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .build_with_writer(Vec::new());
+
+        writer.write_alignment_header(&header)?;
+        writer.write_alignment_record(&header, &expected)?;
+        writer.try_finish(&header)?;
+
+        let mut reader = reader::Builder::default()
+            .set_reference_sequence_repository(repository)
+            .build_from_reader(&writer.get_ref()[..]);
+
+        reader.read_header()?;
+
+        let records: Vec<_> = reader
+            .records(&header)
+            .map(|result| result.and_then(|r| r.try_into_alignment_record(&header)))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].cigar(), expected.cigar());
+        assert_eq!(records[0].sequence(), expected.sequence());
+        assert_eq!(records[0].quality_scores(), expected.quality_scores());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_multiple_workers() -> io::Result<()> {
+        fn write(worker_count: NonZeroUsize) -> io::Result<Vec<u8>> {
+            let header = sam::Header::default();
+
+            let mut writer = Builder::default()
+                .set_worker_count(worker_count)
+                .build_with_writer(Vec::new());
+
+            writer.write_header(&header)?;
+
+            for i in 0..25000 {
+                let record = Record::builder().set_name(format!("r{i}")).build();
+
+                writer.write_record(&header, record)?;
+            }
+
+            writer.try_finish(&header)?;
+
+            Ok(writer.get_ref().clone())
+        }
+
+        fn read_names(src: &[u8]) -> io::Result<Vec<Option<BString>>> {
+            let mut reader = crate::io::Reader::new(src);
+            let header = reader.read_header()?;
+
+            reader
+                .records(&header)
+                .map(|result| result.map(|record| record.name().map(|name| name.into())))
+                .collect()
+        }
+
+        let expected = read_names(&write(NonZeroUsize::MIN)?)?;
+        assert_eq!(expected.len(), 25000);
+
+        let worker_count = NonZeroUsize::try_from(2)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        let actual = read_names(&write(worker_count)?)?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
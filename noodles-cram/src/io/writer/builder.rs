@@ -1,6 +1,8 @@
 use std::{
+    collections::VecDeque,
     fs::File,
     io::{self, Write},
+    num::NonZeroUsize,
     path::Path,
 };
 
@@ -8,15 +10,17 @@ use noodles_fasta as fasta;
 
 use super::{Options, Writer};
 use crate::{
-    codecs::Encoder, data_container::BlockContentEncoderMap, file_definition::Version,
+    codecs::Encoder,
+    data_container::{block_content_encoder_map::Profile, BlockContentEncoderMap},
+    file_definition::Version,
     DataContainer,
 };
 
 /// A CRAM writer builder.
-#[derive(Default)]
 pub struct Builder {
     reference_sequence_repository: fasta::Repository,
     options: Options,
+    worker_count: NonZeroUsize,
 }
 
 impl Builder {
@@ -91,6 +95,44 @@ impl Builder {
         self
     }
 
+    /// Sets the block content-encoder map from an encoding profile.
+    ///
+    /// This overrides any previously set block content-encoder map (see
+    /// [`Self::set_block_content_encoder_map`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{data_container::block_content_encoder_map::Profile, io::writer::Builder};
+    /// let builder = Builder::default().set_profile(Profile::Fast);
+    /// ```
+    pub fn set_profile(self, profile: Profile) -> Self {
+        self.set_block_content_encoder_map(BlockContentEncoderMap::from(profile))
+    }
+
+    /// Sets the worker count.
+    ///
+    /// When the worker count is greater than 1, data containers are encoded in a pipeline: while
+    /// records are added to the next data container, up to `worker_count` full data containers
+    /// are built, compressed, and serialized concurrently on worker threads. Data containers are
+    /// written in the order their records were added.
+    ///
+    /// By default, the worker count is 1, i.e., data containers are encoded on the calling
+    /// thread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_cram::io::writer::Builder;
+    /// let builder = Builder::default().set_worker_count(NonZeroUsize::try_from(4)?);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_worker_count(mut self, worker_count: NonZeroUsize) -> Self {
+        self.worker_count = worker_count;
+        self
+    }
+
     /// Builds a CRAM writer from a path.
     ///
     /// # Examples
@@ -129,6 +171,20 @@ impl Builder {
             options: self.options,
             data_container_builder: DataContainer::builder(0),
             record_counter: 0,
+            worker_count: self.worker_count,
+            header: None,
+            worker_pool: None,
+            pending_data_containers: VecDeque::new(),
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            reference_sequence_repository: fasta::Repository::default(),
+            options: Options::default(),
+            worker_count: NonZeroUsize::MIN,
         }
     }
 }
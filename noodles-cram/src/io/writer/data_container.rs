@@ -49,9 +49,13 @@ fn build_container(
         .set_data(buf.into())
         .build();
 
-    let mut blocks = vec![block];
+    // Landmarks are the offsets of the slices from the start of the container data, i.e., the
+    // first slice starts after the compression header block.
+    let mut landmark = block.len();
     let mut landmarks = Vec::new();
 
+    let mut blocks = vec![block];
+
     let container_reference_sequence_context =
         build_container_reference_sequence_context(data_container.slices())?;
 
@@ -67,6 +71,8 @@ fn build_container(
 
         container_record_count += slice_header.record_count() as i32;
 
+        landmarks.push(landmark);
+
         let mut slice_len = 0;
 
         let mut slice_header_buf = Vec::new();
@@ -89,9 +95,7 @@ fn build_container(
             slice_len += external_block.len();
         }
 
-        let last_landmark = landmarks.last().copied().unwrap_or(0);
-        let landmark = last_landmark + slice_len;
-        landmarks.push(landmark);
+        landmark += slice_len;
     }
 
     let len = blocks.iter().map(|b| b.len()).sum();
@@ -0,0 +1,117 @@
+use std::{
+    io,
+    num::NonZeroUsize,
+    sync::{
+        mpsc::{self, Receiver, SyncSender},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+};
+
+use noodles_fasta as fasta;
+use noodles_sam as sam;
+
+use super::{data_container::write_data_container, Options};
+use crate::data_container;
+
+pub(super) type EncodedDataContainer = Receiver<io::Result<Vec<u8>>>;
+
+type Job = (
+    data_container::Builder,
+    u64,
+    Arc<sam::Header>,
+    SyncSender<io::Result<Vec<u8>>>,
+);
+
+/// A fixed pool of workers that build and serialize data containers.
+#[derive(Debug)]
+pub(super) struct WorkerPool {
+    tx: SyncSender<Job>,
+    handles: Vec<JoinHandle<()>>,
+}
+
+impl WorkerPool {
+    pub(super) fn new(
+        worker_count: NonZeroUsize,
+        options: &Options,
+        reference_sequence_repository: &fasta::Repository,
+    ) -> Self {
+        let (tx, rx) = mpsc::sync_channel(worker_count.get());
+        let rx = Arc::new(Mutex::new(rx));
+
+        let handles = (0..worker_count.get())
+            .map(|_| {
+                let rx = rx.clone();
+                let options = options.clone();
+                let reference_sequence_repository = reference_sequence_repository.clone();
+
+                thread::spawn(move || work(&rx, &options, &reference_sequence_repository))
+            })
+            .collect();
+
+        Self { tx, handles }
+    }
+
+    /// Queues a data container to be encoded.
+    ///
+    /// This blocks when all workers are busy and the queue is full. The returned receiver yields
+    /// the serialized data container.
+    pub(super) fn encode(
+        &self,
+        data_container_builder: data_container::Builder,
+        base_count: u64,
+        header: Arc<sam::Header>,
+    ) -> io::Result<EncodedDataContainer> {
+        let (result_tx, result_rx) = mpsc::sync_channel(1);
+
+        self.tx
+            .send((data_container_builder, base_count, header, result_tx))
+            .map_err(|_| encoder_panicked_error())?;
+
+        Ok(result_rx)
+    }
+
+    /// Stops the workers and waits for them to exit.
+    pub(super) fn finish(self) -> io::Result<()> {
+        drop(self.tx);
+
+        for handle in self.handles {
+            handle.join().map_err(|_| encoder_panicked_error())?;
+        }
+
+        Ok(())
+    }
+}
+
+pub(super) fn encoder_panicked_error() -> io::Error {
+    io::Error::new(io::ErrorKind::Other, "data container encoder panicked")
+}
+
+fn work(
+    rx: &Mutex<Receiver<Job>>,
+    options: &Options,
+    reference_sequence_repository: &fasta::Repository,
+) {
+    loop {
+        let job = match rx.lock() {
+            Ok(rx) => rx.recv(),
+            Err(_) => return,
+        };
+
+        // The sender is dropped when the pool is finished or the writer is dropped.
+        let Ok((data_container_builder, base_count, header, result_tx)) = job else {
+            return;
+        };
+
+        let result = data_container_builder
+            .build(options, reference_sequence_repository, &header)
+            .and_then(|data_container| {
+                let mut buf = Vec::new();
+                write_data_container(&mut buf, &data_container, base_count)?;
+                Ok(buf)
+            });
+
+        // The receiver is dropped if the writer is dropped before the result is read.
+        let _ = result_tx.send(result);
+    }
+}
@@ -15,9 +15,14 @@ mod indexer;
 pub mod io;
 mod num;
 pub mod record;
+#[cfg(feature = "ena")]
+pub mod repository;
 
 pub use self::{
-    data_container::DataContainer, file_definition::FileDefinition, indexer::index, record::Record,
+    data_container::DataContainer,
+    file_definition::FileDefinition,
+    indexer::{index, index_from_reader},
+    record::Record,
 };
 
 #[cfg(feature = "async")]
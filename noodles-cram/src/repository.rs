@@ -0,0 +1,3 @@
+//! Reference sequence repository adapters.
+
+pub mod adapters;
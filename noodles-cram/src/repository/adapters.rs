@@ -0,0 +1,5 @@
+//! Reference sequence repository adapters for [`noodles_fasta::Repository`].
+
+pub mod ena;
+
+pub use self::ena::Ena;
@@ -0,0 +1,223 @@
+//! ENA CRAM reference registry adapter.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use noodles_fasta::{
+    self as fasta,
+    repository::{adapters::ref_path::expand_template, Adapter},
+};
+use noodles_sam::{self as sam, header::record::value::map::reference_sequence::tag};
+
+use crate::data_container::slice::builder::calculate_normalized_sequence_digest;
+
+/// The default base URL of the ENA CRAM reference registry.
+pub const DEFAULT_BASE_URL: &str = "https://www.ebi.ac.uk/ena/cram/md5/";
+
+const CACHE_TEMPLATE: &str = "%2s/%2s/%s";
+
+/// A repository adapter that downloads reference sequences by MD5 checksum.
+///
+/// Reference sequence names are mapped to MD5 checksums using the `M5` field of the reference
+/// sequence records in a SAM header. A sequence is first looked up in the local cache directory,
+/// if set, and then downloaded from `<base-url><md5>`, e.g., the [ENA CRAM reference registry].
+/// Downloaded sequences are verified against their checksums and written to the cache.
+///
+/// The cache layout is the same as the htslib default for `REF_CACHE`, i.e.,
+/// `<cache-dir>/%2s/%2s/%s`, where the checksum is split into a 2-character, 2-character, and
+/// remainder part.
+///
+/// This uses a blocking HTTP client and must not be used within an asynchronous runtime.
+///
+/// [ENA CRAM reference registry]: https://www.ebi.ac.uk/ena/cram/
+pub struct Ena {
+    http_client: reqwest::blocking::Client,
+    base_url: String,
+    cache_dir: Option<PathBuf>,
+    checksums: HashMap<Vec<u8>, String>,
+}
+
+impl Ena {
+    /// Creates an ENA adapter builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::repository::adapters::Ena;
+    /// let builder = Ena::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    /// Creates an ENA adapter using the reference sequence checksums of the given SAM header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::repository::adapters::Ena;
+    /// use noodles_sam as sam;
+    ///
+    /// let header = sam::Header::default();
+    /// let adapter = Ena::new(&header);
+    /// ```
+    pub fn new(header: &sam::Header) -> Self {
+        Builder::default().build(header)
+    }
+
+    /// Returns the MD5 checksum of the reference sequence with the given name.
+    pub fn checksum(&self, name: &[u8]) -> Option<&str> {
+        self.checksums.get(name).map(|s| s.as_str())
+    }
+
+    fn fetch(&self, md5: &str) -> io::Result<Vec<u8>> {
+        if let Some(cache_dir) = &self.cache_dir {
+            let src = build_cache_path(cache_dir, md5);
+
+            match fs::read(&src) {
+                Ok(buf) => return Ok(buf),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        let buf = self.download(md5)?;
+
+        if let Some(cache_dir) = &self.cache_dir {
+            write_cache(&build_cache_path(cache_dir, md5), &buf)?;
+        }
+
+        Ok(buf)
+    }
+
+    fn download(&self, md5: &str) -> io::Result<Vec<u8>> {
+        let url = format!("{}{md5}", self.base_url);
+
+        let buf = self
+            .http_client
+            .get(url)
+            .send()
+            .and_then(|response| response.error_for_status())
+            .and_then(|response| response.bytes())
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+
+        let actual = hex(&calculate_normalized_sequence_digest(&buf));
+
+        if !actual.eq_ignore_ascii_case(md5) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("reference sequence checksum mismatch: expected {md5}, got {actual}"),
+            ));
+        }
+
+        Ok(buf.to_vec())
+    }
+}
+
+impl Adapter for Ena {
+    fn get(&mut self, name: &[u8]) -> Option<io::Result<fasta::Record>> {
+        let md5 = self.checksums.get(name)?;
+
+        Some(self.fetch(md5).map(|sequence| {
+            fasta::Record::new(
+                fasta::record::Definition::new(name, None),
+                fasta::record::Sequence::from(sequence),
+            )
+        }))
+    }
+}
+
+fn build_checksums(header: &sam::Header) -> HashMap<Vec<u8>, String> {
+    header
+        .reference_sequences()
+        .iter()
+        .filter_map(|(name, reference_sequence)| {
+            reference_sequence
+                .other_fields()
+                .get(&tag::MD5_CHECKSUM)
+                .map(|md5| (name.to_vec(), md5.to_string().to_ascii_lowercase()))
+        })
+        .collect()
+}
+
+fn build_cache_path(cache_dir: &Path, md5: &str) -> PathBuf {
+    cache_dir.join(expand_template(CACHE_TEMPLATE, md5))
+}
+
+fn write_cache(dst: &Path, buf: &[u8]) -> io::Result<()> {
+    if let Some(parent) = dst.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    // Write to a temporary file first so that concurrent readers never see a partial sequence.
+    let tmp = dst.with_extension(format!("tmp.{}", std::process::id()));
+    fs::write(&tmp, buf)?;
+    fs::rename(tmp, dst)
+}
+
+fn hex(buf: &[u8]) -> String {
+    use std::fmt::Write;
+
+    buf.iter().fold(String::new(), |mut s, b| {
+        // SAFETY: Writing to a `String` is infallible.
+        write!(s, "{b:02x}").unwrap();
+        s
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use sam::header::record::value::{map::ReferenceSequence, Map};
+
+    use super::*;
+
+    #[test]
+    fn test_build_checksums() -> Result<(), Box<dyn std::error::Error>> {
+        let mut sq0 = Map::<ReferenceSequence>::new(NonZeroUsize::try_from(4)?);
+        sq0.other_fields_mut()
+            .insert(tag::MD5_CHECKSUM, "F1F8F4BF413B16AD135722AA4591043E".into());
+
+        let header = sam::Header::builder()
+            .add_reference_sequence("sq0", sq0)
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let checksums = build_checksums(&header);
+
+        assert_eq!(checksums.len(), 1);
+        assert_eq!(
+            checksums.get(&b"sq0"[..]).map(|s| s.as_str()),
+            Some("f1f8f4bf413b16ad135722aa4591043e")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_cache_path() {
+        assert_eq!(
+            build_cache_path(Path::new("cache"), "f1f8f4bf413b16ad135722aa4591043e"),
+            Path::new("cache/f1/f8/f4bf413b16ad135722aa4591043e")
+        );
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(
+            hex(&calculate_normalized_sequence_digest(b"ACGT")),
+            "f1f8f4bf413b16ad135722aa4591043e"
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use std::path::PathBuf;
+
+use noodles_sam as sam;
+
+use super::{build_checksums, Ena, DEFAULT_BASE_URL};
+
+/// An ENA adapter builder.
+#[derive(Default)]
+pub struct Builder {
+    http_client: Option<reqwest::blocking::Client>,
+    base_url: Option<String>,
+    cache_dir: Option<PathBuf>,
+}
+
+impl Builder {
+    /// Sets the HTTP client.
+    ///
+    /// By default, a new blocking client is created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::repository::adapters::Ena;
+    /// let builder = Ena::builder().set_http_client(reqwest::blocking::Client::new());
+    /// ```
+    pub fn set_http_client(mut self, http_client: reqwest::blocking::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Sets the base URL.
+    ///
+    /// The MD5 checksum of the reference sequence is appended to the base URL to build the
+    /// request URL. By default, this is the base URL of the ENA CRAM reference registry
+    /// ([`DEFAULT_BASE_URL`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::repository::adapters::Ena;
+    /// let builder = Ena::builder().set_base_url("https://localhost/cram/md5/");
+    /// ```
+    pub fn set_base_url<U>(mut self, base_url: U) -> Self
+    where
+        U: Into<String>,
+    {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Sets the local cache directory.
+    ///
+    /// By default, no cache is used, and sequences are always downloaded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::repository::adapters::Ena;
+    /// let builder = Ena::builder().set_cache_dir("cache");
+    /// ```
+    pub fn set_cache_dir<P>(mut self, cache_dir: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.cache_dir = Some(cache_dir.into());
+        self
+    }
+
+    /// Builds an ENA adapter using the reference sequence checksums of the given SAM header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::repository::adapters::Ena;
+    /// use noodles_sam as sam;
+    ///
+    /// let header = sam::Header::default();
+    /// let adapter = Ena::builder().set_cache_dir("cache").build(&header);
+    /// ```
+    pub fn build(self, header: &sam::Header) -> Ena {
+        Ena {
+            http_client: self.http_client.unwrap_or_default(),
+            base_url: self
+                .base_url
+                .unwrap_or_else(|| String::from(DEFAULT_BASE_URL)),
+            cache_dir: self.cache_dir,
+            checksums: build_checksums(header),
+        }
+    }
+}
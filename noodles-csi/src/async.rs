@@ -1,5 +1,6 @@
 //! Async CSI index and fields.
 
+pub mod io;
 mod reader;
 mod writer;
 
@@ -7,7 +8,7 @@ pub use self::{reader::Reader, writer::Writer};
 
 use std::path::Path;
 
-use tokio::{fs::File, io};
+use tokio::fs::File;
 
 use super::Index;
 
@@ -28,7 +29,7 @@ use super::Index;
 /// # Ok(())
 /// # }
 /// ```
-pub async fn read<P>(src: P) -> io::Result<Index>
+pub async fn read<P>(src: P) -> std::io::Result<Index>
 where
     P: AsRef<Path>,
 {
@@ -54,7 +55,7 @@ where
 /// # Ok(())
 /// # }
 /// ```
-pub async fn write<P>(dst: P, index: &Index) -> io::Result<()>
+pub async fn write<P>(dst: P, index: &Index) -> std::io::Result<()>
 where
     P: AsRef<Path>,
 {
@@ -0,0 +1,6 @@
+//! Async CSI I/O.
+
+mod index_records;
+mod index_text;
+
+pub use self::{index_records::index_records, index_text::index_text};
@@ -0,0 +1,66 @@
+use futures::{Stream, TryStreamExt};
+use noodles_core::Position;
+use tokio::io;
+
+use crate::binning_index::{
+    index::reference_sequence::{self, bin::Chunk},
+    Index, Indexer,
+};
+
+/// Builds an index from a stream of record alignment contexts and chunks.
+///
+/// This drives an [`Indexer`] from an async source, e.g., records read by an async alignment or
+/// variant reader. Each item is the argument pair given to [`Indexer::add_record`]. Once the
+/// stream is exhausted, the index is built with the given number of reference sequences.
+///
+/// Set a header on the indexer beforehand if the index requires one, e.g., a tabix index.
+///
+/// # Examples
+///
+/// ```
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use futures::stream;
+/// use noodles_bgzf as bgzf;
+/// use noodles_core::Position;
+/// use noodles_csi::{
+///     self as csi,
+///     binning_index::{
+///         index::reference_sequence::{bin::Chunk, index::BinnedIndex},
+///         Indexer,
+///     },
+/// };
+///
+/// let start = Position::try_from(8)?;
+/// let end = Position::try_from(13)?;
+/// let chunk = Chunk::new(
+///     bgzf::VirtualPosition::from(0),
+///     bgzf::VirtualPosition::from(21),
+/// );
+///
+/// let records = stream::iter([Ok((Some((0, start, end, true)), chunk))]);
+/// let indexer = Indexer::<BinnedIndex>::default();
+/// let index = csi::r#async::io::index_records(records, indexer, 1).await?;
+///
+/// assert_eq!(index.reference_sequences().len(), 1);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn index_records<S, I>(
+    records: S,
+    indexer: Indexer<I>,
+    reference_sequence_count: usize,
+) -> io::Result<Index<I>>
+where
+    S: Stream<Item = io::Result<(Option<(usize, Position, Position, bool)>, Chunk)>>,
+    I: reference_sequence::Index + Default,
+{
+    let mut indexer = indexer;
+    let mut records = Box::pin(records);
+
+    while let Some((alignment_context, chunk)) = records.try_next().await? {
+        indexer.add_record(alignment_context, chunk)?;
+    }
+
+    Ok(indexer.build(reference_sequence_count))
+}
@@ -0,0 +1,97 @@
+use noodles_bgzf as bgzf;
+use tokio::io::{self, AsyncBufReadExt, AsyncRead};
+
+use crate::{
+    binning_index::{
+        index::{
+            reference_sequence::{self, bin::Chunk},
+            Header,
+        },
+        Index, Indexer,
+    },
+    io::TextIndexer,
+};
+
+/// Indexes bgzip-compressed, tab-delimited text from an async reader.
+///
+/// This is the async equivalent of [`crate::io::index_text`].
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Write};
+/// #
+/// # #[tokio::main]
+/// # async fn main() -> io::Result<()> {
+/// use noodles_bgzf as bgzf;
+/// use noodles_csi::{
+///     self as csi,
+///     binning_index::{index::reference_sequence::index::BinnedIndex, Indexer},
+/// };
+///
+/// let mut writer = bgzf::Writer::new(Vec::new());
+/// writer.write_all(b"sq0\t7\t13\nsq1\t20\t34\n")?;
+/// let data = writer.finish()?;
+///
+/// let header = csi::binning_index::index::header::Builder::bed().build();
+/// let indexer = Indexer::<BinnedIndex>::default();
+/// let index = csi::r#async::io::index_text(&data[..], header, indexer).await?;
+///
+/// assert_eq!(index.reference_sequences().len(), 2);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn index_text<R, I>(
+    reader: R,
+    header: Header,
+    indexer: Indexer<I>,
+) -> io::Result<Index<I>>
+where
+    R: AsyncRead + Unpin,
+    I: reference_sequence::Index + Default,
+{
+    let mut reader = bgzf::AsyncReader::new(reader);
+    let mut indexer = TextIndexer::new(header, indexer);
+
+    let mut line = String::new();
+    let mut start_position = reader.virtual_position();
+
+    loop {
+        line.clear();
+
+        if reader.read_line(&mut line).await? == 0 {
+            break;
+        }
+
+        let end_position = reader.virtual_position();
+        indexer.add_line(&line, Chunk::new(start_position, end_position))?;
+        start_position = end_position;
+    }
+
+    Ok(indexer.build())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+    use crate::binning_index::index::reference_sequence::index::BinnedIndex;
+
+    #[tokio::test]
+    async fn test_index_text() -> io::Result<()> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"#noodles\nsq0\t7\t13\nsq0\t20\t34\n\nsq1\t4\t8\n")?;
+        let data = writer.finish()?;
+
+        let header = crate::binning_index::index::header::Builder::bed().build();
+
+        let expected =
+            crate::io::index_text(&data[..], header.clone(), Indexer::<BinnedIndex>::default())?;
+        let actual = index_text(&data[..], header, Indexer::<BinnedIndex>::default()).await?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
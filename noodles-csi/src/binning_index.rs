@@ -1,16 +1,23 @@
 //! Binning index.
 
+mod batch_query;
 pub mod index;
 mod indexer;
 mod reference_sequence;
 
-use std::io;
+use std::{io, sync::Arc};
 
+use bit_vec::BitVec;
 use noodles_bgzf as bgzf;
 use noodles_core::region::Interval;
 
-use self::index::{reference_sequence::bin::Chunk, Header};
-pub use self::{index::Index, indexer::Indexer, reference_sequence::ReferenceSequence};
+use self::index::{
+    reference_sequence::{bin::Chunk, Bin},
+    resolve_interval, Header,
+};
+pub use self::{
+    batch_query::BatchQuery, index::Index, indexer::Indexer, reference_sequence::ReferenceSequence,
+};
 
 /// A binning index.
 pub trait BinningIndex {
@@ -72,6 +79,83 @@ where
     }
 }
 
+impl<I> BinningIndex for Arc<I>
+where
+    I: BinningIndex + ?Sized,
+{
+    fn min_shift(&self) -> u8 {
+        (**self).min_shift()
+    }
+
+    fn depth(&self) -> u8 {
+        (**self).depth()
+    }
+
+    fn header(&self) -> Option<&Header> {
+        (**self).header()
+    }
+
+    fn reference_sequences(&self) -> Box<dyn Iterator<Item = &dyn ReferenceSequence> + '_> {
+        (**self).reference_sequences()
+    }
+
+    fn unplaced_unmapped_record_count(&self) -> Option<u64> {
+        (**self).unplaced_unmapped_record_count()
+    }
+
+    fn query(&self, reference_sequence_id: usize, interval: Interval) -> io::Result<Vec<Chunk>> {
+        (**self).query(reference_sequence_id, interval)
+    }
+
+    fn last_first_record_start_position(&self) -> Option<bgzf::VirtualPosition> {
+        (**self).last_first_record_start_position()
+    }
+}
+
+/// Calculates the IDs of the bins that overlap the given interval.
+///
+/// This is `reg2bins` in the CSI specification, generalized to an arbitrary minimum interval size
+/// (`min_shift`) and depth. A BAI index uses a `min_shift` of 14 and a depth of 5. The bin IDs
+/// are returned in ascending order.
+///
+/// An unbounded start or end resolves to the start or end of the binning scheme, respectively.
+/// This returns an error if the interval is outside the range of the binning scheme.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_csi::binning_index::reg2bins;
+///
+/// const MIN_SHIFT: u8 = 4;
+/// const DEPTH: u8 = 2;
+///
+/// let start = Position::try_from(36)?;
+/// let end = Position::try_from(67)?;
+/// assert_eq!(reg2bins(MIN_SHIFT, DEPTH, start..=end)?, [0, 1, 11, 12, 13]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn reg2bins<I>(min_shift: u8, depth: u8, interval: I) -> io::Result<Vec<usize>>
+where
+    I: Into<Interval>,
+{
+    let (start, end) = resolve_interval(min_shift, depth, interval)?;
+
+    let max_bin_id = Bin::max_id(depth);
+    let mut region_bins = BitVec::from_elem(max_bin_id, false);
+
+    self::index::reference_sequence::reg2bins(start, end, min_shift, depth, &mut region_bins);
+
+    let bin_ids = region_bins
+        .iter()
+        .enumerate()
+        .filter(|(_, is_set)| *is_set)
+        .map(|(id, _)| id)
+        .collect();
+
+    Ok(bin_ids)
+}
+
 /// Merges a list of chunks into a list of non-overlapping chunks.
 ///
 /// This is the same as calling [`optimize_chunks`] with a `min_offset` of 0.
@@ -164,10 +248,108 @@ pub fn optimize_chunks(chunks: &[Chunk], min_offset: bgzf::VirtualPosition) -> V
     merged_chunks
 }
 
+/// Coalesces a list of sorted, non-overlapping chunks separated by small gaps.
+///
+/// Adjacent chunks are merged when the gap between the end of a chunk and the start of the next
+/// chunk is at most `max_gap` bytes of compressed data. This trades reading extra data in the
+/// gaps for fewer, larger reads, e.g., fewer range requests to remote storage. A `max_gap` of 0
+/// only merges chunks where the next chunk starts in the same block that the previous chunk ends
+/// in.
+///
+/// The chunks are typically the result of [`optimize_chunks`] or [`BinningIndex::query`].
+/// Merged chunks can contain records outside of the query, so readers must filter records, as
+/// the query readers in noodles do.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bgzf as bgzf;
+/// use noodles_csi::binning_index::{coalesce_chunks, index::reference_sequence::bin::Chunk};
+///
+/// let chunks = [
+///     Chunk::new(
+///         bgzf::VirtualPosition::try_from((0, 8))?,
+///         bgzf::VirtualPosition::try_from((144, 13))?,
+///     ),
+///     Chunk::new(
+///         bgzf::VirtualPosition::try_from((233, 0))?,
+///         bgzf::VirtualPosition::try_from((377, 21))?,
+///     ),
+///     Chunk::new(
+///         bgzf::VirtualPosition::try_from((8192, 5))?,
+///         bgzf::VirtualPosition::try_from((8196, 34))?,
+///     ),
+/// ];
+///
+/// let actual = coalesce_chunks(&chunks, 1024);
+///
+/// let expected = [
+///     Chunk::new(
+///         bgzf::VirtualPosition::try_from((0, 8))?,
+///         bgzf::VirtualPosition::try_from((377, 21))?,
+///     ),
+///     Chunk::new(
+///         bgzf::VirtualPosition::try_from((8192, 5))?,
+///         bgzf::VirtualPosition::try_from((8196, 34))?,
+///     ),
+/// ];
+///
+/// assert_eq!(actual, expected);
+/// # Ok::<_, bgzf::virtual_position::TryFromU64U16TupleError>(())
+/// ```
+pub fn coalesce_chunks(chunks: &[Chunk], max_gap: u64) -> Vec<Chunk> {
+    let mut coalesced_chunks: Vec<Chunk> = Vec::with_capacity(chunks.len());
+
+    for &chunk in chunks {
+        if let Some(last_chunk) = coalesced_chunks.last_mut() {
+            let gap = chunk
+                .start()
+                .compressed()
+                .saturating_sub(last_chunk.end().compressed());
+
+            if gap <= max_gap {
+                let end = last_chunk.end().max(chunk.end());
+                *last_chunk = Chunk::new(last_chunk.start(), end);
+                continue;
+            }
+        }
+
+        coalesced_chunks.push(chunk);
+    }
+
+    coalesced_chunks
+}
+
 #[cfg(test)]
 mod tests {
+    use noodles_core::Position;
+
     use super::*;
 
+    #[test]
+    fn test_reg2bins() -> Result<(), Box<dyn std::error::Error>> {
+        const MIN_SHIFT: u8 = 14;
+        const DEPTH: u8 = 5;
+
+        let start = Position::try_from(1)?;
+        let end = Position::try_from(16384)?;
+        assert_eq!(
+            reg2bins(MIN_SHIFT, DEPTH, start..=end)?,
+            [0, 1, 9, 73, 585, 4681]
+        );
+
+        let bin_ids = reg2bins(MIN_SHIFT, DEPTH, ..)?;
+        assert_eq!(bin_ids.len(), Bin::max_id(DEPTH));
+
+        let start = Position::try_from(1 << 29)?;
+        assert!(matches!(
+            reg2bins(MIN_SHIFT, DEPTH, start..),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
     fn build_chunks() -> Vec<Chunk> {
         vec![
             Chunk::new(
@@ -227,6 +409,45 @@ mod tests {
         assert!(merged_chunks.is_empty());
     }
 
+    #[test]
+    fn test_coalesce_chunks() -> Result<(), bgzf::virtual_position::TryFromU64U16TupleError> {
+        let chunks = [
+            Chunk::new(
+                bgzf::VirtualPosition::try_from((0, 8))?,
+                bgzf::VirtualPosition::try_from((8, 13))?,
+            ),
+            Chunk::new(
+                bgzf::VirtualPosition::try_from((8, 21))?,
+                bgzf::VirtualPosition::try_from((13, 0))?,
+            ),
+            Chunk::new(
+                bgzf::VirtualPosition::try_from((21, 0))?,
+                bgzf::VirtualPosition::try_from((34, 5))?,
+            ),
+        ];
+
+        let actual = coalesce_chunks(&chunks, 0);
+        let expected = [
+            Chunk::new(
+                bgzf::VirtualPosition::try_from((0, 8))?,
+                bgzf::VirtualPosition::try_from((13, 0))?,
+            ),
+            chunks[2],
+        ];
+        assert_eq!(actual, expected);
+
+        let actual = coalesce_chunks(&chunks, 8);
+        let expected = [Chunk::new(
+            bgzf::VirtualPosition::try_from((0, 8))?,
+            bgzf::VirtualPosition::try_from((34, 5))?,
+        )];
+        assert_eq!(actual, expected);
+
+        assert!(coalesce_chunks(&[], 8).is_empty());
+
+        Ok(())
+    }
+
     #[test]
     fn test_optimize_chunks() {
         let chunks = build_chunks();
@@ -0,0 +1,236 @@
+use std::io;
+
+use noodles_core::{region::Interval, Position};
+
+use super::{index::reference_sequence::bin::Chunk, merge_chunks, BinningIndex};
+
+/// A batch of queries serviced by a single forward sweep.
+///
+/// Querying many small regions one at a time, e.g., for an exon panel, repeatedly seeks back and
+/// forth in the file and can read the same blocks many times. A batch query instead resolves the
+/// chunks of all queries, sorts and merges them by their compressed offsets, and reads each
+/// region of the file at most once, in file order. Each record read is then matched against the
+/// queries it intersects.
+///
+/// A query is a reference sequence ID and an interval. Queries are identified by their index in
+/// the input.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_csi::{self as csi, binning_index::{BatchQuery, Indexer}};
+///
+/// let index: csi::Index = Indexer::default().build(1);
+/// let queries = [
+///     (0, (Position::try_from(8)?..=Position::try_from(13)?).into()),
+///     (0, (Position::try_from(21)?..=Position::try_from(34)?).into()),
+/// ];
+///
+/// let batch_query = BatchQuery::new(&index, queries)?;
+/// assert!(batch_query.chunks().is_empty());
+///
+/// let start = Position::try_from(10)?;
+/// let end = Position::try_from(25)?;
+/// assert_eq!(batch_query.intersecting(0, start, end), [0, 1]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BatchQuery {
+    // Sorted by reference sequence ID and start position.
+    entries: Vec<Entry>,
+    chunks: Vec<Chunk>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Entry {
+    reference_sequence_id: usize,
+    start: Position,
+    end: Position,
+    // The maximum end position of this and all preceding entries with the same reference
+    // sequence ID.
+    max_end: Position,
+    i: usize,
+}
+
+impl BatchQuery {
+    /// Resolves the chunks of a batch of queries.
+    ///
+    /// The resulting chunks are sorted and non-overlapping.
+    pub fn new<I, Q>(index: &I, queries: Q) -> io::Result<Self>
+    where
+        I: BinningIndex,
+        Q: IntoIterator<Item = (usize, Interval)>,
+    {
+        let mut entries = Vec::new();
+        let mut chunks = Vec::new();
+
+        for (i, (reference_sequence_id, interval)) in queries.into_iter().enumerate() {
+            chunks.extend(index.query(reference_sequence_id, interval)?);
+
+            entries.push(Entry {
+                reference_sequence_id,
+                start: interval.start().unwrap_or(Position::MIN),
+                end: interval.end().unwrap_or(Position::MAX),
+                max_end: Position::MIN,
+                i,
+            });
+        }
+
+        entries.sort_unstable_by_key(|entry| (entry.reference_sequence_id, entry.start, entry.i));
+
+        let mut prev: Option<(usize, Position)> = None;
+
+        for entry in &mut entries {
+            entry.max_end = match prev {
+                Some((id, max_end)) if id == entry.reference_sequence_id => max_end.max(entry.end),
+                _ => entry.end,
+            };
+
+            prev = Some((entry.reference_sequence_id, entry.max_end));
+        }
+
+        Ok(Self {
+            entries,
+            chunks: merge_chunks(&chunks),
+        })
+    }
+
+    /// Returns the number of queries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether there are no queries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the sorted, non-overlapping chunks of all queries.
+    ///
+    /// Reading these chunks in order visits each record that intersects any query exactly once.
+    pub fn chunks(&self) -> &[Chunk] {
+        &self.chunks
+    }
+
+    /// Returns the indices of the queries that intersect the given interval.
+    ///
+    /// The indices are sorted in ascending order.
+    pub fn intersecting(
+        &self,
+        reference_sequence_id: usize,
+        start: Position,
+        end: Position,
+    ) -> Vec<usize> {
+        let lo = self
+            .entries
+            .partition_point(|entry| entry.reference_sequence_id < reference_sequence_id);
+
+        let hi = lo
+            + self.entries[lo..].partition_point(|entry| {
+                entry.reference_sequence_id == reference_sequence_id && entry.start <= end
+            });
+
+        let mut indices: Vec<_> = self.entries[lo..hi]
+            .iter()
+            .rev()
+            .take_while(|entry| entry.max_end >= start)
+            .filter(|entry| entry.end >= start)
+            .map(|entry| entry.i)
+            .collect();
+
+        indices.sort_unstable();
+
+        indices
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_bgzf as bgzf;
+
+    use super::*;
+    use crate::binning_index::{index::reference_sequence::index::LinearIndex, Indexer};
+
+    #[test]
+    fn test_new() -> Result<(), Box<dyn std::error::Error>> {
+        let mut indexer = Indexer::<LinearIndex>::default();
+
+        for (reference_sequence_id, start, end, chunk_start, chunk_end) in
+            [(0, 8, 13, 0, 8), (0, 21, 34, 8, 16), (1, 5, 8, 16, 24)]
+        {
+            indexer.add_record(
+                Some((
+                    reference_sequence_id,
+                    Position::try_from(start)?,
+                    Position::try_from(end)?,
+                    true,
+                )),
+                Chunk::new(
+                    bgzf::VirtualPosition::from(chunk_start),
+                    bgzf::VirtualPosition::from(chunk_end),
+                ),
+            )?;
+        }
+
+        let index = indexer.build(2);
+
+        let batch_query = BatchQuery::new(
+            &index,
+            [
+                (1, Interval::from(..)),
+                (0, (Position::try_from(21)?..).into()),
+                (0, (..=Position::try_from(13)?).into()),
+            ],
+        )?;
+
+        assert_eq!(batch_query.len(), 3);
+        assert_eq!(
+            batch_query.chunks(),
+            [Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(24)
+            )]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_intersecting() -> Result<(), Box<dyn std::error::Error>> {
+        let index = Indexer::<LinearIndex>::default().build(2);
+
+        let batch_query = BatchQuery::new(
+            &index,
+            [
+                (0, (Position::try_from(8)?..=Position::try_from(13)?).into()),
+                (0, (Position::try_from(1)?..=Position::try_from(89)?).into()),
+                (
+                    0,
+                    (Position::try_from(21)?..=Position::try_from(34)?).into(),
+                ),
+                (1, (Position::try_from(8)?..=Position::try_from(13)?).into()),
+                (0, (Position::try_from(55)?..).into()),
+            ],
+        )?;
+
+        let intersecting =
+            |reference_sequence_id, start, end| -> Result<_, Box<dyn std::error::Error>> {
+                Ok(batch_query.intersecting(
+                    reference_sequence_id,
+                    Position::try_from(start)?,
+                    Position::try_from(end)?,
+                ))
+            };
+
+        assert_eq!(intersecting(0, 10, 12)?, [0, 1]);
+        assert_eq!(intersecting(0, 14, 20)?, [1]);
+        assert_eq!(intersecting(0, 13, 21)?, [0, 1, 2]);
+        assert_eq!(intersecting(0, 90, 144)?, [4]);
+        assert_eq!(intersecting(1, 1, 8)?, [3]);
+        assert!(intersecting(1, 14, 21)?.is_empty());
+        assert!(intersecting(2, 1, 8)?.is_empty());
+
+        Ok(())
+    }
+}
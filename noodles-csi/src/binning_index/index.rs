@@ -1,10 +1,14 @@
 //! Coordinate-sorted index and fields.
 
 mod builder;
+mod convert;
 pub mod header;
 pub mod reference_sequence;
+pub mod summary;
 
-pub use self::{builder::Builder, header::Header, reference_sequence::ReferenceSequence};
+pub use self::{
+    builder::Builder, header::Header, reference_sequence::ReferenceSequence, summary::Summary,
+};
 
 use std::io;
 
@@ -16,11 +20,11 @@ use super::{index::reference_sequence::bin::Chunk, BinningIndex};
 /// A binning index.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Index<I> {
-    min_shift: u8,
-    depth: u8,
-    header: Option<Header>,
-    reference_sequences: Vec<ReferenceSequence<I>>,
-    unplaced_unmapped_record_count: Option<u64>,
+    pub(super) min_shift: u8,
+    pub(super) depth: u8,
+    pub(super) header: Option<Header>,
+    pub(super) reference_sequences: Vec<ReferenceSequence<I>>,
+    pub(super) unplaced_unmapped_record_count: Option<u64>,
 }
 
 impl<I> Index<I>
@@ -39,6 +43,49 @@ where
         Builder::default()
     }
 
+    /// Returns the number of bits for the minimum interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let index = csi::Index::default();
+    /// assert_eq!(index.min_shift(), 14);
+    /// ```
+    pub fn min_shift(&self) -> u8 {
+        self.min_shift
+    }
+
+    /// Returns the depth of the binning index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let index = csi::Index::default();
+    /// assert_eq!(index.depth(), 5);
+    /// ```
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Returns a summary of the structure of the index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    ///
+    /// let index = csi::Index::default();
+    /// let summary = index.summary();
+    ///
+    /// assert_eq!(summary.min_shift, 14);
+    /// assert!(summary.reference_sequences.is_empty());
+    /// ```
+    pub fn summary(&self) -> Summary {
+        Summary::from(self)
+    }
+
     /// Returns a list of indexed reference sequences.
     ///
     /// # Examples
@@ -51,6 +98,89 @@ where
     pub fn reference_sequences(&self) -> &[ReferenceSequence<I>] {
         &self.reference_sequences
     }
+
+    /// Returns the number of mapped records of a reference sequence.
+    ///
+    /// This is read from the metadata pseudo-bin. It returns `None` if the reference sequence does
+    /// not exist or has no metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_csi::{
+    ///     self as csi,
+    ///     binning_index::index::{reference_sequence::Metadata, ReferenceSequence},
+    /// };
+    ///
+    /// let metadata = Metadata::new(
+    ///     bgzf::VirtualPosition::from(610),
+    ///     bgzf::VirtualPosition::from(1597),
+    ///     55,
+    ///     8,
+    /// );
+    ///
+    /// let index = csi::Index::builder()
+    ///     .set_reference_sequences(vec![ReferenceSequence::new(
+    ///         Default::default(),
+    ///         Default::default(),
+    ///         Some(metadata),
+    ///     )])
+    ///     .build();
+    ///
+    /// assert_eq!(index.mapped_record_count(0), Some(55));
+    /// assert!(index.mapped_record_count(1).is_none());
+    /// ```
+    pub fn mapped_record_count(&self, reference_sequence_id: usize) -> Option<u64> {
+        self.metadata(reference_sequence_id)
+            .map(|metadata| metadata.mapped_record_count())
+    }
+
+    /// Returns the number of unmapped records of a reference sequence.
+    ///
+    /// These are unmapped records that are placed on the reference sequence, e.g., the unmapped
+    /// mate of a mapped record. This is read from the metadata pseudo-bin. It returns `None` if
+    /// the reference sequence does not exist or has no metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_csi::{
+    ///     self as csi,
+    ///     binning_index::index::{reference_sequence::Metadata, ReferenceSequence},
+    /// };
+    ///
+    /// let metadata = Metadata::new(
+    ///     bgzf::VirtualPosition::from(610),
+    ///     bgzf::VirtualPosition::from(1597),
+    ///     55,
+    ///     8,
+    /// );
+    ///
+    /// let index = csi::Index::builder()
+    ///     .set_reference_sequences(vec![ReferenceSequence::new(
+    ///         Default::default(),
+    ///         Default::default(),
+    ///         Some(metadata),
+    ///     )])
+    ///     .build();
+    ///
+    /// assert_eq!(index.unmapped_record_count(0), Some(8));
+    /// assert!(index.unmapped_record_count(1).is_none());
+    /// ```
+    pub fn unmapped_record_count(&self, reference_sequence_id: usize) -> Option<u64> {
+        self.metadata(reference_sequence_id)
+            .map(|metadata| metadata.unmapped_record_count())
+    }
+
+    fn metadata(&self, reference_sequence_id: usize) -> Option<&reference_sequence::Metadata> {
+        use super::ReferenceSequence as _;
+
+        self.reference_sequences
+            .get(reference_sequence_id)
+            .and_then(|reference_sequence| reference_sequence.metadata())
+    }
 }
 
 impl<I> Default for Index<I>
@@ -128,7 +258,11 @@ where
     }
 }
 
-fn resolve_interval<I>(min_shift: u8, depth: u8, interval: I) -> io::Result<(Position, Position)>
+pub(crate) fn resolve_interval<I>(
+    min_shift: u8,
+    depth: u8,
+    interval: I,
+) -> io::Result<(Position, Position)>
 where
     I: Into<Interval>,
 {
@@ -0,0 +1,247 @@
+use std::io;
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+
+use super::{
+    reference_sequence::{
+        index::{BinnedIndex, LinearIndex},
+        Index as _,
+    },
+    Index, ReferenceSequence,
+};
+
+// _Sequence Alignment/Map Format Specification_ (2023-05-24) § 5.1.3 "Combining with linear
+// index": "...each tiling 16384bp window..."
+const LINEAR_INDEX_MIN_SHIFT: u8 = 14;
+const LINEAR_INDEX_WINDOW_SIZE: usize = 1 << LINEAR_INDEX_MIN_SHIFT;
+
+// The depth of a BAI or tabix index.
+const LINEAR_INDEX_DEPTH: u8 = 5;
+
+impl From<Index<LinearIndex>> for Index<BinnedIndex> {
+    /// Converts an index with linear indices (e.g., BAI or tabix) to one with binned indices
+    /// (CSI).
+    ///
+    /// The bins are unchanged. The offset of each bin is the linear index offset of the window
+    /// that contains the start of the bin.
+    fn from(index: Index<LinearIndex>) -> Self {
+        let min_shift = index.min_shift;
+        let depth = index.depth;
+
+        let reference_sequences = index
+            .reference_sequences
+            .into_iter()
+            .map(|reference_sequence| {
+                let (bins, linear_index, metadata) = reference_sequence.into_parts();
+
+                let binned_index = bins
+                    .keys()
+                    .map(|&id| {
+                        let i = bin_start(min_shift, depth, id) / LINEAR_INDEX_WINDOW_SIZE;
+                        let offset = linear_index.get(i).copied().unwrap_or_default();
+                        (id, offset)
+                    })
+                    .collect();
+
+                ReferenceSequence::new(bins, binned_index, metadata)
+            })
+            .collect();
+
+        Self {
+            min_shift,
+            depth,
+            header: index.header,
+            reference_sequences,
+            unplaced_unmapped_record_count: index.unplaced_unmapped_record_count,
+        }
+    }
+}
+
+impl TryFrom<Index<BinnedIndex>> for Index<LinearIndex> {
+    type Error = io::Error;
+
+    /// Converts an index with binned indices (CSI) to one with linear indices (e.g., BAI or
+    /// tabix).
+    ///
+    /// The index must have the parameters of a linear index, i.e., a minimum interval size of
+    /// 2^14 (`min_shift` = 14) and a depth of 5. The bins are unchanged. The offset of each
+    /// linear index window is the offset of the smallest bin with an offset that contains the
+    /// start of the window.
+    fn try_from(index: Index<BinnedIndex>) -> Result<Self, Self::Error> {
+        if index.min_shift != LINEAR_INDEX_MIN_SHIFT || index.depth != LINEAR_INDEX_DEPTH {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "invalid index parameters: expected (min_shift, depth) = ({LINEAR_INDEX_MIN_SHIFT}, {LINEAR_INDEX_DEPTH}), got ({}, {})",
+                    index.min_shift, index.depth
+                ),
+            ));
+        }
+
+        let min_shift = index.min_shift;
+        let depth = index.depth;
+
+        let reference_sequences = index
+            .reference_sequences
+            .into_iter()
+            .map(|reference_sequence| {
+                let (bins, binned_index, metadata) = reference_sequence.into_parts();
+
+                let window_count = bins
+                    .keys()
+                    .map(|&id| bin_start(min_shift, depth, id) / LINEAR_INDEX_WINDOW_SIZE + 1)
+                    .max()
+                    .unwrap_or_default();
+
+                let linear_index = (0..window_count)
+                    .map(|i| {
+                        let start = Position::try_from(i * LINEAR_INDEX_WINDOW_SIZE + 1)
+                            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+                        Ok(binned_index.min_offset(min_shift, depth, start))
+                    })
+                    .collect::<io::Result<Vec<bgzf::VirtualPosition>>>()?;
+
+                Ok(ReferenceSequence::new(bins, linear_index, metadata))
+            })
+            .collect::<io::Result<_>>()?;
+
+        Ok(Self {
+            min_shift,
+            depth,
+            header: index.header,
+            reference_sequences,
+            unplaced_unmapped_record_count: index.unplaced_unmapped_record_count,
+        })
+    }
+}
+
+// Returns the 0-based start position of a bin.
+fn bin_start(min_shift: u8, depth: u8, id: usize) -> usize {
+    let mut level = 0;
+    let mut level_first_id = 0;
+    let mut level_bin_count = 1;
+
+    while level < depth && id >= level_first_id + level_bin_count {
+        level += 1;
+        level_first_id += level_bin_count;
+        level_bin_count <<= 3;
+    }
+
+    let bin_size = 1 << (usize::from(min_shift) + 3 * usize::from(depth - level));
+
+    (id - level_first_id) * bin_size
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        binning_index::{index::reference_sequence::bin::Chunk, Indexer},
+        BinningIndex,
+    };
+
+    fn build_index<I>(
+        records: &[(usize, usize, usize, u64)],
+    ) -> Result<Index<I>, Box<dyn std::error::Error>>
+    where
+        I: super::super::reference_sequence::Index + Default,
+    {
+        let mut indexer = Indexer::default();
+
+        for &(reference_sequence_id, start, end, offset) in records {
+            let start = Position::try_from(start)?;
+            let end = Position::try_from(end)?;
+
+            let chunk = Chunk::new(
+                bgzf::VirtualPosition::from(offset << 16),
+                bgzf::VirtualPosition::from((offset + 1) << 16),
+            );
+
+            indexer.add_record(Some((reference_sequence_id, start, end, true)), chunk)?;
+        }
+
+        Ok(indexer.build(2))
+    }
+
+    const RECORDS: [(usize, usize, usize, u64); 5] = [
+        (0, 1, 100, 1),
+        (0, 16000, 17000, 2),
+        (0, 40000, 40100, 3),
+        (0, 200000, 300000, 4),
+        (1, 8, 13, 5),
+    ];
+
+    #[test]
+    fn test_from_index_with_linear_indices_for_index_with_binned_indices(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let linear_index = build_index::<LinearIndex>(&RECORDS)?;
+        let binned_index = Index::<BinnedIndex>::from(linear_index.clone());
+
+        assert_eq!(binned_index.min_shift(), 14);
+        assert_eq!(binned_index.depth(), 5);
+
+        for (actual, expected) in binned_index
+            .reference_sequences()
+            .iter()
+            .zip(linear_index.reference_sequences())
+        {
+            assert_eq!(actual.bins(), expected.bins());
+        }
+
+        for (reference_sequence_id, start, end) in [(0, 1, 50000), (0, 32769, 250000), (1, 1, 10)] {
+            let interval = Position::try_from(start)?..=Position::try_from(end)?;
+            assert_eq!(
+                binned_index.query(reference_sequence_id, interval.clone().into())?,
+                linear_index.query(reference_sequence_id, interval.into())?
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_index_with_binned_indices_for_index_with_linear_indices(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let binned_index = build_index::<BinnedIndex>(&RECORDS)?;
+        let linear_index = Index::<LinearIndex>::try_from(binned_index.clone())?;
+
+        for (actual, expected) in linear_index
+            .reference_sequences()
+            .iter()
+            .zip(binned_index.reference_sequences())
+        {
+            assert_eq!(actual.bins(), expected.bins());
+        }
+
+        for (reference_sequence_id, start, end) in [(0, 1, 50000), (0, 32769, 250000), (1, 1, 10)] {
+            let interval = Position::try_from(start)?..=Position::try_from(end)?;
+            assert_eq!(
+                linear_index.query(reference_sequence_id, interval.clone().into())?,
+                binned_index.query(reference_sequence_id, interval.into())?
+            );
+        }
+
+        let index = Index::<BinnedIndex>::builder()
+            .set_min_shift(12)
+            .set_depth(6)
+            .build();
+        assert!(matches!(
+            Index::<LinearIndex>::try_from(index),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bin_start() {
+        assert_eq!(bin_start(14, 5, 0), 0);
+        assert_eq!(bin_start(14, 5, 1), 0);
+        assert_eq!(bin_start(14, 5, 2), 1 << 26);
+        assert_eq!(bin_start(14, 5, 4681), 0);
+        assert_eq!(bin_start(14, 5, 4682), 1 << 14);
+        assert_eq!(bin_start(14, 5, 4681 + 32), 32 << 14);
+    }
+}
@@ -204,6 +204,10 @@ where
         self.index.last_first_start_position()
     }
 
+    pub(crate) fn into_parts(self) -> (IndexMap<usize, Bin>, I, Option<Metadata>) {
+        (self.bins, self.index, self.metadata)
+    }
+
     pub(crate) fn update(
         &mut self,
         min_shift: u8,
@@ -279,7 +283,13 @@ fn reg2bin(start: Position, end: Position, min_shift: u8, depth: u8) -> usize {
 
 // `CSIv1.pdf` (2020-07-21)
 #[allow(clippy::many_single_char_names)]
-fn reg2bins(start: Position, end: Position, min_shift: u8, depth: u8, bins: &mut BitVec) {
+pub(crate) fn reg2bins(
+    start: Position,
+    end: Position,
+    min_shift: u8,
+    depth: u8,
+    bins: &mut BitVec,
+) {
     // [beg, end), 0-based
     let beg = usize::from(start) - 1;
     let end = usize::from(end);
@@ -0,0 +1,259 @@
+//! Binning index summary.
+
+use std::fmt;
+
+use super::{reference_sequence, Index};
+use crate::binning_index::ReferenceSequence as _;
+
+/// A binning index summary.
+///
+/// This is a set of statistics about the structure of a binning index, e.g., to find reference
+/// sequences with many chunks, which are slow to query.
+///
+/// With the `serde` feature enabled, the summary can be serialized, e.g., to JSON.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Summary {
+    /// The minimum shift.
+    pub min_shift: u8,
+    /// The depth of the binning index.
+    pub depth: u8,
+    /// The reference sequence summaries.
+    pub reference_sequences: Vec<ReferenceSequence>,
+    /// The number of unplaced, unmapped records.
+    pub unplaced_unmapped_record_count: Option<u64>,
+}
+
+impl Summary {
+    /// Returns the total number of bins in all reference sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi::binning_index::index::Summary;
+    /// assert_eq!(Summary::default().bin_count(), 0);
+    /// ```
+    pub fn bin_count(&self) -> usize {
+        self.reference_sequences.iter().map(|r| r.bin_count).sum()
+    }
+
+    /// Returns the total number of chunks in all reference sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi::binning_index::index::Summary;
+    /// assert_eq!(Summary::default().chunk_count(), 0);
+    /// ```
+    pub fn chunk_count(&self) -> usize {
+        self.reference_sequences.iter().map(|r| r.chunk_count).sum()
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "min_shift\t{}", self.min_shift)?;
+        writeln!(f, "depth\t{}", self.depth)?;
+
+        write!(f, "unplaced_unmapped_record_count\t")?;
+        fmt_count(f, self.unplaced_unmapped_record_count)?;
+        writeln!(f)?;
+
+        writeln!(
+            f,
+            "reference_sequence_id\tbin_count\tchunk_count\tmax_bin_chunk_count\tchunk_span\tmapped_record_count\tunmapped_record_count"
+        )?;
+
+        for (i, reference_sequence) in self.reference_sequences.iter().enumerate() {
+            write!(
+                f,
+                "{i}\t{}\t{}\t{}\t{}\t",
+                reference_sequence.bin_count,
+                reference_sequence.chunk_count,
+                reference_sequence.max_bin_chunk_count,
+                reference_sequence.chunk_span,
+            )?;
+
+            fmt_count(f, reference_sequence.mapped_record_count)?;
+            f.write_str("\t")?;
+            fmt_count(f, reference_sequence.unmapped_record_count)?;
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn fmt_count(f: &mut fmt::Formatter<'_>, count: Option<u64>) -> fmt::Result {
+    match count {
+        Some(n) => write!(f, "{n}"),
+        None => f.write_str("."),
+    }
+}
+
+/// A binning index reference sequence summary.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ReferenceSequence {
+    /// The number of bins.
+    pub bin_count: usize,
+    /// The number of chunks in all bins.
+    pub chunk_count: usize,
+    /// The largest number of chunks in a single bin.
+    pub max_bin_chunk_count: usize,
+    /// The sum of the compressed sizes spanned by each chunk, in bytes.
+    ///
+    /// This approximates the amount of compressed data that is read to query the entire
+    /// reference sequence.
+    pub chunk_span: u64,
+    /// The number of mapped records, if the reference sequence has metadata.
+    pub mapped_record_count: Option<u64>,
+    /// The number of unmapped records, if the reference sequence has metadata.
+    pub unmapped_record_count: Option<u64>,
+}
+
+impl<I> From<&reference_sequence::ReferenceSequence<I>> for ReferenceSequence
+where
+    I: reference_sequence::Index,
+{
+    fn from(reference_sequence: &reference_sequence::ReferenceSequence<I>) -> Self {
+        let bins = reference_sequence.bins();
+
+        let mut chunk_count = 0;
+        let mut max_bin_chunk_count = 0;
+        let mut chunk_span = 0;
+
+        for bin in bins.values() {
+            let chunks = bin.chunks();
+
+            chunk_count += chunks.len();
+            max_bin_chunk_count = max_bin_chunk_count.max(chunks.len());
+
+            chunk_span += chunks
+                .iter()
+                .map(|chunk| {
+                    chunk
+                        .end()
+                        .compressed()
+                        .saturating_sub(chunk.start().compressed())
+                })
+                .sum::<u64>();
+        }
+
+        let metadata = reference_sequence.metadata();
+
+        Self {
+            bin_count: bins.len(),
+            chunk_count,
+            max_bin_chunk_count,
+            chunk_span,
+            mapped_record_count: metadata.map(|m| m.mapped_record_count()),
+            unmapped_record_count: metadata.map(|m| m.unmapped_record_count()),
+        }
+    }
+}
+
+impl<I> From<&Index<I>> for Summary
+where
+    I: reference_sequence::Index,
+{
+    fn from(index: &Index<I>) -> Self {
+        Self {
+            min_shift: index.min_shift,
+            depth: index.depth,
+            reference_sequences: index
+                .reference_sequences
+                .iter()
+                .map(ReferenceSequence::from)
+                .collect(),
+            unplaced_unmapped_record_count: index.unplaced_unmapped_record_count,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_bgzf as bgzf;
+
+    use super::*;
+    use crate::binning_index::index::reference_sequence::{
+        bin::Chunk, index::BinnedIndex, Bin, Metadata,
+    };
+
+    fn build_chunk(start: u64, end: u64) -> Chunk {
+        Chunk::new(
+            bgzf::VirtualPosition::from(start << 16),
+            bgzf::VirtualPosition::from(end << 16),
+        )
+    }
+
+    #[test]
+    fn test_from_index_for_summary() {
+        let bins = [
+            (0, Bin::new(vec![build_chunk(8, 13)])),
+            (
+                4681,
+                Bin::new(vec![build_chunk(21, 34), build_chunk(55, 89)]),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let metadata = Metadata::new(
+            bgzf::VirtualPosition::from(8 << 16),
+            bgzf::VirtualPosition::from(89 << 16),
+            5,
+            2,
+        );
+
+        let index = Index::<BinnedIndex>::builder()
+            .set_reference_sequences(vec![
+                reference_sequence::ReferenceSequence::new(
+                    bins,
+                    BinnedIndex::default(),
+                    Some(metadata),
+                ),
+                reference_sequence::ReferenceSequence::new(
+                    Default::default(),
+                    BinnedIndex::default(),
+                    None,
+                ),
+            ])
+            .set_unplaced_unmapped_record_count(0)
+            .build();
+
+        let summary = Summary::from(&index);
+
+        let expected = Summary {
+            min_shift: 14,
+            depth: 5,
+            reference_sequences: vec![
+                ReferenceSequence {
+                    bin_count: 2,
+                    chunk_count: 3,
+                    max_bin_chunk_count: 2,
+                    chunk_span: 5 + 13 + 34,
+                    mapped_record_count: Some(5),
+                    unmapped_record_count: Some(2),
+                },
+                ReferenceSequence::default(),
+            ],
+            unplaced_unmapped_record_count: Some(0),
+        };
+
+        assert_eq!(summary, expected);
+        assert_eq!(summary.bin_count(), 2);
+        assert_eq!(summary.chunk_count(), 3);
+
+        let expected = "\
+min_shift\t14
+depth\t5
+unplaced_unmapped_record_count\t0
+reference_sequence_id\tbin_count\tchunk_count\tmax_bin_chunk_count\tchunk_span\tmapped_record_count\tunmapped_record_count
+0\t2\t3\t2\t52\t5\t2
+1\t0\t0\t0\t0\t.\t.
+";
+
+        assert_eq!(summary.to_string(), expected);
+    }
+}
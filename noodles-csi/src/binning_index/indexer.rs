@@ -132,6 +132,8 @@ where
     pub fn build(mut self, reference_sequence_count: usize) -> Index<I> {
         if reference_sequence_count == 0 {
             return Index::builder()
+                .set_min_shift(self.min_shift)
+                .set_depth(self.depth)
                 .set_unplaced_unmapped_record_count(self.unplaced_unmapped_record_count)
                 .build();
         }
@@ -140,6 +142,8 @@ where
         self.add_reference_sequences_until(reference_sequence_count - 1);
 
         let mut builder = Index::builder()
+            .set_min_shift(self.min_shift)
+            .set_depth(self.depth)
             .set_reference_sequences(self.reference_sequences)
             .set_unplaced_unmapped_record_count(self.unplaced_unmapped_record_count);
 
@@ -174,6 +178,38 @@ where
     }
 }
 
+impl<I> From<Index<I>> for Indexer<I>
+where
+    I: reference_sequence::Index,
+{
+    /// Creates an indexer that resumes indexing from an existing index.
+    ///
+    /// Subsequent records must be positioned after the records in the given index.
+    fn from(index: Index<I>) -> Self {
+        let mut reference_sequences = index.reference_sequences;
+
+        // Trailing reference sequences without records are padding added by `Indexer::build`.
+        // These are removed to allow records to be added to them.
+        while reference_sequences
+            .last()
+            .map(|reference_sequence| reference_sequence.bins().is_empty())
+            .unwrap_or_default()
+        {
+            reference_sequences.pop();
+        }
+
+        Self {
+            min_shift: index.min_shift,
+            depth: index.depth,
+            header: index.header,
+            reference_sequences,
+            unplaced_unmapped_record_count: index
+                .unplaced_unmapped_record_count
+                .unwrap_or_default(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use noodles_bgzf as bgzf;
@@ -275,9 +311,78 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_from_index() -> Result<(), Box<dyn std::error::Error>> {
+        const MIN_SHIFT: u8 = 14;
+        const DEPTH: u8 = 5;
+
+        let start = Position::try_from(8)?;
+        let end = Position::try_from(13)?;
+
+        let mut indexer = Indexer::<LinearIndex>::new(MIN_SHIFT, DEPTH);
+
+        indexer.add_record(
+            Some((0, start, end, true)),
+            Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(9),
+            ),
+        )?;
+
+        let chunk = Chunk::new(
+            bgzf::VirtualPosition::from(9),
+            bgzf::VirtualPosition::from(18),
+        );
+
+        indexer.add_record(Some((1, start, end, true)), chunk)?;
+
+        let expected = indexer.build(3);
+
+        let mut indexer = Indexer::<LinearIndex>::new(MIN_SHIFT, DEPTH);
+
+        indexer.add_record(
+            Some((0, start, end, true)),
+            Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(9),
+            ),
+        )?;
+
+        let index = indexer.build(3);
+
+        let mut indexer = Indexer::from(index);
+        assert_eq!(indexer.reference_sequences.len(), 1);
+        indexer.add_record(Some((1, start, end, true)), chunk)?;
+
+        assert_eq!(indexer.build(3), expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_build_with_reference_sequence_count() {
         let index = Indexer::<LinearIndex>::default().build(2);
         assert_eq!(index.reference_sequences().len(), 2);
     }
+
+    #[test]
+    fn test_build_with_min_shift_and_depth() -> Result<(), Box<dyn std::error::Error>> {
+        let index = Indexer::<LinearIndex>::new(12, 6).build(0);
+        assert_eq!(index.min_shift(), 12);
+        assert_eq!(index.depth(), 6);
+
+        let mut indexer = Indexer::<LinearIndex>::new(12, 6);
+        indexer.add_record(
+            Some((0, Position::try_from(8)?, Position::try_from(13)?, true)),
+            Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(9),
+            ),
+        )?;
+        let index = indexer.build(1);
+        assert_eq!(index.min_shift(), 12);
+        assert_eq!(index.depth(), 6);
+
+        Ok(())
+    }
 }
@@ -1,14 +1,18 @@
 //! CSI I/O.
 
 mod filter_by_region;
+mod index_text;
 mod indexed_reader;
 mod indexed_record;
 mod indexed_records;
 mod query;
 
 pub use self::{
-    filter_by_region::FilterByRegion, indexed_reader::IndexedReader, indexed_record::IndexedRecord,
-    indexed_records::IndexedRecords, query::Query,
+    filter_by_region::FilterByRegion, index_text::index_text, indexed_reader::IndexedReader,
+    indexed_record::IndexedRecord, indexed_records::IndexedRecords, query::Query,
 };
 
+#[cfg(feature = "async")]
+pub(crate) use self::index_text::TextIndexer;
+
 pub(crate) static MAGIC_NUMBER: &[u8] = b"CSI\x01";
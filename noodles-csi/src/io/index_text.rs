@@ -0,0 +1,229 @@
+use std::io::{self, BufRead, Read};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+
+use super::{indexed_records::parse_record, IndexedRecord};
+use crate::binning_index::{
+    index::{
+        header::ReferenceSequenceNames,
+        reference_sequence::{self, bin::Chunk},
+        Header,
+    },
+    Index, Indexer,
+};
+
+/// Indexes bgzip-compressed, tab-delimited text.
+///
+/// The reader must be positioned at the start of the bgzip-compressed stream. The given header
+/// describes the layout of the text, i.e., the reference sequence name, start position, and end
+/// position columns; the line comment prefix; and the number of lines to skip at the start. Its
+/// reference sequence names are replaced by the names in the order they appear in the input.
+///
+/// Records must be grouped by reference sequence name and sorted by start position. This is the
+/// equivalent of `tabix --preset`/`tabix --sequence --begin --end` as a library call, where the
+/// given indexer selects the index kind, e.g., a tabix (linear) index or a CSI (binned) index.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Write};
+/// use noodles_bgzf as bgzf;
+/// use noodles_csi::{
+///     self as csi,
+///     binning_index::{index::reference_sequence::index::BinnedIndex, Indexer},
+/// };
+///
+/// let mut writer = bgzf::Writer::new(Vec::new());
+/// writer.write_all(b"sq0\t7\t13\nsq1\t20\t34\n")?;
+/// let data = writer.finish()?;
+///
+/// let header = csi::binning_index::index::header::Builder::bed().build();
+/// let indexer = Indexer::<BinnedIndex>::default();
+/// let index = csi::io::index_text(&data[..], header, indexer)?;
+///
+/// assert_eq!(index.reference_sequences().len(), 2);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn index_text<R, I>(reader: R, header: Header, indexer: Indexer<I>) -> io::Result<Index<I>>
+where
+    R: Read,
+    I: reference_sequence::Index + Default,
+{
+    let mut reader = bgzf::Reader::new(reader);
+    let mut indexer = TextIndexer::new(header, indexer);
+
+    let mut line = String::new();
+    let mut start_position = reader.virtual_position();
+
+    loop {
+        line.clear();
+
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+
+        let end_position = reader.virtual_position();
+        indexer.add_line(&line, Chunk::new(start_position, end_position))?;
+        start_position = end_position;
+    }
+
+    Ok(indexer.build())
+}
+
+pub(crate) struct TextIndexer<I> {
+    header: Header,
+    indexer: Indexer<I>,
+    reference_sequence_names: ReferenceSequenceNames,
+    last_position: Option<(usize, Position)>,
+    line_number: u64,
+}
+
+impl<I> TextIndexer<I>
+where
+    I: reference_sequence::Index + Default,
+{
+    pub(crate) fn new(header: Header, indexer: Indexer<I>) -> Self {
+        Self {
+            header,
+            indexer,
+            reference_sequence_names: ReferenceSequenceNames::new(),
+            last_position: None,
+            line_number: 0,
+        }
+    }
+
+    pub(crate) fn add_line(&mut self, line: &str, chunk: Chunk) -> io::Result<()> {
+        self.line_number += 1;
+
+        let line = line.trim_end_matches(['\n', '\r']);
+
+        if self.line_number <= u64::from(self.header.line_skip_count())
+            || line.as_bytes().first() == Some(&self.header.line_comment_prefix())
+            || line.is_empty()
+        {
+            return Ok(());
+        }
+
+        let record = parse_record(
+            line.into(),
+            self.header.reference_sequence_name_index(),
+            self.header.start_position_index(),
+            self.header.end_position_index(),
+            self.header.format().coordinate_system(),
+        )
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let (reference_sequence_id, _) = self
+            .reference_sequence_names
+            .insert_full(record.indexed_reference_sequence_name().into());
+
+        let start = record.indexed_start_position();
+        let end = record.indexed_end_position();
+
+        if let Some((last_reference_sequence_id, last_start)) = self.last_position {
+            if reference_sequence_id < last_reference_sequence_id
+                || (reference_sequence_id == last_reference_sequence_id && start < last_start)
+            {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("unsorted input at line {}", self.line_number),
+                ));
+            }
+        }
+
+        self.last_position = Some((reference_sequence_id, start));
+
+        self.indexer
+            .add_record(Some((reference_sequence_id, start, end, true)), chunk)
+    }
+
+    pub(crate) fn build(self) -> Index<I> {
+        let reference_sequence_count = self.reference_sequence_names.len();
+
+        let mut header = self.header;
+        *header.reference_sequence_names_mut() = self.reference_sequence_names;
+
+        self.indexer
+            .set_header(header)
+            .build(reference_sequence_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+    use crate::{
+        binning_index::index::{
+            header::format::CoordinateSystem, reference_sequence::index::BinnedIndex,
+        },
+        BinningIndex,
+    };
+
+    fn compress(data: &[u8]) -> io::Result<Vec<u8>> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(data)?;
+        writer.finish()
+    }
+
+    #[test]
+    fn test_index_text() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::region::Interval;
+
+        let data = compress(b"#noodles\nsq0\t7\t13\nsq0\t20\t34\n\nsq1\t4\t8\n")?;
+        let header = crate::binning_index::index::header::Builder::bed().build();
+        let index = index_text(&data[..], header, Indexer::<BinnedIndex>::default())?;
+
+        let header = index.header().expect("missing header");
+        let names: Vec<_> = header
+            .reference_sequence_names()
+            .iter()
+            .map(|name| name.as_str())
+            .collect();
+        assert_eq!(names, ["sq0", "sq1"]);
+        assert_eq!(header.format().coordinate_system(), CoordinateSystem::Bed);
+
+        let interval = Interval::from(Position::try_from(1)?..=Position::try_from(10)?);
+        assert_eq!(index.query(0, interval)?.len(), 1);
+        assert_eq!(index.query(1, interval)?.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_text_with_line_skip_count() -> Result<(), Box<dyn std::error::Error>> {
+        let data = compress(b"chrom\tstart\tend\nsq0\t7\t13\n")?;
+
+        let header = Header::builder()
+            .set_start_position_index(1)
+            .set_end_position_index(Some(2))
+            .set_line_skip_count(1)
+            .build();
+
+        let index = index_text(&data[..], header, Indexer::<BinnedIndex>::default())?;
+        assert_eq!(index.reference_sequences().len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_text_with_unsorted_input() -> io::Result<()> {
+        let header = crate::binning_index::index::header::Builder::bed().build();
+
+        let data = compress(b"sq0\t20\t34\nsq0\t7\t13\n")?;
+        assert!(matches!(
+            index_text(&data[..], header.clone(), Indexer::<BinnedIndex>::default()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let data = compress(b"sq0\t7\t13\nsq1\t7\t13\nsq0\t20\t34\n")?;
+        assert!(matches!(
+            index_text(&data[..], header, Indexer::<BinnedIndex>::default()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+//! Async FASTA writer.
+
+mod builder;
+
+use tokio::io::{self, AsyncWrite, AsyncWriteExt};
+
+pub use self::builder::Builder;
+use crate::{
+    io::writer::{write_record, Options},
+    Record,
+};
+
+/// An async FASTA writer.
+pub struct Writer<W> {
+    inner: W,
+    options: Options,
+    buf: Vec<u8>,
+}
+
+impl<W> Writer<W> {
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// use tokio::io;
+    /// let writer = fasta::r#async::io::Writer::new(io::sink());
+    /// let _inner = writer.get_ref();
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// use tokio::io;
+    /// let mut writer = fasta::r#async::io::Writer::new(io::sink());
+    /// let _inner = writer.get_mut();
+    /// ```
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwraps and returns the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// use tokio::io;
+    /// let writer = fasta::r#async::io::Writer::new(io::sink());
+    /// let _inner = writer.into_inner();
+    /// ```
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W> Writer<W>
+where
+    W: AsyncWrite + Unpin,
+{
+    /// Creates an async FASTA writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// use tokio::io;
+    /// let writer = fasta::r#async::io::Writer::new(io::sink());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Builder::default().build_with_writer(inner)
+    }
+
+    /// Writes a FASTA record.
+    ///
+    /// The output is the same as [`crate::io::Writer::write_record`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> tokio::io::Result<()> {
+    /// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+    ///
+    /// let mut writer = fasta::r#async::io::Writer::new(Vec::new());
+    ///
+    /// let definition = Definition::new("sq0", None);
+    /// let sequence = Sequence::from(b"ACGT".to_vec());
+    /// let record = fasta::Record::new(definition, sequence);
+    ///
+    /// writer.write_record(&record).await?;
+    ///
+    /// assert_eq!(writer.get_ref(), b">sq0\nACGT\n");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn write_record(&mut self, record: &Record) -> io::Result<()> {
+        self.buf.clear();
+        write_record(&mut self.buf, record, &self.options)?;
+        self.inner.write_all(&self.buf).await
+    }
+
+    /// Shuts down the output stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> tokio::io::Result<()> {
+    /// use noodles_fasta as fasta;
+    /// use tokio::io;
+    /// let mut writer = fasta::r#async::io::Writer::new(io::sink());
+    /// writer.shutdown().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn shutdown(&mut self) -> io::Result<()> {
+        self.inner.shutdown().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        io::reader::MaskPolicy,
+        record::{Definition, Sequence},
+    };
+
+    #[tokio::test]
+    async fn test_write_record() -> io::Result<()> {
+        let record = Record::new(
+            Definition::new("sq0", Some(Vec::from("LN:6"))),
+            Sequence::from(b"ACgtnN".to_vec()),
+        );
+
+        let mut writer = Builder::default()
+            .set_line_base_count(4)
+            .set_write_descriptions(false)
+            .set_mask_policy(MaskPolicy::HardMask)
+            .build_with_writer(Vec::new());
+
+        writer.write_record(&record).await?;
+        assert_eq!(writer.get_ref(), b">sq0\nACNN\nNN\n");
+
+        Ok(())
+    }
+}
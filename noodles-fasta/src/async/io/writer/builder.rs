@@ -0,0 +1,81 @@
+use tokio::io::AsyncWrite;
+
+use super::Writer;
+use crate::io::{reader::MaskPolicy, writer::Options};
+
+/// An async FASTA writer builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    options: Options,
+}
+
+impl Builder {
+    /// Sets the number of bases per line.
+    ///
+    /// If 0, sequences are not wrapped, i.e., each sequence is written on a single line.
+    ///
+    /// By default, this is set to 80.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// let builder = fasta::r#async::io::writer::Builder::default().set_line_base_count(100);
+    /// ```
+    pub fn set_line_base_count(mut self, line_base_count: usize) -> Self {
+        self.options.line_base_count = line_base_count;
+        self
+    }
+
+    /// Sets whether to write record descriptions.
+    ///
+    /// By default, this is set to `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// let builder = fasta::r#async::io::writer::Builder::default().set_write_descriptions(false);
+    /// ```
+    pub fn set_write_descriptions(mut self, write_descriptions: bool) -> Self {
+        self.options.write_descriptions = write_descriptions;
+        self
+    }
+
+    /// Sets the policy for writing soft-masked (lowercase) bases.
+    ///
+    /// By default, this is set to [`MaskPolicy::Preserve`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, io::reader::MaskPolicy};
+    ///
+    /// let builder = fasta::r#async::io::writer::Builder::default()
+    ///     .set_mask_policy(MaskPolicy::Uppercase);
+    /// ```
+    pub fn set_mask_policy(mut self, mask_policy: MaskPolicy) -> Self {
+        self.options.mask_policy = mask_policy;
+        self
+    }
+
+    /// Builds an async FASTA writer from a writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// use tokio::io;
+    /// let writer = fasta::r#async::io::writer::Builder::default().build_with_writer(io::sink());
+    /// ```
+    pub fn build_with_writer<W>(self, writer: W) -> Writer<W>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        Writer {
+            inner: writer,
+            options: self.options,
+            buf: Vec::new(),
+        }
+    }
+}
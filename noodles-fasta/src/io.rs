@@ -2,6 +2,7 @@
 
 pub mod indexed_reader;
 mod indexer;
+pub mod indexing_writer;
 pub mod reader;
 pub mod writer;
 
@@ -13,7 +14,10 @@ use std::{
 
 use noodles_bgzf as bgzf;
 
-pub use self::{indexed_reader::IndexedReader, indexer::Indexer, reader::Reader, writer::Writer};
+pub use self::{
+    indexed_reader::IndexedReader, indexer::Indexer, indexing_writer::IndexingWriter,
+    reader::Reader, writer::Writer,
+};
 use super::fai;
 
 /// A buffered FASTA reader.
@@ -2,13 +2,14 @@
 
 mod builder;
 
+pub(crate) use self::builder::build_index_src;
 pub use self::builder::Builder;
 
 use std::io::{self, BufRead, Seek};
 
-use noodles_core::Region;
+use noodles_core::{region::Interval, Region};
 
-use super::Reader;
+use super::{reader::MaskPolicy, Reader};
 use crate::{fai, Record};
 
 /// An indexed FASTA reader.
@@ -68,4 +69,17 @@ where
     pub fn query(&mut self, region: &Region) -> io::Result<Record> {
         self.inner.query(&self.index, region)
     }
+
+    /// Returns a record of the given region with soft-masked bases presented using the given
+    /// policy, and the soft-masked intervals of the region.
+    ///
+    /// See [`Reader::query_with_mask_policy`].
+    pub fn query_with_mask_policy(
+        &mut self,
+        region: &Region,
+        mask_policy: MaskPolicy,
+    ) -> io::Result<(Record, Vec<Interval>)> {
+        self.inner
+            .query_with_mask_policy(&self.index, region, mask_policy)
+    }
 }
@@ -92,7 +92,7 @@ impl Builder {
     }
 }
 
-fn build_index_src<P>(src: P) -> PathBuf
+pub(crate) fn build_index_src<P>(src: P) -> PathBuf
 where
     P: AsRef<Path>,
 {
@@ -0,0 +1,279 @@
+//! Indexing FASTA writer.
+
+use std::{
+    fs::File,
+    io::{self, Write},
+    path::PathBuf,
+};
+
+use super::writer::{write_record, Options};
+use crate::{fai, Record};
+
+/// A FASTA writer that builds a FASTA index (FAI) of the records it writes.
+///
+/// The offsets and line widths of each record are tracked as the record is written, so the index
+/// is available without a second pass over the output. The output must be uncompressed.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_fasta::{self as fasta, fai, record::{Definition, Sequence}};
+///
+/// let mut writer = fasta::io::IndexingWriter::new(Vec::new());
+///
+/// let record = fasta::Record::new(Definition::new("sq0", None), Sequence::from(b"ACGT".to_vec()));
+/// writer.write_record(&record)?;
+///
+/// let index = writer.finish()?;
+///
+/// assert_eq!(writer.get_ref(), b">sq0\nACGT\n");
+/// assert_eq!(index, fai::Index::from(vec![fai::Record::new("sq0", 4, 5, 4, 5)]));
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct IndexingWriter<W> {
+    inner: W,
+    options: Options,
+    position: u64,
+    index_records: Vec<fai::Record>,
+    index_dst: Option<PathBuf>,
+}
+
+impl<W> IndexingWriter<W> {
+    pub(crate) fn from_parts(inner: W, options: Options, index_dst: Option<PathBuf>) -> Self {
+        Self {
+            inner,
+            options,
+            position: 0,
+            index_records: Vec::new(),
+            index_dst,
+        }
+    }
+
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fasta as fasta;
+    /// let writer = fasta::io::IndexingWriter::new(io::sink());
+    /// let _inner = writer.get_ref();
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Unwraps and returns the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fasta as fasta;
+    /// let writer = fasta::io::IndexingWriter::new(io::sink());
+    /// let _inner = writer.into_inner();
+    /// ```
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Returns the index records of the records written so far.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fasta as fasta;
+    /// let writer = fasta::io::IndexingWriter::new(io::sink());
+    /// assert!(writer.index_records().is_empty());
+    /// ```
+    pub fn index_records(&self) -> &[fai::Record] {
+        &self.index_records
+    }
+}
+
+impl<W> IndexingWriter<W>
+where
+    W: Write,
+{
+    /// Creates an indexing FASTA writer.
+    ///
+    /// This uses the default writer options. Use
+    /// [`super::writer::Builder::build_indexing_with_writer`] to change them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// let writer = fasta::io::IndexingWriter::new(Vec::new());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Self::from_parts(inner, Options::default(), None)
+    }
+
+    /// Writes a FASTA record and adds it to the index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+    ///
+    /// let mut writer = fasta::io::IndexingWriter::new(Vec::new());
+    ///
+    /// let record = fasta::Record::new(Definition::new("sq0", None), Sequence::from(b"ACGT".to_vec()));
+    /// writer.write_record(&record)?;
+    ///
+    /// assert_eq!(writer.index_records().len(), 1);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
+        let mut writer = CountingWriter {
+            inner: &mut self.inner,
+            count: 0,
+        };
+
+        write_record(&mut writer, record, &self.options)?;
+
+        let record_len = writer.count;
+
+        let length = record.sequence().len();
+
+        let line_bases = match self.options.line_base_count {
+            0 => length,
+            n => n.min(length),
+        };
+
+        let line_count = (length + line_bases)
+            .saturating_sub(1)
+            .checked_div(line_bases)
+            .unwrap_or(0);
+
+        let line_width = if line_bases == 0 { 0 } else { line_bases + 1 };
+
+        // Each sequence line ends with a line feed.
+        let sequence_len = (length + line_count) as u64;
+        let offset = self.position + record_len - sequence_len;
+
+        self.index_records.push(fai::Record::new(
+            record.name(),
+            length as u64,
+            offset,
+            line_bases as u64,
+            line_width as u64,
+        ));
+
+        self.position += record_len;
+
+        Ok(())
+    }
+
+    /// Flushes the output and returns the index.
+    ///
+    /// If the writer was built with [`super::writer::Builder::build_indexing_from_path`], the
+    /// index is also written to `<dst>.fai`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fasta as fasta;
+    /// let mut writer = fasta::io::IndexingWriter::new(Vec::new());
+    /// let index = writer.finish()?;
+    /// assert!(index.as_ref().is_empty());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn finish(&mut self) -> io::Result<fai::Index> {
+        self.inner.flush()?;
+
+        let index = fai::Index::from(self.index_records.clone());
+
+        if let Some(dst) = &self.index_dst {
+            let mut writer = File::create(dst)
+                .map(io::BufWriter::new)
+                .map(fai::Writer::new)?;
+            writer.write_index(&index)?;
+            writer.get_mut().flush()?;
+        }
+
+        Ok(index)
+    }
+}
+
+struct CountingWriter<'a, W> {
+    inner: &'a mut W,
+    count: u64,
+}
+
+impl<W> Write for CountingWriter<'_, W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.count += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        io::{writer::Builder, Indexer},
+        record::{Definition, Sequence},
+    };
+
+    #[test]
+    fn test_write_record() -> Result<(), Box<dyn std::error::Error>> {
+        let records = [
+            Record::new(
+                Definition::new("sq0", Some(Vec::from("LN:10"))),
+                Sequence::from(b"ACGTACGTAC".to_vec()),
+            ),
+            Record::new(Definition::new("sq2", None), Sequence::from(b"AC".to_vec())),
+            Record::new(
+                Definition::new("sq3", None),
+                Sequence::from(b"ACGTACGT".to_vec()),
+            ),
+        ];
+
+        let mut writer = Builder::default()
+            .set_line_base_count(4)
+            .build_indexing_with_writer(Vec::new());
+
+        for record in &records {
+            writer.write_record(record)?;
+        }
+
+        let actual = writer.finish()?;
+
+        let mut indexer = Indexer::new(&writer.get_ref()[..]);
+        let mut index_records = Vec::new();
+
+        while let Some(record) = indexer.index_record()? {
+            index_records.push(record);
+        }
+
+        let expected = fai::Index::from(index_records);
+
+        assert_eq!(actual, expected);
+
+        let mut writer = IndexingWriter::new(Vec::new());
+        writer.write_record(&Record::new(
+            Definition::new("sq0", None),
+            Sequence::default(),
+        ))?;
+        assert_eq!(
+            writer.index_records(),
+            [fai::Record::new("sq0", 0, 5, 0, 0)]
+        );
+
+        Ok(())
+    }
+}
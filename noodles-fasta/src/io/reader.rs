@@ -2,14 +2,15 @@
 
 mod builder;
 mod definition;
+mod mask_policy;
 mod records;
 pub mod sequence;
 
-pub use self::{builder::Builder, records::Records};
+pub use self::{builder::Builder, mask_policy::MaskPolicy, records::Records};
 
 use std::io::{self, BufRead, Seek, SeekFrom};
 
-use noodles_core::{Position, Region};
+use noodles_core::{region::Interval, Position, Region};
 
 use self::definition::read_definition;
 use crate::{fai, Record};
@@ -257,6 +258,73 @@ where
 
         Ok(Record::new(definition, sequence))
     }
+
+    /// Returns a record of the given region with soft-masked bases presented using the given
+    /// policy.
+    ///
+    /// This also returns the soft-masked (lowercase) intervals of the region, in reference
+    /// sequence coordinates. They are found before the mask policy is applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::Cursor;
+    /// use noodles_core::{region::Interval, Position};
+    /// use noodles_fasta::{self as fasta, fai, io::reader::MaskPolicy, record::Sequence};
+    ///
+    /// let data = b">sq0\nACgtNNac\n";
+    /// let index = fai::Index::from(vec![fai::Record::new("sq0", 8, 5, 8, 9)]);
+    /// let mut reader = fasta::io::Reader::new(Cursor::new(data));
+    ///
+    /// let region = "sq0:2-8".parse()?;
+    /// let (record, intervals) =
+    ///     reader.query_with_mask_policy(&index, &region, MaskPolicy::HardMask)?;
+    ///
+    /// assert_eq!(record.sequence(), &Sequence::from(b"CNNNNNN".to_vec()));
+    /// assert_eq!(intervals, [
+    ///     Interval::from(Position::try_from(3)?..=Position::try_from(4)?),
+    ///     Interval::from(Position::try_from(7)?..=Position::try_from(8)?),
+    /// ]);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_with_mask_policy(
+        &mut self,
+        index: &fai::Index,
+        region: &Region,
+        mask_policy: MaskPolicy,
+    ) -> io::Result<(Record, Vec<Interval>)> {
+        let record = self.query(index, region)?;
+
+        let offset = region
+            .interval()
+            .start()
+            .map(|start| usize::from(start) - 1)
+            .unwrap_or_default();
+
+        let intervals = record
+            .sequence()
+            .soft_masked_intervals()
+            .map(|interval| offset_interval(interval, offset))
+            .collect::<io::Result<_>>()?;
+
+        let (definition, sequence) = (record.definition().clone(), record.sequence().clone());
+        let record = Record::new(definition, mask_policy.apply(sequence));
+
+        Ok((record, intervals))
+    }
+}
+
+fn offset_interval(interval: Interval, offset: usize) -> io::Result<Interval> {
+    let offset_position = |position: Option<Position>| {
+        position
+            .and_then(|p| p.checked_add(offset))
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid interval"))
+    };
+
+    let start = offset_position(interval.start())?;
+    let end = offset_position(interval.end())?;
+
+    Ok(Interval::from(start..=end))
 }
 
 // Reads all bytes until a line feed ('\n') or EOF is reached.
@@ -0,0 +1,45 @@
+use crate::record::Sequence;
+
+/// A policy for presenting soft-masked (lowercase) bases in query results and written records.
+///
+/// Soft-masked bases typically mark repeats.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum MaskPolicy {
+    /// Soft-masked bases are kept as-is.
+    #[default]
+    Preserve,
+    /// Soft-masked bases are uppercased.
+    Uppercase,
+    /// Soft-masked bases are replaced with `N`.
+    HardMask,
+}
+
+impl MaskPolicy {
+    pub(crate) fn apply(&self, sequence: Sequence) -> Sequence {
+        match self {
+            Self::Preserve => sequence,
+            Self::Uppercase => sequence.unmask(),
+            Self::HardMask => sequence.hard_mask(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let sequence = Sequence::from(b"ACgtN".to_vec());
+
+        assert_eq!(MaskPolicy::Preserve.apply(sequence.clone()), sequence);
+        assert_eq!(
+            MaskPolicy::Uppercase.apply(sequence.clone()),
+            Sequence::from(b"ACGTN".to_vec())
+        );
+        assert_eq!(
+            MaskPolicy::HardMask.apply(sequence),
+            Sequence::from(b"ACNNN".to_vec())
+        );
+    }
+}
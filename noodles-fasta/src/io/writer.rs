@@ -1,18 +1,19 @@
 //! FASTA writer.
 
 mod builder;
+mod options;
 mod record;
 
 use std::io::{self, Write};
 
 pub use self::builder::Builder;
-use self::record::write_record;
+pub(crate) use self::{options::Options, record::write_record};
 use crate::Record;
 
 /// A FASTA writer.
 pub struct Writer<W> {
     inner: W,
-    line_base_count: usize,
+    options: Options,
 }
 
 impl<W> Writer<W> {
@@ -77,8 +78,10 @@ where
 
     /// Writes a FASTA record.
     ///
-    /// By default, sequence lines are hard wrapped at 80 bases. This can be changed by using
-    /// [`Builder::set_line_base_count`] when creating the writer.
+    /// By default, sequence lines are hard wrapped at 80 bases, descriptions are written, and
+    /// soft-masked (lowercase) bases are preserved. These can be changed by using
+    /// [`Builder::set_line_base_count`], [`Builder::set_write_descriptions`], and
+    /// [`Builder::set_mask_policy`], respectively, when creating the writer.
     ///
     /// # Examples
     ///
@@ -98,7 +101,7 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
-        write_record(&mut self.inner, record, self.line_base_count)
+        write_record(&mut self.inner, record, &self.options)
     }
 }
 
@@ -109,6 +112,38 @@ mod tests {
     #[test]
     fn test_new() {
         let writer = Writer::new(Vec::new());
-        assert_eq!(writer.line_base_count, 80);
+        assert_eq!(writer.options.line_base_count, 80);
+    }
+
+    #[test]
+    fn test_write_record_with_options() -> io::Result<()> {
+        use crate::{
+            io::reader::MaskPolicy,
+            record::{Definition, Sequence},
+        };
+
+        let record = Record::new(
+            Definition::new("sq0", Some(Vec::from("LN:6"))),
+            Sequence::from(b"ACgtnN".to_vec()),
+        );
+
+        let mut writer = Builder::default()
+            .set_line_base_count(4)
+            .set_write_descriptions(false)
+            .set_mask_policy(MaskPolicy::HardMask)
+            .build_with_writer(Vec::new());
+
+        writer.write_record(&record)?;
+        assert_eq!(writer.get_ref(), b">sq0\nACNN\nNN\n");
+
+        let mut writer = Builder::default()
+            .set_line_base_count(0)
+            .set_mask_policy(MaskPolicy::Uppercase)
+            .build_with_writer(Vec::new());
+
+        writer.write_record(&record)?;
+        assert_eq!(writer.get_ref(), b">sq0 LN:6\nACGTNN\n");
+
+        Ok(())
     }
 }
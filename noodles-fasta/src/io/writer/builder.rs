@@ -1,17 +1,23 @@
-use std::io::Write;
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
 
-use super::Writer;
-
-const DEFAULT_LINE_BASE_COUNT: usize = 80;
+use super::{Options, Writer};
+use crate::io::{indexed_reader::build_index_src, reader::MaskPolicy, IndexingWriter};
 
 /// A FASTA writer builder.
+#[derive(Debug, Default)]
 pub struct Builder {
-    line_base_count: usize,
+    options: Options,
 }
 
 impl Builder {
     /// Sets the number of bases per line.
     ///
+    /// If 0, sequences are not wrapped, i.e., each sequence is written on a single line.
+    ///
     /// By default, this is set to 80.
     ///
     /// # Examples
@@ -21,7 +27,41 @@ impl Builder {
     /// let builder = fasta::io::writer::Builder::default().set_line_base_count(100);
     /// ```
     pub fn set_line_base_count(mut self, line_base_count: usize) -> Self {
-        self.line_base_count = line_base_count;
+        self.options.line_base_count = line_base_count;
+        self
+    }
+
+    /// Sets whether to write record descriptions.
+    ///
+    /// If `true`, a record description is written after its name, separated by a space. If
+    /// `false`, descriptions are discarded, and the definition line only contains the name.
+    ///
+    /// By default, this is set to `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// let builder = fasta::io::writer::Builder::default().set_write_descriptions(false);
+    /// ```
+    pub fn set_write_descriptions(mut self, write_descriptions: bool) -> Self {
+        self.options.write_descriptions = write_descriptions;
+        self
+    }
+
+    /// Sets the policy for writing soft-masked (lowercase) bases.
+    ///
+    /// By default, this is set to [`MaskPolicy::Preserve`], i.e., lowercase bases are written
+    /// as-is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, io::reader::MaskPolicy};
+    /// let builder = fasta::io::writer::Builder::default().set_mask_policy(MaskPolicy::Uppercase);
+    /// ```
+    pub fn set_mask_policy(mut self, mask_policy: MaskPolicy) -> Self {
+        self.options.mask_policy = mask_policy;
         self
     }
 
@@ -40,15 +80,50 @@ impl Builder {
     {
         Writer {
             inner: writer,
-            line_base_count: self.line_base_count,
+            options: self.options,
         }
     }
-}
 
-impl Default for Builder {
-    fn default() -> Self {
-        Self {
-            line_base_count: DEFAULT_LINE_BASE_COUNT,
-        }
+    /// Builds an indexing FASTA writer from a writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fasta as fasta;
+    /// let writer = fasta::io::writer::Builder::default().build_indexing_with_writer(io::sink());
+    /// ```
+    pub fn build_indexing_with_writer<W>(self, writer: W) -> IndexingWriter<W>
+    where
+        W: Write,
+    {
+        IndexingWriter::from_parts(writer, self.options, None)
+    }
+
+    /// Builds an indexing FASTA writer from a path.
+    ///
+    /// This creates a file at the given path. When the writer is finished
+    /// ([`IndexingWriter::finish`]), the index is written to `<dst>.fai`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// use noodles_fasta as fasta;
+    /// let writer = fasta::io::writer::Builder::default().build_indexing_from_path("out.fa")?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build_indexing_from_path<P>(self, dst: P) -> io::Result<IndexingWriter<BufWriter<File>>>
+    where
+        P: AsRef<Path>,
+    {
+        let dst = dst.as_ref();
+        let file = File::create(dst).map(BufWriter::new)?;
+        let index_dst = build_index_src(dst);
+        Ok(IndexingWriter::from_parts(
+            file,
+            self.options,
+            Some(index_dst),
+        ))
     }
 }
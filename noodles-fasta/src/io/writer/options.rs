@@ -0,0 +1,20 @@
+use crate::io::reader::MaskPolicy;
+
+const DEFAULT_LINE_BASE_COUNT: usize = 80;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct Options {
+    pub(crate) line_base_count: usize,
+    pub(crate) write_descriptions: bool,
+    pub(crate) mask_policy: MaskPolicy,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            line_base_count: DEFAULT_LINE_BASE_COUNT,
+            write_descriptions: true,
+            mask_policy: MaskPolicy::default(),
+        }
+    }
+}
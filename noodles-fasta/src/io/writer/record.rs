@@ -4,20 +4,22 @@ mod sequence;
 use std::io::{self, Write};
 
 use self::{definition::write_definition, sequence::write_sequence};
+use super::Options;
 use crate::Record;
 
-pub(super) fn write_record<W>(
-    writer: &mut W,
-    record: &Record,
-    line_base_count: usize,
-) -> io::Result<()>
+pub(crate) fn write_record<W>(writer: &mut W, record: &Record, options: &Options) -> io::Result<()>
 where
     W: Write,
 {
-    write_definition(writer, record.definition())?;
+    write_definition(writer, record.definition(), options.write_descriptions)?;
     write_newline(writer)?;
 
-    write_sequence(writer, record.sequence(), line_base_count)?;
+    write_sequence(
+        writer,
+        record.sequence(),
+        options.line_base_count,
+        options.mask_policy,
+    )?;
 
     Ok(())
 }
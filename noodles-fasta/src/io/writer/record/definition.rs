@@ -5,14 +5,18 @@ use crate::record::Definition;
 const SEPARATOR: u8 = b' ';
 const PREFIX: u8 = b'>';
 
-pub(super) fn write_definition<W>(writer: &mut W, definition: &Definition) -> io::Result<()>
+pub(super) fn write_definition<W>(
+    writer: &mut W,
+    definition: &Definition,
+    write_descriptions: bool,
+) -> io::Result<()>
 where
     W: Write,
 {
     write_prefix(writer)?;
     write_name(writer, definition.name())?;
 
-    if let Some(description) = definition.description() {
+    if let Some(description) = definition.description().filter(|_| write_descriptions) {
         write_separator(writer)?;
         write_description(writer, description)?;
     }
@@ -54,21 +58,33 @@ mod tests {
 
     #[test]
     fn test_write_definition() -> io::Result<()> {
-        fn t(buf: &mut Vec<u8>, definition: &Definition, expected: &[u8]) -> io::Result<()> {
+        fn t(
+            buf: &mut Vec<u8>,
+            definition: &Definition,
+            write_descriptions: bool,
+            expected: &[u8],
+        ) -> io::Result<()> {
             buf.clear();
-            write_definition(buf, definition)?;
+            write_definition(buf, definition, write_descriptions)?;
             assert_eq!(buf, expected);
             Ok(())
         }
 
         let mut buf = Vec::new();
 
-        t(&mut buf, &Definition::new("sq0", None), b">sq0")?;
+        t(&mut buf, &Definition::new("sq0", None), true, b">sq0")?;
         t(
             &mut buf,
             &Definition::new("sq0", Some(Vec::from("LN:8"))),
+            true,
             b">sq0 LN:8",
         )?;
+        t(
+            &mut buf,
+            &Definition::new("sq0", Some(Vec::from("LN:8"))),
+            false,
+            b">sq0",
+        )?;
 
         Ok(())
     }
@@ -1,17 +1,50 @@
 use std::io::{self, Write};
 
 use super::write_newline;
-use crate::record::Sequence;
+use crate::{io::reader::MaskPolicy, record::Sequence};
 
 pub(super) fn write_sequence<W>(
     writer: &mut W,
     sequence: &Sequence,
     line_bases: usize,
+    mask_policy: MaskPolicy,
 ) -> io::Result<()>
 where
     W: Write,
 {
-    for bases in sequence.as_ref().chunks(line_bases) {
+    let sequence = sequence.as_ref();
+
+    if sequence.is_empty() {
+        return Ok(());
+    }
+
+    let line_bases = if line_bases == 0 {
+        sequence.len()
+    } else {
+        line_bases
+    };
+
+    let mut buf = Vec::new();
+
+    for bases in sequence.chunks(line_bases) {
+        let bases = match mask_policy {
+            MaskPolicy::Preserve => bases,
+            MaskPolicy::Uppercase => {
+                buf.clear();
+                buf.extend(bases.iter().map(|b| b.to_ascii_uppercase()));
+                &buf[..]
+            }
+            MaskPolicy::HardMask => {
+                buf.clear();
+                buf.extend(
+                    bases
+                        .iter()
+                        .map(|&b| if b.is_ascii_lowercase() { b'N' } else { b }),
+                );
+                &buf[..]
+            }
+        };
+
         writer.write_all(bases)?;
         write_newline(writer)?;
     }
@@ -27,24 +60,48 @@ mod tests {
     fn test_write_sequence() -> io::Result<()> {
         let mut writer = Vec::new();
         let sequence = Sequence::from(b"AC".to_vec());
-        write_sequence(&mut writer, &sequence, 4)?;
+        write_sequence(&mut writer, &sequence, 4, MaskPolicy::Preserve)?;
         assert_eq!(writer, b"AC\n");
 
         writer.clear();
         let sequence = Sequence::from(b"ACGT".to_vec());
-        write_sequence(&mut writer, &sequence, 4)?;
+        write_sequence(&mut writer, &sequence, 4, MaskPolicy::Preserve)?;
         assert_eq!(writer, b"ACGT\n");
 
         writer.clear();
         let sequence = Sequence::from(b"ACGTACGT".to_vec());
-        write_sequence(&mut writer, &sequence, 4)?;
+        write_sequence(&mut writer, &sequence, 4, MaskPolicy::Preserve)?;
         assert_eq!(writer, b"ACGT\nACGT\n");
 
         writer.clear();
         let sequence = Sequence::from(b"ACGTACGTAC".to_vec());
-        write_sequence(&mut writer, &sequence, 4)?;
+        write_sequence(&mut writer, &sequence, 4, MaskPolicy::Preserve)?;
         assert_eq!(writer, b"ACGT\nACGT\nAC\n");
 
+        writer.clear();
+        let sequence = Sequence::from(b"ACGTACGTAC".to_vec());
+        write_sequence(&mut writer, &sequence, 0, MaskPolicy::Preserve)?;
+        assert_eq!(writer, b"ACGTACGTAC\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_sequence_with_mask_policy() -> io::Result<()> {
+        let mut writer = Vec::new();
+        let sequence = Sequence::from(b"ACgtN".to_vec());
+
+        write_sequence(&mut writer, &sequence, 4, MaskPolicy::Preserve)?;
+        assert_eq!(writer, b"ACgt\nN\n");
+
+        writer.clear();
+        write_sequence(&mut writer, &sequence, 4, MaskPolicy::Uppercase)?;
+        assert_eq!(writer, b"ACGT\nN\n");
+
+        writer.clear();
+        write_sequence(&mut writer, &sequence, 4, MaskPolicy::HardMask)?;
+        assert_eq!(writer, b"ACNN\nN\n");
+
         Ok(())
     }
 }
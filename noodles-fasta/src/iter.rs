@@ -0,0 +1,93 @@
+//! FASTA record iterator adapters.
+//!
+//! These are composable adapters over iterators of `io::Result<Record>`, e.g., the iterator
+//! returned by [`crate::io::Reader::records`]. Errors are passed through unchanged.
+
+use std::io;
+
+use crate::Record;
+
+/// An extension trait for iterators over FASTA records.
+pub trait RecordsExt: Iterator<Item = io::Result<Record>> + Sized {
+    /// Keeps only records with a sequence length in `[min, max]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fasta::{self as fasta, iter::RecordsExt};
+    ///
+    /// let data = b">sq0\nA\n>sq1\nACGT\n";
+    /// let mut reader = fasta::io::Reader::new(&data[..]);
+    ///
+    /// let records: Vec<_> = reader.records().filter_by_length(2, 8).collect::<io::Result<_>>()?;
+    /// assert_eq!(records.len(), 1);
+    /// assert_eq!(records[0].name(), b"sq1");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    fn filter_by_length(self, min: usize, max: usize) -> FilterByLength<Self> {
+        FilterByLength {
+            inner: self,
+            min,
+            max,
+        }
+    }
+}
+
+impl<I> RecordsExt for I where I: Iterator<Item = io::Result<Record>> {}
+
+/// An iterator adapter that filters records by sequence length.
+///
+/// This is created by calling [`RecordsExt::filter_by_length`].
+pub struct FilterByLength<I> {
+    inner: I,
+    min: usize,
+    max: usize,
+}
+
+impl<I> Iterator for FilterByLength<I>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (min, max) = (self.min, self.max);
+
+        self.inner.find(|result| match result {
+            Ok(record) => (min..=max).contains(&record.sequence().len()),
+            Err(_) => true,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::{Definition, Sequence};
+
+    #[test]
+    fn test_filter_by_length() {
+        let records = vec![
+            Ok(Record::new(
+                Definition::new("sq0", None),
+                Sequence::from(b"A".to_vec()),
+            )),
+            Err(io::Error::from(io::ErrorKind::InvalidData)),
+            Ok(Record::new(
+                Definition::new("sq1", None),
+                Sequence::from(b"ACGT".to_vec()),
+            )),
+            Ok(Record::new(
+                Definition::new("sq2", None),
+                Sequence::from(b"ACGTACGT".to_vec()),
+            )),
+        ];
+
+        let mut iter = records.into_iter().filter_by_length(2, 4);
+
+        assert!(matches!(iter.next(), Some(Err(_))));
+        assert!(matches!(iter.next(), Some(Ok(record)) if record.name() == b"sq1"));
+        assert!(iter.next().is_none());
+    }
+}
@@ -52,6 +52,7 @@ pub mod r#async;
 
 pub mod fai;
 pub mod io;
+pub mod iter;
 pub mod record;
 pub mod repository;
 pub mod sequence;
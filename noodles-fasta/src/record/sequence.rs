@@ -1,13 +1,17 @@
 //! FASTA record sequence.
 
+pub mod alphabet;
 pub mod complement;
+pub mod packed;
 
-pub use self::complement::Complement;
+pub use self::{alphabet::Alphabet, complement::Complement, packed::Packed};
 
 use std::ops::Index;
 
 use bytes::Bytes;
-use noodles_core::{position::SequenceIndex, region::Interval};
+use noodles_core::{position::SequenceIndex, region::Interval, Position};
+
+use self::complement::ComplementError;
 
 /// A FASTA record sequence.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -136,6 +140,116 @@ impl Sequence {
     pub fn complement(&self) -> Complement<'_> {
         Complement::new(self.0.iter())
     }
+
+    /// Returns the reverse complement of the sequence.
+    ///
+    /// This is equivalent to collecting the reversed iterator of [`Self::complement`]. The case
+    /// of each base is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::Sequence;
+    /// let sequence = Sequence::from(b"AACgtn".to_vec());
+    /// let actual = sequence.reverse_complement()?;
+    /// let expected = Sequence::from(b"nacGTT".to_vec());
+    /// assert_eq!(actual, expected);
+    /// # Ok::<_, noodles_fasta::record::sequence::complement::ComplementError>(())
+    /// ```
+    pub fn reverse_complement(&self) -> Result<Self, ComplementError> {
+        self.complement().rev().collect()
+    }
+
+    /// Validates the sequence using the given alphabet.
+    ///
+    /// Symbols are case-insensitive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::{sequence::Alphabet, Sequence};
+    ///
+    /// let sequence = Sequence::from(b"ACGTacgt".to_vec());
+    /// assert!(sequence.validate(Alphabet::Nucleotide).is_ok());
+    ///
+    /// let sequence = Sequence::from(b"MKVLE".to_vec());
+    /// assert!(sequence.validate(Alphabet::Nucleotide).is_err());
+    /// assert!(sequence.validate(Alphabet::AminoAcid).is_ok());
+    /// ```
+    pub fn validate(&self, alphabet: Alphabet) -> Result<(), alphabet::ValidationError> {
+        alphabet.validate(self.as_ref())
+    }
+
+    /// Returns an iterator over soft-masked intervals.
+    ///
+    /// A soft-masked interval is a maximal run of lowercase bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Interval, Position};
+    /// use noodles_fasta::record::Sequence;
+    ///
+    /// let sequence = Sequence::from(b"ACgtACGTnnA".to_vec());
+    /// let intervals: Vec<_> = sequence.soft_masked_intervals().collect();
+    ///
+    /// assert_eq!(intervals, [
+    ///     Interval::from(Position::try_from(3)?..=Position::try_from(4)?),
+    ///     Interval::from(Position::try_from(9)?..=Position::try_from(10)?),
+    /// ]);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn soft_masked_intervals(&self) -> impl Iterator<Item = Interval> + '_ {
+        let mut i = 0;
+
+        std::iter::from_fn(move || {
+            let buf = &self.0[i..];
+
+            let start = i + buf.iter().position(|b| b.is_ascii_lowercase())?;
+            let len = self.0[start..]
+                .iter()
+                .position(|b| !b.is_ascii_lowercase())
+                .unwrap_or(self.len() - start);
+
+            let end = start + len;
+            i = end;
+
+            // SAFETY: `start + 1` and `end` are > 0.
+            let start = Position::new(start + 1).unwrap();
+            let end = Position::new(end).unwrap();
+
+            Some(Interval::from(start..=end))
+        })
+    }
+
+    /// Returns a copy of the sequence with soft-masked (lowercase) bases unmasked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::Sequence;
+    /// let sequence = Sequence::from(b"ACgtn".to_vec());
+    /// assert_eq!(sequence.unmask(), Sequence::from(b"ACGTN".to_vec()));
+    /// ```
+    pub fn unmask(&self) -> Self {
+        Self::from(self.0.to_ascii_uppercase())
+    }
+
+    /// Returns a copy of the sequence with soft-masked (lowercase) bases hard-masked to `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::Sequence;
+    /// let sequence = Sequence::from(b"ACgtN".to_vec());
+    /// assert_eq!(sequence.hard_mask(), Sequence::from(b"ACNNN".to_vec()));
+    /// ```
+    pub fn hard_mask(&self) -> Self {
+        self.0
+            .iter()
+            .map(|&b| if b.is_ascii_lowercase() { b'N' } else { b })
+            .collect()
+    }
 }
 
 impl AsRef<[u8]> for Sequence {
@@ -177,3 +291,36 @@ where
         index.index(self.as_ref())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_soft_masked_intervals() -> Result<(), noodles_core::position::TryFromIntError> {
+        fn interval(
+            start: usize,
+            end: usize,
+        ) -> Result<Interval, noodles_core::position::TryFromIntError> {
+            Ok(Interval::from(
+                Position::try_from(start)?..=Position::try_from(end)?,
+            ))
+        }
+
+        let sequence = Sequence::default();
+        assert_eq!(sequence.soft_masked_intervals().count(), 0);
+
+        let sequence = Sequence::from(b"ACGT".to_vec());
+        assert_eq!(sequence.soft_masked_intervals().count(), 0);
+
+        let sequence = Sequence::from(b"acgt".to_vec());
+        let actual: Vec<_> = sequence.soft_masked_intervals().collect();
+        assert_eq!(actual, [interval(1, 4)?]);
+
+        let sequence = Sequence::from(b"aCGtnNa".to_vec());
+        let actual: Vec<_> = sequence.soft_masked_intervals().collect();
+        assert_eq!(actual, [interval(1, 1)?, interval(4, 5)?, interval(7, 7)?]);
+
+        Ok(())
+    }
+}
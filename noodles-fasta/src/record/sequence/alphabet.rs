@@ -0,0 +1,164 @@
+//! FASTA record sequence alphabet.
+
+use std::{error, fmt};
+
+use bstr::ByteSlice;
+
+/// A sequence alphabet.
+///
+/// Symbols are case-insensitive, i.e., lowercase (soft-masked) symbols are valid.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Alphabet {
+    /// IUPAC nucleotide codes (`ACGTU`, `RYSWKM`, `BDHV`, and `N`) and gaps (`-`).
+    Nucleotide,
+    /// IUPAC amino acid codes (the 20 standard amino acids, `U`, `O`, `B`, `Z`, `J`, and `X`),
+    /// translation stops (`*`), and gaps (`-`).
+    AminoAcid,
+}
+
+impl Alphabet {
+    /// Returns whether the alphabet contains the given symbol.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::sequence::Alphabet;
+    ///
+    /// assert!(Alphabet::Nucleotide.contains(b'A'));
+    /// assert!(Alphabet::Nucleotide.contains(b'n'));
+    /// assert!(!Alphabet::Nucleotide.contains(b'E'));
+    ///
+    /// assert!(Alphabet::AminoAcid.contains(b'E'));
+    /// assert!(!Alphabet::AminoAcid.contains(b'1'));
+    /// ```
+    pub fn contains(&self, b: u8) -> bool {
+        match self {
+            Self::Nucleotide => matches!(
+                b.to_ascii_uppercase(),
+                b'A' | b'C'
+                    | b'G'
+                    | b'T'
+                    | b'U'
+                    | b'R'
+                    | b'Y'
+                    | b'S'
+                    | b'W'
+                    | b'K'
+                    | b'M'
+                    | b'B'
+                    | b'D'
+                    | b'H'
+                    | b'V'
+                    | b'N'
+                    | b'-'
+            ),
+            Self::AminoAcid => matches!(b.to_ascii_uppercase(), b'A'..=b'Z' | b'*' | b'-'),
+        }
+    }
+
+    /// Validates a sequence.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error at the first symbol that is not in the alphabet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::sequence::Alphabet;
+    ///
+    /// assert!(Alphabet::Nucleotide.validate(b"ACGTacgtN").is_ok());
+    ///
+    /// let e = Alphabet::Nucleotide.validate(b"ACXT").unwrap_err();
+    /// assert_eq!(e.position(), 2);
+    /// assert_eq!(e.symbol(), b'X');
+    /// ```
+    pub fn validate(&self, sequence: &[u8]) -> Result<(), ValidationError> {
+        match sequence.iter().position(|&b| !self.contains(b)) {
+            Some(i) => Err(ValidationError {
+                position: i,
+                symbol: sequence[i],
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// An error returned when a sequence contains a symbol that is not in an alphabet.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValidationError {
+    position: usize,
+    symbol: u8,
+}
+
+impl ValidationError {
+    /// Returns the 0-based position of the invalid symbol.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the invalid symbol.
+    pub fn symbol(&self) -> u8 {
+        self.symbol
+    }
+}
+
+impl error::Error for ValidationError {}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bytes = [self.symbol];
+        write!(
+            f,
+            "invalid symbol at position {}: {:?}",
+            self.position,
+            bytes.as_bstr()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contains() {
+        for &b in b"ACGTURYSWKMBDHVN-acgturyswkmbdhvn" {
+            assert!(Alphabet::Nucleotide.contains(b));
+        }
+
+        for &b in b"EFIJLOPQXZ*.1 " {
+            assert!(!Alphabet::Nucleotide.contains(b));
+        }
+
+        for &b in b"ACDEFGHIKLMNPQRSTVWYUOBZJX*-acdefghiklmnpqrstvwyuobzjx" {
+            assert!(Alphabet::AminoAcid.contains(b));
+        }
+
+        for &b in b".1 \n" {
+            assert!(!Alphabet::AminoAcid.contains(b));
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(Alphabet::Nucleotide.validate(b"").is_ok());
+        assert!(Alphabet::Nucleotide.validate(b"ACGTN-").is_ok());
+        assert_eq!(
+            Alphabet::Nucleotide.validate(b"ACGTQ"),
+            Err(ValidationError {
+                position: 4,
+                symbol: b'Q'
+            })
+        );
+
+        assert!(Alphabet::AminoAcid.validate(b"MKV*").is_ok());
+        assert_eq!(
+            Alphabet::AminoAcid.validate(b"MK1"),
+            Err(ValidationError {
+                position: 2,
+                symbol: b'1'
+            })
+        );
+    }
+}
@@ -0,0 +1,288 @@
+//! Packed FASTA record sequence.
+
+use std::ops::Range;
+
+use noodles_core::{region::Interval, Position};
+
+use super::Sequence;
+
+const BASES: [u8; 4] = *b"ACGT";
+const BASES_PER_BYTE: usize = 4;
+
+/// A packed sequence.
+///
+/// Bases are stored using 2 bits per base (`A`, `C`, `G`, and `T`). Runs of other symbols (e.g.,
+/// `N`) are stored in an exception list, and runs of lowercase (soft-masked) symbols, in a mask
+/// list, similar to the UCSC 2bit format. This uses about a quarter of the memory of a
+/// [`Sequence`] for typical reference sequences.
+///
+/// Packing is lossless for any sequence.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_fasta::record::{sequence::Packed, Sequence};
+///
+/// let sequence = Sequence::from(b"ACgtNNAC".to_vec());
+/// let packed = Packed::from(&sequence);
+///
+/// assert_eq!(packed.len(), 8);
+/// assert_eq!(packed.get(Position::try_from(3)?), Some(b'g'));
+///
+/// let start = Position::try_from(4)?;
+/// let end = Position::try_from(6)?;
+/// assert_eq!(packed.slice(start..=end), Some(Sequence::from(b"tNN".to_vec())));
+///
+/// assert_eq!(Sequence::from(&packed), sequence);
+/// # Ok::<_, noodles_core::position::TryFromIntError>(())
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Packed {
+    len: usize,
+    bases: Vec<u8>,
+    exceptions: Vec<(Range<usize>, u8)>,
+    masks: Vec<Range<usize>>,
+}
+
+impl Packed {
+    /// Returns the number of bases in the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::sequence::Packed;
+    /// let packed = Packed::default();
+    /// assert_eq!(packed.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the sequence is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::sequence::Packed;
+    /// let packed = Packed::default();
+    /// assert!(packed.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the base at the given position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_fasta::record::{sequence::Packed, Sequence};
+    ///
+    /// let packed = Packed::from(&Sequence::from(b"ACNT".to_vec()));
+    ///
+    /// assert_eq!(packed.get(Position::try_from(3)?), Some(b'N'));
+    /// assert_eq!(packed.get(Position::try_from(4)?), Some(b'T'));
+    /// assert!(packed.get(Position::try_from(5)?).is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn get(&self, position: Position) -> Option<u8> {
+        let i = usize::from(position) - 1;
+
+        if i >= self.len {
+            return None;
+        }
+
+        let j = self.exceptions.partition_point(|(r, _)| r.end <= i);
+
+        let b = match self.exceptions.get(j) {
+            Some((r, b)) if r.start <= i => *b,
+            _ => self.get_base(i),
+        };
+
+        let j = self.masks.partition_point(|r| r.end <= i);
+
+        match self.masks.get(j) {
+            Some(r) if r.start <= i => Some(b.to_ascii_lowercase()),
+            _ => Some(b),
+        }
+    }
+
+    fn get_base(&self, i: usize) -> u8 {
+        let n = self.bases[i / BASES_PER_BYTE];
+        let shift = 6 - 2 * (i % BASES_PER_BYTE);
+        BASES[usize::from((n >> shift) & 0x03)]
+    }
+
+    /// Returns a subset of the sequence within the given range as an unpacked sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_fasta::record::{sequence::Packed, Sequence};
+    ///
+    /// let packed = Packed::from(&Sequence::from(b"ACGT".to_vec()));
+    ///
+    /// let start = Position::try_from(2)?;
+    /// let end = Position::try_from(3)?;
+    /// assert_eq!(packed.slice(start..=end), Some(Sequence::from(b"CG".to_vec())));
+    ///
+    /// assert_eq!(packed.slice(start..), Some(Sequence::from(b"CGT".to_vec())));
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn slice<I>(&self, interval: I) -> Option<Sequence>
+    where
+        I: Into<Interval>,
+    {
+        let interval = interval.into();
+
+        let start = interval
+            .start()
+            .map(|position| usize::from(position) - 1)
+            .unwrap_or(usize::MIN);
+
+        let end = interval.end().map(usize::from).unwrap_or(self.len);
+
+        if start <= end && end <= self.len {
+            let mut buf = Vec::with_capacity(end - start);
+            self.decode_into(start..end, &mut buf);
+            Some(Sequence::from(buf))
+        } else {
+            None
+        }
+    }
+
+    fn decode_into(&self, range: Range<usize>, dst: &mut Vec<u8>) {
+        let offset = range.start;
+
+        dst.extend(range.clone().map(|i| self.get_base(i)));
+
+        let i = self
+            .exceptions
+            .partition_point(|(r, _)| r.end <= range.start);
+
+        for (r, b) in self.exceptions[i..]
+            .iter()
+            .take_while(|(r, _)| r.start < range.end)
+        {
+            let start = r.start.max(range.start) - offset;
+            let end = r.end.min(range.end) - offset;
+            dst[start..end].fill(*b);
+        }
+
+        let i = self.masks.partition_point(|r| r.end <= range.start);
+
+        for r in self.masks[i..].iter().take_while(|r| r.start < range.end) {
+            let start = r.start.max(range.start) - offset;
+            let end = r.end.min(range.end) - offset;
+            dst[start..end].make_ascii_lowercase();
+        }
+    }
+}
+
+impl From<&Sequence> for Packed {
+    fn from(sequence: &Sequence) -> Self {
+        let src = sequence.as_ref();
+
+        let mut bases = vec![0; (src.len() + BASES_PER_BYTE - 1) / BASES_PER_BYTE];
+        let mut exceptions: Vec<(Range<usize>, u8)> = Vec::new();
+        let mut masks: Vec<Range<usize>> = Vec::new();
+
+        for (i, &b) in src.iter().enumerate() {
+            if b.is_ascii_lowercase() {
+                match masks.last_mut() {
+                    Some(r) if r.end == i => r.end += 1,
+                    _ => masks.push(i..i + 1),
+                }
+            }
+
+            let b = b.to_ascii_uppercase();
+
+            let code = match b {
+                b'A' => 0,
+                b'C' => 1,
+                b'G' => 2,
+                b'T' => 3,
+                _ => {
+                    match exceptions.last_mut() {
+                        Some((r, c)) if r.end == i && *c == b => r.end += 1,
+                        _ => exceptions.push((i..i + 1, b)),
+                    }
+
+                    0
+                }
+            };
+
+            let shift = 6 - 2 * (i % BASES_PER_BYTE);
+            bases[i / BASES_PER_BYTE] |= code << shift;
+        }
+
+        Self {
+            len: src.len(),
+            bases,
+            exceptions,
+            masks,
+        }
+    }
+}
+
+impl From<&Packed> for Sequence {
+    fn from(packed: &Packed) -> Self {
+        let mut buf = Vec::with_capacity(packed.len);
+        packed.decode_into(0..packed.len, &mut buf);
+        Self::from(buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_sequence_for_packed() {
+        let sequence = Sequence::from(b"ACGTNNnnacgtRYacg-*".to_vec());
+        let packed = Packed::from(&sequence);
+
+        assert_eq!(packed.len(), 19);
+        assert_eq!(packed.bases.len(), 5);
+        assert_eq!(
+            packed.exceptions,
+            [
+                (4..8, b'N'),
+                (12..13, b'R'),
+                (13..14, b'Y'),
+                (17..18, b'-'),
+                (18..19, b'*')
+            ]
+        );
+        assert_eq!(packed.masks, [6..12, 14..17]);
+
+        assert_eq!(Sequence::from(&packed), sequence);
+    }
+
+    #[test]
+    fn test_slice() -> Result<(), noodles_core::position::TryFromIntError> {
+        let sequence = Sequence::from(b"ACGTNNnnacgtRYacg".to_vec());
+        let packed = Packed::from(&sequence);
+
+        for start in 1..=sequence.len() {
+            let position = Position::try_from(start)?;
+            assert_eq!(packed.get(position), sequence.get(position).copied());
+
+            for end in start..=sequence.len() {
+                let start = Position::try_from(start)?;
+                let end = Position::try_from(end)?;
+                assert_eq!(packed.slice(start..=end), sequence.slice(start..=end));
+            }
+        }
+
+        assert_eq!(packed.slice(..), Some(sequence));
+
+        let start = Position::try_from(19)?;
+        assert!(packed.slice(start..).is_none());
+
+        Ok(())
+    }
+}
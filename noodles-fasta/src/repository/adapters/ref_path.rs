@@ -0,0 +1,317 @@
+//! `REF_PATH`/`REF_CACHE` adapter.
+
+use std::{collections::HashMap, env, fs, io, path::PathBuf};
+
+use crate::{
+    record::{Definition, Sequence},
+    repository::Adapter,
+    Record,
+};
+
+const REF_PATH: &str = "REF_PATH";
+const REF_CACHE: &str = "REF_CACHE";
+
+const PATH_SEPARATOR: char = ':';
+const URL_SCHEMES: [&str; 3] = ["http", "https", "ftp"];
+
+/// A `REF_PATH`/`REF_CACHE` adapter.
+///
+/// This looks up reference sequences by MD5 checksum using the same search path semantics as
+/// htslib. Each sequence name is first mapped to its checksum, which is typically the `M5` field
+/// of the corresponding SAM header reference sequence record.
+///
+/// `REF_CACHE` is a single path template, and `REF_PATH` is a list of path templates separated by
+/// `:`. In a template, `%Ns` is replaced with the next `N` characters of the checksum and `%s`,
+/// with the remaining characters. A template without `%s` is treated as a directory, i.e.,
+/// `<dir>/%s`. The cache is searched before the paths in `REF_PATH`.
+///
+/// Files are read as raw sequences, i.e., without a FASTA definition or line breaks, as written
+/// by htslib and `seq_cache_populate.pl`. Remote (`http:`, `https:`, and `ftp:`) templates are
+/// not supported. If a sequence is not found using the local templates and a remote template is
+/// set, the lookup fails with an [`io::ErrorKind::Unsupported`] error.
+pub struct RefPath {
+    ref_cache: Option<String>,
+    ref_path: Vec<String>,
+    checksums: HashMap<Vec<u8>, String>,
+}
+
+impl RefPath {
+    /// Creates a `REF_PATH`/`REF_CACHE` adapter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::repository::adapters::RefPath;
+    ///
+    /// let adapter = RefPath::new(
+    ///     "/data/ref/%2s/%2s/%s:/mnt/ref",
+    ///     Some("/tmp/hts-ref/%2s/%2s/%s"),
+    ///     [("sq0", "f1f8f4bf413b16ad135722aa4591043e")],
+    /// );
+    /// ```
+    pub fn new<I, N, M>(ref_path: &str, ref_cache: Option<&str>, checksums: I) -> Self
+    where
+        I: IntoIterator<Item = (N, M)>,
+        N: Into<Vec<u8>>,
+        M: Into<String>,
+    {
+        let checksums = checksums
+            .into_iter()
+            .map(|(name, md5)| (name.into(), md5.into().to_ascii_lowercase()))
+            .collect();
+
+        Self {
+            ref_cache: ref_cache.filter(|s| !s.is_empty()).map(String::from),
+            ref_path: split_ref_path(ref_path),
+            checksums,
+        }
+    }
+
+    /// Creates a `REF_PATH`/`REF_CACHE` adapter using the `REF_PATH` and `REF_CACHE` environment
+    /// variables.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::repository::adapters::RefPath;
+    /// let adapter = RefPath::from_env([("sq0", "f1f8f4bf413b16ad135722aa4591043e")]);
+    /// ```
+    pub fn from_env<I, N, M>(checksums: I) -> Self
+    where
+        I: IntoIterator<Item = (N, M)>,
+        N: Into<Vec<u8>>,
+        M: Into<String>,
+    {
+        let ref_path = env::var(REF_PATH).unwrap_or_default();
+        let ref_cache = env::var(REF_CACHE).ok();
+        Self::new(&ref_path, ref_cache.as_deref(), checksums)
+    }
+
+    /// Returns the MD5 checksum of the reference sequence with the given name.
+    pub fn checksum(&self, name: &[u8]) -> Option<&str> {
+        self.checksums.get(name).map(|s| s.as_str())
+    }
+
+    fn find(&self, md5: &str) -> io::Result<Option<Vec<u8>>> {
+        let templates = self.ref_cache.iter().chain(self.ref_path.iter());
+        let mut remote_template = None;
+
+        for template in templates {
+            if is_url(template) {
+                remote_template.get_or_insert(template);
+                continue;
+            }
+
+            let src = expand_template(template, md5);
+
+            match fs::read(&src) {
+                Ok(mut buf) => {
+                    buf.retain(|b| !b.is_ascii_whitespace());
+                    return Ok(Some(buf));
+                }
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        match remote_template {
+            Some(template) => Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                format!("unsupported remote template: {template}"),
+            )),
+            None => Ok(None),
+        }
+    }
+}
+
+impl Adapter for RefPath {
+    fn get(&mut self, name: &[u8]) -> Option<io::Result<Record>> {
+        let md5 = self.checksums.get(name)?;
+
+        match self.find(md5) {
+            Ok(Some(buf)) => Some(Ok(Record::new(
+                Definition::new(name, None),
+                Sequence::from(buf),
+            ))),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+// Splits a `REF_PATH` value on `:`, rejoining URL schemes with the rest of the URL, e.g.,
+// `https://example.com/%s`.
+fn split_ref_path(s: &str) -> Vec<String> {
+    let mut templates: Vec<String> = Vec::new();
+    let mut components = s.split(PATH_SEPARATOR).peekable();
+
+    while let Some(component) = components.next() {
+        if URL_SCHEMES.contains(&component) {
+            if let Some(rest) = components.next_if(|t| t.starts_with("//")) {
+                templates.push(format!("{component}{PATH_SEPARATOR}{rest}"));
+                continue;
+            }
+        }
+
+        if !component.is_empty() {
+            templates.push(component.into());
+        }
+    }
+
+    templates
+}
+
+fn is_url(template: &str) -> bool {
+    template
+        .split_once(PATH_SEPARATOR)
+        .map(|(scheme, _)| URL_SCHEMES.contains(&scheme))
+        .unwrap_or(false)
+}
+
+/// Expands a `REF_PATH`/`REF_CACHE` path template using the given MD5 checksum.
+///
+/// `%Ns` is replaced with the next `N` characters of the checksum and `%s`, with the remaining
+/// characters. If the template has no placeholders, the checksum is appended as a path component.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use noodles_fasta::repository::adapters::ref_path::expand_template;
+///
+/// assert_eq!(
+///     expand_template("/data/ref/%2s/%2s/%s", "f1f8f4bf413b16ad135722aa4591043e"),
+///     Path::new("/data/ref/f1/f8/f4bf413b16ad135722aa4591043e"),
+/// );
+/// ```
+pub fn expand_template(template: &str, md5: &str) -> PathBuf {
+    let mut dst = String::with_capacity(template.len() + md5.len());
+    let mut md5 = md5;
+    let mut has_placeholder = false;
+
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            dst.push(c);
+            continue;
+        }
+
+        let mut n = String::new();
+
+        while let Some(d) = chars.next_if(char::is_ascii_digit) {
+            n.push(d);
+        }
+
+        if chars.next_if_eq(&'s').is_some() {
+            let len = n.parse().unwrap_or(md5.len()).min(md5.len());
+            let (head, tail) = md5.split_at(len);
+            dst.push_str(head);
+            md5 = tail;
+            has_placeholder = true;
+        } else {
+            dst.push(c);
+            dst.push_str(&n);
+        }
+    }
+
+    let mut path = PathBuf::from(dst);
+
+    if !has_placeholder {
+        path.push(md5);
+    }
+
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+
+    const MD5: &str = "f1f8f4bf413b16ad135722aa4591043e";
+
+    #[test]
+    fn test_get() -> io::Result<()> {
+        let dir = env::temp_dir().join(format!("noodles-fasta-ref-path-{}", std::process::id()));
+        let src = dir.join("f1").join("f8");
+        fs::create_dir_all(&src)?;
+        fs::write(src.join(&MD5[4..]), b"ACGT\n")?;
+
+        let ref_path = format!("{}/%2s/%2s/%s", dir.display());
+        let mut adapter = RefPath::new(&ref_path, None, [("sq0", MD5.to_uppercase())]);
+
+        let record = adapter.get(b"sq0").transpose()?;
+        assert_eq!(
+            record,
+            Some(Record::new(
+                Definition::new("sq0", None),
+                Sequence::from(b"ACGT".to_vec())
+            ))
+        );
+
+        assert!(adapter.get(b"sq1").is_none());
+
+        fs::remove_dir_all(dir)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_with_remote_template() {
+        let mut adapter = RefPath::new(
+            "https://www.ebi.ac.uk/ena/cram/md5/%s",
+            None,
+            [("sq0", MD5)],
+        );
+
+        assert!(matches!(
+            adapter.get(b"sq0"),
+            Some(Err(e)) if e.kind() == io::ErrorKind::Unsupported
+        ));
+    }
+
+    #[test]
+    fn test_split_ref_path() {
+        assert_eq!(
+            split_ref_path("/data/ref/%2s/%2s/%s::https://www.ebi.ac.uk/ena/cram/md5/%s:/mnt/ref"),
+            [
+                "/data/ref/%2s/%2s/%s",
+                "https://www.ebi.ac.uk/ena/cram/md5/%s",
+                "/mnt/ref",
+            ]
+        );
+
+        assert!(split_ref_path("").is_empty());
+    }
+
+    #[test]
+    fn test_is_url() {
+        assert!(is_url("https://www.ebi.ac.uk/ena/cram/md5/%s"));
+        assert!(!is_url("/data/ref/%s"));
+    }
+
+    #[test]
+    fn test_expand_template() {
+        assert_eq!(
+            expand_template("/data/ref/%2s/%2s/%s", MD5),
+            Path::new("/data/ref/f1/f8/f4bf413b16ad135722aa4591043e")
+        );
+
+        assert_eq!(
+            expand_template("/data/ref/%s.fa", MD5),
+            Path::new("/data/ref/f1f8f4bf413b16ad135722aa4591043e.fa")
+        );
+
+        assert_eq!(
+            expand_template("/data/ref", MD5),
+            Path::new("/data/ref/f1f8f4bf413b16ad135722aa4591043e")
+        );
+
+        assert_eq!(
+            expand_template("/data/100%/%s", MD5),
+            Path::new("/data/100%/f1f8f4bf413b16ad135722aa4591043e")
+        );
+    }
+}
@@ -1,6 +1,7 @@
 //! Async FASTQ I/O.
 
+mod paired_reader;
 mod reader;
 mod writer;
 
-pub use self::{reader::Reader, writer::Writer};
+pub use self::{paired_reader::PairedReader, reader::Reader, writer::Writer};
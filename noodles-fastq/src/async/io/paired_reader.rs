@@ -0,0 +1,202 @@
+use futures::{stream, Stream};
+use tokio::io::{self, AsyncBufRead};
+
+use super::Reader;
+use crate::{io::paired_reader::validate_pair, Record};
+
+/// An async paired-end FASTQ reader.
+///
+/// See [`crate::io::PairedReader`] for how read names are paired.
+pub struct PairedReader<R1, R2> {
+    r1: Reader<R1>,
+    r2: Reader<R2>,
+}
+
+impl<R1, R2> PairedReader<R1, R2> {
+    /// Returns references to the underlying R1 and R2 readers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// use tokio::io;
+    ///
+    /// let reader = fastq::r#async::io::PairedReader::new(
+    ///     fastq::r#async::io::Reader::new(io::empty()),
+    ///     fastq::r#async::io::Reader::new(io::empty()),
+    /// );
+    ///
+    /// let (_r1, _r2) = reader.get_ref();
+    /// ```
+    pub fn get_ref(&self) -> (&Reader<R1>, &Reader<R2>) {
+        (&self.r1, &self.r2)
+    }
+
+    /// Returns mutable references to the underlying R1 and R2 readers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// use tokio::io;
+    ///
+    /// let mut reader = fastq::r#async::io::PairedReader::new(
+    ///     fastq::r#async::io::Reader::new(io::empty()),
+    ///     fastq::r#async::io::Reader::new(io::empty()),
+    /// );
+    ///
+    /// let (_r1, _r2) = reader.get_mut();
+    /// ```
+    pub fn get_mut(&mut self) -> (&mut Reader<R1>, &mut Reader<R2>) {
+        (&mut self.r1, &mut self.r2)
+    }
+
+    /// Returns the underlying R1 and R2 readers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// use tokio::io;
+    ///
+    /// let reader = fastq::r#async::io::PairedReader::new(
+    ///     fastq::r#async::io::Reader::new(io::empty()),
+    ///     fastq::r#async::io::Reader::new(io::empty()),
+    /// );
+    ///
+    /// let (_r1, _r2) = reader.into_inner();
+    /// ```
+    pub fn into_inner(self) -> (Reader<R1>, Reader<R2>) {
+        (self.r1, self.r2)
+    }
+}
+
+impl<R1, R2> PairedReader<R1, R2>
+where
+    R1: AsyncBufRead + Unpin,
+    R2: AsyncBufRead + Unpin,
+{
+    /// Creates an async paired-end FASTQ reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    ///
+    /// let r1 = fastq::r#async::io::Reader::new(&b"@r0/1\nATCG\n+\nNDLS\n"[..]);
+    /// let r2 = fastq::r#async::io::Reader::new(&b"@r0/2\nCGAT\n+\nSLDN\n"[..]);
+    /// let reader = fastq::r#async::io::PairedReader::new(r1, r2);
+    /// ```
+    pub fn new(r1: Reader<R1>, r2: Reader<R2>) -> Self {
+        Self { r1, r2 }
+    }
+
+    /// Reads a pair of FASTQ records.
+    ///
+    /// If successful, the total number of bytes read from both streams is returned. If the number
+    /// of bytes read is 0, both streams reached EOF.
+    ///
+    /// An error with kind [`io::ErrorKind::InvalidData`] and a
+    /// [`crate::io::paired_reader::PairingError`] payload is returned if the read names do not
+    /// match or one stream ends before the other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> tokio::io::Result<()> {
+    /// use noodles_fastq as fastq;
+    ///
+    /// let r1 = fastq::r#async::io::Reader::new(&b"@r0/1\nATCG\n+\nNDLS\n"[..]);
+    /// let r2 = fastq::r#async::io::Reader::new(&b"@r0/2\nCGAT\n+\nSLDN\n"[..]);
+    /// let mut reader = fastq::r#async::io::PairedReader::new(r1, r2);
+    ///
+    /// let mut record1 = fastq::Record::default();
+    /// let mut record2 = fastq::Record::default();
+    /// reader.read_record_pair(&mut record1, &mut record2).await?;
+    ///
+    /// assert_eq!(record1.name(), &b"r0/1"[..]);
+    /// assert_eq!(record2.name(), &b"r0/2"[..]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_record_pair(
+        &mut self,
+        record1: &mut Record,
+        record2: &mut Record,
+    ) -> io::Result<usize> {
+        let n1 = self.r1.read_record(record1).await?;
+        let n2 = self.r2.read_record(record2).await?;
+
+        validate_pair(n1, record1, n2, record2)?;
+
+        Ok(n1 + n2)
+    }
+
+    /// Returns an (async) stream over record pairs starting from the current (input) stream
+    /// positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[tokio::main]
+    /// # async fn main() -> tokio::io::Result<()> {
+    /// use futures::TryStreamExt;
+    /// use noodles_fastq as fastq;
+    ///
+    /// let r1 = fastq::r#async::io::Reader::new(&b"@r0/1\nATCG\n+\nNDLS\n"[..]);
+    /// let r2 = fastq::r#async::io::Reader::new(&b"@r0/2\nCGAT\n+\nSLDN\n"[..]);
+    /// let mut reader = fastq::r#async::io::PairedReader::new(r1, r2);
+    ///
+    /// let mut records = reader.records();
+    ///
+    /// while let Some((record1, record2)) = records.try_next().await? {
+    ///     // ...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn records(&mut self) -> impl Stream<Item = io::Result<(Record, Record)>> + '_ {
+        Box::pin(stream::try_unfold(
+            (self, Record::default(), Record::default()),
+            |(reader, mut buf1, mut buf2)| async {
+                reader
+                    .read_record_pair(&mut buf1, &mut buf2)
+                    .await
+                    .map(|n| match n {
+                        0 => None,
+                        _ => Some(((buf1.clone(), buf2.clone()), (reader, buf1, buf2))),
+                    })
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_records() -> io::Result<()> {
+        let r1 = Reader::new(&b"@r0/1\nATCG\n+\nNDLS\n@r1/1\nA\n+\nN\n"[..]);
+        let r2 = Reader::new(&b"@r0/2\nCGAT\n+\nSLDN\n"[..]);
+        let mut reader = PairedReader::new(r1, r2);
+
+        let mut records = reader.records();
+
+        let (record1, record2) = records.try_next().await?.unwrap();
+        assert_eq!(record1.name(), &b"r0/1"[..]);
+        assert_eq!(record2.name(), &b"r0/2"[..]);
+
+        let e = records.try_next().await.unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            e.to_string(),
+            "missing R2 record: unexpected EOF after R1 = r1/1"
+        );
+
+        Ok(())
+    }
+}
@@ -6,5 +6,55 @@ mod writer;
 
 pub use self::{reader::Reader, record::Record, writer::Writer};
 
+use std::{
+    fs::File,
+    io::{self, BufReader, BufWriter, Write},
+    path::Path,
+};
+
 /// A FASTQ index.
 pub type Index = Vec<Record>;
+
+/// Reads the entire contents of a FASTQ index.
+///
+/// This is a convenience function and is equivalent to opening the file at the given path and
+/// parsing each record.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::io;
+/// use noodles_fastq::fai;
+/// let index = fai::read("sample.fastq.fai")?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn read<P>(src: P) -> io::Result<Index>
+where
+    P: AsRef<Path>,
+{
+    let mut reader = File::open(src).map(BufReader::new).map(Reader::new)?;
+    reader.read_index()
+}
+
+/// Writes a FASTQ index to a file.
+///
+/// This is a convenience function and is equivalent to creating a file at the given path and
+/// writing the index. The output is compatible with `samtools fqidx`.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::io;
+/// use noodles_fastq::{self as fastq, fai};
+/// let index = fastq::io::index("sample.fastq")?;
+/// fai::write("sample.fastq.fai", &index)?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn write<P>(dst: P, index: &Index) -> io::Result<()>
+where
+    P: AsRef<Path>,
+{
+    let mut writer = File::create(dst).map(BufWriter::new).map(Writer::new)?;
+    writer.write_index(index)?;
+    writer.get_mut().flush()
+}
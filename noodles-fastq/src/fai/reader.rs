@@ -1,5 +1,7 @@
 use std::io::{self, BufRead};
 
+use super::Index;
+
 /// A FASTQ index reader.
 #[derive(Debug)]
 pub struct Reader<R> {
@@ -96,6 +98,49 @@ where
     pub fn read_record(&mut self, buf: &mut String) -> io::Result<usize> {
         read_line(&mut self.inner, buf)
     }
+
+    /// Reads a FASTQ index.
+    ///
+    /// The position of the stream is expected to be at the start or at the start of a record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::fai;
+    ///
+    /// let data = b"r0\t4\t4\t4\t5\t11\nr1\t4\t20\t4\t5\t27\n";
+    /// let mut reader = fai::Reader::new(&data[..]);
+    /// let index = reader.read_index()?;
+    ///
+    /// assert_eq!(index, [
+    ///     fai::Record::new("r0", 4, 4, 4, 5, 11),
+    ///     fai::Record::new("r1", 4, 20, 4, 5, 27),
+    /// ]);
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn read_index(&mut self) -> io::Result<Index> {
+        let mut buf = String::new();
+        let mut index = Index::new();
+
+        loop {
+            buf.clear();
+
+            match read_line(&mut self.inner, &mut buf) {
+                Ok(0) => break,
+                Ok(_) => {
+                    let record = buf
+                        .parse()
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+                    index.push(record);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(index)
+    }
 }
 
 fn read_line<R>(reader: &mut R, buf: &mut String) -> io::Result<usize>
@@ -1,6 +1,6 @@
 use std::io::{self, Write};
 
-use super::Record;
+use super::{Index, Record};
 
 /// A FASTQ index writer.
 pub struct Writer<W> {
@@ -67,6 +67,30 @@ where
         Self { inner }
     }
 
+    /// Writes a FASTQ index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::fai;
+    ///
+    /// let mut writer = fai::Writer::new(Vec::new());
+    ///
+    /// let index = vec![fai::Record::new("r0", 4, 4, 4, 5, 11)];
+    /// writer.write_index(&index)?;
+    ///
+    /// assert_eq!(writer.get_ref(), b"r0\t4\t4\t4\t5\t11\n");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn write_index(&mut self, index: &Index) -> io::Result<()> {
+        for record in index {
+            self.write_record(record)?;
+        }
+
+        Ok(())
+    }
+
     /// Writes a FASTQ index record.
     ///
     /// # Examples
@@ -1,12 +1,18 @@
 //! FASTQ I/O.
 
+pub mod fasta_qual;
+pub mod indexed_reader;
 mod indexer;
+pub mod paired_reader;
 pub mod reader;
 pub mod writer;
 
 use std::{fs::File, io::BufReader, path::Path};
 
-pub use self::{indexer::Indexer, reader::Reader, writer::Writer};
+pub use self::{
+    indexed_reader::IndexedReader, indexer::Indexer, paired_reader::PairedReader, reader::Reader,
+    writer::Writer,
+};
 use super::fai;
 
 /// Indexes a FASTQ file.
@@ -0,0 +1,22 @@
+//! FASTA+QUAL I/O.
+//!
+//! Before FASTQ was common, sequencing platforms (e.g., 454 and Sanger capillary) commonly
+//! produced reads as a pair of files: a FASTA file of sequences and a QUAL file of quality
+//! scores. A QUAL file is structured like a FASTA file, but each record body is a list of
+//! whitespace-separated decimal Phred quality scores, e.g.,
+//!
+//! ```text
+//! >r0
+//! 40 40 30 20
+//! ```
+//!
+//! The reader combines each FASTA and QUAL record pair into a FASTQ record with Phred+33-encoded
+//! quality scores, and the writer does the inverse.
+
+mod reader;
+mod writer;
+
+pub use self::{reader::Reader, writer::Writer};
+
+const DEFINITION_PREFIX: u8 = b'>';
+const PHRED_OFFSET: u8 = b'!';
@@ -0,0 +1,343 @@
+use std::{
+    io::{self, BufRead},
+    iter,
+};
+
+use super::{DEFINITION_PREFIX, PHRED_OFFSET};
+use crate::{record::Definition, Record};
+
+const LINE_FEED: u8 = b'\n';
+const CARRIAGE_RETURN: u8 = b'\r';
+
+/// A FASTA+QUAL reader.
+///
+/// This reads records from a FASTA stream and a QUAL stream in lockstep and combines each pair
+/// into a FASTQ record. The record definitions must match, and the number of quality scores must
+/// match the sequence length.
+pub struct Reader<F, Q> {
+    fasta: F,
+    qual: Q,
+    qual_definition: Definition,
+    buf: Vec<u8>,
+}
+
+impl<F, Q> Reader<F, Q> {
+    /// Returns references to the underlying FASTA and QUAL readers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::io::fasta_qual;
+    /// let reader = fasta_qual::Reader::new(io::empty(), io::empty());
+    /// let (_fasta, _qual) = reader.get_ref();
+    /// ```
+    pub fn get_ref(&self) -> (&F, &Q) {
+        (&self.fasta, &self.qual)
+    }
+
+    /// Returns mutable references to the underlying FASTA and QUAL readers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::io::fasta_qual;
+    /// let mut reader = fasta_qual::Reader::new(io::empty(), io::empty());
+    /// let (_fasta, _qual) = reader.get_mut();
+    /// ```
+    pub fn get_mut(&mut self) -> (&mut F, &mut Q) {
+        (&mut self.fasta, &mut self.qual)
+    }
+
+    /// Unwraps and returns the underlying FASTA and QUAL readers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::io::fasta_qual;
+    /// let reader = fasta_qual::Reader::new(io::empty(), io::empty());
+    /// let (_fasta, _qual) = reader.into_inner();
+    /// ```
+    pub fn into_inner(self) -> (F, Q) {
+        (self.fasta, self.qual)
+    }
+}
+
+impl<F, Q> Reader<F, Q>
+where
+    F: BufRead,
+    Q: BufRead,
+{
+    /// Creates a FASTA+QUAL reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::io::fasta_qual;
+    ///
+    /// let fasta = b">r0\nACGT\n";
+    /// let qual = b">r0\n40 40 30 20\n";
+    /// let reader = fasta_qual::Reader::new(&fasta[..], &qual[..]);
+    /// ```
+    pub fn new(fasta: F, qual: Q) -> Self {
+        Self {
+            fasta,
+            qual,
+            qual_definition: Definition::default(),
+            buf: Vec::new(),
+        }
+    }
+
+    /// Reads a FASTA+QUAL record pair as a FASTQ record.
+    ///
+    /// Multiline FASTA sequences and QUAL scores are supported. The quality scores of the
+    /// resulting record are encoded as Phred+33.
+    ///
+    /// If successful, the total number of bytes read from both streams is returned. If the number
+    /// of bytes read is 0, both streams reached EOF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::{self as fastq, io::fasta_qual};
+    ///
+    /// let fasta = b">r0 LN:4\nAC\nGT\n";
+    /// let qual = b">r0 LN:4\n40 40\n30 20\n";
+    /// let mut reader = fasta_qual::Reader::new(&fasta[..], &qual[..]);
+    ///
+    /// let mut record = fastq::Record::default();
+    /// reader.read_record(&mut record)?;
+    ///
+    /// assert_eq!(record.name(), &b"r0"[..]);
+    /// assert_eq!(record.description(), &b"LN:4"[..]);
+    /// assert_eq!(record.sequence(), b"ACGT");
+    /// assert_eq!(record.quality_scores(), b"II?5");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
+        record.definition_mut().clear();
+        record.sequence_mut().clear();
+        record.quality_scores_mut().clear();
+
+        let mut fasta_len = read_definition(&mut self.fasta, record.definition_mut())?;
+
+        self.qual_definition.clear();
+        let mut qual_len = read_definition(&mut self.qual, &mut self.qual_definition)?;
+
+        match (fasta_len, qual_len) {
+            (0, 0) => return Ok(0),
+            (_, 0) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "missing QUAL record",
+                ))
+            }
+            (0, _) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "missing FASTA record",
+                ))
+            }
+            _ => {}
+        }
+
+        if record.name() != self.qual_definition.name() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "FASTA and QUAL record name mismatch: {} != {}",
+                    record.name(),
+                    self.qual_definition.name()
+                ),
+            ));
+        }
+
+        fasta_len += read_body(&mut self.fasta, record.sequence_mut(), None)?;
+
+        self.buf.clear();
+        qual_len += read_body(&mut self.qual, &mut self.buf, Some(b' '))?;
+        parse_quality_scores(&self.buf, record.quality_scores_mut())?;
+
+        if record.sequence().len() != record.quality_scores().len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "sequence length and quality scores length mismatch: {} != {}",
+                    record.sequence().len(),
+                    record.quality_scores().len()
+                ),
+            ));
+        }
+
+        Ok(fasta_len + qual_len)
+    }
+
+    /// Returns an iterator over records starting from the current stream positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::io::fasta_qual;
+    ///
+    /// let fasta = b">r0\nACGT\n>r1\nA\n";
+    /// let qual = b">r0\n40 40 30 20\n>r1\n8\n";
+    /// let mut reader = fasta_qual::Reader::new(&fasta[..], &qual[..]);
+    ///
+    /// let records: Vec<_> = reader.records().collect::<io::Result<_>>()?;
+    /// assert_eq!(records.len(), 2);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn records(&mut self) -> impl Iterator<Item = io::Result<Record>> + '_ {
+        let mut record = Record::default();
+
+        iter::from_fn(move || match self.read_record(&mut record) {
+            Ok(0) => None,
+            Ok(_) => Some(Ok(record.clone())),
+            Err(e) => Some(Err(e)),
+        })
+    }
+}
+
+fn read_definition<R>(reader: &mut R, definition: &mut Definition) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    let mut buf = Vec::new();
+    let n = read_line(reader, &mut buf)?;
+
+    if n == 0 {
+        return Ok(0);
+    }
+
+    let line = buf
+        .strip_prefix(&[DEFINITION_PREFIX])
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid definition prefix"))?;
+
+    match line.iter().position(|&b| b == b' ' || b == b'\t') {
+        Some(i) => {
+            definition.name_mut().extend(&line[..i]);
+            definition.description_mut().extend(&line[i + 1..]);
+        }
+        None => definition.name_mut().extend(line),
+    }
+
+    Ok(n)
+}
+
+fn read_body<R>(reader: &mut R, dst: &mut Vec<u8>, line_separator: Option<u8>) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    let mut len = 0;
+
+    loop {
+        let src = reader.fill_buf()?;
+
+        if src.is_empty() || src[0] == DEFINITION_PREFIX {
+            break;
+        }
+
+        len += read_line(reader, dst)?;
+
+        if let Some(separator) = line_separator {
+            dst.push(separator);
+        }
+    }
+
+    Ok(len)
+}
+
+fn read_line<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    let n = reader.read_until(LINE_FEED, buf)?;
+
+    if buf.ends_with(&[LINE_FEED]) {
+        buf.pop();
+
+        if buf.ends_with(&[CARRIAGE_RETURN]) {
+            buf.pop();
+        }
+    }
+
+    Ok(n)
+}
+
+fn parse_quality_scores(src: &[u8], dst: &mut Vec<u8>) -> io::Result<()> {
+    const MAX_SCORE: u8 = b'~' - PHRED_OFFSET;
+
+    for raw_score in src.split(|b| b.is_ascii_whitespace()) {
+        if raw_score.is_empty() {
+            continue;
+        }
+
+        let score = std::str::from_utf8(raw_score)
+            .ok()
+            .and_then(|s| s.parse::<u8>().ok())
+            .filter(|&n| n <= MAX_SCORE)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid quality score"))?;
+
+        dst.push(score + PHRED_OFFSET);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_record() -> io::Result<()> {
+        let fasta = b">r0\nAC\r\nGT\n>r1 d\nA\n";
+        let qual = b">r0\n40 40\r\n30  20\n>r1 d\n0\n";
+        let mut reader = Reader::new(&fasta[..], &qual[..]);
+
+        let mut record = Record::default();
+
+        reader.read_record(&mut record)?;
+        assert_eq!(
+            record,
+            Record::new(Definition::new("r0", ""), "ACGT", "II?5")
+        );
+
+        reader.read_record(&mut record)?;
+        assert_eq!(record, Record::new(Definition::new("r1", "d"), "A", "!"));
+
+        assert_eq!(reader.read_record(&mut record)?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_invalid_input() {
+        fn t(fasta: &[u8], qual: &[u8]) {
+            let mut reader = Reader::new(fasta, qual);
+            let mut record = Record::default();
+
+            assert!(matches!(
+                reader.read_record(&mut record),
+                Err(e) if e.kind() == io::ErrorKind::InvalidData
+            ));
+        }
+
+        // name mismatch
+        t(b">r0\nA\n", b">r1\n40\n");
+        // length mismatch
+        t(b">r0\nAC\n", b">r0\n40\n");
+        // missing QUAL record
+        t(b">r0\nA\n", b"");
+        // missing FASTA record
+        t(b"", b">r0\n40\n");
+        // invalid quality score
+        t(b">r0\nA\n", b">r0\n94\n");
+        t(b">r0\nA\n", b">r0\nN\n");
+        // invalid definition prefix
+        t(b"r0\nA\n", b">r0\n40\n");
+    }
+}
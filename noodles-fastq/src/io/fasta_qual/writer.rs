@@ -0,0 +1,182 @@
+use std::io::{self, Write};
+
+use super::{DEFINITION_PREFIX, PHRED_OFFSET};
+use crate::Record;
+
+const LINE_FEED: &[u8] = b"\n";
+
+/// A FASTA+QUAL writer.
+///
+/// This writes the sequence of each FASTQ record to a FASTA stream and its quality scores, as
+/// decimal Phred scores, to a QUAL stream. The quality scores of the input records are expected
+/// to be encoded as Phred+33.
+pub struct Writer<F, Q> {
+    fasta: F,
+    qual: Q,
+}
+
+impl<F, Q> Writer<F, Q> {
+    /// Returns references to the underlying FASTA and QUAL writers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::io::fasta_qual;
+    /// let writer = fasta_qual::Writer::new(io::sink(), io::sink());
+    /// let (_fasta, _qual) = writer.get_ref();
+    /// ```
+    pub fn get_ref(&self) -> (&F, &Q) {
+        (&self.fasta, &self.qual)
+    }
+
+    /// Returns mutable references to the underlying FASTA and QUAL writers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::io::fasta_qual;
+    /// let mut writer = fasta_qual::Writer::new(io::sink(), io::sink());
+    /// let (_fasta, _qual) = writer.get_mut();
+    /// ```
+    pub fn get_mut(&mut self) -> (&mut F, &mut Q) {
+        (&mut self.fasta, &mut self.qual)
+    }
+
+    /// Unwraps and returns the underlying FASTA and QUAL writers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::io::fasta_qual;
+    /// let writer = fasta_qual::Writer::new(io::sink(), io::sink());
+    /// let (_fasta, _qual) = writer.into_inner();
+    /// ```
+    pub fn into_inner(self) -> (F, Q) {
+        (self.fasta, self.qual)
+    }
+}
+
+impl<F, Q> Writer<F, Q>
+where
+    F: Write,
+    Q: Write,
+{
+    /// Creates a FASTA+QUAL writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::io::fasta_qual;
+    /// let writer = fasta_qual::Writer::new(io::sink(), io::sink());
+    /// ```
+    pub fn new(fasta: F, qual: Q) -> Self {
+        Self { fasta, qual }
+    }
+
+    /// Writes a FASTQ record as a FASTA+QUAL record pair.
+    ///
+    /// The sequence and quality scores are each written on a single line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::{self as fastq, io::fasta_qual, record::Definition};
+    ///
+    /// let mut writer = fasta_qual::Writer::new(Vec::new(), Vec::new());
+    ///
+    /// let record = fastq::Record::new(Definition::new("r0", ""), "ACGT", "II?5");
+    /// writer.write_record(&record)?;
+    ///
+    /// let (fasta, qual) = writer.get_ref();
+    /// assert_eq!(fasta, b">r0\nACGT\n");
+    /// assert_eq!(qual, b">r0\n40 40 30 20\n");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
+        write_definition(&mut self.fasta, record)?;
+        self.fasta.write_all(record.sequence())?;
+        self.fasta.write_all(LINE_FEED)?;
+
+        write_definition(&mut self.qual, record)?;
+        write_quality_scores(&mut self.qual, record.quality_scores())?;
+        self.qual.write_all(LINE_FEED)?;
+
+        Ok(())
+    }
+}
+
+fn write_definition<W>(writer: &mut W, record: &Record) -> io::Result<()>
+where
+    W: Write,
+{
+    writer.write_all(&[DEFINITION_PREFIX])?;
+    writer.write_all(record.name())?;
+
+    if !record.description().is_empty() {
+        writer.write_all(b" ")?;
+        writer.write_all(record.description())?;
+    }
+
+    writer.write_all(LINE_FEED)
+}
+
+fn write_quality_scores<W>(writer: &mut W, quality_scores: &[u8]) -> io::Result<()>
+where
+    W: Write,
+{
+    for (i, &raw_score) in quality_scores.iter().enumerate() {
+        let score = raw_score
+            .checked_sub(PHRED_OFFSET)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid quality score"))?;
+
+        if i > 0 {
+            writer.write_all(b" ")?;
+        }
+
+        write!(writer, "{score}")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{io::fasta_qual::Reader, record::Definition};
+
+    #[test]
+    fn test_write_record() -> io::Result<()> {
+        let records = [
+            Record::new(Definition::new("r0", "LN:4"), "ACGT", "II?5"),
+            Record::new(Definition::new("r1", ""), "", ""),
+        ];
+
+        let mut writer = Writer::new(Vec::new(), Vec::new());
+
+        for record in &records {
+            writer.write_record(record)?;
+        }
+
+        let (fasta, qual) = writer.into_inner();
+        assert_eq!(fasta, b">r0 LN:4\nACGT\n>r1\n\n");
+        assert_eq!(qual, b">r0 LN:4\n40 40 30 20\n>r1\n\n");
+
+        let mut reader = Reader::new(&fasta[..], &qual[..]);
+        let actual: Vec<_> = reader.records().collect::<io::Result<_>>()?;
+        assert_eq!(actual, records);
+
+        let mut writer = Writer::new(Vec::new(), Vec::new());
+        let record = Record::new(Definition::new("r0", ""), "A", " ");
+        assert!(matches!(
+            writer.write_record(&record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,231 @@
+//! Indexed FASTQ reader.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::{
+    collections::HashMap,
+    io::{self, BufRead, Seek, SeekFrom},
+};
+
+use super::Reader;
+use crate::{fai, record::Definition, Record};
+
+/// An indexed FASTQ reader.
+///
+/// This uses a FASTQ index (`samtools fqidx`) to randomly access records by name.
+pub struct IndexedReader<R> {
+    inner: Reader<R>,
+    index: fai::Index,
+    names: HashMap<String, usize>,
+}
+
+impl<R> IndexedReader<R>
+where
+    R: BufRead,
+{
+    /// Creates an indexed FASTQ reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::{self as fastq, fai};
+    /// let reader = fastq::io::IndexedReader::new(&[][..], fai::Index::new());
+    /// ```
+    pub fn new(inner: R, index: fai::Index) -> Self {
+        let names = index
+            .iter()
+            .enumerate()
+            .map(|(i, record)| (record.name().into(), i))
+            .collect();
+
+        Self {
+            inner: Reader::new(inner),
+            index,
+            names,
+        }
+    }
+
+    /// Returns a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        self.inner.get_ref()
+    }
+
+    /// Returns a mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        self.inner.get_mut()
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.inner.into_inner()
+    }
+
+    /// Reads a FASTQ record.
+    pub fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
+        self.inner.read_record(record)
+    }
+
+    /// Returns the associated index.
+    pub fn index(&self) -> &fai::Index {
+        &self.index
+    }
+}
+
+impl<R> IndexedReader<R>
+where
+    R: BufRead + Seek,
+{
+    /// Returns the record with the given name.
+    ///
+    /// The sequence and quality scores are read using the offsets in the index. The record
+    /// description is not stored in the index and is left empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_fastq::{self as fastq, fai};
+    ///
+    /// let data = b"@r0\nACGT\n+\nNDLS\n@r1\nTGCA\n+\nSLDN\n";
+    /// let index = vec![
+    ///     fai::Record::new("r0", 4, 4, 4, 5, 11),
+    ///     fai::Record::new("r1", 4, 20, 4, 5, 27),
+    /// ];
+    ///
+    /// let mut reader = fastq::io::IndexedReader::new(Cursor::new(data), index);
+    /// let record = reader.query("r1")?;
+    ///
+    /// assert_eq!(record.name(), &b"r1"[..]);
+    /// assert_eq!(record.sequence(), b"TGCA");
+    /// assert_eq!(record.quality_scores(), b"SLDN");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn query<N>(&mut self, name: N) -> io::Result<Record>
+    where
+        N: AsRef<[u8]>,
+    {
+        let name = name.as_ref();
+
+        let index_record = std::str::from_utf8(name)
+            .ok()
+            .and_then(|name| self.names.get(name))
+            .map(|&i| &self.index[i])
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("missing record: {}", String::from_utf8_lossy(name)),
+                )
+            })?;
+
+        let len = usize::try_from(index_record.length())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let reader = self.inner.get_mut();
+
+        reader.seek(SeekFrom::Start(index_record.sequence_offset()))?;
+        let sequence = read_lines(reader, len)?;
+
+        reader.seek(SeekFrom::Start(index_record.quality_scores_offset()))?;
+        let quality_scores = read_lines(reader, len)?;
+
+        Ok(Record::new(
+            Definition::new(name, ""),
+            sequence,
+            quality_scores,
+        ))
+    }
+}
+
+fn read_lines<R>(reader: &mut R, len: usize) -> io::Result<Vec<u8>>
+where
+    R: BufRead,
+{
+    const LINE_FEED: u8 = b'\n';
+    const CARRIAGE_RETURN: u8 = b'\r';
+
+    let mut buf = Vec::with_capacity(len);
+
+    while buf.len() < len {
+        let start = buf.len();
+
+        if reader.read_until(LINE_FEED, &mut buf)? == 0 {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+        }
+
+        if buf.ends_with(&[LINE_FEED]) {
+            buf.pop();
+
+            if buf.ends_with(&[CARRIAGE_RETURN]) {
+                buf.pop();
+            }
+        }
+
+        if buf.len() == start {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "unexpected empty line",
+            ));
+        }
+    }
+
+    buf.truncate(len);
+
+    Ok(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn test_query() -> io::Result<()> {
+        const DATA: &[u8] = b"\
+@r0
+ACGT
++
+NDLS
+@r1 LN:10
+ACGTACGTAC
++r1
+NDLSNDLSND
+@r2
+TG
++
+SL
+";
+
+        let mut indexer = crate::io::Indexer::new(DATA);
+        let mut index = fai::Index::new();
+
+        while let Some(record) = indexer.index_record()? {
+            index.push(record);
+        }
+
+        let mut reader = IndexedReader::new(Cursor::new(DATA), index);
+
+        let record = reader.query("r1")?;
+        assert_eq!(record.name(), &b"r1"[..]);
+        assert!(record.description().is_empty());
+        assert_eq!(record.sequence(), b"ACGTACGTAC");
+        assert_eq!(record.quality_scores(), b"NDLSNDLSND");
+
+        let record = reader.query("r0")?;
+        assert_eq!(record.sequence(), b"ACGT");
+        assert_eq!(record.quality_scores(), b"NDLS");
+
+        let record = reader.query("r2")?;
+        assert_eq!(record.sequence(), b"TG");
+        assert_eq!(record.quality_scores(), b"SL");
+
+        assert!(matches!(
+            reader.query("r3"),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,333 @@
+//! Paired-end FASTQ reader.
+
+mod records;
+
+pub use self::records::Records;
+
+use std::{
+    error, fmt,
+    io::{self, BufRead},
+};
+
+use bstr::{BStr, BString};
+
+use super::Reader;
+use crate::Record;
+
+/// A paired-end FASTQ reader.
+///
+/// This reads records from two synchronized streams, i.e., read 1 (R1) and read 2 (R2), and
+/// verifies that each pair of records have the same read name.
+///
+/// Read names are compared after removing a trailing `/1` (R1) or `/2` (R2) mate suffix. Casava
+/// 1.8+ read numbers (e.g., `1:N:0:ATCACG`) are part of the description and are not compared.
+pub struct PairedReader<R1, R2> {
+    r1: Reader<R1>,
+    r2: Reader<R2>,
+}
+
+impl<R1, R2> PairedReader<R1, R2> {
+    /// Returns references to the underlying R1 and R2 readers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    ///
+    /// let reader = fastq::io::PairedReader::new(
+    ///     fastq::io::Reader::new(io::empty()),
+    ///     fastq::io::Reader::new(io::empty()),
+    /// );
+    ///
+    /// let (_r1, _r2) = reader.get_ref();
+    /// ```
+    pub fn get_ref(&self) -> (&Reader<R1>, &Reader<R2>) {
+        (&self.r1, &self.r2)
+    }
+
+    /// Returns mutable references to the underlying R1 and R2 readers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    ///
+    /// let mut reader = fastq::io::PairedReader::new(
+    ///     fastq::io::Reader::new(io::empty()),
+    ///     fastq::io::Reader::new(io::empty()),
+    /// );
+    ///
+    /// let (_r1, _r2) = reader.get_mut();
+    /// ```
+    pub fn get_mut(&mut self) -> (&mut Reader<R1>, &mut Reader<R2>) {
+        (&mut self.r1, &mut self.r2)
+    }
+
+    /// Unwraps and returns the underlying R1 and R2 readers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    ///
+    /// let reader = fastq::io::PairedReader::new(
+    ///     fastq::io::Reader::new(io::empty()),
+    ///     fastq::io::Reader::new(io::empty()),
+    /// );
+    ///
+    /// let (_r1, _r2) = reader.into_inner();
+    /// ```
+    pub fn into_inner(self) -> (Reader<R1>, Reader<R2>) {
+        (self.r1, self.r2)
+    }
+}
+
+impl<R1, R2> PairedReader<R1, R2>
+where
+    R1: BufRead,
+    R2: BufRead,
+{
+    /// Creates a paired-end FASTQ reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    ///
+    /// let r1 = fastq::io::Reader::new(&b"@r0/1\nATCG\n+\nNDLS\n"[..]);
+    /// let r2 = fastq::io::Reader::new(&b"@r0/2\nCGAT\n+\nSLDN\n"[..]);
+    /// let reader = fastq::io::PairedReader::new(r1, r2);
+    /// ```
+    pub fn new(r1: Reader<R1>, r2: Reader<R2>) -> Self {
+        Self { r1, r2 }
+    }
+
+    /// Reads a pair of FASTQ records.
+    ///
+    /// If successful, the total number of bytes read from both streams is returned. If the number
+    /// of bytes read is 0, both streams reached EOF.
+    ///
+    /// An error with kind [`io::ErrorKind::InvalidData`] and a [`PairingError`] payload is
+    /// returned if the read names do not match or one stream ends before the other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    ///
+    /// let r1 = fastq::io::Reader::new(&b"@r0/1\nATCG\n+\nNDLS\n"[..]);
+    /// let r2 = fastq::io::Reader::new(&b"@r0/2\nCGAT\n+\nSLDN\n"[..]);
+    /// let mut reader = fastq::io::PairedReader::new(r1, r2);
+    ///
+    /// let mut record1 = fastq::Record::default();
+    /// let mut record2 = fastq::Record::default();
+    /// reader.read_record_pair(&mut record1, &mut record2)?;
+    ///
+    /// assert_eq!(record1.name(), &b"r0/1"[..]);
+    /// assert_eq!(record2.name(), &b"r0/2"[..]);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn read_record_pair(
+        &mut self,
+        record1: &mut Record,
+        record2: &mut Record,
+    ) -> io::Result<usize> {
+        let n1 = self.r1.read_record(record1)?;
+        let n2 = self.r2.read_record(record2)?;
+
+        validate_pair(n1, record1, n2, record2)?;
+
+        Ok(n1 + n2)
+    }
+
+    /// Returns an iterator over record pairs starting from the current stream positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    ///
+    /// let r1 = fastq::io::Reader::new(&b"@r0/1\nATCG\n+\nNDLS\n"[..]);
+    /// let r2 = fastq::io::Reader::new(&b"@r0/2\nCGAT\n+\nSLDN\n"[..]);
+    /// let mut reader = fastq::io::PairedReader::new(r1, r2);
+    ///
+    /// for result in reader.records() {
+    ///     let (record1, record2) = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn records(&mut self) -> Records<'_, R1, R2> {
+        Records::new(self)
+    }
+}
+
+/// An error returned when paired-end FASTQ streams are not synchronized.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PairingError {
+    /// The R1 and R2 read names do not match.
+    NameMismatch {
+        /// The R1 read name.
+        name1: BString,
+        /// The R2 read name.
+        name2: BString,
+    },
+    /// R1 has a record, but R2 is at EOF.
+    MissingRecord2 {
+        /// The R1 read name.
+        name1: BString,
+    },
+    /// R2 has a record, but R1 is at EOF.
+    MissingRecord1 {
+        /// The R2 read name.
+        name2: BString,
+    },
+}
+
+impl error::Error for PairingError {}
+
+impl fmt::Display for PairingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NameMismatch { name1, name2 } => {
+                write!(f, "read name mismatch: R1 = {name1}, R2 = {name2}")
+            }
+            Self::MissingRecord2 { name1 } => {
+                write!(f, "missing R2 record: unexpected EOF after R1 = {name1}")
+            }
+            Self::MissingRecord1 { name2 } => {
+                write!(f, "missing R1 record: unexpected EOF after R2 = {name2}")
+            }
+        }
+    }
+}
+
+pub(crate) fn validate_pair(
+    n1: usize,
+    record1: &Record,
+    n2: usize,
+    record2: &Record,
+) -> io::Result<()> {
+    let result = match (n1, n2) {
+        (0, 0) => Ok(()),
+        (_, 0) => Err(PairingError::MissingRecord2 {
+            name1: record1.name().into(),
+        }),
+        (0, _) => Err(PairingError::MissingRecord1 {
+            name2: record2.name().into(),
+        }),
+        _ => {
+            if is_mate_name_pair(record1.name(), record2.name()) {
+                Ok(())
+            } else {
+                Err(PairingError::NameMismatch {
+                    name1: record1.name().into(),
+                    name2: record2.name().into(),
+                })
+            }
+        }
+    };
+
+    result.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+fn is_mate_name_pair(name1: &BStr, name2: &BStr) -> bool {
+    const READ_1_SUFFIX: &[u8] = b"/1";
+    const READ_2_SUFFIX: &[u8] = b"/2";
+
+    let name1 = name1.strip_suffix(READ_1_SUFFIX).unwrap_or(name1);
+    let name2 = name2.strip_suffix(READ_2_SUFFIX).unwrap_or(name2);
+
+    name1 == name2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::Definition;
+
+    #[test]
+    fn test_read_record_pair() -> io::Result<()> {
+        let r1 = Reader::new(&b"@r0/1\nATCG\n+\nNDLS\n@r1 1:N:0:ATCACG\nA\n+\nN\n"[..]);
+        let r2 = Reader::new(&b"@r0/2\nCGAT\n+\nSLDN\n@r1 2:N:0:ATCACG\nT\n+\nS\n"[..]);
+        let mut reader = PairedReader::new(r1, r2);
+
+        let mut record1 = Record::default();
+        let mut record2 = Record::default();
+
+        reader.read_record_pair(&mut record1, &mut record2)?;
+        assert_eq!(
+            record1,
+            Record::new(Definition::new("r0/1", ""), "ATCG", "NDLS")
+        );
+        assert_eq!(
+            record2,
+            Record::new(Definition::new("r0/2", ""), "CGAT", "SLDN")
+        );
+
+        reader.read_record_pair(&mut record1, &mut record2)?;
+        assert_eq!(record1.description(), &b"1:N:0:ATCACG"[..]);
+        assert_eq!(record2.description(), &b"2:N:0:ATCACG"[..]);
+
+        assert_eq!(reader.read_record_pair(&mut record1, &mut record2)?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_pair_with_desynchronized_streams() {
+        fn read_pair_error(r1: &[u8], r2: &[u8]) -> Option<PairingError> {
+            let mut reader = PairedReader::new(Reader::new(r1), Reader::new(r2));
+
+            reader
+                .read_record_pair(&mut Record::default(), &mut Record::default())
+                .err()
+                .and_then(|e| e.into_inner())
+                .and_then(|e| e.downcast().ok())
+                .map(|e| *e)
+        }
+
+        assert_eq!(
+            read_pair_error(b"@r0/1\nA\n+\nN\n", b"@r1/2\nA\n+\nN\n"),
+            Some(PairingError::NameMismatch {
+                name1: BString::from("r0/1"),
+                name2: BString::from("r1/2"),
+            })
+        );
+
+        assert_eq!(
+            read_pair_error(b"@r0/1\nA\n+\nN\n", b"@r0/1\nA\n+\nN\n"),
+            Some(PairingError::NameMismatch {
+                name1: BString::from("r0/1"),
+                name2: BString::from("r0/1"),
+            })
+        );
+
+        assert_eq!(
+            read_pair_error(b"@r0/1\nA\n+\nN\n", b""),
+            Some(PairingError::MissingRecord2 {
+                name1: BString::from("r0/1"),
+            })
+        );
+
+        assert_eq!(
+            read_pair_error(b"", b"@r0/2\nA\n+\nN\n"),
+            Some(PairingError::MissingRecord1 {
+                name2: BString::from("r0/2"),
+            })
+        );
+    }
+
+    #[test]
+    fn test_is_mate_name_pair() {
+        assert!(is_mate_name_pair(b"r0".into(), b"r0".into()));
+        assert!(is_mate_name_pair(b"r0/1".into(), b"r0/2".into()));
+        assert!(!is_mate_name_pair(b"r0/2".into(), b"r0/1".into()));
+        assert!(!is_mate_name_pair(b"r0".into(), b"r1".into()));
+    }
+}
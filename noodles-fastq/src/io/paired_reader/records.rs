@@ -0,0 +1,44 @@
+use std::io::{self, BufRead};
+
+use crate::Record;
+
+use super::PairedReader;
+
+/// An iterator over record pairs of a paired-end FASTQ reader.
+///
+/// This is created by calling [`PairedReader::records`].
+pub struct Records<'a, R1, R2> {
+    inner: &'a mut PairedReader<R1, R2>,
+    buf1: Record,
+    buf2: Record,
+}
+
+impl<'a, R1, R2> Records<'a, R1, R2>
+where
+    R1: BufRead,
+    R2: BufRead,
+{
+    pub(super) fn new(inner: &'a mut PairedReader<R1, R2>) -> Self {
+        Self {
+            inner,
+            buf1: Record::default(),
+            buf2: Record::default(),
+        }
+    }
+}
+
+impl<'a, R1, R2> Iterator for Records<'a, R1, R2>
+where
+    R1: BufRead,
+    R2: BufRead,
+{
+    type Item = io::Result<(Record, Record)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.inner.read_record_pair(&mut self.buf1, &mut self.buf2) {
+            Ok(0) => None,
+            Ok(_) => Some(Ok((self.buf1.clone(), self.buf2.clone()))),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
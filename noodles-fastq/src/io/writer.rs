@@ -1,12 +1,13 @@
 //! FASTQ writer.
 
 mod builder;
+mod options;
 mod record;
 
 use std::io::{self, Write};
 
 pub use self::builder::Builder;
-use self::record::write_record;
+use self::{options::Options, record::write_record};
 use crate::Record;
 
 pub(crate) const DEFAULT_DEFINITION_SEPARATOR: u8 = b' ';
@@ -14,7 +15,7 @@ pub(crate) const DEFAULT_DEFINITION_SEPARATOR: u8 = b' ';
 /// A FASTQ writer.
 pub struct Writer<W> {
     inner: W,
-    definition_separator: u8,
+    options: Options,
 }
 
 impl<W> Writer<W> {
@@ -76,7 +77,7 @@ where
     pub fn new(inner: W) -> Self {
         Self {
             inner,
-            definition_separator: DEFAULT_DEFINITION_SEPARATOR,
+            options: Options::default(),
         }
     }
 
@@ -97,6 +98,6 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
-        write_record(&mut self.inner, self.definition_separator, record)
+        write_record(&mut self.inner, &self.options, record)
     }
 }
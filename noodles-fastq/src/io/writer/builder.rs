@@ -4,11 +4,12 @@ use std::{
     path::Path,
 };
 
-use super::{Writer, DEFAULT_DEFINITION_SEPARATOR};
+use super::{Options, Writer};
 
 /// A FASTQ writer builder.
+#[derive(Default)]
 pub struct Builder {
-    definition_separator: u8,
+    options: Options,
 }
 
 impl Builder {
@@ -23,7 +24,70 @@ impl Builder {
     /// let builder = Builder::default().set_definition_separator(b'\t');
     /// ```
     pub fn set_definition_separator(mut self, definition_separator: u8) -> Self {
-        self.definition_separator = definition_separator;
+        self.options.definition_separator = definition_separator;
+        self
+    }
+
+    /// Sets whether to write record descriptions.
+    ///
+    /// By default, a nonempty description is written after the name, separated by the
+    /// definition separator. When disabled, only the name is written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::{self as fastq, io::writer::Builder, record::Definition};
+    ///
+    /// let mut writer = Builder::default()
+    ///     .set_write_descriptions(false)
+    ///     .build_from_writer(Vec::new());
+    ///
+    /// let record = fastq::Record::new(Definition::new("r0", "LN:4"), "ACGT", "NDLS");
+    /// writer.write_record(&record)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn set_write_descriptions(mut self, write_descriptions: bool) -> Self {
+        self.options.write_descriptions = write_descriptions;
+        self
+    }
+
+    /// Sets whether the quality scores header (`+`) line repeats the record definition.
+    ///
+    /// By default, the line is only `+`. When enabled, it is followed by the same name and, if
+    /// written, description as the name line, e.g., `+r0 LN:4`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::io::writer::Builder;
+    /// let builder = Builder::default().set_repeat_definition(true);
+    /// ```
+    pub fn set_repeat_definition(mut self, repeat_definition: bool) -> Self {
+        self.options.repeat_definition = repeat_definition;
+        self
+    }
+
+    /// Sets whether to validate that the sequence and quality scores have the same length.
+    ///
+    /// By default, lengths are not validated. When enabled, writing a record with mismatched
+    /// lengths returns an [`std::io::ErrorKind::InvalidInput`] error, and nothing is written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::{self as fastq, io::writer::Builder, record::Definition};
+    ///
+    /// let mut writer = Builder::default()
+    ///     .set_validate_lengths(true)
+    ///     .build_from_writer(Vec::new());
+    ///
+    /// let record = fastq::Record::new(Definition::new("r0", ""), "ACGT", "NDL");
+    /// assert!(writer.write_record(&record).is_err());
+    /// ```
+    pub fn set_validate_lengths(mut self, validate_lengths: bool) -> Self {
+        self.options.validate_lengths = validate_lengths;
         self
     }
 
@@ -60,15 +124,7 @@ impl Builder {
     {
         Writer {
             inner: Box::new(writer),
-            definition_separator: self.definition_separator,
-        }
-    }
-}
-
-impl Default for Builder {
-    fn default() -> Self {
-        Self {
-            definition_separator: DEFAULT_DEFINITION_SEPARATOR,
+            options: self.options,
         }
     }
 }
@@ -80,6 +136,9 @@ mod tests {
     #[test]
     fn test_default() {
         let builder = Builder::default();
-        assert_eq!(builder.definition_separator, b' ');
+        assert_eq!(builder.options.definition_separator, b' ');
+        assert!(builder.options.write_descriptions);
+        assert!(!builder.options.repeat_definition);
+        assert!(!builder.options.validate_lengths);
     }
 }
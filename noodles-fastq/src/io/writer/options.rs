@@ -0,0 +1,20 @@
+use super::DEFAULT_DEFINITION_SEPARATOR;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) struct Options {
+    pub definition_separator: u8,
+    pub write_descriptions: bool,
+    pub repeat_definition: bool,
+    pub validate_lengths: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            definition_separator: DEFAULT_DEFINITION_SEPARATOR,
+            write_descriptions: true,
+            repeat_definition: false,
+            validate_lengths: false,
+        }
+    }
+}
@@ -1,35 +1,59 @@
 use std::io::{self, Write};
 
+use super::Options;
 use crate::Record;
 
-pub(super) fn write_record<W>(
-    writer: &mut W,
-    definition_separator: u8,
-    record: &Record,
-) -> io::Result<()>
+const LINE_FEED: &[u8] = b"\n";
+
+pub(super) fn write_record<W>(writer: &mut W, options: &Options, record: &Record) -> io::Result<()>
 where
     W: Write,
 {
     const NAME_PREFIX: &[u8] = b"@";
-    const LINE_FEED: &[u8] = b"\n";
+    const QUALITY_SCORES_PREFIX: &[u8] = b"+";
+
+    if options.validate_lengths && record.sequence().len() != record.quality_scores().len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "sequence length ({}) != quality scores length ({})",
+                record.sequence().len(),
+                record.quality_scores().len()
+            ),
+        ));
+    }
 
     writer.write_all(NAME_PREFIX)?;
-    writer.write_all(record.name())?;
+    write_definition(writer, options, record)?;
 
-    if !record.description().is_empty() {
-        writer.write_all(&[definition_separator])?;
-        writer.write_all(record.description())?;
+    writer.write_all(record.sequence())?;
+    writer.write_all(LINE_FEED)?;
+
+    writer.write_all(QUALITY_SCORES_PREFIX)?;
+
+    if options.repeat_definition {
+        write_definition(writer, options, record)?;
+    } else {
+        writer.write_all(LINE_FEED)?;
     }
 
+    writer.write_all(record.quality_scores())?;
     writer.write_all(LINE_FEED)?;
 
-    writer.write_all(record.sequence())?;
-    writer.write_all(LINE_FEED)?;
+    Ok(())
+}
 
-    writer.write_all(b"+")?;
-    writer.write_all(LINE_FEED)?;
+fn write_definition<W>(writer: &mut W, options: &Options, record: &Record) -> io::Result<()>
+where
+    W: Write,
+{
+    writer.write_all(record.name())?;
+
+    if options.write_descriptions && !record.description().is_empty() {
+        writer.write_all(&[options.definition_separator])?;
+        writer.write_all(record.description())?;
+    }
 
-    writer.write_all(record.quality_scores())?;
     writer.write_all(LINE_FEED)?;
 
     Ok(())
@@ -43,28 +67,86 @@ mod tests {
     fn test_write_record() -> io::Result<()> {
         use crate::record::Definition;
 
-        const HORIZONTAL_TAB: u8 = b'\t';
-        const SPACE: u8 = b' ';
-
+        let mut options = Options::default();
         let mut record = Record::new(Definition::new("r0", ""), "ACGT", "NDLS");
 
         let mut buf = Vec::new();
-        write_record(&mut buf, SPACE, &record)?;
+        write_record(&mut buf, &options, &record)?;
         let expected = b"@r0\nACGT\n+\nNDLS\n";
         assert_eq!(buf, expected);
 
         record.description_mut().extend_from_slice(b"LN:4");
 
         buf.clear();
-        write_record(&mut buf, SPACE, &record)?;
+        write_record(&mut buf, &options, &record)?;
         let expected = b"@r0 LN:4\nACGT\n+\nNDLS\n";
         assert_eq!(buf, expected);
 
+        options.definition_separator = b'\t';
         buf.clear();
-        write_record(&mut buf, HORIZONTAL_TAB, &record)?;
+        write_record(&mut buf, &options, &record)?;
         let expected = b"@r0\tLN:4\nACGT\n+\nNDLS\n";
         assert_eq!(buf, expected);
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_record_with_options() -> io::Result<()> {
+        use crate::record::Definition;
+
+        let record = Record::new(Definition::new("r0", "LN:4"), "ACGT", "NDLS");
+        let mut buf = Vec::new();
+
+        let options = Options {
+            repeat_definition: true,
+            ..Default::default()
+        };
+        write_record(&mut buf, &options, &record)?;
+        assert_eq!(buf, b"@r0 LN:4\nACGT\n+r0 LN:4\nNDLS\n");
+
+        let options = Options {
+            write_descriptions: false,
+            repeat_definition: true,
+            ..Default::default()
+        };
+        buf.clear();
+        write_record(&mut buf, &options, &record)?;
+        assert_eq!(buf, b"@r0\nACGT\n+r0\nNDLS\n");
+
+        let options = Options {
+            write_descriptions: false,
+            ..Default::default()
+        };
+        buf.clear();
+        write_record(&mut buf, &options, &record)?;
+        assert_eq!(buf, b"@r0\nACGT\n+\nNDLS\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_length_validation() -> io::Result<()> {
+        use crate::record::Definition;
+
+        let options = Options {
+            validate_lengths: true,
+            ..Default::default()
+        };
+
+        let record = Record::new(Definition::new("r0", ""), "ACGT", "NDLS");
+        let mut buf = Vec::new();
+        write_record(&mut buf, &options, &record)?;
+        assert_eq!(buf, b"@r0\nACGT\n+\nNDLS\n");
+
+        let record = Record::new(Definition::new("r0", ""), "ACGT", "NDL");
+        buf.clear();
+        assert!(matches!(
+            write_record(&mut buf, &options, &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+        assert!(buf.is_empty());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,372 @@
+//! FASTQ record iterator adapters.
+//!
+//! These are composable adapters over iterators of `io::Result<Record>`, e.g., the iterator
+//! returned by [`crate::io::Reader::records`], for basic quality control. Errors are passed
+//! through unchanged.
+//!
+//! Quality scores are expected to be Phred quality scores offset by 33. Use
+//! [`RecordsExt::convert_quality_scores`] first to convert quality scores from other encodings.
+
+use std::io;
+
+use crate::{
+    quality_scores::{Binning, Encoding},
+    Record,
+};
+
+const QUALITY_SCORE_OFFSET: u8 = b'!';
+const MASKED_BASE: u8 = b'N';
+
+/// An extension trait for iterators over FASTQ records.
+pub trait RecordsExt: Iterator<Item = io::Result<Record>> + Sized {
+    /// Keeps only records with a sequence length in `[min, max]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::{self as fastq, iter::RecordsExt};
+    ///
+    /// let data = b"@r0\nA\n+\nN\n@r1\nACGT\n+\nNDLS\n";
+    /// let mut reader = fastq::io::Reader::new(&data[..]);
+    ///
+    /// let records: Vec<_> = reader.records().filter_by_length(2, 8).collect::<io::Result<_>>()?;
+    /// assert_eq!(records.len(), 1);
+    /// assert_eq!(records[0].name(), &b"r1"[..]);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    fn filter_by_length(self, min: usize, max: usize) -> FilterByLength<Self> {
+        FilterByLength {
+            inner: self,
+            min,
+            max,
+        }
+    }
+
+    /// Trims low quality bases from the 3' end of each record.
+    ///
+    /// This uses the same algorithm as BWA (`-q`): the read is cut at the position that maximizes
+    /// the sum of `threshold - q` from the end of the read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::{self as fastq, iter::RecordsExt};
+    ///
+    /// let data = b"@r0\nACGTAC\n+\nIIII##\n";
+    /// let mut reader = fastq::io::Reader::new(&data[..]);
+    ///
+    /// let records: Vec<_> = reader.records().trim_quality(20).collect::<io::Result<_>>()?;
+    /// assert_eq!(records[0].sequence(), b"ACGT");
+    /// assert_eq!(records[0].quality_scores(), b"IIII");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    fn trim_quality(self, threshold: u8) -> TrimQuality<Self> {
+        TrimQuality {
+            inner: self,
+            threshold,
+        }
+    }
+
+    /// Replaces bases with a quality score less than the threshold with `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::{self as fastq, iter::RecordsExt};
+    ///
+    /// let data = b"@r0\nACGT\n+\nI#I#\n";
+    /// let mut reader = fastq::io::Reader::new(&data[..]);
+    ///
+    /// let records: Vec<_> = reader.records().mask_low_quality(20).collect::<io::Result<_>>()?;
+    /// assert_eq!(records[0].sequence(), b"ANGN");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    fn mask_low_quality(self, threshold: u8) -> MaskLowQuality<Self> {
+        MaskLowQuality {
+            inner: self,
+            threshold,
+        }
+    }
+
+    /// Converts quality scores from the given encoding to Phred quality scores offset by 33.
+    ///
+    /// A record with a quality score that is out of range for the encoding results in an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::{self as fastq, iter::RecordsExt, quality_scores::Encoding};
+    ///
+    /// let data = b"@r0\nACGT\n+\n@Jhh\n";
+    /// let mut reader = fastq::io::Reader::new(&data[..]);
+    ///
+    /// let records: Vec<_> = reader
+    ///     .records()
+    ///     .convert_quality_scores(Encoding::Phred64)
+    ///     .collect::<io::Result<_>>()?;
+    ///
+    /// assert_eq!(records[0].quality_scores(), b"!+II");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    fn convert_quality_scores(self, encoding: Encoding) -> ConvertQualityScores<Self> {
+        ConvertQualityScores {
+            inner: self,
+            encoding,
+        }
+    }
+
+    /// Bins quality scores using the given binning scheme.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::{self as fastq, iter::RecordsExt, quality_scores::Binning};
+    ///
+    /// let data = b"@r0\nACGT\n+\n!+5I\n";
+    /// let mut reader = fastq::io::Reader::new(&data[..]);
+    ///
+    /// let records: Vec<_> = reader
+    ///     .records()
+    ///     .bin_quality_scores(Binning::illumina_8_level())
+    ///     .collect::<io::Result<_>>()?;
+    ///
+    /// assert_eq!(records[0].quality_scores(), b"!07I");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    fn bin_quality_scores(self, binning: Binning) -> BinQualityScores<Self> {
+        BinQualityScores {
+            inner: self,
+            binning,
+        }
+    }
+}
+
+impl<I> RecordsExt for I where I: Iterator<Item = io::Result<Record>> {}
+
+/// An iterator adapter that filters records by sequence length.
+///
+/// This is created by calling [`RecordsExt::filter_by_length`].
+pub struct FilterByLength<I> {
+    inner: I,
+    min: usize,
+    max: usize,
+}
+
+impl<I> Iterator for FilterByLength<I>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (min, max) = (self.min, self.max);
+
+        self.inner.find(|result| match result {
+            Ok(record) => (min..=max).contains(&record.sequence().len()),
+            Err(_) => true,
+        })
+    }
+}
+
+/// An iterator adapter that trims low quality bases from the 3' end of records.
+///
+/// This is created by calling [`RecordsExt::trim_quality`].
+pub struct TrimQuality<I> {
+    inner: I,
+    threshold: u8,
+}
+
+impl<I> Iterator for TrimQuality<I>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|result| {
+            result.map(|mut record| {
+                trim_quality(&mut record, self.threshold);
+                record
+            })
+        })
+    }
+}
+
+/// An iterator adapter that masks low quality bases.
+///
+/// This is created by calling [`RecordsExt::mask_low_quality`].
+pub struct MaskLowQuality<I> {
+    inner: I,
+    threshold: u8,
+}
+
+impl<I> Iterator for MaskLowQuality<I>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|result| {
+            result.map(|mut record| {
+                mask_low_quality(&mut record, self.threshold);
+                record
+            })
+        })
+    }
+}
+
+/// An iterator adapter that converts quality scores to Phred quality scores offset by 33.
+///
+/// This is created by calling [`RecordsExt::convert_quality_scores`].
+pub struct ConvertQualityScores<I> {
+    inner: I,
+    encoding: Encoding,
+}
+
+impl<I> Iterator for ConvertQualityScores<I>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|result| {
+            result.and_then(|mut record| {
+                self.encoding
+                    .to_phred33(record.quality_scores_mut())
+                    .map(|_| record)
+            })
+        })
+    }
+}
+
+/// An iterator adapter that bins quality scores.
+///
+/// This is created by calling [`RecordsExt::bin_quality_scores`].
+pub struct BinQualityScores<I> {
+    inner: I,
+    binning: Binning,
+}
+
+impl<I> Iterator for BinQualityScores<I>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|result| {
+            result.map(|mut record| {
+                self.binning.apply_phred33(record.quality_scores_mut());
+                record
+            })
+        })
+    }
+}
+
+fn trim_quality(record: &mut Record, threshold: u8) {
+    let end = trim_position(record.quality_scores(), threshold);
+    record.sequence_mut().truncate(end);
+    record.quality_scores_mut().truncate(end);
+}
+
+fn trim_position(quality_scores: &[u8], threshold: u8) -> usize {
+    let threshold = i32::from(threshold);
+
+    let mut sum = 0;
+    let mut max = 0;
+    let mut end = quality_scores.len();
+
+    for (i, &score) in quality_scores.iter().enumerate().rev() {
+        sum += threshold - i32::from(score.saturating_sub(QUALITY_SCORE_OFFSET));
+
+        if sum < 0 {
+            break;
+        }
+
+        if sum > max {
+            max = sum;
+            end = i;
+        }
+    }
+
+    end
+}
+
+fn mask_low_quality(record: &mut Record, threshold: u8) {
+    let quality_scores = record.quality_scores().to_vec();
+
+    for (base, score) in record.sequence_mut().iter_mut().zip(quality_scores) {
+        if score.saturating_sub(QUALITY_SCORE_OFFSET) < threshold {
+            *base = MASKED_BASE;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::Definition;
+
+    #[test]
+    fn test_filter_by_length() {
+        let records = vec![
+            Ok(Record::new(Definition::new("r0", ""), "A", "N")),
+            Err(io::Error::from(io::ErrorKind::InvalidData)),
+            Ok(Record::new(Definition::new("r1", ""), "ACGT", "NDLS")),
+            Ok(Record::new(
+                Definition::new("r2", ""),
+                "ACGTACGT",
+                "NDLSNDLS",
+            )),
+        ];
+
+        let mut iter = records.into_iter().filter_by_length(2, 4);
+
+        assert!(matches!(iter.next(), Some(Err(_))));
+        assert!(matches!(iter.next(), Some(Ok(record)) if record.name() == "r1"));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_trim_position() {
+        assert_eq!(trim_position(b"", 20), 0);
+        assert_eq!(trim_position(b"IIII", 20), 4);
+        assert_eq!(trim_position(b"IIII##", 20), 4);
+        assert_eq!(trim_position(b"II#I##", 20), 4);
+        assert_eq!(trim_position(b"II##I###", 20), 2);
+        assert_eq!(trim_position(b"####", 20), 0);
+    }
+
+    #[test]
+    fn test_convert_quality_scores() {
+        let records = vec![
+            Ok(Record::new(Definition::new("r0", ""), "AC", "@h")),
+            Ok(Record::new(Definition::new("r1", ""), "AC", "!!")),
+        ];
+
+        let mut iter = records
+            .into_iter()
+            .convert_quality_scores(Encoding::Phred64);
+
+        assert!(matches!(iter.next(), Some(Ok(record)) if record.quality_scores() == b"!I"));
+        assert!(matches!(
+            iter.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_mask_low_quality() {
+        let mut record = Record::new(Definition::new("r0", ""), "ACGT", "I#5#");
+        mask_low_quality(&mut record, 20);
+        assert_eq!(record.sequence(), b"ANGN");
+    }
+}
@@ -44,6 +44,8 @@ pub mod r#async;
 
 pub mod fai;
 pub mod io;
+pub mod iter;
+pub mod quality_scores;
 pub mod record;
 
 pub use self::record::Record;
@@ -0,0 +1,273 @@
+//! FASTQ quality score encodings and binning.
+//!
+//! FASTQ records in noodles use Phred quality scores offset by 33. This converts quality scores
+//! from legacy encodings and reduces their resolution by binning.
+
+use std::{io, ops::RangeInclusive};
+
+const PHRED_33_OFFSET: u8 = b'!';
+const PHRED_64_OFFSET: u8 = b'@';
+
+const MAX_SCORE: u8 = b'~';
+
+/// A FASTQ quality score encoding.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Encoding {
+    /// Phred quality scores offset by 33 (Sanger and Illumina 1.8+).
+    #[default]
+    Phred33,
+    /// Phred quality scores offset by 64 (Illumina 1.3–1.7).
+    Phred64,
+    /// Solexa quality scores offset by 64 (Solexa and Illumina 1.0).
+    ///
+    /// Solexa quality scores are odds-based and range from -5 to 62.
+    Solexa64,
+}
+
+impl Encoding {
+    /// Converts encoded quality scores to Phred quality scores offset by 33 in place.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if a quality score is out of range for the encoding. The quality
+    /// scores are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::quality_scores::Encoding;
+    ///
+    /// let mut quality_scores = b"@Jh".to_vec();
+    /// Encoding::Phred64.to_phred33(&mut quality_scores)?;
+    /// assert_eq!(quality_scores, b"!+I");
+    ///
+    /// let mut quality_scores = b";@h".to_vec();
+    /// Encoding::Solexa64.to_phred33(&mut quality_scores)?;
+    /// assert_eq!(quality_scores, b"\"$I");
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn to_phred33(self, quality_scores: &mut [u8]) -> io::Result<()> {
+        let min = match self {
+            Self::Phred33 => PHRED_33_OFFSET,
+            Self::Phred64 => PHRED_64_OFFSET,
+            Self::Solexa64 => PHRED_64_OFFSET - 5,
+        };
+
+        if quality_scores
+            .iter()
+            .any(|n| !(min..=MAX_SCORE).contains(n))
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid quality score",
+            ));
+        }
+
+        match self {
+            Self::Phred33 => {}
+            Self::Phred64 => {
+                for n in quality_scores {
+                    *n = *n - PHRED_64_OFFSET + PHRED_33_OFFSET;
+                }
+            }
+            Self::Solexa64 => {
+                for n in quality_scores {
+                    // `n` is in the ASCII range, so the casts are lossless.
+                    let score = *n as i8 - PHRED_64_OFFSET as i8;
+                    *n = solexa_to_phred(score) + PHRED_33_OFFSET;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Converts a Solexa quality score to a Phred quality score.
+///
+/// This is `10 * log10(10^(q / 10) + 1)`, rounded to the nearest integer.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fastq::quality_scores::solexa_to_phred;
+///
+/// assert_eq!(solexa_to_phred(-5), 1);
+/// assert_eq!(solexa_to_phred(0), 3);
+/// assert_eq!(solexa_to_phred(10), 10);
+/// assert_eq!(solexa_to_phred(40), 40);
+/// ```
+pub fn solexa_to_phred(score: i8) -> u8 {
+    let q = f64::from(score);
+    let phred = 10.0 * (10f64.powf(q / 10.0) + 1.0).log10();
+    phred.round() as u8
+}
+
+/// A static quality score binning scheme.
+///
+/// A binning maps raw Phred quality scores to a reduced set of representative scores, which
+/// improves compression, e.g., in CRAM. Scores outside of any bin are unchanged.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Binning {
+    table: Vec<u8>,
+}
+
+impl Binning {
+    /// Creates a binning scheme from a list of score ranges and their representative scores.
+    ///
+    /// If ranges overlap, the last range takes precedence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::quality_scores::Binning;
+    ///
+    /// let binning = Binning::new([(0..=19, 10), (20..=u8::MAX, 30)]);
+    ///
+    /// assert_eq!(binning.bin(8), 10);
+    /// assert_eq!(binning.bin(37), 30);
+    /// ```
+    pub fn new<I>(bins: I) -> Self
+    where
+        I: IntoIterator<Item = (RangeInclusive<u8>, u8)>,
+    {
+        let mut table: Vec<u8> = (0..=u8::MAX).collect();
+
+        for (range, score) in bins {
+            for n in range {
+                table[usize::from(n)] = score;
+            }
+        }
+
+        Self { table }
+    }
+
+    /// Creates the Illumina 8-level binning scheme.
+    ///
+    /// | Score range | Representative score |
+    /// | ----------- | -------------------- |
+    /// | 0–1         | unchanged            |
+    /// | 2–9         | 6                    |
+    /// | 10–19       | 15                   |
+    /// | 20–24       | 22                   |
+    /// | 25–29       | 27                   |
+    /// | 30–34       | 33                   |
+    /// | 35–39       | 37                   |
+    /// | ≥ 40        | 40                   |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::quality_scores::Binning;
+    ///
+    /// let binning = Binning::illumina_8_level();
+    ///
+    /// assert_eq!(binning.bin(0), 0);
+    /// assert_eq!(binning.bin(12), 15);
+    /// assert_eq!(binning.bin(41), 40);
+    /// ```
+    pub fn illumina_8_level() -> Self {
+        Self::new([
+            (2..=9, 6),
+            (10..=19, 15),
+            (20..=24, 22),
+            (25..=29, 27),
+            (30..=34, 33),
+            (35..=39, 37),
+            (40..=93, 40),
+        ])
+    }
+
+    /// Bins a raw Phred quality score.
+    pub fn bin(&self, score: u8) -> u8 {
+        self.table[usize::from(score)]
+    }
+
+    /// Bins raw Phred quality scores in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::quality_scores::Binning;
+    ///
+    /// let mut quality_scores = [8, 13, 21, 37];
+    /// Binning::illumina_8_level().apply(&mut quality_scores);
+    /// assert_eq!(quality_scores, [6, 15, 22, 37]);
+    /// ```
+    pub fn apply(&self, quality_scores: &mut [u8]) {
+        for n in quality_scores {
+            *n = self.bin(*n);
+        }
+    }
+
+    pub(crate) fn apply_phred33(&self, quality_scores: &mut [u8]) {
+        for n in quality_scores {
+            if let Some(score) = n.checked_sub(PHRED_33_OFFSET) {
+                *n = self.bin(score).saturating_add(PHRED_33_OFFSET);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_phred33() -> io::Result<()> {
+        let mut quality_scores = b"!5I".to_vec();
+        Encoding::Phred33.to_phred33(&mut quality_scores)?;
+        assert_eq!(quality_scores, b"!5I");
+
+        let mut quality_scores = b"@Th".to_vec();
+        Encoding::Phred64.to_phred33(&mut quality_scores)?;
+        assert_eq!(quality_scores, b"!5I");
+
+        let mut quality_scores = b"5".to_vec();
+        assert!(matches!(
+            Encoding::Phred64.to_phred33(&mut quality_scores),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+        assert_eq!(quality_scores, b"5");
+
+        let mut quality_scores = b";?@Th".to_vec();
+        Encoding::Solexa64.to_phred33(&mut quality_scores)?;
+        assert_eq!(quality_scores, b"\"$$5I");
+
+        let mut quality_scores = b":".to_vec();
+        assert!(Encoding::Solexa64.to_phred33(&mut quality_scores).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_solexa_to_phred() {
+        assert_eq!(solexa_to_phred(-5), 1);
+        assert_eq!(solexa_to_phred(-1), 3);
+        assert_eq!(solexa_to_phred(0), 3);
+        assert_eq!(solexa_to_phred(5), 6);
+        assert_eq!(solexa_to_phred(62), 62);
+    }
+
+    #[test]
+    fn test_illumina_8_level() {
+        let binning = Binning::illumina_8_level();
+
+        let actual: Vec<_> = [0, 1, 2, 9, 10, 19, 20, 24, 25, 29, 30, 34, 35, 39, 40, 93]
+            .into_iter()
+            .map(|n| binning.bin(n))
+            .collect();
+
+        assert_eq!(
+            actual,
+            [0, 1, 6, 6, 15, 15, 22, 22, 27, 27, 33, 33, 37, 37, 40, 40]
+        );
+    }
+
+    #[test]
+    fn test_apply_phred33() {
+        let mut quality_scores = b"!+5I".to_vec();
+        Binning::illumina_8_level().apply_phred33(&mut quality_scores);
+        assert_eq!(quality_scores, b"!07I");
+    }
+}
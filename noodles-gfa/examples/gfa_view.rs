@@ -0,0 +1,30 @@
+//! Prints all records in a GFA file.
+//!
+//! The result matches the output of `cat <src>`, with the exception that blank lines are
+//! removed.
+
+use std::{
+    env,
+    fs::File,
+    io::{self, BufReader},
+};
+
+use noodles_gfa as gfa;
+
+fn main() -> io::Result<()> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let mut reader = File::open(src)
+        .map(BufReader::new)
+        .map(gfa::io::Reader::new)?;
+
+    let stdout = io::stdout().lock();
+    let mut writer = gfa::io::Writer::new(stdout);
+
+    for result in reader.records() {
+        let record = result?;
+        writer.write_record(&record)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,6 @@
+//! GFA I/O.
+
+mod reader;
+mod writer;
+
+pub use self::{reader::Reader, writer::Writer};
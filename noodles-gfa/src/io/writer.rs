@@ -0,0 +1,83 @@
+use std::io::{self, Write};
+
+use crate::Record;
+
+/// A GFA writer.
+pub struct Writer<W> {
+    inner: W,
+}
+
+impl<W> Writer<W>
+where
+    W: Write,
+{
+    /// Creates a GFA writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gfa as gfa;
+    /// let writer = gfa::io::Writer::new(Vec::new());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gfa as gfa;
+    /// let writer = gfa::io::Writer::new(Vec::new());
+    /// assert!(writer.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gfa as gfa;
+    /// let mut writer = gfa::io::Writer::new(Vec::new());
+    /// assert!(writer.get_mut().is_empty());
+    /// ```
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gfa as gfa;
+    /// let writer = gfa::io::Writer::new(Vec::new());
+    /// assert!(writer.into_inner().is_empty());
+    /// ```
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Writes a GFA record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gfa as gfa;
+    ///
+    /// let record: gfa::Record = "S\ts1\tACGT".parse()?;
+    ///
+    /// let mut writer = gfa::io::Writer::new(Vec::new());
+    /// writer.write_record(&record)?;
+    ///
+    /// assert_eq!(writer.get_ref(), b"S\ts1\tACGT\n");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
+        writeln!(self.inner, "{record}")
+    }
+}
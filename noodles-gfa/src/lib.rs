@@ -0,0 +1,32 @@
+#![warn(missing_docs)]
+
+//! **noodles-gfa** handles the reading and writing of the GFA (Graphical Fragment Assembly)
+//! format.
+//!
+//! GFA is a tab-delimited text format that describes a sequence graph, e.g., an assembly graph.
+//! Each line is a record: segments are the nodes of the graph; and links, containments, and
+//! paths describe how segments are joined. Both GFA 1 and GFA 2 segments are supported.
+//!
+//! # Examples
+//!
+//! ## Read all records in a GFA file
+//!
+//! ```no_run
+//! # use std::{fs::File, io::{self, BufReader}};
+//! use noodles_gfa as gfa;
+//!
+//! let mut reader = File::open("sample.gfa")
+//!     .map(BufReader::new)
+//!     .map(gfa::io::Reader::new)?;
+//!
+//! for result in reader.records() {
+//!     let record = result?;
+//!     // ...
+//! }
+//! # Ok::<_, io::Error>(())
+//! ```
+
+pub mod io;
+pub mod record;
+
+pub use self::record::Record;
@@ -0,0 +1,177 @@
+//! GFA record and fields.
+
+pub mod containment;
+pub mod data;
+pub mod header;
+pub mod link;
+pub mod orientation;
+pub mod overlap;
+pub mod path;
+pub mod segment;
+
+pub use self::{
+    containment::Containment, header::Header, link::Link, orientation::Orientation, path::Path,
+    segment::Segment,
+};
+
+use std::{error, fmt, str::FromStr};
+
+const FIELD_DELIMITER: char = '\t';
+const MISSING: &str = "*";
+const COMMENT_PREFIX: char = '#';
+
+/// A GFA record.
+///
+/// Records with other types, e.g., GFA 1.1 walks (`W`) and GFA 2 edges (`E`), gaps (`G`),
+/// fragments (`F`), and groups (`O` and `U`), are kept as raw lines.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Record {
+    /// A header (`H`).
+    Header(Header),
+    /// A segment (`S`).
+    Segment(Segment),
+    /// A link (`L`).
+    Link(Link),
+    /// A containment (`C`).
+    Containment(Containment),
+    /// A path (`P`).
+    Path(Path),
+    /// A comment (`#`).
+    ///
+    /// This is the text after the prefix.
+    Comment(String),
+    /// A record of another type.
+    ///
+    /// This is the raw line.
+    Other(String),
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Header(header) => write!(f, "{header}"),
+            Self::Segment(segment) => write!(f, "{segment}"),
+            Self::Link(link) => write!(f, "{link}"),
+            Self::Containment(containment) => write!(f, "{containment}"),
+            Self::Path(path) => write!(f, "{path}"),
+            Self::Comment(comment) => write!(f, "{COMMENT_PREFIX}{comment}"),
+            Self::Other(line) => f.write_str(line),
+        }
+    }
+}
+
+/// An error returned when a raw GFA record fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The header is invalid.
+    InvalidHeader(header::ParseError),
+    /// The segment is invalid.
+    InvalidSegment(segment::ParseError),
+    /// The link is invalid.
+    InvalidLink(link::ParseError),
+    /// The containment is invalid.
+    InvalidContainment(containment::ParseError),
+    /// The path is invalid.
+    InvalidPath(path::ParseError),
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Empty => None,
+            Self::InvalidHeader(e) => Some(e),
+            Self::InvalidSegment(e) => Some(e),
+            Self::InvalidLink(e) => Some(e),
+            Self::InvalidContainment(e) => Some(e),
+            Self::InvalidPath(e) => Some(e),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty input"),
+            Self::InvalidHeader(_) => write!(f, "invalid header"),
+            Self::InvalidSegment(_) => write!(f, "invalid segment"),
+            Self::InvalidLink(_) => write!(f, "invalid link"),
+            Self::InvalidContainment(_) => write!(f, "invalid containment"),
+            Self::InvalidPath(_) => write!(f, "invalid path"),
+        }
+    }
+}
+
+impl FromStr for Record {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        if let Some(comment) = s.strip_prefix(COMMENT_PREFIX) {
+            return Ok(Self::Comment(comment.into()));
+        }
+
+        let ty = s.split(FIELD_DELIMITER).next().unwrap_or_default();
+
+        match ty {
+            "H" => s
+                .parse()
+                .map(Self::Header)
+                .map_err(ParseError::InvalidHeader),
+            "S" => s
+                .parse()
+                .map(Self::Segment)
+                .map_err(ParseError::InvalidSegment),
+            "L" => s.parse().map(Self::Link).map_err(ParseError::InvalidLink),
+            "C" => s
+                .parse()
+                .map(Self::Containment)
+                .map_err(ParseError::InvalidContainment),
+            "P" => s.parse().map(Self::Path).map_err(ParseError::InvalidPath),
+            _ => Ok(Self::Other(s.into())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Record::Comment(String::from(" ndls")).to_string(), "# ndls");
+        assert_eq!(
+            Record::Other(String::from("W\tsample0\t0\tsq0\t0\t8\t>s1")).to_string(),
+            "W\tsample0\t0\tsq0\t0\t8\t>s1"
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert!(matches!("H\tVN:Z:1.0".parse(), Ok(Record::Header(_))));
+        assert!(matches!("S\ts1\tACGT".parse(), Ok(Record::Segment(_))));
+        assert!(matches!("L\ts1\t+\ts2\t-\t*".parse(), Ok(Record::Link(_))));
+        assert!(matches!(
+            "C\ts1\t+\ts2\t-\t0\t*".parse(),
+            Ok(Record::Containment(_))
+        ));
+        assert!(matches!("P\tp1\ts1+\t*".parse(), Ok(Record::Path(_))));
+        assert_eq!("# ndls".parse(), Ok(Record::Comment(String::from(" ndls"))));
+        assert_eq!(
+            "E\te1\ts1+\ts2-\t0\t4$\t0\t4\t*".parse(),
+            Ok(Record::Other(String::from(
+                "E\te1\ts1+\ts2-\t0\t4$\t0\t4\t*"
+            )))
+        );
+
+        assert_eq!("".parse::<Record>(), Err(ParseError::Empty));
+        assert!(matches!(
+            "S".parse::<Record>(),
+            Err(ParseError::InvalidSegment(_))
+        ));
+    }
+}
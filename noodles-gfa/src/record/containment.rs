@@ -0,0 +1,265 @@
+//! GFA containment record.
+
+use std::{error, fmt, num, str::FromStr};
+
+use noodles_sam::alignment::record_buf::{Cigar, Data};
+
+use super::{
+    data::{self, fmt_data, parse_data},
+    orientation,
+    overlap::{self, fmt_overlap, parse_overlap},
+    Orientation, FIELD_DELIMITER,
+};
+
+const PREFIX: &str = "C";
+
+/// A GFA containment record (`C`).
+///
+/// A containment places an oriented segment (the contained) within another oriented segment
+/// (the container).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Containment {
+    container: String,
+    container_orientation: Orientation,
+    contained: String,
+    contained_orientation: Orientation,
+    position: usize,
+    overlap: Option<Cigar>,
+    data: Data,
+}
+
+impl Containment {
+    /// Creates a GFA containment record.
+    ///
+    /// The position is the 0-based start of the contained segment in the container.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gfa::record::{Containment, Orientation};
+    ///
+    /// let containment = Containment::new(
+    ///     ("s1", Orientation::Forward),
+    ///     ("s2", Orientation::Reverse),
+    ///     4,
+    ///     None,
+    /// );
+    ///
+    /// assert_eq!(containment.to_string(), "C\ts1\t+\ts2\t-\t4\t*");
+    /// ```
+    pub fn new<C, D>(
+        container: (C, Orientation),
+        contained: (D, Orientation),
+        position: usize,
+        overlap: Option<Cigar>,
+    ) -> Self
+    where
+        C: Into<String>,
+        D: Into<String>,
+    {
+        Self {
+            container: container.0.into(),
+            container_orientation: container.1,
+            contained: contained.0.into(),
+            contained_orientation: contained.1,
+            position,
+            overlap,
+            data: Data::default(),
+        }
+    }
+
+    /// Returns the name of the container segment.
+    pub fn container(&self) -> &str {
+        &self.container
+    }
+
+    /// Returns the orientation of the container segment.
+    pub fn container_orientation(&self) -> Orientation {
+        self.container_orientation
+    }
+
+    /// Returns the name of the contained segment.
+    pub fn contained(&self) -> &str {
+        &self.contained
+    }
+
+    /// Returns the orientation of the contained segment.
+    pub fn contained_orientation(&self) -> Orientation {
+        self.contained_orientation
+    }
+
+    /// Returns the 0-based start of the contained segment in the container.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the overlap.
+    pub fn overlap(&self) -> Option<&Cigar> {
+        self.overlap.as_ref()
+    }
+
+    /// Returns the optional fields.
+    pub fn data(&self) -> &Data {
+        &self.data
+    }
+
+    /// Returns a mutable reference to the optional fields.
+    pub fn data_mut(&mut self) -> &mut Data {
+        &mut self.data
+    }
+}
+
+impl fmt::Display for Containment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{PREFIX}\t{}\t{}\t{}\t{}\t{}\t",
+            self.container,
+            self.container_orientation,
+            self.contained,
+            self.contained_orientation,
+            self.position,
+        )?;
+
+        fmt_overlap(f, self.overlap.as_ref())?;
+        fmt_data(f, &self.data)
+    }
+}
+
+/// An error returned when a raw GFA containment record fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The record type is invalid.
+    InvalidRecordType,
+    /// The container segment name is missing.
+    MissingContainer,
+    /// The container segment orientation is invalid.
+    InvalidContainerOrientation(orientation::ParseError),
+    /// The contained segment name is missing.
+    MissingContained,
+    /// The contained segment orientation is invalid.
+    InvalidContainedOrientation(orientation::ParseError),
+    /// The position is invalid.
+    InvalidPosition(num::ParseIntError),
+    /// The overlap is invalid.
+    InvalidOverlap(overlap::ParseError),
+    /// The optional fields are invalid.
+    InvalidData(data::ParseError),
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidContainerOrientation(e) | Self::InvalidContainedOrientation(e) => Some(e),
+            Self::InvalidPosition(e) => Some(e),
+            Self::InvalidOverlap(e) => Some(e),
+            Self::InvalidData(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidRecordType => write!(f, "invalid record type"),
+            Self::MissingContainer => write!(f, "missing container"),
+            Self::InvalidContainerOrientation(_) => write!(f, "invalid container orientation"),
+            Self::MissingContained => write!(f, "missing contained"),
+            Self::InvalidContainedOrientation(_) => write!(f, "invalid contained orientation"),
+            Self::InvalidPosition(_) => write!(f, "invalid position"),
+            Self::InvalidOverlap(_) => write!(f, "invalid overlap"),
+            Self::InvalidData(_) => write!(f, "invalid data"),
+        }
+    }
+}
+
+impl FromStr for Containment {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.split(FIELD_DELIMITER);
+
+        if fields.next() != Some(PREFIX) {
+            return Err(ParseError::InvalidRecordType);
+        }
+
+        let container = fields
+            .next()
+            .filter(|t| !t.is_empty())
+            .ok_or(ParseError::MissingContainer)?;
+
+        let container_orientation = fields
+            .next()
+            .unwrap_or_default()
+            .parse()
+            .map_err(ParseError::InvalidContainerOrientation)?;
+
+        let contained = fields
+            .next()
+            .filter(|t| !t.is_empty())
+            .ok_or(ParseError::MissingContained)?;
+
+        let contained_orientation = fields
+            .next()
+            .unwrap_or_default()
+            .parse()
+            .map_err(ParseError::InvalidContainedOrientation)?;
+
+        let position = fields
+            .next()
+            .unwrap_or_default()
+            .parse()
+            .map_err(ParseError::InvalidPosition)?;
+
+        let overlap =
+            parse_overlap(fields.next().unwrap_or_default()).map_err(ParseError::InvalidOverlap)?;
+
+        let data = parse_data(fields).map_err(ParseError::InvalidData)?;
+
+        Ok(Self {
+            container: container.into(),
+            container_orientation,
+            contained: contained.into(),
+            contained_orientation,
+            position,
+            overlap,
+            data,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::alignment::record::cigar::{op::Kind, Op};
+
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        let expected = Containment::new(
+            ("s1", Orientation::Forward),
+            ("s2", Orientation::Reverse),
+            4,
+            Some(Cigar::from(vec![Op::new(Kind::Match, 2)])),
+        );
+        assert_eq!("C\ts1\t+\ts2\t-\t4\t2M".parse(), Ok(expected));
+
+        assert_eq!(
+            "L\ts1\t+\ts2\t-\t4\t*".parse::<Containment>(),
+            Err(ParseError::InvalidRecordType)
+        );
+        assert_eq!(
+            "C".parse::<Containment>(),
+            Err(ParseError::MissingContainer)
+        );
+        assert_eq!(
+            "C\ts1\t+".parse::<Containment>(),
+            Err(ParseError::MissingContained)
+        );
+        assert!(matches!(
+            "C\ts1\t+\ts2\t-\t.\t*".parse::<Containment>(),
+            Err(ParseError::InvalidPosition(_))
+        ));
+    }
+}
@@ -0,0 +1,257 @@
+//! GFA record optional fields.
+//!
+//! Optional fields use the SAM optional field format, i.e., `TAG:TYPE:VALUE`, and are parsed
+//! into SAM record data. JSON (`J`) values are not supported.
+
+use std::{error, fmt, num};
+
+use bstr::BString;
+use noodles_sam::alignment::{
+    record::data::field::Tag,
+    record_buf::{
+        data::field::{value::Array, Value},
+        Data,
+    },
+};
+
+const DELIMITER: char = ':';
+const ARRAY_VALUE_DELIMITER: char = ',';
+
+/// An error returned when a raw GFA record optional field fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The tag is invalid.
+    InvalidTag,
+    /// The type is invalid.
+    InvalidType(Tag),
+    /// The type is unsupported.
+    UnsupportedType(Tag, char),
+    /// The value is invalid.
+    InvalidValue(Tag),
+    /// An integer value is invalid.
+    InvalidIntegerValue(Tag, num::ParseIntError),
+    /// A floating-point value is invalid.
+    InvalidFloatValue(Tag, num::ParseFloatError),
+    /// A tag is duplicated.
+    DuplicateTag(Tag),
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidIntegerValue(_, e) => Some(e),
+            Self::InvalidFloatValue(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidTag => write!(f, "invalid tag"),
+            Self::InvalidType(tag) => write!(f, "invalid type: {tag:?}"),
+            Self::UnsupportedType(tag, ty) => write!(f, "unsupported type: {tag:?}: {ty}"),
+            Self::InvalidValue(tag) => write!(f, "invalid value: {tag:?}"),
+            Self::InvalidIntegerValue(tag, _) => write!(f, "invalid integer value: {tag:?}"),
+            Self::InvalidFloatValue(tag, _) => write!(f, "invalid float value: {tag:?}"),
+            Self::DuplicateTag(tag) => write!(f, "duplicate tag: {tag:?}"),
+        }
+    }
+}
+
+pub(crate) fn parse_data<'a, I>(fields: I) -> Result<Data, ParseError>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let mut data = Data::default();
+
+    for s in fields {
+        let (tag, value) = parse_field(s)?;
+
+        if data.insert(tag, value).is_some() {
+            return Err(ParseError::DuplicateTag(tag));
+        }
+    }
+
+    Ok(data)
+}
+
+fn parse_field(s: &str) -> Result<(Tag, Value), ParseError> {
+    let mut components = s.splitn(3, DELIMITER);
+
+    let tag = components
+        .next()
+        .and_then(parse_tag)
+        .ok_or(ParseError::InvalidTag)?;
+
+    let ty = match components.next().map(|t| t.as_bytes()) {
+        Some(&[ty]) => char::from(ty),
+        _ => return Err(ParseError::InvalidType(tag)),
+    };
+
+    let raw_value = components.next().ok_or(ParseError::InvalidValue(tag))?;
+    let value = parse_value(tag, ty, raw_value)?;
+
+    Ok((tag, value))
+}
+
+// § 1.4 "Optional fields" (2024-04-16): `[A-Za-z][A-Za-z0-9]`.
+fn parse_tag(s: &str) -> Option<Tag> {
+    match *s.as_bytes() {
+        [a, b] if a.is_ascii_alphabetic() && b.is_ascii_alphanumeric() => Some(Tag::new(a, b)),
+        _ => None,
+    }
+}
+
+fn parse_value(tag: Tag, ty: char, s: &str) -> Result<Value, ParseError> {
+    match ty {
+        'A' => match *s.as_bytes() {
+            [c] if c.is_ascii_graphic() => Ok(Value::Character(c)),
+            _ => Err(ParseError::InvalidValue(tag)),
+        },
+        'i' => s
+            .parse::<i64>()
+            .map_err(|e| ParseError::InvalidIntegerValue(tag, e))
+            .and_then(|n| Value::try_from(n).map_err(|_| ParseError::InvalidValue(tag))),
+        'f' => s
+            .parse()
+            .map(Value::Float)
+            .map_err(|e| ParseError::InvalidFloatValue(tag, e)),
+        'Z' => Ok(Value::String(BString::from(s))),
+        'H' => {
+            if s.len() % 2 == 0 && s.bytes().all(|b| matches!(b, b'0'..=b'9' | b'A'..=b'F')) {
+                Ok(Value::Hex(BString::from(s)))
+            } else {
+                Err(ParseError::InvalidValue(tag))
+            }
+        }
+        'B' => parse_array(tag, s).map(Value::Array),
+        'J' => Err(ParseError::UnsupportedType(tag, ty)),
+        _ => Err(ParseError::InvalidType(tag)),
+    }
+}
+
+fn parse_array(tag: Tag, s: &str) -> Result<Array, ParseError> {
+    fn parse_values<T>(tag: Tag, values: &[&str]) -> Result<Vec<T>, ParseError>
+    where
+        T: std::str::FromStr,
+    {
+        values
+            .iter()
+            .map(|t| t.parse().map_err(|_| ParseError::InvalidValue(tag)))
+            .collect()
+    }
+
+    let mut components = s.split(ARRAY_VALUE_DELIMITER);
+
+    let subtype = components.next().ok_or(ParseError::InvalidValue(tag))?;
+    let values: Vec<_> = components.collect();
+
+    match subtype {
+        "c" => parse_values(tag, &values).map(Array::Int8),
+        "C" => parse_values(tag, &values).map(Array::UInt8),
+        "s" => parse_values(tag, &values).map(Array::Int16),
+        "S" => parse_values(tag, &values).map(Array::UInt16),
+        "i" => parse_values(tag, &values).map(Array::Int32),
+        "I" => parse_values(tag, &values).map(Array::UInt32),
+        "f" => parse_values(tag, &values).map(Array::Float),
+        _ => Err(ParseError::InvalidValue(tag)),
+    }
+}
+
+pub(crate) fn fmt_data(f: &mut fmt::Formatter<'_>, data: &Data) -> fmt::Result {
+    for (tag, value) in data.iter() {
+        let [a, b] = *tag.as_ref();
+        write!(f, "\t{}{}{DELIMITER}", char::from(a), char::from(b))?;
+        fmt_value(f, value)?;
+    }
+
+    Ok(())
+}
+
+fn fmt_value(f: &mut fmt::Formatter<'_>, value: &Value) -> fmt::Result {
+    fn fmt_array<T>(f: &mut fmt::Formatter<'_>, subtype: char, values: &[T]) -> fmt::Result
+    where
+        T: fmt::Display,
+    {
+        write!(f, "B{DELIMITER}{subtype}")?;
+
+        for value in values {
+            write!(f, "{ARRAY_VALUE_DELIMITER}{value}")?;
+        }
+
+        Ok(())
+    }
+
+    match value {
+        Value::Character(c) => write!(f, "A{DELIMITER}{}", char::from(*c)),
+        Value::Int8(n) => write!(f, "i{DELIMITER}{n}"),
+        Value::UInt8(n) => write!(f, "i{DELIMITER}{n}"),
+        Value::Int16(n) => write!(f, "i{DELIMITER}{n}"),
+        Value::UInt16(n) => write!(f, "i{DELIMITER}{n}"),
+        Value::Int32(n) => write!(f, "i{DELIMITER}{n}"),
+        Value::UInt32(n) => write!(f, "i{DELIMITER}{n}"),
+        Value::Float(n) => write!(f, "f{DELIMITER}{n}"),
+        Value::String(s) => write!(f, "Z{DELIMITER}{s}"),
+        Value::Hex(s) => write!(f, "H{DELIMITER}{s}"),
+        Value::Array(Array::Int8(values)) => fmt_array(f, 'c', values),
+        Value::Array(Array::UInt8(values)) => fmt_array(f, 'C', values),
+        Value::Array(Array::Int16(values)) => fmt_array(f, 's', values),
+        Value::Array(Array::UInt16(values)) => fmt_array(f, 'S', values),
+        Value::Array(Array::Int32(values)) => fmt_array(f, 'i', values),
+        Value::Array(Array::UInt32(values)) => fmt_array(f, 'I', values),
+        Value::Array(Array::Float(values)) => fmt_array(f, 'f', values),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_data() {
+        const LN: Tag = Tag::new(b'L', b'N');
+        const RC: Tag = Tag::new(b'R', b'C');
+
+        let expected: Data = [
+            (LN, Value::from(8)),
+            (Tag::COMMENT, Value::from("noodles")),
+            (Tag::new(b'x', b'0'), Value::Character(b'n')),
+            (Tag::new(b'x', b'1'), Value::Float(0.5)),
+            (Tag::new(b'x', b'2'), Value::Hex(BString::from("CAFE"))),
+            (Tag::new(b'x', b'3'), Value::from(vec![-1i16, 2])),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            parse_data([
+                "LN:i:8",
+                "CO:Z:noodles",
+                "x0:A:n",
+                "x1:f:0.5",
+                "x2:H:CAFE",
+                "x3:B:s,-1,2"
+            ]),
+            Ok(expected)
+        );
+
+        assert_eq!(parse_data(["L:i:8"]), Err(ParseError::InvalidTag));
+        assert_eq!(parse_data(["8N:i:8"]), Err(ParseError::InvalidTag));
+        assert_eq!(parse_data(["LN:ii:8"]), Err(ParseError::InvalidType(LN)));
+        assert_eq!(parse_data(["LN:i"]), Err(ParseError::InvalidValue(LN)));
+        assert!(matches!(
+            parse_data(["LN:i:n"]),
+            Err(ParseError::InvalidIntegerValue(LN, _))
+        ));
+        assert_eq!(
+            parse_data(["RC:J:{}"]),
+            Err(ParseError::UnsupportedType(RC, 'J'))
+        );
+        assert_eq!(
+            parse_data(["LN:i:8", "LN:i:13"]),
+            Err(ParseError::DuplicateTag(LN))
+        );
+    }
+}
@@ -0,0 +1,141 @@
+//! GFA header record.
+
+use std::{error, fmt, str::FromStr};
+
+use bstr::BStr;
+use noodles_sam::alignment::{
+    record::data::field::Tag,
+    record_buf::{data::field::Value, Data},
+};
+
+use super::data::{self, fmt_data, parse_data};
+
+const PREFIX: &str = "H";
+
+const VERSION: Tag = Tag::new(b'V', b'N');
+
+/// A GFA header record (`H`).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Header {
+    data: Data,
+}
+
+impl Header {
+    /// Creates a GFA header record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gfa::record::Header;
+    /// use noodles_sam::alignment::record_buf::Data;
+    /// let header = Header::new(Data::default());
+    /// ```
+    pub fn new(data: Data) -> Self {
+        Self { data }
+    }
+
+    /// Returns the format version (`VN`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gfa::record::Header;
+    /// let header: Header = "H\tVN:Z:1.0".parse()?;
+    /// assert_eq!(header.version(), Some("1.0".into()));
+    /// # Ok::<_, noodles_gfa::record::header::ParseError>(())
+    /// ```
+    pub fn version(&self) -> Option<&BStr> {
+        match self.data.get(&VERSION) {
+            Some(Value::String(s)) => Some(s.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Returns the optional fields.
+    pub fn data(&self) -> &Data {
+        &self.data
+    }
+
+    /// Returns a mutable reference to the optional fields.
+    pub fn data_mut(&mut self) -> &mut Data {
+        &mut self.data
+    }
+}
+
+impl fmt::Display for Header {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(PREFIX)?;
+        fmt_data(f, &self.data)
+    }
+}
+
+/// An error returned when a raw GFA header record fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The record type is invalid.
+    InvalidRecordType,
+    /// The optional fields are invalid.
+    InvalidData(data::ParseError),
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidData(e) => Some(e),
+            Self::InvalidRecordType => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidRecordType => write!(f, "invalid record type"),
+            Self::InvalidData(_) => write!(f, "invalid data"),
+        }
+    }
+}
+
+impl FromStr for Header {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.split(super::FIELD_DELIMITER);
+
+        if fields.next() != Some(PREFIX) {
+            return Err(ParseError::InvalidRecordType);
+        }
+
+        parse_data(fields)
+            .map(Self::new)
+            .map_err(ParseError::InvalidData)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        let header = Header::default();
+        assert_eq!(header.to_string(), "H");
+
+        let data = [(VERSION, Value::from("1.0"))].into_iter().collect();
+        let header = Header::new(data);
+        assert_eq!(header.to_string(), "H\tVN:Z:1.0");
+    }
+
+    #[test]
+    fn test_from_str() {
+        let data = [(VERSION, Value::from("1.0"))].into_iter().collect();
+        assert_eq!("H\tVN:Z:1.0".parse(), Ok(Header::new(data)));
+        assert_eq!("H".parse(), Ok(Header::default()));
+
+        assert_eq!("S".parse::<Header>(), Err(ParseError::InvalidRecordType));
+        assert!(matches!(
+            "H\tVN".parse::<Header>(),
+            Err(ParseError::InvalidData(_))
+        ));
+    }
+}
@@ -0,0 +1,251 @@
+//! GFA link record.
+
+use std::{error, fmt, str::FromStr};
+
+use noodles_sam::alignment::record_buf::{Cigar, Data};
+
+use super::{
+    data::{self, fmt_data, parse_data},
+    orientation,
+    overlap::{self, fmt_overlap, parse_overlap},
+    Orientation, FIELD_DELIMITER,
+};
+
+const PREFIX: &str = "L";
+
+/// A GFA link record (`L`).
+///
+/// A link is an edge in the graph that joins the end of one oriented segment to the start of
+/// another, with an optional overlap.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Link {
+    from: String,
+    from_orientation: Orientation,
+    to: String,
+    to_orientation: Orientation,
+    overlap: Option<Cigar>,
+    data: Data,
+}
+
+impl Link {
+    /// Creates a GFA link record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gfa::record::{Link, Orientation};
+    /// let link = Link::new("s1", Orientation::Forward, "s2", Orientation::Reverse, None);
+    /// assert_eq!(link.to_string(), "L\ts1\t+\ts2\t-\t*");
+    /// ```
+    pub fn new<F, T>(
+        from: F,
+        from_orientation: Orientation,
+        to: T,
+        to_orientation: Orientation,
+        overlap: Option<Cigar>,
+    ) -> Self
+    where
+        F: Into<String>,
+        T: Into<String>,
+    {
+        Self {
+            from: from.into(),
+            from_orientation,
+            to: to.into(),
+            to_orientation,
+            overlap,
+            data: Data::default(),
+        }
+    }
+
+    /// Returns the name of the segment the link starts from.
+    pub fn from(&self) -> &str {
+        &self.from
+    }
+
+    /// Returns the orientation of the segment the link starts from.
+    pub fn from_orientation(&self) -> Orientation {
+        self.from_orientation
+    }
+
+    /// Returns the name of the segment the link goes to.
+    pub fn to(&self) -> &str {
+        &self.to
+    }
+
+    /// Returns the orientation of the segment the link goes to.
+    pub fn to_orientation(&self) -> Orientation {
+        self.to_orientation
+    }
+
+    /// Returns the overlap.
+    pub fn overlap(&self) -> Option<&Cigar> {
+        self.overlap.as_ref()
+    }
+
+    /// Returns the optional fields.
+    pub fn data(&self) -> &Data {
+        &self.data
+    }
+
+    /// Returns a mutable reference to the optional fields.
+    pub fn data_mut(&mut self) -> &mut Data {
+        &mut self.data
+    }
+}
+
+impl fmt::Display for Link {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{PREFIX}\t{}\t{}\t{}\t{}\t",
+            self.from, self.from_orientation, self.to, self.to_orientation
+        )?;
+
+        fmt_overlap(f, self.overlap.as_ref())?;
+        fmt_data(f, &self.data)
+    }
+}
+
+/// An error returned when a raw GFA link record fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The record type is invalid.
+    InvalidRecordType,
+    /// The from segment name is missing.
+    MissingFrom,
+    /// The from segment orientation is invalid.
+    InvalidFromOrientation(orientation::ParseError),
+    /// The to segment name is missing.
+    MissingTo,
+    /// The to segment orientation is invalid.
+    InvalidToOrientation(orientation::ParseError),
+    /// The overlap is invalid.
+    InvalidOverlap(overlap::ParseError),
+    /// The optional fields are invalid.
+    InvalidData(data::ParseError),
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidFromOrientation(e) | Self::InvalidToOrientation(e) => Some(e),
+            Self::InvalidOverlap(e) => Some(e),
+            Self::InvalidData(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidRecordType => write!(f, "invalid record type"),
+            Self::MissingFrom => write!(f, "missing from"),
+            Self::InvalidFromOrientation(_) => write!(f, "invalid from orientation"),
+            Self::MissingTo => write!(f, "missing to"),
+            Self::InvalidToOrientation(_) => write!(f, "invalid to orientation"),
+            Self::InvalidOverlap(_) => write!(f, "invalid overlap"),
+            Self::InvalidData(_) => write!(f, "invalid data"),
+        }
+    }
+}
+
+impl FromStr for Link {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.split(FIELD_DELIMITER);
+
+        if fields.next() != Some(PREFIX) {
+            return Err(ParseError::InvalidRecordType);
+        }
+
+        let from = fields
+            .next()
+            .filter(|t| !t.is_empty())
+            .ok_or(ParseError::MissingFrom)?;
+
+        let from_orientation = fields
+            .next()
+            .unwrap_or_default()
+            .parse()
+            .map_err(ParseError::InvalidFromOrientation)?;
+
+        let to = fields
+            .next()
+            .filter(|t| !t.is_empty())
+            .ok_or(ParseError::MissingTo)?;
+
+        let to_orientation = fields
+            .next()
+            .unwrap_or_default()
+            .parse()
+            .map_err(ParseError::InvalidToOrientation)?;
+
+        let overlap =
+            parse_overlap(fields.next().unwrap_or_default()).map_err(ParseError::InvalidOverlap)?;
+
+        let data = parse_data(fields).map_err(ParseError::InvalidData)?;
+
+        Ok(Self {
+            from: from.into(),
+            from_orientation,
+            to: to.into(),
+            to_orientation,
+            overlap,
+            data,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::alignment::record::cigar::{op::Kind, Op};
+
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        let link = Link::new(
+            "s1",
+            Orientation::Forward,
+            "s2",
+            Orientation::Reverse,
+            Some(Cigar::from(vec![Op::new(Kind::Match, 2)])),
+        );
+
+        assert_eq!(link.to_string(), "L\ts1\t+\ts2\t-\t2M");
+    }
+
+    #[test]
+    fn test_from_str() {
+        let expected = Link::new(
+            "s1",
+            Orientation::Forward,
+            "s2",
+            Orientation::Reverse,
+            Some(Cigar::from(vec![Op::new(Kind::Match, 2)])),
+        );
+        assert_eq!("L\ts1\t+\ts2\t-\t2M".parse(), Ok(expected));
+
+        assert_eq!(
+            "S\ts1\t+\ts2\t-\t*".parse::<Link>(),
+            Err(ParseError::InvalidRecordType)
+        );
+        assert_eq!("L".parse::<Link>(), Err(ParseError::MissingFrom));
+        assert!(matches!(
+            "L\ts1\t.\ts2\t-\t*".parse::<Link>(),
+            Err(ParseError::InvalidFromOrientation(_))
+        ));
+        assert_eq!("L\ts1\t+".parse::<Link>(), Err(ParseError::MissingTo));
+        assert!(matches!(
+            "L\ts1\t+\ts2".parse::<Link>(),
+            Err(ParseError::InvalidToOrientation(_))
+        ));
+        assert!(matches!(
+            "L\ts1\t+\ts2\t-".parse::<Link>(),
+            Err(ParseError::InvalidOverlap(_))
+        ));
+    }
+}
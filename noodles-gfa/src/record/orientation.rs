@@ -0,0 +1,80 @@
+//! GFA record segment orientation.
+
+use std::{error, fmt, str::FromStr};
+
+/// A GFA record segment orientation.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Orientation {
+    /// The segment sequence (`+`).
+    Forward,
+    /// The reverse complement of the segment sequence (`-`).
+    Reverse,
+}
+
+impl AsRef<str> for Orientation {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Forward => "+",
+            Self::Reverse => "-",
+        }
+    }
+}
+
+impl fmt::Display for Orientation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// An error returned when a raw GFA record segment orientation fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The input is invalid.
+    Invalid,
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::Invalid => f.write_str("invalid input"),
+        }
+    }
+}
+
+impl FromStr for Orientation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err(ParseError::Empty),
+            "+" => Ok(Self::Forward),
+            "-" => Ok(Self::Reverse),
+            _ => Err(ParseError::Invalid),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Orientation::Forward.to_string(), "+");
+        assert_eq!(Orientation::Reverse.to_string(), "-");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("+".parse(), Ok(Orientation::Forward));
+        assert_eq!("-".parse(), Ok(Orientation::Reverse));
+
+        assert_eq!("".parse::<Orientation>(), Err(ParseError::Empty));
+        assert_eq!("ndls".parse::<Orientation>(), Err(ParseError::Invalid));
+    }
+}
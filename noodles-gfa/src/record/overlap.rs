@@ -0,0 +1,142 @@
+//! GFA record overlap.
+
+use std::{error, fmt, num};
+
+use noodles_sam::alignment::{
+    record::cigar::{op::Kind, Op},
+    record_buf::Cigar,
+};
+
+/// An error returned when a raw GFA record overlap fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// An operation length is invalid.
+    InvalidLength(num::ParseIntError),
+    /// An operation kind is missing.
+    MissingKind,
+    /// An operation kind is invalid.
+    InvalidKind(char),
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidLength(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty input"),
+            Self::InvalidLength(_) => write!(f, "invalid length"),
+            Self::MissingKind => write!(f, "missing kind"),
+            Self::InvalidKind(c) => write!(f, "invalid kind: {c}"),
+        }
+    }
+}
+
+const MISSING: &str = "*";
+
+pub(crate) fn parse_overlap(s: &str) -> Result<Option<Cigar>, ParseError> {
+    match s {
+        "" => Err(ParseError::Empty),
+        MISSING => Ok(None),
+        _ => parse_cigar(s).map(Some),
+    }
+}
+
+fn parse_cigar(s: &str) -> Result<Cigar, ParseError> {
+    let mut ops = Vec::new();
+    let mut src = s;
+
+    while !src.is_empty() {
+        let i = src
+            .find(|c: char| !c.is_ascii_digit())
+            .ok_or(ParseError::MissingKind)?;
+
+        let (raw_len, rest) = src.split_at(i);
+        let len = raw_len.parse().map_err(ParseError::InvalidLength)?;
+
+        let mut chars = rest.chars();
+        // SAFETY: `rest` is non-empty.
+        let c = chars.next().unwrap();
+        let kind = parse_kind(c)?;
+
+        ops.push(Op::new(kind, len));
+        src = chars.as_str();
+    }
+
+    Ok(Cigar::from(ops))
+}
+
+fn parse_kind(c: char) -> Result<Kind, ParseError> {
+    match c {
+        'M' => Ok(Kind::Match),
+        'I' => Ok(Kind::Insertion),
+        'D' => Ok(Kind::Deletion),
+        'N' => Ok(Kind::Skip),
+        'S' => Ok(Kind::SoftClip),
+        'H' => Ok(Kind::HardClip),
+        'P' => Ok(Kind::Pad),
+        '=' => Ok(Kind::SequenceMatch),
+        'X' => Ok(Kind::SequenceMismatch),
+        _ => Err(ParseError::InvalidKind(c)),
+    }
+}
+
+pub(crate) fn fmt_overlap(f: &mut fmt::Formatter<'_>, overlap: Option<&Cigar>) -> fmt::Result {
+    let Some(cigar) = overlap else {
+        return f.write_str(MISSING);
+    };
+
+    for op in cigar.as_ref() {
+        let c = match op.kind() {
+            Kind::Match => 'M',
+            Kind::Insertion => 'I',
+            Kind::Deletion => 'D',
+            Kind::Skip => 'N',
+            Kind::SoftClip => 'S',
+            Kind::HardClip => 'H',
+            Kind::Pad => 'P',
+            Kind::SequenceMatch => '=',
+            Kind::SequenceMismatch => 'X',
+        };
+
+        write!(f, "{}{c}", op.len())?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_overlap() {
+        assert_eq!(parse_overlap("*"), Ok(None));
+
+        assert_eq!(
+            parse_overlap("8M2I3=1X"),
+            Ok(Some(Cigar::from(vec![
+                Op::new(Kind::Match, 8),
+                Op::new(Kind::Insertion, 2),
+                Op::new(Kind::SequenceMatch, 3),
+                Op::new(Kind::SequenceMismatch, 1),
+            ])))
+        );
+
+        assert_eq!(parse_overlap(""), Err(ParseError::Empty));
+        assert_eq!(parse_overlap("8"), Err(ParseError::MissingKind));
+        assert_eq!(parse_overlap("8Z"), Err(ParseError::InvalidKind('Z')));
+        assert!(matches!(
+            parse_overlap("M"),
+            Err(ParseError::InvalidLength(_))
+        ));
+    }
+}
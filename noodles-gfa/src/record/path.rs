@@ -0,0 +1,281 @@
+//! GFA path record.
+
+use std::{error, fmt, str::FromStr};
+
+use noodles_sam::alignment::record_buf::{Cigar, Data};
+
+use super::{
+    data::{self, fmt_data, parse_data},
+    overlap::{self, fmt_overlap, parse_overlap},
+    Orientation, FIELD_DELIMITER, MISSING,
+};
+
+const PREFIX: &str = "P";
+const LIST_DELIMITER: char = ',';
+
+/// A GFA path record (`P`).
+///
+/// A path is a named walk through a list of oriented segments, e.g., a contig or scaffold.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Path {
+    name: String,
+    segments: Vec<(String, Orientation)>,
+    overlaps: Vec<Option<Cigar>>,
+    data: Data,
+}
+
+impl Path {
+    /// Creates a GFA path record.
+    ///
+    /// The overlaps are between consecutive segments. If the list is empty, overlaps are written
+    /// as `*`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gfa::record::{Orientation, Path};
+    ///
+    /// let path = Path::new(
+    ///     "p1",
+    ///     vec![
+    ///         (String::from("s1"), Orientation::Forward),
+    ///         (String::from("s2"), Orientation::Reverse),
+    ///     ],
+    ///     Vec::new(),
+    /// );
+    ///
+    /// assert_eq!(path.to_string(), "P\tp1\ts1+,s2-\t*");
+    /// ```
+    pub fn new<N>(
+        name: N,
+        segments: Vec<(String, Orientation)>,
+        overlaps: Vec<Option<Cigar>>,
+    ) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            segments,
+            overlaps,
+            data: Data::default(),
+        }
+    }
+
+    /// Returns the path name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the oriented segment names.
+    pub fn segments(&self) -> &[(String, Orientation)] {
+        &self.segments
+    }
+
+    /// Returns the overlaps between consecutive segments.
+    pub fn overlaps(&self) -> &[Option<Cigar>] {
+        &self.overlaps
+    }
+
+    /// Returns the optional fields.
+    pub fn data(&self) -> &Data {
+        &self.data
+    }
+
+    /// Returns a mutable reference to the optional fields.
+    pub fn data_mut(&mut self) -> &mut Data {
+        &mut self.data
+    }
+}
+
+impl fmt::Display for Path {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{PREFIX}\t{}\t", self.name)?;
+
+        for (i, (name, orientation)) in self.segments.iter().enumerate() {
+            if i > 0 {
+                write!(f, "{LIST_DELIMITER}")?;
+            }
+
+            write!(f, "{name}{orientation}")?;
+        }
+
+        f.write_str("\t")?;
+
+        if self.overlaps.is_empty() {
+            f.write_str(MISSING)?;
+        } else {
+            for (i, overlap) in self.overlaps.iter().enumerate() {
+                if i > 0 {
+                    write!(f, "{LIST_DELIMITER}")?;
+                }
+
+                fmt_overlap(f, overlap.as_ref())?;
+            }
+        }
+
+        fmt_data(f, &self.data)
+    }
+}
+
+/// An error returned when a raw GFA path record fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The record type is invalid.
+    InvalidRecordType,
+    /// The name is missing.
+    MissingName,
+    /// The segment names are missing.
+    MissingSegments,
+    /// A segment name is invalid.
+    InvalidSegment,
+    /// The overlaps are missing.
+    MissingOverlaps,
+    /// An overlap is invalid.
+    InvalidOverlap(overlap::ParseError),
+    /// The optional fields are invalid.
+    InvalidData(data::ParseError),
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidOverlap(e) => Some(e),
+            Self::InvalidData(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidRecordType => write!(f, "invalid record type"),
+            Self::MissingName => write!(f, "missing name"),
+            Self::MissingSegments => write!(f, "missing segments"),
+            Self::InvalidSegment => write!(f, "invalid segment"),
+            Self::MissingOverlaps => write!(f, "missing overlaps"),
+            Self::InvalidOverlap(_) => write!(f, "invalid overlap"),
+            Self::InvalidData(_) => write!(f, "invalid data"),
+        }
+    }
+}
+
+impl FromStr for Path {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.split(FIELD_DELIMITER);
+
+        if fields.next() != Some(PREFIX) {
+            return Err(ParseError::InvalidRecordType);
+        }
+
+        let name = fields
+            .next()
+            .filter(|t| !t.is_empty())
+            .ok_or(ParseError::MissingName)?;
+
+        let segments = fields
+            .next()
+            .filter(|t| !t.is_empty())
+            .ok_or(ParseError::MissingSegments)
+            .and_then(parse_segments)?;
+
+        let overlaps = match fields.next() {
+            None | Some("") => return Err(ParseError::MissingOverlaps),
+            Some(MISSING) => Vec::new(),
+            Some(t) => t
+                .split(LIST_DELIMITER)
+                .map(parse_overlap)
+                .collect::<Result<_, _>>()
+                .map_err(ParseError::InvalidOverlap)?,
+        };
+
+        let data = parse_data(fields).map_err(ParseError::InvalidData)?;
+
+        Ok(Self {
+            name: name.into(),
+            segments,
+            overlaps,
+            data,
+        })
+    }
+}
+
+fn parse_segments(s: &str) -> Result<Vec<(String, Orientation)>, ParseError> {
+    s.split(LIST_DELIMITER)
+        .map(|t| {
+            let i = t
+                .len()
+                .checked_sub(1)
+                .filter(|&i| i > 0 && t.is_char_boundary(i))
+                .ok_or(ParseError::InvalidSegment)?;
+
+            let (name, raw_orientation) = t.split_at(i);
+
+            let orientation = raw_orientation
+                .parse()
+                .map_err(|_| ParseError::InvalidSegment)?;
+
+            Ok((name.into(), orientation))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::alignment::record::cigar::{op::Kind, Op};
+
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        let path = Path::new(
+            "p1",
+            vec![
+                (String::from("s1"), Orientation::Forward),
+                (String::from("s2"), Orientation::Reverse),
+                (String::from("s3"), Orientation::Forward),
+            ],
+            vec![Some(Cigar::from(vec![Op::new(Kind::Match, 2)])), None],
+        );
+
+        assert_eq!(path.to_string(), "P\tp1\ts1+,s2-,s3+\t2M,*");
+    }
+
+    #[test]
+    fn test_from_str() {
+        let expected = Path::new(
+            "p1",
+            vec![
+                (String::from("s1"), Orientation::Forward),
+                (String::from("s2"), Orientation::Reverse),
+            ],
+            vec![Some(Cigar::from(vec![Op::new(Kind::Match, 2)]))],
+        );
+        assert_eq!("P\tp1\ts1+,s2-\t2M".parse(), Ok(expected));
+
+        let path: Path = "P\tp1\ts1+\t*".parse().unwrap();
+        assert!(path.overlaps().is_empty());
+
+        assert_eq!(
+            "L\tp1\ts1+\t*".parse::<Path>(),
+            Err(ParseError::InvalidRecordType)
+        );
+        assert_eq!("P".parse::<Path>(), Err(ParseError::MissingName));
+        assert_eq!("P\tp1".parse::<Path>(), Err(ParseError::MissingSegments));
+        assert_eq!(
+            "P\tp1\ts1,s2-\t*".parse::<Path>(),
+            Err(ParseError::InvalidSegment)
+        );
+        assert_eq!(
+            "P\tp1\t+\t*".parse::<Path>(),
+            Err(ParseError::InvalidSegment)
+        );
+        assert_eq!(
+            "P\tp1\ts1+".parse::<Path>(),
+            Err(ParseError::MissingOverlaps)
+        );
+    }
+}
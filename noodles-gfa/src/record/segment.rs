@@ -0,0 +1,236 @@
+//! GFA segment record.
+
+use std::{error, fmt, num, str::FromStr};
+
+use bstr::BString;
+use noodles_sam::alignment::record_buf::Data;
+
+use super::{
+    data::{self, fmt_data, parse_data},
+    FIELD_DELIMITER, MISSING,
+};
+
+const PREFIX: &str = "S";
+
+/// A GFA segment record (`S`).
+///
+/// A segment is a node in the graph. In GFA 1, a segment line is `S <name> <sequence>`, and in
+/// GFA 2, `S <name> <length> <sequence>`. The length is only set for GFA 2 segments.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Segment {
+    name: String,
+    length: Option<usize>,
+    sequence: Option<BString>,
+    data: Data,
+}
+
+impl Segment {
+    /// Creates a GFA 1 segment record.
+    ///
+    /// A missing sequence is written as `*`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gfa::record::Segment;
+    /// let segment = Segment::new("s1", Some(b"ACGT".into()));
+    /// assert_eq!(segment.to_string(), "S\ts1\tACGT");
+    /// ```
+    pub fn new<N>(name: N, sequence: Option<BString>) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            length: None,
+            sequence,
+            data: Data::default(),
+        }
+    }
+
+    /// Returns the segment name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the GFA 2 segment length.
+    ///
+    /// This is `None` for GFA 1 segments. The length of a GFA 1 segment without a sequence is
+    /// given by the `LN` optional field.
+    pub fn length(&self) -> Option<usize> {
+        self.length
+    }
+
+    /// Returns a mutable reference to the GFA 2 segment length.
+    ///
+    /// Setting a length makes the segment a GFA 2 segment.
+    pub fn length_mut(&mut self) -> &mut Option<usize> {
+        &mut self.length
+    }
+
+    /// Returns the sequence.
+    pub fn sequence(&self) -> Option<&BString> {
+        self.sequence.as_ref()
+    }
+
+    /// Returns the optional fields.
+    pub fn data(&self) -> &Data {
+        &self.data
+    }
+
+    /// Returns a mutable reference to the optional fields.
+    pub fn data_mut(&mut self) -> &mut Data {
+        &mut self.data
+    }
+}
+
+impl fmt::Display for Segment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{PREFIX}{FIELD_DELIMITER}{}{FIELD_DELIMITER}", self.name)?;
+
+        if let Some(length) = self.length {
+            write!(f, "{length}{FIELD_DELIMITER}")?;
+        }
+
+        match &self.sequence {
+            Some(sequence) => write!(f, "{sequence}")?,
+            None => f.write_str(MISSING)?,
+        }
+
+        fmt_data(f, &self.data)
+    }
+}
+
+/// An error returned when a raw GFA segment record fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The record type is invalid.
+    InvalidRecordType,
+    /// The name is missing.
+    MissingName,
+    /// The GFA 2 length is invalid.
+    InvalidLength(num::ParseIntError),
+    /// The sequence is missing.
+    MissingSequence,
+    /// The optional fields are invalid.
+    InvalidData(data::ParseError),
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidLength(e) => Some(e),
+            Self::InvalidData(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidRecordType => write!(f, "invalid record type"),
+            Self::MissingName => write!(f, "missing name"),
+            Self::InvalidLength(_) => write!(f, "invalid length"),
+            Self::MissingSequence => write!(f, "missing sequence"),
+            Self::InvalidData(_) => write!(f, "invalid data"),
+        }
+    }
+}
+
+impl FromStr for Segment {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.split(FIELD_DELIMITER);
+
+        if fields.next() != Some(PREFIX) {
+            return Err(ParseError::InvalidRecordType);
+        }
+
+        let name = fields
+            .next()
+            .filter(|t| !t.is_empty())
+            .ok_or(ParseError::MissingName)?;
+
+        let mut raw_sequence = fields.next().ok_or(ParseError::MissingSequence)?;
+
+        // A GFA 1 sequence is never numeric, so a numeric field is a GFA 2 segment length.
+        let length = if is_length(raw_sequence) {
+            let length = raw_sequence.parse().map_err(ParseError::InvalidLength)?;
+            raw_sequence = fields.next().ok_or(ParseError::MissingSequence)?;
+            Some(length)
+        } else {
+            None
+        };
+
+        let sequence = match raw_sequence {
+            "" => return Err(ParseError::MissingSequence),
+            MISSING => None,
+            t => Some(BString::from(t)),
+        };
+
+        let data = parse_data(fields).map_err(ParseError::InvalidData)?;
+
+        Ok(Self {
+            name: name.into(),
+            length,
+            sequence,
+            data,
+        })
+    }
+}
+
+fn is_length(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::alignment::{record::data::field::Tag, record_buf::data::field::Value};
+
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        let mut segment = Segment::new("s1", None);
+        segment
+            .data_mut()
+            .insert(Tag::new(b'L', b'N'), Value::from(8));
+        assert_eq!(segment.to_string(), "S\ts1\t*\tLN:i:8");
+
+        let mut segment = Segment::new("s1", Some(BString::from("ACGT")));
+        *segment.length_mut() = Some(4);
+        assert_eq!(segment.to_string(), "S\ts1\t4\tACGT");
+    }
+
+    #[test]
+    fn test_from_str() -> Result<(), ParseError> {
+        let segment: Segment = "S\ts1\tACGT\tLN:i:4".parse()?;
+        assert_eq!(segment.name(), "s1");
+        assert!(segment.length().is_none());
+        assert_eq!(segment.sequence(), Some(&BString::from("ACGT")));
+        assert_eq!(segment.data().len(), 1);
+
+        let segment: Segment = "S\ts1\t4\t*".parse()?;
+        assert_eq!(segment.length(), Some(4));
+        assert!(segment.sequence().is_none());
+
+        assert_eq!(
+            "L\ts1\tACGT".parse::<Segment>(),
+            Err(ParseError::InvalidRecordType)
+        );
+        assert_eq!("S".parse::<Segment>(), Err(ParseError::MissingName));
+        assert_eq!("S\ts1".parse::<Segment>(), Err(ParseError::MissingSequence));
+        assert_eq!(
+            "S\ts1\t4".parse::<Segment>(),
+            Err(ParseError::MissingSequence)
+        );
+        assert!(matches!(
+            "S\ts1\tACGT\tLN".parse::<Segment>(),
+            Err(ParseError::InvalidData(_))
+        ));
+
+        Ok(())
+    }
+}
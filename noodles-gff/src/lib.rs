@@ -39,8 +39,9 @@ pub mod io;
 pub mod lazy;
 pub mod line;
 pub mod record;
+pub mod validator;
 
-pub use self::{directive::Directive, line::Line, record::Record};
+pub use self::{directive::Directive, line::Line, record::Record, validator::Validator};
 
 #[deprecated(since = "0.33.0", note = "Use `noodles_gff::io::Reader` instead.")]
 pub use self::io::Reader;
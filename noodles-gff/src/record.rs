@@ -12,7 +12,10 @@ pub use self::{
 
 use std::{error, fmt, num, str::FromStr};
 
-use noodles_core::Position;
+use noodles_core::{
+    position::{Positioned, ReferenceSequence},
+    Position,
+};
 
 pub(crate) const MISSING_FIELD: &str = ".";
 const FIELD_DELIMITER: char = '\t';
@@ -174,6 +177,22 @@ impl Record {
     }
 }
 
+impl Positioned for Record {
+    fn reference_sequence(&self) -> Option<ReferenceSequence<'_>> {
+        Some(ReferenceSequence::Name(
+            self.reference_sequence_name().into(),
+        ))
+    }
+
+    fn start(&self) -> Option<Position> {
+        Some(Record::start(self))
+    }
+
+    fn end(&self) -> Option<Position> {
+        Some(Record::end(self))
+    }
+}
+
 impl Default for Record {
     fn default() -> Self {
         Builder::new().build()
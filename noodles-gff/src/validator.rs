@@ -0,0 +1,415 @@
+//! GFF feature validation.
+
+use std::{collections::HashMap, fmt, io};
+
+use noodles_core::Position;
+
+use super::{directive::SequenceRegion, Directive, Line, Record};
+
+const CDS_TYPE: &str = "CDS";
+
+/// A GFF feature validator.
+///
+/// This checks feature coordinates against `##sequence-region` directives and an optional
+/// dictionary of reference sequence lengths, e.g., from a FASTA index or SAM header.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_gff::{self as gff, validator::Violation, Validator};
+///
+/// let mut validator = Validator::default();
+/// validator.add_reference_sequence("sq0", 8);
+///
+/// let record = gff::Record::builder()
+///     .set_reference_sequence_name(String::from("sq0"))
+///     .set_start(Position::try_from(5)?)
+///     .set_end(Position::try_from(13)?)
+///     .build();
+///
+/// assert_eq!(
+///     validator.validate_record(&record),
+///     [Violation::OutOfReferenceSequenceBounds { length: 8 }],
+/// );
+/// # Ok::<_, noodles_core::position::TryFromIntError>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Validator {
+    sequence_regions: HashMap<String, (i32, i32)>,
+    reference_sequence_lengths: HashMap<String, usize>,
+}
+
+impl Validator {
+    /// Adds a reference sequence to the dictionary.
+    ///
+    /// When the dictionary is not empty, features on reference sequences missing from it are
+    /// reported as violations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::Validator;
+    /// let mut validator = Validator::default();
+    /// validator.add_reference_sequence("sq0", 8);
+    /// ```
+    pub fn add_reference_sequence<N>(&mut self, name: N, length: usize)
+    where
+        N: Into<String>,
+    {
+        self.reference_sequence_lengths.insert(name.into(), length);
+    }
+
+    /// Adds a sequence region.
+    ///
+    /// This replaces any previous sequence region with the same reference sequence name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{directive::SequenceRegion, Validator};
+    /// let mut validator = Validator::default();
+    /// validator.add_sequence_region(&SequenceRegion::new(String::from("sq0"), 1, 8));
+    /// ```
+    pub fn add_sequence_region(&mut self, sequence_region: &SequenceRegion) {
+        self.sequence_regions.insert(
+            sequence_region.reference_sequence_name().into(),
+            (sequence_region.start(), sequence_region.end()),
+        );
+    }
+
+    /// Validates a record.
+    ///
+    /// This returns a list of violations, which is empty if the record is valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, Validator};
+    /// let validator = Validator::default();
+    /// let record = gff::Record::default();
+    /// assert!(validator.validate_record(&record).is_empty());
+    /// ```
+    pub fn validate_record(&self, record: &Record) -> Vec<Violation> {
+        let mut violations = Vec::new();
+
+        let start = record.start();
+        let end = record.end();
+
+        if start > end {
+            violations.push(Violation::InvalidInterval { start, end });
+        }
+
+        let reference_sequence_name = record.reference_sequence_name();
+
+        if let Some(&(region_start, region_end)) =
+            self.sequence_regions.get(reference_sequence_name)
+        {
+            if !is_in_sequence_region(start, region_start, region_end)
+                || !is_in_sequence_region(end, region_start, region_end)
+            {
+                violations.push(Violation::OutOfSequenceRegion {
+                    start: region_start,
+                    end: region_end,
+                });
+            }
+        }
+
+        if !self.reference_sequence_lengths.is_empty() {
+            match self.reference_sequence_lengths.get(reference_sequence_name) {
+                Some(&length) => {
+                    if usize::from(start) > length || usize::from(end) > length {
+                        violations.push(Violation::OutOfReferenceSequenceBounds { length });
+                    }
+                }
+                None => violations.push(Violation::MissingReferenceSequence),
+            }
+        }
+
+        if record.ty() == CDS_TYPE && record.phase().is_none() {
+            violations.push(Violation::MissingPhase);
+        }
+
+        violations
+    }
+
+    /// Validates a list of lines.
+    ///
+    /// Sequence region directives are added to the validator as they are read and apply to
+    /// subsequent records.
+    ///
+    /// # Errors
+    ///
+    /// This returns an `io::Error` if a line fails to read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_gff::{self as gff, validator::Violation, Validator};
+    ///
+    /// let data = b"##gff-version 3
+    /// ###sequence-region sq0 1 8
+    /// sq0\tNOODLES\tgene\t3\t5\t.\t+\t.\tID=g0
+    /// sq0\tNOODLES\tgene\t5\t13\t.\t+\t.\tID=g1
+    /// ";
+    /// let mut reader = gff::io::Reader::new(&data[..]);
+    ///
+    /// let mut validator = Validator::default();
+    /// let report = validator.validate(reader.lines())?;
+    ///
+    /// assert_eq!(
+    ///     report.violations(),
+    ///     [(4, Violation::OutOfSequenceRegion { start: 1, end: 8 })],
+    /// );
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn validate<I>(&mut self, lines: I) -> io::Result<Report>
+    where
+        I: IntoIterator<Item = io::Result<Line>>,
+    {
+        let mut report = Report::default();
+
+        for (i, result) in lines.into_iter().enumerate() {
+            let line_number = i + 1;
+
+            match result? {
+                Line::Directive(Directive::SequenceRegion(sequence_region)) => {
+                    self.add_sequence_region(&sequence_region);
+                }
+                Line::Directive(Directive::StartOfFasta) => break,
+                Line::Record(record) => {
+                    let violations = self.validate_record(&record);
+
+                    report.violations.extend(
+                        violations
+                            .into_iter()
+                            .map(|violation| (line_number, violation)),
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+fn is_in_sequence_region(position: Position, start: i32, end: i32) -> bool {
+    i64::try_from(usize::from(position))
+        .map(|n| (i64::from(start)..=i64::from(end)).contains(&n))
+        .unwrap_or(false)
+}
+
+/// A feature validation violation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Violation {
+    /// The start is greater than the end.
+    InvalidInterval {
+        /// The feature start.
+        start: Position,
+        /// The feature end.
+        end: Position,
+    },
+    /// The feature is outside the bounds of its sequence region.
+    OutOfSequenceRegion {
+        /// The sequence region start.
+        start: i32,
+        /// The sequence region end.
+        end: i32,
+    },
+    /// The reference sequence is not in the dictionary.
+    MissingReferenceSequence,
+    /// The feature is outside the bounds of its reference sequence.
+    OutOfReferenceSequenceBounds {
+        /// The reference sequence length.
+        length: usize,
+    },
+    /// The phase is missing from a CDS feature.
+    MissingPhase,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidInterval { start, end } => {
+                write!(f, "invalid interval: start ({start}) > end ({end})")
+            }
+            Self::OutOfSequenceRegion { start, end } => {
+                write!(f, "feature is outside of sequence region {start}-{end}")
+            }
+            Self::MissingReferenceSequence => f.write_str("missing reference sequence"),
+            Self::OutOfReferenceSequenceBounds { length } => write!(
+                f,
+                "feature is outside of reference sequence bounds (length = {length})"
+            ),
+            Self::MissingPhase => f.write_str("missing phase"),
+        }
+    }
+}
+
+/// A feature validation report.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Report {
+    violations: Vec<(usize, Violation)>,
+}
+
+impl Report {
+    /// Returns whether there are no violations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::validator::Report;
+    /// let report = Report::default();
+    /// assert!(report.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Returns the violations with their 1-based line numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::validator::Report;
+    /// let report = Report::default();
+    /// assert!(report.violations().is_empty());
+    /// ```
+    pub fn violations(&self) -> &[(usize, Violation)] {
+        &self.violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::Phase;
+
+    #[test]
+    fn test_validate_record() -> Result<(), noodles_core::position::TryFromIntError> {
+        let mut validator = Validator::default();
+
+        let record = Record::builder()
+            .set_reference_sequence_name("sq0".into())
+            .set_type("gene".into())
+            .set_start(Position::try_from(8)?)
+            .set_end(Position::try_from(5)?)
+            .build();
+        assert_eq!(
+            validator.validate_record(&record),
+            [Violation::InvalidInterval {
+                start: Position::try_from(8)?,
+                end: Position::try_from(5)?,
+            }]
+        );
+
+        let record = Record::builder()
+            .set_reference_sequence_name("sq0".into())
+            .set_type("CDS".into())
+            .set_start(Position::try_from(1)?)
+            .set_end(Position::try_from(3)?)
+            .build();
+        assert_eq!(
+            validator.validate_record(&record),
+            [Violation::MissingPhase]
+        );
+
+        let record = Record::builder()
+            .set_reference_sequence_name("sq0".into())
+            .set_type("CDS".into())
+            .set_start(Position::try_from(1)?)
+            .set_end(Position::try_from(3)?)
+            .set_phase(Phase::Zero)
+            .build();
+        assert!(validator.validate_record(&record).is_empty());
+
+        validator.add_sequence_region(&SequenceRegion::new(String::from("sq0"), 5, 21));
+
+        let record = Record::builder()
+            .set_reference_sequence_name("sq0".into())
+            .set_type("gene".into())
+            .set_start(Position::try_from(5)?)
+            .set_end(Position::try_from(21)?)
+            .build();
+        assert!(validator.validate_record(&record).is_empty());
+
+        let record = Record::builder()
+            .set_reference_sequence_name("sq0".into())
+            .set_type("gene".into())
+            .set_start(Position::try_from(3)?)
+            .set_end(Position::try_from(8)?)
+            .build();
+        assert_eq!(
+            validator.validate_record(&record),
+            [Violation::OutOfSequenceRegion { start: 5, end: 21 }]
+        );
+
+        let record = Record::builder()
+            .set_reference_sequence_name("sq1".into())
+            .set_type("gene".into())
+            .set_start(Position::try_from(3)?)
+            .set_end(Position::try_from(8)?)
+            .build();
+        assert!(validator.validate_record(&record).is_empty());
+
+        validator.add_reference_sequence("sq0", 13);
+
+        let record = Record::builder()
+            .set_reference_sequence_name("sq0".into())
+            .set_type("gene".into())
+            .set_start(Position::try_from(5)?)
+            .set_end(Position::try_from(21)?)
+            .build();
+        assert_eq!(
+            validator.validate_record(&record),
+            [Violation::OutOfReferenceSequenceBounds { length: 13 }]
+        );
+
+        let record = Record::builder()
+            .set_reference_sequence_name("sq1".into())
+            .set_type("gene".into())
+            .set_start(Position::try_from(3)?)
+            .set_end(Position::try_from(8)?)
+            .build();
+        assert_eq!(
+            validator.validate_record(&record),
+            [Violation::MissingReferenceSequence]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate() -> Result<(), Box<dyn std::error::Error>> {
+        let lines = [
+            "##gff-version 3",
+            "##sequence-region sq0 1 8",
+            "sq0\tNOODLES\tgene\t1\t8\t.\t+\t.\tID=g0",
+            "sq1\tNOODLES\tgene\t1\t21\t.\t+\t.\tID=g1",
+            "##sequence-region sq1 1 13",
+            "sq1\tNOODLES\tgene\t1\t21\t.\t+\t.\tID=g2",
+            "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=g3",
+            "##FASTA",
+        ]
+        .into_iter()
+        .map(|s| {
+            s.parse::<Line>()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        });
+
+        let mut validator = Validator::default();
+        let report = validator.validate(lines)?;
+
+        assert_eq!(
+            report.violations(),
+            [
+                (6, Violation::OutOfSequenceRegion { start: 1, end: 13 }),
+                (7, Violation::OutOfSequenceRegion { start: 1, end: 8 }),
+            ]
+        );
+
+        Ok(())
+    }
+}
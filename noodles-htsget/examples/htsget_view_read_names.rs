@@ -0,0 +1,41 @@
+//! Prints the names of reads using the htsget protocol.
+//!
+//! The server is expected to return BAM data.
+
+use std::env;
+
+use futures::TryStreamExt;
+use noodles_bam as bam;
+use noodles_htsget as htsget;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = env::args().skip(1);
+
+    let base_url = args.next().expect("missing base URL").parse()?;
+    let id = args.next().expect("missing ID");
+
+    let client = htsget::Client::new(base_url);
+
+    let mut request = client.reads(id).set_format(htsget::reads::Format::Bam);
+
+    for arg in args {
+        let region = arg.parse()?;
+        request = request.add_region(region);
+    }
+
+    let reads = request.send().await?;
+
+    let mut reader = bam::r#async::io::Reader::new(reads.reader());
+    reader.read_header().await?;
+
+    let mut records = reader.records();
+
+    while let Some(record) = records.try_next().await? {
+        if let Some(name) = record.name() {
+            println!("{name}");
+        }
+    }
+
+    Ok(())
+}
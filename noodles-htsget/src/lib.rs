@@ -5,6 +5,8 @@
 pub(crate) mod chunks;
 mod client;
 mod format;
+#[cfg(feature = "io")]
+mod reader;
 pub mod reads;
 pub(crate) mod request;
 pub mod response;
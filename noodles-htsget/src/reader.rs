@@ -0,0 +1,47 @@
+use std::io;
+
+use bytes::Bytes;
+use futures::{Stream, TryStreamExt};
+use tokio::io::AsyncBufRead;
+use tokio_util::io::StreamReader;
+
+pub(crate) fn reader<S>(chunks: S) -> impl AsyncBufRead + Unpin
+where
+    S: Stream<Item = crate::Result<Bytes>> + Unpin,
+{
+    StreamReader::new(chunks.map_err(|e| io::Error::new(io::ErrorKind::Other, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::stream;
+    use tokio::io::AsyncReadExt;
+
+    use super::*;
+    use crate::Error;
+
+    #[tokio::test]
+    async fn test_reader() -> io::Result<()> {
+        let chunks = stream::iter([
+            Ok(Bytes::from_static(b"nood")),
+            Ok(Bytes::new()),
+            Ok(Bytes::from_static(b"les")),
+        ]);
+
+        let mut buf = Vec::new();
+        reader(chunks).read_to_end(&mut buf).await?;
+
+        assert_eq!(buf, b"noodles");
+
+        let chunks = stream::iter([Ok(Bytes::from_static(b"nood")), Err(Error::InvalidDataUrl)]);
+
+        let mut buf = Vec::new();
+
+        assert!(matches!(
+            reader(chunks).read_to_end(&mut buf).await,
+            Err(e) if e.kind() == io::ErrorKind::Other
+        ));
+
+        Ok(())
+    }
+}
@@ -34,4 +34,30 @@ impl Response {
         use super::chunks::chunks;
         chunks(&self.client, self.ticket.urls())
     }
+
+    /// Returns an async reader over the data from the ticket URLs.
+    ///
+    /// This concatenates the data blocks, which can be given to an async format reader, e.g.,
+    /// `noodles_bam::r#async::io::Reader` or `noodles_vcf::r#async::io::Reader`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use noodles_bam as bam;
+    /// use noodles_htsget as htsget;
+    ///
+    /// let client = htsget::Client::new("https://localhost/".parse()?);
+    /// let reads = client.reads("NDLS0001").send().await?;
+    ///
+    /// let mut reader = bam::r#async::io::Reader::new(reads.reader());
+    /// let header = reader.read_header().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "io")]
+    pub fn reader(&self) -> impl tokio::io::AsyncBufRead + Unpin + '_ {
+        crate::reader::reader(self.chunks())
+    }
 }
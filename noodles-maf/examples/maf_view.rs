@@ -0,0 +1,37 @@
+//! Prints the forward strand regions of each sequence in each alignment block of a MAF file.
+//!
+//! Each line is `<block index>\t<source>\t<start>\t<end>`, where start and end are 1-based.
+
+use std::{
+    env,
+    fs::File,
+    io::{self, BufReader},
+};
+
+use noodles_maf as maf;
+
+fn main() -> io::Result<()> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let mut reader = File::open(src)
+        .map(BufReader::new)
+        .map(maf::io::Reader::new)?;
+
+    reader.read_header()?;
+
+    for (i, result) in reader.blocks().enumerate() {
+        let block = result?;
+
+        for sequence in block.sequences() {
+            if let Some(interval) = sequence.forward_interval() {
+                let (Some(start), Some(end)) = (interval.start(), interval.end()) else {
+                    continue;
+                };
+
+                println!("{i}\t{}\t{start}\t{end}", sequence.source());
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,310 @@
+//! MAF alignment block and rows.
+
+pub mod empty;
+pub mod info;
+pub mod quality;
+pub mod sequence;
+pub mod status;
+pub mod strand;
+
+pub use self::{
+    empty::Empty, info::Info, quality::Quality, sequence::Sequence, status::Status, strand::Strand,
+};
+
+use std::{error, fmt, num, str::FromStr};
+
+const LINE_DELIMITER: char = '\n';
+const SCORE_KEY: &str = "score";
+
+/// A MAF alignment block row.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Line {
+    /// A sequence row (`s`).
+    Sequence(Sequence),
+    /// An information row (`i`).
+    Info(Info),
+    /// An empty region row (`e`).
+    Empty(Empty),
+    /// A quality row (`q`).
+    Quality(Quality),
+}
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sequence(sequence) => write!(f, "{sequence}"),
+            Self::Info(info) => write!(f, "{info}"),
+            Self::Empty(empty) => write!(f, "{empty}"),
+            Self::Quality(quality) => write!(f, "{quality}"),
+        }
+    }
+}
+
+/// A MAF alignment block.
+///
+/// A block starts with an alignment line (`a`) and is followed by rows, one sequence row (`s`)
+/// per aligned sequence, which can be annotated by information (`i`) and quality (`q`) rows.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Block {
+    score: Option<f64>,
+    other_fields: Vec<(String, String)>,
+    lines: Vec<Line>,
+}
+
+impl Block {
+    /// Returns the alignment score (`score`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_maf as maf;
+    /// let block: maf::Block = "a score=23262.0\ns hg16.chr7 27578828 4 + 158545518 AAAG".parse()?;
+    /// assert_eq!(block.score(), Some(23262.0));
+    /// # Ok::<_, maf::block::ParseError>(())
+    /// ```
+    pub fn score(&self) -> Option<f64> {
+        self.score
+    }
+
+    /// Returns the alignment line fields other than the score, e.g., `pass`.
+    pub fn other_fields(&self) -> &[(String, String)] {
+        &self.other_fields
+    }
+
+    /// Returns the rows.
+    pub fn lines(&self) -> &[Line] {
+        &self.lines
+    }
+
+    /// Returns an iterator over the sequence rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_maf as maf;
+    ///
+    /// let block: maf::Block = "a score=0
+    /// s hg16.chr7 27578828 4 + 158545518 AAAG
+    /// i hg16.chr7 N 0 C 0
+    /// s panTro1.chr6 28741140 4 + 161576975 AAAG"
+    ///     .parse()?;
+    ///
+    /// let sources: Vec<_> = block.sequences().map(|sequence| sequence.source()).collect();
+    /// assert_eq!(sources, ["hg16.chr7", "panTro1.chr6"]);
+    /// # Ok::<_, maf::block::ParseError>(())
+    /// ```
+    pub fn sequences(&self) -> impl Iterator<Item = &Sequence> {
+        self.lines.iter().filter_map(|line| match line {
+            Line::Sequence(sequence) => Some(sequence),
+            _ => None,
+        })
+    }
+
+    /// Returns the first sequence row of the given species.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_maf as maf;
+    ///
+    /// let block: maf::Block = "a score=0
+    /// s hg16.chr7 27578828 4 + 158545518 AAAG
+    /// s panTro1.chr6 28741140 4 + 161576975 AAAG"
+    ///     .parse()?;
+    ///
+    /// let sequence = block.get("panTro1");
+    /// assert_eq!(sequence.map(|s| s.source()), Some("panTro1.chr6"));
+    ///
+    /// assert!(block.get("mm4").is_none());
+    /// # Ok::<_, maf::block::ParseError>(())
+    /// ```
+    pub fn get(&self, species: &str) -> Option<&Sequence> {
+        self.sequences()
+            .find(|sequence| sequence.species() == species)
+    }
+
+    /// Returns the number of alignment columns.
+    ///
+    /// This is the length of the text of the first sequence row.
+    pub fn column_count(&self) -> usize {
+        self.sequences()
+            .next()
+            .map(|sequence| sequence.text().len())
+            .unwrap_or_default()
+    }
+}
+
+impl fmt::Display for Block {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a")?;
+
+        if let Some(score) = self.score {
+            write!(f, " {SCORE_KEY}={score}")?;
+        }
+
+        for (key, value) in &self.other_fields {
+            write!(f, " {key}={value}")?;
+        }
+
+        for line in &self.lines {
+            write!(f, "{LINE_DELIMITER}{line}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned when a raw MAF block fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The alignment line (`a`) is missing.
+    MissingAlignmentLine,
+    /// An alignment line field is invalid.
+    InvalidField(String),
+    /// The score is invalid.
+    InvalidScore(num::ParseFloatError),
+    /// A row type is invalid.
+    InvalidLineType(String),
+    /// A sequence row is invalid.
+    InvalidSequence(sequence::ParseError),
+    /// An information row is invalid.
+    InvalidInfo(info::ParseError),
+    /// An empty region row is invalid.
+    InvalidEmpty(empty::ParseError),
+    /// A quality row is invalid.
+    InvalidQuality(quality::ParseError),
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidScore(e) => Some(e),
+            Self::InvalidSequence(e) => Some(e),
+            Self::InvalidInfo(e) => Some(e),
+            Self::InvalidEmpty(e) => Some(e),
+            Self::InvalidQuality(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingAlignmentLine => write!(f, "missing alignment line"),
+            Self::InvalidField(s) => write!(f, "invalid field: {s}"),
+            Self::InvalidScore(_) => write!(f, "invalid score"),
+            Self::InvalidLineType(s) => write!(f, "invalid line type: {s}"),
+            Self::InvalidSequence(_) => write!(f, "invalid sequence line"),
+            Self::InvalidInfo(_) => write!(f, "invalid info line"),
+            Self::InvalidEmpty(_) => write!(f, "invalid empty line"),
+            Self::InvalidQuality(_) => write!(f, "invalid quality line"),
+        }
+    }
+}
+
+impl FromStr for Block {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+
+        let mut tokens = lines
+            .next()
+            .map(|line| line.split_ascii_whitespace())
+            .ok_or(ParseError::MissingAlignmentLine)?;
+
+        if tokens.next() != Some("a") {
+            return Err(ParseError::MissingAlignmentLine);
+        }
+
+        let mut other_fields =
+            crate::parse_fields(tokens).map_err(|t| ParseError::InvalidField(t.into()))?;
+
+        let score = match other_fields.iter().position(|(key, _)| key == SCORE_KEY) {
+            Some(i) => {
+                let (_, value) = other_fields.remove(i);
+                value.parse().map(Some).map_err(ParseError::InvalidScore)?
+            }
+            None => None,
+        };
+
+        let lines = lines.map(parse_line).collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            score,
+            other_fields,
+            lines,
+        })
+    }
+}
+
+fn parse_line(s: &str) -> Result<Line, ParseError> {
+    match s.split_ascii_whitespace().next() {
+        Some("s") => s
+            .parse()
+            .map(Line::Sequence)
+            .map_err(ParseError::InvalidSequence),
+        Some("i") => s.parse().map(Line::Info).map_err(ParseError::InvalidInfo),
+        Some("e") => s.parse().map(Line::Empty).map_err(ParseError::InvalidEmpty),
+        Some("q") => s
+            .parse()
+            .map(Line::Quality)
+            .map_err(ParseError::InvalidQuality),
+        Some(t) => Err(ParseError::InvalidLineType(t.into())),
+        None => Err(ParseError::InvalidLineType(String::new())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RAW_BLOCK: &str = "a score=23262 pass=2
+s hg16.chr7 27578828 38 + 158545518 AAA-GGGAATGTTAACCAAATGA---ATTGTCTCTTACGGTG
+s panTro1.chr6 28741140 38 + 161576975 AAA-GGGAATGTTAACCAAATGA---ATTGTCTCTTACGGTG
+q panTro1.chr6 999-99999999999999999999---9999999999999999
+i panTro1.chr6 N 0 C 0
+e mm4.chr6 53310102 13 + 151104725 I";
+
+    #[test]
+    fn test_fmt() -> Result<(), ParseError> {
+        let block: Block = RAW_BLOCK.parse()?;
+        assert_eq!(block.to_string(), RAW_BLOCK);
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str() -> Result<(), ParseError> {
+        let block: Block = RAW_BLOCK.parse()?;
+
+        assert_eq!(block.score(), Some(23262.0));
+        assert_eq!(
+            block.other_fields(),
+            [(String::from("pass"), String::from("2"))]
+        );
+        assert_eq!(block.lines().len(), 5);
+        assert_eq!(block.sequences().count(), 2);
+        assert_eq!(block.column_count(), 42);
+
+        assert_eq!("".parse::<Block>(), Err(ParseError::MissingAlignmentLine));
+        assert_eq!(
+            "s hg16.chr7 0 1 + 1 A".parse::<Block>(),
+            Err(ParseError::MissingAlignmentLine)
+        );
+        assert!(matches!(
+            "a score=.".parse::<Block>(),
+            Err(ParseError::InvalidScore(_))
+        ));
+        assert_eq!(
+            "a score=0\nx".parse::<Block>(),
+            Err(ParseError::InvalidLineType(String::from("x")))
+        );
+        assert!(matches!(
+            "a score=0\ns hg16.chr7".parse::<Block>(),
+            Err(ParseError::InvalidSequence(_))
+        ));
+
+        Ok(())
+    }
+}
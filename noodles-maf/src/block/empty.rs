@@ -0,0 +1,203 @@
+//! MAF block empty region row (`e`).
+
+use std::{error, fmt, num, str::FromStr};
+
+use noodles_core::region::Interval;
+
+use super::{sequence::forward_interval, status, strand, Status, Strand};
+
+/// A MAF block empty region row (`e`).
+///
+/// This describes a source sequence that does not align in the block but aligns in the
+/// previous or next block. The coordinates are the same as those of a sequence row.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Empty {
+    source: String,
+    start: usize,
+    size: usize,
+    strand: Strand,
+    source_size: usize,
+    status: Status,
+}
+
+impl Empty {
+    /// Returns the source name (`src`).
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the 0-based start of the region relative to the strand (`start`).
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the number of bases in the region (`size`).
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the strand (`strand`).
+    pub fn strand(&self) -> Strand {
+        self.strand
+    }
+
+    /// Returns the length of the entire source sequence (`srcSize`).
+    pub fn source_size(&self) -> usize {
+        self.source_size
+    }
+
+    /// Returns the relationship with the sequences in the adjacent blocks (`status`).
+    pub fn status(&self) -> Status {
+        self.status
+    }
+
+    /// Returns the region on the forward strand of the source sequence.
+    ///
+    /// See [`super::Sequence::forward_interval`].
+    pub fn forward_interval(&self) -> Option<Interval> {
+        forward_interval(self.start, self.size, self.strand, self.source_size)
+    }
+}
+
+impl fmt::Display for Empty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "e {} {} {} {} {} {}",
+            self.source, self.start, self.size, self.strand, self.source_size, self.status
+        )
+    }
+}
+
+/// An error returned when a raw MAF block empty region row fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The line type is invalid.
+    InvalidType,
+    /// The source is missing.
+    MissingSource,
+    /// The start is missing.
+    MissingStart,
+    /// The start is invalid.
+    InvalidStart(num::ParseIntError),
+    /// The size is missing.
+    MissingSize,
+    /// The size is invalid.
+    InvalidSize(num::ParseIntError),
+    /// The strand is missing.
+    MissingStrand,
+    /// The strand is invalid.
+    InvalidStrand(strand::ParseError),
+    /// The source size is missing.
+    MissingSourceSize,
+    /// The source size is invalid.
+    InvalidSourceSize(num::ParseIntError),
+    /// The status is missing.
+    MissingStatus,
+    /// The status is invalid.
+    InvalidStatus(status::ParseError),
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidStart(e) | Self::InvalidSize(e) | Self::InvalidSourceSize(e) => Some(e),
+            Self::InvalidStrand(e) => Some(e),
+            Self::InvalidStatus(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidType => write!(f, "invalid type"),
+            Self::MissingSource => write!(f, "missing source"),
+            Self::MissingStart => write!(f, "missing start"),
+            Self::InvalidStart(_) => write!(f, "invalid start"),
+            Self::MissingSize => write!(f, "missing size"),
+            Self::InvalidSize(_) => write!(f, "invalid size"),
+            Self::MissingStrand => write!(f, "missing strand"),
+            Self::InvalidStrand(_) => write!(f, "invalid strand"),
+            Self::MissingSourceSize => write!(f, "missing source size"),
+            Self::InvalidSourceSize(_) => write!(f, "invalid source size"),
+            Self::MissingStatus => write!(f, "missing status"),
+            Self::InvalidStatus(_) => write!(f, "invalid status"),
+        }
+    }
+}
+
+impl FromStr for Empty {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.split_ascii_whitespace();
+
+        if fields.next() != Some("e") {
+            return Err(ParseError::InvalidType);
+        }
+
+        let source = fields.next().ok_or(ParseError::MissingSource)?;
+
+        let start = fields
+            .next()
+            .ok_or(ParseError::MissingStart)
+            .and_then(|t| t.parse().map_err(ParseError::InvalidStart))?;
+
+        let size = fields
+            .next()
+            .ok_or(ParseError::MissingSize)
+            .and_then(|t| t.parse().map_err(ParseError::InvalidSize))?;
+
+        let strand = fields
+            .next()
+            .ok_or(ParseError::MissingStrand)
+            .and_then(|t| t.parse().map_err(ParseError::InvalidStrand))?;
+
+        let source_size = fields
+            .next()
+            .ok_or(ParseError::MissingSourceSize)
+            .and_then(|t| t.parse().map_err(ParseError::InvalidSourceSize))?;
+
+        let status = fields
+            .next()
+            .ok_or(ParseError::MissingStatus)
+            .and_then(|t| t.parse().map_err(ParseError::InvalidStatus))?;
+
+        Ok(Self {
+            source: source.into(),
+            start,
+            size,
+            strand,
+            source_size,
+            status,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "e mm4.chr6 53310102 13 + 151104725 I".parse(),
+            Ok(Empty {
+                source: String::from("mm4.chr6"),
+                start: 53310102,
+                size: 13,
+                strand: Strand::Forward,
+                source_size: 151104725,
+                status: Status::Intervening,
+            })
+        );
+
+        assert_eq!("s".parse::<Empty>(), Err(ParseError::InvalidType));
+        assert_eq!(
+            "e mm4.chr6 53310102 13 + 151104725".parse::<Empty>(),
+            Err(ParseError::MissingStatus)
+        );
+    }
+}
@@ -0,0 +1,178 @@
+//! MAF block information row (`i`).
+
+use std::{error, fmt, num, str::FromStr};
+
+use super::{status, Status};
+
+/// A MAF block information row (`i`).
+///
+/// This describes the context of the sequence in the preceding sequence row relative to the
+/// sequences of the same species in the previous and next blocks.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Info {
+    source: String,
+    left_status: Status,
+    left_count: usize,
+    right_status: Status,
+    right_count: usize,
+}
+
+impl Info {
+    /// Returns the source name (`src`).
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the relationship with the sequence in the previous block (`leftStatus`).
+    pub fn left_status(&self) -> Status {
+        self.left_status
+    }
+
+    /// Returns the number of bases or gaps between this and the previous block (`leftCount`).
+    pub fn left_count(&self) -> usize {
+        self.left_count
+    }
+
+    /// Returns the relationship with the sequence in the next block (`rightStatus`).
+    pub fn right_status(&self) -> Status {
+        self.right_status
+    }
+
+    /// Returns the number of bases or gaps between this and the next block (`rightCount`).
+    pub fn right_count(&self) -> usize {
+        self.right_count
+    }
+}
+
+impl fmt::Display for Info {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "i {} {} {} {} {}",
+            self.source, self.left_status, self.left_count, self.right_status, self.right_count
+        )
+    }
+}
+
+/// An error returned when a raw MAF block information row fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The line type is invalid.
+    InvalidType,
+    /// The source is missing.
+    MissingSource,
+    /// The left status is missing.
+    MissingLeftStatus,
+    /// The left status is invalid.
+    InvalidLeftStatus(status::ParseError),
+    /// The left count is missing.
+    MissingLeftCount,
+    /// The left count is invalid.
+    InvalidLeftCount(num::ParseIntError),
+    /// The right status is missing.
+    MissingRightStatus,
+    /// The right status is invalid.
+    InvalidRightStatus(status::ParseError),
+    /// The right count is missing.
+    MissingRightCount,
+    /// The right count is invalid.
+    InvalidRightCount(num::ParseIntError),
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidLeftStatus(e) | Self::InvalidRightStatus(e) => Some(e),
+            Self::InvalidLeftCount(e) | Self::InvalidRightCount(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidType => write!(f, "invalid type"),
+            Self::MissingSource => write!(f, "missing source"),
+            Self::MissingLeftStatus => write!(f, "missing left status"),
+            Self::InvalidLeftStatus(_) => write!(f, "invalid left status"),
+            Self::MissingLeftCount => write!(f, "missing left count"),
+            Self::InvalidLeftCount(_) => write!(f, "invalid left count"),
+            Self::MissingRightStatus => write!(f, "missing right status"),
+            Self::InvalidRightStatus(_) => write!(f, "invalid right status"),
+            Self::MissingRightCount => write!(f, "missing right count"),
+            Self::InvalidRightCount(_) => write!(f, "invalid right count"),
+        }
+    }
+}
+
+impl FromStr for Info {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.split_ascii_whitespace();
+
+        if fields.next() != Some("i") {
+            return Err(ParseError::InvalidType);
+        }
+
+        let source = fields.next().ok_or(ParseError::MissingSource)?;
+
+        let left_status = fields
+            .next()
+            .ok_or(ParseError::MissingLeftStatus)
+            .and_then(|t| t.parse().map_err(ParseError::InvalidLeftStatus))?;
+
+        let left_count = fields
+            .next()
+            .ok_or(ParseError::MissingLeftCount)
+            .and_then(|t| t.parse().map_err(ParseError::InvalidLeftCount))?;
+
+        let right_status = fields
+            .next()
+            .ok_or(ParseError::MissingRightStatus)
+            .and_then(|t| t.parse().map_err(ParseError::InvalidRightStatus))?;
+
+        let right_count = fields
+            .next()
+            .ok_or(ParseError::MissingRightCount)
+            .and_then(|t| t.parse().map_err(ParseError::InvalidRightCount))?;
+
+        Ok(Self {
+            source: source.into(),
+            left_status,
+            left_count,
+            right_status,
+            right_count,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "i panTro1.chr6 N 0 C 0".parse(),
+            Ok(Info {
+                source: String::from("panTro1.chr6"),
+                left_status: Status::New,
+                left_count: 0,
+                right_status: Status::Contiguous,
+                right_count: 0,
+            })
+        );
+
+        assert_eq!("s".parse::<Info>(), Err(ParseError::InvalidType));
+        assert!(matches!(
+            "i panTro1.chr6 X 0 C 0".parse::<Info>(),
+            Err(ParseError::InvalidLeftStatus(_))
+        ));
+        assert_eq!(
+            "i panTro1.chr6 N 0 C".parse::<Info>(),
+            Err(ParseError::MissingRightCount)
+        );
+    }
+}
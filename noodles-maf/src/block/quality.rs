@@ -0,0 +1,114 @@
+//! MAF block quality row (`q`).
+
+use std::{error, fmt, str::FromStr};
+
+/// A MAF block quality row (`q`).
+///
+/// This holds the quality scores of the sequence in the preceding sequence row. Each value is
+/// either a gap (`-`), a quality score from `0` to `9`, or `F` (finished).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Quality {
+    source: String,
+    values: Vec<u8>,
+}
+
+impl Quality {
+    /// Returns the source name (`src`).
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the raw quality values, including gaps (`value`).
+    pub fn values(&self) -> &[u8] {
+        &self.values
+    }
+}
+
+impl fmt::Display for Quality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "q {} ", self.source)?;
+
+        self.values
+            .iter()
+            .try_for_each(|&b| write!(f, "{}", char::from(b)))
+    }
+}
+
+/// An error returned when a raw MAF block quality row fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The line type is invalid.
+    InvalidType,
+    /// The source is missing.
+    MissingSource,
+    /// The values are missing.
+    MissingValues,
+    /// The values are invalid.
+    InvalidValues,
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidType => write!(f, "invalid type"),
+            Self::MissingSource => write!(f, "missing source"),
+            Self::MissingValues => write!(f, "missing values"),
+            Self::InvalidValues => write!(f, "invalid values"),
+        }
+    }
+}
+
+impl FromStr for Quality {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.split_ascii_whitespace();
+
+        if fields.next() != Some("q") {
+            return Err(ParseError::InvalidType);
+        }
+
+        let source = fields.next().ok_or(ParseError::MissingSource)?;
+        let values = fields.next().ok_or(ParseError::MissingValues)?;
+
+        if !values
+            .bytes()
+            .all(|b| matches!(b, b'-' | b'0'..=b'9' | b'F'))
+        {
+            return Err(ParseError::InvalidValues);
+        }
+
+        Ok(Self {
+            source: source.into(),
+            values: values.as_bytes().to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "q panTro1.chr6 99-9F".parse(),
+            Ok(Quality {
+                source: String::from("panTro1.chr6"),
+                values: b"99-9F".to_vec(),
+            })
+        );
+
+        assert_eq!("s".parse::<Quality>(), Err(ParseError::InvalidType));
+        assert_eq!(
+            "q panTro1.chr6".parse::<Quality>(),
+            Err(ParseError::MissingValues)
+        );
+        assert_eq!(
+            "q panTro1.chr6 99!9".parse::<Quality>(),
+            Err(ParseError::InvalidValues)
+        );
+    }
+}
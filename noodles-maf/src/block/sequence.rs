@@ -0,0 +1,340 @@
+//! MAF block sequence row (`s`).
+
+use std::{error, fmt, num, str::FromStr};
+
+use noodles_core::{region::Interval, Position};
+
+use super::{strand, Strand};
+
+const GAP: u8 = b'-';
+
+/// A MAF block sequence row (`s`).
+///
+/// The start is 0-based and relative to the strand, i.e., if the strand is reverse, the start is
+/// from the end of the reverse-complemented source sequence.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sequence {
+    source: String,
+    start: usize,
+    size: usize,
+    strand: Strand,
+    source_size: usize,
+    text: Vec<u8>,
+}
+
+impl Sequence {
+    /// Returns the source name (`src`).
+    ///
+    /// This is typically in the form `<species>.<chromosome>`, e.g., `hg38.chr1`.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the species, i.e., the part of the source name before the first `.`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_maf::block::Sequence;
+    /// let sequence: Sequence = "s hg38.chr1 0 4 + 8 ACGT".parse()?;
+    /// assert_eq!(sequence.species(), "hg38");
+    /// # Ok::<_, noodles_maf::block::sequence::ParseError>(())
+    /// ```
+    pub fn species(&self) -> &str {
+        self.source
+            .split_once('.')
+            .map(|(species, _)| species)
+            .unwrap_or(&self.source)
+    }
+
+    /// Returns the 0-based start of the aligned region relative to the strand (`start`).
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the number of bases in the aligned region, excluding gaps (`size`).
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the strand (`strand`).
+    pub fn strand(&self) -> Strand {
+        self.strand
+    }
+
+    /// Returns the length of the entire source sequence (`srcSize`).
+    pub fn source_size(&self) -> usize {
+        self.source_size
+    }
+
+    /// Returns the aligned bases, including gaps (`text`).
+    pub fn text(&self) -> &[u8] {
+        &self.text
+    }
+
+    /// Returns the aligned region on the forward strand of the source sequence.
+    ///
+    /// The interval is 1-based and inclusive. This returns `None` if the region is empty or
+    /// exceeds the source sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_maf::block::Sequence;
+    ///
+    /// let sequence: Sequence = "s hg38.chr1 2 4 - 13 AC-GT".parse()?;
+    ///
+    /// let interval = sequence.forward_interval().unwrap();
+    /// assert_eq!(interval.start(), Position::new(8));
+    /// assert_eq!(interval.end(), Position::new(11));
+    /// # Ok::<_, noodles_maf::block::sequence::ParseError>(())
+    /// ```
+    pub fn forward_interval(&self) -> Option<Interval> {
+        forward_interval(self.start, self.size, self.strand, self.source_size)
+    }
+
+    /// Returns the position on the forward strand of the source sequence of the base in the
+    /// given alignment column.
+    ///
+    /// The column is 0-based. This returns `None` if the column is out of bounds or is a gap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_maf::block::Sequence;
+    ///
+    /// let sequence: Sequence = "s hg38.chr1 2 4 + 13 AC-GT".parse()?;
+    /// assert_eq!(sequence.position(3), Position::new(5));
+    /// assert!(sequence.position(2).is_none());
+    ///
+    /// let sequence: Sequence = "s hg38.chr1 2 4 - 13 AC-GT".parse()?;
+    /// assert_eq!(sequence.position(3), Position::new(9));
+    /// # Ok::<_, noodles_maf::block::sequence::ParseError>(())
+    /// ```
+    pub fn position(&self, column: usize) -> Option<Position> {
+        let b = self.text.get(column).copied()?;
+
+        if b == GAP {
+            return None;
+        }
+
+        let offset = self.text[..column].iter().filter(|&&b| b != GAP).count();
+        let i = self.start.checked_add(offset)?;
+
+        let j = match self.strand {
+            Strand::Forward => i,
+            Strand::Reverse => self.source_size.checked_sub(i)?.checked_sub(1)?,
+        };
+
+        Position::new(j + 1)
+    }
+}
+
+pub(super) fn forward_interval(
+    start: usize,
+    size: usize,
+    strand: Strand,
+    source_size: usize,
+) -> Option<Interval> {
+    if size == 0 {
+        return None;
+    }
+
+    let end = start.checked_add(size)?;
+
+    if end > source_size {
+        return None;
+    }
+
+    let forward_start = match strand {
+        Strand::Forward => start,
+        Strand::Reverse => source_size - end,
+    };
+
+    let interval_start = Position::new(forward_start + 1)?;
+    let interval_end = Position::new(forward_start + size)?;
+
+    Some(Interval::from(interval_start..=interval_end))
+}
+
+impl fmt::Display for Sequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "s {} {} {} {} {} ",
+            self.source, self.start, self.size, self.strand, self.source_size
+        )?;
+
+        self.text
+            .iter()
+            .try_for_each(|&b| write!(f, "{}", char::from(b)))
+    }
+}
+
+/// An error returned when a raw MAF block sequence row fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The line type is invalid.
+    InvalidType,
+    /// The source is missing.
+    MissingSource,
+    /// The start is missing.
+    MissingStart,
+    /// The start is invalid.
+    InvalidStart(num::ParseIntError),
+    /// The size is missing.
+    MissingSize,
+    /// The size is invalid.
+    InvalidSize(num::ParseIntError),
+    /// The strand is missing.
+    MissingStrand,
+    /// The strand is invalid.
+    InvalidStrand(strand::ParseError),
+    /// The source size is missing.
+    MissingSourceSize,
+    /// The source size is invalid.
+    InvalidSourceSize(num::ParseIntError),
+    /// The text is missing.
+    MissingText,
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidStart(e) | Self::InvalidSize(e) | Self::InvalidSourceSize(e) => Some(e),
+            Self::InvalidStrand(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidType => write!(f, "invalid type"),
+            Self::MissingSource => write!(f, "missing source"),
+            Self::MissingStart => write!(f, "missing start"),
+            Self::InvalidStart(_) => write!(f, "invalid start"),
+            Self::MissingSize => write!(f, "missing size"),
+            Self::InvalidSize(_) => write!(f, "invalid size"),
+            Self::MissingStrand => write!(f, "missing strand"),
+            Self::InvalidStrand(_) => write!(f, "invalid strand"),
+            Self::MissingSourceSize => write!(f, "missing source size"),
+            Self::InvalidSourceSize(_) => write!(f, "invalid source size"),
+            Self::MissingText => write!(f, "missing text"),
+        }
+    }
+}
+
+impl FromStr for Sequence {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.split_ascii_whitespace();
+
+        if fields.next() != Some("s") {
+            return Err(ParseError::InvalidType);
+        }
+
+        let source = fields.next().ok_or(ParseError::MissingSource)?;
+
+        let start = fields
+            .next()
+            .ok_or(ParseError::MissingStart)
+            .and_then(|t| t.parse().map_err(ParseError::InvalidStart))?;
+
+        let size = fields
+            .next()
+            .ok_or(ParseError::MissingSize)
+            .and_then(|t| t.parse().map_err(ParseError::InvalidSize))?;
+
+        let strand = fields
+            .next()
+            .ok_or(ParseError::MissingStrand)
+            .and_then(|t| t.parse().map_err(ParseError::InvalidStrand))?;
+
+        let source_size = fields
+            .next()
+            .ok_or(ParseError::MissingSourceSize)
+            .and_then(|t| t.parse().map_err(ParseError::InvalidSourceSize))?;
+
+        let text = fields.next().ok_or(ParseError::MissingText)?;
+
+        Ok(Self {
+            source: source.into(),
+            start,
+            size,
+            strand,
+            source_size,
+            text: text.as_bytes().to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_forward_interval() -> Result<(), noodles_core::position::TryFromIntError> {
+        let interval = forward_interval(2, 4, Strand::Forward, 13);
+        assert_eq!(
+            interval,
+            Some(Interval::from(
+                Position::try_from(3)?..=Position::try_from(6)?
+            ))
+        );
+
+        let interval = forward_interval(2, 4, Strand::Reverse, 13);
+        assert_eq!(
+            interval,
+            Some(Interval::from(
+                Position::try_from(8)?..=Position::try_from(11)?
+            ))
+        );
+
+        assert!(forward_interval(2, 0, Strand::Forward, 13).is_none());
+        assert!(forward_interval(10, 4, Strand::Reverse, 13).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fmt() -> Result<(), ParseError> {
+        let sequence: Sequence = "s hg38.chr1   2 4 + 13 AC-GT".parse()?;
+        assert_eq!(sequence.to_string(), "s hg38.chr1 2 4 + 13 AC-GT");
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "s hg38.chr1 2 4 - 13 AC-GT".parse(),
+            Ok(Sequence {
+                source: String::from("hg38.chr1"),
+                start: 2,
+                size: 4,
+                strand: Strand::Reverse,
+                source_size: 13,
+                text: b"AC-GT".to_vec(),
+            })
+        );
+
+        assert_eq!("e".parse::<Sequence>(), Err(ParseError::InvalidType));
+        assert_eq!("s".parse::<Sequence>(), Err(ParseError::MissingSource));
+        assert!(matches!(
+            "s hg38.chr1 . 4 - 13 AC-GT".parse::<Sequence>(),
+            Err(ParseError::InvalidStart(_))
+        ));
+        assert!(matches!(
+            "s hg38.chr1 2 4 . 13 AC-GT".parse::<Sequence>(),
+            Err(ParseError::InvalidStrand(_))
+        ));
+        assert_eq!(
+            "s hg38.chr1 2 4 - 13".parse::<Sequence>(),
+            Err(ParseError::MissingText)
+        );
+    }
+}
@@ -0,0 +1,97 @@
+//! MAF block row status.
+
+use std::{error, fmt, str::FromStr};
+
+/// A MAF block row status.
+///
+/// This describes the relationship between the sequence of a row and the sequence of the same
+/// species in the previous or next block.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Status {
+    /// The sequences are contiguous (`C`).
+    Contiguous,
+    /// There are bases between the sequences (`I`).
+    Intervening,
+    /// This is the first sequence from the source in the file (`N`).
+    New,
+    /// This is the first sequence from the source, but it bridges another sequence (`n`).
+    NewBridged,
+    /// There is missing data before or after the sequence (`M`).
+    Missing,
+    /// The sequence in the previous or next block is a tandem duplication (`T`).
+    Tandem,
+}
+
+impl AsRef<str> for Status {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Contiguous => "C",
+            Self::Intervening => "I",
+            Self::New => "N",
+            Self::NewBridged => "n",
+            Self::Missing => "M",
+            Self::Tandem => "T",
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// An error returned when a raw MAF block row status fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The input is invalid.
+    Invalid,
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::Invalid => f.write_str("invalid input"),
+        }
+    }
+}
+
+impl FromStr for Status {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err(ParseError::Empty),
+            "C" => Ok(Self::Contiguous),
+            "I" => Ok(Self::Intervening),
+            "N" => Ok(Self::New),
+            "n" => Ok(Self::NewBridged),
+            "M" => Ok(Self::Missing),
+            "T" => Ok(Self::Tandem),
+            _ => Err(ParseError::Invalid),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("C".parse(), Ok(Status::Contiguous));
+        assert_eq!("I".parse(), Ok(Status::Intervening));
+        assert_eq!("N".parse(), Ok(Status::New));
+        assert_eq!("n".parse(), Ok(Status::NewBridged));
+        assert_eq!("M".parse(), Ok(Status::Missing));
+        assert_eq!("T".parse(), Ok(Status::Tandem));
+
+        assert_eq!("".parse::<Status>(), Err(ParseError::Empty));
+        assert_eq!("ndls".parse::<Status>(), Err(ParseError::Invalid));
+    }
+}
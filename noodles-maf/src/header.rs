@@ -0,0 +1,149 @@
+//! MAF header.
+
+use std::{error, fmt, str::FromStr};
+
+const PREFIX: &str = "##maf";
+const VERSION_KEY: &str = "version";
+
+/// A MAF header.
+///
+/// This is the first line of a MAF file, e.g., `##maf version=1 scoring=tba.v8`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Header {
+    version: String,
+    other_fields: Vec<(String, String)>,
+}
+
+impl Header {
+    /// Returns the format version (`version`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_maf as maf;
+    /// let header: maf::Header = "##maf version=1".parse()?;
+    /// assert_eq!(header.version(), "1");
+    /// # Ok::<_, maf::header::ParseError>(())
+    /// ```
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Returns the fields other than the version, e.g., `scoring` and `program`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_maf as maf;
+    /// let header: maf::Header = "##maf version=1 scoring=tba.v8".parse()?;
+    /// assert_eq!(header.other_fields(), [(String::from("scoring"), String::from("tba.v8"))]);
+    /// # Ok::<_, maf::header::ParseError>(())
+    /// ```
+    pub fn other_fields(&self) -> &[(String, String)] {
+        &self.other_fields
+    }
+}
+
+impl Default for Header {
+    fn default() -> Self {
+        Self {
+            version: String::from("1"),
+            other_fields: Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for Header {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{PREFIX} {VERSION_KEY}={}", self.version)?;
+
+        for (key, value) in &self.other_fields {
+            write!(f, " {key}={value}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned when a raw MAF header fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The prefix (`##maf`) is missing.
+    MissingPrefix,
+    /// A field is invalid.
+    InvalidField(String),
+    /// The version is missing.
+    MissingVersion,
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingPrefix => write!(f, "missing prefix"),
+            Self::InvalidField(s) => write!(f, "invalid field: {s}"),
+            Self::MissingVersion => write!(f, "missing version"),
+        }
+    }
+}
+
+impl FromStr for Header {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = s.split_ascii_whitespace();
+
+        if tokens.next() != Some(PREFIX) {
+            return Err(ParseError::MissingPrefix);
+        }
+
+        let mut fields =
+            crate::parse_fields(tokens).map_err(|s| ParseError::InvalidField(s.into()))?;
+
+        let i = fields
+            .iter()
+            .position(|(key, _)| key == VERSION_KEY)
+            .ok_or(ParseError::MissingVersion)?;
+
+        let (_, version) = fields.remove(i);
+
+        Ok(Self {
+            version,
+            other_fields: fields,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() -> Result<(), ParseError> {
+        assert_eq!(Header::default().to_string(), "##maf version=1");
+
+        let header: Header = "##maf version=1 scoring=tba.v8".parse()?;
+        assert_eq!(header.to_string(), "##maf version=1 scoring=tba.v8");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "##maf version=1 scoring=tba.v8".parse(),
+            Ok(Header {
+                version: String::from("1"),
+                other_fields: vec![(String::from("scoring"), String::from("tba.v8"))],
+            })
+        );
+
+        assert_eq!("".parse::<Header>(), Err(ParseError::MissingPrefix));
+        assert_eq!("##maf".parse::<Header>(), Err(ParseError::MissingVersion));
+        assert_eq!(
+            "##maf version".parse::<Header>(),
+            Err(ParseError::InvalidField(String::from("version")))
+        );
+    }
+}
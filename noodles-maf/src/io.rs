@@ -0,0 +1,5 @@
+//! MAF I/O.
+
+mod reader;
+
+pub use self::reader::Reader;
@@ -0,0 +1,296 @@
+use std::{
+    io::{self, BufRead},
+    iter,
+};
+
+use crate::{Block, Header};
+
+const COMMENT_PREFIX: char = '#';
+
+/// A MAF reader.
+pub struct Reader<R> {
+    inner: R,
+    line_buf: String,
+    block_buf: String,
+}
+
+impl<R> Reader<R>
+where
+    R: BufRead,
+{
+    /// Creates a MAF reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_maf as maf;
+    /// let data = [];
+    /// let reader = maf::io::Reader::new(&data[..]);
+    /// ```
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            line_buf: String::new(),
+            block_buf: String::new(),
+        }
+    }
+
+    /// Returns a reference to the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_maf as maf;
+    /// let data = [];
+    /// let reader = maf::io::Reader::new(&data[..]);
+    /// assert!(reader.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_maf as maf;
+    /// let data = [];
+    /// let mut reader = maf::io::Reader::new(&data[..]);
+    /// assert!(reader.get_mut().is_empty());
+    /// ```
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_maf as maf;
+    /// let data = [];
+    /// let reader = maf::io::Reader::new(&data[..]);
+    /// assert!(reader.into_inner().is_empty());
+    /// ```
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Reads the MAF header.
+    ///
+    /// The position of the stream is expected to be at the start.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_maf as maf;
+    ///
+    /// let data = b"##maf version=1\n";
+    /// let mut reader = maf::io::Reader::new(&data[..]);
+    ///
+    /// let header = reader.read_header()?;
+    /// assert_eq!(header.version(), "1");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn read_header(&mut self) -> io::Result<Header> {
+        self.line_buf.clear();
+        read_line(&mut self.inner, &mut self.line_buf)?;
+
+        self.line_buf
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Reads an alignment block.
+    ///
+    /// Blank lines and comments before the block are skipped, and the block ends at a blank
+    /// line or EOF. This returns the number of bytes read, which is 0 at EOF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_maf as maf;
+    ///
+    /// let data = b"##maf version=1
+    ///
+    /// a score=0
+    /// s hg16.chr7 27578828 4 + 158545518 AAAG
+    /// s panTro1.chr6 28741140 4 + 161576975 AAAG
+    /// ";
+    /// let mut reader = maf::io::Reader::new(&data[..]);
+    /// reader.read_header()?;
+    ///
+    /// let mut block = maf::Block::default();
+    /// reader.read_block(&mut block)?;
+    /// assert_eq!(block.sequences().count(), 2);
+    ///
+    /// assert_eq!(reader.read_block(&mut block)?, 0);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn read_block(&mut self, block: &mut Block) -> io::Result<usize> {
+        self.block_buf.clear();
+
+        let mut len = 0;
+
+        loop {
+            self.line_buf.clear();
+
+            match read_line(&mut self.inner, &mut self.line_buf)? {
+                0 => break,
+                n => len += n,
+            }
+
+            let line = self.line_buf.trim_end();
+
+            if line.is_empty() {
+                if self.block_buf.is_empty() {
+                    continue;
+                } else {
+                    break;
+                }
+            } else if line.starts_with(COMMENT_PREFIX) {
+                continue;
+            }
+
+            self.block_buf.push_str(line);
+            self.block_buf.push('\n');
+        }
+
+        if self.block_buf.is_empty() {
+            return Ok(0);
+        }
+
+        *block = self
+            .block_buf
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        Ok(len)
+    }
+
+    /// Returns an iterator over alignment blocks starting from the current stream position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_maf as maf;
+    ///
+    /// let data = b"##maf version=1
+    ///
+    /// a score=0
+    /// s hg16.chr7 27578828 4 + 158545518 AAAG
+    /// ";
+    /// let mut reader = maf::io::Reader::new(&data[..]);
+    /// reader.read_header()?;
+    ///
+    /// let mut blocks = reader.blocks();
+    ///
+    /// let block = blocks.next().transpose()?;
+    /// assert_eq!(block.map(|b| b.score()), Some(Some(0.0)));
+    ///
+    /// assert!(blocks.next().is_none());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn blocks(&mut self) -> impl Iterator<Item = io::Result<Block>> + '_ {
+        let mut block = Block::default();
+
+        iter::from_fn(move || match self.read_block(&mut block) {
+            Ok(0) => None,
+            Ok(_) => Some(Ok(block.clone())),
+            Err(e) => Some(Err(e)),
+        })
+    }
+}
+
+fn read_line<R>(reader: &mut R, buf: &mut String) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    const LINE_FEED: char = '\n';
+    const CARRIAGE_RETURN: char = '\r';
+
+    match reader.read_line(buf) {
+        Ok(0) => Ok(0),
+        Ok(n) => {
+            if buf.ends_with(LINE_FEED) {
+                buf.pop();
+
+                if buf.ends_with(CARRIAGE_RETURN) {
+                    buf.pop();
+                }
+            }
+
+            Ok(n)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_line() -> io::Result<()> {
+        fn t(buf: &mut String, mut reader: &[u8], expected: &str) -> io::Result<()> {
+            buf.clear();
+            read_line(&mut reader, buf)?;
+            assert_eq!(buf, expected);
+            Ok(())
+        }
+
+        let mut buf = String::new();
+
+        t(&mut buf, b"noodles\n", "noodles")?;
+        t(&mut buf, b"noodles\r\n", "noodles")?;
+        t(&mut buf, b"noodles", "noodles")?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_blocks() -> io::Result<()> {
+        let data = b"##maf version=1 scoring=tba.v8
+# tba.v8 (((human chimp) baboon) (mouse rat))
+
+a score=23262.0
+s hg16.chr7    27578828 38 + 158545518 AAA-GGGAATGTTAACCAAATGA---ATTGTCTCTTACGGTG
+s panTro1.chr6 28741140 38 + 161576975 AAA-GGGAATGTTAACCAAATGA---ATTGTCTCTTACGGTG
+
+a score=5062.0
+s hg16.chr7    27699739 6 + 158545518 TAAAGA
+s panTro1.chr6 28862317 6 + 161576975 TAAAGA
+e mm4.chr6     53310102 13 + 151104725 I
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let header = reader.read_header()?;
+        assert_eq!(header.version(), "1");
+
+        let blocks: Vec<_> = reader.blocks().collect::<io::Result<_>>()?;
+
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(
+            blocks.iter().map(|b| b.score()).collect::<Vec<_>>(),
+            [Some(23262.0), Some(5062.0)]
+        );
+        assert_eq!(blocks[1].lines().len(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_header_with_invalid_header() {
+        let data = b"a score=0\n";
+        let mut reader = Reader::new(&data[..]);
+
+        assert!(matches!(
+            reader.read_header(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+}
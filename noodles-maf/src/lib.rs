@@ -0,0 +1,52 @@
+#![warn(missing_docs)]
+
+//! **noodles-maf** handles the reading of the MAF (Multiple Alignment Format) format.
+//!
+//! MAF stores multiple alignments of genomic sequences, e.g., whole-genome alignments between
+//! species. A file is a header followed by alignment blocks, each of which has one row per
+//! aligned sequence.
+//!
+//! # Examples
+//!
+//! ## Read all blocks in a MAF file
+//!
+//! ```no_run
+//! # use std::{fs::File, io::{self, BufReader}};
+//! use noodles_maf as maf;
+//!
+//! let mut reader = File::open("alignments.maf")
+//!     .map(BufReader::new)
+//!     .map(maf::io::Reader::new)?;
+//!
+//! let header = reader.read_header()?;
+//!
+//! for result in reader.blocks() {
+//!     let block = result?;
+//!
+//!     for sequence in block.sequences() {
+//!         // ...
+//!     }
+//! }
+//! # Ok::<_, io::Error>(())
+//! ```
+
+pub mod block;
+pub mod header;
+pub mod io;
+
+pub use self::{block::Block, header::Header};
+
+fn parse_fields<'a, I>(tokens: I) -> Result<Vec<(String, String)>, &'a str>
+where
+    I: Iterator<Item = &'a str>,
+{
+    tokens
+        .map(|token| {
+            token
+                .split_once('=')
+                .filter(|(key, _)| !key.is_empty())
+                .map(|(key, value)| (key.into(), value.into()))
+                .ok_or(token)
+        })
+        .collect()
+}
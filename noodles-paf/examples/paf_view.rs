@@ -0,0 +1,30 @@
+//! Prints all records in a PAF file.
+//!
+//! The result matches the output of `cat <src>`, with the exception that the CIGAR (`cg`) and
+//! difference string (`cs`) fields are written last.
+
+use std::{
+    env,
+    fs::File,
+    io::{self, BufReader},
+};
+
+use noodles_paf as paf;
+
+fn main() -> io::Result<()> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let mut reader = File::open(src)
+        .map(BufReader::new)
+        .map(paf::io::Reader::new)?;
+
+    let stdout = io::stdout().lock();
+    let mut writer = paf::io::Writer::new(stdout);
+
+    for result in reader.records() {
+        let record = result?;
+        writer.write_record(&record)?;
+    }
+
+    Ok(())
+}
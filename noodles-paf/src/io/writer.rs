@@ -0,0 +1,83 @@
+use std::io::{self, Write};
+
+use crate::Record;
+
+/// A PAF writer.
+pub struct Writer<W> {
+    inner: W,
+}
+
+impl<W> Writer<W>
+where
+    W: Write,
+{
+    /// Creates a PAF writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_paf as paf;
+    /// let writer = paf::io::Writer::new(Vec::new());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_paf as paf;
+    /// let writer = paf::io::Writer::new(Vec::new());
+    /// assert!(writer.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_paf as paf;
+    /// let mut writer = paf::io::Writer::new(Vec::new());
+    /// assert!(writer.get_mut().is_empty());
+    /// ```
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_paf as paf;
+    /// let writer = paf::io::Writer::new(Vec::new());
+    /// assert!(writer.into_inner().is_empty());
+    /// ```
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Writes a PAF record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_paf as paf;
+    ///
+    /// let record: paf::Record = "r0\t8\t0\t8\t+\tsq0\t13\t2\t10\t8\t8\t60".parse()?;
+    ///
+    /// let mut writer = paf::io::Writer::new(Vec::new());
+    /// writer.write_record(&record)?;
+    ///
+    /// assert_eq!(writer.get_ref(), b"r0\t8\t0\t8\t+\tsq0\t13\t2\t10\t8\t8\t60\n");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
+        writeln!(self.inner, "{record}")
+    }
+}
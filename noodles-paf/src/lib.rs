@@ -0,0 +1,30 @@
+#![warn(missing_docs)]
+
+//! **noodles-paf** handles the reading and writing of the PAF (Pairwise mApping Format) format.
+//!
+//! PAF is a tab-delimited text format that describes the approximate mapping positions between
+//! two sets of sequences. It is commonly emitted by minimap2, e.g., for long-read alignments.
+//!
+//! # Examples
+//!
+//! ## Read all records in a PAF file
+//!
+//! ```no_run
+//! # use std::{fs::File, io::{self, BufReader}};
+//! use noodles_paf as paf;
+//!
+//! let mut reader = File::open("sample.paf")
+//!     .map(BufReader::new)
+//!     .map(paf::io::Reader::new)?;
+//!
+//! for result in reader.records() {
+//!     let record = result?;
+//!     // ...
+//! }
+//! # Ok::<_, io::Error>(())
+//! ```
+
+pub mod io;
+pub mod record;
+
+pub use self::record::Record;
@@ -0,0 +1,747 @@
+//! PAF record and fields.
+
+pub mod cigar;
+pub mod difference_string;
+pub mod field;
+pub mod strand;
+
+pub use self::{difference_string::DifferenceString, field::Field, strand::Strand};
+
+use std::{error, fmt, io, num, str::FromStr};
+
+use noodles_core::Position;
+use noodles_sam::{
+    self as sam,
+    alignment::{
+        record::{
+            cigar::{op::Kind, Op},
+            data::field::Tag,
+            Flags, MappingQuality,
+        },
+        record_buf::{data::field::Value, Cigar, Data},
+        RecordBuf,
+    },
+};
+
+const FIELD_DELIMITER: char = '\t';
+
+const CIGAR_TAG: &str = "cg";
+const DIFFERENCE_STRING_TAG: &str = "cs";
+
+/// A PAF record.
+///
+/// A record describes an approximate mapping between an interval of a query sequence and an
+/// interval of a target sequence. Coordinates are 0-based and end-exclusive.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Record {
+    query_name: String,
+    query_length: usize,
+    query_start: usize,
+    query_end: usize,
+    strand: Strand,
+    target_name: String,
+    target_length: usize,
+    target_start: usize,
+    target_end: usize,
+    residue_matches: usize,
+    alignment_block_length: usize,
+    mapping_quality: Option<MappingQuality>,
+    cigar: Option<Cigar>,
+    difference_string: Option<DifferenceString>,
+    other_fields: Vec<Field>,
+}
+
+impl Record {
+    /// Creates a PAF record.
+    ///
+    /// The query and target are given as (name, length, start, end) tuples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_paf::{self as paf, record::Strand};
+    ///
+    /// let record = paf::Record::new(
+    ///     ("r0", 8, 0, 8),
+    ///     Strand::Forward,
+    ///     ("sq0", 13, 2, 10),
+    ///     8,
+    ///     8,
+    ///     None,
+    /// );
+    /// ```
+    pub fn new<Q, T>(
+        query: (Q, usize, usize, usize),
+        strand: Strand,
+        target: (T, usize, usize, usize),
+        residue_matches: usize,
+        alignment_block_length: usize,
+        mapping_quality: Option<MappingQuality>,
+    ) -> Self
+    where
+        Q: Into<String>,
+        T: Into<String>,
+    {
+        let (query_name, query_length, query_start, query_end) = query;
+        let (target_name, target_length, target_start, target_end) = target;
+
+        Self {
+            query_name: query_name.into(),
+            query_length,
+            query_start,
+            query_end,
+            strand,
+            target_name: target_name.into(),
+            target_length,
+            target_start,
+            target_end,
+            residue_matches,
+            alignment_block_length,
+            mapping_quality,
+            cigar: None,
+            difference_string: None,
+            other_fields: Vec::new(),
+        }
+    }
+
+    /// Returns the query sequence name.
+    pub fn query_name(&self) -> &str {
+        &self.query_name
+    }
+
+    /// Returns the query sequence length.
+    pub fn query_length(&self) -> usize {
+        self.query_length
+    }
+
+    /// Returns the query start (0-based, inclusive).
+    pub fn query_start(&self) -> usize {
+        self.query_start
+    }
+
+    /// Returns the query end (0-based, exclusive).
+    pub fn query_end(&self) -> usize {
+        self.query_end
+    }
+
+    /// Returns the relative strand of the query and target.
+    pub fn strand(&self) -> Strand {
+        self.strand
+    }
+
+    /// Returns the target sequence name.
+    pub fn target_name(&self) -> &str {
+        &self.target_name
+    }
+
+    /// Returns the target sequence length.
+    pub fn target_length(&self) -> usize {
+        self.target_length
+    }
+
+    /// Returns the target start (0-based, inclusive).
+    ///
+    /// This is on the forward strand of the target.
+    pub fn target_start(&self) -> usize {
+        self.target_start
+    }
+
+    /// Returns the target end (0-based, exclusive).
+    ///
+    /// This is on the forward strand of the target.
+    pub fn target_end(&self) -> usize {
+        self.target_end
+    }
+
+    /// Returns the number of residue matches.
+    pub fn residue_matches(&self) -> usize {
+        self.residue_matches
+    }
+
+    /// Returns the alignment block length.
+    ///
+    /// This is the number of bases in the mapping, including gaps.
+    pub fn alignment_block_length(&self) -> usize {
+        self.alignment_block_length
+    }
+
+    /// Returns the mapping quality.
+    ///
+    /// This is `None` if the mapping quality is missing (255).
+    pub fn mapping_quality(&self) -> Option<MappingQuality> {
+        self.mapping_quality
+    }
+
+    /// Returns the CIGAR (`cg`).
+    pub fn cigar(&self) -> Option<&Cigar> {
+        self.cigar.as_ref()
+    }
+
+    /// Returns a mutable reference to the CIGAR (`cg`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_paf as paf;
+    /// use noodles_sam::alignment::{record::cigar::{op::Kind, Op}, record_buf::Cigar};
+    ///
+    /// let mut record: paf::Record = "r0\t8\t0\t8\t+\tsq0\t13\t2\t10\t8\t8\t60".parse()?;
+    ///
+    /// let cigar: Cigar = [Op::new(Kind::Match, 8)].into_iter().collect();
+    /// *record.cigar_mut() = Some(cigar.clone());
+    ///
+    /// assert_eq!(record.cigar(), Some(&cigar));
+    /// assert!(record.to_string().ends_with("\tcg:Z:8M"));
+    /// # Ok::<_, paf::record::ParseError>(())
+    /// ```
+    pub fn cigar_mut(&mut self) -> &mut Option<Cigar> {
+        &mut self.cigar
+    }
+
+    /// Returns the difference string (`cs`).
+    pub fn difference_string(&self) -> Option<&DifferenceString> {
+        self.difference_string.as_ref()
+    }
+
+    /// Returns a mutable reference to the difference string (`cs`).
+    pub fn difference_string_mut(&mut self) -> &mut Option<DifferenceString> {
+        &mut self.difference_string
+    }
+
+    /// Returns the optional fields, excluding the CIGAR (`cg`) and difference string (`cs`).
+    pub fn other_fields(&self) -> &[Field] {
+        &self.other_fields
+    }
+
+    /// Returns a mutable reference to the optional fields, excluding the CIGAR (`cg`) and
+    /// difference string (`cs`).
+    pub fn other_fields_mut(&mut self) -> &mut Vec<Field> {
+        &mut self.other_fields
+    }
+
+    /// Converts the PAF record to an alignment record.
+    ///
+    /// The target sequence must be a reference sequence in the given header, and the record must
+    /// have either a CIGAR (`cg`) or a difference string (`cs`). The unaligned ends of the query
+    /// are added as hard clips, as the record does not include the query sequence. Secondary
+    /// mappings (`tp:A:S`) are flagged as secondary, and the edit distance (`NM`) and alignment
+    /// score (`AS`) are copied to the alignment record data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_core::Position;
+    /// use noodles_paf as paf;
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     alignment::record::cigar::{op::Kind, Op},
+    ///     header::record::value::{map::ReferenceSequence, Map},
+    /// };
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?))
+    ///     .build();
+    ///
+    /// let record: paf::Record = "r0\t10\t1\t9\t+\tsq0\t13\t2\t10\t8\t8\t60\tcg:Z:8M".parse()?;
+    /// let alignment_record = record.to_alignment_record(&header)?;
+    ///
+    /// assert_eq!(alignment_record.reference_sequence_id(), Some(0));
+    /// assert_eq!(alignment_record.alignment_start(), Position::new(3));
+    /// assert_eq!(
+    ///     alignment_record.cigar().as_ref(),
+    ///     [
+    ///         Op::new(Kind::HardClip, 1),
+    ///         Op::new(Kind::Match, 8),
+    ///         Op::new(Kind::HardClip, 1),
+    ///     ]
+    /// );
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn to_alignment_record(&self, header: &sam::Header) -> io::Result<RecordBuf> {
+        let reference_sequence_id = header
+            .reference_sequences()
+            .get_index_of(self.target_name.as_bytes())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("missing reference sequence: {}", self.target_name),
+                )
+            })?;
+
+        let alignment_start = self
+            .target_start
+            .checked_add(1)
+            .and_then(Position::new)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid target start"))?;
+
+        let ops = match (&self.cigar, &self.difference_string) {
+            (Some(cigar), _) => cigar.clone(),
+            (None, Some(difference_string)) => difference_string.to_cigar(),
+            (None, None) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "missing CIGAR (cg) or difference string (cs)",
+                ))
+            }
+        };
+
+        let trailing_len = self
+            .query_length
+            .checked_sub(self.query_end)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid query end"))?;
+
+        let (head_clip_len, tail_clip_len) = match self.strand {
+            Strand::Forward => (self.query_start, trailing_len),
+            Strand::Reverse => (trailing_len, self.query_start),
+        };
+
+        let mut cigar = Cigar::default();
+
+        if head_clip_len > 0 {
+            cigar.as_mut().push(Op::new(Kind::HardClip, head_clip_len));
+        }
+
+        cigar.extend(ops.as_ref().iter().copied());
+
+        if tail_clip_len > 0 {
+            cigar.as_mut().push(Op::new(Kind::HardClip, tail_clip_len));
+        }
+
+        let mut flags = Flags::empty();
+
+        if self.strand == Strand::Reverse {
+            flags.insert(Flags::REVERSE_COMPLEMENTED);
+        }
+
+        let mut data = Data::default();
+
+        for field in &self.other_fields {
+            match (field.tag(), field.value()) {
+                ("tp", field::Value::Character('S')) => flags.insert(Flags::SECONDARY),
+                ("NM", field::Value::Integer(n)) => {
+                    data.insert(Tag::EDIT_DISTANCE, integer_value(*n)?);
+                }
+                ("AS", field::Value::Integer(n)) => {
+                    data.insert(Tag::ALIGNMENT_SCORE, integer_value(*n)?);
+                }
+                _ => {}
+            }
+        }
+
+        let mut builder = RecordBuf::builder()
+            .set_name(self.query_name.as_str())
+            .set_flags(flags)
+            .set_reference_sequence_id(reference_sequence_id)
+            .set_alignment_start(alignment_start)
+            .set_cigar(cigar)
+            .set_data(data);
+
+        if let Some(mapping_quality) = self.mapping_quality {
+            builder = builder.set_mapping_quality(mapping_quality);
+        }
+
+        Ok(builder.build())
+    }
+}
+
+fn integer_value(n: i64) -> io::Result<Value> {
+    i32::try_from(n)
+        .map(Value::from)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const MISSING_MAPPING_QUALITY: u8 = 255;
+
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            self.query_name,
+            self.query_length,
+            self.query_start,
+            self.query_end,
+            self.strand,
+            self.target_name,
+            self.target_length,
+            self.target_start,
+            self.target_end,
+            self.residue_matches,
+            self.alignment_block_length,
+            self.mapping_quality
+                .map(u8::from)
+                .unwrap_or(MISSING_MAPPING_QUALITY),
+        )?;
+
+        for field in &self.other_fields {
+            write!(f, "{FIELD_DELIMITER}{field}")?;
+        }
+
+        if let Some(cigar) = &self.cigar {
+            write!(f, "{FIELD_DELIMITER}{CIGAR_TAG}:Z:")?;
+            cigar::fmt_cigar(f, cigar)?;
+        }
+
+        if let Some(difference_string) = &self.difference_string {
+            write!(
+                f,
+                "{FIELD_DELIMITER}{DIFFERENCE_STRING_TAG}:Z:{difference_string}"
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned when a raw PAF record fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The query name is missing.
+    MissingQueryName,
+    /// The query length is missing.
+    MissingQueryLength,
+    /// The query length is invalid.
+    InvalidQueryLength(num::ParseIntError),
+    /// The query start is missing.
+    MissingQueryStart,
+    /// The query start is invalid.
+    InvalidQueryStart(num::ParseIntError),
+    /// The query end is missing.
+    MissingQueryEnd,
+    /// The query end is invalid.
+    InvalidQueryEnd(num::ParseIntError),
+    /// The strand is missing.
+    MissingStrand,
+    /// The strand is invalid.
+    InvalidStrand(strand::ParseError),
+    /// The target name is missing.
+    MissingTargetName,
+    /// The target length is missing.
+    MissingTargetLength,
+    /// The target length is invalid.
+    InvalidTargetLength(num::ParseIntError),
+    /// The target start is missing.
+    MissingTargetStart,
+    /// The target start is invalid.
+    InvalidTargetStart(num::ParseIntError),
+    /// The target end is missing.
+    MissingTargetEnd,
+    /// The target end is invalid.
+    InvalidTargetEnd(num::ParseIntError),
+    /// The number of residue matches is missing.
+    MissingResidueMatches,
+    /// The number of residue matches is invalid.
+    InvalidResidueMatches(num::ParseIntError),
+    /// The alignment block length is missing.
+    MissingAlignmentBlockLength,
+    /// The alignment block length is invalid.
+    InvalidAlignmentBlockLength(num::ParseIntError),
+    /// The mapping quality is missing.
+    MissingMappingQuality,
+    /// The mapping quality is invalid.
+    InvalidMappingQuality(num::ParseIntError),
+    /// An optional field is invalid.
+    InvalidField(field::ParseError),
+    /// The CIGAR (`cg`) is invalid.
+    InvalidCigar(cigar::ParseError),
+    /// The difference string (`cs`) is invalid.
+    InvalidDifferenceString(difference_string::ParseError),
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidQueryLength(e)
+            | Self::InvalidQueryStart(e)
+            | Self::InvalidQueryEnd(e)
+            | Self::InvalidTargetLength(e)
+            | Self::InvalidTargetStart(e)
+            | Self::InvalidTargetEnd(e)
+            | Self::InvalidResidueMatches(e)
+            | Self::InvalidAlignmentBlockLength(e)
+            | Self::InvalidMappingQuality(e) => Some(e),
+            Self::InvalidStrand(e) => Some(e),
+            Self::InvalidField(e) => Some(e),
+            Self::InvalidCigar(e) => Some(e),
+            Self::InvalidDifferenceString(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingQueryName => write!(f, "missing query name"),
+            Self::MissingQueryLength => write!(f, "missing query length"),
+            Self::InvalidQueryLength(_) => write!(f, "invalid query length"),
+            Self::MissingQueryStart => write!(f, "missing query start"),
+            Self::InvalidQueryStart(_) => write!(f, "invalid query start"),
+            Self::MissingQueryEnd => write!(f, "missing query end"),
+            Self::InvalidQueryEnd(_) => write!(f, "invalid query end"),
+            Self::MissingStrand => write!(f, "missing strand"),
+            Self::InvalidStrand(_) => write!(f, "invalid strand"),
+            Self::MissingTargetName => write!(f, "missing target name"),
+            Self::MissingTargetLength => write!(f, "missing target length"),
+            Self::InvalidTargetLength(_) => write!(f, "invalid target length"),
+            Self::MissingTargetStart => write!(f, "missing target start"),
+            Self::InvalidTargetStart(_) => write!(f, "invalid target start"),
+            Self::MissingTargetEnd => write!(f, "missing target end"),
+            Self::InvalidTargetEnd(_) => write!(f, "invalid target end"),
+            Self::MissingResidueMatches => write!(f, "missing residue matches"),
+            Self::InvalidResidueMatches(_) => write!(f, "invalid residue matches"),
+            Self::MissingAlignmentBlockLength => write!(f, "missing alignment block length"),
+            Self::InvalidAlignmentBlockLength(_) => write!(f, "invalid alignment block length"),
+            Self::MissingMappingQuality => write!(f, "missing mapping quality"),
+            Self::InvalidMappingQuality(_) => write!(f, "invalid mapping quality"),
+            Self::InvalidField(_) => write!(f, "invalid field"),
+            Self::InvalidCigar(_) => write!(f, "invalid CIGAR"),
+            Self::InvalidDifferenceString(_) => write!(f, "invalid difference string"),
+        }
+    }
+}
+
+impl FromStr for Record {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.split(FIELD_DELIMITER);
+
+        let query_name = parse_name(fields.next(), ParseError::MissingQueryName)?;
+        let query_length = parse_usize(
+            fields.next(),
+            ParseError::MissingQueryLength,
+            ParseError::InvalidQueryLength,
+        )?;
+        let query_start = parse_usize(
+            fields.next(),
+            ParseError::MissingQueryStart,
+            ParseError::InvalidQueryStart,
+        )?;
+        let query_end = parse_usize(
+            fields.next(),
+            ParseError::MissingQueryEnd,
+            ParseError::InvalidQueryEnd,
+        )?;
+
+        let strand = fields
+            .next()
+            .ok_or(ParseError::MissingStrand)
+            .and_then(|t| t.parse().map_err(ParseError::InvalidStrand))?;
+
+        let target_name = parse_name(fields.next(), ParseError::MissingTargetName)?;
+        let target_length = parse_usize(
+            fields.next(),
+            ParseError::MissingTargetLength,
+            ParseError::InvalidTargetLength,
+        )?;
+        let target_start = parse_usize(
+            fields.next(),
+            ParseError::MissingTargetStart,
+            ParseError::InvalidTargetStart,
+        )?;
+        let target_end = parse_usize(
+            fields.next(),
+            ParseError::MissingTargetEnd,
+            ParseError::InvalidTargetEnd,
+        )?;
+
+        let residue_matches = parse_usize(
+            fields.next(),
+            ParseError::MissingResidueMatches,
+            ParseError::InvalidResidueMatches,
+        )?;
+        let alignment_block_length = parse_usize(
+            fields.next(),
+            ParseError::MissingAlignmentBlockLength,
+            ParseError::InvalidAlignmentBlockLength,
+        )?;
+
+        let mapping_quality = fields
+            .next()
+            .ok_or(ParseError::MissingMappingQuality)
+            .and_then(|t| t.parse::<u8>().map_err(ParseError::InvalidMappingQuality))
+            .map(MappingQuality::new)?;
+
+        let mut record = Self::new(
+            (query_name, query_length, query_start, query_end),
+            strand,
+            (target_name, target_length, target_start, target_end),
+            residue_matches,
+            alignment_block_length,
+            mapping_quality,
+        );
+
+        for raw_field in fields {
+            let field: Field = raw_field.parse().map_err(ParseError::InvalidField)?;
+
+            match (field.tag(), field.value()) {
+                (CIGAR_TAG, field::Value::String(s)) => {
+                    record.cigar = cigar::parse_cigar(s)
+                        .map(Some)
+                        .map_err(ParseError::InvalidCigar)?;
+                }
+                (DIFFERENCE_STRING_TAG, field::Value::String(s)) => {
+                    record.difference_string = s
+                        .parse()
+                        .map(Some)
+                        .map_err(ParseError::InvalidDifferenceString)?;
+                }
+                _ => record.other_fields.push(field),
+            }
+        }
+
+        Ok(record)
+    }
+}
+
+fn parse_name(s: Option<&str>, missing_error: ParseError) -> Result<&str, ParseError> {
+    s.filter(|t| !t.is_empty()).ok_or(missing_error)
+}
+
+fn parse_usize<F>(s: Option<&str>, missing_error: ParseError, f: F) -> Result<usize, ParseError>
+where
+    F: FnOnce(num::ParseIntError) -> ParseError,
+{
+    s.ok_or(missing_error).and_then(|t| t.parse().map_err(f))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use sam::header::record::value::{map::ReferenceSequence, Map};
+
+    use super::*;
+
+    const RAW_RECORD: &str =
+        "r0\t10\t1\t9\t-\tsq0\t13\t2\t11\t7\t9\t60\ttp:A:P\tNM:i:2\tcg:Z:4M1D4M\tcs:Z::4-a:3*ag";
+
+    #[test]
+    fn test_from_str() {
+        let actual: Record = RAW_RECORD.parse().unwrap();
+
+        let mut expected = Record::new(
+            ("r0", 10, 1, 9),
+            Strand::Reverse,
+            ("sq0", 13, 2, 11),
+            7,
+            9,
+            MappingQuality::new(60),
+        );
+
+        *expected.cigar_mut() = Some(Cigar::from(vec![
+            Op::new(Kind::Match, 4),
+            Op::new(Kind::Deletion, 1),
+            Op::new(Kind::Match, 4),
+        ]));
+
+        *expected.difference_string_mut() = Some(DifferenceString::from(vec![
+            difference_string::Op::Match(4),
+            difference_string::Op::Deletion(b"a".to_vec()),
+            difference_string::Op::Match(3),
+            difference_string::Op::Substitution {
+                reference_base: b'a',
+                query_base: b'g',
+            },
+        ]));
+
+        expected.other_fields_mut().extend([
+            Field::new("tp", field::Value::Character('P')),
+            Field::new("NM", field::Value::Integer(2)),
+        ]);
+
+        assert_eq!(actual, expected);
+
+        assert_eq!(
+            "r0\t10\t1\t9\t+\tsq0\t13\t2\t10\t8\t8\t255"
+                .parse::<Record>()
+                .map(|record| record.mapping_quality()),
+            Ok(None)
+        );
+
+        assert_eq!("".parse::<Record>(), Err(ParseError::MissingQueryName));
+        assert_eq!(
+            "r0\t10".parse::<Record>(),
+            Err(ParseError::MissingQueryStart)
+        );
+        assert!(matches!(
+            "r0\t10\t1\t9\t.\tsq0\t13\t2\t10\t8\t8\t60".parse::<Record>(),
+            Err(ParseError::InvalidStrand(_))
+        ));
+        assert!(matches!(
+            "r0\t10\t1\t9\t+\tsq0\t13\t2\t10\t8\t8\t256".parse::<Record>(),
+            Err(ParseError::InvalidMappingQuality(_))
+        ));
+        assert!(matches!(
+            "r0\t10\t1\t9\t+\tsq0\t13\t2\t10\t8\t8\t60\tcg:Z:8Q".parse::<Record>(),
+            Err(ParseError::InvalidCigar(_))
+        ));
+    }
+
+    #[test]
+    fn test_fmt() {
+        let record: Record = RAW_RECORD.parse().unwrap();
+        assert_eq!(record.to_string(), RAW_RECORD);
+    }
+
+    #[test]
+    fn test_to_alignment_record() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .build();
+
+        let record: Record = RAW_RECORD.parse()?;
+        let alignment_record = record.to_alignment_record(&header)?;
+
+        assert_eq!(
+            alignment_record.name().map(|name| name.to_string()),
+            Some(String::from("r0"))
+        );
+        assert_eq!(alignment_record.flags(), Flags::REVERSE_COMPLEMENTED);
+        assert_eq!(alignment_record.reference_sequence_id(), Some(0));
+        assert_eq!(alignment_record.alignment_start(), Position::new(3));
+        assert_eq!(alignment_record.mapping_quality(), MappingQuality::new(60));
+        assert_eq!(
+            alignment_record.cigar().as_ref(),
+            [
+                Op::new(Kind::HardClip, 1),
+                Op::new(Kind::Match, 4),
+                Op::new(Kind::Deletion, 1),
+                Op::new(Kind::Match, 4),
+                Op::new(Kind::HardClip, 1),
+            ]
+        );
+        assert_eq!(
+            alignment_record.data().get(&Tag::EDIT_DISTANCE),
+            Some(&Value::from(2))
+        );
+
+        let mut record: Record =
+            "r0\t10\t0\t8\t+\tsq0\t13\t2\t10\t8\t8\t60\ttp:A:S\tcs:Z::8".parse()?;
+        let alignment_record = record.to_alignment_record(&header)?;
+        assert_eq!(alignment_record.flags(), Flags::SECONDARY);
+        assert_eq!(
+            alignment_record.cigar().as_ref(),
+            [Op::new(Kind::SequenceMatch, 8), Op::new(Kind::HardClip, 2),]
+        );
+
+        *record.difference_string_mut() = None;
+        assert!(matches!(
+            record.to_alignment_record(&header),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            record.to_alignment_record(&sam::Header::default()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
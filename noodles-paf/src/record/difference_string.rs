@@ -0,0 +1,351 @@
+//! PAF record difference string (`cs`).
+
+use std::{error, fmt, num, str::FromStr};
+
+use noodles_sam::alignment::{record::cigar::op::Kind, record_buf::Cigar};
+
+/// A PAF record difference string operation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Op {
+    /// A run of identical bases (`:[0-9]+`).
+    Match(usize),
+    /// A run of identical bases, given as the bases (`=[ACGTN]+`).
+    Identity(Vec<u8>),
+    /// A substitution of a reference base with a query base (`*[acgtn][acgtn]`).
+    Substitution {
+        /// The reference base.
+        reference_base: u8,
+        /// The query base.
+        query_base: u8,
+    },
+    /// An insertion into the reference (`+[acgtn]+`).
+    Insertion(Vec<u8>),
+    /// A deletion from the reference (`-[acgtn]+`).
+    Deletion(Vec<u8>),
+    /// An intron (`~[acgtn]{2}[0-9]+[acgtn]{2}`).
+    Intron {
+        /// The first two bases of the intron.
+        donor: [u8; 2],
+        /// The length of the intron.
+        len: usize,
+        /// The last two bases of the intron.
+        acceptor: [u8; 2],
+    },
+}
+
+impl Op {
+    fn to_cigar_op(&self) -> (Kind, usize) {
+        match self {
+            Self::Match(len) => (Kind::SequenceMatch, *len),
+            Self::Identity(bases) => (Kind::SequenceMatch, bases.len()),
+            Self::Substitution { .. } => (Kind::SequenceMismatch, 1),
+            Self::Insertion(bases) => (Kind::Insertion, bases.len()),
+            Self::Deletion(bases) => (Kind::Deletion, bases.len()),
+            Self::Intron { len, .. } => (Kind::Skip, *len),
+        }
+    }
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn write_bases(f: &mut fmt::Formatter<'_>, bases: &[u8]) -> fmt::Result {
+            bases
+                .iter()
+                .try_for_each(|&b| write!(f, "{}", char::from(b)))
+        }
+
+        match self {
+            Self::Match(len) => write!(f, ":{len}"),
+            Self::Identity(bases) => {
+                f.write_str("=")?;
+                write_bases(f, bases)
+            }
+            Self::Substitution {
+                reference_base,
+                query_base,
+            } => write!(
+                f,
+                "*{}{}",
+                char::from(*reference_base),
+                char::from(*query_base)
+            ),
+            Self::Insertion(bases) => {
+                f.write_str("+")?;
+                write_bases(f, bases)
+            }
+            Self::Deletion(bases) => {
+                f.write_str("-")?;
+                write_bases(f, bases)
+            }
+            Self::Intron {
+                donor,
+                len,
+                acceptor,
+            } => {
+                f.write_str("~")?;
+                write_bases(f, donor)?;
+                write!(f, "{len}")?;
+                write_bases(f, acceptor)
+            }
+        }
+    }
+}
+
+/// A PAF record difference string (`cs`).
+///
+/// A difference string encodes the bases of the differences between the query and target
+/// sequences.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DifferenceString(Vec<Op>);
+
+impl DifferenceString {
+    /// Converts the difference string to a CIGAR.
+    ///
+    /// Identical bases are represented as sequence matches (`=`) and substitutions as sequence
+    /// mismatches (`X`). Adjacent operations of the same kind are merged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_paf::record::DifferenceString;
+    /// use noodles_sam::alignment::{record::cigar::{op::Kind, Op}, record_buf::Cigar};
+    ///
+    /// let difference_string: DifferenceString = ":4*ag*ct+gg:2".parse()?;
+    ///
+    /// assert_eq!(
+    ///     difference_string.to_cigar(),
+    ///     Cigar::from(vec![
+    ///         Op::new(Kind::SequenceMatch, 4),
+    ///         Op::new(Kind::SequenceMismatch, 2),
+    ///         Op::new(Kind::Insertion, 2),
+    ///         Op::new(Kind::SequenceMatch, 2),
+    ///     ])
+    /// );
+    /// # Ok::<_, noodles_paf::record::difference_string::ParseError>(())
+    /// ```
+    pub fn to_cigar(&self) -> Cigar {
+        use noodles_sam::alignment::record::cigar::Op as CigarOp;
+
+        let mut ops: Vec<CigarOp> = Vec::new();
+
+        for op in &self.0 {
+            let (kind, len) = op.to_cigar_op();
+
+            match ops.last_mut() {
+                Some(last) if last.kind() == kind => *last = CigarOp::new(kind, last.len() + len),
+                _ => ops.push(CigarOp::new(kind, len)),
+            }
+        }
+
+        Cigar::from(ops)
+    }
+}
+
+impl AsRef<[Op]> for DifferenceString {
+    fn as_ref(&self) -> &[Op] {
+        &self.0
+    }
+}
+
+impl From<Vec<Op>> for DifferenceString {
+    fn from(ops: Vec<Op>) -> Self {
+        Self(ops)
+    }
+}
+
+impl fmt::Display for DifferenceString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|op| write!(f, "{op}"))
+    }
+}
+
+/// An error returned when a raw PAF record difference string fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// An operation kind is invalid.
+    InvalidKind(char),
+    /// An operation is invalid.
+    InvalidOp,
+    /// An operation length is invalid.
+    InvalidLength(num::ParseIntError),
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidLength(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty input"),
+            Self::InvalidKind(c) => write!(f, "invalid kind: {c}"),
+            Self::InvalidOp => write!(f, "invalid op"),
+            Self::InvalidLength(_) => write!(f, "invalid length"),
+        }
+    }
+}
+
+impl FromStr for DifferenceString {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let mut ops = Vec::new();
+        let mut src = s.as_bytes();
+
+        while let Some((&c, rest)) = src.split_first() {
+            let end = rest
+                .iter()
+                .position(|b| b"=:*+-~".contains(b))
+                .unwrap_or(rest.len());
+
+            let (buf, rest) = rest.split_at(end);
+            ops.push(parse_op(c, buf)?);
+            src = rest;
+        }
+
+        Ok(Self(ops))
+    }
+}
+
+fn parse_op(c: u8, buf: &[u8]) -> Result<Op, ParseError> {
+    fn parse_len(buf: &[u8]) -> Result<usize, ParseError> {
+        let s = std::str::from_utf8(buf).map_err(|_| ParseError::InvalidOp)?;
+        s.parse().map_err(ParseError::InvalidLength)
+    }
+
+    fn parse_bases(buf: &[u8]) -> Result<Vec<u8>, ParseError> {
+        if !buf.is_empty() && buf.iter().all(|b| b.is_ascii_alphabetic()) {
+            Ok(buf.to_vec())
+        } else {
+            Err(ParseError::InvalidOp)
+        }
+    }
+
+    match c {
+        b':' => parse_len(buf).map(Op::Match),
+        b'=' => parse_bases(buf).map(Op::Identity),
+        b'*' => match buf {
+            [r, q] if r.is_ascii_alphabetic() && q.is_ascii_alphabetic() => Ok(Op::Substitution {
+                reference_base: *r,
+                query_base: *q,
+            }),
+            _ => Err(ParseError::InvalidOp),
+        },
+        b'+' => parse_bases(buf).map(Op::Insertion),
+        b'-' => parse_bases(buf).map(Op::Deletion),
+        b'~' => {
+            const SPLICE_SITE_LENGTH: usize = 2;
+
+            if buf.len() <= 2 * SPLICE_SITE_LENGTH {
+                return Err(ParseError::InvalidOp);
+            }
+
+            let (donor, rest) = buf.split_at(SPLICE_SITE_LENGTH);
+            let (raw_len, acceptor) = rest.split_at(rest.len() - SPLICE_SITE_LENGTH);
+
+            let donor = parse_bases(donor)?;
+            let acceptor = parse_bases(acceptor)?;
+            let len = parse_len(raw_len)?;
+
+            Ok(Op::Intron {
+                donor: [donor[0], donor[1]],
+                len,
+                acceptor: [acceptor[0], acceptor[1]],
+            })
+        }
+        _ => Err(ParseError::InvalidKind(char::from(c))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        let difference_string = DifferenceString::from(vec![
+            Op::Match(4),
+            Op::Substitution {
+                reference_base: b'a',
+                query_base: b'g',
+            },
+            Op::Identity(b"ACG".to_vec()),
+            Op::Insertion(b"gg".to_vec()),
+            Op::Deletion(b"t".to_vec()),
+            Op::Intron {
+                donor: *b"gt",
+                len: 32,
+                acceptor: *b"ag",
+            },
+        ]);
+
+        assert_eq!(difference_string.to_string(), ":4*ag=ACG+gg-t~gt32ag");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            ":4*ag=ACG+gg-t~gt32ag".parse(),
+            Ok(DifferenceString::from(vec![
+                Op::Match(4),
+                Op::Substitution {
+                    reference_base: b'a',
+                    query_base: b'g',
+                },
+                Op::Identity(b"ACG".to_vec()),
+                Op::Insertion(b"gg".to_vec()),
+                Op::Deletion(b"t".to_vec()),
+                Op::Intron {
+                    donor: *b"gt",
+                    len: 32,
+                    acceptor: *b"ag",
+                },
+            ]))
+        );
+
+        assert_eq!("".parse::<DifferenceString>(), Err(ParseError::Empty));
+        assert_eq!(
+            "!4".parse::<DifferenceString>(),
+            Err(ParseError::InvalidKind('!'))
+        );
+        assert_eq!("*a".parse::<DifferenceString>(), Err(ParseError::InvalidOp));
+        assert_eq!("+".parse::<DifferenceString>(), Err(ParseError::InvalidOp));
+        assert_eq!(
+            "~gtag".parse::<DifferenceString>(),
+            Err(ParseError::InvalidOp)
+        );
+        assert!(matches!(
+            ":a".parse::<DifferenceString>(),
+            Err(ParseError::InvalidLength(_))
+        ));
+    }
+
+    #[test]
+    fn test_to_cigar() {
+        use noodles_sam::alignment::record::cigar::Op as CigarOp;
+
+        let difference_string: DifferenceString = "=AC:2*ag-t~gt32ag:3".parse().unwrap();
+
+        assert_eq!(
+            difference_string.to_cigar(),
+            Cigar::from(vec![
+                CigarOp::new(Kind::SequenceMatch, 4),
+                CigarOp::new(Kind::SequenceMismatch, 1),
+                CigarOp::new(Kind::Deletion, 1),
+                CigarOp::new(Kind::Skip, 32),
+                CigarOp::new(Kind::SequenceMatch, 3),
+            ])
+        );
+    }
+}
@@ -0,0 +1,248 @@
+//! PAF record optional field.
+
+use std::{error, fmt, num, str::FromStr};
+
+const DELIMITER: char = ':';
+
+/// A PAF record optional field value.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// A character (`A`).
+    Character(char),
+    /// An integer (`i`).
+    Integer(i64),
+    /// A single-precision floating-point (`f`).
+    Float(f32),
+    /// A string (`Z`).
+    String(String),
+    /// A hex string (`H`).
+    Hex(String),
+    /// An array (`B`).
+    ///
+    /// This is the raw value, i.e., the subtype followed by comma-separated values.
+    Array(String),
+}
+
+impl Value {
+    fn ty(&self) -> char {
+        match self {
+            Self::Character(_) => 'A',
+            Self::Integer(_) => 'i',
+            Self::Float(_) => 'f',
+            Self::String(_) => 'Z',
+            Self::Hex(_) => 'H',
+            Self::Array(_) => 'B',
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Character(c) => write!(f, "{c}"),
+            Self::Integer(n) => write!(f, "{n}"),
+            Self::Float(n) => write!(f, "{n}"),
+            Self::String(s) | Self::Hex(s) | Self::Array(s) => f.write_str(s),
+        }
+    }
+}
+
+/// A PAF record optional field.
+///
+/// Optional fields use the SAM optional field format, i.e., `TAG:TYPE:VALUE`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Field {
+    tag: String,
+    value: Value,
+}
+
+impl Field {
+    /// Creates a PAF record optional field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_paf::record::field::{Field, Value};
+    /// let field = Field::new("tp", Value::Character('P'));
+    /// ```
+    pub fn new<T>(tag: T, value: Value) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            tag: tag.into(),
+            value,
+        }
+    }
+
+    /// Returns the tag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_paf::record::field::{Field, Value};
+    /// let field = Field::new("tp", Value::Character('P'));
+    /// assert_eq!(field.tag(), "tp");
+    /// ```
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// Returns the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_paf::record::field::{Field, Value};
+    /// let field = Field::new("tp", Value::Character('P'));
+    /// assert_eq!(field.value(), &Value::Character('P'));
+    /// ```
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{tag}{DELIMITER}{ty}{DELIMITER}{value}",
+            tag = self.tag,
+            ty = self.value.ty(),
+            value = self.value
+        )
+    }
+}
+
+/// An error returned when a raw PAF record optional field fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The tag is invalid.
+    InvalidTag,
+    /// The type is missing.
+    MissingType,
+    /// The type is invalid.
+    InvalidType,
+    /// The value is missing.
+    MissingValue,
+    /// The value is invalid.
+    InvalidValue,
+    /// The integer value is invalid.
+    InvalidIntegerValue(num::ParseIntError),
+    /// The floating-point value is invalid.
+    InvalidFloatValue(num::ParseFloatError),
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidIntegerValue(e) => Some(e),
+            Self::InvalidFloatValue(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidTag => write!(f, "invalid tag"),
+            Self::MissingType => write!(f, "missing type"),
+            Self::InvalidType => write!(f, "invalid type"),
+            Self::MissingValue => write!(f, "missing value"),
+            Self::InvalidValue | Self::InvalidIntegerValue(_) | Self::InvalidFloatValue(_) => {
+                write!(f, "invalid value")
+            }
+        }
+    }
+}
+
+impl FromStr for Field {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const TAG_LENGTH: usize = 2;
+
+        let mut components = s.splitn(3, DELIMITER);
+
+        let tag = components
+            .next()
+            .filter(|t| t.len() == TAG_LENGTH && t.bytes().all(|b| b.is_ascii_alphanumeric()))
+            .ok_or(ParseError::InvalidTag)?;
+
+        let ty = components.next().ok_or(ParseError::MissingType)?;
+        let raw_value = components.next().ok_or(ParseError::MissingValue)?;
+
+        let value = match ty {
+            "A" => {
+                let mut chars = raw_value.chars();
+
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Value::Character(c),
+                    _ => return Err(ParseError::InvalidValue),
+                }
+            }
+            "i" => raw_value
+                .parse()
+                .map(Value::Integer)
+                .map_err(ParseError::InvalidIntegerValue)?,
+            "f" => raw_value
+                .parse()
+                .map(Value::Float)
+                .map_err(ParseError::InvalidFloatValue)?,
+            "Z" => Value::String(raw_value.into()),
+            "H" => Value::Hex(raw_value.into()),
+            "B" => Value::Array(raw_value.into()),
+            _ => return Err(ParseError::InvalidType),
+        };
+
+        Ok(Self::new(tag, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(
+            Field::new("tp", Value::Character('P')).to_string(),
+            "tp:A:P"
+        );
+        assert_eq!(Field::new("NM", Value::Integer(8)).to_string(), "NM:i:8");
+        assert_eq!(Field::new("dv", Value::Float(0.5)).to_string(), "dv:f:0.5");
+        assert_eq!(
+            Field::new("co", Value::String(String::from("ndls"))).to_string(),
+            "co:Z:ndls"
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "tp:A:P".parse(),
+            Ok(Field::new("tp", Value::Character('P')))
+        );
+        assert_eq!("NM:i:-8".parse(), Ok(Field::new("NM", Value::Integer(-8))));
+        assert_eq!("dv:f:0.5".parse(), Ok(Field::new("dv", Value::Float(0.5))));
+        assert_eq!(
+            "co:Z:a:b".parse(),
+            Ok(Field::new("co", Value::String(String::from("a:b"))))
+        );
+        assert_eq!(
+            "zb:B:c,1,2".parse(),
+            Ok(Field::new("zb", Value::Array(String::from("c,1,2"))))
+        );
+
+        assert_eq!("t:A:P".parse::<Field>(), Err(ParseError::InvalidTag));
+        assert_eq!("tp".parse::<Field>(), Err(ParseError::MissingType));
+        assert_eq!("tp:A".parse::<Field>(), Err(ParseError::MissingValue));
+        assert_eq!("tp:X:P".parse::<Field>(), Err(ParseError::InvalidType));
+        assert_eq!("tp:A:PS".parse::<Field>(), Err(ParseError::InvalidValue));
+        assert!(matches!(
+            "NM:i:.".parse::<Field>(),
+            Err(ParseError::InvalidIntegerValue(_))
+        ));
+    }
+}
@@ -8,7 +8,7 @@ use std::io;
 
 use noodles_sam::{
     self as sam,
-    alignment::{io::Write, RecordBuf},
+    alignment::RecordBuf,
     header::record::value::{map::Program, Map},
 };
 
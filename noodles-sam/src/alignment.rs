@@ -1,7 +1,16 @@
 //! Alignment record.
 
+mod clip;
+pub mod cmp;
+pub mod filter;
 pub mod io;
 pub mod record;
 pub mod record_buf;
+pub mod template;
 
-pub use self::{record::Record, record_buf::RecordBuf};
+pub use self::{
+    clip::{clip, clip_and_update_template_length},
+    record::Record,
+    record_buf::RecordBuf,
+    template::Template,
+};
@@ -0,0 +1,394 @@
+//! Alignment record soft clipping.
+
+use std::io;
+
+use noodles_core::{region::Interval, Position};
+
+use super::{
+    record::cigar::{op::Kind, Op},
+    RecordBuf,
+};
+
+/// Soft clips the aligned bases of a record that overlap the given reference interval.
+///
+/// This is the core operation of amplicon primer trimming. Aligned bases at the start or end of
+/// the alignment that fall inside the interval are converted to soft clips. The CIGAR and
+/// alignment start are adjusted accordingly, and the sequence and quality scores are unchanged.
+/// Insertions and deletions adjacent to the new clip boundary are clipped as well.
+///
+/// Because bases can only be clipped from either end of an alignment, an interval that lies
+/// strictly inside the alignment does not change the record. Unmapped records and records
+/// without an alignment start are also not changed.
+///
+/// The template length is not changed. See [`clip_and_update_template_length`] to also adjust
+/// it.
+///
+/// The number of aligned bases converted to soft clips is returned. An error is returned if the
+/// interval covers all the aligned bases of the record, in which case the record is not changed.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::alignment::{
+///     self,
+///     record::{
+///         cigar::{op::Kind, Op},
+///         Flags,
+///     },
+///     RecordBuf,
+/// };
+///
+/// let mut record = RecordBuf::builder()
+///     .set_flags(Flags::empty())
+///     .set_alignment_start(Position::try_from(8)?)
+///     .set_cigar([Op::new(Kind::Match, 10)].into_iter().collect())
+///     .build();
+///
+/// let start = Position::try_from(1)?;
+/// let end = Position::try_from(10)?;
+/// assert_eq!(alignment::clip(&mut record, start..=end)?, 3);
+///
+/// assert_eq!(record.alignment_start(), Position::new(11));
+/// assert_eq!(
+///     record.cigar().as_ref(),
+///     [Op::new(Kind::SoftClip, 3), Op::new(Kind::Match, 7)]
+/// );
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn clip<I>(record: &mut RecordBuf, interval: I) -> io::Result<usize>
+where
+    I: Into<Interval>,
+{
+    clip_inner(record, interval.into()).map(|(clipped_len, _, _)| clipped_len)
+}
+
+/// Soft clips the aligned bases of a record that overlap the given reference interval and
+/// adjusts its template length.
+///
+/// This is the same as [`clip`], but the template length is also shortened by the number of
+/// reference positions removed from the start (when the template length is positive, i.e., the
+/// record is the leftmost segment) or the end (when the template length is negative, i.e., the
+/// record is the rightmost segment) of the alignment.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::alignment::{
+///     self,
+///     record::{
+///         cigar::{op::Kind, Op},
+///         Flags,
+///     },
+///     RecordBuf,
+/// };
+///
+/// let mut record = RecordBuf::builder()
+///     .set_flags(Flags::empty())
+///     .set_alignment_start(Position::try_from(8)?)
+///     .set_cigar([Op::new(Kind::Match, 10)].into_iter().collect())
+///     .set_template_length(30)
+///     .build();
+///
+/// let start = Position::try_from(1)?;
+/// let end = Position::try_from(10)?;
+/// alignment::clip_and_update_template_length(&mut record, start..=end)?;
+///
+/// assert_eq!(record.template_length(), 27);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn clip_and_update_template_length<I>(record: &mut RecordBuf, interval: I) -> io::Result<usize>
+where
+    I: Into<Interval>,
+{
+    let (clipped_len, start_span, end_span) = clip_inner(record, interval.into())?;
+
+    let template_length = record.template_length();
+
+    let span = if template_length > 0 {
+        start_span
+    } else {
+        end_span
+    };
+
+    let span = i32::try_from(span).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    *record.template_length_mut() = match template_length {
+        0 => 0,
+        n if n > 0 => (n - span).max(0),
+        n => (n + span).min(0),
+    };
+
+    Ok(clipped_len)
+}
+
+// Returns the number of clipped bases and the number of reference positions removed from the
+// start and end of the alignment.
+fn clip_inner(record: &mut RecordBuf, interval: Interval) -> io::Result<(usize, usize, usize)> {
+    if record.flags().is_unmapped() {
+        return Ok((0, 0, 0));
+    }
+
+    let (Some(alignment_start), Some(alignment_end)) =
+        (record.alignment_start(), record.alignment_end())
+    else {
+        return Ok((0, 0, 0));
+    };
+
+    let interval_start = interval.start().unwrap_or(Position::MIN);
+    let interval_end = interval.end().unwrap_or(Position::MAX);
+
+    if interval_end < alignment_start || interval_start > alignment_end {
+        return Ok((0, 0, 0));
+    }
+
+    let mut ops = record.cigar().as_ref().to_vec();
+    let mut clipped_len = 0;
+    let mut start_span = 0;
+    let mut end_span = 0;
+
+    if interval_start <= alignment_start {
+        let len = usize::from(interval_end.min(alignment_end)) - usize::from(alignment_start) + 1;
+        (ops, clipped_len, start_span) = clip_start(&ops, len)?;
+    } else if interval_end >= alignment_end {
+        let len = usize::from(alignment_end) - usize::from(interval_start) + 1;
+
+        ops.reverse();
+        let (mut reversed_ops, n, span) = clip_start(&ops, len)?;
+        reversed_ops.reverse();
+
+        (ops, clipped_len, end_span) = (reversed_ops, n, span);
+    }
+
+    if start_span > 0 {
+        let alignment_start = usize::from(alignment_start) + start_span;
+        *record.alignment_start_mut() = Position::new(alignment_start);
+    }
+
+    *record.cigar_mut() = ops.into_iter().collect();
+
+    Ok((clipped_len, start_span, end_span))
+}
+
+// Clips `len` reference positions from the start of the alignment.
+//
+// This returns the new ops, the number of clipped read bases, and the number of reference
+// positions removed, which can be larger than `len` when a deletion or skip follows the clip.
+fn clip_start(ops: &[Op], mut len: usize) -> io::Result<(Vec<Op>, usize, usize)> {
+    let mut iter = ops.iter().copied().peekable();
+
+    let hard_clip = iter.next_if(|op| op.kind() == Kind::HardClip);
+
+    let mut soft_clip_len = 0;
+
+    while let Some(op) = iter.next_if(|op| op.kind() == Kind::SoftClip) {
+        soft_clip_len += op.len();
+    }
+
+    let mut clipped_len = 0;
+    let mut span = 0;
+    let mut rest = None;
+
+    for op in iter.by_ref() {
+        let kind = op.kind();
+
+        match (kind.consumes_read(), kind.consumes_reference()) {
+            (true, true) => {
+                if len == 0 {
+                    rest = Some(op);
+                    break;
+                }
+
+                let n = op.len().min(len);
+
+                len -= n;
+                clipped_len += n;
+                span += n;
+
+                if n < op.len() {
+                    rest = Some(Op::new(kind, op.len() - n));
+                    break;
+                }
+            }
+            // Deletions and skips at the clip boundary are removed entirely.
+            (false, true) => {
+                len = len.saturating_sub(op.len());
+                span += op.len();
+            }
+            (true, false) => {
+                if kind == Kind::SoftClip {
+                    // The trailing soft clip was reached, i.e., all aligned bases were clipped.
+                    break;
+                }
+
+                clipped_len += op.len();
+            }
+            (false, false) => {}
+        }
+    }
+
+    let Some(rest) = rest else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "interval covers all aligned bases",
+        ));
+    };
+
+    soft_clip_len += clipped_len;
+
+    let mut clipped_ops = Vec::with_capacity(ops.len() + 1);
+    clipped_ops.extend(hard_clip);
+
+    if soft_clip_len > 0 {
+        clipped_ops.push(Op::new(Kind::SoftClip, soft_clip_len));
+    }
+
+    clipped_ops.push(rest);
+    clipped_ops.extend(iter);
+
+    Ok((clipped_ops, clipped_len, span))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alignment::record::Flags;
+
+    fn interval(start: usize, end: usize) -> Interval {
+        let start = Position::new(start).unwrap();
+        let end = Position::new(end).unwrap();
+        (start..=end).into()
+    }
+
+    #[test]
+    fn test_clip() -> Result<(), Box<dyn std::error::Error>> {
+        // start
+        let mut record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_alignment_start(Position::try_from(10)?)
+            .set_cigar(
+                [
+                    Op::new(Kind::HardClip, 2),
+                    Op::new(Kind::SoftClip, 1),
+                    Op::new(Kind::Match, 4),
+                    Op::new(Kind::Insertion, 1),
+                    Op::new(Kind::Match, 4),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+        assert_eq!(clip(&mut record, interval(1, 12))?, 3);
+        assert_eq!(record.alignment_start(), Position::new(13));
+        assert_eq!(
+            record.cigar().as_ref(),
+            [
+                Op::new(Kind::HardClip, 2),
+                Op::new(Kind::SoftClip, 4),
+                Op::new(Kind::Match, 1),
+                Op::new(Kind::Insertion, 1),
+                Op::new(Kind::Match, 4),
+            ]
+        );
+
+        // start, followed by an insertion and deletion
+        let mut record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_alignment_start(Position::try_from(10)?)
+            .set_cigar(
+                [
+                    Op::new(Kind::Match, 4),
+                    Op::new(Kind::Insertion, 2),
+                    Op::new(Kind::Deletion, 3),
+                    Op::new(Kind::Match, 4),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+        assert_eq!(clip(&mut record, interval(1, 13))?, 6);
+        assert_eq!(record.alignment_start(), Position::new(17));
+        assert_eq!(
+            record.cigar().as_ref(),
+            [Op::new(Kind::SoftClip, 6), Op::new(Kind::Match, 4)]
+        );
+
+        // end
+        let mut record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_alignment_start(Position::try_from(10)?)
+            .set_cigar(
+                [
+                    Op::new(Kind::Match, 4),
+                    Op::new(Kind::Deletion, 1),
+                    Op::new(Kind::Match, 4),
+                    Op::new(Kind::SoftClip, 2),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+        assert_eq!(clip(&mut record, interval(17, 30))?, 2);
+        assert_eq!(record.alignment_start(), Position::new(10));
+        assert_eq!(
+            record.cigar().as_ref(),
+            [
+                Op::new(Kind::Match, 4),
+                Op::new(Kind::Deletion, 1),
+                Op::new(Kind::Match, 2),
+                Op::new(Kind::SoftClip, 4),
+            ]
+        );
+
+        // inside
+        let mut record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_alignment_start(Position::try_from(10)?)
+            .set_cigar([Op::new(Kind::Match, 8)].into_iter().collect())
+            .build();
+        assert_eq!(clip(&mut record, interval(12, 14))?, 0);
+        assert_eq!(record.cigar().as_ref(), [Op::new(Kind::Match, 8)]);
+
+        // outside
+        assert_eq!(clip(&mut record, interval(1, 9))?, 0);
+        assert_eq!(clip(&mut record, interval(18, 30))?, 0);
+        assert_eq!(record.cigar().as_ref(), [Op::new(Kind::Match, 8)]);
+
+        // all
+        assert!(matches!(
+            clip(&mut record, interval(1, 30)),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+        assert_eq!(record.alignment_start(), Position::new(10));
+        assert_eq!(record.cigar().as_ref(), [Op::new(Kind::Match, 8)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_clip_and_update_template_length() -> Result<(), Box<dyn std::error::Error>> {
+        let mut record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_alignment_start(Position::try_from(10)?)
+            .set_cigar([Op::new(Kind::Match, 8)].into_iter().collect())
+            .build();
+        *record.template_length_mut() = 20;
+        clip_and_update_template_length(&mut record, interval(1, 11))?;
+        assert_eq!(record.template_length(), 18);
+
+        clip_and_update_template_length(&mut record, interval(16, 20))?;
+        assert_eq!(record.template_length(), 18);
+
+        let mut record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_alignment_start(Position::try_from(10)?)
+            .set_cigar([Op::new(Kind::Match, 8)].into_iter().collect())
+            .build();
+        *record.template_length_mut() = -20;
+        clip_and_update_template_length(&mut record, interval(16, 20))?;
+        assert_eq!(record.template_length(), -18);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,365 @@
+//! Alignment record comparators.
+//!
+//! These order records the same way as samtools and Picard, including their tie-breaking rules,
+//! so that sorted or merged output agrees with these tools.
+
+use std::{cmp::Ordering, io};
+
+use super::{
+    record::{data::field::Tag, Flags},
+    Record,
+};
+use crate::Header;
+
+const MISSING_NAME: &[u8] = b"*";
+
+/// Compares two alignment records by coordinate.
+///
+/// This is the order of `samtools sort`. Records are compared by reference sequence ID, alignment
+/// start, and strand (forward before reverse). Records without a reference sequence ID or
+/// alignment start are ordered last.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// use noodles_core::Position;
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{cmp, record::Flags, RecordBuf},
+/// };
+///
+/// let header = sam::Header::default();
+///
+/// let a = RecordBuf::builder()
+///     .set_flags(Flags::empty())
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(Position::MIN)
+///     .build();
+///
+/// let b = RecordBuf::default();
+///
+/// assert_eq!(cmp::coordinate(&a, &b, &header)?, Ordering::Less);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn coordinate<A, B>(a: &A, b: &B, header: &Header) -> io::Result<Ordering>
+where
+    A: Record + ?Sized,
+    B: Record + ?Sized,
+{
+    fn key<R>(record: &R, header: &Header) -> io::Result<(usize, usize, bool)>
+    where
+        R: Record + ?Sized,
+    {
+        let reference_sequence_id = record
+            .reference_sequence_id(header)
+            .transpose()?
+            .unwrap_or(usize::MAX);
+
+        let alignment_start = record
+            .alignment_start()
+            .transpose()?
+            .map(usize::from)
+            .unwrap_or(usize::MAX);
+
+        let is_reverse_complemented = record.flags()?.is_reverse_complemented();
+
+        Ok((
+            reference_sequence_id,
+            alignment_start,
+            is_reverse_complemented,
+        ))
+    }
+
+    Ok(key(a, header)?.cmp(&key(b, header)?))
+}
+
+/// Compares two alignment records by natural queryname.
+///
+/// This is the order of `samtools sort -n`. Names are compared in natural order, i.e., runs of
+/// digits are compared numerically (e.g., `r2` < `r10`). Ties are broken by the segment flags,
+/// ordering records that are neither first nor last segments first, then first segments, and
+/// then last segments.
+///
+/// Missing names are compared as `*`.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+/// use noodles_sam::alignment::{cmp, RecordBuf};
+///
+/// let a = RecordBuf::builder().set_name("r2").build();
+/// let b = RecordBuf::builder().set_name("r10").build();
+///
+/// assert_eq!(cmp::queryname_natural(&a, &b)?, Ordering::Less);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn queryname_natural<A, B>(a: &A, b: &B) -> io::Result<Ordering>
+where
+    A: Record + ?Sized,
+    B: Record + ?Sized,
+{
+    let ordering = natural_cmp(name(a), name(b));
+
+    if ordering.is_ne() {
+        return Ok(ordering);
+    }
+
+    let segment_flags = Flags::FIRST_SEGMENT | Flags::LAST_SEGMENT;
+    let a_flags = a.flags()? & segment_flags;
+    let b_flags = b.flags()? & segment_flags;
+
+    Ok(a_flags.bits().cmp(&b_flags.bits()))
+}
+
+/// Compares two alignment records by lexicographic queryname.
+///
+/// This is the order of Picard `SortSam SORT_ORDER=queryname` and `samtools sort -N`. Names are
+/// compared byte by byte. Ties are broken as Picard does: unpaired records after paired records,
+/// first segments before last segments, forward before reverse strand, primary before secondary,
+/// nonsupplementary before supplementary, and then by hit index (`HI`), with records missing a hit
+/// index first.
+///
+/// Missing names are compared as `*`.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+/// use noodles_sam::alignment::{cmp, RecordBuf};
+///
+/// let a = RecordBuf::builder().set_name("r10").build();
+/// let b = RecordBuf::builder().set_name("r2").build();
+///
+/// assert_eq!(cmp::queryname_lexicographic(&a, &b)?, Ordering::Less);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn queryname_lexicographic<A, B>(a: &A, b: &B) -> io::Result<Ordering>
+where
+    A: Record + ?Sized,
+    B: Record + ?Sized,
+{
+    let ordering = name(a).cmp(name(b));
+
+    if ordering.is_ne() {
+        return Ok(ordering);
+    }
+
+    let a_flags = a.flags()?;
+    let b_flags = b.flags()?;
+
+    if a_flags.is_segmented() || b_flags.is_segmented() {
+        match (a_flags.is_segmented(), b_flags.is_segmented()) {
+            (false, _) => return Ok(Ordering::Greater),
+            (_, false) => return Ok(Ordering::Less),
+            _ => {}
+        }
+
+        if a_flags.is_first_segment() && b_flags.is_last_segment() {
+            return Ok(Ordering::Less);
+        } else if a_flags.is_last_segment() && b_flags.is_first_segment() {
+            return Ok(Ordering::Greater);
+        }
+    }
+
+    let ordering = a_flags
+        .is_reverse_complemented()
+        .cmp(&b_flags.is_reverse_complemented())
+        .then_with(|| a_flags.is_secondary().cmp(&b_flags.is_secondary()))
+        .then_with(|| a_flags.is_supplementary().cmp(&b_flags.is_supplementary()));
+
+    if ordering.is_ne() {
+        return Ok(ordering);
+    }
+
+    Ok(hit_index(a)?.cmp(&hit_index(b)?))
+}
+
+fn name<R>(record: &R) -> &[u8]
+where
+    R: Record + ?Sized,
+{
+    record
+        .name()
+        .map(|name| name.as_ref())
+        .unwrap_or(MISSING_NAME)
+}
+
+fn hit_index<R>(record: &R) -> io::Result<Option<i64>>
+where
+    R: Record + ?Sized,
+{
+    record
+        .data()
+        .get(&Tag::HIT_INDEX)
+        .transpose()?
+        .map(|value| {
+            value
+                .as_int()
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid HI value"))
+        })
+        .transpose()
+}
+
+// This is a port of samtools `strnum_cmp`.
+fn natural_cmp(a: &[u8], b: &[u8]) -> Ordering {
+    let mut i = 0;
+    let mut j = 0;
+
+    while i < a.len() && j < b.len() {
+        if !a[i].is_ascii_digit() || !b[j].is_ascii_digit() {
+            if a[i] != b[j] {
+                return a[i].cmp(&b[j]);
+            }
+
+            i += 1;
+            j += 1;
+        } else {
+            while a.get(i) == Some(&b'0') {
+                i += 1;
+            }
+
+            while b.get(j) == Some(&b'0') {
+                j += 1;
+            }
+
+            while is_digit_at(a, i) && a.get(i) == b.get(j) {
+                i += 1;
+                j += 1;
+            }
+
+            let ordering = a.get(i).cmp(&b.get(j));
+
+            while is_digit_at(a, i) && is_digit_at(b, j) {
+                i += 1;
+                j += 1;
+            }
+
+            if is_digit_at(a, i) {
+                return Ordering::Greater;
+            } else if is_digit_at(b, j) {
+                return Ordering::Less;
+            } else if ordering.is_ne() {
+                return ordering;
+            }
+        }
+    }
+
+    (i < a.len()).cmp(&(j < b.len()))
+}
+
+fn is_digit_at(buf: &[u8], i: usize) -> bool {
+    buf.get(i).map(|b| b.is_ascii_digit()).unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::alignment::{record_buf::data::field::Value, RecordBuf};
+
+    #[test]
+    fn test_coordinate() -> io::Result<()> {
+        fn build(
+            reference_sequence_id: Option<usize>,
+            alignment_start: Option<usize>,
+            flags: Flags,
+        ) -> RecordBuf {
+            let mut record = RecordBuf::builder().set_flags(flags).build();
+            *record.reference_sequence_id_mut() = reference_sequence_id;
+            *record.alignment_start_mut() = alignment_start.and_then(Position::new);
+            record
+        }
+
+        let header = Header::default();
+
+        let records = [
+            build(Some(0), Some(8), Flags::empty()),
+            build(Some(0), Some(8), Flags::REVERSE_COMPLEMENTED),
+            build(Some(0), Some(13), Flags::empty()),
+            build(Some(0), None, Flags::UNMAPPED),
+            build(Some(1), Some(5), Flags::empty()),
+            build(None, None, Flags::UNMAPPED),
+        ];
+
+        for (i, a) in records.iter().enumerate() {
+            for (j, b) in records.iter().enumerate() {
+                assert_eq!(coordinate(a, b, &header)?, i.cmp(&j));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_queryname_natural() -> io::Result<()> {
+        let build =
+            |name: &str, flags| RecordBuf::builder().set_name(name).set_flags(flags).build();
+
+        let records = [
+            build("r1", Flags::empty()),
+            build("r1", Flags::SEGMENTED | Flags::FIRST_SEGMENT),
+            build("r1", Flags::SEGMENTED | Flags::LAST_SEGMENT),
+            build("r2", Flags::empty()),
+            build("r10", Flags::empty()),
+            build("s", Flags::empty()),
+        ];
+
+        for (i, a) in records.iter().enumerate() {
+            for (j, b) in records.iter().enumerate() {
+                assert_eq!(queryname_natural(a, b)?, i.cmp(&j));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_queryname_lexicographic() -> io::Result<()> {
+        let build =
+            |name: &str, flags| RecordBuf::builder().set_name(name).set_flags(flags).build();
+
+        let mut hi_1 = build("r0", Flags::empty());
+        hi_1.data_mut().insert(Tag::HIT_INDEX, Value::from(1));
+
+        let mut hi_2 = build("r0", Flags::empty());
+        hi_2.data_mut().insert(Tag::HIT_INDEX, Value::from(2));
+
+        let records = [
+            build("r0", Flags::SEGMENTED | Flags::FIRST_SEGMENT),
+            build("r0", Flags::SEGMENTED | Flags::LAST_SEGMENT),
+            build("r0", Flags::empty()),
+            hi_1,
+            hi_2,
+            build("r0", Flags::SUPPLEMENTARY),
+            build("r0", Flags::SECONDARY),
+            build("r0", Flags::REVERSE_COMPLEMENTED),
+            build("r10", Flags::empty()),
+            build("r2", Flags::empty()),
+        ];
+
+        for (i, a) in records.iter().enumerate() {
+            for (j, b) in records.iter().enumerate() {
+                assert_eq!(queryname_lexicographic(a, b)?, i.cmp(&j));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_natural_cmp() {
+        assert_eq!(natural_cmp(b"r2", b"r10"), Ordering::Less);
+        assert_eq!(natural_cmp(b"r10", b"r2"), Ordering::Greater);
+        assert_eq!(natural_cmp(b"r10", b"r10"), Ordering::Equal);
+        assert_eq!(natural_cmp(b"r010", b"r10"), Ordering::Equal);
+        assert_eq!(natural_cmp(b"r10", b"r11"), Ordering::Less);
+        assert_eq!(natural_cmp(b"r1:2", b"r1:10"), Ordering::Less);
+        assert_eq!(natural_cmp(b"r1", b"r1a"), Ordering::Less);
+        assert_eq!(natural_cmp(b"a", b"b"), Ordering::Less);
+        assert_eq!(natural_cmp(b"", b""), Ordering::Equal);
+    }
+}
@@ -0,0 +1,327 @@
+//! Alignment record filter expressions.
+
+mod parser;
+
+pub use self::parser::ParseError;
+
+use std::{cmp::Ordering, io, str::FromStr};
+
+use super::{
+    record::{data::field::Tag, Flags},
+    Record,
+};
+
+/// An alignment record filter.
+///
+/// A filter is parsed from an expression similar to those used by `samtools view -e` and
+/// `bcftools view -i`, e.g., `!flag.unmapped && mapq >= 30 && [NM] <= 2`.
+///
+/// # Syntax
+///
+/// Expressions are comparisons (`==`, `!=`, `<`, `<=`, `>`, `>=`) of operands, combined with `&&`
+/// and `||` and negated with `!`. Parentheses group subexpressions. `&&` has a higher precedence
+/// than `||`.
+///
+/// An operand is a literal (an integer, a float, a double-quoted string, `true`, or `false`), a
+/// record field, or a data field. The record fields are
+///
+///   * `qname`: the name,
+///   * `flag`: the flags as an integer,
+///   * `flag.<name>`: a single flag, where `<name>` is one of `paired`, `proper_pair`,
+///     `unmapped` (`unmap`), `mate_unmapped` (`munmap`), `reverse`, `mate_reverse`
+///     (`mreverse`), `read1`, `read2`, `secondary`, `qcfail`, `dup`, or `supplementary`,
+///   * `mapq`: the mapping quality,
+///   * `pos`: the (1-based) alignment start,
+///   * `endpos`: the (1-based) alignment end,
+///   * `mpos`: the (1-based) mate alignment start,
+///   * `tlen`: the template length,
+///   * `rlen`: the alignment span, and
+///   * `qlen`: the sequence length.
+///
+/// A data field is a tag in square brackets, e.g., `[NM]`.
+///
+/// A field can be missing, e.g., a missing mapping quality or data field. Any comparison with a
+/// missing value is false. An operand not in a comparison is true if it is present and nonzero,
+/// e.g., `[SA]` matches records that have a supplementary alignments data field.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{filter::Filter, record::{Flags, MappingQuality}},
+/// };
+///
+/// let filter: Filter = "!flag.unmapped && mapq >= 30".parse()?;
+///
+/// let record = sam::alignment::RecordBuf::builder()
+///     .set_flags(Flags::empty())
+///     .set_mapping_quality(MappingQuality::new(34).unwrap())
+///     .build();
+///
+/// assert!(filter.matches(&record)?);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Filter(Expression);
+
+impl Filter {
+    /// Returns whether the given record matches the filter.
+    ///
+    /// This returns an error if a record field fails to decode or if the operands of a comparison
+    /// have incompatible types, e.g., an integer and a string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, alignment::filter::Filter};
+    /// let filter: Filter = "flag.unmapped".parse()?;
+    /// let record = sam::alignment::RecordBuf::default();
+    /// assert!(filter.matches(&record)?);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn matches<R>(&self, record: &R) -> io::Result<bool>
+    where
+        R: Record + ?Sized,
+    {
+        evaluate(&self.0, record)
+    }
+}
+
+impl FromStr for Filter {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parser::parse(s).map(Self)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Expression {
+    Or(Box<Expression>, Box<Expression>),
+    And(Box<Expression>, Box<Expression>),
+    Not(Box<Expression>),
+    Comparison(Operand, Operator, Operand),
+    Operand(Operand),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Operator {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Operand {
+    Literal(Value),
+    Field(Field),
+    DataField(Tag),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Field {
+    Name,
+    Flags,
+    Flag(Flags),
+    MappingQuality,
+    AlignmentStart,
+    AlignmentEnd,
+    MateAlignmentStart,
+    TemplateLength,
+    AlignmentSpan,
+    SequenceLength,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Value {
+    Missing,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    String(Vec<u8>),
+    Array,
+}
+
+fn evaluate<R>(expression: &Expression, record: &R) -> io::Result<bool>
+where
+    R: Record + ?Sized,
+{
+    match expression {
+        Expression::Or(a, b) => Ok(evaluate(a, record)? || evaluate(b, record)?),
+        Expression::And(a, b) => Ok(evaluate(a, record)? && evaluate(b, record)?),
+        Expression::Not(a) => evaluate(a, record).map(|is_match| !is_match),
+        Expression::Comparison(a, operator, b) => {
+            let a = resolve(a, record)?;
+            let b = resolve(b, record)?;
+            compare(&a, *operator, &b)
+        }
+        Expression::Operand(a) => resolve(a, record).map(|value| is_truthy(&value)),
+    }
+}
+
+fn resolve<R>(operand: &Operand, record: &R) -> io::Result<Value>
+where
+    R: Record + ?Sized,
+{
+    fn from_position(position: Option<io::Result<noodles_core::Position>>) -> io::Result<Value> {
+        position.transpose().map(|p| {
+            p.map(|p| Value::Int(usize::from(p) as i64))
+                .unwrap_or(Value::Missing)
+        })
+    }
+
+    match operand {
+        Operand::Literal(value) => Ok(value.clone()),
+        Operand::Field(field) => match field {
+            Field::Name => Ok(record
+                .name()
+                .map(|name| Value::String(name.to_vec()))
+                .unwrap_or(Value::Missing)),
+            Field::Flags => record
+                .flags()
+                .map(|flags| Value::Int(i64::from(flags.bits()))),
+            Field::Flag(flag) => record
+                .flags()
+                .map(|flags| Value::Bool(flags.contains(*flag))),
+            Field::MappingQuality => record.mapping_quality().transpose().map(|mapq| {
+                mapq.map(|mapq| Value::Int(i64::from(mapq.get())))
+                    .unwrap_or(Value::Missing)
+            }),
+            Field::AlignmentStart => from_position(record.alignment_start()),
+            Field::AlignmentEnd => from_position(record.alignment_end()),
+            Field::MateAlignmentStart => from_position(record.mate_alignment_start()),
+            Field::TemplateLength => record.template_length().map(|n| Value::Int(i64::from(n))),
+            Field::AlignmentSpan => record
+                .alignment_span()
+                .map(|span| span.map(|n| Value::Int(n as i64)).unwrap_or(Value::Missing)),
+            Field::SequenceLength => Ok(Value::Int(record.sequence().len() as i64)),
+        },
+        Operand::DataField(tag) => {
+            use super::record::data::field::Value as DataValue;
+
+            let data = record.data();
+
+            let Some(value) = data.get(tag).transpose()? else {
+                return Ok(Value::Missing);
+            };
+
+            Ok(match value {
+                DataValue::Character(c) => Value::String(vec![c]),
+                DataValue::Float(n) => Value::Float(f64::from(n)),
+                DataValue::String(s) | DataValue::Hex(s) => Value::String(s.to_vec()),
+                DataValue::Array(_) => Value::Array,
+                value => value.as_int().map(Value::Int).unwrap_or(Value::Missing),
+            })
+        }
+    }
+}
+
+fn is_truthy(value: &Value) -> bool {
+    match value {
+        Value::Missing => false,
+        Value::Bool(b) => *b,
+        Value::Int(n) => *n != 0,
+        Value::Float(n) => *n != 0.0,
+        Value::String(_) | Value::Array => true,
+    }
+}
+
+fn compare(a: &Value, operator: Operator, b: &Value) -> io::Result<bool> {
+    let ordering = match (a, b) {
+        (Value::Missing, _) | (_, Value::Missing) => return Ok(false),
+        (Value::Bool(a), Value::Bool(b)) => Some(a.cmp(b)),
+        (Value::Int(a), Value::Int(b)) => Some(a.cmp(b)),
+        (Value::Int(a), Value::Float(b)) => (*a as f64).partial_cmp(b),
+        (Value::Float(a), Value::Int(b)) => a.partial_cmp(&(*b as f64)),
+        (Value::Float(a), Value::Float(b)) => a.partial_cmp(b),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("cannot compare {a:?} with {b:?}"),
+            ))
+        }
+    };
+
+    let Some(ordering) = ordering else {
+        return Ok(false);
+    };
+
+    Ok(match operator {
+        Operator::Eq => ordering == Ordering::Equal,
+        Operator::Ne => ordering != Ordering::Equal,
+        Operator::Lt => ordering == Ordering::Less,
+        Operator::Le => ordering != Ordering::Greater,
+        Operator::Gt => ordering == Ordering::Greater,
+        Operator::Ge => ordering != Ordering::Less,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::alignment::{
+        record::{
+            cigar::{op::Kind, Op},
+            MappingQuality,
+        },
+        record_buf::{data::field::Value as DataValue, Cigar, Data},
+        RecordBuf,
+    };
+
+    #[test]
+    fn test_matches() -> Result<(), Box<dyn std::error::Error>> {
+        let data: Data = [
+            (Tag::EDIT_DISTANCE, DataValue::from(1u8)),
+            (Tag::READ_GROUP, DataValue::from("rg0")),
+            (Tag::ALIGNMENT_SCORE, DataValue::from(-8i32)),
+        ]
+        .into_iter()
+        .collect();
+
+        let record = RecordBuf::builder()
+            .set_name("r0")
+            .set_flags(Flags::SEGMENTED | Flags::REVERSE_COMPLEMENTED)
+            .set_alignment_start(Position::try_from(8)?)
+            .set_mapping_quality(MappingQuality::new(34).unwrap())
+            .set_cigar(Cigar::from(vec![Op::new(Kind::Match, 4)]))
+            .set_sequence(b"ACGT".to_vec().into())
+            .set_data(data)
+            .build();
+
+        let matches = |s: &str| -> Result<bool, Box<dyn std::error::Error>> {
+            let filter: Filter = s.parse()?;
+            Ok(filter.matches(&record)?)
+        };
+
+        assert!(matches(
+            "flag.unmapped == false && mapq >= 30 && [NM] <= 2"
+        )?);
+        assert!(matches("!flag.unmap && flag.reverse && flag.paired")?);
+        assert!(!matches("flag.dup || flag.secondary")?);
+        assert!(matches("flag == 17")?);
+        assert!(matches("qname == \"r0\" && [RG] == \"rg0\"")?);
+        assert!(matches(
+            "pos == 8 && endpos == 11 && rlen == 4 && qlen == 4"
+        )?);
+        assert!(matches("[AS] < -5 && [AS] > -8.5")?);
+        assert!(matches("!(mapq < 30 || [NM] > 0) || true")?);
+        assert!(matches("[NM] && !![RG]")?);
+
+        // missing values
+        assert!(!matches("[XA]")?);
+        assert!(!matches("[XA] == 0")?);
+        assert!(!matches("[XA] != 0")?);
+        assert!(!matches("mpos > 0")?);
+
+        assert!(matches("mapq == \"34\"").is_err());
+
+        Ok(())
+    }
+}
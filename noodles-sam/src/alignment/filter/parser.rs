@@ -0,0 +1,396 @@
+use std::{error, fmt, iter::Peekable, str::CharIndices};
+
+use super::{Expression, Field, Operand, Operator, Value};
+use crate::alignment::record::{data::field::Tag, Flags};
+
+/// An error returned when an alignment record filter expression fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input ended unexpectedly.
+    UnexpectedEof,
+    /// A character is unexpected.
+    UnexpectedCharacter(char),
+    /// A token is unexpected.
+    UnexpectedToken(String),
+    /// A string is not terminated.
+    UnterminatedString,
+    /// A number is invalid.
+    InvalidNumber(String),
+    /// A data field tag is invalid.
+    InvalidTag(String),
+    /// A field is invalid.
+    InvalidField(String),
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEof => f.write_str("unexpected EOF"),
+            Self::UnexpectedCharacter(c) => write!(f, "unexpected character: {c:?}"),
+            Self::UnexpectedToken(s) => write!(f, "unexpected token: {s}"),
+            Self::UnterminatedString => f.write_str("unterminated string"),
+            Self::InvalidNumber(s) => write!(f, "invalid number: {s}"),
+            Self::InvalidTag(s) => write!(f, "invalid tag: {s}"),
+            Self::InvalidField(s) => write!(f, "invalid field: {s}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Identifier(String),
+    Number(String),
+    String(Vec<u8>),
+    Tag(String),
+    Operator(Operator),
+    And,
+    Or,
+    Not,
+    LeftParen,
+    RightParen,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Identifier(s) | Self::Number(s) => f.write_str(s),
+            Self::String(s) => write!(f, "{:?}", String::from_utf8_lossy(s)),
+            Self::Tag(s) => write!(f, "[{s}]"),
+            Self::Operator(operator) => f.write_str(match operator {
+                Operator::Eq => "==",
+                Operator::Ne => "!=",
+                Operator::Lt => "<",
+                Operator::Le => "<=",
+                Operator::Gt => ">",
+                Operator::Ge => ">=",
+            }),
+            Self::And => f.write_str("&&"),
+            Self::Or => f.write_str("||"),
+            Self::Not => f.write_str("!"),
+            Self::LeftParen => f.write_str("("),
+            Self::RightParen => f.write_str(")"),
+        }
+    }
+}
+
+pub(super) fn parse(s: &str) -> Result<Expression, ParseError> {
+    let tokens = tokenize(s)?;
+    let mut tokens = tokens.into_iter().peekable();
+
+    let expression = parse_or(&mut tokens)?;
+
+    match tokens.next() {
+        Some(token) => Err(ParseError::UnexpectedToken(token.to_string())),
+        None => Ok(expression),
+    }
+}
+
+type Tokens = Peekable<std::vec::IntoIter<Token>>;
+
+fn parse_or(tokens: &mut Tokens) -> Result<Expression, ParseError> {
+    let mut expression = parse_and(tokens)?;
+
+    while tokens.next_if_eq(&Token::Or).is_some() {
+        let rhs = parse_and(tokens)?;
+        expression = Expression::Or(Box::new(expression), Box::new(rhs));
+    }
+
+    Ok(expression)
+}
+
+fn parse_and(tokens: &mut Tokens) -> Result<Expression, ParseError> {
+    let mut expression = parse_not(tokens)?;
+
+    while tokens.next_if_eq(&Token::And).is_some() {
+        let rhs = parse_not(tokens)?;
+        expression = Expression::And(Box::new(expression), Box::new(rhs));
+    }
+
+    Ok(expression)
+}
+
+fn parse_not(tokens: &mut Tokens) -> Result<Expression, ParseError> {
+    if tokens.next_if_eq(&Token::Not).is_some() {
+        parse_not(tokens).map(|expression| Expression::Not(Box::new(expression)))
+    } else {
+        parse_comparison(tokens)
+    }
+}
+
+fn parse_comparison(tokens: &mut Tokens) -> Result<Expression, ParseError> {
+    if tokens.next_if_eq(&Token::LeftParen).is_some() {
+        let expression = parse_or(tokens)?;
+
+        return match tokens.next() {
+            Some(Token::RightParen) => Ok(expression),
+            Some(token) => Err(ParseError::UnexpectedToken(token.to_string())),
+            None => Err(ParseError::UnexpectedEof),
+        };
+    }
+
+    let lhs = parse_operand(tokens)?;
+
+    if let Some(Token::Operator(operator)) = tokens.peek().cloned() {
+        tokens.next();
+        let rhs = parse_operand(tokens)?;
+        Ok(Expression::Comparison(lhs, operator, rhs))
+    } else {
+        Ok(Expression::Operand(lhs))
+    }
+}
+
+fn parse_operand(tokens: &mut Tokens) -> Result<Operand, ParseError> {
+    match tokens.next().ok_or(ParseError::UnexpectedEof)? {
+        Token::Identifier(s) => parse_identifier(&s),
+        Token::Number(s) => parse_number(&s).map(Operand::Literal),
+        Token::String(s) => Ok(Operand::Literal(Value::String(s))),
+        Token::Tag(s) => parse_tag(&s).map(Operand::DataField),
+        token => Err(ParseError::UnexpectedToken(token.to_string())),
+    }
+}
+
+fn parse_identifier(s: &str) -> Result<Operand, ParseError> {
+    let field = match s {
+        "true" => return Ok(Operand::Literal(Value::Bool(true))),
+        "false" => return Ok(Operand::Literal(Value::Bool(false))),
+        "qname" => Field::Name,
+        "flag" => Field::Flags,
+        "mapq" => Field::MappingQuality,
+        "pos" => Field::AlignmentStart,
+        "endpos" => Field::AlignmentEnd,
+        "mpos" => Field::MateAlignmentStart,
+        "tlen" => Field::TemplateLength,
+        "rlen" => Field::AlignmentSpan,
+        "qlen" => Field::SequenceLength,
+        _ => {
+            let flag = s
+                .strip_prefix("flag.")
+                .and_then(parse_flag)
+                .ok_or_else(|| ParseError::InvalidField(s.into()))?;
+
+            Field::Flag(flag)
+        }
+    };
+
+    Ok(Operand::Field(field))
+}
+
+fn parse_flag(s: &str) -> Option<Flags> {
+    match s {
+        "paired" => Some(Flags::SEGMENTED),
+        "proper_pair" => Some(Flags::PROPERLY_SEGMENTED),
+        "unmapped" | "unmap" => Some(Flags::UNMAPPED),
+        "mate_unmapped" | "munmap" => Some(Flags::MATE_UNMAPPED),
+        "reverse" => Some(Flags::REVERSE_COMPLEMENTED),
+        "mate_reverse" | "mreverse" => Some(Flags::MATE_REVERSE_COMPLEMENTED),
+        "read1" => Some(Flags::FIRST_SEGMENT),
+        "read2" => Some(Flags::LAST_SEGMENT),
+        "secondary" => Some(Flags::SECONDARY),
+        "qcfail" => Some(Flags::QC_FAIL),
+        "dup" => Some(Flags::DUPLICATE),
+        "supplementary" => Some(Flags::SUPPLEMENTARY),
+        _ => None,
+    }
+}
+
+fn parse_number(s: &str) -> Result<Value, ParseError> {
+    if let Ok(n) = s.parse() {
+        Ok(Value::Int(n))
+    } else {
+        s.parse()
+            .map(Value::Float)
+            .map_err(|_| ParseError::InvalidNumber(s.into()))
+    }
+}
+
+fn parse_tag(s: &str) -> Result<Tag, ParseError> {
+    match s.as_bytes() {
+        &[a, b] if a.is_ascii_alphabetic() && b.is_ascii_alphanumeric() => Ok(Tag::from([a, b])),
+        _ => Err(ParseError::InvalidTag(s.into())),
+    }
+}
+
+fn tokenize(s: &str) -> Result<Vec<Token>, ParseError> {
+    let mut chars = s.char_indices().peekable();
+    let mut tokens = Vec::new();
+
+    while let Some((i, c)) = chars.next() {
+        let token = match c {
+            _ if c.is_whitespace() => continue,
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            '&' => expect(&mut chars, '&', Token::And)?,
+            '|' => expect(&mut chars, '|', Token::Or)?,
+            '=' => expect(&mut chars, '=', Token::Operator(Operator::Eq))?,
+            '!' => {
+                if chars.next_if(|(_, c)| *c == '=').is_some() {
+                    Token::Operator(Operator::Ne)
+                } else {
+                    Token::Not
+                }
+            }
+            '<' => {
+                if chars.next_if(|(_, c)| *c == '=').is_some() {
+                    Token::Operator(Operator::Le)
+                } else {
+                    Token::Operator(Operator::Lt)
+                }
+            }
+            '>' => {
+                if chars.next_if(|(_, c)| *c == '=').is_some() {
+                    Token::Operator(Operator::Ge)
+                } else {
+                    Token::Operator(Operator::Gt)
+                }
+            }
+            '"' => tokenize_string(&mut chars)?,
+            '[' => {
+                let end = take_while(&mut chars, i + 1, |c| c != ']');
+
+                if chars.next().is_none() {
+                    return Err(ParseError::UnexpectedEof);
+                }
+
+                Token::Tag(s[i + 1..end].into())
+            }
+            '-' | '.' | '0'..='9' => {
+                let end = take_while(&mut chars, i + 1, |c| {
+                    c.is_ascii_alphanumeric() || matches!(c, '.' | '+' | '-')
+                });
+
+                Token::Number(s[i..end].into())
+            }
+            'A'..='Z' | 'a'..='z' | '_' => {
+                let end = take_while(&mut chars, i + 1, |c| {
+                    c.is_ascii_alphanumeric() || matches!(c, '_' | '.')
+                });
+
+                Token::Identifier(s[i..end].into())
+            }
+            _ => return Err(ParseError::UnexpectedCharacter(c)),
+        };
+
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+fn expect(
+    chars: &mut Peekable<CharIndices<'_>>,
+    expected: char,
+    token: Token,
+) -> Result<Token, ParseError> {
+    match chars.next() {
+        Some((_, c)) if c == expected => Ok(token),
+        Some((_, c)) => Err(ParseError::UnexpectedCharacter(c)),
+        None => Err(ParseError::UnexpectedEof),
+    }
+}
+
+fn take_while<F>(chars: &mut Peekable<CharIndices<'_>>, start: usize, f: F) -> usize
+where
+    F: Fn(char) -> bool,
+{
+    let mut end = start;
+
+    while let Some((i, c)) = chars.next_if(|(_, c)| f(*c)) {
+        end = i + c.len_utf8();
+    }
+
+    end
+}
+
+fn tokenize_string(chars: &mut Peekable<CharIndices<'_>>) -> Result<Token, ParseError> {
+    let mut buf = String::new();
+
+    loop {
+        match chars.next() {
+            Some((_, '"')) => return Ok(Token::String(buf.into_bytes())),
+            Some((_, '\\')) => match chars.next() {
+                Some((_, c)) => buf.push(c),
+                None => return Err(ParseError::UnterminatedString),
+            },
+            Some((_, c)) => buf.push(c),
+            None => return Err(ParseError::UnterminatedString),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("!flag.dup && mapq >= 30 || [NM] == 0"),
+            Ok(Expression::Or(
+                Box::new(Expression::And(
+                    Box::new(Expression::Not(Box::new(Expression::Operand(
+                        Operand::Field(Field::Flag(Flags::DUPLICATE))
+                    )))),
+                    Box::new(Expression::Comparison(
+                        Operand::Field(Field::MappingQuality),
+                        Operator::Ge,
+                        Operand::Literal(Value::Int(30)),
+                    )),
+                )),
+                Box::new(Expression::Comparison(
+                    Operand::DataField(Tag::EDIT_DISTANCE),
+                    Operator::Eq,
+                    Operand::Literal(Value::Int(0)),
+                )),
+            ))
+        );
+
+        assert_eq!(
+            parse("qname != \"r\\\"0\""),
+            Ok(Expression::Comparison(
+                Operand::Field(Field::Name),
+                Operator::Ne,
+                Operand::Literal(Value::String(b"r\"0".to_vec())),
+            ))
+        );
+
+        assert_eq!(
+            parse("[AS] < -1.5"),
+            Ok(Expression::Comparison(
+                Operand::DataField(Tag::ALIGNMENT_SCORE),
+                Operator::Lt,
+                Operand::Literal(Value::Float(-1.5)),
+            ))
+        );
+
+        assert_eq!(parse(""), Err(ParseError::UnexpectedEof));
+        assert_eq!(parse("mapq >="), Err(ParseError::UnexpectedEof));
+        assert_eq!(parse("(mapq"), Err(ParseError::UnexpectedEof));
+        assert_eq!(
+            parse("mapq 30"),
+            Err(ParseError::UnexpectedToken(String::from("30")))
+        );
+        assert_eq!(
+            parse("mapq = 30"),
+            Err(ParseError::UnexpectedCharacter(' '))
+        );
+        assert_eq!(
+            parse("mapq ~ 30"),
+            Err(ParseError::UnexpectedCharacter('~'))
+        );
+        assert_eq!(parse("qname == \"r0"), Err(ParseError::UnterminatedString));
+        assert_eq!(
+            parse("pos > 1.2.3"),
+            Err(ParseError::InvalidNumber(String::from("1.2.3")))
+        );
+        assert_eq!(
+            parse("[NMX] > 0"),
+            Err(ParseError::InvalidTag(String::from("NMX")))
+        );
+        assert_eq!(
+            parse("flag.mapped"),
+            Err(ParseError::InvalidField(String::from("flag.mapped")))
+        );
+    }
+}
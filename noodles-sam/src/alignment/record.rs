@@ -114,14 +114,178 @@ pub trait Record {
         };
 
         match self.alignment_span() {
-            Ok(Some(span)) => {
-                let end = usize::from(start) + span - 1;
-                core::Position::new(end).map(Ok)
-            }
+            Ok(Some(span)) => match usize::from(start).checked_add(span - 1) {
+                Some(end) => core::Position::new(end).map(Ok),
+                None => Some(Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "alignment end overflows usize",
+                ))),
+            },
             Ok(None) => Some(Ok(start)),
             Err(e) => Some(Err(e)),
         }
     }
+
+    /// Calculates the unclipped start position.
+    ///
+    /// This is the alignment start moved back by the lengths of the soft and hard clips at the
+    /// start of the alignment, i.e., the position the first base of the original read would align
+    /// to. It is not clamped to the start of the reference sequence, so it can be less than 1.
+    ///
+    /// This position is 1-based, inclusive.
+    fn unclipped_start(&self) -> Option<io::Result<isize>> {
+        let start = match self.alignment_start().transpose() {
+            Ok(position) => position?,
+            Err(e) => return Some(Err(e)),
+        };
+
+        let ops = match self.cigar().iter().collect::<io::Result<Vec<_>>>() {
+            Ok(ops) => ops,
+            Err(e) => return Some(Err(e)),
+        };
+
+        let (len, _) = cigar::clips_length(&ops);
+
+        let position = isize::try_from(usize::from(start))
+            .ok()
+            .zip(isize::try_from(len).ok())
+            .and_then(|(start, len)| start.checked_sub(len))
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "unclipped start overflows isize",
+                )
+            });
+
+        Some(position)
+    }
+
+    /// Calculates the unclipped end position.
+    ///
+    /// This is the alignment end moved forward by the lengths of the soft and hard clips at the
+    /// end of the alignment.
+    ///
+    /// This position is 1-based, inclusive.
+    fn unclipped_end(&self) -> Option<io::Result<core::Position>> {
+        let end = match self.alignment_end()? {
+            Ok(position) => position,
+            Err(e) => return Some(Err(e)),
+        };
+
+        let ops = match self.cigar().iter().collect::<io::Result<Vec<_>>>() {
+            Ok(ops) => ops,
+            Err(e) => return Some(Err(e)),
+        };
+
+        let (_, len) = cigar::clips_length(&ops);
+
+        match usize::from(end).checked_add(len) {
+            Some(n) => core::Position::new(n).map(Ok),
+            None => Some(Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "unclipped end overflows usize",
+            ))),
+        }
+    }
+
+    /// Returns the reference interval covered by the alignment.
+    ///
+    /// This is the interval from the alignment start to the alignment end, i.e., excluding soft
+    /// clips.
+    fn alignment_interval(&self) -> Option<io::Result<core::region::Interval>> {
+        let start = match self.alignment_start().transpose() {
+            Ok(position) => position?,
+            Err(e) => return Some(Err(e)),
+        };
+
+        let end = match self.alignment_end()? {
+            Ok(position) => position,
+            Err(e) => return Some(Err(e)),
+        };
+
+        Some(Ok((start..=end).into()))
+    }
+
+    /// Returns the reference interval covered by the alignment, including soft clips.
+    ///
+    /// This is the alignment interval extended by the lengths of the soft clips at either end,
+    /// i.e., the positions the soft-clipped bases would cover if they were aligned. Hard clips
+    /// are excluded. The start is clamped to the start of the reference sequence (position 1).
+    fn soft_clipped_interval(&self) -> Option<io::Result<core::region::Interval>> {
+        let (start, end) = match self.alignment_interval()? {
+            Ok(interval) => (interval.start()?, interval.end()?),
+            Err(e) => return Some(Err(e)),
+        };
+
+        let ops = match self.cigar().iter().collect::<io::Result<Vec<_>>>() {
+            Ok(ops) => ops,
+            Err(e) => return Some(Err(e)),
+        };
+
+        let (leading_len, trailing_len) = cigar::clip_lengths(&ops, cigar::op::Kind::SoftClip);
+
+        let start = usize::from(start)
+            .checked_sub(leading_len)
+            .and_then(core::Position::new)
+            .unwrap_or(core::Position::MIN);
+
+        let end = match usize::from(end).checked_add(trailing_len) {
+            Some(n) => core::Position::new(n)?,
+            None => {
+                return Some(Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "soft-clipped end overflows usize",
+                )))
+            }
+        };
+
+        Some(Ok((start..=end).into()))
+    }
+
+    /// Returns the reference interval the record is placed at.
+    ///
+    /// For mapped records, this is the alignment interval ([`Self::alignment_interval`]). An
+    /// unmapped record that has an alignment start is placed at the position of its mapped mate,
+    /// so its placement interval is that single position, regardless of its CIGAR operations.
+    /// Unplaced records have no placement interval.
+    fn placement_interval(&self) -> Option<io::Result<core::region::Interval>> {
+        let flags = match self.flags() {
+            Ok(flags) => flags,
+            Err(e) => return Some(Err(e)),
+        };
+
+        if flags.is_unmapped() {
+            match self.alignment_start()? {
+                Ok(start) => Some(Ok((start..=start).into())),
+                Err(e) => Some(Err(e)),
+            }
+        } else {
+            self.alignment_interval()
+        }
+    }
+
+    /// Formats the record as a SAM record line.
+    ///
+    /// The returned string does not include a trailing newline. This is mostly useful for
+    /// debugging, e.g., log messages; use a SAM writer to write many records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, alignment::{Record, RecordBuf}};
+    ///
+    /// let header = sam::Header::default();
+    /// let record = RecordBuf::default();
+    ///
+    /// assert_eq!(record.to_sam_string(&header)?, "*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*");
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    fn to_sam_string(&self, header: &Header) -> io::Result<String> {
+        let mut buf = Vec::new();
+        crate::io::writer::record::write_record(&mut buf, header, self)?;
+        buf.pop();
+        String::from_utf8(buf).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
 }
 
 impl Record for Box<dyn Record> {
@@ -216,4 +380,139 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_alignment_soft_clipped_and_placement_intervals(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use crate::alignment::{
+            record::cigar::{op::Kind, Op},
+            RecordBuf,
+        };
+
+        let cigar: crate::alignment::record_buf::Cigar = [
+            Op::new(Kind::HardClip, 2),
+            Op::new(Kind::SoftClip, 3),
+            Op::new(Kind::Match, 4),
+            Op::new(Kind::Deletion, 1),
+            Op::new(Kind::Match, 4),
+            Op::new(Kind::SoftClip, 1),
+        ]
+        .into_iter()
+        .collect();
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_alignment_start(core::Position::try_from(8)?)
+            .set_cigar(cigar.clone())
+            .build();
+
+        let interval = |start: usize,
+                        end: usize|
+         -> Result<core::region::Interval, core::position::TryFromIntError> {
+            Ok((core::Position::try_from(start)?..=core::Position::try_from(end)?).into())
+        };
+
+        assert_eq!(
+            Record::alignment_interval(&record).transpose()?,
+            Some(interval(8, 16)?)
+        );
+        assert_eq!(
+            Record::soft_clipped_interval(&record).transpose()?,
+            Some(interval(5, 17)?)
+        );
+        assert_eq!(
+            Record::placement_interval(&record).transpose()?,
+            Some(interval(8, 16)?)
+        );
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_alignment_start(core::Position::try_from(2)?)
+            .set_cigar(cigar.clone())
+            .build();
+
+        assert_eq!(
+            Record::soft_clipped_interval(&record).transpose()?,
+            Some(interval(1, 11)?)
+        );
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::UNMAPPED)
+            .set_alignment_start(core::Position::try_from(8)?)
+            .set_cigar(cigar)
+            .build();
+
+        assert_eq!(
+            Record::placement_interval(&record).transpose()?,
+            Some(interval(8, 8)?)
+        );
+
+        let record = RecordBuf::default();
+        assert!(Record::alignment_interval(&record).is_none());
+        assert!(Record::placement_interval(&record).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unclipped_start_and_end() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::alignment::{
+            record::cigar::{op::Kind, Op},
+            RecordBuf,
+        };
+
+        let record = RecordBuf::builder()
+            .set_alignment_start(core::Position::try_from(8)?)
+            .set_cigar(
+                [
+                    Op::new(Kind::HardClip, 2),
+                    Op::new(Kind::SoftClip, 3),
+                    Op::new(Kind::Match, 4),
+                    Op::new(Kind::Deletion, 1),
+                    Op::new(Kind::Match, 4),
+                    Op::new(Kind::SoftClip, 1),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        assert_eq!(Record::unclipped_start(&record).transpose()?, Some(3));
+        assert_eq!(
+            Record::unclipped_end(&record).transpose()?,
+            core::Position::new(17)
+        );
+
+        let record = RecordBuf::builder()
+            .set_alignment_start(core::Position::try_from(2)?)
+            .set_cigar(
+                [Op::new(Kind::SoftClip, 5), Op::new(Kind::Match, 4)]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+
+        assert_eq!(Record::unclipped_start(&record).transpose()?, Some(-3));
+
+        let record = RecordBuf::builder()
+            .set_alignment_start(core::Position::try_from(8)?)
+            .set_cigar(
+                [Op::new(Kind::HardClip, 5), Op::new(Kind::SoftClip, 10)]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+
+        assert_eq!(Record::unclipped_start(&record).transpose()?, Some(-7));
+        assert_eq!(
+            Record::unclipped_end(&record).transpose()?,
+            core::Position::new(8)
+        );
+
+        let record = RecordBuf::default();
+        assert!(Record::unclipped_start(&record).is_none());
+        assert!(Record::unclipped_end(&record).is_none());
+
+        Ok(())
+    }
 }
@@ -7,6 +7,9 @@ pub mod iter;
 
 use std::io;
 
+use noodles_core::Position;
+
+use self::op::Kind;
 pub use self::op::Op;
 
 /// Alignment record CIGAR operations.
@@ -28,7 +31,7 @@ pub trait Cigar {
             let op = result?;
 
             if op.kind().consumes_reference() {
-                span += op.len();
+                span = checked_add_len(span, op.len(), "alignment span")?;
             }
         }
 
@@ -43,12 +46,167 @@ pub trait Cigar {
             let op = result?;
 
             if op.kind().consumes_read() {
-                length += op.len();
+                length = checked_add_len(length, op.len(), "read length")?;
             }
         }
 
         Ok(length)
     }
+
+    /// Calculates the lengths of the soft clips at the start and end of the alignment.
+    ///
+    /// Hard clips outside the soft clips are ignored.
+    fn soft_clips(&self) -> io::Result<(usize, usize)> {
+        let ops = self.iter().collect::<io::Result<Vec<_>>>()?;
+        Ok(clip_lengths(&ops, Kind::SoftClip))
+    }
+
+    /// Calculates the lengths of the hard clips at the start and end of the alignment.
+    fn hard_clips(&self) -> io::Result<(usize, usize)> {
+        let ops = self.iter().collect::<io::Result<Vec<_>>>()?;
+        Ok(clip_lengths(&ops, Kind::HardClip))
+    }
+
+    /// Returns the read position aligned to the given reference position.
+    ///
+    /// Read positions are 1-based positions in the read sequence, including soft-clipped bases.
+    ///
+    /// This returns `None` if the reference position is outside the alignment or is aligned to a
+    /// deletion or skipped region.
+    fn read_position_at_reference_position(
+        &self,
+        alignment_start: Position,
+        reference_position: Position,
+    ) -> io::Result<Option<Position>> {
+        let target = usize::from(reference_position);
+
+        let mut reference_start = usize::from(alignment_start);
+        let mut read_start = 1;
+
+        for result in self.iter() {
+            let op = result?;
+
+            let kind = op.kind();
+            let len = op.len();
+
+            if kind.consumes_reference() {
+                if (reference_start..reference_start + len).contains(&target) {
+                    return if kind.consumes_read() {
+                        Ok(Position::new(read_start + (target - reference_start)))
+                    } else {
+                        Ok(None)
+                    };
+                }
+
+                reference_start += len;
+            }
+
+            if kind.consumes_read() {
+                read_start += len;
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Returns the reference position aligned to the given read position.
+    ///
+    /// Read positions are 1-based positions in the read sequence, including soft-clipped bases.
+    ///
+    /// This returns `None` if the read position is outside the read or is an insertion or soft
+    /// clip.
+    fn reference_position_at_read_position(
+        &self,
+        alignment_start: Position,
+        read_position: Position,
+    ) -> io::Result<Option<Position>> {
+        let target = usize::from(read_position);
+
+        let mut reference_start = usize::from(alignment_start);
+        let mut read_start = 1;
+
+        for result in self.iter() {
+            let op = result?;
+
+            let kind = op.kind();
+            let len = op.len();
+
+            if kind.consumes_read() {
+                if (read_start..read_start + len).contains(&target) {
+                    return if kind.consumes_reference() {
+                        Ok(Position::new(reference_start + (target - read_start)))
+                    } else {
+                        Ok(None)
+                    };
+                }
+
+                read_start += len;
+            }
+
+            if kind.consumes_reference() {
+                reference_start += len;
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+// Returns the lengths of the clips of the given kind at the start and end of the alignment.
+//
+// The end is only scanned up to the clip at the start, so operations that are all clips are
+// counted once, as a clip at the start.
+pub(crate) fn clip_lengths(ops: &[Op], clip_kind: Kind) -> (usize, usize) {
+    let (start, n) = clip_length(ops.iter(), clip_kind);
+    let (end, _) = clip_length(ops[n..].iter().rev(), clip_kind);
+    (start, end)
+}
+
+// Returns the length of the leading clip and the number of operations it spans.
+//
+// Hard clips are always outermost, so they are skipped when measuring soft clips.
+fn clip_length<'a, I>(ops: I, clip_kind: Kind) -> (usize, usize)
+where
+    I: Iterator<Item = &'a Op>,
+{
+    let mut ops = ops.peekable();
+    let mut len = 0;
+    let mut n = 0;
+
+    if clip_kind == Kind::SoftClip {
+        while ops.next_if(|op| op.kind() == Kind::HardClip).is_some() {
+            n += 1;
+        }
+    }
+
+    while let Some(op) = ops.next_if(|op| op.kind() == clip_kind) {
+        len += op.len();
+        n += 1;
+    }
+
+    (len, n)
+}
+
+// Returns the total lengths of the soft and hard clips at the start and end of the alignment.
+//
+// As with `clip_lengths`, the end is only scanned up to the clips at the start.
+pub(crate) fn clips_length(ops: &[Op]) -> (usize, usize) {
+    fn is_clip(op: &&Op) -> bool {
+        matches!(op.kind(), Kind::SoftClip | Kind::HardClip)
+    }
+
+    let n = ops.iter().take_while(is_clip).count();
+    let (start_ops, rest) = ops.split_at(n);
+
+    let start = start_ops.iter().map(|op| op.len()).sum();
+    let end = rest
+        .iter()
+        .rev()
+        .take_while(is_clip)
+        .map(|op| op.len())
+        .sum();
+
+    (start, end)
 }
 
 impl<'a> IntoIterator for &'a dyn Cigar {
@@ -74,6 +232,15 @@ impl Cigar for Box<dyn Cigar + '_> {
     }
 }
 
+fn checked_add_len(a: usize, b: usize, name: &str) -> io::Result<usize> {
+    a.checked_add(b).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{name} overflows usize"),
+        )
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -117,6 +284,121 @@ mod tests {
 
         assert_eq!(cigar.alignment_span()?, 40);
 
+        let cigar: &dyn Cigar = &T(vec![
+            Op::new(Kind::Match, usize::MAX),
+            Op::new(Kind::Deletion, 1),
+        ]);
+
+        assert!(matches!(
+            cigar.alignment_span(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_soft_clips_and_hard_clips() -> io::Result<()> {
+        let cigar: &dyn Cigar = &T(vec![
+            Op::new(Kind::HardClip, 2),
+            Op::new(Kind::SoftClip, 3),
+            Op::new(Kind::Match, 8),
+            Op::new(Kind::SoftClip, 5),
+        ]);
+
+        assert_eq!(cigar.soft_clips()?, (3, 5));
+        assert_eq!(cigar.hard_clips()?, (2, 0));
+
+        let cigar: &dyn Cigar = &T(vec![Op::new(Kind::Match, 8)]);
+        assert_eq!(cigar.soft_clips()?, (0, 0));
+        assert_eq!(cigar.hard_clips()?, (0, 0));
+
+        let cigar: &dyn Cigar = &T(vec![Op::new(Kind::SoftClip, 10)]);
+        assert_eq!(cigar.soft_clips()?, (10, 0));
+        assert_eq!(cigar.hard_clips()?, (0, 0));
+
+        let cigar: &dyn Cigar = &T(vec![
+            Op::new(Kind::HardClip, 5),
+            Op::new(Kind::SoftClip, 10),
+        ]);
+        assert_eq!(cigar.soft_clips()?, (10, 0));
+        assert_eq!(cigar.hard_clips()?, (5, 0));
+
+        let cigar: &dyn Cigar = &T(vec![
+            Op::new(Kind::SoftClip, 10),
+            Op::new(Kind::HardClip, 5),
+        ]);
+        assert_eq!(cigar.soft_clips()?, (10, 0));
+        assert_eq!(cigar.hard_clips()?, (0, 5));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_position_at_reference_position() -> Result<(), Box<dyn std::error::Error>> {
+        // reference: 10 11 12 13 14 15 -- 16
+        //      read:  S  S  M  M  D  M  I  M
+        let cigar: &dyn Cigar = &T(vec![
+            Op::new(Kind::SoftClip, 2),
+            Op::new(Kind::Match, 2),
+            Op::new(Kind::Deletion, 1),
+            Op::new(Kind::Match, 1),
+            Op::new(Kind::Insertion, 1),
+            Op::new(Kind::Match, 1),
+        ]);
+
+        let alignment_start = Position::try_from(12)?;
+
+        let t = |reference_position: usize| -> Result<Option<usize>, Box<dyn std::error::Error>> {
+            Ok(cigar
+                .read_position_at_reference_position(
+                    alignment_start,
+                    Position::try_from(reference_position)?,
+                )?
+                .map(usize::from))
+        };
+
+        assert_eq!(t(11)?, None);
+        assert_eq!(t(12)?, Some(3));
+        assert_eq!(t(13)?, Some(4));
+        assert_eq!(t(14)?, None);
+        assert_eq!(t(15)?, Some(5));
+        assert_eq!(t(16)?, Some(7));
+        assert_eq!(t(17)?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reference_position_at_read_position() -> Result<(), Box<dyn std::error::Error>> {
+        let cigar: &dyn Cigar = &T(vec![
+            Op::new(Kind::SoftClip, 2),
+            Op::new(Kind::Match, 2),
+            Op::new(Kind::Deletion, 1),
+            Op::new(Kind::Match, 1),
+            Op::new(Kind::Insertion, 1),
+            Op::new(Kind::Match, 1),
+        ]);
+
+        let alignment_start = Position::try_from(12)?;
+
+        let t = |read_position: usize| -> Result<Option<usize>, Box<dyn std::error::Error>> {
+            Ok(cigar
+                .reference_position_at_read_position(
+                    alignment_start,
+                    Position::try_from(read_position)?,
+                )?
+                .map(usize::from))
+        };
+
+        assert_eq!(t(1)?, None);
+        assert_eq!(t(3)?, Some(12));
+        assert_eq!(t(4)?, Some(13));
+        assert_eq!(t(5)?, Some(15));
+        assert_eq!(t(6)?, None);
+        assert_eq!(t(7)?, Some(16));
+        assert_eq!(t(8)?, None);
+
         Ok(())
     }
 
@@ -4,7 +4,9 @@ pub mod field;
 
 use std::io;
 
-use self::field::{Tag, Value};
+use bstr::BStr;
+
+use self::field::{tag::ExpectedType, Tag, Value};
 
 /// Alignment record data.
 pub trait Data {
@@ -16,6 +18,50 @@ pub trait Data {
 
     /// Returns an iterator over fields.
     fn iter(&self) -> Box<dyn Iterator<Item = io::Result<(Tag, Value<'_>)>> + '_>;
+
+    /// Returns the edit distance to the reference (`NM`).
+    ///
+    /// This returns an error if the value is not a non-negative integer.
+    fn nm(&self) -> Option<io::Result<u32>> {
+        let tag = Tag::EDIT_DISTANCE;
+
+        self.get(&tag).map(|result| {
+            result
+                .and_then(|value| get_int(tag, &value))
+                .and_then(|n| u32::try_from(n).map_err(|e| invalid_value(tag, e)))
+        })
+    }
+
+    /// Returns the read group (`RG`).
+    ///
+    /// This returns an error if the value is not a string.
+    fn rg(&self) -> Option<io::Result<&BStr>> {
+        let tag = Tag::READ_GROUP;
+        self.get(&tag)
+            .map(|result| result.and_then(|value| get_string(tag, value)))
+    }
+
+    /// Returns the alignment score (`AS`).
+    ///
+    /// This returns an error if the value is not a 32-bit integer.
+    fn as_(&self) -> Option<io::Result<i32>> {
+        let tag = Tag::ALIGNMENT_SCORE;
+
+        self.get(&tag).map(|result| {
+            result
+                .and_then(|value| get_int(tag, &value))
+                .and_then(|n| i32::try_from(n).map_err(|e| invalid_value(tag, e)))
+        })
+    }
+
+    /// Returns the mismatched positions (`MD`).
+    ///
+    /// This returns an error if the value is not a string.
+    fn md(&self) -> Option<io::Result<&BStr>> {
+        let tag = Tag::MISMATCHED_POSITIONS;
+        self.get(&tag)
+            .map(|result| result.and_then(|value| get_string(tag, value)))
+    }
 }
 
 impl Data for Box<dyn Data + '_> {
@@ -31,3 +77,90 @@ impl Data for Box<dyn Data + '_> {
         (**self).iter()
     }
 }
+
+fn get_int(tag: Tag, value: &Value<'_>) -> io::Result<i64> {
+    value
+        .as_int()
+        .ok_or_else(|| invalid_type(tag, ExpectedType::Integer, value))
+}
+
+fn get_string<'a>(tag: Tag, value: Value<'a>) -> io::Result<&'a BStr> {
+    match value {
+        Value::String(s) => Ok(s),
+        _ => Err(invalid_type(tag, ExpectedType::String, &value)),
+    }
+}
+
+fn invalid_type(tag: Tag, expected: ExpectedType, value: &Value<'_>) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!(
+            "invalid {tag:?} value type: expected {expected:?}, got {:?}",
+            value.ty()
+        ),
+    )
+}
+
+fn invalid_value<E>(tag: Tag, e: E) -> io::Error
+where
+    E: std::fmt::Display,
+{
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("invalid {tag:?} value: {e}"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alignment::record_buf::{data::field::Value as ValueBuf, Data as DataBuf};
+
+    #[test]
+    fn test_standard_tag_accessors() -> io::Result<()> {
+        let data: DataBuf = [
+            (Tag::EDIT_DISTANCE, ValueBuf::UInt8(2)),
+            (Tag::READ_GROUP, ValueBuf::from("rg0")),
+            (Tag::ALIGNMENT_SCORE, ValueBuf::Int16(-8)),
+            (Tag::MISMATCHED_POSITIONS, ValueBuf::from("10A5")),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(data.nm().transpose()?, Some(2));
+        assert_eq!(data.rg().transpose()?, Some(BStr::new("rg0")));
+        assert_eq!(data.as_().transpose()?, Some(-8));
+        assert_eq!(data.md().transpose()?, Some(BStr::new("10A5")));
+
+        let data = DataBuf::default();
+        assert!(data.nm().is_none());
+        assert!(data.rg().is_none());
+        assert!(data.as_().is_none());
+        assert!(data.md().is_none());
+
+        let data: DataBuf = [
+            (Tag::EDIT_DISTANCE, ValueBuf::from("2")),
+            (Tag::READ_GROUP, ValueBuf::Int8(0)),
+            (Tag::ALIGNMENT_SCORE, ValueBuf::UInt32(u32::MAX)),
+            (Tag::MISMATCHED_POSITIONS, ValueBuf::Character(b'A')),
+        ]
+        .into_iter()
+        .collect();
+
+        for result in [
+            data.nm().map(|r| r.map(|_| ())),
+            data.rg().map(|r| r.map(|_| ())),
+            data.as_().map(|r| r.map(|_| ())),
+            data.md().map(|r| r.map(|_| ())),
+        ] {
+            assert!(matches!(result, Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData));
+        }
+
+        let data: DataBuf = [(Tag::EDIT_DISTANCE, ValueBuf::Int8(-1))]
+            .into_iter()
+            .collect();
+        assert!(matches!(data.nm(), Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData));
+
+        Ok(())
+    }
+}
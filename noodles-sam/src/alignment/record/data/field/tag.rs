@@ -1,9 +1,13 @@
 //! Alignment record data field tag.
 
+mod expected_type;
+
 use std::{borrow::Borrow, fmt};
 
 use bstr::ByteSlice;
 
+pub use self::expected_type::ExpectedType;
+
 /// An alignment record data field tag.
 #[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Tag([u8; 2]);
@@ -212,6 +216,24 @@ impl Tag {
     pub const fn new(a: u8, b: u8) -> Self {
         Self([a, b])
     }
+
+    /// Returns the expected value type of a standard tag.
+    ///
+    /// This returns `None` for reserved, deprecated, and non-standard (e.g., `X?`, `Y?`, `Z?`, and
+    /// lowercase) tags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::data::field::{tag::ExpectedType, Tag};
+    ///
+    /// assert_eq!(Tag::EDIT_DISTANCE.expected_type(), Some(ExpectedType::Integer));
+    /// assert_eq!(Tag::READ_GROUP.expected_type(), Some(ExpectedType::String));
+    /// assert!(Tag::new(b'x', b'n').expected_type().is_none());
+    /// ```
+    pub fn expected_type(&self) -> Option<ExpectedType> {
+        expected_type::expected_type(&self.0)
+    }
 }
 
 impl fmt::Debug for Tag {
@@ -260,4 +282,24 @@ mod tests {
     fn test_fmt_debug() {
         assert_eq!(format!("{:?}", Tag::ALIGNMENT_HIT_COUNT), r#"Tag("NH")"#);
     }
+
+    #[test]
+    fn test_expected_type() {
+        assert_eq!(
+            Tag::MIN_MAPPING_QUALITY.expected_type(),
+            Some(ExpectedType::Integer)
+        );
+        assert_eq!(
+            Tag::TRANSCRIPT_STRAND.expected_type(),
+            Some(ExpectedType::Character)
+        );
+        assert_eq!(
+            Tag::MISMATCHED_POSITIONS.expected_type(),
+            Some(ExpectedType::String)
+        );
+        assert_eq!(Tag::CIGAR.expected_type(), Some(ExpectedType::Array));
+
+        assert!(Tag::RESERVED_GC.expected_type().is_none());
+        assert!(Tag::new(b'Z', b'Z').expected_type().is_none());
+    }
 }
@@ -0,0 +1,96 @@
+use crate::alignment::record::data::field::Type;
+
+/// The expected value type of a standard alignment record data field tag.
+///
+/// This is the type given in the SAM optional fields specification (SAMtags). Unlike [`Type`],
+/// integers are not distinguished by width or signedness, and arrays are not distinguished by
+/// subtype.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ExpectedType {
+    /// Character (`A`).
+    Character,
+    /// Integer (`i`).
+    Integer,
+    /// Single-precision floating-point (`f`).
+    Float,
+    /// String (`Z`).
+    String,
+    /// Hex string (`H`).
+    Hex,
+    /// Array (`B`).
+    Array,
+}
+
+impl ExpectedType {
+    /// Returns whether a value type is compatible with the expected type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::data::field::{tag::ExpectedType, Type};
+    ///
+    /// assert!(ExpectedType::Integer.matches(Type::UInt8));
+    /// assert!(ExpectedType::Integer.matches(Type::Int32));
+    /// assert!(!ExpectedType::Integer.matches(Type::String));
+    /// ```
+    pub fn matches(&self, ty: Type) -> bool {
+        matches!(
+            (self, ty),
+            (Self::Character, Type::Character)
+                | (
+                    Self::Integer,
+                    Type::Int8
+                        | Type::UInt8
+                        | Type::Int16
+                        | Type::UInt16
+                        | Type::Int32
+                        | Type::UInt32
+                )
+                | (Self::Float, Type::Float)
+                | (Self::String, Type::String)
+                | (Self::Hex, Type::Hex)
+                | (Self::Array, Type::Array)
+        )
+    }
+}
+
+pub(super) fn expected_type(tag: &[u8; 2]) -> Option<ExpectedType> {
+    match tag {
+        b"TS" => Some(ExpectedType::Character),
+
+        b"AM" | b"AS" | b"CM" | b"CP" | b"FI" | b"H0" | b"H1" | b"H2" | b"HI" | b"IH" | b"MN"
+        | b"MQ" | b"NH" | b"NM" | b"OP" | b"PQ" | b"SM" | b"TC" | b"UQ" => {
+            Some(ExpectedType::Integer)
+        }
+
+        b"BC" | b"BQ" | b"BZ" | b"CB" | b"CC" | b"CO" | b"CQ" | b"CR" | b"CS" | b"CT" | b"CY"
+        | b"E2" | b"FS" | b"LB" | b"MC" | b"MD" | b"MI" | b"MM" | b"OA" | b"OC" | b"OQ" | b"OX"
+        | b"PG" | b"PT" | b"PU" | b"Q2" | b"QT" | b"QX" | b"R2" | b"RG" | b"RX" | b"SA" | b"U2" => {
+            Some(ExpectedType::String)
+        }
+
+        b"CG" | b"FZ" | b"ML" => Some(ExpectedType::Array),
+
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches() {
+        assert!(ExpectedType::Character.matches(Type::Character));
+        assert!(ExpectedType::Integer.matches(Type::Int8));
+        assert!(ExpectedType::Integer.matches(Type::UInt32));
+        assert!(ExpectedType::Float.matches(Type::Float));
+        assert!(ExpectedType::String.matches(Type::String));
+        assert!(ExpectedType::Hex.matches(Type::Hex));
+        assert!(ExpectedType::Array.matches(Type::Array));
+
+        assert!(!ExpectedType::Integer.matches(Type::Float));
+        assert!(!ExpectedType::String.matches(Type::Hex));
+        assert!(!ExpectedType::Character.matches(Type::String));
+    }
+}
@@ -38,6 +38,80 @@ impl<'a> Array<'a> {
             Array::Float(_) => Subtype::Float,
         }
     }
+
+    /// Returns the number of values in the array.
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Int8(values) => values.len(),
+            Self::UInt8(values) => values.len(),
+            Self::Int16(values) => values.len(),
+            Self::UInt16(values) => values.len(),
+            Self::Int32(values) => values.len(),
+            Self::UInt32(values) => values.len(),
+            Self::Float(values) => values.len(),
+        }
+    }
+
+    /// Returns whether the array has any values.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the values if this is an 8-bit integer array (`B:c`).
+    pub fn as_int8(&self) -> Option<&(dyn Values<'a, i8> + 'a)> {
+        match self {
+            Self::Int8(values) => Some(values.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if this is an 8-bit unsigned integer array (`B:C`).
+    pub fn as_uint8(&self) -> Option<&(dyn Values<'a, u8> + 'a)> {
+        match self {
+            Self::UInt8(values) => Some(values.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if this is a 16-bit integer array (`B:s`).
+    pub fn as_int16(&self) -> Option<&(dyn Values<'a, i16> + 'a)> {
+        match self {
+            Self::Int16(values) => Some(values.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if this is a 16-bit unsigned integer array (`B:S`).
+    pub fn as_uint16(&self) -> Option<&(dyn Values<'a, u16> + 'a)> {
+        match self {
+            Self::UInt16(values) => Some(values.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if this is a 32-bit integer array (`B:i`).
+    pub fn as_int32(&self) -> Option<&(dyn Values<'a, i32> + 'a)> {
+        match self {
+            Self::Int32(values) => Some(values.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if this is a 32-bit unsigned integer array (`B:I`).
+    pub fn as_uint32(&self) -> Option<&(dyn Values<'a, u32> + 'a)> {
+        match self {
+            Self::UInt32(values) => Some(values.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if this is a single-precision floating-point array (`B:f`).
+    pub fn as_float(&self) -> Option<&(dyn Values<'a, f32> + 'a)> {
+        match self {
+            Self::Float(values) => Some(values.as_ref()),
+            _ => None,
+        }
+    }
 }
 
 impl<'a> fmt::Debug for Array<'a> {
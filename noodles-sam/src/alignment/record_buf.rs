@@ -10,7 +10,7 @@ mod sequence;
 use std::io;
 
 use bstr::{BStr, BString};
-use noodles_core::Position;
+use noodles_core::{position::Positioned, Position};
 
 pub use self::{
     builder::Builder, cigar::Cigar, data::Data, quality_scores::QualityScores, sequence::Sequence,
@@ -506,6 +506,74 @@ impl RecordBuf {
                 None => Some(start),
             })
     }
+
+    /// Calculates the unclipped start position.
+    ///
+    /// This is the alignment start moved back by the lengths of the soft and hard clips at the
+    /// start of the alignment. It is not clamped to the start of the reference sequence, so it can
+    /// be less than 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     alignment::record::cigar::{op::Kind, Op},
+    /// };
+    ///
+    /// let record = sam::alignment::RecordBuf::builder()
+    ///     .set_alignment_start(Position::try_from(8)?)
+    ///     .set_cigar(
+    ///         [Op::new(Kind::HardClip, 2), Op::new(Kind::SoftClip, 3), Op::new(Kind::Match, 5)]
+    ///             .into_iter()
+    ///             .collect(),
+    ///     )
+    ///     .build();
+    ///
+    /// assert_eq!(record.unclipped_start(), Some(3));
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn unclipped_start(&self) -> Option<isize> {
+        let start = self.alignment_start()?;
+        let (len, _) = super::record::cigar::clips_length(self.cigar().as_ref());
+
+        let start = isize::try_from(usize::from(start)).ok()?;
+        let len = isize::try_from(len).ok()?;
+        start.checked_sub(len)
+    }
+
+    /// Calculates the unclipped end position.
+    ///
+    /// This is the alignment end moved forward by the lengths of the soft and hard clips at the
+    /// end of the alignment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     alignment::record::cigar::{op::Kind, Op},
+    /// };
+    ///
+    /// let record = sam::alignment::RecordBuf::builder()
+    ///     .set_alignment_start(Position::try_from(8)?)
+    ///     .set_cigar(
+    ///         [Op::new(Kind::Match, 5), Op::new(Kind::SoftClip, 3), Op::new(Kind::HardClip, 2)]
+    ///             .into_iter()
+    ///             .collect(),
+    ///     )
+    ///     .build();
+    ///
+    /// assert_eq!(record.unclipped_end(), Position::new(17));
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn unclipped_end(&self) -> Option<Position> {
+        let end = self.alignment_end()?;
+        let (_, len) = super::record::cigar::clips_length(self.cigar().as_ref());
+        Position::new(usize::from(end) + len)
+    }
 }
 
 impl Record for RecordBuf {
@@ -561,6 +629,21 @@ impl Record for RecordBuf {
     }
 }
 
+impl Positioned for RecordBuf {
+    fn reference_sequence(&self) -> Option<noodles_core::position::ReferenceSequence<'_>> {
+        self.reference_sequence_id()
+            .map(noodles_core::position::ReferenceSequence::Id)
+    }
+
+    fn start(&self) -> Option<Position> {
+        self.alignment_start()
+    }
+
+    fn end(&self) -> Option<Position> {
+        self.alignment_end()
+    }
+}
+
 impl Default for RecordBuf {
     fn default() -> Self {
         Self::builder().build()
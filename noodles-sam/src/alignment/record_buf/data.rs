@@ -231,12 +231,138 @@ impl Data {
         }
     }
 
+    /// Inserts a field before the field with the given tag.
+    ///
+    /// If the tag already exists in the map, the existing field is removed and returned, and the
+    /// new field is inserted at the new position. If the `before` tag does not exist in the map,
+    /// the field is appended.
+    ///
+    /// The order of the other fields is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::data::field::Tag,
+    ///     record_buf::{data::field::Value, Data},
+    /// };
+    ///
+    /// let nh = (Tag::ALIGNMENT_HIT_COUNT, Value::from(1));
+    /// let rg = (Tag::READ_GROUP, Value::from("rg0"));
+    /// let mut data: Data = [nh.clone(), rg.clone()].into_iter().collect();
+    ///
+    /// let as_ = (Tag::ALIGNMENT_SCORE, Value::from(98));
+    /// data.insert_before(&Tag::READ_GROUP, as_.0, as_.1.clone());
+    ///
+    /// let expected = [nh, as_, rg].into_iter().collect();
+    /// assert_eq!(data, expected);
+    /// ```
+    pub fn insert_before<K>(&mut self, before: &K, tag: Tag, value: Value) -> Option<(Tag, Value)>
+    where
+        K: indexmap::Equivalent<Tag>,
+    {
+        let old_field = self.shift_remove(&tag);
+        let i = self.get_index_of(before).unwrap_or(self.0.len());
+        self.0.insert(i, (tag, value));
+        old_field
+    }
+
+    /// Renames the tag of a field.
+    ///
+    /// The field keeps its position. This returns `false` and leaves the map unchanged if no field
+    /// has the given tag or if a different field already has the new tag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::data::field::Tag,
+    ///     record_buf::{data::field::Value, Data},
+    /// };
+    ///
+    /// let xa = Tag::new(b'X', b'A');
+    /// let nh = (Tag::ALIGNMENT_HIT_COUNT, Value::from(1));
+    /// let mut data: Data = [(xa, Value::from("rg0")), nh.clone()].into_iter().collect();
+    ///
+    /// assert!(data.rename_tag(&xa, Tag::READ_GROUP));
+    /// assert!(!data.rename_tag(&xa, Tag::READ_GROUP));
+    ///
+    /// let expected = [(Tag::READ_GROUP, Value::from("rg0")), nh].into_iter().collect();
+    /// assert_eq!(data, expected);
+    /// ```
+    pub fn rename_tag<K>(&mut self, tag: &K, new_tag: Tag) -> bool
+    where
+        K: indexmap::Equivalent<Tag>,
+    {
+        let Some(i) = self.get_index_of(tag) else {
+            return false;
+        };
+
+        if self.get_index_of(&new_tag).is_some_and(|j| j != i) {
+            return false;
+        }
+
+        self.0[i].0 = new_tag;
+
+        true
+    }
+
+    /// Returns an iterator over all fields with mutable values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::data::field::Tag,
+    ///     record_buf::{data::field::Value, Data},
+    /// };
+    ///
+    /// let mut data: Data = [(Tag::ALIGNMENT_HIT_COUNT, Value::from(1))].into_iter().collect();
+    ///
+    /// for (_, value) in data.iter_mut() {
+    ///     *value = Value::from(2);
+    /// }
+    ///
+    /// assert_eq!(data.get(&Tag::ALIGNMENT_HIT_COUNT), Some(&Value::from(2)));
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Tag, &mut Value)> {
+        self.0.iter_mut().map(|(tag, value)| (*tag, value))
+    }
+
+    /// Retains only the fields specified by the predicate.
+    ///
+    /// The order of the retained fields is preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::data::field::Tag,
+    ///     record_buf::{data::field::Value, Data},
+    /// };
+    ///
+    /// let nh = (Tag::ALIGNMENT_HIT_COUNT, Value::from(1));
+    /// let rg = (Tag::READ_GROUP, Value::from("rg0"));
+    /// let mut data: Data = [nh.clone(), rg].into_iter().collect();
+    ///
+    /// data.retain(|tag, _| tag != Tag::READ_GROUP);
+    ///
+    /// let expected = [nh].into_iter().collect();
+    /// assert_eq!(data, expected);
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Tag, &mut Value) -> bool,
+    {
+        self.0.retain_mut(|(tag, value)| f(*tag, value));
+    }
+
     /// Removes the field with the given tag.
     ///
     /// The field is returned if it exists.
     ///
-    /// This works like [`Vec::swap_remove`]; it does not preserve the order but has a constant
-    /// time complexity.
+    /// This works like [`Vec::remove`]; it preserves the order of the other fields but has a
+    /// linear time complexity. See [`Self::swap_remove`] for a constant time removal.
     ///
     /// # Examples
     ///
@@ -254,22 +380,54 @@ impl Data {
     /// assert_eq!(data.remove(&Tag::ALIGNMENT_HIT_COUNT), Some(nh));
     /// assert!(data.remove(&Tag::COMMENT).is_none());
     ///
-    /// let expected = [md, rg].into_iter().collect();
+    /// let expected = [rg, md].into_iter().collect();
     /// assert_eq!(data, expected);
     /// ```
     pub fn remove<K>(&mut self, tag: &K) -> Option<(Tag, Value)>
     where
         K: indexmap::Equivalent<Tag>,
     {
-        self.swap_remove(tag)
+        self.shift_remove(tag)
     }
 
-    fn swap_remove<K>(&mut self, tag: &K) -> Option<(Tag, Value)>
+    /// Removes the field with the given tag by swapping it with the last field.
+    ///
+    /// The field is returned if it exists.
+    ///
+    /// This works like [`Vec::swap_remove`]; it does not preserve the order but has a constant
+    /// time complexity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::data::field::Tag,
+    ///     record_buf::{data::field::Value, Data},
+    /// };
+    ///
+    /// let nh = (Tag::ALIGNMENT_HIT_COUNT, Value::from(1));
+    /// let rg = (Tag::READ_GROUP, Value::from("rg0"));
+    /// let md = (Tag::ALIGNMENT_SCORE, Value::from(98));
+    /// let mut data: Data = [nh.clone(), rg.clone(), md.clone()].into_iter().collect();
+    ///
+    /// assert_eq!(data.swap_remove(&Tag::ALIGNMENT_HIT_COUNT), Some(nh));
+    ///
+    /// let expected = [md, rg].into_iter().collect();
+    /// assert_eq!(data, expected);
+    /// ```
+    pub fn swap_remove<K>(&mut self, tag: &K) -> Option<(Tag, Value)>
     where
         K: indexmap::Equivalent<Tag>,
     {
         self.get_index_of(tag).map(|i| self.0.swap_remove(i))
     }
+
+    fn shift_remove<K>(&mut self, tag: &K) -> Option<(Tag, Value)>
+    where
+        K: indexmap::Equivalent<Tag>,
+    {
+        self.get_index_of(tag).map(|i| self.0.remove(i))
+    }
 }
 
 impl crate::alignment::record::Data for &Data {
@@ -354,4 +512,79 @@ mod tests {
 
         assert!(data.is_empty());
     }
+
+    #[test]
+    fn test_insert_before() {
+        let zz = Tag::new(b'z', b'z');
+
+        let mut data: Data = [
+            (Tag::ALIGNMENT_HIT_COUNT, Value::from(2)),
+            (Tag::EDIT_DISTANCE, Value::from(1)),
+            (zz, Value::from(0)),
+        ]
+        .into_iter()
+        .collect();
+
+        // Moves an existing field.
+        assert_eq!(
+            data.insert_before(&Tag::ALIGNMENT_HIT_COUNT, zz, Value::from(3)),
+            Some((zz, Value::from(0)))
+        );
+
+        // Appends when the `before` tag is missing.
+        assert!(data
+            .insert_before(&Tag::COMMENT, Tag::READ_GROUP, Value::from("rg0"))
+            .is_none());
+
+        let keys: Vec<_> = data.keys().collect();
+        assert_eq!(
+            keys,
+            [
+                zz,
+                Tag::ALIGNMENT_HIT_COUNT,
+                Tag::EDIT_DISTANCE,
+                Tag::READ_GROUP
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rename_tag() {
+        let mut data: Data = [
+            (Tag::ALIGNMENT_HIT_COUNT, Value::from(2)),
+            (Tag::EDIT_DISTANCE, Value::from(1)),
+        ]
+        .into_iter()
+        .collect();
+
+        assert!(data.rename_tag(&Tag::ALIGNMENT_HIT_COUNT, Tag::ALIGNMENT_HIT_COUNT));
+        assert!(!data.rename_tag(&Tag::ALIGNMENT_HIT_COUNT, Tag::EDIT_DISTANCE));
+        assert!(!data.rename_tag(&Tag::COMMENT, Tag::READ_GROUP));
+
+        let zz = Tag::new(b'z', b'z');
+        assert!(data.rename_tag(&Tag::ALIGNMENT_HIT_COUNT, zz));
+
+        let keys: Vec<_> = data.keys().collect();
+        assert_eq!(keys, [zz, Tag::EDIT_DISTANCE]);
+        assert!(data.get(&Tag::ALIGNMENT_HIT_COUNT).is_none());
+        assert_eq!(data.get(&zz), Some(&Value::from(2)));
+    }
+
+    #[test]
+    fn test_remove_preserves_order() {
+        let zz = Tag::new(b'z', b'z');
+
+        let mut data: Data = [
+            (Tag::ALIGNMENT_HIT_COUNT, Value::from(2)),
+            (Tag::EDIT_DISTANCE, Value::from(1)),
+            (zz, Value::from(0)),
+        ]
+        .into_iter()
+        .collect();
+
+        data.remove(&Tag::ALIGNMENT_HIT_COUNT);
+
+        let keys: Vec<_> = data.keys().collect();
+        assert_eq!(keys, [Tag::EDIT_DISTANCE, zz]);
+    }
 }
@@ -103,6 +103,49 @@ impl Value {
                 | Self::UInt32(_)
         )
     }
+
+    /// Returns the value as an array.
+    ///
+    /// Use the typed accessors of [`Array`] to get the values with their original subtype.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::Value;
+    ///
+    /// let value = Value::from(vec![0u16, 1]);
+    /// assert_eq!(value.as_array().and_then(|array| array.as_uint16()), Some(&[0, 1][..]));
+    ///
+    /// assert!(Value::from(0).as_array().is_none());
+    /// ```
+    pub fn as_array(&self) -> Option<&Array> {
+        match self {
+            Self::Array(array) => Some(array),
+            _ => None,
+        }
+    }
+
+    /// Returns the value as a mutable array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::{value::Array, Value};
+    ///
+    /// let mut value = Value::from(vec![0u16, 1]);
+    ///
+    /// if let Some(Array::UInt16(values)) = value.as_array_mut() {
+    ///     values.push(2);
+    /// }
+    ///
+    /// assert_eq!(value, Value::from(vec![0u16, 1, 2]));
+    /// ```
+    pub fn as_array_mut(&mut self) -> Option<&mut Array> {
+        match self {
+            Self::Array(array) => Some(array),
+            _ => None,
+        }
+    }
 }
 
 impl From<i8> for Value {
@@ -46,6 +46,199 @@ impl Array {
             Self::Float(_) => Subtype::Float,
         }
     }
+
+    /// Returns the number of values in the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::value::Array;
+    /// assert_eq!(Array::UInt8(vec![0, 1]).len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Int8(values) => values.len(),
+            Self::UInt8(values) => values.len(),
+            Self::Int16(values) => values.len(),
+            Self::UInt16(values) => values.len(),
+            Self::Int32(values) => values.len(),
+            Self::UInt32(values) => values.len(),
+            Self::Float(values) => values.len(),
+        }
+    }
+
+    /// Returns whether the array has any values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::value::Array;
+    /// assert!(Array::UInt8(Vec::new()).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the values if this is an 8-bit integer array (`B:c`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::value::Array;
+    /// let array = Array::from(vec![0i8]);
+    /// assert_eq!(array.as_int8(), Some(&[0i8][..]));
+    /// assert!(array.as_uint8().is_none());
+    /// ```
+    pub fn as_int8(&self) -> Option<&[i8]> {
+        match self {
+            Self::Int8(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if this is an 8-bit unsigned integer array (`B:C`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::value::Array;
+    /// let array = Array::from(vec![0u8]);
+    /// assert_eq!(array.as_uint8(), Some(&[0u8][..]));
+    /// assert!(array.as_int8().is_none());
+    /// ```
+    pub fn as_uint8(&self) -> Option<&[u8]> {
+        match self {
+            Self::UInt8(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if this is a 16-bit integer array (`B:s`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::value::Array;
+    /// let array = Array::from(vec![0i16]);
+    /// assert_eq!(array.as_int16(), Some(&[0i16][..]));
+    /// assert!(array.as_uint8().is_none());
+    /// ```
+    pub fn as_int16(&self) -> Option<&[i16]> {
+        match self {
+            Self::Int16(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if this is a 16-bit unsigned integer array (`B:S`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::value::Array;
+    /// let array = Array::from(vec![0u16]);
+    /// assert_eq!(array.as_uint16(), Some(&[0u16][..]));
+    /// assert!(array.as_uint8().is_none());
+    /// ```
+    pub fn as_uint16(&self) -> Option<&[u16]> {
+        match self {
+            Self::UInt16(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if this is a 32-bit integer array (`B:i`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::value::Array;
+    /// let array = Array::from(vec![0i32]);
+    /// assert_eq!(array.as_int32(), Some(&[0i32][..]));
+    /// assert!(array.as_uint8().is_none());
+    /// ```
+    pub fn as_int32(&self) -> Option<&[i32]> {
+        match self {
+            Self::Int32(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if this is a 32-bit unsigned integer array (`B:I`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::value::Array;
+    /// let array = Array::from(vec![0u32]);
+    /// assert_eq!(array.as_uint32(), Some(&[0u32][..]));
+    /// assert!(array.as_uint8().is_none());
+    /// ```
+    pub fn as_uint32(&self) -> Option<&[u32]> {
+        match self {
+            Self::UInt32(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if this is a single-precision floating-point array (`B:f`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::value::Array;
+    /// let array = Array::from(vec![0f32]);
+    /// assert_eq!(array.as_float(), Some(&[0f32][..]));
+    /// assert!(array.as_uint8().is_none());
+    /// ```
+    pub fn as_float(&self) -> Option<&[f32]> {
+        match self {
+            Self::Float(values) => Some(values),
+            _ => None,
+        }
+    }
+}
+
+impl From<Vec<i8>> for Array {
+    fn from(values: Vec<i8>) -> Self {
+        Self::Int8(values)
+    }
+}
+
+impl From<Vec<u8>> for Array {
+    fn from(values: Vec<u8>) -> Self {
+        Self::UInt8(values)
+    }
+}
+
+impl From<Vec<i16>> for Array {
+    fn from(values: Vec<i16>) -> Self {
+        Self::Int16(values)
+    }
+}
+
+impl From<Vec<u16>> for Array {
+    fn from(values: Vec<u16>) -> Self {
+        Self::UInt16(values)
+    }
+}
+
+impl From<Vec<i32>> for Array {
+    fn from(values: Vec<i32>) -> Self {
+        Self::Int32(values)
+    }
+}
+
+impl From<Vec<u32>> for Array {
+    fn from(values: Vec<u32>) -> Self {
+        Self::UInt32(values)
+    }
+}
+
+impl From<Vec<f32>> for Array {
+    fn from(values: Vec<f32>) -> Self {
+        Self::Float(values)
+    }
 }
 
 impl<'a> From<&'a Array> for crate::alignment::record::data::field::value::Array<'a> {
@@ -97,4 +290,22 @@ mod tests {
         assert_eq!(Array::UInt32(Vec::new()).subtype(), Subtype::UInt32);
         assert_eq!(Array::Float(Vec::new()).subtype(), Subtype::Float);
     }
+
+    #[test]
+    fn test_as_values() {
+        let array = Array::from(vec![-1i8, 0, 1]);
+        assert_eq!(array.subtype(), Subtype::Int8);
+        assert_eq!(array.as_int8(), Some(&[-1, 0, 1][..]));
+        assert!(array.as_int16().is_none());
+        assert!(array.as_int32().is_none());
+
+        let array = Array::from(vec![0u32, 1]);
+        assert_eq!(array.subtype(), Subtype::UInt32);
+        assert_eq!(array.as_uint32(), Some(&[0, 1][..]));
+        assert!(array.as_uint8().is_none());
+
+        let array = Array::from(vec![0.5f32]);
+        assert_eq!(array.as_float(), Some(&[0.5][..]));
+        assert!(array.as_int32().is_none());
+    }
 }
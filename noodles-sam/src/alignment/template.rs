@@ -0,0 +1,399 @@
+//! Alignment template.
+
+use std::{error, fmt};
+
+use bstr::{BStr, BString};
+
+use super::RecordBuf;
+
+/// An alignment template.
+///
+/// A template is the set of all alignment records of one query (read) name: the primary
+/// record(s) of each segment, and any supplementary and secondary records. Records can be
+/// converted to a template ([`Template::try_from`]), which checks its invariants, and back to a
+/// list of records ([`Template::into_records`]).
+///
+/// The primary records are either a single unsegmented record or up to two segmented records
+/// (first and last segment).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Template {
+    name: Option<BString>,
+    primary_records: Vec<RecordBuf>,
+    supplementary_records: Vec<RecordBuf>,
+    secondary_records: Vec<RecordBuf>,
+}
+
+impl Template {
+    /// Returns the template name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{RecordBuf, Template};
+    ///
+    /// let record = RecordBuf::builder().set_name("r0").build();
+    /// let template = Template::try_from(vec![record])?;
+    ///
+    /// assert_eq!(template.name(), Some(b"r0".as_ref().into()));
+    /// # Ok::<_, noodles_sam::alignment::template::TryFromRecordsError>(())
+    /// ```
+    pub fn name(&self) -> Option<&BStr> {
+        self.name.as_ref().map(|name| name.as_ref())
+    }
+
+    /// Returns whether the template has segmented (paired) primary records.
+    pub fn is_segmented(&self) -> bool {
+        self.primary_records
+            .iter()
+            .any(|record| record.flags().is_segmented())
+    }
+
+    /// Returns the primary records.
+    ///
+    /// If the template is segmented, the first segment, if present, is ordered before the last
+    /// segment.
+    pub fn primary_records(&self) -> &[RecordBuf] {
+        &self.primary_records
+    }
+
+    /// Returns a mutable reference to the primary records.
+    pub fn primary_records_mut(&mut self) -> &mut [RecordBuf] {
+        &mut self.primary_records
+    }
+
+    /// Returns the primary records of the first and last segments, if both are present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{record::Flags, RecordBuf, Template};
+    ///
+    /// let records = vec![
+    ///     RecordBuf::builder()
+    ///         .set_name("r0")
+    ///         .set_flags(Flags::SEGMENTED | Flags::LAST_SEGMENT)
+    ///         .build(),
+    ///     RecordBuf::builder()
+    ///         .set_name("r0")
+    ///         .set_flags(Flags::SEGMENTED | Flags::FIRST_SEGMENT)
+    ///         .build(),
+    /// ];
+    ///
+    /// let template = Template::try_from(records)?;
+    /// let (read1, read2) = template.mates().unwrap();
+    ///
+    /// assert!(read1.flags().is_first_segment());
+    /// assert!(read2.flags().is_last_segment());
+    /// # Ok::<_, noodles_sam::alignment::template::TryFromRecordsError>(())
+    /// ```
+    pub fn mates(&self) -> Option<(&RecordBuf, &RecordBuf)> {
+        match &self.primary_records[..] {
+            [read1, read2] => Some((read1, read2)),
+            _ => None,
+        }
+    }
+
+    /// Returns mutable references to the primary records of the first and last segments, if both
+    /// are present.
+    pub fn mates_mut(&mut self) -> Option<(&mut RecordBuf, &mut RecordBuf)> {
+        match &mut self.primary_records[..] {
+            [read1, read2] => Some((read1, read2)),
+            _ => None,
+        }
+    }
+
+    /// Returns the supplementary records.
+    pub fn supplementary_records(&self) -> &[RecordBuf] {
+        &self.supplementary_records
+    }
+
+    /// Returns a mutable reference to the supplementary records.
+    pub fn supplementary_records_mut(&mut self) -> &mut [RecordBuf] {
+        &mut self.supplementary_records
+    }
+
+    /// Returns the secondary records.
+    pub fn secondary_records(&self) -> &[RecordBuf] {
+        &self.secondary_records
+    }
+
+    /// Returns a mutable reference to the secondary records.
+    pub fn secondary_records_mut(&mut self) -> &mut [RecordBuf] {
+        &mut self.secondary_records
+    }
+
+    /// Converts the template into a list of records.
+    ///
+    /// The records are ordered by primary, supplementary, and then secondary records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{record::Flags, RecordBuf, Template};
+    ///
+    /// let records = vec![
+    ///     RecordBuf::builder()
+    ///         .set_name("r0")
+    ///         .set_flags(Flags::SECONDARY)
+    ///         .build(),
+    ///     RecordBuf::builder().set_name("r0").build(),
+    /// ];
+    ///
+    /// let template = Template::try_from(records)?;
+    /// let records = template.into_records();
+    ///
+    /// assert_eq!(records.len(), 2);
+    /// assert!(!records[0].flags().is_secondary());
+    /// # Ok::<_, noodles_sam::alignment::template::TryFromRecordsError>(())
+    /// ```
+    pub fn into_records(self) -> Vec<RecordBuf> {
+        let mut records = self.primary_records;
+        records.extend(self.supplementary_records);
+        records.extend(self.secondary_records);
+        records
+    }
+}
+
+/// An error returned when records fail to convert to a template.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TryFromRecordsError {
+    /// The list of records is empty.
+    Empty,
+    /// The record names do not match.
+    NameMismatch {
+        /// The name of the first record.
+        expected: Option<BString>,
+        /// The mismatched name.
+        actual: Option<BString>,
+    },
+    /// The template has no primary record.
+    MissingPrimaryRecord,
+    /// The template has more than one primary record for a segment.
+    DuplicatePrimaryRecord,
+    /// The template has both segmented and unsegmented primary records.
+    MixedSegmentation,
+}
+
+impl error::Error for TryFromRecordsError {}
+
+impl fmt::Display for TryFromRecordsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty input"),
+            Self::NameMismatch { expected, actual } => {
+                write!(f, "name mismatch: expected {expected:?}, got {actual:?}")
+            }
+            Self::MissingPrimaryRecord => write!(f, "missing primary record"),
+            Self::DuplicatePrimaryRecord => write!(f, "duplicate primary record"),
+            Self::MixedSegmentation => {
+                write!(f, "mixed segmented and unsegmented primary records")
+            }
+        }
+    }
+}
+
+impl TryFrom<Vec<RecordBuf>> for Template {
+    type Error = TryFromRecordsError;
+
+    fn try_from(records: Vec<RecordBuf>) -> Result<Self, Self::Error> {
+        let name = records
+            .first()
+            .map(|record| record.name().map(BString::from))
+            .ok_or(TryFromRecordsError::Empty)?;
+
+        let mut primary_records = Vec::new();
+        let mut supplementary_records = Vec::new();
+        let mut secondary_records = Vec::new();
+
+        for record in records {
+            if record.name() != name.as_ref().map(|name| name.as_ref()) {
+                return Err(TryFromRecordsError::NameMismatch {
+                    expected: name,
+                    actual: record.name().map(BString::from),
+                });
+            }
+
+            let flags = record.flags();
+
+            if flags.is_secondary() {
+                secondary_records.push(record);
+            } else if flags.is_supplementary() {
+                supplementary_records.push(record);
+            } else {
+                primary_records.push(record);
+            }
+        }
+
+        primary_records.sort_by_key(segment_rank);
+        validate_primary_records(&primary_records)?;
+
+        Ok(Self {
+            name,
+            primary_records,
+            supplementary_records,
+            secondary_records,
+        })
+    }
+}
+
+impl From<Template> for Vec<RecordBuf> {
+    fn from(template: Template) -> Self {
+        template.into_records()
+    }
+}
+
+// Unsegmented records and first segments sort before last segments.
+fn segment_rank(record: &RecordBuf) -> u8 {
+    if record.flags().is_last_segment() {
+        1
+    } else {
+        0
+    }
+}
+
+fn validate_primary_records(records: &[RecordBuf]) -> Result<(), TryFromRecordsError> {
+    match records {
+        [] => Err(TryFromRecordsError::MissingPrimaryRecord),
+        [_] => Ok(()),
+        [a, b] => {
+            let (a, b) = (a.flags(), b.flags());
+
+            if !a.is_segmented() || !b.is_segmented() {
+                if a.is_segmented() || b.is_segmented() {
+                    Err(TryFromRecordsError::MixedSegmentation)
+                } else {
+                    Err(TryFromRecordsError::DuplicatePrimaryRecord)
+                }
+            } else if a.is_last_segment() == b.is_last_segment() {
+                Err(TryFromRecordsError::DuplicatePrimaryRecord)
+            } else {
+                Ok(())
+            }
+        }
+        _ => Err(TryFromRecordsError::DuplicatePrimaryRecord),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alignment::record::Flags;
+
+    #[test]
+    fn test_try_from_vec_record_buf_for_template() {
+        const READ_1: Flags = Flags::SEGMENTED.union(Flags::FIRST_SEGMENT);
+        const READ_2: Flags = Flags::SEGMENTED.union(Flags::LAST_SEGMENT);
+
+        let records = vec![
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_flags(READ_2)
+                .build(),
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_flags(READ_1.union(Flags::SECONDARY))
+                .build(),
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_flags(READ_1.union(Flags::SUPPLEMENTARY))
+                .build(),
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_flags(READ_1)
+                .build(),
+        ];
+
+        let template = Template::try_from(records).unwrap();
+        assert!(template.is_segmented());
+        assert_eq!(template.primary_records().len(), 2);
+        assert!(template.primary_records()[0].flags().is_first_segment());
+        assert_eq!(template.supplementary_records().len(), 1);
+        assert_eq!(template.secondary_records().len(), 1);
+
+        let records = template.into_records();
+        assert_eq!(
+            records
+                .iter()
+                .map(|record| record.flags())
+                .collect::<Vec<_>>(),
+            [
+                READ_1,
+                READ_2,
+                READ_1.union(Flags::SUPPLEMENTARY),
+                READ_1.union(Flags::SECONDARY)
+            ]
+        );
+
+        assert_eq!(
+            Template::try_from(Vec::new()),
+            Err(TryFromRecordsError::Empty)
+        );
+
+        assert_eq!(
+            Template::try_from(vec![
+                RecordBuf::builder()
+                    .set_name("r0")
+                    .set_flags(READ_1)
+                    .build(),
+                RecordBuf::builder()
+                    .set_name("r1")
+                    .set_flags(READ_2)
+                    .build()
+            ]),
+            Err(TryFromRecordsError::NameMismatch {
+                expected: Some(BString::from("r0")),
+                actual: Some(BString::from("r1")),
+            })
+        );
+
+        assert_eq!(
+            Template::try_from(vec![RecordBuf::builder()
+                .set_name("r0")
+                .set_flags(Flags::SECONDARY)
+                .build()]),
+            Err(TryFromRecordsError::MissingPrimaryRecord)
+        );
+
+        assert_eq!(
+            Template::try_from(vec![
+                RecordBuf::builder()
+                    .set_name("r0")
+                    .set_flags(READ_1)
+                    .build(),
+                RecordBuf::builder()
+                    .set_name("r0")
+                    .set_flags(READ_1)
+                    .build()
+            ]),
+            Err(TryFromRecordsError::DuplicatePrimaryRecord)
+        );
+
+        assert_eq!(
+            Template::try_from(vec![
+                RecordBuf::builder()
+                    .set_name("r0")
+                    .set_flags(Flags::empty())
+                    .build(),
+                RecordBuf::builder()
+                    .set_name("r0")
+                    .set_flags(Flags::empty())
+                    .build()
+            ]),
+            Err(TryFromRecordsError::DuplicatePrimaryRecord)
+        );
+
+        assert_eq!(
+            Template::try_from(vec![
+                RecordBuf::builder()
+                    .set_name("r0")
+                    .set_flags(READ_1)
+                    .build(),
+                RecordBuf::builder()
+                    .set_name("r0")
+                    .set_flags(Flags::empty())
+                    .build()
+            ]),
+            Err(TryFromRecordsError::MixedSegmentation)
+        );
+    }
+}
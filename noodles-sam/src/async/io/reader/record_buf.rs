@@ -12,14 +12,14 @@ pub(super) async fn read_record_buf<R>(
 where
     R: AsyncBufRead + Unpin,
 {
-    use crate::io::reader::record_buf::parse_record_buf;
+    use crate::io::reader::{record_buf::parse_record_buf, ValidationLevel};
 
     buf.clear();
 
     match read_line(reader, buf).await? {
         0 => Ok(0),
         n => {
-            parse_record_buf(buf, header, record)
+            parse_record_buf(buf, header, record, ValidationLevel::default())
                 .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
             Ok(n)
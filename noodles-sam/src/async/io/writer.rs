@@ -141,11 +141,10 @@ where
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn write_alignment_record(
-        &mut self,
-        header: &Header,
-        record: &dyn crate::alignment::Record,
-    ) -> io::Result<()> {
+    pub async fn write_alignment_record<R>(&mut self, header: &Header, record: &R) -> io::Result<()>
+    where
+        R: crate::alignment::Record + ?Sized,
+    {
         use crate::io::writer::write_record;
 
         let mut buf = Vec::new();
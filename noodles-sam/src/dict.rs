@@ -0,0 +1,192 @@
+//! Sequence dictionary (`.dict`).
+//!
+//! A sequence dictionary, as written by Picard `CreateSequenceDictionary`, is a SAM header that
+//! only contains a header (`@HD`) record and reference sequence (`@SQ`) records.
+//!
+//! # Examples
+//!
+//! ## Reading a sequence dictionary
+//!
+//! ```no_run
+//! use noodles_sam as sam;
+//! let header = sam::dict::read("reference.dict")?;
+//! let reference_sequences = header.reference_sequences();
+//! # Ok::<_, std::io::Error>(())
+//! ```
+
+mod checksum;
+mod validate;
+
+pub use self::{
+    checksum::{calculate_md5_checksum, read_reference_sequences, set_md5_checksums},
+    validate::{validate_against, validate_checksums, ValidationError},
+};
+
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::Path,
+};
+
+use crate::{
+    header::record::value::{map, Map},
+    io::{Reader, Writer},
+    Header,
+};
+
+/// Reads a sequence dictionary from a file.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_sam as sam;
+/// let header = sam::dict::read("reference.dict")?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn read<P>(src: P) -> io::Result<Header>
+where
+    P: AsRef<Path>,
+{
+    File::open(src).map(BufReader::new).and_then(read_dict)
+}
+
+/// Reads a sequence dictionary from a reader.
+///
+/// Any records other than the header and reference sequence records are discarded.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam as sam;
+///
+/// let data = b"@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:8\n";
+/// let header = sam::dict::read_dict(&data[..])?;
+///
+/// assert_eq!(header.reference_sequences().len(), 1);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn read_dict<R>(reader: R) -> io::Result<Header>
+where
+    R: BufRead,
+{
+    let header = Reader::new(reader).read_header()?;
+    Ok(build_dict(&header))
+}
+
+/// Writes the sequence dictionary of a SAM header to a file.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_sam as sam;
+/// let header = sam::Header::default();
+/// sam::dict::write("reference.dict", &header)?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn write<P>(dst: P, header: &Header) -> io::Result<()>
+where
+    P: AsRef<Path>,
+{
+    let mut writer = File::create(dst).map(BufWriter::new)?;
+    write_dict(&mut writer, header)?;
+    writer.flush()
+}
+
+/// Writes the sequence dictionary of a SAM header to a writer.
+///
+/// Only the header record and reference sequence records are written. If the header does not
+/// have a header record, a default one is written.
+///
+/// # Examples
+///
+/// ```
+/// # use std::num::NonZeroUsize;
+/// use noodles_sam::{
+///     self as sam,
+///     header::record::value::{map::ReferenceSequence, Map},
+/// };
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence(
+///         "sq0",
+///         Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+///     )
+///     .add_comment("noodles-sam")
+///     .build();
+///
+/// let mut buf = Vec::new();
+/// sam::dict::write_dict(&mut buf, &header)?;
+///
+/// assert_eq!(buf, b"@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:8\n");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn write_dict<W>(writer: W, header: &Header) -> io::Result<()>
+where
+    W: Write,
+{
+    Writer::new(writer).write_header(&build_dict(header))
+}
+
+fn build_dict(header: &Header) -> Header {
+    Header::builder()
+        .set_header(
+            header
+                .header()
+                .cloned()
+                .unwrap_or_else(Map::<map::Header>::default),
+        )
+        .set_reference_sequences(header.reference_sequences().clone())
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use super::*;
+    use crate::header::record::value::map::ReferenceSequence;
+
+    #[test]
+    fn test_read_dict() -> Result<(), Box<dyn std::error::Error>> {
+        let data = b"\
+@HD\tVN:1.6\tSO:unsorted
+@SQ\tSN:sq0\tLN:8\tM5:d7eba311421bbc9d3ada44709dd61534\tUR:file:/tmp/reference.fa
+@SQ\tSN:sq1\tLN:13
+@CO\tnoodles-sam
+";
+
+        let header = read_dict(&data[..])?;
+
+        assert!(header.header().is_some());
+        assert_eq!(
+            header.reference_sequences().keys().collect::<Vec<_>>(),
+            ["sq0", "sq1"]
+        );
+        assert_eq!(
+            header.reference_sequences()[&b"sq1"[..]].length(),
+            NonZeroUsize::try_from(13)?
+        );
+        assert!(header.comments().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_dict() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .set_header(Map::<map::Header>::default())
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .add_read_group("rg0", Map::default())
+            .build();
+
+        let mut buf = Vec::new();
+        write_dict(&mut buf, &header)?;
+
+        assert_eq!(buf, b"@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:8\n");
+
+        Ok(())
+    }
+}
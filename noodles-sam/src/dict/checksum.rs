@@ -0,0 +1,312 @@
+use std::{
+    io::{self, BufRead},
+    num::NonZeroUsize,
+};
+
+use bstr::{BString, ByteSlice};
+use md5::{Digest, Md5};
+use noodles_fasta::{self as fasta, record::Definition};
+
+use super::ValidationError;
+use crate::header::{
+    record::value::{
+        map::{
+            reference_sequence::{tag, Md5Checksum},
+            ReferenceSequence,
+        },
+        Map,
+    },
+    ReferenceSequences,
+};
+
+/// Calculates the MD5 checksum of a reference sequence.
+///
+/// This normalizes the sequence the same way as `samtools dict`, i.e., characters outside of the
+/// inclusive range 33 (`!`) to 126 (`~`) are stripped out, and lowercase characters are
+/// converted to uppercase (_Sequence Alignment/Map Format Specification_ (2021-06-03) § 1.3.2
+/// "Reference MD5 calculation").
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+/// use noodles_sam as sam;
+///
+/// let record = fasta::Record::new(
+///     Definition::new("sq0", None),
+///     Sequence::from(b"ACgt".to_vec()),
+/// );
+///
+/// let md5_checksum = sam::dict::calculate_md5_checksum(record.sequence().as_ref());
+/// assert_eq!(md5_checksum.to_string(), "f1f8f4bf413b16ad135722aa4591043e");
+/// ```
+pub fn calculate_md5_checksum(sequence: &[u8]) -> Md5Checksum {
+    let mut hasher = Md5::new();
+    update(&mut hasher, sequence);
+    <[u8; 16]>::from(hasher.finalize()).into()
+}
+
+/// Builds reference sequences from a FASTA reader.
+///
+/// This reads each record, streaming its sequence, and creates a reference sequence with the
+/// record name, sequence length (`LN`), and MD5 checksum (`M5`), like `samtools dict`.
+///
+/// # Errors
+///
+/// This returns an error if a record fails to read, has an empty sequence, or has a duplicate
+/// name.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fasta as fasta;
+/// use noodles_sam::{self as sam, header::record::value::map::reference_sequence::tag};
+///
+/// let data = b">sq0\nACGT\n>sq1\nNNNN\nNNNN\n";
+/// let mut reader = fasta::io::Reader::new(&data[..]);
+///
+/// let reference_sequences = sam::dict::read_reference_sequences(&mut reader)?;
+///
+/// assert_eq!(reference_sequences.len(), 2);
+///
+/// let sq0 = &reference_sequences[&b"sq0"[..]];
+/// assert_eq!(usize::from(sq0.length()), 4);
+/// assert_eq!(
+///     sq0.other_fields().get(&tag::MD5_CHECKSUM),
+///     Some(&"f1f8f4bf413b16ad135722aa4591043e".into()),
+/// );
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn read_reference_sequences<R>(
+    reader: &mut fasta::io::Reader<R>,
+) -> io::Result<ReferenceSequences>
+where
+    R: BufRead,
+{
+    let mut reference_sequences = ReferenceSequences::new();
+    let mut buf = String::new();
+
+    loop {
+        buf.clear();
+
+        if reader.read_definition(&mut buf)? == 0 {
+            break;
+        }
+
+        let definition: Definition = buf
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let (length, md5_checksum) = read_sequence_length_and_md5_checksum(reader)?;
+
+        let length = NonZeroUsize::new(length).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("empty reference sequence: {}", definition.name().as_bstr()),
+            )
+        })?;
+
+        let mut reference_sequence = Map::<ReferenceSequence>::new(length);
+        set_md5_checksum(&mut reference_sequence, md5_checksum);
+
+        reference_sequences.insert(definition.name(), reference_sequence)?;
+    }
+
+    Ok(reference_sequences)
+}
+
+/// Sets the MD5 checksums (`M5`) of reference sequences from the sequences in a repository.
+///
+/// Existing MD5 checksums are overwritten.
+///
+/// # Errors
+///
+/// This returns an error if a reference sequence is missing from the repository or fails to
+/// load.
+///
+/// # Examples
+///
+/// ```
+/// # use std::num::NonZeroUsize;
+/// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+/// use noodles_sam::{
+///     self as sam,
+///     header::record::value::{
+///         map::{reference_sequence::tag, ReferenceSequence},
+///         Map,
+///     },
+/// };
+///
+/// let mut header = sam::Header::builder()
+///     .add_reference_sequence(
+///         "sq0",
+///         Map::<ReferenceSequence>::new(NonZeroUsize::try_from(4)?),
+///     )
+///     .build();
+///
+/// let repository = fasta::Repository::new(vec![fasta::Record::new(
+///     Definition::new("sq0", None),
+///     Sequence::from(b"ACGT".to_vec()),
+/// )]);
+///
+/// sam::dict::set_md5_checksums(header.reference_sequences_mut(), &repository)?;
+///
+/// assert_eq!(
+///     header.reference_sequences()[&b"sq0"[..]]
+///         .other_fields()
+///         .get(&tag::MD5_CHECKSUM),
+///     Some(&"f1f8f4bf413b16ad135722aa4591043e".into()),
+/// );
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn set_md5_checksums(
+    reference_sequences: &mut ReferenceSequences,
+    repository: &fasta::Repository,
+) -> Result<(), ValidationError> {
+    for (name, reference_sequence) in reference_sequences.iter_mut() {
+        let sequence = repository
+            .get(name)
+            .ok_or_else(|| ValidationError::MissingReferenceSequence(name.clone()))?
+            .map_err(ValidationError::Io)?;
+
+        let md5_checksum = calculate_md5_checksum(sequence.as_ref());
+        set_md5_checksum(reference_sequence, md5_checksum);
+    }
+
+    Ok(())
+}
+
+fn read_sequence_length_and_md5_checksum<R>(
+    reader: &mut fasta::io::Reader<R>,
+) -> io::Result<(usize, Md5Checksum)>
+where
+    R: BufRead,
+{
+    let mut sequence_reader = reader.sequence_reader();
+    let mut hasher = Md5::new();
+    let mut length = 0;
+
+    loop {
+        let src = sequence_reader.fill_buf()?;
+
+        if src.is_empty() {
+            break;
+        }
+
+        update(&mut hasher, src);
+        length += src.len();
+
+        let amt = src.len();
+        sequence_reader.consume(amt);
+    }
+
+    let md5_checksum = <[u8; 16]>::from(hasher.finalize()).into();
+
+    Ok((length, md5_checksum))
+}
+
+fn update(hasher: &mut Md5, buf: &[u8]) {
+    for &b in buf {
+        // "All characters outside of the inclusive range 33 ('!') to 126 ('~') are stripped out."
+        if b.is_ascii_graphic() {
+            // "All lowercase characters are converted to uppercase."
+            hasher.update([b.to_ascii_uppercase()]);
+        }
+    }
+}
+
+fn set_md5_checksum(reference_sequence: &mut Map<ReferenceSequence>, md5_checksum: Md5Checksum) {
+    reference_sequence
+        .other_fields_mut()
+        .insert(tag::MD5_CHECKSUM, BString::from(md5_checksum.to_string()));
+}
+
+#[cfg(test)]
+mod tests {
+    use fasta::record::Sequence;
+
+    use super::*;
+
+    #[test]
+    fn test_calculate_md5_checksum() {
+        assert_eq!(
+            calculate_md5_checksum(b"ACGT").to_string(),
+            "f1f8f4bf413b16ad135722aa4591043e"
+        );
+
+        assert_eq!(
+            calculate_md5_checksum(b"ACgt").to_string(),
+            "f1f8f4bf413b16ad135722aa4591043e"
+        );
+
+        // _Sequence Alignment/Map Format Specification_ (2021-06-03) § 1.3.2 "Reference MD5
+        // calculation"
+        assert_eq!(
+            calculate_md5_checksum(b"ACGTACGTACGTACGTACGTACGT...12345!!!").to_string(),
+            "dfabdbb36e239a6da88957841f32b8e4"
+        );
+    }
+
+    #[test]
+    fn test_read_reference_sequences() -> Result<(), Box<dyn std::error::Error>> {
+        let data = b">sq0 LN:4\nAC\ngt\n>sq1\nNNNN\r\nNNNN\r\n";
+        let mut reader = fasta::io::Reader::new(&data[..]);
+        let reference_sequences = read_reference_sequences(&mut reader)?;
+
+        let expected: ReferenceSequences = [
+            ("sq0", 4, calculate_md5_checksum(b"ACGT")),
+            ("sq1", 8, calculate_md5_checksum(b"NNNNNNNN")),
+        ]
+        .into_iter()
+        .map(|(name, length, md5_checksum)| {
+            let mut reference_sequence =
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(length)?);
+            set_md5_checksum(&mut reference_sequence, md5_checksum);
+            Ok((BString::from(name), reference_sequence))
+        })
+        .collect::<Result<_, std::num::TryFromIntError>>()?;
+
+        assert_eq!(reference_sequences, expected);
+
+        let data = b">sq0\n>sq1\nACGT\n";
+        let mut reader = fasta::io::Reader::new(&data[..]);
+        assert!(matches!(
+            read_reference_sequences(&mut reader),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_md5_checksums() -> Result<(), Box<dyn std::error::Error>> {
+        let mut reference_sequences: ReferenceSequences = [(
+            BString::from("sq0"),
+            Map::<ReferenceSequence>::new(NonZeroUsize::try_from(4)?),
+        )]
+        .into_iter()
+        .collect();
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            Definition::new("sq0", None),
+            Sequence::from(b"ACGT".to_vec()),
+        )]);
+
+        set_md5_checksums(&mut reference_sequences, &repository)?;
+
+        assert_eq!(
+            reference_sequences[&b"sq0"[..]]
+                .other_fields()
+                .get(&tag::MD5_CHECKSUM),
+            Some(&BString::from("f1f8f4bf413b16ad135722aa4591043e"))
+        );
+
+        let repository = fasta::Repository::default();
+        assert!(matches!(
+            set_md5_checksums(&mut reference_sequences, &repository),
+            Err(ValidationError::MissingReferenceSequence(name)) if name == "sq0"
+        ));
+
+        Ok(())
+    }
+}
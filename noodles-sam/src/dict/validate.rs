@@ -0,0 +1,287 @@
+use std::{error, fmt, io, num::NonZeroUsize};
+
+use bstr::BString;
+use noodles_fasta::{self as fasta, fai};
+
+use super::calculate_md5_checksum;
+use crate::header::{record::value::map::reference_sequence::tag, ReferenceSequences};
+
+/// An error returned when a sequence dictionary does not match a reference.
+#[derive(Debug)]
+pub enum ValidationError {
+    /// A reference sequence in the dictionary is missing from the reference.
+    MissingReferenceSequence(BString),
+    /// A reference sequence in the reference is missing from the dictionary.
+    UnexpectedReferenceSequence(BString),
+    /// The length of a reference sequence does not match.
+    LengthMismatch {
+        /// The reference sequence name.
+        name: BString,
+        /// The length in the dictionary.
+        expected: NonZeroUsize,
+        /// The length in the reference.
+        actual: u64,
+    },
+    /// The MD5 checksum of a reference sequence does not match.
+    ChecksumMismatch {
+        /// The reference sequence name.
+        name: BString,
+        /// The MD5 checksum in the dictionary.
+        expected: String,
+        /// The MD5 checksum of the reference sequence.
+        actual: String,
+    },
+    /// An I/O error occurred when reading a reference sequence.
+    Io(io::Error),
+}
+
+impl error::Error for ValidationError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingReferenceSequence(name) => {
+                write!(f, "missing reference sequence: {name}")
+            }
+            Self::UnexpectedReferenceSequence(name) => {
+                write!(f, "unexpected reference sequence: {name}")
+            }
+            Self::LengthMismatch {
+                name,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "reference sequence length mismatch: {name}: expected {expected}, got {actual}"
+            ),
+            Self::ChecksumMismatch {
+                name,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "reference sequence checksum mismatch: {name}: expected {expected}, got {actual}"
+            ),
+            Self::Io(_) => write!(f, "I/O error"),
+        }
+    }
+}
+
+/// Validates the names and lengths of a reference sequence dictionary against a FASTA index.
+///
+/// Every reference sequence in the dictionary must be in the index with the same length, and
+/// every record in the index must be in the dictionary.
+///
+/// # Examples
+///
+/// ```
+/// # use std::num::NonZeroUsize;
+/// use noodles_fasta::fai;
+/// use noodles_sam::{
+///     self as sam,
+///     header::record::value::{map::ReferenceSequence, Map},
+/// };
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence(
+///         "sq0",
+///         Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+///     )
+///     .build();
+///
+/// let index = fai::Index::from(vec![fai::Record::new("sq0", 8, 5, 8, 9)]);
+///
+/// sam::dict::validate_against(header.reference_sequences(), &index)?;
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn validate_against(
+    reference_sequences: &ReferenceSequences,
+    index: &fai::Index,
+) -> Result<(), ValidationError> {
+    let records = index.as_ref();
+
+    for (name, reference_sequence) in reference_sequences {
+        let record = records
+            .iter()
+            .find(|record| record.name() == name.as_slice())
+            .ok_or_else(|| ValidationError::MissingReferenceSequence(name.clone()))?;
+
+        let expected = reference_sequence.length();
+        let actual = record.length();
+
+        if u64::try_from(expected.get()).ok() != Some(actual) {
+            return Err(ValidationError::LengthMismatch {
+                name: name.clone(),
+                expected,
+                actual,
+            });
+        }
+    }
+
+    for record in records {
+        if !reference_sequences.contains_key(record.name()) {
+            return Err(ValidationError::UnexpectedReferenceSequence(
+                record.name().into(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates the MD5 checksums (`M5`) of a reference sequence dictionary against the sequences
+/// in a repository.
+///
+/// Reference sequences without an MD5 checksum are skipped.
+///
+/// # Examples
+///
+/// ```
+/// # use std::num::NonZeroUsize;
+/// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+/// use noodles_sam::{
+///     self as sam,
+///     header::record::value::{
+///         map::{reference_sequence::tag, ReferenceSequence},
+///         Map,
+///     },
+/// };
+///
+/// let mut sq0 = Map::<ReferenceSequence>::new(NonZeroUsize::try_from(4)?);
+/// sq0.other_fields_mut()
+///     .insert(tag::MD5_CHECKSUM, "f1f8f4bf413b16ad135722aa4591043e".into());
+///
+/// let header = sam::Header::builder().add_reference_sequence("sq0", sq0).build();
+///
+/// let repository = fasta::Repository::new(vec![fasta::Record::new(
+///     Definition::new("sq0", None),
+///     Sequence::from(b"ACGT".to_vec()),
+/// )]);
+///
+/// sam::dict::validate_checksums(header.reference_sequences(), &repository)?;
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn validate_checksums(
+    reference_sequences: &ReferenceSequences,
+    repository: &fasta::Repository,
+) -> Result<(), ValidationError> {
+    for (name, reference_sequence) in reference_sequences {
+        let Some(expected) = reference_sequence.other_fields().get(&tag::MD5_CHECKSUM) else {
+            continue;
+        };
+
+        let sequence = repository
+            .get(name)
+            .ok_or_else(|| ValidationError::MissingReferenceSequence(name.clone()))?
+            .map_err(ValidationError::Io)?;
+
+        let actual = calculate_md5_checksum(sequence.as_ref()).to_string();
+
+        if !expected.eq_ignore_ascii_case(actual.as_bytes()) {
+            return Err(ValidationError::ChecksumMismatch {
+                name: name.clone(),
+                expected: expected.to_string(),
+                actual,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use fasta::record::{Definition, Sequence};
+
+    use super::*;
+    use crate::header::record::value::{map::ReferenceSequence, Map};
+
+    fn build_reference_sequences() -> Result<ReferenceSequences, Box<dyn std::error::Error>> {
+        let mut sq0 = Map::<ReferenceSequence>::new(NonZeroUsize::try_from(4)?);
+        sq0.other_fields_mut()
+            .insert(tag::MD5_CHECKSUM, "F1F8F4BF413B16AD135722AA4591043E".into());
+
+        Ok([
+            (BString::from("sq0"), sq0),
+            (
+                BString::from("sq1"),
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            ),
+        ]
+        .into_iter()
+        .collect())
+    }
+
+    #[test]
+    fn test_validate_against() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequences = build_reference_sequences()?;
+
+        let index = fai::Index::from(vec![
+            fai::Record::new("sq0", 4, 5, 4, 5),
+            fai::Record::new("sq1", 8, 15, 8, 9),
+        ]);
+        assert!(validate_against(&reference_sequences, &index).is_ok());
+
+        let index = fai::Index::from(vec![fai::Record::new("sq0", 4, 5, 4, 5)]);
+        assert!(matches!(
+            validate_against(&reference_sequences, &index),
+            Err(ValidationError::MissingReferenceSequence(name)) if name == "sq1"
+        ));
+
+        let index = fai::Index::from(vec![
+            fai::Record::new("sq0", 4, 5, 4, 5),
+            fai::Record::new("sq1", 13, 15, 13, 14),
+        ]);
+        assert!(matches!(
+            validate_against(&reference_sequences, &index),
+            Err(ValidationError::LengthMismatch { name, actual: 13, .. }) if name == "sq1"
+        ));
+
+        let index = fai::Index::from(vec![
+            fai::Record::new("sq0", 4, 5, 4, 5),
+            fai::Record::new("sq1", 8, 15, 8, 9),
+            fai::Record::new("sq2", 2, 29, 2, 3),
+        ]);
+        assert!(matches!(
+            validate_against(&reference_sequences, &index),
+            Err(ValidationError::UnexpectedReferenceSequence(name)) if name == "sq2"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_checksums() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequences = build_reference_sequences()?;
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            Definition::new("sq0", None),
+            Sequence::from(b"acgt".to_vec()),
+        )]);
+        assert!(validate_checksums(&reference_sequences, &repository).is_ok());
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            Definition::new("sq0", None),
+            Sequence::from(b"ACGA".to_vec()),
+        )]);
+        assert!(matches!(
+            validate_checksums(&reference_sequences, &repository),
+            Err(ValidationError::ChecksumMismatch { name, .. }) if name == "sq0"
+        ));
+
+        let repository = fasta::Repository::default();
+        assert!(matches!(
+            validate_checksums(&reference_sequences, &repository),
+            Err(ValidationError::MissingReferenceSequence(name)) if name == "sq0"
+        ));
+
+        Ok(())
+    }
+}
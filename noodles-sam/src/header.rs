@@ -74,6 +74,9 @@ mod builder;
 mod parser;
 mod programs;
 pub mod record;
+mod reference_sequences;
+#[cfg(feature = "serde")]
+mod serde;
 
 pub use self::{
     builder::Builder,
@@ -86,14 +89,11 @@ use std::str::{self, FromStr};
 use bstr::BString;
 use indexmap::IndexMap;
 
-pub use self::programs::Programs;
 use self::record::value::{
-    map::{self, ReadGroup, ReferenceSequence},
+    map::{self, ReadGroup},
     Map,
 };
-
-/// A reference sequence dictionary.
-pub type ReferenceSequences = IndexMap<BString, Map<ReferenceSequence>>;
+pub use self::{programs::Programs, reference_sequences::ReferenceSequences};
 
 /// An ordered map of read groups.
 pub type ReadGroups = IndexMap<BString, Map<ReadGroup>>;
@@ -102,6 +102,8 @@ pub type ReadGroups = IndexMap<BString, Map<ReadGroup>>;
 ///
 /// Records are grouped by their types: header, reference sequence, read group, program, and
 /// comment.
+///
+/// With the `serde` feature enabled, the header can be (de)serialized, e.g., to and from JSON.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Header {
     header: Option<Map<map::Header>>,
@@ -165,6 +167,64 @@ impl Header {
         &mut self.header
     }
 
+    /// Returns the sort order of the records.
+    ///
+    /// This is the sort order (`SO`) of the header (`HD`) record. If either is missing, the sort
+    /// order is unknown.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the sort order is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, header::record::value::map::header::SortOrder};
+    ///
+    /// let header = sam::Header::default();
+    /// assert_eq!(header.sort_order(), Ok(SortOrder::Unknown));
+    ///
+    /// let header: sam::Header = "@HD\tVN:1.6\tSO:coordinate\n".parse()?;
+    /// assert_eq!(header.sort_order(), Ok(SortOrder::Coordinate));
+    /// # Ok::<_, sam::header::ParseError>(())
+    /// ```
+    pub fn sort_order(
+        &self,
+    ) -> Result<map::header::SortOrder, map::header::sort_order::ParseError> {
+        self.header()
+            .and_then(|header| header.sort_order())
+            .unwrap_or(Ok(map::header::SortOrder::Unknown))
+    }
+
+    /// Returns the group order of the records.
+    ///
+    /// This is the group order (`GO`) of the header (`HD`) record. If either is missing, the
+    /// records are not grouped.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the group order is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, header::record::value::map::header::GroupOrder};
+    ///
+    /// let header = sam::Header::default();
+    /// assert_eq!(header.group_order(), Ok(GroupOrder::None));
+    ///
+    /// let header: sam::Header = "@HD\tVN:1.6\tGO:query\n".parse()?;
+    /// assert_eq!(header.group_order(), Ok(GroupOrder::Query));
+    /// # Ok::<_, sam::header::ParseError>(())
+    /// ```
+    pub fn group_order(
+        &self,
+    ) -> Result<map::header::GroupOrder, map::header::group_order::ParseError> {
+        self.header()
+            .and_then(|header| header.group_order())
+            .unwrap_or(Ok(map::header::GroupOrder::None))
+    }
+
     /// Returns the SAM header reference sequences.
     ///
     /// This is also called the reference sequence dictionary.
@@ -212,9 +272,9 @@ impl Header {
     /// let mut header = sam::Header::default();
     ///
     /// header.reference_sequences_mut().insert(
-    ///     String::from("sq0").into(),
+    ///     "sq0",
     ///     Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
-    /// );
+    /// )?;
     ///
     /// let reference_sequences = header.reference_sequences();
     /// assert_eq!(reference_sequences.len(), 1);
@@ -104,6 +104,7 @@ impl Builder {
         N: Into<BString>,
     {
         self.reference_sequences
+            .as_mut()
             .insert(name.into(), reference_sequence);
 
         self
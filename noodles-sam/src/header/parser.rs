@@ -105,7 +105,7 @@ impl Parser {
                 }
             }
             Record::ReferenceSequence(name, reference_sequence) => try_insert(
-                &mut self.reference_sequences,
+                self.reference_sequences.as_mut(),
                 name,
                 reference_sequence,
                 ParseError::DuplicateReferenceSequenceName,
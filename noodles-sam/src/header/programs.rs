@@ -108,6 +108,151 @@ impl Programs {
         Ok(())
     }
 
+    /// Appends a program to the end of the most recently added program chain.
+    ///
+    /// Unlike [`Self::add`], this links the program to a single previous program: the last leaf
+    /// program in insertion order. If the program does not have a previous program ID (`PP`),
+    /// it is set to the ID of that terminal program. If the given ID is already used, it is made
+    /// unique by appending a numeric suffix, e.g., `pg0.1`, `pg0.2`, etc.
+    ///
+    /// This returns the ID of the appended program.
+    ///
+    /// # Errors
+    ///
+    /// This returns an `io::Error` if any program chain has a cycle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     header::record::value::{map::{program::tag, Program}, Map},
+    /// };
+    ///
+    /// let mut header = sam::Header::default();
+    /// let programs = header.programs_mut();
+    ///
+    /// assert_eq!(programs.append("noodles", Map::default())?, "noodles");
+    /// assert_eq!(programs.append("noodles", Map::default())?, "noodles.1");
+    ///
+    /// let expected = sam::Header::builder()
+    ///     .add_program("noodles", Map::default())
+    ///     .add_program(
+    ///         "noodles.1",
+    ///         Map::builder().insert(tag::PREVIOUS_PROGRAM_ID, "noodles").build()?,
+    ///     )
+    ///     .build();
+    ///
+    /// assert_eq!(programs, expected.programs());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn append<P>(&mut self, id: P, mut map: Map<Program>) -> io::Result<BString>
+    where
+        P: Into<BString>,
+    {
+        const SEPARATOR: u8 = b'.';
+
+        let id_prefix = id.into();
+
+        let previous_program_id: Option<BString> = self
+            .leaves()?
+            .max_by_key(|(id, _)| self.0.get_index_of(*id))
+            .map(|(id, _)| id.into());
+
+        if let Some(previous_program_id) = previous_program_id {
+            map.other_fields_mut()
+                .entry(tag::PREVIOUS_PROGRAM_ID)
+                .or_insert(previous_program_id);
+        }
+
+        let mut id = id_prefix.clone();
+        let mut n = 0;
+
+        while self.0.contains_key(&id) {
+            n += 1;
+
+            id = id_prefix.clone();
+            id.push_byte(SEPARATOR);
+            id.push_str(n.to_string());
+        }
+
+        self.0.insert(id.clone(), map);
+
+        Ok(id)
+    }
+
+    /// Returns an iterator over programs ordered by their program chains.
+    ///
+    /// Each program chain is resolved from its root program, and a program is always listed after
+    /// its previous program. Root programs and programs that share a previous program are visited
+    /// in insertion order.
+    ///
+    /// # Errors
+    ///
+    /// This returns an `io::Error` if a previous program ID (`PP`) references a missing program or
+    /// if any program chain has a cycle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     header::record::value::{map::{program::tag, Program}, Map},
+    /// };
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_program("pg2", Map::builder().insert(tag::PREVIOUS_PROGRAM_ID, "pg1").build()?)
+    ///     .add_program("pg0", Map::default())
+    ///     .add_program("pg1", Map::builder().insert(tag::PREVIOUS_PROGRAM_ID, "pg0").build()?)
+    ///     .build();
+    ///
+    /// let ids: Vec<_> = header
+    ///     .programs()
+    ///     .programs_in_order()?
+    ///     .map(|(id, _)| id)
+    ///     .collect();
+    ///
+    /// assert_eq!(ids, ["pg0", "pg1", "pg2"]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn programs_in_order(&self) -> io::Result<impl Iterator<Item = (&BStr, &Map<Program>)>> {
+        let mut roots = Vec::new();
+        let mut children = vec![Vec::new(); self.0.len()];
+
+        for (i, map) in self.0.values().enumerate() {
+            match map.other_fields().get(&tag::PREVIOUS_PROGRAM_ID) {
+                Some(previous_program_id) => {
+                    let j = self.0.get_index_of(previous_program_id).ok_or_else(|| {
+                        io::Error::new(io::ErrorKind::InvalidData, "missing previous program")
+                    })?;
+
+                    children[j].push(i);
+                }
+                None => roots.push(i),
+            }
+        }
+
+        let mut indices = Vec::with_capacity(self.0.len());
+        let mut stack: Vec<_> = roots.into_iter().rev().collect();
+
+        while let Some(i) = stack.pop() {
+            indices.push(i);
+            stack.extend(children[i].iter().rev());
+        }
+
+        if indices.len() != self.0.len() {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "cycle detected"));
+        }
+
+        Ok(indices.into_iter().map(|i| {
+            // SAFETY: `i` is guaranteed to be a valid index.
+            self.0
+                .get_index(i)
+                .map(|(id, map)| (id.as_ref(), map))
+                .unwrap()
+        }))
+    }
+
     /// Returns an iterator over root programs.
     ///
     /// A root program is a first program of a program chain.
@@ -290,6 +435,109 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_append() -> Result<(), Box<dyn std::error::Error>> {
+        let mut programs = Programs::default();
+
+        assert_eq!(programs.append("pg", Map::default())?, "pg");
+        assert_eq!(programs.append("pg", Map::default())?, "pg.1");
+
+        programs
+            .as_mut()
+            .insert(BString::from("pg.2"), Map::default());
+
+        assert_eq!(programs.append("pg", Map::default())?, "pg.3");
+
+        let previous_program_ids: Vec<_> = programs
+            .as_ref()
+            .values()
+            .map(|map| map.other_fields().get(&tag::PREVIOUS_PROGRAM_ID).cloned())
+            .collect();
+
+        assert_eq!(
+            previous_program_ids,
+            [
+                None,
+                Some(BString::from("pg")),
+                None,
+                Some(BString::from("pg.2")),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_programs_in_order() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_program(
+                "pg3",
+                Map::builder()
+                    .insert(tag::PREVIOUS_PROGRAM_ID, "pg0")
+                    .build()?,
+            )
+            .add_program("pg0", Map::default())
+            .add_program("pg1", Map::default())
+            .add_program(
+                "pg2",
+                Map::builder()
+                    .insert(tag::PREVIOUS_PROGRAM_ID, "pg3")
+                    .build()?,
+            )
+            .add_program(
+                "pg4",
+                Map::builder()
+                    .insert(tag::PREVIOUS_PROGRAM_ID, "pg0")
+                    .build()?,
+            )
+            .build();
+
+        let ids: Vec<_> = header
+            .programs()
+            .programs_in_order()?
+            .map(|(id, _)| id)
+            .collect();
+
+        assert_eq!(ids, ["pg0", "pg3", "pg2", "pg4", "pg1"]);
+
+        let header = Header::builder()
+            .add_program(
+                "pg0",
+                Map::builder()
+                    .insert(tag::PREVIOUS_PROGRAM_ID, "pg1")
+                    .build()?,
+            )
+            .build();
+
+        assert!(matches!(
+            header.programs().programs_in_order(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let header = Header::builder()
+            .add_program("pg0", Map::default())
+            .add_program(
+                "pg1",
+                Map::builder()
+                    .insert(tag::PREVIOUS_PROGRAM_ID, "pg2")
+                    .build()?,
+            )
+            .add_program(
+                "pg2",
+                Map::builder()
+                    .insert(tag::PREVIOUS_PROGRAM_ID, "pg1")
+                    .build()?,
+            )
+            .build();
+
+        assert!(matches!(
+            header.programs().programs_in_order(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_leaves() -> Result<(), Box<dyn std::error::Error>> {
         let header = Header::builder()
@@ -6,7 +6,7 @@ pub mod sort_order;
 pub mod tag;
 pub mod version;
 
-pub use self::{tag::Tag, version::Version};
+pub use self::{group_order::GroupOrder, sort_order::SortOrder, tag::Tag, version::Version};
 
 use self::builder::Builder;
 use super::{Inner, Map, OtherFields};
@@ -74,6 +74,96 @@ impl Map<Header> {
     pub fn version_mut(&mut self) -> &mut Version {
         &mut self.inner.version
     }
+
+    /// Returns the sort order (`SO`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{
+    ///     map::{self, header::{tag, SortOrder}},
+    ///     Map,
+    /// };
+    ///
+    /// let header = Map::<map::Header>::default();
+    /// assert!(header.sort_order().is_none());
+    ///
+    /// let header = Map::<map::Header>::builder()
+    ///     .insert(tag::SORT_ORDER, "coordinate")
+    ///     .build()?;
+    ///
+    /// assert_eq!(header.sort_order(), Some(Ok(SortOrder::Coordinate)));
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn sort_order(&self) -> Option<Result<SortOrder, sort_order::ParseError>> {
+        self.other_fields()
+            .get(&tag::SORT_ORDER)
+            .map(|value| SortOrder::try_from(value.as_ref()))
+    }
+
+    /// Sets the sort order (`SO`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{
+    ///     map::{self, header::SortOrder},
+    ///     Map,
+    /// };
+    ///
+    /// let mut header = Map::<map::Header>::default();
+    /// header.set_sort_order(SortOrder::QueryName);
+    /// assert_eq!(header.sort_order(), Some(Ok(SortOrder::QueryName)));
+    /// ```
+    pub fn set_sort_order(&mut self, sort_order: SortOrder) {
+        self.other_fields_mut()
+            .insert(tag::SORT_ORDER, sort_order.as_ref().into());
+    }
+
+    /// Returns the group order (`GO`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{
+    ///     map::{self, header::{tag, GroupOrder}},
+    ///     Map,
+    /// };
+    ///
+    /// let header = Map::<map::Header>::default();
+    /// assert!(header.group_order().is_none());
+    ///
+    /// let header = Map::<map::Header>::builder()
+    ///     .insert(tag::GROUP_ORDER, "query")
+    ///     .build()?;
+    ///
+    /// assert_eq!(header.group_order(), Some(Ok(GroupOrder::Query)));
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn group_order(&self) -> Option<Result<GroupOrder, group_order::ParseError>> {
+        self.other_fields()
+            .get(&tag::GROUP_ORDER)
+            .map(|value| GroupOrder::try_from(value.as_ref()))
+    }
+
+    /// Sets the group order (`GO`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{
+    ///     map::{self, header::GroupOrder},
+    ///     Map,
+    /// };
+    ///
+    /// let mut header = Map::<map::Header>::default();
+    /// header.set_group_order(GroupOrder::Reference);
+    /// assert_eq!(header.group_order(), Some(Ok(GroupOrder::Reference)));
+    /// ```
+    pub fn set_group_order(&mut self, group_order: GroupOrder) {
+        self.other_fields_mut()
+            .insert(tag::GROUP_ORDER, group_order.as_ref().into());
+    }
 }
 
 #[cfg(test)]
@@ -85,4 +175,22 @@ mod tests {
         let header = Map::<Header>::default();
         assert_eq!(header.version(), Version::default());
     }
+
+    #[test]
+    fn test_sort_order() {
+        let mut header = Map::<Header>::default();
+        assert!(header.sort_order().is_none());
+
+        header
+            .other_fields_mut()
+            .insert(tag::SORT_ORDER, "sorted".into());
+
+        assert_eq!(
+            header.sort_order(),
+            Some(Err(sort_order::ParseError::Invalid))
+        );
+
+        header.set_sort_order(SortOrder::Coordinate);
+        assert_eq!(header.sort_order(), Some(Ok(SortOrder::Coordinate)));
+    }
 }
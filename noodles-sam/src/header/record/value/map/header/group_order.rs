@@ -1,5 +1,7 @@
 //! SAM header header group order.
 
+use std::{error, fmt};
+
 /// Records are not grouped (`none`).
 pub const NONE: &[u8] = b"none";
 
@@ -8,3 +10,90 @@ pub const QUERY: &[u8] = b"query";
 
 /// Alignments are grouped by reference sequence and position (`reference`).
 pub const REFERENCE: &[u8] = b"reference";
+
+/// A SAM header header group order (`GO`).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum GroupOrder {
+    /// Records are not grouped (`none`).
+    #[default]
+    None,
+    /// Records are grouped by name (`query`).
+    Query,
+    /// Alignments are grouped by reference sequence and position (`reference`).
+    Reference,
+}
+
+impl AsRef<[u8]> for GroupOrder {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            Self::None => NONE,
+            Self::Query => QUERY,
+            Self::Reference => REFERENCE,
+        }
+    }
+}
+
+impl fmt::Display for GroupOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::None => "none",
+            Self::Query => "query",
+            Self::Reference => "reference",
+        };
+
+        f.write_str(s)
+    }
+}
+
+/// An error returned when a raw SAM header header group order fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is invalid.
+    Invalid,
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid => f.write_str("invalid input"),
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for GroupOrder {
+    type Error = ParseError;
+
+    fn try_from(s: &[u8]) -> Result<Self, Self::Error> {
+        match s {
+            NONE => Ok(Self::None),
+            QUERY => Ok(Self::Query),
+            REFERENCE => Ok(Self::Reference),
+            _ => Err(ParseError::Invalid),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(GroupOrder::None.to_string(), "none");
+        assert_eq!(GroupOrder::Query.to_string(), "query");
+        assert_eq!(GroupOrder::Reference.to_string(), "reference");
+    }
+
+    #[test]
+    fn test_try_from_u8_slice_for_group_order() {
+        assert_eq!(GroupOrder::try_from(&b"none"[..]), Ok(GroupOrder::None));
+        assert_eq!(GroupOrder::try_from(&b"query"[..]), Ok(GroupOrder::Query));
+        assert_eq!(
+            GroupOrder::try_from(&b"reference"[..]),
+            Ok(GroupOrder::Reference)
+        );
+        assert_eq!(GroupOrder::try_from(&b"ref"[..]), Err(ParseError::Invalid));
+    }
+}
@@ -1,5 +1,7 @@
 //! SAM header header sort order.
 
+use std::{error, fmt};
+
 /// The record order is unknown (`unknown`).
 pub const UNKNOWN: &[u8] = b"unknown";
 
@@ -11,3 +13,108 @@ pub const QUERY_NAME: &[u8] = b"queryname";
 
 /// Records are sorted by reference sequence and position (`coordinate`).
 pub const COORDINATE: &[u8] = b"coordinate";
+
+/// A SAM header header sort order (`SO`).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum SortOrder {
+    /// The record order is unknown (`unknown`).
+    #[default]
+    Unknown,
+    /// Records are not sorted (`unsorted`).
+    Unsorted,
+    /// Records are sorted by name (`queryname`).
+    QueryName,
+    /// Records are sorted by reference sequence and position (`coordinate`).
+    Coordinate,
+}
+
+impl AsRef<[u8]> for SortOrder {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            Self::Unknown => UNKNOWN,
+            Self::Unsorted => UNSORTED,
+            Self::QueryName => QUERY_NAME,
+            Self::Coordinate => COORDINATE,
+        }
+    }
+}
+
+impl fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Unknown => "unknown",
+            Self::Unsorted => "unsorted",
+            Self::QueryName => "queryname",
+            Self::Coordinate => "coordinate",
+        };
+
+        f.write_str(s)
+    }
+}
+
+/// An error returned when a raw SAM header header sort order fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is invalid.
+    Invalid,
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid => f.write_str("invalid input"),
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for SortOrder {
+    type Error = ParseError;
+
+    fn try_from(s: &[u8]) -> Result<Self, Self::Error> {
+        match s {
+            UNKNOWN => Ok(Self::Unknown),
+            UNSORTED => Ok(Self::Unsorted),
+            QUERY_NAME => Ok(Self::QueryName),
+            COORDINATE => Ok(Self::Coordinate),
+            _ => Err(ParseError::Invalid),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(SortOrder::Unknown.to_string(), "unknown");
+        assert_eq!(SortOrder::Unsorted.to_string(), "unsorted");
+        assert_eq!(SortOrder::QueryName.to_string(), "queryname");
+        assert_eq!(SortOrder::Coordinate.to_string(), "coordinate");
+    }
+
+    #[test]
+    fn test_try_from_u8_slice_for_sort_order() {
+        assert_eq!(SortOrder::try_from(&b"unknown"[..]), Ok(SortOrder::Unknown));
+        assert_eq!(
+            SortOrder::try_from(&b"unsorted"[..]),
+            Ok(SortOrder::Unsorted)
+        );
+        assert_eq!(
+            SortOrder::try_from(&b"queryname"[..]),
+            Ok(SortOrder::QueryName)
+        );
+        assert_eq!(
+            SortOrder::try_from(&b"coordinate"[..]),
+            Ok(SortOrder::Coordinate)
+        );
+
+        assert_eq!(SortOrder::try_from(&b""[..]), Err(ParseError::Invalid));
+        assert_eq!(
+            SortOrder::try_from(&b"Coordinate"[..]),
+            Err(ParseError::Invalid)
+        );
+    }
+}
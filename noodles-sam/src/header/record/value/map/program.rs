@@ -1,12 +1,15 @@
 //! SAM header record program map value.
 
 mod builder;
+pub mod command_line;
 pub mod tag;
 
 pub(crate) use self::tag::Tag;
 
+use bstr::{BStr, BString, ByteSlice};
+
 use self::builder::Builder;
-use super::Inner;
+use super::{Inner, Map};
 
 // A SAM header record program map value.
 ///
@@ -18,3 +21,52 @@ impl Inner for Program {
     type StandardTag = tag::Standard;
     type Builder = Builder;
 }
+
+impl Map<Program> {
+    /// Returns the command line (`CL`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{
+    ///     map::{program::tag, Program},
+    ///     Map,
+    /// };
+    ///
+    /// let program = Map::<Program>::builder()
+    ///     .insert(tag::COMMAND_LINE, "samtools view in.bam")
+    ///     .build()?;
+    ///
+    /// assert_eq!(program.command_line(), Some(b"samtools view in.bam".as_ref().into()));
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn command_line(&self) -> Option<&BStr> {
+        self.other_fields()
+            .get(&tag::COMMAND_LINE)
+            .map(|value| value.as_bstr())
+    }
+
+    /// Returns the command line (`CL`) split into arguments.
+    ///
+    /// See [`command_line::split`] for the splitting rules.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{
+    ///     map::{program::tag, Program},
+    ///     Map,
+    /// };
+    ///
+    /// let program = Map::<Program>::builder()
+    ///     .insert(tag::COMMAND_LINE, "bwa mem -R '@RG\\tID:rg0' ref.fa")
+    ///     .build()?;
+    ///
+    /// let args = program.command_line_args().transpose()?;
+    /// assert_eq!(args, Some(vec!["bwa".into(), "mem".into(), "-R".into(), r"@RG\tID:rg0".into(), "ref.fa".into()]));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn command_line_args(&self) -> Option<Result<Vec<BString>, command_line::SplitError>> {
+        self.command_line().map(|src| command_line::split(src))
+    }
+}
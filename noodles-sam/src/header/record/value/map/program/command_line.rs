@@ -0,0 +1,127 @@
+//! SAM header program command line.
+
+use std::{error, fmt};
+
+use bstr::BString;
+
+/// An error returned when a SAM header program command line fails to split.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SplitError {
+    /// A quote is not closed.
+    UnterminatedQuote,
+    /// The command line ends with an escape character.
+    TrailingEscape,
+}
+
+impl error::Error for SplitError {}
+
+impl fmt::Display for SplitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnterminatedQuote => f.write_str("unterminated quote"),
+            Self::TrailingEscape => f.write_str("trailing escape"),
+        }
+    }
+}
+
+/// Splits a command line into arguments.
+///
+/// This follows POSIX shell word splitting: arguments are delimited by unquoted whitespace;
+/// single quotes preserve all enclosed characters; double quotes preserve all enclosed characters
+/// except a backslash, which escapes a following `"`, `\`, `$`, or `` ` ``; and an unquoted
+/// backslash escapes the following character. No expansions are performed.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::header::record::value::map::program::command_line;
+///
+/// let args = command_line::split(br#"samtools view -o "out put.bam" 'in.bam'"#)?;
+/// assert_eq!(args, ["samtools", "view", "-o", "out put.bam", "in.bam"]);
+/// # Ok::<_, command_line::SplitError>(())
+/// ```
+pub fn split(src: &[u8]) -> Result<Vec<BString>, SplitError> {
+    const BACKSLASH: u8 = b'\\';
+    const SINGLE_QUOTE: u8 = b'\'';
+    const DOUBLE_QUOTE: u8 = b'"';
+
+    let mut args = Vec::new();
+    let mut arg: Option<Vec<u8>> = None;
+
+    let mut iter = src.iter().copied();
+
+    while let Some(b) = iter.next() {
+        match b {
+            BACKSLASH => {
+                let c = iter.next().ok_or(SplitError::TrailingEscape)?;
+                arg.get_or_insert_with(Vec::new).push(c);
+            }
+            SINGLE_QUOTE => {
+                let buf = arg.get_or_insert_with(Vec::new);
+
+                loop {
+                    match iter.next() {
+                        Some(SINGLE_QUOTE) => break,
+                        Some(c) => buf.push(c),
+                        None => return Err(SplitError::UnterminatedQuote),
+                    }
+                }
+            }
+            DOUBLE_QUOTE => {
+                let buf = arg.get_or_insert_with(Vec::new);
+
+                loop {
+                    match iter.next() {
+                        Some(DOUBLE_QUOTE) => break,
+                        Some(BACKSLASH) => match iter.next() {
+                            Some(c @ (DOUBLE_QUOTE | BACKSLASH | b'$' | b'`')) => buf.push(c),
+                            Some(c) => buf.extend([BACKSLASH, c]),
+                            None => return Err(SplitError::UnterminatedQuote),
+                        },
+                        Some(c) => buf.push(c),
+                        None => return Err(SplitError::UnterminatedQuote),
+                    }
+                }
+            }
+            _ if b.is_ascii_whitespace() => {
+                if let Some(buf) = arg.take() {
+                    args.push(buf.into());
+                }
+            }
+            _ => arg.get_or_insert_with(Vec::new).push(b),
+        }
+    }
+
+    if let Some(buf) = arg {
+        args.push(buf.into());
+    }
+
+    Ok(args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split() {
+        assert_eq!(split(b""), Ok(Vec::new()));
+        assert_eq!(split(b"  \t "), Ok(Vec::new()));
+        assert_eq!(
+            split(b"bwa mem  ref.fa"),
+            Ok(vec!["bwa".into(), "mem".into(), "ref.fa".into()])
+        );
+        assert_eq!(
+            split(b"-R '@RG\\tID:rg0'"),
+            Ok(vec!["-R".into(), "@RG\\tID:rg0".into()])
+        );
+        assert_eq!(split(br#"a"b c"d"#), Ok(vec!["ab cd".into()]));
+        assert_eq!(split(br#""a\"b\n""#), Ok(vec![BString::from("a\"b\\n")]));
+        assert_eq!(split(br"a\ b"), Ok(vec!["a b".into()]));
+        assert_eq!(split(b"''"), Ok(vec!["".into()]));
+
+        assert_eq!(split(b"'a"), Err(SplitError::UnterminatedQuote));
+        assert_eq!(split(b"\"a"), Err(SplitError::UnterminatedQuote));
+        assert_eq!(split(b"a\\"), Err(SplitError::TrailingEscape));
+    }
+}
@@ -1,18 +1,28 @@
 //! SAM header record read group map value.
 
 mod builder;
+pub mod date_time;
 pub mod platform;
+pub mod platform_unit;
 pub mod tag;
 
+use std::{error, fmt, num};
+
+use bstr::{BStr, ByteSlice};
+
 pub(crate) use self::tag::Tag;
+pub use self::{date_time::DateTime, platform::Platform, platform_unit::PlatformUnit};
 
 use self::builder::Builder;
-use super::Inner;
+use super::{tag::Other, Inner, Map};
 
 /// A SAM header record read group map value.
 ///
 /// A read group typically defines the set of reads that came from the same run on a sequencing
 /// instrument.
+///
+/// Read group fields are stored as other fields. Typed accessors parse their values on access,
+/// and [`Map::<ReadGroup>::validate`] checks all typed fields at once.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct ReadGroup;
 
@@ -20,3 +30,395 @@ impl Inner for ReadGroup {
     type StandardTag = tag::Standard;
     type Builder = Builder;
 }
+
+/// An error returned when a SAM header read group field is invalid.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidationError {
+    /// The datetime of run (`DT`) is invalid.
+    InvalidProducedAt(date_time::ParseError),
+    /// The predicted median insert size (`PI`) is invalid.
+    InvalidPredictedMedianInsertSize(num::ParseIntError),
+    /// The platform (`PL`) is invalid.
+    InvalidPlatform(platform::ParseError),
+    /// The barcode sequence (`BC`) is invalid.
+    InvalidBarcode,
+    /// The platform unit (`PU`) is invalid.
+    InvalidPlatformUnit(platform_unit::ParseError),
+}
+
+impl error::Error for ValidationError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidProducedAt(e) => Some(e),
+            Self::InvalidPredictedMedianInsertSize(e) => Some(e),
+            Self::InvalidPlatform(e) => Some(e),
+            Self::InvalidBarcode => None,
+            Self::InvalidPlatformUnit(e) => Some(e),
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidProducedAt(_) => write!(f, "invalid datetime of run (DT)"),
+            Self::InvalidPredictedMedianInsertSize(_) => {
+                write!(f, "invalid predicted median insert size (PI)")
+            }
+            Self::InvalidPlatform(_) => write!(f, "invalid platform (PL)"),
+            Self::InvalidBarcode => write!(f, "invalid barcode sequence (BC)"),
+            Self::InvalidPlatformUnit(_) => write!(f, "invalid platform unit (PU)"),
+        }
+    }
+}
+
+impl Map<ReadGroup> {
+    /// Returns the barcode sequence (`BC`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::ReadGroup, Map};
+    /// let read_group = Map::<ReadGroup>::builder().set_barcode("ACGT-TTGA").build()?;
+    /// assert_eq!(read_group.barcode(), Some(b"ACGT-TTGA".as_ref().into()));
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn barcode(&self) -> Option<&BStr> {
+        self.get(tag::BARCODE)
+    }
+
+    /// Returns the barcode sequences (`BC`), split by `-`.
+    ///
+    /// This returns an error if a barcode is empty or contains a non-alphabetic base.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::ReadGroup, Map};
+    ///
+    /// let read_group = Map::<ReadGroup>::builder().set_barcode("ACGT-TTGA").build()?;
+    ///
+    /// let barcodes = read_group.barcodes().transpose()?;
+    /// assert_eq!(barcodes, Some(vec![b"ACGT".as_ref().into(), b"TTGA".as_ref().into()]));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn barcodes(&self) -> Option<Result<Vec<&BStr>, ValidationError>> {
+        self.barcode().map(|src| {
+            src.split_str("-")
+                .map(|barcode| {
+                    if !barcode.is_empty() && barcode.iter().all(u8::is_ascii_alphabetic) {
+                        Ok(barcode.as_bstr())
+                    } else {
+                        Err(ValidationError::InvalidBarcode)
+                    }
+                })
+                .collect()
+        })
+    }
+
+    /// Returns the sequencing center (`CN`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::ReadGroup, Map};
+    /// let read_group = Map::<ReadGroup>::builder().set_sequencing_center("ndls").build()?;
+    /// assert_eq!(read_group.sequencing_center(), Some(b"ndls".as_ref().into()));
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn sequencing_center(&self) -> Option<&BStr> {
+        self.get(tag::SEQUENCING_CENTER)
+    }
+
+    /// Returns the description (`DS`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::ReadGroup, Map};
+    /// let read_group = Map::<ReadGroup>::builder().set_description("noodles").build()?;
+    /// assert_eq!(read_group.description(), Some(b"noodles".as_ref().into()));
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn description(&self) -> Option<&BStr> {
+        self.get(tag::DESCRIPTION)
+    }
+
+    /// Returns the datetime of run (`DT`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::ReadGroup, Map};
+    ///
+    /// let date_time = "2024-08-04T13:21:34Z".parse()?;
+    /// let read_group = Map::<ReadGroup>::builder()
+    ///     .set_produced_at(date_time)
+    ///     .build()?;
+    ///
+    /// let date_time = read_group.produced_at().transpose()?;
+    /// assert_eq!(date_time.map(|dt| dt.year()), Some(2024));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn produced_at(&self) -> Option<Result<DateTime, ValidationError>> {
+        self.get(tag::PRODUCED_AT).map(|src| {
+            DateTime::try_from(src.as_bytes()).map_err(ValidationError::InvalidProducedAt)
+        })
+    }
+
+    /// Returns the flow order (`FO`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::ReadGroup, Map};
+    /// let read_group = Map::<ReadGroup>::builder().set_flow_order("TACG").build()?;
+    /// assert_eq!(read_group.flow_order(), Some(b"TACG".as_ref().into()));
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn flow_order(&self) -> Option<&BStr> {
+        self.get(tag::FLOW_ORDER)
+    }
+
+    /// Returns the key sequence (`KS`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::ReadGroup, Map};
+    /// let read_group = Map::<ReadGroup>::builder().set_key_sequence("TCAG").build()?;
+    /// assert_eq!(read_group.key_sequence(), Some(b"TCAG".as_ref().into()));
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn key_sequence(&self) -> Option<&BStr> {
+        self.get(tag::KEY_SEQUENCE)
+    }
+
+    /// Returns the library (`LB`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::ReadGroup, Map};
+    /// let read_group = Map::<ReadGroup>::builder().set_library("lb0").build()?;
+    /// assert_eq!(read_group.library(), Some(b"lb0".as_ref().into()));
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn library(&self) -> Option<&BStr> {
+        self.get(tag::LIBRARY)
+    }
+
+    /// Returns the programs used (`PG`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::ReadGroup, Map};
+    /// let read_group = Map::<ReadGroup>::builder().set_program("pg0").build()?;
+    /// assert_eq!(read_group.program(), Some(b"pg0".as_ref().into()));
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn program(&self) -> Option<&BStr> {
+        self.get(tag::PROGRAM)
+    }
+
+    /// Returns the predicted median insert size (`PI`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::ReadGroup, Map};
+    ///
+    /// let read_group = Map::<ReadGroup>::builder()
+    ///     .set_predicted_median_insert_size(144)
+    ///     .build()?;
+    ///
+    /// assert_eq!(read_group.predicted_median_insert_size(), Some(Ok(144)));
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn predicted_median_insert_size(&self) -> Option<Result<u64, ValidationError>> {
+        self.get(tag::PREDICTED_MEDIAN_INSERT_SIZE).map(|src| {
+            src.to_str_lossy()
+                .parse()
+                .map_err(ValidationError::InvalidPredictedMedianInsertSize)
+        })
+    }
+
+    /// Returns the platform (`PL`).
+    ///
+    /// Platform names are case-insensitive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::{read_group::Platform, ReadGroup}, Map};
+    ///
+    /// let read_group = Map::<ReadGroup>::builder()
+    ///     .set_platform(Platform::Illumina)
+    ///     .build()?;
+    ///
+    /// assert_eq!(read_group.platform(), Some(Ok(Platform::Illumina)));
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn platform(&self) -> Option<Result<Platform, ValidationError>> {
+        self.get(tag::PLATFORM)
+            .map(|src| Platform::try_from(src.as_bytes()).map_err(ValidationError::InvalidPlatform))
+    }
+
+    /// Returns the platform model (`PM`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::ReadGroup, Map};
+    /// let read_group = Map::<ReadGroup>::builder().set_platform_model("NovaSeq").build()?;
+    /// assert_eq!(read_group.platform_model(), Some(b"NovaSeq".as_ref().into()));
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn platform_model(&self) -> Option<&BStr> {
+        self.get(tag::PLATFORM_MODEL)
+    }
+
+    /// Returns the platform unit (`PU`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::ReadGroup, Map};
+    /// let read_group = Map::<ReadGroup>::builder().set_platform_unit("fc0.1").build()?;
+    /// assert_eq!(read_group.platform_unit(), Some(b"fc0.1".as_ref().into()));
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn platform_unit(&self) -> Option<&BStr> {
+        self.get(tag::PLATFORM_UNIT)
+    }
+
+    /// Returns the platform unit (`PU`) split into its flowcell, lane, and barcode.
+    ///
+    /// This expects the conventional `{flowcell}.{lane}[.{barcode}]` format. Since the format is
+    /// not defined by the SAM specification, it is not checked by [`Self::validate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::ReadGroup, Map};
+    ///
+    /// let read_group = Map::<ReadGroup>::builder()
+    ///     .set_platform_unit("HXXXXXXXX.1.ACGTACGT")
+    ///     .build()?;
+    ///
+    /// let platform_unit = read_group.parsed_platform_unit().transpose()?.unwrap();
+    /// assert_eq!(platform_unit.flowcell(), "HXXXXXXXX");
+    /// assert_eq!(platform_unit.lane(), 1);
+    /// assert_eq!(platform_unit.barcode(), Some("ACGTACGT".into()));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parsed_platform_unit(&self) -> Option<Result<PlatformUnit, ValidationError>> {
+        self.platform_unit().map(|src| {
+            PlatformUnit::try_from(src.as_bytes()).map_err(ValidationError::InvalidPlatformUnit)
+        })
+    }
+
+    /// Returns the sample (`SM`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::ReadGroup, Map};
+    /// let read_group = Map::<ReadGroup>::builder().set_sample("sample0").build()?;
+    /// assert_eq!(read_group.sample(), Some(b"sample0".as_ref().into()));
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn sample(&self) -> Option<&BStr> {
+        self.get(tag::SAMPLE)
+    }
+
+    /// Validates the typed fields.
+    ///
+    /// This checks the barcode sequence (`BC`), datetime of run (`DT`), predicted median insert
+    /// size (`PI`), and platform (`PL`), if present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{
+    ///     map::{read_group::{tag, ValidationError}, ReadGroup},
+    ///     Map,
+    /// };
+    ///
+    /// let read_group = Map::<ReadGroup>::builder().insert(tag::PLATFORM, "ILLUMINA").build()?;
+    /// assert!(read_group.validate().is_ok());
+    ///
+    /// let read_group = Map::<ReadGroup>::builder().insert(tag::PLATFORM, "NOODLES").build()?;
+    /// assert!(matches!(
+    ///     read_group.validate(),
+    ///     Err(ValidationError::InvalidPlatform(_))
+    /// ));
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        self.barcodes().transpose()?;
+        self.produced_at().transpose()?;
+        self.predicted_median_insert_size().transpose()?;
+        self.platform().transpose()?;
+        Ok(())
+    }
+
+    fn get(&self, tag: Other<tag::Standard>) -> Option<&BStr> {
+        self.other_fields().get(&tag).map(|value| value.as_bstr())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate() -> Result<(), Box<dyn std::error::Error>> {
+        let read_group = Map::<ReadGroup>::default();
+        assert_eq!(read_group.validate(), Ok(()));
+
+        let read_group = Map::<ReadGroup>::builder()
+            .insert(tag::BARCODE, "ACGT-TTGA")
+            .insert(tag::PRODUCED_AT, "2024-08-04")
+            .insert(tag::PREDICTED_MEDIAN_INSERT_SIZE, "144")
+            .insert(tag::PLATFORM, "pacbio")
+            .build()?;
+        assert_eq!(read_group.validate(), Ok(()));
+
+        let read_group = Map::<ReadGroup>::builder()
+            .insert(tag::BARCODE, "ACGT-")
+            .build()?;
+        assert_eq!(read_group.validate(), Err(ValidationError::InvalidBarcode));
+
+        let read_group = Map::<ReadGroup>::builder()
+            .insert(tag::PRODUCED_AT, "2024-13-01")
+            .build()?;
+        assert_eq!(
+            read_group.validate(),
+            Err(ValidationError::InvalidProducedAt(
+                date_time::ParseError::InvalidDate
+            ))
+        );
+
+        let read_group = Map::<ReadGroup>::builder()
+            .insert(tag::PREDICTED_MEDIAN_INSERT_SIZE, "-8")
+            .build()?;
+        assert!(matches!(
+            read_group.validate(),
+            Err(ValidationError::InvalidPredictedMedianInsertSize(_))
+        ));
+
+        let read_group = Map::<ReadGroup>::builder()
+            .insert(tag::PLATFORM, "NOODLES")
+            .build()?;
+        assert_eq!(
+            read_group.validate(),
+            Err(ValidationError::InvalidPlatform(
+                platform::ParseError::Invalid
+            ))
+        );
+
+        Ok(())
+    }
+}
@@ -1,10 +1,225 @@
-use super::ReadGroup;
+use bstr::BString;
+
+use super::{tag, DateTime, Platform, ReadGroup};
 use crate::header::record::value::map::{self, builder::BuildError};
 
 /// A SAM header reference read group.
 #[derive(Debug, Default)]
 pub struct Builder;
 
+impl map::Builder<ReadGroup> {
+    /// Sets the barcode sequence (`BC`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::ReadGroup, Map};
+    /// let read_group = Map::<ReadGroup>::builder().set_barcode("ACGT-TTGA").build()?;
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn set_barcode<V>(self, barcode: V) -> Self
+    where
+        V: Into<BString>,
+    {
+        self.insert(tag::BARCODE, barcode)
+    }
+
+    /// Sets the sequencing center (`CN`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::ReadGroup, Map};
+    /// let read_group = Map::<ReadGroup>::builder().set_sequencing_center("ndls").build()?;
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn set_sequencing_center<V>(self, sequencing_center: V) -> Self
+    where
+        V: Into<BString>,
+    {
+        self.insert(tag::SEQUENCING_CENTER, sequencing_center)
+    }
+
+    /// Sets the description (`DS`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::ReadGroup, Map};
+    /// let read_group = Map::<ReadGroup>::builder().set_description("noodles").build()?;
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn set_description<V>(self, description: V) -> Self
+    where
+        V: Into<BString>,
+    {
+        self.insert(tag::DESCRIPTION, description)
+    }
+
+    /// Sets the datetime of run (`DT`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::ReadGroup, Map};
+    ///
+    /// let read_group = Map::<ReadGroup>::builder()
+    ///     .set_produced_at("2024-08-04T13:21:34Z".parse()?)
+    ///     .build()?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_produced_at(self, produced_at: DateTime) -> Self {
+        self.insert(tag::PRODUCED_AT, produced_at.to_string())
+    }
+
+    /// Sets the flow order (`FO`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::ReadGroup, Map};
+    /// let read_group = Map::<ReadGroup>::builder().set_flow_order("TACG").build()?;
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn set_flow_order<V>(self, flow_order: V) -> Self
+    where
+        V: Into<BString>,
+    {
+        self.insert(tag::FLOW_ORDER, flow_order)
+    }
+
+    /// Sets the key sequence (`KS`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::ReadGroup, Map};
+    /// let read_group = Map::<ReadGroup>::builder().set_key_sequence("TCAG").build()?;
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn set_key_sequence<V>(self, key_sequence: V) -> Self
+    where
+        V: Into<BString>,
+    {
+        self.insert(tag::KEY_SEQUENCE, key_sequence)
+    }
+
+    /// Sets the library (`LB`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::ReadGroup, Map};
+    /// let read_group = Map::<ReadGroup>::builder().set_library("lb0").build()?;
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn set_library<V>(self, library: V) -> Self
+    where
+        V: Into<BString>,
+    {
+        self.insert(tag::LIBRARY, library)
+    }
+
+    /// Sets the programs used (`PG`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::ReadGroup, Map};
+    /// let read_group = Map::<ReadGroup>::builder().set_program("pg0").build()?;
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn set_program<V>(self, program: V) -> Self
+    where
+        V: Into<BString>,
+    {
+        self.insert(tag::PROGRAM, program)
+    }
+
+    /// Sets the predicted median insert size (`PI`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::ReadGroup, Map};
+    ///
+    /// let read_group = Map::<ReadGroup>::builder()
+    ///     .set_predicted_median_insert_size(144)
+    ///     .build()?;
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn set_predicted_median_insert_size(self, predicted_median_insert_size: u64) -> Self {
+        self.insert(
+            tag::PREDICTED_MEDIAN_INSERT_SIZE,
+            predicted_median_insert_size.to_string(),
+        )
+    }
+
+    /// Sets the platform (`PL`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::{read_group::Platform, ReadGroup}, Map};
+    ///
+    /// let read_group = Map::<ReadGroup>::builder()
+    ///     .set_platform(Platform::Ont)
+    ///     .build()?;
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn set_platform(self, platform: Platform) -> Self {
+        self.insert(tag::PLATFORM, platform.as_ref())
+    }
+
+    /// Sets the platform model (`PM`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::ReadGroup, Map};
+    /// let read_group = Map::<ReadGroup>::builder().set_platform_model("NovaSeq").build()?;
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn set_platform_model<V>(self, platform_model: V) -> Self
+    where
+        V: Into<BString>,
+    {
+        self.insert(tag::PLATFORM_MODEL, platform_model)
+    }
+
+    /// Sets the platform unit (`PU`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::ReadGroup, Map};
+    /// let read_group = Map::<ReadGroup>::builder().set_platform_unit("fc0.1").build()?;
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn set_platform_unit<V>(self, platform_unit: V) -> Self
+    where
+        V: Into<BString>,
+    {
+        self.insert(tag::PLATFORM_UNIT, platform_unit)
+    }
+
+    /// Sets the sample (`SM`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::ReadGroup, Map};
+    /// let read_group = Map::<ReadGroup>::builder().set_sample("sample0").build()?;
+    /// # Ok::<_, noodles_sam::header::record::value::map::builder::BuildError>(())
+    /// ```
+    pub fn set_sample<V>(self, sample: V) -> Self
+    where
+        V: Into<BString>,
+    {
+        self.insert(tag::SAMPLE, sample)
+    }
+}
+
 impl map::builder::Inner<ReadGroup> for Builder {
     fn build(self) -> Result<ReadGroup, BuildError> {
         Ok(ReadGroup)
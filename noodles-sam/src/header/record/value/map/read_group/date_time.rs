@@ -0,0 +1,296 @@
+//! SAM header read group date time.
+
+use std::{error, fmt, str::FromStr};
+
+/// A SAM header read group date time (`DT`).
+///
+/// This is an ISO 8601 date or date and time, e.g., `2024-08-04` or `2024-08-04T13:21:34Z`. The
+/// time, when present, may include fractional seconds and a time zone designator (`Z` or an
+/// offset).
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DateTime {
+    year: u16,
+    month: u8,
+    day: u8,
+    raw: String,
+}
+
+impl DateTime {
+    /// Returns the year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::map::read_group::DateTime;
+    /// let date_time: DateTime = "2024-08-04T13:21:34Z".parse()?;
+    /// assert_eq!(date_time.year(), 2024);
+    /// # Ok::<_, noodles_sam::header::record::value::map::read_group::date_time::ParseError>(())
+    /// ```
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Returns the month (1-12).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::map::read_group::DateTime;
+    /// let date_time: DateTime = "2024-08-04T13:21:34Z".parse()?;
+    /// assert_eq!(date_time.month(), 8);
+    /// # Ok::<_, noodles_sam::header::record::value::map::read_group::date_time::ParseError>(())
+    /// ```
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month (1-31).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::map::read_group::DateTime;
+    /// let date_time: DateTime = "2024-08-04T13:21:34Z".parse()?;
+    /// assert_eq!(date_time.day(), 4);
+    /// # Ok::<_, noodles_sam::header::record::value::map::read_group::date_time::ParseError>(())
+    /// ```
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+impl AsRef<str> for DateTime {
+    fn as_ref(&self) -> &str {
+        &self.raw
+    }
+}
+
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+/// An error returned when a raw SAM header read group date time fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The date is invalid.
+    InvalidDate,
+    /// The time is invalid.
+    InvalidTime,
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::InvalidDate => f.write_str("invalid date"),
+            Self::InvalidTime => f.write_str("invalid time"),
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for DateTime {
+    type Error = ParseError;
+
+    fn try_from(src: &[u8]) -> Result<Self, Self::Error> {
+        if src.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let (date, time) = match src.iter().position(|&b| b == b'T' || b == b' ') {
+            Some(i) => (&src[..i], Some(&src[i + 1..])),
+            None => (src, None),
+        };
+
+        let (year, month, day) = parse_date(date).ok_or(ParseError::InvalidDate)?;
+
+        if let Some(time) = time {
+            if !is_valid_time(time) {
+                return Err(ParseError::InvalidTime);
+            }
+        }
+
+        // `src` is ASCII after validation.
+        let raw = src.iter().copied().map(char::from).collect();
+
+        Ok(Self {
+            year,
+            month,
+            day,
+            raw,
+        })
+    }
+}
+
+impl FromStr for DateTime {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s.as_bytes())
+    }
+}
+
+fn parse_digits(src: &mut &[u8], len: usize) -> Option<u16> {
+    if src.len() < len || !src[..len].iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+
+    let n = src[..len]
+        .iter()
+        .fold(0, |n, &b| n * 10 + u16::from(b - b'0'));
+
+    *src = &src[len..];
+
+    Some(n)
+}
+
+fn consume(src: &mut &[u8], b: u8) -> Option<()> {
+    let (c, rest) = src.split_first()?;
+
+    if *c == b {
+        *src = rest;
+        Some(())
+    } else {
+        None
+    }
+}
+
+fn parse_date(mut src: &[u8]) -> Option<(u16, u8, u8)> {
+    let year = parse_digits(&mut src, 4)?;
+    consume(&mut src, b'-')?;
+    let month = parse_digits(&mut src, 2).and_then(|n| u8::try_from(n).ok())?;
+    consume(&mut src, b'-')?;
+    let day = parse_digits(&mut src, 2).and_then(|n| u8::try_from(n).ok())?;
+
+    if !src.is_empty() || !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month)
+    {
+        return None;
+    }
+
+    Some((year, month, day))
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn is_valid_time(mut src: &[u8]) -> bool {
+    fn parse_component(src: &mut &[u8], max: u16) -> Option<()> {
+        parse_digits(src, 2).filter(|&n| n <= max).map(|_| ())
+    }
+
+    fn parse_time(src: &mut &[u8]) -> Option<()> {
+        parse_component(src, 23)?;
+        consume(src, b':')?;
+        parse_component(src, 59)?;
+
+        if consume(src, b':').is_some() {
+            // Allows leap seconds.
+            parse_component(src, 60)?;
+
+            if consume(src, b'.').is_some() {
+                let n = src.iter().take_while(|b| b.is_ascii_digit()).count();
+
+                if n == 0 {
+                    return None;
+                }
+
+                *src = &src[n..];
+            }
+        }
+
+        Some(())
+    }
+
+    fn parse_time_zone(src: &mut &[u8]) -> Option<()> {
+        match src.first() {
+            None => Some(()),
+            Some(b'Z') => {
+                *src = &src[1..];
+                Some(())
+            }
+            Some(b'+' | b'-') => {
+                *src = &src[1..];
+                parse_component(src, 23)?;
+
+                if !src.is_empty() {
+                    let _ = consume(src, b':');
+                    parse_component(src, 59)?;
+                }
+
+                Some(())
+            }
+            Some(_) => None,
+        }
+    }
+
+    parse_time(&mut src).is_some() && parse_time_zone(&mut src).is_some() && src.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_u8_slice_for_date_time() {
+        fn t(s: &str, (year, month, day): (u16, u8, u8)) {
+            let date_time = DateTime::try_from(s.as_bytes()).unwrap();
+            assert_eq!(date_time.year(), year);
+            assert_eq!(date_time.month(), month);
+            assert_eq!(date_time.day(), day);
+            assert_eq!(date_time.to_string(), s);
+        }
+
+        t("2024-08-04", (2024, 8, 4));
+        t("2024-02-29", (2024, 2, 29));
+        t("2024-08-04T13:21", (2024, 8, 4));
+        t("2024-08-04T13:21:34", (2024, 8, 4));
+        t("2024-08-04T13:21:34.144Z", (2024, 8, 4));
+        t("2024-08-04T13:21:34+05:30", (2024, 8, 4));
+        t("2024-08-04T13:21:34-0800", (2024, 8, 4));
+        t("2024-08-04T13:21:34-08", (2024, 8, 4));
+        t("2024-08-04 13:21:34", (2024, 8, 4));
+
+        assert_eq!(DateTime::try_from(&b""[..]), Err(ParseError::Empty));
+
+        for s in [
+            "2024",
+            "2024-8-4",
+            "2024-13-01",
+            "2023-02-29",
+            "2024-08-32",
+            "noodles",
+        ] {
+            assert_eq!(
+                DateTime::try_from(s.as_bytes()),
+                Err(ParseError::InvalidDate),
+                "{s}"
+            );
+        }
+
+        for s in [
+            "2024-08-04T",
+            "2024-08-04T24:00",
+            "2024-08-04T13",
+            "2024-08-04T13:21:34.",
+            "2024-08-04T13:21:34Y",
+            "2024-08-04T13:21:34+",
+        ] {
+            assert_eq!(
+                DateTime::try_from(s.as_bytes()),
+                Err(ParseError::InvalidTime),
+                "{s}"
+            );
+        }
+    }
+}
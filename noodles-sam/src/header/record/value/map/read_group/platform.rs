@@ -1,5 +1,7 @@
 //! SAM header read group platform.
 
+use std::{error, fmt, str::FromStr};
+
 /// Capillary electrophoresis sequencing (`CAPILLARY`).
 pub const CAPILLARY: &[u8] = b"CAPILLARY";
 
@@ -35,3 +37,144 @@ pub const SOLID: &[u8] = b"SOLID";
 
 /// Ultima Genomics (`ULTIMA`).
 pub const ULTIMA: &[u8] = b"ULTIMA";
+
+/// A SAM header read group platform (`PL`).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Platform {
+    /// Capillary electrophoresis sequencing (`CAPILLARY`).
+    Capillary,
+    /// DNBseq (`DNBSEQ`).
+    DnbSeq,
+    /// Element Biosciences (`ELEMENT`).
+    Element,
+    /// Helicos (`HELICOS`).
+    Helicos,
+    /// Illumina (`ILLUMINA`).
+    Illumina,
+    /// Ion Torrent (`IONTORRENT`).
+    IonTorrent,
+    /// 454 Life Sciences (`LS454`).
+    Ls454,
+    /// Oxford Nanopore Technologies (ONT) (`ONT`).
+    Ont,
+    /// Pacific Biosciences (PacBio) (`PACBIO`).
+    PacBio,
+    /// Singular Genomics (`SINGULAR`).
+    Singular,
+    /// SOLiD (`SOLID`).
+    Solid,
+    /// Ultima Genomics (`ULTIMA`).
+    Ultima,
+}
+
+impl AsRef<[u8]> for Platform {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            Self::Capillary => CAPILLARY,
+            Self::DnbSeq => DNB_SEQ,
+            Self::Element => ELEMENT,
+            Self::Helicos => HELICOS,
+            Self::Illumina => ILLUMINA,
+            Self::IonTorrent => ION_TORRENT,
+            Self::Ls454 => LS454,
+            Self::Ont => ONT,
+            Self::PacBio => PAC_BIO,
+            Self::Singular => SINGULAR,
+            Self::Solid => SOLID,
+            Self::Ultima => ULTIMA,
+        }
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // SAFETY: All platform names are ASCII.
+        f.write_str(std::str::from_utf8(self.as_ref()).unwrap())
+    }
+}
+
+/// An error returned when a raw SAM header read group platform fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The input is invalid.
+    Invalid,
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::Invalid => f.write_str("invalid input"),
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for Platform {
+    type Error = ParseError;
+
+    /// Parses a platform name.
+    ///
+    /// Platform names are case-insensitive.
+    fn try_from(src: &[u8]) -> Result<Self, Self::Error> {
+        const PLATFORMS: [Platform; 12] = [
+            Platform::Capillary,
+            Platform::DnbSeq,
+            Platform::Element,
+            Platform::Helicos,
+            Platform::Illumina,
+            Platform::IonTorrent,
+            Platform::Ls454,
+            Platform::Ont,
+            Platform::PacBio,
+            Platform::Singular,
+            Platform::Solid,
+            Platform::Ultima,
+        ];
+
+        if src.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        PLATFORMS
+            .into_iter()
+            .find(|platform| platform.as_ref().eq_ignore_ascii_case(src))
+            .ok_or(ParseError::Invalid)
+    }
+}
+
+impl FromStr for Platform {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Platform::Illumina.to_string(), "ILLUMINA");
+        assert_eq!(Platform::PacBio.to_string(), "PACBIO");
+    }
+
+    #[test]
+    fn test_try_from_u8_slice_for_platform() {
+        assert_eq!(Platform::try_from(&b"ILLUMINA"[..]), Ok(Platform::Illumina));
+        assert_eq!(Platform::try_from(&b"illumina"[..]), Ok(Platform::Illumina));
+        assert_eq!(Platform::try_from(&b"ONT"[..]), Ok(Platform::Ont));
+        assert_eq!(Platform::try_from(&b"DNBSEQ"[..]), Ok(Platform::DnbSeq));
+
+        assert_eq!(Platform::try_from(&b""[..]), Err(ParseError::Empty));
+        assert_eq!(
+            Platform::try_from(&b"NOODLES"[..]),
+            Err(ParseError::Invalid)
+        );
+    }
+}
@@ -0,0 +1,230 @@
+//! SAM header read group platform unit.
+
+use std::{error, fmt, num, str::FromStr};
+
+use bstr::{BStr, BString, ByteSlice};
+
+const DELIMITER: u8 = b'.';
+
+/// A SAM header read group platform unit (`PU`) split into its components.
+///
+/// The SAM specification does not define a format for the platform unit, but it is
+/// conventionally `{flowcell}.{lane}[.{barcode}]`, e.g., `HXXXXXXXX.1.ACGTACGT`. This is the
+/// format, e.g., used by GATK and Picard to identify the flowcell lane for optical duplicate
+/// detection.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PlatformUnit {
+    flowcell: BString,
+    lane: u32,
+    barcode: Option<BString>,
+}
+
+impl PlatformUnit {
+    /// Creates a platform unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::map::read_group::PlatformUnit;
+    /// let platform_unit = PlatformUnit::new("HXXXXXXXX", 1, Some("ACGTACGT".into()));
+    /// ```
+    pub fn new<F>(flowcell: F, lane: u32, barcode: Option<BString>) -> Self
+    where
+        F: Into<BString>,
+    {
+        Self {
+            flowcell: flowcell.into(),
+            lane,
+            barcode,
+        }
+    }
+
+    /// Returns the flowcell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::map::read_group::PlatformUnit;
+    /// let platform_unit = PlatformUnit::new("HXXXXXXXX", 1, None);
+    /// assert_eq!(platform_unit.flowcell(), "HXXXXXXXX");
+    /// ```
+    pub fn flowcell(&self) -> &BStr {
+        self.flowcell.as_bstr()
+    }
+
+    /// Returns the lane.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::map::read_group::PlatformUnit;
+    /// let platform_unit = PlatformUnit::new("HXXXXXXXX", 1, None);
+    /// assert_eq!(platform_unit.lane(), 1);
+    /// ```
+    pub fn lane(&self) -> u32 {
+        self.lane
+    }
+
+    /// Returns the barcode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::map::read_group::PlatformUnit;
+    /// let platform_unit = PlatformUnit::new("HXXXXXXXX", 1, Some("ACGTACGT".into()));
+    /// assert_eq!(platform_unit.barcode(), Some("ACGTACGT".into()));
+    /// ```
+    pub fn barcode(&self) -> Option<&BStr> {
+        self.barcode.as_ref().map(|barcode| barcode.as_bstr())
+    }
+}
+
+impl fmt::Display for PlatformUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.flowcell, self.lane)?;
+
+        if let Some(barcode) = &self.barcode {
+            write!(f, ".{barcode}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned when a raw SAM header read group platform unit fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The flowcell is missing.
+    MissingFlowcell,
+    /// The lane is missing.
+    MissingLane,
+    /// The lane is invalid.
+    InvalidLane(num::ParseIntError),
+    /// The barcode is missing.
+    MissingBarcode,
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidLane(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::MissingFlowcell => f.write_str("missing flowcell"),
+            Self::MissingLane => f.write_str("missing lane"),
+            Self::InvalidLane(_) => f.write_str("invalid lane"),
+            Self::MissingBarcode => f.write_str("missing barcode"),
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for PlatformUnit {
+    type Error = ParseError;
+
+    /// Parses a platform unit.
+    ///
+    /// Everything after the lane is the barcode, which can itself contain delimiters.
+    fn try_from(src: &[u8]) -> Result<Self, Self::Error> {
+        if src.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let mut components = src.splitn(3, |&b| b == DELIMITER);
+
+        let flowcell = components
+            .next()
+            .filter(|s| !s.is_empty())
+            .ok_or(ParseError::MissingFlowcell)?;
+
+        let lane = components
+            .next()
+            .filter(|s| !s.is_empty())
+            .ok_or(ParseError::MissingLane)?;
+
+        let lane = lane
+            .to_str_lossy()
+            .parse()
+            .map_err(ParseError::InvalidLane)?;
+
+        let barcode = match components.next() {
+            Some([]) => return Err(ParseError::MissingBarcode),
+            Some(s) => Some(s.into()),
+            None => None,
+        };
+
+        Ok(Self {
+            flowcell: flowcell.into(),
+            lane,
+            barcode,
+        })
+    }
+}
+
+impl FromStr for PlatformUnit {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        let platform_unit = PlatformUnit::new("HXXXXXXXX", 1, None);
+        assert_eq!(platform_unit.to_string(), "HXXXXXXXX.1");
+
+        let platform_unit = PlatformUnit::new("HXXXXXXXX", 2, Some("ACGT-TTGA".into()));
+        assert_eq!(platform_unit.to_string(), "HXXXXXXXX.2.ACGT-TTGA");
+    }
+
+    #[test]
+    fn test_try_from_u8_slice_for_platform_unit() {
+        assert_eq!(
+            PlatformUnit::try_from(&b"HXXXXXXXX.1"[..]),
+            Ok(PlatformUnit::new("HXXXXXXXX", 1, None))
+        );
+        assert_eq!(
+            PlatformUnit::try_from(&b"HXXXXXXXX.2.ACGT-TTGA"[..]),
+            Ok(PlatformUnit::new("HXXXXXXXX", 2, Some("ACGT-TTGA".into())))
+        );
+        assert_eq!(
+            PlatformUnit::try_from(&b"HXXXXXXXX.2.ACGT.TTGA"[..]),
+            Ok(PlatformUnit::new("HXXXXXXXX", 2, Some("ACGT.TTGA".into())))
+        );
+
+        assert_eq!(PlatformUnit::try_from(&b""[..]), Err(ParseError::Empty));
+        assert_eq!(
+            PlatformUnit::try_from(&b".1"[..]),
+            Err(ParseError::MissingFlowcell)
+        );
+        assert_eq!(
+            PlatformUnit::try_from(&b"HXXXXXXXX"[..]),
+            Err(ParseError::MissingLane)
+        );
+        assert_eq!(
+            PlatformUnit::try_from(&b"HXXXXXXXX."[..]),
+            Err(ParseError::MissingLane)
+        );
+        assert!(matches!(
+            PlatformUnit::try_from(&b"HXXXXXXXX.one"[..]),
+            Err(ParseError::InvalidLane(_))
+        ));
+        assert_eq!(
+            PlatformUnit::try_from(&b"HXXXXXXXX.1."[..]),
+            Err(ParseError::MissingBarcode)
+        );
+    }
+}
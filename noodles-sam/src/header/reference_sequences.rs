@@ -0,0 +1,434 @@
+use std::{io, ops::Deref};
+
+use bstr::{BStr, BString};
+use indexmap::IndexMap;
+use noodles_core::Assembly;
+
+use super::record::value::{
+    map::{reference_sequence::tag, ReferenceSequence},
+    Map,
+};
+
+type Inner = IndexMap<BString, Map<ReferenceSequence>>;
+
+/// A reference sequence dictionary.
+///
+/// This is an ordered map of reference sequence names to reference sequence records. The order is
+/// the insertion order, and the position of a reference sequence in the dictionary is its
+/// reference sequence ID, as used by, e.g., BAM and CRAM records. Names are unique.
+///
+/// Lookups by name, including name to ID, are O(1).
+///
+/// Read-only access to the inner map is available through [`Deref`].
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroUsize;
+///
+/// use noodles_sam::header::{
+///     record::value::{map::ReferenceSequence, Map},
+///     ReferenceSequences,
+/// };
+///
+/// let mut reference_sequences = ReferenceSequences::new();
+///
+/// let sq0 = Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?);
+/// assert_eq!(reference_sequences.insert("sq0", sq0.clone())?, 0);
+///
+/// let sq1 = Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?);
+/// assert_eq!(reference_sequences.insert("sq1", sq1)?, 1);
+///
+/// assert_eq!(reference_sequences.get_index_of(&b"sq1"[..]), Some(1));
+/// assert!(reference_sequences.insert("sq0", sq0).is_err());
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ReferenceSequences(Inner);
+
+impl ReferenceSequences {
+    /// Creates an empty reference sequence dictionary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::ReferenceSequences;
+    /// let reference_sequences = ReferenceSequences::new();
+    /// assert!(reference_sequences.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty reference sequence dictionary with at least the given capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::ReferenceSequences;
+    /// let reference_sequences = ReferenceSequences::with_capacity(2);
+    /// assert!(reference_sequences.capacity() >= 2);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(Inner::with_capacity(capacity))
+    }
+
+    /// Adds a reference sequence to the end of the dictionary.
+    ///
+    /// This returns the reference sequence ID of the new reference sequence. If a reference
+    /// sequence with the same name already exists, the dictionary is not modified, and an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_sam::header::{
+    ///     record::value::{map::ReferenceSequence, Map},
+    ///     ReferenceSequences,
+    /// };
+    ///
+    /// let mut reference_sequences = ReferenceSequences::new();
+    ///
+    /// let sq0 = Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?);
+    /// assert_eq!(reference_sequences.insert("sq0", sq0.clone())?, 0);
+    /// assert!(reference_sequences.insert("sq0", sq0).is_err());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn insert<N>(
+        &mut self,
+        name: N,
+        reference_sequence: Map<ReferenceSequence>,
+    ) -> io::Result<usize>
+    where
+        N: Into<BString>,
+    {
+        use indexmap::map::Entry;
+
+        match self.0.entry(name.into()) {
+            Entry::Vacant(entry) => {
+                let i = entry.index();
+                entry.insert(reference_sequence);
+                Ok(i)
+            }
+            Entry::Occupied(entry) => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("duplicate reference sequence name: {}", entry.key()),
+            )),
+        }
+    }
+
+    /// Returns the reference sequence ID of the reference sequence with the given name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_sam::header::{
+    ///     record::value::{map::ReferenceSequence, Map},
+    ///     ReferenceSequences,
+    /// };
+    ///
+    /// let mut reference_sequences = ReferenceSequences::new();
+    /// reference_sequences.insert("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::MIN))?;
+    ///
+    /// assert_eq!(reference_sequences.get_index_of(&b"sq0"[..]), Some(0));
+    /// assert!(reference_sequences.get_index_of(&b"sq1"[..]).is_none());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn get_index_of<N>(&self, name: &N) -> Option<usize>
+    where
+        N: AsRef<[u8]> + ?Sized,
+    {
+        self.0.get_index_of(BStr::new(name.as_ref()))
+    }
+
+    /// Returns a mutable reference to the reference sequence with the given name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_sam::header::{
+    ///     record::value::{map::ReferenceSequence, Map},
+    ///     ReferenceSequences,
+    /// };
+    ///
+    /// let mut reference_sequences = ReferenceSequences::new();
+    /// reference_sequences.insert("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::MIN))?;
+    ///
+    /// assert!(reference_sequences.get_mut(&b"sq0"[..]).is_some());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn get_mut<N>(&mut self, name: &N) -> Option<&mut Map<ReferenceSequence>>
+    where
+        N: AsRef<[u8]> + ?Sized,
+    {
+        self.0.get_mut(BStr::new(name.as_ref()))
+    }
+
+    /// Returns an iterator over mutable references to the reference sequences.
+    ///
+    /// Names and order cannot be changed through this iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::ReferenceSequences;
+    /// let mut reference_sequences = ReferenceSequences::new();
+    /// assert!(reference_sequences.iter_mut().next().is_none());
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&BString, &mut Map<ReferenceSequence>)> {
+        self.0.iter_mut()
+    }
+
+    /// Returns the genome assembly of the reference sequences.
+    ///
+    /// This is the first assembly ID (`AS`) of the reference sequences. If no reference sequence
+    /// has an assembly ID, the assembly is inferred from the reference sequence names, lengths,
+    /// and MD5 checksums (`M5`) (see [`Assembly::infer`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_core::Assembly;
+    /// use noodles_sam::header::{
+    ///     record::value::{map::ReferenceSequence, Map},
+    ///     ReferenceSequences,
+    /// };
+    ///
+    /// let mut reference_sequences = ReferenceSequences::new();
+    /// reference_sequences.insert(
+    ///     "chr1",
+    ///     Map::<ReferenceSequence>::new(NonZeroUsize::try_from(248956422)?),
+    /// )?;
+    ///
+    /// assert_eq!(
+    ///     reference_sequences.assembly(),
+    ///     Some(Assembly::new("GRCh38", None))
+    /// );
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn assembly(&self) -> Option<Assembly> {
+        let assembly_id = self.0.values().find_map(|reference_sequence| {
+            reference_sequence.other_fields().get(&tag::ASSEMBLY_ID)
+        });
+
+        if let Some(assembly_id) = assembly_id {
+            return std::str::from_utf8(assembly_id).ok()?.parse().ok();
+        }
+
+        Assembly::infer(self.0.iter().map(|(name, reference_sequence)| {
+            (
+                name.as_slice(),
+                reference_sequence.length().get(),
+                reference_sequence
+                    .other_fields()
+                    .get(&tag::MD5_CHECKSUM)
+                    .map(|md5| md5.as_slice()),
+            )
+        }))
+    }
+
+    /// Sets the genome assembly of all reference sequences.
+    ///
+    /// This sets the assembly ID (`AS`) of each reference sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_core::Assembly;
+    /// use noodles_sam::header::{
+    ///     record::value::{map::ReferenceSequence, Map},
+    ///     ReferenceSequences,
+    /// };
+    ///
+    /// let mut reference_sequences = ReferenceSequences::new();
+    /// reference_sequences.insert("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::MIN))?;
+    ///
+    /// let assembly = Assembly::new("GRCh38", Some(14));
+    /// reference_sequences.set_assembly(&assembly);
+    ///
+    /// assert_eq!(reference_sequences.assembly(), Some(assembly));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn set_assembly(&mut self, assembly: &Assembly) {
+        let assembly_id = BString::from(assembly.to_string());
+
+        for reference_sequence in self.0.values_mut() {
+            reference_sequence
+                .other_fields_mut()
+                .insert(tag::ASSEMBLY_ID, assembly_id.clone());
+        }
+    }
+
+    /// Removes all reference sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_sam::header::{
+    ///     record::value::{map::ReferenceSequence, Map},
+    ///     ReferenceSequences,
+    /// };
+    ///
+    /// let mut reference_sequences = ReferenceSequences::new();
+    /// reference_sequences.insert("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::MIN))?;
+    /// reference_sequences.clear();
+    ///
+    /// assert!(reference_sequences.is_empty());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+}
+
+impl Deref for ReferenceSequences {
+    type Target = Inner;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<Inner> for ReferenceSequences {
+    fn as_ref(&self) -> &Inner {
+        &self.0
+    }
+}
+
+impl AsMut<Inner> for ReferenceSequences {
+    fn as_mut(&mut self) -> &mut Inner {
+        &mut self.0
+    }
+}
+
+impl From<Inner> for ReferenceSequences {
+    fn from(inner: Inner) -> Self {
+        Self(inner)
+    }
+}
+
+impl From<ReferenceSequences> for Inner {
+    fn from(reference_sequences: ReferenceSequences) -> Self {
+        reference_sequences.0
+    }
+}
+
+impl FromIterator<(BString, Map<ReferenceSequence>)> for ReferenceSequences {
+    /// Creates a reference sequence dictionary from an iterator of names and reference sequences.
+    ///
+    /// Later reference sequences replace earlier ones with the same name, keeping the position of
+    /// the first.
+    fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<Item = (BString, Map<ReferenceSequence>)>,
+    {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for ReferenceSequences {
+    type Item = (BString, Map<ReferenceSequence>);
+    type IntoIter = indexmap::map::IntoIter<BString, Map<ReferenceSequence>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ReferenceSequences {
+    type Item = (&'a BString, &'a Map<ReferenceSequence>);
+    type IntoIter = indexmap::map::Iter<'a, BString, Map<ReferenceSequence>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut ReferenceSequences {
+    type Item = (&'a BString, &'a mut Map<ReferenceSequence>);
+    type IntoIter = indexmap::map::IterMut<'a, BString, Map<ReferenceSequence>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use super::*;
+
+    #[test]
+    fn test_insert() -> io::Result<()> {
+        let mut reference_sequences = ReferenceSequences::new();
+
+        let sq0 = Map::<ReferenceSequence>::new(NonZeroUsize::MIN);
+        let sq1 = Map::<ReferenceSequence>::new(NonZeroUsize::MAX);
+
+        assert_eq!(reference_sequences.insert("sq0", sq0.clone())?, 0);
+        assert_eq!(reference_sequences.insert("sq1", sq1.clone())?, 1);
+
+        assert!(matches!(
+            reference_sequences.insert("sq0", sq1.clone()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let actual: Vec<_> = reference_sequences.iter().collect();
+        let expected = [(&BString::from("sq0"), &sq0), (&BString::from("sq1"), &sq1)];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_index_of() -> io::Result<()> {
+        let mut reference_sequences = ReferenceSequences::new();
+        reference_sequences.insert("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::MIN))?;
+        reference_sequences.insert("sq1", Map::<ReferenceSequence>::new(NonZeroUsize::MIN))?;
+
+        assert_eq!(reference_sequences.get_index_of("sq1"), Some(1));
+        assert_eq!(reference_sequences.get_index_of(&b"sq0"[..]), Some(0));
+        assert!(reference_sequences.get_index_of("sq2").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_assembly() -> Result<(), Box<dyn std::error::Error>> {
+        let mut reference_sequences = ReferenceSequences::new();
+        assert!(reference_sequences.assembly().is_none());
+
+        let mut sq0 = Map::<ReferenceSequence>::new(NonZeroUsize::try_from(249250621)?);
+        sq0.other_fields_mut().insert(
+            tag::MD5_CHECKSUM,
+            BString::from("1b22b98cdeb4a9304cb5d48026a85128"),
+        );
+        reference_sequences.insert("1", sq0)?;
+
+        assert_eq!(
+            reference_sequences.assembly(),
+            Some(Assembly::new("GRCh37", None))
+        );
+
+        reference_sequences.set_assembly(&Assembly::new("hg19", None));
+        assert_eq!(
+            reference_sequences.assembly(),
+            Some(Assembly::new("hg19", None))
+        );
+
+        Ok(())
+    }
+}
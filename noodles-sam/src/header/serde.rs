@@ -0,0 +1,325 @@
+//! SAM header (de)serialization.
+//!
+//! A header is represented structurally by its record groups. Each record is a map of field tags
+//! to raw values, in the order they are written, e.g.,
+//!
+//! ```json
+//! {
+//!   "header": { "VN": "1.6", "SO": "coordinate" },
+//!   "reference_sequences": [{ "SN": "sq0", "LN": "8" }],
+//!   "read_groups": [],
+//!   "programs": [],
+//!   "comments": ["noodles"]
+//! }
+//! ```
+
+use std::{fmt, str};
+
+use serde::{
+    de::{self, MapAccess, Visitor},
+    ser::{self, SerializeMap},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use super::Header;
+use crate::io::Writer;
+
+const PREFIX: &str = "@";
+const DELIMITER: char = '\t';
+const SEPARATOR: char = ':';
+
+const HEADER: &str = "HD";
+const REFERENCE_SEQUENCE: &str = "SQ";
+const READ_GROUP: &str = "RG";
+const PROGRAM: &str = "PG";
+const COMMENT: &str = "CO";
+
+#[derive(Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "Header")]
+struct Repr {
+    header: Option<Fields>,
+    reference_sequences: Vec<Fields>,
+    read_groups: Vec<Fields>,
+    programs: Vec<Fields>,
+    comments: Vec<String>,
+}
+
+impl Repr {
+    fn from_raw_header(s: &str) -> Result<Self, String> {
+        let mut repr = Self::default();
+
+        for line in s.lines() {
+            let (kind, rest) = line
+                .strip_prefix(PREFIX)
+                .and_then(|t| t.get(..2).map(|kind| (kind, &t[2..])))
+                .ok_or_else(|| format!("invalid header record: {line}"))?;
+
+            let rest = rest.strip_prefix(DELIMITER).unwrap_or(rest);
+
+            if kind == COMMENT {
+                repr.comments.push(rest.into());
+                continue;
+            }
+
+            let fields = Fields::parse(rest)?;
+
+            match kind {
+                HEADER => repr.header = Some(fields),
+                REFERENCE_SEQUENCE => repr.reference_sequences.push(fields),
+                READ_GROUP => repr.read_groups.push(fields),
+                PROGRAM => repr.programs.push(fields),
+                _ => return Err(format!("invalid header record kind: {kind}")),
+            }
+        }
+
+        Ok(repr)
+    }
+
+    fn to_raw_header(&self) -> Result<String, String> {
+        let mut s = String::new();
+
+        if let Some(fields) = &self.header {
+            fields.write(&mut s, HEADER)?;
+        }
+
+        for (kind, records) in [
+            (REFERENCE_SEQUENCE, &self.reference_sequences),
+            (READ_GROUP, &self.read_groups),
+            (PROGRAM, &self.programs),
+        ] {
+            for fields in records {
+                fields.write(&mut s, kind)?;
+            }
+        }
+
+        for comment in &self.comments {
+            validate_value(comment)?;
+            s.push_str(PREFIX);
+            s.push_str(COMMENT);
+            s.push(DELIMITER);
+            s.push_str(comment);
+            s.push('\n');
+        }
+
+        Ok(s)
+    }
+}
+
+#[derive(Debug, Default, Eq, PartialEq)]
+struct Fields(Vec<(String, String)>);
+
+impl Fields {
+    fn parse(s: &str) -> Result<Self, String> {
+        if s.is_empty() {
+            return Ok(Self::default());
+        }
+
+        s.split(DELIMITER)
+            .map(|field| {
+                field
+                    .split_once(SEPARATOR)
+                    .map(|(tag, value)| (tag.into(), value.into()))
+                    .ok_or_else(|| format!("invalid header record field: {field}"))
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+
+    fn write(&self, s: &mut String, kind: &str) -> Result<(), String> {
+        s.push_str(PREFIX);
+        s.push_str(kind);
+
+        for (tag, value) in &self.0 {
+            validate_value(tag)?;
+            validate_value(value)?;
+
+            s.push(DELIMITER);
+            s.push_str(tag);
+            s.push(SEPARATOR);
+            s.push_str(value);
+        }
+
+        s.push('\n');
+
+        Ok(())
+    }
+}
+
+impl Serialize for Fields {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+
+        for (tag, value) in &self.0 {
+            map.serialize_entry(tag, value)?;
+        }
+
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Fields {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct FieldsVisitor;
+
+        impl<'de> Visitor<'de> for FieldsVisitor {
+            type Value = Fields;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a map of SAM header record fields")
+            }
+
+            fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut fields = Vec::with_capacity(access.size_hint().unwrap_or(0));
+
+                while let Some(field) = access.next_entry()? {
+                    fields.push(field);
+                }
+
+                Ok(Fields(fields))
+            }
+        }
+
+        deserializer.deserialize_map(FieldsVisitor)
+    }
+}
+
+fn validate_value(s: &str) -> Result<(), String> {
+    if s.contains(['\t', '\n', '\r']) {
+        Err(format!("invalid header record value: {s:?}"))
+    } else {
+        Ok(())
+    }
+}
+
+impl Serialize for Header {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(self).map_err(ser::Error::custom)?;
+
+        let s = str::from_utf8(writer.get_ref()).map_err(ser::Error::custom)?;
+        let repr = Repr::from_raw_header(s).map_err(ser::Error::custom)?;
+
+        repr.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Header {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let repr = Repr::deserialize(deserializer)?;
+        let s = repr.to_raw_header().map_err(de::Error::custom)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    use super::*;
+
+    #[test]
+    fn test_serde() -> Result<(), Box<dyn std::error::Error>> {
+        let header: Header = "\
+@HD\tVN:1.6\tSO:coordinate
+@SQ\tSN:sq0\tLN:8
+@RG\tID:rg0
+@PG\tID:pg0\tPN:noodles
+@CO\tndls
+"
+        .parse()?;
+
+        assert_tokens(
+            &header,
+            &[
+                Token::Struct {
+                    name: "Header",
+                    len: 5,
+                },
+                Token::Str("header"),
+                Token::Some,
+                Token::Map { len: Some(2) },
+                Token::Str("VN"),
+                Token::Str("1.6"),
+                Token::Str("SO"),
+                Token::Str("coordinate"),
+                Token::MapEnd,
+                Token::Str("reference_sequences"),
+                Token::Seq { len: Some(1) },
+                Token::Map { len: Some(2) },
+                Token::Str("SN"),
+                Token::Str("sq0"),
+                Token::Str("LN"),
+                Token::Str("8"),
+                Token::MapEnd,
+                Token::SeqEnd,
+                Token::Str("read_groups"),
+                Token::Seq { len: Some(1) },
+                Token::Map { len: Some(1) },
+                Token::Str("ID"),
+                Token::Str("rg0"),
+                Token::MapEnd,
+                Token::SeqEnd,
+                Token::Str("programs"),
+                Token::Seq { len: Some(1) },
+                Token::Map { len: Some(2) },
+                Token::Str("ID"),
+                Token::Str("pg0"),
+                Token::Str("PN"),
+                Token::Str("noodles"),
+                Token::MapEnd,
+                Token::SeqEnd,
+                Token::Str("comments"),
+                Token::Seq { len: Some(1) },
+                Token::Str("ndls"),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_deserialize_with_invalid_value() {
+        assert_de_tokens_error::<Header>(
+            &[
+                Token::Struct {
+                    name: "Header",
+                    len: 5,
+                },
+                Token::Str("header"),
+                Token::None,
+                Token::Str("reference_sequences"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("read_groups"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("programs"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::Str("comments"),
+                Token::Seq { len: Some(1) },
+                Token::Str("nd\tls"),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+            "invalid header record value: \"nd\\tls\"",
+        );
+    }
+}
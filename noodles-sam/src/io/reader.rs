@@ -2,10 +2,12 @@
 
 mod builder;
 mod header;
+mod lending_records;
 mod query;
 mod record;
 pub(crate) mod record_buf;
 mod record_bufs;
+mod validation_level;
 
 use std::{
     io::{self, BufRead},
@@ -17,7 +19,10 @@ use noodles_core::Region;
 use noodles_csi::BinningIndex;
 
 pub(crate) use self::record::read_record;
-pub use self::{builder::Builder, record_bufs::RecordBufs};
+pub use self::{
+    builder::Builder, lending_records::LendingRecords, record_bufs::RecordBufs,
+    validation_level::ValidationLevel,
+};
 use self::{header::read_header, query::Query, record_buf::read_record_buf};
 use crate::{alignment::RecordBuf, header::ReferenceSequences, Header, Record};
 
@@ -48,6 +53,7 @@ use crate::{alignment::RecordBuf, header::ReferenceSequences, Header, Record};
 pub struct Reader<R> {
     inner: R,
     buf: Vec<u8>,
+    validation_level: ValidationLevel,
 }
 
 impl<R> Reader<R> {
@@ -181,7 +187,13 @@ where
         header: &Header,
         record: &mut RecordBuf,
     ) -> io::Result<usize> {
-        read_record_buf(&mut self.inner, &mut self.buf, header, record)
+        read_record_buf(
+            &mut self.inner,
+            &mut self.buf,
+            header,
+            record,
+            self.validation_level,
+        )
     }
 
     /// Returns an iterator over alignment record buffers starting from the current stream
@@ -274,6 +286,40 @@ where
             Err(e) => Some(Err(e)),
         })
     }
+
+    /// Returns a lending iterator over records.
+    ///
+    /// This reuses a single record buffer, avoiding an allocation per record. See
+    /// [`LendingRecords`].
+    ///
+    /// The stream is expected to be directly after the header or at the start of another record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    ///
+    /// let data = b"@HD\tVN:1.6
+    /// r0\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*
+    /// r1\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*
+    /// ";
+    ///
+    /// let mut reader = sam::io::Reader::new(&data[..]);
+    /// reader.read_header()?;
+    ///
+    /// let mut records = reader.lending_records();
+    /// let mut names = Vec::new();
+    ///
+    /// while let Some(record) = records.next_record().transpose()? {
+    ///     names.extend(record.name().map(|name| name.to_vec()));
+    /// }
+    ///
+    /// assert_eq!(names, [b"r0", b"r1"]);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn lending_records(&mut self) -> LendingRecords<'_, R> {
+        LendingRecords::new(self)
+    }
 }
 
 impl<R> Reader<R>
@@ -404,6 +450,7 @@ where
         Self {
             inner,
             buf: Vec::new(),
+            validation_level: ValidationLevel::default(),
         }
     }
 }
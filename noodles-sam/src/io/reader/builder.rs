@@ -6,13 +6,14 @@ use std::{
 
 use noodles_bgzf as bgzf;
 
-use super::Reader;
+use super::{Reader, ValidationLevel};
 use crate::io::CompressionMethod;
 
 /// A SAM reader builder.
 #[derive(Debug, Default)]
 pub struct Builder {
     compression_method: Option<CompressionMethod>,
+    validation_level: ValidationLevel,
 }
 
 impl Builder {
@@ -29,6 +30,21 @@ impl Builder {
         self
     }
 
+    /// Sets the validation level.
+    ///
+    /// By default, records are parsed strictly ([`ValidationLevel::Strict`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::io::reader::{Builder, ValidationLevel};
+    /// let builder = Builder::default().set_validation_level(ValidationLevel::Lenient);
+    /// ```
+    pub fn set_validation_level(mut self, validation_level: ValidationLevel) -> Self {
+        self.validation_level = validation_level;
+        self
+    }
+
     /// Builds a SAM reader from a path.
     ///
     /// By default, the compression method will be autodetected. This can be overridden by using
@@ -77,6 +93,9 @@ impl Builder {
             Some(CompressionMethod::None) | None => Box::new(BufReader::new(reader)),
         };
 
-        Ok(Reader::new(inner))
+        let mut reader = Reader::new(inner);
+        reader.validation_level = self.validation_level;
+
+        Ok(reader)
     }
 }
@@ -107,4 +107,38 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_header_with_long_values() -> io::Result<()> {
+        use std::io::BufReader;
+
+        use crate::header::record::value::map::program::tag as program_tag;
+
+        let command_line = format!("bwa mem {}", "-v ".repeat(1 << 16));
+        let comment = "n".repeat(1 << 20);
+        let data = format!("@HD\tVN:1.6\n@PG\tID:bwa\tCL:{command_line}\n@CO\t{comment}\n");
+
+        let mut reader = BufReader::with_capacity(16, data.as_bytes());
+        let header = read_header(&mut reader)?;
+
+        let program = header.programs().as_ref().get(&b"bwa"[..]).unwrap();
+        assert_eq!(
+            program.other_fields().get(&program_tag::COMMAND_LINE),
+            Some(&command_line.clone().into())
+        );
+        assert_eq!(
+            program
+                .command_line_args()
+                .map(|r| r.map(|args| args.len())),
+            Some(Ok((1 << 16) + 2))
+        );
+
+        assert_eq!(header.comments(), [bstr::BString::from(comment)]);
+
+        let mut writer = crate::io::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        assert_eq!(writer.get_ref(), data.as_bytes());
+
+        Ok(())
+    }
 }
@@ -10,7 +10,9 @@ mod sequence;
 mod template_length;
 
 pub(crate) use self::{
-    cigar::parse_cigar, flags::parse_flags, template_length::parse_template_length,
+    cigar::{parse_cigar, parse_cigar_lenient},
+    flags::parse_flags,
+    template_length::parse_template_length,
 };
 
 use std::{
@@ -23,7 +25,7 @@ use self::{
     position::parse_alignment_start, quality_scores::parse_quality_scores,
     reference_sequence_id::parse_reference_sequence_id, sequence::parse_sequence,
 };
-use super::read_line;
+use super::{read_line, ValidationLevel};
 use crate::{alignment::RecordBuf, Header};
 
 pub fn read_record_buf<R>(
@@ -31,6 +33,7 @@ pub fn read_record_buf<R>(
     buf: &mut Vec<u8>,
     header: &Header,
     record: &mut RecordBuf,
+    validation_level: ValidationLevel,
 ) -> io::Result<usize>
 where
     R: BufRead,
@@ -40,7 +43,7 @@ where
     match read_line(reader, buf)? {
         0 => Ok(0),
         n => {
-            parse_record_buf(buf, header, record)
+            parse_record_buf(buf, header, record, validation_level)
                 .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
             Ok(n)
@@ -122,6 +125,7 @@ pub(crate) fn parse_record_buf(
     mut src: &[u8],
     header: &Header,
     record: &mut RecordBuf,
+    validation_level: ValidationLevel,
 ) -> Result<(), ParseError> {
     const MISSING: &[u8] = b"*";
 
@@ -157,7 +161,11 @@ pub(crate) fn parse_record_buf(
     record.cigar_mut().as_mut().clear();
     let field = next_field(&mut src);
     if field != MISSING {
-        parse_cigar(field, record.cigar_mut()).map_err(ParseError::InvalidCigar)?;
+        match validation_level {
+            ValidationLevel::Strict => parse_cigar(field, record.cigar_mut()),
+            ValidationLevel::Lenient => parse_cigar_lenient(field, record.cigar_mut()),
+        }
+        .map_err(ParseError::InvalidCigar)?;
     }
 
     *record.mate_reference_sequence_id_mut() = match next_field(&mut src) {
@@ -237,7 +245,7 @@ mod tests {
         let header = Header::default();
         let s = b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\tNH:i:1\tCO:Z:ndls";
         let mut record = RecordBuf::default();
-        parse_record_buf(s, &header, &mut record)?;
+        parse_record_buf(s, &header, &mut record, ValidationLevel::Strict)?;
 
         let expected = RecordBuf::builder()
             .set_data(
@@ -255,6 +263,31 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_with_validation_level() -> Result<(), ParseError> {
+        use crate::alignment::record::cigar::{op::Kind, Op};
+
+        let header = Header::default();
+        let s = b"*\t4\t*\t0\t255\t8m 13n\t*\t0\t0\t*\t*";
+        let mut record = RecordBuf::default();
+
+        assert!(matches!(
+            parse_record_buf(s, &header, &mut record, ValidationLevel::Strict),
+            Err(ParseError::InvalidCigar(_))
+        ));
+
+        parse_record_buf(s, &header, &mut record, ValidationLevel::Lenient)?;
+
+        assert_eq!(
+            record.cigar(),
+            &[Op::new(Kind::Match, 8), Op::new(Kind::Skip, 13)]
+                .into_iter()
+                .collect()
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_parse_mate_reference_sequence_id() {
         use crate::header::record::value::{map::ReferenceSequence, Map};
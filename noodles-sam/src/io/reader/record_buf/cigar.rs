@@ -47,6 +47,20 @@ pub(crate) fn parse_cigar(mut src: &[u8], cigar: &mut Cigar) -> Result<(), Parse
     Ok(())
 }
 
+/// Parses a CIGAR, accepting lowercase op codes and whitespace.
+///
+/// Whitespace is removed and op codes are uppercased before parsing, i.e., `8m 13n` is
+/// normalized to `8M13N`.
+pub(crate) fn parse_cigar_lenient(src: &[u8], cigar: &mut Cigar) -> Result<(), ParseError> {
+    let normalized_src: Vec<u8> = src
+        .iter()
+        .filter(|b| !b.is_ascii_whitespace())
+        .map(|b| b.to_ascii_uppercase())
+        .collect();
+
+    parse_cigar(&normalized_src, cigar)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -83,6 +97,11 @@ mod tests {
         assert_eq!(parse_cigar(b"", &mut cigar), Err(ParseError::Empty));
 
         cigar.as_mut().clear();
+        assert!(matches!(
+            parse_cigar(b"8m", &mut cigar),
+            Err(ParseError::InvalidOp(_))
+        ));
+
         assert!(matches!(
             parse_cigar(b"8Z", &mut cigar),
             Err(ParseError::InvalidOp(_))
@@ -90,4 +109,31 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_cigar_lenient() -> Result<(), ParseError> {
+        use crate::alignment::record::cigar::{op::Kind, Op};
+
+        let expected: Cigar = [Op::new(Kind::Match, 8), Op::new(Kind::Skip, 13)]
+            .into_iter()
+            .collect();
+
+        let mut cigar = Cigar::default();
+
+        for src in [&b"8M13N"[..], b"8m13n", b"8M 13n", b" 8 M\t13N "] {
+            parse_cigar_lenient(src, &mut cigar)?;
+            assert_eq!(cigar, expected);
+        }
+
+        assert_eq!(
+            parse_cigar_lenient(b" ", &mut cigar),
+            Err(ParseError::Empty)
+        );
+        assert!(matches!(
+            parse_cigar_lenient(b"8z", &mut cigar),
+            Err(ParseError::InvalidOp(_))
+        ));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,14 @@
+/// A SAM reader validation level.
+///
+/// This controls how strictly fields are parsed when reading alignment record buffers.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ValidationLevel {
+    /// Fields must conform to the SAM specification.
+    #[default]
+    Strict,
+    /// Common, unambiguous deviations from the SAM specification are accepted and normalized.
+    ///
+    /// This currently allows lowercase CIGAR op codes and whitespace in the CIGAR field, which
+    /// some legacy tools emit.
+    Lenient,
+}
@@ -144,6 +144,40 @@ where
     pub fn write_record(&mut self, header: &Header, record: &Record) -> io::Result<()> {
         write_record(&mut self.inner, header, record)
     }
+
+    /// Writes an alignment record.
+    ///
+    /// This accepts any alignment record, e.g., an alignment record buffer or a lazy BAM or CRAM
+    /// record, without first converting it to an alignment record buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_sam::{self as sam, alignment::{Record, RecordBuf}};
+    ///
+    /// let mut writer = sam::io::Writer::new(Vec::new());
+    ///
+    /// let header = sam::Header::default();
+    ///
+    /// let record = RecordBuf::default();
+    /// writer.write_alignment_record(&header, &record)?;
+    ///
+    /// let record: Box<dyn Record> = Box::new(sam::Record::default());
+    /// writer.write_alignment_record(&header, &record)?;
+    ///
+    /// assert_eq!(
+    ///     writer.get_ref(),
+    ///     b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\n*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\n",
+    /// );
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn write_alignment_record<R>(&mut self, header: &Header, record: &R) -> io::Result<()>
+    where
+        R: crate::alignment::Record + ?Sized,
+    {
+        write_record(&mut self.inner, header, record)
+    }
 }
 
 impl<W> crate::alignment::io::Write for Writer<W>
@@ -159,10 +193,69 @@ where
         header: &Header,
         record: &dyn crate::alignment::Record,
     ) -> io::Result<()> {
-        write_record(&mut self.inner, header, record)
+        Writer::write_alignment_record(self, header, record)
     }
 
     fn finish(&mut self, _: &Header) -> io::Result<()> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alignment::{
+        record::data::field::Tag,
+        record_buf::data::field::{value::Array, Value},
+        RecordBuf,
+    };
+
+    #[test]
+    fn test_write_alignment_record_with_array_data_fields() -> io::Result<()> {
+        use crate::io::Reader;
+
+        // Integer arrays with values that fit in smaller subtypes keep their subtypes.
+        let arrays = [
+            Array::from(vec![-1i8, 0, 1]),
+            Array::from(vec![0u8, 1, 2]),
+            Array::from(vec![-1i16, 0, 1]),
+            Array::from(vec![0u16, 1, 2]),
+            Array::from(vec![-1i32, 0, 1]),
+            Array::from(vec![0u32, 1, 2]),
+            Array::from(vec![-1.5f32, 0.0, 1.5]),
+            Array::from(Vec::<i32>::new()),
+        ];
+
+        let data = arrays
+            .into_iter()
+            .zip(b'a'..)
+            .map(|(array, c)| (Tag::new(b'X', c), Value::Array(array)))
+            .collect();
+
+        let header = Header::default();
+        let expected = RecordBuf::builder().set_data(data).build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_alignment_record(&header, &expected)?;
+
+        assert_eq!(
+            writer.get_ref().as_slice(),
+            b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\t\
+              Xa:B:c,-1,0,1\tXb:B:C,0,1,2\tXc:B:s,-1,0,1\tXd:B:S,0,1,2\t\
+              Xe:B:i,-1,0,1\tXf:B:I,0,1,2\tXg:B:f,-1.5,0,1.5\tXh:B:i\n"
+        );
+
+        let mut reader = Reader::new(writer.get_ref().as_slice());
+        let mut actual = RecordBuf::default();
+        reader.read_record_buf(&header, &mut actual)?;
+        assert_eq!(actual.data(), expected.data());
+
+        let mut reader = Reader::new(writer.get_ref().as_slice());
+        let mut record = Record::default();
+        reader.read_record(&mut record)?;
+        let actual = RecordBuf::try_from_alignment_record(&header, &record)?;
+        assert_eq!(actual.data(), expected.data());
+
+        Ok(())
+    }
+}
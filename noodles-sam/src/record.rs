@@ -251,6 +251,54 @@ impl fmt::Debug for Record {
     }
 }
 
+impl fmt::Display for Record {
+    /// Formats the record as a SAM record line, without a trailing newline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// let record = sam::Record::try_from(&b"r0\t4\t*\t0\t255\t*\t*\t0\t0\tACGT\tNDLS\tNH:i:1\n"[..])?;
+    /// assert_eq!(record.to_string(), "r0\t4\t*\t0\t255\t*\t*\t0\t0\tACGT\tNDLS\tNH:i:1");
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use bstr::ByteSlice;
+
+        let Fields { buf, bounds } = &self.0;
+
+        let ranges = [
+            bounds.name_range(),
+            bounds.flags_range(),
+            bounds.reference_sequence_name_range(),
+            bounds.alignment_start_range(),
+            bounds.mapping_quality_range(),
+            bounds.cigar_range(),
+            bounds.mate_reference_sequence_name_range(),
+            bounds.mate_alignment_start_range(),
+            bounds.template_length_range(),
+            bounds.sequence_range(),
+            bounds.quality_scores_range(),
+        ];
+
+        for (i, range) in ranges.into_iter().enumerate() {
+            if i > 0 {
+                f.write_str("\t")?;
+            }
+
+            write!(f, "{}", buf[range].as_bstr())?;
+        }
+
+        let data = &buf[bounds.data_range()];
+
+        if !data.is_empty() {
+            write!(f, "\t{}", data.as_bstr())?;
+        }
+
+        Ok(())
+    }
+}
+
 impl TryFrom<&[u8]> for Record {
     type Error = io::Error;
 
@@ -319,3 +367,20 @@ impl crate::alignment::Record for Record {
         Box::new(self.data())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() -> io::Result<()> {
+        let record = Record::default();
+        assert_eq!(record.to_string(), "*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*");
+
+        let src = b"r0\t0\tsq0\t1\t60\t4M\t=\t5\t8\tACGT\tNDLS\tNH:i:1\tRG:Z:rg0";
+        let record = Record::try_from(&src[..])?;
+        assert_eq!(record.to_string().as_bytes(), src);
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use std::io;
+use std::{io, mem};
 
 use noodles_core::Position;
 use noodles_csi::{
@@ -8,6 +8,7 @@ use noodles_csi::{
         reference_sequence::{bin::Chunk, index::LinearIndex},
         Header,
     },
+    BinningIndex,
 };
 
 use crate::Index;
@@ -88,3 +89,58 @@ impl Indexer {
             .build(reference_sequence_count)
     }
 }
+
+impl From<Index> for Indexer {
+    /// Creates an indexer that resumes indexing from an existing index.
+    ///
+    /// Subsequent records must be positioned after the records in the given index.
+    fn from(index: Index) -> Self {
+        let mut header = index.header().cloned().unwrap_or_default();
+        let reference_sequence_names = mem::take(header.reference_sequence_names_mut());
+
+        Self {
+            header,
+            reference_sequence_names,
+            indexer: csi::binning_index::Indexer::from(index),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_bgzf as bgzf;
+
+    use super::*;
+
+    #[test]
+    fn test_from_index() -> Result<(), Box<dyn std::error::Error>> {
+        let start = Position::try_from(8)?;
+        let end = Position::try_from(13)?;
+
+        let chunks = [
+            Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(9),
+            ),
+            Chunk::new(
+                bgzf::VirtualPosition::from(9),
+                bgzf::VirtualPosition::from(18),
+            ),
+        ];
+
+        let mut indexer = Indexer::default();
+        indexer.add_record("sq0", start, end, chunks[0])?;
+        indexer.add_record("sq1", start, end, chunks[1])?;
+        let expected = indexer.build();
+
+        let mut indexer = Indexer::default();
+        indexer.add_record("sq0", start, end, chunks[0])?;
+        let index = indexer.build();
+
+        let mut indexer = Indexer::from(index);
+        indexer.add_record("sq1", start, end, chunks[1])?;
+        assert_eq!(indexer.build(), expected);
+
+        Ok(())
+    }
+}
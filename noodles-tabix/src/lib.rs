@@ -38,9 +38,15 @@ pub use self::{reader::Reader, writer::Writer};
 #[cfg(feature = "async")]
 pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
 
-use std::{fs::File, path::Path};
+use std::{fs::File, io::Read, path::Path};
 
-use noodles_csi::binning_index::{self, index::reference_sequence::index::LinearIndex};
+use noodles_csi::{
+    self as csi,
+    binning_index::{
+        self,
+        index::{reference_sequence::index::LinearIndex, Header},
+    },
+};
 
 static MAGIC_NUMBER: &[u8] = b"TBI\x01";
 
@@ -90,3 +96,60 @@ where
     writer.write_index(index)?;
     Ok(())
 }
+
+/// Indexes a bgzip-compressed, tab-delimited text file.
+///
+/// This is a convenience function and is equivalent to opening the file at the given path and
+/// indexing its contents using [`index_text_from_reader`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_csi::binning_index::index::header;
+/// use noodles_tabix as tabix;
+/// let index = tabix::index_text("sample.bed.gz", header::Builder::bed().build())?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub fn index_text<P>(src: P, header: Header) -> std::io::Result<Index>
+where
+    P: AsRef<Path>,
+{
+    File::open(src).and_then(|reader| index_text_from_reader(reader, header))
+}
+
+/// Indexes bgzip-compressed, tab-delimited text from a reader.
+///
+/// The given header describes the columns of the reference sequence name, start position, and
+/// end position; the line comment prefix; and the number of lines to skip. Presets for common
+/// formats are available, e.g., [`binning_index::index::header::Builder::bed`].
+///
+/// Records must be grouped by reference sequence name and sorted by start position.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Write};
+/// use noodles_bgzf as bgzf;
+/// use noodles_csi::binning_index::index::Header;
+/// use noodles_tabix as tabix;
+///
+/// let mut writer = bgzf::Writer::new(Vec::new());
+/// writer.write_all(b"sq0\t8\t13\n")?;
+/// let data = writer.finish()?;
+///
+/// let header = Header::builder()
+///     .set_start_position_index(1)
+///     .set_end_position_index(Some(2))
+///     .build();
+///
+/// let index = tabix::index_text_from_reader(&data[..], header)?;
+/// assert_eq!(index.reference_sequences().len(), 1);
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn index_text_from_reader<R>(reader: R, header: Header) -> std::io::Result<Index>
+where
+    R: Read,
+{
+    let indexer = binning_index::Indexer::default();
+    csi::io::index_text(reader, header, indexer)
+}
@@ -0,0 +1,39 @@
+//! Converts an alignment file to FASTQ.
+//!
+//! Secondary and supplementary records are excluded. Reverse strand reads are reverse
+//! complemented, and sample barcodes (`BC`) and UMIs (`RX`) are added to the description.
+//!
+//! The result is similar to the output of `samtools fastq -T BC,RX <src>`.
+
+use std::io::{self, BufWriter};
+
+use noodles_fastq as fastq;
+use noodles_sam::alignment::{record::data::field::Tag, RecordBuf};
+use noodles_util::alignment;
+
+fn main() -> io::Result<()> {
+    let src = std::env::args().nth(1).expect("missing src");
+
+    let mut reader = alignment::io::reader::Builder::default().build_from_path(src)?;
+    let header = reader.read_header()?;
+
+    let converter = alignment::fastq::Builder::default()
+        .add_description_tag(Tag::SAMPLE_BARCODE_SEQUENCE)
+        .add_description_tag(Tag::UMI_SEQUENCE)
+        .set_append_read_number(true)
+        .build();
+
+    let stdout = io::stdout().lock();
+    let mut writer = fastq::io::Writer::new(BufWriter::new(stdout));
+
+    for result in reader.records(&header) {
+        let record = result?;
+        let record = RecordBuf::try_from_alignment_record(&header, &record)?;
+
+        if let Some(fastq_record) = converter.convert(&record)? {
+            writer.write_record(&fastq_record)?;
+        }
+    }
+
+    Ok(())
+}
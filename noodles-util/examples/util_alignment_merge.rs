@@ -0,0 +1,47 @@
+//! Merges coordinate-sorted alignment files into a single coordinate-sorted alignment file.
+//!
+//! The output format is determined from the extension of the destination.
+//!
+//! This is similar to `samtools merge <dst> <srcs...>`.
+
+use std::{env, io};
+
+use noodles_util::alignment;
+
+fn main() -> io::Result<()> {
+    let mut args = env::args().skip(1);
+
+    let dst = args.next().expect("missing dst");
+    let srcs: Vec<_> = args.collect();
+
+    let mut readers = srcs
+        .iter()
+        .map(|src| alignment::io::reader::Builder::default().build_from_path(src))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    let headers = readers
+        .iter_mut()
+        .map(|reader| reader.read_header())
+        .collect::<io::Result<Vec<_>>>()?;
+
+    let inputs = readers
+        .iter_mut()
+        .zip(&headers)
+        .map(|(reader, header)| (header.clone(), reader.records(header)));
+
+    let merge = alignment::merge::Builder::default().build_from_inputs(inputs)?;
+    let header = merge.header().clone();
+
+    let mut writer = alignment::io::writer::Builder::default().build_from_path(dst)?;
+
+    writer.write_header(&header)?;
+
+    for result in merge {
+        let record = result?;
+        writer.write_record(&header, &record)?;
+    }
+
+    writer.finish(&header)?;
+
+    Ok(())
+}
@@ -10,7 +10,7 @@ use std::{
 };
 
 use noodles_fasta as fasta;
-use noodles_sam::{self as sam, alignment::io::Write};
+use noodles_sam as sam;
 use noodles_util::alignment;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
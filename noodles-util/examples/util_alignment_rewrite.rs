@@ -35,7 +35,7 @@ fn main() -> io::Result<()> {
 
     for result in reader.records(&header) {
         let record = result?;
-        writer.write_record(&header, &record)?;
+        writer.write_record(&header, record.as_ref())?;
     }
 
     writer.finish(&header)?;
@@ -0,0 +1,37 @@
+//! Simulates paired reads aligned to a reference and their truth variants.
+//!
+//! The results are written as BAM to `<dst-prefix>.bam`, VCF to `<dst-prefix>.vcf`, and the
+//! unaligned reads as FASTQ to `<dst-prefix>_1.fq` and `<dst-prefix>_2.fq`.
+
+use std::{env, fs::File, io};
+
+use noodles_bam as bam;
+use noodles_fasta as fasta;
+use noodles_fastq as fastq;
+use noodles_util::simulate;
+use noodles_vcf as vcf;
+
+fn main() -> io::Result<()> {
+    let mut args = env::args().skip(1);
+
+    let src = args.next().expect("missing src");
+    let dst_prefix = args.next().expect("missing dst-prefix");
+
+    let mut reader = fasta::io::reader::Builder.build_from_path(src)?;
+    let reference_sequences = reader.records().collect::<io::Result<Vec<_>>>()?;
+
+    let simulation =
+        simulate::Builder::default().build_from_reference_sequences(&reference_sequences)?;
+
+    let mut writer = File::create(format!("{dst_prefix}.bam")).map(bam::io::Writer::new)?;
+    simulation.write_alignments(&mut writer)?;
+
+    let mut writer = File::create(format!("{dst_prefix}.vcf")).map(vcf::io::Writer::new)?;
+    simulation.write_variants(&mut writer)?;
+
+    let mut writer_1 = File::create(format!("{dst_prefix}_1.fq")).map(fastq::io::Writer::new)?;
+    let mut writer_2 = File::create(format!("{dst_prefix}_2.fq")).map(fastq::io::Writer::new)?;
+    simulation.write_reads(&mut writer_1, &mut writer_2)?;
+
+    Ok(())
+}
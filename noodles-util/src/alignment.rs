@@ -3,5 +3,25 @@
 #[cfg(feature = "async")]
 pub mod r#async;
 
+pub mod baq;
+pub mod depth;
+pub mod error_profile;
+pub mod fastq;
 pub mod io;
 pub mod iter;
+pub mod liftover;
+pub mod mate_pairer;
+pub mod memory_store;
+pub mod merge;
+pub mod proper_pair;
+pub mod redact;
+pub mod sanitize;
+pub mod tile_quality;
+pub mod umi;
+pub mod unaligned;
+pub mod view;
+
+pub use self::{
+    error_profile::ErrorProfile, liftover::Liftover, mate_pairer::MatePairer,
+    memory_store::MemoryStore, tile_quality::TileQuality,
+};
@@ -120,7 +120,7 @@ impl Builder {
     where
         R: AsyncRead + Unpin + 'static,
     {
-        use crate::alignment::io::reader::builder::{detect_compression_method, detect_format};
+        use crate::alignment::io::{compression_method, format};
 
         let mut reader = BufReader::new(reader);
 
@@ -128,7 +128,7 @@ impl Builder {
             Some(compression_method) => compression_method,
             None => {
                 let mut src = reader.fill_buf().await?;
-                detect_compression_method(&mut src)?
+                compression_method::detect(&mut src)?
             }
         };
 
@@ -136,7 +136,7 @@ impl Builder {
             Some(format) => format,
             None => {
                 let mut src = reader.fill_buf().await?;
-                detect_format(&mut src, compression_method)?
+                format::detect_with_compression_method(&mut src, compression_method)?
             }
         };
 
@@ -0,0 +1,672 @@
+//! Per-base alignment quality (BAQ).
+//!
+//! BAQ is the Phred-scaled probability of a read base being misaligned (Li, 2011). It is
+//! computed using a profile HMM that realigns the read to the reference around its alignment.
+//! Capping base qualities by BAQ reduces false SNVs near indels, e.g., in pileup-based variant
+//! calling.
+//!
+//! This follows `samtools mpileup`, which applies (non-extended) BAQ by default when a reference
+//! is given.
+
+use std::io;
+
+use noodles_sam::alignment::{
+    record::{cigar::op::Kind, data::field::Tag},
+    record_buf::data::field::Value,
+    RecordBuf,
+};
+
+// The precomputed BAQ offsets, as `BQ:Z` Phred+64 characters.
+const BASE_ALIGNMENT_QUALITY_OFFSETS: Tag = Tag::new(b'B', b'Q');
+
+const DEFAULT_BAND_WIDTH: usize = 7;
+
+// Substitution and insertion emission probabilities.
+const EM: f64 = 1.0 / 3.0;
+const EI: f64 = 0.25;
+
+/// Profile HMM parameters.
+#[derive(Clone, Copy, Debug)]
+struct Parameters {
+    // The gap open probability.
+    d: f64,
+    // The gap extension probability.
+    e: f64,
+    band_width: usize,
+}
+
+/// Applies BAQ to the quality scores of an alignment record.
+///
+/// Base qualities of aligned bases are capped by their BAQ. A base that the realignment places at
+/// a different reference position than the alignment gets a quality of 0. Inserted and soft
+/// clipped bases are unchanged.
+///
+/// If the record has precomputed BAQ offsets (`BQ`), they are applied instead. Unmapped records,
+/// records without quality scores, and records with a reference skip are unchanged.
+///
+/// The reference sequence is the complete sequence of the record's reference sequence.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::alignment::{
+///     record::{cigar::{op::Kind, Op}, Flags},
+///     record_buf::{QualityScores, Sequence},
+///     RecordBuf,
+/// };
+/// use noodles_util::alignment::baq;
+///
+/// let reference_sequence = b"ACGTACGTACGTACGTACGT";
+///
+/// let mut record = RecordBuf::builder()
+///     .set_flags(Flags::empty())
+///     .set_alignment_start(Position::try_from(5)?)
+///     .set_cigar([Op::new(Kind::Match, 8)].into_iter().collect())
+///     .set_sequence(Sequence::from(b"ACGTACGT".to_vec()))
+///     .set_quality_scores(QualityScores::from(vec![30; 8]))
+///     .build();
+///
+/// baq::apply(&mut record, reference_sequence)?;
+///
+/// assert!(record.quality_scores().as_ref().iter().all(|&score| score <= 30));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn apply(record: &mut RecordBuf, reference_sequence: &[u8]) -> io::Result<()> {
+    if let Some(offsets) = precomputed_offsets(record)? {
+        let quality_scores = record.quality_scores_mut().as_mut();
+
+        if offsets.len() != quality_scores.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "BQ length mismatch",
+            ));
+        }
+
+        for (score, offset) in quality_scores.iter_mut().zip(offsets) {
+            *score = score.saturating_sub(offset);
+        }
+
+        return Ok(());
+    }
+
+    if let Some(quality_scores) = compute(record, reference_sequence)? {
+        *record.quality_scores_mut().as_mut() = quality_scores;
+    }
+
+    Ok(())
+}
+
+/// Computes the BAQ-capped quality scores of an alignment record.
+///
+/// Unlike [`apply`], this always computes BAQ, ignoring any precomputed BAQ offsets (`BQ`). This
+/// returns `None` if BAQ is not applicable to the record, i.e., it is unmapped, has no quality
+/// scores, has no aligned bases, or has a reference skip.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::alignment::{
+///     record::{cigar::{op::Kind, Op}, Flags},
+///     record_buf::{QualityScores, Sequence},
+///     RecordBuf,
+/// };
+/// use noodles_util::alignment::baq;
+///
+/// let record = RecordBuf::builder()
+///     .set_flags(Flags::empty())
+///     .set_alignment_start(Position::MIN)
+///     .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+///     .set_sequence(Sequence::from(b"ACGT".to_vec()))
+///     .build();
+///
+/// assert!(baq::compute(&record, b"ACGT")?.is_none());
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn compute(record: &RecordBuf, reference_sequence: &[u8]) -> io::Result<Option<Vec<u8>>> {
+    let sequence = record.sequence().as_ref();
+    let quality_scores = record.quality_scores().as_ref();
+
+    if record.flags().is_unmapped() || quality_scores.is_empty() {
+        return Ok(None);
+    }
+
+    if sequence.len() != quality_scores.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "sequence and quality scores length mismatch",
+        ));
+    }
+
+    let Some(alignment_start) = record.alignment_start() else {
+        return Ok(None);
+    };
+
+    let alignment_start = usize::from(alignment_start) - 1;
+
+    // The 0-based aligned reference [xb, xe) and read [yb, ye) intervals.
+    let mut x = alignment_start;
+    let mut y = 0;
+    let mut aligned_intervals = None;
+
+    for op in record.cigar().as_ref() {
+        let len = op.len();
+
+        match op.kind() {
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
+                let (xb, yb, _, _) = aligned_intervals.unwrap_or((x, y, x, y));
+                aligned_intervals = Some((xb, yb, x + len, y + len));
+                x += len;
+                y += len;
+            }
+            Kind::SoftClip | Kind::Insertion => y += len,
+            Kind::Deletion => x += len,
+            Kind::Skip => return Ok(None),
+            Kind::HardClip | Kind::Pad => {}
+        }
+    }
+
+    let Some((xb, yb, xe, ye)) = aligned_intervals else {
+        return Ok(None);
+    };
+
+    let read_length = sequence.len();
+
+    let mut band_width = DEFAULT_BAND_WIDTH;
+    let d = (xe - xb).abs_diff(ye - yb);
+
+    if d > band_width {
+        band_width = d + 3;
+    }
+
+    let mut xb = xb.saturating_sub(yb + band_width / 2);
+    let mut xe = xe + (read_length - ye) + band_width / 2;
+
+    if xe - xb > read_length + band_width {
+        xb += (xe - xb - read_length - band_width) / 2;
+        xe -= (xe - xb - read_length - band_width) / 2;
+    }
+
+    let xe = xe.min(reference_sequence.len());
+
+    if xb >= xe {
+        return Ok(None);
+    }
+
+    let reference: Vec<_> = reference_sequence[xb..xe]
+        .iter()
+        .copied()
+        .map(encode_base)
+        .collect();
+
+    let query: Vec<_> = sequence.iter().copied().map(encode_base).collect();
+
+    let params = Parameters {
+        d: 0.001,
+        e: 0.1,
+        band_width,
+    };
+
+    let (states, qs) = probaln_glocal(&reference, &query, quality_scores, &params);
+
+    let mut baq_quality_scores = quality_scores.to_vec();
+
+    let mut x = alignment_start;
+    let mut y = 0;
+
+    for op in record.cigar().as_ref() {
+        let len = op.len();
+
+        match op.kind() {
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
+                for i in y..y + len {
+                    let expected_position = (x + (i - y)).checked_sub(xb);
+                    let score = &mut baq_quality_scores[i];
+
+                    match states[i] {
+                        Some(State {
+                            kind: StateKind::Match,
+                            position,
+                        }) if Some(position) == expected_position => {
+                            *score = (*score).min(qs[i]);
+                        }
+                        _ => *score = 0,
+                    }
+                }
+
+                x += len;
+                y += len;
+            }
+            Kind::SoftClip | Kind::Insertion => y += len,
+            Kind::Deletion => x += len,
+            Kind::Skip | Kind::HardClip | Kind::Pad => {}
+        }
+    }
+
+    Ok(Some(baq_quality_scores))
+}
+
+fn precomputed_offsets(record: &RecordBuf) -> io::Result<Option<Vec<u8>>> {
+    match record.data().get(&BASE_ALIGNMENT_QUALITY_OFFSETS) {
+        None => Ok(None),
+        Some(Value::String(s)) => s
+            .iter()
+            .map(|&b| {
+                b.checked_sub(b'@')
+                    .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid BQ"))
+            })
+            .collect::<io::Result<_>>()
+            .map(Some),
+        Some(_) => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid BQ type",
+        )),
+    }
+}
+
+fn encode_base(b: u8) -> u8 {
+    match b.to_ascii_uppercase() {
+        b'A' => 0,
+        b'C' => 1,
+        b'G' => 2,
+        b'T' => 3,
+        _ => 4,
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum StateKind {
+    Match,
+    Insertion,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct State {
+    kind: StateKind,
+    // The 0-based position in the reference window.
+    position: usize,
+}
+
+// A banded matrix of the forward or backward probabilities of the match, insertion, and deletion
+// states.
+struct Matrix {
+    band_width: usize,
+    row_len: usize,
+    buf: Vec<f64>,
+}
+
+impl Matrix {
+    fn new(row_count: usize, band_width: usize) -> Self {
+        let row_len = (2 * band_width + 1) * 3 + 6;
+
+        Self {
+            band_width,
+            row_len,
+            buf: vec![0.0; row_count * row_len],
+        }
+    }
+
+    // Returns the offset of the match state of (i, k) in row i.
+    fn u(&self, i: usize, k: usize) -> usize {
+        self.try_u(i, k).expect("k is in the band")
+    }
+
+    // Returns the offset of the match state of (i, k) in row i if k is at or after the start of
+    // the band.
+    fn try_u(&self, i: usize, k: usize) -> Option<usize> {
+        let x = i.saturating_sub(self.band_width);
+        (k + 1).checked_sub(x).map(|n| n * 3)
+    }
+
+    fn row(&self, i: usize) -> &[f64] {
+        let start = i * self.row_len;
+        &self.buf[start..start + self.row_len]
+    }
+
+    fn row_mut(&mut self, i: usize) -> &mut [f64] {
+        let start = i * self.row_len;
+        &mut self.buf[start..start + self.row_len]
+    }
+
+    fn rows_mut(&mut self, i: usize, j: usize) -> (&mut [f64], &mut [f64]) {
+        debug_assert!(i < j);
+        let (a, b) = self.buf.split_at_mut(j * self.row_len);
+        let start = i * self.row_len;
+        (&mut a[start..start + self.row_len], &mut b[..self.row_len])
+    }
+}
+
+// Computes the posterior state and quality of each query base using a glocal (global on the
+// query, local on the reference) profile HMM.
+//
+// This is a port of `probaln_glocal` from htslib. Bases are encoded as 0-3 (ACGT) or 4
+// (ambiguous).
+fn probaln_glocal(
+    reference: &[u8],
+    query: &[u8],
+    quality_scores: &[u8],
+    params: &Parameters,
+) -> (Vec<Option<State>>, Vec<u8>) {
+    let l_ref = reference.len();
+    let l_query = query.len();
+
+    let mut band_width = l_ref.max(l_query).min(params.band_width);
+    band_width = band_width.max(l_ref.abs_diff(l_query));
+    let bw2 = 2 * band_width + 1;
+
+    // 1-based query and qualities.
+    let qual: Vec<f64> = [0.0]
+        .into_iter()
+        .chain(
+            quality_scores
+                .iter()
+                .map(|&q| 10f64.powf(-f64::from(q) / 10.0)),
+        )
+        .collect();
+
+    let qy = |i: usize| query[i - 1];
+
+    let emission = |k: usize, i: usize| {
+        let (r, q) = (reference[k - 1], qy(i));
+
+        if r > 3 || q > 3 {
+            1.0
+        } else if r == q {
+            1.0 - qual[i]
+        } else {
+            qual[i] * EM
+        }
+    };
+
+    let mut f = Matrix::new(l_query + 1, band_width);
+    let mut b = Matrix::new(l_query + 1, band_width);
+    let mut s = vec![0.0; l_query + 2];
+
+    // Transition probabilities.
+    let s_m = 1.0 / (2 * l_query + 2) as f64;
+    let s_i = s_m;
+
+    let (d, e) = (params.d, params.e);
+
+    let m = [
+        (1.0 - d - d) * (1.0 - s_m),
+        d * (1.0 - s_m),
+        d * (1.0 - s_m),
+        (1.0 - e) * (1.0 - s_i),
+        e * (1.0 - s_i),
+        0.0,
+        1.0 - e,
+        0.0,
+        e,
+    ];
+
+    let b_m = (1.0 - d) / l_ref as f64;
+    let b_i = d / l_ref as f64;
+
+    let in_band = |u: Option<usize>| u.filter(|u| (3..bw2 * 3 + 3).contains(u));
+
+    // Forward
+    let u = f.u(0, 0);
+    f.row_mut(0)[u] = 1.0;
+    s[0] = 1.0;
+
+    {
+        let end = l_ref.min(band_width + 1);
+        let mut sum = 0.0;
+
+        for k in 1..=end {
+            let u = f.u(1, k);
+            let fi = f.row_mut(1);
+            fi[u] = emission(k, 1) * b_m;
+            fi[u + 1] = EI * b_i;
+            sum += fi[u] + fi[u + 1];
+        }
+
+        s[1] = sum;
+
+        let (beg, end) = (f.u(1, 1), f.u(1, end) + 2);
+
+        for p in &mut f.row_mut(1)[beg..=end] {
+            *p /= sum;
+        }
+    }
+
+    for (i, s_i) in s.iter_mut().enumerate().take(l_query + 1).skip(2) {
+        let beg = 1.max(i.saturating_sub(band_width));
+        let end = l_ref.min(i + band_width);
+        let mut sum = 0.0;
+
+        for k in beg..=end {
+            let e = emission(k, i);
+
+            let u = f.u(i, k);
+            let v11 = f.u(i - 1, k - 1);
+            let v10 = f.u(i - 1, k);
+            let v01 = f.u(i, k - 1);
+
+            let (fi1, fi) = f.rows_mut(i - 1, i);
+
+            fi[u] = e * (m[0] * fi1[v11] + m[3] * fi1[v11 + 1] + m[6] * fi1[v11 + 2]);
+            fi[u + 1] = EI * (m[1] * fi1[v10] + m[4] * fi1[v10 + 1]);
+            fi[u + 2] = m[2] * fi[v01] + m[8] * fi[v01 + 2];
+
+            sum += fi[u] + fi[u + 1] + fi[u + 2];
+        }
+
+        *s_i = sum;
+
+        let (beg, end) = (f.u(i, beg), f.u(i, end) + 2);
+
+        for p in &mut f.row_mut(i)[beg..=end] {
+            *p /= sum;
+        }
+    }
+
+    {
+        let mut sum = 0.0;
+
+        for k in 1..=l_ref {
+            let Some(u) = in_band(f.try_u(l_query, k)) else {
+                continue;
+            };
+
+            let fi = f.row(l_query);
+            sum += fi[u] * s_m + fi[u + 1] * s_i;
+        }
+
+        s[l_query + 1] = sum;
+    }
+
+    // Backward
+    for k in 1..=l_ref {
+        let Some(u) = in_band(b.try_u(l_query, k)) else {
+            continue;
+        };
+
+        let bi = b.row_mut(l_query);
+        bi[u] = s_m / s[l_query] / s[l_query + 1];
+        bi[u + 1] = s_i / s[l_query] / s[l_query + 1];
+    }
+
+    for i in (1..l_query).rev() {
+        let beg = 1.max(i.saturating_sub(band_width));
+        let end = l_ref.min(i + band_width);
+        let y = if i > 1 { 1.0 } else { 0.0 };
+
+        for k in (beg..=end).rev() {
+            let u = b.u(i, k);
+            let v11 = b.u(i + 1, k + 1);
+            let v10 = b.u(i + 1, k);
+            let v01 = b.u(i, k + 1);
+
+            let (bi, bi1) = b.rows_mut(i, i + 1);
+
+            let e = if k >= l_ref {
+                0.0
+            } else {
+                emission(k + 1, i + 1) * bi1[v11]
+            };
+
+            bi[u] = e * m[0] + EI * m[1] * bi1[v10 + 1] + m[2] * bi[v01 + 2];
+            bi[u + 1] = e * m[3] + EI * m[4] * bi1[v10 + 1];
+            bi[u + 2] = (e * m[6] + m[8] * bi[v01 + 2]) * y;
+        }
+
+        let (beg, end) = (b.u(i, beg), b.u(i, end) + 2);
+        let y = 1.0 / s[i];
+
+        for p in &mut b.row_mut(i)[beg..=end] {
+            *p *= y;
+        }
+    }
+
+    // MAP
+    let mut states = vec![None; l_query];
+    let mut qs = vec![0; l_query];
+
+    for i in 1..=l_query {
+        let beg = 1.max(i.saturating_sub(band_width));
+        let end = l_ref.min(i + band_width);
+
+        let (fi, bi) = (f.row(i), b.row(i));
+
+        let mut sum = 0.0;
+        let mut max = 0.0;
+        let mut max_state = None;
+
+        for k in beg..=end {
+            let u = f.u(i, k);
+
+            for (offset, kind) in [(0, StateKind::Match), (1, StateKind::Insertion)] {
+                let z = fi[u + offset] * bi[u + offset];
+
+                if z > max {
+                    max = z;
+                    max_state = Some(State {
+                        kind,
+                        position: k - 1,
+                    });
+                }
+
+                sum += z;
+            }
+        }
+
+        max /= sum;
+
+        states[i - 1] = max_state;
+
+        let q = (-4.343 * (1.0 - max).ln() + 0.499) as i32;
+        qs[i - 1] = if q > 100 { 99 } else { q as u8 };
+    }
+
+    (states, qs)
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+    use noodles_sam::alignment::{
+        record::{
+            cigar::{op::Kind, Op},
+            Flags,
+        },
+        record_buf::{QualityScores, Sequence},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_compute() -> io::Result<()> {
+        let reference_sequence = b"GATTACAGATTACACCGGTTAACCGGTTAAGATTACAGATTACA";
+
+        // A perfect match in a unique context keeps its qualities.
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_alignment_start(Position::new(11).unwrap())
+            .set_cigar([Op::new(Kind::Match, 20)].into_iter().collect())
+            .set_sequence(Sequence::from(b"TACACCGGTTAACCGGTTAA".to_vec()))
+            .set_quality_scores(QualityScores::from(vec![30; 20]))
+            .build();
+        let actual = compute(&record, reference_sequence)?;
+        assert_eq!(actual, Some(vec![30; 20]));
+
+        // A mismatch next to the end of the read can be explained by a gap, lowering its BAQ.
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_alignment_start(Position::new(11).unwrap())
+            .set_cigar([Op::new(Kind::Match, 20)].into_iter().collect())
+            .set_sequence(Sequence::from(b"TACACCGGTTAACCGGTTAT".to_vec()))
+            .set_quality_scores(QualityScores::from(vec![30; 20]))
+            .build();
+        let actual = compute(&record, reference_sequence)?.unwrap();
+        assert!(actual[19] < 30);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compute_with_inapplicable_records() -> io::Result<()> {
+        let reference_sequence = b"ACGTACGT";
+
+        let mut record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_alignment_start(Position::new(1).unwrap())
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .set_sequence(Sequence::from(b"ACGT".to_vec()))
+            .set_quality_scores(QualityScores::from(vec![30; 4]))
+            .build();
+        *record.flags_mut() = Flags::UNMAPPED;
+        assert!(compute(&record, reference_sequence)?.is_none());
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_alignment_start(Position::new(1).unwrap())
+            .set_cigar(
+                [
+                    Op::new(Kind::Match, 2),
+                    Op::new(Kind::Skip, 2),
+                    Op::new(Kind::Match, 2),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .set_sequence(Sequence::from(b"ACAC".to_vec()))
+            .set_quality_scores(QualityScores::from(vec![30; 4]))
+            .build();
+        assert!(compute(&record, reference_sequence)?.is_none());
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_alignment_start(Position::new(1).unwrap())
+            .set_cigar([Op::new(Kind::SoftClip, 4)].into_iter().collect())
+            .set_sequence(Sequence::from(b"ACGT".to_vec()))
+            .set_quality_scores(QualityScores::from(vec![30; 4]))
+            .build();
+        assert!(compute(&record, reference_sequence)?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_with_precomputed_offsets() -> io::Result<()> {
+        let mut record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_alignment_start(Position::new(1).unwrap())
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .set_sequence(Sequence::from(b"ACGT".to_vec()))
+            .set_quality_scores(QualityScores::from(vec![30; 4]))
+            .build();
+
+        record
+            .data_mut()
+            .insert(BASE_ALIGNMENT_QUALITY_OFFSETS, Value::from("@AB@"));
+
+        apply(&mut record, b"ACGT")?;
+
+        assert_eq!(record.quality_scores().as_ref(), [30, 29, 28, 30]);
+
+        Ok(())
+    }
+}
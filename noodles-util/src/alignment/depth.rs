@@ -0,0 +1,516 @@
+//! Alignment depth.
+//!
+//! This computes per-base or windowed read depth from coordinate-sorted alignment records and
+//! emits it as bedGraph segments, similar to `samtools depth` or `bedtools genomecov -bg`.
+//!
+//! Only bases aligned to the reference sequence (`M`, `=`, and `X`) are counted. Deletions and
+//! skips do not add depth.
+//!
+//! # Examples
+//!
+//! ```
+//! use noodles_core::Position;
+//! use noodles_sam::{
+//!     self as sam,
+//!     alignment::{
+//!         record::{cigar::{op::Kind, Op}, Flags},
+//!         RecordBuf,
+//!     },
+//!     header::record::value::{map::ReferenceSequence, Map},
+//! };
+//! use noodles_util::alignment::depth;
+//!
+//! let header = sam::Header::builder()
+//!     .add_reference_sequence(
+//!         "sq0",
+//!         Map::<ReferenceSequence>::new(std::num::NonZeroUsize::try_from(100)?),
+//!     )
+//!     .build();
+//!
+//! let records = [(1, 10), (6, 10)].into_iter().map(|(start, len)| {
+//!     RecordBuf::builder()
+//!         .set_flags(Flags::empty())
+//!         .set_reference_sequence_id(0)
+//!         .set_alignment_start(Position::try_from(start).unwrap())
+//!         .set_cigar([Op::new(Kind::Match, len)].into_iter().collect())
+//!         .build()
+//! });
+//!
+//! let lines: Vec<_> = depth::Builder::default()
+//!     .build(&header, records.map(Ok))
+//!     .map(|result| result.map(|segment| segment.to_string()))
+//!     .collect::<std::io::Result<_>>()?;
+//!
+//! assert_eq!(lines, ["sq0\t0\t5\t1", "sq0\t5\t10\t2", "sq0\t10\t15\t1"]);
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+    io,
+};
+
+use noodles_bed::{coverage::Interval, record::Strand};
+use noodles_core::Position;
+use noodles_sam::{
+    alignment::{record::cigar::op::Kind, Record},
+    Header,
+};
+
+/// An iterator over the aligned blocks of alignment records as coverage intervals.
+///
+/// Aligned blocks are emitted sorted by reference sequence and start position, which is the
+/// order required by [`noodles_bed::coverage::Coverage`].
+///
+/// This is created by [`Builder::build`].
+pub struct Intervals<'h, I> {
+    header: &'h Header,
+    records: I,
+    min_mapping_quality: u8,
+    include_duplicates: bool,
+    include_secondary: bool,
+    strand_split: bool,
+    reference_sequence: Option<(usize, String)>,
+    pending: BinaryHeap<Reverse<(Position, Position, bool)>>,
+    queue: VecDeque<Interval>,
+    is_eof: bool,
+}
+
+impl<'h, I, R> Intervals<'h, I>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: Record,
+{
+    fn is_counted(&self, record: &R) -> io::Result<bool> {
+        let flags = record.flags()?;
+
+        if flags.is_unmapped()
+            || flags.is_qc_fail()
+            || (flags.is_duplicate() && !self.include_duplicates)
+            || (flags.is_secondary() && !self.include_secondary)
+        {
+            return Ok(false);
+        }
+
+        // A missing mapping quality (255) is treated as the maximum value.
+        let mapping_quality = record
+            .mapping_quality()
+            .transpose()?
+            .map(|mapping_quality| mapping_quality.get())
+            .unwrap_or(u8::MAX);
+
+        Ok(mapping_quality >= self.min_mapping_quality)
+    }
+
+    fn add_record(&mut self, record: &R) -> io::Result<()> {
+        let (reference_sequence_id, start) = match (
+            record.reference_sequence_id(self.header).transpose()?,
+            record.alignment_start().transpose()?,
+        ) {
+            (Some(id), Some(start)) => (id, start),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "missing reference sequence ID or alignment start",
+                ))
+            }
+        };
+
+        if self.reference_sequence.as_ref().map(|(id, _)| *id) != Some(reference_sequence_id) {
+            self.flush(None);
+
+            let name = self
+                .header
+                .reference_sequences()
+                .get_index(reference_sequence_id)
+                .map(|(name, _)| name.to_string())
+                .ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidData, "invalid reference sequence ID")
+                })?;
+
+            self.reference_sequence = Some((reference_sequence_id, name));
+        } else {
+            // Subsequent records start at or after this record, so any pending block that starts
+            // at or before it is final.
+            self.flush(Some(start));
+        }
+
+        let is_reverse = self.strand_split && record.flags()?.is_reverse_complemented();
+
+        for (block_start, block_end) in aligned_blocks(record, start)? {
+            self.pending
+                .push(Reverse((block_start, block_end, is_reverse)));
+        }
+
+        Ok(())
+    }
+
+    fn flush(&mut self, max_start: Option<Position>) {
+        let Some((_, name)) = self.reference_sequence.as_ref() else {
+            return;
+        };
+
+        while let Some(Reverse((start, end, is_reverse))) = self.pending.peek().copied() {
+            if max_start
+                .map(|max_start| start > max_start)
+                .unwrap_or_default()
+            {
+                break;
+            }
+
+            self.pending.pop();
+
+            let strand = if self.strand_split {
+                Some(if is_reverse {
+                    Strand::Reverse
+                } else {
+                    Strand::Forward
+                })
+            } else {
+                None
+            };
+
+            self.queue
+                .push_back(Interval::new(name.clone(), start, end, strand));
+        }
+    }
+}
+
+impl<'h, I, R> Iterator for Intervals<'h, I>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: Record,
+{
+    type Item = io::Result<Interval>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(interval) = self.queue.pop_front() {
+                return Some(Ok(interval));
+            }
+
+            if self.is_eof {
+                return None;
+            }
+
+            let result = match self.records.next() {
+                Some(Ok(record)) => match self.is_counted(&record) {
+                    Ok(true) => self.add_record(&record),
+                    Ok(false) => Ok(()),
+                    Err(e) => Err(e),
+                },
+                Some(Err(e)) => Err(e),
+                None => {
+                    self.is_eof = true;
+                    self.flush(None);
+                    Ok(())
+                }
+            };
+
+            if let Err(e) = result {
+                return Some(Err(e));
+            }
+        }
+    }
+}
+
+fn aligned_blocks<R>(record: &R, start: Position) -> io::Result<Vec<(Position, Position)>>
+where
+    R: Record,
+{
+    let mut blocks = Vec::new();
+    let mut position = usize::from(start);
+
+    for result in record.cigar().iter() {
+        let op = result?;
+
+        match op.kind() {
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch if !op.is_empty() => {
+                let block_start = position;
+                position = checked_add_len(position, op.len())?;
+                let block_end = position - 1;
+
+                // SAFETY: `block_start` and `block_end` are >= `start`.
+                blocks.push((
+                    Position::new(block_start).unwrap(),
+                    Position::new(block_end).unwrap(),
+                ));
+            }
+            Kind::Deletion | Kind::Skip => position = checked_add_len(position, op.len())?,
+            _ => {}
+        }
+    }
+
+    Ok(blocks)
+}
+
+fn checked_add_len(position: usize, len: usize) -> io::Result<usize> {
+    position.checked_add(len).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            "alignment position overflows usize",
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_sam::{
+        alignment::{
+            record::{
+                cigar::{op::Kind, Op},
+                Flags, MappingQuality,
+            },
+            RecordBuf,
+        },
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+
+    fn build_header() -> Header {
+        Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::MAX))
+            .add_reference_sequence("sq1", Map::<ReferenceSequence>::new(NonZeroUsize::MAX))
+            .build()
+    }
+
+    fn depth(
+        builder: Builder,
+        header: &Header,
+        records: Vec<RecordBuf>,
+    ) -> io::Result<Vec<String>> {
+        builder
+            .build(header, records.into_iter().map(Ok))
+            .map(|result| result.map(|segment| segment.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_build() -> io::Result<()> {
+        let header = build_header();
+
+        // 1 2 3 4 5 6 7 8 9
+        // [ ]     [ ]
+        //   [   ]
+        let records = vec![
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(1).unwrap())
+                .set_mapping_quality(MappingQuality::new(30).unwrap())
+                .set_cigar(
+                    [
+                        Op::new(Kind::Match, 2),
+                        Op::new(Kind::Deletion, 2),
+                        Op::new(Kind::Match, 2),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )
+                .build(),
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(2).unwrap())
+                .set_mapping_quality(MappingQuality::new(30).unwrap())
+                .set_cigar(
+                    [Op::new(Kind::SoftClip, 5), Op::new(Kind::Match, 3)]
+                        .into_iter()
+                        .collect(),
+                )
+                .build(),
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(1)
+                .set_alignment_start(Position::new(3).unwrap())
+                .set_mapping_quality(MappingQuality::new(30).unwrap())
+                .set_cigar([Op::new(Kind::Match, 2)].into_iter().collect())
+                .build(),
+        ];
+
+        let actual = depth(Builder::default(), &header, records)?;
+        let expected = [
+            "sq0\t0\t1\t1",
+            "sq0\t1\t2\t2",
+            "sq0\t2\t6\t1",
+            "sq1\t2\t4\t1",
+        ];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_per_base_windows() -> io::Result<()> {
+        let header = build_header();
+        let records = vec![RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::new(2).unwrap())
+            .set_mapping_quality(MappingQuality::new(30).unwrap())
+            .set_cigar([Op::new(Kind::Match, 2)].into_iter().collect())
+            .build()];
+
+        let builder = Builder::default().set_window_size(NonZeroUsize::MIN);
+        let actual = depth(builder, &header, records)?;
+        let expected = ["sq0\t1\t2\t1", "sq0\t2\t3\t1"];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_filters() -> io::Result<()> {
+        let header = build_header();
+
+        let records = || {
+            vec![
+                RecordBuf::builder()
+                    .set_flags(Flags::empty())
+                    .set_reference_sequence_id(0)
+                    .set_alignment_start(Position::new(1).unwrap())
+                    .set_mapping_quality(MappingQuality::new(30).unwrap())
+                    .set_cigar([Op::new(Kind::Match, 2)].into_iter().collect())
+                    .build(),
+                RecordBuf::builder()
+                    .set_flags(Flags::DUPLICATE)
+                    .set_reference_sequence_id(0)
+                    .set_alignment_start(Position::new(1).unwrap())
+                    .set_mapping_quality(MappingQuality::new(30).unwrap())
+                    .set_cigar([Op::new(Kind::Match, 2)].into_iter().collect())
+                    .build(),
+                RecordBuf::builder()
+                    .set_flags(Flags::SECONDARY)
+                    .set_reference_sequence_id(0)
+                    .set_alignment_start(Position::new(1).unwrap())
+                    .set_mapping_quality(MappingQuality::new(30).unwrap())
+                    .set_cigar([Op::new(Kind::Match, 2)].into_iter().collect())
+                    .build(),
+                RecordBuf::builder()
+                    .set_flags(Flags::QC_FAIL)
+                    .set_reference_sequence_id(0)
+                    .set_alignment_start(Position::new(1).unwrap())
+                    .set_mapping_quality(MappingQuality::new(30).unwrap())
+                    .set_cigar([Op::new(Kind::Match, 2)].into_iter().collect())
+                    .build(),
+            ]
+        };
+
+        let actual = depth(Builder::default(), &header, records())?;
+        assert_eq!(actual, ["sq0\t0\t2\t1"]);
+
+        let builder = Builder::default()
+            .set_include_duplicates(true)
+            .set_include_secondary(true);
+        let actual = depth(builder, &header, records())?;
+        assert_eq!(actual, ["sq0\t0\t2\t3"]);
+
+        let builder = Builder::default().set_min_mapping_quality(31);
+        let actual = depth(builder, &header, records())?;
+        assert!(actual.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_strand_split() -> io::Result<()> {
+        let header = build_header();
+
+        let records = vec![
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(1).unwrap())
+                .set_mapping_quality(MappingQuality::new(30).unwrap())
+                .set_cigar([Op::new(Kind::Match, 2)].into_iter().collect())
+                .build(),
+            RecordBuf::builder()
+                .set_flags(Flags::REVERSE_COMPLEMENTED)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(1).unwrap())
+                .set_mapping_quality(MappingQuality::new(30).unwrap())
+                .set_cigar([Op::new(Kind::Match, 2)].into_iter().collect())
+                .build(),
+        ];
+
+        let actual: Vec<_> = Builder::default()
+            .set_strand_split(true)
+            .build(&header, records.into_iter().map(Ok))
+            .map(|result| result.map(|segment| (segment.to_string(), segment.strand())))
+            .collect::<io::Result<_>>()?;
+
+        let expected = [
+            (String::from("sq0\t0\t2\t1"), Some(Strand::Forward)),
+            (String::from("sq0\t0\t2\t1"), Some(Strand::Reverse)),
+        ];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_long_read() -> io::Result<()> {
+        const OP_PAIR_COUNT: usize = 100_000;
+
+        let header = build_header();
+
+        // A spliced read with 1.5 Mbp of aligned bases over 200,000 ops.
+        let ops: Vec<_> = [Op::new(Kind::Match, 15), Op::new(Kind::Skip, 5)]
+            .into_iter()
+            .cycle()
+            .take(2 * OP_PAIR_COUNT)
+            .collect();
+
+        let records = vec![
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(1).unwrap())
+                .set_mapping_quality(MappingQuality::new(30).unwrap())
+                .set_cigar(ops.iter().copied().collect())
+                .build(),
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(1).unwrap())
+                .set_mapping_quality(MappingQuality::new(30).unwrap())
+                .set_cigar([Op::new(Kind::Match, 1 << 28)].into_iter().collect())
+                .build(),
+        ];
+
+        let actual = depth(Builder::default(), &header, records)?;
+
+        // Each aligned block is covered twice, and each skip once.
+        assert_eq!(actual.len(), 2 * OP_PAIR_COUNT);
+        assert_eq!(actual[0], "sq0\t0\t15\t2");
+        assert_eq!(actual[1], "sq0\t15\t20\t1");
+        assert_eq!(actual[actual.len() - 1], "sq0\t1999995\t268435456\t1");
+
+        let records = vec![RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::new(2).unwrap())
+            .set_mapping_quality(MappingQuality::new(30).unwrap())
+            .set_cigar(
+                [Op::new(Kind::Match, 1), Op::new(Kind::Skip, usize::MAX)]
+                    .into_iter()
+                    .collect(),
+            )
+            .build()];
+
+        assert!(matches!(
+            depth(Builder::default(), &header, records),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+use std::{
+    collections::{BinaryHeap, VecDeque},
+    io,
+    num::NonZeroUsize,
+};
+
+use noodles_bed::coverage::{self, Coverage, Normalization};
+use noodles_sam::{alignment::Record, Header};
+
+use super::Intervals;
+
+/// An alignment depth builder.
+#[derive(Clone, Debug, Default)]
+pub struct Builder {
+    min_mapping_quality: u8,
+    include_duplicates: bool,
+    include_secondary: bool,
+    window_size: Option<NonZeroUsize>,
+    strand_split: bool,
+    normalization: Normalization,
+}
+
+impl Builder {
+    /// Sets the minimum mapping quality.
+    ///
+    /// Records with a mapping quality less than this are not counted. A missing mapping quality
+    /// (255) is treated as the maximum value. The default is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::depth;
+    /// let builder = depth::Builder::default().set_min_mapping_quality(20);
+    /// ```
+    pub fn set_min_mapping_quality(mut self, min_mapping_quality: u8) -> Self {
+        self.min_mapping_quality = min_mapping_quality;
+        self
+    }
+
+    /// Sets whether to count duplicate records.
+    ///
+    /// By default, records marked as PCR or optical duplicates are not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::depth;
+    /// let builder = depth::Builder::default().set_include_duplicates(true);
+    /// ```
+    pub fn set_include_duplicates(mut self, include_duplicates: bool) -> Self {
+        self.include_duplicates = include_duplicates;
+        self
+    }
+
+    /// Sets whether to count secondary records.
+    ///
+    /// By default, secondary alignments are not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::depth;
+    /// let builder = depth::Builder::default().set_include_secondary(true);
+    /// ```
+    pub fn set_include_secondary(mut self, include_secondary: bool) -> Self {
+        self.include_secondary = include_secondary;
+        self
+    }
+
+    /// Sets the window size.
+    ///
+    /// By default, adjacent positions with the same depth are merged into a single segment. With
+    /// a window size, the mean depth of each fixed-size window is emitted instead. A window size
+    /// of 1 emits the depth of each covered position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_util::alignment::depth;
+    /// let builder = depth::Builder::default().set_window_size(NonZeroUsize::MIN);
+    /// ```
+    pub fn set_window_size(mut self, window_size: NonZeroUsize) -> Self {
+        self.window_size = Some(window_size);
+        self
+    }
+
+    /// Sets whether to split depth by strand.
+    ///
+    /// When enabled, the depths of forward and reverse strand records are computed separately.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::depth;
+    /// let builder = depth::Builder::default().set_strand_split(true);
+    /// ```
+    pub fn set_strand_split(mut self, strand_split: bool) -> Self {
+        self.strand_split = strand_split;
+        self
+    }
+
+    /// Sets the normalization method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroU64;
+    /// use noodles_bed::coverage::Normalization;
+    /// use noodles_util::alignment::depth;
+    ///
+    /// let total = NonZeroU64::try_from(2_000_000)?;
+    /// let builder = depth::Builder::default()
+    ///     .set_normalization(Normalization::CountsPerMillion(total));
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_normalization(mut self, normalization: Normalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
+    /// Builds a depth iterator from an iterator of coordinate-sorted records.
+    ///
+    /// Unmapped and QC-failed records are never counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::iter;
+    /// use noodles_sam::{self as sam, alignment::RecordBuf};
+    /// use noodles_util::alignment::depth;
+    ///
+    /// let header = sam::Header::default();
+    /// let records = iter::empty::<std::io::Result<RecordBuf>>();
+    ///
+    /// let mut depth = depth::Builder::default().build(&header, records);
+    /// assert!(depth.next().is_none());
+    /// ```
+    pub fn build<I, R>(self, header: &Header, records: I) -> Coverage<Intervals<'_, I::IntoIter>>
+    where
+        I: IntoIterator<Item = io::Result<R>>,
+        R: Record,
+    {
+        let intervals = Intervals {
+            header,
+            records: records.into_iter(),
+            min_mapping_quality: self.min_mapping_quality,
+            include_duplicates: self.include_duplicates,
+            include_secondary: self.include_secondary,
+            strand_split: self.strand_split,
+            reference_sequence: None,
+            pending: BinaryHeap::new(),
+            queue: VecDeque::new(),
+            is_eof: false,
+        };
+
+        let mut builder = coverage::Builder::default()
+            .set_strand_split(self.strand_split)
+            .set_normalization(self.normalization);
+
+        if let Some(window_size) = self.window_size {
+            builder = builder.set_window_size(window_size);
+        }
+
+        builder.build(intervals)
+    }
+}
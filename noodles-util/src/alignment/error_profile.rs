@@ -0,0 +1,448 @@
+//! Alignment error profile.
+
+use std::{collections::BTreeMap, io};
+
+use noodles_fasta as fasta;
+use noodles_sam::{
+    self as sam,
+    alignment::{record::cigar::op::Kind, Record},
+};
+
+const BASES: [u8; 4] = [b'A', b'C', b'G', b'T'];
+
+/// Substitution counts.
+///
+/// This is a matrix of the number of observations of each read base (`A`, `C`, `G`, or `T`)
+/// aligned to each reference base. Bases are in reference strand orientation.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct SubstitutionCounts([[u64; 4]; 4]);
+
+impl SubstitutionCounts {
+    /// Returns the number of observations of a read base aligned to a reference base.
+    ///
+    /// Bases are case-insensitive. This returns 0 if either base is not `A`, `C`, `G`, or `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::error_profile::SubstitutionCounts;
+    /// let counts = SubstitutionCounts::default();
+    /// assert_eq!(counts.get(b'A', b'C'), 0);
+    /// ```
+    pub fn get(&self, reference_base: u8, read_base: u8) -> u64 {
+        match (base_index(reference_base), base_index(read_base)) {
+            (Some(i), Some(j)) => self.0[i][j],
+            _ => 0,
+        }
+    }
+
+    /// Returns the total number of observations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::error_profile::SubstitutionCounts;
+    /// let counts = SubstitutionCounts::default();
+    /// assert_eq!(counts.base_count(), 0);
+    /// ```
+    pub fn base_count(&self) -> u64 {
+        self.0.iter().flatten().sum()
+    }
+
+    /// Returns the number of observations where the read base differs from the reference base.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::error_profile::SubstitutionCounts;
+    /// let counts = SubstitutionCounts::default();
+    /// assert_eq!(counts.mismatch_count(), 0);
+    /// ```
+    pub fn mismatch_count(&self) -> u64 {
+        self.base_count() - (0..BASES.len()).map(|i| self.0[i][i]).sum::<u64>()
+    }
+
+    /// Returns the ratio of mismatches to observations.
+    ///
+    /// This returns `None` if there are no observations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::error_profile::SubstitutionCounts;
+    /// let counts = SubstitutionCounts::default();
+    /// assert!(counts.mismatch_rate().is_none());
+    /// ```
+    pub fn mismatch_rate(&self) -> Option<f64> {
+        match self.base_count() {
+            0 => None,
+            n => Some(self.mismatch_count() as f64 / n as f64),
+        }
+    }
+
+    fn increment(&mut self, reference_base: usize, read_base: usize) {
+        self.0[reference_base][read_base] += 1;
+    }
+}
+
+/// An alignment error profile.
+///
+/// This collects substitution counts of aligned bases (`M`, `=`, and `X`) against the
+/// reference sequence, grouped by read cycle and by reference context.
+///
+/// The read cycle is the 0-based position of a base in the read as it was sequenced, i.e.,
+/// positions of reverse complemented records are reversed. Clipped bases are included in the
+/// cycle. The reference context is the trinucleotide centered on the reference base.
+///
+/// Unmapped, secondary, and supplementary records are skipped, as are bases that are not `A`,
+/// `C`, `G`, or `T`.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{
+///         record::{cigar::{op::Kind, Op}, Flags},
+///         RecordBuf,
+///     },
+///     header::record::value::{map::ReferenceSequence, Map},
+/// };
+/// use noodles_util::alignment::ErrorProfile;
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence(
+///         "sq0",
+///         Map::<ReferenceSequence>::new(std::num::NonZeroUsize::try_from(8)?),
+///     )
+///     .build();
+///
+/// let repository = fasta::Repository::new(vec![fasta::Record::new(
+///     Definition::new("sq0", None),
+///     Sequence::from(b"ACGTACGT".to_vec()),
+/// )]);
+///
+/// let record = RecordBuf::builder()
+///     .set_flags(Flags::empty())
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(noodles_core::Position::MIN)
+///     .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+///     .set_sequence(b"ACTT".to_vec().into())
+///     .build();
+///
+/// let mut error_profile = ErrorProfile::default();
+/// error_profile.add_record(&header, &repository, &record)?;
+///
+/// assert_eq!(error_profile.cycles()[2].mismatch_count(), 1);
+/// assert_eq!(error_profile.cycles()[2].get(b'G', b'T'), 1);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ErrorProfile {
+    cycles: Vec<SubstitutionCounts>,
+    contexts: BTreeMap<[u8; 3], SubstitutionCounts>,
+}
+
+impl ErrorProfile {
+    /// Returns the substitution counts by read cycle.
+    ///
+    /// The index is the 0-based read cycle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::ErrorProfile;
+    /// let error_profile = ErrorProfile::default();
+    /// assert!(error_profile.cycles().is_empty());
+    /// ```
+    pub fn cycles(&self) -> &[SubstitutionCounts] {
+        &self.cycles
+    }
+
+    /// Returns the substitution counts by reference trinucleotide context.
+    ///
+    /// Contexts are uppercase and sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::ErrorProfile;
+    /// let error_profile = ErrorProfile::default();
+    /// assert!(error_profile.contexts().is_empty());
+    /// ```
+    pub fn contexts(&self) -> &BTreeMap<[u8; 3], SubstitutionCounts> {
+        &self.contexts
+    }
+
+    /// Adds the aligned bases of an alignment record to the error profile.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the reference sequence of the record is missing from the
+    /// repository or the record is invalid.
+    pub fn add_record<R>(
+        &mut self,
+        header: &sam::Header,
+        repository: &fasta::Repository,
+        record: &R,
+    ) -> io::Result<()>
+    where
+        R: Record + ?Sized,
+    {
+        let flags = record.flags()?;
+
+        if flags.is_unmapped() || flags.is_secondary() || flags.is_supplementary() {
+            return Ok(());
+        }
+
+        let Some((reference_sequence_name, _)) = record.reference_sequence(header).transpose()?
+        else {
+            return Ok(());
+        };
+
+        let Some(alignment_start) = record.alignment_start().transpose()? else {
+            return Ok(());
+        };
+
+        let reference_sequence = repository
+            .get(reference_sequence_name)
+            .transpose()?
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("missing reference sequence: {reference_sequence_name}"),
+                )
+            })?;
+
+        let reference_sequence = reference_sequence.as_ref();
+        let sequence = record.sequence();
+        let ops: Vec<_> = record.cigar().iter().collect::<io::Result<_>>()?;
+
+        // Hard clips are counted toward the read cycle but are not in the sequence.
+        let cycle_count: usize = ops
+            .iter()
+            .filter(|op| op.kind().consumes_read() || is_hard_clip(op.kind()))
+            .map(|op| op.len())
+            .sum();
+
+        let is_reverse_complemented = flags.is_reverse_complemented();
+
+        let mut cycle = 0;
+        let mut read_position = 0;
+        let mut reference_position = usize::from(alignment_start) - 1;
+
+        for op in ops {
+            let kind = op.kind();
+            let len = op.len();
+
+            if kind.consumes_read() && kind.consumes_reference() {
+                for k in 0..len {
+                    let i = reference_position + k;
+
+                    let Some(reference_base) = reference_sequence.get(i).copied() else {
+                        break;
+                    };
+
+                    let Some(read_base) = sequence.get(read_position + k) else {
+                        break;
+                    };
+
+                    let (Some(reference_base_index), Some(read_base_index)) =
+                        (base_index(reference_base), base_index(read_base))
+                    else {
+                        continue;
+                    };
+
+                    let c = if is_reverse_complemented {
+                        cycle_count - 1 - (cycle + k)
+                    } else {
+                        cycle + k
+                    };
+
+                    if c >= self.cycles.len() {
+                        self.cycles.resize(c + 1, SubstitutionCounts::default());
+                    }
+
+                    self.cycles[c].increment(reference_base_index, read_base_index);
+
+                    if let Some(context) = reference_context(reference_sequence, i) {
+                        self.contexts
+                            .entry(context)
+                            .or_default()
+                            .increment(reference_base_index, read_base_index);
+                    }
+                }
+            }
+
+            if kind.consumes_read() {
+                read_position += len;
+            }
+
+            if kind.consumes_read() || is_hard_clip(kind) {
+                cycle += len;
+            }
+
+            if kind.consumes_reference() {
+                reference_position += len;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn is_hard_clip(kind: Kind) -> bool {
+    kind == Kind::HardClip
+}
+
+fn base_index(base: u8) -> Option<usize> {
+    let base = base.to_ascii_uppercase();
+    BASES.iter().position(|&b| b == base)
+}
+
+fn reference_context(reference_sequence: &[u8], i: usize) -> Option<[u8; 3]> {
+    let start = i.checked_sub(1)?;
+    let bases = reference_sequence.get(start..=i + 1)?;
+
+    if bases.iter().all(|&b| base_index(b).is_some()) {
+        Some([
+            bases[0].to_ascii_uppercase(),
+            bases[1].to_ascii_uppercase(),
+            bases[2].to_ascii_uppercase(),
+        ])
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_core::Position;
+    use noodles_fasta::record::{Definition, Sequence};
+    use noodles_sam::{
+        alignment::{
+            record::{cigar::Op, Flags},
+            RecordBuf,
+        },
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_add_record() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            Definition::new("sq0", None),
+            Sequence::from(b"ACGTACGT".to_vec()),
+        )]);
+
+        let mut error_profile = ErrorProfile::default();
+
+        // forward: 1H1S2M1I1D2M, aligned to 2-6 (CG-AC)
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(2)?)
+            .set_cigar(
+                [
+                    Op::new(Kind::HardClip, 1),
+                    Op::new(Kind::SoftClip, 1),
+                    Op::new(Kind::Match, 2),
+                    Op::new(Kind::Insertion, 1),
+                    Op::new(Kind::Deletion, 1),
+                    Op::new(Kind::Match, 2),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .set_sequence(b"NCTGAN".to_vec().into())
+            .build();
+
+        error_profile.add_record(&header, &repository, &record)?;
+
+        // reverse: 3M, aligned to 6-8 (CGT)
+        let record = RecordBuf::builder()
+            .set_flags(Flags::REVERSE_COMPLEMENTED)
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(6)?)
+            .set_cigar([Op::new(Kind::Match, 3)].into_iter().collect())
+            .set_sequence(b"AGT".to_vec().into())
+            .build();
+
+        error_profile.add_record(&header, &repository, &record)?;
+
+        // skipped
+        let record = RecordBuf::builder()
+            .set_flags(Flags::SECONDARY)
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_cigar([Op::new(Kind::Match, 1)].into_iter().collect())
+            .set_sequence(b"T".to_vec().into())
+            .build();
+
+        error_profile.add_record(&header, &repository, &record)?;
+
+        let cycles = error_profile.cycles();
+        assert_eq!(cycles.len(), 6);
+
+        // forward cycles 2 (C=C), 3 (T!=G), 5 (A=A); cycle 6 (N) is skipped
+        // reverse cycles 2 (A!=C), 1 (G=G), 0 (T=T)
+        assert_eq!(cycles[0].base_count(), 1);
+        assert_eq!(cycles[1].base_count(), 1);
+        assert_eq!(cycles[2].base_count(), 2);
+        assert_eq!(cycles[2].get(b'C', b'C'), 1);
+        assert_eq!(cycles[2].get(b'C', b'A'), 1);
+        assert_eq!(cycles[2].mismatch_rate(), Some(0.5));
+        assert_eq!(cycles[3].get(b'G', b'T'), 1);
+        assert_eq!(cycles[4].base_count(), 0);
+        assert_eq!(cycles[5].get(b'A', b'A'), 1);
+
+        let contexts = error_profile.contexts();
+        assert_eq!(contexts.len(), 3);
+        assert_eq!(contexts[b"ACG"].base_count(), 2);
+        assert_eq!(contexts[b"ACG"].mismatch_count(), 1);
+        assert_eq!(contexts[b"CGT"].get(b'G', b'T'), 1);
+        assert_eq!(contexts[b"TAC"].get(b'A', b'A'), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_record_with_missing_reference_sequence() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let repository = fasta::Repository::default();
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_cigar([Op::new(Kind::Match, 1)].into_iter().collect())
+            .set_sequence(b"A".to_vec().into())
+            .build();
+
+        let mut error_profile = ErrorProfile::default();
+
+        assert!(matches!(
+            error_profile.add_record(&header, &repository, &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
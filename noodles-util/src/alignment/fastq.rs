@@ -0,0 +1,525 @@
+//! Alignment record to FASTQ conversion.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::io::{self, Write};
+
+use noodles_fastq as fastq;
+use noodles_sam::alignment::{
+    record::{data::field::Tag, Flags},
+    record_buf::data::field::{value::Array, Value},
+    RecordBuf,
+};
+
+// The quality score used when a record does not have quality scores.
+const DEFAULT_QUALITY_SCORE: u8 = 1;
+
+const PHRED_OFFSET: u8 = b'!';
+
+/// An alignment record to FASTQ record converter.
+///
+/// This converts alignment records to FASTQ records, as they were originally sequenced: the
+/// sequence and quality scores of records that are aligned to the reverse strand are reverse
+/// complemented and reversed, respectively.
+///
+/// Records are filtered by flags. By default, secondary and supplementary records are excluded.
+/// Data fields, e.g., barcodes (`BC`) and UMIs (`RX`), can be added to the FASTQ record
+/// description or, using [`Self::convert_tag`], converted to separate FASTQ records, e.g., for
+/// an index read file.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::alignment::{
+///     record::{data::field::Tag, Flags},
+///     record_buf::data::field::Value,
+///     RecordBuf,
+/// };
+/// use noodles_util::alignment::fastq;
+///
+/// let converter = fastq::Builder::default()
+///     .add_description_tag(Tag::SAMPLE_BARCODE_SEQUENCE)
+///     .build();
+///
+/// let record = RecordBuf::builder()
+///     .set_name("r0")
+///     .set_flags(Flags::REVERSE_COMPLEMENTED)
+///     .set_sequence(b"AACG".to_vec().into())
+///     .set_quality_scores(vec![1, 2, 3, 4].into())
+///     .set_data(
+///         [(Tag::SAMPLE_BARCODE_SEQUENCE, Value::from("ACGT"))]
+///             .into_iter()
+///             .collect(),
+///     )
+///     .build();
+///
+/// let fastq_record = converter.convert(&record)?.expect("record is selected");
+///
+/// assert_eq!(fastq_record.name(), &b"r0"[..]);
+/// assert_eq!(fastq_record.description(), &b"BC:Z:ACGT"[..]);
+/// assert_eq!(fastq_record.sequence(), b"CGTT");
+/// assert_eq!(fastq_record.quality_scores(), b"%$#\"");
+/// # Ok::<_, std::io::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct Converter {
+    required_flags: Flags,
+    excluded_flags: Flags,
+    description_tags: Vec<Tag>,
+    append_read_number: bool,
+}
+
+impl Converter {
+    /// Returns whether the record passes the flag filters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{record::Flags, RecordBuf};
+    /// use noodles_util::alignment::fastq;
+    ///
+    /// let converter = fastq::Builder::default().build();
+    ///
+    /// let record = RecordBuf::builder().set_flags(Flags::UNMAPPED).build();
+    /// assert!(converter.is_selected(&record));
+    ///
+    /// let record = RecordBuf::builder().set_flags(Flags::SECONDARY).build();
+    /// assert!(!converter.is_selected(&record));
+    /// ```
+    pub fn is_selected(&self, record: &RecordBuf) -> bool {
+        let flags = record.flags();
+        flags.contains(self.required_flags) && !flags.intersects(self.excluded_flags)
+    }
+
+    /// Converts an alignment record to a FASTQ record.
+    ///
+    /// This returns `None` if the record does not pass the flag filters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{record::Flags, RecordBuf};
+    /// use noodles_util::alignment::fastq;
+    ///
+    /// let converter = fastq::Builder::default().build();
+    ///
+    /// let record = RecordBuf::builder()
+    ///     .set_name("r0")
+    ///     .set_flags(Flags::UNMAPPED)
+    ///     .set_sequence(b"ACGT".to_vec().into())
+    ///     .build();
+    ///
+    /// let fastq_record = converter.convert(&record)?.expect("record is selected");
+    ///
+    /// assert_eq!(fastq_record.name(), &b"r0"[..]);
+    /// assert_eq!(fastq_record.sequence(), b"ACGT");
+    /// assert_eq!(fastq_record.quality_scores(), b"\"\"\"\"");
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn convert(&self, record: &RecordBuf) -> io::Result<Option<fastq::Record>> {
+        if !self.is_selected(record) {
+            return Ok(None);
+        }
+
+        let is_reverse_complemented = record.flags().is_reverse_complemented();
+
+        let sequence = record.sequence().as_ref();
+        let quality_scores = record.quality_scores().as_ref();
+
+        let mut fastq_sequence = sequence.to_vec();
+        let mut fastq_quality_scores = encode_quality_scores(quality_scores, sequence.len())?;
+
+        if is_reverse_complemented {
+            reverse_complement(&mut fastq_sequence);
+            fastq_quality_scores.reverse();
+        }
+
+        let name = self.build_name(record);
+        let description = self.build_description(record)?;
+
+        Ok(Some(fastq::Record::new(
+            fastq::record::Definition::new(name, description),
+            fastq_sequence,
+            fastq_quality_scores,
+        )))
+    }
+
+    /// Converts a data field of an alignment record to a FASTQ record.
+    ///
+    /// This is typically used to write index reads, e.g., a sample barcode (`BC`) with its
+    /// quality scores (`QT`), or a UMI (`RX`) with its quality scores (`QX`), to a separate
+    /// file. The sequence is read from the string value of `sequence_tag` and the quality scores
+    /// (as Phred+33 characters) from the string value of `quality_scores_tag`, if given.
+    /// Sequences are not reverse complemented.
+    ///
+    /// This returns `None` if the record does not pass the flag filters or does not have the
+    /// sequence data field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::{data::field::Tag, Flags},
+    ///     record_buf::data::field::Value,
+    ///     RecordBuf,
+    /// };
+    /// use noodles_util::alignment::fastq;
+    ///
+    /// let converter = fastq::Builder::default().build();
+    ///
+    /// let record = RecordBuf::builder()
+    ///     .set_name("r0")
+    ///     .set_flags(Flags::UNMAPPED)
+    ///     .set_data(
+    ///         [
+    ///             (Tag::SAMPLE_BARCODE_SEQUENCE, Value::from("ACGT")),
+    ///             (Tag::SAMPLE_BARCODE_QUALITY_SCORES, Value::from("NDLS")),
+    ///         ]
+    ///         .into_iter()
+    ///         .collect(),
+    ///     )
+    ///     .build();
+    ///
+    /// let fastq_record = converter
+    ///     .convert_tag(
+    ///         &record,
+    ///         Tag::SAMPLE_BARCODE_SEQUENCE,
+    ///         Some(Tag::SAMPLE_BARCODE_QUALITY_SCORES),
+    ///     )?
+    ///     .expect("record has a barcode");
+    ///
+    /// assert_eq!(fastq_record.sequence(), b"ACGT");
+    /// assert_eq!(fastq_record.quality_scores(), b"NDLS");
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn convert_tag(
+        &self,
+        record: &RecordBuf,
+        sequence_tag: Tag,
+        quality_scores_tag: Option<Tag>,
+    ) -> io::Result<Option<fastq::Record>> {
+        if !self.is_selected(record) {
+            return Ok(None);
+        }
+
+        let data = record.data();
+
+        let Some(value) = data.get(&sequence_tag) else {
+            return Ok(None);
+        };
+
+        let sequence = get_string(sequence_tag, value)?.to_vec();
+
+        let quality_scores =
+            match quality_scores_tag.and_then(|tag| data.get(&tag).map(|v| (tag, v))) {
+                Some((tag, value)) => {
+                    let quality_scores = get_string(tag, value)?.to_vec();
+
+                    if quality_scores.len() != sequence.len() {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            "sequence and quality scores length mismatch",
+                        ));
+                    }
+
+                    quality_scores
+                }
+                None => vec![PHRED_OFFSET + DEFAULT_QUALITY_SCORE; sequence.len()],
+            };
+
+        let name = self.build_name(record);
+
+        Ok(Some(fastq::Record::new(
+            fastq::record::Definition::new(name, ""),
+            sequence,
+            quality_scores,
+        )))
+    }
+
+    fn build_name(&self, record: &RecordBuf) -> Vec<u8> {
+        let mut name = record
+            .name()
+            .map(|name| name.to_vec())
+            .unwrap_or_else(|| b"*".to_vec());
+
+        if self.append_read_number {
+            let flags = record.flags();
+
+            if flags.is_first_segment() && !flags.is_last_segment() {
+                name.extend_from_slice(b"/1");
+            } else if flags.is_last_segment() && !flags.is_first_segment() {
+                name.extend_from_slice(b"/2");
+            }
+        }
+
+        name
+    }
+
+    fn build_description(&self, record: &RecordBuf) -> io::Result<Vec<u8>> {
+        let data = record.data();
+        let mut description = Vec::new();
+
+        for tag in &self.description_tags {
+            let Some(value) = data.get(tag) else {
+                continue;
+            };
+
+            if !description.is_empty() {
+                description.push(b'\t');
+            }
+
+            write_field(&mut description, *tag, value)?;
+        }
+
+        Ok(description)
+    }
+}
+
+impl Default for Converter {
+    fn default() -> Self {
+        Builder::default().build()
+    }
+}
+
+fn encode_quality_scores(quality_scores: &[u8], sequence_len: usize) -> io::Result<Vec<u8>> {
+    if quality_scores.is_empty() {
+        return Ok(vec![PHRED_OFFSET + DEFAULT_QUALITY_SCORE; sequence_len]);
+    } else if quality_scores.len() != sequence_len {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "sequence and quality scores length mismatch",
+        ));
+    }
+
+    quality_scores
+        .iter()
+        .map(|&score| {
+            score
+                .checked_add(PHRED_OFFSET)
+                .filter(|n| n.is_ascii_graphic())
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid quality score"))
+        })
+        .collect()
+}
+
+fn reverse_complement(sequence: &mut [u8]) {
+    fn complement(base: u8) -> u8 {
+        match base {
+            b'=' => b'=',
+            b'A' => b'T',
+            b'C' => b'G',
+            b'M' => b'K',
+            b'G' => b'C',
+            b'R' => b'Y',
+            b'S' => b'S',
+            b'V' => b'B',
+            b'T' => b'A',
+            b'W' => b'W',
+            b'Y' => b'R',
+            b'H' => b'D',
+            b'K' => b'M',
+            b'D' => b'H',
+            b'B' => b'V',
+            _ => b'N',
+        }
+    }
+
+    sequence.reverse();
+
+    for base in sequence {
+        *base = complement(*base);
+    }
+}
+
+fn get_string(tag: Tag, value: &Value) -> io::Result<&[u8]> {
+    match value {
+        Value::String(s) => Ok(s.as_ref()),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid {tag:?} value type: expected a string"),
+        )),
+    }
+}
+
+fn write_field(buf: &mut Vec<u8>, tag: Tag, value: &Value) -> io::Result<()> {
+    fn write_values<T: std::fmt::Display>(buf: &mut Vec<u8>, values: &[T]) -> io::Result<()> {
+        for value in values {
+            write!(buf, ",{value}")?;
+        }
+
+        Ok(())
+    }
+
+    buf.extend_from_slice(tag.as_ref());
+    buf.push(b':');
+
+    match value {
+        Value::Character(c) => write!(buf, "A:{}", char::from(*c))?,
+        Value::Int8(n) => write!(buf, "i:{n}")?,
+        Value::UInt8(n) => write!(buf, "i:{n}")?,
+        Value::Int16(n) => write!(buf, "i:{n}")?,
+        Value::UInt16(n) => write!(buf, "i:{n}")?,
+        Value::Int32(n) => write!(buf, "i:{n}")?,
+        Value::UInt32(n) => write!(buf, "i:{n}")?,
+        Value::Float(n) => write!(buf, "f:{n}")?,
+        Value::String(s) => {
+            buf.extend_from_slice(b"Z:");
+            buf.extend_from_slice(s);
+        }
+        Value::Hex(s) => {
+            buf.extend_from_slice(b"H:");
+            buf.extend_from_slice(s);
+        }
+        Value::Array(array) => match array {
+            Array::Int8(values) => {
+                buf.extend_from_slice(b"B:c");
+                write_values(buf, values)?;
+            }
+            Array::UInt8(values) => {
+                buf.extend_from_slice(b"B:C");
+                write_values(buf, values)?;
+            }
+            Array::Int16(values) => {
+                buf.extend_from_slice(b"B:s");
+                write_values(buf, values)?;
+            }
+            Array::UInt16(values) => {
+                buf.extend_from_slice(b"B:S");
+                write_values(buf, values)?;
+            }
+            Array::Int32(values) => {
+                buf.extend_from_slice(b"B:i");
+                write_values(buf, values)?;
+            }
+            Array::UInt32(values) => {
+                buf.extend_from_slice(b"B:I");
+                write_values(buf, values)?;
+            }
+            Array::Float(values) => {
+                buf.extend_from_slice(b"B:f");
+                write_values(buf, values)?;
+            }
+        },
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert() -> io::Result<()> {
+        let converter = Builder::default()
+            .add_description_tag(Tag::SAMPLE_BARCODE_SEQUENCE)
+            .add_description_tag(Tag::EDIT_DISTANCE)
+            .add_description_tag(Tag::UMI_SEQUENCE)
+            .add_description_tag(Tag::ALIGNMENT_HIT_COUNT)
+            .set_append_read_number(true)
+            .build();
+
+        let mut record = RecordBuf::builder()
+            .set_name("r0")
+            .set_flags(Flags::SEGMENTED | Flags::LAST_SEGMENT)
+            .set_sequence(b"ACGN".to_vec().into())
+            .set_quality_scores(vec![0, 10, 20, 30].into())
+            .set_data(
+                [
+                    (Tag::SAMPLE_BARCODE_SEQUENCE, Value::from("TTGA")),
+                    (Tag::UMI_SEQUENCE, Value::from("CCAT")),
+                    (Tag::ALIGNMENT_HIT_COUNT, Value::from(2u8)),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        let actual = converter.convert(&record)?;
+        let expected = fastq::Record::new(
+            fastq::record::Definition::new("r0/2", "BC:Z:TTGA\tRX:Z:CCAT\tNH:i:2"),
+            "ACGN",
+            "!+5?",
+        );
+        assert_eq!(actual, Some(expected));
+
+        *record.flags_mut() = Flags::REVERSE_COMPLEMENTED;
+        let actual = converter.convert(&record)?;
+        let expected = fastq::Record::new(
+            fastq::record::Definition::new("r0", "BC:Z:TTGA\tRX:Z:CCAT\tNH:i:2"),
+            "NCGT",
+            "?5+!",
+        );
+        assert_eq!(actual, Some(expected));
+
+        *record.flags_mut() = Flags::SUPPLEMENTARY;
+        assert!(converter.convert(&record)?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_convert_with_flag_filters() -> io::Result<()> {
+        let converter = Builder::default()
+            .set_required_flags(Flags::FIRST_SEGMENT)
+            .set_excluded_flags(Flags::QC_FAIL)
+            .build();
+
+        for (flags, is_some) in [
+            (Flags::FIRST_SEGMENT, true),
+            (Flags::LAST_SEGMENT, false),
+            (Flags::FIRST_SEGMENT | Flags::QC_FAIL, false),
+            (Flags::FIRST_SEGMENT | Flags::SECONDARY, true),
+        ] {
+            let record = RecordBuf::builder()
+                .set_name("r0")
+                .set_flags(flags)
+                .set_sequence(b"ACGN".to_vec().into())
+                .set_quality_scores(vec![0, 10, 20, 30].into())
+                .build();
+
+            assert_eq!(converter.convert(&record)?.is_some(), is_some);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_convert_tag() -> io::Result<()> {
+        let converter = Converter::default();
+        let record = RecordBuf::builder()
+            .set_name("r0")
+            .set_flags(Flags::REVERSE_COMPLEMENTED)
+            .set_sequence(b"ACGN".to_vec().into())
+            .set_quality_scores(vec![0, 10, 20, 30].into())
+            .set_data(
+                [
+                    (Tag::SAMPLE_BARCODE_SEQUENCE, Value::from("TTGA")),
+                    (Tag::UMI_SEQUENCE, Value::from("CCAT")),
+                    (Tag::ALIGNMENT_HIT_COUNT, Value::from(2u8)),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        let actual =
+            converter.convert_tag(&record, Tag::UMI_SEQUENCE, Some(Tag::UMI_QUALITY_SCORES))?;
+        let expected =
+            fastq::Record::new(fastq::record::Definition::new("r0", ""), "CCAT", "\"\"\"\"");
+        assert_eq!(actual, Some(expected));
+
+        assert!(converter
+            .convert_tag(&record, Tag::CELL_BARCODE_SEQUENCE, None)?
+            .is_none());
+
+        assert!(matches!(
+            converter.convert_tag(&record, Tag::ALIGNMENT_HIT_COUNT, None),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use noodles_sam::alignment::record::{data::field::Tag, Flags};
+
+use super::Converter;
+
+/// An alignment record to FASTQ record converter builder.
+#[derive(Clone, Debug)]
+pub struct Builder {
+    required_flags: Flags,
+    excluded_flags: Flags,
+    description_tags: Vec<Tag>,
+    append_read_number: bool,
+}
+
+impl Builder {
+    /// Sets the flags that a record must have to be converted.
+    ///
+    /// By default, no flags are required.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// use noodles_util::alignment::fastq;
+    ///
+    /// let converter = fastq::Builder::default()
+    ///     .set_required_flags(Flags::FIRST_SEGMENT)
+    ///     .build();
+    /// ```
+    pub fn set_required_flags(mut self, required_flags: Flags) -> Self {
+        self.required_flags = required_flags;
+        self
+    }
+
+    /// Sets the flags that a record must not have to be converted.
+    ///
+    /// By default, secondary and supplementary records are excluded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// use noodles_util::alignment::fastq;
+    ///
+    /// let converter = fastq::Builder::default()
+    ///     .set_excluded_flags(Flags::SECONDARY | Flags::SUPPLEMENTARY | Flags::QC_FAIL)
+    ///     .build();
+    /// ```
+    pub fn set_excluded_flags(mut self, excluded_flags: Flags) -> Self {
+        self.excluded_flags = excluded_flags;
+        self
+    }
+
+    /// Adds a data field tag to include in the FASTQ record description.
+    ///
+    /// Fields are written in SAM format (`TAG:TYPE:VALUE`), separated by tabs, in the order they
+    /// are added. Fields missing from a record are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::data::field::Tag;
+    /// use noodles_util::alignment::fastq;
+    ///
+    /// let converter = fastq::Builder::default()
+    ///     .add_description_tag(Tag::SAMPLE_BARCODE_SEQUENCE)
+    ///     .add_description_tag(Tag::UMI_SEQUENCE)
+    ///     .build();
+    /// ```
+    pub fn add_description_tag(mut self, tag: Tag) -> Self {
+        self.description_tags.push(tag);
+        self
+    }
+
+    /// Sets whether to append the read number (`/1` or `/2`) to the read name.
+    ///
+    /// The read number is determined by the first and last segment flags. By default, the read
+    /// name is not changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::fastq;
+    /// let converter = fastq::Builder::default().set_append_read_number(true).build();
+    /// ```
+    pub fn set_append_read_number(mut self, append_read_number: bool) -> Self {
+        self.append_read_number = append_read_number;
+        self
+    }
+
+    /// Builds an alignment record to FASTQ record converter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::fastq;
+    /// let converter = fastq::Builder::default().build();
+    /// ```
+    pub fn build(self) -> Converter {
+        Converter {
+            required_flags: self.required_flags,
+            excluded_flags: self.excluded_flags,
+            description_tags: self.description_tags,
+            append_read_number: self.append_read_number,
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            required_flags: Flags::empty(),
+            excluded_flags: Flags::SECONDARY | Flags::SUPPLEMENTARY,
+            description_tags: Vec::new(),
+            append_read_number: false,
+        }
+    }
+}
@@ -1,7 +1,7 @@
 //! Alignment format I/O.
 
-mod compression_method;
-mod format;
+pub mod compression_method;
+pub mod format;
 pub mod indexed_reader;
 pub mod reader;
 pub mod writer;
@@ -1,6 +1,43 @@
+//! Alignment compression method.
+
+use std::io::{self, BufRead};
+
 /// A compression method.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum CompressionMethod {
     /// BGZF.
     Bgzf,
 }
+
+/// Detects the compression method of the input.
+///
+/// This only inspects the buffered magic number; no data is consumed from the reader.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_util::alignment::io::{compression_method, CompressionMethod};
+///
+/// let mut src = &[0x1f, 0x8b, 0x08, 0x04][..];
+/// assert_eq!(compression_method::detect(&mut src)?, Some(CompressionMethod::Bgzf));
+///
+/// let mut src = &b"@HD"[..];
+/// assert!(compression_method::detect(&mut src)?.is_none());
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn detect<R>(reader: &mut R) -> io::Result<Option<CompressionMethod>>
+where
+    R: BufRead,
+{
+    const GZIP_MAGIC_NUMBER: [u8; 2] = [0x1f, 0x8b];
+
+    let src = reader.fill_buf()?;
+
+    if let Some(buf) = src.get(..GZIP_MAGIC_NUMBER.len()) {
+        if buf == GZIP_MAGIC_NUMBER {
+            return Ok(Some(CompressionMethod::Bgzf));
+        }
+    }
+
+    Ok(None)
+}
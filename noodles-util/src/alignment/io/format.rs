@@ -1,3 +1,9 @@
+//! Alignment format.
+
+use std::io::{self, BufRead, Read};
+
+use super::{compression_method, CompressionMethod};
+
 /// An alignment format.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Format {
@@ -8,3 +14,118 @@ pub enum Format {
     /// CRAM.
     Cram,
 }
+
+/// Detects the format of the input.
+///
+/// This first detects whether the input is bgzip-compressed (see [`compression_method::detect`])
+/// and then checks the (decompressed) magic number of the input: BAM (`BAM\x01`), CRAM (`CRAM`), or otherwise SAM. Only buffered
+/// data is inspected; no data is consumed from the reader.
+///
+/// The reader builders use this to autodetect the format unless it is overridden, e.g., using
+/// [`super::reader::Builder::set_format`].
+///
+/// # Examples
+///
+/// ```
+/// use noodles_util::alignment::io::{format, Format};
+///
+/// let mut src = &b"CRAM"[..];
+/// assert_eq!(format::detect(&mut src)?, Format::Cram);
+///
+/// let mut src = &b"@HD\tVN:1.6\n"[..];
+/// assert_eq!(format::detect(&mut src)?, Format::Sam);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn detect<R>(reader: &mut R) -> io::Result<Format>
+where
+    R: BufRead,
+{
+    let compression_method = compression_method::detect(reader)?;
+    detect_with_compression_method(reader, compression_method)
+}
+
+pub(crate) fn detect_with_compression_method<R>(
+    reader: &mut R,
+    compression_method: Option<CompressionMethod>,
+) -> io::Result<Format>
+where
+    R: BufRead,
+{
+    use flate2::bufread::MultiGzDecoder;
+
+    const CRAM_MAGIC_NUMBER: [u8; 4] = [b'C', b'R', b'A', b'M'];
+    const BAM_MAGIC_NUMBER: [u8; 4] = [b'B', b'A', b'M', 0x01];
+
+    let src = reader.fill_buf()?;
+
+    if matches!(compression_method, Some(CompressionMethod::Bgzf)) {
+        let mut decoder = MultiGzDecoder::new(src);
+        let mut buf = [0; BAM_MAGIC_NUMBER.len()];
+        decoder.read_exact(&mut buf)?;
+
+        if buf == BAM_MAGIC_NUMBER {
+            return Ok(Format::Bam);
+        }
+    } else if let Some(buf) = src.get(..BAM_MAGIC_NUMBER.len()) {
+        if buf == BAM_MAGIC_NUMBER {
+            return Ok(Format::Bam);
+        } else if buf == CRAM_MAGIC_NUMBER {
+            return Ok(Format::Cram);
+        }
+    }
+
+    Ok(Format::Sam)
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_bgzf as bgzf;
+
+    use super::*;
+
+    #[test]
+    fn test_detect_with_compression_method() -> io::Result<()> {
+        use std::io::Write;
+
+        fn t(mut src: &[u8], expected: Format, compression_method: Option<CompressionMethod>) {
+            assert!(
+                matches!(detect_with_compression_method(&mut src, compression_method), Ok(value) if value == expected)
+            );
+        }
+
+        t(b"@HD\tVN:1.6\n", Format::Sam, None);
+        t(b"", Format::Sam, None);
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"@HD\tVN:1.6\n")?;
+        let src = writer.finish()?;
+        t(&src, Format::Sam, Some(CompressionMethod::Bgzf));
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"BAM\x01")?;
+        let src = writer.finish()?;
+        t(&src, Format::Bam, Some(CompressionMethod::Bgzf));
+
+        // An incomplete gzip stream. See #179.
+        #[rustfmt::skip]
+        let src = [
+            0x1f, 0x8b, // ID1, ID2
+            0x08, // CM = DEFLATE
+            0x04, // FLG = FEXTRA
+            0x00, 0x00, 0x00, 0x00, // MTIME = 0
+            0x00, // XFL = 0
+            0xff, // OS = 255 (unknown)
+            0x06, 0x00, // XLEN = 6
+            b'B', b'C', // SI1, SI2
+            0x02, 0x00, // SLEN = 2
+            0x00, 0x40, // BSIZE = 16384
+            0x73, 0x72, 0xf4, 0x65, 0x04, 0x00, // CDATA = deflate(b"BAM\x01")
+            // ...
+        ];
+        t(&src, Format::Bam, Some(CompressionMethod::Bgzf));
+
+        t(b"CRAM", Format::Cram, None);
+
+        Ok(())
+    }
+}
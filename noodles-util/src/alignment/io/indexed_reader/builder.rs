@@ -15,15 +15,12 @@ use noodles_fasta as fasta;
 use noodles_sam as sam;
 
 use super::IndexedReader;
-use crate::alignment::io::{
-    reader::builder::{detect_compression_method, detect_format},
-    CompressionMethod, Format,
-};
+use crate::alignment::io::{compression_method, format, CompressionMethod, Format};
 
 /// An alignment index.
 pub enum Index {
     /// CSI.
-    Csi(Box<dyn BinningIndex>),
+    Csi(Box<dyn BinningIndex + Send + Sync>),
     /// CRAI.
     Crai(crai::Index),
 }
@@ -144,12 +141,12 @@ impl Builder {
 
         let compression_method = match self.compression_method {
             Some(compression_method) => compression_method,
-            None => detect_compression_method(&mut reader)?,
+            None => compression_method::detect(&mut reader)?,
         };
 
         let format = match self.format {
             Some(format) => format,
-            None => detect_format(&mut reader, compression_method)?,
+            None => format::detect_with_compression_method(&mut reader, compression_method)?,
         };
 
         match (format, compression_method) {
@@ -209,12 +206,12 @@ impl Builder {
 
         let compression_method = match self.compression_method {
             Some(compression_method) => compression_method,
-            None => detect_compression_method(&mut reader)?,
+            None => compression_method::detect(&mut reader)?,
         };
 
         let format = match self.format {
             Some(format) => format,
-            None => detect_format(&mut reader, compression_method)?,
+            None => format::detect_with_compression_method(&mut reader, compression_method)?,
         };
 
         match (format, compression_method) {
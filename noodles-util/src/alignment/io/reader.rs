@@ -8,9 +8,12 @@ use std::io::{self, Read};
 
 use noodles_sam as sam;
 
+use crate::progress::Progress;
+
 /// An alignment reader.
 pub struct Reader<R> {
     inner: Box<dyn sam::alignment::io::Read<R>>,
+    progress: Progress,
 }
 
 impl<R> Reader<R>
@@ -73,6 +76,29 @@ where
         &'a mut self,
         header: &'a sam::Header,
     ) -> impl Iterator<Item = io::Result<Box<dyn sam::alignment::Record>>> + 'a {
-        self.inner.alignment_records(header)
+        let progress = self.progress.clone();
+
+        self.inner.alignment_records(header).inspect(move |result| {
+            if result.is_ok() {
+                progress.add_records(1);
+            }
+        })
+    }
+
+    /// Returns a handle to the progress of the reader.
+    ///
+    /// The handle can be cloned to sample progress, e.g., from another thread, while reading.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_util::alignment;
+    /// let reader = alignment::io::reader::Builder::default().build_from_reader(io::empty())?;
+    /// assert_eq!(reader.progress().records(), 0);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn progress(&self) -> &Progress {
+        &self.progress
     }
 }
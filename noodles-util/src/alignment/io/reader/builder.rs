@@ -11,7 +11,8 @@ use noodles_fasta as fasta;
 use noodles_sam as sam;
 
 use super::Reader;
-use crate::alignment::io::{CompressionMethod, Format};
+use crate::alignment::io::{compression_method, format, CompressionMethod, Format};
+use crate::progress::{self, Counter, Progress};
 
 /// An alignment reader builder.
 #[derive(Default)]
@@ -116,37 +117,43 @@ impl Builder {
     where
         R: Read + 'static,
     {
-        let mut reader = BufReader::new(reader);
+        let progress = Progress::default();
+
+        let mut reader = BufReader::new(progress::Reader::new(
+            reader,
+            progress.clone(),
+            Counter::Compressed,
+        ));
 
         let compression_method = match self.compression_method {
             Some(compression_method) => compression_method,
-            None => detect_compression_method(&mut reader)?,
+            None => compression_method::detect(&mut reader)?,
         };
 
         let format = match self.format {
             Some(format) => format,
-            None => detect_format(&mut reader, compression_method)?,
+            None => format::detect_with_compression_method(&mut reader, compression_method)?,
         };
 
         let inner: Box<dyn sam::alignment::io::Read<_>> = match (format, compression_method) {
             (Format::Sam, None) => {
-                let inner: Box<dyn BufRead> = Box::new(reader);
+                let inner = track(reader, &progress);
                 Box::new(sam::io::Reader::from(inner))
             }
             (Format::Sam, Some(CompressionMethod::Bgzf)) => {
-                let inner: Box<dyn BufRead> = Box::new(bgzf::Reader::new(reader));
+                let inner = track(bgzf::Reader::new(reader), &progress);
                 Box::new(sam::io::Reader::from(inner))
             }
             (Format::Bam, None) => {
-                let inner: Box<dyn BufRead> = Box::new(reader);
+                let inner = track(reader, &progress);
                 Box::new(bam::io::Reader::from(inner))
             }
             (Format::Bam, Some(CompressionMethod::Bgzf)) => {
-                let inner: Box<dyn BufRead> = Box::new(bgzf::Reader::new(reader));
+                let inner = track(bgzf::Reader::new(reader), &progress);
                 Box::new(bam::io::Reader::from(inner))
             }
             (Format::Cram, None) => {
-                let inner: Box<dyn BufRead> = Box::new(reader);
+                let inner = track(reader, &progress);
 
                 Box::new(
                     cram::io::reader::Builder::default()
@@ -162,107 +169,17 @@ impl Builder {
             }
         };
 
-        Ok(Reader { inner })
+        Ok(Reader { inner, progress })
     }
 }
 
-pub(crate) fn detect_compression_method<R>(reader: &mut R) -> io::Result<Option<CompressionMethod>>
+fn track<R>(reader: R, progress: &Progress) -> Box<dyn BufRead>
 where
-    R: BufRead,
+    R: BufRead + 'static,
 {
-    const GZIP_MAGIC_NUMBER: [u8; 2] = [0x1f, 0x8b];
-
-    let src = reader.fill_buf()?;
-
-    if let Some(buf) = src.get(..GZIP_MAGIC_NUMBER.len()) {
-        if buf == GZIP_MAGIC_NUMBER {
-            return Ok(Some(CompressionMethod::Bgzf));
-        }
-    }
-
-    Ok(None)
-}
-
-pub(crate) fn detect_format<R>(
-    reader: &mut R,
-    compression_method: Option<CompressionMethod>,
-) -> io::Result<Format>
-where
-    R: BufRead,
-{
-    use flate2::bufread::MultiGzDecoder;
-
-    const CRAM_MAGIC_NUMBER: [u8; 4] = [b'C', b'R', b'A', b'M'];
-    const BAM_MAGIC_NUMBER: [u8; 4] = [b'B', b'A', b'M', 0x01];
-
-    let src = reader.fill_buf()?;
-
-    if matches!(compression_method, Some(CompressionMethod::Bgzf)) {
-        let mut decoder = MultiGzDecoder::new(src);
-        let mut buf = [0; BAM_MAGIC_NUMBER.len()];
-        decoder.read_exact(&mut buf)?;
-
-        if buf == BAM_MAGIC_NUMBER {
-            return Ok(Format::Bam);
-        }
-    } else if let Some(buf) = src.get(..BAM_MAGIC_NUMBER.len()) {
-        if buf == BAM_MAGIC_NUMBER {
-            return Ok(Format::Bam);
-        } else if buf == CRAM_MAGIC_NUMBER {
-            return Ok(Format::Cram);
-        }
-    }
-
-    Ok(Format::Sam)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_detect_format() -> io::Result<()> {
-        use std::io::Write;
-
-        fn t(mut src: &[u8], expected: Format, compression_method: Option<CompressionMethod>) {
-            assert!(
-                matches!(detect_format(&mut src, compression_method), Ok(value) if value == expected)
-            );
-        }
-
-        t(b"@HD\tVN:1.6\n", Format::Sam, None);
-        t(b"", Format::Sam, None);
-
-        let mut writer = bgzf::Writer::new(Vec::new());
-        writer.write_all(b"@HD\tVN:1.6\n")?;
-        let src = writer.finish()?;
-        t(&src, Format::Sam, Some(CompressionMethod::Bgzf));
-
-        let mut writer = bgzf::Writer::new(Vec::new());
-        writer.write_all(b"BAM\x01")?;
-        let src = writer.finish()?;
-        t(&src, Format::Bam, Some(CompressionMethod::Bgzf));
-
-        // An incomplete gzip stream. See #179.
-        #[rustfmt::skip]
-        let src = [
-            0x1f, 0x8b, // ID1, ID2
-            0x08, // CM = DEFLATE
-            0x04, // FLG = FEXTRA
-            0x00, 0x00, 0x00, 0x00, // MTIME = 0
-            0x00, // XFL = 0
-            0xff, // OS = 255 (unknown)
-            0x06, 0x00, // XLEN = 6
-            b'B', b'C', // SI1, SI2
-            0x02, 0x00, // SLEN = 2
-            0x00, 0x40, // BSIZE = 16384
-            0x73, 0x72, 0xf4, 0x65, 0x04, 0x00, // CDATA = deflate(b"BAM\x01")
-            // ...
-        ];
-        t(&src, Format::Bam, Some(CompressionMethod::Bgzf));
-
-        t(b"CRAM", Format::Cram, None);
-
-        Ok(())
-    }
+    Box::new(progress::Reader::new(
+        reader,
+        progress.clone(),
+        Counter::Uncompressed,
+    ))
 }
@@ -37,6 +37,9 @@ impl Writer {
 
     /// Writes an alignment record.
     ///
+    /// The record can be from any alignment format, e.g., a record read using
+    /// [`crate::alignment::io::Reader`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -53,12 +56,19 @@ impl Writer {
     ///
     /// let record = RecordBuf::default();
     /// writer.write_record(&header, &record)?;
+    ///
+    /// let data = b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\n";
+    /// let mut reader = alignment::io::reader::Builder::default()
+    ///     .set_format(Format::Sam)
+    ///     .build_from_reader(&data[..])?;
+    ///
+    /// for result in reader.records(&header) {
+    ///     let record = result?;
+    ///     writer.write_record(&header, record.as_ref())?;
+    /// }
     /// # Ok::<_, io::Error>(())
     /// ```
-    pub fn write_record<R>(&mut self, header: &sam::Header, record: &R) -> io::Result<()>
-    where
-        R: Record,
-    {
+    pub fn write_record(&mut self, header: &sam::Header, record: &dyn Record) -> io::Result<()> {
         self.inner.write_alignment_record(header, record)
     }
 
@@ -223,7 +223,15 @@ where
 
         match op.kind() {
             Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
-                let end = i + op.len();
+                let end = i
+                    .checked_add(op.len())
+                    .filter(|&end| end <= window.len())
+                    .ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            "CIGAR op exceeds the alignment span",
+                        )
+                    })?;
 
                 for depth in window.range_mut(i..end) {
                     *depth += 1;
@@ -231,7 +239,7 @@ where
 
                 i = end;
             }
-            Kind::Deletion | Kind::Skip => i += op.len(),
+            Kind::Deletion | Kind::Skip => i = i.saturating_add(op.len()),
             _ => {}
         }
     }
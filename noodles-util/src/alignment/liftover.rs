@@ -0,0 +1,429 @@
+//! Alignment liftover.
+
+use std::io;
+
+use noodles_core::{Position, Region};
+use noodles_sam::{self as sam, alignment::Record};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Block {
+    read_start: usize,
+    reference_start: usize,
+    len: usize,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Chain {
+    read_name: Vec<u8>,
+    reference_sequence_name: Vec<u8>,
+    blocks: Vec<Block>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Direction {
+    ReadToReference,
+    ReferenceToRead,
+}
+
+/// An alignment liftover.
+///
+/// This translates intervals between read and reference coordinates through the CIGAR operations
+/// of alignment records, e.g., contigs of one assembly aligned to another.
+///
+/// Read positions are 1-based positions in the read sequence, including soft-clipped bases.
+/// Only bases that are aligned to the reference sequence (`M`, `=`, and `X`) are mapped; an
+/// interval is translated to the span of its aligned bases, which includes any deletions,
+/// insertions, or skips between them.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::{Position, Region};
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{
+///         record::{cigar::{op::Kind, Op}, Flags},
+///         RecordBuf,
+///     },
+///     header::record::value::{map::ReferenceSequence, Map},
+/// };
+/// use noodles_util::alignment::Liftover;
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence(
+///         "sq0",
+///         Map::<ReferenceSequence>::new(std::num::NonZeroUsize::try_from(1000)?),
+///     )
+///     .build();
+///
+/// let record = RecordBuf::builder()
+///     .set_name("r0")
+///     .set_flags(Flags::empty())
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(Position::try_from(101)?)
+///     .set_cigar([Op::new(Kind::SoftClip, 2), Op::new(Kind::Match, 8)].into_iter().collect())
+///     .build();
+///
+/// let mut liftover = Liftover::default();
+/// liftover.add_record(&header, &record)?;
+///
+/// let region = Region::new("r0", Position::try_from(3)?..=Position::try_from(4)?);
+/// let regions = liftover.to_reference(&region);
+/// assert_eq!(regions, [Region::new("sq0", Position::try_from(101)?..=Position::try_from(102)?)]);
+///
+/// let region = Region::new("sq0", Position::try_from(101)?..=Position::try_from(102)?);
+/// let regions = liftover.to_read(&region);
+/// assert_eq!(regions, [Region::new("r0", Position::try_from(3)?..=Position::try_from(4)?)]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Liftover {
+    chains: Vec<Chain>,
+}
+
+impl Liftover {
+    /// Returns the number of alignment chains.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::Liftover;
+    /// let liftover = Liftover::default();
+    /// assert_eq!(liftover.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.chains.len()
+    }
+
+    /// Returns whether there are any alignment chains.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::Liftover;
+    /// let liftover = Liftover::default();
+    /// assert!(liftover.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.chains.is_empty()
+    }
+
+    /// Adds an alignment record as a chain.
+    ///
+    /// Unmapped records are ignored.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if a mapped record is missing a name or its fields are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, alignment::RecordBuf};
+    /// use noodles_util::alignment::Liftover;
+    ///
+    /// let header = sam::Header::default();
+    /// let record = RecordBuf::default();
+    ///
+    /// let mut liftover = Liftover::default();
+    /// liftover.add_record(&header, &record)?;
+    /// assert!(liftover.is_empty());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn add_record<R>(&mut self, header: &sam::Header, record: &R) -> io::Result<()>
+    where
+        R: Record + ?Sized,
+    {
+        if record.flags()?.is_unmapped() {
+            return Ok(());
+        }
+
+        let Some((reference_sequence_name, _)) = record.reference_sequence(header).transpose()?
+        else {
+            return Ok(());
+        };
+
+        let Some(alignment_start) = record.alignment_start().transpose()? else {
+            return Ok(());
+        };
+
+        let read_name = record
+            .name()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing read name"))?;
+
+        let mut blocks = Vec::new();
+
+        let mut read_start = 1;
+        let mut reference_start = usize::from(alignment_start);
+
+        for result in record.cigar().iter() {
+            let op = result?;
+
+            let kind = op.kind();
+            let len = op.len();
+
+            if kind.consumes_read() && kind.consumes_reference() && len > 0 {
+                blocks.push(Block {
+                    read_start,
+                    reference_start,
+                    len,
+                });
+            }
+
+            if kind.consumes_read() {
+                read_start += len;
+            }
+
+            if kind.consumes_reference() {
+                reference_start += len;
+            }
+        }
+
+        self.chains.push(Chain {
+            read_name: read_name.to_vec(),
+            reference_sequence_name: reference_sequence_name.to_vec(),
+            blocks,
+        });
+
+        Ok(())
+    }
+
+    /// Maps a region in read coordinates to regions in reference coordinates.
+    ///
+    /// The region name is the read name. One region is returned for each chain of the read that
+    /// has aligned bases in the region.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Region;
+    /// use noodles_util::alignment::Liftover;
+    ///
+    /// let liftover = Liftover::default();
+    /// let region = Region::new("r0", ..);
+    /// assert!(liftover.to_reference(&region).is_empty());
+    /// ```
+    pub fn to_reference(&self, region: &Region) -> Vec<Region> {
+        self.map(region, Direction::ReadToReference)
+    }
+
+    /// Maps a region in reference coordinates to regions in read coordinates.
+    ///
+    /// The region name is the reference sequence name. One region is returned for each chain on
+    /// the reference sequence that has aligned bases in the region, named by its read name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Region;
+    /// use noodles_util::alignment::Liftover;
+    ///
+    /// let liftover = Liftover::default();
+    /// let region = Region::new("sq0", ..);
+    /// assert!(liftover.to_read(&region).is_empty());
+    /// ```
+    pub fn to_read(&self, region: &Region) -> Vec<Region> {
+        self.map(region, Direction::ReferenceToRead)
+    }
+
+    fn map(&self, region: &Region, direction: Direction) -> Vec<Region> {
+        let interval = region.interval();
+        let start = interval.start().map(usize::from).unwrap_or(1);
+        let end = interval.end().map(usize::from).unwrap_or(usize::MAX);
+
+        self.chains
+            .iter()
+            .filter_map(|chain| {
+                let (src_name, dst_name) = match direction {
+                    Direction::ReadToReference => {
+                        (&chain.read_name, &chain.reference_sequence_name)
+                    }
+                    Direction::ReferenceToRead => {
+                        (&chain.reference_sequence_name, &chain.read_name)
+                    }
+                };
+
+                if src_name.as_slice() != region.name() {
+                    return None;
+                }
+
+                let (dst_start, dst_end) = map_interval(&chain.blocks, direction, start, end)?;
+
+                Some(Region::new(
+                    dst_name.clone(),
+                    Position::new(dst_start)?..=Position::new(dst_end)?,
+                ))
+            })
+            .collect()
+    }
+}
+
+// Blocks are monotonic in both coordinate systems, so the first and last overlapping positions
+// are the bounds of the mapped interval.
+fn map_interval(
+    blocks: &[Block],
+    direction: Direction,
+    start: usize,
+    end: usize,
+) -> Option<(usize, usize)> {
+    let coordinates = |block: &Block| match direction {
+        Direction::ReadToReference => (block.read_start, block.reference_start),
+        Direction::ReferenceToRead => (block.reference_start, block.read_start),
+    };
+
+    let mut mapped: Option<(usize, usize)> = None;
+
+    for block in blocks {
+        let (src_start, dst_start) = coordinates(block);
+        let src_end = src_start + block.len - 1;
+
+        if src_end < start || src_start > end {
+            continue;
+        }
+
+        let overlap_start = start.max(src_start);
+        let overlap_end = end.min(src_end);
+
+        let mapped_start = dst_start + (overlap_start - src_start);
+        let mapped_end = dst_start + (overlap_end - src_start);
+
+        mapped = match mapped {
+            Some((s, _)) => Some((s, mapped_end)),
+            None => Some((mapped_start, mapped_end)),
+        };
+    }
+
+    mapped
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_sam::{
+        alignment::{
+            record::{
+                cigar::{op::Kind, Op},
+                Flags,
+            },
+            RecordBuf,
+        },
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+
+    fn build_liftover() -> Result<Liftover, Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000)?),
+            )
+            .build();
+
+        // read:      1-2 (S), 3-7 (M), 8-9 (I), 10-12 (M),          13-17 (M)
+        // reference:          101-105,          106-108, 109-112 (D), 113-117
+        let record = RecordBuf::builder()
+            .set_name("r0")
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(101)?)
+            .set_cigar(
+                [
+                    Op::new(Kind::SoftClip, 2),
+                    Op::new(Kind::Match, 5),
+                    Op::new(Kind::Insertion, 2),
+                    Op::new(Kind::Match, 3),
+                    Op::new(Kind::Deletion, 4),
+                    Op::new(Kind::Match, 5),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        let mut liftover = Liftover::default();
+        liftover.add_record(&header, &record)?;
+
+        Ok(liftover)
+    }
+
+    fn region(name: &str, start: usize, end: usize) -> Result<Region, Box<dyn std::error::Error>> {
+        Ok(Region::new(
+            name,
+            Position::try_from(start)?..=Position::try_from(end)?,
+        ))
+    }
+
+    #[test]
+    fn test_add_record() -> Result<(), Box<dyn std::error::Error>> {
+        let liftover = build_liftover()?;
+
+        assert_eq!(liftover.len(), 1);
+        assert_eq!(
+            liftover.chains[0].blocks,
+            [
+                Block {
+                    read_start: 3,
+                    reference_start: 101,
+                    len: 5
+                },
+                Block {
+                    read_start: 10,
+                    reference_start: 106,
+                    len: 3
+                },
+                Block {
+                    read_start: 13,
+                    reference_start: 113,
+                    len: 5
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_reference() -> Result<(), Box<dyn std::error::Error>> {
+        let liftover = build_liftover()?;
+
+        let t = |name, start, end| -> Result<Vec<Region>, Box<dyn std::error::Error>> {
+            Ok(liftover.to_reference(&region(name, start, end)?))
+        };
+
+        assert_eq!(t("r0", 3, 7)?, [region("sq0", 101, 105)?]);
+        assert_eq!(t("r0", 1, 9)?, [region("sq0", 101, 105)?]);
+        assert_eq!(t("r0", 8, 13)?, [region("sq0", 106, 113)?]);
+        assert!(t("r0", 8, 9)?.is_empty());
+        assert!(t("r1", 3, 7)?.is_empty());
+
+        assert_eq!(
+            liftover.to_reference(&Region::new("r0", ..)),
+            [region("sq0", 101, 117)?]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_read() -> Result<(), Box<dyn std::error::Error>> {
+        let liftover = build_liftover()?;
+
+        let t = |name, start, end| -> Result<Vec<Region>, Box<dyn std::error::Error>> {
+            Ok(liftover.to_read(&region(name, start, end)?))
+        };
+
+        assert_eq!(t("sq0", 106, 113)?, [region("r0", 10, 13)?]);
+        assert_eq!(t("sq0", 1, 103)?, [region("r0", 3, 5)?]);
+        assert!(t("sq0", 109, 112)?.is_empty());
+        assert!(t("sq1", 101, 105)?.is_empty());
+
+        assert_eq!(
+            liftover.to_read(&Region::new("sq0", ..)),
+            [region("r0", 3, 17)?]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,412 @@
+//! Alignment record mate pairing.
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap, VecDeque},
+    env,
+    fs::{self, File},
+    hash::{Hash, Hasher},
+    io::{self, BufReader, BufWriter, Write},
+    mem,
+    path::PathBuf,
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use noodles_bam as bam;
+use noodles_sam::{
+    self as sam,
+    alignment::{io::Write as _, RecordBuf},
+};
+
+const DEFAULT_CAPACITY: usize = 1 << 20;
+const BUCKET_COUNT: usize = 16;
+
+/// A primary record pairing.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Pairing {
+    /// A pair of mates, ordered by first segment and then last segment.
+    Pair(RecordBuf, RecordBuf),
+    /// A record without a mate.
+    ///
+    /// This is either an unsegmented record, a record without a name, or a segmented record
+    /// whose mate is not in the input.
+    Single(RecordBuf),
+}
+
+/// An iterator that pairs mates of alignment records.
+///
+/// This groups primary records by read name, yielding pairs of mates and singletons. Secondary
+/// and supplementary records are skipped.
+///
+/// The input can be in any order. Unpaired records are held in a cache until their mates are
+/// read. For queryname-sorted input, mates are adjacent, and the cache stays small. When the
+/// cache reaches its capacity, the cached records are spilled to temporary files in the system
+/// temporary directory, partitioned by read name, and are paired bucket by bucket after the
+/// input is exhausted.
+///
+/// Pairs are yielded as soon as both mates are read. Singletons are yielded after the input is
+/// exhausted.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_sam as sam;
+/// use noodles_util::alignment::{mate_pairer::Pairing, MatePairer};
+///
+/// let mut reader = sam::io::reader::Builder::default().build_from_path("sample.sam")?;
+/// let header = reader.read_header()?;
+///
+/// let records: Vec<_> = reader.record_bufs(&header).collect();
+///
+/// for result in MatePairer::new(header, records.into_iter()) {
+///     match result? {
+///         Pairing::Pair(_read_1, _read_2) => {}
+///         Pairing::Single(_record) => {}
+///     }
+/// }
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub struct MatePairer<I> {
+    records: I,
+    header: sam::Header,
+    capacity: usize,
+    cache: HashMap<Vec<u8>, RecordBuf>,
+    spill: Option<Spill>,
+    buckets: Vec<PathBuf>,
+    queue: VecDeque<Pairing>,
+    is_eof: bool,
+}
+
+impl<I> MatePairer<I>
+where
+    I: Iterator<Item = io::Result<RecordBuf>>,
+{
+    /// Creates a mate pairer with a default cache capacity.
+    ///
+    /// The header is used to encode records if they are spilled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::iter;
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::MatePairer;
+    ///
+    /// let header = sam::Header::default();
+    /// let pairer = MatePairer::new(header, iter::empty());
+    /// ```
+    pub fn new(header: sam::Header, records: I) -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY, header, records)
+    }
+
+    /// Creates a mate pairer with a given cache capacity.
+    ///
+    /// The capacity is the maximum number of unpaired records held in memory before they are
+    /// spilled to temporary files.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::iter;
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::MatePairer;
+    ///
+    /// let header = sam::Header::default();
+    /// let pairer = MatePairer::with_capacity(1024, header, iter::empty());
+    /// ```
+    pub fn with_capacity(capacity: usize, header: sam::Header, records: I) -> Self {
+        Self {
+            records,
+            header,
+            capacity,
+            cache: HashMap::new(),
+            spill: None,
+            buckets: Vec::new(),
+            queue: VecDeque::new(),
+            is_eof: false,
+        }
+    }
+
+    fn add_record(&mut self, record: RecordBuf) -> io::Result<()> {
+        let flags = record.flags();
+
+        if flags.is_secondary() || flags.is_supplementary() {
+            return Ok(());
+        }
+
+        let Some(name) = record.name().filter(|_| flags.is_segmented()) else {
+            self.queue.push_back(Pairing::Single(record));
+            return Ok(());
+        };
+
+        if let Some(mate) = self.cache.remove(name.as_ref() as &[u8]) {
+            self.queue.push_back(pair(mate, record));
+            return Ok(());
+        }
+
+        self.cache.insert(name.to_vec(), record);
+
+        if self.cache.len() > self.capacity {
+            self.spill_cache()?;
+        }
+
+        Ok(())
+    }
+
+    fn spill_cache(&mut self) -> io::Result<()> {
+        let spill = match self.spill.as_mut() {
+            Some(spill) => spill,
+            None => self.spill.insert(Spill::new()?),
+        };
+
+        for (name, record) in self.cache.drain() {
+            spill.write_record(&self.header, &name, &record)?;
+        }
+
+        Ok(())
+    }
+
+    fn finish_reading(&mut self) -> io::Result<()> {
+        if self.spill.is_some() {
+            self.spill_cache()?;
+
+            if let Some(spill) = self.spill.take() {
+                self.buckets = spill.finish()?;
+            }
+        } else {
+            let records = mem::take(&mut self.cache)
+                .into_values()
+                .map(Pairing::Single);
+            self.queue.extend(records);
+        }
+
+        Ok(())
+    }
+
+    fn read_next_bucket(&mut self) -> io::Result<bool> {
+        let Some(src) = self.buckets.pop() else {
+            return Ok(false);
+        };
+
+        let mut reader = File::open(&src)
+            .map(BufReader::new)
+            .map(bam::io::Reader::from)?;
+
+        let mut cache: HashMap<Vec<u8>, RecordBuf> = HashMap::new();
+        let mut record = RecordBuf::default();
+
+        while reader.read_record_buf(&self.header, &mut record)? != 0 {
+            let record = mem::take(&mut record);
+
+            // Spilled records are always named.
+            let name = record.name().map(|name| name.to_vec()).unwrap_or_default();
+
+            match cache.remove(&name) {
+                Some(mate) => self.queue.push_back(pair(mate, record)),
+                None => {
+                    cache.insert(name, record);
+                }
+            }
+        }
+
+        self.queue.extend(cache.into_values().map(Pairing::Single));
+
+        fs::remove_file(src)?;
+
+        Ok(true)
+    }
+}
+
+impl<I> Iterator for MatePairer<I>
+where
+    I: Iterator<Item = io::Result<RecordBuf>>,
+{
+    type Item = io::Result<Pairing>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(pairing) = self.queue.pop_front() {
+                return Some(Ok(pairing));
+            }
+
+            if self.is_eof {
+                match self.read_next_bucket() {
+                    Ok(true) => continue,
+                    Ok(false) => return None,
+                    Err(e) => return Some(Err(e)),
+                }
+            }
+
+            let result = match self.records.next() {
+                Some(Ok(record)) => self.add_record(record),
+                Some(Err(e)) => Err(e),
+                None => {
+                    self.is_eof = true;
+                    self.finish_reading()
+                }
+            };
+
+            if let Err(e) = result {
+                return Some(Err(e));
+            }
+        }
+    }
+}
+
+impl<I> Drop for MatePairer<I> {
+    fn drop(&mut self) {
+        for src in &self.buckets {
+            let _ = fs::remove_file(src);
+        }
+    }
+}
+
+fn pair(a: RecordBuf, b: RecordBuf) -> Pairing {
+    if b.flags().is_first_segment() && !a.flags().is_first_segment() {
+        Pairing::Pair(b, a)
+    } else {
+        Pairing::Pair(a, b)
+    }
+}
+
+struct Spill {
+    paths: Vec<PathBuf>,
+    writers: Vec<bam::io::Writer<BufWriter<File>>>,
+}
+
+impl Spill {
+    fn new() -> io::Result<Self> {
+        static ID: AtomicUsize = AtomicUsize::new(0);
+
+        let id = ID.fetch_add(1, Ordering::Relaxed);
+        let tmp_dir = env::temp_dir();
+
+        let mut spill = Self {
+            paths: Vec::with_capacity(BUCKET_COUNT),
+            writers: Vec::with_capacity(BUCKET_COUNT),
+        };
+
+        for i in 0..BUCKET_COUNT {
+            let dst = tmp_dir.join(format!(
+                "noodles-util-mate-pairer-{}-{id}-{i}.bam",
+                process::id()
+            ));
+
+            let writer = File::create(&dst)
+                .map(BufWriter::new)
+                .map(bam::io::Writer::from)?;
+
+            spill.paths.push(dst);
+            spill.writers.push(writer);
+        }
+
+        Ok(spill)
+    }
+
+    fn write_record(
+        &mut self,
+        header: &sam::Header,
+        name: &[u8],
+        record: &RecordBuf,
+    ) -> io::Result<()> {
+        let mut hasher = DefaultHasher::new();
+        name.hash(&mut hasher);
+        let i = (hasher.finish() as usize) % BUCKET_COUNT;
+
+        self.writers[i].write_alignment_record(header, record)
+    }
+
+    fn finish(mut self) -> io::Result<Vec<PathBuf>> {
+        for writer in &mut self.writers {
+            writer.get_mut().flush()?;
+        }
+
+        self.writers.clear();
+
+        Ok(mem::take(&mut self.paths))
+    }
+}
+
+impl Drop for Spill {
+    fn drop(&mut self) {
+        for src in &self.paths {
+            let _ = fs::remove_file(src);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::alignment::record::Flags;
+
+    use super::*;
+
+    fn collect_pairings<I>(pairer: MatePairer<I>) -> io::Result<(Vec<Pairing>, Vec<Pairing>)>
+    where
+        I: Iterator<Item = io::Result<RecordBuf>>,
+    {
+        let mut pairings: Vec<_> = pairer.collect::<io::Result<_>>()?;
+
+        pairings.sort_by_key(|pairing| match pairing {
+            Pairing::Pair(r, _) | Pairing::Single(r) => r.name().map(|name| name.to_vec()),
+        });
+
+        Ok(pairings
+            .into_iter()
+            .partition(|pairing| matches!(pairing, Pairing::Pair(..))))
+    }
+
+    #[test]
+    fn test_next() -> io::Result<()> {
+        const READ_1: Flags = Flags::SEGMENTED.union(Flags::FIRST_SEGMENT);
+        const READ_2: Flags = Flags::SEGMENTED.union(Flags::LAST_SEGMENT);
+
+        let records: Vec<_> = [
+            ("r0", READ_1),
+            ("r1", READ_2),
+            ("r2", Flags::empty()),
+            ("r1", READ_1.union(Flags::SECONDARY)),
+            ("r3", READ_1),
+            ("r1", READ_1),
+            ("r0", READ_2),
+            ("r0", READ_2.union(Flags::SUPPLEMENTARY)),
+        ]
+        .into_iter()
+        .map(|(name, flags)| RecordBuf::builder().set_name(name).set_flags(flags).build())
+        .collect();
+
+        for capacity in [DEFAULT_CAPACITY, 1] {
+            let pairer = MatePairer::with_capacity(
+                capacity,
+                sam::Header::default(),
+                records.iter().cloned().map(Ok),
+            );
+
+            let (pairs, singles) = collect_pairings(pairer)?;
+
+            assert_eq!(pairs.len(), 2);
+
+            for pairing in pairs {
+                let Pairing::Pair(read_1, read_2) = pairing else {
+                    unreachable!();
+                };
+
+                assert_eq!(read_1.name(), read_2.name());
+                assert!(read_1.flags().is_first_segment());
+                assert!(read_2.flags().is_last_segment());
+            }
+
+            let names: Vec<_> = singles
+                .iter()
+                .filter_map(|pairing| match pairing {
+                    Pairing::Single(record) => record.name().map(|name| name.to_vec()),
+                    Pairing::Pair(..) => None,
+                })
+                .collect();
+
+            assert_eq!(names, [b"r2".to_vec(), b"r3".to_vec()]);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,417 @@
+//! In-memory alignment record store.
+
+use std::{collections::HashMap, io};
+
+use noodles_core::{Position, Region};
+use noodles_sam::{self as sam, alignment::RecordBuf};
+
+/// An in-memory alignment record store.
+///
+/// This holds alignment records in memory and indexes them by position and by name, allowing
+/// region and name queries without an alignment file and associated index. It is intended for
+/// tests and small datasets.
+///
+/// Records can be inserted in any order. Region queries return intersecting records in
+/// coordinate order, and name queries return records in insertion order.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroUsize;
+///
+/// use noodles_core::{Position, Region};
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{
+///         record::cigar::{op::Kind, Op},
+///         RecordBuf,
+///     },
+///     header::record::value::{map::ReferenceSequence, Map},
+/// };
+/// use noodles_util::alignment::MemoryStore;
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence(
+///         "sq0",
+///         Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+///     )
+///     .build();
+///
+/// let record = RecordBuf::builder()
+///     .set_name("r0")
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(Position::try_from(5)?)
+///     .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+///     .build();
+///
+/// let mut store = MemoryStore::new(header);
+/// store.insert(record);
+///
+/// let region = "sq0:8-13".parse()?;
+/// assert_eq!(store.query(&region)?.count(), 1);
+///
+/// assert_eq!(store.query_name(b"r0").count(), 1);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    header: sam::Header,
+    records: Vec<RecordBuf>,
+    reference_sequences: Vec<ReferenceSequenceIndex>,
+    names: HashMap<Vec<u8>, Vec<usize>>,
+}
+
+#[derive(Debug, Default)]
+struct ReferenceSequenceIndex {
+    // (start, end, record index), sorted by start and then record index.
+    intervals: Vec<(Position, Position, usize)>,
+    max_span: usize,
+}
+
+impl MemoryStore {
+    /// Creates an empty in-memory alignment record store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::MemoryStore;
+    /// let store = MemoryStore::new(sam::Header::default());
+    /// assert!(store.is_empty());
+    /// ```
+    pub fn new(header: sam::Header) -> Self {
+        Self {
+            header,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the SAM header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::MemoryStore;
+    /// let store = MemoryStore::new(sam::Header::default());
+    /// assert!(store.header().is_empty());
+    /// ```
+    pub fn header(&self) -> &sam::Header {
+        &self.header
+    }
+
+    /// Returns the number of records in the store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, alignment::RecordBuf};
+    /// use noodles_util::alignment::MemoryStore;
+    ///
+    /// let mut store = MemoryStore::new(sam::Header::default());
+    /// store.insert(RecordBuf::default());
+    ///
+    /// assert_eq!(store.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns whether the store has any records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::MemoryStore;
+    /// let store = MemoryStore::new(sam::Header::default());
+    /// assert!(store.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Returns an iterator over all records in insertion order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, alignment::RecordBuf};
+    /// use noodles_util::alignment::MemoryStore;
+    ///
+    /// let mut store = MemoryStore::new(sam::Header::default());
+    /// store.insert(RecordBuf::default());
+    ///
+    /// assert_eq!(store.records().count(), 1);
+    /// ```
+    pub fn records(&self) -> impl Iterator<Item = &RecordBuf> {
+        self.records.iter()
+    }
+
+    /// Inserts a record.
+    ///
+    /// Records with a reference sequence ID and alignment start are indexed by position. Records
+    /// with a name are indexed by name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, alignment::RecordBuf};
+    /// use noodles_util::alignment::MemoryStore;
+    ///
+    /// let mut store = MemoryStore::new(sam::Header::default());
+    /// store.insert(RecordBuf::default());
+    /// ```
+    pub fn insert(&mut self, record: RecordBuf) {
+        let i = self.records.len();
+
+        if let (Some(reference_sequence_id), Some(start)) =
+            (record.reference_sequence_id(), record.alignment_start())
+        {
+            let end = record.alignment_end().unwrap_or(start).max(start);
+
+            if reference_sequence_id >= self.reference_sequences.len() {
+                self.reference_sequences
+                    .resize_with(reference_sequence_id + 1, Default::default);
+            }
+
+            let index = &mut self.reference_sequences[reference_sequence_id];
+            let j = index.intervals.partition_point(|(s, _, _)| *s <= start);
+            index.intervals.insert(j, (start, end, i));
+            index.max_span = index.max_span.max(usize::from(end) - usize::from(start));
+        }
+
+        if let Some(name) = record.name() {
+            self.names.entry(name.to_vec()).or_default().push(i);
+        }
+
+        self.records.push(record);
+    }
+
+    /// Returns an iterator over records that intersect the given region.
+    ///
+    /// Records are returned in coordinate order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     header::record::value::{map::ReferenceSequence, Map},
+    /// };
+    /// use noodles_util::alignment::MemoryStore;
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_reference_sequence(
+    ///         "sq0",
+    ///         Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+    ///     )
+    ///     .build();
+    ///
+    /// let store = MemoryStore::new(header);
+    ///
+    /// let region = "sq0:8-13".parse()?;
+    /// assert_eq!(store.query(&region)?.count(), 0);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query(&self, region: &Region) -> io::Result<impl Iterator<Item = &RecordBuf>> {
+        let reference_sequence_id = resolve_region(self.header.reference_sequences(), region)?;
+
+        let interval = region.interval();
+
+        let intervals = self
+            .reference_sequences
+            .get(reference_sequence_id)
+            .map(|index| {
+                // Records that start before this position cannot reach the query start.
+                let min_start = interval
+                    .start()
+                    .map(|start| usize::from(start).saturating_sub(index.max_span))
+                    .unwrap_or_default();
+
+                let i = index
+                    .intervals
+                    .partition_point(|(s, _, _)| usize::from(*s) < min_start);
+
+                &index.intervals[i..]
+            })
+            .unwrap_or_default();
+
+        Ok(intervals
+            .iter()
+            .take_while(move |(start, _, _)| interval.end().map_or(true, |end| *start <= end))
+            .filter(move |(start, end, _)| interval.intersects((*start..=*end).into()))
+            .map(|(_, _, i)| &self.records[*i]))
+    }
+
+    /// Returns an iterator over records with the given name.
+    ///
+    /// Records are returned in insertion order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, alignment::RecordBuf};
+    /// use noodles_util::alignment::MemoryStore;
+    ///
+    /// let mut store = MemoryStore::new(sam::Header::default());
+    /// store.insert(RecordBuf::builder().set_name("r0").build());
+    ///
+    /// assert_eq!(store.query_name(b"r0").count(), 1);
+    /// assert_eq!(store.query_name(b"r1").count(), 0);
+    /// ```
+    pub fn query_name<N>(&self, name: N) -> impl Iterator<Item = &RecordBuf>
+    where
+        N: AsRef<[u8]>,
+    {
+        self.names
+            .get(name.as_ref())
+            .map(|indices| indices.as_slice())
+            .unwrap_or_default()
+            .iter()
+            .map(|i| &self.records[*i])
+    }
+}
+
+impl Extend<RecordBuf> for MemoryStore {
+    fn extend<T: IntoIterator<Item = RecordBuf>>(&mut self, iter: T) {
+        for record in iter {
+            self.insert(record);
+        }
+    }
+}
+
+fn resolve_region(
+    reference_sequences: &sam::header::ReferenceSequences,
+    region: &Region,
+) -> io::Result<usize> {
+    reference_sequences
+        .get_index_of(region.name())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "region reference sequence does not exist in reference sequences: {region:?}"
+                ),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_sam::{
+        alignment::record::cigar::{op::Kind, Op},
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000)?),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000)?),
+            )
+            .build();
+
+        let mut store = MemoryStore::new(header);
+
+        store.extend([
+            RecordBuf::builder()
+                .set_name("r3")
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(200)?)
+                .set_cigar([Op::new(Kind::Match, 10)].into_iter().collect())
+                .build(),
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(1)?)
+                .set_cigar([Op::new(Kind::Match, 500)].into_iter().collect())
+                .build(),
+            RecordBuf::builder()
+                .set_name("r1")
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(100)?)
+                .set_cigar([Op::new(Kind::Match, 10)].into_iter().collect())
+                .build(),
+            RecordBuf::builder()
+                .set_name("r2")
+                .set_reference_sequence_id(1)
+                .set_alignment_start(Position::try_from(100)?)
+                .set_cigar([Op::new(Kind::Match, 10)].into_iter().collect())
+                .build(),
+            RecordBuf::builder().set_name("r4").build(),
+        ]);
+
+        assert_eq!(store.len(), 5);
+
+        let names = |region: &str| -> Result<Vec<String>, Box<dyn std::error::Error>> {
+            let region = region.parse()?;
+            Ok(store
+                .query(&region)?
+                .filter_map(|record| record.name().map(|name| name.to_string()))
+                .collect())
+        };
+
+        assert_eq!(names("sq0")?, ["r0", "r1", "r3"]);
+        assert_eq!(names("sq0:105-205")?, ["r0", "r1", "r3"]);
+        assert_eq!(names("sq0:150-199")?, ["r0"]);
+        assert_eq!(names("sq0:501-1000")?, Vec::<String>::new());
+        assert_eq!(names("sq1:109")?, ["r2"]);
+
+        let region = "sq2".parse()?;
+        assert!(matches!(
+            store.query(&region),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_name() -> Result<(), Box<dyn std::error::Error>> {
+        let mut store = MemoryStore::new(sam::Header::default());
+
+        store.extend([
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(8)?)
+                .set_cigar([Op::new(Kind::Match, 5)].into_iter().collect())
+                .build(),
+            RecordBuf::builder()
+                .set_name("r1")
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(13)?)
+                .set_cigar([Op::new(Kind::Match, 5)].into_iter().collect())
+                .build(),
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(21)?)
+                .set_cigar([Op::new(Kind::Match, 5)].into_iter().collect())
+                .build(),
+        ]);
+
+        let starts: Vec<_> = store
+            .query_name("r0")
+            .filter_map(|record| record.alignment_start())
+            .map(usize::from)
+            .collect();
+
+        assert_eq!(starts, [8, 21]);
+        assert_eq!(store.query_name("r2").count(), 0);
+
+        Ok(())
+    }
+}
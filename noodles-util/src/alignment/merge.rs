@@ -0,0 +1,467 @@
+//! Sorted alignment record merging.
+
+mod builder;
+mod header;
+
+pub use self::builder::Builder;
+
+use std::{cmp::Ordering, collections::HashMap, io};
+
+use bstr::BString;
+use noodles_core::position::merge::Merger;
+use noodles_sam::{
+    self as sam,
+    alignment::{cmp, record::data::field::Tag, record_buf::data::field::Value, Record, RecordBuf},
+};
+
+/// A sort order of merged records.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SortOrder {
+    /// Records are sorted by coordinate (see [`cmp::coordinate`]).
+    #[default]
+    Coordinate,
+    /// Records are sorted by natural queryname (see [`cmp::queryname_natural`]).
+    QueryNameNatural,
+    /// Records are sorted by lexicographic queryname (see [`cmp::queryname_lexicographic`]).
+    QueryNameLexicographic,
+}
+
+impl SortOrder {
+    fn cmp(self, a: &RecordBuf, b: &RecordBuf, header: &sam::Header) -> io::Result<Ordering> {
+        match self {
+            Self::Coordinate => cmp::coordinate(a, b, header),
+            Self::QueryNameNatural => cmp::queryname_natural(a, b),
+            Self::QueryNameLexicographic => cmp::queryname_lexicographic(a, b),
+        }
+    }
+}
+
+/// A policy to rename reads when merging.
+///
+/// Inputs from different runs may reuse read names, which makes unrelated reads appear to be
+/// mates or duplicates downstream. A renaming policy scopes read names by adding a prefix that is
+/// the same for all records of a template, so mates remain consistently named.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ReadNamePolicy {
+    /// Read names are unchanged.
+    #[default]
+    Keep,
+    /// Read names are prefixed with the 0-based input index, e.g., `r0` in the second input is
+    /// renamed to `1:r0`.
+    PrefixInputIndex,
+    /// Read names are prefixed with the merged read group ID (`RG`), e.g., `r0` in read group
+    /// `rg0` is renamed to `rg0:r0`.
+    ///
+    /// Records without a read group are unchanged. Mates must be in the same read group.
+    ///
+    /// Because the prefix can differ between records of an input, an input that is sorted by
+    /// queryname is only guaranteed to remain sorted if it has a single read group.
+    PrefixReadGroup,
+}
+
+const READ_NAME_PREFIX_DELIMITER: u8 = b':';
+
+struct Input<I> {
+    index: usize,
+    read_name_policy: ReadNamePolicy,
+    records: I,
+    header: sam::Header,
+    reference_sequence_ids: Vec<usize>,
+    read_group_ids: HashMap<BString, BString>,
+    program_ids: HashMap<BString, BString>,
+}
+
+impl<I, R> Iterator for Input<I>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: Record,
+{
+    type Item = io::Result<RecordBuf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.records
+            .next()
+            .map(|result| result.and_then(|record| translate_record(self, &record)))
+    }
+}
+
+/// An iterator that merges sorted alignment record streams into a single sorted stream.
+///
+/// This is similar to `samtools merge`. Each input is a header and an iterator of records that
+/// is sorted in the merge sort order. The input headers are reconciled into a single header (see
+/// [`Self::header`]), and each record is converted to a [`RecordBuf`] and rewritten to be valid
+/// in the merged header, i.e., reference sequence IDs, read group IDs (`RG`), and program IDs
+/// (`PG`) are translated.
+///
+/// Records that compare equal are emitted in input order, so the merge is stable. This also
+/// makes it suitable to merge sorted chunks in an external sort.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroUsize;
+///
+/// use noodles_core::Position;
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{record::Flags, RecordBuf},
+///     header::record::value::{map::ReferenceSequence, Map},
+/// };
+/// use noodles_util::alignment::merge;
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::MIN))
+///     .build();
+///
+/// let build_record = |name, alignment_start| {
+///     RecordBuf::builder()
+///         .set_name(name)
+///         .set_flags(Flags::empty())
+///         .set_reference_sequence_id(0)
+///         .set_alignment_start(Position::new(alignment_start).unwrap())
+///         .build()
+/// };
+///
+/// let a = vec![build_record("r0", 1), build_record("r2", 3)];
+/// let b = vec![build_record("r1", 2)];
+///
+/// let merge = merge::Builder::default().build_from_inputs([
+///     (header.clone(), a.into_iter().map(Ok)),
+///     (header, b.into_iter().map(Ok)),
+/// ])?;
+///
+/// let names: Vec<_> = merge
+///     .map(|result| result.map(|record| record.name().map(|name| name.to_string())))
+///     .collect::<std::io::Result<_>>()?;
+///
+/// assert_eq!(names, [Some("r0".into()), Some("r1".into()), Some("r2".into())]);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub struct Merge<I> {
+    header: sam::Header,
+    sort_order: SortOrder,
+    merger: Merger<Input<I>, RecordBuf>,
+}
+
+impl<I> Merge<I> {
+    /// Returns the merged header.
+    ///
+    /// Reference sequences, read groups, programs, and comments are the union of those of the
+    /// inputs, and the header (`@HD`) is that of the first input with its sort order (`SO`) set
+    /// to the merge sort order.
+    ///
+    /// Reference sequences must have the same lengths and relative order in all inputs. Read
+    /// group and program IDs that collide with a different record in a previous input are made
+    /// unique by appending a numeric suffix, e.g., `rg0.1`, `rg0.2`, etc. Identical records are
+    /// combined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, alignment::RecordBuf};
+    /// use noodles_util::alignment::merge;
+    ///
+    /// let header = sam::Header::default();
+    /// let merge = merge::Builder::default()
+    ///     .build_from_inputs([(header, std::iter::empty::<std::io::Result<RecordBuf>>())])?;
+    ///
+    /// assert!(merge.header().reference_sequences().is_empty());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn header(&self) -> &sam::Header {
+        &self.header
+    }
+}
+
+impl<I, R> Iterator for Merge<I>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: Record,
+{
+    type Item = io::Result<RecordBuf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (header, sort_order) = (&self.header, self.sort_order);
+
+        self.merger
+            .next_by(|a, b| sort_order.cmp(a, b, header))
+            .transpose()
+    }
+}
+
+fn translate_record<R>(input: &Input<impl Sized>, record: &R) -> io::Result<RecordBuf>
+where
+    R: Record + ?Sized,
+{
+    let translate_reference_sequence_id = |id: usize| {
+        input
+            .reference_sequence_ids
+            .get(id)
+            .copied()
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, "invalid reference sequence ID")
+            })
+    };
+
+    let mut record = RecordBuf::try_from_alignment_record(&input.header, record)?;
+
+    if let Some(id) = record.reference_sequence_id_mut() {
+        *id = translate_reference_sequence_id(*id)?;
+    }
+
+    if let Some(id) = record.mate_reference_sequence_id_mut() {
+        *id = translate_reference_sequence_id(*id)?;
+    }
+
+    translate_id(&mut record, Tag::READ_GROUP, &input.read_group_ids);
+    translate_id(&mut record, Tag::PROGRAM, &input.program_ids);
+
+    rename(&mut record, input.read_name_policy, input.index);
+
+    Ok(record)
+}
+
+fn rename(record: &mut RecordBuf, read_name_policy: ReadNamePolicy, input_index: usize) {
+    let prefix = match read_name_policy {
+        ReadNamePolicy::Keep => return,
+        ReadNamePolicy::PrefixInputIndex => BString::from(input_index.to_string()),
+        ReadNamePolicy::PrefixReadGroup => match record.data().get(&Tag::READ_GROUP) {
+            Some(Value::String(id)) => id.clone(),
+            _ => return,
+        },
+    };
+
+    if let Some(name) = record.name_mut() {
+        let mut new_name = prefix;
+        new_name.push(READ_NAME_PREFIX_DELIMITER);
+        new_name.extend_from_slice(name);
+        *name = new_name;
+    }
+}
+
+fn translate_id(record: &mut RecordBuf, tag: Tag, ids: &HashMap<BString, BString>) {
+    if ids.is_empty() {
+        return;
+    }
+
+    if let Some(Value::String(id)) = record.data_mut().get_mut(&tag) {
+        if let Some(new_id) = ids.get(id) {
+            *id = new_id.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_core::Position;
+    use sam::{
+        alignment::record::Flags,
+        header::record::value::{
+            map::{read_group::tag as read_group_tag, ReadGroup, ReferenceSequence},
+            Map,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let sq0 = Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?);
+        let sq1 = Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?);
+
+        let build_read_group = |sample| {
+            Map::<ReadGroup>::builder()
+                .insert(read_group_tag::SAMPLE, sample)
+                .build()
+        };
+
+        let a = sam::Header::builder()
+            .add_reference_sequence("sq1", sq1.clone())
+            .add_read_group("rg0", build_read_group("sample0")?)
+            .build();
+
+        let b = sam::Header::builder()
+            .add_reference_sequence("sq1", sq1)
+            .add_reference_sequence("sq0", sq0)
+            .add_read_group("rg0", build_read_group("sample1")?)
+            .build();
+
+        let a_records = [
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(3).unwrap())
+                .set_data(
+                    [(Tag::READ_GROUP, Value::from("rg0"))]
+                        .into_iter()
+                        .collect(),
+                )
+                .build(),
+            RecordBuf::builder()
+                .set_name("r1")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(5).unwrap())
+                .set_data(
+                    [(Tag::READ_GROUP, Value::from("rg0"))]
+                        .into_iter()
+                        .collect(),
+                )
+                .build(),
+        ];
+
+        let b_records = [
+            RecordBuf::builder()
+                .set_name("r3")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(3).unwrap())
+                .set_data(
+                    [(Tag::READ_GROUP, Value::from("rg0"))]
+                        .into_iter()
+                        .collect(),
+                )
+                .build(),
+            RecordBuf::builder()
+                .set_name("r2")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(1)
+                .set_alignment_start(Position::new(8).unwrap())
+                .set_data(
+                    [(Tag::READ_GROUP, Value::from("rg0"))]
+                        .into_iter()
+                        .collect(),
+                )
+                .build(),
+        ];
+
+        let merge = Builder::default().build_from_inputs([
+            (a, a_records.into_iter().map(Ok)),
+            (b, b_records.into_iter().map(Ok)),
+        ])?;
+
+        let header = merge.header().clone();
+
+        let names: Vec<_> = header.reference_sequences().keys().collect();
+        assert_eq!(names, ["sq1", "sq0"]);
+
+        let actual: Vec<_> = merge
+            .map(|result| {
+                result.map(|record| {
+                    let name = record.name().map(|name| name.to_string());
+                    let read_group_id = match record.data().get(&Tag::READ_GROUP) {
+                        Some(Value::String(id)) => id.to_string(),
+                        _ => unreachable!(),
+                    };
+
+                    (name.unwrap(), record.reference_sequence_id(), read_group_id)
+                })
+            })
+            .collect::<io::Result<_>>()?;
+
+        let expected = [
+            (String::from("r0"), Some(0), String::from("rg0")),
+            (String::from("r3"), Some(0), String::from("rg0.1")),
+            (String::from("r1"), Some(0), String::from("rg0")),
+            (String::from("r2"), Some(1), String::from("rg0.1")),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_read_name_policy() -> Result<(), Box<dyn std::error::Error>> {
+        let build_read_group = || Map::<ReadGroup>::default();
+
+        let a = sam::Header::builder()
+            .add_read_group("rg0", build_read_group())
+            .build();
+
+        let b = sam::Header::builder()
+            .add_read_group("rg0", build_read_group())
+            .add_read_group("rg1", build_read_group())
+            .build();
+
+        let a_records = [
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_data(
+                    [(Tag::READ_GROUP, Value::from("rg0"))]
+                        .into_iter()
+                        .collect(),
+                )
+                .build(),
+            RecordBuf::builder().set_name("r0").build(),
+        ];
+
+        let b_records = [
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_data(
+                    [(Tag::READ_GROUP, Value::from("rg1"))]
+                        .into_iter()
+                        .collect(),
+                )
+                .build(),
+            RecordBuf::default(),
+        ];
+
+        let names = |read_name_policy| -> io::Result<Vec<String>> {
+            Builder::default()
+                .set_sort_order(SortOrder::QueryNameLexicographic)
+                .set_read_name_policy(read_name_policy)
+                .build_from_inputs([
+                    (a.clone(), a_records.clone().into_iter().map(Ok)),
+                    (b.clone(), b_records.clone().into_iter().map(Ok)),
+                ])?
+                .map(|result| {
+                    result.map(|record| {
+                        record
+                            .name()
+                            .map(|name| name.to_string())
+                            .unwrap_or_default()
+                    })
+                })
+                .collect()
+        };
+
+        assert_eq!(names(ReadNamePolicy::Keep)?, ["r0", "r0", "r0", ""]);
+        assert_eq!(
+            names(ReadNamePolicy::PrefixInputIndex)?,
+            ["0:r0", "0:r0", "1:r0", ""]
+        );
+        assert_eq!(
+            names(ReadNamePolicy::PrefixReadGroup)?,
+            ["rg0:r0", "r0", "rg1:r0", ""]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_input_error() -> io::Result<()> {
+        let header = sam::Header::default();
+
+        let records = vec![
+            Ok(RecordBuf::default()),
+            Err(io::Error::from(io::ErrorKind::InvalidData)),
+        ];
+
+        let mut merge = Builder::default()
+            .set_sort_order(SortOrder::QueryNameNatural)
+            .build_from_inputs([(header, records.into_iter())])?;
+
+        assert!(merge.next().transpose()?.is_some());
+        assert!(matches!(
+            merge.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
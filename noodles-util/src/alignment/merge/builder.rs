@@ -0,0 +1,96 @@
+use std::io;
+
+use noodles_core::position::merge::Merger;
+use noodles_sam as sam;
+
+use super::{header, Input, Merge, ReadNamePolicy, SortOrder};
+
+/// A sorted alignment record merger builder.
+#[derive(Clone, Debug, Default)]
+pub struct Builder {
+    sort_order: SortOrder,
+    read_name_policy: ReadNamePolicy,
+}
+
+impl Builder {
+    /// Sets the sort order of the inputs and merged records.
+    ///
+    /// By default, this is [`SortOrder::Coordinate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::merge::{self, SortOrder};
+    /// let builder = merge::Builder::default().set_sort_order(SortOrder::QueryNameNatural);
+    /// ```
+    pub fn set_sort_order(mut self, sort_order: SortOrder) -> Self {
+        self.sort_order = sort_order;
+        self
+    }
+
+    /// Sets the policy to rename reads.
+    ///
+    /// By default, this is [`ReadNamePolicy::Keep`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::merge::{self, ReadNamePolicy};
+    /// let builder = merge::Builder::default().set_read_name_policy(ReadNamePolicy::PrefixInputIndex);
+    /// ```
+    pub fn set_read_name_policy(mut self, read_name_policy: ReadNamePolicy) -> Self {
+        self.read_name_policy = read_name_policy;
+        self
+    }
+
+    /// Builds a sorted alignment record merger from inputs.
+    ///
+    /// Each input is a header and an iterator of records that is sorted in the merge sort order.
+    ///
+    /// # Errors
+    ///
+    /// This returns an `io::Error` if the input headers cannot be merged, e.g., a reference
+    /// sequence has conflicting lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, alignment::RecordBuf};
+    /// use noodles_util::alignment::merge;
+    ///
+    /// let header = sam::Header::default();
+    /// let records: [std::io::Result<RecordBuf>; 0] = [];
+    ///
+    /// let merge = merge::Builder::default().build_from_inputs([(header, records.into_iter())])?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn build_from_inputs<J, I>(self, inputs: J) -> io::Result<Merge<I>>
+    where
+        J: IntoIterator<Item = (sam::Header, I)>,
+    {
+        let (headers, records): (Vec<_>, Vec<_>) = inputs.into_iter().unzip();
+        let (header, translations) = header::merge(&headers, self.sort_order)?;
+
+        let inputs: Vec<_> = records
+            .into_iter()
+            .zip(headers)
+            .zip(translations)
+            .enumerate()
+            .map(|(index, ((records, header), translation))| Input {
+                index,
+                read_name_policy: self.read_name_policy,
+                records,
+                header,
+                reference_sequence_ids: translation.reference_sequence_ids,
+                read_group_ids: translation.read_group_ids,
+                program_ids: translation.program_ids,
+            })
+            .collect();
+
+        Ok(Merge {
+            header,
+            sort_order: self.sort_order,
+            merger: Merger::new(inputs),
+        })
+    }
+}
@@ -0,0 +1,340 @@
+use std::{collections::HashMap, io};
+
+use bstr::BString;
+use noodles_sam::{
+    self as sam,
+    header::record::value::{
+        map::{self, header::tag as header_tag, program::tag as program_tag},
+        Map,
+    },
+};
+
+use super::SortOrder;
+
+#[derive(Debug, Default)]
+pub(super) struct Translation {
+    pub(super) reference_sequence_ids: Vec<usize>,
+    pub(super) read_group_ids: HashMap<BString, BString>,
+    pub(super) program_ids: HashMap<BString, BString>,
+}
+
+pub(super) fn merge(
+    headers: &[sam::Header],
+    sort_order: SortOrder,
+) -> io::Result<(sam::Header, Vec<Translation>)> {
+    let mut merged_header = sam::Header::default();
+
+    if let Some(header) = headers.first() {
+        let mut map = header.header().cloned().unwrap_or_default();
+        set_sort_order(&mut map, sort_order);
+        *merged_header.header_mut() = Some(map);
+    }
+
+    let mut translations = Vec::with_capacity(headers.len());
+
+    for header in headers {
+        let reference_sequence_ids =
+            merge_reference_sequences(&mut merged_header, header, sort_order)?;
+        let read_group_ids = merge_read_groups(&mut merged_header, header);
+        let program_ids = merge_programs(&mut merged_header, header);
+
+        for comment in header.comments() {
+            if !merged_header.comments().contains(comment) {
+                merged_header.add_comment(comment.clone());
+            }
+        }
+
+        translations.push(Translation {
+            reference_sequence_ids,
+            read_group_ids,
+            program_ids,
+        });
+    }
+
+    Ok((merged_header, translations))
+}
+
+fn set_sort_order(map: &mut Map<map::Header>, sort_order: SortOrder) {
+    use sam::header::record::value::map::header::SortOrder as HeaderSortOrder;
+
+    let (so, ss) = match sort_order {
+        SortOrder::Coordinate => (HeaderSortOrder::Coordinate, None),
+        SortOrder::QueryNameNatural => (HeaderSortOrder::QueryName, Some("queryname:natural")),
+        SortOrder::QueryNameLexicographic => (
+            HeaderSortOrder::QueryName,
+            Some("queryname:lexicographical"),
+        ),
+    };
+
+    map.set_sort_order(so);
+
+    let other_fields = map.other_fields_mut();
+    other_fields.shift_remove(&header_tag::SUBSORT_ORDER);
+
+    if let Some(ss) = ss {
+        other_fields.insert(header_tag::SUBSORT_ORDER, ss.into());
+    }
+}
+
+fn merge_reference_sequences(
+    merged_header: &mut sam::Header,
+    header: &sam::Header,
+    sort_order: SortOrder,
+) -> io::Result<Vec<usize>> {
+    let mut ids = Vec::with_capacity(header.reference_sequences().len());
+
+    for (name, reference_sequence) in header.reference_sequences().iter() {
+        let id = match merged_header.reference_sequences().get_full(name) {
+            Some((id, _, merged_reference_sequence)) => {
+                if merged_reference_sequence.length() != reference_sequence.length() {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("conflicting reference sequence length: {name}"),
+                    ));
+                }
+
+                id
+            }
+            None => merged_header
+                .reference_sequences_mut()
+                .insert(name.clone(), reference_sequence.clone())?,
+        };
+
+        ids.push(id);
+    }
+
+    // Translated IDs must keep the input order for coordinate-sorted records to remain sorted.
+    if sort_order == SortOrder::Coordinate && ids.windows(2).any(|w| w[0] > w[1]) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "inconsistent reference sequence order",
+        ));
+    }
+
+    Ok(ids)
+}
+
+fn merge_read_groups(
+    merged_header: &mut sam::Header,
+    header: &sam::Header,
+) -> HashMap<BString, BString> {
+    let mut ids = HashMap::new();
+
+    for (id, read_group) in header.read_groups() {
+        match merged_header.read_groups().get(id) {
+            None => {
+                merged_header
+                    .read_groups_mut()
+                    .insert(id.clone(), read_group.clone());
+            }
+            Some(merged_read_group) if merged_read_group == read_group => {}
+            Some(_) => {
+                let new_id = unique_id(id, |id| {
+                    merged_header.read_groups().contains_key(id)
+                        || header.read_groups().contains_key(id)
+                });
+
+                merged_header
+                    .read_groups_mut()
+                    .insert(new_id.clone(), read_group.clone());
+
+                ids.insert(id.clone(), new_id);
+            }
+        }
+    }
+
+    ids
+}
+
+fn merge_programs(
+    merged_header: &mut sam::Header,
+    header: &sam::Header,
+) -> HashMap<BString, BString> {
+    let programs = header.programs().as_ref();
+    let mut ids = HashMap::new();
+
+    for (id, program) in programs {
+        let merged_programs = merged_header.programs().as_ref();
+
+        if merged_programs.get(id).is_some_and(|p| p != program) {
+            let new_id = unique_id(id, |id| {
+                merged_programs.contains_key(id)
+                    || programs.contains_key(id)
+                    || ids.values().any(|new_id| new_id == id)
+            });
+
+            ids.insert(id.clone(), new_id);
+        }
+    }
+
+    for (id, program) in programs {
+        let id = ids.get(id).unwrap_or(id);
+
+        if merged_header.programs().as_ref().contains_key(id) {
+            continue;
+        }
+
+        let mut program = program.clone();
+
+        if let Some(previous_program_id) = program
+            .other_fields_mut()
+            .get_mut(&program_tag::PREVIOUS_PROGRAM_ID)
+        {
+            if let Some(new_id) = ids.get(previous_program_id) {
+                *previous_program_id = new_id.clone();
+            }
+        }
+
+        merged_header
+            .programs_mut()
+            .as_mut()
+            .insert(id.clone(), program);
+    }
+
+    ids
+}
+
+fn unique_id<F>(id: &BString, is_used: F) -> BString
+where
+    F: Fn(&BString) -> bool,
+{
+    (1..)
+        .map(|i| BString::from(format!("{id}.{i}")))
+        .find(|new_id| !is_used(new_id))
+        // SAFETY: The range is unbounded.
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use sam::header::record::value::map::{read_group::tag as read_group_tag, ReferenceSequence};
+
+    use super::*;
+
+    const SQ0_LN: NonZeroUsize = match NonZeroUsize::new(8) {
+        Some(n) => n,
+        None => unreachable!(),
+    };
+
+    const SQ1_LN: NonZeroUsize = match NonZeroUsize::new(13) {
+        Some(n) => n,
+        None => unreachable!(),
+    };
+
+    #[test]
+    fn test_merge() -> Result<(), Box<dyn std::error::Error>> {
+        let a = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(SQ0_LN))
+            .add_read_group(
+                "rg0",
+                Map::<map::ReadGroup>::builder()
+                    .insert(read_group_tag::SAMPLE, "sample0")
+                    .build()?,
+            )
+            .add_program("pg0", Map::default())
+            .add_comment("noodles")
+            .build();
+
+        let b = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(SQ0_LN))
+            .add_reference_sequence("sq1", Map::<ReferenceSequence>::new(SQ1_LN))
+            .add_read_group(
+                "rg0",
+                Map::<map::ReadGroup>::builder()
+                    .insert(read_group_tag::SAMPLE, "sample1")
+                    .build()?,
+            )
+            .add_program(
+                "pg0",
+                Map::<map::Program>::builder()
+                    .insert(program_tag::NAME, "noodles")
+                    .build()?,
+            )
+            .add_program(
+                "pg1",
+                Map::<map::Program>::builder()
+                    .insert(program_tag::PREVIOUS_PROGRAM_ID, "pg0")
+                    .build()?,
+            )
+            .add_comment("noodles")
+            .build();
+
+        let (header, translations) = merge(&[a, b], SortOrder::Coordinate)?;
+
+        assert_eq!(
+            header.sort_order(),
+            Ok(sam::header::record::value::map::header::SortOrder::Coordinate)
+        );
+
+        assert_eq!(header.reference_sequences().len(), 2);
+
+        let read_group_ids: Vec<_> = header.read_groups().keys().collect();
+        assert_eq!(
+            read_group_ids,
+            [&BString::from("rg0"), &BString::from("rg0.1")]
+        );
+
+        let programs = header.programs().as_ref();
+        let program_ids: Vec<_> = programs.keys().collect();
+        assert_eq!(
+            program_ids,
+            [
+                &BString::from("pg0"),
+                &BString::from("pg0.1"),
+                &BString::from("pg1")
+            ]
+        );
+        assert_eq!(
+            programs[&BString::from("pg1")]
+                .other_fields()
+                .get(&program_tag::PREVIOUS_PROGRAM_ID),
+            Some(&BString::from("pg0.1"))
+        );
+
+        assert_eq!(header.comments().len(), 1);
+
+        assert_eq!(translations[0].reference_sequence_ids, [0]);
+        assert!(translations[0].read_group_ids.is_empty());
+        assert_eq!(translations[1].reference_sequence_ids, [0, 1]);
+        assert_eq!(
+            translations[1].read_group_ids.get(&BString::from("rg0")),
+            Some(&BString::from("rg0.1"))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_conflicting_reference_sequence_length() {
+        let a = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(SQ0_LN))
+            .build();
+
+        let b = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(SQ1_LN))
+            .build();
+
+        assert!(matches!(
+            merge(&[a, b], SortOrder::Coordinate),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_merge_with_inconsistent_reference_sequence_order() {
+        let a = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(SQ0_LN))
+            .add_reference_sequence("sq1", Map::<ReferenceSequence>::new(SQ1_LN))
+            .build();
+
+        let b = sam::Header::builder()
+            .add_reference_sequence("sq1", Map::<ReferenceSequence>::new(SQ1_LN))
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(SQ0_LN))
+            .build();
+
+        assert!(merge(&[a.clone(), b.clone()], SortOrder::Coordinate).is_err());
+        assert!(merge(&[a, b], SortOrder::QueryNameNatural).is_ok());
+    }
+}
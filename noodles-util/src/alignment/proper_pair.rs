@@ -0,0 +1,437 @@
+//! Alignment record proper pair recomputation.
+//!
+//! Aligners set the properly aligned flag (`0x02`) inconsistently. This recomputes it from the
+//! relative orientation of mates and an insert size model, which can either be given or
+//! estimated from the input.
+
+use noodles_sam::alignment::{record::Flags, RecordBuf};
+
+/// The expected relative orientation of properly paired mates.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Orientation {
+    /// The leftmost mate is on the forward strand, and the rightmost mate is on the reverse
+    /// strand (`FR`), e.g., Illumina paired-end reads.
+    #[default]
+    ForwardReverse,
+    /// The leftmost mate is on the reverse strand, and the rightmost mate is on the forward
+    /// strand (`RF`), e.g., Illumina mate pair reads.
+    ReverseForward,
+    /// Both mates are on the same strand (`FF` or `RR`).
+    Tandem,
+}
+
+impl Orientation {
+    fn matches(self, record: &RecordBuf) -> Option<bool> {
+        let flags = record.flags();
+
+        let is_reverse = flags.is_reverse_complemented();
+        let is_mate_reverse = flags.is_mate_reverse_complemented();
+
+        let start = record.alignment_start()?;
+        let mate_start = record.mate_alignment_start()?;
+
+        let is_matched = match self {
+            Self::ForwardReverse | Self::ReverseForward if is_reverse == is_mate_reverse => false,
+            Self::ForwardReverse | Self::ReverseForward if start == mate_start => true,
+            Self::ForwardReverse => (start < mate_start) != is_reverse,
+            Self::ReverseForward => (start < mate_start) == is_reverse,
+            Self::Tandem => is_reverse == is_mate_reverse,
+        };
+
+        Some(is_matched)
+    }
+}
+
+/// An insert size model.
+///
+/// This is the inclusive range of insert sizes (absolute template lengths) of properly paired
+/// mates.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InsertSizeModel {
+    min: u32,
+    max: u32,
+}
+
+impl InsertSizeModel {
+    /// Creates an insert size model from an inclusive range of insert sizes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::proper_pair::InsertSizeModel;
+    /// let model = InsertSizeModel::new(100, 500);
+    /// ```
+    pub fn new(min: u32, max: u32) -> Self {
+        Self { min, max }
+    }
+
+    /// Estimates an insert size model from records.
+    ///
+    /// Only mapped primary first segments on the same reference sequence as their mates and in
+    /// the given orientation are considered. The model is the range of insert sizes within 3
+    /// interquartile ranges (IQR) of the first (Q1) and third (Q3) quartiles, i.e.,
+    /// [Q1 - 3 IQR, Q3 + 3 IQR].
+    ///
+    /// This returns `None` if no records are considered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_sam::alignment::{record::Flags, RecordBuf};
+    /// use noodles_util::alignment::proper_pair::{InsertSizeModel, Orientation};
+    ///
+    /// let record = RecordBuf::builder()
+    ///     .set_flags(Flags::SEGMENTED | Flags::FIRST_SEGMENT | Flags::MATE_REVERSE_COMPLEMENTED)
+    ///     .set_reference_sequence_id(0)
+    ///     .set_alignment_start(Position::try_from(8)?)
+    ///     .set_mate_reference_sequence_id(0)
+    ///     .set_mate_alignment_start(Position::try_from(213)?)
+    ///     .set_template_length(300)
+    ///     .build();
+    ///
+    /// let model = InsertSizeModel::estimate([&record], Orientation::default());
+    /// assert_eq!(model, Some(InsertSizeModel::new(300, 300)));
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn estimate<'a, I>(records: I, orientation: Orientation) -> Option<Self>
+    where
+        I: IntoIterator<Item = &'a RecordBuf>,
+    {
+        let mut insert_sizes: Vec<_> = records
+            .into_iter()
+            .filter(|record| {
+                let flags = record.flags();
+                flags.is_first_segment() && is_paired_on_same_reference_sequence(record)
+            })
+            .filter(|record| orientation.matches(record).unwrap_or(false))
+            .map(|record| record.template_length().unsigned_abs())
+            .filter(|&insert_size| insert_size > 0)
+            .collect();
+
+        if insert_sizes.is_empty() {
+            return None;
+        }
+
+        insert_sizes.sort_unstable();
+
+        let q1 = u64::from(percentile(&insert_sizes, 25));
+        let q3 = u64::from(percentile(&insert_sizes, 75));
+        let iqr = q3 - q1;
+
+        let min = q1.saturating_sub(3 * iqr);
+        let max = (q3 + 3 * iqr).min(u64::from(u32::MAX));
+
+        // SAFETY: `min <= q1 <= u32::MAX` and `max <= u32::MAX`.
+        Some(Self::new(min as u32, max as u32))
+    }
+
+    /// Returns the minimum insert size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::proper_pair::InsertSizeModel;
+    /// let model = InsertSizeModel::new(100, 500);
+    /// assert_eq!(model.min(), 100);
+    /// ```
+    pub fn min(&self) -> u32 {
+        self.min
+    }
+
+    /// Returns the maximum insert size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::proper_pair::InsertSizeModel;
+    /// let model = InsertSizeModel::new(100, 500);
+    /// assert_eq!(model.max(), 500);
+    /// ```
+    pub fn max(&self) -> u32 {
+        self.max
+    }
+
+    /// Returns whether the given insert size is in the model range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::proper_pair::InsertSizeModel;
+    /// let model = InsertSizeModel::new(100, 500);
+    /// assert!(model.contains(300));
+    /// assert!(!model.contains(800));
+    /// ```
+    pub fn contains(&self, insert_size: u32) -> bool {
+        (self.min..=self.max).contains(&insert_size)
+    }
+}
+
+/// Returns whether a record is properly paired.
+///
+/// A record is properly paired if it is a primary segment, both it and its mate are mapped to
+/// the same reference sequence, the mates are in the given orientation, and the insert size
+/// (absolute template length) is in the model range.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::alignment::{record::Flags, RecordBuf};
+/// use noodles_util::alignment::proper_pair::{self, InsertSizeModel, Orientation};
+///
+/// let record = RecordBuf::builder()
+///     .set_flags(Flags::SEGMENTED | Flags::FIRST_SEGMENT | Flags::MATE_REVERSE_COMPLEMENTED)
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(Position::try_from(8)?)
+///     .set_mate_reference_sequence_id(0)
+///     .set_mate_alignment_start(Position::try_from(213)?)
+///     .set_template_length(300)
+///     .build();
+///
+/// let model = InsertSizeModel::new(100, 500);
+/// assert!(proper_pair::is_proper_pair(&record, &model, Orientation::ForwardReverse));
+///
+/// let model = InsertSizeModel::new(400, 500);
+/// assert!(!proper_pair::is_proper_pair(&record, &model, Orientation::ForwardReverse));
+/// # Ok::<_, noodles_core::position::TryFromIntError>(())
+/// ```
+pub fn is_proper_pair(
+    record: &RecordBuf,
+    model: &InsertSizeModel,
+    orientation: Orientation,
+) -> bool {
+    is_paired_on_same_reference_sequence(record)
+        && orientation.matches(record).unwrap_or(false)
+        && model.contains(record.template_length().unsigned_abs())
+}
+
+/// Recomputes and sets the properly aligned flag of a record.
+///
+/// See [`is_proper_pair`].
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::alignment::{record::Flags, RecordBuf};
+/// use noodles_util::alignment::proper_pair::{self, InsertSizeModel, Orientation};
+///
+/// let mut record = RecordBuf::builder()
+///     .set_flags(Flags::SEGMENTED | Flags::PROPERLY_SEGMENTED | Flags::MATE_UNMAPPED)
+///     .build();
+///
+/// let model = InsertSizeModel::new(100, 500);
+/// proper_pair::set_proper_pair(&mut record, &model, Orientation::default());
+///
+/// assert!(!record.flags().is_properly_segmented());
+/// ```
+pub fn set_proper_pair(record: &mut RecordBuf, model: &InsertSizeModel, orientation: Orientation) {
+    let is_proper_pair = is_proper_pair(record, model, orientation);
+    record
+        .flags_mut()
+        .set(Flags::PROPERLY_SEGMENTED, is_proper_pair);
+}
+
+fn is_paired_on_same_reference_sequence(record: &RecordBuf) -> bool {
+    let flags = record.flags();
+
+    if !flags.is_segmented()
+        || flags.is_unmapped()
+        || flags.is_mate_unmapped()
+        || flags.is_secondary()
+        || flags.is_supplementary()
+    {
+        return false;
+    }
+
+    match (
+        record.reference_sequence_id(),
+        record.mate_reference_sequence_id(),
+    ) {
+        (Some(id), Some(mate_id)) => id == mate_id,
+        _ => false,
+    }
+}
+
+fn percentile(sorted_values: &[u32], p: usize) -> u32 {
+    let i = (sorted_values.len() - 1) * p / 100;
+    sorted_values[i]
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+
+    #[test]
+    fn test_orientation_matches() -> Result<(), Box<dyn std::error::Error>> {
+        let fr = RecordBuf::builder()
+            .set_flags(Flags::SEGMENTED | Flags::MATE_REVERSE_COMPLEMENTED)
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(8)?)
+            .set_mate_reference_sequence_id(0)
+            .set_mate_alignment_start(Position::try_from(13)?)
+            .set_template_length(21)
+            .build();
+        let rf = RecordBuf::builder()
+            .set_flags(Flags::SEGMENTED | Flags::REVERSE_COMPLEMENTED)
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(8)?)
+            .set_mate_reference_sequence_id(0)
+            .set_mate_alignment_start(Position::try_from(13)?)
+            .set_template_length(21)
+            .build();
+        let ff = RecordBuf::builder()
+            .set_flags(Flags::SEGMENTED)
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(8)?)
+            .set_mate_reference_sequence_id(0)
+            .set_mate_alignment_start(Position::try_from(13)?)
+            .set_template_length(21)
+            .build();
+        let rr = RecordBuf::builder()
+            .set_flags(
+                Flags::SEGMENTED | Flags::REVERSE_COMPLEMENTED | Flags::MATE_REVERSE_COMPLEMENTED,
+            )
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(8)?)
+            .set_mate_reference_sequence_id(0)
+            .set_mate_alignment_start(Position::try_from(13)?)
+            .set_template_length(21)
+            .build();
+        let fr_rightmost = RecordBuf::builder()
+            .set_flags(Flags::SEGMENTED | Flags::REVERSE_COMPLEMENTED)
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(13)?)
+            .set_mate_reference_sequence_id(0)
+            .set_mate_alignment_start(Position::try_from(8)?)
+            .set_template_length(-21)
+            .build();
+
+        assert_eq!(Orientation::ForwardReverse.matches(&fr), Some(true));
+        assert_eq!(Orientation::ForwardReverse.matches(&rf), Some(false));
+        assert_eq!(Orientation::ForwardReverse.matches(&ff), Some(false));
+        assert_eq!(
+            Orientation::ForwardReverse.matches(&fr_rightmost),
+            Some(true)
+        );
+
+        assert_eq!(Orientation::ReverseForward.matches(&fr), Some(false));
+        assert_eq!(Orientation::ReverseForward.matches(&rf), Some(true));
+        assert_eq!(Orientation::ReverseForward.matches(&rr), Some(false));
+
+        assert_eq!(Orientation::Tandem.matches(&fr), Some(false));
+        assert_eq!(Orientation::Tandem.matches(&ff), Some(true));
+        assert_eq!(Orientation::Tandem.matches(&rr), Some(true));
+
+        assert!(Orientation::default()
+            .matches(&RecordBuf::default())
+            .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_estimate() -> Result<(), Box<dyn std::error::Error>> {
+        let mut records = Vec::new();
+
+        for template_length in [280, 290, 300, 300, 310, 320] {
+            records.push(
+                RecordBuf::builder()
+                    .set_flags(
+                        Flags::SEGMENTED | Flags::FIRST_SEGMENT | Flags::MATE_REVERSE_COMPLEMENTED,
+                    )
+                    .set_reference_sequence_id(0)
+                    .set_alignment_start(Position::try_from(1)?)
+                    .set_mate_reference_sequence_id(0)
+                    .set_mate_alignment_start(Position::try_from(2)?)
+                    .set_template_length(template_length)
+                    .build(),
+            );
+
+            // Last segments are not counted.
+            records.push(
+                RecordBuf::builder()
+                    .set_flags(Flags::SEGMENTED | Flags::LAST_SEGMENT | Flags::REVERSE_COMPLEMENTED)
+                    .set_reference_sequence_id(0)
+                    .set_alignment_start(Position::try_from(2)?)
+                    .set_mate_reference_sequence_id(0)
+                    .set_mate_alignment_start(Position::try_from(1)?)
+                    .set_template_length(-template_length)
+                    .build(),
+            );
+        }
+
+        // Improperly oriented and unmapped mates are not counted.
+        records.push(
+            RecordBuf::builder()
+                .set_flags(Flags::SEGMENTED | Flags::FIRST_SEGMENT)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(1)?)
+                .set_mate_reference_sequence_id(0)
+                .set_mate_alignment_start(Position::try_from(2)?)
+                .set_template_length(10000)
+                .build(),
+        );
+        records.push(
+            RecordBuf::builder()
+                .set_flags(
+                    Flags::SEGMENTED
+                        | Flags::FIRST_SEGMENT
+                        | Flags::MATE_UNMAPPED
+                        | Flags::MATE_REVERSE_COMPLEMENTED,
+                )
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(1)?)
+                .set_mate_reference_sequence_id(0)
+                .set_mate_alignment_start(Position::try_from(2)?)
+                .set_template_length(10000)
+                .build(),
+        );
+
+        let model = InsertSizeModel::estimate(&records, Orientation::ForwardReverse);
+        // Q1 = 290, Q3 = 300, IQR = 10
+        assert_eq!(model, Some(InsertSizeModel::new(260, 330)));
+
+        assert!(InsertSizeModel::estimate(&[], Orientation::default()).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_proper_pair() -> Result<(), Box<dyn std::error::Error>> {
+        let model = InsertSizeModel::new(100, 500);
+
+        let mut record = RecordBuf::builder()
+            .set_flags(Flags::SEGMENTED | Flags::MATE_REVERSE_COMPLEMENTED)
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(8)?)
+            .set_mate_reference_sequence_id(0)
+            .set_mate_alignment_start(Position::try_from(213)?)
+            .set_template_length(300)
+            .build();
+        set_proper_pair(&mut record, &model, Orientation::ForwardReverse);
+        assert!(record.flags().is_properly_segmented());
+
+        *record.template_length_mut() = 1000;
+        set_proper_pair(&mut record, &model, Orientation::ForwardReverse);
+        assert!(!record.flags().is_properly_segmented());
+
+        let mut record = RecordBuf::builder()
+            .set_flags(
+                Flags::SEGMENTED | Flags::PROPERLY_SEGMENTED | Flags::MATE_REVERSE_COMPLEMENTED,
+            )
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(8)?)
+            .set_mate_reference_sequence_id(0)
+            .set_mate_alignment_start(Position::try_from(213)?)
+            .set_template_length(300)
+            .build();
+        *record.mate_reference_sequence_id_mut() = Some(1);
+        set_proper_pair(&mut record, &model, Orientation::ForwardReverse);
+        assert!(!record.flags().is_properly_segmented());
+
+        Ok(())
+    }
+}
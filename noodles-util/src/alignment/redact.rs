@@ -0,0 +1,337 @@
+//! Alignment record redaction.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    io,
+};
+
+use noodles_sam::{
+    self as sam,
+    alignment::{io::Write, record::data::field::Tag, Record, RecordBuf},
+    header::record::value::map::read_group::tag as read_group_tag,
+};
+
+/// The default data field tags to remove from records.
+///
+/// These are sample barcodes (`BC`, `QT`), UMIs (`RX`, `QX`, `OX`, `BZ`, `MI`), and cell barcodes
+/// (`CB`, `CR`, `CY`).
+pub const DEFAULT_TAGS: [Tag; 10] = [
+    Tag::SAMPLE_BARCODE_SEQUENCE,
+    Tag::SAMPLE_BARCODE_QUALITY_SCORES,
+    Tag::UMI_SEQUENCE,
+    Tag::UMI_QUALITY_SCORES,
+    Tag::ORIGINAL_UMI_BARCODE_SEQUENCE,
+    Tag::new(b'B', b'Z'),
+    Tag::UMI_ID,
+    Tag::CELL_BARCODE_ID,
+    Tag::CELL_BARCODE_SEQUENCE,
+    Tag::CELL_BARCODE_QUALITY_SCORES,
+];
+
+/// A redaction of a field value.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Redaction {
+    /// The value is kept as is.
+    Keep,
+    /// The value is removed.
+    Remove,
+    /// The value is replaced with a salted hash of it.
+    ///
+    /// Equal values are replaced with equal hashes, e.g., mates keep the same read name.
+    Hash,
+}
+
+/// A writer adapter that redacts identifying content from alignment headers and records.
+///
+/// This wraps an alignment writer and rewrites the header and each record before passing them
+/// to the inner writer. Read names and read group samples (`SM`) are hashed, and barcode and UMI
+/// data fields (see [`DEFAULT_TAGS`]) are removed by default. The alignment itself, i.e., the
+/// flags, positions, CIGAR, sequence, and quality scores, is kept, so the output remains valid
+/// and can be used as a shareable test fixture.
+///
+/// Hashes are only stable for a given salt and build of the library. Set a secret salt (see
+/// [`Builder::set_salt`]) to prevent recovering short values by brute force.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{io::Write, RecordBuf},
+/// };
+/// use noodles_util::alignment::redact;
+///
+/// let mut writer = redact::Builder::default()
+///     .set_salt("noodles")
+///     .build_from_writer(sam::io::Writer::new(Vec::new()));
+///
+/// let header = sam::Header::default();
+/// writer.write_alignment_header(&header)?;
+///
+/// let record = RecordBuf::builder().set_name("r0").build();
+/// writer.write_alignment_record(&header, &record)?;
+///
+/// writer.finish(&header)?;
+///
+/// let output = writer.into_inner().into_inner();
+/// assert!(!output.starts_with(b"r0\t"));
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub struct Writer<W> {
+    inner: W,
+    read_names: Redaction,
+    samples: Redaction,
+    tags: Vec<Tag>,
+    salt: Vec<u8>,
+}
+
+impl<W> Writer<W> {
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::redact;
+    /// let writer = redact::Builder::default().build_from_writer(sam::io::Writer::new(Vec::new()));
+    /// assert!(writer.get_ref().get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::redact;
+    /// let mut writer = redact::Builder::default().build_from_writer(sam::io::Writer::new(Vec::new()));
+    /// assert!(writer.get_mut().get_mut().is_empty());
+    /// ```
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::redact;
+    /// let writer = redact::Builder::default().build_from_writer(sam::io::Writer::new(Vec::new()));
+    /// assert!(writer.into_inner().into_inner().is_empty());
+    /// ```
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Redacts a SAM header.
+    ///
+    /// This redacts the sample (`SM`) of each read group. The reference sequences and read group
+    /// IDs are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     header::record::value::{map::ReadGroup, Map},
+    /// };
+    /// use noodles_util::alignment::redact::{self, Redaction};
+    ///
+    /// let writer = redact::Builder::default()
+    ///     .set_samples(Redaction::Remove)
+    ///     .build_from_writer(sam::io::Writer::new(Vec::new()));
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_read_group("rg0", Map::<ReadGroup>::builder().set_sample("sample0").build()?)
+    ///     .build();
+    ///
+    /// let actual = writer.redact_header(&header);
+    /// assert!(actual.read_groups()[&b"rg0"[..]].sample().is_none());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn redact_header(&self, header: &sam::Header) -> sam::Header {
+        let mut header = header.clone();
+
+        for read_group in header.read_groups_mut().values_mut() {
+            let other_fields = read_group.other_fields_mut();
+
+            match self.samples {
+                Redaction::Keep => {}
+                Redaction::Remove => {
+                    other_fields.shift_remove(&read_group_tag::SAMPLE);
+                }
+                Redaction::Hash => {
+                    if let Some(sample) = other_fields.get_mut(&read_group_tag::SAMPLE) {
+                        *sample = hash(&self.salt, sample).into();
+                    }
+                }
+            }
+        }
+
+        header
+    }
+
+    /// Redacts an alignment record.
+    ///
+    /// This redacts the read name and removes the configured data fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     alignment::{
+    ///         record::data::field::Tag,
+    ///         record_buf::data::field::Value,
+    ///         RecordBuf,
+    ///     },
+    /// };
+    /// use noodles_util::alignment::redact::{self, Redaction};
+    ///
+    /// let writer = redact::Builder::default()
+    ///     .set_read_names(Redaction::Remove)
+    ///     .build_from_writer(sam::io::Writer::new(Vec::new()));
+    ///
+    /// let header = sam::Header::default();
+    /// let record = RecordBuf::builder()
+    ///     .set_name("r0")
+    ///     .set_data(
+    ///         [
+    ///             (Tag::SAMPLE_BARCODE_SEQUENCE, Value::from("ACGT")),
+    ///             (Tag::ALIGNMENT_HIT_COUNT, Value::from(1)),
+    ///         ]
+    ///         .into_iter()
+    ///         .collect(),
+    ///     )
+    ///     .build();
+    ///
+    /// let actual = writer.redact_record(&header, &record)?;
+    /// assert!(actual.name().is_none());
+    /// assert_eq!(actual.data().keys().collect::<Vec<_>>(), [Tag::ALIGNMENT_HIT_COUNT]);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn redact_record(
+        &self,
+        header: &sam::Header,
+        record: &dyn Record,
+    ) -> io::Result<RecordBuf> {
+        let mut record = RecordBuf::try_from_alignment_record(header, record)?;
+
+        match self.read_names {
+            Redaction::Keep => {}
+            Redaction::Remove => *record.name_mut() = None,
+            Redaction::Hash => {
+                if let Some(name) = record.name_mut() {
+                    *name = hash(&self.salt, name).into();
+                }
+            }
+        }
+
+        let data = record.data_mut();
+
+        for tag in &self.tags {
+            data.remove(tag);
+        }
+
+        Ok(record)
+    }
+}
+
+impl<W> Write for Writer<W>
+where
+    W: Write,
+{
+    fn write_alignment_header(&mut self, header: &sam::Header) -> io::Result<()> {
+        let header = self.redact_header(header);
+        self.inner.write_alignment_header(&header)
+    }
+
+    fn write_alignment_record(
+        &mut self,
+        header: &sam::Header,
+        record: &dyn Record,
+    ) -> io::Result<()> {
+        let record = self.redact_record(header, record)?;
+        self.inner.write_alignment_record(header, &record)
+    }
+
+    fn finish(&mut self, header: &sam::Header) -> io::Result<()> {
+        self.inner.finish(header)
+    }
+}
+
+fn hash(salt: &[u8], value: &[u8]) -> String {
+    let mut hasher = DefaultHasher::new();
+    salt.hash(&mut hasher);
+    value.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::{
+        alignment::record_buf::data::field::Value,
+        header::record::value::{map::ReadGroup, Map},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_write_alignment_record() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = Builder::default()
+            .set_salt("noodles")
+            .build_from_writer(sam::io::Writer::new(Vec::new()));
+
+        let header = sam::Header::builder()
+            .add_read_group(
+                "rg0",
+                Map::<ReadGroup>::builder().set_sample("sample0").build()?,
+            )
+            .build();
+
+        writer.write_alignment_header(&header)?;
+
+        let read_1 = RecordBuf::builder()
+            .set_name("r0")
+            .set_data(
+                [
+                    (Tag::READ_GROUP, Value::from("rg0")),
+                    (Tag::UMI_SEQUENCE, Value::from("ACGT")),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        let read_2 = RecordBuf::builder().set_name("r0").build();
+
+        writer.write_alignment_record(&header, &read_1)?;
+        writer.write_alignment_record(&header, &read_2)?;
+        writer.finish(&header)?;
+
+        let output = String::from_utf8(writer.into_inner().into_inner())?;
+        let lines: Vec<_> = output.lines().collect();
+
+        let sample = hash(b"noodles", b"sample0");
+        assert_eq!(lines[0], format!("@RG\tID:rg0\tSM:{sample}"));
+
+        let name = hash(b"noodles", b"r0");
+        assert_eq!(
+            lines[1],
+            format!("{name}\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\tRG:Z:rg0")
+        );
+        assert_eq!(lines[2], format!("{name}\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+use noodles_sam::alignment::record::data::field::Tag;
+
+use super::{Redaction, Writer, DEFAULT_TAGS};
+
+/// An alignment redacting writer builder.
+#[derive(Clone, Debug)]
+pub struct Builder {
+    read_names: Redaction,
+    samples: Redaction,
+    tags: Vec<Tag>,
+    salt: Vec<u8>,
+}
+
+impl Builder {
+    /// Sets how read names are redacted.
+    ///
+    /// Removing read names breaks the association between mates. Hashing keeps it.
+    ///
+    /// By default, read names are hashed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::redact::{self, Redaction};
+    /// let builder = redact::Builder::default().set_read_names(Redaction::Remove);
+    /// ```
+    pub fn set_read_names(mut self, read_names: Redaction) -> Self {
+        self.read_names = read_names;
+        self
+    }
+
+    /// Sets how read group samples (`SM`) are redacted.
+    ///
+    /// By default, samples are hashed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::redact::{self, Redaction};
+    /// let builder = redact::Builder::default().set_samples(Redaction::Remove);
+    /// ```
+    pub fn set_samples(mut self, samples: Redaction) -> Self {
+        self.samples = samples;
+        self
+    }
+
+    /// Sets the data field tags to remove from records.
+    ///
+    /// By default, these are [`DEFAULT_TAGS`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::data::field::Tag;
+    /// use noodles_util::alignment::redact;
+    /// let builder = redact::Builder::default().set_tags([Tag::COMMENT]);
+    /// ```
+    pub fn set_tags<I>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = Tag>,
+    {
+        self.tags = tags.into_iter().collect();
+        self
+    }
+
+    /// Sets the salt used to hash values.
+    ///
+    /// By default, the salt is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::redact;
+    /// let builder = redact::Builder::default().set_salt("noodles");
+    /// ```
+    pub fn set_salt<S>(mut self, salt: S) -> Self
+    where
+        S: Into<Vec<u8>>,
+    {
+        self.salt = salt.into();
+        self
+    }
+
+    /// Builds an alignment redacting writer from an alignment writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::redact;
+    /// let writer = redact::Builder::default().build_from_writer(sam::io::Writer::new(Vec::new()));
+    /// ```
+    pub fn build_from_writer<W>(self, writer: W) -> Writer<W> {
+        Writer {
+            inner: writer,
+            read_names: self.read_names,
+            samples: self.samples,
+            tags: self.tags,
+            salt: self.salt,
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            read_names: Redaction::Hash,
+            samples: Redaction::Hash,
+            tags: DEFAULT_TAGS.to_vec(),
+            salt: Vec::new(),
+        }
+    }
+}
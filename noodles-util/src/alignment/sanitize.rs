@@ -0,0 +1,663 @@
+//! Alignment record sanitization.
+//!
+//! This fixes common specification violations in alignment records, similar to Picard
+//! `CleanSam` and `samtools fixmate`, so that records from misbehaving tools can be written and
+//! read by strict consumers. Each fix that is applied to a record is reported.
+
+use std::{fmt, io};
+
+use noodles_core::Position;
+use noodles_sam::{
+    self as sam,
+    alignment::{
+        record::{
+            cigar::{op::Kind, Op},
+            Flags, MappingQuality,
+        },
+        record_buf::Cigar,
+        RecordBuf,
+    },
+};
+
+/// A fix applied to an alignment record.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Fix {
+    /// The alignment extended past the end of the reference sequence, and the overhang was soft
+    /// clipped.
+    SoftClippedOverhang,
+    /// The record was mapped but was missing a reference sequence ID or alignment start or
+    /// started past the end of the reference sequence, and it was marked as unmapped.
+    MarkedUnmapped,
+    /// The unmapped record had a mapping quality other than 0, and it was set to 0.
+    ResetUnmappedMappingQuality,
+    /// The unmapped record had a CIGAR, and it was cleared.
+    ClearedUnmappedCigar,
+    /// The unmapped record was not placed at its mate's position, or it was placed without a
+    /// mapped mate, and it was moved.
+    MovedUnmapped,
+    /// The mate flags, reference sequence ID, or alignment start did not match the mate, or
+    /// they were set for an unsegmented record, and they were updated.
+    UpdatedMateFields,
+    /// The properly segmented flag was set for an unsegmented or unmapped pair, and it was
+    /// cleared.
+    ClearedProperPair,
+    /// The template length did not match the mate alignment, and it was updated.
+    UpdatedTemplateLength,
+}
+
+impl fmt::Display for Fix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SoftClippedOverhang => write!(f, "soft clipped overhang"),
+            Self::MarkedUnmapped => write!(f, "marked unmapped"),
+            Self::ResetUnmappedMappingQuality => write!(f, "reset unmapped mapping quality"),
+            Self::ClearedUnmappedCigar => write!(f, "cleared unmapped CIGAR"),
+            Self::MovedUnmapped => write!(f, "moved unmapped"),
+            Self::UpdatedMateFields => write!(f, "updated mate fields"),
+            Self::ClearedProperPair => write!(f, "cleared proper pair"),
+            Self::UpdatedTemplateLength => write!(f, "updated template length"),
+        }
+    }
+}
+
+/// Sanitizes an alignment record.
+///
+/// The following fixes are applied, in order:
+///
+///   1. A mapped record without a reference sequence ID or alignment start, or that starts past
+///      the end of the reference sequence, is marked as unmapped.
+///   2. An alignment that extends past the end of the reference sequence is soft clipped at the
+///      reference sequence end.
+///   3. An unmapped record has its mapping quality set to 0 and CIGAR cleared. If its mate is
+///      mapped, it is placed at the mate position. Otherwise, its reference sequence ID and
+///      alignment start are cleared.
+///   4. An unsegmented record has its mate flags, mate fields, and template length cleared.
+///   5. A segmented record that is unmapped or has an unmapped mate has its properly segmented
+///      flag and template length cleared.
+///
+/// This only uses the record itself. Use [`sanitize_mates`] to also synchronize mate fields.
+///
+/// # Errors
+///
+/// This returns an error if the reference sequence ID is not in the header.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{
+///         record::{Flags, MappingQuality},
+///         RecordBuf,
+///     },
+/// };
+/// use noodles_util::alignment::sanitize::{self, Fix};
+///
+/// let header = sam::Header::default();
+///
+/// let mut record = RecordBuf::builder()
+///     .set_flags(Flags::UNMAPPED)
+///     .set_mapping_quality(MappingQuality::try_from(13)?)
+///     .build();
+///
+/// let fixes = sanitize::sanitize(&header, &mut record)?;
+///
+/// assert_eq!(fixes, [Fix::ResetUnmappedMappingQuality]);
+/// assert_eq!(record.mapping_quality(), Some(MappingQuality::MIN));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn sanitize(header: &sam::Header, record: &mut RecordBuf) -> io::Result<Vec<Fix>> {
+    let mut fixes = Vec::new();
+
+    if !record.flags().is_unmapped() {
+        clip_to_reference_sequence(header, record, &mut fixes)?;
+    }
+
+    if record.flags().is_unmapped() {
+        sanitize_unmapped(record, &mut fixes);
+
+        let position = record
+            .flags()
+            .is_segmented()
+            .then(|| mate_position(record))
+            .flatten();
+
+        place_unmapped(record, position, &mut fixes);
+    }
+
+    if record.flags().is_segmented() {
+        sanitize_segmented(record, &mut fixes);
+    } else {
+        sanitize_unsegmented(record, &mut fixes);
+    }
+
+    Ok(fixes)
+}
+
+/// Sanitizes a pair of mates.
+///
+/// Each record is first sanitized (see [`sanitize`]). The mate fields of each record are then
+/// synchronized with its mate, i.e., the mate unmapped (`0x08`) and mate reverse complemented
+/// (`0x20`) flags, mate reference sequence ID, and mate alignment start. An unmapped record with
+/// a mapped mate is placed at the mate position.
+///
+/// The template length is recomputed as the distance from the leftmost mapped base to the
+/// rightmost mapped base of the pair. It is positive for the leftmost mate (or the first segment,
+/// if both start at the same position) and negative for the other. It is 0 if either mate is
+/// unmapped or the mates are on different reference sequences.
+///
+/// This returns the fixes applied to each record.
+///
+/// # Errors
+///
+/// This returns an error if either record is not segmented or a reference sequence ID is not in
+/// the header.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroUsize;
+///
+/// use noodles_core::Position;
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{
+///         record::{
+///             cigar::{op::Kind, Op},
+///             Flags,
+///         },
+///         RecordBuf,
+///     },
+///     header::record::value::{map::ReferenceSequence, Map},
+/// };
+/// use noodles_util::alignment::sanitize::{self, Fix};
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::try_from(100)?))
+///     .build();
+///
+/// let mut a = RecordBuf::builder()
+///     .set_flags(Flags::SEGMENTED | Flags::FIRST_SEGMENT)
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(Position::try_from(8)?)
+///     .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+///     .build();
+///
+/// let mut b = RecordBuf::builder()
+///     .set_flags(Flags::SEGMENTED | Flags::LAST_SEGMENT | Flags::REVERSE_COMPLEMENTED)
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(Position::try_from(13)?)
+///     .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+///     .build();
+///
+/// let (a_fixes, _) = sanitize::sanitize_mates(&header, &mut a, &mut b)?;
+///
+/// assert_eq!(a_fixes, [Fix::UpdatedMateFields, Fix::UpdatedTemplateLength]);
+/// assert_eq!(a.mate_alignment_start(), b.alignment_start());
+/// assert_eq!(a.template_length(), 9);
+/// assert_eq!(b.template_length(), -9);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn sanitize_mates(
+    header: &sam::Header,
+    a: &mut RecordBuf,
+    b: &mut RecordBuf,
+) -> io::Result<(Vec<Fix>, Vec<Fix>)> {
+    if !a.flags().is_segmented() || !b.flags().is_segmented() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "mates must be segmented",
+        ));
+    }
+
+    let mut a_fixes = sanitize(header, a)?;
+    let mut b_fixes = sanitize(header, b)?;
+
+    if a.flags().is_unmapped() {
+        place_unmapped(a, mapped_position(b), &mut a_fixes);
+    }
+
+    if b.flags().is_unmapped() {
+        place_unmapped(b, mapped_position(a), &mut b_fixes);
+    }
+
+    update_mate_fields(a, b, &mut a_fixes);
+    update_mate_fields(b, a, &mut b_fixes);
+
+    let (a_template_length, b_template_length) = template_lengths(a, b);
+    set_template_length(a, a_template_length, &mut a_fixes);
+    set_template_length(b, b_template_length, &mut b_fixes);
+
+    sanitize_segmented(a, &mut a_fixes);
+    sanitize_segmented(b, &mut b_fixes);
+
+    Ok((a_fixes, b_fixes))
+}
+
+fn push_fix(fixes: &mut Vec<Fix>, fix: Fix) {
+    if !fixes.contains(&fix) {
+        fixes.push(fix);
+    }
+}
+
+fn clip_to_reference_sequence(
+    header: &sam::Header,
+    record: &mut RecordBuf,
+    fixes: &mut Vec<Fix>,
+) -> io::Result<()> {
+    let (Some(id), Some(start)) = (record.reference_sequence_id(), record.alignment_start()) else {
+        mark_unmapped(record, fixes);
+        return Ok(());
+    };
+
+    let reference_sequence_length = header
+        .reference_sequences()
+        .get_index(id)
+        .map(|(_, reference_sequence)| usize::from(reference_sequence.length()))
+        .ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "invalid reference sequence ID")
+        })?;
+
+    let start = usize::from(start);
+
+    if start > reference_sequence_length {
+        mark_unmapped(record, fixes);
+        return Ok(());
+    }
+
+    let max_span = reference_sequence_length - start + 1;
+
+    if record.cigar().alignment_span() > max_span {
+        *record.cigar_mut() = soft_clip_end(record.cigar(), max_span);
+        push_fix(fixes, Fix::SoftClippedOverhang);
+    }
+
+    Ok(())
+}
+
+fn soft_clip_end(cigar: &Cigar, max_span: usize) -> Cigar {
+    let src = cigar.as_ref();
+
+    let trailing_hard_clip_count = src
+        .iter()
+        .rev()
+        .take_while(|op| op.kind() == Kind::HardClip)
+        .count();
+
+    let (body, trailing_hard_clips) = src.split_at(src.len() - trailing_hard_clip_count);
+
+    let mut ops = Vec::with_capacity(src.len() + 1);
+    let mut remaining = max_span;
+    let mut clip_len = 0;
+
+    for op in body {
+        let kind = op.kind();
+        let len = op.len();
+
+        if remaining == 0 {
+            if kind.consumes_read() {
+                clip_len += len;
+            }
+        } else if kind.consumes_reference() {
+            let n = len.min(remaining);
+            remaining -= n;
+
+            ops.push(Op::new(kind, n));
+
+            if kind.consumes_read() {
+                clip_len += len - n;
+            }
+        } else {
+            ops.push(*op);
+        }
+    }
+
+    // Insertions and deletions adjacent to the clip are not part of the alignment.
+    while let Some(op) = ops.last() {
+        match op.kind() {
+            Kind::Insertion | Kind::SoftClip => clip_len += op.len(),
+            Kind::Deletion | Kind::Skip | Kind::Pad => {}
+            _ => break,
+        }
+
+        ops.pop();
+    }
+
+    if clip_len > 0 {
+        ops.push(Op::new(Kind::SoftClip, clip_len));
+    }
+
+    ops.extend(trailing_hard_clips);
+
+    Cigar::from(ops)
+}
+
+fn mark_unmapped(record: &mut RecordBuf, fixes: &mut Vec<Fix>) {
+    record.flags_mut().insert(Flags::UNMAPPED);
+    push_fix(fixes, Fix::MarkedUnmapped);
+}
+
+fn sanitize_unmapped(record: &mut RecordBuf, fixes: &mut Vec<Fix>) {
+    if record.mapping_quality() != Some(MappingQuality::MIN) {
+        *record.mapping_quality_mut() = Some(MappingQuality::MIN);
+        push_fix(fixes, Fix::ResetUnmappedMappingQuality);
+    }
+
+    if !record.cigar().as_ref().is_empty() {
+        record.cigar_mut().as_mut().clear();
+        push_fix(fixes, Fix::ClearedUnmappedCigar);
+    }
+}
+
+fn mate_position(record: &RecordBuf) -> Option<(usize, Position)> {
+    if record.flags().is_mate_unmapped() {
+        None
+    } else {
+        record
+            .mate_reference_sequence_id()
+            .zip(record.mate_alignment_start())
+    }
+}
+
+fn mapped_position(record: &RecordBuf) -> Option<(usize, Position)> {
+    if record.flags().is_unmapped() {
+        None
+    } else {
+        record.reference_sequence_id().zip(record.alignment_start())
+    }
+}
+
+fn place_unmapped(
+    record: &mut RecordBuf,
+    position: Option<(usize, Position)>,
+    fixes: &mut Vec<Fix>,
+) {
+    let (reference_sequence_id, alignment_start) = position.unzip();
+
+    if record.reference_sequence_id() != reference_sequence_id
+        || record.alignment_start() != alignment_start
+    {
+        *record.reference_sequence_id_mut() = reference_sequence_id;
+        *record.alignment_start_mut() = alignment_start;
+        push_fix(fixes, Fix::MovedUnmapped);
+    }
+}
+
+fn sanitize_unsegmented(record: &mut RecordBuf, fixes: &mut Vec<Fix>) {
+    const MATE_FLAGS: Flags = Flags::MATE_UNMAPPED
+        .union(Flags::MATE_REVERSE_COMPLEMENTED)
+        .union(Flags::FIRST_SEGMENT)
+        .union(Flags::LAST_SEGMENT);
+
+    if record.flags().intersects(MATE_FLAGS)
+        || record.mate_reference_sequence_id().is_some()
+        || record.mate_alignment_start().is_some()
+    {
+        record.flags_mut().remove(MATE_FLAGS);
+        *record.mate_reference_sequence_id_mut() = None;
+        *record.mate_alignment_start_mut() = None;
+        push_fix(fixes, Fix::UpdatedMateFields);
+    }
+
+    if record.flags().is_properly_segmented() {
+        record.flags_mut().remove(Flags::PROPERLY_SEGMENTED);
+        push_fix(fixes, Fix::ClearedProperPair);
+    }
+
+    set_template_length(record, 0, fixes);
+}
+
+fn sanitize_segmented(record: &mut RecordBuf, fixes: &mut Vec<Fix>) {
+    let flags = record.flags();
+
+    if flags.is_unmapped() || flags.is_mate_unmapped() {
+        if flags.is_properly_segmented() {
+            record.flags_mut().remove(Flags::PROPERLY_SEGMENTED);
+            push_fix(fixes, Fix::ClearedProperPair);
+        }
+
+        set_template_length(record, 0, fixes);
+    }
+}
+
+fn update_mate_fields(record: &mut RecordBuf, mate: &RecordBuf, fixes: &mut Vec<Fix>) {
+    let mate_flags = mate.flags();
+
+    let mut flags = record.flags();
+    flags.set(Flags::MATE_UNMAPPED, mate_flags.is_unmapped());
+    flags.set(
+        Flags::MATE_REVERSE_COMPLEMENTED,
+        mate_flags.is_reverse_complemented(),
+    );
+
+    if flags != record.flags()
+        || record.mate_reference_sequence_id() != mate.reference_sequence_id()
+        || record.mate_alignment_start() != mate.alignment_start()
+    {
+        *record.flags_mut() = flags;
+        *record.mate_reference_sequence_id_mut() = mate.reference_sequence_id();
+        *record.mate_alignment_start_mut() = mate.alignment_start();
+        push_fix(fixes, Fix::UpdatedMateFields);
+    }
+}
+
+fn template_lengths(a: &RecordBuf, b: &RecordBuf) -> (i32, i32) {
+    let (Some((a_id, a_start)), Some((b_id, b_start))) = (mapped_position(a), mapped_position(b))
+    else {
+        return (0, 0);
+    };
+
+    if a_id != b_id {
+        return (0, 0);
+    }
+
+    // SAFETY: The alignment starts are set.
+    let a_end = a.alignment_end().unwrap();
+    let b_end = b.alignment_end().unwrap();
+
+    let start = usize::from(a_start.min(b_start));
+    let end = usize::from(a_end.max(b_end));
+
+    let len = i32::try_from(end - start + 1).unwrap_or(i32::MAX);
+
+    let a_is_leftmost = match a_start.cmp(&b_start) {
+        std::cmp::Ordering::Less => true,
+        std::cmp::Ordering::Greater => false,
+        std::cmp::Ordering::Equal => !b.flags().is_first_segment(),
+    };
+
+    if a_is_leftmost {
+        (len, -len)
+    } else {
+        (-len, len)
+    }
+}
+
+fn set_template_length(record: &mut RecordBuf, template_length: i32, fixes: &mut Vec<Fix>) {
+    if record.template_length() != template_length {
+        *record.template_length_mut() = template_length;
+        push_fix(fixes, Fix::UpdatedTemplateLength);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use sam::header::record::value::{map::ReferenceSequence, Map};
+
+    use super::*;
+
+    fn build_header() -> Result<sam::Header, Box<dyn std::error::Error>> {
+        Ok(sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(10)?),
+            )
+            .build())
+    }
+
+    fn build_cigar(ops: &[(Kind, usize)]) -> Cigar {
+        ops.iter().map(|&(kind, len)| Op::new(kind, len)).collect()
+    }
+
+    #[test]
+    fn test_soft_clip_end() {
+        let t = |ops: &[(Kind, usize)], max_span, expected: &[(Kind, usize)]| {
+            assert_eq!(
+                soft_clip_end(&build_cigar(ops), max_span),
+                build_cigar(expected)
+            );
+        };
+
+        t(
+            &[(Kind::Match, 8)],
+            5,
+            &[(Kind::Match, 5), (Kind::SoftClip, 3)],
+        );
+        t(
+            &[(Kind::SoftClip, 2), (Kind::Match, 8), (Kind::SoftClip, 2)],
+            5,
+            &[(Kind::SoftClip, 2), (Kind::Match, 5), (Kind::SoftClip, 5)],
+        );
+        t(
+            &[
+                (Kind::Match, 4),
+                (Kind::Insertion, 2),
+                (Kind::Match, 4),
+                (Kind::HardClip, 3),
+            ],
+            4,
+            &[(Kind::Match, 4), (Kind::SoftClip, 6), (Kind::HardClip, 3)],
+        );
+        t(
+            &[(Kind::Match, 4), (Kind::Deletion, 2), (Kind::Match, 4)],
+            5,
+            &[(Kind::Match, 4), (Kind::SoftClip, 4)],
+        );
+    }
+
+    #[test]
+    fn test_sanitize() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let mut record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(8)?)
+            .set_cigar(build_cigar(&[(Kind::Match, 5)]))
+            .set_mate_reference_sequence_id(0)
+            .set_template_length(8)
+            .build();
+
+        let fixes = sanitize(&header, &mut record)?;
+
+        assert_eq!(
+            fixes,
+            [
+                Fix::SoftClippedOverhang,
+                Fix::UpdatedMateFields,
+                Fix::UpdatedTemplateLength
+            ]
+        );
+        assert_eq!(
+            record.cigar(),
+            &build_cigar(&[(Kind::Match, 3), (Kind::SoftClip, 2)])
+        );
+        assert!(record.mate_reference_sequence_id().is_none());
+        assert_eq!(record.template_length(), 0);
+
+        let mut record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(13)?)
+            .set_cigar(build_cigar(&[(Kind::Match, 5)]))
+            .build();
+
+        let fixes = sanitize(&header, &mut record)?;
+
+        assert_eq!(
+            fixes,
+            [
+                Fix::MarkedUnmapped,
+                Fix::ResetUnmappedMappingQuality,
+                Fix::ClearedUnmappedCigar,
+                Fix::MovedUnmapped
+            ]
+        );
+        assert!(record.flags().is_unmapped());
+        assert!(record.reference_sequence_id().is_none());
+        assert!(record.alignment_start().is_none());
+
+        let mut record = RecordBuf::builder()
+            .set_flags(Flags::SEGMENTED | Flags::UNMAPPED | Flags::PROPERLY_SEGMENTED)
+            .set_mapping_quality(MappingQuality::MIN)
+            .set_mate_reference_sequence_id(0)
+            .set_mate_alignment_start(Position::try_from(5)?)
+            .build();
+
+        let fixes = sanitize(&header, &mut record)?;
+
+        assert_eq!(fixes, [Fix::MovedUnmapped, Fix::ClearedProperPair]);
+        assert_eq!(record.reference_sequence_id(), Some(0));
+        assert_eq!(record.alignment_start(), Position::new(5));
+
+        let mut record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(1)
+            .set_alignment_start(Position::MIN)
+            .build();
+
+        assert!(matches!(
+            sanitize(&header, &mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sanitize_mates() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let mut a = RecordBuf::builder()
+            .set_flags(Flags::SEGMENTED | Flags::FIRST_SEGMENT | Flags::PROPERLY_SEGMENTED)
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(2)?)
+            .set_cigar(build_cigar(&[(Kind::Match, 4)]))
+            .set_template_length(100)
+            .build();
+
+        let mut b = RecordBuf::builder()
+            .set_flags(Flags::SEGMENTED | Flags::LAST_SEGMENT | Flags::UNMAPPED)
+            .set_mapping_quality(MappingQuality::MIN)
+            .build();
+
+        let (a_fixes, b_fixes) = sanitize_mates(&header, &mut a, &mut b)?;
+
+        assert_eq!(
+            a_fixes,
+            [
+                Fix::UpdatedMateFields,
+                Fix::UpdatedTemplateLength,
+                Fix::ClearedProperPair
+            ]
+        );
+        assert!(a.flags().is_mate_unmapped());
+        assert_eq!(a.mate_reference_sequence_id(), Some(0));
+        assert_eq!(a.template_length(), 0);
+
+        assert_eq!(b_fixes, [Fix::MovedUnmapped, Fix::UpdatedMateFields]);
+        assert_eq!(b.reference_sequence_id(), Some(0));
+        assert_eq!(b.alignment_start(), Position::new(2));
+        assert_eq!(b.mate_alignment_start(), Position::new(2));
+
+        let mut c = RecordBuf::builder().set_flags(Flags::empty()).build();
+        assert!(matches!(
+            sanitize_mates(&header, &mut a, &mut c),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,381 @@
+//! Per-tile quality metrics.
+
+use std::{collections::BTreeMap, io};
+
+use noodles_fastq as fastq;
+use noodles_sam::alignment::Record;
+
+use crate::read_name::illumina;
+
+const PHRED_OFFSET: u8 = b'!';
+const MISSING_QUALITY_SCORE: u8 = 0xff;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+struct Sum {
+    total: u64,
+    count: u64,
+}
+
+impl Sum {
+    fn add(&mut self, score: u8) {
+        self.total += u64::from(score);
+        self.count += 1;
+    }
+
+    fn mean(&self) -> Option<f64> {
+        match self.count {
+            0 => None,
+            n => Some(self.total as f64 / n as f64),
+        }
+    }
+}
+
+/// Per-tile quality metrics.
+///
+/// This collects the mean quality score of each read cycle on each flow cell tile, as parsed
+/// from Illumina read names (see [`illumina::Name`]). This is the data of a per-tile sequence
+/// quality heatmap, e.g., as in FastQC.
+///
+/// Records can be added from FASTQ or alignment (e.g., BAM) input. Read cycles are in sequencing
+/// order, i.e., quality scores of reverse complemented alignment records are reversed. Records
+/// whose names are not Illumina read names are skipped and counted.
+///
+/// Tiles are keyed by tile number only, so the same tile in different lanes is merged.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fastq as fastq;
+/// use noodles_util::alignment::TileQuality;
+///
+/// let mut tile_quality = TileQuality::default();
+///
+/// for (name, quality_scores) in [
+///     ("A00123:8:H5KJ7DRXX:1:1101:13:21", "II#"),
+///     ("A00123:8:H5KJ7DRXX:1:1101:34:55", "5I#"),
+///     ("A00123:8:H5KJ7DRXX:1:1102:89:144", "III"),
+///     ("r0", "III"),
+/// ] {
+///     let record = fastq::Record::new(fastq::record::Definition::new(name, ""), "ACG", quality_scores);
+///     tile_quality.add_fastq_record(&record)?;
+/// }
+///
+/// assert_eq!(tile_quality.tiles().collect::<Vec<_>>(), [1101, 1102]);
+/// assert_eq!(tile_quality.mean_quality_score(1101, 0), Some(30.0));
+/// assert_eq!(tile_quality.mean_quality_score(1101, 2), Some(2.0));
+/// assert_eq!(tile_quality.skipped_record_count(), 1);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TileQuality {
+    tiles: BTreeMap<u32, Vec<Sum>>,
+    skipped_record_count: u64,
+}
+
+impl TileQuality {
+    /// Returns an iterator over the observed tile numbers.
+    ///
+    /// Tile numbers are sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::TileQuality;
+    /// let tile_quality = TileQuality::default();
+    /// assert!(tile_quality.tiles().next().is_none());
+    /// ```
+    pub fn tiles(&self) -> impl Iterator<Item = u32> + '_ {
+        self.tiles.keys().copied()
+    }
+
+    /// Returns the number of read cycles.
+    ///
+    /// This is the length of the longest read added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::TileQuality;
+    /// let tile_quality = TileQuality::default();
+    /// assert_eq!(tile_quality.cycle_count(), 0);
+    /// ```
+    pub fn cycle_count(&self) -> usize {
+        self.tiles
+            .values()
+            .map(|sums| sums.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the number of records that were skipped because their names are not Illumina
+    /// read names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::TileQuality;
+    /// let tile_quality = TileQuality::default();
+    /// assert_eq!(tile_quality.skipped_record_count(), 0);
+    /// ```
+    pub fn skipped_record_count(&self) -> u64 {
+        self.skipped_record_count
+    }
+
+    /// Returns the mean quality score of a 0-based read cycle on a tile.
+    ///
+    /// This returns `None` if there are no observations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::TileQuality;
+    /// let tile_quality = TileQuality::default();
+    /// assert!(tile_quality.mean_quality_score(1101, 0).is_none());
+    /// ```
+    pub fn mean_quality_score(&self, tile: u32, cycle: usize) -> Option<f64> {
+        self.tiles
+            .get(&tile)
+            .and_then(|sums| sums.get(cycle))
+            .and_then(Sum::mean)
+    }
+
+    /// Returns the mean quality score matrix.
+    ///
+    /// Each row is a tile, in the order of [`Self::tiles`], and each column is a read cycle, up
+    /// to [`Self::cycle_count`]. Cells without observations are `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::TileQuality;
+    /// let tile_quality = TileQuality::default();
+    /// assert!(tile_quality.mean_quality_scores().is_empty());
+    /// ```
+    pub fn mean_quality_scores(&self) -> Vec<Vec<Option<f64>>> {
+        let cycle_count = self.cycle_count();
+
+        self.tiles
+            .values()
+            .map(|sums| {
+                (0..cycle_count)
+                    .map(|i| sums.get(i).and_then(Sum::mean))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the deviation matrix of tile mean quality scores from cycle mean quality scores.
+    ///
+    /// Each cell is the difference between the mean quality score of a read cycle on a tile and
+    /// the mean quality score of that read cycle across all tiles. This is the value plotted in a
+    /// FastQC per-tile sequence quality heatmap, where negative values indicate a tile with lower
+    /// quality than the average. Rows and columns are as in [`Self::mean_quality_scores`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::TileQuality;
+    /// let tile_quality = TileQuality::default();
+    /// assert!(tile_quality.deviations().is_empty());
+    /// ```
+    pub fn deviations(&self) -> Vec<Vec<Option<f64>>> {
+        let cycle_count = self.cycle_count();
+        let mut cycle_sums = vec![Sum::default(); cycle_count];
+
+        for sums in self.tiles.values() {
+            for (cycle_sum, sum) in cycle_sums.iter_mut().zip(sums) {
+                cycle_sum.total += sum.total;
+                cycle_sum.count += sum.count;
+            }
+        }
+
+        self.mean_quality_scores()
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .zip(&cycle_sums)
+                    .map(|(mean, cycle_sum)| Some(mean? - cycle_sum.mean()?))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Adds the quality scores of a read.
+    ///
+    /// The quality scores are raw Phred quality scores in sequencing order. Missing quality
+    /// scores (`0xff`) are ignored.
+    ///
+    /// This returns whether the read was added, i.e., whether the name is an Illumina read name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::TileQuality;
+    ///
+    /// let mut tile_quality = TileQuality::default();
+    ///
+    /// assert!(tile_quality.add(b"A00123:8:H5KJ7DRXX:1:1101:13:21", [40, 20]));
+    /// assert_eq!(tile_quality.mean_quality_score(1101, 1), Some(20.0));
+    ///
+    /// assert!(!tile_quality.add(b"r0", [40, 20]));
+    /// ```
+    pub fn add<I>(&mut self, name: &[u8], quality_scores: I) -> bool
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        let Some(name) = illumina::Name::parse(name) else {
+            self.skipped_record_count += 1;
+            return false;
+        };
+
+        let sums = self.tiles.entry(name.tile()).or_default();
+
+        for (i, score) in quality_scores.into_iter().enumerate() {
+            if score == MISSING_QUALITY_SCORE {
+                continue;
+            }
+
+            if i >= sums.len() {
+                sums.resize(i + 1, Sum::default());
+            }
+
+            sums[i].add(score);
+        }
+
+        true
+    }
+
+    /// Adds a FASTQ record.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if a quality score is invalid.
+    pub fn add_fastq_record(&mut self, record: &fastq::Record) -> io::Result<()> {
+        let quality_scores = record
+            .quality_scores()
+            .iter()
+            .map(|&c| {
+                c.checked_sub(PHRED_OFFSET).ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidData, "invalid quality score")
+                })
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        self.add(record.name(), quality_scores);
+
+        Ok(())
+    }
+
+    /// Adds an alignment record.
+    ///
+    /// Secondary and supplementary records are skipped, as their reads are already counted by
+    /// the primary records. Records without a name are counted as skipped.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the record flags are invalid.
+    pub fn add_record<R>(&mut self, record: &R) -> io::Result<()>
+    where
+        R: Record + ?Sized,
+    {
+        let flags = record.flags()?;
+
+        if flags.is_secondary() || flags.is_supplementary() {
+            return Ok(());
+        }
+
+        let Some(name) = record.name() else {
+            self.skipped_record_count += 1;
+            return Ok(());
+        };
+
+        let quality_scores = record.quality_scores();
+
+        if flags.is_reverse_complemented() {
+            let scores: Vec<_> = quality_scores.iter().collect();
+            self.add(name, scores.into_iter().rev());
+        } else {
+            self.add(name, quality_scores.iter());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::alignment::{record::Flags, record_buf::QualityScores, RecordBuf};
+
+    use super::*;
+
+    #[test]
+    fn test_add_record() -> io::Result<()> {
+        let mut tile_quality = TileQuality::default();
+
+        let records = [
+            RecordBuf::builder()
+                .set_name("A00123:8:H5KJ7DRXX:1:1101:13:21")
+                .set_flags(Flags::UNMAPPED)
+                .set_quality_scores(QualityScores::from(vec![40, 30, 20]))
+                .build(),
+            RecordBuf::builder()
+                .set_name("A00123:8:H5KJ7DRXX:1:1101:34:55")
+                .set_flags(Flags::REVERSE_COMPLEMENTED)
+                .set_quality_scores(QualityScores::from(vec![10, 20, 30]))
+                .build(),
+            RecordBuf::builder()
+                .set_name("A00123:8:H5KJ7DRXX:1:1101:34:55")
+                .set_flags(Flags::SUPPLEMENTARY)
+                .set_quality_scores(QualityScores::from(vec![0, 0, 0]))
+                .build(),
+            RecordBuf::builder()
+                .set_name("A00123:8:H5KJ7DRXX:1:2101:89:144")
+                .set_flags(Flags::UNMAPPED)
+                .set_quality_scores(QualityScores::from(vec![10, 10]))
+                .build(),
+            RecordBuf::builder().set_flags(Flags::UNMAPPED).build(),
+        ];
+
+        for record in &records {
+            tile_quality.add_record(record)?;
+        }
+
+        assert_eq!(tile_quality.tiles().collect::<Vec<_>>(), [1101, 2101]);
+        assert_eq!(tile_quality.cycle_count(), 3);
+        assert_eq!(tile_quality.skipped_record_count(), 1);
+
+        assert_eq!(
+            tile_quality.mean_quality_scores(),
+            [
+                vec![Some(35.0), Some(25.0), Some(15.0)],
+                vec![Some(10.0), Some(10.0), None],
+            ]
+        );
+
+        assert_eq!(
+            tile_quality.deviations(),
+            [
+                vec![Some(35.0 - 80.0 / 3.0), Some(25.0 - 20.0), Some(0.0)],
+                vec![Some(10.0 - 80.0 / 3.0), Some(10.0 - 20.0), None],
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_fastq_record() {
+        let mut tile_quality = TileQuality::default();
+
+        let record = fastq::Record::new(
+            fastq::record::Definition::new("A00123:8:H5KJ7DRXX:1:1101:13:21", ""),
+            "A",
+            " ",
+        );
+
+        assert!(matches!(
+            tile_quality.add_fastq_record(&record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+}
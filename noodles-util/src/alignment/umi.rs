@@ -0,0 +1,177 @@
+//! Unique molecular identifier (UMI) utilities.
+//!
+//! These are the preprocessing steps of UMI-aware duplicate marking and consensus calling:
+//! extracting UMIs from read names or data fields, normalizing them into the `RX` data field, and
+//! grouping records by position and UMI (see [`Grouper`]).
+
+mod grouper;
+
+pub use self::grouper::{Group, Grouper};
+
+use noodles_sam::alignment::{
+    record::data::field::Tag,
+    record_buf::{data::field::Value, Data},
+    RecordBuf,
+};
+
+const DUAL_UMI_DELIMITER: u8 = b'-';
+
+/// Returns the UMI at the end of a read name.
+///
+/// The UMI is the last field of the name when split by the given delimiter, e.g., `:` for
+/// Illumina read names (`<instrument>:...:<y>:<UMI>`) or `_` for UMI-tools read names
+/// (`<name>_<UMI>`). Only the first word of the name is considered, i.e., a trailing FASTQ
+/// description is ignored.
+///
+/// A UMI is a sequence of bases (`A`, `C`, `G`, `T`, or `N`). Dual UMIs are separated by `+` or
+/// `-`. This returns `None` if the last field is not a UMI.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_util::alignment::umi;
+///
+/// assert_eq!(
+///     umi::from_name(b"A00123:8:H5KJ7DRXX:1:2101:1234:5678:ACGT", b':'),
+///     Some(&b"ACGT"[..])
+/// );
+/// assert_eq!(umi::from_name(b"r0_ACGT+TTGA", b'_'), Some(&b"ACGT+TTGA"[..]));
+/// assert!(umi::from_name(b"A00123:8:H5KJ7DRXX:1:2101:1234:5678", b':').is_none());
+/// ```
+pub fn from_name(name: &[u8], delimiter: u8) -> Option<&[u8]> {
+    let name = name
+        .split(|b| b.is_ascii_whitespace())
+        .next()
+        .unwrap_or_default();
+
+    let i = name.iter().rposition(|&b| b == delimiter)?;
+    let umi = &name[i + 1..];
+
+    is_valid(umi).then_some(umi)
+}
+
+/// Returns the UMI in the given data.
+///
+/// This is the UMI sequence (`RX`) or, if missing, the original UMI sequence (`OX`).
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::alignment::{
+///     record::data::field::Tag,
+///     record_buf::{data::field::Value, Data},
+/// };
+/// use noodles_util::alignment::umi;
+///
+/// let data: Data = [(Tag::UMI_SEQUENCE, Value::from("ACGT"))].into_iter().collect();
+/// assert_eq!(umi::from_data(&data), Some(&b"ACGT"[..]));
+///
+/// assert!(umi::from_data(&Data::default()).is_none());
+/// ```
+pub fn from_data(data: &Data) -> Option<&[u8]> {
+    [Tag::UMI_SEQUENCE, Tag::ORIGINAL_UMI_BARCODE_SEQUENCE]
+        .iter()
+        .find_map(|tag| match data.get(tag) {
+            Some(Value::String(s)) => Some(s.as_ref()),
+            _ => None,
+        })
+}
+
+/// Moves the UMI at the end of the read name to the UMI sequence data field (`RX`).
+///
+/// The UMI and its delimiter are removed from the read name (see [`from_name`]), and dual UMI
+/// delimiters (`+`) are normalized to `-`, similar to `fgbio CopyUmiFromReadName`. An existing
+/// `RX` field is replaced.
+///
+/// This returns whether the read name had a UMI. If not, the record is unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::alignment::{record::data::field::Tag, RecordBuf};
+/// use noodles_util::alignment::umi;
+///
+/// let mut record = RecordBuf::builder().set_name("r0:ACGT+TTGA").build();
+/// assert!(umi::move_from_name(&mut record, b':'));
+///
+/// assert_eq!(record.name().map(|name| name.to_string()), Some(String::from("r0")));
+/// assert_eq!(umi::from_data(record.data()), Some(&b"ACGT-TTGA"[..]));
+/// ```
+pub fn move_from_name(record: &mut RecordBuf, delimiter: u8) -> bool {
+    let Some(name) = record.name_mut() else {
+        return false;
+    };
+
+    let Some(umi) = from_name(name, delimiter) else {
+        return false;
+    };
+
+    let umi: Vec<_> = umi
+        .iter()
+        .map(|&b| if b == b'+' { DUAL_UMI_DELIMITER } else { b })
+        .collect();
+
+    let end = name
+        .iter()
+        .position(|b| b.is_ascii_whitespace())
+        .unwrap_or(name.len());
+    let start = end - umi.len() - 1;
+    name.drain(start..end);
+
+    record
+        .data_mut()
+        .insert(Tag::UMI_SEQUENCE, Value::String(umi.into()));
+
+    true
+}
+
+fn is_valid(umi: &[u8]) -> bool {
+    !umi.is_empty()
+        && umi
+            .split(|&b| b == b'+' || b == DUAL_UMI_DELIMITER)
+            .all(|s| !s.is_empty() && s.iter().all(|b| b"ACGTN".contains(b)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_name() {
+        assert_eq!(from_name(b"r0:ACGT", b':'), Some(&b"ACGT"[..]));
+        assert_eq!(from_name(b"r0:ACGT 1:N:0:TTGA", b':'), Some(&b"ACGT"[..]));
+        assert_eq!(from_name(b"r0_ACGT-TTGA", b'_'), Some(&b"ACGT-TTGA"[..]));
+
+        assert!(from_name(b"r0", b':').is_none());
+        assert!(from_name(b"r0:", b':').is_none());
+        assert!(from_name(b"r0:acgt", b':').is_none());
+        assert!(from_name(b"r0:ACGT+", b':').is_none());
+        assert!(from_name(b"r0:5678", b':').is_none());
+    }
+
+    #[test]
+    fn test_move_from_name() {
+        let mut record = RecordBuf::builder()
+            .set_name("r0:ACGT 1:N:0")
+            .set_data(
+                [(Tag::UMI_SEQUENCE, Value::from("TTGA"))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+
+        assert!(move_from_name(&mut record, b':'));
+        assert_eq!(
+            record.name().map(|name| name.to_string()),
+            Some(String::from("r0 1:N:0"))
+        );
+        assert_eq!(from_data(record.data()), Some(&b"ACGT"[..]));
+
+        let mut record = RecordBuf::builder().set_name("r0").build();
+        assert!(!move_from_name(&mut record, b':'));
+        assert!(record.data().is_empty());
+
+        let mut record = RecordBuf::default();
+        assert!(!move_from_name(&mut record, b':'));
+    }
+}
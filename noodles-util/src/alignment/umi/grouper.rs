@@ -0,0 +1,417 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    io,
+};
+
+use noodles_core::Position;
+use noodles_sam::alignment::RecordBuf;
+
+type Key = (usize, Position, bool, Vec<u8>);
+
+/// A group of alignment records with the same position and UMI.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Group {
+    reference_sequence_id: usize,
+    position: Position,
+    is_reverse_complemented: bool,
+    umi: Vec<u8>,
+    records: Vec<RecordBuf>,
+}
+
+impl Group {
+    /// Returns the reference sequence ID of the records.
+    pub fn reference_sequence_id(&self) -> usize {
+        self.reference_sequence_id
+    }
+
+    /// Returns the 5' alignment position of the records.
+    ///
+    /// This is the alignment start of forward strand records and the alignment end of reverse
+    /// strand records.
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Returns whether the records are on the reverse strand.
+    pub fn is_reverse_complemented(&self) -> bool {
+        self.is_reverse_complemented
+    }
+
+    /// Returns the UMI of the records.
+    pub fn umi(&self) -> &[u8] {
+        &self.umi
+    }
+
+    /// Returns the records in input order.
+    pub fn records(&self) -> &[RecordBuf] {
+        &self.records
+    }
+
+    /// Returns the records in input order, consuming the group.
+    pub fn into_records(self) -> Vec<RecordBuf> {
+        self.records
+    }
+}
+
+/// An iterator that groups coordinate-sorted alignment records by position and UMI.
+///
+/// Records are grouped by reference sequence, 5' alignment position, strand, and UMI, i.e., each
+/// group is a set of reads that are likely PCR duplicates of the same molecule, which can then be
+/// collapsed into a consensus read. The UMI is read from the data (see [`super::from_data`]);
+/// UMIs in read names should first be moved to the data (see [`super::move_from_name`]). UMIs
+/// must match exactly.
+///
+/// Unmapped, secondary, supplementary, and records without a UMI are skipped.
+///
+/// The input must be coordinate-sorted. A group is yielded as soon as no later record can be a
+/// member, so memory use is bounded by the records at nearby positions. Groups are yielded in
+/// order of reference sequence, position, strand, and UMI.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::alignment::{
+///     record::{data::field::Tag, Flags},
+///     record_buf::data::field::Value,
+///     RecordBuf,
+/// };
+/// use noodles_util::alignment::umi::Grouper;
+///
+/// let build_record = |name, umi| {
+///     RecordBuf::builder()
+///         .set_name(name)
+///         .set_flags(Flags::empty())
+///         .set_reference_sequence_id(0)
+///         .set_alignment_start(Position::MIN)
+///         .set_data([(Tag::UMI_SEQUENCE, Value::from(umi))].into_iter().collect())
+///         .build()
+/// };
+///
+/// let records = [
+///     build_record("r0", "ACGT"),
+///     build_record("r1", "TTGA"),
+///     build_record("r2", "ACGT"),
+/// ];
+///
+/// let groups: Vec<_> = Grouper::new(records.into_iter().map(Ok))
+///     .map(|result| result.map(|group| group.records().len()))
+///     .collect::<std::io::Result<_>>()?;
+///
+/// assert_eq!(groups, [2, 1]);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub struct Grouper<I> {
+    records: I,
+    groups: BTreeMap<Key, Vec<RecordBuf>>,
+    queue: VecDeque<Group>,
+    last_position: Option<(usize, Position)>,
+    is_eof: bool,
+}
+
+impl<I> Grouper<I>
+where
+    I: Iterator<Item = io::Result<RecordBuf>>,
+{
+    /// Creates a UMI grouper.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::iter;
+    /// use noodles_util::alignment::umi::Grouper;
+    /// let grouper = Grouper::new(iter::empty());
+    /// ```
+    pub fn new(records: I) -> Self {
+        Self {
+            records,
+            groups: BTreeMap::new(),
+            queue: VecDeque::new(),
+            last_position: None,
+            is_eof: false,
+        }
+    }
+
+    fn add_record(&mut self, record: RecordBuf) -> io::Result<()> {
+        let flags = record.flags();
+
+        if flags.is_unmapped() || flags.is_secondary() || flags.is_supplementary() {
+            return Ok(());
+        }
+
+        let (Some(reference_sequence_id), Some(alignment_start)) =
+            (record.reference_sequence_id(), record.alignment_start())
+        else {
+            return Ok(());
+        };
+
+        let position = (reference_sequence_id, alignment_start);
+
+        if self.last_position.is_some_and(|last| position < last) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "records are not coordinate-sorted",
+            ));
+        }
+
+        self.last_position = Some(position);
+        self.flush(Some(position));
+
+        let Some(umi) = super::from_data(record.data()) else {
+            return Ok(());
+        };
+
+        let is_reverse_complemented = flags.is_reverse_complemented();
+
+        let five_prime_position = if is_reverse_complemented {
+            record.alignment_end().unwrap_or(alignment_start)
+        } else {
+            alignment_start
+        };
+
+        let key = (
+            reference_sequence_id,
+            five_prime_position,
+            is_reverse_complemented,
+            umi.to_vec(),
+        );
+
+        self.groups.entry(key).or_default().push(record);
+
+        Ok(())
+    }
+
+    // Moves the groups before the given position to the queue. If no position is given, all
+    // groups are moved.
+    fn flush(&mut self, position: Option<(usize, Position)>) {
+        while let Some(((id, pos, _, _), _)) = self.groups.first_key_value() {
+            if let Some(position) = position {
+                if (*id, *pos) >= position {
+                    break;
+                }
+            }
+
+            // SAFETY: `groups` is non-empty.
+            let ((reference_sequence_id, position, is_reverse_complemented, umi), records) =
+                self.groups.pop_first().unwrap();
+
+            self.queue.push_back(Group {
+                reference_sequence_id,
+                position,
+                is_reverse_complemented,
+                umi,
+                records,
+            });
+        }
+    }
+}
+
+impl<I> Iterator for Grouper<I>
+where
+    I: Iterator<Item = io::Result<RecordBuf>>,
+{
+    type Item = io::Result<Group>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(group) = self.queue.pop_front() {
+                return Some(Ok(group));
+            }
+
+            if self.is_eof {
+                return None;
+            }
+
+            match self.records.next() {
+                Some(Ok(record)) => {
+                    if let Err(e) = self.add_record(record) {
+                        return Some(Err(e));
+                    }
+                }
+                Some(Err(e)) => return Some(Err(e)),
+                None => {
+                    self.is_eof = true;
+                    self.flush(None);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::alignment::{
+        record::{
+            cigar::{op::Kind, Op},
+            data::field::Tag,
+            Flags,
+        },
+        record_buf::{data::field::Value, Cigar},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_next() -> io::Result<()> {
+        let records = [
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_flags(Flags::REVERSE_COMPLEMENTED)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(1).unwrap())
+                .set_cigar(Cigar::from(vec![Op::new(Kind::Match, 4)]))
+                .set_data(
+                    [(Tag::UMI_SEQUENCE, Value::from("ACGT"))]
+                        .into_iter()
+                        .collect(),
+                )
+                .build(),
+            RecordBuf::builder()
+                .set_name("r1")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(2).unwrap())
+                .set_cigar(Cigar::from(vec![Op::new(Kind::Match, 4)]))
+                .set_data(
+                    [(Tag::UMI_SEQUENCE, Value::from("ACGT"))]
+                        .into_iter()
+                        .collect(),
+                )
+                .build(),
+            RecordBuf::builder()
+                .set_name("r2")
+                .set_flags(Flags::REVERSE_COMPLEMENTED)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(2).unwrap())
+                .set_cigar(Cigar::from(vec![Op::new(Kind::Match, 4)]))
+                .set_data(
+                    [(Tag::UMI_SEQUENCE, Value::from("ACGT"))]
+                        .into_iter()
+                        .collect(),
+                )
+                .build(),
+            RecordBuf::builder()
+                .set_name("r3")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(2).unwrap())
+                .set_cigar(Cigar::from(vec![Op::new(Kind::Match, 4)]))
+                .set_data(
+                    [(Tag::UMI_SEQUENCE, Value::from("ACGT"))]
+                        .into_iter()
+                        .collect(),
+                )
+                .build(),
+            RecordBuf::builder()
+                .set_name("r4")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(2).unwrap())
+                .set_cigar(Cigar::from(vec![Op::new(Kind::Match, 4)]))
+                .set_data(
+                    [(Tag::UMI_SEQUENCE, Value::from("TTGA"))]
+                        .into_iter()
+                        .collect(),
+                )
+                .build(),
+            RecordBuf::builder()
+                .set_name("r5")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(3).unwrap())
+                .set_cigar(Cigar::from(vec![Op::new(Kind::Match, 4)]))
+                .build(),
+            RecordBuf::builder()
+                .set_name("r6")
+                .set_flags(Flags::SECONDARY)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(3).unwrap())
+                .set_cigar(Cigar::from(vec![Op::new(Kind::Match, 4)]))
+                .set_data(
+                    [(Tag::UMI_SEQUENCE, Value::from("ACGT"))]
+                        .into_iter()
+                        .collect(),
+                )
+                .build(),
+            RecordBuf::builder()
+                .set_name("r7")
+                .set_flags(Flags::REVERSE_COMPLEMENTED)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(4).unwrap())
+                .set_cigar(Cigar::from(vec![Op::new(Kind::Match, 4)]))
+                .set_data(
+                    [(Tag::UMI_SEQUENCE, Value::from("ACGT"))]
+                        .into_iter()
+                        .collect(),
+                )
+                .build(),
+        ];
+
+        let actual: Vec<_> = Grouper::new(records.into_iter().map(Ok))
+            .map(|result| {
+                result.map(|group| {
+                    let names: Vec<_> = group
+                        .records()
+                        .iter()
+                        .filter_map(|record| record.name().map(|name| name.to_string()))
+                        .collect();
+
+                    (
+                        usize::from(group.position()),
+                        group.is_reverse_complemented(),
+                        names,
+                    )
+                })
+            })
+            .collect::<io::Result<_>>()?;
+
+        let expected = [
+            (2, false, vec![String::from("r1"), String::from("r3")]),
+            (2, false, vec![String::from("r4")]),
+            (4, true, vec![String::from("r0")]),
+            (5, true, vec![String::from("r2")]),
+            (7, true, vec![String::from("r7")]),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_unsorted_records() {
+        let records = [
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(8).unwrap())
+                .set_cigar(Cigar::from(vec![Op::new(Kind::Match, 4)]))
+                .set_data(
+                    [(Tag::UMI_SEQUENCE, Value::from("ACGT"))]
+                        .into_iter()
+                        .collect(),
+                )
+                .build(),
+            RecordBuf::builder()
+                .set_name("r1")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(2).unwrap())
+                .set_cigar(Cigar::from(vec![Op::new(Kind::Match, 4)]))
+                .set_data(
+                    [(Tag::UMI_SEQUENCE, Value::from("ACGT"))]
+                        .into_iter()
+                        .collect(),
+                )
+                .build(),
+        ];
+
+        let mut grouper = Grouper::new(records.into_iter().map(Ok));
+
+        assert!(matches!(
+            grouper.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+}
@@ -0,0 +1,510 @@
+//! FASTQ record to unaligned alignment record conversion.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::io;
+
+use noodles_fastq as fastq;
+use noodles_sam::alignment::{
+    record::{data::field::Tag, Flags},
+    record_buf::{
+        data::field::{value::Array, Value},
+        Data, QualityScores, Sequence,
+    },
+    RecordBuf,
+};
+
+const PHRED_OFFSET: u8 = b'!';
+
+/// A FASTQ record to unaligned alignment record converter.
+///
+/// This converts FASTQ records to unmapped alignment records, e.g., to write an unaligned BAM
+/// (uBAM). It is the inverse of [`super::fastq::Converter`] and is similar to Picard
+/// `FastqToSam`.
+///
+/// Read number suffixes (`/1` and `/2`) are removed from read names, and records can be assigned
+/// to a read group (`RG`). FASTQ record descriptions are converted to data fields (see
+/// [`Builder::set_parse_description`]). Index reads can be added as sample barcodes using
+/// [`add_index_read`].
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fastq as fastq;
+/// use noodles_sam::alignment::record::data::field::Tag;
+/// use noodles_util::alignment::unaligned;
+///
+/// let converter = unaligned::Builder::default().set_read_group_id("rg0").build();
+///
+/// let fastq_record = fastq::Record::new(
+///     fastq::record::Definition::new("r0/1", "BC:Z:ACGT"),
+///     "AACG",
+///     "NDLS",
+/// );
+///
+/// let record = converter.convert(&fastq_record)?;
+///
+/// assert_eq!(record.name().map(|name| name.to_vec()), Some(b"r0".to_vec()));
+/// assert!(record.flags().is_unmapped());
+/// assert_eq!(record.sequence().as_ref(), b"AACG");
+/// assert_eq!(record.quality_scores().as_ref(), [45, 35, 43, 50]);
+/// assert!(record.data().get(&Tag::SAMPLE_BARCODE_SEQUENCE).is_some());
+/// assert!(record.data().get(&Tag::READ_GROUP).is_some());
+/// # Ok::<_, std::io::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct Converter {
+    read_group_id: Option<String>,
+    parse_description: bool,
+    strip_read_number: bool,
+}
+
+impl Converter {
+    /// Converts a FASTQ record to an unpaired, unmapped alignment record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// use noodles_util::alignment::unaligned;
+    ///
+    /// let converter = unaligned::Builder::default().build();
+    ///
+    /// let fastq_record = fastq::Record::new(
+    ///     fastq::record::Definition::new("r0", ""),
+    ///     "ACGT",
+    ///     "NDLS",
+    /// );
+    ///
+    /// let record = converter.convert(&fastq_record)?;
+    /// assert!(record.flags().is_unmapped());
+    /// assert!(!record.flags().is_segmented());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn convert(&self, record: &fastq::Record) -> io::Result<RecordBuf> {
+        self.build_record(record, Flags::UNMAPPED)
+    }
+
+    /// Converts a pair of FASTQ records to a pair of unmapped alignment records.
+    ///
+    /// The first record is the first segment (read 1), and the second record is the last
+    /// segment (read 2). The read names, after removing read number suffixes, must be the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// use noodles_util::alignment::unaligned;
+    ///
+    /// let converter = unaligned::Builder::default().build();
+    ///
+    /// let r1 = fastq::Record::new(fastq::record::Definition::new("r0/1", ""), "ACGT", "NDLS");
+    /// let r2 = fastq::Record::new(fastq::record::Definition::new("r0/2", ""), "TGCA", "NDLS");
+    ///
+    /// let (record_1, record_2) = converter.convert_pair(&r1, &r2)?;
+    /// assert!(record_1.flags().is_first_segment());
+    /// assert!(record_2.flags().is_last_segment());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn convert_pair(
+        &self,
+        record_1: &fastq::Record,
+        record_2: &fastq::Record,
+    ) -> io::Result<(RecordBuf, RecordBuf)> {
+        const FLAGS: Flags = Flags::SEGMENTED
+            .union(Flags::UNMAPPED)
+            .union(Flags::MATE_UNMAPPED);
+
+        let name_1 = self.build_name(record_1.name());
+        let name_2 = self.build_name(record_2.name());
+
+        if name_1 != name_2 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "read name mismatch: {} != {}",
+                    String::from_utf8_lossy(name_1),
+                    String::from_utf8_lossy(name_2)
+                ),
+            ));
+        }
+
+        let record_1 = self.build_record(record_1, FLAGS.union(Flags::FIRST_SEGMENT))?;
+        let record_2 = self.build_record(record_2, FLAGS.union(Flags::LAST_SEGMENT))?;
+
+        Ok((record_1, record_2))
+    }
+
+    fn build_record(&self, record: &fastq::Record, flags: Flags) -> io::Result<RecordBuf> {
+        let sequence = record.sequence();
+        let quality_scores = decode_quality_scores(record.quality_scores(), sequence.len())?;
+
+        let mut data = if self.parse_description {
+            parse_description(record.description())?
+        } else {
+            Data::default()
+        };
+
+        if let Some(read_group_id) = &self.read_group_id {
+            data.insert(Tag::READ_GROUP, Value::from(read_group_id.as_str()));
+        }
+
+        Ok(RecordBuf::builder()
+            .set_name(self.build_name(record.name()).to_vec())
+            .set_flags(flags)
+            .set_sequence(Sequence::from(sequence.to_vec()))
+            .set_quality_scores(QualityScores::from(quality_scores))
+            .set_data(data)
+            .build())
+    }
+
+    fn build_name<'a>(&self, name: &'a [u8]) -> &'a [u8] {
+        if self.strip_read_number {
+            name.strip_suffix(b"/1")
+                .or_else(|| name.strip_suffix(b"/2"))
+                .unwrap_or(name)
+        } else {
+            name
+        }
+    }
+}
+
+impl Default for Converter {
+    fn default() -> Self {
+        Builder::default().build()
+    }
+}
+
+/// Adds an index read to a record as a sample barcode.
+///
+/// The index read sequence is appended to the sample barcode (`BC`), and its quality scores, to
+/// the sample barcode quality scores (`QT`). Multiple index reads (e.g., for dual indexing) are
+/// separated by hyphens and spaces, respectively.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fastq as fastq;
+/// use noodles_sam::alignment::{
+///     record::data::field::Tag, record_buf::data::field::Value, RecordBuf,
+/// };
+/// use noodles_util::alignment::unaligned;
+///
+/// let mut record = RecordBuf::default();
+///
+/// let i1 = fastq::Record::new(fastq::record::Definition::new("r0", ""), "ACGT", "NDLS");
+/// unaligned::add_index_read(&mut record, &i1)?;
+///
+/// let i2 = fastq::Record::new(fastq::record::Definition::new("r0", ""), "TGCA", "SLDN");
+/// unaligned::add_index_read(&mut record, &i2)?;
+///
+/// let data = record.data();
+/// assert_eq!(
+///     data.get(&Tag::SAMPLE_BARCODE_SEQUENCE),
+///     Some(&Value::from("ACGT-TGCA"))
+/// );
+/// assert_eq!(
+///     data.get(&Tag::SAMPLE_BARCODE_QUALITY_SCORES),
+///     Some(&Value::from("NDLS SLDN"))
+/// );
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn add_index_read(record: &mut RecordBuf, index_record: &fastq::Record) -> io::Result<()> {
+    fn append(data: &mut Data, tag: Tag, separator: u8, src: &[u8]) -> io::Result<()> {
+        let mut buf = match data.remove(&tag) {
+            Some((_, Value::String(s))) => {
+                let mut buf: Vec<u8> = s.into();
+                buf.push(separator);
+                buf
+            }
+            Some(_) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid {tag:?} value type: expected a string"),
+                ))
+            }
+            None => Vec::new(),
+        };
+
+        buf.extend_from_slice(src);
+        data.insert(tag, Value::String(buf.into()));
+
+        Ok(())
+    }
+
+    if index_record.sequence().len() != index_record.quality_scores().len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "sequence and quality scores length mismatch",
+        ));
+    }
+
+    let data = record.data_mut();
+
+    append(
+        data,
+        Tag::SAMPLE_BARCODE_SEQUENCE,
+        b'-',
+        index_record.sequence(),
+    )?;
+
+    append(
+        data,
+        Tag::SAMPLE_BARCODE_QUALITY_SCORES,
+        b' ',
+        index_record.quality_scores(),
+    )?;
+
+    Ok(())
+}
+
+fn decode_quality_scores(src: &[u8], sequence_len: usize) -> io::Result<Vec<u8>> {
+    if src.len() != sequence_len {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "sequence and quality scores length mismatch",
+        ));
+    }
+
+    src.iter()
+        .map(|&c| {
+            c.checked_sub(PHRED_OFFSET)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid quality score"))
+        })
+        .collect()
+}
+
+fn parse_description(src: &[u8]) -> io::Result<Data> {
+    const DELIMITER: u8 = b'\t';
+
+    let mut data = Data::default();
+
+    if src.is_empty() {
+        return Ok(data);
+    }
+
+    let fields: Option<Vec<_>> = src.split(|&b| b == DELIMITER).map(parse_field).collect();
+
+    if let Some(fields) = fields {
+        data.extend(fields);
+    } else if let Some(barcode) = parse_illumina_barcode(src) {
+        data.insert(Tag::SAMPLE_BARCODE_SEQUENCE, Value::String(barcode.into()));
+    } else {
+        data.insert(Tag::COMMENT, Value::String(src.to_vec().into()));
+    }
+
+    Ok(data)
+}
+
+// Parses a SAM data field, e.g., `NH:i:1`.
+fn parse_field(src: &[u8]) -> Option<(Tag, Value)> {
+    let [t0, t1, b':', ty, b':', raw_value @ ..] = src else {
+        return None;
+    };
+
+    if !t0.is_ascii_alphabetic() || !t1.is_ascii_alphanumeric() {
+        return None;
+    }
+
+    let tag = Tag::new(*t0, *t1);
+    let s = std::str::from_utf8(raw_value).ok()?;
+
+    let value = match ty {
+        b'A' => match raw_value {
+            [c] if c.is_ascii_graphic() => Value::Character(*c),
+            _ => return None,
+        },
+        b'i' => {
+            let n: i64 = s.parse().ok()?;
+
+            if let Ok(m) = i32::try_from(n) {
+                Value::Int32(m)
+            } else {
+                Value::UInt32(u32::try_from(n).ok()?)
+            }
+        }
+        b'f' => Value::Float(s.parse().ok()?),
+        b'Z' => Value::String(raw_value.to_vec().into()),
+        b'H' => Value::Hex(raw_value.to_vec().into()),
+        b'B' => Value::Array(parse_array(s)?),
+        _ => return None,
+    };
+
+    Some((tag, value))
+}
+
+fn parse_array(s: &str) -> Option<Array> {
+    fn parse_values<T: std::str::FromStr>(s: &str) -> Option<Vec<T>> {
+        if s.is_empty() {
+            return Some(Vec::new());
+        }
+
+        s.strip_prefix(',')?
+            .split(',')
+            .map(|t| t.parse().ok())
+            .collect()
+    }
+
+    let subtype = s.get(..1)?;
+    let values = &s[1..];
+
+    match subtype {
+        "c" => parse_values(values).map(Array::Int8),
+        "C" => parse_values(values).map(Array::UInt8),
+        "s" => parse_values(values).map(Array::Int16),
+        "S" => parse_values(values).map(Array::UInt16),
+        "i" => parse_values(values).map(Array::Int32),
+        "I" => parse_values(values).map(Array::UInt32),
+        "f" => parse_values(values).map(Array::Float),
+        _ => None,
+    }
+}
+
+// Parses the index sequence of an Illumina (CASAVA 1.8) comment, e.g., `1:N:0:ACGT+TGCA`.
+//
+// Dual index sequences are separated by a hyphen, as in the sample barcode (`BC`) field. Older
+// comments use a sample number instead of an index sequence, which is not a barcode.
+fn parse_illumina_barcode(src: &[u8]) -> Option<Vec<u8>> {
+    let mut fields = src.splitn(4, |&b| b == b':');
+
+    let read_number = fields.next()?;
+    let is_filtered = fields.next()?;
+    let control_number = fields.next()?;
+    let index = fields.next()?;
+
+    if !matches!(read_number, [b'1'..=b'9'])
+        || !matches!(is_filtered, b"Y" | b"N")
+        || control_number.is_empty()
+        || !control_number.iter().all(u8::is_ascii_digit)
+        || index.is_empty()
+        || index.iter().all(u8::is_ascii_digit)
+    {
+        return None;
+    }
+
+    Some(
+        index
+            .iter()
+            .map(|&b| if b == b'+' { b'-' } else { b })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_fastq_record(name: &str, description: &str) -> fastq::Record {
+        fastq::Record::new(
+            fastq::record::Definition::new(name, description),
+            "ACGT",
+            "NDLS",
+        )
+    }
+
+    #[test]
+    fn test_convert_pair() -> io::Result<()> {
+        let converter = Builder::default().set_read_group_id("rg0").build();
+
+        let r1 = build_fastq_record("r0/1", "1:N:0:ACGT+TGCA");
+        let r2 = build_fastq_record("r0/2", "2:N:0:ACGT+TGCA");
+
+        let (record_1, record_2) = converter.convert_pair(&r1, &r2)?;
+
+        for (record, flag) in [
+            (&record_1, Flags::FIRST_SEGMENT),
+            (&record_2, Flags::LAST_SEGMENT),
+        ] {
+            assert_eq!(
+                record.name().map(|name| name.to_vec()),
+                Some(b"r0".to_vec())
+            );
+            assert_eq!(
+                record.flags(),
+                Flags::SEGMENTED | Flags::UNMAPPED | Flags::MATE_UNMAPPED | flag
+            );
+            assert_eq!(record.quality_scores().as_ref(), [45, 35, 43, 50]);
+
+            let data = record.data();
+            assert_eq!(data.get(&Tag::READ_GROUP), Some(&Value::from("rg0")));
+            assert_eq!(
+                data.get(&Tag::SAMPLE_BARCODE_SEQUENCE),
+                Some(&Value::from("ACGT-TGCA"))
+            );
+        }
+
+        let r2 = build_fastq_record("r1/2", "");
+        assert!(matches!(
+            converter.convert_pair(&r1, &r2),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_convert_round_trip() -> io::Result<()> {
+        use crate::alignment::fastq;
+
+        let src = build_fastq_record("r0", "BC:Z:ACGT\tNH:i:1\tXB:B:c,-1,2");
+
+        let record = Converter::default().convert(&src)?;
+
+        let converter = fastq::Builder::default()
+            .add_description_tag(Tag::SAMPLE_BARCODE_SEQUENCE)
+            .add_description_tag(Tag::ALIGNMENT_HIT_COUNT)
+            .add_description_tag(Tag::new(b'X', b'B'))
+            .build();
+
+        let dst = converter.convert(&record)?.expect("record is selected");
+
+        assert_eq!(dst, src);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_description() -> io::Result<()> {
+        assert!(parse_description(b"")?.is_empty());
+
+        let data = parse_description(b"BC:Z:ACGT\tNH:i:1")?;
+        assert_eq!(
+            data.get(&Tag::SAMPLE_BARCODE_SEQUENCE),
+            Some(&Value::from("ACGT"))
+        );
+        assert_eq!(data.get(&Tag::ALIGNMENT_HIT_COUNT), Some(&Value::Int32(1)));
+
+        let data = parse_description(b"1:Y:0:ACGT")?;
+        assert_eq!(
+            data.get(&Tag::SAMPLE_BARCODE_SEQUENCE),
+            Some(&Value::from("ACGT"))
+        );
+
+        let data = parse_description(b"1:N:0:2")?;
+        assert_eq!(data.get(&Tag::COMMENT), Some(&Value::from("1:N:0:2")));
+
+        let data = parse_description(b"length=4")?;
+        assert_eq!(data.get(&Tag::COMMENT), Some(&Value::from("length=4")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_quality_scores() -> io::Result<()> {
+        assert_eq!(decode_quality_scores(b"!NDLS", 5)?, [0, 45, 35, 43, 50]);
+
+        assert!(matches!(
+            decode_quality_scores(b"NDLS", 3),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert!(matches!(
+            decode_quality_scores(b" ", 1),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use super::Converter;
+
+/// A FASTQ record to unaligned alignment record converter builder.
+#[derive(Clone, Debug)]
+pub struct Builder {
+    read_group_id: Option<String>,
+    parse_description: bool,
+    strip_read_number: bool,
+}
+
+impl Builder {
+    /// Sets the read group ID.
+    ///
+    /// If set, each converted record is assigned to this read group (`RG`). The read group
+    /// should be added to the output header.
+    ///
+    /// By default, records are not assigned to a read group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::unaligned;
+    /// let converter = unaligned::Builder::default().set_read_group_id("rg0").build();
+    /// ```
+    pub fn set_read_group_id<I>(mut self, read_group_id: I) -> Self
+    where
+        I: Into<String>,
+    {
+        self.read_group_id = Some(read_group_id.into());
+        self
+    }
+
+    /// Sets whether to convert the FASTQ record description to data fields.
+    ///
+    /// If enabled, a description of tab-separated SAM data fields (e.g., `BC:Z:ACGT`) is
+    /// converted to these fields; an Illumina (CASAVA 1.8) comment (e.g., `1:N:0:ACGT+TGCA`) is
+    /// converted to a sample barcode (`BC`); and any other description is kept as a comment
+    /// (`CO`). If disabled, the description is discarded.
+    ///
+    /// By default, descriptions are converted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::unaligned;
+    /// let converter = unaligned::Builder::default().set_parse_description(false).build();
+    /// ```
+    pub fn set_parse_description(mut self, parse_description: bool) -> Self {
+        self.parse_description = parse_description;
+        self
+    }
+
+    /// Sets whether to remove a read number suffix (`/1` or `/2`) from read names.
+    ///
+    /// By default, read number suffixes are removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::unaligned;
+    /// let converter = unaligned::Builder::default().set_strip_read_number(false).build();
+    /// ```
+    pub fn set_strip_read_number(mut self, strip_read_number: bool) -> Self {
+        self.strip_read_number = strip_read_number;
+        self
+    }
+
+    /// Builds a FASTQ record to unaligned alignment record converter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::unaligned;
+    /// let converter = unaligned::Builder::default().build();
+    /// ```
+    pub fn build(self) -> Converter {
+        Converter {
+            read_group_id: self.read_group_id,
+            parse_description: self.parse_description,
+            strip_read_number: self.strip_read_number,
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            read_group_id: None,
+            parse_description: true,
+            strip_read_number: true,
+        }
+    }
+}
@@ -0,0 +1,556 @@
+//! Alignment text view.
+
+use std::{fmt, io};
+
+use noodles_core::Position;
+use noodles_sam::alignment::{record::cigar::op::Kind, Record};
+
+const RULER_INTERVAL: usize = 10;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Cell {
+    Empty,
+    Base {
+        base: u8,
+        is_reverse_complemented: bool,
+    },
+    Deletion,
+    Skip {
+        is_reverse_complemented: bool,
+    },
+}
+
+#[derive(Clone, Debug)]
+struct Row {
+    cells: Vec<Cell>,
+    end: Position,
+}
+
+/// A text view of alignment records over a reference sequence window.
+///
+/// This is similar to the view in `samtools tview`. Each column is a reference sequence position
+/// in the window, and records are stacked into rows, where each record is placed in the first row
+/// that is free at its alignment start.
+///
+/// Aligned bases are shown as-is, with reverse strand bases in lowercase. When a reference
+/// sequence is set, bases that match the reference are shown as `.` (forward) or `,` (reverse).
+/// Deletions are shown as `*`, and reference skips as `>` (forward) or `<` (reverse). Insertions,
+/// soft clips, hard clips, and padding are not shown.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::alignment::{
+///     record::cigar::{op::Kind, Op},
+///     RecordBuf,
+/// };
+/// use noodles_util::alignment::view::TextView;
+///
+/// let mut view = TextView::new(Position::MIN, 8);
+/// view.set_reference_sequence(b"ACGTACGT");
+///
+/// let cigar = [
+///     Op::new(Kind::Match, 2),
+///     Op::new(Kind::Deletion, 1),
+///     Op::new(Kind::Match, 2),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// let record = RecordBuf::builder()
+///     .set_alignment_start(Position::try_from(2)?)
+///     .set_cigar(cigar)
+///     .set_sequence(b"CGAA".to_vec().into())
+///     .build();
+///
+/// view.add_record(&record)?;
+///
+/// assert_eq!(view.to_string(), "ACGTACGT\n ..*.A  \n");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct TextView {
+    start: Position,
+    width: usize,
+    reference_sequence: Option<Vec<u8>>,
+    rows: Vec<Row>,
+}
+
+impl TextView {
+    /// Creates a text view of a reference sequence window.
+    ///
+    /// The window starts at the given position and is `width` columns wide.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_util::alignment::view::TextView;
+    /// let view = TextView::new(Position::MIN, 80);
+    /// ```
+    pub fn new(start: Position, width: usize) -> Self {
+        Self {
+            start,
+            width,
+            reference_sequence: None,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Returns the start position of the window.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_util::alignment::view::TextView;
+    /// let view = TextView::new(Position::MIN, 80);
+    /// assert_eq!(view.start(), Position::MIN);
+    /// ```
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Returns the width of the window.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_util::alignment::view::TextView;
+    /// let view = TextView::new(Position::MIN, 80);
+    /// assert_eq!(view.width(), 80);
+    /// ```
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Sets the reference sequence of the window.
+    ///
+    /// The sequence is expected to start at the window start. Bases past the window width are
+    /// ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_util::alignment::view::TextView;
+    /// let mut view = TextView::new(Position::MIN, 4);
+    /// view.set_reference_sequence(b"ACGT");
+    /// ```
+    pub fn set_reference_sequence(&mut self, reference_sequence: &[u8]) {
+        let len = reference_sequence.len().min(self.width);
+        self.reference_sequence = Some(reference_sequence[..len].to_vec());
+    }
+
+    /// Adds a record to the view.
+    ///
+    /// The record is placed in the first row in which it does not overlap any other record.
+    /// Records should be added in coordinate order. Records without an alignment start or that do
+    /// not intersect the window are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_sam::alignment::RecordBuf;
+    /// use noodles_util::alignment::view::TextView;
+    /// let mut view = TextView::new(Position::MIN, 8);
+    /// view.add_record(&RecordBuf::default())?;
+    /// assert_eq!(view.row_count(), 0);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn add_record<R>(&mut self, record: &R) -> io::Result<()>
+    where
+        R: Record,
+    {
+        let (Some(start), Some(end)) = (
+            record.alignment_start().transpose()?,
+            record.alignment_end().transpose()?,
+        ) else {
+            return Ok(());
+        };
+
+        if end < self.start || usize::from(start) >= self.end() {
+            return Ok(());
+        }
+
+        let i = match self.rows.iter().position(|row| row.end < start) {
+            Some(i) => i,
+            None => {
+                self.rows.push(Row {
+                    cells: vec![Cell::Empty; self.width],
+                    end: Position::MIN,
+                });
+
+                self.rows.len() - 1
+            }
+        };
+
+        let row = &mut self.rows[i];
+        row.end = end;
+
+        render_record(&mut row.cells, self.start, start, record)
+    }
+
+    /// Returns the number of read rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_util::alignment::view::TextView;
+    /// let view = TextView::new(Position::MIN, 80);
+    /// assert_eq!(view.row_count(), 0);
+    /// ```
+    pub fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns a ruler row.
+    ///
+    /// Positions that are multiples of 10 are labeled at their column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_util::alignment::view::TextView;
+    /// let view = TextView::new(Position::try_from(8)?, 16);
+    /// assert_eq!(view.ruler(), "  10        20  ");
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn ruler(&self) -> String {
+        let mut buf = vec![b' '; self.width];
+        let start = usize::from(self.start);
+
+        for i in 0..self.width {
+            let position = start + i;
+
+            if position % RULER_INTERVAL != 0 {
+                continue;
+            }
+
+            let label = position.to_string();
+
+            for (dst, src) in buf[i..].iter_mut().zip(label.bytes()) {
+                *dst = src;
+            }
+        }
+
+        // SAFETY: `buf` only contains ASCII characters.
+        String::from_utf8(buf).unwrap()
+    }
+
+    /// Returns the reference sequence row, if a reference sequence is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_util::alignment::view::TextView;
+    ///
+    /// let mut view = TextView::new(Position::MIN, 6);
+    /// assert!(view.reference_sequence_row().is_none());
+    ///
+    /// view.set_reference_sequence(b"ACGT");
+    /// assert_eq!(view.reference_sequence_row(), Some(String::from("ACGT  ")));
+    /// ```
+    pub fn reference_sequence_row(&self) -> Option<String> {
+        self.reference_sequence.as_ref().map(|reference_sequence| {
+            (0..self.width)
+                .map(|i| {
+                    reference_sequence
+                        .get(i)
+                        .copied()
+                        .map(to_char)
+                        .unwrap_or(' ')
+                })
+                .collect()
+        })
+    }
+
+    /// Returns an iterator over read rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_util::alignment::view::TextView;
+    /// let view = TextView::new(Position::MIN, 80);
+    /// assert!(view.rows().next().is_none());
+    /// ```
+    pub fn rows(&self) -> impl Iterator<Item = String> + '_ {
+        self.rows.iter().map(|row| {
+            row.cells
+                .iter()
+                .enumerate()
+                .map(|(i, cell)| self.render_cell(i, *cell))
+                .collect()
+        })
+    }
+
+    fn end(&self) -> usize {
+        usize::from(self.start) + self.width
+    }
+
+    fn render_cell(&self, i: usize, cell: Cell) -> char {
+        match cell {
+            Cell::Empty => ' ',
+            Cell::Base {
+                base,
+                is_reverse_complemented,
+            } => {
+                let is_match = self
+                    .reference_sequence
+                    .as_ref()
+                    .and_then(|reference_sequence| reference_sequence.get(i))
+                    .map(|reference_base| reference_base.eq_ignore_ascii_case(&base))
+                    .unwrap_or(false);
+
+                match (is_match, is_reverse_complemented) {
+                    (true, false) => '.',
+                    (true, true) => ',',
+                    (false, false) => to_char(base.to_ascii_uppercase()),
+                    (false, true) => to_char(base.to_ascii_lowercase()),
+                }
+            }
+            Cell::Deletion => '*',
+            Cell::Skip {
+                is_reverse_complemented: false,
+            } => '>',
+            Cell::Skip {
+                is_reverse_complemented: true,
+            } => '<',
+        }
+    }
+}
+
+impl fmt::Display for TextView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(row) = self.reference_sequence_row() {
+            writeln!(f, "{row}")?;
+        }
+
+        for row in self.rows() {
+            writeln!(f, "{row}")?;
+        }
+
+        Ok(())
+    }
+}
+
+fn render_record<R>(
+    cells: &mut [Cell],
+    window_start: Position,
+    alignment_start: Position,
+    record: &R,
+) -> io::Result<()>
+where
+    R: Record,
+{
+    let is_reverse_complemented = record.flags()?.is_reverse_complemented();
+    let sequence = record.sequence();
+
+    let window_start = usize::from(window_start);
+    let mut reference_position = usize::from(alignment_start);
+    let mut read_position = 0;
+
+    let mut set_cell = |reference_position: usize, cell: Cell| {
+        if let Some(dst) = reference_position
+            .checked_sub(window_start)
+            .and_then(|i| cells.get_mut(i))
+        {
+            *dst = cell;
+        }
+    };
+
+    for result in record.cigar().iter() {
+        let op = result?;
+        let len = op.len();
+
+        match op.kind() {
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
+                for j in 0..len {
+                    let base = sequence.get(read_position + j).unwrap_or(b'N');
+
+                    set_cell(
+                        reference_position + j,
+                        Cell::Base {
+                            base,
+                            is_reverse_complemented,
+                        },
+                    );
+                }
+
+                reference_position += len;
+                read_position += len;
+            }
+            Kind::Deletion => {
+                for j in 0..len {
+                    set_cell(reference_position + j, Cell::Deletion);
+                }
+
+                reference_position += len;
+            }
+            Kind::Skip => {
+                for j in 0..len {
+                    set_cell(
+                        reference_position + j,
+                        Cell::Skip {
+                            is_reverse_complemented,
+                        },
+                    );
+                }
+
+                reference_position += len;
+            }
+            Kind::Insertion | Kind::SoftClip => read_position += len,
+            Kind::HardClip | Kind::Pad => {}
+        }
+    }
+
+    Ok(())
+}
+
+fn to_char(b: u8) -> char {
+    if b.is_ascii_graphic() {
+        char::from(b)
+    } else {
+        '?'
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::alignment::{
+        record::{
+            cigar::{op::Kind, Op},
+            Flags,
+        },
+        RecordBuf,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_add_record() -> Result<(), Box<dyn std::error::Error>> {
+        let mut view = TextView::new(Position::try_from(3)?, 10);
+        view.set_reference_sequence(b"GTACGTACGT");
+
+        // ACGT occupies positions 1-4, which is clipped by the window start.
+        view.add_record(
+            &RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_alignment_start(Position::try_from(1)?)
+                .set_cigar(
+                    [Op::new(Kind::SoftClip, 1), Op::new(Kind::Match, 4)]
+                        .into_iter()
+                        .collect(),
+                )
+                .set_sequence(b"NACGT".to_vec().into())
+                .build(),
+        )?;
+
+        view.add_record(
+            &RecordBuf::builder()
+                .set_flags(Flags::REVERSE_COMPLEMENTED)
+                .set_alignment_start(Position::try_from(3)?)
+                .set_cigar(
+                    [
+                        Op::new(Kind::Match, 2),
+                        Op::new(Kind::Insertion, 2),
+                        Op::new(Kind::Match, 2),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )
+                .set_sequence(b"GTTTAG".to_vec().into())
+                .build(),
+        )?;
+
+        view.add_record(
+            &RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_alignment_start(Position::try_from(6)?)
+                .set_cigar(
+                    [
+                        Op::new(Kind::Match, 1),
+                        Op::new(Kind::Skip, 3),
+                        Op::new(Kind::Match, 2),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )
+                .set_sequence(b"CCG".to_vec().into())
+                .build(),
+        )?;
+
+        view.add_record(
+            &RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_alignment_start(Position::try_from(11)?)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(b"GTAC".to_vec().into())
+                .build(),
+        )?;
+
+        // unmapped
+        view.add_record(&RecordBuf::default())?;
+
+        // outside of the window
+        view.add_record(
+            &RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_alignment_start(Position::try_from(13)?)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(b"ACGT".to_vec().into())
+                .build(),
+        )?;
+
+        assert_eq!(view.row_count(), 2);
+
+        let actual: Vec<_> = view.rows().collect();
+        let expected = [".. .>>>.. ", ",,,g    .."];
+        assert_eq!(actual, expected);
+
+        assert_eq!(view.to_string(), "GTACGTACGT\n.. .>>>.. \n,,,g    ..\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rows_without_reference_sequence() -> Result<(), Box<dyn std::error::Error>> {
+        let mut view = TextView::new(Position::MIN, 6);
+
+        view.add_record(
+            &RecordBuf::builder()
+                .set_flags(Flags::REVERSE_COMPLEMENTED)
+                .set_alignment_start(Position::try_from(2)?)
+                .set_cigar(
+                    [
+                        Op::new(Kind::Match, 2),
+                        Op::new(Kind::Deletion, 1),
+                        Op::new(Kind::Match, 1),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )
+                .set_sequence(b"ACG".to_vec().into())
+                .build(),
+        )?;
+
+        let actual: Vec<_> = view.rows().collect();
+        assert_eq!(actual, [" ac*g "]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ruler() -> Result<(), noodles_core::position::TryFromIntError> {
+        let view = TextView::new(Position::try_from(95)?, 12);
+        assert_eq!(view.ruler(), "     100    ");
+        Ok(())
+    }
+}
@@ -0,0 +1,621 @@
+//! Allele counting at variant sites from alignments.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::io;
+
+use noodles_core::Position;
+use noodles_fasta as fasta;
+use noodles_sam::{
+    self as sam,
+    alignment::{record::cigar::op::Kind, RecordBuf},
+};
+use noodles_vcf as vcf;
+
+use crate::alignment::baq;
+
+/// Read support counts of the alleles of a variant site.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AlleleCounts {
+    depth: usize,
+    counts: Vec<usize>,
+}
+
+impl AlleleCounts {
+    /// Returns the number of reads that span the site and pass the filters (`DP`).
+    ///
+    /// This includes reads that support neither the reference nor an alternate allele.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the number of reads that support each allele (`AD`).
+    ///
+    /// The first count is of the reference allele, followed by the counts of the alternate
+    /// alleles, in order.
+    pub fn counts(&self) -> &[usize] {
+        &self.counts
+    }
+
+    /// Returns the fraction of reads that support the allele at the given index.
+    ///
+    /// Index 0 is the reference allele, and index 1 is the first alternate allele, i.e., the
+    /// variant allele frequency (VAF) of a biallelic site is the allele frequency at index 1.
+    /// This returns `None` if the index is out of bounds or the depth is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::allele_count::AlleleCounts;
+    /// let counts = AlleleCounts::default();
+    /// assert!(counts.allele_frequency(1).is_none());
+    /// ```
+    pub fn allele_frequency(&self, i: usize) -> Option<f64> {
+        let count = self.counts.get(i).copied()?;
+
+        if self.depth == 0 {
+            None
+        } else {
+            Some(count as f64 / self.depth as f64)
+        }
+    }
+}
+
+/// An allele counter.
+///
+/// This counts the reads that support the reference and alternate alleles of a variant site,
+/// similar to the `AD` and `DP` annotations of `bcftools mpileup`. SNVs, MNVs, and simple
+/// insertions and deletions (with a leading anchor base) are supported. An indel is only
+/// supported by a read if the read has the same indel, i.e., the representation of the variant
+/// must match that of the aligner, which is typically left-aligned.
+///
+/// A read is counted if it spans the site and passes the filters. Unmapped, secondary, QC fail,
+/// and duplicate records are skipped, as are reads below the minimum mapping quality and reads
+/// with a base in the allele below the minimum base quality. Overlapping mates are counted
+/// separately. If a reference sequence repository is set, base qualities are first capped by
+/// their BAQ.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{
+///         record::{cigar::{op::Kind, Op}, Flags},
+///         record_buf::{Cigar, QualityScores, Sequence},
+///         RecordBuf,
+///     },
+///     header::record::value::{map::ReferenceSequence, Map},
+/// };
+/// use noodles_util::allele_count;
+/// use noodles_vcf::{self as vcf, variant::record_buf::AlternateBases};
+///
+/// let alignment_header = sam::Header::builder()
+///     .add_reference_sequence(
+///         "sq0",
+///         Map::<ReferenceSequence>::new(std::num::NonZeroUsize::try_from(8)?),
+///     )
+///     .build();
+///
+/// let build_record = |sequence: &[u8]| {
+///     RecordBuf::builder()
+///         .set_flags(Flags::empty())
+///         .set_reference_sequence_id(0)
+///         .set_alignment_start(Position::MIN)
+///         .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+///         .set_sequence(Sequence::from(sequence.to_vec()))
+///         .set_quality_scores(QualityScores::from(vec![30; 4]))
+///         .build()
+/// };
+///
+/// let records = [build_record(b"ACGT"), build_record(b"ACTT"), build_record(b"ACAT")];
+///
+/// let site = vcf::variant::RecordBuf::builder()
+///     .set_reference_sequence_name("sq0")
+///     .set_variant_start(Position::try_from(3)?)
+///     .set_reference_bases("G")
+///     .set_alternate_bases(AlternateBases::from(vec![String::from("T")]))
+///     .build();
+///
+/// let counter = allele_count::Builder::default().build();
+/// let counts = counter.count(&alignment_header, &site, records.into_iter().map(Ok))?;
+///
+/// assert_eq!(counts.depth(), 3);
+/// assert_eq!(counts.counts(), [1, 1]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct Counter {
+    min_base_quality: u8,
+    min_mapping_quality: u8,
+    reference_sequence_repository: Option<fasta::Repository>,
+}
+
+impl Counter {
+    /// Counts the reads that support each allele of the given variant site.
+    ///
+    /// The alignment records are typically the result of a query of the region of the site.
+    /// Records on other reference sequences or that do not span the site are ignored.
+    ///
+    /// # Errors
+    ///
+    /// This returns an `io::Error` if the variant site has no start position, reading a record
+    /// fails, or, when applying BAQ, the reference sequence of the site is missing from the
+    /// repository.
+    pub fn count<I>(
+        &self,
+        alignment_header: &sam::Header,
+        site: &vcf::variant::RecordBuf,
+        records: I,
+    ) -> io::Result<AlleleCounts>
+    where
+        I: IntoIterator<Item = io::Result<RecordBuf>>,
+    {
+        let start = site
+            .variant_start()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing variant start"))?;
+
+        let reference_bases = site.reference_bases().as_bytes();
+
+        let end = reference_bases
+            .len()
+            .checked_sub(1)
+            .and_then(|len| start.checked_add(len))
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidInput, "invalid reference bases")
+            })?;
+
+        let alleles: Vec<&[u8]> = [reference_bases]
+            .into_iter()
+            .chain(site.alternate_bases().as_ref().iter().map(|s| s.as_bytes()))
+            .collect();
+
+        // An insertion after the last reference base is part of the allele only at sites with an
+        // insertion allele.
+        let has_trailing_insertion = alleles.iter().any(|a| a.len() > reference_bases.len());
+
+        let reference_sequence_id = alignment_header
+            .reference_sequences()
+            .get_index_of(site.reference_sequence_name().as_bytes());
+
+        let reference_sequence = match &self.reference_sequence_repository {
+            Some(repository) if reference_sequence_id.is_some() => {
+                let name = site.reference_sequence_name();
+
+                let sequence = repository
+                    .get(name.as_bytes())
+                    .transpose()?
+                    .ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidInput,
+                            format!("missing reference sequence: {name}"),
+                        )
+                    })?;
+
+                Some(sequence)
+            }
+            _ => None,
+        };
+
+        let mut counts = AlleleCounts {
+            depth: 0,
+            counts: vec![0; alleles.len()],
+        };
+
+        for result in records {
+            let mut record = result?;
+
+            if reference_sequence_id.is_none()
+                || record.reference_sequence_id() != reference_sequence_id
+                || !self.is_countable(&record)
+            {
+                continue;
+            }
+
+            if let Some(sequence) = &reference_sequence {
+                baq::apply(&mut record, sequence.as_ref())?;
+            }
+
+            let Some((haplotype, min_base_quality)) =
+                read_haplotype(&record, start, end, has_trailing_insertion)
+            else {
+                continue;
+            };
+
+            if min_base_quality < self.min_base_quality {
+                continue;
+            }
+
+            counts.depth += 1;
+
+            if let Some(i) = alleles
+                .iter()
+                .position(|allele| allele.eq_ignore_ascii_case(&haplotype))
+            {
+                counts.counts[i] += 1;
+            }
+        }
+
+        Ok(counts)
+    }
+
+    fn is_countable(&self, record: &RecordBuf) -> bool {
+        let flags = record.flags();
+
+        if flags.is_unmapped() || flags.is_secondary() || flags.is_qc_fail() || flags.is_duplicate()
+        {
+            return false;
+        }
+
+        record
+            .mapping_quality()
+            .map(|mapping_quality| mapping_quality.get() >= self.min_mapping_quality)
+            .unwrap_or(true)
+    }
+}
+
+// Returns the read bases aligned to the reference interval [start, end] and the minimum quality
+// of those bases.
+//
+// This returns `None` if the read does not span the interval or the alignment is ambiguous at its
+// edges, e.g., a deletion that extends past the end.
+fn read_haplotype(
+    record: &RecordBuf,
+    start: Position,
+    end: Position,
+    has_trailing_insertion: bool,
+) -> Option<(Vec<u8>, u8)> {
+    let (start, end) = (usize::from(start), usize::from(end));
+
+    let sequence = record.sequence().as_ref();
+    let quality_scores = record.quality_scores().as_ref();
+
+    let mut reference_position = usize::from(record.alignment_start()?);
+    let mut read_position = 0;
+
+    let mut haplotype = Vec::new();
+    let mut min_base_quality = u8::MAX;
+    let mut covered_len = 0;
+
+    let mut push = |read_position: usize, haplotype: &mut Vec<u8>| -> Option<()> {
+        haplotype.push(sequence.get(read_position).copied()?);
+
+        if let Some(&score) = quality_scores.get(read_position) {
+            min_base_quality = min_base_quality.min(score);
+        }
+
+        Some(())
+    };
+
+    let is_in_interval = |position: usize| (start..=end).contains(&position);
+
+    for op in record.cigar().as_ref() {
+        let len = op.len();
+
+        match op.kind() {
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
+                for i in 0..len {
+                    if is_in_interval(reference_position + i) {
+                        push(read_position + i, &mut haplotype)?;
+                        covered_len += 1;
+                    }
+                }
+
+                reference_position += len;
+                read_position += len;
+            }
+            Kind::Insertion => {
+                let previous_position = reference_position - 1;
+
+                if start <= previous_position
+                    && (previous_position < end
+                        || (previous_position == end && has_trailing_insertion))
+                {
+                    for i in 0..len {
+                        push(read_position + i, &mut haplotype)?;
+                    }
+                }
+
+                read_position += len;
+            }
+            Kind::Deletion | Kind::Skip => {
+                let op_end = reference_position + len - 1;
+
+                if reference_position <= end && op_end >= start {
+                    if op.kind() == Kind::Skip || op_end > end || reference_position < start {
+                        return None;
+                    }
+
+                    covered_len += len;
+                }
+
+                reference_position += len;
+            }
+            Kind::SoftClip => read_position += len,
+            Kind::HardClip | Kind::Pad => {}
+        }
+    }
+
+    if covered_len == end - start + 1 {
+        Some((haplotype, min_base_quality))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::alignment::{
+        record::{cigar::Op, Flags, MappingQuality},
+        record_buf::{QualityScores, Sequence},
+    };
+
+    use super::*;
+
+    fn haplotype(record: &RecordBuf, start: usize, end: usize, trailing: bool) -> Option<Vec<u8>> {
+        read_haplotype(
+            record,
+            Position::new(start).unwrap(),
+            Position::new(end).unwrap(),
+            trailing,
+        )
+        .map(|(haplotype, _)| haplotype)
+    }
+
+    #[test]
+    fn test_read_haplotype() {
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::new(1).unwrap())
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .set_sequence(Sequence::from(b"ACGT".to_vec()))
+            .set_quality_scores(QualityScores::from(vec![30; 4]))
+            .build();
+        assert_eq!(haplotype(&record, 2, 3, false), Some(b"CG".to_vec()));
+        assert!(haplotype(&record, 4, 5, false).is_none());
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::new(1).unwrap())
+            .set_cigar(
+                [
+                    Op::new(Kind::Match, 2),
+                    Op::new(Kind::Insertion, 2),
+                    Op::new(Kind::Match, 2),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .set_sequence(Sequence::from(b"ACTTGT".to_vec()))
+            .set_quality_scores(QualityScores::from(vec![30; 6]))
+            .build();
+        assert_eq!(haplotype(&record, 2, 2, true), Some(b"CTT".to_vec()));
+        assert_eq!(haplotype(&record, 2, 2, false), Some(b"C".to_vec()));
+        assert_eq!(haplotype(&record, 3, 3, true), Some(b"G".to_vec()));
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::new(1).unwrap())
+            .set_cigar(
+                [
+                    Op::new(Kind::Match, 2),
+                    Op::new(Kind::Deletion, 2),
+                    Op::new(Kind::Match, 2),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .set_sequence(Sequence::from(b"ACGT".to_vec()))
+            .set_quality_scores(QualityScores::from(vec![30; 4]))
+            .build();
+        assert_eq!(haplotype(&record, 2, 4, false), Some(b"C".to_vec()));
+        assert!(haplotype(&record, 2, 3, false).is_none());
+        assert!(haplotype(&record, 4, 5, false).is_none());
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::new(1).unwrap())
+            .set_cigar(
+                [Op::new(Kind::SoftClip, 2), Op::new(Kind::Match, 2)]
+                    .into_iter()
+                    .collect(),
+            )
+            .set_sequence(Sequence::from(b"TTAC".to_vec()))
+            .set_quality_scores(QualityScores::from(vec![30; 4]))
+            .build();
+        assert_eq!(haplotype(&record, 2, 2, false), Some(b"C".to_vec()));
+    }
+
+    #[test]
+    fn test_count() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use sam::header::record::value::{map::ReferenceSequence, Map};
+        use vcf::variant::record_buf::AlternateBases;
+
+        let alignment_header = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::MIN))
+            .build();
+
+        let site = vcf::variant::RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(2)?)
+            .set_reference_bases("CGT")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("C")]))
+            .build();
+
+        let mut low_mapping_quality = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::new(1).unwrap())
+            .set_cigar(
+                [
+                    Op::new(Kind::Match, 2),
+                    Op::new(Kind::Deletion, 2),
+                    Op::new(Kind::Match, 1),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .set_sequence(Sequence::from(b"ACA".to_vec()))
+            .set_quality_scores(QualityScores::from(vec![30; 3]))
+            .build();
+        *low_mapping_quality.mapping_quality_mut() = MappingQuality::new(5);
+
+        let mut low_base_quality = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::new(1).unwrap())
+            .set_cigar([Op::new(Kind::Match, 5)].into_iter().collect())
+            .set_sequence(Sequence::from(b"ACGTA".to_vec()))
+            .set_quality_scores(QualityScores::from(vec![30; 5]))
+            .build();
+        *low_base_quality.quality_scores_mut() = QualityScores::from(vec![30, 30, 2, 30, 30]);
+
+        let records = [
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(1).unwrap())
+                .set_cigar([Op::new(Kind::Match, 5)].into_iter().collect())
+                .set_sequence(Sequence::from(b"ACGTA".to_vec()))
+                .set_quality_scores(QualityScores::from(vec![30; 5]))
+                .build(),
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(1).unwrap())
+                .set_cigar(
+                    [
+                        Op::new(Kind::Match, 2),
+                        Op::new(Kind::Deletion, 2),
+                        Op::new(Kind::Match, 1),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )
+                .set_sequence(Sequence::from(b"ACA".to_vec()))
+                .set_quality_scores(QualityScores::from(vec![30; 3]))
+                .build(),
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(1).unwrap())
+                .set_cigar(
+                    [
+                        Op::new(Kind::Match, 2),
+                        Op::new(Kind::Deletion, 2),
+                        Op::new(Kind::Match, 1),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )
+                .set_sequence(Sequence::from(b"ACA".to_vec()))
+                .set_quality_scores(QualityScores::from(vec![30; 3]))
+                .build(),
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(1).unwrap())
+                .set_cigar([Op::new(Kind::Match, 5)].into_iter().collect())
+                .set_sequence(Sequence::from(b"ACCTA".to_vec()))
+                .set_quality_scores(QualityScores::from(vec![30; 5]))
+                .build(),
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::new(3).unwrap())
+                .set_cigar([Op::new(Kind::Match, 2)].into_iter().collect())
+                .set_sequence(Sequence::from(b"TA".to_vec()))
+                .set_quality_scores(QualityScores::from(vec![30; 2]))
+                .build(),
+            low_mapping_quality,
+            low_base_quality,
+        ];
+
+        let counter = Builder::default().set_min_mapping_quality(10).build();
+        let counts = counter.count(&alignment_header, &site, records.into_iter().map(Ok))?;
+
+        assert_eq!(counts.depth(), 4);
+        assert_eq!(counts.counts(), [1, 2]);
+        assert_eq!(counts.allele_frequency(1), Some(0.5));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_with_baq() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use sam::header::record::value::{map::ReferenceSequence, Map};
+        use vcf::variant::record_buf::AlternateBases;
+
+        let alignment_header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(16)?),
+            )
+            .build();
+
+        let site = vcf::variant::RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(8)?)
+            .set_reference_bases("T")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("A")]))
+            .build();
+
+        let records = || {
+            [
+                RecordBuf::builder()
+                    .set_flags(Flags::empty())
+                    .set_reference_sequence_id(0)
+                    .set_alignment_start(Position::new(1).unwrap())
+                    .set_cigar([Op::new(Kind::Match, 16)].into_iter().collect())
+                    .set_sequence(Sequence::from(b"ACGTACGTACGTACGT".to_vec()))
+                    .set_quality_scores(QualityScores::from(vec![30; 16]))
+                    .build(),
+                RecordBuf::builder()
+                    .set_flags(Flags::empty())
+                    .set_reference_sequence_id(0)
+                    .set_alignment_start(Position::new(1).unwrap())
+                    .set_cigar([Op::new(Kind::Match, 16)].into_iter().collect())
+                    .set_sequence(Sequence::from(b"ACGTACGAACGTACGT".to_vec()))
+                    .set_quality_scores(QualityScores::from(vec![30; 16]))
+                    .build(),
+            ]
+            .into_iter()
+            .map(Ok)
+        };
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(b"ACGTACGTACGTACGT".to_vec()),
+        )]);
+
+        let counter = Builder::default()
+            .set_reference_sequence_repository(repository)
+            .build();
+
+        let counts = counter.count(&alignment_header, &site, records())?;
+        assert_eq!(counts.depth(), 2);
+        assert_eq!(counts.counts(), [1, 1]);
+
+        let counter = Builder::default()
+            .set_reference_sequence_repository(fasta::Repository::default())
+            .build();
+
+        assert!(matches!(
+            counter.count(&alignment_header, &site, records()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
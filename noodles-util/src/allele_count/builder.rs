@@ -0,0 +1,96 @@
+use noodles_fasta as fasta;
+
+use super::Counter;
+
+const DEFAULT_MIN_BASE_QUALITY: u8 = 13;
+
+/// An allele counter builder.
+#[derive(Clone, Debug)]
+pub struct Builder {
+    min_base_quality: u8,
+    min_mapping_quality: u8,
+    reference_sequence_repository: Option<fasta::Repository>,
+}
+
+impl Builder {
+    /// Sets the minimum base quality.
+    ///
+    /// Reads with a base in the allele below this quality are not counted. By default, this is
+    /// 13, the same as `samtools mpileup`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::allele_count;
+    /// let builder = allele_count::Builder::default().set_min_base_quality(20);
+    /// ```
+    pub fn set_min_base_quality(mut self, min_base_quality: u8) -> Self {
+        self.min_base_quality = min_base_quality;
+        self
+    }
+
+    /// Sets the minimum mapping quality.
+    ///
+    /// Reads below this mapping quality are not counted. Reads with a missing mapping quality
+    /// (255) are always counted. By default, this is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::allele_count;
+    /// let builder = allele_count::Builder::default().set_min_mapping_quality(20);
+    /// ```
+    pub fn set_min_mapping_quality(mut self, min_mapping_quality: u8) -> Self {
+        self.min_mapping_quality = min_mapping_quality;
+        self
+    }
+
+    /// Sets the reference sequence repository.
+    ///
+    /// When set, base qualities are capped by their per-base alignment quality (BAQ) before
+    /// counting, the same as `samtools mpileup` when given a reference. See
+    /// [`crate::alignment::baq`]. By default, BAQ is not applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// use noodles_util::allele_count;
+    ///
+    /// let repository = fasta::Repository::default();
+    /// let builder = allele_count::Builder::default().set_reference_sequence_repository(repository);
+    /// ```
+    pub fn set_reference_sequence_repository(
+        mut self,
+        reference_sequence_repository: fasta::Repository,
+    ) -> Self {
+        self.reference_sequence_repository = Some(reference_sequence_repository);
+        self
+    }
+
+    /// Builds an allele counter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::allele_count;
+    /// let counter = allele_count::Builder::default().build();
+    /// ```
+    pub fn build(self) -> Counter {
+        Counter {
+            min_base_quality: self.min_base_quality,
+            min_mapping_quality: self.min_mapping_quality,
+            reference_sequence_repository: self.reference_sequence_repository,
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            min_base_quality: DEFAULT_MIN_BASE_QUALITY,
+            min_mapping_quality: 0,
+            reference_sequence_repository: None,
+        }
+    }
+}
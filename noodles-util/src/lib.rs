@@ -1,10 +1,27 @@
 #![warn(missing_docs)]
 
 //! **noodles-util** are utilities for working with noodles. Currently, this consists of a unified
-//! interface for reading and writing [alignment] (BAM/CRAM/SAM) and [variant] (VCF/BCF) data.
+//! interface for reading and writing [alignment] (BAM/CRAM/SAM) and [variant] (VCF/BCF) data,
+//! [read name] helpers, [allele counting] at variant sites, [simulated] alignment and variant
+//! data, and reader [progress] reporting.
+//!
+//! [allele counting]: allele_count
+//! [read name]: read_name
+//! [simulated]: simulate
 
 #[cfg(feature = "alignment")]
 pub mod alignment;
 
+#[cfg(all(feature = "alignment", feature = "variant"))]
+pub mod allele_count;
+
+#[cfg(any(feature = "alignment", feature = "variant"))]
+pub mod progress;
+
+pub mod read_name;
+
+#[cfg(all(feature = "alignment", feature = "variant"))]
+pub mod simulate;
+
 #[cfg(feature = "variant")]
 pub mod variant;
@@ -0,0 +1,198 @@
+//! Reader progress.
+
+use std::{
+    io::{self, BufRead, Read},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+/// A handle to the progress of a reader.
+///
+/// This is a cheap, cloneable handle to counters that a reader updates as it reads. A clone can
+/// be sent to another thread, e.g., to periodically render a progress bar.
+///
+/// Counters are updated with relaxed atomic operations, so a sample is not necessarily
+/// consistent across counters.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_util::alignment;
+///
+/// let data = io::Cursor::new(b"@HD\tVN:1.6\n*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\n");
+/// let mut reader = alignment::io::reader::Builder::default().build_from_reader(data)?;
+/// let progress = reader.progress().clone();
+///
+/// let header = reader.read_header()?;
+///
+/// for result in reader.records(&header) {
+///     let _record = result?;
+/// }
+///
+/// assert_eq!(progress.compressed_bytes(), 35);
+/// assert_eq!(progress.uncompressed_bytes(), 35);
+/// assert_eq!(progress.records(), 1);
+/// # Ok::<_, io::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct Progress {
+    inner: Arc<Counters>,
+}
+
+#[derive(Debug)]
+struct Counters {
+    compressed_bytes: AtomicU64,
+    uncompressed_bytes: AtomicU64,
+    records: AtomicU64,
+    start: Instant,
+}
+
+impl Progress {
+    /// Returns the number of bytes read from the underlying reader.
+    ///
+    /// This includes bytes that are buffered but not yet decoded. For uncompressed inputs, this
+    /// can be ahead of the number of uncompressed bytes.
+    pub fn compressed_bytes(&self) -> u64 {
+        self.inner.compressed_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of bytes consumed by the format reader.
+    ///
+    /// For bgzip-compressed inputs, this is the number of decompressed bytes. CRAM inputs are
+    /// read as is, so this is the number of container bytes.
+    pub fn uncompressed_bytes(&self) -> u64 {
+        self.inner.uncompressed_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of records read.
+    pub fn records(&self) -> u64 {
+        self.inner.records.load(Ordering::Relaxed)
+    }
+
+    /// Returns the time elapsed since the reader was created.
+    pub fn elapsed(&self) -> Duration {
+        self.inner.start.elapsed()
+    }
+
+    /// Returns the average number of records read per second since the reader was created.
+    pub fn records_per_second(&self) -> f64 {
+        let secs = self.elapsed().as_secs_f64();
+
+        if secs > 0.0 {
+            self.records() as f64 / secs
+        } else {
+            0.0
+        }
+    }
+
+    pub(crate) fn add_records(&self, n: u64) {
+        self.inner.records.fetch_add(n, Ordering::Relaxed);
+    }
+}
+
+impl Default for Progress {
+    fn default() -> Self {
+        Self {
+            inner: Arc::new(Counters {
+                compressed_bytes: AtomicU64::new(0),
+                uncompressed_bytes: AtomicU64::new(0),
+                records: AtomicU64::new(0),
+                start: Instant::now(),
+            }),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum Counter {
+    Compressed,
+    Uncompressed,
+}
+
+/// A reader that counts the bytes read into a progress counter.
+pub(crate) struct Reader<R> {
+    inner: R,
+    progress: Progress,
+    counter: Counter,
+}
+
+impl<R> Reader<R> {
+    pub(crate) fn new(inner: R, progress: Progress, counter: Counter) -> Self {
+        Self {
+            inner,
+            progress,
+            counter,
+        }
+    }
+
+    fn add(&self, n: usize) {
+        let counters = &self.progress.inner;
+
+        let counter = match self.counter {
+            Counter::Compressed => &counters.compressed_bytes,
+            Counter::Uncompressed => &counters.uncompressed_bytes,
+        };
+
+        counter.fetch_add(n as u64, Ordering::Relaxed);
+    }
+}
+
+impl<R> Read for Reader<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.add(n);
+        Ok(n)
+    }
+}
+
+impl<R> BufRead for Reader<R>
+where
+    R: BufRead,
+{
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.inner.consume(amt);
+        self.add(amt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reader() -> io::Result<()> {
+        let progress = Progress::default();
+
+        let mut reader = Reader::new(
+            &b"noodles\nbgzf"[..],
+            progress.clone(),
+            Counter::Uncompressed,
+        );
+
+        let mut buf = Vec::new();
+        reader.read_until(b'\n', &mut buf)?;
+        assert_eq!(progress.uncompressed_bytes(), 8);
+
+        let mut buf = [0; 2];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(progress.uncompressed_bytes(), 10);
+
+        assert_eq!(progress.compressed_bytes(), 0);
+
+        progress.add_records(2);
+        assert_eq!(progress.records(), 2);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,4 @@
+//! Read name utilities.
+
+pub mod illumina;
+pub mod sra;
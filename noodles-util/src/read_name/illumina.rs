@@ -0,0 +1,160 @@
+//! Illumina read names.
+//!
+//! Illumina read names encode the location of a cluster on the flow cell. Since CASAVA 1.8, the
+//! read name is of the form `<instrument>:<run number>:<flowcell ID>:<lane>:<tile>:<x>:<y>`,
+//! e.g., `A00123:8:H5KJ7DRXX:1:2101:1234:5678`. Older read names are of the form
+//! `<instrument>:<lane>:<tile>:<x>:<y>`, optionally followed by an index and read number, e.g.,
+//! `HWI-ST1234:8:1101:1234:5678#0/1`.
+
+/// An Illumina read name.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Name<'a> {
+    instrument: &'a [u8],
+    lane: u32,
+    tile: u32,
+    x: u32,
+    y: u32,
+}
+
+impl<'a> Name<'a> {
+    /// Parses an Illumina read name.
+    ///
+    /// Only the first word of the name is parsed, i.e., a trailing FASTQ description (e.g.,
+    /// `1:N:0:ACGT`) is ignored. Index (`#0`) and mate (`/1` or `/2`) suffixes are also ignored.
+    ///
+    /// This returns `None` if the name is not an Illumina read name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::read_name::illumina::Name;
+    ///
+    /// let name = Name::parse(b"A00123:8:H5KJ7DRXX:1:2101:1234:5678").unwrap();
+    /// assert_eq!(name.instrument(), b"A00123");
+    /// assert_eq!(name.lane(), 1);
+    /// assert_eq!(name.tile(), 2101);
+    /// assert_eq!(name.x(), 1234);
+    /// assert_eq!(name.y(), 5678);
+    ///
+    /// let name = Name::parse(b"HWI-ST1234:8:1101:13:21#0/1").unwrap();
+    /// assert_eq!(name.lane(), 8);
+    /// assert_eq!(name.tile(), 1101);
+    ///
+    /// assert!(Name::parse(b"SRR390728.1").is_none());
+    /// ```
+    pub fn parse(src: &'a [u8]) -> Option<Self> {
+        let src = src
+            .split(|b| b.is_ascii_whitespace())
+            .next()
+            .unwrap_or_default();
+
+        let src = strip_suffixes(src);
+
+        let fields: Vec<_> = src.split(|&b| b == DELIMITER).collect();
+
+        let (instrument, location) = match fields[..] {
+            [instrument, _run_number, _flowcell_id, ref location @ ..] if location.len() == 4 => {
+                (instrument, location)
+            }
+            [instrument, ref location @ ..] if location.len() == 4 => (instrument, location),
+            _ => return None,
+        };
+
+        if instrument.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            instrument,
+            lane: parse_number(location[0])?,
+            tile: parse_number(location[1])?,
+            x: parse_number(location[2])?,
+            y: parse_number(location[3])?,
+        })
+    }
+
+    /// Returns the instrument ID.
+    pub fn instrument(&self) -> &'a [u8] {
+        self.instrument
+    }
+
+    /// Returns the flow cell lane.
+    pub fn lane(&self) -> u32 {
+        self.lane
+    }
+
+    /// Returns the tile number.
+    pub fn tile(&self) -> u32 {
+        self.tile
+    }
+
+    /// Returns the x-coordinate of the cluster.
+    pub fn x(&self) -> u32 {
+        self.x
+    }
+
+    /// Returns the y-coordinate of the cluster.
+    pub fn y(&self) -> u32 {
+        self.y
+    }
+}
+
+const DELIMITER: u8 = b':';
+
+fn strip_suffixes(src: &[u8]) -> &[u8] {
+    let src = src
+        .strip_suffix(b"/1")
+        .or_else(|| src.strip_suffix(b"/2"))
+        .unwrap_or(src);
+
+    match src.iter().rposition(|&b| b == b'#') {
+        Some(i) => &src[..i],
+        None => src,
+    }
+}
+
+fn parse_number(s: &[u8]) -> Option<u32> {
+    if s.is_empty() || !s.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+
+    std::str::from_utf8(s).ok()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let expected = Name {
+            instrument: b"A00123",
+            lane: 1,
+            tile: 2101,
+            x: 1234,
+            y: 5678,
+        };
+
+        assert_eq!(
+            Name::parse(b"A00123:8:H5KJ7DRXX:1:2101:1234:5678"),
+            Some(expected)
+        );
+
+        assert_eq!(
+            Name::parse(b"A00123:8:H5KJ7DRXX:1:2101:1234:5678 1:N:0:ACGT"),
+            Some(expected)
+        );
+
+        assert_eq!(
+            Name::parse(b"A00123:1:2101:1234:5678#ACGT/2"),
+            Some(expected)
+        );
+
+        assert!(Name::parse(b"").is_none());
+        assert!(Name::parse(b"r0").is_none());
+        assert!(Name::parse(b":1:2101:1234:5678").is_none());
+        assert!(Name::parse(b"A00123:1:2101:1234").is_none());
+        assert!(Name::parse(b"A00123:1:2101:1234:y").is_none());
+        assert!(Name::parse(b"A00123:8:H5KJ7DRXX:1:2101:1234:5678:9").is_none());
+    }
+}
@@ -0,0 +1,250 @@
+//! SRA read names.
+//!
+//! Reads downloaded from the Sequence Read Archive (SRA), e.g., using `fastq-dump` or
+//! `fasterq-dump`, commonly have normalized names of the form `<accession>.<spot>`, e.g.,
+//! `SRR390728.1`, optionally followed by a read number (`SRR390728.1.1`) or a mate suffix
+//! (`SRR390728.1/1`). When dumped with original read names, the original name is the first word
+//! of the description, e.g., `@SRR390728.1 HWI-ST1234:8:1101:1234:5678 length=72`.
+
+/// An SRA-normalized read name.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Name<'a> {
+    accession: &'a [u8],
+    spot_number: u64,
+    read_number: Option<u64>,
+}
+
+impl<'a> Name<'a> {
+    /// Parses an SRA-normalized read name.
+    ///
+    /// The name must be an SRA, ENA, or DDBJ run accession (`SRR`, `ERR`, or `DRR` followed by
+    /// digits), a spot number, and an optional read number, each delimited by a `.` (period). A
+    /// trailing `/1` or `/2` mate suffix is ignored.
+    ///
+    /// This returns `None` if the name is not an SRA-normalized read name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::read_name::sra::Name;
+    ///
+    /// let name = Name::parse(b"SRR390728.7.2").unwrap();
+    /// assert_eq!(name.accession(), b"SRR390728");
+    /// assert_eq!(name.spot_number(), 7);
+    /// assert_eq!(name.read_number(), Some(2));
+    ///
+    /// assert!(Name::parse(b"HWI-ST1234:8:1101:1234:5678").is_none());
+    /// ```
+    pub fn parse(src: &'a [u8]) -> Option<Self> {
+        let src = strip_mate_suffix(src);
+
+        let mut fields = src.split(|&b| b == DELIMITER);
+
+        let accession = fields.next().filter(|s| is_run_accession(s))?;
+        let spot_number = fields.next().and_then(parse_number)?;
+
+        let read_number = match fields.next() {
+            Some(s) => Some(parse_number(s)?),
+            None => None,
+        };
+
+        if fields.next().is_some() {
+            return None;
+        }
+
+        Some(Self {
+            accession,
+            spot_number,
+            read_number,
+        })
+    }
+
+    /// Returns the run accession.
+    pub fn accession(&self) -> &'a [u8] {
+        self.accession
+    }
+
+    /// Returns the spot number.
+    pub fn spot_number(&self) -> u64 {
+        self.spot_number
+    }
+
+    /// Returns the read number, if present.
+    pub fn read_number(&self) -> Option<u64> {
+        self.read_number
+    }
+
+    /// Returns the spot name, i.e., the name without the read number.
+    ///
+    /// All the reads of a spot (e.g., both mates of a pair) have the same spot name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::read_name::sra::Name;
+    /// let name = Name::parse(b"SRR390728.7.2").unwrap();
+    /// assert_eq!(name.spot_name(), b"SRR390728.7");
+    /// ```
+    pub fn spot_name(&self) -> Vec<u8> {
+        let mut dst = self.accession.to_vec();
+        dst.push(DELIMITER);
+        dst.extend(self.spot_number.to_string().as_bytes());
+        dst
+    }
+}
+
+const DELIMITER: u8 = b'.';
+
+/// Returns whether the given read name is an SRA-normalized read name.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_util::read_name::sra;
+/// assert!(sra::is_sra_name(b"SRR390728.1"));
+/// assert!(!sra::is_sra_name(b"r0"));
+/// ```
+pub fn is_sra_name(src: &[u8]) -> bool {
+    Name::parse(src).is_some()
+}
+
+/// Removes the read number or mate suffix of a read name.
+///
+/// For SRA-normalized read names, this removes the read number (e.g., `.1`) and mate suffix
+/// (e.g., `/1`), leaving the spot name, so that mates have the same name. For other read names,
+/// only a `/1` or `/2` mate suffix is removed.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_util::read_name::sra;
+///
+/// assert_eq!(sra::strip_read_number(b"SRR390728.7.1"), b"SRR390728.7");
+/// assert_eq!(sra::strip_read_number(b"SRR390728.7/2"), b"SRR390728.7");
+/// assert_eq!(sra::strip_read_number(b"SRR390728.7"), b"SRR390728.7");
+/// assert_eq!(sra::strip_read_number(b"r0/1"), b"r0");
+/// assert_eq!(sra::strip_read_number(b"r0.1"), b"r0.1");
+/// ```
+pub fn strip_read_number(src: &[u8]) -> &[u8] {
+    let src = strip_mate_suffix(src);
+
+    match Name::parse(src) {
+        Some(name) if name.read_number().is_some() => {
+            // SAFETY: `src` is a valid name with a read number, which is the last field.
+            let i = src.iter().rposition(|&b| b == DELIMITER).unwrap();
+            &src[..i]
+        }
+        _ => src,
+    }
+}
+
+/// Returns the original read name from the description of an SRA-normalized read.
+///
+/// This is the first word of the description, unless it is a `length=` attribute.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_util::read_name::sra;
+///
+/// assert_eq!(
+///     sra::original_name(b"HWI-ST1234:8:1101:1234:5678 length=72"),
+///     Some(&b"HWI-ST1234:8:1101:1234:5678"[..])
+/// );
+///
+/// assert!(sra::original_name(b"length=72").is_none());
+/// assert!(sra::original_name(b"").is_none());
+/// ```
+pub fn original_name(description: &[u8]) -> Option<&[u8]> {
+    const LENGTH_PREFIX: &[u8] = b"length=";
+
+    description
+        .split(|b| b.is_ascii_whitespace())
+        .find(|s| !s.is_empty())
+        .filter(|s| !s.starts_with(LENGTH_PREFIX))
+}
+
+/// Returns the original-style read name of a read, if available.
+///
+/// If the name is an SRA-normalized read name and the description contains the original name,
+/// the original name is returned. Otherwise, the given name is returned.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_util::read_name::sra;
+///
+/// assert_eq!(
+///     sra::restore_original_name(b"SRR390728.1", b"HWI-ST1234:8:1101:1234:5678 length=72"),
+///     b"HWI-ST1234:8:1101:1234:5678"
+/// );
+///
+/// assert_eq!(sra::restore_original_name(b"SRR390728.1", b"length=72"), b"SRR390728.1");
+/// assert_eq!(sra::restore_original_name(b"r0", b"HWI-ST1234"), b"r0");
+/// ```
+pub fn restore_original_name<'a>(name: &'a [u8], description: &'a [u8]) -> &'a [u8] {
+    if is_sra_name(name) {
+        original_name(description).unwrap_or(name)
+    } else {
+        name
+    }
+}
+
+fn strip_mate_suffix(src: &[u8]) -> &[u8] {
+    src.strip_suffix(b"/1")
+        .or_else(|| src.strip_suffix(b"/2"))
+        .unwrap_or(src)
+}
+
+fn is_run_accession(s: &[u8]) -> bool {
+    const PREFIXES: [&[u8]; 3] = [b"SRR", b"ERR", b"DRR"];
+
+    PREFIXES.iter().any(|prefix| {
+        s.strip_prefix(*prefix)
+            .map(|digits| !digits.is_empty() && digits.iter().all(u8::is_ascii_digit))
+            .unwrap_or(false)
+    })
+}
+
+fn parse_number(s: &[u8]) -> Option<u64> {
+    if s.is_empty() || !s.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+
+    std::str::from_utf8(s).ok()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Name::parse(b"SRR390728.1"),
+            Some(Name {
+                accession: b"SRR390728",
+                spot_number: 1,
+                read_number: None,
+            })
+        );
+
+        assert_eq!(
+            Name::parse(b"ERR1.2.1/1"),
+            Some(Name {
+                accession: b"ERR1",
+                spot_number: 2,
+                read_number: Some(1),
+            })
+        );
+
+        assert!(Name::parse(b"").is_none());
+        assert!(Name::parse(b"SRR390728").is_none());
+        assert!(Name::parse(b"SRR.1").is_none());
+        assert!(Name::parse(b"SRX390728.1").is_none());
+        assert!(Name::parse(b"SRR390728.").is_none());
+        assert!(Name::parse(b"SRR390728.1.").is_none());
+        assert!(Name::parse(b"SRR390728.a").is_none());
+        assert!(Name::parse(b"SRR390728.1.2.3").is_none());
+    }
+}
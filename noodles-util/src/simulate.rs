@@ -0,0 +1,257 @@
+//! Synthetic alignment and variant data.
+
+mod builder;
+mod rng;
+
+pub use self::builder::Builder;
+
+use std::io::{self, Write};
+
+use noodles_fastq as fastq;
+use noodles_sam::{self as sam, alignment::RecordBuf};
+use noodles_vcf as vcf;
+
+/// A simulation of reads aligned to a reference and its truth variants.
+///
+/// Truth variants (SNVs and indels) are drawn at random and applied to each reference sequence,
+/// resulting in a homozygous haplotype. Paired reads are then sampled from the haplotype, with
+/// sequencing errors (substitutions), and aligned back to the reference. The alignments
+/// reflect the truth variants, i.e., insertions and deletions appear in the CIGAR strings. The
+/// reads are also available unaligned, as FASTQ record pairs.
+///
+/// This is useful to produce fixtures for benchmarks and integration tests, e.g., to check that
+/// a variant caller recovers the truth variants from the alignments. Simulations are
+/// reproducible for a given seed.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+/// use noodles_sam as sam;
+/// use noodles_util::simulate;
+/// use noodles_vcf as vcf;
+///
+/// let reference_sequences = [fasta::Record::new(
+///     Definition::new("sq0", None),
+///     Sequence::from(b"ACGT".repeat(256)),
+/// )];
+///
+/// let simulation = simulate::Builder::default()
+///     .set_seed(8)
+///     .build_from_reference_sequences(&reference_sequences)?;
+///
+/// let mut writer = sam::io::Writer::new(Vec::new());
+/// simulation.write_alignments(&mut writer)?;
+///
+/// let mut writer = vcf::io::Writer::new(Vec::new());
+/// simulation.write_variants(&mut writer)?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Simulation {
+    alignment_header: sam::Header,
+    alignment_records: Vec<RecordBuf>,
+    reads: Vec<(fastq::Record, fastq::Record)>,
+    variant_header: vcf::Header,
+    variant_records: Vec<vcf::variant::RecordBuf>,
+}
+
+impl Simulation {
+    /// Creates a simulation builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::simulate::Simulation;
+    /// let builder = Simulation::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    /// Returns the alignment header.
+    ///
+    /// This lists the reference sequences and marks the records as coordinate-sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::simulate;
+    /// let simulation = simulate::Builder::default().build_from_reference_sequences(&[])?;
+    /// assert!(simulation.alignment_header().reference_sequences().is_empty());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn alignment_header(&self) -> &sam::Header {
+        &self.alignment_header
+    }
+
+    /// Returns the coordinate-sorted alignment records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::simulate;
+    /// let simulation = simulate::Builder::default().build_from_reference_sequences(&[])?;
+    /// assert!(simulation.alignment_records().is_empty());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn alignment_records(&self) -> &[RecordBuf] {
+        &self.alignment_records
+    }
+
+    /// Returns the variant header.
+    ///
+    /// This lists the reference sequences as contigs and a single sample.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::simulate;
+    /// let simulation = simulate::Builder::default().build_from_reference_sequences(&[])?;
+    /// assert_eq!(simulation.variant_header().sample_names().len(), 1);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn variant_header(&self) -> &vcf::Header {
+        &self.variant_header
+    }
+
+    /// Returns the sorted truth variant records.
+    ///
+    /// Each record has a single alternate allele and a homozygous alternate genotype (`1/1`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::simulate;
+    /// let simulation = simulate::Builder::default().build_from_reference_sequences(&[])?;
+    /// assert!(simulation.variant_records().is_empty());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn variant_records(&self) -> &[vcf::variant::RecordBuf] {
+        &self.variant_records
+    }
+
+    /// Writes the alignment header and records to an alignment writer.
+    ///
+    /// This also finishes the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::simulate;
+    ///
+    /// let simulation = simulate::Builder::default().build_from_reference_sequences(&[])?;
+    ///
+    /// let mut writer = sam::io::Writer::new(Vec::new());
+    /// simulation.write_alignments(&mut writer)?;
+    ///
+    /// assert!(writer.get_ref().starts_with(b"@HD\t"));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn write_alignments<W>(&self, writer: &mut W) -> io::Result<()>
+    where
+        W: sam::alignment::io::Write + ?Sized,
+    {
+        writer.write_alignment_header(&self.alignment_header)?;
+
+        for record in &self.alignment_records {
+            writer.write_alignment_record(&self.alignment_header, record)?;
+        }
+
+        writer.finish(&self.alignment_header)
+    }
+
+    /// Returns the simulated reads as FASTQ record pairs.
+    ///
+    /// Each pair is the first and last segment of a template, as they were sequenced, i.e.,
+    /// reverse-complemented reads are reverse complemented back. Pairs are in the order they
+    /// were simulated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::simulate;
+    /// let simulation = simulate::Builder::default().build_from_reference_sequences(&[])?;
+    /// assert!(simulation.reads().is_empty());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn reads(&self) -> &[(fastq::Record, fastq::Record)] {
+        &self.reads
+    }
+
+    /// Writes the simulated reads to a pair of FASTQ writers.
+    ///
+    /// The first segments are written to `writer_1` and the last segments to `writer_2`, in the
+    /// same order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+    /// use noodles_fastq as fastq;
+    /// use noodles_util::simulate;
+    ///
+    /// let reference_sequences = [fasta::Record::new(
+    ///     Definition::new("sq0", None),
+    ///     Sequence::from(b"ACGT".repeat(256)),
+    /// )];
+    ///
+    /// let simulation = simulate::Builder::default()
+    ///     .set_coverage(1.0)
+    ///     .build_from_reference_sequences(&reference_sequences)?;
+    ///
+    /// let mut writer_1 = fastq::io::Writer::new(Vec::new());
+    /// let mut writer_2 = fastq::io::Writer::new(Vec::new());
+    /// simulation.write_reads(&mut writer_1, &mut writer_2)?;
+    ///
+    /// assert!(writer_1.get_ref().starts_with(b"@r"));
+    /// assert!(writer_2.get_ref().starts_with(b"@r"));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn write_reads<W1, W2>(
+        &self,
+        writer_1: &mut fastq::io::Writer<W1>,
+        writer_2: &mut fastq::io::Writer<W2>,
+    ) -> io::Result<()>
+    where
+        W1: Write,
+        W2: Write,
+    {
+        for (first, last) in &self.reads {
+            writer_1.write_record(first)?;
+            writer_2.write_record(last)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the variant header and records to a variant writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::simulate;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let simulation = simulate::Builder::default().build_from_reference_sequences(&[])?;
+    ///
+    /// let mut writer = vcf::io::Writer::new(Vec::new());
+    /// simulation.write_variants(&mut writer)?;
+    ///
+    /// assert!(writer.get_ref().starts_with(b"##fileformat=VCF"));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn write_variants<W>(&self, writer: &mut W) -> io::Result<()>
+    where
+        W: vcf::variant::io::Write + ?Sized,
+    {
+        writer.write_variant_header(&self.variant_header)?;
+
+        for record in &self.variant_records {
+            writer.write_variant_record(&self.variant_header, record)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,886 @@
+use std::{io, num::NonZeroUsize, str};
+
+use noodles_core::Position;
+use noodles_fasta as fasta;
+use noodles_fastq as fastq;
+use noodles_sam::{
+    self as sam,
+    alignment::{
+        record::{
+            cigar::{op::Kind, Op},
+            Flags, MappingQuality,
+        },
+        record_buf::{Cigar, QualityScores, Sequence},
+        RecordBuf,
+    },
+    header::record::value::{
+        map::{
+            self,
+            header::{self as header_map, Version},
+            ReferenceSequence,
+        },
+        Map,
+    },
+};
+use noodles_vcf::{
+    self as vcf,
+    header::record::value::{
+        map::{Contig, Format},
+        Map as VcfMap,
+    },
+    variant::{
+        record::samples::keys::key,
+        record_buf::{
+            samples::{keys::Keys, sample::value::Genotype, sample::Value},
+            AlternateBases, Filters, Samples,
+        },
+    },
+};
+
+use super::{rng::Rng, Simulation};
+
+const MAPPING_QUALITY: MappingQuality = match MappingQuality::new(60) {
+    Some(mapping_quality) => mapping_quality,
+    None => unreachable!(),
+};
+
+const QUALITY_SCORE: u8 = 30;
+
+/// A simulation builder.
+#[derive(Clone, Debug)]
+pub struct Builder {
+    seed: u64,
+    coverage: f64,
+    read_length: usize,
+    insert_size_mean: f64,
+    insert_size_std_dev: f64,
+    error_rate: f64,
+    snv_rate: f64,
+    indel_rate: f64,
+    max_indel_length: usize,
+    sample_name: String,
+}
+
+impl Builder {
+    /// Sets the seed of the random number generator.
+    ///
+    /// Simulations with the same seed and parameters are equal.
+    ///
+    /// By default, the seed is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::simulate;
+    /// let builder = simulate::Builder::default().set_seed(8);
+    /// ```
+    pub fn set_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Sets the mean read depth.
+    ///
+    /// This determines the number of read pairs sampled from each reference sequence.
+    ///
+    /// By default, the coverage is 10.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::simulate;
+    /// let builder = simulate::Builder::default().set_coverage(30.0);
+    /// ```
+    pub fn set_coverage(mut self, coverage: f64) -> Self {
+        self.coverage = coverage;
+        self
+    }
+
+    /// Sets the read length.
+    ///
+    /// By default, the read length is 100.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::simulate;
+    /// let builder = simulate::Builder::default().set_read_length(150);
+    /// ```
+    pub fn set_read_length(mut self, read_length: usize) -> Self {
+        self.read_length = read_length;
+        self
+    }
+
+    /// Sets the mean and standard deviation of the insert (fragment) size.
+    ///
+    /// Insert sizes are normally distributed and clamped to at least the read length.
+    ///
+    /// By default, the mean is 300 and the standard deviation is 30.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::simulate;
+    /// let builder = simulate::Builder::default().set_insert_size(500.0, 50.0);
+    /// ```
+    pub fn set_insert_size(mut self, mean: f64, std_dev: f64) -> Self {
+        self.insert_size_mean = mean;
+        self.insert_size_std_dev = std_dev;
+        self
+    }
+
+    /// Sets the per-base sequencing error (substitution) rate of reads.
+    ///
+    /// By default, the error rate is 0.001.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::simulate;
+    /// let builder = simulate::Builder::default().set_error_rate(0.01);
+    /// ```
+    pub fn set_error_rate(mut self, error_rate: f64) -> Self {
+        self.error_rate = error_rate;
+        self
+    }
+
+    /// Sets the per-base rate of truth single nucleotide variants.
+    ///
+    /// By default, the SNV rate is 0.001.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::simulate;
+    /// let builder = simulate::Builder::default().set_snv_rate(0.01);
+    /// ```
+    pub fn set_snv_rate(mut self, snv_rate: f64) -> Self {
+        self.snv_rate = snv_rate;
+        self
+    }
+
+    /// Sets the per-base rate of truth insertions and deletions.
+    ///
+    /// Insertions and deletions are equally likely.
+    ///
+    /// By default, the indel rate is 0.0001.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::simulate;
+    /// let builder = simulate::Builder::default().set_indel_rate(0.001);
+    /// ```
+    pub fn set_indel_rate(mut self, indel_rate: f64) -> Self {
+        self.indel_rate = indel_rate;
+        self
+    }
+
+    /// Sets the maximum length of truth insertions and deletions.
+    ///
+    /// Indel lengths are uniformly distributed in [1, `max_indel_length`].
+    ///
+    /// By default, the maximum indel length is 5.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::simulate;
+    /// let builder = simulate::Builder::default().set_max_indel_length(10);
+    /// ```
+    pub fn set_max_indel_length(mut self, max_indel_length: usize) -> Self {
+        self.max_indel_length = max_indel_length;
+        self
+    }
+
+    /// Sets the sample name of the truth variants.
+    ///
+    /// By default, the sample name is `sample0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::simulate;
+    /// let builder = simulate::Builder::default().set_sample_name("NA12878");
+    /// ```
+    pub fn set_sample_name<N>(mut self, sample_name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.sample_name = sample_name.into();
+        self
+    }
+
+    /// Builds a simulation from reference sequences.
+    ///
+    /// Reference sequences shorter than the read length have truth variants but no reads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+    /// use noodles_util::simulate;
+    ///
+    /// let reference_sequences = [fasta::Record::new(
+    ///     Definition::new("sq0", None),
+    ///     Sequence::from(b"ACGT".repeat(256)),
+    /// )];
+    ///
+    /// let simulation = simulate::Builder::default()
+    ///     .set_read_length(50)
+    ///     .set_insert_size(200.0, 20.0)
+    ///     .build_from_reference_sequences(&reference_sequences)?;
+    ///
+    /// assert!(!simulation.alignment_records().is_empty());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn build_from_reference_sequences(
+        self,
+        reference_sequences: &[fasta::Record],
+    ) -> io::Result<Simulation> {
+        self.validate()?;
+
+        let mut rng = Rng::new(self.seed);
+
+        let keys: Keys = [String::from(key::GENOTYPE)].into_iter().collect();
+        let genotype: Genotype = "1/1"
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        let mut alignment_header_builder = sam::Header::builder().set_header(
+            Map::<map::Header>::builder()
+                .set_version(Version::new(1, 6))
+                .insert(header_map::tag::SORT_ORDER, "coordinate")
+                .build()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?,
+        );
+
+        let mut variant_header_builder = vcf::Header::builder()
+            .add_format(key::GENOTYPE, VcfMap::<Format>::from(key::GENOTYPE))
+            .add_sample_name(self.sample_name.clone());
+
+        let mut alignment_records = Vec::new();
+        let mut variant_records = Vec::new();
+        let mut pair_count = 0;
+
+        for (reference_sequence_id, reference_sequence) in reference_sequences.iter().enumerate() {
+            let name = str::from_utf8(reference_sequence.name())
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+            let reference_bases = reference_sequence.sequence().as_ref();
+
+            let length = NonZeroUsize::new(reference_bases.len()).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidInput, "empty reference sequence")
+            })?;
+
+            alignment_header_builder = alignment_header_builder
+                .add_reference_sequence(name, Map::<ReferenceSequence>::new(length));
+
+            variant_header_builder = variant_header_builder.add_contig(
+                name,
+                VcfMap::<Contig>::builder()
+                    .set_length(length.get())
+                    .build()
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?,
+            );
+
+            let variants = self.simulate_variants(&mut rng, reference_bases);
+            let haplotype = Haplotype::new(reference_bases, &variants);
+
+            self.simulate_reads(
+                &mut rng,
+                &haplotype,
+                reference_sequence_id,
+                reference_bases.len(),
+                &mut pair_count,
+                &mut alignment_records,
+            )?;
+
+            for variant in variants {
+                let record = build_variant_record(name, variant, &keys, &genotype)?;
+                variant_records.push(record);
+            }
+        }
+
+        let reads = build_reads(&alignment_records)?;
+
+        alignment_records
+            .sort_by_key(|record| (record.reference_sequence_id(), record.alignment_start()));
+
+        Ok(Simulation {
+            alignment_header: alignment_header_builder.build(),
+            alignment_records,
+            reads,
+            variant_header: variant_header_builder.build(),
+            variant_records,
+        })
+    }
+
+    fn validate(&self) -> io::Result<()> {
+        let rates = [self.error_rate, self.snv_rate, self.indel_rate];
+
+        if rates.iter().any(|rate| !(0.0..=1.0).contains(rate))
+            || self.snv_rate + self.indel_rate > 1.0
+        {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "invalid rate"));
+        } else if self.read_length == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid read length",
+            ));
+        } else if self.max_indel_length == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid max indel length",
+            ));
+        } else if !self.coverage.is_finite()
+            || self.coverage < 0.0
+            || !self.insert_size_mean.is_finite()
+            || !self.insert_size_std_dev.is_finite()
+            || self.insert_size_std_dev < 0.0
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid coverage or insert size",
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn simulate_variants(&self, rng: &mut Rng, reference_bases: &[u8]) -> Vec<Variant> {
+        let mut variants = Vec::new();
+        let mut i = 0;
+
+        // Variants are separated by at least one unchanged reference base so that they never
+        // overlap.
+        while i < reference_bases.len() {
+            let base = reference_bases[i];
+
+            if !is_base(base) {
+                i += 1;
+                continue;
+            }
+
+            let r = rng.next_f64();
+
+            if r < self.snv_rate {
+                variants.push(Variant {
+                    position: i,
+                    reference_bases: vec![base],
+                    alternate_bases: vec![rng.substitute(base)],
+                });
+
+                i += 2;
+            } else if r < self.snv_rate + self.indel_rate {
+                let len = rng.below(self.max_indel_length) + 1;
+
+                if rng.next_bool() {
+                    let mut alternate_bases = vec![base];
+                    alternate_bases.extend((0..len).map(|_| rng.base()));
+
+                    variants.push(Variant {
+                        position: i,
+                        reference_bases: vec![base],
+                        alternate_bases,
+                    });
+
+                    i += 2;
+                } else if i + len < reference_bases.len() {
+                    variants.push(Variant {
+                        position: i,
+                        reference_bases: reference_bases[i..=i + len].to_vec(),
+                        alternate_bases: vec![base],
+                    });
+
+                    i += len + 2;
+                } else {
+                    i += 1;
+                }
+            } else {
+                i += 1;
+            }
+        }
+
+        variants
+    }
+
+    fn simulate_reads(
+        &self,
+        rng: &mut Rng,
+        haplotype: &Haplotype,
+        reference_sequence_id: usize,
+        reference_sequence_length: usize,
+        pair_count: &mut usize,
+        records: &mut Vec<RecordBuf>,
+    ) -> io::Result<()> {
+        let haplotype_length = haplotype.bases.len();
+
+        if haplotype_length < self.read_length {
+            return Ok(());
+        }
+
+        let n = (self.coverage * reference_sequence_length as f64 / (2 * self.read_length) as f64)
+            .round() as usize;
+
+        for _ in 0..n {
+            let fragment_length = rng
+                .normal(self.insert_size_mean, self.insert_size_std_dev)
+                .round()
+                .max(self.read_length as f64);
+            let fragment_length = (fragment_length as usize).min(haplotype_length);
+
+            let start = rng.below(haplotype_length - fragment_length + 1);
+            let end = start + fragment_length;
+
+            let left_read = self.simulate_read(rng, haplotype, start)?;
+            let right_read = self.simulate_read(rng, haplotype, end - self.read_length)?;
+
+            let (Some(left_read), Some(right_read)) = (left_read, right_read) else {
+                continue;
+            };
+
+            let name = format!("r{pair_count}");
+            *pair_count += 1;
+
+            let left_start = usize::from(left_read.alignment_start);
+            let right_end =
+                usize::from(right_read.alignment_start) + right_read.cigar.alignment_span() - 1;
+
+            let template_length = i32::try_from(right_end - left_start + 1)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+            let (left_segment, right_segment) = if rng.next_bool() {
+                (Flags::FIRST_SEGMENT, Flags::LAST_SEGMENT)
+            } else {
+                (Flags::LAST_SEGMENT, Flags::FIRST_SEGMENT)
+            };
+
+            let flags = Flags::SEGMENTED | Flags::PROPERLY_SEGMENTED;
+            let left_alignment_start = left_read.alignment_start;
+            let right_alignment_start = right_read.alignment_start;
+
+            records.push(left_read.into_record(
+                name.clone(),
+                flags | left_segment | Flags::MATE_REVERSE_COMPLEMENTED,
+                reference_sequence_id,
+                right_alignment_start,
+                template_length,
+            ));
+
+            records.push(right_read.into_record(
+                name,
+                flags | right_segment | Flags::REVERSE_COMPLEMENTED,
+                reference_sequence_id,
+                left_alignment_start,
+                -template_length,
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn simulate_read(
+        &self,
+        rng: &mut Rng,
+        haplotype: &Haplotype,
+        start: usize,
+    ) -> io::Result<Option<Read>> {
+        let end = start + self.read_length;
+        let reference_positions = &haplotype.reference_positions[start..end];
+
+        // Inserted bases at either end of the read are soft clipped.
+        let Some(first) = reference_positions.iter().position(Option::is_some) else {
+            return Ok(None);
+        };
+
+        let Some(last) = reference_positions.iter().rposition(Option::is_some) else {
+            return Ok(None);
+        };
+
+        let mut cigar = Cigar::default();
+        push_op(&mut cigar, Kind::SoftClip, first);
+
+        let mut prev_reference_position = None;
+
+        for reference_position in &reference_positions[first..=last] {
+            match reference_position {
+                Some(reference_position) => {
+                    if let Some(prev) = prev_reference_position {
+                        push_op(&mut cigar, Kind::Deletion, reference_position - prev - 1);
+                    }
+
+                    push_op(&mut cigar, Kind::Match, 1);
+                    prev_reference_position = Some(*reference_position);
+                }
+                None => push_op(&mut cigar, Kind::Insertion, 1),
+            }
+        }
+
+        push_op(&mut cigar, Kind::SoftClip, self.read_length - last - 1);
+
+        let sequence: Vec<_> = haplotype.bases[start..end]
+            .iter()
+            .map(|&base| {
+                if rng.next_f64() < self.error_rate {
+                    rng.substitute(base)
+                } else {
+                    base
+                }
+            })
+            .collect();
+
+        let alignment_start = reference_positions[first]
+            .map(|position| position + 1)
+            .and_then(Position::new)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid position"))?;
+
+        Ok(Some(Read {
+            alignment_start,
+            cigar,
+            sequence,
+        }))
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            coverage: 10.0,
+            read_length: 100,
+            insert_size_mean: 300.0,
+            insert_size_std_dev: 30.0,
+            error_rate: 0.001,
+            snv_rate: 0.001,
+            indel_rate: 0.0001,
+            max_indel_length: 5,
+            sample_name: String::from("sample0"),
+        }
+    }
+}
+
+struct Variant {
+    // 0-based
+    position: usize,
+    reference_bases: Vec<u8>,
+    alternate_bases: Vec<u8>,
+}
+
+struct Haplotype {
+    bases: Vec<u8>,
+    // 0-based reference positions of each base. Inserted bases have no reference position.
+    reference_positions: Vec<Option<usize>>,
+}
+
+impl Haplotype {
+    fn new(reference_bases: &[u8], variants: &[Variant]) -> Self {
+        let mut haplotype = Self {
+            bases: Vec::with_capacity(reference_bases.len()),
+            reference_positions: Vec::with_capacity(reference_bases.len()),
+        };
+
+        let mut start = 0;
+
+        for variant in variants {
+            haplotype.extend_from_reference(reference_bases, start, variant.position);
+
+            // The first alternate base is aligned to the first reference base, i.e., it is either
+            // a substitution or the padding base of an indel.
+            let (first, rest) = variant.alternate_bases.split_at(1);
+            haplotype.bases.push(first[0]);
+            haplotype.reference_positions.push(Some(variant.position));
+
+            haplotype.bases.extend(rest);
+            haplotype
+                .reference_positions
+                .extend(rest.iter().map(|_| None));
+
+            start = variant.position + variant.reference_bases.len();
+        }
+
+        haplotype.extend_from_reference(reference_bases, start, reference_bases.len());
+
+        haplotype
+    }
+
+    fn extend_from_reference(&mut self, reference_bases: &[u8], start: usize, end: usize) {
+        self.bases.extend(&reference_bases[start..end]);
+        self.reference_positions.extend((start..end).map(Some));
+    }
+}
+
+struct Read {
+    alignment_start: Position,
+    cigar: Cigar,
+    sequence: Vec<u8>,
+}
+
+impl Read {
+    fn into_record(
+        self,
+        name: String,
+        flags: Flags,
+        reference_sequence_id: usize,
+        mate_alignment_start: Position,
+        template_length: i32,
+    ) -> RecordBuf {
+        let quality_scores = QualityScores::from(vec![QUALITY_SCORE; self.sequence.len()]);
+
+        RecordBuf::builder()
+            .set_name(name)
+            .set_flags(flags)
+            .set_reference_sequence_id(reference_sequence_id)
+            .set_alignment_start(self.alignment_start)
+            .set_mapping_quality(MAPPING_QUALITY)
+            .set_cigar(self.cigar)
+            .set_mate_reference_sequence_id(reference_sequence_id)
+            .set_mate_alignment_start(mate_alignment_start)
+            .set_template_length(template_length)
+            .set_sequence(Sequence::from(self.sequence))
+            .set_quality_scores(quality_scores)
+            .build()
+    }
+}
+
+// Converts the alignment records, in pair order, to FASTQ record pairs, as they were sequenced.
+fn build_reads(records: &[RecordBuf]) -> io::Result<Vec<(fastq::Record, fastq::Record)>> {
+    let converter = crate::alignment::fastq::Builder::default().build();
+
+    records
+        .chunks_exact(2)
+        .map(|pair| {
+            let (first, last) = if pair[0].flags().is_first_segment() {
+                (&pair[0], &pair[1])
+            } else {
+                (&pair[1], &pair[0])
+            };
+
+            let first = converter.convert(first)?;
+            let last = converter.convert(last)?;
+
+            first
+                .zip(last)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "unselected record"))
+        })
+        .collect()
+}
+
+fn is_base(b: u8) -> bool {
+    matches!(b.to_ascii_uppercase(), b'A' | b'C' | b'G' | b'T')
+}
+
+fn push_op(cigar: &mut Cigar, kind: Kind, len: usize) {
+    if len == 0 {
+        return;
+    }
+
+    let ops = cigar.as_mut();
+
+    if let Some(op) = ops.last_mut() {
+        if op.kind() == kind {
+            *op = Op::new(kind, op.len() + len);
+            return;
+        }
+    }
+
+    ops.push(Op::new(kind, len));
+}
+
+fn build_variant_record(
+    reference_sequence_name: &str,
+    variant: Variant,
+    keys: &Keys,
+    genotype: &Genotype,
+) -> io::Result<vcf::variant::RecordBuf> {
+    let position = Position::new(variant.position + 1)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid position"))?;
+
+    let reference_bases = String::from_utf8(variant.reference_bases)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let alternate_bases = String::from_utf8(variant.alternate_bases)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let samples = Samples::new(
+        keys.clone(),
+        vec![vec![Some(Value::Genotype(genotype.clone()))]],
+    );
+
+    Ok(vcf::variant::RecordBuf::builder()
+        .set_reference_sequence_name(reference_sequence_name)
+        .set_variant_start(position)
+        .set_reference_bases(reference_bases)
+        .set_alternate_bases(AlternateBases::from(vec![alternate_bases]))
+        .set_filters(Filters::pass())
+        .set_samples(samples)
+        .build())
+}
+
+#[cfg(test)]
+mod tests {
+    use fasta::record::Definition;
+
+    use super::*;
+
+    fn build_reference_sequences() -> Vec<fasta::Record> {
+        let mut rng = Rng::new(0);
+
+        ["sq0", "sq1"]
+            .into_iter()
+            .map(|name| {
+                let bases: Vec<_> = (0..2048).map(|_| rng.base()).collect();
+                fasta::Record::new(
+                    Definition::new(name, None),
+                    fasta::record::Sequence::from(bases),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_build_from_reference_sequences() -> io::Result<()> {
+        let reference_sequences = build_reference_sequences();
+
+        let simulation = Builder::default()
+            .set_snv_rate(0.01)
+            .set_indel_rate(0.01)
+            .build_from_reference_sequences(&reference_sequences)?;
+
+        assert_eq!(simulation.alignment_header().reference_sequences().len(), 2);
+        assert_eq!(simulation.variant_header().contigs().len(), 2);
+
+        // 2 * (10 * 2048 / (2 * 100))
+        assert_eq!(simulation.alignment_records().len(), 408);
+        assert!(!simulation.variant_records().is_empty());
+
+        assert!(simulation.alignment_records().windows(2).all(|w| (
+            w[0].reference_sequence_id(),
+            w[0].alignment_start()
+        ) <= (
+            w[1].reference_sequence_id(),
+            w[1].alignment_start()
+        )));
+
+        assert!(simulation.alignment_records().iter().all(|record| {
+            record.cigar().read_length() == record.sequence().len()
+                && record
+                    .cigar()
+                    .as_ref()
+                    .iter()
+                    .any(|op| op.kind() == Kind::Match)
+        }));
+
+        assert!(simulation
+            .alignment_records()
+            .iter()
+            .any(|record| record.cigar().as_ref().len() > 1));
+
+        assert_eq!(simulation.reads().len(), 204);
+
+        assert!(simulation.reads().iter().all(|(first, last)| {
+            first.name() == last.name()
+                && first.sequence().len() == 100
+                && last.sequence().len() == 100
+        }));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_from_reference_sequences_without_variants_or_errors() -> io::Result<()> {
+        let reference_sequences = build_reference_sequences();
+
+        let simulation = Builder::default()
+            .set_error_rate(0.0)
+            .set_snv_rate(0.0)
+            .set_indel_rate(0.0)
+            .build_from_reference_sequences(&reference_sequences)?;
+
+        assert!(simulation.variant_records().is_empty());
+
+        for record in simulation.alignment_records() {
+            let reference_sequence_id = record.reference_sequence_id().unwrap();
+            let reference_bases = reference_sequences[reference_sequence_id]
+                .sequence()
+                .as_ref();
+
+            let start = usize::from(record.alignment_start().unwrap()) - 1;
+            let end = start + 100;
+
+            assert_eq!(
+                record.cigar().as_ref(),
+                [Op::new(Kind::Match, 100)].as_slice()
+            );
+            assert_eq!(record.sequence().as_ref(), &reference_bases[start..end]);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_from_reference_sequences_is_reproducible() -> io::Result<()> {
+        let reference_sequences = build_reference_sequences();
+
+        let a = Builder::default().build_from_reference_sequences(&reference_sequences)?;
+        let b = Builder::default().build_from_reference_sequences(&reference_sequences)?;
+        assert_eq!(a, b);
+
+        let c = Builder::default()
+            .set_seed(1)
+            .build_from_reference_sequences(&reference_sequences)?;
+        assert_ne!(a, c);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_from_reference_sequences_with_invalid_rate() {
+        let result = Builder::default()
+            .set_error_rate(2.0)
+            .build_from_reference_sequences(&[]);
+
+        assert!(matches!(
+            result,
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+    }
+
+    #[test]
+    fn test_haplotype_new() {
+        let variants = [
+            Variant {
+                position: 1,
+                reference_bases: b"C".to_vec(),
+                alternate_bases: b"CTT".to_vec(),
+            },
+            Variant {
+                position: 3,
+                reference_bases: b"TAC".to_vec(),
+                alternate_bases: b"T".to_vec(),
+            },
+            Variant {
+                position: 7,
+                reference_bases: b"T".to_vec(),
+                alternate_bases: b"A".to_vec(),
+            },
+        ];
+
+        let haplotype = Haplotype::new(b"ACGTACGT", &variants);
+
+        assert_eq!(haplotype.bases, b"ACTTGTGA");
+        assert_eq!(
+            haplotype.reference_positions,
+            [
+                Some(0),
+                Some(1),
+                None,
+                None,
+                Some(2),
+                Some(3),
+                Some(6),
+                Some(7)
+            ]
+        );
+    }
+}
@@ -0,0 +1,93 @@
+/// A seedable pseudorandom number generator (SplitMix64).
+///
+/// This is not cryptographically secure. It is only used to make simulations reproducible for a
+/// given seed.
+#[derive(Clone, Debug)]
+pub(super) struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub(super) fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub(super) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a uniformly distributed value in [0, 1).
+    pub(super) fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns a uniformly distributed value in [0, n).
+    pub(super) fn below(&mut self, n: usize) -> usize {
+        (self.next_f64() * n as f64) as usize
+    }
+
+    pub(super) fn next_bool(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+
+    /// Returns a normally distributed value using the Box-Muller transform.
+    pub(super) fn normal(&mut self, mean: f64, std_dev: f64) -> f64 {
+        let u = 1.0 - self.next_f64();
+        let v = self.next_f64();
+        let z = (-2.0 * u.ln()).sqrt() * (2.0 * std::f64::consts::PI * v).cos();
+        mean + std_dev * z
+    }
+
+    pub(super) fn base(&mut self) -> u8 {
+        BASES[self.below(BASES.len())]
+    }
+
+    /// Returns a base that differs from the given base.
+    pub(super) fn substitute(&mut self, base: u8) -> u8 {
+        let base = base.to_ascii_uppercase();
+
+        loop {
+            let b = self.base();
+
+            if b != base {
+                return b;
+            }
+        }
+    }
+}
+
+const BASES: [u8; 4] = *b"ACGT";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_u64() {
+        let mut a = Rng::new(8);
+        let mut b = Rng::new(8);
+
+        for _ in 0..16 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+
+        assert_ne!(Rng::new(0).next_u64(), Rng::new(1).next_u64());
+    }
+
+    #[test]
+    fn test_below() {
+        let mut rng = Rng::new(0);
+        assert!((0..1024).all(|_| rng.below(5) < 5));
+    }
+
+    #[test]
+    fn test_substitute() {
+        let mut rng = Rng::new(0);
+        assert!((0..1024).all(|_| rng.substitute(b'a') != b'A'));
+    }
+}
@@ -3,4 +3,11 @@
 #[cfg(feature = "async")]
 pub mod r#async;
 
+pub mod classifier;
+pub mod consensus;
 pub mod io;
+pub mod memory_store;
+pub mod merge;
+pub mod redact;
+
+pub use self::memory_store::MemoryStore;
@@ -99,7 +99,7 @@ impl Builder {
     where
         R: AsyncRead + Unpin + 'static,
     {
-        use crate::variant::io::reader::builder::{detect_compression_method, detect_format};
+        use crate::variant::io::{compression_method, format};
 
         let mut reader = BufReader::new(reader);
 
@@ -107,7 +107,7 @@ impl Builder {
             Some(compression_method) => compression_method,
             None => {
                 let mut src = reader.fill_buf().await?;
-                detect_compression_method(&mut src)?
+                compression_method::detect(&mut src)?
             }
         };
 
@@ -115,7 +115,7 @@ impl Builder {
             Some(format) => format,
             None => {
                 let mut src = reader.fill_buf().await?;
-                detect_format(&mut src, compression_method)?
+                format::detect_with_compression_method(&mut src, compression_method)?
             }
         };
 
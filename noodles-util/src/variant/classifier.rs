@@ -0,0 +1,297 @@
+//! Variant region classification.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::{collections::HashMap, fmt, io};
+
+use noodles_core::{
+    region::{Interval, IntervalSet},
+    Position,
+};
+use noodles_vcf::{self as vcf, variant::Record};
+
+/// A variant region class.
+///
+/// When a variant overlaps regions of multiple classes, the class with the highest precedence is
+/// used. Classes are listed in order of decreasing precedence.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RegionClass {
+    /// Within the splice site distance of an exon-intron boundary.
+    SpliceSite,
+    /// A coding sequence (`CDS`).
+    Coding,
+    /// A 5' untranslated region (`five_prime_UTR`).
+    FivePrimeUtr,
+    /// A 3' untranslated region (`three_prime_UTR`).
+    ThreePrimeUtr,
+    /// An exon that is not coding or an untranslated region, e.g., of a noncoding transcript.
+    NoncodingExon,
+    /// Within a gene or transcript but not an exon.
+    Intronic,
+    /// Outside of any gene or transcript.
+    Intergenic,
+}
+
+impl AsRef<str> for RegionClass {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::SpliceSite => "splice_site",
+            Self::Coding => "coding",
+            Self::FivePrimeUtr => "five_prime_utr",
+            Self::ThreePrimeUtr => "three_prime_utr",
+            Self::NoncodingExon => "noncoding_exon",
+            Self::Intronic => "intronic",
+            Self::Intergenic => "intergenic",
+        }
+    }
+}
+
+impl fmt::Display for RegionClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+#[derive(Debug, Default)]
+struct Regions {
+    splice_sites: IntervalSet,
+    coding: IntervalSet,
+    five_prime_utrs: IntervalSet,
+    three_prime_utrs: IntervalSet,
+    exons: IntervalSet,
+    genes: IntervalSet,
+}
+
+/// A variant region classifier.
+///
+/// This tags variants with the class of the gene model region they overlap, e.g., coding,
+/// intronic, or intergenic. It is a lightweight alternative to full variant effect prediction.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_gff as gff;
+/// use noodles_util::variant::classifier::{Classifier, RegionClass};
+///
+/// let records = [
+///     ("gene", 1, 100, "ID=g0"),
+///     ("mRNA", 1, 100, "ID=t0;Parent=g0"),
+///     ("exon", 1, 30, "Parent=t0"),
+///     ("exon", 71, 100, "Parent=t0"),
+///     ("CDS", 11, 30, "Parent=t0"),
+///     ("CDS", 71, 90, "Parent=t0"),
+/// ]
+/// .into_iter()
+/// .map(|(ty, start, end, attributes)| {
+///     let phase = if ty == "CDS" { "0" } else { "." };
+///     format!("sq0\tNOODLES\t{ty}\t{start}\t{end}\t.\t+\t{phase}\t{attributes}")
+///         .parse::<gff::Record>()
+///         .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+/// });
+///
+/// let classifier = Classifier::builder().build_from_records(records)?;
+///
+/// let position = Position::try_from(50)?;
+/// assert_eq!(classifier.classify("sq0", position, position), RegionClass::Intronic);
+///
+/// let position = Position::try_from(20)?;
+/// assert_eq!(classifier.classify("sq0", position, position), RegionClass::Coding);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct Classifier {
+    regions: HashMap<String, Regions>,
+}
+
+impl Classifier {
+    /// Returns a builder to create a classifier from GFF records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::classifier::Classifier;
+    /// let builder = Classifier::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    /// Classifies the region of the given interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_util::variant::classifier::{Classifier, RegionClass};
+    ///
+    /// let classifier = Classifier::default();
+    /// let position = Position::MIN;
+    /// assert_eq!(classifier.classify("sq0", position, position), RegionClass::Intergenic);
+    /// ```
+    pub fn classify(
+        &self,
+        reference_sequence_name: &str,
+        start: Position,
+        end: Position,
+    ) -> RegionClass {
+        let Some(regions) = self.regions.get(reference_sequence_name) else {
+            return RegionClass::Intergenic;
+        };
+
+        let interval = Interval::from(start..=end);
+
+        if regions.splice_sites.intersects(interval) {
+            RegionClass::SpliceSite
+        } else if regions.coding.intersects(interval) {
+            RegionClass::Coding
+        } else if regions.five_prime_utrs.intersects(interval) {
+            RegionClass::FivePrimeUtr
+        } else if regions.three_prime_utrs.intersects(interval) {
+            RegionClass::ThreePrimeUtr
+        } else if regions.exons.intersects(interval) {
+            RegionClass::NoncodingExon
+        } else if regions.genes.intersects(interval) {
+            RegionClass::Intronic
+        } else {
+            RegionClass::Intergenic
+        }
+    }
+
+    /// Classifies the region of a variant record.
+    ///
+    /// The variant interval is from its start to its end, as given by
+    /// [`Record::variant_end`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_util::variant::classifier::{Classifier, RegionClass};
+    /// use noodles_vcf::{self as vcf, variant::RecordBuf};
+    ///
+    /// let header = vcf::Header::default();
+    ///
+    /// let record = RecordBuf::builder()
+    ///     .set_reference_sequence_name("sq0")
+    ///     .set_variant_start(Position::MIN)
+    ///     .set_reference_bases("A")
+    ///     .build();
+    ///
+    /// let classifier = Classifier::default();
+    ///
+    /// assert_eq!(
+    ///     classifier.classify_variant_record(&header, &record)?,
+    ///     RegionClass::Intergenic,
+    /// );
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn classify_variant_record(
+        &self,
+        header: &vcf::Header,
+        record: &dyn Record,
+    ) -> io::Result<RegionClass> {
+        let reference_sequence_name = record.reference_sequence_name(header)?;
+
+        let start = record
+            .variant_start()
+            .transpose()?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing variant start"))?;
+
+        let end = record.variant_end(header)?;
+
+        Ok(self.classify(reference_sequence_name, start, end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_gff as gff;
+
+    use super::*;
+
+    fn build_classifier(splice_site_distance: usize) -> io::Result<Classifier> {
+        // t0 is a coding transcript with exons [1, 30] and [71, 100], and t1 is a noncoding
+        // transcript with exons [150, 180] and [220, 250].
+        let records = [
+            ("gene", 1, 100, "ID=g0"),
+            ("mRNA", 1, 100, "ID=t0;Parent=g0"),
+            ("exon", 1, 30, "ID=e0;Parent=t0"),
+            ("exon", 71, 100, "ID=e1;Parent=t0"),
+            ("five_prime_UTR", 1, 10, "Parent=t0"),
+            ("CDS", 11, 30, "Parent=t0"),
+            ("CDS", 71, 90, "Parent=t0"),
+            ("three_prime_UTR", 91, 100, "Parent=t0"),
+            ("ncRNA_gene", 150, 250, "ID=g1"),
+            ("lnc_RNA", 150, 250, "ID=t1;Parent=g1"),
+            ("exon", 150, 180, "Parent=t1"),
+            ("exon", 220, 250, "Parent=t1"),
+        ]
+        .into_iter()
+        .map(|(ty, start, end, attributes)| {
+            let phase = if ty == "CDS" { "0" } else { "." };
+            format!("sq0\tNOODLES\t{ty}\t{start}\t{end}\t.\t+\t{phase}\t{attributes}")
+                .parse::<gff::Record>()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        });
+
+        Classifier::builder()
+            .set_splice_site_distance(splice_site_distance)
+            .build_from_records(records)
+    }
+
+    #[test]
+    fn test_classify() -> Result<(), Box<dyn std::error::Error>> {
+        let classifier = build_classifier(2)?;
+
+        for (start, end, expected) in [
+            (5, 5, RegionClass::FivePrimeUtr),
+            (20, 20, RegionClass::Coding),
+            (29, 29, RegionClass::SpliceSite),
+            (31, 32, RegionClass::SpliceSite),
+            (33, 33, RegionClass::Intronic),
+            (50, 50, RegionClass::Intronic),
+            (68, 68, RegionClass::Intronic),
+            (69, 69, RegionClass::SpliceSite),
+            (72, 72, RegionClass::SpliceSite),
+            (73, 73, RegionClass::Coding),
+            (95, 95, RegionClass::ThreePrimeUtr),
+            (88, 95, RegionClass::Coding),
+            (120, 120, RegionClass::Intergenic),
+            (160, 160, RegionClass::NoncodingExon),
+            (200, 200, RegionClass::Intronic),
+            (219, 219, RegionClass::SpliceSite),
+        ] {
+            let start = Position::try_from(start)?;
+            let end = Position::try_from(end)?;
+            assert_eq!(classifier.classify("sq0", start, end), expected);
+        }
+
+        let position = Position::try_from(20)?;
+        assert_eq!(
+            classifier.classify("sq1", position, position),
+            RegionClass::Intergenic
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_classify_with_no_splice_site_distance() -> Result<(), Box<dyn std::error::Error>> {
+        let classifier = build_classifier(0)?;
+
+        for (position, expected) in [
+            (30, RegionClass::Coding),
+            (31, RegionClass::Intronic),
+            (70, RegionClass::Intronic),
+            (71, RegionClass::Coding),
+        ] {
+            let position = Position::try_from(position)?;
+            assert_eq!(classifier.classify("sq0", position, position), expected);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+use std::{collections::HashMap, io};
+
+use noodles_core::{self as core, region::IntervalSet, Position};
+use noodles_gff::{self as gff, record::attributes::field::tag};
+
+use super::{Classifier, Regions};
+
+const DEFAULT_SPLICE_SITE_DISTANCE: usize = 2;
+
+const GENE_TYPE: &str = "gene";
+const EXON_TYPE: &str = "exon";
+const CDS_TYPE: &str = "CDS";
+const FIVE_PRIME_UTR_TYPE: &str = "five_prime_UTR";
+const THREE_PRIME_UTR_TYPE: &str = "three_prime_UTR";
+
+type Interval = (usize, usize);
+
+#[derive(Default)]
+struct Intervals {
+    coding: Vec<Interval>,
+    five_prime_utrs: Vec<Interval>,
+    three_prime_utrs: Vec<Interval>,
+    genes: Vec<Interval>,
+    exons: HashMap<Option<String>, Vec<Interval>>,
+}
+
+/// A variant region classifier builder.
+#[derive(Debug)]
+pub struct Builder {
+    splice_site_distance: usize,
+}
+
+impl Builder {
+    /// Sets the splice site distance.
+    ///
+    /// Positions within this many bases on either side of an exon-intron boundary are classified
+    /// as splice sites. Set this to 0 to disable splice site classification. The default is 2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::classifier::Classifier;
+    /// let builder = Classifier::builder().set_splice_site_distance(8);
+    /// ```
+    pub fn set_splice_site_distance(mut self, splice_site_distance: usize) -> Self {
+        self.splice_site_distance = splice_site_distance;
+        self
+    }
+
+    /// Builds a variant region classifier from GFF records.
+    ///
+    /// Genes (`gene`), exons (`exon`), coding sequences (`CDS`), and untranslated regions
+    /// (`five_prime_UTR` and `three_prime_UTR`) are used. Exons are grouped into transcripts by
+    /// their `Parent` attribute. Each transcript spans its exons, and introns are the gaps
+    /// between consecutive exons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_gff as gff;
+    /// use noodles_util::variant::classifier::Classifier;
+    ///
+    /// let data = b"##gff-version 3
+    /// sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=g0
+    /// ";
+    /// let mut reader = gff::io::Reader::new(&data[..]);
+    ///
+    /// let classifier = Classifier::builder().build_from_records(reader.records())?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build_from_records<I>(self, records: I) -> io::Result<Classifier>
+    where
+        I: IntoIterator<Item = io::Result<gff::Record>>,
+    {
+        let mut intervals: HashMap<String, Intervals> = HashMap::new();
+
+        for result in records {
+            let record = result?;
+
+            let interval = (usize::from(record.start()), usize::from(record.end()));
+
+            let entry = intervals
+                .entry(record.reference_sequence_name().into())
+                .or_default();
+
+            match record.ty() {
+                GENE_TYPE => entry.genes.push(interval),
+                EXON_TYPE => {
+                    let parent = record
+                        .attributes()
+                        .get(tag::PARENT)
+                        .and_then(|value| value.iter().next())
+                        .cloned();
+
+                    entry.exons.entry(parent).or_default().push(interval);
+                }
+                CDS_TYPE => entry.coding.push(interval),
+                FIVE_PRIME_UTR_TYPE => entry.five_prime_utrs.push(interval),
+                THREE_PRIME_UTR_TYPE => entry.three_prime_utrs.push(interval),
+                _ => {}
+            }
+        }
+
+        let regions = intervals
+            .into_iter()
+            .map(|(name, intervals)| (name, build_regions(intervals, self.splice_site_distance)))
+            .collect();
+
+        Ok(Classifier { regions })
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            splice_site_distance: DEFAULT_SPLICE_SITE_DISTANCE,
+        }
+    }
+}
+
+fn build_regions(intervals: Intervals, splice_site_distance: usize) -> Regions {
+    let mut genes = intervals.genes;
+    let mut exons = Vec::new();
+    let mut splice_sites = Vec::new();
+
+    for (parent, mut transcript_exons) in intervals.exons {
+        transcript_exons.sort_unstable();
+
+        // Exons without a parent are not part of a known transcript and have no introns.
+        if parent.is_some() {
+            let start = transcript_exons.first().map(|&(s, _)| s);
+            let end = transcript_exons.iter().map(|&(_, e)| e).max();
+
+            if let (Some(start), Some(end)) = (start, end) {
+                genes.push((start, end));
+            }
+
+            if splice_site_distance > 0 {
+                for pair in transcript_exons.windows(2) {
+                    let (intron_start, intron_end) = (pair[0].1 + 1, pair[1].0 - 1);
+
+                    if intron_start > intron_end {
+                        continue;
+                    }
+
+                    splice_sites.push((
+                        intron_start.saturating_sub(splice_site_distance),
+                        intron_start + splice_site_distance - 1,
+                    ));
+
+                    splice_sites.push((
+                        intron_end + 1 - splice_site_distance.min(intron_end),
+                        intron_end + splice_site_distance,
+                    ));
+                }
+            }
+        }
+
+        exons.extend(transcript_exons);
+    }
+
+    Regions {
+        splice_sites: build_interval_set(splice_sites),
+        coding: build_interval_set(intervals.coding),
+        five_prime_utrs: build_interval_set(intervals.five_prime_utrs),
+        three_prime_utrs: build_interval_set(intervals.three_prime_utrs),
+        exons: build_interval_set(exons),
+        genes: build_interval_set(genes),
+    }
+}
+
+fn build_interval_set(intervals: Vec<Interval>) -> IntervalSet {
+    intervals
+        .into_iter()
+        .filter_map(|(start, end)| {
+            // Splice site intervals can start before the first position.
+            let start = Position::new(start.max(1))?;
+            let end = Position::new(end)?;
+            Some(core::region::Interval::from(start..=end))
+        })
+        .collect()
+}
@@ -0,0 +1,276 @@
+//! Variant consensus sequence.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use noodles_core::Position;
+use noodles_fasta as fasta;
+
+/// An allele selection.
+///
+/// This determines which allele of a variant record is applied to the reference sequence.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum AlleleSelection {
+    /// The first alternate allele.
+    #[default]
+    FirstAlternate,
+    /// The allele called on a haplotype of a sample's genotype (`GT`).
+    ///
+    /// Both the sample and haplotype indices are 0-based.
+    Genotype {
+        /// The sample index.
+        sample_index: usize,
+        /// The haplotype index, i.e., the position of the allele in the genotype.
+        haplotype_index: usize,
+    },
+}
+
+/// An ungapped block of bases shared by the reference and consensus sequences.
+///
+/// Bases in a block may differ, e.g., from a substitution, but they are aligned one-to-one.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Block {
+    reference_start: Position,
+    consensus_start: Position,
+    len: usize,
+}
+
+impl Block {
+    /// Creates a block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_util::variant::consensus::Block;
+    /// let block = Block::new(Position::MIN, Position::MIN, 8);
+    /// ```
+    pub fn new(reference_start: Position, consensus_start: Position, len: usize) -> Self {
+        Self {
+            reference_start,
+            consensus_start,
+            len,
+        }
+    }
+
+    /// Returns the start position in the reference sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_util::variant::consensus::Block;
+    /// let block = Block::new(Position::MIN, Position::MIN, 8);
+    /// assert_eq!(block.reference_start(), Position::MIN);
+    /// ```
+    pub fn reference_start(&self) -> Position {
+        self.reference_start
+    }
+
+    /// Returns the start position in the consensus sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_util::variant::consensus::Block;
+    /// let block = Block::new(Position::MIN, Position::MIN, 8);
+    /// assert_eq!(block.consensus_start(), Position::MIN);
+    /// ```
+    pub fn consensus_start(&self) -> Position {
+        self.consensus_start
+    }
+
+    /// Returns the number of bases in the block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_util::variant::consensus::Block;
+    /// let block = Block::new(Position::MIN, Position::MIN, 8);
+    /// assert_eq!(block.len(), 8);
+    /// ```
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// A consensus sequence.
+///
+/// This is a reference sequence with variants applied, e.g., for a sample's haplotype, similar to
+/// `bcftools consensus`. It also holds the chain of ungapped blocks between the reference and
+/// consensus sequences, which maps positions from one to the other.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+/// use noodles_util::variant::consensus::Consensus;
+/// use noodles_vcf::{
+///     self as vcf,
+///     header::record::value::{map::Contig, Map},
+///     variant::{record_buf::AlternateBases, RecordBuf},
+/// };
+///
+/// let header = vcf::Header::builder()
+///     .add_contig("sq0", Map::<Contig>::new())
+///     .build();
+///
+/// let reference = fasta::Record::new(
+///     Definition::new("sq0", None),
+///     Sequence::from(b"ACGTACGT".to_vec()),
+/// );
+///
+/// let record = RecordBuf::builder()
+///     .set_reference_sequence_name("sq0")
+///     .set_variant_start(Position::try_from(2)?)
+///     .set_reference_bases("C")
+///     .set_alternate_bases(AlternateBases::from(vec![String::from("CTT")]))
+///     .build();
+///
+/// let consensus = Consensus::builder().build_from_records(
+///     &header,
+///     &reference,
+///     [Ok::<_, std::io::Error>(record)],
+/// )?;
+///
+/// assert_eq!(consensus.record().sequence().as_ref(), b"ACTTGTACGT");
+/// assert_eq!(
+///     consensus.to_consensus_position(Position::try_from(3)?),
+///     Some(Position::try_from(5)?),
+/// );
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Consensus {
+    record: fasta::Record,
+    blocks: Vec<Block>,
+}
+
+impl Consensus {
+    /// Returns a builder to create a consensus sequence from variant records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::consensus::Consensus;
+    /// let builder = Consensus::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    /// Returns the consensus sequence record.
+    ///
+    /// The definition is the same as the reference sequence record's.
+    pub fn record(&self) -> &fasta::Record {
+        &self.record
+    }
+
+    /// Returns the ungapped blocks shared by the reference and consensus sequences.
+    ///
+    /// Blocks are sorted by their start positions. Bases between blocks are insertions (in the
+    /// consensus sequence) or deletions (from the reference sequence).
+    pub fn blocks(&self) -> &[Block] {
+        &self.blocks
+    }
+
+    /// Converts a reference sequence position to a consensus sequence position.
+    ///
+    /// This returns `None` if the position is not in an ungapped block, e.g., it was deleted.
+    pub fn to_consensus_position(&self, position: Position) -> Option<Position> {
+        self.blocks.iter().find_map(|block| {
+            translate(
+                block.reference_start,
+                block.consensus_start,
+                block.len,
+                position,
+            )
+        })
+    }
+
+    /// Converts a consensus sequence position to a reference sequence position.
+    ///
+    /// This returns `None` if the position is not in an ungapped block, e.g., it was inserted.
+    pub fn to_reference_position(&self, position: Position) -> Option<Position> {
+        self.blocks.iter().find_map(|block| {
+            translate(
+                block.consensus_start,
+                block.reference_start,
+                block.len,
+                position,
+            )
+        })
+    }
+
+    /// Returns the consensus sequence record.
+    pub fn into_record(self) -> fasta::Record {
+        self.record
+    }
+}
+
+fn translate(
+    src_start: Position,
+    dst_start: Position,
+    len: usize,
+    position: Position,
+) -> Option<Position> {
+    let offset = usize::from(position).checked_sub(usize::from(src_start))?;
+
+    if offset < len {
+        dst_start.checked_add(offset)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_consensus_position_and_to_reference_position(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use fasta::record::{Definition, Sequence};
+
+        // reference: AC--GTACGT
+        // consensus: ACTTGTA--T (2 C>CTT, 5 ACG>A)
+        let consensus = Consensus {
+            record: fasta::Record::new(
+                Definition::new("sq0", None),
+                Sequence::from(b"ACTTGTAT".to_vec()),
+            ),
+            blocks: vec![
+                Block::new(Position::MIN, Position::MIN, 2),
+                Block::new(Position::try_from(3)?, Position::try_from(5)?, 3),
+                Block::new(Position::try_from(8)?, Position::try_from(8)?, 1),
+            ],
+        };
+
+        assert_eq!(
+            consensus.to_consensus_position(Position::try_from(2)?),
+            Some(Position::try_from(2)?)
+        );
+        assert_eq!(
+            consensus.to_consensus_position(Position::try_from(4)?),
+            Some(Position::try_from(6)?)
+        );
+        assert!(consensus
+            .to_consensus_position(Position::try_from(6)?)
+            .is_none());
+
+        assert_eq!(
+            consensus.to_reference_position(Position::try_from(8)?),
+            Some(Position::try_from(8)?)
+        );
+        assert!(consensus
+            .to_reference_position(Position::try_from(3)?)
+            .is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,482 @@
+use std::io;
+
+use noodles_core::Position;
+use noodles_fasta::{self as fasta, record::Sequence};
+use noodles_vcf::{
+    self as vcf,
+    variant::record::{
+        samples::{keys::key, series::Value},
+        Record,
+    },
+};
+
+use super::{AlleleSelection, Block, Consensus};
+
+/// A variant consensus sequence builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    allele_selection: AlleleSelection,
+}
+
+impl Builder {
+    /// Sets the allele selection.
+    ///
+    /// By default, the first alternate allele of each record is applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::consensus::{AlleleSelection, Consensus};
+    ///
+    /// let builder = Consensus::builder().set_allele_selection(AlleleSelection::Genotype {
+    ///     sample_index: 0,
+    ///     haplotype_index: 1,
+    /// });
+    /// ```
+    pub fn set_allele_selection(mut self, allele_selection: AlleleSelection) -> Self {
+        self.allele_selection = allele_selection;
+        self
+    }
+
+    /// Builds a consensus sequence by applying variant records to a reference sequence.
+    ///
+    /// Records must be sorted by position. Records on other reference sequences are skipped,
+    /// so records can be prefiltered, e.g., by region or filter status, before being given.
+    ///
+    /// A record is not applied when its selected allele is the reference allele, missing (`.`),
+    /// the upstream deletion allele (`*`), or symbolic (e.g., `<DEL>` or a breakend). A record
+    /// that overlaps a previously applied record is also skipped, i.e., the first of
+    /// overlapping variants wins.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if the records are unsorted, a record's reference bases do not match
+    /// the reference sequence, or a genotype is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+    /// use noodles_util::variant::consensus::Consensus;
+    /// use noodles_vcf::{self as vcf, variant::RecordBuf};
+    ///
+    /// let header = vcf::Header::default();
+    ///
+    /// let reference = fasta::Record::new(
+    ///     Definition::new("sq0", None),
+    ///     Sequence::from(b"ACGT".to_vec()),
+    /// );
+    ///
+    /// let consensus = Consensus::builder().build_from_records(
+    ///     &header,
+    ///     &reference,
+    ///     std::iter::empty::<std::io::Result<RecordBuf>>(),
+    /// )?;
+    ///
+    /// assert_eq!(consensus.record(), &reference);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn build_from_records<I, R>(
+        self,
+        header: &vcf::Header,
+        reference: &fasta::Record,
+        records: I,
+    ) -> io::Result<Consensus>
+    where
+        I: IntoIterator<Item = io::Result<R>>,
+        R: Record,
+    {
+        let src = reference.sequence().as_ref();
+
+        let mut dst = Vec::with_capacity(src.len());
+        let mut blocks = Vec::new();
+
+        // The 0-based start of the reference sequence that has not yet been copied.
+        let mut reference_cursor = 0;
+        let mut prev_start = 0;
+
+        for result in records {
+            let record = result?;
+
+            if record.reference_sequence_name(header)?.as_bytes() != reference.name() {
+                continue;
+            }
+
+            let Some(start) = record.variant_start().transpose()? else {
+                continue;
+            };
+
+            let start = usize::from(start) - 1;
+
+            if start < prev_start {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "records are not sorted by position",
+                ));
+            }
+
+            prev_start = start;
+
+            let Some(allele) = select_allele(header, &record, self.allele_selection)? else {
+                continue;
+            };
+
+            if start < reference_cursor {
+                continue;
+            }
+
+            let reference_bases: Vec<u8> =
+                record.reference_bases().iter().collect::<io::Result<_>>()?;
+            let end = start + reference_bases.len();
+
+            if !src
+                .get(start..end)
+                .map(|bases| bases.eq_ignore_ascii_case(&reference_bases))
+                .unwrap_or_default()
+            {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("reference bases mismatch at position {}", start + 1),
+                ));
+            }
+
+            push_block(
+                &mut blocks,
+                reference_cursor,
+                dst.len(),
+                start - reference_cursor,
+            )?;
+            dst.extend_from_slice(&src[reference_cursor..start]);
+
+            // Leading bases of the reference and alternate alleles are aligned one-to-one. Any
+            // remaining bases are deleted or inserted.
+            let aligned_len = reference_bases.len().min(allele.len());
+            push_block(&mut blocks, start, dst.len(), aligned_len)?;
+            dst.extend_from_slice(allele.as_bytes());
+
+            reference_cursor = end;
+        }
+
+        push_block(
+            &mut blocks,
+            reference_cursor,
+            dst.len(),
+            src.len() - reference_cursor,
+        )?;
+        dst.extend_from_slice(&src[reference_cursor..]);
+
+        Ok(Consensus {
+            record: fasta::Record::new(reference.definition().clone(), Sequence::from(dst)),
+            blocks,
+        })
+    }
+}
+
+fn select_allele<R>(
+    header: &vcf::Header,
+    record: &R,
+    allele_selection: AlleleSelection,
+) -> io::Result<Option<String>>
+where
+    R: Record,
+{
+    let alternate_bases = record.alternate_bases();
+
+    let allele = match allele_selection {
+        AlleleSelection::FirstAlternate => alternate_bases.iter().next().transpose()?,
+        AlleleSelection::Genotype {
+            sample_index,
+            haplotype_index,
+        } => {
+            let samples = record.samples()?;
+
+            let sample = samples
+                .iter()
+                .nth(sample_index)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing sample"))?;
+
+            let genotype = match sample.get(header, key::GENOTYPE).transpose()?.flatten() {
+                Some(Value::Genotype(genotype)) => genotype,
+                Some(_) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "invalid genotype value",
+                    ))
+                }
+                None => return Ok(None),
+            };
+
+            let allele_index = genotype
+                .iter()
+                .nth(haplotype_index)
+                .transpose()?
+                .and_then(|(position, _)| position);
+
+            match allele_index {
+                None | Some(0) => None,
+                Some(i) => {
+                    let allele =
+                        alternate_bases
+                            .iter()
+                            .nth(i - 1)
+                            .transpose()?
+                            .ok_or_else(|| {
+                                io::Error::new(io::ErrorKind::InvalidData, "invalid allele index")
+                            })?;
+
+                    Some(allele)
+                }
+            }
+        }
+    };
+
+    Ok(allele.filter(|a| !is_skipped_allele(a)).map(String::from))
+}
+
+fn is_skipped_allele(allele: &str) -> bool {
+    const MISSING: &str = ".";
+    const UPSTREAM_DELETION: &str = "*";
+
+    allele == MISSING
+        || allele == UPSTREAM_DELETION
+        || allele.starts_with('<')
+        || allele.contains(['[', ']'])
+}
+
+fn push_block(
+    blocks: &mut Vec<Block>,
+    reference_start: usize,
+    consensus_start: usize,
+    len: usize,
+) -> io::Result<()> {
+    if len == 0 {
+        return Ok(());
+    }
+
+    if let Some(block) = blocks.last_mut() {
+        let reference_end = usize::from(block.reference_start) + block.len;
+        let consensus_end = usize::from(block.consensus_start) + block.len;
+
+        if reference_end == reference_start + 1 && consensus_end == consensus_start + 1 {
+            block.len += len;
+            return Ok(());
+        }
+    }
+
+    let reference_start = Position::new(reference_start + 1)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid position"))?;
+
+    let consensus_start = Position::new(consensus_start + 1)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid position"))?;
+
+    blocks.push(Block::new(reference_start, consensus_start, len));
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use fasta::record::Definition;
+    use noodles_vcf::{
+        header::record::value::{
+            map::{Contig, Format},
+            Map,
+        },
+        variant::{
+            record_buf::{
+                samples::{keys::Keys, sample::Value as SampleValue},
+                AlternateBases, Samples,
+            },
+            RecordBuf,
+        },
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_build_from_records() -> Result<(), Box<dyn std::error::Error>> {
+        let header = vcf::Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .add_contig("sq1", Map::<Contig>::new())
+            .build();
+
+        let reference = fasta::Record::new(
+            Definition::new("sq0", None),
+            Sequence::from(b"ACGTACGT".to_vec()),
+        );
+
+        let records = [
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::new(2).unwrap())
+                .set_reference_bases("C")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("CTT")]))
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq1")
+                .set_variant_start(Position::new(1).unwrap())
+                .set_reference_bases("A")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("T")]))
+                .build(),
+            // overlaps the next record, which is skipped
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::new(5).unwrap())
+                .set_reference_bases("ACG")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("A")]))
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::new(6).unwrap())
+                .set_reference_bases("C")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("G")]))
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::new(7).unwrap())
+                .set_reference_bases("G")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("<DEL>")]))
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::new(8).unwrap())
+                .set_reference_bases("t")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("A")]))
+                .build(),
+        ];
+
+        let consensus = Builder::default().build_from_records(
+            &header,
+            &reference,
+            records.into_iter().map(Ok),
+        )?;
+
+        assert_eq!(consensus.record().name(), b"sq0");
+        assert_eq!(consensus.record().sequence().as_ref(), b"ACTTGTAA");
+
+        let expected = [
+            Block::new(Position::MIN, Position::MIN, 2),
+            Block::new(Position::try_from(3)?, Position::try_from(5)?, 3),
+            Block::new(Position::try_from(8)?, Position::try_from(8)?, 1),
+        ];
+
+        assert_eq!(consensus.blocks(), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_from_records_with_genotype_allele_selection(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let header = vcf::Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .add_format(key::GENOTYPE, Map::<Format>::from(key::GENOTYPE))
+            .add_sample_name("sample0")
+            .build();
+
+        let reference = fasta::Record::new(
+            Definition::new("sq0", None),
+            Sequence::from(b"ACGTACGT".to_vec()),
+        );
+
+        let keys: Keys = [String::from(key::GENOTYPE)].into_iter().collect();
+
+        let records = [
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::new(1).unwrap())
+                .set_reference_bases("A")
+                .set_alternate_bases(AlternateBases::from(vec![
+                    String::from("C"),
+                    String::from("G"),
+                ]))
+                .set_samples(Samples::new(
+                    keys.clone(),
+                    vec![vec![Some(SampleValue::Genotype("0|2".parse()?))]],
+                ))
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::new(3).unwrap())
+                .set_reference_bases("G")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("T")]))
+                .set_samples(Samples::new(
+                    keys.clone(),
+                    vec![vec![Some(SampleValue::Genotype("1|0".parse()?))]],
+                ))
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::new(4).unwrap())
+                .set_reference_bases("T")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("A")]))
+                .set_samples(Samples::new(
+                    keys.clone(),
+                    vec![vec![Some(SampleValue::Genotype("1|.".parse()?))]],
+                ))
+                .build(),
+        ];
+
+        let consensus = Builder::default()
+            .set_allele_selection(AlleleSelection::Genotype {
+                sample_index: 0,
+                haplotype_index: 0,
+            })
+            .build_from_records(&header, &reference, records.clone().into_iter().map(Ok))?;
+
+        assert_eq!(consensus.record().sequence().as_ref(), b"ACTAACGT");
+
+        let consensus = Builder::default()
+            .set_allele_selection(AlleleSelection::Genotype {
+                sample_index: 0,
+                haplotype_index: 1,
+            })
+            .build_from_records(&header, &reference, records.into_iter().map(Ok))?;
+
+        assert_eq!(consensus.record().sequence().as_ref(), b"GCGTACGT");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_from_records_with_invalid_records() {
+        let header = vcf::Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .build();
+
+        let reference = fasta::Record::new(
+            Definition::new("sq0", None),
+            Sequence::from(b"ACGTACGT".to_vec()),
+        );
+
+        let records = [RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::new(2).unwrap())
+            .set_reference_bases("G")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("T")]))
+            .build()];
+        assert!(matches!(
+            Builder::default().build_from_records(&header, &reference, records.into_iter().map(Ok)),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let records = [
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::new(3).unwrap())
+                .set_reference_bases("G")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("T")]))
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::new(2).unwrap())
+                .set_reference_bases("C")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("T")]))
+                .build(),
+        ];
+        assert!(matches!(
+            Builder::default().build_from_records(&header, &reference, records.into_iter().map(Ok)),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+}
@@ -1,3 +1,9 @@
+//! Variant format.
+
+use std::io::{self, BufRead, Read};
+
+use super::{compression_method, CompressionMethod};
+
 /// A variant format.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Format {
@@ -6,3 +12,127 @@ pub enum Format {
     /// BCF.
     Bcf,
 }
+
+/// Detects the format of the input.
+///
+/// This first detects whether the input is bgzip-compressed (see [`compression_method::detect`])
+/// and then checks the (decompressed) magic number of the input: BCF (`BCF`) or otherwise VCF. Only buffered
+/// data is inspected; no data is consumed from the reader.
+///
+/// The reader builders use this to autodetect the format unless it is overridden, e.g., using
+/// [`super::reader::Builder::set_format`].
+///
+/// # Examples
+///
+/// ```
+/// use noodles_util::variant::io::{format, Format};
+///
+/// let mut src = &b"BCF\x02\x02"[..];
+/// assert_eq!(format::detect(&mut src)?, Format::Bcf);
+///
+/// let mut src = &b"##fileformat=VCFv4.4\n"[..];
+/// assert_eq!(format::detect(&mut src)?, Format::Vcf);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn detect<R>(reader: &mut R) -> io::Result<Format>
+where
+    R: BufRead,
+{
+    let compression_method = compression_method::detect(reader)?;
+    detect_with_compression_method(reader, compression_method)
+}
+
+pub(crate) fn detect_with_compression_method<R>(
+    reader: &mut R,
+    compression_method: Option<CompressionMethod>,
+) -> io::Result<Format>
+where
+    R: BufRead,
+{
+    use flate2::bufread::MultiGzDecoder;
+
+    const BCF_MAGIC_NUMBER: [u8; 3] = *b"BCF";
+
+    let src = reader.fill_buf()?;
+
+    if let Some(compression_method) = compression_method {
+        if compression_method == CompressionMethod::Bgzf {
+            let mut decoder = MultiGzDecoder::new(src);
+            let mut buf = [0; BCF_MAGIC_NUMBER.len()];
+            decoder.read_exact(&mut buf)?;
+
+            if buf == BCF_MAGIC_NUMBER {
+                return Ok(Format::Bcf);
+            }
+        }
+    } else if let Some(buf) = src.get(..BCF_MAGIC_NUMBER.len()) {
+        if buf == BCF_MAGIC_NUMBER {
+            return Ok(Format::Bcf);
+        }
+    }
+
+    Ok(Format::Vcf)
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_bcf as bcf;
+    use noodles_bgzf as bgzf;
+    use noodles_vcf as vcf;
+
+    use super::*;
+
+    #[test]
+    fn test_detect_with_compression_method() -> io::Result<()> {
+        use std::io::Write;
+
+        fn t(mut src: &[u8], compression_method: Option<CompressionMethod>, expected: Format) {
+            assert!(
+                matches!(detect_with_compression_method(&mut src, compression_method), Ok(value) if value == expected)
+            );
+        }
+
+        let header = vcf::Header::default();
+        let mut writer = vcf::io::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        let raw_header = writer.into_inner();
+
+        let src = &raw_header;
+        t(src, None, Format::Vcf);
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(&raw_header)?;
+        let src = writer.finish()?;
+        t(&src, Some(CompressionMethod::Bgzf), Format::Vcf);
+
+        let mut writer = bcf::io::Writer::from(Vec::new());
+        writer.write_header(&header)?;
+        let src = writer.into_inner();
+        t(&src, None, Format::Bcf);
+
+        let mut writer = bcf::io::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        let src = writer.into_inner().finish()?;
+        t(&src, Some(CompressionMethod::Bgzf), Format::Bcf);
+
+        // An incomplete gzip stream.
+        #[rustfmt::skip]
+        let src = [
+            0x1f, 0x8b, // ID1, ID2
+            0x08, // CM = DEFLATE
+            0x04, // FLG = FEXTRA
+            0x00, 0x00, 0x00, 0x00, // MTIME = 0
+            0x00, // XFL = 0
+            0xff, // OS = 255 (unknown)
+            0x06, 0x00, // XLEN = 6
+            b'B', b'C', // SI1, SI2
+            0x02, 0x00, // SLEN = 2
+            0x00, 0x40, // BSIZE = 16384
+            0x73, 0x72, 0x76, 0x03, 0x00, // CDATA = deflate(b"BCF")
+            // ...
+        ];
+        t(&src, Some(CompressionMethod::Bgzf), Format::Bcf);
+
+        Ok(())
+    }
+}
@@ -10,10 +10,7 @@ use noodles_csi::BinningIndex;
 use noodles_vcf as vcf;
 
 use super::IndexedReader;
-use crate::variant::io::{
-    reader::builder::{detect_compression_method, detect_format},
-    CompressionMethod, Format,
-};
+use crate::variant::io::{compression_method, format, CompressionMethod, Format};
 
 /// An indexed variant reader builder.
 #[derive(Default)]
@@ -100,12 +97,12 @@ impl Builder {
 
         let compression_method = match self.compression_method {
             Some(compression_method) => compression_method,
-            None => detect_compression_method(&mut reader)?,
+            None => compression_method::detect(&mut reader)?,
         };
 
         let format = match self.format {
             Some(format) => format,
-            None => detect_format(&mut reader, compression_method)?,
+            None => format::detect_with_compression_method(&mut reader, compression_method)?,
         };
 
         match (format, compression_method) {
@@ -168,12 +165,12 @@ impl Builder {
 
         let compression = match self.compression_method {
             Some(compression) => compression,
-            None => detect_compression_method(&mut reader)?,
+            None => compression_method::detect(&mut reader)?,
         };
 
         let format = match self.format {
             Some(format) => format,
-            None => detect_format(&mut reader, compression)?,
+            None => format::detect_with_compression_method(&mut reader, compression)?,
         };
 
         match (format, compression) {
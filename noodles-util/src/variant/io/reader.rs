@@ -8,9 +8,12 @@ use std::io::{self, BufRead};
 
 use noodles_vcf::{self as vcf, variant::Record};
 
+use crate::progress::Progress;
+
 /// A variant reader.
 pub struct Reader<R> {
     inner: Box<dyn vcf::variant::io::Read<R>>,
+    progress: Progress,
 }
 
 impl<R> Reader<R>
@@ -70,6 +73,29 @@ where
         &'a mut self,
         header: &'a vcf::Header,
     ) -> impl Iterator<Item = io::Result<Box<dyn Record>>> + 'a {
-        self.inner.variant_records(header)
+        let progress = self.progress.clone();
+
+        self.inner.variant_records(header).inspect(move |result| {
+            if result.is_ok() {
+                progress.add_records(1);
+            }
+        })
+    }
+
+    /// Returns a handle to the progress of the reader.
+    ///
+    /// The handle can be cloned to sample progress, e.g., from another thread, while reading.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_util::variant;
+    /// let reader = variant::io::reader::Builder::default().build_from_reader(io::empty())?;
+    /// assert_eq!(reader.progress().records(), 0);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn progress(&self) -> &Progress {
+        &self.progress
     }
 }
@@ -4,13 +4,17 @@ pub(crate) mod builder;
 
 pub use self::builder::Builder;
 
-use std::io;
+use std::io::{self, Write};
 
+use noodles_bcf as bcf;
+use noodles_bgzf as bgzf;
 use noodles_vcf as vcf;
 
 /// A variant writer.
+///
+/// A call to [`Self::finish`] must be made before the writer is dropped.
 pub struct Writer {
-    inner: Box<dyn vcf::variant::io::Write>,
+    inner: Box<dyn VariantWrite>,
 }
 
 impl Writer {
@@ -64,4 +68,56 @@ impl Writer {
     ) -> io::Result<()> {
         self.inner.write_variant_record(header, record)
     }
+
+    /// Finishes the output stream.
+    ///
+    /// This flushes the underlying writer and, if the output is bgzip-compressed, writes the
+    /// final BGZF block and EOF marker.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_vcf as vcf;
+    /// use noodles_util::variant;
+    ///
+    /// let mut writer = variant::io::writer::Builder::default().build_from_writer(io::sink());
+    ///
+    /// let header = vcf::Header::default();
+    /// writer.write_header(&header)?;
+    /// writer.finish(&header)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn finish(&mut self, header: &vcf::Header) -> io::Result<()> {
+        self.inner.finish(header)
+    }
+}
+
+// A variant format writer that can finish its output stream.
+pub(crate) trait VariantWrite: vcf::variant::io::Write {
+    fn finish(&mut self, header: &vcf::Header) -> io::Result<()>;
+}
+
+impl VariantWrite for vcf::io::Writer<Box<dyn Write>> {
+    fn finish(&mut self, _: &vcf::Header) -> io::Result<()> {
+        vcf::io::Writer::finish(self)
+    }
+}
+
+impl VariantWrite for vcf::io::Writer<bgzf::Writer<Box<dyn Write>>> {
+    fn finish(&mut self, _: &vcf::Header) -> io::Result<()> {
+        self.get_mut().try_finish()
+    }
+}
+
+impl VariantWrite for bcf::io::Writer<Box<dyn Write>> {
+    fn finish(&mut self, _: &vcf::Header) -> io::Result<()> {
+        self.get_mut().flush()
+    }
+}
+
+impl VariantWrite for bcf::io::Writer<bgzf::Writer<Box<dyn Write>>> {
+    fn finish(&mut self, _: &vcf::Header) -> io::Result<()> {
+        self.try_finish()
+    }
 }
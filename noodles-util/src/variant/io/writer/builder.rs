@@ -8,7 +8,7 @@ use noodles_bcf as bcf;
 use noodles_bgzf as bgzf;
 use noodles_vcf as vcf;
 
-use super::Writer;
+use super::{VariantWrite, Writer};
 use crate::variant::io::{CompressionMethod, Format};
 
 /// A variant writer builder.
@@ -101,7 +101,9 @@ impl Builder {
             },
         };
 
-        let inner: Box<dyn vcf::variant::io::Write> = match (format, compression_method) {
+        let writer: Box<dyn Write> = Box::new(writer);
+
+        let inner: Box<dyn VariantWrite> = match (format, compression_method) {
             (Format::Vcf, None) => Box::new(vcf::io::Writer::new(writer)),
             (Format::Vcf, Some(CompressionMethod::Bgzf)) => {
                 Box::new(vcf::io::Writer::new(bgzf::Writer::new(writer)))
@@ -0,0 +1,521 @@
+//! In-memory variant record store.
+
+use std::{collections::HashMap, io};
+
+use noodles_core::{Position, Region};
+use noodles_vcf::{
+    self as vcf,
+    variant::{record_buf::Samples, Record as _, RecordBuf},
+};
+
+/// An in-memory variant record store.
+///
+/// This holds variant records in memory and indexes them by position and by ID, allowing region
+/// and ID (e.g., rsID) queries without a variant file and associated index. It is intended for
+/// tests and small datasets.
+///
+/// Records can be inserted in any order. Region queries return intersecting records in
+/// coordinate order, and ID queries return records in insertion order.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_util::variant::MemoryStore;
+/// use noodles_vcf::{self as vcf, variant::RecordBuf};
+///
+/// let record = RecordBuf::builder()
+///     .set_reference_sequence_name("sq0")
+///     .set_variant_start(Position::try_from(8)?)
+///     .set_ids([String::from("rs1")].into_iter().collect())
+///     .set_reference_bases("A")
+///     .build();
+///
+/// let mut store = MemoryStore::new(vcf::Header::default());
+/// store.insert(record)?;
+///
+/// let region = "sq0:5-13".parse()?;
+/// assert_eq!(store.query(&region)?.count(), 1);
+///
+/// assert_eq!(store.query_id("rs1").count(), 1);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    header: vcf::Header,
+    records: Vec<RecordBuf>,
+    reference_sequences: HashMap<String, ReferenceSequenceIndex>,
+    ids: HashMap<String, Vec<usize>>,
+}
+
+#[derive(Debug, Default)]
+struct ReferenceSequenceIndex {
+    // (start, end, record index), sorted by start and then record index.
+    intervals: Vec<(Position, Position, usize)>,
+    max_span: usize,
+}
+
+impl MemoryStore {
+    /// Creates an empty in-memory variant record store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::MemoryStore;
+    /// use noodles_vcf as vcf;
+    /// let store = MemoryStore::new(vcf::Header::default());
+    /// assert!(store.is_empty());
+    /// ```
+    pub fn new(header: vcf::Header) -> Self {
+        Self {
+            header,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the VCF header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::MemoryStore;
+    /// use noodles_vcf as vcf;
+    /// let store = MemoryStore::new(vcf::Header::default());
+    /// assert!(store.header().sample_names().is_empty());
+    /// ```
+    pub fn header(&self) -> &vcf::Header {
+        &self.header
+    }
+
+    /// Returns the number of records in the store.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::MemoryStore;
+    /// use noodles_vcf as vcf;
+    /// let store = MemoryStore::new(vcf::Header::default());
+    /// assert_eq!(store.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns whether the store has any records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::MemoryStore;
+    /// use noodles_vcf as vcf;
+    /// let store = MemoryStore::new(vcf::Header::default());
+    /// assert!(store.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Returns an iterator over all records in insertion order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::MemoryStore;
+    /// use noodles_vcf as vcf;
+    /// let store = MemoryStore::new(vcf::Header::default());
+    /// assert!(store.records().next().is_none());
+    /// ```
+    pub fn records(&self) -> impl Iterator<Item = &RecordBuf> {
+        self.records.iter()
+    }
+
+    /// Inserts a record.
+    ///
+    /// Records with a variant start position are indexed by position. The end position is
+    /// calculated using the header, e.g., from the `END` INFO field or the reference bases length.
+    /// Records are also indexed by each of their IDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_util::variant::MemoryStore;
+    /// use noodles_vcf::{self as vcf, variant::RecordBuf};
+    ///
+    /// let record = RecordBuf::builder()
+    ///     .set_reference_sequence_name("sq0")
+    ///     .set_variant_start(Position::MIN)
+    ///     .set_reference_bases("A")
+    ///     .build();
+    ///
+    /// let mut store = MemoryStore::new(vcf::Header::default());
+    /// store.insert(record)?;
+    ///
+    /// assert_eq!(store.len(), 1);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn insert(&mut self, record: RecordBuf) -> io::Result<()> {
+        let i = self.records.len();
+
+        if let Some(start) = record.variant_start() {
+            let end = record.variant_end(&self.header)?.max(start);
+
+            let index = self
+                .reference_sequences
+                .entry(record.reference_sequence_name().into())
+                .or_default();
+
+            let j = index.intervals.partition_point(|(s, _, _)| *s <= start);
+            index.intervals.insert(j, (start, end, i));
+            index.max_span = index.max_span.max(usize::from(end) - usize::from(start));
+        }
+
+        for id in record.ids().as_ref() {
+            self.ids.entry(id.clone()).or_default().push(i);
+        }
+
+        self.records.push(record);
+
+        Ok(())
+    }
+
+    /// Returns an iterator over records that intersect the given region.
+    ///
+    /// Records are returned in coordinate order. If no records are on the region's reference
+    /// sequence, the iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::MemoryStore;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let store = MemoryStore::new(vcf::Header::default());
+    ///
+    /// let region = "sq0:5-13".parse()?;
+    /// assert_eq!(store.query(&region)?.count(), 0);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query(&self, region: &Region) -> io::Result<impl Iterator<Item = &RecordBuf>> {
+        let name = std::str::from_utf8(region.name())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        let interval = region.interval();
+
+        let intervals = self
+            .reference_sequences
+            .get(name)
+            .map(|index| {
+                // Records that start before this position cannot reach the query start.
+                let min_start = interval
+                    .start()
+                    .map(|start| usize::from(start).saturating_sub(index.max_span))
+                    .unwrap_or_default();
+
+                let i = index
+                    .intervals
+                    .partition_point(|(s, _, _)| usize::from(*s) < min_start);
+
+                &index.intervals[i..]
+            })
+            .unwrap_or_default();
+
+        Ok(intervals
+            .iter()
+            .take_while(move |(start, _, _)| interval.end().map_or(true, |end| *start <= end))
+            .filter(move |(start, end, _)| interval.intersects((*start..=*end).into()))
+            .map(|(_, _, i)| &self.records[*i]))
+    }
+
+    /// Returns an iterator over records with the given ID.
+    ///
+    /// Records are returned in insertion order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::MemoryStore;
+    /// use noodles_vcf as vcf;
+    /// let store = MemoryStore::new(vcf::Header::default());
+    /// assert_eq!(store.query_id("rs1").count(), 0);
+    /// ```
+    pub fn query_id(&self, id: &str) -> impl Iterator<Item = &RecordBuf> {
+        self.ids
+            .get(id)
+            .map(|indices| indices.as_slice())
+            .unwrap_or_default()
+            .iter()
+            .map(|i| &self.records[*i])
+    }
+
+    /// Returns a view of the store with a subset of samples.
+    ///
+    /// The view's header only lists the given samples, in the given order, and records read
+    /// through the view only have the columns of these samples.
+    ///
+    /// This fails if a sample is not in the header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::MemoryStore;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_sample_name("sample0")
+    ///     .add_sample_name("sample1")
+    ///     .build();
+    ///
+    /// let store = MemoryStore::new(header);
+    ///
+    /// let view = store.select_samples(["sample1"])?;
+    /// assert_eq!(view.header().sample_names().len(), 1);
+    ///
+    /// assert!(store.select_samples(["sample2"]).is_err());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn select_samples<I, N>(&self, sample_names: I) -> io::Result<SampleView<'_>>
+    where
+        I: IntoIterator<Item = N>,
+        N: AsRef<str>,
+    {
+        let mut header = self.header.clone();
+        let header_sample_names = header.sample_names_mut();
+
+        let mut selected_sample_names = vcf::header::SampleNames::new();
+        let mut sample_indices = Vec::new();
+
+        for sample_name in sample_names {
+            let sample_name = sample_name.as_ref();
+
+            let i = header_sample_names
+                .get_index_of(sample_name)
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("sample does not exist in header: {sample_name}"),
+                    )
+                })?;
+
+            if selected_sample_names.insert(sample_name.into()) {
+                sample_indices.push(i);
+            }
+        }
+
+        *header_sample_names = selected_sample_names;
+
+        Ok(SampleView {
+            store: self,
+            header,
+            sample_indices,
+        })
+    }
+}
+
+/// A view of an in-memory variant record store with a subset of samples.
+///
+/// This is created by [`MemoryStore::select_samples`].
+#[derive(Debug)]
+pub struct SampleView<'s> {
+    store: &'s MemoryStore,
+    header: vcf::Header,
+    sample_indices: Vec<usize>,
+}
+
+impl<'s> SampleView<'s> {
+    /// Returns the VCF header with the selected samples.
+    pub fn header(&self) -> &vcf::Header {
+        &self.header
+    }
+
+    /// Returns an iterator over all records in insertion order with the selected samples.
+    pub fn records(&self) -> impl Iterator<Item = RecordBuf> + '_ {
+        self.store.records().map(|record| self.subset(record))
+    }
+
+    /// Returns an iterator over records that intersect the given region with the selected
+    /// samples.
+    ///
+    /// See [`MemoryStore::query`].
+    pub fn query(&self, region: &Region) -> io::Result<impl Iterator<Item = RecordBuf> + '_> {
+        self.store
+            .query(region)
+            .map(|records| records.map(|record| self.subset(record)))
+    }
+
+    /// Returns an iterator over records with the given ID with the selected samples.
+    ///
+    /// See [`MemoryStore::query_id`].
+    pub fn query_id(&self, id: &str) -> impl Iterator<Item = RecordBuf> + '_ {
+        self.store.query_id(id).map(|record| self.subset(record))
+    }
+
+    fn subset(&self, record: &RecordBuf) -> RecordBuf {
+        let samples = record.samples();
+
+        let values = self
+            .sample_indices
+            .iter()
+            .map(|&i| {
+                samples
+                    .get_index(i)
+                    .map(|sample| sample.values().to_vec())
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        let mut record = record.clone();
+        *record.samples_mut() = Samples::new(samples.keys().clone(), values);
+        record
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vcf::variant::{record::samples::keys::key, record_buf::samples::sample::Value};
+
+    use super::*;
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        let mut store = MemoryStore::new(vcf::Header::default());
+
+        for record in [
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::try_from(13)?)
+                .set_ids([String::from("rs3")].into_iter().collect())
+                .set_reference_bases("A")
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::try_from(1)?)
+                .set_ids([String::from("rs0")].into_iter().collect())
+                .set_reference_bases("ACGTACGTAC")
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::try_from(8)?)
+                .set_ids([String::from("rs1")].into_iter().collect())
+                .set_reference_bases("AC")
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq1")
+                .set_variant_start(Position::try_from(8)?)
+                .set_ids([String::from("rs2")].into_iter().collect())
+                .set_reference_bases("A")
+                .build(),
+        ] {
+            store.insert(record)?;
+        }
+
+        let ids = |region: &str| -> Result<Vec<String>, Box<dyn std::error::Error>> {
+            let region = region.parse()?;
+            Ok(store
+                .query(&region)?
+                .flat_map(|record| record.ids().as_ref().iter().cloned())
+                .collect())
+        };
+
+        assert_eq!(ids("sq0")?, ["rs0", "rs1", "rs3"]);
+        assert_eq!(ids("sq0:9-12")?, ["rs0", "rs1"]);
+        assert_eq!(ids("sq0:11-12")?, Vec::<String>::new());
+        assert_eq!(ids("sq1:8")?, ["rs2"]);
+        assert_eq!(ids("sq2")?, Vec::<String>::new());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_id() -> Result<(), Box<dyn std::error::Error>> {
+        let mut store = MemoryStore::new(vcf::Header::default());
+
+        store.insert(
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::try_from(8)?)
+                .set_ids([String::from("rs1")].into_iter().collect())
+                .set_reference_bases("A")
+                .build(),
+        )?;
+        store.insert(
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::try_from(13)?)
+                .set_ids([String::from("rs2")].into_iter().collect())
+                .set_reference_bases("A")
+                .build(),
+        )?;
+
+        let starts: Vec<_> = store
+            .query_id("rs2")
+            .filter_map(|record| record.variant_start())
+            .map(usize::from)
+            .collect();
+
+        assert_eq!(starts, [13]);
+        assert_eq!(store.query_id("rs3").count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_samples() -> Result<(), Box<dyn std::error::Error>> {
+        let header = vcf::Header::builder()
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .add_sample_name("sample2")
+            .build();
+
+        let keys = [String::from(key::GENOTYPE)].into_iter().collect();
+        let samples = Samples::new(
+            keys,
+            vec![
+                vec![Some(Value::from("0|0"))],
+                vec![Some(Value::from("0|1"))],
+                vec![Some(Value::from("1|1"))],
+            ],
+        );
+
+        let mut record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(8)?)
+            .set_ids([String::from("rs1")].into_iter().collect())
+            .set_reference_bases("A")
+            .build();
+        *record.samples_mut() = samples;
+
+        let mut store = MemoryStore::new(header);
+        store.insert(record)?;
+
+        let view = store.select_samples(["sample2", "sample0"])?;
+
+        let sample_names: Vec<_> = view.header().sample_names().iter().collect();
+        assert_eq!(sample_names, ["sample2", "sample0"]);
+
+        let region = "sq0".parse()?;
+        let records: Vec<_> = view.query(&region)?.collect();
+        assert_eq!(records.len(), 1);
+
+        let actual: Vec<_> = records[0]
+            .samples()
+            .values()
+            .map(|sample| sample.values().to_vec())
+            .collect();
+        let expected = [
+            vec![Some(Value::from("1|1"))],
+            vec![Some(Value::from("0|0"))],
+        ];
+        assert_eq!(actual, expected);
+
+        assert_eq!(view.query_id("rs1").count(), 1);
+        assert_eq!(view.records().count(), 1);
+
+        assert!(matches!(
+            store.select_samples(["sample3"]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
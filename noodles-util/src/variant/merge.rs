@@ -0,0 +1,247 @@
+//! Sorted variant record merging.
+
+mod builder;
+mod header;
+
+pub use self::builder::Builder;
+
+use std::{collections::HashMap, io};
+
+use noodles_core::{position::merge::Merger, Position};
+use noodles_vcf::{self as vcf, variant::Record};
+
+type SortKey = (usize, Position);
+
+struct Input<I> {
+    records: I,
+    header: vcf::Header,
+    contig_ids: HashMap<String, usize>,
+}
+
+impl<I, R> Iterator for Input<I>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: Record,
+{
+    type Item = io::Result<(R, SortKey)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.records.next().map(|result| {
+            result.and_then(|record| {
+                let key = sort_key(&self.contig_ids, &self.header, &record)?;
+                Ok((record, key))
+            })
+        })
+    }
+}
+
+/// An iterator that merges sorted variant record streams into a single sorted stream.
+///
+/// Each input is a header and an iterator of records that is sorted by position, i.e., by
+/// reference sequence in header contig order and then by variant start. The input headers are
+/// reconciled into a single header (see [`Self::header`]), and records are passed through
+/// unchanged.
+///
+/// Unlike `bcftools merge`, this does not combine samples. All inputs must have the same samples
+/// in the same order, e.g., chunks of an external sort or per-region calls of the same samples.
+///
+/// Records that compare equal are emitted in input order, so the merge is stable.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_util::variant::merge;
+/// use noodles_vcf::{
+///     self as vcf,
+///     header::record::value::{map::Contig, Map},
+///     variant::RecordBuf,
+/// };
+///
+/// let header = vcf::Header::builder()
+///     .add_contig("sq0", Map::<Contig>::new())
+///     .build();
+///
+/// let build_record = |variant_start| {
+///     RecordBuf::builder()
+///         .set_reference_sequence_name("sq0")
+///         .set_variant_start(Position::new(variant_start).unwrap())
+///         .build()
+/// };
+///
+/// let a = vec![build_record(1), build_record(3)];
+/// let b = vec![build_record(2)];
+///
+/// let merge = merge::Builder::default().build_from_inputs([
+///     (header.clone(), a.into_iter().map(Ok)),
+///     (header, b.into_iter().map(Ok)),
+/// ])?;
+///
+/// let positions: Vec<_> = merge
+///     .map(|result| result.map(|record| record.variant_start().map(usize::from)))
+///     .collect::<std::io::Result<_>>()?;
+///
+/// assert_eq!(positions, [Some(1), Some(2), Some(3)]);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub struct Merge<I, R> {
+    header: vcf::Header,
+    merger: Merger<Input<I>, (R, SortKey)>,
+}
+
+impl<I, R> Merge<I, R> {
+    /// Returns the merged header.
+    ///
+    /// This is the union of the input headers (see [`vcf::Header::merge`]). Contigs must have
+    /// the same relative order in all inputs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::merge;
+    /// use noodles_vcf::{self as vcf, variant::RecordBuf};
+    ///
+    /// let header = vcf::Header::default();
+    /// let merge = merge::Builder::default()
+    ///     .build_from_inputs([(header, std::iter::empty::<std::io::Result<RecordBuf>>())])?;
+    ///
+    /// assert!(merge.header().contigs().is_empty());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn header(&self) -> &vcf::Header {
+        &self.header
+    }
+}
+
+impl<I, R> Iterator for Merge<I, R>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: Record,
+{
+    type Item = io::Result<R>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.merger
+            .next_by(|(_, a), (_, b)| Ok(a.cmp(b)))
+            .map(|record| record.map(|(record, _)| record))
+            .transpose()
+    }
+}
+
+fn sort_key<R>(
+    contig_ids: &HashMap<String, usize>,
+    header: &vcf::Header,
+    record: &R,
+) -> io::Result<SortKey>
+where
+    R: Record + ?Sized,
+{
+    let reference_sequence_name = record.reference_sequence_name(header)?;
+
+    let i = contig_ids
+        .get(reference_sequence_name)
+        .copied()
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("missing contig: {reference_sequence_name}"),
+            )
+        })?;
+
+    let variant_start = record.variant_start().transpose()?.unwrap_or(Position::MIN);
+
+    Ok((i, variant_start))
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_vcf::{
+        header::record::value::{map::Contig, Map},
+        variant::RecordBuf,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_next() -> io::Result<()> {
+        let a = vcf::Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .add_contig("sq1", Map::<Contig>::new())
+            .build();
+
+        let b = vcf::Header::builder()
+            .add_contig("sq1", Map::<Contig>::new())
+            .add_contig("sq2", Map::<Contig>::new())
+            .build();
+
+        let a_records = [
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::new(5).unwrap())
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq1")
+                .set_variant_start(Position::new(3).unwrap())
+                .build(),
+        ];
+        let b_records = [
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq1")
+                .set_variant_start(Position::new(2).unwrap())
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq2")
+                .set_variant_start(Position::new(1).unwrap())
+                .build(),
+        ];
+
+        let merge = Builder::default().build_from_inputs([
+            (a, a_records.into_iter().map(Ok)),
+            (b, b_records.into_iter().map(Ok)),
+        ])?;
+
+        let names: Vec<_> = merge.header().contigs().keys().collect();
+        assert_eq!(names, ["sq0", "sq1", "sq2"]);
+
+        let actual: Vec<_> = merge
+            .map(|result| {
+                result.map(|record| {
+                    (
+                        record.reference_sequence_name().to_string(),
+                        record.variant_start().map(usize::from),
+                    )
+                })
+            })
+            .collect::<io::Result<_>>()?;
+
+        let expected = [
+            (String::from("sq0"), Some(5)),
+            (String::from("sq1"), Some(2)),
+            (String::from("sq1"), Some(3)),
+            (String::from("sq2"), Some(1)),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_missing_contig() -> io::Result<()> {
+        let header = vcf::Header::default();
+        let records = [RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::new(1).unwrap())
+            .build()];
+
+        let mut merge =
+            Builder::default().build_from_inputs([(header, records.into_iter().map(Ok))])?;
+
+        assert!(matches!(
+            merge.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
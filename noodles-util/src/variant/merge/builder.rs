@@ -0,0 +1,65 @@
+use std::{collections::HashMap, io};
+
+use noodles_core::position::merge::Merger;
+use noodles_vcf as vcf;
+
+use super::{header, Input, Merge};
+
+/// A sorted variant record merger builder.
+#[derive(Debug, Default)]
+#[non_exhaustive]
+pub struct Builder;
+
+impl Builder {
+    /// Builds a sorted variant record merger from inputs.
+    ///
+    /// Each input is a header and an iterator of records that is sorted by position.
+    ///
+    /// # Errors
+    ///
+    /// This returns an `io::Error` if the input headers cannot be merged, e.g., the sample names
+    /// differ or the contigs are in a different relative order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::merge;
+    /// use noodles_vcf::{self as vcf, variant::RecordBuf};
+    ///
+    /// let header = vcf::Header::default();
+    /// let records: [std::io::Result<RecordBuf>; 0] = [];
+    ///
+    /// let merge = merge::Builder::default().build_from_inputs([(header, records.into_iter())])?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn build_from_inputs<J, I, R>(self, inputs: J) -> io::Result<Merge<I, R>>
+    where
+        J: IntoIterator<Item = (vcf::Header, I)>,
+        I: Iterator<Item = io::Result<R>>,
+    {
+        let (headers, records): (Vec<_>, Vec<_>) = inputs.into_iter().unzip();
+        let header = header::merge(&headers)?;
+
+        let contig_ids: HashMap<_, _> = header
+            .contigs()
+            .keys()
+            .enumerate()
+            .map(|(i, name)| (name.clone(), i))
+            .collect();
+
+        let inputs: Vec<_> = records
+            .into_iter()
+            .zip(headers)
+            .map(|(records, header)| Input {
+                records,
+                header,
+                contig_ids: contig_ids.clone(),
+            })
+            .collect();
+
+        Ok(Merge {
+            header,
+            merger: Merger::new(inputs),
+        })
+    }
+}
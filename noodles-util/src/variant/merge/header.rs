@@ -0,0 +1,109 @@
+use std::io;
+
+use noodles_vcf as vcf;
+
+pub(super) fn merge(headers: &[vcf::Header]) -> io::Result<vcf::Header> {
+    let Some((first, rest)) = headers.split_first() else {
+        return Ok(vcf::Header::default());
+    };
+
+    let mut merged_header = first.clone();
+
+    for header in rest {
+        if header.sample_names() != first.sample_names() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "sample names differ",
+            ));
+        }
+
+        // Samples are shared rather than combined.
+        let mut header = header.clone();
+        header.sample_names_mut().clear();
+
+        merged_header = merged_header
+            .merge(&header)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    }
+
+    for header in headers {
+        let mut prev_index = None;
+
+        for name in header.contigs().keys() {
+            // SAFETY: The merged header contains all contigs.
+            let i = merged_header.contigs().get_index_of(name).unwrap();
+
+            // Contig indices must keep the input order for sorted records to remain sorted.
+            if prev_index.is_some_and(|j| i < j) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "inconsistent contig order",
+                ));
+            }
+
+            prev_index = Some(i);
+        }
+    }
+
+    Ok(merged_header)
+}
+
+#[cfg(test)]
+mod tests {
+    use vcf::header::record::value::{map::Contig, Map};
+
+    use super::*;
+
+    #[test]
+    fn test_merge() -> io::Result<()> {
+        let a = vcf::Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .add_sample_name("sample0")
+            .build();
+
+        let b = vcf::Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .add_contig("sq1", Map::<Contig>::new())
+            .add_sample_name("sample0")
+            .build();
+
+        let header = merge(&[a, b])?;
+
+        let names: Vec<_> = header.contigs().keys().collect();
+        assert_eq!(names, ["sq0", "sq1"]);
+
+        let sample_names: Vec<_> = header.sample_names().iter().collect();
+        assert_eq!(sample_names, ["sample0"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_different_sample_names() {
+        let a = vcf::Header::builder().add_sample_name("sample0").build();
+        let b = vcf::Header::builder().add_sample_name("sample1").build();
+
+        assert!(matches!(
+            merge(&[a, b]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_merge_with_inconsistent_contig_order() {
+        let a = vcf::Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .add_contig("sq1", Map::<Contig>::new())
+            .build();
+
+        let b = vcf::Header::builder()
+            .add_contig("sq1", Map::<Contig>::new())
+            .add_contig("sq0", Map::<Contig>::new())
+            .build();
+
+        assert!(matches!(
+            merge(&[a, b]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+}
@@ -0,0 +1,233 @@
+//! Variant record redaction.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+    io, mem,
+};
+
+use noodles_vcf::{
+    self as vcf,
+    header::record::value::Collection,
+    variant::{io::Write, Record},
+};
+
+const SAMPLE_KEY: &str = "SAMPLE";
+
+/// A redaction of sample names.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Redaction {
+    /// Sample names are kept as is.
+    Keep,
+    /// Sample names are replaced with a salted hash of them.
+    Hash,
+    /// Sample names are replaced with their position, e.g., `sample0`, `sample1`, etc.
+    Sequential,
+}
+
+/// A writer adapter that redacts identifying content from variant headers.
+///
+/// This wraps a variant writer and renames the samples in the header before passing it to the
+/// inner writer. The sample names in the header sample list and the IDs of matching `SAMPLE`
+/// records are renamed. Sample columns are kept in order, so records are passed through
+/// unchanged.
+///
+/// Hashes are only stable for a given salt and build of the library. Set a secret salt (see
+/// [`Builder::set_salt`]) to prevent recovering short values by brute force.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{self as vcf, variant::io::Write};
+/// use noodles_util::variant::redact::{self, Redaction};
+///
+/// let mut writer = redact::Builder::default()
+///     .set_sample_names(Redaction::Sequential)
+///     .build_from_writer(vcf::io::Writer::new(Vec::new()));
+///
+/// let header = vcf::Header::builder().add_sample_name("NA12878").build();
+/// writer.write_variant_header(&header)?;
+///
+/// let output = writer.into_inner().into_inner();
+/// assert!(output.ends_with(b"\tFORMAT\tsample0\n"));
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub struct Writer<W> {
+    inner: W,
+    sample_names: Redaction,
+    salt: Vec<u8>,
+}
+
+impl<W> Writer<W> {
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// use noodles_util::variant::redact;
+    /// let writer = redact::Builder::default().build_from_writer(vcf::io::Writer::new(Vec::new()));
+    /// assert!(writer.get_ref().get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// use noodles_util::variant::redact;
+    /// let mut writer = redact::Builder::default().build_from_writer(vcf::io::Writer::new(Vec::new()));
+    /// assert!(writer.get_mut().get_mut().is_empty());
+    /// ```
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// use noodles_util::variant::redact;
+    /// let writer = redact::Builder::default().build_from_writer(vcf::io::Writer::new(Vec::new()));
+    /// assert!(writer.into_inner().into_inner().is_empty());
+    /// ```
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Redacts a VCF header.
+    ///
+    /// This returns an error if two redacted sample names collide.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// use noodles_util::variant::redact::{self, Redaction};
+    ///
+    /// let writer = redact::Builder::default()
+    ///     .set_sample_names(Redaction::Sequential)
+    ///     .build_from_writer(vcf::io::Writer::new(Vec::new()));
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_sample_name("NA12878")
+    ///     .add_sample_name("NA12891")
+    ///     .build();
+    ///
+    /// let actual = writer.redact_header(&header)?;
+    /// let sample_names: Vec<_> = actual.sample_names().iter().collect();
+    /// assert_eq!(sample_names, ["sample0", "sample1"]);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn redact_header(&self, header: &vcf::Header) -> io::Result<vcf::Header> {
+        let mut header = header.clone();
+
+        let names: HashMap<String, String> = match self.sample_names {
+            Redaction::Keep => return Ok(header),
+            Redaction::Hash => header
+                .sample_names()
+                .iter()
+                .map(|name| (name.clone(), hash(&self.salt, name.as_bytes())))
+                .collect(),
+            Redaction::Sequential => header
+                .sample_names()
+                .iter()
+                .enumerate()
+                .map(|(i, name)| (name.clone(), format!("sample{i}")))
+                .collect(),
+        };
+
+        let rename = |name: String| names.get(&name).cloned().unwrap_or(name);
+
+        let sample_names = header.sample_names_mut();
+        let sample_count = sample_names.len();
+        *sample_names = mem::take(sample_names).into_iter().map(rename).collect();
+
+        if sample_names.len() != sample_count {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "redacted sample names collide",
+            ));
+        }
+
+        if let Some(Collection::Structured(map)) = header.other_records_mut().get_mut(SAMPLE_KEY) {
+            *map = mem::take(map)
+                .into_iter()
+                .map(|(id, value)| (rename(id), value))
+                .collect();
+        }
+
+        Ok(header)
+    }
+}
+
+impl<W> Write for Writer<W>
+where
+    W: Write,
+{
+    fn write_variant_header(&mut self, header: &vcf::Header) -> io::Result<()> {
+        let header = self.redact_header(header)?;
+        self.inner.write_variant_header(&header)
+    }
+
+    fn write_variant_record(
+        &mut self,
+        header: &vcf::Header,
+        record: &dyn Record,
+    ) -> io::Result<()> {
+        self.inner.write_variant_record(header, record)
+    }
+}
+
+fn hash(salt: &[u8], value: &[u8]) -> String {
+    let mut hasher = DefaultHasher::new();
+    salt.hash(&mut hasher);
+    value.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_vcf::header::record::value::{map::Other, Map};
+
+    use super::*;
+
+    #[test]
+    fn test_redact_header() -> Result<(), Box<dyn std::error::Error>> {
+        let writer = Builder::default()
+            .set_salt("noodles")
+            .build_from_writer(vcf::io::Writer::new(Vec::new()));
+
+        let header = vcf::Header::builder()
+            .insert(
+                "SAMPLE".parse()?,
+                vcf::header::record::Value::Map(String::from("NA12878"), Map::<Other>::new()),
+            )?
+            .add_sample_name("NA12878")
+            .build();
+
+        let actual = writer.redact_header(&header)?;
+
+        let name = hash(b"noodles", b"NA12878");
+        assert_eq!(
+            actual.sample_names().iter().collect::<Vec<_>>(),
+            [name.as_str()]
+        );
+        assert!(matches!(
+            actual.get(SAMPLE_KEY),
+            Some(Collection::Structured(map)) if map.contains_key(&name)
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use super::{Redaction, Writer};
+
+/// A variant redacting writer builder.
+#[derive(Clone, Debug)]
+pub struct Builder {
+    sample_names: Redaction,
+    salt: Vec<u8>,
+}
+
+impl Builder {
+    /// Sets how sample names are redacted.
+    ///
+    /// By default, sample names are hashed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::redact::{self, Redaction};
+    /// let builder = redact::Builder::default().set_sample_names(Redaction::Sequential);
+    /// ```
+    pub fn set_sample_names(mut self, sample_names: Redaction) -> Self {
+        self.sample_names = sample_names;
+        self
+    }
+
+    /// Sets the salt used to hash values.
+    ///
+    /// By default, the salt is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::variant::redact;
+    /// let builder = redact::Builder::default().set_salt("noodles");
+    /// ```
+    pub fn set_salt<S>(mut self, salt: S) -> Self
+    where
+        S: Into<Vec<u8>>,
+    {
+        self.salt = salt.into();
+        self
+    }
+
+    /// Builds a variant redacting writer from a variant writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// use noodles_util::variant::redact;
+    /// let writer = redact::Builder::default().build_from_writer(vcf::io::Writer::new(Vec::new()));
+    /// ```
+    pub fn build_from_writer<W>(self, writer: W) -> Writer<W> {
+        Writer {
+            inner: writer,
+            sample_names: self.sample_names,
+            salt: self.salt,
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            sample_names: Redaction::Hash,
+            salt: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! Queries a remote bgzipped VCF with a given region over HTTP.
+//!
+//! The VCF must have an associated tabix index at the same URL with a `.tbi` extension, and the
+//! server must support range requests.
+//!
+//! The result matches the output `bcftools view --no-header <url> <region>`.
+
+use std::env;
+
+use futures::TryStreamExt;
+use noodles_vcf as vcf;
+use tokio::io;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = env::args().skip(1);
+
+    let url = args.next().expect("missing url");
+    let region = args.next().map(|s| s.parse()).expect("missing region")?;
+
+    let mut reader = vcf::r#async::io::remote::IndexedReader::open(url).await?;
+    let header = reader.header().clone();
+
+    let mut query = reader.query(&region)?;
+
+    let mut writer = vcf::r#async::io::Writer::new(io::stdout());
+
+    while let Some(record) = query.try_next().await? {
+        writer.write_variant_record(&header, &record).await?;
+    }
+
+    writer.shutdown().await?;
+
+    Ok(())
+}
@@ -1,6 +1,8 @@
 //! Async VCF I/O.
 
 mod reader;
+#[cfg(feature = "http")]
+pub mod remote;
 mod writer;
 
 pub use self::{reader::Reader, writer::Writer};
@@ -0,0 +1,6 @@
+//! Async remote VCF I/O over HTTP.
+
+mod http_reader;
+mod indexed_reader;
+
+pub use self::{http_reader::HttpReader, indexed_reader::IndexedReader};
@@ -0,0 +1,351 @@
+use std::{
+    io::{self, SeekFrom},
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+
+use bytes::Bytes;
+use futures::{future::BoxFuture, FutureExt};
+use reqwest::{header, Client, IntoUrl, StatusCode, Url};
+use tokio::io::{AsyncRead, AsyncSeek, ReadBuf};
+
+const DEFAULT_CAPACITY: usize = 1 << 16;
+
+/// An async reader over a remote resource using HTTP range requests.
+///
+/// Each buffer refill is a single range request. Seeking within the current buffer does not make a
+/// request. The server must support range requests.
+///
+/// This can be used as the inner reader of, e.g., a [`noodles_bgzf::AsyncReader`] to query
+/// indexed data without downloading the entire resource.
+pub struct HttpReader {
+    client: Client,
+    url: Url,
+    len: u64,
+    capacity: usize,
+    position: u64,
+    buf: Bytes,
+    buf_start: u64,
+    request: Option<(u64, BoxFuture<'static, io::Result<Bytes>>)>,
+}
+
+impl HttpReader {
+    /// Opens a remote resource.
+    ///
+    /// This makes a `HEAD` request to get the length of the resource.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> std::io::Result<()> {
+    /// use noodles_vcf::r#async::io::remote::HttpReader;
+    /// let reader = HttpReader::open("https://example.com/sample.vcf.gz").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn open<U>(url: U) -> io::Result<Self>
+    where
+        U: IntoUrl,
+    {
+        Self::open_with_client(Client::new(), url).await
+    }
+
+    /// Opens a remote resource using the given HTTP client.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> std::io::Result<()> {
+    /// use noodles_vcf::r#async::io::remote::HttpReader;
+    ///
+    /// let client = reqwest::Client::new();
+    /// let reader = HttpReader::open_with_client(client, "https://example.com/sample.vcf.gz").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn open_with_client<U>(client: Client, url: U) -> io::Result<Self>
+    where
+        U: IntoUrl,
+    {
+        let url = url.into_url().map_err(into_io_error)?;
+
+        let response = client
+            .head(url.clone())
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(into_io_error)?;
+
+        let len = response
+            .headers()
+            .get(header::CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing content length"))?;
+
+        Ok(Self {
+            client,
+            url,
+            len,
+            capacity: DEFAULT_CAPACITY,
+            position: 0,
+            buf: Bytes::new(),
+            buf_start: 0,
+            request: None,
+        })
+    }
+
+    /// Sets the number of bytes requested per buffer refill.
+    ///
+    /// The default is 64 KiB.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> std::io::Result<()> {
+    /// use noodles_vcf::r#async::io::remote::HttpReader;
+    ///
+    /// let reader = HttpReader::open("https://example.com/sample.vcf.gz")
+    ///     .await?
+    ///     .with_capacity(1 << 20);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// Returns the URL of the remote resource.
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the length of the remote resource.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns whether the remote resource is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the current position of the stream.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    fn buffered(&self) -> Option<&[u8]> {
+        let i = self.position.checked_sub(self.buf_start)?;
+        let i = usize::try_from(i).ok()?;
+        self.buf.get(i..).filter(|buf| !buf.is_empty())
+    }
+
+    fn poll_fill_buf(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        // A pending request for a previous position is no longer useful.
+        if matches!(self.request, Some((start, _)) if start != self.position) {
+            self.request = None;
+        }
+
+        if self.request.is_none() {
+            let start = self.position;
+            let end = start.saturating_add(self.capacity as u64).min(self.len) - 1;
+            let request = fetch(self.client.clone(), self.url.clone(), start, end).boxed();
+            self.request = Some((start, request));
+        }
+
+        // SAFETY: `self.request` is set above.
+        let (start, request) = self.request.as_mut().unwrap();
+        let start = *start;
+        let result = ready!(request.as_mut().poll(cx));
+        self.request = None;
+
+        self.buf = result?;
+        self.buf_start = start;
+
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncRead for HttpReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+
+        if this.position >= this.len || buf.remaining() == 0 {
+            return Poll::Ready(Ok(()));
+        }
+
+        if this.buffered().is_none() {
+            ready!(this.poll_fill_buf(cx))?;
+        }
+
+        let Some(src) = this.buffered() else {
+            return Poll::Ready(Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "empty range response",
+            )));
+        };
+
+        let n = src.len().min(buf.remaining());
+        buf.put_slice(&src[..n]);
+        this.position += n as u64;
+
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncSeek for HttpReader {
+    fn start_seek(self: Pin<&mut Self>, pos: SeekFrom) -> io::Result<()> {
+        let this = self.get_mut();
+
+        let position = match pos {
+            SeekFrom::Start(n) => Some(n),
+            SeekFrom::End(n) => this.len.checked_add_signed(n),
+            SeekFrom::Current(n) => this.position.checked_add_signed(n),
+        };
+
+        this.position = position.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+
+        Ok(())
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<u64>> {
+        Poll::Ready(Ok(self.position))
+    }
+}
+
+async fn fetch(client: Client, url: Url, start: u64, end: u64) -> io::Result<Bytes> {
+    let response = client
+        .get(url)
+        .header(header::RANGE, format!("bytes={start}-{end}"))
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(into_io_error)?;
+
+    if response.status() != StatusCode::PARTIAL_CONTENT {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "server does not support range requests",
+        ));
+    }
+
+    response.bytes().await.map_err(into_io_error)
+}
+
+pub(super) fn into_io_error(e: reqwest::Error) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, e)
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::{
+        io::{AsyncBufReadExt, AsyncReadExt, AsyncSeekExt, AsyncWriteExt, BufReader},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    // Serves `data` with support for `HEAD` and single range `GET` requests.
+    async fn serve(data: &'static [u8]) -> io::Result<Url> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let (reader, mut writer) = stream.into_split();
+                    let mut reader = BufReader::new(reader);
+
+                    loop {
+                        let mut request_line = String::new();
+
+                        if reader.read_line(&mut request_line).await? == 0 {
+                            return Ok::<_, io::Error>(());
+                        }
+
+                        let mut range = None;
+
+                        loop {
+                            let mut line = String::new();
+                            reader.read_line(&mut line).await?;
+                            let line = line.trim_end();
+
+                            if line.is_empty() {
+                                break;
+                            }
+
+                            if let Some((name, value)) = line.split_once(": ") {
+                                if name.eq_ignore_ascii_case("range") {
+                                    let (start, end) =
+                                        value.trim_start_matches("bytes=").split_once('-').unwrap();
+                                    let start: usize = start.parse().unwrap();
+                                    let end: usize = end.parse().unwrap();
+                                    range = Some(start..end.min(data.len() - 1) + 1);
+                                }
+                            }
+                        }
+
+                        let response = if request_line.starts_with("HEAD") {
+                            format!("HTTP/1.1 200 OK\r\ncontent-length: {}\r\n\r\n", data.len())
+                                .into_bytes()
+                        } else {
+                            let range = range.unwrap();
+                            let mut buf = format!(
+                                "HTTP/1.1 206 Partial Content\r\ncontent-length: {}\r\n\r\n",
+                                range.len()
+                            )
+                            .into_bytes();
+                            buf.extend_from_slice(&data[range]);
+                            buf
+                        };
+
+                        writer.write_all(&response).await?;
+                    }
+                });
+            }
+        });
+
+        Url::parse(&format!("http://{addr}/data"))
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))
+    }
+
+    #[tokio::test]
+    async fn test_read_and_seek() -> io::Result<()> {
+        let url = serve(b"noodles").await?;
+
+        let mut reader = HttpReader::open(url).await?.with_capacity(2);
+        assert_eq!(reader.len(), 7);
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf).await?;
+        assert_eq!(buf, b"noodles");
+
+        reader.seek(SeekFrom::Start(2)).await?;
+        let mut buf = [0; 3];
+        reader.read_exact(&mut buf).await?;
+        assert_eq!(&buf, b"odl");
+
+        reader.seek(SeekFrom::End(-2)).await?;
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf).await?;
+        assert_eq!(buf, b"es");
+
+        assert!(reader.seek(SeekFrom::Current(-8)).await.is_err());
+
+        Ok(())
+    }
+}
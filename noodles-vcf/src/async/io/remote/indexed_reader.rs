@@ -0,0 +1,184 @@
+use std::io;
+
+use futures::Stream;
+use noodles_bgzf as bgzf;
+use noodles_core::Region;
+use noodles_tabix as tabix;
+use reqwest::{Client, IntoUrl, Url};
+
+use super::{http_reader::into_io_error, HttpReader};
+use crate::{r#async::io::Reader, Header, Record};
+
+/// An async indexed VCF reader over HTTP.
+///
+/// This reads a bgzipped VCF and its tabix index from a remote server. Queries only request the
+/// byte ranges of the BGZF blocks that intersect the query region.
+pub struct IndexedReader {
+    inner: Reader<bgzf::AsyncReader<HttpReader>>,
+    header: Header,
+    index: tabix::Index,
+}
+
+impl IndexedReader {
+    /// Opens a remote bgzipped VCF.
+    ///
+    /// The tabix index is expected to be at the same URL with a `.tbi` extension. The VCF header
+    /// and tabix index are read when opened.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> std::io::Result<()> {
+    /// use noodles_vcf::r#async::io::remote::IndexedReader;
+    /// let reader = IndexedReader::open("https://example.com/sample.vcf.gz").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn open<U>(url: U) -> io::Result<Self>
+    where
+        U: IntoUrl,
+    {
+        let url = url.into_url().map_err(into_io_error)?;
+        let index_url = build_index_url(&url)?;
+        Self::open_with_index_url(Client::new(), url, index_url).await
+    }
+
+    /// Opens a remote bgzipped VCF with a tabix index at the given URL.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> std::io::Result<()> {
+    /// use noodles_vcf::r#async::io::remote::IndexedReader;
+    ///
+    /// let reader = IndexedReader::open_with_index_url(
+    ///     reqwest::Client::new(),
+    ///     "https://example.com/sample.vcf.gz",
+    ///     "https://example.com/sample.vcf.gz.tbi",
+    /// )
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn open_with_index_url<U, V>(client: Client, url: U, index_url: V) -> io::Result<Self>
+    where
+        U: IntoUrl,
+        V: IntoUrl,
+    {
+        let index = read_index(&client, index_url).await?;
+
+        let mut inner = HttpReader::open_with_client(client, url)
+            .await
+            .map(bgzf::AsyncReader::new)
+            .map(Reader::new)?;
+
+        let header = inner.read_header().await?;
+
+        Ok(Self {
+            inner,
+            header,
+            index,
+        })
+    }
+
+    /// Returns the VCF header.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Returns the tabix index.
+    pub fn index(&self) -> &tabix::Index {
+        &self.index
+    }
+
+    /// Returns the underlying reader.
+    pub fn get_mut(&mut self) -> &mut Reader<bgzf::AsyncReader<HttpReader>> {
+        &mut self.inner
+    }
+
+    /// Returns a stream over records that intersect the given region.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures::TryStreamExt;
+    /// use noodles_vcf::r#async::io::remote::IndexedReader;
+    ///
+    /// let mut reader = IndexedReader::open("https://example.com/sample.vcf.gz").await?;
+    ///
+    /// let region = "sq0:8-13".parse()?;
+    /// let mut query = reader.query(&region)?;
+    ///
+    /// while let Some(record) = query.try_next().await? {
+    ///     // ...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn query(
+        &mut self,
+        region: &Region,
+    ) -> io::Result<impl Stream<Item = io::Result<Record>> + '_> {
+        self.inner.query(&self.header, &self.index, region)
+    }
+}
+
+fn build_index_url(url: &Url) -> io::Result<Url> {
+    let mut index_url = url.clone();
+
+    {
+        let mut segments = index_url.path_segments_mut().map_err(|_| {
+            io::Error::new(io::ErrorKind::InvalidInput, "invalid URL: cannot be a base")
+        })?;
+
+        let name = url
+            .path_segments()
+            .and_then(|mut segments| segments.next_back())
+            .filter(|name| !name.is_empty())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing file name"))?;
+
+        segments.pop().push(&format!("{name}.tbi"));
+    }
+
+    Ok(index_url)
+}
+
+async fn read_index<U>(client: &Client, url: U) -> io::Result<tabix::Index>
+where
+    U: IntoUrl,
+{
+    let buf = client
+        .get(url)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(into_io_error)?
+        .bytes()
+        .await
+        .map_err(into_io_error)?;
+
+    let mut reader = tabix::AsyncReader::new(&buf[..]);
+    reader.read_index().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_index_url() -> Result<(), Box<dyn std::error::Error>> {
+        let url = Url::parse("https://example.com/data/sample.vcf.gz?token=ndls")?;
+        let actual = build_index_url(&url)?;
+        let expected = Url::parse("https://example.com/data/sample.vcf.gz.tbi?token=ndls")?;
+        assert_eq!(actual, expected);
+
+        let url = Url::parse("https://example.com/")?;
+        assert!(build_index_url(&url).is_err());
+
+        Ok(())
+    }
+}
@@ -2,18 +2,23 @@
 
 mod builder;
 pub mod file_format;
+mod merge;
 pub mod parser;
 pub mod record;
+#[cfg(feature = "serde")]
+mod serde;
 pub mod string_maps;
+pub mod table;
 
 pub use self::{
-    builder::Builder, file_format::FileFormat, parser::ParseError, parser::Parser, record::Record,
-    string_maps::StringMaps,
+    builder::Builder, file_format::FileFormat, merge::MergeError, parser::ParseError,
+    parser::Parser, record::Record, string_maps::StringMaps,
 };
 
 use std::{hash::Hash, str::FromStr};
 
 use indexmap::{IndexMap, IndexSet};
+use noodles_core::Assembly;
 
 use self::record::value::{
     map::{AlternativeAllele, Contig, Filter, Format, Info},
@@ -42,6 +47,8 @@ pub type SampleNames = IndexSet<String>;
 pub type OtherRecords = IndexMap<record::key::Other, record::value::Collection>;
 
 /// A VCF header.
+///
+/// With the `serde` feature enabled, the header can be (de)serialized, e.g., to and from JSON.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Header {
     file_format: FileFormat,
@@ -338,6 +345,48 @@ impl Header {
         &mut self.contigs
     }
 
+    /// Returns the genome assembly of the contigs.
+    ///
+    /// This is the first `assembly` field of the contig records. If no contig has an `assembly`
+    /// field, the assembly is inferred from the contig IDs, lengths, and MD5 checksums (see
+    /// [`Assembly::infer`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Assembly;
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Contig, Map}};
+    ///
+    /// let mut chr1 = Map::<Contig>::new();
+    /// *chr1.length_mut() = Some(248956422);
+    ///
+    /// let header = vcf::Header::builder().add_contig("chr1", chr1).build();
+    ///
+    /// assert_eq!(header.assembly(), Some(Assembly::new("GRCh38", None)));
+    /// ```
+    pub fn assembly(&self) -> Option<Assembly> {
+        const ASSEMBLY: &str = "assembly";
+
+        let assembly = self
+            .contigs
+            .values()
+            .find_map(|contig| contig.other_fields().get(ASSEMBLY));
+
+        if let Some(assembly) = assembly {
+            return assembly.parse().ok();
+        }
+
+        Assembly::infer(self.contigs.iter().filter_map(|(id, contig)| {
+            contig.length().map(|length| {
+                (
+                    id.as_bytes(),
+                    length,
+                    contig.md5().map(|md5| md5.as_bytes()),
+                )
+            })
+        }))
+    }
+
     /// Returns a list of sample names that come after the FORMAT column in the header record.
     ///
     /// # Examples
@@ -499,6 +548,70 @@ impl Header {
         collection.add(value)
     }
 
+    /// Merges another header into a copy of this header.
+    ///
+    /// This unions the contig (`contig`), information (`INFO`), format (`FORMAT`), filter
+    /// (`FILTER`), and alternative allele (`ALT`) records; other records; and sample names.
+    /// Entries from this header take precedence, and new entries from `other` are appended. The
+    /// file format is the greater of the two.
+    ///
+    /// Definitions from `other` that are not in this header have their `IDX` field cleared, as
+    /// it is only meaningful within the source header.
+    ///
+    /// This returns an error if the headers have incompatible genome assemblies (see
+    /// [`Self::assembly`]), if a contig has conflicting lengths, if an information or format
+    /// definition has a conflicting number or type, or if a sample name is in both headers. See
+    /// [`Self::merge_renaming_duplicate_sample_names`] to rename colliding sample names instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Contig, Map}};
+    ///
+    /// let a = vcf::Header::builder()
+    ///     .add_contig("sq0", Map::<Contig>::new())
+    ///     .add_sample_name("sample0")
+    ///     .build();
+    ///
+    /// let b = vcf::Header::builder()
+    ///     .add_contig("sq1", Map::<Contig>::new())
+    ///     .add_sample_name("sample1")
+    ///     .build();
+    ///
+    /// let header = a.merge(&b)?;
+    /// assert_eq!(header.contigs().len(), 2);
+    /// assert_eq!(header.sample_names().len(), 2);
+    /// # Ok::<_, vcf::header::MergeError>(())
+    /// ```
+    pub fn merge(&self, other: &Self) -> Result<Self, MergeError> {
+        merge::merge(self, other, false)
+    }
+
+    /// Merges another header into a copy of this header, renaming duplicate sample names.
+    ///
+    /// This is the same as [`Self::merge`], except a sample name in `other` that collides with
+    /// an existing sample name is prefixed with the lowest integer `n` ≥ 2 that makes it unique,
+    /// i.e., `n:<sample-name>`. This matches the behavior of `bcftools merge --force-samples`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    ///
+    /// let a = vcf::Header::builder().add_sample_name("sample0").build();
+    /// let b = vcf::Header::builder().add_sample_name("sample0").build();
+    ///
+    /// let header = a.merge_renaming_duplicate_sample_names(&b)?;
+    /// assert_eq!(
+    ///     header.sample_names().iter().collect::<Vec<_>>(),
+    ///     ["sample0", "2:sample0"]
+    /// );
+    /// # Ok::<_, vcf::header::MergeError>(())
+    /// ```
+    pub fn merge_renaming_duplicate_sample_names(&self, other: &Self) -> Result<Self, MergeError> {
+        merge::merge(self, other, true)
+    }
+
     #[doc(hidden)]
     pub fn string_maps(&self) -> &StringMaps {
         &self.string_maps
@@ -0,0 +1,308 @@
+use std::{error, fmt, hash::Hash};
+
+use indexmap::IndexMap;
+use noodles_core::assembly::MismatchError;
+
+use super::{
+    record::value::{map::Indexed, Collection, Map},
+    Header, SampleNames,
+};
+
+/// An error returned when two VCF headers fail to merge.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MergeError {
+    /// A contig has conflicting lengths.
+    ContigLengthConflict(String),
+    /// An info definition has a conflicting number or type.
+    InfoDefinitionConflict(String),
+    /// A format definition has a conflicting number or type.
+    FormatDefinitionConflict(String),
+    /// A sample name is duplicated.
+    DuplicateSampleName(String),
+    /// The genome assemblies are incompatible.
+    AssemblyMismatch(MismatchError),
+}
+
+impl error::Error for MergeError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::AssemblyMismatch(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for MergeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ContigLengthConflict(id) => write!(f, "conflicting contig length: {id}"),
+            Self::InfoDefinitionConflict(id) => write!(f, "conflicting INFO definition: {id}"),
+            Self::FormatDefinitionConflict(id) => {
+                write!(f, "conflicting FORMAT definition: {id}")
+            }
+            Self::DuplicateSampleName(sample_name) => {
+                write!(f, "duplicate sample name: {sample_name}")
+            }
+            Self::AssemblyMismatch(_) => write!(f, "assembly mismatch"),
+        }
+    }
+}
+
+pub(super) fn merge(
+    dst: &Header,
+    src: &Header,
+    rename_duplicate_sample_names: bool,
+) -> Result<Header, MergeError> {
+    if let (Some(a), Some(b)) = (dst.assembly(), src.assembly()) {
+        a.check_compatible(&b)
+            .map_err(MergeError::AssemblyMismatch)?;
+    }
+
+    let mut header = dst.clone();
+
+    header.file_format = dst.file_format.max(src.file_format);
+
+    for (id, contig) in &src.contigs {
+        match header.contigs.get(id) {
+            Some(c) => match (c.length(), contig.length()) {
+                (Some(a), Some(b)) if a != b => {
+                    return Err(MergeError::ContigLengthConflict(id.clone()))
+                }
+                (None, Some(_)) => {
+                    // SAFETY: The contig exists.
+                    *header.contigs.get_mut(id).unwrap().length_mut() = contig.length();
+                }
+                _ => {}
+            },
+            None => insert_unindexed(&mut header.contigs, id, contig),
+        }
+    }
+
+    for (id, info) in &src.infos {
+        match header.infos.get(id) {
+            Some(i) => {
+                if i.number() != info.number() || i.ty() != info.ty() {
+                    return Err(MergeError::InfoDefinitionConflict(id.clone()));
+                }
+            }
+            None => insert_unindexed(&mut header.infos, id, info),
+        }
+    }
+
+    for (id, format) in &src.formats {
+        match header.formats.get(id) {
+            Some(f) => {
+                if f.number() != format.number() || f.ty() != format.ty() {
+                    return Err(MergeError::FormatDefinitionConflict(id.clone()));
+                }
+            }
+            None => insert_unindexed(&mut header.formats, id, format),
+        }
+    }
+
+    for (id, filter) in &src.filters {
+        if !header.filters.contains_key(id) {
+            insert_unindexed(&mut header.filters, id, filter);
+        }
+    }
+
+    for (id, alternative_allele) in &src.alternative_alleles {
+        header
+            .alternative_alleles
+            .entry(id.clone())
+            .or_insert_with(|| alternative_allele.clone());
+    }
+
+    for (key, collection) in &src.other_records {
+        match header.other_records.get_mut(key) {
+            Some(Collection::Unstructured(dst_values)) => {
+                if let Collection::Unstructured(src_values) = collection {
+                    for value in src_values {
+                        if !dst_values.contains(value) {
+                            dst_values.push(value.clone());
+                        }
+                    }
+                }
+            }
+            Some(Collection::Structured(dst_map)) => {
+                if let Collection::Structured(src_map) = collection {
+                    for (id, map) in src_map {
+                        dst_map.entry(id.clone()).or_insert_with(|| map.clone());
+                    }
+                }
+            }
+            None => {
+                header.other_records.insert(key.clone(), collection.clone());
+            }
+        }
+    }
+
+    merge_sample_names(
+        &mut header.sample_names,
+        &src.sample_names,
+        rename_duplicate_sample_names,
+    )?;
+
+    header.string_maps = Default::default();
+
+    Ok(header)
+}
+
+// The `IDX` of a definition from another header is only meaningful within that header's
+// dictionary, so it is dropped.
+fn insert_unindexed<K, I>(dst: &mut IndexMap<K, Map<I>>, id: &K, map: &Map<I>)
+where
+    K: Clone + Eq + Hash,
+    I: Indexed,
+    Map<I>: Clone,
+{
+    let mut map = map.clone();
+    *map.idx_mut() = None;
+    dst.insert(id.clone(), map);
+}
+
+fn merge_sample_names(
+    dst: &mut SampleNames,
+    src: &SampleNames,
+    rename_duplicate_sample_names: bool,
+) -> Result<(), MergeError> {
+    for sample_name in src {
+        if !dst.contains(sample_name) {
+            dst.insert(sample_name.clone());
+        } else if rename_duplicate_sample_names {
+            // This follows the renaming scheme of `bcftools merge --force-samples`.
+            let name = (2..)
+                .map(|i| format!("{i}:{sample_name}"))
+                .find(|name| !dst.contains(name) && !src.contains(name))
+                // SAFETY: The range is unbounded.
+                .unwrap();
+
+            dst.insert(name);
+        } else {
+            return Err(MergeError::DuplicateSampleName(sample_name.clone()));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::header::{
+        record::value::map::{info::Number, Contig, Format, Info},
+        FileFormat,
+    };
+
+    #[test]
+    fn test_merge() -> Result<(), Box<dyn std::error::Error>> {
+        let a = Header::builder()
+            .set_file_format(FileFormat::new(4, 3))
+            .add_contig("sq0", Map::<Contig>::new())
+            .add_info("DP", Map::<Info>::from("DP"))
+            .add_sample_name("sample0")
+            .build();
+
+        let mut sq0 = Map::<Contig>::new();
+        *sq0.length_mut() = Some(8);
+
+        let mut af = Map::<Info>::from("AF");
+        *af.idx_mut() = Some(5);
+
+        let b = Header::builder()
+            .set_file_format(FileFormat::new(4, 4))
+            .add_contig("sq0", sq0)
+            .add_contig("sq1", Map::<Contig>::new())
+            .add_info("DP", Map::<Info>::from("DP"))
+            .add_info("AF", af)
+            .add_format("GT", Map::<Format>::from("GT"))
+            .add_sample_name("sample1")
+            .build();
+
+        let actual = a.merge(&b)?;
+
+        assert_eq!(actual.file_format(), FileFormat::new(4, 4));
+        assert_eq!(actual.contigs().keys().collect::<Vec<_>>(), ["sq0", "sq1"]);
+        assert_eq!(actual.contigs()["sq0"].length(), Some(8));
+        assert_eq!(actual.infos().keys().collect::<Vec<_>>(), ["DP", "AF"]);
+        assert!(actual.infos()["AF"].idx().is_none());
+        assert_eq!(actual.formats().keys().collect::<Vec<_>>(), ["GT"]);
+        assert_eq!(
+            actual.sample_names().iter().collect::<Vec<_>>(),
+            ["sample0", "sample1"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_conflicts() {
+        let mut sq0 = Map::<Contig>::new();
+        *sq0.length_mut() = Some(8);
+        let a = Header::builder().add_contig("sq0", sq0).build();
+
+        let mut sq0 = Map::<Contig>::new();
+        *sq0.length_mut() = Some(13);
+        let b = Header::builder().add_contig("sq0", sq0).build();
+
+        assert_eq!(
+            a.merge(&b),
+            Err(MergeError::ContigLengthConflict(String::from("sq0")))
+        );
+
+        let a = Header::builder()
+            .add_info("DP", Map::<Info>::from("DP"))
+            .build();
+
+        let mut dp = Map::<Info>::from("DP");
+        *dp.number_mut() = Number::Unknown;
+        let b = Header::builder().add_info("DP", dp).build();
+
+        assert_eq!(
+            a.merge(&b),
+            Err(MergeError::InfoDefinitionConflict(String::from("DP")))
+        );
+
+        let mut chr1 = Map::<Contig>::new();
+        *chr1.length_mut() = Some(249250621);
+        let a = Header::builder().add_contig("chr1", chr1).build();
+
+        let mut chr2 = Map::<Contig>::new();
+        *chr2.length_mut() = Some(242193529);
+        let b = Header::builder().add_contig("chr2", chr2).build();
+
+        assert!(matches!(
+            a.merge(&b),
+            Err(MergeError::AssemblyMismatch(e))
+                if e.expected().name() == "GRCh37" && e.actual().name() == "GRCh38"
+        ));
+    }
+
+    #[test]
+    fn test_merge_with_duplicate_sample_names() -> Result<(), MergeError> {
+        let a = Header::builder().add_sample_name("sample0").build();
+        let b = Header::builder()
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .build();
+
+        assert_eq!(
+            a.merge(&b),
+            Err(MergeError::DuplicateSampleName(String::from("sample0")))
+        );
+
+        let actual = a.merge_renaming_duplicate_sample_names(&b)?;
+        assert_eq!(
+            actual.sample_names().iter().collect::<Vec<_>>(),
+            ["sample0", "2:sample0", "sample1"]
+        );
+
+        let actual = actual.merge_renaming_duplicate_sample_names(&a)?;
+        assert_eq!(
+            actual.sample_names().iter().collect::<Vec<_>>(),
+            ["sample0", "2:sample0", "sample1", "3:sample0"]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,267 @@
+//! VCF header (de)serialization.
+//!
+//! A header is represented by its file format, structured records (as a [`Table`]), unstructured
+//! records, and sample names.
+
+use std::fmt::Write;
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{
+    record::value::Collection,
+    table::{Row, Table},
+    Header,
+};
+
+const PREFIX: &str = "##";
+const HEADER_PREFIX: &str = "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO";
+const FORMAT: &str = "FORMAT";
+
+#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename = "Header")]
+struct Repr {
+    file_format: String,
+    records: Table,
+    other_records: Vec<OtherRecord>,
+    sample_names: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+struct OtherRecord {
+    key: String,
+    value: String,
+}
+
+impl From<&Header> for Repr {
+    fn from(header: &Header) -> Self {
+        let file_format = header.file_format();
+
+        let other_records = header
+            .other_records()
+            .iter()
+            .filter_map(|(key, collection)| match collection {
+                Collection::Unstructured(values) => Some((key, values)),
+                Collection::Structured(_) => None,
+            })
+            .flat_map(|(key, values)| {
+                values.iter().map(move |value| OtherRecord {
+                    key: key.as_ref().into(),
+                    value: value.clone(),
+                })
+            })
+            .collect();
+
+        Self {
+            file_format: format!("VCFv{}.{}", file_format.major(), file_format.minor()),
+            records: Table::from(header),
+            other_records,
+            sample_names: header.sample_names().iter().cloned().collect(),
+        }
+    }
+}
+
+impl Repr {
+    fn to_raw_header(&self) -> Result<String, String> {
+        let mut s = String::new();
+
+        validate_value(&self.file_format)?;
+        writeln!(s, "{PREFIX}fileformat={}", self.file_format).map_err(|e| e.to_string())?;
+
+        for row in self.records.rows() {
+            write_row(&mut s, row)?;
+        }
+
+        for record in &self.other_records {
+            validate_value(&record.key)?;
+            validate_value(&record.value)?;
+            writeln!(s, "{PREFIX}{}={}", record.key, record.value).map_err(|e| e.to_string())?;
+        }
+
+        s.push_str(HEADER_PREFIX);
+
+        if !self.sample_names.is_empty() {
+            s.push('\t');
+            s.push_str(FORMAT);
+
+            for sample_name in &self.sample_names {
+                validate_value(sample_name)?;
+                s.push('\t');
+                s.push_str(sample_name);
+            }
+        }
+
+        s.push('\n');
+
+        Ok(s)
+    }
+}
+
+fn write_row(s: &mut String, row: &Row) -> Result<(), String> {
+    validate_value(row.kind())?;
+    validate_value(row.id())?;
+
+    write!(s, "{PREFIX}{}=<ID={}", row.kind(), row.id()).map_err(|e| e.to_string())?;
+
+    for (key, value) in row.fields() {
+        validate_value(key)?;
+        validate_value(value)?;
+
+        write!(s, ",{key}=\"").map_err(|e| e.to_string())?;
+
+        for c in value.chars() {
+            if matches!(c, '"' | '\\') {
+                s.push('\\');
+            }
+
+            s.push(c);
+        }
+
+        s.push('"');
+    }
+
+    s.push_str(">\n");
+
+    Ok(())
+}
+
+fn validate_value(s: &str) -> Result<(), String> {
+    if s.contains(['\t', '\n', '\r']) {
+        Err(format!("invalid header record value: {s:?}"))
+    } else {
+        Ok(())
+    }
+}
+
+impl Serialize for Header {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Repr::from(self).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Header {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let repr = Repr::deserialize(deserializer)?;
+        let s = repr.to_raw_header().map_err(de::Error::custom)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_test::{assert_tokens, Token};
+
+    use super::*;
+
+    #[test]
+    fn test_serde() -> Result<(), Box<dyn std::error::Error>> {
+        let header: Header = r#"##fileformat=VCFv4.4
+##INFO=<ID=DP,Number=1,Type=Integer,Description="Combined depth across samples">
+##contig=<ID=sq0,length=8>
+##source=noodles
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO	FORMAT	sample0
+"#
+        .parse()?;
+
+        assert_tokens(
+            &header,
+            &[
+                Token::Struct {
+                    name: "Header",
+                    len: 4,
+                },
+                Token::Str("file_format"),
+                Token::Str("VCFv4.4"),
+                Token::Str("records"),
+                Token::Seq { len: Some(2) },
+                Token::Struct {
+                    name: "Row",
+                    len: 3,
+                },
+                Token::Str("kind"),
+                Token::Str("INFO"),
+                Token::Str("id"),
+                Token::Str("DP"),
+                Token::Str("fields"),
+                Token::Seq { len: Some(3) },
+                Token::Tuple { len: 2 },
+                Token::Str("Number"),
+                Token::Str("1"),
+                Token::TupleEnd,
+                Token::Tuple { len: 2 },
+                Token::Str("Type"),
+                Token::Str("Integer"),
+                Token::TupleEnd,
+                Token::Tuple { len: 2 },
+                Token::Str("Description"),
+                Token::Str("Combined depth across samples"),
+                Token::TupleEnd,
+                Token::SeqEnd,
+                Token::StructEnd,
+                Token::Struct {
+                    name: "Row",
+                    len: 3,
+                },
+                Token::Str("kind"),
+                Token::Str("contig"),
+                Token::Str("id"),
+                Token::Str("sq0"),
+                Token::Str("fields"),
+                Token::Seq { len: Some(1) },
+                Token::Tuple { len: 2 },
+                Token::Str("length"),
+                Token::Str("8"),
+                Token::TupleEnd,
+                Token::SeqEnd,
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("other_records"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "OtherRecord",
+                    len: 2,
+                },
+                Token::Str("key"),
+                Token::Str("source"),
+                Token::Str("value"),
+                Token::Str("noodles"),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("sample_names"),
+                Token::Seq { len: Some(1) },
+                Token::Str("sample0"),
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_raw_header_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        let header: Header = r#"##fileformat=VCFv4.3
+##INFO=<ID=DP,Number=1,Type=Integer,Description="Combined \"depth\"">
+##FILTER=<ID=q10,Description="Quality below 10">
+##FORMAT=<ID=AD,Number=R,Type=Integer,Description="Read depth for each allele">
+##ALT=<ID=DEL,Description="Deletion">
+##contig=<ID=sq0,length=8,md5=d7eba311421bbc9d3ada44709dd61534>
+##SAMPLE=<ID=sample0,Assay=WholeGenome>
+##source=noodles
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO	FORMAT	sample0	sample1
+"#
+        .parse()?;
+
+        let s = Repr::from(&header).to_raw_header()?;
+        let actual: Header = s.parse()?;
+
+        assert_eq!(actual, header);
+
+        Ok(())
+    }
+}
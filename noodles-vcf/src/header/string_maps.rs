@@ -65,7 +65,19 @@ impl StringMaps {
         &self.string_string_map
     }
 
-    fn strings_mut(&mut self) -> &mut StringStringMap {
+    /// Returns a mutable reference to the indexed map of VCF strings (FILTER, FORMAT, and INFO).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::StringMaps;
+    ///
+    /// let mut string_maps = StringMaps::default();
+    /// string_maps.strings_mut().insert(String::from("DP"));
+    ///
+    /// assert_eq!(string_maps.strings().get_index_of("DP"), Some(1));
+    /// ```
+    pub fn strings_mut(&mut self) -> &mut StringStringMap {
         &mut self.string_string_map
     }
 
@@ -101,7 +113,19 @@ impl StringMaps {
         &self.contig_string_map
     }
 
-    fn contigs_mut(&mut self) -> &mut ContigStringMap {
+    /// Returns a mutable reference to the indexed map of contig names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::StringMaps;
+    ///
+    /// let mut string_maps = StringMaps::default();
+    /// string_maps.contigs_mut().insert(String::from("sq0"));
+    ///
+    /// assert_eq!(string_maps.contigs().get_index_of("sq0"), Some(0));
+    /// ```
+    pub fn contigs_mut(&mut self) -> &mut ContigStringMap {
         &mut self.contig_string_map
     }
 
@@ -194,6 +218,11 @@ fn insert(string_map: &mut StringMap, id: &str, idx: Option<usize>) -> Result<()
             if actual != expected {
                 return Err(ParseError::StringMapPositionMismatch(actual, expected));
             }
+        } else if let Some(entry) = string_map.get_index(i) {
+            return Err(ParseError::StringMapPositionMismatch(
+                (i, id.into()),
+                (i, entry.into()),
+            ));
         } else {
             string_map.insert_at(i, id.into());
         }
@@ -403,6 +432,34 @@ mod tests {
                 (1, String::from("DP"))
             ))
         );
+
+        let s = r#"##fileformat=VCFv4.3
+##INFO=<ID=DP,Number=1,Type=Integer,Description="Combined depth across samples",IDX=1>
+##FILTER=<ID=q10,Description="Quality below 10",IDX=1>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO	FORMAT	sample0
+"#;
+
+        assert_eq!(
+            s.parse::<StringMaps>(),
+            Err(ParseError::StringMapPositionMismatch(
+                (1, String::from("q10")),
+                (1, String::from("DP"))
+            ))
+        );
+
+        let s = r#"##fileformat=VCFv4.3
+##INFO=<ID=DP,Number=1,Type=Integer,Description="Combined depth across samples">
+##FILTER=<ID=q10,Description="Quality below 10",IDX=1>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO	FORMAT	sample0
+"#;
+
+        assert_eq!(
+            s.parse::<StringMaps>(),
+            Err(ParseError::StringMapPositionMismatch(
+                (1, String::from("q10")),
+                (1, String::from("DP"))
+            ))
+        );
     }
 
     #[test]
@@ -43,7 +43,21 @@ impl StringMap {
             .and_then(|i| self.get_index(i).map(|entry| (i, entry)))
     }
 
-    #[doc(hidden)]
+    /// Inserts an entry.
+    ///
+    /// If the entry does not exist, it is appended to the end of the map. Otherwise, its position
+    /// is kept, and the previous entry is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::string_maps::StringMap;
+    ///
+    /// let mut string_map = StringMap::default();
+    /// assert!(string_map.insert(String::from("PASS")).is_none());
+    /// assert_eq!(string_map.insert(String::from("PASS")), Some(String::from("PASS")));
+    /// assert_eq!(string_map.get_index_of("PASS"), Some(0));
+    /// ```
     pub fn insert(&mut self, value: String) -> Option<String> {
         self.insert_full(value).1
     }
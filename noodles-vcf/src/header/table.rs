@@ -0,0 +1,450 @@
+//! VCF header structured record table.
+
+use super::{
+    record::{
+        key,
+        value::{
+            map::{self, tag, OtherFields},
+            Collection, Map,
+        },
+    },
+    Header,
+};
+
+/// A VCF header structured record table row.
+///
+/// A row is a uniform representation of a structured header record, e.g., `##INFO=<ID=DP,...>`,
+/// as its key (kind), ID, and list of fields. Fields are listed in the order they are written,
+/// excluding the ID, and values are unescaped.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Row {
+    kind: String,
+    id: String,
+    fields: Vec<(String, String)>,
+}
+
+impl Row {
+    /// Creates a VCF header structured record table row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::table::Row;
+    ///
+    /// let row = Row::new(
+    ///     "FILTER",
+    ///     "PASS",
+    ///     vec![(String::from("Description"), String::from("All filters passed"))],
+    /// );
+    /// ```
+    pub fn new<K, I>(kind: K, id: I, fields: Vec<(String, String)>) -> Self
+    where
+        K: Into<String>,
+        I: Into<String>,
+    {
+        Self {
+            kind: kind.into(),
+            id: id.into(),
+            fields,
+        }
+    }
+
+    /// Returns the kind of record, i.e., the record key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::table::Row;
+    /// let row = Row::new("FILTER", "PASS", Vec::new());
+    /// assert_eq!(row.kind(), "FILTER");
+    /// ```
+    pub fn kind(&self) -> &str {
+        &self.kind
+    }
+
+    /// Returns the record ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::table::Row;
+    /// let row = Row::new("FILTER", "PASS", Vec::new());
+    /// assert_eq!(row.id(), "PASS");
+    /// ```
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the record fields, excluding the ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::table::Row;
+    /// let row = Row::new("FILTER", "PASS", Vec::new());
+    /// assert!(row.fields().is_empty());
+    /// ```
+    pub fn fields(&self) -> &[(String, String)] {
+        &self.fields
+    }
+
+    /// Returns the value of the field with the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::table::Row;
+    ///
+    /// let row = Row::new(
+    ///     "FILTER",
+    ///     "PASS",
+    ///     vec![(String::from("Description"), String::from("All filters passed"))],
+    /// );
+    ///
+    /// assert_eq!(row.get("Description"), Some("All filters passed"));
+    /// assert!(row.get("Source").is_none());
+    /// ```
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// A VCF header structured record table.
+///
+/// This is a flat list of all structured records in a header, i.e., `INFO`, `FILTER`, `FORMAT`,
+/// `ALT`, `contig`, and other structured records (e.g., `META` and `SAMPLE`), in that order. It
+/// can be used to audit or compare header metadata across files.
+///
+/// With the `serde` feature enabled, the table can be serialized, e.g., to JSON.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{
+///     self as vcf,
+///     header::{
+///         record::value::{map::Filter, Map},
+///         table::Table,
+///     },
+/// };
+///
+/// let header = vcf::Header::builder()
+///     .add_filter("PASS", Map::<Filter>::pass())
+///     .build();
+///
+/// let table = Table::from(&header);
+///
+/// assert_eq!(table.len(), 1);
+///
+/// let row = &table.rows()[0];
+/// assert_eq!(row.kind(), "FILTER");
+/// assert_eq!(row.id(), "PASS");
+/// assert_eq!(row.get("Description"), Some("All filters passed"));
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize, serde::Serialize),
+    serde(transparent)
+)]
+pub struct Table {
+    rows: Vec<Row>,
+}
+
+impl Table {
+    /// Returns the number of rows in the table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::table::Table;
+    /// let table = Table::default();
+    /// assert_eq!(table.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns whether there are no rows in the table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::table::Table;
+    /// let table = Table::default();
+    /// assert!(table.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Returns the rows in the table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::table::Table;
+    /// let table = Table::default();
+    /// assert!(table.rows().is_empty());
+    /// ```
+    pub fn rows(&self) -> &[Row] {
+        &self.rows
+    }
+
+    /// Returns an iterator over rows of the given kind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::table::Table;
+    /// let table = Table::default();
+    /// assert!(table.rows_of_kind("INFO").next().is_none());
+    /// ```
+    pub fn rows_of_kind<'a>(&'a self, kind: &'a str) -> impl Iterator<Item = &'a Row> + 'a {
+        self.rows.iter().filter(move |row| row.kind() == kind)
+    }
+}
+
+impl From<Vec<Row>> for Table {
+    fn from(rows: Vec<Row>) -> Self {
+        Self { rows }
+    }
+}
+
+impl From<&Header> for Table {
+    fn from(header: &Header) -> Self {
+        let mut rows = Vec::new();
+
+        for (id, info) in header.infos() {
+            let mut fields = vec![
+                field(tag::NUMBER, info_number_to_string(info.number())),
+                field(tag::TYPE, info.ty()),
+                field(tag::DESCRIPTION, info.description()),
+            ];
+
+            extend_other_fields(&mut fields, info);
+
+            rows.push(Row::new(key::INFO.as_ref(), id.as_str(), fields));
+        }
+
+        for (id, filter) in header.filters() {
+            let mut fields = vec![field(tag::DESCRIPTION, filter.description())];
+            extend_other_fields(&mut fields, filter);
+            rows.push(Row::new(key::FILTER.as_ref(), id.as_str(), fields));
+        }
+
+        for (id, format) in header.formats() {
+            let mut fields = vec![
+                field(tag::NUMBER, format_number_to_string(format.number())),
+                field(tag::TYPE, format.ty()),
+                field(tag::DESCRIPTION, format.description()),
+            ];
+
+            extend_other_fields(&mut fields, format);
+
+            rows.push(Row::new(key::FORMAT.as_ref(), id.as_str(), fields));
+        }
+
+        for (id, alternative_allele) in header.alternative_alleles() {
+            let mut fields = vec![field(tag::DESCRIPTION, alternative_allele.description())];
+            extend_other_fields(&mut fields, alternative_allele);
+            rows.push(Row::new(
+                key::ALTERNATIVE_ALLELE.as_ref(),
+                id.as_str(),
+                fields,
+            ));
+        }
+
+        for (id, contig) in header.contigs() {
+            let mut fields = Vec::new();
+
+            if let Some(length) = contig.length() {
+                fields.push(field(map::contig::tag::LENGTH, length.to_string()));
+            }
+
+            if let Some(md5) = contig.md5() {
+                fields.push(field(map::contig::tag::MD5, md5));
+            }
+
+            if let Some(url) = contig.url() {
+                fields.push(field(map::contig::tag::URL, url));
+            }
+
+            extend_other_fields(&mut fields, contig);
+
+            rows.push(Row::new(key::CONTIG.as_ref(), id.as_str(), fields));
+        }
+
+        for (key, collection) in header.other_records() {
+            if let Collection::Structured(maps) = collection {
+                for (id, map) in maps {
+                    let mut fields = Vec::new();
+                    extend_other_fields(&mut fields, map);
+                    rows.push(Row::new(key.as_ref(), id.as_str(), fields));
+                }
+            }
+        }
+
+        Self { rows }
+    }
+}
+
+fn field<K, V>(key: K, value: V) -> (String, String)
+where
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
+    (key.as_ref().into(), value.as_ref().into())
+}
+
+fn extend_other_fields<I>(fields: &mut Vec<(String, String)>, map: &Map<I>)
+where
+    I: map::Inner,
+{
+    let other_fields: &OtherFields<I::StandardTag> = map.other_fields();
+    fields.extend(other_fields.iter().map(|(k, v)| field(k, v)));
+}
+
+fn info_number_to_string(number: map::info::Number) -> String {
+    use map::info::Number;
+
+    match number {
+        Number::Count(n) => n.to_string(),
+        Number::AlternateBases => String::from("A"),
+        Number::ReferenceAlternateBases => String::from("R"),
+        Number::Samples => String::from("G"),
+        Number::Unknown => String::from("."),
+    }
+}
+
+fn format_number_to_string(number: map::format::Number) -> String {
+    use map::format::Number;
+
+    match number {
+        Number::Count(n) => n.to_string(),
+        Number::AlternateBases => String::from("A"),
+        Number::ReferenceAlternateBases => String::from("R"),
+        Number::Samples => String::from("G"),
+        Number::LocalAlternateBases => String::from("LA"),
+        Number::LocalReferenceAlternateBases => String::from("LR"),
+        Number::LocalSamples => String::from("LG"),
+        Number::Ploidy => String::from("P"),
+        Number::BaseModifications => String::from("M"),
+        Number::Unknown => String::from("."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_header() -> Result<Header, Box<dyn std::error::Error>> {
+        const DATA: &str = r#"##fileformat=VCFv4.4
+##INFO=<ID=DP,Number=1,Type=Integer,Description="Combined depth across samples">
+##FILTER=<ID=q10,Description="Quality below 10">
+##FORMAT=<ID=AD,Number=R,Type=Integer,Description="Read depth for each allele">
+##ALT=<ID=DEL,Description="Deletion">
+##contig=<ID=sq0,length=8,assembly=NOODLES>
+##META=<ID=Assay,Type=String,Number=.,Values=[WholeGenome, Exome]>
+##SAMPLE=<ID=sample0,Assay=WholeGenome>
+##source=noodles
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO	FORMAT	sample0
+"#;
+
+        Ok(DATA.parse()?)
+    }
+
+    fn f(key: &str, value: &str) -> (String, String) {
+        (key.into(), value.into())
+    }
+
+    #[test]
+    fn test_from_header_for_table() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+        let table = Table::from(&header);
+
+        let expected = Table::from(vec![
+            Row::new(
+                "INFO",
+                "DP",
+                vec![
+                    f("Number", "1"),
+                    f("Type", "Integer"),
+                    f("Description", "Combined depth across samples"),
+                ],
+            ),
+            Row::new("FILTER", "q10", vec![f("Description", "Quality below 10")]),
+            Row::new(
+                "FORMAT",
+                "AD",
+                vec![
+                    f("Number", "R"),
+                    f("Type", "Integer"),
+                    f("Description", "Read depth for each allele"),
+                ],
+            ),
+            Row::new("ALT", "DEL", vec![f("Description", "Deletion")]),
+            Row::new(
+                "contig",
+                "sq0",
+                vec![f("length", "8"), f("assembly", "NOODLES")],
+            ),
+            Row::new(
+                "META",
+                "Assay",
+                vec![
+                    f("Type", "String"),
+                    f("Number", "."),
+                    f("Values", "[WholeGenome, Exome]"),
+                ],
+            ),
+            Row::new("SAMPLE", "sample0", vec![f("Assay", "WholeGenome")]),
+        ]);
+
+        assert_eq!(table, expected);
+
+        assert_eq!(table.rows_of_kind("SAMPLE").count(), 1);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize() {
+        use serde_test::{assert_tokens, Token};
+
+        let table = Table::from(vec![Row::new(
+            "FILTER",
+            "q10",
+            vec![f("Description", "Quality below 10")],
+        )]);
+
+        assert_tokens(
+            &table,
+            &[
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "Row",
+                    len: 3,
+                },
+                Token::Str("kind"),
+                Token::Str("FILTER"),
+                Token::Str("id"),
+                Token::Str("q10"),
+                Token::Str("fields"),
+                Token::Seq { len: Some(1) },
+                Token::Tuple { len: 2 },
+                Token::Str("Description"),
+                Token::Str("Quality below 10"),
+                Token::TupleEnd,
+                Token::SeqEnd,
+                Token::StructEnd,
+                Token::SeqEnd,
+            ],
+        );
+    }
+}
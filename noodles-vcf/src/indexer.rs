@@ -1,4 +1,8 @@
-use std::{fs::File, io, path::Path};
+use std::{
+    fs::File,
+    io::{self, Read},
+    path::Path,
+};
 
 use noodles_bgzf as bgzf;
 use noodles_csi::{self as csi, binning_index::index::reference_sequence::bin::Chunk};
@@ -17,7 +21,24 @@ pub fn index<P>(src: P) -> io::Result<tabix::Index>
 where
     P: AsRef<Path>,
 {
-    let mut reader = File::open(src).map(bgzf::Reader::new).map(Reader::new)?;
+    File::open(src).and_then(index_from_reader)
+}
+
+/// Indexes bgzipped-compressed VCF data from a reader.
+///
+/// The reader must be positioned at the start of the bgzipped-compressed VCF file.
+///
+/// ```no_run
+/// use std::fs::File;
+/// use noodles_vcf as vcf;
+/// let index = File::open("sample.vcf.gz").and_then(vcf::index_from_reader)?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn index_from_reader<R>(reader: R) -> io::Result<tabix::Index>
+where
+    R: Read,
+{
+    let mut reader = Reader::new(bgzf::Reader::new(reader));
     let header = reader.read_header()?;
 
     let mut indexer = tabix::index::Indexer::default();
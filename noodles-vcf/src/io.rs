@@ -1,11 +1,13 @@
 //! VCF I/O.
 
+pub mod appender;
 mod compression_method;
 pub mod indexed_reader;
 pub mod reader;
+mod reheader;
 pub mod writer;
 
 pub use self::{
-    compression_method::CompressionMethod, indexed_reader::IndexedReader, reader::Reader,
-    writer::Writer,
+    appender::Appender, compression_method::CompressionMethod, indexed_reader::IndexedReader,
+    reader::Reader, reheader::reheader, writer::Writer,
 };
@@ -0,0 +1,320 @@
+//! VCF appender.
+
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, Read, Seek},
+    path::{Path, PathBuf},
+};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+use noodles_csi::{
+    binning_index::index::{header::ReferenceSequenceNames, reference_sequence::bin::Chunk},
+    BinningIndex,
+};
+use noodles_tabix as tabix;
+
+use super::{indexed_reader::build_index_src, Reader, Writer};
+use crate::{variant::io::Write as _, Header, Record};
+
+/// The last record in a bgzipped VCF file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LastRecord {
+    reference_sequence_name: String,
+    variant_start: Position,
+    start_position: bgzf::VirtualPosition,
+}
+
+impl LastRecord {
+    /// Returns the reference sequence name.
+    pub fn reference_sequence_name(&self) -> &str {
+        &self.reference_sequence_name
+    }
+
+    /// Returns the variant start position.
+    pub fn variant_start(&self) -> Position {
+        self.variant_start
+    }
+
+    /// Returns the virtual position of the start of the record.
+    pub fn start_position(&self) -> bgzf::VirtualPosition {
+        self.start_position
+    }
+}
+
+/// Finds the last record in a bgzipped VCF file using its index.
+///
+/// The reader is expected to be positioned after the header. It seeks to the start of the last
+/// linear bin in the index and reads the remaining records, so only the tail of the file is read.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::fs::File;
+/// use noodles_bgzf as bgzf;
+/// use noodles_tabix as tabix;
+/// use noodles_vcf::{self as vcf, io::appender::find_last_record};
+///
+/// let mut reader = File::open("sample.vcf.gz")
+///     .map(bgzf::Reader::new)
+///     .map(vcf::io::Reader::new)?;
+/// reader.read_header()?;
+///
+/// let index = tabix::read("sample.vcf.gz.tbi")?;
+/// let last_record = find_last_record(&mut reader, &index)?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn find_last_record<R, I>(
+    reader: &mut Reader<bgzf::Reader<R>>,
+    index: &I,
+) -> io::Result<Option<LastRecord>>
+where
+    R: Read + Seek,
+    I: BinningIndex,
+{
+    if let Some(pos) = index.last_first_record_start_position() {
+        reader.get_mut().seek(pos)?;
+    }
+
+    let mut record = Record::default();
+    let mut last_record = None;
+
+    loop {
+        let start_position = reader.get_ref().virtual_position();
+
+        if reader.read_record(&mut record)? == 0 {
+            break;
+        }
+
+        let variant_start = record
+            .variant_start()
+            .transpose()?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing position"))?;
+
+        last_record = Some(LastRecord {
+            reference_sequence_name: record.reference_sequence_name().into(),
+            variant_start,
+            start_position,
+        });
+    }
+
+    Ok(last_record)
+}
+
+/// A VCF appender.
+///
+/// This appends coordinate-sorted records to an existing bgzipped VCF file with an associated
+/// tabix index (`<src>.tbi`) and updates the index with the new records, without rewriting or
+/// reindexing the existing records.
+///
+/// Appended records must sort after the last record in the file: records on the same reference
+/// sequence must not start before it, and records on a different reference sequence must be on
+/// one that has no records yet.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_vcf as vcf;
+///
+/// let mut appender = vcf::io::Appender::open("sample.vcf.gz")?;
+///
+/// let record = vcf::variant::RecordBuf::default();
+/// appender.append_record(&record)?;
+///
+/// appender.finish()?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub struct Appender {
+    writer: Writer<bgzf::Writer<File>>,
+    header: Header,
+    indexer: tabix::index::Indexer,
+    index_dst: PathBuf,
+    reference_sequence_names: ReferenceSequenceNames,
+    last_record: Option<(String, Position)>,
+}
+
+impl Appender {
+    /// Opens an indexed bgzipped VCF file for appending.
+    ///
+    /// This reads the header and the tabix index (`<src>.tbi`) and locates the last record.
+    pub fn open<P>(src: P) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let src = src.as_ref();
+
+        let index_dst = build_index_src(src, "tbi");
+        let index = tabix::read(&index_dst)?;
+
+        let reference_sequence_names = index
+            .header()
+            .map(|header| header.reference_sequence_names().clone())
+            .unwrap_or_default();
+
+        let mut reader = File::open(src).map(bgzf::Reader::new).map(Reader::new)?;
+        let header = reader.read_header()?;
+        let last_record = find_last_record(&mut reader, &index)?;
+
+        let file = OpenOptions::new().read(true).write(true).open(src)?;
+        let writer = bgzf::writer::Builder::default()
+            .build_for_append(file)
+            .map(Writer::new)?;
+
+        Ok(Self {
+            writer,
+            header,
+            indexer: tabix::index::Indexer::from(index),
+            index_dst,
+            reference_sequence_names,
+            last_record: last_record
+                .map(|record| (record.reference_sequence_name, record.variant_start)),
+        })
+    }
+
+    /// Returns the VCF header.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Appends a variant record.
+    ///
+    /// This returns an error if the record sorts before the last written record.
+    pub fn append_record(&mut self, record: &dyn crate::variant::Record) -> io::Result<()> {
+        let reference_sequence_name = record.reference_sequence_name(&self.header)?;
+
+        let start = record
+            .variant_start()
+            .transpose()?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing position"))?;
+
+        let end = record.variant_end(&self.header)?;
+
+        let is_in_order = match &self.last_record {
+            Some((name, position)) if name == reference_sequence_name => start >= *position,
+            _ => !self
+                .reference_sequence_names
+                .contains(reference_sequence_name),
+        };
+
+        if !is_in_order {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "record is out of order",
+            ));
+        }
+
+        let start_position = self.writer.get_ref().virtual_position();
+        self.writer.write_variant_record(&self.header, record)?;
+        let end_position = self.writer.get_ref().virtual_position();
+
+        self.indexer.add_record(
+            reference_sequence_name,
+            start,
+            end,
+            Chunk::new(start_position, end_position),
+        )?;
+
+        self.reference_sequence_names
+            .insert(reference_sequence_name.into());
+        self.last_record = Some((reference_sequence_name.into(), start));
+
+        Ok(())
+    }
+
+    /// Finishes the bgzipped VCF file and writes the updated index.
+    ///
+    /// This writes the BGZF EOF block and overwrites the associated tabix index (`<src>.tbi`). The
+    /// updated index is returned.
+    pub fn finish(mut self) -> io::Result<tabix::Index> {
+        self.writer.get_mut().try_finish()?;
+
+        let index = self.indexer.build();
+        tabix::write(&self.index_dst, &index)?;
+
+        Ok(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::variant::RecordBuf;
+
+    #[test]
+    fn test_append_record() -> Result<(), Box<dyn std::error::Error>> {
+        let dst = std::env::temp_dir().join(format!(
+            "noodles-vcf-appender-{}.vcf.gz",
+            std::process::id()
+        ));
+        let index_dst = build_index_src(&dst, "tbi");
+
+        let header = Header::default();
+
+        let mut writer = File::create(&dst).map(bgzf::Writer::new).map(Writer::new)?;
+        writer.write_header(&header)?;
+
+        for variant_start in [8, 13] {
+            let record = RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::try_from(variant_start)?)
+                .set_reference_bases("A")
+                .build();
+
+            writer.write_variant_record(&header, &record)?;
+        }
+
+        writer.get_mut().try_finish()?;
+        drop(writer);
+
+        tabix::write(&index_dst, &crate::index(&dst)?)?;
+
+        let mut appender = Appender::open(&dst)?;
+
+        for (reference_sequence_name, variant_start, is_ok) in [
+            ("sq0", 5, false),
+            ("sq0", 21, true),
+            ("sq1", 1, true),
+            ("sq0", 34, false),
+        ] {
+            let record = RecordBuf::builder()
+                .set_reference_sequence_name(reference_sequence_name)
+                .set_variant_start(Position::try_from(variant_start)?)
+                .set_reference_bases("A")
+                .build();
+
+            assert_eq!(appender.append_record(&record).is_ok(), is_ok);
+        }
+
+        let actual = appender.finish()?;
+
+        let expected = crate::index(&dst)?;
+        assert_eq!(
+            actual
+                .header()
+                .map(|header| header.reference_sequence_names()),
+            expected
+                .header()
+                .map(|header| header.reference_sequence_names())
+        );
+
+        let mut reader = File::open(&dst).map(bgzf::Reader::new).map(Reader::new)?;
+        reader.read_header()?;
+        let records: Vec<_> = reader.records().collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 4);
+
+        let mut reader = File::open(&dst).map(bgzf::Reader::new).map(Reader::new)?;
+        reader.read_header()?;
+        let last_record = find_last_record(&mut reader, &actual)?;
+        assert_eq!(
+            last_record.map(|record| (record.reference_sequence_name, record.variant_start)),
+            Some((String::from("sq1"), Position::MIN))
+        );
+
+        fs::remove_file(dst)?;
+        fs::remove_file(index_dst)?;
+
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@
 
 mod builder;
 
+pub(crate) use self::builder::build_index_src;
 pub use self::builder::Builder;
 
 use std::io::{self, BufRead, Read};
@@ -17,6 +18,28 @@ use super::{
 use crate::{variant::RecordBuf, Header, Record};
 
 /// An indexed VCF reader.
+///
+/// The index can be a tabix or CSI index. When built from a path, the associated index is read
+/// from `<src>.tbi` or, if that does not exist, `<src>.csi`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_vcf as vcf;
+///
+/// let mut reader = vcf::io::indexed_reader::Builder::default()
+///     .build_from_path("sample.vcf.gz")?;
+///
+/// let header = reader.read_header()?;
+///
+/// let region = "sq0:100-200".parse()?;
+///
+/// for result in reader.query(&header, &region)? {
+///     let record = result?;
+///     // ...
+/// }
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
 pub struct IndexedReader<R> {
     inner: Reader<R>,
     index: Box<dyn BinningIndex>,
@@ -107,3 +130,89 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, io::Cursor};
+
+    use noodles_csi as csi;
+
+    use super::*;
+
+    fn build_data() -> io::Result<Vec<u8>> {
+        const DATA: &[u8] = b"##fileformat=VCFv4.4
+##contig=<ID=sq0,length=100000>
+##contig=<ID=sq1,length=100000>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t8\t.\tA\t.\t.\tPASS\t.
+sq0\t100\t.\tA\t.\t.\tPASS\t.
+sq0\t150\t.\tACGT\t.\t.\tPASS\t.
+sq0\t40000\t.\tA\t.\t.\tPASS\t.
+sq1\t120\t.\tA\t.\t.\tPASS\t.
+";
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        io::Write::write_all(&mut writer, DATA)?;
+        writer.finish()
+    }
+
+    fn query_positions<I>(data: &[u8], index: I, region: &str) -> io::Result<Vec<usize>>
+    where
+        I: BinningIndex + 'static,
+    {
+        let mut reader = Builder::default()
+            .set_index(index)
+            .build_from_reader(Cursor::new(data))?;
+
+        let header = reader.read_header()?;
+
+        let region = region
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        reader
+            .query(&header, &region)?
+            .map(|result| {
+                result.and_then(|record| {
+                    record
+                        .variant_start()
+                        .transpose()
+                        .map(|position| position.map(usize::from).unwrap_or_default())
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_query() -> io::Result<()> {
+        let data = build_data()?;
+
+        let src = env::temp_dir().join(format!(
+            "noodles-vcf-indexed-reader-test-query-{}.vcf.gz",
+            std::process::id()
+        ));
+
+        fs::write(&src, &data)?;
+        let result = crate::index(&src);
+        fs::remove_file(&src)?;
+
+        let tabix_index = result?;
+        let csi_index = csi::Index::from(tabix_index.clone());
+
+        for (region, expected) in [
+            ("sq0:100-200", &[100, 150][..]),
+            ("sq0:152-153", &[150][..]),
+            ("sq0", &[8, 100, 150, 40000][..]),
+            ("sq1:1-100", &[][..]),
+            ("sq1", &[120][..]),
+        ] {
+            assert_eq!(
+                query_positions(&data, tabix_index.clone(), region)?,
+                expected
+            );
+            assert_eq!(query_positions(&data, csi_index.clone(), region)?, expected);
+        }
+
+        Ok(())
+    }
+}
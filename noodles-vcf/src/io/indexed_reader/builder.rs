@@ -105,7 +105,7 @@ where
     }
 }
 
-fn build_index_src<P, S>(src: P, ext: S) -> PathBuf
+pub(crate) fn build_index_src<P, S>(src: P, ext: S) -> PathBuf
 where
     P: AsRef<Path>,
     S: AsRef<OsStr>,
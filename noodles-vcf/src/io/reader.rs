@@ -2,18 +2,25 @@
 
 mod builder;
 mod header;
+mod lending_records;
 pub(crate) mod query;
 pub(crate) mod record;
 pub mod record_buf;
 mod record_bufs;
+mod sample_selection;
+pub mod warning;
 
 use self::record::read_record;
 pub(crate) use self::record_buf::parse_record_buf;
-pub use self::{builder::Builder, query::Query, record_bufs::RecordBufs};
+pub use self::{
+    builder::Builder, lending_records::LendingRecords, query::Query, record_bufs::RecordBufs,
+    sample_selection::SampleSelection, warning::Warning,
+};
 
 use std::{
+    borrow::Cow,
     io::{self, BufRead},
-    iter, str,
+    iter, mem, str,
 };
 
 use noodles_bgzf as bgzf;
@@ -50,6 +57,8 @@ use crate::{variant::RecordBuf, Header, Record};
 pub struct Reader<R> {
     inner: R,
     buf: String,
+    line_number: usize,
+    warnings: Option<Vec<Warning>>,
 }
 
 impl<R> Reader<R> {
@@ -118,6 +127,8 @@ where
         Self {
             inner,
             buf: String::new(),
+            line_number: 0,
+            warnings: None,
         }
     }
 
@@ -144,7 +155,59 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn read_header(&mut self) -> io::Result<Header> {
-        read_header(&mut self.inner)
+        let (header, line_count) = read_header(&mut self.inner)?;
+        self.line_number = line_count;
+        Ok(header)
+    }
+
+    /// Returns the warnings collected while reading records.
+    ///
+    /// Warnings are only collected when enabled using
+    /// [`Builder::set_collect_warnings`]. Otherwise, this is always empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, io::reader::warning::Kind};
+    ///
+    /// let data = b"##fileformat=VCFv4.3
+    /// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+    /// sq0\t1\tr0;r0\tA\t.\t.\tPASS\t.
+    /// ";
+    ///
+    /// let mut reader = vcf::io::reader::Builder::default()
+    ///     .set_collect_warnings(true)
+    ///     .build_from_reader(&data[..])?;
+    ///
+    /// let header = reader.read_header()?;
+    ///
+    /// let mut record = vcf::variant::RecordBuf::default();
+    /// reader.read_record_buf(&header, &mut record)?;
+    /// assert_eq!(record.ids().as_ref().len(), 1);
+    ///
+    /// let warnings = reader.warnings();
+    /// assert_eq!(warnings.len(), 1);
+    /// assert_eq!(warnings[0].line_number(), 3);
+    /// assert_eq!(warnings[0].kind(), &Kind::DuplicateId(String::from("r0")));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn warnings(&self) -> &[Warning] {
+        self.warnings.as_deref().unwrap_or_default()
+    }
+
+    /// Removes and returns the warnings collected while reading records.
+    ///
+    /// This can be used to periodically drain warnings when reading large files.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let mut reader = vcf::io::Reader::new(&[][..]);
+    /// assert!(reader.take_warnings().is_empty());
+    /// ```
+    pub fn take_warnings(&mut self) -> Vec<Warning> {
+        self.warnings.as_mut().map(mem::take).unwrap_or_default()
     }
 
     /// Reads a single VCF record.
@@ -187,7 +250,11 @@ where
         match read_line(&mut self.inner, &mut self.buf)? {
             0 => Ok(0),
             n => {
-                parse_record_buf(&self.buf, header, record)
+                self.line_number += 1;
+
+                let line = inspect_line(self.line_number, &self.buf, &mut self.warnings);
+
+                parse_record_buf(&line, header, record)
                     .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
                 Ok(n)
@@ -195,6 +262,63 @@ where
         }
     }
 
+    /// Reads a single record, only parsing the selected sample columns.
+    ///
+    /// This is the same as [`Self::read_record_buf`], but sample columns that are not in the
+    /// given selection are skipped. The samples of the resulting record are described by the
+    /// header of the selection ([`SampleSelection::header`]), not the given header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, io::reader::SampleSelection};
+    ///
+    /// let data = b"##fileformat=VCFv4.3
+    /// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\tsample1\tsample2
+    /// sq0\t1\t.\tA\t.\t.\tPASS\t.\tGT\t0|0\t0|1\t1|1
+    /// ";
+    ///
+    /// let mut reader = vcf::io::Reader::new(&data[..]);
+    /// let header = reader.read_header()?;
+    ///
+    /// let selection = SampleSelection::new(&header, ["sample1"])?;
+    ///
+    /// let mut record = vcf::variant::RecordBuf::default();
+    /// reader.read_record_buf_with_sample_selection(&header, &selection, &mut record)?;
+    ///
+    /// assert_eq!(record.samples().values().count(), 1);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn read_record_buf_with_sample_selection(
+        &mut self,
+        header: &Header,
+        sample_selection: &SampleSelection,
+        record: &mut RecordBuf,
+    ) -> io::Result<usize> {
+        use self::record_buf::parse_record_buf_with_sample_selection;
+
+        self.buf.clear();
+
+        match read_line(&mut self.inner, &mut self.buf)? {
+            0 => Ok(0),
+            n => {
+                self.line_number += 1;
+
+                let line = inspect_line(self.line_number, &self.buf, &mut self.warnings);
+
+                parse_record_buf_with_sample_selection(
+                    &line,
+                    header,
+                    Some(sample_selection),
+                    record,
+                )
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+                Ok(n)
+            }
+        }
+    }
+
     /// Returns an iterator over records starting from the current stream position.
     ///
     /// The stream is expected to be directly after the header or at the start of another record.
@@ -250,7 +374,29 @@ where
     /// # Ok::<_, std::io::Error>(())
     /// ```
     pub fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
-        read_record(&mut self.inner, record)
+        if self.warnings.is_none() {
+            let n = read_record(&mut self.inner, record)?;
+
+            if n > 0 {
+                self.line_number += 1;
+            }
+
+            return Ok(n);
+        }
+
+        self.buf.clear();
+
+        match read_line(&mut self.inner, &mut self.buf)? {
+            0 => Ok(0),
+            n => {
+                self.line_number += 1;
+
+                let line = inspect_line(self.line_number, &self.buf, &mut self.warnings);
+                read_record(&mut line.as_bytes(), record)?;
+
+                Ok(n)
+            }
+        }
     }
 
     /// Returns an iterator over records.
@@ -285,6 +431,42 @@ where
             Err(e) => Some(Err(e)),
         })
     }
+
+    /// Returns a lending iterator over records.
+    ///
+    /// This reuses a single record buffer, avoiding an allocation per record. See
+    /// [`LendingRecords`].
+    ///
+    /// The stream is expected to be directly after the header or at the start of another record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    ///
+    /// const DATA: &[u8] = b"##fileformat=VCFv4.3
+    /// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+    /// sq0\t1\t.\tA\t.\t.\tPASS\t.
+    /// sq0\t2\t.\tC\t.\t.\tPASS\t.
+    /// ";
+    ///
+    /// let mut reader = vcf::io::Reader::new(DATA);
+    /// reader.read_header()?;
+    ///
+    /// let mut records = reader.lending_records();
+    /// let mut n = 0;
+    ///
+    /// while let Some(record) = records.next_record().transpose()? {
+    ///     assert_eq!(record.reference_sequence_name(), "sq0");
+    ///     n += 1;
+    /// }
+    ///
+    /// assert_eq!(n, 2);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn lending_records(&mut self) -> LendingRecords<'_, R> {
+        LendingRecords::new(self)
+    }
 }
 
 impl<R> Reader<R>
@@ -360,6 +542,17 @@ where
     }
 }
 
+fn inspect_line<'a>(
+    line_number: usize,
+    line: &'a str,
+    warnings: &mut Option<Vec<Warning>>,
+) -> Cow<'a, str> {
+    match warnings {
+        Some(warnings) => warning::inspect(line_number, line, warnings),
+        None => Cow::from(line),
+    }
+}
+
 // Reads all bytes until a line feed ('\n') or EOF is reached.
 //
 // The buffer will not include the trailing newline ('\n' or '\r\n').
@@ -440,6 +633,41 @@ sq0\t1\t.\tA\t.\t.\tPASS\t.
         Ok(())
     }
 
+    #[test]
+    fn test_read_record_with_warnings() -> io::Result<()> {
+        use self::warning::Kind;
+
+        static DATA: &[u8] = b"\
+##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\tA\t.\t.\tPASS\t.
+sq0\t2\tr0;r0\tA\t.\t.\tPASS\tNOTE=a b\t
+";
+
+        let mut reader = Builder::default()
+            .set_collect_warnings(true)
+            .build_from_reader(DATA)?;
+
+        reader.read_header()?;
+
+        let records: Vec<_> = reader.records().collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].ids().as_ref(), "r0");
+
+        assert_eq!(
+            reader.take_warnings(),
+            [
+                Warning::new(4, Kind::TrailingTab),
+                Warning::new(4, Kind::DuplicateId(String::from("r0"))),
+                Warning::new(4, Kind::SpaceInInfo),
+            ]
+        );
+
+        assert!(reader.warnings().is_empty());
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_line() -> io::Result<()> {
         let mut buf = String::new();
@@ -1,6 +1,7 @@
 use std::{
     fs::File,
     io::{self, BufRead, BufReader, Read},
+    num::NonZeroUsize,
     path::Path,
 };
 
@@ -13,6 +14,8 @@ use crate::io::CompressionMethod;
 #[derive(Debug, Default)]
 pub struct Builder {
     compression_method: Option<CompressionMethod>,
+    worker_count: Option<NonZeroUsize>,
+    collect_warnings: bool,
 }
 
 impl Builder {
@@ -29,6 +32,44 @@ impl Builder {
         self
     }
 
+    /// Sets the number of worker threads used to decompress BGZF-compressed input.
+    ///
+    /// When set and the input is bgzip-compressed, [`Self::build_from_path`] uses a
+    /// multithreaded BGZF reader, which decompresses blocks in parallel while records are
+    /// parsed. By default, a single-threaded BGZF reader is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_vcf::io::reader::Builder;
+    /// let builder = Builder::default().set_worker_count(NonZeroUsize::MIN);
+    /// ```
+    pub fn set_worker_count(mut self, worker_count: NonZeroUsize) -> Self {
+        self.worker_count = Some(worker_count);
+        self
+    }
+
+    /// Sets whether to collect warnings for recoverable spec violations.
+    ///
+    /// When enabled, records are checked for duplicate IDs, duplicate filters, spaces in INFO,
+    /// and trailing tabs. These are recorded as warnings (see [`Reader::warnings`]) with their
+    /// line numbers, and the record is still read. Duplicate IDs and filters and trailing tabs
+    /// are removed before parsing.
+    ///
+    /// By default, warnings are not collected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::io::reader::Builder;
+    /// let builder = Builder::default().set_collect_warnings(true);
+    /// ```
+    pub fn set_collect_warnings(mut self, collect_warnings: bool) -> Self {
+        self.collect_warnings = collect_warnings;
+        self
+    }
+
     /// Builds a VCF reader from a path.
     ///
     /// By default, the compression method will be autodetected. This can be overridden by using
@@ -55,7 +96,23 @@ impl Builder {
         }
 
         let reader = File::open(src)?;
-        self.build_from_reader(reader)
+
+        match (self.compression_method, self.worker_count) {
+            (Some(CompressionMethod::Bgzf), Some(worker_count)) => {
+                let inner: Box<dyn BufRead> = Box::new(
+                    bgzf::MultithreadedReader::with_worker_count(worker_count, reader),
+                );
+
+                let mut reader = Reader::new(inner);
+
+                if self.collect_warnings {
+                    reader.warnings = Some(Vec::new());
+                }
+
+                Ok(reader)
+            }
+            _ => self.build_from_reader(reader),
+        }
     }
 
     /// Builds a VCF reader from a reader.
@@ -77,6 +134,12 @@ impl Builder {
             Some(CompressionMethod::None) | None => Box::new(BufReader::new(reader)),
         };
 
-        Ok(Reader::new(inner))
+        let mut reader = Reader::new(inner);
+
+        if self.collect_warnings {
+            reader.warnings = Some(Vec::new());
+        }
+
+        Ok(reader)
     }
 }
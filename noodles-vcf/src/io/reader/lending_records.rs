@@ -0,0 +1,39 @@
+use std::io::{self, BufRead};
+
+use super::Reader;
+use crate::Record;
+
+/// A lending iterator over records of a VCF reader.
+///
+/// Unlike an iterator from `Reader::records`, this reuses a single record buffer rather than
+/// yielding an owned record per iteration. A returned record borrows the buffer and is valid until
+/// the next call to [`Self::next_record`].
+///
+/// This is created by calling [`Reader::lending_records`].
+pub struct LendingRecords<'r, R> {
+    reader: &'r mut Reader<R>,
+    record: Record,
+}
+
+impl<'r, R> LendingRecords<'r, R>
+where
+    R: BufRead,
+{
+    pub(super) fn new(reader: &'r mut Reader<R>) -> Self {
+        Self {
+            reader,
+            record: Record::default(),
+        }
+    }
+
+    /// Reads the next record into the record buffer and returns a reference to it.
+    ///
+    /// This returns `None` when the end of the stream is reached.
+    pub fn next_record(&mut self) -> Option<io::Result<&Record>> {
+        match self.reader.read_record(&mut self.record) {
+            Ok(0) => None,
+            Ok(_) => Some(Ok(&self.record)),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
@@ -19,6 +19,7 @@ use self::{
     reference_bases::parse_reference_bases, reference_sequence_name::parse_reference_sequence_name,
     samples::parse_samples,
 };
+use super::SampleSelection;
 use crate::{variant::RecordBuf, Header};
 
 const MISSING: &str = ".";
@@ -76,8 +77,17 @@ impl fmt::Display for ParseError {
 }
 
 pub(crate) fn parse_record_buf(
+    s: &str,
+    header: &Header,
+    record: &mut RecordBuf,
+) -> Result<(), ParseError> {
+    parse_record_buf_with_sample_selection(s, header, None, record)
+}
+
+pub(super) fn parse_record_buf_with_sample_selection(
     mut s: &str,
     header: &Header,
+    sample_selection: Option<&SampleSelection>,
     record: &mut RecordBuf,
 ) -> Result<(), ParseError> {
     let field = next_field(&mut s);
@@ -123,7 +133,8 @@ pub(crate) fn parse_record_buf(
         parse_info(header, field, record.info_mut()).map_err(ParseError::InvalidInfo)?;
     }
 
-    parse_samples(header, s, record.samples_mut()).map_err(ParseError::InvalidSamples)?;
+    parse_samples(header, s, sample_selection, record.samples_mut())
+        .map_err(ParseError::InvalidSamples)?;
 
     Ok(())
 }
@@ -4,7 +4,7 @@ mod values;
 use std::{error, fmt};
 
 use self::{keys::parse_keys, values::parse_values};
-use super::next_field;
+use super::{next_field, SampleSelection};
 use crate::{variant::record_buf::Samples, Header};
 
 /// An error when raw VCF record genotypes fail to parse.
@@ -43,6 +43,7 @@ impl fmt::Display for ParseError {
 pub(super) fn parse_samples(
     header: &Header,
     mut s: &str,
+    sample_selection: Option<&SampleSelection>,
     genotypes: &mut Samples,
 ) -> Result<(), ParseError> {
     genotypes.keys.as_mut().clear();
@@ -66,6 +67,10 @@ pub(super) fn parse_samples(
     let field = next_field(&mut s);
     parse_keys(header, field, &mut genotypes.keys).map_err(ParseError::InvalidKeys)?;
 
+    if let Some(sample_selection) = sample_selection {
+        return parse_selected_values(header, s, sample_selection, genotypes);
+    }
+
     genotypes.values.resize(sample_count, Vec::new());
 
     for values in &mut genotypes.values {
@@ -76,6 +81,35 @@ pub(super) fn parse_samples(
     Ok(())
 }
 
+fn parse_selected_values(
+    header: &Header,
+    mut s: &str,
+    sample_selection: &SampleSelection,
+    genotypes: &mut Samples,
+) -> Result<(), ParseError> {
+    genotypes.values.resize(sample_selection.len(), Vec::new());
+
+    let column_indices = sample_selection.column_indices();
+
+    // Columns after the last selected column are never split.
+    let column_count = column_indices
+        .iter()
+        .rposition(|i| i.is_some())
+        .map(|i| i + 1)
+        .unwrap_or_default();
+
+    for &i in &column_indices[..column_count] {
+        let field = next_field(&mut s);
+
+        if let Some(i) = i {
+            parse_values(header, &genotypes.keys, field, &mut genotypes.values[i])
+                .map_err(ParseError::InvalidValues)?;
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -90,11 +124,11 @@ mod tests {
         let mut genotypes = Samples::default();
 
         let header = Header::default();
-        parse_samples(&header, "", &mut genotypes)?;
+        parse_samples(&header, "", None, &mut genotypes)?;
         assert!(genotypes.is_empty());
 
         let header = Header::builder().add_sample_name("sample0").build();
-        parse_samples(&header, "GT\t0|0", &mut genotypes)?;
+        parse_samples(&header, "GT\t0|0", None, &mut genotypes)?;
         let expected = Samples::new(
             [String::from(key::GENOTYPE)].into_iter().collect(),
             vec![vec![Some(Value::Genotype(
@@ -112,7 +146,7 @@ mod tests {
             .add_sample_name("sample0")
             .add_sample_name("sample1")
             .build();
-        parse_samples(&header, "GQ\t8\t13", &mut genotypes)?;
+        parse_samples(&header, "GQ\t8\t13", None, &mut genotypes)?;
         let expected = Samples::new(
             [String::from(key::CONDITIONAL_GENOTYPE_QUALITY)]
                 .into_iter()
@@ -123,24 +157,67 @@ mod tests {
 
         let header = Header::default();
         assert_eq!(
-            parse_samples(&header, "GT\t0|0", &mut genotypes),
+            parse_samples(&header, "GT\t0|0", None, &mut genotypes),
             Err(ParseError::UnexpectedInput)
         );
 
         let header = Header::builder().add_sample_name("sample0").build();
 
         assert!(matches!(
-            parse_samples(&header, "\t0|0", &mut genotypes),
+            parse_samples(&header, "\t0|0", None, &mut genotypes),
             Err(ParseError::InvalidKeys(_))
         ));
 
         assert!(matches!(
-            parse_samples(&header, "GT:GQ", &mut genotypes),
+            parse_samples(&header, "GT:GQ", None, &mut genotypes),
+            Err(ParseError::InvalidValues(_))
+        ));
+
+        assert!(matches!(
+            parse_samples(&header, "GQ\tndls", None, &mut genotypes),
             Err(ParseError::InvalidValues(_))
         ));
 
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_samples_with_sample_selection() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::variant::{record::samples::keys::key, record_buf::samples::sample::Value};
+
+        let header = Header::builder()
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .add_sample_name("sample2")
+            .build();
+
+        let mut genotypes = Samples::default();
+
+        let sample_selection = SampleSelection::new(&header, ["sample1", "sample0"])?;
+        // The unselected last column is not parsed.
+        parse_samples(
+            &header,
+            "GQ\t8\t13\tndls",
+            Some(&sample_selection),
+            &mut genotypes,
+        )?;
+
+        let expected = Samples::new(
+            [String::from(key::CONDITIONAL_GENOTYPE_QUALITY)]
+                .into_iter()
+                .collect(),
+            vec![vec![Some(Value::from(13))], vec![Some(Value::from(8))]],
+        );
+        assert_eq!(genotypes, expected);
+
+        let sample_selection = SampleSelection::new(&header, ["sample2"])?;
         assert!(matches!(
-            parse_samples(&header, "GQ\tndls", &mut genotypes),
+            parse_samples(
+                &header,
+                "GQ\t8\t13\tndls",
+                Some(&sample_selection),
+                &mut genotypes,
+            ),
             Err(ParseError::InvalidValues(_))
         ));
 
@@ -0,0 +1,249 @@
+use std::{io, mem};
+
+use crate::{header::SampleNames, variant::RecordBuf, Header};
+
+/// A selection of sample columns to parse when reading records.
+///
+/// This is used to only parse a subset of sample columns of wide VCFs. Unselected sample columns
+/// are skipped without being parsed. A selection can also subset and reorder the samples of
+/// records that are already read (see [`Self::apply`]).
+///
+/// The samples of records read using a selection are in the order of the selected sample names,
+/// which matches the sample names of [`Self::header`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SampleSelection {
+    header: Header,
+    column_indices: Vec<Option<usize>>,
+}
+
+impl SampleSelection {
+    /// Creates a sample selection from a list of sample names.
+    ///
+    /// This returns an error if a sample name is not in the header or is duplicated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, io::reader::SampleSelection};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_sample_name("sample0")
+    ///     .add_sample_name("sample1")
+    ///     .add_sample_name("sample2")
+    ///     .build();
+    ///
+    /// let selection = SampleSelection::new(&header, ["sample2", "sample0"])?;
+    ///
+    /// let sample_names: Vec<_> = selection.header().sample_names().iter().collect();
+    /// assert_eq!(sample_names, ["sample2", "sample0"]);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn new<I, N>(header: &Header, sample_names: I) -> io::Result<Self>
+    where
+        I: IntoIterator<Item = N>,
+        N: AsRef<str>,
+    {
+        let mut column_indices = vec![None; header.sample_names().len()];
+        let mut selected_sample_names = SampleNames::new();
+
+        for sample_name in sample_names {
+            let sample_name = sample_name.as_ref();
+
+            let i = header
+                .sample_names()
+                .get_index_of(sample_name)
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("missing sample name: {sample_name}"),
+                    )
+                })?;
+
+            if !selected_sample_names.insert(sample_name.into()) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("duplicate sample name: {sample_name}"),
+                ));
+            }
+
+            column_indices[i] = Some(selected_sample_names.len() - 1);
+        }
+
+        let mut header = header.clone();
+        *header.sample_names_mut() = selected_sample_names;
+
+        Ok(Self {
+            header,
+            column_indices,
+        })
+    }
+
+    /// Returns the header with only the selected sample names.
+    ///
+    /// This is the header that describes records read using the selection.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Returns the number of selected samples.
+    pub fn len(&self) -> usize {
+        self.header.sample_names().len()
+    }
+
+    /// Returns whether no samples are selected.
+    pub fn is_empty(&self) -> bool {
+        self.header.sample_names().is_empty()
+    }
+
+    /// Subsets and reorders the samples of a record.
+    ///
+    /// The record must have the samples of the header the selection was created from. Afterward,
+    /// its samples are in the order of [`Self::header`]. This is independent of the output
+    /// format, e.g., when the record is written as BCF, the genotype fields are encoded from the
+    /// selected samples.
+    ///
+    /// Info fields are not changed. Use [`RecordBuf::update_allele_counts`] to recalculate the
+    /// allele counts (`AC` and `AN`) of the selected samples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     io::reader::SampleSelection,
+    ///     variant::{
+    ///         record::samples::keys::key,
+    ///         record_buf::{samples::{sample::Value, Keys}, RecordBuf, Samples},
+    ///     },
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_sample_name("sample0")
+    ///     .add_sample_name("sample1")
+    ///     .add_sample_name("sample2")
+    ///     .build();
+    ///
+    /// let keys: Keys = [String::from(key::GENOTYPE)].into_iter().collect();
+    /// let samples = Samples::new(
+    ///     keys.clone(),
+    ///     vec![
+    ///         vec![Some(Value::from("0/0"))],
+    ///         vec![Some(Value::from("0/1"))],
+    ///         vec![Some(Value::from("1/1"))],
+    ///     ],
+    /// );
+    ///
+    /// let mut record = RecordBuf::builder().set_samples(samples).build();
+    ///
+    /// let selection = SampleSelection::new(&header, ["sample2", "sample0"])?;
+    /// selection.apply(&mut record);
+    ///
+    /// let expected = Samples::new(
+    ///     keys,
+    ///     vec![vec![Some(Value::from("1/1"))], vec![Some(Value::from("0/0"))]],
+    /// );
+    ///
+    /// assert_eq!(record.samples(), &expected);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn apply(&self, record: &mut RecordBuf) {
+        let samples = record.samples_mut();
+
+        let mut values = vec![Vec::new(); self.len()];
+
+        for (sample_values, column_index) in mem::take(&mut samples.values)
+            .into_iter()
+            .zip(&self.column_indices)
+        {
+            if let Some(j) = column_index {
+                values[*j] = sample_values;
+            }
+        }
+
+        samples.values = values;
+    }
+
+    // Returns, for each sample column in the input, the index of the sample in the selection, if
+    // selected.
+    pub(crate) fn column_indices(&self) -> &[Option<usize>] {
+        &self.column_indices
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() -> io::Result<()> {
+        let header = Header::builder()
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .add_sample_name("sample2")
+            .build();
+
+        let selection = SampleSelection::new(&header, ["sample2", "sample0"])?;
+        assert_eq!(selection.len(), 2);
+        assert_eq!(selection.column_indices(), [Some(1), None, Some(0)]);
+
+        assert!(matches!(
+            SampleSelection::new(&header, ["sample3"]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            SampleSelection::new(&header, ["sample0", "sample0"]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply() -> io::Result<()> {
+        use crate::variant::record_buf::{
+            samples::{sample::Value, Keys},
+            Samples,
+        };
+
+        let header = Header::builder()
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .add_sample_name("sample2")
+            .build();
+
+        let keys: Keys = [String::from("GT")].into_iter().collect();
+        let samples = Samples::new(
+            keys.clone(),
+            vec![
+                vec![Some(Value::from("0/0"))],
+                vec![Some(Value::from("0/1"))],
+                vec![Some(Value::from("1/1"))],
+            ],
+        );
+
+        let mut record = RecordBuf::builder().set_samples(samples).build();
+
+        let selection = SampleSelection::new(&header, ["sample1", "sample2", "sample0"])?;
+        selection.apply(&mut record);
+
+        let expected = Samples::new(
+            keys.clone(),
+            vec![
+                vec![Some(Value::from("0/1"))],
+                vec![Some(Value::from("1/1"))],
+                vec![Some(Value::from("0/0"))],
+            ],
+        );
+
+        assert_eq!(record.samples(), &expected);
+
+        let selection = SampleSelection::new(selection.header(), ["sample0"])?;
+        selection.apply(&mut record);
+
+        let expected = Samples::new(keys, vec![vec![Some(Value::from("0/0"))]]);
+        assert_eq!(record.samples(), &expected);
+
+        Ok(())
+    }
+}
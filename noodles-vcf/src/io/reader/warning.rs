@@ -0,0 +1,190 @@
+//! VCF reader warnings.
+
+use std::{borrow::Cow, fmt};
+
+const DELIMITER: char = '\t';
+const MISSING: &str = ".";
+const LIST_DELIMITER: char = ';';
+
+const IDS_INDEX: usize = 2;
+const FILTERS_INDEX: usize = 6;
+const INFO_INDEX: usize = 7;
+
+/// A VCF reader warning.
+///
+/// A warning is a recoverable spec violation found while reading a record. The record is still
+/// read, and where needed, normalized, e.g., duplicate IDs are removed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Warning {
+    line_number: usize,
+    kind: Kind,
+}
+
+impl Warning {
+    /// Creates a VCF reader warning.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::io::reader::{warning::Kind, Warning};
+    /// let warning = Warning::new(3, Kind::TrailingTab);
+    /// ```
+    pub fn new(line_number: usize, kind: Kind) -> Self {
+        Self { line_number, kind }
+    }
+
+    /// Returns the (1-based) line number of the record the warning applies to.
+    ///
+    /// Line numbers include header lines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::io::reader::{warning::Kind, Warning};
+    /// let warning = Warning::new(3, Kind::TrailingTab);
+    /// assert_eq!(warning.line_number(), 3);
+    /// ```
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+
+    /// Returns the kind of warning.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::io::reader::{warning::Kind, Warning};
+    /// let warning = Warning::new(3, Kind::TrailingTab);
+    /// assert_eq!(warning.kind(), &Kind::TrailingTab);
+    /// ```
+    pub fn kind(&self) -> &Kind {
+        &self.kind
+    }
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line_number, self.kind)
+    }
+}
+
+/// A VCF reader warning kind.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Kind {
+    /// An ID is duplicated in the ID column. The duplicate is removed.
+    DuplicateId(String),
+    /// A filter is duplicated in the FILTER column. The duplicate is removed.
+    DuplicateFilter(String),
+    /// The INFO column contains a space.
+    SpaceInInfo,
+    /// The line ends with a tab. Trailing tabs are removed.
+    TrailingTab,
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateId(id) => write!(f, "duplicate ID: {id}"),
+            Self::DuplicateFilter(filter) => write!(f, "duplicate filter: {filter}"),
+            Self::SpaceInInfo => write!(f, "space in INFO"),
+            Self::TrailingTab => write!(f, "trailing tab"),
+        }
+    }
+}
+
+// Checks a record line for recoverable spec violations.
+//
+// This returns the line with duplicate IDs and filters and trailing tabs removed.
+pub(super) fn inspect<'a>(
+    line_number: usize,
+    line: &'a str,
+    warnings: &mut Vec<Warning>,
+) -> Cow<'a, str> {
+    let trimmed_line = line.trim_end_matches(DELIMITER);
+
+    if trimmed_line.len() < line.len() {
+        warnings.push(Warning::new(line_number, Kind::TrailingTab));
+    }
+
+    let mut fields: Vec<Cow<'_, str>> = trimmed_line.split(DELIMITER).map(Cow::from).collect();
+    let mut is_modified = false;
+
+    for (i, kind) in [
+        (IDS_INDEX, Kind::DuplicateId as fn(String) -> Kind),
+        (FILTERS_INDEX, Kind::DuplicateFilter),
+    ] {
+        if let Some(field) = fields.get_mut(i) {
+            if let Some(s) = remove_duplicates(line_number, field, kind, warnings) {
+                *field = Cow::from(s);
+                is_modified = true;
+            }
+        }
+    }
+
+    if fields
+        .get(INFO_INDEX)
+        .is_some_and(|field| field.contains(' '))
+    {
+        warnings.push(Warning::new(line_number, Kind::SpaceInInfo));
+    }
+
+    if is_modified {
+        Cow::from(fields.join("\t"))
+    } else {
+        Cow::from(trimmed_line)
+    }
+}
+
+fn remove_duplicates(
+    line_number: usize,
+    field: &str,
+    kind: fn(String) -> Kind,
+    warnings: &mut Vec<Warning>,
+) -> Option<String> {
+    if field == MISSING {
+        return None;
+    }
+
+    let mut values: Vec<&str> = Vec::new();
+    let mut has_duplicates = false;
+
+    for value in field.split(LIST_DELIMITER) {
+        if values.contains(&value) {
+            warnings.push(Warning::new(line_number, kind(value.into())));
+            has_duplicates = true;
+        } else {
+            values.push(value);
+        }
+    }
+
+    has_duplicates.then(|| values.join(";"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inspect() {
+        let mut warnings = Vec::new();
+        let line = "sq0\t1\tr0\tA\t.\t.\tPASS\tNS=2";
+        assert!(matches!(inspect(3, line, &mut warnings), Cow::Borrowed(s) if s == line));
+        assert!(warnings.is_empty());
+
+        let mut warnings = Vec::new();
+        let line = "sq0\t1\tr0;r1;r0\tA\t.\t.\tq10;q10\tNOTE=a b\t";
+        assert_eq!(
+            inspect(3, line, &mut warnings),
+            "sq0\t1\tr0;r1\tA\t.\t.\tq10\tNOTE=a b"
+        );
+        assert_eq!(
+            warnings,
+            [
+                Warning::new(3, Kind::TrailingTab),
+                Warning::new(3, Kind::DuplicateId(String::from("r0"))),
+                Warning::new(3, Kind::DuplicateFilter(String::from("q10"))),
+                Warning::new(3, Kind::SpaceInInfo),
+            ]
+        );
+    }
+}
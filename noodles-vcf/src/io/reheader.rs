@@ -0,0 +1,201 @@
+use std::io::{self, BufRead, Read, Write};
+
+use noodles_bgzf as bgzf;
+
+use super::{Reader, Writer};
+use crate::Header;
+
+/// Replaces the header of a bgzipped VCF file.
+///
+/// This reads the header from `reader` and writes the given header to `writer`, followed by the
+/// records. Only the BGZF block that contains the end of the original header is decompressed and
+/// recompressed. All following blocks are copied byte-for-byte, so this is much faster than
+/// transcoding the file. This is similar to `bcftools reheader`.
+///
+/// Records are not validated against the new header. Sample columns are not changed, so the new
+/// header must have the same number of samples as the original header, though samples can be
+/// renamed. Any INFO, FORMAT, and FILTER keys used by the records should remain defined.
+///
+/// The virtual positions of the records change, so an associated index (e.g., tabix) must be
+/// rebuilt.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::fs::File;
+/// use noodles_vcf::{self as vcf, header::record::Value};
+///
+/// let mut reader = vcf::io::reader::Builder::default().build_from_path("sample.vcf.gz")?;
+/// let mut header = reader.read_header()?;
+///
+/// header.insert("comment".parse()?, Value::from("noodles-vcf"))?;
+///
+/// let src = File::open("sample.vcf.gz")?;
+/// let mut dst = File::create("sample.reheadered.vcf.gz")?;
+/// vcf::io::reheader(src, &mut dst, &header)?;
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn reheader<R, W>(reader: R, writer: &mut W, header: &Header) -> io::Result<()>
+where
+    R: Read,
+    W: Write,
+{
+    let mut reader = Reader::new(bgzf::Reader::new(reader));
+    let src_header = reader.read_header()?;
+
+    let src_sample_count = src_header.sample_names().len();
+    let dst_sample_count = header.sample_names().len();
+
+    if dst_sample_count != src_sample_count {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("sample count mismatch: expected {src_sample_count}, got {dst_sample_count}"),
+        ));
+    }
+
+    let mut reader = reader.into_inner();
+    let mut encoder = bgzf::Writer::new(writer);
+
+    Writer::new(&mut encoder).write_header(header)?;
+
+    // The remainder of the current block, i.e., the start of the records, is recompressed with
+    // the new header.
+    let buf = reader.fill_buf()?;
+    let is_eof = buf.is_empty();
+    encoder.write_all(buf)?;
+
+    if is_eof {
+        encoder.try_finish()?;
+        return Ok(());
+    }
+
+    let len = buf.len();
+    reader.consume(len);
+
+    encoder.flush()?;
+    let writer = encoder.into_inner();
+
+    io::copy(&mut reader.into_inner(), writer)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::{
+        header::record::value::{map::Contig, Map},
+        variant::{
+            io::Write as _,
+            record::samples::keys::key,
+            record_buf::{samples::sample::Value, Samples},
+            RecordBuf,
+        },
+    };
+
+    fn write(header: &Header, records: &[RecordBuf]) -> io::Result<Vec<u8>> {
+        let mut writer = Writer::new(bgzf::Writer::new(Vec::new()));
+        writer.write_header(header)?;
+
+        for record in records {
+            writer.write_variant_record(header, record)?;
+        }
+
+        writer.into_inner().finish()
+    }
+
+    fn read(src: &[u8]) -> io::Result<(Header, Vec<RecordBuf>)> {
+        let mut reader = Reader::new(bgzf::Reader::new(src));
+        let header = reader.read_header()?;
+        let records = reader.record_bufs(&header).collect::<io::Result<_>>()?;
+        Ok((header, records))
+    }
+
+    #[test]
+    fn test_reheader() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .add_sample_name("sample0")
+            .build();
+
+        // Enough records to span multiple BGZF blocks.
+        let records: Vec<_> = (1..=8192)
+            .map(|i| {
+                Position::try_from(i).map(|position| {
+                    RecordBuf::builder()
+                        .set_reference_sequence_name("sq0")
+                        .set_variant_start(position)
+                        .set_reference_bases("A")
+                        .set_samples(Samples::new(
+                            [String::from(key::GENOTYPE)].into_iter().collect(),
+                            vec![vec![Some(Value::from("0|0"))]],
+                        ))
+                        .build()
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        let src = write(&header, &records)?;
+
+        let mut new_header = Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .add_sample_name("sample1")
+            .build();
+
+        new_header.insert(
+            "comment".parse()?,
+            crate::header::record::Value::from("noodles-vcf"),
+        )?;
+
+        let mut dst = Vec::new();
+        reheader(&src[..], &mut dst, &new_header)?;
+
+        let (_, expected_records) = read(&src)?;
+        let (actual_header, actual_records) = read(&dst)?;
+        assert_eq!(actual_header, new_header);
+        assert_eq!(actual_records, expected_records);
+
+        // The blocks after the first block are copied unchanged.
+        let mut reader = bgzf::Reader::new(&src[..]);
+        reader.fill_buf()?;
+        let first_block_len = usize::try_from(reader.position())?;
+        assert!(dst.ends_with(&src[first_block_len..]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reheader_with_no_records() -> io::Result<()> {
+        let header = Header::default();
+        let src = write(&header, &[])?;
+
+        let new_header = Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .build();
+
+        let mut dst = Vec::new();
+        reheader(&src[..], &mut dst, &new_header)?;
+
+        let (actual_header, actual_records) = read(&dst)?;
+        assert_eq!(actual_header, new_header);
+        assert!(actual_records.is_empty());
+        assert!(dst.ends_with(&src[src.len() - 28..]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reheader_with_sample_count_mismatch() -> io::Result<()> {
+        let header = Header::builder().add_sample_name("sample0").build();
+        let src = write(&header, &[])?;
+
+        assert!(matches!(
+            reheader(&src[..], &mut Vec::new(), &Header::default()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -1,13 +1,41 @@
 //! VCF writer.
 
 mod builder;
+pub(crate) mod float_format;
 mod header;
+mod index_format;
+mod indexer;
+mod info_key_order;
+pub(crate) mod options;
 mod record;
 
-use std::io::{self, Write};
+use std::{
+    io::{self, Write},
+    num::NonZeroUsize,
+    path::PathBuf,
+    thread,
+};
 
-pub use self::builder::Builder;
-use self::{header::write_header, record::write_record};
+use noodles_bgzf as bgzf;
+use noodles_csi::{
+    self as csi,
+    binning_index::{
+        index::reference_sequence::{bin::Chunk, index::BinnedIndex},
+        Indexer as CsiIndexer,
+    },
+};
+use noodles_tabix as tabix;
+
+pub(crate) use self::record::write_record;
+pub use self::{
+    builder::Builder, float_format::FloatFormat, index_format::IndexFormat,
+    info_key_order::InfoKeyOrder,
+};
+use self::{
+    header::write_header,
+    indexer::{Index, Indexer},
+    options::Options,
+};
 use crate::{Header, Record};
 
 /// A VCF writer.
@@ -51,6 +79,9 @@ use crate::{Header, Record};
 #[derive(Debug)]
 pub struct Writer<W> {
     inner: W,
+    indexer: Option<Indexer<W>>,
+    index_dst: Option<PathBuf>,
+    options: Options,
 }
 
 impl<W> Writer<W>
@@ -66,7 +97,21 @@ where
     /// let writer = vcf::io::Writer::new(Vec::new());
     /// ```
     pub fn new(inner: W) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            indexer: None,
+            index_dst: None,
+            options: Options::default(),
+        }
+    }
+
+    fn with_options(inner: W, options: Options) -> Self {
+        Self {
+            inner,
+            indexer: None,
+            index_dst: None,
+            options,
+        }
     }
 
     /// Returns a reference to the underlying writer.
@@ -144,10 +189,358 @@ where
     /// # Ok::<_, std::io::Error>(())
     /// ```
     pub fn write_record(&mut self, header: &Header, record: &Record) -> io::Result<()> {
-        write_record(&mut self.inner, header, record)
+        self.write_indexed_record(header, record)
+    }
+
+    /// Writes a gVCF reference block.
+    ///
+    /// The record must be a reference block (see [`crate::variant::gvcf::is_reference_block`])
+    /// with an `END` at or after its start, and the header must define the `END` INFO field (see
+    /// [`crate::variant::gvcf::add_header_records`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_vcf::{self as vcf, variant::gvcf};
+    ///
+    /// let mut writer = vcf::io::Writer::new(Vec::new());
+    ///
+    /// let mut header = vcf::Header::default();
+    /// gvcf::add_header_records(&mut header);
+    ///
+    /// let record = gvcf::reference_block("sq0", Position::MIN, Position::try_from(8)?, b'A');
+    /// writer.write_reference_block(&header, &record)?;
+    ///
+    /// assert_eq!(writer.get_ref(), b"sq0\t1\t.\tA\t<NON_REF>\t.\t.\tEND=8\n");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_reference_block(
+        &mut self,
+        header: &Header,
+        record: &crate::variant::RecordBuf,
+    ) -> io::Result<()> {
+        use crate::variant::{gvcf, record::info::field::key};
+
+        if !gvcf::is_reference_block(record) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "record is not a reference block",
+            ));
+        }
+
+        if !header.infos().contains_key(key::END_POSITION) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "missing END INFO header record",
+            ));
+        }
+
+        let start = record
+            .variant_start()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing variant start"))?;
+
+        let end = gvcf::end(record)?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing END"))?;
+
+        if end < start {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "END is before the variant start",
+            ));
+        }
+
+        self.write_indexed_record(header, record)
+    }
+
+    /// Writes a batch of variant records, encoding them in parallel.
+    ///
+    /// The records are split into up to `worker_count` contiguous chunks, which are encoded
+    /// concurrently. The encoded chunks are written in input order, so the output is identical to
+    /// writing each record sequentially.
+    ///
+    /// This can be paired with a multithreaded BGZF writer (see
+    /// [`Builder::set_worker_count`]) to parallelize both encoding and compression.
+    ///
+    /// If indexing is enabled (see [`Self::set_indexer`]), records are written sequentially.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_core::Position;
+    /// use noodles_vcf::{self as vcf, variant::RecordBuf};
+    ///
+    /// let mut writer = vcf::io::Writer::new(Vec::new());
+    ///
+    /// let header = vcf::Header::default();
+    ///
+    /// let records: Vec<_> = (1..=4)
+    ///     .map(|i| {
+    ///         RecordBuf::builder()
+    ///             .set_reference_sequence_name("sq0")
+    ///             .set_variant_start(Position::try_from(i).unwrap())
+    ///             .set_reference_bases("A")
+    ///             .build()
+    ///     })
+    ///     .collect();
+    ///
+    /// let worker_count = NonZeroUsize::try_from(2)?;
+    /// writer.write_variant_records(&header, &records, worker_count)?;
+    ///
+    /// assert_eq!(
+    ///     writer.get_ref(),
+    ///     b"sq0\t1\t.\tA\t.\t.\t.\t.
+    /// sq0\t2\t.\tA\t.\t.\t.\t.
+    /// sq0\t3\t.\tA\t.\t.\t.\t.
+    /// sq0\t4\t.\tA\t.\t.\t.\t.
+    /// "
+    /// );
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn write_variant_records<R>(
+        &mut self,
+        header: &Header,
+        records: &[R],
+        worker_count: NonZeroUsize,
+    ) -> io::Result<()>
+    where
+        R: crate::variant::Record + Sync,
+    {
+        if records.is_empty() {
+            return Ok(());
+        }
+
+        if self.indexer.is_some() {
+            for record in records {
+                self.write_indexed_record(header, record)?;
+            }
+
+            return Ok(());
+        }
+
+        let chunk_size = (records.len() + worker_count.get() - 1) / worker_count.get();
+        let options = &self.options;
+
+        let bufs = thread::scope(|scope| {
+            let handles: Vec<_> = records
+                .chunks(chunk_size)
+                .map(|chunk| scope.spawn(move || encode_records(options, header, chunk)))
+                .collect();
+
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle.join().map_err(|_| {
+                        io::Error::new(io::ErrorKind::Other, "encoder thread panicked")
+                    })?
+                })
+                .collect::<io::Result<Vec<_>>>()
+        })?;
+
+        for buf in bufs {
+            self.inner.write_all(&buf)?;
+        }
+
+        Ok(())
+    }
+
+    /// Finishes the output stream.
+    ///
+    /// This flushes the underlying writer. If the writer was built with an index output (see
+    /// [`Builder::set_write_index`]), this also finishes the BGZF stream and writes the index to
+    /// `<dst>.tbi` or `<dst>.csi`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_vcf as vcf;
+    /// let mut writer = vcf::io::Writer::new(Vec::new());
+    /// writer.finish()?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn finish(&mut self) -> io::Result<()> {
+        self.inner.flush()?;
+
+        let Some(dst) = self.index_dst.take() else {
+            return Ok(());
+        };
+
+        let Some(mut indexer) = self.indexer.take() else {
+            return Ok(());
+        };
+
+        indexer.finish()?;
+
+        match indexer.build() {
+            Index::Tabix(index) => tabix::write(dst, &index),
+            Index::Csi(index) => csi::write(dst, &index),
+        }
+    }
+
+    fn write_indexed_record(
+        &mut self,
+        header: &Header,
+        record: &dyn crate::variant::Record,
+    ) -> io::Result<()> {
+        let Some(indexer) = self.indexer.as_mut() else {
+            return write_record(&mut self.inner, &self.options, header, record);
+        };
+
+        let start_position = indexer.virtual_position(&self.inner)?;
+        write_record(&mut self.inner, &self.options, header, record)?;
+        let end_position = indexer.virtual_position(&self.inner)?;
+
+        let chunk = Chunk::new(start_position, end_position);
+        indexer.add_record(header, record, chunk)
+    }
+}
+
+impl<W> Writer<bgzf::Writer<W>>
+where
+    W: Write,
+{
+    /// Enables building a tabix index of written records.
+    ///
+    /// Each record is indexed by its reference sequence name, variant start, and variant end,
+    /// which accounts for the reference bases length and the `END` and `SVLEN` INFO fields.
+    /// Records must be written in coordinate order. The indexer header is typically a VCF index
+    /// header (see [`noodles_csi::binning_index::index::header::Builder::vcf`]).
+    ///
+    /// The index is retrieved using [`Self::finish_index`] after writing all records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_csi::binning_index::index::header::Builder;
+    /// use noodles_tabix as tabix;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut indexer = tabix::index::Indexer::default();
+    /// indexer.set_header(Builder::vcf().build());
+    ///
+    /// let mut writer = vcf::io::Writer::new(bgzf::Writer::new(io::sink()));
+    /// writer.set_indexer(indexer);
+    /// ```
+    pub fn set_indexer(&mut self, indexer: tabix::index::Indexer) {
+        self.indexer = Some(Indexer::new(
+            indexer::Kind::Tabix(indexer),
+            bgzf::Writer::virtual_position,
+        ));
+    }
+
+    /// Enables building a coordinate-sorted index (CSI) of written records.
+    ///
+    /// Records are indexed the same way as with a tabix indexer (see [`Self::set_indexer`]). The
+    /// index header is a VCF index header with the reference sequence names of the written
+    /// records.
+    ///
+    /// The index is retrieved using [`Self::finish_csi_index`] after writing all records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_csi::binning_index::{index::reference_sequence::index::BinnedIndex, Indexer};
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut writer = vcf::io::Writer::new(bgzf::Writer::new(io::sink()));
+    /// writer.set_csi_indexer(Indexer::<BinnedIndex>::default());
+    /// ```
+    pub fn set_csi_indexer(&mut self, indexer: CsiIndexer<BinnedIndex>) {
+        self.indexer = Some(Indexer::new(
+            indexer::Kind::csi(indexer),
+            bgzf::Writer::virtual_position,
+        ));
+    }
+
+    /// Builds and returns the tabix index of written records.
+    ///
+    /// This returns `None` if tabix indexing is not enabled (see [`Self::set_indexer`]). Indexing
+    /// is disabled after the index is built.
+    ///
+    /// The underlying stream should be finished (see [`bgzf::Writer::try_finish`]) before
+    /// writing the index so that the final block is flushed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_tabix as tabix;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut writer = vcf::io::Writer::new(bgzf::Writer::new(io::sink()));
+    /// writer.set_indexer(tabix::index::Indexer::default());
+    /// writer.write_header(&vcf::Header::default())?;
+    /// writer.get_mut().try_finish()?;
+    ///
+    /// let index = writer.finish_index();
+    /// assert!(index.is_some());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn finish_index(&mut self) -> Option<tabix::Index> {
+        match self.take_indexer(IndexFormat::Tabix)?.build() {
+            Index::Tabix(index) => Some(index),
+            Index::Csi(_) => None,
+        }
+    }
+
+    /// Builds and returns the coordinate-sorted index (CSI) of written records.
+    ///
+    /// This returns `None` if CSI indexing is not enabled (see [`Self::set_csi_indexer`]).
+    /// Indexing is disabled after the index is built.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_csi::binning_index::{index::reference_sequence::index::BinnedIndex, Indexer};
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut writer = vcf::io::Writer::new(bgzf::Writer::new(io::sink()));
+    /// writer.set_csi_indexer(Indexer::<BinnedIndex>::default());
+    /// writer.write_header(&vcf::Header::default())?;
+    /// writer.get_mut().try_finish()?;
+    ///
+    /// let index = writer.finish_csi_index();
+    /// assert!(index.is_some());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn finish_csi_index(&mut self) -> Option<csi::Index> {
+        match self.take_indexer(IndexFormat::Csi)?.build() {
+            Index::Csi(index) => Some(index),
+            Index::Tabix(_) => None,
+        }
+    }
+
+    fn take_indexer(&mut self, format: IndexFormat) -> Option<Indexer<bgzf::Writer<W>>> {
+        if self.indexer.as_ref()?.format() == format {
+            self.indexer.take()
+        } else {
+            None
+        }
     }
 }
 
+fn encode_records<R>(options: &Options, header: &Header, records: &[R]) -> io::Result<Vec<u8>>
+where
+    R: crate::variant::Record,
+{
+    let mut buf = Vec::new();
+
+    for record in records {
+        write_record(&mut buf, options, header, record)?;
+    }
+
+    Ok(buf)
+}
+
 impl<W> crate::variant::io::Write for Writer<W>
 where
     W: Write,
@@ -161,7 +554,7 @@ where
         header: &Header,
         record: &dyn crate::variant::Record,
     ) -> io::Result<()> {
-        write_record(&mut self.inner, header, record)
+        self.write_indexed_record(header, record)
     }
 }
 
@@ -191,6 +584,201 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_write_variant_records() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::default();
+
+        let records = (1..=5)
+            .map(|i| {
+                Position::try_from(i).map(|position| {
+                    RecordBuf::builder()
+                        .set_reference_sequence_name("sq0")
+                        .set_variant_start(position)
+                        .set_reference_bases("A")
+                        .build()
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut expected = Writer::new(Vec::new());
+
+        for record in &records {
+            expected.write_variant_record(&header, record)?;
+        }
+
+        for worker_count in [1, 2, 3, 8] {
+            let mut writer = Writer::new(Vec::new());
+            writer.write_variant_records(
+                &header,
+                &records,
+                NonZeroUsize::try_from(worker_count)?,
+            )?;
+            assert_eq!(writer.get_ref(), expected.get_ref());
+        }
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_variant_records::<RecordBuf>(&header, &[], NonZeroUsize::MIN)?;
+        assert!(writer.get_ref().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_finish_index() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Cursor;
+
+        use noodles_csi::binning_index::index::header::Builder as IndexHeaderBuilder;
+
+        use crate::{
+            header::record::value::{
+                map::{Contig, Info},
+                Map,
+            },
+            io::indexed_reader,
+            variant::{record::info::field::key, record_buf::info::field::Value},
+        };
+
+        let header = Header::builder()
+            .add_info(key::END_POSITION, Map::<Info>::from(key::END_POSITION))
+            .add_contig("sq0", Map::<Contig>::new())
+            .add_contig("sq1", Map::<Contig>::new())
+            .build();
+
+        let mut indexer = tabix::index::Indexer::default();
+        indexer.set_header(IndexHeaderBuilder::vcf().build());
+
+        let mut writer = Writer::new(bgzf::Writer::new(Vec::new()));
+        writer.set_indexer(indexer);
+        writer.write_header(&header)?;
+
+        for (reference_sequence_name, position, reference_bases, end) in [
+            ("sq0", 8, "A", None),
+            ("sq0", 100, "ACGT", None),
+            ("sq0", 150, "A", Some(300)),
+            ("sq1", 120, "A", None),
+        ] {
+            let mut builder = RecordBuf::builder()
+                .set_reference_sequence_name(reference_sequence_name)
+                .set_variant_start(Position::try_from(position)?)
+                .set_reference_bases(reference_bases);
+
+            if let Some(end) = end {
+                builder = builder.set_info(
+                    [(String::from(key::END_POSITION), Some(Value::from(end)))]
+                        .into_iter()
+                        .collect(),
+                );
+            }
+
+            writer.write_variant_record(&header, &builder.build())?;
+        }
+
+        writer.get_mut().try_finish()?;
+
+        let index = writer.finish_index().expect("missing index");
+        assert!(writer.finish_index().is_none());
+
+        let data = writer.into_inner().finish()?;
+
+        for (region, expected) in [
+            ("sq0:102-103", &[100][..]),
+            ("sq0:250-260", &[150][..]),
+            ("sq0", &[8, 100, 150][..]),
+            ("sq1", &[120][..]),
+        ] {
+            let mut reader = indexed_reader::Builder::default()
+                .set_index(index.clone())
+                .build_from_reader(Cursor::new(&data))?;
+
+            let header = reader.read_header()?;
+            let region = region.parse()?;
+
+            let actual = reader
+                .query(&header, &region)?
+                .map(|result| {
+                    result.and_then(|record| {
+                        record
+                            .variant_start()
+                            .transpose()
+                            .map(|position| position.map(usize::from).unwrap_or_default())
+                    })
+                })
+                .collect::<io::Result<Vec<_>>>()?;
+
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_finish_csi_index() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Cursor;
+
+        use noodles_csi::BinningIndex;
+
+        use crate::{
+            header::record::value::{map::Contig, Map},
+            io::indexed_reader,
+        };
+
+        let header = Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .add_contig("sq1", Map::<Contig>::new())
+            .build();
+
+        let mut writer = Writer::new(bgzf::Writer::new(Vec::new()));
+        writer.set_csi_indexer(CsiIndexer::<BinnedIndex>::default());
+        writer.write_header(&header)?;
+
+        for (reference_sequence_name, position) in [("sq0", 8), ("sq1", 13)] {
+            let record = RecordBuf::builder()
+                .set_reference_sequence_name(reference_sequence_name)
+                .set_variant_start(Position::try_from(position)?)
+                .set_reference_bases("A")
+                .build();
+
+            writer.write_variant_record(&header, &record)?;
+        }
+
+        writer.get_mut().try_finish()?;
+
+        assert!(writer.finish_index().is_none());
+        let index = writer.finish_csi_index().expect("missing index");
+        assert!(writer.finish_csi_index().is_none());
+
+        let reference_sequence_names: Vec<_> = index
+            .header()
+            .map(|header| header.reference_sequence_names().iter().cloned().collect())
+            .unwrap_or_default();
+        assert_eq!(reference_sequence_names, ["sq0", "sq1"]);
+
+        let data = writer.into_inner().finish()?;
+
+        let mut reader = indexed_reader::Builder::default()
+            .set_index(index)
+            .build_from_reader(Cursor::new(&data))?;
+
+        let header = reader.read_header()?;
+        let region = "sq1".parse()?;
+
+        let actual = reader
+            .query(&header, &region)?
+            .map(|result| {
+                result.and_then(|record| {
+                    record
+                        .variant_start()
+                        .transpose()
+                        .map(|position| position.map(usize::from).unwrap_or_default())
+                })
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        assert_eq!(actual, [13]);
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_record_with_format() -> Result<(), Box<dyn std::error::Error>> {
         use crate::variant::{
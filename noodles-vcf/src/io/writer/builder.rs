@@ -1,18 +1,28 @@
 use std::{
     fs::File,
     io::{self, BufWriter, Write},
+    num::NonZeroUsize,
     path::Path,
 };
 
 use noodles_bgzf as bgzf;
+use noodles_csi::binning_index::index::header;
+use noodles_tabix as tabix;
 
-use super::Writer;
-use crate::io::CompressionMethod;
+use super::{
+    indexer::{self, Indexer},
+    options::Options,
+    FloatFormat, IndexFormat, InfoKeyOrder, Writer,
+};
+use crate::io::{indexed_reader::build_index_src, CompressionMethod};
 
 /// A BAM writer builder.
 #[derive(Debug, Default)]
 pub struct Builder {
     compression_method: Option<CompressionMethod>,
+    worker_count: Option<NonZeroUsize>,
+    index_format: Option<IndexFormat>,
+    options: Options,
 }
 
 impl Builder {
@@ -29,6 +39,80 @@ impl Builder {
         self
     }
 
+    /// Sets the number of worker threads used to compress BGZF-compressed output.
+    ///
+    /// When set and the output is bgzip-compressed, [`Self::build_from_path`] uses a
+    /// multithreaded BGZF writer, which compresses blocks in parallel while records are encoded.
+    /// By default, a single-threaded BGZF writer is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_vcf::io::writer::Builder;
+    /// let builder = Builder::default().set_worker_count(NonZeroUsize::MIN);
+    /// ```
+    pub fn set_worker_count(mut self, worker_count: NonZeroUsize) -> Self {
+        self.worker_count = Some(worker_count);
+        self
+    }
+
+    /// Sets the format of an index to write alongside the output.
+    ///
+    /// When set, [`Self::build_from_path`] builds an index of the written records, and
+    /// [`Writer::finish`] writes it to `<dst>.tbi` ([`IndexFormat::Tabix`]) or `<dst>.csi`
+    /// ([`IndexFormat::Csi`]). The output must be bgzip-compressed and is written using a
+    /// single-threaded BGZF writer, i.e., this cannot be used with [`Self::set_worker_count`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::io::writer::{Builder, IndexFormat};
+    /// let builder = Builder::default().set_write_index(IndexFormat::Tabix);
+    /// ```
+    pub fn set_write_index(mut self, index_format: IndexFormat) -> Self {
+        self.index_format = Some(index_format);
+        self
+    }
+
+    /// Sets the floating-point number format.
+    ///
+    /// This is used to format QUAL and floating-point INFO and FORMAT values. By default, the
+    /// shortest representation that round-trips is used ([`FloatFormat::Shortest`]). To match
+    /// the output of bcftools, use [`FloatFormat::General`] with 6 significant digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_vcf::io::writer::{Builder, FloatFormat};
+    ///
+    /// let builder = Builder::default()
+    ///     .set_float_format(FloatFormat::General(NonZeroUsize::try_from(6)?));
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_float_format(mut self, float_format: FloatFormat) -> Self {
+        self.options.float_format = float_format;
+        self
+    }
+
+    /// Sets the order of INFO fields.
+    ///
+    /// By default, INFO fields are written in record order ([`InfoKeyOrder::Record`]).
+    ///
+    /// An empty INFO field is always written as missing (`.`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::io::writer::{Builder, InfoKeyOrder};
+    /// let builder = Builder::default().set_info_key_order(InfoKeyOrder::Header);
+    /// ```
+    pub fn set_info_key_order(mut self, info_key_order: InfoKeyOrder) -> Self {
+        self.options.info_key_order = info_key_order;
+        self
+    }
+
     /// Builds a VCF writer from a path.
     ///
     /// If the compression method is not set, it is detected from the path extension.
@@ -53,8 +137,61 @@ impl Builder {
             };
         }
 
+        if let Some(index_format) = self.index_format {
+            return self.build_indexing_from_path(dst, index_format);
+        }
+
+        let file = File::create(dst)?;
+
+        match (self.compression_method, self.worker_count) {
+            (Some(CompressionMethod::Bgzf), Some(worker_count)) => {
+                let inner: Box<dyn Write> = Box::new(bgzf::MultithreadedWriter::with_worker_count(
+                    worker_count,
+                    file,
+                ));
+
+                Ok(Writer::with_options(inner, self.options))
+            }
+            _ => Ok(self.build_from_writer(file)),
+        }
+    }
+
+    fn build_indexing_from_path(
+        self,
+        dst: &Path,
+        index_format: IndexFormat,
+    ) -> io::Result<Writer<Box<dyn Write>>> {
+        if self.compression_method != Some(CompressionMethod::Bgzf) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "writing an index requires BGZF compression",
+            ));
+        }
+
+        if self.worker_count.is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "writing an index is not supported with a worker count",
+            ));
+        }
+
+        let kind = match index_format {
+            IndexFormat::Tabix => {
+                let mut indexer = tabix::index::Indexer::default();
+                indexer.set_header(header::Builder::vcf().build());
+                indexer::Kind::Tabix(indexer)
+            }
+            IndexFormat::Csi => indexer::Kind::csi(Default::default()),
+        };
+
         let file = File::create(dst)?;
-        Ok(self.build_from_writer(file))
+        let (indexer, inner) = Indexer::shared(kind, bgzf::Writer::new(file));
+
+        let mut writer = Writer::with_options(inner, self.options);
+        writer.indexer = Some(indexer);
+        writer.index_dst = Some(build_index_src(dst, index_format.extension()));
+
+        Ok(writer)
     }
 
     /// Builds a VCF writer from a writer.
@@ -77,6 +214,204 @@ impl Builder {
             Some(CompressionMethod::None) | None => Box::new(BufWriter::new(writer)),
         };
 
-        Writer::new(inner)
+        Writer::with_options(inner, self.options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::{
+        io::reader,
+        variant::{io::Write as _, RecordBuf},
+        Header,
+    };
+
+    #[test]
+    fn test_build_from_path_with_worker_count() -> Result<(), Box<dyn std::error::Error>> {
+        let dst = env::temp_dir().join(format!(
+            "noodles-vcf-writer-builder-{}.vcf.gz",
+            std::process::id()
+        ));
+
+        let worker_count = NonZeroUsize::try_from(2)?;
+
+        let header = Header::default();
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("A")
+            .build();
+
+        let mut writer = Builder::default()
+            .set_worker_count(worker_count)
+            .build_from_path(&dst)?;
+
+        writer.write_header(&header)?;
+        writer.write_variant_records(&header, &[record.clone(), record], worker_count)?;
+        drop(writer);
+
+        let mut reader = reader::Builder::default()
+            .set_worker_count(worker_count)
+            .build_from_path(&dst)?;
+
+        let actual_header = reader.read_header()?;
+        assert_eq!(actual_header, header);
+
+        let records: Vec<_> = reader.record_bufs(&header).collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 2);
+
+        fs::remove_file(dst)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_from_path_with_write_index() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{
+            header::record::value::{map::Contig, Map},
+            io::indexed_reader,
+        };
+
+        let header = Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .build();
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(8)?)
+            .set_reference_bases("A")
+            .build();
+
+        for (index_format, ext) in [(IndexFormat::Tabix, "tbi"), (IndexFormat::Csi, "csi")] {
+            let dst = env::temp_dir().join(format!(
+                "noodles-vcf-writer-builder-{}-{ext}.vcf.gz",
+                std::process::id()
+            ));
+
+            let mut writer = Builder::default()
+                .set_write_index(index_format)
+                .build_from_path(&dst)?;
+
+            writer.write_header(&header)?;
+            writer.write_variant_record(&header, &record)?;
+            writer.finish()?;
+            drop(writer);
+
+            let index_dst = build_index_src(&dst, ext);
+            assert!(index_dst.exists());
+
+            let mut reader = indexed_reader::Builder::default().build_from_path(&dst)?;
+            let actual_header = reader.read_header()?;
+            let region = "sq0:8".parse()?;
+            let records: Vec<_> = reader
+                .query(&actual_header, &region)?
+                .collect::<io::Result<_>>()?;
+            assert_eq!(records.len(), 1);
+
+            fs::remove_file(index_dst)?;
+            fs::remove_file(dst)?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_from_path_with_write_index_and_invalid_options() {
+        let dst = env::temp_dir().join(format!(
+            "noodles-vcf-writer-builder-{}-invalid.vcf",
+            std::process::id()
+        ));
+
+        assert!(matches!(
+            Builder::default()
+                .set_write_index(IndexFormat::Tabix)
+                .build_from_path(&dst),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            Builder::default()
+                .set_compression_method(CompressionMethod::Bgzf)
+                .set_worker_count(NonZeroUsize::MIN)
+                .set_write_index(IndexFormat::Csi)
+                .build_from_path(&dst),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(!dst.exists());
+    }
+
+    #[test]
+    fn test_build_from_writer_with_options() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{
+            header::record::value::{map::Info, Map},
+            variant::{
+                record::info::field::key,
+                record_buf::info::field::{value::Array, Value},
+            },
+        };
+
+        let header = Header::builder()
+            .add_info(key::TOTAL_DEPTH, Map::<Info>::from(key::TOTAL_DEPTH))
+            .add_info(
+                key::ALLELE_FREQUENCIES,
+                Map::<Info>::from(key::ALLELE_FREQUENCIES),
+            )
+            .build();
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("A")
+            .set_quality_score(1.0 / 3.0)
+            .set_info(
+                [
+                    (String::from("NOVEL"), Some(Value::Integer(1))),
+                    (
+                        String::from(key::ALLELE_FREQUENCIES),
+                        Some(Value::Array(Array::Float(vec![Some(1.0e-5), None]))),
+                    ),
+                    (String::from(key::TOTAL_DEPTH), Some(Value::Integer(13))),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        let empty_record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("A")
+            .build();
+
+        let mut buf = Vec::new();
+        let mut writer = Builder::default().build_from_writer(&mut buf);
+        writer.write_variant_records(&header, std::slice::from_ref(&record), NonZeroUsize::MIN)?;
+        drop(writer);
+
+        assert_eq!(
+            buf,
+            b"sq0\t1\t.\tA\t.\t0.33333334\t.\tNOVEL=1;AF=0.00001,.;DP=13\n"
+        );
+
+        let mut buf = Vec::new();
+        let mut writer = Builder::default()
+            .set_float_format(FloatFormat::General(NonZeroUsize::try_from(6)?))
+            .set_info_key_order(InfoKeyOrder::Header)
+            .build_from_writer(&mut buf);
+        writer.write_variant_records(&header, &[record, empty_record], NonZeroUsize::MIN)?;
+        drop(writer);
+
+        let expected = b"sq0\t1\t.\tA\t.\t0.333333\t.\tDP=13;AF=1e-05,.;NOVEL=1
+sq0\t1\t.\tA\t.\t.\t.\t.
+";
+        assert_eq!(buf, expected);
+
+        Ok(())
     }
 }
@@ -0,0 +1,118 @@
+use std::{
+    io::{self, Write},
+    num::NonZeroUsize,
+};
+
+/// A floating-point number format.
+///
+/// This is used to format QUAL and floating-point INFO and FORMAT values.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum FloatFormat {
+    /// The shortest representation that round-trips, e.g., `0.1` or `8.13`.
+    #[default]
+    Shortest,
+    /// A general format with the given number of significant digits, similar to C `printf`
+    /// `%.*g`.
+    ///
+    /// Trailing zeros are removed, and scientific notation is used for small and large exponents,
+    /// e.g., `1e-05`. bcftools uses `%g`, i.e., 6 significant digits.
+    General(NonZeroUsize),
+}
+
+pub(crate) fn write_float<W>(writer: &mut W, float_format: FloatFormat, n: f32) -> io::Result<()>
+where
+    W: Write,
+{
+    match float_format {
+        FloatFormat::Shortest => write!(writer, "{n}"),
+        FloatFormat::General(precision) => write_general(writer, precision.get(), n),
+    }
+}
+
+fn write_general<W>(writer: &mut W, precision: usize, n: f32) -> io::Result<()>
+where
+    W: Write,
+{
+    // C promotes `float` to `double` for variadic arguments.
+    let n = f64::from(n);
+
+    if !n.is_finite() || n == 0.0 {
+        return write!(writer, "{n}");
+    }
+
+    let s = format!("{:.*e}", precision - 1, n);
+
+    // SAFETY: The scientific notation of a finite number always has an exponent.
+    let (mantissa, exponent) = s.split_once('e').unwrap();
+    let exponent: i32 = exponent
+        .parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let precision = i32::try_from(precision).unwrap_or(i32::MAX);
+
+    if (-4..precision).contains(&exponent) {
+        // SAFETY: `exponent` < `precision`.
+        let scale = usize::try_from(precision - 1 - exponent).unwrap();
+        let s = format!("{n:.scale$}");
+        writer.write_all(trim_fraction(&s).as_bytes())
+    } else {
+        let sign = if exponent < 0 { '-' } else { '+' };
+        let mantissa = trim_fraction(mantissa);
+        write!(writer, "{mantissa}e{sign}{:02}", exponent.unsigned_abs())
+    }
+}
+
+fn trim_fraction(s: &str) -> &str {
+    if s.contains('.') {
+        s.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_float() -> io::Result<()> {
+        fn t(
+            buf: &mut Vec<u8>,
+            float_format: FloatFormat,
+            n: f32,
+            expected: &str,
+        ) -> io::Result<()> {
+            buf.clear();
+            write_float(buf, float_format, n)?;
+            assert_eq!(std::str::from_utf8(buf).unwrap(), expected);
+            Ok(())
+        }
+
+        let mut buf = Vec::new();
+
+        t(&mut buf, FloatFormat::Shortest, 0.1, "0.1")?;
+        t(&mut buf, FloatFormat::Shortest, 8.13, "8.13")?;
+
+        let g6 = FloatFormat::General(NonZeroUsize::try_from(6).unwrap());
+
+        t(&mut buf, g6, 0.0, "0")?;
+        t(&mut buf, g6, 1.0, "1")?;
+        t(&mut buf, g6, 0.1, "0.1")?;
+        t(&mut buf, g6, 8.13, "8.13")?;
+        t(&mut buf, g6, -8.13, "-8.13")?;
+        t(&mut buf, g6, 0.333_333_34, "0.333333")?;
+        t(&mut buf, g6, 123_456.0, "123456")?;
+        t(&mut buf, g6, 1_234_567.0, "1.23457e+06")?;
+        t(&mut buf, g6, 0.0001, "0.0001")?;
+        t(&mut buf, g6, 0.000_012_5, "1.25e-05")?;
+        t(&mut buf, g6, 1e30, "1e+30")?;
+        t(&mut buf, g6, 1e-30, "1e-30")?;
+        t(&mut buf, g6, 999_999.5, "1e+06")?;
+
+        let g2 = FloatFormat::General(NonZeroUsize::try_from(2).unwrap());
+        t(&mut buf, g2, 8.13, "8.1")?;
+        t(&mut buf, g2, 100.0, "1e+02")?;
+
+        Ok(())
+    }
+}
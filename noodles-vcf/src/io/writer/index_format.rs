@@ -0,0 +1,17 @@
+/// A VCF index format.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IndexFormat {
+    /// Tabix (TBI).
+    Tabix,
+    /// Coordinate-sorted index (CSI).
+    Csi,
+}
+
+impl IndexFormat {
+    pub(super) fn extension(&self) -> &'static str {
+        match self {
+            Self::Tabix => "tbi",
+            Self::Csi => "csi",
+        }
+    }
+}
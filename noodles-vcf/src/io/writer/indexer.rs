@@ -0,0 +1,188 @@
+use std::{
+    fs::File,
+    io::{self, Write},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use noodles_bgzf as bgzf;
+use noodles_csi::{
+    self as csi,
+    binning_index::{
+        index::{
+            header::{self, ReferenceSequenceNames},
+            reference_sequence::{bin::Chunk, index::BinnedIndex},
+        },
+        Indexer as CsiIndexer,
+    },
+};
+use noodles_tabix as tabix;
+
+use super::IndexFormat;
+use crate::{variant::Record, Header};
+
+type SharedBgzfWriter = Arc<Mutex<Option<bgzf::Writer<File>>>>;
+
+/// A record indexer that tracks the virtual positions of records written to a stream.
+#[derive(Debug)]
+pub(super) struct Indexer<W> {
+    kind: Kind,
+    virtual_position: VirtualPosition<W>,
+}
+
+#[derive(Debug)]
+pub(super) enum Kind {
+    Tabix(tabix::index::Indexer),
+    Csi {
+        indexer: CsiIndexer<BinnedIndex>,
+        reference_sequence_names: ReferenceSequenceNames,
+    },
+}
+
+impl Kind {
+    pub(super) fn csi(indexer: CsiIndexer<BinnedIndex>) -> Self {
+        Self::Csi {
+            indexer,
+            reference_sequence_names: ReferenceSequenceNames::new(),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum VirtualPosition<W> {
+    Writer(fn(&W) -> bgzf::VirtualPosition),
+    Shared(SharedBgzfWriter),
+}
+
+pub(super) enum Index {
+    Tabix(tabix::Index),
+    Csi(csi::Index),
+}
+
+impl<W> Indexer<W> {
+    pub(super) fn new(kind: Kind, virtual_position: fn(&W) -> bgzf::VirtualPosition) -> Self {
+        Self {
+            kind,
+            virtual_position: VirtualPosition::Writer(virtual_position),
+        }
+    }
+
+    pub(super) fn format(&self) -> IndexFormat {
+        match self.kind {
+            Kind::Tabix(_) => IndexFormat::Tabix,
+            Kind::Csi { .. } => IndexFormat::Csi,
+        }
+    }
+
+    pub(super) fn virtual_position(&self, writer: &W) -> io::Result<bgzf::VirtualPosition> {
+        match &self.virtual_position {
+            VirtualPosition::Writer(f) => Ok(f(writer)),
+            VirtualPosition::Shared(inner) => lock(inner)?
+                .as_ref()
+                .map(|writer| writer.virtual_position())
+                .ok_or_else(finished_error),
+        }
+    }
+
+    pub(super) fn add_record(
+        &mut self,
+        header: &Header,
+        record: &dyn Record,
+        chunk: Chunk,
+    ) -> io::Result<()> {
+        let reference_sequence_name = record.reference_sequence_name(header)?;
+
+        let start = record
+            .variant_start()
+            .transpose()?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing variant start"))?;
+
+        let end = record.variant_end(header)?;
+
+        match &mut self.kind {
+            Kind::Tabix(indexer) => indexer.add_record(reference_sequence_name, start, end, chunk),
+            Kind::Csi {
+                indexer,
+                reference_sequence_names,
+            } => {
+                let (reference_sequence_id, _) =
+                    reference_sequence_names.insert_full(reference_sequence_name.into());
+
+                indexer.add_record(Some((reference_sequence_id, start, end, true)), chunk)
+            }
+        }
+    }
+
+    /// Finishes the shared output stream, if any.
+    pub(super) fn finish(&mut self) -> io::Result<()> {
+        if let VirtualPosition::Shared(inner) = &self.virtual_position {
+            if let Some(writer) = lock(inner)?.take() {
+                writer.finish()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn build(self) -> Index {
+        match self.kind {
+            Kind::Tabix(indexer) => Index::Tabix(indexer.build()),
+            Kind::Csi {
+                indexer,
+                reference_sequence_names,
+            } => {
+                let reference_sequence_count = reference_sequence_names.len();
+
+                let header = header::Builder::vcf()
+                    .set_reference_sequence_names(reference_sequence_names)
+                    .build();
+
+                Index::Csi(indexer.set_header(header).build(reference_sequence_count))
+            }
+        }
+    }
+}
+
+impl Indexer<Box<dyn Write>> {
+    /// Creates an indexer and a writer that share a BGZF writer.
+    ///
+    /// The indexer reads the virtual position of the shared writer, and the returned writer
+    /// writes to it.
+    pub(super) fn shared(kind: Kind, inner: bgzf::Writer<File>) -> (Self, Box<dyn Write>) {
+        let inner = Arc::new(Mutex::new(Some(inner)));
+
+        let indexer = Self {
+            kind,
+            virtual_position: VirtualPosition::Shared(inner.clone()),
+        };
+
+        (indexer, Box::new(SharedWriter(inner)))
+    }
+}
+
+struct SharedWriter(SharedBgzfWriter);
+
+impl Write for SharedWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        lock(&self.0)?
+            .as_mut()
+            .ok_or_else(finished_error)?
+            .write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match lock(&self.0)?.as_mut() {
+            Some(writer) => writer.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+fn lock(inner: &SharedBgzfWriter) -> io::Result<MutexGuard<'_, Option<bgzf::Writer<File>>>> {
+    inner
+        .lock()
+        .map_err(|_| io::Error::new(io::ErrorKind::Other, "poisoned writer lock"))
+}
+
+fn finished_error() -> io::Error {
+    io::Error::new(io::ErrorKind::Other, "writer is finished")
+}
@@ -0,0 +1,12 @@
+/// The order of INFO fields in a written record.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum InfoKeyOrder {
+    /// The order of the fields in the record, i.e., insertion order.
+    #[default]
+    Record,
+    /// The order of the INFO definitions in the header.
+    ///
+    /// Fields without a header definition are written last, in record order. This matches the
+    /// output of bcftools.
+    Header,
+}
@@ -0,0 +1,8 @@
+use super::{FloatFormat, InfoKeyOrder};
+
+/// Record formatting options.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct Options {
+    pub(crate) float_format: FloatFormat,
+    pub(crate) info_key_order: InfoKeyOrder,
+}
@@ -17,11 +17,17 @@ use self::{
     reference_bases::write_reference_bases, reference_sequence_name::write_reference_sequence_name,
     samples::write_samples,
 };
+use super::options::Options;
 use crate::{variant::Record, Header};
 
 const MISSING: &[u8] = b".";
 
-pub(super) fn write_record<W, R>(writer: &mut W, header: &Header, record: &R) -> io::Result<()>
+pub(crate) fn write_record<W, R>(
+    writer: &mut W,
+    options: &Options,
+    header: &Header,
+    record: &R,
+) -> io::Result<()>
 where
     W: Write,
     R: Record + ?Sized,
@@ -47,19 +53,19 @@ where
 
     writer.write_all(DELIMITER)?;
     let quality_score = record.quality_score().transpose()?;
-    write_quality_score(writer, quality_score)?;
+    write_quality_score(writer, options.float_format, quality_score)?;
 
     writer.write_all(DELIMITER)?;
     write_filters(writer, header, record.filters())?;
 
     writer.write_all(DELIMITER)?;
-    write_info(writer, header, record.info())?;
+    write_info(writer, options, header, record.info())?;
 
     let samples = record.samples()?;
 
     if !samples.is_empty() {
         writer.write_all(DELIMITER)?;
-        write_samples(writer, header, samples)?;
+        write_samples(writer, options.float_format, header, samples)?;
     }
 
     writer.write_all(b"\n")?;
@@ -84,7 +90,7 @@ mod tests {
 
         let header = Header::default();
         let mut buf = Vec::new();
-        write_record(&mut buf, &header, &record)?;
+        write_record(&mut buf, &Options::default(), &header, &record)?;
         assert_eq!(buf, b"sq0\t1\t.\tA\t.\t.\t.\t.\n");
 
         Ok(())
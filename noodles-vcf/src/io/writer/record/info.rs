@@ -4,9 +4,18 @@ use std::io::{self, Write};
 
 use self::field::write_field;
 use super::MISSING;
-use crate::{variant::record::Info, Header};
-
-pub(super) fn write_info<W, I>(writer: &mut W, header: &Header, info: I) -> io::Result<()>
+use crate::{
+    io::writer::{options::Options, InfoKeyOrder},
+    variant::record::Info,
+    Header,
+};
+
+pub(super) fn write_info<W, I>(
+    writer: &mut W,
+    options: &Options,
+    header: &Header,
+    info: I,
+) -> io::Result<()>
 where
     W: Write,
     I: Info,
@@ -14,17 +23,23 @@ where
     const DELIMITER: &[u8] = b";";
 
     if info.is_empty() {
-        writer.write_all(MISSING)?;
-    } else {
-        for (i, result) in info.iter(header).enumerate() {
-            let (key, value) = result?;
+        return writer.write_all(MISSING);
+    }
 
-            if i > 0 {
-                writer.write_all(DELIMITER)?;
-            }
+    let mut fields: Vec<_> = info.iter(header).collect::<io::Result<_>>()?;
 
-            write_field(writer, key, value.as_ref())?;
+    if options.info_key_order == InfoKeyOrder::Header {
+        let infos = header.infos();
+        // Fields without a header definition are stable sorted last.
+        fields.sort_by_key(|(key, _)| infos.get_index_of(*key).unwrap_or(usize::MAX));
+    }
+
+    for (i, (key, value)) in fields.into_iter().enumerate() {
+        if i > 0 {
+            writer.write_all(DELIMITER)?;
         }
+
+        write_field(writer, options.float_format, key, value.as_ref())?;
     }
 
     Ok(())
@@ -48,7 +63,7 @@ mod tests {
             expected: &[u8],
         ) -> io::Result<()> {
             buf.clear();
-            write_info(buf, header, info)?;
+            write_info(buf, &Options::default(), header, info)?;
             assert_eq!(buf, expected);
             Ok(())
         }
@@ -4,18 +4,25 @@ mod string;
 use std::io::{self, Write};
 
 use self::{array::write_array, string::write_string};
-use crate::variant::record::info::field::Value;
+use crate::{
+    io::writer::{float_format::write_float, FloatFormat},
+    variant::record::info::field::Value,
+};
 
-pub(super) fn write_value<W>(writer: &mut W, value: &Value) -> io::Result<()>
+pub(super) fn write_value<W>(
+    writer: &mut W,
+    float_format: FloatFormat,
+    value: &Value,
+) -> io::Result<()>
 where
     W: Write,
 {
     match value {
         Value::Integer(n) => write!(writer, "{n}"),
-        Value::Float(n) => write!(writer, "{n}"),
+        Value::Float(n) => write_float(writer, float_format, *n),
         Value::Flag => Ok(()),
         Value::Character(c) => write!(writer, "{c}"),
         Value::String(s) => write_string(writer, s),
-        Value::Array(array) => write_array(writer, array),
+        Value::Array(array) => write_array(writer, float_format, array),
     }
 }
@@ -1,8 +1,15 @@
 use std::io::{self, Write};
 
-use crate::{io::writer::record::MISSING, variant::record::info::field::value::Array};
+use crate::{
+    io::writer::{float_format::write_float, record::MISSING, FloatFormat},
+    variant::record::info::field::value::Array,
+};
 
-pub(super) fn write_array<W>(writer: &mut W, array: &Array) -> io::Result<()>
+pub(super) fn write_array<W>(
+    writer: &mut W,
+    float_format: FloatFormat,
+    array: &Array,
+) -> io::Result<()>
 where
     W: Write,
 {
@@ -31,7 +38,7 @@ where
                 }
 
                 if let Some(n) = result? {
-                    write!(writer, "{n}")?;
+                    write_float(writer, float_format, n)?;
                 } else {
                     writer.write_all(MISSING)?;
                 }
@@ -4,9 +4,14 @@ mod sample;
 use std::io::{self, Write};
 
 use self::{keys::write_keys, sample::write_sample};
-use crate::{variant::record::Samples, Header};
+use crate::{io::writer::FloatFormat, variant::record::Samples, Header};
 
-pub(super) fn write_samples<W, S>(writer: &mut W, header: &Header, samples: S) -> io::Result<()>
+pub(super) fn write_samples<W, S>(
+    writer: &mut W,
+    float_format: FloatFormat,
+    header: &Header,
+    samples: S,
+) -> io::Result<()>
 where
     W: Write,
     S: Samples,
@@ -17,7 +22,7 @@ where
 
     for sample in samples.iter() {
         writer.write_all(DELIMITER)?;
-        write_sample(writer, header, sample)?;
+        write_sample(writer, float_format, header, sample)?;
     }
 
     Ok(())
@@ -39,7 +44,7 @@ mod tests {
             expected: &[u8],
         ) -> io::Result<()> {
             buf.clear();
-            write_samples(buf, header, genotypes)?;
+            write_samples(buf, FloatFormat::default(), header, genotypes)?;
             assert_eq!(buf, expected);
             Ok(())
         }
@@ -3,9 +3,18 @@ mod value;
 use std::io::{self, Write};
 
 use self::value::write_value;
-use crate::{io::writer::record::MISSING, variant::record::samples::Sample, Header};
+use crate::{
+    io::writer::{record::MISSING, FloatFormat},
+    variant::record::samples::Sample,
+    Header,
+};
 
-pub(super) fn write_sample<W, S>(writer: &mut W, header: &Header, sample: S) -> io::Result<()>
+pub(super) fn write_sample<W, S>(
+    writer: &mut W,
+    float_format: FloatFormat,
+    header: &Header,
+    sample: S,
+) -> io::Result<()>
 where
     W: Write,
     S: Sample,
@@ -20,7 +29,7 @@ where
         }
 
         match value {
-            Some(v) => write_value(writer, header, &v)?,
+            Some(v) => write_value(writer, float_format, header, &v)?,
             None => writer.write_all(MISSING)?,
         }
     }
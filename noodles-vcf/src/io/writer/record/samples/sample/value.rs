@@ -5,19 +5,28 @@ mod string;
 use std::io::{self, Write};
 
 use self::{array::write_array, genotype::write_genotype, string::write_string};
-use crate::{variant::record::samples::series::Value, Header};
-
-pub(super) fn write_value<W>(writer: &mut W, header: &Header, value: &Value) -> io::Result<()>
+use crate::{
+    io::writer::{float_format::write_float, FloatFormat},
+    variant::record::samples::series::Value,
+    Header,
+};
+
+pub(super) fn write_value<W>(
+    writer: &mut W,
+    float_format: FloatFormat,
+    header: &Header,
+    value: &Value,
+) -> io::Result<()>
 where
     W: Write,
 {
     match value {
         Value::Integer(n) => write!(writer, "{n}"),
-        Value::Float(n) => write!(writer, "{n}"),
+        Value::Float(n) => write_float(writer, float_format, *n),
         Value::Character(c) => write!(writer, "{c}"),
         Value::String(s) => write_string(writer, s),
         Value::Genotype(genotype) => write_genotype(writer, header, genotype.as_ref()),
-        Value::Array(array) => write_array(writer, array),
+        Value::Array(array) => write_array(writer, float_format, array),
     }
 }
 
@@ -57,7 +66,7 @@ mod tests {
 
         fn t(buf: &mut Vec<u8>, header: &Header, value: &Value, expected: &[u8]) -> io::Result<()> {
             buf.clear();
-            write_value(buf, header, value)?;
+            write_value(buf, FloatFormat::default(), header, value)?;
             assert_eq!(buf, expected);
             Ok(())
         }
@@ -28,7 +28,11 @@ pub mod io;
 pub mod record;
 pub mod variant;
 
-pub use self::{header::Header, indexer::index, record::Record};
+pub use self::{
+    header::Header,
+    indexer::{index, index_from_reader},
+    record::Record,
+};
 
 #[cfg(feature = "async")]
 pub use self::r#async::io::{Reader as AsyncReader, Writer as AsyncWriter};
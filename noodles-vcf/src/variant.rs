@@ -1,7 +1,15 @@
 //! Variant format.
 
+pub mod bedpe;
+pub mod filter;
+pub mod gvcf;
 pub mod io;
+mod normalize;
 pub mod record;
 pub mod record_buf;
 
-pub use self::{record::Record, record_buf::RecordBuf};
+pub use self::{
+    normalize::{normalize, split_multiallelic},
+    record::Record,
+    record_buf::RecordBuf,
+};
@@ -0,0 +1,826 @@
+//! Structural variant conversion to and from BEDPE.
+//!
+//! BEDPE describes a structural variant as a pair of breakpoints, each with an interval and a
+//! strand. The strand is the side of the breakpoint that is retained in the rearranged
+//! sequence: `+` joins the sequence to the left of the breakpoint and `-`, the sequence to the
+//! right.
+
+pub mod record;
+
+pub use self::record::{Breakpoint, Record, Strand};
+
+use std::io;
+
+use noodles_core::Position;
+
+use super::{
+    record::info::field::key,
+    record_buf::{
+        info::field::{value::Array, Value},
+        AlternateBases, Ids, Info,
+    },
+    RecordBuf,
+};
+
+const MISSING_REFERENCE_BASES: &str = "N";
+const BREAKEND_SV_TYPE: &str = "BND";
+
+/// Converts a structural variant record to a BEDPE record.
+///
+/// The record must have a single alternate allele, which is either a symbolic allele (e.g.,
+/// `<DEL>`) or a breakend (e.g., `G[sq1:13[`).
+///
+/// For symbolic alleles, the first breakpoint is at the variant start, and the second, at the
+/// variant end (`END`), or if missing, the variant start plus the SV length (`SVLEN`). The
+/// intervals are widened by the confidence intervals (`CIPOS` and `CIEND`). The strands are
+/// `+-` for deletions (`DEL`) and `-+` for duplications (`DUP`) and otherwise unknown.
+///
+/// For breakends, the first breakpoint is at the variant start, and the second, at the mate
+/// position. The strands are given by the bracket orientation.
+///
+/// The name is the record ID. If the ID and mate ID (`MATEID`) only differ by a `_1` or `_2`
+/// suffix, the suffix is removed, which is the inverse of [`to_breakends`]. The score is the
+/// quality score.
+///
+/// Note that a breakend and its mate describe the same adjacency. Only one of the pair should be
+/// converted.
+///
+/// # Errors
+///
+/// This returns an `io::Error` if the record is missing a variant start, does not have exactly
+/// one symbolic or breakend alternate allele, or has invalid SV fields.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_vcf::variant::{
+///     bedpe::{self, Strand},
+///     record_buf::AlternateBases,
+///     RecordBuf,
+/// };
+///
+/// let record = RecordBuf::builder()
+///     .set_reference_sequence_name("sq0")
+///     .set_variant_start(Position::try_from(8)?)
+///     .set_reference_bases("N")
+///     .set_alternate_bases(AlternateBases::from(vec![String::from("N[sq1:13[")]))
+///     .build();
+///
+/// let bedpe_record = bedpe::from_variant_record(&record)?;
+///
+/// assert_eq!(bedpe_record.breakpoint_1.start, 7);
+/// assert_eq!(bedpe_record.breakpoint_1.strand, Strand::Forward);
+/// assert_eq!(bedpe_record.breakpoint_2.reference_sequence_name, "sq1");
+/// assert_eq!(bedpe_record.breakpoint_2.start, 12);
+/// assert_eq!(bedpe_record.breakpoint_2.strand, Strand::Reverse);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn from_variant_record(record: &RecordBuf) -> io::Result<Record> {
+    let start = record
+        .variant_start()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing variant start"))?;
+
+    let alternate_bases = record.alternate_bases().as_ref();
+
+    let [alt] = alternate_bases else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "expected exactly one alternate allele",
+        ));
+    };
+
+    let info = record.info();
+
+    let (breakpoint_1, breakpoint_2) = if let Some(breakend) = parse_breakend(alt)? {
+        let breakpoint_1 = Breakpoint {
+            reference_sequence_name: record.reference_sequence_name().into(),
+            start: usize::from(start) - 1,
+            end: usize::from(start),
+            strand: breakend.strand,
+        };
+
+        let breakpoint_2 = Breakpoint {
+            reference_sequence_name: breakend.mate_reference_sequence_name.into(),
+            start: usize::from(breakend.mate_position) - 1,
+            end: usize::from(breakend.mate_position),
+            strand: breakend.mate_strand,
+        };
+
+        (
+            widen(
+                breakpoint_1,
+                get_confidence_interval(info, key::POSITION_CONFIDENCE_INTERVALS)?,
+            )?,
+            breakpoint_2,
+        )
+    } else if let Some(sv_type) = parse_symbolic_sv_type(alt) {
+        let end = get_end(info, start)?;
+
+        let (strand_1, strand_2) = match sv_type {
+            "DEL" => (Strand::Forward, Strand::Reverse),
+            "DUP" => (Strand::Reverse, Strand::Forward),
+            _ => (Strand::Unknown, Strand::Unknown),
+        };
+
+        let reference_sequence_name = record.reference_sequence_name();
+
+        let breakpoint_1 = Breakpoint {
+            reference_sequence_name: reference_sequence_name.into(),
+            start: usize::from(start) - 1,
+            end: usize::from(start),
+            strand: strand_1,
+        };
+
+        let breakpoint_2 = Breakpoint {
+            reference_sequence_name: reference_sequence_name.into(),
+            start: usize::from(end) - 1,
+            end: usize::from(end),
+            strand: strand_2,
+        };
+
+        (
+            widen(
+                breakpoint_1,
+                get_confidence_interval(info, key::POSITION_CONFIDENCE_INTERVALS)?,
+            )?,
+            widen(
+                breakpoint_2,
+                get_confidence_interval(info, key::END_CONFIDENCE_INTERVALS)?,
+            )?,
+        )
+    } else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "expected a symbolic or breakend alternate allele",
+        ));
+    };
+
+    Ok(Record {
+        breakpoint_1,
+        breakpoint_2,
+        name: event_name(record),
+        score: record.quality_score(),
+        other_fields: Vec::new(),
+    })
+}
+
+/// Converts a BEDPE record to a breakend mate pair.
+///
+/// Each breakend is at the start of its breakpoint interval. If the interval is wider than a
+/// single base, the rest of the interval is set as the position confidence interval (`CIPOS`).
+/// The reference bases are `N`.
+///
+/// If the BEDPE record has a name, the breakend IDs are the name suffixed with `_1` and `_2`,
+/// and each references the other as its mate ID (`MATEID`).
+///
+/// # Errors
+///
+/// This returns an `io::Error` if either strand is unknown or a breakpoint interval is empty.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::variant::bedpe::{self, Breakpoint, Record, Strand};
+///
+/// let record = Record {
+///     breakpoint_1: Breakpoint {
+///         reference_sequence_name: String::from("sq0"),
+///         start: 7,
+///         end: 8,
+///         strand: Strand::Forward,
+///     },
+///     breakpoint_2: Breakpoint {
+///         reference_sequence_name: String::from("sq1"),
+///         start: 12,
+///         end: 13,
+///         strand: Strand::Reverse,
+///     },
+///     name: Some(String::from("sv0")),
+///     ..Default::default()
+/// };
+///
+/// let (a, b) = bedpe::to_breakends(&record)?;
+///
+/// assert_eq!(a.alternate_bases().as_ref(), [String::from("N[sq1:13[")]);
+/// assert_eq!(b.alternate_bases().as_ref(), [String::from("]sq0:8]N")]);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn to_breakends(record: &Record) -> io::Result<(RecordBuf, RecordBuf)> {
+    let ids = record
+        .name
+        .as_ref()
+        .map(|name| (format!("{name}_1"), format!("{name}_2")));
+
+    let (id_1, id_2) = match ids {
+        Some((id_1, id_2)) => (Some(id_1), Some(id_2)),
+        None => (None, None),
+    };
+
+    let a = build_breakend(
+        &record.breakpoint_1,
+        &record.breakpoint_2,
+        id_1.clone(),
+        id_2.clone(),
+        record.score,
+    )?;
+
+    let b = build_breakend(
+        &record.breakpoint_2,
+        &record.breakpoint_1,
+        id_2,
+        id_1,
+        record.score,
+    )?;
+
+    Ok((a, b))
+}
+
+/// Converts a BEDPE record to a symbolic structural variant record.
+///
+/// This is the inverse of [`from_variant_record`] for symbolic alleles. Only intrachromosomal
+/// breakpoint pairs are converted: `+-` to a deletion (`<DEL>`), `-+` to a duplication
+/// (`<DUP>`), and `++` or `--` to an inversion (`<INV>`). Otherwise, this returns `None`, and
+/// the record can be represented using [`to_breakends`].
+///
+/// # Errors
+///
+/// This returns an `io::Error` if a breakpoint interval is empty or a position is out of range.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::variant::bedpe::{self, Breakpoint, Record, Strand};
+///
+/// let record = Record {
+///     breakpoint_1: Breakpoint {
+///         reference_sequence_name: String::from("sq0"),
+///         start: 7,
+///         end: 8,
+///         strand: Strand::Forward,
+///     },
+///     breakpoint_2: Breakpoint {
+///         reference_sequence_name: String::from("sq0"),
+///         start: 12,
+///         end: 13,
+///         strand: Strand::Reverse,
+///     },
+///     ..Default::default()
+/// };
+///
+/// let variant_record = bedpe::to_symbolic_variant_record(&record)?.unwrap();
+/// assert_eq!(variant_record.alternate_bases().as_ref(), [String::from("<DEL>")]);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn to_symbolic_variant_record(record: &Record) -> io::Result<Option<RecordBuf>> {
+    let (a, b) = (&record.breakpoint_1, &record.breakpoint_2);
+
+    if a.reference_sequence_name != b.reference_sequence_name {
+        return Ok(None);
+    }
+
+    let sv_type = match (a.strand, b.strand) {
+        (Strand::Forward, Strand::Reverse) => "DEL",
+        (Strand::Reverse, Strand::Forward) => "DUP",
+        (Strand::Forward, Strand::Forward) | (Strand::Reverse, Strand::Reverse) => "INV",
+        _ => return Ok(None),
+    };
+
+    let (start, position_confidence_interval) = breakpoint_position(a)?;
+    let (end, end_confidence_interval) = breakpoint_position(b)?;
+
+    let end = i32::try_from(usize::from(end))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    let mut info: Info = [
+        (String::from(key::SV_TYPE), Some(Value::from(sv_type))),
+        (String::from(key::END_POSITION), Some(Value::Integer(end))),
+    ]
+    .into_iter()
+    .collect();
+
+    if let Some(value) = position_confidence_interval {
+        info.insert(
+            String::from(key::POSITION_CONFIDENCE_INTERVALS),
+            Some(value),
+        );
+    }
+
+    if let Some(value) = end_confidence_interval {
+        info.insert(String::from(key::END_CONFIDENCE_INTERVALS), Some(value));
+    }
+
+    let mut builder = RecordBuf::builder()
+        .set_reference_sequence_name(a.reference_sequence_name.clone())
+        .set_variant_start(start)
+        .set_reference_bases(MISSING_REFERENCE_BASES)
+        .set_alternate_bases(AlternateBases::from(vec![format!("<{sv_type}>")]))
+        .set_info(info);
+
+    if let Some(name) = &record.name {
+        builder = builder.set_ids([name.clone()].into_iter().collect());
+    }
+
+    if let Some(score) = record.score {
+        builder = builder.set_quality_score(score);
+    }
+
+    Ok(Some(builder.build()))
+}
+
+struct Breakend<'a> {
+    strand: Strand,
+    mate_reference_sequence_name: &'a str,
+    mate_position: Position,
+    mate_strand: Strand,
+}
+
+// § 5.4 "Specifying complex rearrangements with breakends" (2023-08-23).
+fn parse_breakend(s: &str) -> io::Result<Option<Breakend<'_>>> {
+    let Some(i) = s.find(['[', ']']) else {
+        return Ok(None);
+    };
+
+    let bracket = char::from(s.as_bytes()[i]);
+    let is_prefix = i == 0;
+
+    let rest = &s[i + 1..];
+
+    let j = rest
+        .find(bracket)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid breakend"))?;
+
+    let (name, position) = rest[..j]
+        .rsplit_once(':')
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid breakend mate"))?;
+
+    let mate_position = position
+        .parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let bases = if is_prefix { &rest[j + 1..] } else { &s[..i] };
+
+    if bases.is_empty() || (!is_prefix && j + 1 != rest.len()) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid breakend",
+        ));
+    }
+
+    let strand = if is_prefix {
+        Strand::Reverse
+    } else {
+        Strand::Forward
+    };
+
+    let mate_strand = if bracket == '[' {
+        Strand::Reverse
+    } else {
+        Strand::Forward
+    };
+
+    Ok(Some(Breakend {
+        strand,
+        mate_reference_sequence_name: name,
+        mate_position,
+        mate_strand,
+    }))
+}
+
+fn format_breakend(strand: Strand, mate: &str, mate_strand: Strand) -> io::Result<String> {
+    match (strand, mate_strand) {
+        (Strand::Forward, Strand::Reverse) => Ok(format!("{MISSING_REFERENCE_BASES}[{mate}[")),
+        (Strand::Forward, Strand::Forward) => Ok(format!("{MISSING_REFERENCE_BASES}]{mate}]")),
+        (Strand::Reverse, Strand::Forward) => Ok(format!("]{mate}]{MISSING_REFERENCE_BASES}")),
+        (Strand::Reverse, Strand::Reverse) => Ok(format!("[{mate}[{MISSING_REFERENCE_BASES}")),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "unknown breakpoint strand",
+        )),
+    }
+}
+
+fn parse_symbolic_sv_type(s: &str) -> Option<&str> {
+    s.strip_prefix('<')
+        .and_then(|t| t.strip_suffix('>'))
+        .map(|t| t.split(':').next().unwrap_or(t))
+}
+
+fn build_breakend(
+    breakpoint: &Breakpoint,
+    mate_breakpoint: &Breakpoint,
+    id: Option<String>,
+    mate_id: Option<String>,
+    score: Option<f32>,
+) -> io::Result<RecordBuf> {
+    let (position, confidence_interval) = breakpoint_position(breakpoint)?;
+    let (mate_position, _) = breakpoint_position(mate_breakpoint)?;
+
+    let mate = format!(
+        "{}:{mate_position}",
+        mate_breakpoint.reference_sequence_name
+    );
+
+    let alt = format_breakend(breakpoint.strand, &mate, mate_breakpoint.strand)?;
+
+    let mut info: Info = [(
+        String::from(key::SV_TYPE),
+        Some(Value::from(BREAKEND_SV_TYPE)),
+    )]
+    .into_iter()
+    .collect();
+
+    if let Some(value) = confidence_interval {
+        info.insert(
+            String::from(key::POSITION_CONFIDENCE_INTERVALS),
+            Some(value),
+        );
+    }
+
+    if let Some(mate_id) = mate_id {
+        info.insert(
+            String::from(key::MATE_BREAKEND_IDS),
+            Some(Value::from(mate_id)),
+        );
+    }
+
+    let mut builder = RecordBuf::builder()
+        .set_reference_sequence_name(breakpoint.reference_sequence_name.clone())
+        .set_variant_start(position)
+        .set_reference_bases(MISSING_REFERENCE_BASES)
+        .set_alternate_bases(AlternateBases::from(vec![alt]))
+        .set_info(info);
+
+    if let Some(id) = id {
+        builder = builder.set_ids([id].into_iter().collect::<Ids>());
+    }
+
+    if let Some(score) = score {
+        builder = builder.set_quality_score(score);
+    }
+
+    Ok(builder.build())
+}
+
+fn breakpoint_position(breakpoint: &Breakpoint) -> io::Result<(Position, Option<Value>)> {
+    if breakpoint.start >= breakpoint.end {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "empty breakpoint interval",
+        ));
+    }
+
+    let position = Position::new(breakpoint.start + 1)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid position"))?;
+
+    let width = breakpoint.end - breakpoint.start;
+
+    let confidence_interval = if width > 1 {
+        let n =
+            i32::try_from(width - 1).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        Some(Value::Array(Array::Integer(vec![Some(0), Some(n)])))
+    } else {
+        None
+    };
+
+    Ok((position, confidence_interval))
+}
+
+fn widen(mut breakpoint: Breakpoint, (lo, hi): (i32, i32)) -> io::Result<Breakpoint> {
+    let offset = |n: usize, d: i32| {
+        i64::try_from(n)
+            .ok()
+            .map(|n| n + i64::from(d))
+            .and_then(|n| usize::try_from(n).ok())
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, "invalid confidence interval")
+            })
+    };
+
+    breakpoint.start = offset(breakpoint.start, lo)?;
+    breakpoint.end = offset(breakpoint.end, hi)?;
+
+    if breakpoint.start >= breakpoint.end {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid confidence interval",
+        ));
+    }
+
+    Ok(breakpoint)
+}
+
+fn get_end(info: &Info, start: Position) -> io::Result<Position> {
+    if let Some(n) = get_integer(info, key::END_POSITION)? {
+        return usize::try_from(n)
+            .ok()
+            .and_then(Position::new)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid END"));
+    }
+
+    if let Some(n) = get_integer(info, key::SV_LENGTHS)? {
+        let len = usize::try_from(n.unsigned_abs())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        return start
+            .checked_add(len)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid SVLEN"));
+    }
+
+    Err(io::Error::new(
+        io::ErrorKind::InvalidData,
+        "missing END and SVLEN",
+    ))
+}
+
+fn get_integer(info: &Info, key: &str) -> io::Result<Option<i32>> {
+    match info.get(key).flatten() {
+        None => Ok(None),
+        Some(Value::Integer(n)) => Ok(Some(*n)),
+        Some(Value::Array(Array::Integer(values))) => Ok(values.first().copied().flatten()),
+        Some(_) => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid {key} field value type"),
+        )),
+    }
+}
+
+fn get_confidence_interval(info: &Info, key: &str) -> io::Result<(i32, i32)> {
+    match info.get(key).flatten() {
+        None => Ok((0, 0)),
+        Some(Value::Array(Array::Integer(values))) => match values[..] {
+            [Some(lo), Some(hi)] if lo <= 0 && hi >= 0 => Ok((lo, hi)),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid {key}"),
+            )),
+        },
+        Some(_) => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid {key} field value type"),
+        )),
+    }
+}
+
+fn event_name(record: &RecordBuf) -> Option<String> {
+    let id = record.ids().as_ref().first()?;
+
+    let mate_id = match record.info().get(key::MATE_BREAKEND_IDS).flatten() {
+        Some(Value::String(s)) => Some(s.as_str()),
+        Some(Value::Array(Array::String(values))) => {
+            values.first().and_then(|value| value.as_deref())
+        }
+        _ => None,
+    };
+
+    if let Some(mate_id) = mate_id {
+        for (suffix, mate_suffix) in [("_1", "_2"), ("_2", "_1")] {
+            if let Some(name) = id.strip_suffix(suffix) {
+                if mate_id.strip_suffix(mate_suffix) == Some(name) {
+                    return Some(name.into());
+                }
+            }
+        }
+    }
+
+    Some(id.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_bedpe_record(
+        (name_1, start_1, end_1, strand_1): (&str, usize, usize, Strand),
+        (name_2, start_2, end_2, strand_2): (&str, usize, usize, Strand),
+    ) -> Record {
+        Record {
+            breakpoint_1: Breakpoint {
+                reference_sequence_name: name_1.into(),
+                start: start_1,
+                end: end_1,
+                strand: strand_1,
+            },
+            breakpoint_2: Breakpoint {
+                reference_sequence_name: name_2.into(),
+                start: start_2,
+                end: end_2,
+                strand: strand_2,
+            },
+            name: Some(String::from("sv0")),
+            score: Some(13.0),
+            other_fields: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_from_variant_record_with_symbolic_allele() -> Result<(), Box<dyn std::error::Error>> {
+        let info: Info = [
+            (String::from(key::SV_TYPE), Some(Value::from("DEL"))),
+            (String::from(key::END_POSITION), Some(Value::Integer(21))),
+            (
+                String::from(key::POSITION_CONFIDENCE_INTERVALS),
+                Some(Value::Array(Array::Integer(vec![Some(-2), Some(3)]))),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(8)?)
+            .set_ids([String::from("sv0")].into_iter().collect())
+            .set_reference_bases("N")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("<DEL>")]))
+            .set_quality_score(13.0)
+            .set_info(info)
+            .build();
+
+        let actual = from_variant_record(&record)?;
+        let expected = build_bedpe_record(
+            ("sq0", 5, 11, Strand::Forward),
+            ("sq0", 20, 21, Strand::Reverse),
+        );
+        assert_eq!(actual, expected);
+
+        let info: Info = [(
+            String::from(key::SV_LENGTHS),
+            Some(Value::Array(Array::Integer(vec![Some(-5)]))),
+        )]
+        .into_iter()
+        .collect();
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(8)?)
+            .set_reference_bases("N")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("<DUP:TANDEM>")]))
+            .set_info(info)
+            .build();
+
+        let actual = from_variant_record(&record)?;
+        assert_eq!(actual.breakpoint_1.strand, Strand::Reverse);
+        assert_eq!(actual.breakpoint_2.start, 12);
+        assert_eq!(actual.breakpoint_2.strand, Strand::Forward);
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(8)?)
+            .set_reference_bases("N")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("<INV>")]))
+            .build();
+
+        assert!(matches!(
+            from_variant_record(&record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_variant_record_with_breakend() -> Result<(), Box<dyn std::error::Error>> {
+        let info: Info = [
+            (String::from(key::SV_TYPE), Some(Value::from("BND"))),
+            (
+                String::from(key::MATE_BREAKEND_IDS),
+                Some(Value::from("sv0_2")),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN.checked_add(7).unwrap())
+            .set_ids([String::from("sv0_1")].into_iter().collect())
+            .set_reference_bases("G")
+            .set_info(info)
+            .build();
+
+        for (alt, strands) in [
+            ("G[sq1:13[", (Strand::Forward, Strand::Reverse)),
+            ("G]sq1:13]", (Strand::Forward, Strand::Forward)),
+            ("]sq1:13]G", (Strand::Reverse, Strand::Forward)),
+            ("[sq1:13[G", (Strand::Reverse, Strand::Reverse)),
+        ] {
+            *record.alternate_bases_mut() = AlternateBases::from(vec![String::from(alt)]);
+            let actual = from_variant_record(&record)?;
+
+            assert_eq!(actual.name.as_deref(), Some("sv0"));
+            assert_eq!(actual.breakpoint_1.start, 7);
+            assert_eq!(actual.breakpoint_2.reference_sequence_name, "sq1");
+            assert_eq!(actual.breakpoint_2.start, 12);
+            assert_eq!(actual.breakpoint_2.end, 13);
+            assert_eq!(
+                (actual.breakpoint_1.strand, actual.breakpoint_2.strand),
+                strands
+            );
+        }
+
+        for alt in ["G[sq1:13", "G[sq1[", "[sq1:13[", "G[sq1:13[G"] {
+            *record.alternate_bases_mut() = AlternateBases::from(vec![String::from(alt)]);
+            assert!(from_variant_record(&record).is_err(), "{alt}");
+        }
+
+        *record.ids_mut() = [String::from("bnd_W")].into_iter().collect();
+        *record.alternate_bases_mut() = AlternateBases::from(vec![String::from("G]sq1:13]")]);
+        record.info_mut().insert(
+            String::from(key::MATE_BREAKEND_IDS),
+            Some(Value::from("bnd_Y")),
+        );
+        let actual = from_variant_record(&record)?;
+        assert_eq!(actual.name.as_deref(), Some("bnd_W"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_breakends() -> io::Result<()> {
+        for strands in [
+            (Strand::Forward, Strand::Reverse),
+            (Strand::Forward, Strand::Forward),
+            (Strand::Reverse, Strand::Forward),
+            (Strand::Reverse, Strand::Reverse),
+        ] {
+            let record = build_bedpe_record(("sq0", 5, 11, strands.0), ("sq1", 12, 13, strands.1));
+
+            let (a, b) = to_breakends(&record)?;
+
+            assert_eq!(a.variant_start(), Position::new(6));
+            assert!(a.ids().as_ref().contains("sv0_1"));
+            assert_eq!(
+                a.info().get(key::MATE_BREAKEND_IDS),
+                Some(Some(&Value::from("sv0_2")))
+            );
+            assert_eq!(
+                a.info().get(key::POSITION_CONFIDENCE_INTERVALS),
+                Some(Some(&Value::Array(Array::Integer(vec![Some(0), Some(5)]))))
+            );
+            assert_eq!(b.variant_start(), Position::new(13));
+            assert!(b.ids().as_ref().contains("sv0_2"));
+
+            assert_eq!(from_variant_record(&a)?, record);
+
+            let actual = from_variant_record(&b)?;
+            assert_eq!(actual.name.as_deref(), Some("sv0"));
+            assert_eq!(actual.breakpoint_1, record.breakpoint_2);
+            assert_eq!(actual.breakpoint_2.start, 5);
+            assert_eq!(actual.breakpoint_2.strand, strands.0);
+        }
+
+        let record = build_bedpe_record(
+            ("sq0", 7, 8, Strand::Unknown),
+            ("sq1", 12, 13, Strand::Reverse),
+        );
+
+        assert!(matches!(
+            to_breakends(&record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_symbolic_variant_record() -> io::Result<()> {
+        for (strands, alt) in [
+            ((Strand::Forward, Strand::Reverse), "<DEL>"),
+            ((Strand::Reverse, Strand::Forward), "<DUP>"),
+            ((Strand::Forward, Strand::Forward), "<INV>"),
+        ] {
+            let record = build_bedpe_record(("sq0", 7, 8, strands.0), ("sq0", 19, 21, strands.1));
+
+            let variant_record = to_symbolic_variant_record(&record)?.unwrap();
+
+            assert_eq!(variant_record.variant_start(), Position::new(8));
+            assert_eq!(
+                variant_record.alternate_bases().as_ref(),
+                [String::from(alt)]
+            );
+            assert_eq!(
+                variant_record.info().get(key::END_POSITION),
+                Some(Some(&Value::Integer(20)))
+            );
+
+            if alt != "<INV>" {
+                assert_eq!(from_variant_record(&variant_record)?, record);
+            }
+        }
+
+        let record = build_bedpe_record(
+            ("sq0", 7, 8, Strand::Forward),
+            ("sq1", 19, 20, Strand::Reverse),
+        );
+        assert!(to_symbolic_variant_record(&record)?.is_none());
+
+        let record = build_bedpe_record(
+            ("sq0", 7, 8, Strand::Unknown),
+            ("sq0", 19, 20, Strand::Reverse),
+        );
+        assert!(to_symbolic_variant_record(&record)?.is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,287 @@
+//! BEDPE record.
+
+use std::{error, fmt, num, str::FromStr};
+
+const FIELD_DELIMITER: char = '\t';
+const MISSING: &str = ".";
+
+/// A BEDPE breakpoint strand.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Strand {
+    /// Forward strand (`+`).
+    ///
+    /// The sequence to the left of the breakpoint is retained.
+    Forward,
+    /// Reverse strand (`-`).
+    ///
+    /// The sequence to the right of the breakpoint is retained.
+    Reverse,
+    /// Unknown strand (`.`).
+    #[default]
+    Unknown,
+}
+
+impl AsRef<str> for Strand {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Forward => "+",
+            Self::Reverse => "-",
+            Self::Unknown => MISSING,
+        }
+    }
+}
+
+impl fmt::Display for Strand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// A BEDPE breakpoint.
+///
+/// The interval is 0-based and end-exclusive, i.e., the same as BED.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Breakpoint {
+    /// The reference sequence name.
+    pub reference_sequence_name: String,
+    /// The interval start (0-based, inclusive).
+    pub start: usize,
+    /// The interval end (0-based, exclusive).
+    pub end: usize,
+    /// The strand.
+    pub strand: Strand,
+}
+
+/// A BEDPE record.
+///
+/// A BEDPE record is a pair of breakpoints, e.g., the two ends of a structural variant. The
+/// optional fields after the strands are kept as raw strings.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Record {
+    /// The first breakpoint (columns 1–3 and 9).
+    pub breakpoint_1: Breakpoint,
+    /// The second breakpoint (columns 4–6 and 10).
+    pub breakpoint_2: Breakpoint,
+    /// The name (column 7).
+    pub name: Option<String>,
+    /// The score (column 8).
+    pub score: Option<f32>,
+    /// The optional fields after the strands.
+    pub other_fields: Vec<String>,
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (a, b) = (&self.breakpoint_1, &self.breakpoint_2);
+
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t",
+            a.reference_sequence_name,
+            a.start,
+            a.end,
+            b.reference_sequence_name,
+            b.start,
+            b.end,
+            self.name.as_deref().unwrap_or(MISSING),
+        )?;
+
+        match self.score {
+            Some(score) => write!(f, "{score}")?,
+            None => f.write_str(MISSING)?,
+        }
+
+        write!(f, "\t{}\t{}", a.strand, b.strand)?;
+
+        for field in &self.other_fields {
+            write!(f, "{FIELD_DELIMITER}{field}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned when a raw BEDPE record fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// A reference sequence name is missing.
+    MissingReferenceSequenceName,
+    /// A start is missing.
+    MissingStart,
+    /// A start is invalid.
+    InvalidStart(num::ParseIntError),
+    /// An end is missing.
+    MissingEnd,
+    /// An end is invalid.
+    InvalidEnd(num::ParseIntError),
+    /// The score is invalid.
+    InvalidScore(num::ParseFloatError),
+    /// A strand is invalid.
+    InvalidStrand,
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidStart(e) | Self::InvalidEnd(e) => Some(e),
+            Self::InvalidScore(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingReferenceSequenceName => write!(f, "missing reference sequence name"),
+            Self::MissingStart => write!(f, "missing start"),
+            Self::InvalidStart(_) => write!(f, "invalid start"),
+            Self::MissingEnd => write!(f, "missing end"),
+            Self::InvalidEnd(_) => write!(f, "invalid end"),
+            Self::InvalidScore(_) => write!(f, "invalid score"),
+            Self::InvalidStrand => write!(f, "invalid strand"),
+        }
+    }
+}
+
+impl FromStr for Record {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.split(FIELD_DELIMITER);
+
+        let mut breakpoint_1 = parse_breakpoint(&mut fields)?;
+        let mut breakpoint_2 = parse_breakpoint(&mut fields)?;
+
+        let name = fields.next().filter(|t| *t != MISSING).map(String::from);
+
+        let score = match fields.next() {
+            None | Some(MISSING) => None,
+            Some(t) => t.parse().map(Some).map_err(ParseError::InvalidScore)?,
+        };
+
+        breakpoint_1.strand = parse_strand(fields.next())?;
+        breakpoint_2.strand = parse_strand(fields.next())?;
+
+        let other_fields = fields.map(String::from).collect();
+
+        Ok(Self {
+            breakpoint_1,
+            breakpoint_2,
+            name,
+            score,
+            other_fields,
+        })
+    }
+}
+
+fn parse_breakpoint<'a, I>(fields: &mut I) -> Result<Breakpoint, ParseError>
+where
+    I: Iterator<Item = &'a str>,
+{
+    let reference_sequence_name = fields
+        .next()
+        .filter(|t| !t.is_empty())
+        .ok_or(ParseError::MissingReferenceSequenceName)?;
+
+    let start = fields
+        .next()
+        .ok_or(ParseError::MissingStart)
+        .and_then(|t| t.parse().map_err(ParseError::InvalidStart))?;
+
+    let end = fields
+        .next()
+        .ok_or(ParseError::MissingEnd)
+        .and_then(|t| t.parse().map_err(ParseError::InvalidEnd))?;
+
+    Ok(Breakpoint {
+        reference_sequence_name: reference_sequence_name.into(),
+        start,
+        end,
+        strand: Strand::Unknown,
+    })
+}
+
+fn parse_strand(s: Option<&str>) -> Result<Strand, ParseError> {
+    match s {
+        None | Some(MISSING) => Ok(Strand::Unknown),
+        Some("+") => Ok(Strand::Forward),
+        Some("-") => Ok(Strand::Reverse),
+        Some(_) => Err(ParseError::InvalidStrand),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        let record = Record {
+            breakpoint_1: Breakpoint {
+                reference_sequence_name: String::from("sq0"),
+                start: 7,
+                end: 8,
+                strand: Strand::Forward,
+            },
+            breakpoint_2: Breakpoint {
+                reference_sequence_name: String::from("sq1"),
+                start: 12,
+                end: 13,
+                strand: Strand::Reverse,
+            },
+            name: Some(String::from("sv0")),
+            score: Some(5.0),
+            other_fields: vec![String::from("BND")],
+        };
+
+        assert_eq!(
+            record.to_string(),
+            "sq0\t7\t8\tsq1\t12\t13\tsv0\t5\t+\t-\tBND"
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        let expected = Record {
+            breakpoint_1: Breakpoint {
+                reference_sequence_name: String::from("sq0"),
+                start: 7,
+                end: 8,
+                strand: Strand::Forward,
+            },
+            breakpoint_2: Breakpoint {
+                reference_sequence_name: String::from("sq1"),
+                start: 12,
+                end: 13,
+                strand: Strand::Reverse,
+            },
+            name: Some(String::from("sv0")),
+            score: Some(5.0),
+            other_fields: vec![String::from("BND")],
+        };
+
+        assert_eq!(
+            "sq0\t7\t8\tsq1\t12\t13\tsv0\t5\t+\t-\tBND".parse(),
+            Ok(expected)
+        );
+
+        let record: Record = "sq0\t7\t8\tsq1\t12\t13".parse().unwrap();
+        assert!(record.name.is_none());
+        assert_eq!(record.breakpoint_2.strand, Strand::Unknown);
+
+        assert_eq!(
+            "".parse::<Record>(),
+            Err(ParseError::MissingReferenceSequenceName)
+        );
+        assert_eq!("sq0\t7".parse::<Record>(), Err(ParseError::MissingEnd));
+        assert!(matches!(
+            "sq0\t7\t8\tsq1\t.\t13".parse::<Record>(),
+            Err(ParseError::InvalidStart(_))
+        ));
+        assert_eq!(
+            "sq0\t7\t8\tsq1\t12\t13\tsv0\t5\tx\t-".parse::<Record>(),
+            Err(ParseError::InvalidStrand)
+        );
+    }
+}
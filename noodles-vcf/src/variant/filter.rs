@@ -0,0 +1,495 @@
+//! Variant record filter expressions.
+
+mod parser;
+
+pub use self::parser::ParseError;
+
+use std::{cmp::Ordering, io};
+
+use super::{
+    record::{
+        info::field::{value::Array as InfoArray, Value as InfoValue},
+        samples::series::{
+            value::{genotype::Phasing, Array as SamplesArray},
+            Value as SamplesValue,
+        },
+    },
+    Record,
+};
+use crate::Header;
+
+/// A variant record filter.
+///
+/// A filter is parsed from an expression similar to those used by `bcftools view -i`, e.g.,
+/// `QUAL>20 && INFO/DP>10 && FMT/GT="het"`.
+///
+/// # Syntax
+///
+/// Expressions are comparisons (`=` or `==`, `!=`, `<`, `<=`, `>`, `>=`) of operands, combined
+/// with `&&` (or `&`) and `||` (or `|`) and negated with `!`. Parentheses group subexpressions.
+/// `&&` has a higher precedence than `||`.
+///
+/// An operand is a literal (a number or a single- or double-quoted string) or a field. The fields
+/// are
+///
+///   * `CHROM`, `POS`, `ID`, `REF`, `ALT`, `QUAL`, and `FILTER`: the record fields,
+///   * `N_ALT`: the number of alternate bases,
+///   * `INFO/<key>` (or `<key>`): an info field, and
+///   * `FMT/<key>` (or `FORMAT/<key>`): a samples field.
+///
+/// Info and samples fields must be defined in the header, which gives their types. A comparison
+/// of a string and a number is an error. Flag fields cannot be compared, but like any other
+/// field, a flag can be used alone, which tests whether it is set.
+///
+/// A field can have multiple values, e.g., `ALT`, array fields, and samples fields (one or more
+/// per sample). A comparison is true if it is true for any of its values. Missing values are
+/// ignored, so any comparison with a missing field is false.
+///
+/// The genotype (`FMT/GT`) can be compared to a string genotype (e.g., `"0/1"`) or one of the
+/// following classes:
+///
+///   * `ref`: all alleles are the reference allele,
+///   * `alt`: at least one allele is an alternate allele,
+///   * `het`: a diploid or polyploid genotype with different alleles,
+///   * `hom`: a diploid or polyploid genotype with identical alleles,
+///   * `hap`: a haploid genotype, and
+///   * `mis`: all alleles are missing.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{self as vcf, variant::filter::Filter};
+///
+/// let header: vcf::Header = concat!(
+///     "##fileformat=VCFv4.5\n",
+///     "##INFO=<ID=DP,Number=1,Type=Integer,Description=\"Combined depth across samples\">\n",
+///     "##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">\n",
+///     "#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\n",
+/// )
+/// .parse()?;
+///
+/// let filter = Filter::parse(&header, "QUAL>20 && INFO/DP>10 && FMT/GT=\"het\"")?;
+///
+/// let record = vcf::Record::try_from(&b"sq0\t1\t.\tA\tC\t34\tPASS\tDP=13\tGT\t0/1"[..])?;
+/// assert!(filter.matches(&header, &record)?);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Filter(Expression);
+
+impl Filter {
+    /// Parses a filter expression.
+    ///
+    /// The header is used to resolve info and samples fields and check their types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, variant::filter::Filter};
+    /// let header = vcf::Header::default();
+    /// let filter = Filter::parse(&header, "QUAL >= 30 && FILTER == \"PASS\"")?;
+    /// # Ok::<_, vcf::variant::filter::ParseError>(())
+    /// ```
+    pub fn parse(header: &Header, s: &str) -> Result<Self, ParseError> {
+        parser::parse(header, s).map(Self)
+    }
+
+    /// Returns whether the given record matches the filter.
+    ///
+    /// This returns an error if a record field fails to decode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, variant::filter::Filter};
+    ///
+    /// let header = vcf::Header::default();
+    /// let filter = Filter::parse(&header, "N_ALT > 1 || ALT = \"<DEL>\"")?;
+    ///
+    /// let record = vcf::Record::try_from(&b"sq0\t1\t.\tA\tC,G\t.\t.\t."[..])?;
+    /// assert!(filter.matches(&header, &record)?);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn matches<R>(&self, header: &Header, record: &R) -> io::Result<bool>
+    where
+        R: Record + ?Sized,
+    {
+        evaluate(&self.0, header, record)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Expression {
+    Or(Box<Expression>, Box<Expression>),
+    And(Box<Expression>, Box<Expression>),
+    Not(Box<Expression>),
+    Comparison(Operand, Operator, Operand),
+    Operand(Operand),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Operator {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Operand {
+    Literal(Value),
+    Field(Field),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Field {
+    ReferenceSequenceName,
+    VariantStart,
+    Ids,
+    ReferenceBases,
+    AlternateBases,
+    QualityScore,
+    Filters,
+    AlternateBasesCount,
+    Info(String),
+    Samples(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Value {
+    Flag,
+    Integer(i64),
+    Float(f64),
+    String(String),
+    Genotype(Vec<(Option<usize>, Phasing)>),
+}
+
+fn evaluate<R>(expression: &Expression, header: &Header, record: &R) -> io::Result<bool>
+where
+    R: Record + ?Sized,
+{
+    match expression {
+        Expression::Or(a, b) => Ok(evaluate(a, header, record)? || evaluate(b, header, record)?),
+        Expression::And(a, b) => Ok(evaluate(a, header, record)? && evaluate(b, header, record)?),
+        Expression::Not(a) => evaluate(a, header, record).map(|is_match| !is_match),
+        Expression::Comparison(a, operator, b) => {
+            let a = resolve(a, header, record)?;
+            let b = resolve(b, header, record)?;
+
+            Ok(a.iter().any(|x| b.iter().any(|y| compare(x, *operator, y))))
+        }
+        Expression::Operand(a) => resolve(a, header, record).map(|values| !values.is_empty()),
+    }
+}
+
+// Returns all present values of an operand. Missing values are skipped.
+fn resolve<R>(operand: &Operand, header: &Header, record: &R) -> io::Result<Vec<Value>>
+where
+    R: Record + ?Sized,
+{
+    let field = match operand {
+        Operand::Literal(value) => return Ok(vec![value.clone()]),
+        Operand::Field(field) => field,
+    };
+
+    match field {
+        Field::ReferenceSequenceName => record
+            .reference_sequence_name(header)
+            .map(|name| vec![Value::String(name.into())]),
+        Field::VariantStart => record.variant_start().transpose().map(|position| {
+            position
+                .map(|p| Value::Integer(usize::from(p) as i64))
+                .into_iter()
+                .collect()
+        }),
+        Field::Ids => Ok(record
+            .ids()
+            .iter()
+            .map(|id| Value::String(id.into()))
+            .collect()),
+        Field::ReferenceBases => record
+            .reference_bases()
+            .iter()
+            .collect::<io::Result<Vec<_>>>()
+            .map(|bases| vec![Value::String(String::from_utf8_lossy(&bases).into())]),
+        Field::AlternateBases => record
+            .alternate_bases()
+            .iter()
+            .map(|result| result.map(|s| Value::String(s.into())))
+            .collect(),
+        Field::QualityScore => record
+            .quality_score()
+            .transpose()
+            .map(|n| n.map(|n| Value::Float(f64::from(n))).into_iter().collect()),
+        Field::Filters => record
+            .filters()
+            .iter(header)
+            .map(|result| result.map(|s| Value::String(s.into())))
+            .collect(),
+        Field::AlternateBasesCount => {
+            Ok(vec![Value::Integer(record.alternate_bases().len() as i64)])
+        }
+        Field::Info(key) => {
+            let info = record.info();
+
+            let mut values = Vec::new();
+
+            if let Some(value) = info.get(header, key).transpose()?.flatten() {
+                push_info_values(&mut values, value)?;
+            }
+
+            Ok(values)
+        }
+        Field::Samples(key) => {
+            let samples = record.samples()?;
+
+            let mut values = Vec::new();
+
+            if let Some(series) = samples.select(header, key).transpose()? {
+                for result in series.iter(header) {
+                    if let Some(value) = result? {
+                        push_samples_values(&mut values, value)?;
+                    }
+                }
+            }
+
+            Ok(values)
+        }
+    }
+}
+
+fn push_info_values(values: &mut Vec<Value>, value: InfoValue<'_>) -> io::Result<()> {
+    match value {
+        InfoValue::Integer(n) => values.push(Value::Integer(i64::from(n))),
+        InfoValue::Float(n) => values.push(Value::Float(f64::from(n))),
+        InfoValue::Flag => values.push(Value::Flag),
+        InfoValue::Character(c) => values.push(Value::String(c.into())),
+        InfoValue::String(s) => values.push(Value::String(s.into())),
+        InfoValue::Array(InfoArray::Integer(vs)) => {
+            for v in vs.iter() {
+                values.extend(v?.map(|n| Value::Integer(i64::from(n))));
+            }
+        }
+        InfoValue::Array(InfoArray::Float(vs)) => {
+            for v in vs.iter() {
+                values.extend(v?.map(|n| Value::Float(f64::from(n))));
+            }
+        }
+        InfoValue::Array(InfoArray::Character(vs)) => {
+            for v in vs.iter() {
+                values.extend(v?.map(|c| Value::String(c.into())));
+            }
+        }
+        InfoValue::Array(InfoArray::String(vs)) => {
+            for v in vs.iter() {
+                values.extend(v?.map(|s| Value::String(s.into())));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn push_samples_values(values: &mut Vec<Value>, value: SamplesValue<'_>) -> io::Result<()> {
+    match value {
+        SamplesValue::Integer(n) => values.push(Value::Integer(i64::from(n))),
+        SamplesValue::Float(n) => values.push(Value::Float(f64::from(n))),
+        SamplesValue::Character(c) => values.push(Value::String(c.into())),
+        SamplesValue::String(s) => values.push(Value::String(s.into())),
+        SamplesValue::Genotype(genotype) => {
+            let alleles = genotype.iter().collect::<io::Result<_>>()?;
+            values.push(Value::Genotype(alleles));
+        }
+        SamplesValue::Array(SamplesArray::Integer(vs)) => {
+            for v in vs.iter() {
+                values.extend(v?.map(|n| Value::Integer(i64::from(n))));
+            }
+        }
+        SamplesValue::Array(SamplesArray::Float(vs)) => {
+            for v in vs.iter() {
+                values.extend(v?.map(|n| Value::Float(f64::from(n))));
+            }
+        }
+        SamplesValue::Array(SamplesArray::Character(vs)) => {
+            for v in vs.iter() {
+                values.extend(v?.map(|c| Value::String(c.into())));
+            }
+        }
+        SamplesValue::Array(SamplesArray::String(vs)) => {
+            for v in vs.iter() {
+                values.extend(v?.map(|s| Value::String(s.into())));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn compare(a: &Value, operator: Operator, b: &Value) -> bool {
+    let ordering = match (a, b) {
+        (Value::Integer(a), Value::Integer(b)) => Some(a.cmp(b)),
+        (Value::Integer(a), Value::Float(b)) => (*a as f64).partial_cmp(b),
+        (Value::Float(a), Value::Integer(b)) => a.partial_cmp(&(*b as f64)),
+        (Value::Float(a), Value::Float(b)) => a.partial_cmp(b),
+        (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+        (Value::Genotype(alleles), Value::String(s)) => {
+            return compare_genotype(alleles, operator, s)
+        }
+        (Value::String(s), Value::Genotype(alleles)) => {
+            return compare_genotype(alleles, reverse(operator), s)
+        }
+        _ => None,
+    };
+
+    ordering.map(|o| is_satisfied(operator, o)).unwrap_or(false)
+}
+
+fn compare_genotype(alleles: &[(Option<usize>, Phasing)], operator: Operator, s: &str) -> bool {
+    if let Some(is_class) = classify_genotype(alleles, s) {
+        match operator {
+            Operator::Eq => return is_class,
+            Operator::Ne => return !is_class,
+            _ => {}
+        }
+    }
+
+    let genotype = format_genotype(alleles);
+    is_satisfied(operator, genotype.as_str().cmp(s))
+}
+
+fn classify_genotype(alleles: &[(Option<usize>, Phasing)], class: &str) -> Option<bool> {
+    let positions: Vec<_> = alleles.iter().map(|(position, _)| *position).collect();
+    let is_complete = positions.iter().all(Option::is_some);
+
+    let is_class = match class {
+        "ref" => is_complete && positions.iter().all(|p| *p == Some(0)),
+        "alt" => positions.iter().any(|p| matches!(p, Some(n) if *n > 0)),
+        "het" => is_complete && positions.len() > 1 && positions.windows(2).any(|w| w[0] != w[1]),
+        "hom" => is_complete && positions.len() > 1 && positions.windows(2).all(|w| w[0] == w[1]),
+        "hap" => is_complete && positions.len() == 1,
+        "mis" => !positions.is_empty() && positions.iter().all(Option::is_none),
+        _ => return None,
+    };
+
+    Some(is_class)
+}
+
+fn format_genotype(alleles: &[(Option<usize>, Phasing)]) -> String {
+    let mut s = String::new();
+
+    for (i, (position, phasing)) in alleles.iter().enumerate() {
+        if i > 0 {
+            s.push(match phasing {
+                Phasing::Phased => '|',
+                Phasing::Unphased => '/',
+            });
+        }
+
+        match position {
+            Some(n) => s.push_str(&n.to_string()),
+            None => s.push('.'),
+        }
+    }
+
+    s
+}
+
+fn reverse(operator: Operator) -> Operator {
+    match operator {
+        Operator::Lt => Operator::Gt,
+        Operator::Le => Operator::Ge,
+        Operator::Gt => Operator::Lt,
+        Operator::Ge => Operator::Le,
+        operator => operator,
+    }
+}
+
+fn is_satisfied(operator: Operator, ordering: Ordering) -> bool {
+    match operator {
+        Operator::Eq => ordering == Ordering::Equal,
+        Operator::Ne => ordering != Ordering::Equal,
+        Operator::Lt => ordering == Ordering::Less,
+        Operator::Le => ordering != Ordering::Greater,
+        Operator::Gt => ordering == Ordering::Greater,
+        Operator::Ge => ordering != Ordering::Less,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_header() -> Result<Header, Box<dyn std::error::Error>> {
+        let header = "##fileformat=VCFv4.5
+##INFO=<ID=DP,Number=1,Type=Integer,Description=\"Combined depth across samples\">
+##INFO=<ID=AF,Number=A,Type=Float,Description=\"Allele frequency\">
+##INFO=<ID=DB,Number=0,Type=Flag,Description=\"dbSNP membership\">
+##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+##FORMAT=<ID=GQ,Number=1,Type=Integer,Description=\"Conditional genotype quality\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\tsample1
+"
+        .parse()?;
+
+        Ok(header)
+    }
+
+    fn matches(
+        header: &Header,
+        record: &crate::Record,
+        s: &str,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        let filter = Filter::parse(header, s)?;
+        Ok(filter.matches(header, record)?)
+    }
+
+    #[test]
+    fn test_matches() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+
+        let record = crate::Record::try_from(
+            &b"sq0\t8\trs13\tA\tC,G\t34.5\tPASS\tDP=13;AF=0.25,0.5;DB\tGT:GQ\t0/1:21\t1|1:."[..],
+        )?;
+
+        for s in [
+            "QUAL>20 && INFO/DP>10 && FMT/GT=\"het\"",
+            "CHROM == 'sq0' && POS = 8 && ID = \"rs13\"",
+            "REF = \"A\" && ALT = \"G\" && N_ALT = 2",
+            "FILTER = \"PASS\"",
+            "DP >= 13 & DB",
+            "INFO/AF > 0.4 && INFO/AF < 0.3",
+            "FMT/GT = \"hom\" && FMT/GT = \"alt\" && FMT/GT != \"ref\"",
+            "FMT/GT = \"1|1\" && \"0/1\" = FMT/GT",
+            "FMT/GQ > 20 && !(FMT/GQ < 20)",
+        ] {
+            assert!(matches(&header, &record, s)?, "{s}");
+        }
+
+        for s in [
+            "FMT/GT = \"mis\" | FMT/GT = \"hap\"",
+            "QUAL < 30 || DP > 13",
+        ] {
+            assert!(!matches(&header, &record, s)?, "{s}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_matches_with_missing_values() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+        let record = crate::Record::try_from(&b"sq0\t8\t.\tA\t.\t.\t.\t.\tGT:GQ\t./.:.\t."[..])?;
+
+        assert!(matches(
+            &header,
+            &record,
+            "!QUAL && !DP && !FILTER && !ID && N_ALT == 0"
+        )?);
+        assert!(!matches(&header, &record, "QUAL < 30 || QUAL >= 30")?);
+        assert!(!matches(&header, &record, "FMT/GQ")?);
+        assert!(matches(&header, &record, "FMT/GT = \"mis\"")?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,433 @@
+use std::{error, fmt, iter::Peekable, str::CharIndices};
+
+use super::{Expression, Field, Operand, Operator, Value};
+use crate::{
+    header::record::value::map::{format, info},
+    variant::record::samples::keys::key,
+    Header,
+};
+
+/// An error returned when a variant record filter expression fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input ended unexpectedly.
+    UnexpectedEof,
+    /// A character is unexpected.
+    UnexpectedCharacter(char),
+    /// A token is unexpected.
+    UnexpectedToken(String),
+    /// A string is not terminated.
+    UnterminatedString,
+    /// A number is invalid.
+    InvalidNumber(String),
+    /// A field is invalid.
+    InvalidField(String),
+    /// An info field is not defined in the header.
+    UndefinedInfoField(String),
+    /// A samples field is not defined in the header.
+    UndefinedSamplesField(String),
+    /// The operands of a comparison have incompatible types.
+    TypeMismatch,
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEof => f.write_str("unexpected EOF"),
+            Self::UnexpectedCharacter(c) => write!(f, "unexpected character: {c:?}"),
+            Self::UnexpectedToken(s) => write!(f, "unexpected token: {s}"),
+            Self::UnterminatedString => f.write_str("unterminated string"),
+            Self::InvalidNumber(s) => write!(f, "invalid number: {s}"),
+            Self::InvalidField(s) => write!(f, "invalid field: {s}"),
+            Self::UndefinedInfoField(key) => write!(f, "undefined info field: {key}"),
+            Self::UndefinedSamplesField(key) => write!(f, "undefined samples field: {key}"),
+            Self::TypeMismatch => f.write_str("type mismatch"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Type {
+    Number,
+    String,
+    Flag,
+    Genotype,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Identifier(String),
+    Number(String),
+    String(String),
+    Operator(Operator),
+    And,
+    Or,
+    Not,
+    LeftParen,
+    RightParen,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Identifier(s) | Self::Number(s) => f.write_str(s),
+            Self::String(s) => write!(f, "{s:?}"),
+            Self::Operator(operator) => f.write_str(match operator {
+                Operator::Eq => "==",
+                Operator::Ne => "!=",
+                Operator::Lt => "<",
+                Operator::Le => "<=",
+                Operator::Gt => ">",
+                Operator::Ge => ">=",
+            }),
+            Self::And => f.write_str("&&"),
+            Self::Or => f.write_str("||"),
+            Self::Not => f.write_str("!"),
+            Self::LeftParen => f.write_str("("),
+            Self::RightParen => f.write_str(")"),
+        }
+    }
+}
+
+type Tokens = Peekable<std::vec::IntoIter<Token>>;
+
+pub(super) fn parse(header: &Header, s: &str) -> Result<Expression, ParseError> {
+    let tokens = tokenize(s)?;
+    let mut tokens = tokens.into_iter().peekable();
+
+    let expression = parse_or(header, &mut tokens)?;
+
+    match tokens.next() {
+        Some(token) => Err(ParseError::UnexpectedToken(token.to_string())),
+        None => Ok(expression),
+    }
+}
+
+fn parse_or(header: &Header, tokens: &mut Tokens) -> Result<Expression, ParseError> {
+    let mut expression = parse_and(header, tokens)?;
+
+    while tokens.next_if_eq(&Token::Or).is_some() {
+        let rhs = parse_and(header, tokens)?;
+        expression = Expression::Or(Box::new(expression), Box::new(rhs));
+    }
+
+    Ok(expression)
+}
+
+fn parse_and(header: &Header, tokens: &mut Tokens) -> Result<Expression, ParseError> {
+    let mut expression = parse_not(header, tokens)?;
+
+    while tokens.next_if_eq(&Token::And).is_some() {
+        let rhs = parse_not(header, tokens)?;
+        expression = Expression::And(Box::new(expression), Box::new(rhs));
+    }
+
+    Ok(expression)
+}
+
+fn parse_not(header: &Header, tokens: &mut Tokens) -> Result<Expression, ParseError> {
+    if tokens.next_if_eq(&Token::Not).is_some() {
+        parse_not(header, tokens).map(|expression| Expression::Not(Box::new(expression)))
+    } else {
+        parse_comparison(header, tokens)
+    }
+}
+
+fn parse_comparison(header: &Header, tokens: &mut Tokens) -> Result<Expression, ParseError> {
+    if tokens.next_if_eq(&Token::LeftParen).is_some() {
+        let expression = parse_or(header, tokens)?;
+
+        return match tokens.next() {
+            Some(Token::RightParen) => Ok(expression),
+            Some(token) => Err(ParseError::UnexpectedToken(token.to_string())),
+            None => Err(ParseError::UnexpectedEof),
+        };
+    }
+
+    let (lhs, lhs_type) = parse_operand(header, tokens)?;
+
+    if let Some(Token::Operator(operator)) = tokens.peek().cloned() {
+        tokens.next();
+
+        let (rhs, rhs_type) = parse_operand(header, tokens)?;
+
+        if is_comparable(lhs_type, rhs_type) {
+            Ok(Expression::Comparison(lhs, operator, rhs))
+        } else {
+            Err(ParseError::TypeMismatch)
+        }
+    } else {
+        Ok(Expression::Operand(lhs))
+    }
+}
+
+fn is_comparable(a: Type, b: Type) -> bool {
+    matches!(
+        (a, b),
+        (Type::Number, Type::Number)
+            | (Type::String, Type::String)
+            | (Type::Genotype, Type::String)
+            | (Type::String, Type::Genotype)
+    )
+}
+
+fn parse_operand(header: &Header, tokens: &mut Tokens) -> Result<(Operand, Type), ParseError> {
+    match tokens.next().ok_or(ParseError::UnexpectedEof)? {
+        Token::Identifier(s) => {
+            parse_field(header, &s).map(|(field, ty)| (Operand::Field(field), ty))
+        }
+        Token::Number(s) => parse_number(&s).map(|value| (Operand::Literal(value), Type::Number)),
+        Token::String(s) => Ok((Operand::Literal(Value::String(s)), Type::String)),
+        token => Err(ParseError::UnexpectedToken(token.to_string())),
+    }
+}
+
+fn parse_field(header: &Header, s: &str) -> Result<(Field, Type), ParseError> {
+    let (field, ty) = match s {
+        "CHROM" => (Field::ReferenceSequenceName, Type::String),
+        "POS" => (Field::VariantStart, Type::Number),
+        "ID" => (Field::Ids, Type::String),
+        "REF" => (Field::ReferenceBases, Type::String),
+        "ALT" => (Field::AlternateBases, Type::String),
+        "QUAL" => (Field::QualityScore, Type::Number),
+        "FILTER" => (Field::Filters, Type::String),
+        "N_ALT" => (Field::AlternateBasesCount, Type::Number),
+        _ => {
+            if let Some(key) = s.strip_prefix("INFO/") {
+                parse_info_field(header, key)?
+            } else if let Some(key) = s.strip_prefix("FMT/").or_else(|| s.strip_prefix("FORMAT/")) {
+                parse_samples_field(header, key)?
+            } else if header.infos().contains_key(s) {
+                parse_info_field(header, s)?
+            } else {
+                return Err(ParseError::InvalidField(s.into()));
+            }
+        }
+    };
+
+    Ok((field, ty))
+}
+
+fn parse_info_field(header: &Header, key: &str) -> Result<(Field, Type), ParseError> {
+    let info = header
+        .infos()
+        .get(key)
+        .ok_or_else(|| ParseError::UndefinedInfoField(key.into()))?;
+
+    let ty = match info.ty() {
+        info::Type::Integer | info::Type::Float => Type::Number,
+        info::Type::Flag => Type::Flag,
+        info::Type::Character | info::Type::String => Type::String,
+    };
+
+    Ok((Field::Info(key.into()), ty))
+}
+
+fn parse_samples_field(header: &Header, key: &str) -> Result<(Field, Type), ParseError> {
+    let ty = if key == key::GENOTYPE {
+        Type::Genotype
+    } else {
+        let format = header
+            .formats()
+            .get(key)
+            .ok_or_else(|| ParseError::UndefinedSamplesField(key.into()))?;
+
+        match format.ty() {
+            format::Type::Integer | format::Type::Float => Type::Number,
+            format::Type::Character | format::Type::String => Type::String,
+        }
+    };
+
+    Ok((Field::Samples(key.into()), ty))
+}
+
+fn parse_number(s: &str) -> Result<Value, ParseError> {
+    if let Ok(n) = s.parse() {
+        Ok(Value::Integer(n))
+    } else {
+        s.parse()
+            .map(Value::Float)
+            .map_err(|_| ParseError::InvalidNumber(s.into()))
+    }
+}
+
+fn tokenize(s: &str) -> Result<Vec<Token>, ParseError> {
+    let mut chars = s.char_indices().peekable();
+    let mut tokens = Vec::new();
+
+    while let Some((i, c)) = chars.next() {
+        let token = match c {
+            _ if c.is_whitespace() => continue,
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            '&' => {
+                chars.next_if(|(_, c)| *c == '&');
+                Token::And
+            }
+            '|' => {
+                chars.next_if(|(_, c)| *c == '|');
+                Token::Or
+            }
+            '=' => {
+                chars.next_if(|(_, c)| *c == '=');
+                Token::Operator(Operator::Eq)
+            }
+            '!' => {
+                if chars.next_if(|(_, c)| *c == '=').is_some() {
+                    Token::Operator(Operator::Ne)
+                } else {
+                    Token::Not
+                }
+            }
+            '<' => {
+                if chars.next_if(|(_, c)| *c == '=').is_some() {
+                    Token::Operator(Operator::Le)
+                } else {
+                    Token::Operator(Operator::Lt)
+                }
+            }
+            '>' => {
+                if chars.next_if(|(_, c)| *c == '=').is_some() {
+                    Token::Operator(Operator::Ge)
+                } else {
+                    Token::Operator(Operator::Gt)
+                }
+            }
+            '"' | '\'' => tokenize_string(&mut chars, c)?,
+            '-' | '.' | '0'..='9' => {
+                let end = take_while(&mut chars, i + 1, |c| {
+                    c.is_ascii_alphanumeric() || matches!(c, '.' | '+' | '-')
+                });
+
+                Token::Number(s[i..end].into())
+            }
+            'A'..='Z' | 'a'..='z' | '_' => {
+                let end = take_while(&mut chars, i + 1, |c| {
+                    c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '/')
+                });
+
+                Token::Identifier(s[i..end].into())
+            }
+            _ => return Err(ParseError::UnexpectedCharacter(c)),
+        };
+
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+fn take_while<F>(chars: &mut Peekable<CharIndices<'_>>, start: usize, f: F) -> usize
+where
+    F: Fn(char) -> bool,
+{
+    let mut end = start;
+
+    while let Some((i, c)) = chars.next_if(|(_, c)| f(*c)) {
+        end = i + c.len_utf8();
+    }
+
+    end
+}
+
+fn tokenize_string(
+    chars: &mut Peekable<CharIndices<'_>>,
+    quote: char,
+) -> Result<Token, ParseError> {
+    let mut buf = String::new();
+
+    loop {
+        match chars.next() {
+            Some((_, c)) if c == quote => return Ok(Token::String(buf)),
+            Some((_, '\\')) => match chars.next() {
+                Some((_, c)) => buf.push(c),
+                None => return Err(ParseError::UnterminatedString),
+            },
+            Some((_, c)) => buf.push(c),
+            None => return Err(ParseError::UnterminatedString),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> Result<(), Box<dyn std::error::Error>> {
+        let header: Header = "##fileformat=VCFv4.5
+##INFO=<ID=DP,Number=1,Type=Integer,Description=\"Combined depth across samples\">
+##INFO=<ID=DB,Number=0,Type=Flag,Description=\"dbSNP membership\">
+##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0
+"
+        .parse()?;
+
+        assert_eq!(
+            parse(&header, "!DB && QUAL>=30 | FMT/GT='het'"),
+            Ok(Expression::Or(
+                Box::new(Expression::And(
+                    Box::new(Expression::Not(Box::new(Expression::Operand(
+                        Operand::Field(Field::Info(String::from("DB")))
+                    )))),
+                    Box::new(Expression::Comparison(
+                        Operand::Field(Field::QualityScore),
+                        Operator::Ge,
+                        Operand::Literal(Value::Integer(30)),
+                    )),
+                )),
+                Box::new(Expression::Comparison(
+                    Operand::Field(Field::Samples(String::from("GT"))),
+                    Operator::Eq,
+                    Operand::Literal(Value::String(String::from("het"))),
+                )),
+            ))
+        );
+
+        assert_eq!(
+            parse(&header, "INFO/DP < 1e3"),
+            Ok(Expression::Comparison(
+                Operand::Field(Field::Info(String::from("DP"))),
+                Operator::Lt,
+                Operand::Literal(Value::Float(1000.0)),
+            ))
+        );
+
+        assert_eq!(parse(&header, ""), Err(ParseError::UnexpectedEof));
+        assert_eq!(parse(&header, "(DB"), Err(ParseError::UnexpectedEof));
+        assert_eq!(
+            parse(&header, "DP 8"),
+            Err(ParseError::UnexpectedToken(String::from("8")))
+        );
+        assert_eq!(
+            parse(&header, "DP ~ 8"),
+            Err(ParseError::UnexpectedCharacter('~'))
+        );
+        assert_eq!(
+            parse(&header, "CHROM = \"sq0"),
+            Err(ParseError::UnterminatedString)
+        );
+        assert_eq!(
+            parse(&header, "POS > 1.2.3"),
+            Err(ParseError::InvalidNumber(String::from("1.2.3")))
+        );
+        assert_eq!(
+            parse(&header, "AF > 0.5"),
+            Err(ParseError::InvalidField(String::from("AF")))
+        );
+        assert_eq!(
+            parse(&header, "INFO/AF > 0.5"),
+            Err(ParseError::UndefinedInfoField(String::from("AF")))
+        );
+        assert_eq!(
+            parse(&header, "FMT/GQ > 20"),
+            Err(ParseError::UndefinedSamplesField(String::from("GQ")))
+        );
+        assert_eq!(parse(&header, "DP = \"8\""), Err(ParseError::TypeMismatch));
+        assert_eq!(parse(&header, "DB = 1"), Err(ParseError::TypeMismatch));
+
+        Ok(())
+    }
+}
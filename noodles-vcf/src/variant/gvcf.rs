@@ -0,0 +1,545 @@
+//! Genomic VCF (gVCF) reference blocks.
+//!
+//! A gVCF includes reference blocks, i.e., records that summarize a stretch of positions that
+//! match the reference. A reference block has a single non-reference alternate allele
+//! (`<NON_REF>` or `<*>`), the reference base at its start position as its reference bases, and
+//! an `END` INFO field that is its end position.
+
+use std::io;
+
+use noodles_core::{Position, Region};
+use noodles_fasta as fasta;
+
+use super::{
+    record::info::field::key,
+    record_buf::{info::field::Value, AlternateBases},
+    RecordBuf,
+};
+use crate::{
+    header::record::value::{map::AlternativeAllele, Map},
+    Header,
+};
+
+/// The ID of the non-reference allele (`NON_REF`).
+pub const NON_REF: &str = "NON_REF";
+
+const NON_REF_ALLELE: &str = "<NON_REF>";
+const UNSPECIFIED_ALLELE: &str = "<*>";
+
+/// Returns the end position of the record from the `END` INFO field.
+///
+/// This is `None` if the record does not have an `END` field.
+///
+/// # Errors
+///
+/// This returns an error if the `END` value is not a valid position.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_vcf::variant::{gvcf, RecordBuf};
+///
+/// let record = gvcf::reference_block("sq0", Position::MIN, Position::try_from(8)?, b'A');
+/// assert_eq!(gvcf::end(&record)?, Position::new(8));
+///
+/// let record = RecordBuf::default();
+/// assert!(gvcf::end(&record)?.is_none());
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn end(record: &RecordBuf) -> io::Result<Option<Position>> {
+    match record.info().get(key::END_POSITION) {
+        Some(Some(Value::Integer(n))) => usize::try_from(*n)
+            .ok()
+            .and_then(Position::new)
+            .map(Some)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid END position")),
+        Some(Some(_)) => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid END value type",
+        )),
+        Some(None) | None => Ok(None),
+    }
+}
+
+/// Sets the `END` INFO field of the record.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_vcf::variant::{gvcf, RecordBuf};
+///
+/// let mut record = RecordBuf::default();
+/// gvcf::set_end(&mut record, Position::try_from(8)?)?;
+/// assert_eq!(gvcf::end(&record)?, Position::new(8));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn set_end(record: &mut RecordBuf, end: Position) -> io::Result<()> {
+    let n = i32::try_from(usize::from(end))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    record
+        .info_mut()
+        .insert(String::from(key::END_POSITION), Some(Value::Integer(n)));
+
+    Ok(())
+}
+
+/// Returns whether the record is a reference block.
+///
+/// A reference block has a single non-reference alternate allele (`<NON_REF>` or `<*>`).
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_vcf::variant::{gvcf, RecordBuf};
+///
+/// let record = gvcf::reference_block("sq0", Position::MIN, Position::try_from(8)?, b'A');
+/// assert!(gvcf::is_reference_block(&record));
+///
+/// let record = RecordBuf::default();
+/// assert!(!gvcf::is_reference_block(&record));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn is_reference_block(record: &RecordBuf) -> bool {
+    matches!(
+        record.alternate_bases().as_ref(),
+        [allele] if allele == NON_REF_ALLELE || allele == UNSPECIFIED_ALLELE
+    )
+}
+
+/// Builds a reference block.
+///
+/// The record has the given reference base, a `<NON_REF>` alternate allele, and an `END` INFO
+/// field. Other fields, e.g., samples, can be set on the returned record.
+///
+/// # Panics
+///
+/// This panics if `end` does not fit in an `END` value (`i32`).
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_vcf::variant::gvcf;
+///
+/// let record = gvcf::reference_block("sq0", Position::MIN, Position::try_from(8)?, b'A');
+///
+/// assert_eq!(record.reference_sequence_name(), "sq0");
+/// assert_eq!(record.variant_start(), Some(Position::MIN));
+/// assert_eq!(record.reference_bases(), "A");
+/// assert_eq!(record.alternate_bases().as_ref(), [String::from("<NON_REF>")]);
+/// assert_eq!(gvcf::end(&record)?, Position::new(8));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn reference_block<N>(
+    reference_sequence_name: N,
+    start: Position,
+    end: Position,
+    reference_base: u8,
+) -> RecordBuf
+where
+    N: Into<String>,
+{
+    let mut record = RecordBuf::builder()
+        .set_reference_sequence_name(reference_sequence_name)
+        .set_variant_start(start)
+        .set_reference_bases(char::from(reference_base).to_string())
+        .set_alternate_bases(AlternateBases::from(vec![String::from(NON_REF_ALLELE)]))
+        .build();
+
+    set_end(&mut record, end).expect("invalid END position");
+
+    record
+}
+
+/// Adds the header records used by reference blocks.
+///
+/// This adds the `END` INFO and `NON_REF` ALT definitions if they are not already in the header.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{self as vcf, variant::gvcf};
+///
+/// let mut header = vcf::Header::default();
+/// gvcf::add_header_records(&mut header);
+///
+/// assert!(header.infos().contains_key("END"));
+/// assert!(header.alternative_alleles().contains_key("NON_REF"));
+/// ```
+pub fn add_header_records(header: &mut Header) {
+    header
+        .infos_mut()
+        .entry(String::from(key::END_POSITION))
+        .or_insert_with(|| Map::from(key::END_POSITION));
+
+    header
+        .alternative_alleles_mut()
+        .entry(String::from(NON_REF))
+        .or_insert_with(|| {
+            Map::<AlternativeAllele>::new(
+                "Represents any possible alternative allele not already represented at this location",
+            )
+        });
+}
+
+/// An iterator that expands reference blocks into single-position records.
+///
+/// This is created by [`expand`].
+pub struct Expand<'a, I> {
+    records: I,
+    repository: &'a fasta::Repository,
+    block: Option<(RecordBuf, Position, Position)>,
+}
+
+impl<I> Iterator for Expand<'_, I>
+where
+    I: Iterator<Item = io::Result<RecordBuf>>,
+{
+    type Item = io::Result<RecordBuf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((block, position, end)) = self.block.take() {
+                let result = clip(&block, position, position, self.repository);
+
+                if let Some(next) = position.checked_add(1).filter(|&next| next <= end) {
+                    self.block = Some((block, next, end));
+                }
+
+                return Some(result);
+            }
+
+            let record = match self.records.next()? {
+                Ok(record) => record,
+                Err(e) => return Some(Err(e)),
+            };
+
+            if !is_reference_block(&record) {
+                return Some(Ok(record));
+            }
+
+            match block_interval(&record) {
+                Ok((start, end)) => self.block = Some((record, start, end)),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+/// Expands reference blocks into single-position records.
+///
+/// Each reference block is replaced by a record for each position it covers, with the reference
+/// base at that position and an `END` equal to that position. Other records are passed through
+/// unchanged.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_core::Position;
+/// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+/// use noodles_vcf::variant::gvcf;
+///
+/// let repository = fasta::Repository::new(vec![fasta::Record::new(
+///     Definition::new("sq0", None),
+///     Sequence::from(b"ACGT".to_vec()),
+/// )]);
+///
+/// let records = [gvcf::reference_block("sq0", Position::try_from(2)?, Position::try_from(3)?, b'C')];
+///
+/// let expanded: Vec<_> = gvcf::expand(records.into_iter().map(Ok), &repository)
+///     .map(|result| result.map(|record| record.reference_bases().to_string()))
+///     .collect::<io::Result<_>>()?;
+///
+/// assert_eq!(expanded, ["C", "G"]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn expand<I>(records: I, repository: &fasta::Repository) -> Expand<'_, I>
+where
+    I: Iterator<Item = io::Result<RecordBuf>>,
+{
+    Expand {
+        records,
+        repository,
+        block: None,
+    }
+}
+
+/// An iterator that intersects records with a region.
+///
+/// This is created by [`intersect`].
+pub struct Intersect<'a, I> {
+    records: I,
+    repository: &'a fasta::Repository,
+    region: Region,
+}
+
+impl<I> Iterator for Intersect<'_, I>
+where
+    I: Iterator<Item = io::Result<RecordBuf>>,
+{
+    type Item = io::Result<RecordBuf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let record = match self.records.next()? {
+                Ok(record) => record,
+                Err(e) => return Some(Err(e)),
+            };
+
+            if record.reference_sequence_name().as_bytes() != self.region.name() {
+                continue;
+            }
+
+            let (start, end) = match record_interval(&record) {
+                Ok(interval) => interval,
+                Err(e) => return Some(Err(e)),
+            };
+
+            let interval = self.region.interval();
+            let region_start = interval.start().unwrap_or(Position::MIN);
+            let region_end = interval.end().unwrap_or(Position::MAX);
+
+            if end < region_start || start > region_end {
+                continue;
+            }
+
+            if is_reference_block(&record) {
+                return Some(clip(
+                    &record,
+                    start.max(region_start),
+                    end.min(region_end),
+                    self.repository,
+                ));
+            }
+
+            return Some(Ok(record));
+        }
+    }
+}
+
+/// Intersects records with a region.
+///
+/// Records that overlap the region are kept. Reference blocks that extend past the region are
+/// trimmed to the region: the start is moved to the start of the region, with the reference
+/// base at that position, and the `END` is moved to the end of the region. Other records are
+/// kept unchanged.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_core::Position;
+/// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+/// use noodles_vcf::variant::gvcf;
+///
+/// let repository = fasta::Repository::new(vec![fasta::Record::new(
+///     Definition::new("sq0", None),
+///     Sequence::from(b"ACGTACGT".to_vec()),
+/// )]);
+///
+/// let records = [gvcf::reference_block("sq0", Position::MIN, Position::try_from(8)?, b'A')];
+/// let region = "sq0:3-5".parse()?;
+///
+/// let intersected: Vec<_> = gvcf::intersect(records.into_iter().map(Ok), &repository, region)
+///     .collect::<io::Result<_>>()?;
+///
+/// assert_eq!(intersected.len(), 1);
+/// assert_eq!(intersected[0].variant_start(), Position::new(3));
+/// assert_eq!(intersected[0].reference_bases(), "G");
+/// assert_eq!(gvcf::end(&intersected[0])?, Position::new(5));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn intersect<I>(records: I, repository: &fasta::Repository, region: Region) -> Intersect<'_, I>
+where
+    I: Iterator<Item = io::Result<RecordBuf>>,
+{
+    Intersect {
+        records,
+        repository,
+        region,
+    }
+}
+
+fn variant_start(record: &RecordBuf) -> io::Result<Position> {
+    record
+        .variant_start()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing variant start"))
+}
+
+fn block_interval(record: &RecordBuf) -> io::Result<(Position, Position)> {
+    let start = variant_start(record)?;
+
+    let end =
+        end(record)?.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing END"))?;
+
+    if end < start {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "END is before the variant start",
+        ));
+    }
+
+    Ok((start, end))
+}
+
+fn record_interval(record: &RecordBuf) -> io::Result<(Position, Position)> {
+    if is_reference_block(record) {
+        return block_interval(record);
+    }
+
+    let start = variant_start(record)?;
+
+    let end = match end(record)? {
+        Some(end) => end,
+        None => {
+            let len = record.reference_bases().len().max(1);
+
+            start
+                .checked_add(len - 1)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "position overflow"))?
+        }
+    };
+
+    Ok((start, end))
+}
+
+fn clip(
+    block: &RecordBuf,
+    start: Position,
+    end: Position,
+    repository: &fasta::Repository,
+) -> io::Result<RecordBuf> {
+    let mut record = block.clone();
+
+    if Some(start) != block.variant_start() {
+        let reference_sequence_name = block.reference_sequence_name();
+
+        let reference_sequence = repository
+            .get(reference_sequence_name.as_bytes())
+            .transpose()?
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("missing reference sequence: {reference_sequence_name}"),
+                )
+            })?;
+
+        let base = reference_sequence.get(start).copied().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "position out of reference sequence bounds: {reference_sequence_name}:{start}"
+                ),
+            )
+        })?;
+
+        *record.variant_start_mut() = Some(start);
+        *record.reference_bases_mut() = char::from(base).to_string();
+    }
+
+    set_end(&mut record, end)?;
+
+    Ok(record)
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_fasta::record::{Definition, Sequence};
+
+    use super::*;
+
+    fn build_repository() -> fasta::Repository {
+        fasta::Repository::new(vec![fasta::Record::new(
+            Definition::new("sq0", None),
+            Sequence::from(b"ACGTACGT".to_vec()),
+        )])
+    }
+
+    fn build_variant(start: usize) -> Result<RecordBuf, Box<dyn std::error::Error>> {
+        Ok(RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(start)?)
+            .set_reference_bases("C")
+            .set_alternate_bases(AlternateBases::from(vec![
+                String::from("T"),
+                String::from(NON_REF_ALLELE),
+            ]))
+            .build())
+    }
+
+    #[test]
+    fn test_expand() -> Result<(), Box<dyn std::error::Error>> {
+        let repository = build_repository();
+
+        let records = vec![
+            reference_block("sq0", Position::MIN, Position::try_from(2)?, b'A'),
+            build_variant(6)?,
+            reference_block("sq0", Position::try_from(7)?, Position::try_from(8)?, b'G'),
+        ];
+
+        let actual: Vec<_> = expand(records.into_iter().map(Ok), &repository)
+            .map(|result| {
+                result.and_then(|record| {
+                    Ok((
+                        record.variant_start().map(usize::from),
+                        record.reference_bases().to_string(),
+                        end(&record)?.map(usize::from),
+                    ))
+                })
+            })
+            .collect::<io::Result<_>>()?;
+
+        let expected = [
+            (Some(1), String::from("A"), Some(1)),
+            (Some(2), String::from("C"), Some(2)),
+            (Some(6), String::from("C"), None),
+            (Some(7), String::from("G"), Some(7)),
+            (Some(8), String::from("T"), Some(8)),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_intersect() -> Result<(), Box<dyn std::error::Error>> {
+        let repository = build_repository();
+
+        let records = vec![
+            reference_block("sq0", Position::MIN, Position::try_from(3)?, b'A'),
+            build_variant(4)?,
+            reference_block("sq0", Position::try_from(5)?, Position::try_from(8)?, b'A'),
+            reference_block("sq1", Position::MIN, Position::try_from(8)?, b'A'),
+        ];
+
+        let region = "sq0:3-6".parse()?;
+
+        let actual: Vec<_> = intersect(records.into_iter().map(Ok), &repository, region)
+            .map(|result| {
+                result.and_then(|record| {
+                    Ok((
+                        record.variant_start().map(usize::from),
+                        record.reference_bases().to_string(),
+                        end(&record)?.map(usize::from),
+                    ))
+                })
+            })
+            .collect::<io::Result<_>>()?;
+
+        let expected = [
+            (Some(3), String::from("G"), Some(3)),
+            (Some(4), String::from("C"), None),
+            (Some(5), String::from("A"), Some(6)),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
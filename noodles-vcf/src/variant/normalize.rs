@@ -0,0 +1,580 @@
+use std::io;
+
+use noodles_core::Position;
+use noodles_fasta as fasta;
+
+use super::{
+    record::samples::keys::key,
+    record_buf::{
+        info::field::{value::Array as InfoArray, Value as InfoValue},
+        samples::{
+            sample::{
+                value::{genotype::Allele, Array as SampleArray, Genotype},
+                Value as SampleValue,
+            },
+            Samples,
+        },
+        AlternateBases,
+    },
+    RecordBuf,
+};
+use crate::{
+    header::record::value::map::{format, info},
+    Header,
+};
+
+/// Normalizes a variant record.
+///
+/// This trims bases shared by all alleles and left-aligns indels against the reference sequence,
+/// i.e., the algorithm used by `vt normalize` and `bcftools norm`:
+///
+///   1. While all alleles end with the same base, the last base is removed. If this would leave
+///      an allele empty, the alleles are first extended one base to the left using the reference
+///      sequence.
+///   2. While all alleles start with the same base and have at least 2 bases, the first base is
+///      removed.
+///
+/// Records with symbolic alleles, breakends, overlapping deletions (`*`), missing alleles, or no
+/// alternate bases are left unchanged.
+///
+/// Multiallelic records are normalized as a whole. Use [`split_multiallelic`] first to normalize
+/// each alternate allele independently.
+///
+/// # Errors
+///
+/// This returns an error if the record is missing a variant start, the reference sequence is not
+/// in the repository, or the reference bases do not match the reference sequence.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+/// use noodles_vcf::variant::{self, RecordBuf};
+///
+/// let repository = fasta::Repository::new(vec![fasta::Record::new(
+///     Definition::new("sq0", None),
+///     Sequence::from(b"GCACACAT".to_vec()),
+/// )]);
+///
+/// // sq0:5 ACA>A is equivalent to sq0:1 GCA>G.
+/// let mut record = RecordBuf::builder()
+///     .set_reference_sequence_name("sq0")
+///     .set_variant_start(Position::try_from(5)?)
+///     .set_reference_bases("ACA")
+///     .set_alternate_bases(vec![String::from("A")].into())
+///     .build();
+///
+/// variant::normalize(&mut record, &repository)?;
+///
+/// assert_eq!(record.variant_start(), Some(Position::MIN));
+/// assert_eq!(record.reference_bases(), "GCA");
+/// assert_eq!(record.alternate_bases().as_ref(), [String::from("G")]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn normalize(record: &mut RecordBuf, repository: &fasta::Repository) -> io::Result<()> {
+    if record.alternate_bases().as_ref().is_empty()
+        || !is_normalizable(record.reference_bases())
+        || !record
+            .alternate_bases()
+            .as_ref()
+            .iter()
+            .all(|allele| is_normalizable(allele))
+    {
+        return Ok(());
+    }
+
+    let start = record
+        .variant_start()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing variant start"))?;
+
+    let reference_sequence_name = record.reference_sequence_name();
+
+    let reference_sequence = repository
+        .get(reference_sequence_name.as_bytes())
+        .transpose()?
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("missing reference sequence: {reference_sequence_name}"),
+            )
+        })?;
+
+    validate_reference_bases(&reference_sequence, start, record.reference_bases())?;
+
+    let mut alleles: Vec<Vec<u8>> = Vec::with_capacity(record.alternate_bases().as_ref().len() + 1);
+    alleles.push(record.reference_bases().as_bytes().to_vec());
+    alleles.extend(
+        record
+            .alternate_bases()
+            .as_ref()
+            .iter()
+            .map(|allele| allele.as_bytes().to_vec()),
+    );
+
+    let mut position = usize::from(start);
+
+    // Trim the right and extend to the left.
+    loop {
+        if !ends_with_same_base(&alleles) {
+            break;
+        }
+
+        if alleles.iter().any(|allele| allele.len() == 1) {
+            let Some(base) = position
+                .checked_sub(1)
+                .and_then(Position::new)
+                .and_then(|p| reference_sequence.get(p))
+                .copied()
+            else {
+                break;
+            };
+
+            for allele in &mut alleles {
+                allele.insert(0, base);
+            }
+
+            position -= 1;
+        }
+
+        for allele in &mut alleles {
+            allele.pop();
+        }
+    }
+
+    // Trim the left.
+    while alleles.iter().all(|allele| allele.len() >= 2) && starts_with_same_base(&alleles) {
+        for allele in &mut alleles {
+            allele.remove(0);
+        }
+
+        position += 1;
+    }
+
+    let mut alleles = alleles.into_iter().map(|allele| {
+        // SAFETY: Alleles only contain ASCII bases.
+        String::from_utf8(allele).unwrap()
+    });
+
+    // SAFETY: There is at least one allele.
+    *record.reference_bases_mut() = alleles.next().unwrap();
+    *record.alternate_bases_mut() = AlternateBases::from(alleles.collect::<Vec<_>>());
+    *record.variant_start_mut() = Position::new(position);
+
+    Ok(())
+}
+
+/// Splits a multiallelic variant record into biallelic records.
+///
+/// Each alternate allele is written to its own record. INFO and FORMAT fields are split using
+/// their header definitions:
+///
+///   * `Number=A` fields keep the value of the alternate allele;
+///   * `Number=R` fields keep the values of the reference and alternate alleles;
+///   * `Number=G` fields keep the values of the genotypes made of the reference and alternate
+///     alleles for haploid and diploid samples; and
+///   * all other fields are copied as-is.
+///
+/// In genotypes (`GT`), the reference allele and the alternate allele are kept, and other
+/// alternate alleles are set as missing.
+///
+/// A record with at most one alternate allele is returned as-is.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_vcf::{self as vcf, variant::{self, RecordBuf}};
+///
+/// let header = vcf::Header::default();
+///
+/// let record = RecordBuf::builder()
+///     .set_reference_sequence_name("sq0")
+///     .set_variant_start(Position::MIN)
+///     .set_reference_bases("A")
+///     .set_alternate_bases(vec![String::from("C"), String::from("G")].into())
+///     .build();
+///
+/// let records = variant::split_multiallelic(&header, &record);
+///
+/// assert_eq!(records.len(), 2);
+/// assert_eq!(records[0].alternate_bases().as_ref(), [String::from("C")]);
+/// assert_eq!(records[1].alternate_bases().as_ref(), [String::from("G")]);
+/// ```
+pub fn split_multiallelic(header: &Header, record: &RecordBuf) -> Vec<RecordBuf> {
+    let alternate_bases = record.alternate_bases().as_ref();
+    let allele_count = alternate_bases.len() + 1;
+
+    if alternate_bases.len() <= 1 {
+        return vec![record.clone()];
+    }
+
+    alternate_bases
+        .iter()
+        .enumerate()
+        .map(|(i, allele)| {
+            let allele_index = i + 1;
+
+            let mut split_record = record.clone();
+            *split_record.alternate_bases_mut() = AlternateBases::from(vec![allele.clone()]);
+
+            for (key, value) in split_record.info_mut().as_mut().iter_mut() {
+                let Some(number) = header.infos().get(key).map(|map| map.number()) else {
+                    continue;
+                };
+
+                let indices = match number {
+                    info::Number::AlternateBases => vec![allele_index - 1],
+                    info::Number::ReferenceAlternateBases => vec![0, allele_index],
+                    info::Number::Samples => match value.as_ref().and_then(info_value_len) {
+                        Some(len) => genotype_indices(len, allele_count, allele_index),
+                        None => continue,
+                    },
+                    _ => continue,
+                };
+
+                if let Some(InfoValue::Array(array)) = value {
+                    if let Some(split_array) = select_info_array(array, &indices) {
+                        *array = split_array;
+                    }
+                }
+            }
+
+            *split_record.samples_mut() =
+                split_samples(header, record.samples(), allele_count, allele_index);
+
+            split_record
+        })
+        .collect()
+}
+
+fn is_normalizable(allele: &str) -> bool {
+    !allele.is_empty()
+        && allele
+            .bytes()
+            .all(|b| matches!(b.to_ascii_uppercase(), b'A' | b'C' | b'G' | b'T' | b'N'))
+}
+
+fn validate_reference_bases(
+    reference_sequence: &fasta::record::Sequence,
+    start: Position,
+    reference_bases: &str,
+) -> io::Result<()> {
+    let end = start
+        .checked_add(reference_bases.len() - 1)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid variant end"))?;
+
+    let is_match = reference_sequence
+        .get(start..=end)
+        .map(|bases| bases.eq_ignore_ascii_case(reference_bases.as_bytes()))
+        .unwrap_or(false);
+
+    if is_match {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "reference bases do not match reference sequence",
+        ))
+    }
+}
+
+fn ends_with_same_base(alleles: &[Vec<u8>]) -> bool {
+    same_base(alleles.iter().map(|allele| allele.last()))
+}
+
+fn starts_with_same_base(alleles: &[Vec<u8>]) -> bool {
+    same_base(alleles.iter().map(|allele| allele.first()))
+}
+
+fn same_base<'a, I>(mut bases: I) -> bool
+where
+    I: Iterator<Item = Option<&'a u8>>,
+{
+    let Some(Some(first)) = bases.next() else {
+        return false;
+    };
+
+    bases.all(|base| base.map(|b| b.eq_ignore_ascii_case(first)).unwrap_or(false))
+}
+
+fn genotype_indices(len: usize, allele_count: usize, allele_index: usize) -> Vec<usize> {
+    let diploid_len = allele_count * (allele_count + 1) / 2;
+
+    if len == allele_count {
+        vec![0, allele_index]
+    } else if len == diploid_len {
+        // The index of genotype j/k (j <= k) is k * (k + 1) / 2 + j.
+        let offset = allele_index * (allele_index + 1) / 2;
+        vec![0, offset, offset + allele_index]
+    } else {
+        Vec::new()
+    }
+}
+
+fn select<T>(values: &[Option<T>], indices: &[usize]) -> Option<Vec<Option<T>>>
+where
+    T: Clone,
+{
+    if indices.is_empty() {
+        return None;
+    }
+
+    indices.iter().map(|&i| values.get(i).cloned()).collect()
+}
+
+fn info_value_len(value: &InfoValue) -> Option<usize> {
+    match value {
+        InfoValue::Array(InfoArray::Integer(values)) => Some(values.len()),
+        InfoValue::Array(InfoArray::Float(values)) => Some(values.len()),
+        InfoValue::Array(InfoArray::Character(values)) => Some(values.len()),
+        InfoValue::Array(InfoArray::String(values)) => Some(values.len()),
+        _ => None,
+    }
+}
+
+fn select_info_array(array: &InfoArray, indices: &[usize]) -> Option<InfoArray> {
+    match array {
+        InfoArray::Integer(values) => select(values, indices).map(InfoArray::Integer),
+        InfoArray::Float(values) => select(values, indices).map(InfoArray::Float),
+        InfoArray::Character(values) => select(values, indices).map(InfoArray::Character),
+        InfoArray::String(values) => select(values, indices).map(InfoArray::String),
+    }
+}
+
+fn sample_value_len(value: &SampleValue) -> Option<usize> {
+    match value {
+        SampleValue::Array(SampleArray::Integer(values)) => Some(values.len()),
+        SampleValue::Array(SampleArray::Float(values)) => Some(values.len()),
+        SampleValue::Array(SampleArray::Character(values)) => Some(values.len()),
+        SampleValue::Array(SampleArray::String(values)) => Some(values.len()),
+        _ => None,
+    }
+}
+
+fn select_sample_array(array: &SampleArray, indices: &[usize]) -> Option<SampleArray> {
+    match array {
+        SampleArray::Integer(values) => select(values, indices).map(SampleArray::Integer),
+        SampleArray::Float(values) => select(values, indices).map(SampleArray::Float),
+        SampleArray::Character(values) => select(values, indices).map(SampleArray::Character),
+        SampleArray::String(values) => select(values, indices).map(SampleArray::String),
+    }
+}
+
+fn split_genotype(genotype: &Genotype, allele_index: usize) -> Genotype {
+    genotype
+        .as_ref()
+        .iter()
+        .map(|allele| {
+            let position = match allele.position() {
+                Some(0) => Some(0),
+                Some(i) if i == allele_index => Some(1),
+                _ => None,
+            };
+
+            Allele::new(position, allele.phasing())
+        })
+        .collect()
+}
+
+fn split_samples(
+    header: &Header,
+    samples: &Samples,
+    allele_count: usize,
+    allele_index: usize,
+) -> Samples {
+    let keys = samples.keys().clone();
+
+    let values = samples
+        .values()
+        .map(|sample| {
+            sample
+                .values()
+                .iter()
+                .zip(keys.as_ref())
+                .map(|(value, key)| {
+                    let value = value.as_ref()?;
+
+                    if key == key::GENOTYPE {
+                        if let SampleValue::Genotype(genotype) = value {
+                            return Some(SampleValue::Genotype(split_genotype(
+                                genotype,
+                                allele_index,
+                            )));
+                        }
+                    }
+
+                    let indices = match header.formats().get(key).map(|map| map.number()) {
+                        Some(format::Number::AlternateBases) => vec![allele_index - 1],
+                        Some(format::Number::ReferenceAlternateBases) => vec![0, allele_index],
+                        Some(format::Number::Samples) => sample_value_len(value)
+                            .map(|len| genotype_indices(len, allele_count, allele_index))
+                            .unwrap_or_default(),
+                        _ => Vec::new(),
+                    };
+
+                    match value {
+                        SampleValue::Array(array) => select_sample_array(array, &indices)
+                            .map(SampleValue::Array)
+                            .or_else(|| Some(value.clone())),
+                        _ => Some(value.clone()),
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    Samples::new(keys, values)
+}
+
+#[cfg(test)]
+mod tests {
+    use fasta::record::{Definition, Sequence};
+
+    use super::*;
+
+    fn build_repository() -> fasta::Repository {
+        fasta::Repository::new(vec![fasta::Record::new(
+            Definition::new("sq0", None),
+            // 1 2 3 4 5 6 7 8 9 10 11 12
+            // G C A C A C A T T T  T  G
+            Sequence::from(b"GCACACATTTTG".to_vec()),
+        )])
+    }
+
+    #[test]
+    fn test_normalize() -> Result<(), Box<dyn std::error::Error>> {
+        fn t(
+            repository: &fasta::Repository,
+            (position, reference_bases, alternate_bases): (usize, &str, &[&str]),
+            (expected_position, expected_reference_bases, expected_alternate_bases): (
+                usize,
+                &str,
+                &[&str],
+            ),
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            let mut record = RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::try_from(position)?)
+                .set_reference_bases(reference_bases)
+                .set_alternate_bases(AlternateBases::from(
+                    alternate_bases
+                        .iter()
+                        .map(|s| String::from(*s))
+                        .collect::<Vec<_>>(),
+                ))
+                .build();
+
+            normalize(&mut record, repository)?;
+
+            let expected = RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::try_from(expected_position)?)
+                .set_reference_bases(expected_reference_bases)
+                .set_alternate_bases(AlternateBases::from(
+                    expected_alternate_bases
+                        .iter()
+                        .map(|s| String::from(*s))
+                        .collect::<Vec<_>>(),
+                ))
+                .build();
+
+            assert_eq!(record, expected);
+
+            Ok(())
+        }
+
+        let repository = build_repository();
+
+        // SNV
+        t(&repository, (2, "C", &["T"]), (2, "C", &["T"]))?;
+        // right-trimmed MNV
+        t(&repository, (2, "CA", &["TA"]), (2, "C", &["T"]))?;
+        // left-trimmed MNV
+        t(&repository, (2, "CA", &["CT"]), (3, "A", &["T"]))?;
+        // deletion in a repeat
+        t(&repository, (5, "ACA", &["A"]), (1, "GCA", &["G"]))?;
+        t(&repository, (4, "CAC", &["C"]), (1, "GCA", &["G"]))?;
+        // already left-aligned deletion
+        t(&repository, (6, "CAT", &["CT"]), (6, "CA", &["C"]))?;
+        // insertion in a repeat
+        t(&repository, (8, "T", &["TT"]), (7, "A", &["AT"]))?;
+        // deletion at the start of the reference sequence
+        t(&repository, (1, "GC", &["C"]), (1, "GC", &["C"]))?;
+        // multiallelic
+        t(
+            &repository,
+            (10, "TTG", &["TG", "TTTG"]),
+            (7, "AT", &["A", "ATT"]),
+        )?;
+        // symbolic
+        t(&repository, (2, "C", &["<DEL>"]), (2, "C", &["<DEL>"]))?;
+
+        let mut record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(2)?)
+            .set_reference_bases("G")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("T")]))
+            .build();
+        assert!(matches!(
+            normalize(&mut record, &repository),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let mut record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(2)?)
+            .set_reference_bases("C")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("T")]))
+            .build();
+        *record.reference_sequence_name_mut() = String::from("sq1");
+        assert!(matches!(
+            normalize(&mut record, &repository),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_multiallelic() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::variant::record_buf::info::field::Value;
+
+        const DATA: &str = r#"##fileformat=VCFv4.3
+##INFO=<ID=AC,Number=A,Type=Integer,Description="Allele count">
+##INFO=<ID=AD,Number=R,Type=Integer,Description="Allele depths">
+##INFO=<ID=DP,Number=1,Type=Integer,Description="Depth">
+##FORMAT=<ID=GT,Number=1,Type=String,Description="Genotype">
+##FORMAT=<ID=AD,Number=R,Type=Integer,Description="Allele depths">
+##FORMAT=<ID=PL,Number=G,Type=Integer,Description="Phred-scaled genotype likelihoods">
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO	FORMAT	sample0	sample1
+"#;
+
+        let header: Header = DATA.parse()?;
+
+        let record = RecordBuf::try_from_str(
+            "sq0\t1\t.\tA\tC,G\t.\tPASS\tAC=1,2;AD=3,5,8;DP=16\tGT:AD:PL\t1/2:0,5,8:10,20,30,40,50,60\t0|2:3,0,8:.",
+            &header,
+        )?;
+
+        let records = split_multiallelic(&header, &record);
+        assert_eq!(records.len(), 2);
+
+        let actual: Vec<_> = records
+            .iter()
+            .map(|record| record.display(&header).to_string())
+            .collect();
+
+        assert_eq!(
+            actual,
+            [
+                "sq0\t1\t.\tA\tC\t.\tPASS\tAC=1;AD=3,5;DP=16\tGT:AD:PL\t1/.:0,5:10,20,30\t0|.:3,0:.",
+                "sq0\t1\t.\tA\tG\t.\tPASS\tAC=2;AD=3,8;DP=16\tGT:AD:PL\t./1:0,8:10,40,60\t0|1:3,8:.",
+            ]
+        );
+
+        assert_eq!(records[0].info().get("DP"), Some(Some(&Value::Integer(16))));
+
+        Ok(())
+    }
+}
@@ -6,14 +6,20 @@ mod ids;
 pub mod info;
 mod reference_bases;
 pub mod samples;
+pub mod validation;
 
 use std::io;
 
 use noodles_core::Position;
 
 pub use self::{
-    alternate_bases::AlternateBases, filters::Filters, ids::Ids, info::Info,
-    reference_bases::ReferenceBases, samples::Samples,
+    alternate_bases::AlternateBases,
+    filters::Filters,
+    ids::Ids,
+    info::Info,
+    reference_bases::ReferenceBases,
+    samples::Samples,
+    validation::{validate, validate_with_repository},
 };
 use crate::Header;
 
@@ -88,6 +94,52 @@ pub trait Record {
     }
 }
 
+impl Record for Box<dyn Record> {
+    fn reference_sequence_name<'a, 'h: 'a>(&'a self, header: &'h Header) -> io::Result<&'a str> {
+        (**self).reference_sequence_name(header)
+    }
+
+    fn variant_start(&self) -> Option<io::Result<Position>> {
+        (**self).variant_start()
+    }
+
+    fn ids(&self) -> Box<dyn Ids + '_> {
+        (**self).ids()
+    }
+
+    fn reference_bases(&self) -> Box<dyn ReferenceBases + '_> {
+        (**self).reference_bases()
+    }
+
+    fn alternate_bases(&self) -> Box<dyn AlternateBases + '_> {
+        (**self).alternate_bases()
+    }
+
+    fn quality_score(&self) -> Option<io::Result<f32>> {
+        (**self).quality_score()
+    }
+
+    fn filters(&self) -> Box<dyn Filters + '_> {
+        (**self).filters()
+    }
+
+    fn info(&self) -> Box<dyn Info + '_> {
+        (**self).info()
+    }
+
+    fn samples(&self) -> io::Result<Box<dyn Samples + '_>> {
+        (**self).samples()
+    }
+
+    fn variant_span(&self, header: &Header) -> io::Result<usize> {
+        (**self).variant_span(header)
+    }
+
+    fn variant_end(&self, header: &Header) -> io::Result<Position> {
+        (**self).variant_end(header)
+    }
+}
+
 fn reference_bases_len<B>(reference_bases: &B) -> io::Result<usize>
 where
     B: ReferenceBases,
@@ -0,0 +1,482 @@
+//! Variant record validation.
+
+use std::{fmt, io};
+
+use noodles_core::Position;
+use noodles_fasta as fasta;
+
+use super::Record;
+use crate::Header;
+
+const PASS: &str = "PASS";
+
+/// A variant record validation report.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Report {
+    issues: Vec<Issue>,
+}
+
+impl Report {
+    /// Returns whether no issues were found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record::validation::Report;
+    /// let report = Report::default();
+    /// assert!(report.is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Returns the issues found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record::validation::Report;
+    /// let report = Report::default();
+    /// assert!(report.issues().is_empty());
+    /// ```
+    pub fn issues(&self) -> &[Issue] {
+        &self.issues
+    }
+}
+
+/// A variant record validation issue.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Issue {
+    /// The reference sequence name (`CHROM`) is not a contig in the header.
+    UndefinedContig(String),
+    /// The variant start (`POS`) or end is greater than the contig length.
+    PositionOutOfBounds {
+        /// The variant start or end.
+        position: Position,
+        /// The contig length.
+        length: usize,
+    },
+    /// The reference sequence is not in the reference sequence repository.
+    MissingReferenceSequence(String),
+    /// The reference bases (`REF`) do not match the reference sequence.
+    ReferenceBasesMismatch {
+        /// The reference sequence bases at the variant position.
+        expected: String,
+        /// The record reference bases.
+        actual: String,
+    },
+    /// A filter (`FILTER`) is not defined in the header.
+    UndefinedFilter(String),
+    /// An INFO field key is not defined in the header.
+    UndefinedInfoKey(String),
+    /// A sample (`FORMAT`) field key is not defined in the header.
+    UndefinedFormatKey(String),
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UndefinedContig(name) => write!(f, "undefined contig: {name}"),
+            Self::PositionOutOfBounds { position, length } => {
+                write!(f, "position out of bounds: {position} > {length}")
+            }
+            Self::MissingReferenceSequence(name) => {
+                write!(f, "missing reference sequence: {name}")
+            }
+            Self::ReferenceBasesMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "reference bases mismatch: expected {expected}, got {actual}"
+                )
+            }
+            Self::UndefinedFilter(id) => write!(f, "undefined filter: {id}"),
+            Self::UndefinedInfoKey(key) => write!(f, "undefined INFO key: {key}"),
+            Self::UndefinedFormatKey(key) => write!(f, "undefined FORMAT key: {key}"),
+        }
+    }
+}
+
+/// Validates a variant record against a header.
+///
+/// This checks that
+///
+///   * the reference sequence name is a contig in the header;
+///   * the variant start and end are within the contig length, if the length is known;
+///   * the filters are defined in the header (`PASS` is always defined); and
+///   * the INFO and sample field keys are defined in the header.
+///
+/// Use [`validate_with_repository`] to also check the reference bases.
+///
+/// # Errors
+///
+/// This returns an error if a record field fails to decode.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_vcf::{
+///     self as vcf,
+///     header::record::value::{map::Contig, Map},
+///     variant::record::{self, validation::Issue},
+/// };
+///
+/// let header = vcf::Header::builder()
+///     .add_contig("sq0", Map::<Contig>::new())
+///     .build();
+///
+/// let record = vcf::variant::RecordBuf::builder()
+///     .set_reference_sequence_name("sq1")
+///     .set_variant_start(Position::MIN)
+///     .set_reference_bases("A")
+///     .build();
+///
+/// let report = record::validate(&record, &header)?;
+/// assert_eq!(report.issues(), [Issue::UndefinedContig(String::from("sq1"))]);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn validate<R>(record: &R, header: &Header) -> io::Result<Report>
+where
+    R: Record + ?Sized,
+{
+    let mut issues = Vec::new();
+
+    validate_position(record, header, &mut issues)?;
+    validate_filters(record, header, &mut issues)?;
+    validate_info_keys(record, header, &mut issues)?;
+    validate_format_keys(record, header, &mut issues)?;
+
+    Ok(Report { issues })
+}
+
+/// Validates a variant record against a header and reference sequences.
+///
+/// In addition to the checks in [`validate`], this checks that the reference bases match the
+/// reference sequence in the repository. The comparison is case-insensitive.
+///
+/// # Errors
+///
+/// This returns an error if a record field fails to decode or the reference sequence fails to
+/// load.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+/// use noodles_vcf::{
+///     self as vcf,
+///     header::record::value::{map::Contig, Map},
+///     variant::record::{self, validation::Issue},
+/// };
+///
+/// let repository = fasta::Repository::new(vec![fasta::Record::new(
+///     Definition::new("sq0", None),
+///     Sequence::from(b"ACGT".to_vec()),
+/// )]);
+///
+/// let header = vcf::Header::builder()
+///     .add_contig("sq0", Map::<Contig>::new())
+///     .build();
+///
+/// let record = vcf::variant::RecordBuf::builder()
+///     .set_reference_sequence_name("sq0")
+///     .set_variant_start(Position::MIN)
+///     .set_reference_bases("C")
+///     .build();
+///
+/// let report = record::validate_with_repository(&record, &header, &repository)?;
+///
+/// assert_eq!(
+///     report.issues(),
+///     [Issue::ReferenceBasesMismatch {
+///         expected: String::from("A"),
+///         actual: String::from("C"),
+///     }]
+/// );
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn validate_with_repository<R>(
+    record: &R,
+    header: &Header,
+    repository: &fasta::Repository,
+) -> io::Result<Report>
+where
+    R: Record + ?Sized,
+{
+    let mut report = validate(record, header)?;
+    validate_reference_bases(record, header, repository, &mut report.issues)?;
+    Ok(report)
+}
+
+fn validate_position<R>(record: &R, header: &Header, issues: &mut Vec<Issue>) -> io::Result<()>
+where
+    R: Record + ?Sized,
+{
+    let name = record.reference_sequence_name(header)?;
+
+    let Some(contig) = header.contigs().get(name) else {
+        issues.push(Issue::UndefinedContig(name.into()));
+        return Ok(());
+    };
+
+    let Some(length) = contig.length() else {
+        return Ok(());
+    };
+
+    let Some(start) = record.variant_start().transpose()? else {
+        return Ok(());
+    };
+
+    let end = record.variant_end(header)?;
+
+    for position in [start, end] {
+        if usize::from(position) > length {
+            issues.push(Issue::PositionOutOfBounds { position, length });
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_filters<R>(record: &R, header: &Header, issues: &mut Vec<Issue>) -> io::Result<()>
+where
+    R: Record + ?Sized,
+{
+    for result in record.filters().iter(header) {
+        let id = result?;
+
+        if id != PASS && !header.filters().contains_key(id) {
+            issues.push(Issue::UndefinedFilter(id.into()));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_info_keys<R>(record: &R, header: &Header, issues: &mut Vec<Issue>) -> io::Result<()>
+where
+    R: Record + ?Sized,
+{
+    for result in record.info().iter(header) {
+        let (key, _) = result?;
+
+        if !header.infos().contains_key(key) {
+            issues.push(Issue::UndefinedInfoKey(key.into()));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_format_keys<R>(record: &R, header: &Header, issues: &mut Vec<Issue>) -> io::Result<()>
+where
+    R: Record + ?Sized,
+{
+    let samples = record.samples()?;
+
+    for result in samples.column_names(header) {
+        let key = result?;
+
+        if !header.formats().contains_key(key) {
+            issues.push(Issue::UndefinedFormatKey(key.into()));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_reference_bases<R>(
+    record: &R,
+    header: &Header,
+    repository: &fasta::Repository,
+    issues: &mut Vec<Issue>,
+) -> io::Result<()>
+where
+    R: Record + ?Sized,
+{
+    let Some(start) = record.variant_start().transpose()? else {
+        return Ok(());
+    };
+
+    let reference_bases: Vec<u8> = record.reference_bases().iter().collect::<io::Result<_>>()?;
+
+    if reference_bases.is_empty() {
+        return Ok(());
+    }
+
+    let name = record.reference_sequence_name(header)?;
+
+    let Some(reference_sequence) = repository.get(name.as_bytes()).transpose()? else {
+        issues.push(Issue::MissingReferenceSequence(name.into()));
+        return Ok(());
+    };
+
+    let expected = start
+        .checked_add(reference_bases.len() - 1)
+        .and_then(|end| reference_sequence.get(start..=end))
+        .unwrap_or_default();
+
+    if !expected.eq_ignore_ascii_case(&reference_bases) {
+        issues.push(Issue::ReferenceBasesMismatch {
+            expected: String::from_utf8_lossy(expected).into(),
+            actual: String::from_utf8_lossy(&reference_bases).into(),
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        header::record::value::{
+            map::{Contig, Filter, Format, Info},
+            Map,
+        },
+        variant::{
+            record::{info::field::key as info_key, samples::keys::key as format_key},
+            record_buf::{
+                info::field::Value as InfoValue,
+                samples::{sample::Value as SampleValue, Keys},
+                Samples,
+            },
+            RecordBuf,
+        },
+    };
+
+    fn build_header() -> Header {
+        let mut contig = Map::<Contig>::new();
+        *contig.length_mut() = Some(8);
+
+        Header::builder()
+            .add_contig("sq0", contig)
+            .add_contig("sq1", Map::<Contig>::new())
+            .add_filter("q10", Map::<Filter>::new("Quality below 10"))
+            .add_info(
+                info_key::TOTAL_DEPTH,
+                Map::<Info>::from(info_key::TOTAL_DEPTH),
+            )
+            .add_format(
+                format_key::READ_DEPTH,
+                Map::<Format>::from(format_key::READ_DEPTH),
+            )
+            .add_sample_name("sample0")
+            .build()
+    }
+
+    #[test]
+    fn test_validate() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(7)?)
+            .set_reference_bases("AC")
+            .set_filters(["PASS", "q10"].into_iter().map(String::from).collect())
+            .set_info(
+                [(
+                    String::from(info_key::TOTAL_DEPTH),
+                    Some(InfoValue::from(8)),
+                )]
+                .into_iter()
+                .collect(),
+            )
+            .set_samples(Samples::new(
+                Keys::from_iter([String::from(format_key::READ_DEPTH)]),
+                vec![vec![Some(SampleValue::from(8))]],
+            ))
+            .build();
+
+        assert!(validate(&record, &header)?.is_valid());
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(8)?)
+            .set_reference_bases("AC")
+            .set_filters(["q20"].into_iter().map(String::from).collect())
+            .set_info(
+                [(String::from("NDLS"), Some(InfoValue::from(1)))]
+                    .into_iter()
+                    .collect(),
+            )
+            .set_samples(Samples::new(
+                Keys::from_iter([String::from("ND")]),
+                vec![vec![Some(SampleValue::from(1))]],
+            ))
+            .build();
+
+        assert_eq!(
+            validate(&record, &header)?.issues(),
+            [
+                Issue::PositionOutOfBounds {
+                    position: Position::try_from(9)?,
+                    length: 8,
+                },
+                Issue::UndefinedFilter(String::from("q20")),
+                Issue::UndefinedInfoKey(String::from("NDLS")),
+                Issue::UndefinedFormatKey(String::from("ND")),
+            ]
+        );
+
+        // The contig length is unknown.
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq1")
+            .set_variant_start(Position::try_from(13)?)
+            .set_reference_bases("A")
+            .build();
+
+        assert!(validate(&record, &header)?.is_valid());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_with_repository() -> Result<(), Box<dyn std::error::Error>> {
+        use fasta::record::{Definition, Sequence};
+
+        let header = build_header();
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            Definition::new("sq0", None),
+            Sequence::from(b"ACGTACGT".to_vec()),
+        )]);
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(2)?)
+            .set_reference_bases("cg")
+            .build();
+
+        assert!(validate_with_repository(&record, &header, &repository)?.is_valid());
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(2)?)
+            .set_reference_bases("GG")
+            .build();
+
+        assert_eq!(
+            validate_with_repository(&record, &header, &repository)?.issues(),
+            [Issue::ReferenceBasesMismatch {
+                expected: String::from("CG"),
+                actual: String::from("GG"),
+            }]
+        );
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq1")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("A")
+            .build();
+
+        assert_eq!(
+            validate_with_repository(&record, &header, &repository)?.issues(),
+            [Issue::MissingReferenceSequence(String::from("sq1"))]
+        );
+
+        Ok(())
+    }
+}
@@ -3,20 +3,28 @@
 mod alternate_bases;
 pub mod builder;
 mod convert;
+mod display;
 mod filters;
 pub mod ids;
 pub mod info;
+mod local_alleles;
 pub mod samples;
 
 use std::io;
 
-use noodles_core::Position;
+use noodles_core::{
+    position::{Positioned, ReferenceSequence},
+    Position,
+};
 
 pub use self::{
-    alternate_bases::AlternateBases, builder::Builder, filters::Filters, ids::Ids, info::Info,
-    samples::Samples,
+    alternate_bases::AlternateBases, builder::Builder, display::Display, filters::Filters,
+    ids::Ids, info::Info, samples::Samples,
+};
+use crate::{
+    io::reader::record_buf::{parse_record_buf, ParseError},
+    Header,
 };
-use crate::Header;
 
 /// A variant record buffer.
 #[derive(Clone, Debug, PartialEq)]
@@ -45,6 +53,58 @@ impl RecordBuf {
         Builder::default()
     }
 
+    /// Parses a VCF record from a line of text using the given header.
+    ///
+    /// The header is used to parse the info and samples fields. A trailing newline is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_vcf::{self as vcf, variant::RecordBuf};
+    ///
+    /// let header = vcf::Header::default();
+    /// let record = RecordBuf::try_from_str("sq0\t1\t.\tA\t.\t.\tPASS\t.\n", &header)?;
+    ///
+    /// assert_eq!(record.reference_sequence_name(), "sq0");
+    /// assert_eq!(record.variant_start(), Some(Position::MIN));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn try_from_str(s: &str, header: &Header) -> Result<Self, ParseError> {
+        let s = s
+            .strip_suffix('\n')
+            .map(|t| t.strip_suffix('\r').unwrap_or(t))
+            .unwrap_or(s);
+
+        let mut record = Self::default();
+        parse_record_buf(s, header, &mut record)?;
+        Ok(record)
+    }
+
+    /// Returns an object that formats the record as a line of VCF text using the given header.
+    ///
+    /// The output does not include a trailing newline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_vcf::{self as vcf, variant::RecordBuf};
+    ///
+    /// let header = vcf::Header::default();
+    ///
+    /// let record = RecordBuf::builder()
+    ///     .set_reference_sequence_name("sq0")
+    ///     .set_variant_start(Position::MIN)
+    ///     .set_reference_bases("A")
+    ///     .build();
+    ///
+    /// assert_eq!(record.display(&header).to_string(), "sq0\t1\t.\tA\t.\t.\t.\t.");
+    /// ```
+    pub fn display<'r, 'h>(&'r self, header: &'h Header) -> Display<'r, 'h> {
+        Display::new(self, header)
+    }
+
     /// Returns the reference sequence name.
     ///
     /// # Examples
@@ -465,6 +525,247 @@ impl RecordBuf {
     pub fn samples_mut(&mut self) -> &mut Samples {
         &mut self.samples
     }
+
+    /// Recalculates the allele count (`AC`) and total number of alleles (`AN`) info fields from
+    /// the sample genotypes (`GT`).
+    ///
+    /// This is typically used after the samples change, e.g., after subsetting samples. Missing
+    /// alleles are not counted. If the samples do not have genotypes, the info fields are
+    /// removed.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if a genotype is invalid or refers to a nonexistent allele.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::{
+    ///     record::{info::field::key, samples::keys::key as format_key},
+    ///     record_buf::{
+    ///         info::field::{value::Array, Value},
+    ///         samples::{sample::Value as SampleValue, Keys},
+    ///         AlternateBases, RecordBuf, Samples,
+    ///     },
+    /// };
+    ///
+    /// let keys: Keys = [String::from(format_key::GENOTYPE)].into_iter().collect();
+    /// let samples = Samples::new(
+    ///     keys,
+    ///     vec![
+    ///         vec![Some(SampleValue::from("0/1"))],
+    ///         vec![Some(SampleValue::from("1|1"))],
+    ///         vec![Some(SampleValue::from("./."))],
+    ///     ],
+    /// );
+    ///
+    /// let mut record = RecordBuf::builder()
+    ///     .set_alternate_bases(AlternateBases::from(vec![String::from("C")]))
+    ///     .set_samples(samples)
+    ///     .build();
+    ///
+    /// record.update_allele_counts()?;
+    ///
+    /// let info = record.info();
+    /// assert_eq!(
+    ///     info.get(key::ALLELE_COUNT),
+    ///     Some(Some(&Value::Array(Array::Integer(vec![Some(3)]))))
+    /// );
+    /// assert_eq!(info.get(key::TOTAL_ALLELE_COUNT), Some(Some(&Value::Integer(4))));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn update_allele_counts(&mut self) -> io::Result<()> {
+        use self::{
+            info::field::{value::Array, Value as InfoValue},
+            samples::sample::{value::Genotype, Value},
+        };
+        use super::record::{info::field::key, samples::keys::key as format_key};
+
+        let Some(i) = self
+            .samples
+            .keys()
+            .as_ref()
+            .get_index_of(format_key::GENOTYPE)
+        else {
+            self.info.as_mut().shift_remove(key::ALLELE_COUNT);
+            self.info.as_mut().shift_remove(key::TOTAL_ALLELE_COUNT);
+            return Ok(());
+        };
+
+        let mut allele_counts = vec![0; self.alternate_bases.as_ref().len()];
+        let mut total_allele_count = 0;
+
+        for sample in &self.samples.values {
+            let genotype = match sample.get(i) {
+                Some(Some(Value::Genotype(genotype))) => genotype.clone(),
+                Some(Some(Value::String(s))) => s
+                    .parse::<Genotype>()
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+                Some(Some(_)) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "invalid genotype",
+                    ))
+                }
+                Some(None) | None => continue,
+            };
+
+            for allele in genotype.as_ref() {
+                let Some(position) = allele.position() else {
+                    continue;
+                };
+
+                total_allele_count += 1;
+
+                if let Some(j) = position.checked_sub(1) {
+                    let count = allele_counts.get_mut(j).ok_or_else(|| {
+                        io::Error::new(io::ErrorKind::InvalidData, "invalid allele position")
+                    })?;
+
+                    *count += 1;
+                }
+            }
+        }
+
+        let allele_counts = allele_counts.into_iter().map(Some).collect();
+
+        self.info.insert(
+            key::ALLELE_COUNT.into(),
+            Some(InfoValue::Array(Array::Integer(allele_counts))),
+        );
+
+        self.info.insert(
+            key::TOTAL_ALLELE_COUNT.into(),
+            Some(InfoValue::Integer(total_allele_count)),
+        );
+
+        Ok(())
+    }
+
+    /// Converts local-allele sample fields to their global forms.
+    ///
+    /// Local-allele fields (VCF 4.4) are indexed by the local alleles of a sample, i.e., the
+    /// reference allele followed by the alternate alleles listed in its `LAA` field. This expands
+    /// `LAD`, `LADF`, `LADR`, `LGL`, `LGP`, `LPL`, and `LPP` to `AD`, `ADF`, `ADR`, `GL`, `GP`,
+    /// `PL`, and `PP`, respectively, and removes the local-allele fields, including `LAA`. Values
+    /// of nonlocal alleles and genotypes are missing.
+    ///
+    /// Genotype (`G`) fields must be haploid or diploid. This does nothing if there is no `LAA`
+    /// field.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if an `LAA` value refers to a nonexistent alternate allele or a
+    /// local-allele field has an unexpected number of values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::{
+    ///     record::samples::keys::key,
+    ///     record_buf::{
+    ///         samples::{sample::Value, Keys},
+    ///         AlternateBases, RecordBuf, Samples,
+    ///     },
+    /// };
+    ///
+    /// let keys: Keys = [
+    ///     String::from(key::GENOTYPE),
+    ///     String::from(key::LOCAL_ALTERNATIVE_ALLELE),
+    ///     String::from(key::LOCAL_READ_DEPTHS),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let samples = Samples::new(
+    ///     keys,
+    ///     vec![vec![
+    ///         Some(Value::from("0/2")),
+    ///         Some(Value::from(vec![Some(2)])),
+    ///         Some(Value::from(vec![Some(5), Some(8)])),
+    ///     ]],
+    /// );
+    ///
+    /// let mut record = RecordBuf::builder()
+    ///     .set_alternate_bases(AlternateBases::from(vec![String::from("C"), String::from("G")]))
+    ///     .set_samples(samples)
+    ///     .build();
+    ///
+    /// record.expand_local_alleles()?;
+    ///
+    /// let sample = record.samples().get_index(0).unwrap();
+    /// assert!(sample.get(key::LOCAL_READ_DEPTHS).is_none());
+    /// assert_eq!(
+    ///     sample.get(key::READ_DEPTHS),
+    ///     Some(Some(&Value::from(vec![Some(5), None, Some(8)])))
+    /// );
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn expand_local_alleles(&mut self) -> io::Result<()> {
+        let allele_count = self.alternate_bases.as_ref().len() + 1;
+        local_alleles::expand(&mut self.samples, allele_count)
+    }
+
+    /// Converts global-allele sample fields to their local-allele forms.
+    ///
+    /// This is the inverse of [`Self::expand_local_alleles`]. The local alleles of a sample are
+    /// the reference allele and the alternate alleles called in its genotype (`GT`), which are
+    /// written to `LAA`. `AD`, `ADF`, `ADR`, `GL`, `GP`, `PL`, and `PP` are subset to the local
+    /// alleles and replaced with their local-allele fields.
+    ///
+    /// Values of alleles not called in a genotype are dropped. The header must define the
+    /// local-allele fields to write the record.
+    ///
+    /// # Errors
+    ///
+    /// This returns an error if there is no genotype field, a genotype refers to a nonexistent
+    /// allele, or a field has an unexpected number of values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::{
+    ///     record::samples::keys::key,
+    ///     record_buf::{
+    ///         samples::{sample::Value, Keys},
+    ///         AlternateBases, RecordBuf, Samples,
+    ///     },
+    /// };
+    ///
+    /// let keys: Keys = [String::from(key::GENOTYPE), String::from(key::READ_DEPTHS)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// let samples = Samples::new(
+    ///     keys,
+    ///     vec![vec![
+    ///         Some(Value::from("0/2")),
+    ///         Some(Value::from(vec![Some(5), Some(1), Some(8)])),
+    ///     ]],
+    /// );
+    ///
+    /// let mut record = RecordBuf::builder()
+    ///     .set_alternate_bases(AlternateBases::from(vec![String::from("C"), String::from("G")]))
+    ///     .set_samples(samples)
+    ///     .build();
+    ///
+    /// record.localize_alleles()?;
+    ///
+    /// let sample = record.samples().get_index(0).unwrap();
+    /// assert_eq!(
+    ///     sample.get(key::LOCAL_ALTERNATIVE_ALLELE),
+    ///     Some(Some(&Value::from(vec![Some(2)])))
+    /// );
+    /// assert_eq!(
+    ///     sample.get(key::LOCAL_READ_DEPTHS),
+    ///     Some(Some(&Value::from(vec![Some(5), Some(8)])))
+    /// );
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn localize_alleles(&mut self) -> io::Result<()> {
+        let allele_count = self.alternate_bases.as_ref().len() + 1;
+        local_alleles::localize(&mut self.samples, allele_count)
+    }
 }
 
 impl Default for RecordBuf {
@@ -483,6 +784,25 @@ impl Default for RecordBuf {
     }
 }
 
+impl Positioned for RecordBuf {
+    fn reference_sequence(&self) -> Option<ReferenceSequence<'_>> {
+        Some(ReferenceSequence::Name(
+            self.reference_sequence_name().into(),
+        ))
+    }
+
+    fn start(&self) -> Option<Position> {
+        self.variant_start()
+    }
+
+    // The fields of a record buffer are already typed, so the end position does not depend on the
+    // header.
+    fn end(&self) -> Option<Position> {
+        self.variant_start()?;
+        super::Record::variant_end(self, &Header::default()).ok()
+    }
+}
+
 impl super::Record for RecordBuf {
     fn reference_sequence_name<'a, 'h: 'a>(&'a self, _: &'h Header) -> io::Result<&'a str> {
         Ok(self.reference_sequence_name())
@@ -539,4 +859,70 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_positioned() -> Result<(), Box<dyn std::error::Error>> {
+        use self::info::field::Value;
+        use crate::variant::record::info::field::key;
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(8)?)
+            .set_reference_bases("ACG")
+            .build();
+
+        assert_eq!(
+            Positioned::reference_sequence(&record),
+            Some(ReferenceSequence::Name("sq0".into()))
+        );
+        assert_eq!(Positioned::start(&record), Position::new(8));
+        assert_eq!(Positioned::end(&record), Position::new(10));
+
+        let record = RecordBuf::builder()
+            .set_variant_start(Position::try_from(8)?)
+            .set_info(
+                [(String::from(key::END_POSITION), Some(Value::Integer(13)))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+
+        assert_eq!(Positioned::end(&record), Position::new(13));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_allele_counts() -> io::Result<()> {
+        use self::{
+            info::field::Value as InfoValue,
+            samples::{sample::Value, Keys},
+        };
+        use crate::variant::record::info::field::key;
+
+        let mut record = RecordBuf::builder()
+            .set_alternate_bases(AlternateBases::from(vec![String::from("C")]))
+            .set_info(
+                [(
+                    String::from(key::TOTAL_ALLELE_COUNT),
+                    Some(InfoValue::Integer(8)),
+                )]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        record.update_allele_counts()?;
+        assert!(record.info().as_ref().is_empty());
+
+        let keys: Keys = [String::from("GT")].into_iter().collect();
+        *record.samples_mut() = Samples::new(keys, vec![vec![Some(Value::from("0/2"))]]);
+
+        assert!(matches!(
+            record.update_allele_counts(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,51 @@
+use std::fmt;
+
+use super::RecordBuf;
+use crate::{
+    io::writer::{options::Options, write_record},
+    Header,
+};
+
+/// A header-bound formatter of a variant record buffer.
+///
+/// This is created by calling [`RecordBuf::display`].
+pub struct Display<'r, 'h> {
+    record: &'r RecordBuf,
+    header: &'h Header,
+}
+
+impl<'r, 'h> Display<'r, 'h> {
+    pub(super) fn new(record: &'r RecordBuf, header: &'h Header) -> Self {
+        Self { record, header }
+    }
+}
+
+impl fmt::Display for Display<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = Vec::new();
+        write_record(&mut buf, &Options::default(), self.header, self.record)
+            .map_err(|_| fmt::Error)?;
+
+        // Discard the line terminator.
+        buf.pop();
+
+        let s = std::str::from_utf8(&buf).map_err(|_| fmt::Error)?;
+        f.write_str(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::default();
+
+        let s = "sq0\t8\trs0\tA\tC,G\t13\tPASS\t.";
+        let record = RecordBuf::try_from_str(s, &header)?;
+        assert_eq!(record.display(&header).to_string(), s);
+
+        Ok(())
+    }
+}
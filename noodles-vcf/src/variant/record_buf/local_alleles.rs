@@ -0,0 +1,511 @@
+use std::io;
+
+use super::samples::{
+    sample::{
+        value::{Array, Genotype},
+        Value,
+    },
+    Samples,
+};
+use crate::variant::record::samples::keys::key;
+
+#[derive(Clone, Copy)]
+enum Number {
+    /// One value per allele.
+    R,
+    /// One value per genotype.
+    G,
+}
+
+// (local key, global key, number)
+static FIELDS: &[(&str, &str, Number)] = &[
+    (key::LOCAL_READ_DEPTHS, key::READ_DEPTHS, Number::R),
+    (
+        key::LOCAL_FORWARD_STRAND_READ_DEPTHS,
+        key::FORWARD_STRAND_READ_DEPTHS,
+        Number::R,
+    ),
+    (
+        key::LOCAL_REVERSE_STRAND_READ_DEPTHS,
+        key::REVERSE_STRAND_READ_DEPTHS,
+        Number::R,
+    ),
+    (
+        key::LOCAL_GENOTYPE_LIKELIHOODS,
+        key::GENOTYPE_LIKELIHOODS,
+        Number::G,
+    ),
+    (
+        key::LOCAL_GENOTYPE_POSTERIOR_PROBABILITIES,
+        key::GENOTYPE_POSTERIOR_PROBABILITIES,
+        Number::G,
+    ),
+    (
+        key::LOCAL_ROUNDED_GENOTYPE_LIKELIHOODS,
+        key::ROUNDED_GENOTYPE_LIKELIHOODS,
+        Number::G,
+    ),
+    (
+        key::LOCAL_ROUNDED_GENOTYPE_POSTERIOR_PROBABILITIES,
+        key::ROUNDED_GENOTYPE_POSTERIOR_PROBABILITIES,
+        Number::G,
+    ),
+];
+
+pub(super) fn expand(samples: &mut Samples, allele_count: usize) -> io::Result<()> {
+    let Some(i) = samples
+        .keys
+        .as_ref()
+        .get_index_of(key::LOCAL_ALTERNATIVE_ALLELE)
+    else {
+        return Ok(());
+    };
+
+    let sample_alleles = samples
+        .values
+        .iter()
+        .map(|sample| parse_local_alleles(sample.get(i).and_then(|v| v.as_ref()), allele_count))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    for &(local_key, global_key, number) in FIELDS {
+        let Some(j) = samples.keys.as_ref().get_index_of(local_key) else {
+            continue;
+        };
+
+        let global_len = match number {
+            Number::R => allele_count,
+            Number::G => genotype_count(allele_count),
+        };
+
+        let values = samples
+            .values
+            .iter()
+            .zip(&sample_alleles)
+            .map(|(sample, alleles)| {
+                let Some(value) = sample.get(j).and_then(|v| v.as_ref()) else {
+                    return Ok(None);
+                };
+
+                let is_diploid = is_diploid(number, alleles.len(), value_len(value)?)?;
+                let map = build_map(alleles, is_diploid, global_len)?;
+                expand_value(value, &map, global_len).map(Some)
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        set_field(samples, global_key, values);
+        remove_field(samples, local_key);
+    }
+
+    remove_field(samples, key::LOCAL_ALTERNATIVE_ALLELE);
+
+    Ok(())
+}
+
+pub(super) fn localize(samples: &mut Samples, allele_count: usize) -> io::Result<()> {
+    let Some(i) = samples.keys.as_ref().get_index_of(key::GENOTYPE) else {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "missing GT"));
+    };
+
+    let sample_alleles = samples
+        .values
+        .iter()
+        .map(|sample| called_alleles(sample.get(i).and_then(|v| v.as_ref()), allele_count))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    for &(local_key, global_key, number) in FIELDS {
+        let Some(j) = samples.keys.as_ref().get_index_of(global_key) else {
+            continue;
+        };
+
+        let values = samples
+            .values
+            .iter()
+            .zip(&sample_alleles)
+            .map(|(sample, alleles)| {
+                let Some(value) = sample.get(j).and_then(|v| v.as_ref()) else {
+                    return Ok(None);
+                };
+
+                let len = value_len(value)?;
+                let is_diploid = is_diploid(number, allele_count, len)?;
+                let map = build_map(alleles, is_diploid, len)?;
+                localize_value(value, &map).map(Some)
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        set_field(samples, local_key, values);
+        remove_field(samples, global_key);
+    }
+
+    let values = sample_alleles
+        .iter()
+        .map(|alleles| {
+            let local_alternate_alleles = alleles[1..]
+                .iter()
+                .map(|&n| {
+                    i32::try_from(n)
+                        .map(Some)
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+                })
+                .collect::<io::Result<Vec<_>>>()?;
+
+            if local_alternate_alleles.is_empty() {
+                Ok(None)
+            } else {
+                Ok(Some(Value::Array(Array::Integer(local_alternate_alleles))))
+            }
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+
+    set_field(samples, key::LOCAL_ALTERNATIVE_ALLELE, values);
+
+    Ok(())
+}
+
+// Returns the allele indices of the local alleles, including the reference allele.
+fn parse_local_alleles(value: Option<&Value>, allele_count: usize) -> io::Result<Vec<usize>> {
+    let mut alleles = vec![0];
+
+    let values = match value {
+        None => return Ok(alleles),
+        Some(Value::Integer(n)) => vec![Some(*n)],
+        Some(Value::Array(Array::Integer(values))) => values.clone(),
+        Some(_) => return Err(invalid_local_alternative_alleles()),
+    };
+
+    for n in values {
+        let j = n
+            .and_then(|n| usize::try_from(n).ok())
+            .filter(|&j| j > 0 && j < allele_count)
+            .ok_or_else(invalid_local_alternative_alleles)?;
+
+        alleles.push(j);
+    }
+
+    Ok(alleles)
+}
+
+fn invalid_local_alternative_alleles() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "invalid LAA")
+}
+
+// Returns the reference allele and the sorted alternate alleles called in the genotype.
+fn called_alleles(value: Option<&Value>, allele_count: usize) -> io::Result<Vec<usize>> {
+    let genotype = match value {
+        None => return Ok(vec![0]),
+        Some(Value::Genotype(genotype)) => genotype.clone(),
+        Some(Value::String(s)) => s
+            .parse::<Genotype>()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+        Some(_) => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid genotype",
+            ))
+        }
+    };
+
+    let mut alleles = vec![0];
+
+    for position in genotype.as_ref().iter().filter_map(|a| a.position()) {
+        if position >= allele_count {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid allele position",
+            ));
+        }
+
+        alleles.push(position);
+    }
+
+    alleles.sort_unstable();
+    alleles.dedup();
+
+    Ok(alleles)
+}
+
+fn genotype_count(allele_count: usize) -> usize {
+    allele_count * (allele_count + 1) / 2
+}
+
+// Returns whether values of a `G` field are per diploid genotype, given the number of alleles
+// and values. Only haploid and diploid genotypes are supported.
+fn is_diploid(number: Number, allele_count: usize, len: usize) -> io::Result<bool> {
+    match number {
+        Number::R => Ok(false),
+        Number::G if len == genotype_count(allele_count) => Ok(true),
+        Number::G if len == allele_count => Ok(false),
+        Number::G => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "unsupported genotype ploidy",
+        )),
+    }
+}
+
+// Builds a map from local value indices to global value indices.
+fn build_map(alleles: &[usize], is_diploid: bool, global_len: usize) -> io::Result<Vec<usize>> {
+    let map = if is_diploid {
+        let n = alleles.len();
+        let mut map = Vec::with_capacity(genotype_count(n));
+
+        for b in 0..n {
+            for a in 0..=b {
+                let (j, k) = (alleles[a].min(alleles[b]), alleles[a].max(alleles[b]));
+                map.push(genotype_count(k) + j);
+            }
+        }
+
+        map
+    } else {
+        alleles.to_vec()
+    };
+
+    if map.iter().all(|&i| i < global_len) {
+        Ok(map)
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid allele field value count",
+        ))
+    }
+}
+
+fn value_len(value: &Value) -> io::Result<usize> {
+    match value {
+        Value::Integer(_) | Value::Float(_) => Ok(1),
+        Value::Array(Array::Integer(values)) => Ok(values.len()),
+        Value::Array(Array::Float(values)) => Ok(values.len()),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid local allele field value",
+        )),
+    }
+}
+
+fn expand_value(value: &Value, map: &[usize], len: usize) -> io::Result<Value> {
+    fn scatter<N: Copy>(src: &[Option<N>], map: &[usize], len: usize) -> Vec<Option<N>> {
+        let mut dst = vec![None; len];
+
+        for (&i, &n) in map.iter().zip(src) {
+            dst[i] = n;
+        }
+
+        dst
+    }
+
+    let array = match value {
+        Value::Integer(n) if map.len() == 1 => Array::Integer(scatter(&[Some(*n)], map, len)),
+        Value::Float(n) if map.len() == 1 => Array::Float(scatter(&[Some(*n)], map, len)),
+        Value::Array(Array::Integer(values)) if values.len() == map.len() => {
+            Array::Integer(scatter(values, map, len))
+        }
+        Value::Array(Array::Float(values)) if values.len() == map.len() => {
+            Array::Float(scatter(values, map, len))
+        }
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid local allele field value",
+            ))
+        }
+    };
+
+    Ok(Value::Array(array))
+}
+
+fn localize_value(value: &Value, map: &[usize]) -> io::Result<Value> {
+    fn gather<N: Copy>(src: &[Option<N>], map: &[usize]) -> Vec<Option<N>> {
+        map.iter().map(|&i| src[i]).collect()
+    }
+
+    let array = match value {
+        Value::Integer(n) => Array::Integer(gather(&[Some(*n)], map)),
+        Value::Float(n) => Array::Float(gather(&[Some(*n)], map)),
+        Value::Array(Array::Integer(values)) => Array::Integer(gather(values, map)),
+        Value::Array(Array::Float(values)) => Array::Float(gather(values, map)),
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid allele field value",
+            ))
+        }
+    };
+
+    Ok(Value::Array(array))
+}
+
+fn set_field(samples: &mut Samples, key: &str, values: Vec<Option<Value>>) {
+    let (i, _) = samples.keys.as_mut().insert_full(key.into());
+    let len = samples.keys.as_ref().len();
+
+    for (sample, value) in samples.values.iter_mut().zip(values) {
+        if sample.len() < len {
+            sample.resize(len, None);
+        }
+
+        sample[i] = value;
+    }
+}
+
+fn remove_field(samples: &mut Samples, key: &str) {
+    let Some(i) = samples.keys.as_mut().shift_remove_full(key).map(|(i, _)| i) else {
+        return;
+    };
+
+    for sample in &mut samples.values {
+        if i < sample.len() {
+            sample.remove(i);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variant::record_buf::samples::Keys;
+
+    #[test]
+    fn test_expand() -> io::Result<()> {
+        let keys: Keys = [
+            String::from(key::GENOTYPE),
+            String::from(key::LOCAL_ALTERNATIVE_ALLELE),
+            String::from(key::LOCAL_READ_DEPTHS),
+            String::from(key::LOCAL_ROUNDED_GENOTYPE_LIKELIHOODS),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut samples = Samples::new(
+            keys,
+            vec![
+                vec![
+                    Some(Value::from("0/2")),
+                    Some(Value::from(vec![Some(2)])),
+                    Some(Value::from(vec![Some(5), Some(8)])),
+                    Some(Value::from(vec![Some(30), Some(0), Some(40)])),
+                ],
+                vec![
+                    Some(Value::from("0/0")),
+                    None,
+                    Some(Value::from(vec![Some(9)])),
+                ],
+            ],
+        );
+
+        expand(&mut samples, 3)?;
+
+        let expected_keys: Keys = [
+            String::from(key::GENOTYPE),
+            String::from(key::READ_DEPTHS),
+            String::from(key::ROUNDED_GENOTYPE_LIKELIHOODS),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(samples.keys(), &expected_keys);
+
+        assert_eq!(
+            samples.values,
+            [
+                vec![
+                    Some(Value::from("0/2")),
+                    Some(Value::from(vec![Some(5), None, Some(8)])),
+                    Some(Value::from(vec![
+                        Some(30),
+                        None,
+                        None,
+                        Some(0),
+                        None,
+                        Some(40)
+                    ])),
+                ],
+                vec![
+                    Some(Value::from("0/0")),
+                    Some(Value::from(vec![Some(9), None, None])),
+                    None,
+                ],
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_expand_with_invalid_local_alternative_alleles() {
+        let keys: Keys = [String::from(key::LOCAL_ALTERNATIVE_ALLELE)]
+            .into_iter()
+            .collect();
+        let mut samples = Samples::new(keys, vec![vec![Some(Value::from(vec![Some(3)]))]]);
+
+        assert!(matches!(
+            expand(&mut samples, 3),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_localize() -> io::Result<()> {
+        let keys: Keys = [
+            String::from(key::GENOTYPE),
+            String::from(key::READ_DEPTHS),
+            String::from(key::ROUNDED_GENOTYPE_LIKELIHOODS),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut samples = Samples::new(
+            keys,
+            vec![
+                vec![
+                    Some(Value::from("0/2")),
+                    Some(Value::from(vec![Some(5), Some(1), Some(8)])),
+                    Some(Value::from(vec![
+                        Some(30),
+                        Some(20),
+                        Some(50),
+                        Some(0),
+                        Some(25),
+                        Some(40),
+                    ])),
+                ],
+                vec![
+                    Some(Value::from("0/0")),
+                    Some(Value::from(vec![Some(9), Some(0), Some(0)])),
+                ],
+            ],
+        );
+
+        localize(&mut samples, 3)?;
+
+        let expected_keys: Keys = [
+            String::from(key::GENOTYPE),
+            String::from(key::LOCAL_READ_DEPTHS),
+            String::from(key::LOCAL_ROUNDED_GENOTYPE_LIKELIHOODS),
+            String::from(key::LOCAL_ALTERNATIVE_ALLELE),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(samples.keys(), &expected_keys);
+
+        assert_eq!(
+            samples.values,
+            [
+                vec![
+                    Some(Value::from("0/2")),
+                    Some(Value::from(vec![Some(5), Some(8)])),
+                    Some(Value::from(vec![Some(30), Some(0), Some(40)])),
+                    Some(Value::from(vec![Some(2)])),
+                ],
+                vec![
+                    Some(Value::from("0/0")),
+                    Some(Value::from(vec![Some(9)])),
+                    None,
+                    None,
+                ],
+            ]
+        );
+
+        Ok(())
+    }
+}
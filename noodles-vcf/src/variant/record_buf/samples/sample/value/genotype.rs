@@ -6,12 +6,155 @@ mod parser;
 pub use self::{allele::Allele, parser::ParseError};
 use crate::variant::record::samples::series::value::genotype::Phasing;
 
-use std::{io, str::FromStr};
+use std::{fmt, io, str::FromStr};
 
 /// A variant record samples genotype value.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Genotype(Vec<Allele>);
 
+impl Genotype {
+    /// Returns the ploidy, i.e., the number of alleles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::samples::sample::value::Genotype;
+    ///
+    /// let genotype: Genotype = "0/1".parse()?;
+    /// assert_eq!(genotype.ploidy(), 2);
+    ///
+    /// let genotype: Genotype = "1".parse()?;
+    /// assert_eq!(genotype.ploidy(), 1);
+    /// # Ok::<_, noodles_vcf::variant::record_buf::samples::sample::value::genotype::ParseError>(())
+    /// ```
+    pub fn ploidy(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether all alleles are phased.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::samples::sample::value::Genotype;
+    ///
+    /// let genotype: Genotype = "0|1".parse()?;
+    /// assert!(genotype.is_phased());
+    ///
+    /// let genotype: Genotype = "0/1".parse()?;
+    /// assert!(!genotype.is_phased());
+    /// # Ok::<_, noodles_vcf::variant::record_buf::samples::sample::value::genotype::ParseError>(())
+    /// ```
+    pub fn is_phased(&self) -> bool {
+        self.0
+            .iter()
+            .all(|allele| allele.phasing() == Phasing::Phased)
+    }
+
+    /// Returns whether all alleles are missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::samples::sample::value::Genotype;
+    ///
+    /// let genotype: Genotype = "./.".parse()?;
+    /// assert!(genotype.is_missing());
+    ///
+    /// let genotype: Genotype = "0/.".parse()?;
+    /// assert!(!genotype.is_missing());
+    /// # Ok::<_, noodles_vcf::variant::record_buf::samples::sample::value::genotype::ParseError>(())
+    /// ```
+    pub fn is_missing(&self) -> bool {
+        self.0.iter().all(|allele| allele.position().is_none())
+    }
+
+    /// Returns whether the genotype is homozygous for the reference allele.
+    ///
+    /// This is false if any allele is missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::samples::sample::value::Genotype;
+    ///
+    /// let genotype: Genotype = "0/0".parse()?;
+    /// assert!(genotype.is_hom_ref());
+    ///
+    /// let genotype: Genotype = "0/.".parse()?;
+    /// assert!(!genotype.is_hom_ref());
+    /// # Ok::<_, noodles_vcf::variant::record_buf::samples::sample::value::genotype::ParseError>(())
+    /// ```
+    pub fn is_hom_ref(&self) -> bool {
+        !self.0.is_empty() && self.0.iter().all(|allele| allele.position() == Some(0))
+    }
+
+    /// Returns whether the genotype is homozygous for an alternate allele.
+    ///
+    /// This is false if any allele is missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::samples::sample::value::Genotype;
+    ///
+    /// let genotype: Genotype = "1|1".parse()?;
+    /// assert!(genotype.is_hom_alt());
+    ///
+    /// let genotype: Genotype = "1/2".parse()?;
+    /// assert!(!genotype.is_hom_alt());
+    /// # Ok::<_, noodles_vcf::variant::record_buf::samples::sample::value::genotype::ParseError>(())
+    /// ```
+    pub fn is_hom_alt(&self) -> bool {
+        match self.called_positions() {
+            Some((first, rest)) => first > 0 && rest.iter().all(|&position| position == first),
+            None => false,
+        }
+    }
+
+    /// Returns whether the genotype is heterozygous.
+    ///
+    /// A genotype is heterozygous if it has at least two different alleles. This is false if any
+    /// allele is missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::samples::sample::value::Genotype;
+    ///
+    /// let genotype: Genotype = "0/1".parse()?;
+    /// assert!(genotype.is_het());
+    ///
+    /// let genotype: Genotype = "1|2".parse()?;
+    /// assert!(genotype.is_het());
+    ///
+    /// let genotype: Genotype = "0/0".parse()?;
+    /// assert!(!genotype.is_het());
+    ///
+    /// let genotype: Genotype = "0/.".parse()?;
+    /// assert!(!genotype.is_het());
+    /// # Ok::<_, noodles_vcf::variant::record_buf::samples::sample::value::genotype::ParseError>(())
+    /// ```
+    pub fn is_het(&self) -> bool {
+        match self.called_positions() {
+            Some((first, rest)) => rest.iter().any(|&position| position != first),
+            None => false,
+        }
+    }
+
+    fn called_positions(&self) -> Option<(usize, Vec<usize>)> {
+        let positions: Option<Vec<_>> = self.0.iter().map(|allele| allele.position()).collect();
+        let mut positions = positions?;
+
+        if positions.is_empty() {
+            None
+        } else {
+            let first = positions.remove(0);
+            Some((first, positions))
+        }
+    }
+}
+
 impl AsRef<[Allele]> for Genotype {
     fn as_ref(&self) -> &[Allele] {
         &self.0
@@ -24,6 +167,55 @@ impl AsMut<Vec<Allele>> for Genotype {
     }
 }
 
+impl fmt::Display for Genotype {
+    /// Formats the genotype as a VCF genotype string.
+    ///
+    /// The phasing of the first allele is only written when it differs from the phasing inferred
+    /// when parsing, i.e., phased if and only if all other alleles are phased. This makes the
+    /// output round-trip through [`FromStr`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn write_phasing(f: &mut fmt::Formatter<'_>, phasing: Phasing) -> fmt::Result {
+            match phasing {
+                Phasing::Phased => f.write_str("|"),
+                Phasing::Unphased => f.write_str("/"),
+            }
+        }
+
+        fn write_position(f: &mut fmt::Formatter<'_>, position: Option<usize>) -> fmt::Result {
+            match position {
+                Some(n) => write!(f, "{n}"),
+                None => f.write_str("."),
+            }
+        }
+
+        let Some((first, rest)) = self.0.split_first() else {
+            return Ok(());
+        };
+
+        let implicit_phasing = if rest
+            .iter()
+            .all(|allele| allele.phasing() == Phasing::Phased)
+        {
+            Phasing::Phased
+        } else {
+            Phasing::Unphased
+        };
+
+        if first.phasing() != implicit_phasing {
+            write_phasing(f, first.phasing())?;
+        }
+
+        write_position(f, first.position())?;
+
+        for allele in rest {
+            write_phasing(f, allele.phasing())?;
+            write_position(f, allele.position())?;
+        }
+
+        Ok(())
+    }
+}
+
 impl FromStr for Genotype {
     type Err = ParseError;
 
@@ -137,4 +329,44 @@ mod tests {
             Err(ParseError::InvalidAllele(_))
         ));
     }
+
+    #[test]
+    fn test_fmt() -> Result<(), ParseError> {
+        for s in [
+            "0/1", "1|0", "./.", "0", "0/1/2", "0/1|2", "|0/1/2", "/0|1", "/1",
+        ] {
+            let genotype: Genotype = s.parse()?;
+            assert_eq!(genotype.to_string(), s);
+        }
+
+        assert_eq!(Genotype::default().to_string(), "");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_zygosity() -> Result<(), ParseError> {
+        fn t(s: &str, is_hom_ref: bool, is_hom_alt: bool, is_het: bool) -> Result<(), ParseError> {
+            let genotype: Genotype = s.parse()?;
+            assert_eq!(genotype.is_hom_ref(), is_hom_ref, "{s}");
+            assert_eq!(genotype.is_hom_alt(), is_hom_alt, "{s}");
+            assert_eq!(genotype.is_het(), is_het, "{s}");
+            Ok(())
+        }
+
+        t("0/0", true, false, false)?;
+        t("0", true, false, false)?;
+        t("1/1", false, true, false)?;
+        t("2|2|2", false, true, false)?;
+        t("0/1", false, false, true)?;
+        t("1|2", false, false, true)?;
+        t("0/0/1", false, false, true)?;
+        t("./.", false, false, false)?;
+        t("0/.", false, false, false)?;
+
+        assert!(!Genotype::default().is_hom_ref());
+        assert!(!Genotype::default().is_het());
+
+        Ok(())
+    }
 }
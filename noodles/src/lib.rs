@@ -1,9 +1,13 @@
 #![warn(missing_docs)]
 
 //! **noodles** attempts to provide specification-compliant (when applicable) implementations of
-//! libraries for handling various bioinformatics file formats. It currently supports BAM 1.6, BCF
-//! 2.2, BED, BGZF, CRAM 3.0/3.1, CSI, FASTA, FASTQ, GFF3, GTF 2.2, htsget 1.3, refget 2.0, SAM
-//! 1.6, tabix, and VCF 4.3/4.4.
+//! libraries for handling various bioinformatics file formats. It currently supports AGP 2.1, BAM
+//! 1.6, BCF 2.2, BED, BGZF, CRAM 3.0/3.1, CSI, FASTA, FASTQ, GFA 1/2, GFF3, GTF 2.2, htsget 1.3,
+//! MAF, PAF, refget 2.0, SAM 1.6, tabix, and VCF 4.3/4.4.
+
+#[cfg(feature = "agp")]
+#[doc(inline)]
+pub use noodles_agp as agp;
 
 #[cfg(feature = "bam")]
 #[doc(inline)]
@@ -41,6 +45,10 @@ pub use noodles_fasta as fasta;
 #[doc(inline)]
 pub use noodles_fastq as fastq;
 
+#[cfg(feature = "gfa")]
+#[doc(inline)]
+pub use noodles_gfa as gfa;
+
 #[cfg(feature = "gff")]
 #[doc(inline)]
 pub use noodles_gff as gff;
@@ -53,6 +61,14 @@ pub use noodles_gtf as gtf;
 #[doc(inline)]
 pub use noodles_htsget as htsget;
 
+#[cfg(feature = "maf")]
+#[doc(inline)]
+pub use noodles_maf as maf;
+
+#[cfg(feature = "paf")]
+#[doc(inline)]
+pub use noodles_paf as paf;
+
 #[cfg(feature = "refget")]
 #[doc(inline)]
 pub use noodles_refget as refget;